@@ -32,6 +32,10 @@ pub struct InferenceConfig {
     /// System prompt to use by default
     #[serde(default)]
     pub system_prompt: Option<String>,
+
+    /// Load the default model during startup instead of on first use
+    #[serde(default = "default_warmup_on_start")]
+    pub warmup_on_start: bool,
 }
 
 fn default_base_url() -> String {
@@ -58,6 +62,10 @@ const fn default_top_p() -> f32 {
     0.9
 }
 
+const fn default_warmup_on_start() -> bool {
+    true
+}
+
 impl Default for InferenceConfig {
     fn default() -> Self {
         Self {
@@ -68,6 +76,7 @@ impl Default for InferenceConfig {
             temperature: default_temperature(),
             top_p: default_top_p(),
             system_prompt: None,
+            warmup_on_start: default_warmup_on_start(),
         }
     }
 }
@@ -113,6 +122,7 @@ mod tests {
         assert!((config.temperature - 0.7).abs() < 0.01);
         assert!((config.top_p - 0.9).abs() < 0.01);
         assert!(config.system_prompt.is_none());
+        assert!(config.warmup_on_start);
     }
 
     #[test]
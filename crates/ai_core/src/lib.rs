@@ -9,9 +9,11 @@ pub mod error;
 pub mod ollama;
 pub mod ports;
 pub mod selector;
+pub mod tokens;
 
 pub use config::InferenceConfig;
 pub use error::InferenceError;
 pub use ollama::{EmbeddingConfig, EmbeddingEngine, OllamaEmbeddingEngine, OllamaInferenceEngine};
 pub use ports::{InferenceEngine, InferenceRequest, InferenceResponse, StreamingChunk};
 pub use selector::{ModelSelector, ModelSelectorConfig, TaskComplexity};
+pub use tokens::{TokenizerFamily, estimate_tokens, estimate_usage};
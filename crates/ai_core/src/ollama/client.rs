@@ -17,6 +17,7 @@ use crate::{
     config::InferenceConfig,
     error::InferenceError,
     ports::{InferenceEngine, InferenceRequest, InferenceResponse, StreamingResponse, TokenUsage},
+    tokens::estimate_usage,
 };
 
 /// Ollama-compatible inference engine
@@ -87,6 +88,16 @@ impl OllamaInferenceEngine {
             .unwrap_or_else(|| self.current_model.read().clone())
     }
 
+    /// Sampling options for a request, falling back to the configured defaults
+    fn options(&self, request: &InferenceRequest) -> OllamaOptions {
+        OllamaOptions {
+            temperature: request.temperature.or(Some(self.config.temperature)),
+            num_predict: request.max_tokens.or(Some(self.config.max_tokens)),
+            top_p: request.top_p.or(Some(self.config.top_p)),
+            seed: request.seed,
+        }
+    }
+
     /// Set the default model to use for requests
     pub fn set_default_model(&self, model_name: &str) {
         *self.current_model.write() = model_name.to_string();
@@ -101,6 +112,8 @@ struct OllamaChatRequest {
     messages: Vec<OllamaMessage>,
     stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<OllamaOptions>,
 }
 
@@ -118,6 +131,8 @@ struct OllamaOptions {
     num_predict: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 /// Ollama-format chat response
@@ -170,11 +185,8 @@ impl InferenceEngine for OllamaInferenceEngine {
                 })
                 .collect(),
             stream: false,
-            options: Some(OllamaOptions {
-                temperature: request.temperature.or(Some(self.config.temperature)),
-                num_predict: request.max_tokens.or(Some(self.config.max_tokens)),
-                top_p: Some(self.config.top_p),
-            }),
+            format: request.format.clone(),
+            options: Some(self.options(&request)),
         };
 
         debug!("Sending request to Ollama server");
@@ -209,7 +221,12 @@ impl InferenceEngine for OllamaInferenceEngine {
                 completion_tokens: completion,
                 total_tokens: prompt + completion,
             }),
-            _ => None,
+            // hailo-ollama reports no eval counts
+            _ => Some(estimate_usage(
+                &request.messages,
+                &ollama_response.message.content,
+                &ollama_response.model,
+            )),
         };
 
         debug!(
@@ -247,11 +264,8 @@ impl InferenceEngine for OllamaInferenceEngine {
                 })
                 .collect(),
             stream: true,
-            options: Some(OllamaOptions {
-                temperature: request.temperature.or(Some(self.config.temperature)),
-                num_predict: request.max_tokens.or(Some(self.config.max_tokens)),
-                top_p: Some(self.config.top_p),
-            }),
+            format: request.format.clone(),
+            options: Some(self.options(&request)),
         };
 
         debug!("Starting streaming request to Ollama server");
@@ -367,6 +381,7 @@ mod tests {
                 content: "Hello".to_string(),
             }],
             stream: false,
+            format: None,
             options: None,
         };
         let json = serde_json::to_string(&request).unwrap();
@@ -380,10 +395,12 @@ mod tests {
             model: "test".to_string(),
             messages: vec![],
             stream: false,
+            format: None,
             options: Some(OllamaOptions {
                 temperature: Some(0.7),
                 num_predict: None,
                 top_p: None,
+                seed: None,
             }),
         };
         let json = serde_json::to_string(&request).unwrap();
@@ -391,6 +408,26 @@ mod tests {
         assert!(!json.contains("num_predict"));
     }
 
+    #[test]
+    fn options_fall_back_to_config() {
+        let engine = OllamaInferenceEngine::new(InferenceConfig::default()).unwrap();
+        let defaults = engine.options(&InferenceRequest::simple("test"));
+        assert_eq!(defaults.temperature, Some(engine.config.temperature));
+        assert_eq!(defaults.num_predict, Some(engine.config.max_tokens));
+        assert_eq!(defaults.top_p, Some(engine.config.top_p));
+        assert_eq!(defaults.seed, None);
+
+        let request = InferenceRequest::simple("test")
+            .with_top_p(0.5)
+            .with_max_tokens(32)
+            .deterministic(7);
+        let options = engine.options(&request);
+        assert_eq!(options.temperature, Some(0.0));
+        assert_eq!(options.num_predict, Some(32));
+        assert_eq!(options.top_p, Some(0.5));
+        assert_eq!(options.seed, Some(7));
+    }
+
     #[test]
     fn engine_has_debug() {
         let engine = OllamaInferenceEngine::with_defaults().unwrap();
@@ -25,6 +25,15 @@ pub struct InferenceRequest {
     /// Temperature for sampling
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
+    /// Top-p (nucleus) sampling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Random seed; with temperature 0 the output is reproducible
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Structured output: `"json"` or a JSON schema the reply must follow
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<serde_json::Value>,
     /// Whether to stream the response
     #[serde(default)]
     pub stream: bool,
@@ -61,6 +70,9 @@ impl InferenceRequest {
             model: None,
             max_tokens: None,
             temperature: None,
+            top_p: None,
+            seed: None,
+            format: None,
             stream: false,
         }
     }
@@ -81,6 +93,9 @@ impl InferenceRequest {
             model: None,
             max_tokens: None,
             temperature: None,
+            top_p: None,
+            seed: None,
+            format: None,
             stream: false,
         }
     }
@@ -105,6 +120,40 @@ impl InferenceRequest {
         self.temperature = Some(temp);
         self
     }
+
+    /// Set top-p (nucleus) sampling
+    #[must_use]
+    pub const fn with_top_p(mut self, top_p: f32) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// Set the maximum number of tokens to generate
+    #[must_use]
+    pub const fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Set the random seed
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Make the output reproducible: temperature 0 and a fixed seed
+    #[must_use]
+    pub const fn deterministic(self, seed: u64) -> Self {
+        self.with_temperature(0.0).with_seed(seed)
+    }
+
+    /// Constrain the reply to JSON matching `schema`
+    #[must_use]
+    pub fn with_json_schema(mut self, schema: serde_json::Value) -> Self {
+        self.format = Some(schema);
+        self
+    }
 }
 
 /// Response from inference
@@ -264,6 +313,24 @@ mod tests {
         assert!(!json.contains("model"));
         assert!(!json.contains("max_tokens"));
         assert!(!json.contains("temperature"));
+        assert!(!json.contains("top_p"));
+        assert!(!json.contains("seed"));
+        assert!(!json.contains("format"));
+    }
+
+    #[test]
+    fn inference_request_sampling_parameters() {
+        let req = InferenceRequest::simple("Test")
+            .with_top_p(0.9)
+            .with_max_tokens(64)
+            .deterministic(42);
+        assert_eq!(req.temperature, Some(0.0));
+        assert_eq!(req.top_p, Some(0.9));
+        assert_eq!(req.max_tokens, Some(64));
+        assert_eq!(req.seed, Some(42));
+
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains("\"seed\":42"));
     }
 
     #[test]
@@ -2,11 +2,16 @@
 //!
 //! Provides intelligent routing of inference requests to appropriate models
 //! based on task complexity, resource availability, and performance requirements.
+//!
+//! With a model registry attached, selection first narrows the candidates to
+//! models advertising the required capabilities (e.g. tool calling), then
+//! applies the complexity heuristic among them.
 
 use std::sync::Arc;
 
+use application::ports::{ModelCapability, ModelRegistryPort};
 use async_trait::async_trait;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
 use crate::{
     error::InferenceError,
@@ -26,6 +31,9 @@ pub struct ModelSelectorConfig {
     pub complexity_keywords: Vec<String>,
     /// Maximum prompt length for small model (characters)
     pub small_model_max_prompt_chars: usize,
+    /// Capabilities every selected model must advertise (checked only when
+    /// a model registry is attached)
+    pub required_capabilities: Vec<ModelCapability>,
 }
 
 impl Default for ModelSelectorConfig {
@@ -47,6 +55,7 @@ impl Default for ModelSelectorConfig {
                 "research".to_string(),
             ],
             small_model_max_prompt_chars: 500,
+            required_capabilities: Vec::new(),
         }
     }
 }
@@ -70,17 +79,31 @@ impl std::fmt::Display for TaskComplexity {
 }
 
 /// Model selector that routes requests based on complexity
-#[derive(Debug)]
 pub struct ModelSelector<E: InferenceEngine> {
     engine: Arc<E>,
     config: ModelSelectorConfig,
+    registry: Option<Arc<dyn ModelRegistryPort>>,
+}
+
+impl<E: InferenceEngine + std::fmt::Debug> std::fmt::Debug for ModelSelector<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModelSelector")
+            .field("engine", &self.engine)
+            .field("config", &self.config)
+            .field("has_registry", &self.registry.is_some())
+            .finish()
+    }
 }
 
 impl<E: InferenceEngine> ModelSelector<E> {
     /// Create a new model selector
     #[allow(clippy::missing_const_for_fn)]
     pub fn new(engine: Arc<E>, config: ModelSelectorConfig) -> Self {
-        Self { engine, config }
+        Self {
+            engine,
+            config,
+            registry: None,
+        }
     }
 
     /// Create with default configuration
@@ -88,6 +111,13 @@ impl<E: InferenceEngine> ModelSelector<E> {
         Self::new(engine, ModelSelectorConfig::default())
     }
 
+    /// Attach a model registry for capability-aware selection
+    #[must_use]
+    pub fn with_registry(mut self, registry: Arc<dyn ModelRegistryPort>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
     /// Analyze the complexity of a request
     #[must_use]
     pub fn analyze_complexity(&self, request: &InferenceRequest) -> TaskComplexity {
@@ -142,6 +172,86 @@ impl<E: InferenceEngine> ModelSelector<E> {
         }
     }
 
+    /// Select a model that advertises all `required` capabilities
+    ///
+    /// Prefers the model picked by complexity, then the other configured
+    /// model, then any capable model from the registry. Without a registry,
+    /// or if no model is capable, falls back to the complexity choice.
+    pub async fn select_capable_model(
+        &self,
+        complexity: TaskComplexity,
+        required: &[ModelCapability],
+    ) -> String {
+        let preferred = self.select_model(complexity);
+        let Some(ref registry) = self.registry else {
+            return preferred.to_string();
+        };
+        if required.is_empty() {
+            return preferred.to_string();
+        }
+
+        let capable: Vec<String> = match registry.list_models().await {
+            Ok(models) => models
+                .into_iter()
+                .filter(|m| m.available && m.capabilities.supports_all(required))
+                .map(|m| m.id)
+                .collect(),
+            Err(e) => {
+                warn!(error = %e, "Model registry unavailable, selecting by complexity only");
+                return preferred.to_string();
+            },
+        };
+
+        let alternate = match complexity {
+            TaskComplexity::Simple => &self.config.large_model,
+            TaskComplexity::Complex => &self.config.small_model,
+        };
+        let chosen = [preferred, alternate.as_str()]
+            .into_iter()
+            .find(|candidate| capable.iter().any(|id| id == candidate))
+            .or_else(|| capable.first().map(String::as_str));
+
+        if let Some(model) = chosen {
+            if model != preferred {
+                debug!(
+                    preferred = %preferred,
+                    model = %model,
+                    required = ?required,
+                    "Preferred model lacks required capabilities"
+                );
+            }
+            return model.to_string();
+        }
+
+        warn!(
+            required = ?required,
+            model = %preferred,
+            "No available model advertises the required capabilities, using complexity choice"
+        );
+        preferred.to_string()
+    }
+
+    /// Apply a model that advertises all `required` capabilities to the request
+    pub async fn apply_capable_model(
+        &self,
+        request: InferenceRequest,
+        required: &[ModelCapability],
+    ) -> InferenceRequest {
+        // If request already has a model, respect it
+        if request.model.is_some() {
+            return request;
+        }
+
+        let complexity = self.analyze_complexity(&request);
+        let model = self.select_capable_model(complexity, required).await;
+        debug!(
+            complexity = %complexity,
+            model = %model,
+            "Selected model for request"
+        );
+        request.with_model(model)
+    }
+
     /// Apply the selected model to the request
     #[must_use]
     pub fn apply_model(&self, request: InferenceRequest) -> InferenceRequest {
@@ -191,7 +301,9 @@ impl<E: InferenceEngine + 'static> InferenceEngine for ModelSelector<E> {
         &self,
         request: InferenceRequest,
     ) -> Result<InferenceResponse, InferenceError> {
-        let request = self.apply_model(request);
+        let request = self
+            .apply_capable_model(request, &self.config.required_capabilities)
+            .await;
         tracing::Span::current().record(
             "selected_model",
             request.model.as_deref().unwrap_or("default"),
@@ -203,7 +315,9 @@ impl<E: InferenceEngine + 'static> InferenceEngine for ModelSelector<E> {
         &self,
         request: InferenceRequest,
     ) -> Result<StreamingResponse, InferenceError> {
-        let request = self.apply_model(request);
+        let request = self
+            .apply_capable_model(request, &self.config.required_capabilities)
+            .await;
         self.engine.generate_stream(request).await
     }
 
@@ -228,6 +342,11 @@ impl<E: InferenceEngine + 'static> InferenceEngine for ModelSelector<E> {
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
+    use application::{
+        error::ApplicationError,
+        ports::{ModelCapabilities, ModelInfo},
+    };
+
     use super::*;
     use crate::ports::{InferenceMessage, TokenUsage};
 
@@ -293,6 +412,50 @@ mod tests {
         }
     }
 
+    /// Registry serving a fixed model list
+    struct StaticRegistry(Vec<ModelInfo>);
+
+    #[async_trait]
+    impl ModelRegistryPort for StaticRegistry {
+        async fn list_models(&self) -> Result<Vec<ModelInfo>, ApplicationError> {
+            Ok(self.0.clone())
+        }
+
+        async fn get_model(&self, model_id: &str) -> Result<Option<ModelInfo>, ApplicationError> {
+            Ok(self.0.iter().find(|m| m.id == model_id).cloned())
+        }
+
+        async fn refresh(&self) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    fn model_info(id: &str, tools: bool) -> ModelInfo {
+        ModelInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            capabilities: ModelCapabilities {
+                text_generation: true,
+                chat: true,
+                tools,
+                ..Default::default()
+            },
+            variant: None,
+            parameter_size: None,
+            available: true,
+        }
+    }
+
+    fn selector_with_registry(models: Vec<ModelInfo>) -> ModelSelector<MockInferenceEngine> {
+        let config = ModelSelectorConfig {
+            required_capabilities: vec![ModelCapability::ToolCalling],
+            ..Default::default()
+        };
+        ModelSelector::new(Arc::new(MockInferenceEngine::new()), config)
+            .with_registry(Arc::new(StaticRegistry(models)))
+    }
+
     // === Configuration Tests ===
 
     #[test]
@@ -529,6 +692,80 @@ mod tests {
         assert_eq!(selector.default_model(), "qwen2.5-1.5b-instruct");
     }
 
+    // === Capability Tests ===
+
+    #[tokio::test]
+    async fn capable_smaller_model_replaces_incapable_large_model() {
+        let selector = selector_with_registry(vec![
+            model_info("qwen2.5-1.5b-instruct", true),
+            model_info("qwen2.5-7b-instruct", false),
+        ]);
+
+        let model = selector
+            .select_capable_model(TaskComplexity::Complex, &[ModelCapability::ToolCalling])
+            .await;
+
+        assert_eq!(model, "qwen2.5-1.5b-instruct");
+    }
+
+    #[tokio::test]
+    async fn preferred_model_kept_when_capable() {
+        let selector = selector_with_registry(vec![
+            model_info("qwen2.5-1.5b-instruct", true),
+            model_info("qwen2.5-7b-instruct", true),
+        ]);
+
+        let model = selector
+            .select_capable_model(TaskComplexity::Complex, &[ModelCapability::ToolCalling])
+            .await;
+
+        assert_eq!(model, "qwen2.5-7b-instruct");
+    }
+
+    #[tokio::test]
+    async fn unconfigured_capable_model_is_used_as_last_resort() {
+        let selector = selector_with_registry(vec![
+            model_info("qwen2.5-1.5b-instruct", false),
+            model_info("qwen2.5-7b-instruct", false),
+            model_info("llama3.1:8b", true),
+        ]);
+
+        let model = selector
+            .select_capable_model(TaskComplexity::Simple, &[ModelCapability::ToolCalling])
+            .await;
+
+        assert_eq!(model, "llama3.1:8b");
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_complexity_choice_without_capable_model() {
+        let selector = selector_with_registry(vec![
+            model_info("qwen2.5-1.5b-instruct", false),
+            model_info("qwen2.5-7b-instruct", false),
+        ]);
+
+        let model = selector
+            .select_capable_model(TaskComplexity::Complex, &[ModelCapability::ToolCalling])
+            .await;
+
+        assert_eq!(model, "qwen2.5-7b-instruct");
+    }
+
+    #[tokio::test]
+    async fn generate_applies_required_capabilities() {
+        let selector = selector_with_registry(vec![
+            model_info("qwen2.5-1.5b-instruct", true),
+            model_info("qwen2.5-7b-instruct", false),
+        ]);
+
+        let response = selector
+            .generate(InferenceRequest::simple("analyze this"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.model, "qwen2.5-1.5b-instruct");
+    }
+
     // === Display Tests ===
 
     #[test]
@@ -558,6 +795,9 @@ mod tests {
             model: None,
             max_tokens: None,
             temperature: None,
+            top_p: None,
+            seed: None,
+            format: None,
             stream: false,
         };
 
@@ -590,6 +830,9 @@ mod tests {
             model: None,
             max_tokens: None,
             temperature: None,
+            top_p: None,
+            seed: None,
+            format: None,
             stream: false,
         };
 
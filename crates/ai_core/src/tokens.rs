@@ -0,0 +1,309 @@
+//! Token count estimation
+//!
+//! Approximates how many tokens a model's tokenizer produces for a text
+//! without loading the tokenizer or asking the backend. Used for context
+//! trimming and for usage figures when the backend reports none
+//! (hailo-ollama does not return eval counts).
+//!
+//! The estimate splits the text into runs of letters, digits, whitespace,
+//! punctuation and CJK characters and applies per-family rules for each
+//! run. It is usually within 20% of the real count for prose; code and
+//! symbol-heavy text tends to be overestimated.
+
+use crate::ports::{InferenceMessage, TokenUsage};
+
+/// Tokens added per chat message for role markers and separators
+const MESSAGE_OVERHEAD: usize = 4;
+
+/// Tokenizer family of a model, as far as it matters for estimates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenizerFamily {
+    /// Llama 3, Phi-4, DeepSeek and other 100k+ BPE vocabularies that
+    /// group digits in threes
+    Llama3,
+    /// Qwen 2 and later: large vocabulary, digits split one by one
+    Qwen,
+    /// Gemma: 256k vocabulary, digits split one by one
+    Gemma,
+    /// Llama 2, Mistral, Phi-3 and other 32k `SentencePiece` vocabularies
+    SentencePiece,
+}
+
+impl TokenizerFamily {
+    /// Guess the tokenizer family from an Ollama model name
+    ///
+    /// Unknown models are treated as [`Self::Llama3`], which matches most
+    /// current open-weight models.
+    #[must_use]
+    pub fn from_model(model: &str) -> Self {
+        let model = model.to_lowercase();
+
+        if model.contains("qwen") {
+            Self::Qwen
+        } else if model.contains("gemma") {
+            Self::Gemma
+        } else if model.contains("llama2")
+            || model.contains("llama-2")
+            || model.contains("mistral")
+            || model.contains("mixtral")
+            || model.contains("phi3")
+            || model.contains("phi-3")
+            || model.contains("tinyllama")
+        {
+            Self::SentencePiece
+        } else {
+            Self::Llama3
+        }
+    }
+
+    /// Estimate the number of tokens in `text`
+    #[must_use]
+    pub fn estimate(self, text: &str) -> usize {
+        let mut tokens = 0;
+        let mut chars = text.chars().peekable();
+
+        while let Some(first) = chars.next() {
+            let class = CharClass::of(first);
+            let mut bytes = first.len_utf8();
+            let mut count = 1;
+            while let Some(&next) = chars.peek() {
+                if CharClass::of(next) != class {
+                    break;
+                }
+                bytes += next.len_utf8();
+                count += 1;
+                chars.next();
+            }
+            tokens += self.run_tokens(class, bytes, count);
+        }
+
+        tokens
+    }
+
+    /// Bytes of a word covered by one token beyond the first
+    const fn bytes_per_piece(self) -> usize {
+        match self {
+            Self::Llama3 | Self::Qwen => 6,
+            Self::Gemma => 7,
+            Self::SentencePiece => 5,
+        }
+    }
+
+    /// Digits merged into one token
+    const fn digits_per_token(self) -> usize {
+        match self {
+            Self::Llama3 => 3,
+            Self::Qwen | Self::Gemma | Self::SentencePiece => 1,
+        }
+    }
+
+    /// CJK characters merged into one token
+    const fn ideographs_per_token(self) -> usize {
+        match self {
+            Self::Llama3 | Self::Qwen | Self::Gemma => 2,
+            Self::SentencePiece => 1,
+        }
+    }
+
+    /// Tokens for a run of `count` characters (`bytes` in UTF-8) of one class
+    fn run_tokens(self, class: CharClass, bytes: usize, count: usize) -> usize {
+        match class {
+            // Common words are a single token; long or non-ASCII words split
+            CharClass::Letter => 1 + (bytes - 1) / self.bytes_per_piece(),
+            CharClass::Digit => count.div_ceil(self.digits_per_token()),
+            CharClass::Ideograph => count.div_ceil(self.ideographs_per_token()),
+            // Punctuation pairs like `);` or `**` are usually merged
+            CharClass::Symbol => bytes.div_ceil(2),
+            // A single space is part of the following word's token
+            CharClass::Space => usize::from(count > 1),
+            CharClass::Newline => 1,
+        }
+    }
+}
+
+/// Character classes with different tokenization behaviour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Letter,
+    Digit,
+    Ideograph,
+    Symbol,
+    Space,
+    Newline,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c == '\n' || c == '\r' {
+            Self::Newline
+        } else if c.is_whitespace() {
+            Self::Space
+        } else if c.is_ascii_digit() {
+            Self::Digit
+        } else if is_ideograph(c) {
+            Self::Ideograph
+        } else if c.is_alphanumeric() || c == '\'' {
+            Self::Letter
+        } else {
+            Self::Symbol
+        }
+    }
+}
+
+/// Whether `c` is a CJK ideograph, kana or hangul syllable
+const fn is_ideograph(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'   // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+    )
+}
+
+/// Estimate the number of tokens `model` produces for `text`
+///
+/// # Example
+///
+/// ```
+/// use ai_core::estimate_tokens;
+///
+/// assert_eq!(estimate_tokens("Hello, world!", "llama3.2:3b"), 4);
+/// assert_eq!(estimate_tokens("", "qwen2.5:1.5b"), 0);
+/// ```
+#[must_use]
+pub fn estimate_tokens(text: &str, model: &str) -> usize {
+    TokenizerFamily::from_model(model).estimate(text)
+}
+
+/// Estimate token usage for a chat exchange
+///
+/// Counts every message plus a small per-message overhead for the chat
+/// template, and the completion.
+#[must_use]
+pub fn estimate_usage(messages: &[InferenceMessage], completion: &str, model: &str) -> TokenUsage {
+    let family = TokenizerFamily::from_model(model);
+    let prompt: usize = messages
+        .iter()
+        .map(|m| family.estimate(&m.content) + MESSAGE_OVERHEAD)
+        .sum();
+    let prompt_tokens = u32::try_from(prompt).unwrap_or(u32::MAX);
+    let completion_tokens = u32::try_from(family.estimate(completion)).unwrap_or(u32::MAX);
+
+    TokenUsage {
+        prompt_tokens,
+        completion_tokens,
+        total_tokens: prompt_tokens.saturating_add(completion_tokens),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assert that `estimate` is within `percent` of the real `known` count
+    fn assert_within(estimate: usize, known: usize, percent: usize) {
+        let slack = (known * percent).div_ceil(100);
+        assert!(
+            estimate.abs_diff(known) <= slack,
+            "estimate {estimate} not within {percent}% of {known}"
+        );
+    }
+
+    #[test]
+    fn empty_text_has_no_tokens() {
+        assert_eq!(estimate_tokens("", "llama3.2"), 0);
+        assert_eq!(estimate_tokens("", "mistral"), 0);
+    }
+
+    #[test]
+    fn short_english_matches_known_counts() {
+        // "Hello" "," " world" "!"
+        assert_within(estimate_tokens("Hello, world!", "llama3.2:3b"), 4, 0);
+        assert_within(estimate_tokens("Hello, world!", "mistral:7b"), 4, 0);
+        assert_within(
+            estimate_tokens(
+                "The quick brown fox jumps over the lazy dog.",
+                "llama3.2:3b",
+            ),
+            10,
+            10,
+        );
+    }
+
+    #[test]
+    fn longer_prose_is_within_band() {
+        let text = ["The quick brown fox jumps over the lazy dog."; 10].join(" ");
+
+        assert_within(estimate_tokens(&text, "qwen2.5:1.5b"), 100, 20);
+    }
+
+    #[test]
+    fn german_text_is_within_band() {
+        // Llama 3 tokenizer: 14 tokens
+        let text = "Guten Morgen! Hier ist deine Zusammenfassung für heute.";
+
+        assert_within(estimate_tokens(text, "llama3.2:3b"), 14, 25);
+    }
+
+    #[test]
+    fn digits_follow_family_rules() {
+        // Llama 3 groups digits in threes, Qwen splits every digit
+        assert_eq!(estimate_tokens("1234567890", "llama3.2"), 4);
+        assert_eq!(estimate_tokens("1234567890", "qwen2.5"), 10);
+    }
+
+    #[test]
+    fn cjk_text_is_within_band() {
+        // Qwen 2 tokenizer: "你好" "世界"
+        assert_within(estimate_tokens("你好世界", "qwen2.5"), 2, 0);
+    }
+
+    #[test]
+    fn family_from_model_name() {
+        assert_eq!(
+            TokenizerFamily::from_model("qwen2.5:1.5b"),
+            TokenizerFamily::Qwen
+        );
+        assert_eq!(
+            TokenizerFamily::from_model("gemma2:2b"),
+            TokenizerFamily::Gemma
+        );
+        assert_eq!(
+            TokenizerFamily::from_model("Mistral:7b-instruct"),
+            TokenizerFamily::SentencePiece
+        );
+        assert_eq!(
+            TokenizerFamily::from_model("llama3.2:3b"),
+            TokenizerFamily::Llama3
+        );
+        assert_eq!(
+            TokenizerFamily::from_model("unknown"),
+            TokenizerFamily::Llama3
+        );
+    }
+
+    #[test]
+    fn usage_counts_prompt_and_completion() {
+        let messages = vec![
+            InferenceMessage {
+                role: "system".to_string(),
+                content: "You are helpful.".to_string(),
+            },
+            InferenceMessage {
+                role: "user".to_string(),
+                content: "Hello, world!".to_string(),
+            },
+        ];
+
+        let usage = estimate_usage(&messages, "Hi there!", "llama3.2");
+
+        // 5 + 4 content tokens plus two message overheads
+        assert_eq!(usage.prompt_tokens, 17);
+        assert_eq!(usage.completion_tokens, 3);
+        assert_eq!(
+            usage.total_tokens,
+            usage.prompt_tokens + usage.completion_tokens
+        );
+    }
+}
@@ -9,7 +9,7 @@ use ai_core::{
 };
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path},
+    matchers::{body_partial_json, method, path},
 };
 
 // =============================================================================
@@ -25,6 +25,7 @@ fn inference_config_for_mock(base_url: &str) -> InferenceConfig {
         top_p: 0.9,
         timeout_ms: 5000,
         system_prompt: None,
+        warmup_on_start: false,
     }
 }
 
@@ -119,6 +120,35 @@ mod inference_tests {
         assert_eq!(usage.total_tokens, 25);
     }
 
+    #[tokio::test]
+    async fn generate_estimates_usage_without_eval_counts() {
+        let mock_server = MockServer::start().await;
+
+        // hailo-ollama omits prompt_eval_count and eval_count
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "model": "test-model",
+                "message": {"role": "assistant", "content": "Hello, world!"},
+                "done": true
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = inference_config_for_mock(&mock_server.uri());
+        let engine = OllamaInferenceEngine::new(config).expect("Failed to create engine");
+
+        let response = engine
+            .generate(InferenceRequest::simple("Hello"))
+            .await
+            .unwrap();
+
+        let usage = response.usage.expect("usage is estimated");
+        assert_eq!(usage.completion_tokens, 4);
+        assert!(usage.prompt_tokens > 0);
+        assert_eq!(usage.total_tokens, usage.prompt_tokens + 4);
+    }
+
     #[tokio::test]
     async fn generate_with_system_prompt() {
         let mock_server = MockServer::start().await;
@@ -159,6 +189,60 @@ mod inference_tests {
         assert!(response.is_ok());
     }
 
+    #[tokio::test]
+    async fn generate_passes_sampling_parameters() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .and(body_partial_json(serde_json::json!({
+                "options": {"temperature": 0.0, "top_p": 0.5, "num_predict": 32, "seed": 42}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(chat_success_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = inference_config_for_mock(&mock_server.uri());
+        let engine = OllamaInferenceEngine::new(config).expect("Failed to create engine");
+
+        let request = InferenceRequest::simple("Hello")
+            .with_top_p(0.5)
+            .with_max_tokens(32)
+            .deterministic(42);
+        let response = engine.generate(request).await;
+
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn generate_passes_json_schema_format() {
+        let mock_server = MockServer::start().await;
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {"intent": {"type": "string"}},
+            "required": ["intent"]
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .and(body_partial_json(
+                serde_json::json!({ "format": schema.clone() }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(chat_success_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = inference_config_for_mock(&mock_server.uri());
+        let engine = OllamaInferenceEngine::new(config).expect("Failed to create engine");
+
+        let request = InferenceRequest::simple("Hello").with_json_schema(schema);
+        let response = engine.generate(request).await;
+
+        assert!(response.is_ok());
+    }
+
     #[tokio::test]
     async fn generate_server_error() {
         let mock_server = MockServer::start().await;
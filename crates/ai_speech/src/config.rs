@@ -57,6 +57,16 @@ pub struct SpeechConfig {
     /// TTS speaking speed (0.25 to 4.0)
     #[serde(default = "default_speed")]
     pub speed: f32,
+
+    /// Target loudness in LUFS for incoming voice messages and synthesized
+    /// replies (-70.0 to 0.0, e.g. -16.0); no normalization if unset
+    #[serde(default)]
+    pub loudness_target_lufs: Option<f64>,
+
+    /// Minimum transcription confidence (0.0 to 1.0); less confident voice
+    /// messages get a request to repeat instead of a reply. Off if unset
+    #[serde(default)]
+    pub min_transcription_confidence: Option<f32>,
 }
 
 /// Speech provider selection
@@ -90,6 +100,12 @@ pub struct LocalSttConfig {
     /// Default language hint (ISO 639-1)
     #[serde(default)]
     pub default_language: Option<String>,
+
+    /// Split the transcript by speaker (whisper.cpp `--tinydiarize`)
+    ///
+    /// Requires a tinydiarize model such as `ggml-small.en-tdrz.bin`.
+    #[serde(default)]
+    pub diarize: bool,
 }
 
 impl Default for LocalSttConfig {
@@ -99,6 +115,7 @@ impl Default for LocalSttConfig {
             model_path: default_whisper_model(),
             threads: default_threads(),
             default_language: Some("de".to_string()),
+            diarize: false,
         }
     }
 }
@@ -140,6 +157,11 @@ pub struct LocalTtsConfig {
     #[serde(default)]
     pub voices: HashMap<String, PathBuf>,
 
+    /// Directory scanned for installed voice models
+    /// (defaults to the directory of `default_model_path`)
+    #[serde(default)]
+    pub voices_dir: Option<PathBuf>,
+
     /// Output audio format
     #[serde(default = "default_output_format")]
     pub output_format: AudioFormat,
@@ -160,6 +182,7 @@ impl Default for LocalTtsConfig {
             default_model_path: default_piper_model(),
             default_voice: default_piper_voice(),
             voices: HashMap::new(),
+            voices_dir: None,
             output_format: default_output_format(),
             length_scale: default_length_scale(),
             sentence_silence: default_sentence_silence(),
@@ -370,6 +393,8 @@ impl Default for SpeechConfig {
             include_transcription: default_include_transcription(),
             response_format: ResponseFormatPreference::default(),
             speed: default_speed(),
+            loudness_target_lufs: None,
+            min_transcription_confidence: None,
         }
     }
 }
@@ -403,6 +428,20 @@ impl SpeechConfig {
             ));
         }
 
+        // Validate loudness target
+        if let Some(target) = self.loudness_target_lufs {
+            crate::loudness::validate_target(target).map_err(|e| e.to_string())?;
+        }
+
+        // Validate confidence threshold
+        if let Some(threshold) = self.min_transcription_confidence {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!(
+                    "Minimum transcription confidence must be between 0.0 and 1.0, got {threshold}"
+                ));
+            }
+        }
+
         // Validate timeout
         if self.timeout_ms == 0 {
             return Err("Timeout must be greater than 0".to_string());
@@ -467,6 +506,26 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn validate_fails_with_invalid_loudness_target() {
+        let mut config = SpeechConfig::test();
+        config.loudness_target_lufs = Some(-16.0);
+        assert!(config.validate().is_ok());
+
+        config.loudness_target_lufs = Some(3.0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_fails_with_invalid_confidence_threshold() {
+        let mut config = SpeechConfig::test();
+        config.min_transcription_confidence = Some(0.5);
+        assert!(config.validate().is_ok());
+
+        config.min_transcription_confidence = Some(1.5);
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn validate_fails_with_zero_timeout() {
         let mut config = SpeechConfig::test();
@@ -515,6 +574,8 @@ mod tests {
             include_transcription = false
             response_format = "text"
             speed = 1.25
+            loudness_target_lufs = -16.0
+            min_transcription_confidence = 0.5
         "#;
 
         let config: SpeechConfig = toml::from_str(toml).unwrap();
@@ -528,6 +589,8 @@ mod tests {
         assert!(!config.include_transcription);
         assert_eq!(config.response_format, ResponseFormatPreference::Text);
         assert!((config.speed - 1.25).abs() < f32::EPSILON);
+        assert_eq!(config.loudness_target_lufs, Some(-16.0));
+        assert_eq!(config.min_transcription_confidence, Some(0.5));
     }
 
     #[test]
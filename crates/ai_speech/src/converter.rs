@@ -2,7 +2,7 @@
 //!
 //! Provides functionality to convert audio between formats, particularly
 //! for converting WhatsApp's OGG/Opus format to formats supported by
-//! OpenAI's Whisper API.
+//! OpenAI's Whisper API, and loudness normalization of voice messages.
 
 use std::process::Stdio;
 
@@ -11,6 +11,7 @@ use tokio::process::Command;
 use tracing::{debug, instrument};
 
 use crate::error::SpeechError;
+use crate::loudness::{self, PcmAudio};
 use crate::types::{AudioData, AudioFormat};
 
 /// Audio converter for transforming between audio formats
@@ -83,6 +84,22 @@ impl AudioConverter {
             target_format
         );
 
+        let output = self
+            .run_ffmpeg(audio, target_format, Self::format_options(target_format))
+            .await?;
+
+        debug!("Conversion successful, output size: {} bytes", output.len());
+
+        Ok(AudioData::new(output, target_format))
+    }
+
+    /// Run FFmpeg on the audio with the given codec options
+    async fn run_ffmpeg(
+        &self,
+        audio: &AudioData,
+        target_format: AudioFormat,
+        codec_options: &[&str],
+    ) -> Result<Vec<u8>, SpeechError> {
         // Build FFmpeg command
         // -i pipe:0 reads from stdin
         // -f <format> specifies output format
@@ -101,7 +118,7 @@ impl AudioConverter {
             .stderr(Stdio::piped());
 
         // Add format-specific options
-        Self::add_format_options(&mut cmd, target_format);
+        cmd.args(codec_options);
 
         let mut child = cmd
             .spawn()
@@ -135,12 +152,7 @@ impl AudioConverter {
             ));
         }
 
-        debug!(
-            "Conversion successful, output size: {} bytes",
-            output.stdout.len()
-        );
-
-        Ok(AudioData::new(output.stdout, target_format))
+        Ok(output.stdout)
     }
 
     /// Convert audio to a Whisper-compatible format
@@ -164,6 +176,58 @@ impl AudioConverter {
         self.convert(audio, AudioFormat::Mp3).await
     }
 
+    /// Normalize the loudness of audio towards `target_lufs`
+    ///
+    /// Loudness is measured as gated RMS level, which approximates LUFS for
+    /// speech; -16 LUFS suits voice messages. Audio other than WAV is
+    /// decoded with FFmpeg (keeping its sample rate), normalized and encoded
+    /// back to its original format. A limiter keeps peaks below -1 dBFS so
+    /// amplified audio never clips. Silent audio is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The target is outside -70 to 0 LUFS
+    /// - WAV input is not 16-bit PCM
+    /// - FFmpeg decoding or encoding fails
+    #[instrument(skip(self, audio), fields(format = %audio.format()))]
+    pub async fn normalize_loudness(
+        &self,
+        audio: &AudioData,
+        target_lufs: f64,
+    ) -> Result<AudioData, SpeechError> {
+        loudness::validate_target(target_lufs)?;
+
+        let format = audio.format();
+        let mut pcm = if format == AudioFormat::Wav {
+            PcmAudio::from_wav(audio.data())?
+        } else {
+            let wav = self
+                .run_ffmpeg(audio, AudioFormat::Wav, &["-codec:a", "pcm_s16le"])
+                .await?;
+            PcmAudio::from_wav(&wav)?
+        };
+
+        let gain_db = pcm.normalize(target_lufs);
+        debug!(gain_db, "Loudness normalized");
+
+        let wav = AudioData::new(pcm.to_wav(), AudioFormat::Wav);
+        let normalized = if format == AudioFormat::Wav {
+            wav
+        } else {
+            AudioData::new(
+                self.run_ffmpeg(&wav, format, Self::format_options(format))
+                    .await?,
+                format,
+            )
+        };
+
+        Ok(match audio.duration_ms() {
+            Some(duration_ms) => normalized.with_duration(duration_ms),
+            None => normalized,
+        })
+    }
+
     /// Get the FFmpeg format name for an audio format
     const fn format_to_ffmpeg(format: AudioFormat) -> &'static str {
         match format {
@@ -177,33 +241,21 @@ impl AudioConverter {
         }
     }
 
-    /// Add format-specific encoding options
-    fn add_format_options(cmd: &mut Command, format: AudioFormat) {
+    /// Get the format-specific encoding options
+    const fn format_options(format: AudioFormat) -> &'static [&'static str] {
         match format {
-            AudioFormat::Mp3 => {
-                // Use good quality for speech
-                cmd.args(["-codec:a", "libmp3lame", "-q:a", "2"]);
-            },
-            AudioFormat::Opus => {
-                // Optimize for speech
-                cmd.args(["-codec:a", "libopus", "-application", "voip", "-b:a", "32k"]);
-            },
-            AudioFormat::Wav => {
-                // PCM 16-bit, mono, 16kHz for speech processing
-                cmd.args(["-codec:a", "pcm_s16le", "-ar", "16000", "-ac", "1"]);
-            },
-            AudioFormat::Flac => {
-                // Lossless compression
-                cmd.args(["-codec:a", "flac", "-compression_level", "5"]);
-            },
-            AudioFormat::M4a => {
-                // AAC encoding
-                cmd.args(["-codec:a", "aac", "-b:a", "128k"]);
-            },
-            AudioFormat::Ogg | AudioFormat::Webm => {
-                // Use Vorbis for OGG, VP9 audio for WebM
-                cmd.args(["-codec:a", "libvorbis", "-q:a", "4"]);
-            },
+            // Use good quality for speech
+            AudioFormat::Mp3 => &["-codec:a", "libmp3lame", "-q:a", "2"],
+            // Optimize for speech
+            AudioFormat::Opus => &["-codec:a", "libopus", "-application", "voip", "-b:a", "32k"],
+            // PCM 16-bit, mono, 16kHz for speech processing
+            AudioFormat::Wav => &["-codec:a", "pcm_s16le", "-ar", "16000", "-ac", "1"],
+            // Lossless compression
+            AudioFormat::Flac => &["-codec:a", "flac", "-compression_level", "5"],
+            // AAC encoding
+            AudioFormat::M4a => &["-codec:a", "aac", "-b:a", "128k"],
+            // Use Vorbis for OGG, VP9 audio for WebM
+            AudioFormat::Ogg | AudioFormat::Webm => &["-codec:a", "libvorbis", "-q:a", "4"],
         }
     }
 }
@@ -318,6 +370,81 @@ mod tests {
         assert!(result.is_err());
     }
 
+    /// WAV file with one second of a 440 Hz sine at the given amplitude
+    fn sine_wav(amplitude: f64) -> AudioData {
+        let samples = (0..16_000)
+            .map(|i| {
+                let t = f64::from(i) / 16_000.0;
+                amplitude * (2.0 * std::f64::consts::PI * 440.0 * t).sin()
+            })
+            .collect();
+        let pcm = PcmAudio {
+            sample_rate: 16_000,
+            channels: 1,
+            samples,
+        };
+        AudioData::new(pcm.to_wav(), AudioFormat::Wav)
+    }
+
+    fn loudness_of(audio: &AudioData) -> f64 {
+        PcmAudio::from_wav(audio.data())
+            .unwrap()
+            .loudness_dbfs()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn normalize_loudness_raises_quiet_wav() {
+        // Works on WAV without FFmpeg
+        let converter = AudioConverter::with_ffmpeg_path("/nonexistent/ffmpeg");
+        let quiet = sine_wav(0.02);
+
+        let result = converter.normalize_loudness(&quiet, -16.0).await.unwrap();
+
+        assert_eq!(result.format(), AudioFormat::Wav);
+        assert!(loudness_of(&quiet) < -30.0);
+        assert!((loudness_of(&result) + 16.0).abs() < 0.5);
+    }
+
+    #[tokio::test]
+    async fn normalize_loudness_lowers_loud_wav() {
+        let converter = AudioConverter::new();
+        let loud = sine_wav(0.95);
+
+        let result = converter.normalize_loudness(&loud, -16.0).await.unwrap();
+
+        assert!((loudness_of(&result) + 16.0).abs() < 0.5);
+    }
+
+    #[tokio::test]
+    async fn normalize_loudness_keeps_duration() {
+        let converter = AudioConverter::new();
+        let audio = sine_wav(0.1).with_duration(1000);
+
+        let result = converter.normalize_loudness(&audio, -16.0).await.unwrap();
+
+        assert_eq!(result.duration_ms(), Some(1000));
+    }
+
+    #[tokio::test]
+    async fn normalize_loudness_rejects_invalid_target() {
+        let converter = AudioConverter::new();
+
+        let result = converter.normalize_loudness(&sine_wav(0.1), 6.0).await;
+
+        assert!(matches!(result, Err(SpeechError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn normalize_loudness_of_compressed_audio_needs_ffmpeg() {
+        let audio = AudioData::new(vec![0, 1, 2, 3], AudioFormat::Opus);
+        let converter = AudioConverter::with_ffmpeg_path("/nonexistent/ffmpeg");
+
+        let result = converter.normalize_loudness(&audio, -16.0).await;
+
+        assert!(matches!(result, Err(SpeechError::AudioProcessing(_))));
+    }
+
     #[tokio::test]
     async fn convert_fails_with_invalid_ffmpeg() {
         let audio = AudioData::new(vec![0, 1, 2, 3], AudioFormat::Opus);
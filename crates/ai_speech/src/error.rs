@@ -13,6 +13,10 @@ pub enum SpeechError {
     #[error("Request failed: {0}")]
     RequestFailed(String),
 
+    /// Invalid request parameter
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
     /// Invalid audio format or corrupted data
     #[error("Invalid audio: {0}")]
     InvalidAudio(String),
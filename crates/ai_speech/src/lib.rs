@@ -4,7 +4,7 @@
 //! Provides traits and implementations for speech processing:
 //! - `SpeechToText` - Transcribe audio to text (STT)
 //! - `TextToSpeech` - Synthesize speech from text (TTS)
-//! - `AudioConverter` - Convert audio between formats
+//! - `AudioConverter` - Convert audio between formats and normalize loudness
 //!
 //! # Architecture
 //!
@@ -27,7 +27,7 @@
 //! ```ignore
 //! use ai_speech::{
 //!     HybridSpeechProvider, SpeechToText, TextToSpeech,
-//!     AudioData, AudioFormat, AudioConverter,
+//!     AudioData, AudioFormat, AudioConverter, TranscriptionOptions,
 //!     LocalSttConfig, LocalTtsConfig, HybridConfig,
 //! };
 //!
@@ -46,7 +46,9 @@
 //! )?;
 //!
 //! // Transcribe audio
-//! let transcription = provider.transcribe(audio).await?;
+//! let transcription = provider
+//!     .transcribe(audio, &TranscriptionOptions::default())
+//!     .await?;
 //! println!("Transcribed: {}", transcription.text);
 //!
 //! // Synthesize speech
@@ -56,6 +58,7 @@
 pub mod config;
 pub mod converter;
 pub mod error;
+mod loudness;
 pub mod ports;
 pub mod providers;
 pub mod types;
@@ -71,4 +74,7 @@ pub use providers::hybrid::HybridSpeechProvider;
 pub use providers::openai::OpenAISpeechProvider;
 pub use providers::piper::PiperProvider;
 pub use providers::whisper_cpp::WhisperCppProvider;
-pub use types::{AudioData, AudioFormat, Transcription, VoiceInfo};
+pub use types::{
+    AudioData, AudioFormat, Segment, SynthesisOptions, Transcription, TranscriptionOptions,
+    VoiceInfo,
+};
@@ -0,0 +1,352 @@
+//! Loudness normalization of 16-bit PCM WAV audio
+//!
+//! Loudness is approximated by the RMS level in dBFS over 400 ms blocks,
+//! ignoring blocks below an absolute silence gate. This is close to LUFS for
+//! speech (a full-scale sine measures -3 dBFS RMS and -3 LUFS) without the
+//! K-weighting and relative gating of ITU-R BS.1770. After the gain is
+//! applied, a peak limiter with instant attack keeps every sample below
+//! [`CEILING_DBFS`].
+
+use crate::error::SpeechError;
+
+/// Lowest accepted loudness target
+pub const MIN_TARGET_LUFS: f64 = -70.0;
+
+/// Highest accepted loudness target
+pub const MAX_TARGET_LUFS: f64 = 0.0;
+
+/// Peak level the limiter never exceeds
+pub const CEILING_DBFS: f64 = -1.0;
+
+/// Maximum gain, so near-silent recordings are not blown up to noise
+const MAX_GAIN_DB: f64 = 30.0;
+
+/// Blocks quieter than this are not counted towards the measured loudness
+const SILENCE_GATE_DBFS: f64 = -70.0;
+
+/// Measurement block length in seconds
+const BLOCK_SECS: f64 = 0.4;
+
+/// Time for the limiter to recover after a peak, in seconds
+const RELEASE_SECS: f64 = 0.05;
+
+/// `WAVE_FORMAT_PCM`
+const FORMAT_PCM: u16 = 1;
+
+/// `WAVE_FORMAT_EXTENSIBLE`
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Interleaved 16-bit PCM audio decoded from a WAV file
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcmAudio {
+    /// Frames per second
+    pub sample_rate: u32,
+    /// Number of interleaved channels
+    pub channels: u16,
+    /// Interleaved samples scaled to -1.0..=1.0
+    pub samples: Vec<f64>,
+}
+
+impl PcmAudio {
+    /// Decode a 16-bit PCM WAV file
+    ///
+    /// Missing or placeholder data chunk sizes (as written by FFmpeg to a
+    /// pipe) are treated as "until the end of the file".
+    pub fn from_wav(bytes: &[u8]) -> Result<Self, SpeechError> {
+        let invalid = |msg: &str| SpeechError::InvalidAudio(format!("WAV: {msg}"));
+
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return Err(invalid("missing RIFF/WAVE header"));
+        }
+
+        let mut format = None;
+        let mut pos = 12;
+        while pos + 8 <= bytes.len() {
+            let id = &bytes[pos..pos + 4];
+            let size = read_u32(bytes, pos + 4) as usize;
+            let body = pos + 8;
+
+            if id == b"fmt " {
+                if size < 16 || body + 16 > bytes.len() {
+                    return Err(invalid("truncated fmt chunk"));
+                }
+                let audio_format = read_u16(bytes, body);
+                let channels = read_u16(bytes, body + 2);
+                let sample_rate = read_u32(bytes, body + 4);
+                let bits = read_u16(bytes, body + 14);
+                if !matches!(audio_format, FORMAT_PCM | FORMAT_EXTENSIBLE) || bits != 16 {
+                    return Err(invalid("only 16-bit PCM is supported"));
+                }
+                if channels == 0 || sample_rate == 0 {
+                    return Err(invalid("invalid channel count or sample rate"));
+                }
+                format = Some((channels, sample_rate));
+            } else if id == b"data" {
+                let (channels, sample_rate) =
+                    format.ok_or_else(|| invalid("data chunk before fmt chunk"))?;
+                let available = bytes.len() - body;
+                let size = if size == 0 || size > available {
+                    available
+                } else {
+                    size
+                };
+                let samples = bytes[body..body + size]
+                    .chunks_exact(2)
+                    .map(|s| f64::from(i16::from_le_bytes([s[0], s[1]])) / 32768.0)
+                    .collect();
+                return Ok(Self {
+                    sample_rate,
+                    channels,
+                    samples,
+                });
+            }
+
+            // Chunks are padded to an even size
+            pos = body.saturating_add(size).saturating_add(size & 1);
+        }
+
+        Err(invalid("missing data chunk"))
+    }
+
+    /// Encode as a canonical 16-bit PCM WAV file
+    #[allow(clippy::cast_possible_truncation)] // Samples are clamped to the i16 range
+    pub fn to_wav(&self) -> Vec<u8> {
+        let data_len = u32::try_from(self.samples.len() * 2).unwrap_or(u32::MAX);
+        let block_align = self.channels * 2;
+        let byte_rate = self.sample_rate.saturating_mul(u32::from(block_align));
+
+        let mut out = Vec::with_capacity(44 + self.samples.len() * 2);
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&data_len.saturating_add(36).to_le_bytes());
+        out.extend_from_slice(b"WAVEfmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&FORMAT_PCM.to_le_bytes());
+        out.extend_from_slice(&self.channels.to_le_bytes());
+        out.extend_from_slice(&self.sample_rate.to_le_bytes());
+        out.extend_from_slice(&byte_rate.to_le_bytes());
+        out.extend_from_slice(&block_align.to_le_bytes());
+        out.extend_from_slice(&16u16.to_le_bytes());
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&data_len.to_le_bytes());
+        for sample in &self.samples {
+            let value = (sample * 32768.0).round().clamp(-32768.0, 32767.0) as i16;
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out
+    }
+
+    /// Measured loudness in dBFS RMS, or `None` if the audio is silent
+    #[allow(clippy::cast_precision_loss)] // Sample counts stay far below 2^52
+    pub fn loudness_dbfs(&self) -> Option<f64> {
+        let block_len = self.frames_per(BLOCK_SECS) * usize::from(self.channels);
+        let mut sum = 0.0;
+        let mut counted = 0usize;
+
+        for block in self.samples.chunks(block_len) {
+            let block_sum: f64 = block.iter().map(|s| s * s).sum();
+            let block_db = mean_square_to_db(block_sum / block.len() as f64);
+            if block_db > SILENCE_GATE_DBFS {
+                sum += block_sum;
+                counted += block.len();
+            }
+        }
+
+        (counted > 0).then(|| mean_square_to_db(sum / counted as f64))
+    }
+
+    /// Bring the audio towards `target_lufs` without exceeding the ceiling
+    ///
+    /// Silent audio is left unchanged. Returns the applied gain in dB.
+    pub fn normalize(&mut self, target_lufs: f64) -> f64 {
+        let Some(current) = self.loudness_dbfs() else {
+            return 0.0;
+        };
+        let gain_db = (target_lufs - current).min(MAX_GAIN_DB);
+        let gain = db_to_amplitude(gain_db);
+        for sample in &mut self.samples {
+            *sample *= gain;
+        }
+        self.limit();
+        gain_db
+    }
+
+    /// Peak limiter with instant attack and exponential release
+    ///
+    /// Works on whole frames so all channels are reduced together.
+    fn limit(&mut self) {
+        let ceiling = db_to_amplitude(CEILING_DBFS);
+        let release = (-1.0 / (RELEASE_SECS * f64::from(self.sample_rate))).exp();
+        let mut envelope: f64 = 1.0;
+
+        for frame in self.samples.chunks_mut(usize::from(self.channels)) {
+            let peak = frame.iter().fold(0.0_f64, |max, s| max.max(s.abs()));
+            let required = if peak > ceiling { ceiling / peak } else { 1.0 };
+            // Recover towards unity, but never above what this frame allows
+            envelope = (1.0 - envelope).mul_add(-release, 1.0).min(required);
+            for sample in frame {
+                *sample *= envelope;
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn frames_per(&self, secs: f64) -> usize {
+        ((f64::from(self.sample_rate) * secs) as usize).max(1)
+    }
+}
+
+/// Check that a loudness target is within the supported range
+pub fn validate_target(target_lufs: f64) -> Result<(), SpeechError> {
+    if (MIN_TARGET_LUFS..=MAX_TARGET_LUFS).contains(&target_lufs) {
+        Ok(())
+    } else {
+        Err(SpeechError::InvalidInput(format!(
+            "Loudness target must be between {MIN_TARGET_LUFS} and {MAX_TARGET_LUFS} LUFS, got {target_lufs}"
+        )))
+    }
+}
+
+fn mean_square_to_db(mean_square: f64) -> f64 {
+    if mean_square <= 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        10.0 * mean_square.log10()
+    }
+}
+
+fn db_to_amplitude(db: f64) -> f64 {
+    10f64.powf(db / 20.0)
+}
+
+fn read_u16(bytes: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([bytes[pos], bytes[pos + 1]])
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16_000;
+
+    /// One second of a 440 Hz mono sine with the given peak amplitude
+    fn sine(amplitude: f64) -> PcmAudio {
+        let samples = (0..RATE)
+            .map(|i| {
+                let t = f64::from(i) / f64::from(RATE);
+                amplitude * (2.0 * std::f64::consts::PI * 440.0 * t).sin()
+            })
+            .collect();
+        PcmAudio {
+            sample_rate: RATE,
+            channels: 1,
+            samples,
+        }
+    }
+
+    fn peak(audio: &PcmAudio) -> f64 {
+        audio
+            .samples
+            .iter()
+            .fold(0.0_f64, |max, s| max.max(s.abs()))
+    }
+
+    #[test]
+    fn sine_loudness_matches_rms() {
+        // Full-scale sine has an RMS of -3.01 dBFS
+        let loudness = sine(1.0).loudness_dbfs().unwrap();
+        assert!((loudness + 3.01).abs() < 0.05, "{loudness}");
+    }
+
+    #[test]
+    fn quiet_signal_is_raised_to_target() {
+        let mut audio = sine(0.01);
+        let gain = audio.normalize(-20.0);
+
+        assert!(gain > 20.0);
+        let loudness = audio.loudness_dbfs().unwrap();
+        assert!((loudness + 20.0).abs() < 0.5, "{loudness}");
+    }
+
+    #[test]
+    fn loud_signal_is_lowered_to_target() {
+        let mut audio = sine(0.9);
+        let gain = audio.normalize(-20.0);
+
+        assert!(gain < -15.0);
+        let loudness = audio.loudness_dbfs().unwrap();
+        assert!((loudness + 20.0).abs() < 0.5, "{loudness}");
+    }
+
+    #[test]
+    fn limiter_prevents_clipping() {
+        // A -3 LUFS target needs a full-scale sine, which exceeds the ceiling
+        let mut audio = sine(0.3);
+        audio.normalize(-3.0);
+
+        let ceiling = db_to_amplitude(CEILING_DBFS);
+        assert!(peak(&audio) <= ceiling + 1e-9, "{}", peak(&audio));
+        // Still considerably louder than before
+        assert!(audio.loudness_dbfs().unwrap() > -8.0);
+    }
+
+    #[test]
+    fn gain_is_capped_for_near_silence() {
+        let mut audio = sine(0.001);
+        let gain = audio.normalize(-16.0);
+
+        assert!((gain - MAX_GAIN_DB).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn silence_is_left_unchanged() {
+        let mut audio = sine(0.0);
+        assert_eq!(audio.loudness_dbfs(), None);
+        assert!(audio.normalize(-16.0).abs() < f64::EPSILON);
+        assert!(audio.samples.iter().all(|s| s.abs() < f64::EPSILON));
+    }
+
+    #[test]
+    fn wav_round_trip() {
+        let audio = sine(0.5);
+        let decoded = PcmAudio::from_wav(&audio.to_wav()).unwrap();
+
+        assert_eq!(decoded.sample_rate, RATE);
+        assert_eq!(decoded.channels, 1);
+        assert_eq!(decoded.samples.len(), audio.samples.len());
+        assert!((peak(&decoded) - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn wav_with_placeholder_data_size_reads_to_end() {
+        let mut wav = sine(0.5).to_wav();
+        wav[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let decoded = PcmAudio::from_wav(&wav).unwrap();
+        assert_eq!(decoded.samples.len(), RATE as usize);
+    }
+
+    #[test]
+    fn rejects_non_pcm16_wav() {
+        let mut wav = sine(0.5).to_wav();
+        // 8 bits per sample
+        wav[34..36].copy_from_slice(&8u16.to_le_bytes());
+
+        assert!(matches!(
+            PcmAudio::from_wav(&wav),
+            Err(SpeechError::InvalidAudio(_))
+        ));
+        assert!(PcmAudio::from_wav(b"not a wav file").is_err());
+    }
+
+    #[test]
+    fn target_range_is_validated() {
+        assert!(validate_target(-16.0).is_ok());
+        assert!(validate_target(1.0).is_err());
+        assert!(validate_target(-80.0).is_err());
+        assert!(validate_target(f64::NAN).is_err());
+    }
+}
@@ -5,7 +5,7 @@
 use async_trait::async_trait;
 
 use crate::error::SpeechError;
-use crate::types::{AudioData, Transcription, VoiceInfo};
+use crate::types::{AudioData, SynthesisOptions, Transcription, TranscriptionOptions, VoiceInfo};
 
 /// Port for Speech-to-Text (STT) implementations
 ///
@@ -14,13 +14,13 @@ use crate::types::{AudioData, Transcription, VoiceInfo};
 /// # Example
 ///
 /// ```ignore
-/// use ai_speech::{SpeechToText, AudioData, AudioFormat};
+/// use ai_speech::{SpeechToText, AudioData, AudioFormat, TranscriptionOptions};
 ///
 /// async fn transcribe_voice_message(
 ///     stt: &impl SpeechToText,
 ///     audio: AudioData,
 /// ) -> Result<String, SpeechError> {
-///     let transcription = stt.transcribe(audio).await?;
+///     let transcription = stt.transcribe(audio, &TranscriptionOptions::default()).await?;
 ///     Ok(transcription.text)
 /// }
 /// ```
@@ -31,6 +31,7 @@ pub trait SpeechToText: Send + Sync {
     /// # Arguments
     ///
     /// * `audio` - Audio data to transcribe
+    /// * `options` - Request options, e.g. translation to English
     ///
     /// # Returns
     ///
@@ -39,7 +40,11 @@ pub trait SpeechToText: Send + Sync {
     /// # Errors
     ///
     /// Returns `SpeechError` if transcription fails.
-    async fn transcribe(&self, audio: AudioData) -> Result<Transcription, SpeechError>;
+    async fn transcribe(
+        &self,
+        audio: AudioData,
+        options: &TranscriptionOptions,
+    ) -> Result<Transcription, SpeechError>;
 
     /// Transcribe audio with a specific language hint
     ///
@@ -133,6 +138,29 @@ pub trait TextToSpeech: Send + Sync {
         format: crate::types::AudioFormat,
     ) -> Result<AudioData, SpeechError>;
 
+    /// Convert text to speech with adjusted rate and pitch
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to synthesize
+    /// * `voice` - Optional voice ID to use
+    /// * `options` - Rate and pitch relative to the configured voice
+    ///
+    /// # Returns
+    ///
+    /// Returns `AudioData` in the provider's default format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpeechError::InvalidInput` if rate or pitch are out of range,
+    /// or another `SpeechError` if synthesis fails.
+    async fn synthesize_with_options(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        options: &SynthesisOptions,
+    ) -> Result<AudioData, SpeechError>;
+
     /// List available voices
     ///
     /// # Returns
@@ -178,7 +206,11 @@ mod tests {
 
     #[async_trait]
     impl SpeechToText for MockSpeechToText {
-        async fn transcribe(&self, _audio: AudioData) -> Result<Transcription, SpeechError> {
+        async fn transcribe(
+            &self,
+            _audio: AudioData,
+            _options: &TranscriptionOptions,
+        ) -> Result<Transcription, SpeechError> {
             Ok(Transcription::new("Mock transcription"))
         }
 
@@ -227,6 +259,19 @@ mod tests {
             Ok(AudioData::new(vec![0, 1, 2, 3], format))
         }
 
+        async fn synthesize_with_options(
+            &self,
+            _text: &str,
+            _voice: Option<&str>,
+            options: &SynthesisOptions,
+        ) -> Result<AudioData, SpeechError> {
+            options.validate()?;
+            Ok(AudioData::new(
+                vec![0, 1, 2, 3],
+                crate::types::AudioFormat::Mp3,
+            ))
+        }
+
         async fn list_voices(&self) -> Result<Vec<VoiceInfo>, SpeechError> {
             Ok(vec![VoiceInfo::new("alloy", "Alloy")])
         }
@@ -252,7 +297,9 @@ mod tests {
         };
 
         let audio = AudioData::new(vec![0, 1, 2], crate::types::AudioFormat::Mp3);
-        let result = stt.transcribe(audio).await;
+        let result = stt
+            .transcribe(audio, &TranscriptionOptions::default())
+            .await;
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap().text, "Mock transcription");
@@ -33,7 +33,9 @@ use crate::ports::{SpeechToText, TextToSpeech};
 use crate::providers::openai::OpenAISpeechProvider;
 use crate::providers::piper::PiperProvider;
 use crate::providers::whisper_cpp::WhisperCppProvider;
-use crate::types::{AudioData, AudioFormat, Transcription, VoiceInfo};
+use crate::types::{
+    AudioData, AudioFormat, SynthesisOptions, Transcription, TranscriptionOptions, VoiceInfo,
+};
 
 /// Hybrid speech provider with local-first, cloud fallback
 pub struct HybridSpeechProvider {
@@ -166,8 +168,12 @@ impl HybridSpeechProvider {
 
 #[async_trait]
 impl SpeechToText for HybridSpeechProvider {
-    #[instrument(skip(self, audio), fields(format = ?audio.format()))]
-    async fn transcribe(&self, audio: AudioData) -> Result<Transcription, SpeechError> {
+    #[instrument(skip(self, audio), fields(format = ?audio.format(), translate = options.translate))]
+    async fn transcribe(
+        &self,
+        audio: AudioData,
+        options: &TranscriptionOptions,
+    ) -> Result<Transcription, SpeechError> {
         let mut last_error: Option<SpeechError> = None;
 
         // Try local first if preferred and available
@@ -175,7 +181,7 @@ impl SpeechToText for HybridSpeechProvider {
             if let Some(ref local) = self.local_stt {
                 if local.is_available().await {
                     debug!("Attempting local STT with whisper.cpp");
-                    match local.transcribe(audio.clone()).await {
+                    match local.transcribe(audio.clone(), options).await {
                         Ok(result) => {
                             info!("Local STT succeeded");
                             return Ok(result);
@@ -195,7 +201,7 @@ impl SpeechToText for HybridSpeechProvider {
         if self.config.allow_cloud_fallback {
             if let Some(ref cloud) = self.cloud {
                 debug!("Attempting cloud STT with OpenAI");
-                match cloud.transcribe(audio).await {
+                match cloud.transcribe(audio, options).await {
                     Ok(result) => {
                         info!("Cloud STT succeeded (fallback)");
                         return Ok(result);
@@ -379,6 +385,58 @@ impl TextToSpeech for HybridSpeechProvider {
             .unwrap_or_else(|| SpeechError::NotAvailable("No TTS provider available".to_string())))
     }
 
+    #[instrument(skip(self, text), fields(text_len = text.len(), rate = options.rate, pitch = options.pitch))]
+    async fn synthesize_with_options(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        options: &SynthesisOptions,
+    ) -> Result<AudioData, SpeechError> {
+        // Invalid options would fail on every provider
+        options.validate()?;
+
+        let mut last_error: Option<SpeechError> = None;
+
+        // Try local first if preferred
+        if self.config.prefer_local {
+            if let Some(ref local) = self.local_tts {
+                if local.is_available().await {
+                    debug!("Attempting local TTS with Piper");
+                    match local.synthesize_with_options(text, voice, options).await {
+                        Ok(result) => {
+                            info!("Local TTS succeeded");
+                            return Ok(result);
+                        },
+                        Err(e) => {
+                            warn!("Local TTS failed: {e}");
+                            last_error = Some(e);
+                        },
+                    }
+                }
+            }
+        }
+
+        // Fall back to cloud
+        if self.config.allow_cloud_fallback {
+            if let Some(ref cloud) = self.cloud {
+                debug!("Attempting cloud TTS with OpenAI");
+                match cloud.synthesize_with_options(text, voice, options).await {
+                    Ok(result) => {
+                        info!("Cloud TTS succeeded (fallback)");
+                        return Ok(result);
+                    },
+                    Err(e) => {
+                        warn!("Cloud TTS failed: {e}");
+                        last_error = Some(e);
+                    },
+                }
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| SpeechError::NotAvailable("No TTS provider available".to_string())))
+    }
+
     async fn list_voices(&self) -> Result<Vec<VoiceInfo>, SpeechError> {
         let mut all_voices = Vec::new();
 
@@ -451,6 +509,7 @@ mod tests {
             model_path: PathBuf::from("/models/ggml-base.bin"),
             threads: 4,
             default_language: Some("de".to_string()),
+            diarize: false,
         }
     }
 
@@ -460,6 +519,7 @@ mod tests {
             default_model_path: PathBuf::from("/models/de_DE-thorsten-medium.onnx"),
             default_voice: "de_DE-thorsten-medium".to_string(),
             voices: HashMap::new(),
+            voices_dir: None,
             output_format: AudioFormat::Wav,
             length_scale: 1.0,
             sentence_silence: 0.2,
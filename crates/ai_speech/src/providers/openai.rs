@@ -7,9 +7,12 @@
 //! ## STT (Whisper)
 //! - mp3, mp4, mpeg, mpga, m4a, wav, webm
 //! - Note: OGG/Opus (WhatsApp format) is NOT directly supported - use converter
+//! - With `TranscriptionOptions::translate`, the translations endpoint returns
+//!   English text
 //!
 //! ## TTS
 //! - mp3, opus, aac, flac, wav, pcm
+//! - `SynthesisOptions::rate` scales `speed`; OpenAI TTS has no pitch control
 
 use std::time::Duration;
 
@@ -23,7 +26,10 @@ use tracing::{debug, instrument, warn};
 use crate::config::SpeechConfig;
 use crate::error::SpeechError;
 use crate::ports::{SpeechToText, TextToSpeech};
-use crate::types::{AudioData, AudioFormat, Transcription, VoiceGender, VoiceInfo};
+use crate::types::{
+    AudioData, AudioFormat, SynthesisOptions, Transcription, TranscriptionOptions, VoiceGender,
+    VoiceInfo,
+};
 
 /// OpenAI speech provider implementing both STT and TTS
 #[derive(Debug, Clone)]
@@ -69,11 +75,109 @@ impl OpenAISpeechProvider {
         format!("{}/audio/transcriptions", self.config.openai_base_url)
     }
 
+    /// Build the translation (speech to English text) endpoint URL
+    fn translation_url(&self) -> String {
+        format!("{}/audio/translations", self.config.openai_base_url)
+    }
+
     /// Build the TTS endpoint URL
     fn tts_url(&self) -> String {
         format!("{}/audio/speech", self.config.openai_base_url)
     }
 
+    /// TTS `speed` for a request
+    ///
+    /// The configured speed scaled by `options.rate`, clamped to the API's
+    /// 0.25-4.0 range. `None` (API default) for normal speed.
+    fn tts_speed(&self, options: SynthesisOptions) -> Option<f32> {
+        let speed = (self.config.speed * options.rate).clamp(0.25, 4.0);
+        if (speed - 1.0).abs() < f32::EPSILON {
+            None
+        } else {
+            Some(speed)
+        }
+    }
+
+    /// Send a TTS request
+    async fn request_speech(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        format: AudioFormat,
+        options: &SynthesisOptions,
+    ) -> Result<AudioData, SpeechError> {
+        debug!("Synthesizing speech with OpenAI TTS");
+
+        options.validate()?;
+
+        if text.is_empty() {
+            return Err(SpeechError::SynthesisFailed(
+                "Text cannot be empty".to_string(),
+            ));
+        }
+
+        // OpenAI TTS has a 4096 character limit
+        if text.len() > 4096 {
+            return Err(SpeechError::SynthesisFailed(format!(
+                "Text too long: {} characters exceeds 4096 limit",
+                text.len()
+            )));
+        }
+
+        if (options.pitch - 1.0).abs() >= f32::EPSILON {
+            debug!(pitch = options.pitch, "OpenAI TTS ignores pitch");
+        }
+
+        let voice = voice.unwrap_or(&self.config.default_voice);
+        let response_format = Self::audio_format_to_response_format(format);
+
+        let request = TtsRequest {
+            model: &self.config.tts_model,
+            input: text,
+            voice,
+            response_format: Some(response_format),
+            speed: self.tts_speed(*options),
+        };
+
+        let response = self
+            .client
+            .post(self.tts_url())
+            .bearer_auth(self.api_key())
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_body = response.text().await.unwrap_or_default();
+
+            if let Ok(api_error) = serde_json::from_str::<ApiError>(&error_body) {
+                return match api_error.error.code.as_deref() {
+                    Some("rate_limit_exceeded") => Err(SpeechError::RateLimited),
+                    Some("model_not_found") => Err(SpeechError::ModelNotAvailable(
+                        self.config.tts_model.clone(),
+                    )),
+                    Some("invalid_voice") => Err(SpeechError::VoiceNotFound(voice.to_string())),
+                    _ => Err(SpeechError::SynthesisFailed(api_error.error.message)),
+                };
+            }
+
+            return Err(SpeechError::SynthesisFailed(format!(
+                "HTTP {status}: {error_body}"
+            )));
+        }
+
+        let audio_bytes: Bytes = response
+            .bytes()
+            .await
+            .map_err(|e| SpeechError::InvalidResponse(format!("Failed to read audio: {e}")))?;
+
+        debug!(audio_size = audio_bytes.len(), "Speech synthesis complete");
+
+        let output_format = Self::response_format_to_audio_format(response_format);
+        Ok(AudioData::new(audio_bytes.to_vec(), output_format))
+    }
+
     /// Convert OpenAI response format string to AudioFormat
     fn response_format_to_audio_format(format: &str) -> AudioFormat {
         match format {
@@ -107,6 +211,22 @@ struct WhisperResponse {
     language: Option<String>,
     #[serde(default)]
     duration: Option<f64>,
+    /// Timed segments, only present in verbose JSON
+    #[serde(default)]
+    segments: Vec<WhisperSegment>,
+}
+
+/// A segment of a verbose JSON transcription
+#[derive(Debug, Deserialize)]
+struct WhisperSegment {
+    /// Average log probability of the segment's tokens
+    avg_logprob: f64,
+}
+
+/// Per-segment confidence scores (token probability averaged in log space)
+#[allow(clippy::cast_possible_truncation)]
+fn segment_scores(segments: &[WhisperSegment]) -> impl Iterator<Item = f32> + '_ {
+    segments.iter().map(|s| s.avg_logprob.exp() as f32)
 }
 
 /// OpenAI TTS request body
@@ -138,8 +258,12 @@ struct ApiErrorDetail {
 
 #[async_trait]
 impl SpeechToText for OpenAISpeechProvider {
-    #[instrument(skip(self, audio), fields(audio_size = audio.size_bytes(), format = ?audio.format()))]
-    async fn transcribe(&self, audio: AudioData) -> Result<Transcription, SpeechError> {
+    #[instrument(skip(self, audio), fields(audio_size = audio.size_bytes(), format = ?audio.format(), translate = options.translate))]
+    async fn transcribe(
+        &self,
+        audio: AudioData,
+        options: &TranscriptionOptions,
+    ) -> Result<Transcription, SpeechError> {
         debug!("Transcribing audio with OpenAI Whisper");
 
         // Check audio duration if known
@@ -179,10 +303,19 @@ impl SpeechToText for OpenAISpeechProvider {
             .part("file", file_part)
             .text("model", self.config.stt_model.clone());
 
+        // Verbose JSON reports segment scores, and the source language of
+        // translations
+        let form = form.text("response_format", "verbose_json");
+        let url = if options.translate {
+            self.translation_url()
+        } else {
+            self.stt_url()
+        };
+
         // Send request
         let response = self
             .client
-            .post(self.stt_url())
+            .post(url)
             .bearer_auth(self.api_key())
             .multipart(form)
             .send()
@@ -220,7 +353,8 @@ impl SpeechToText for OpenAISpeechProvider {
             "Transcription complete"
         );
 
-        let mut transcription = Transcription::new(whisper_response.text);
+        let mut transcription = Transcription::new(whisper_response.text)
+            .with_average_confidence(segment_scores(&whisper_response.segments));
 
         if let Some(lang) = whisper_response.language {
             transcription = transcription.with_language(lang);
@@ -276,7 +410,8 @@ impl SpeechToText for OpenAISpeechProvider {
         let form = Form::new()
             .part("file", file_part)
             .text("model", self.config.stt_model.clone())
-            .text("language", language.to_string());
+            .text("language", language.to_string())
+            .text("response_format", "verbose_json");
 
         let response = self
             .client
@@ -310,7 +445,9 @@ impl SpeechToText for OpenAISpeechProvider {
             .await
             .map_err(|e| SpeechError::InvalidResponse(format!("Failed to parse response: {e}")))?;
 
-        let mut transcription = Transcription::new(whisper_response.text).with_language(language);
+        let mut transcription = Transcription::new(whisper_response.text)
+            .with_language(language)
+            .with_average_confidence(segment_scores(&whisper_response.segments));
 
         if let Some(duration) = whisper_response.duration {
             #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
@@ -361,74 +498,19 @@ impl TextToSpeech for OpenAISpeechProvider {
         voice: Option<&str>,
         format: AudioFormat,
     ) -> Result<AudioData, SpeechError> {
-        debug!("Synthesizing speech with OpenAI TTS");
-
-        if text.is_empty() {
-            return Err(SpeechError::SynthesisFailed(
-                "Text cannot be empty".to_string(),
-            ));
-        }
-
-        // OpenAI TTS has a 4096 character limit
-        if text.len() > 4096 {
-            return Err(SpeechError::SynthesisFailed(format!(
-                "Text too long: {} characters exceeds 4096 limit",
-                text.len()
-            )));
-        }
-
-        let voice = voice.unwrap_or(&self.config.default_voice);
-        let response_format = Self::audio_format_to_response_format(format);
-
-        let request = TtsRequest {
-            model: &self.config.tts_model,
-            input: text,
-            voice,
-            response_format: Some(response_format),
-            speed: if (self.config.speed - 1.0).abs() < f32::EPSILON {
-                None
-            } else {
-                Some(self.config.speed)
-            },
-        };
-
-        let response = self
-            .client
-            .post(self.tts_url())
-            .bearer_auth(self.api_key())
-            .json(&request)
-            .send()
-            .await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_body = response.text().await.unwrap_or_default();
-
-            if let Ok(api_error) = serde_json::from_str::<ApiError>(&error_body) {
-                return match api_error.error.code.as_deref() {
-                    Some("rate_limit_exceeded") => Err(SpeechError::RateLimited),
-                    Some("model_not_found") => Err(SpeechError::ModelNotAvailable(
-                        self.config.tts_model.clone(),
-                    )),
-                    Some("invalid_voice") => Err(SpeechError::VoiceNotFound(voice.to_string())),
-                    _ => Err(SpeechError::SynthesisFailed(api_error.error.message)),
-                };
-            }
-
-            return Err(SpeechError::SynthesisFailed(format!(
-                "HTTP {status}: {error_body}"
-            )));
-        }
-
-        let audio_bytes: Bytes = response
-            .bytes()
+        self.request_speech(text, voice, format, &SynthesisOptions::default())
             .await
-            .map_err(|e| SpeechError::InvalidResponse(format!("Failed to read audio: {e}")))?;
-
-        debug!(audio_size = audio_bytes.len(), "Speech synthesis complete");
+    }
 
-        let output_format = Self::response_format_to_audio_format(response_format);
-        Ok(AudioData::new(audio_bytes.to_vec(), output_format))
+    #[instrument(skip(self, text), fields(text_len = text.len(), rate = options.rate, pitch = options.pitch))]
+    async fn synthesize_with_options(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        options: &SynthesisOptions,
+    ) -> Result<AudioData, SpeechError> {
+        self.request_speech(text, voice, self.config.output_format, options)
+            .await
     }
 
     async fn list_voices(&self) -> Result<Vec<VoiceInfo>, SpeechError> {
@@ -517,7 +599,7 @@ impl TextToSpeech for OpenAISpeechProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{body_partial_json, body_string_contains, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn create_test_provider(mock_server: &MockServer) -> OpenAISpeechProvider {
@@ -551,7 +633,9 @@ mod tests {
             let provider = create_test_provider(&mock_server);
             let audio = AudioData::new(vec![0, 1, 2, 3], AudioFormat::Mp3);
 
-            let result = provider.transcribe(audio).await;
+            let result = provider
+                .transcribe(audio, &TranscriptionOptions::default())
+                .await;
 
             assert!(result.is_ok());
             let transcription = result.unwrap();
@@ -560,6 +644,67 @@ mod tests {
             assert_eq!(transcription.duration_ms, Some(2500));
         }
 
+        #[tokio::test]
+        async fn transcribe_with_translate_uses_translations_endpoint() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/audio/translations"))
+                .and(body_string_contains("verbose_json"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "text": "Good morning, everyone!",
+                    "language": "es",
+                    "duration": 1.2
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let provider = create_test_provider(&mock_server);
+            let audio = AudioData::new(vec![0, 1, 2, 3], AudioFormat::Mp3);
+
+            let transcription = provider
+                .transcribe(audio, &TranscriptionOptions::translate())
+                .await
+                .unwrap();
+
+            assert_eq!(transcription.text, "Good morning, everyone!");
+            assert_eq!(transcription.language, Some("es".to_string()));
+        }
+
+        #[tokio::test]
+        async fn transcribe_averages_segment_confidence() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/audio/transcriptions"))
+                .and(body_string_contains("verbose_json"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "text": "Turn on the lights.",
+                    "language": "english",
+                    "duration": 1.8,
+                    "segments": [
+                        {"id": 0, "text": " Turn on", "avg_logprob": -0.1, "no_speech_prob": 0.01},
+                        {"id": 1, "text": " the lights.", "avg_logprob": -0.5, "no_speech_prob": 0.02}
+                    ]
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let provider = create_test_provider(&mock_server);
+            let audio = AudioData::new(vec![0, 1, 2, 3], AudioFormat::Mp3);
+
+            let transcription = provider
+                .transcribe(audio, &TranscriptionOptions::default())
+                .await
+                .unwrap();
+
+            let expected = f64::midpoint((-0.1_f64).exp(), (-0.5_f64).exp());
+            let confidence = f64::from(transcription.confidence.unwrap());
+            assert!((confidence - expected).abs() < 1e-4);
+        }
+
         #[tokio::test]
         async fn transcribe_with_language_success() {
             let mock_server = MockServer::start().await;
@@ -591,7 +736,9 @@ mod tests {
             let provider = create_test_provider(&mock_server);
             let audio = AudioData::new(vec![], AudioFormat::Mp3);
 
-            let result = provider.transcribe(audio).await;
+            let result = provider
+                .transcribe(audio, &TranscriptionOptions::default())
+                .await;
 
             assert!(matches!(result, Err(SpeechError::InvalidAudio(_))));
         }
@@ -603,7 +750,9 @@ mod tests {
             // Opus is not directly supported by Whisper
             let audio = AudioData::new(vec![1, 2, 3], AudioFormat::Opus);
 
-            let result = provider.transcribe(audio).await;
+            let result = provider
+                .transcribe(audio, &TranscriptionOptions::default())
+                .await;
 
             assert!(matches!(result, Err(SpeechError::InvalidAudio(_))));
         }
@@ -615,7 +764,9 @@ mod tests {
             // Audio with duration exceeding max
             let audio = AudioData::new(vec![1, 2, 3], AudioFormat::Mp3).with_duration(200_000);
 
-            let result = provider.transcribe(audio).await;
+            let result = provider
+                .transcribe(audio, &TranscriptionOptions::default())
+                .await;
 
             assert!(matches!(
                 result,
@@ -646,7 +797,9 @@ mod tests {
             let provider = create_test_provider(&mock_server);
             let audio = AudioData::new(vec![1, 2, 3], AudioFormat::Mp3);
 
-            let result = provider.transcribe(audio).await;
+            let result = provider
+                .transcribe(audio, &TranscriptionOptions::default())
+                .await;
 
             assert!(matches!(result, Err(SpeechError::RateLimited)));
         }
@@ -761,6 +914,68 @@ mod tests {
 
             assert!(matches!(result, Err(SpeechError::RateLimited)));
         }
+
+        #[tokio::test]
+        async fn synthesize_with_options_scales_speed() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/audio/speech"))
+                .and(body_partial_json(serde_json::json!({ "speed": 1.5 })))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0u8; 256]))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let provider = create_test_provider(&mock_server);
+
+            let result = provider
+                .synthesize_with_options("Test", None, &SynthesisOptions::new(1.5, 1.0))
+                .await;
+
+            assert!(result.is_ok());
+        }
+
+        #[tokio::test]
+        async fn synthesize_with_out_of_range_options_fails() {
+            let mock_server = MockServer::start().await;
+            let provider = create_test_provider(&mock_server);
+
+            let result = provider
+                .synthesize_with_options("Test", None, &SynthesisOptions::new(5.0, 1.0))
+                .await;
+
+            assert!(matches!(result, Err(SpeechError::InvalidInput(_))));
+        }
+
+        #[test]
+        fn tts_speed_is_scaled_and_clamped() {
+            let provider = |speed| {
+                OpenAISpeechProvider::new(SpeechConfig {
+                    speed,
+                    ..SpeechConfig::test()
+                })
+                .unwrap()
+            };
+
+            assert_eq!(provider(1.0).tts_speed(SynthesisOptions::default()), None);
+            assert_eq!(
+                provider(1.25).tts_speed(SynthesisOptions::default()),
+                Some(1.25)
+            );
+            assert_eq!(
+                provider(2.0).tts_speed(SynthesisOptions::new(0.5, 1.0)),
+                None
+            );
+            assert_eq!(
+                provider(2.0).tts_speed(SynthesisOptions::new(4.0, 1.0)),
+                Some(4.0)
+            );
+            assert_eq!(
+                provider(0.5).tts_speed(SynthesisOptions::new(0.25, 1.0)),
+                Some(0.25)
+            );
+        }
     }
 
     mod availability_tests {
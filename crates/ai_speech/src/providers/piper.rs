@@ -29,11 +29,26 @@
 //! | German | de_DE-thorsten-medium | Good | Natural German male voice |
 //! | English | en_US-lessac-medium | Good | Clear American English |
 //! | English | en_GB-alan-medium | Good | British English |
+//!
+//! # Voice Discovery
+//!
+//! `list_voices` scans the voices directory (`voices_dir`, or the directory
+//! of the default model) for `.onnx` models and reads speaker, language,
+//! quality and gender from the `.onnx.json` config next to each model.
+//! Installed voices can be selected by their file stem.
+//!
+//! # Rate and Pitch
+//!
+//! `SynthesisOptions::rate` divides the configured `length_scale`. Piper has
+//! no pitch control; `pitch` scales its noise parameters (`--noise_scale`,
+//! `--noise_w`), which makes intonation livelier or flatter.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 
 use async_trait::async_trait;
+use serde::Deserialize;
 use tempfile::NamedTempFile;
 use tokio::process::Command;
 use tracing::{debug, error, instrument, warn};
@@ -41,7 +56,81 @@ use tracing::{debug, error, instrument, warn};
 use crate::config::LocalTtsConfig;
 use crate::error::SpeechError;
 use crate::ports::TextToSpeech;
-use crate::types::{AudioData, AudioFormat, VoiceInfo};
+use crate::types::{AudioData, AudioFormat, SynthesisOptions, VoiceGender, VoiceInfo};
+
+/// Piper's default `--noise_scale`
+const DEFAULT_NOISE_SCALE: f32 = 0.667;
+
+/// Piper's default `--noise_w`
+const DEFAULT_NOISE_W: f32 = 0.8;
+
+/// File extension of Piper voice models
+const MODEL_EXTENSION: &str = "onnx";
+
+/// Fields of a Piper voice config (`<voice>.onnx.json`) used for listing
+#[derive(Debug, Deserialize)]
+struct PiperVoiceConfig {
+    /// Training dataset, which names the speaker
+    dataset: Option<String>,
+    language: Option<PiperLanguage>,
+    audio: Option<PiperAudio>,
+    /// Not part of upstream configs, but set by some community voices
+    gender: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PiperLanguage {
+    /// Locale code (e.g., "de_DE")
+    code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PiperAudio {
+    /// Model quality (e.g., "medium")
+    quality: Option<String>,
+}
+
+/// Parse a gender label from a voice config
+fn parse_gender(gender: &str) -> Option<VoiceGender> {
+    match gender.to_ascii_lowercase().as_str() {
+        "male" | "m" => Some(VoiceGender::Male),
+        "female" | "f" => Some(VoiceGender::Female),
+        "neutral" => Some(VoiceGender::Neutral),
+        _ => None,
+    }
+}
+
+/// Build voice info from a voice name and its optional config
+///
+/// Missing config fields fall back to the Piper naming scheme
+/// `<locale>-<speaker>-<quality>` (e.g., "de_DE-thorsten-medium").
+fn voice_info(id: &str, config: Option<&PiperVoiceConfig>) -> VoiceInfo {
+    let parts: Vec<&str> = id.split('-').collect();
+    let locale = config
+        .and_then(|c| c.language.as_ref())
+        .and_then(|l| l.code.as_deref())
+        .or_else(|| parts.first().copied())
+        .unwrap_or("unknown");
+    let speaker = config
+        .and_then(|c| c.dataset.as_deref())
+        .or_else(|| parts.get(1).copied())
+        .unwrap_or("default");
+    let quality = config
+        .and_then(|c| c.audio.as_ref())
+        .and_then(|a| a.quality.as_deref())
+        .or_else(|| parts.get(2).copied());
+
+    let mut voice = VoiceInfo::new(id, format!("{speaker} ({locale})"));
+    voice.languages = vec![locale.replace('_', "-")];
+    voice.description = Some(quality.map_or_else(
+        || format!("Piper voice: {id}"),
+        |quality| format!("Piper voice: {id} ({quality} quality)"),
+    ));
+    voice.gender = config
+        .and_then(|c| c.gender.as_deref())
+        .and_then(parse_gender);
+    voice
+}
 
 /// Local TTS provider using Piper
 #[derive(Debug, Clone)]
@@ -73,39 +162,132 @@ impl PiperProvider {
         &self.config.executable_path
     }
 
+    /// Directory scanned for installed voice models
+    fn voices_dir(&self) -> Option<&Path> {
+        self.config
+            .voices_dir
+            .as_deref()
+            .or_else(|| self.config.default_model_path.parent())
+    }
+
+    /// Get the model path of a voice installed in the voices directory
+    fn installed_model_path(&self, voice: &str) -> Option<PathBuf> {
+        // Voice names must not escape the voices directory
+        if voice.is_empty() || voice.starts_with('.') || voice.contains(['/', '\\']) {
+            return None;
+        }
+
+        let path = self
+            .voices_dir()?
+            .join(format!("{voice}.{MODEL_EXTENSION}"));
+        path.is_file().then_some(path)
+    }
+
     /// Get the voice model path for a voice name
-    fn voice_model_path(&self, voice: Option<&str>) -> &Path {
+    fn voice_model_path(&self, voice: Option<&str>) -> PathBuf {
         // Use specified voice or default
         let voice_name = voice.unwrap_or(&self.config.default_voice);
 
-        // Look up in voice map or use default path
+        // Look up in voice map, then installed models, else use default path
         self.config
             .voices
             .get(voice_name)
-            .map_or(&self.config.default_model_path, PathBuf::as_path)
+            .cloned()
+            .or_else(|| self.installed_model_path(voice_name))
+            .unwrap_or_else(|| self.config.default_model_path.clone())
     }
 
-    /// Run Piper to synthesize speech
-    #[instrument(skip(self, text), fields(voice = ?voice, text_len = text.len()))]
-    async fn run_piper(&self, text: &str, voice: Option<&str>) -> Result<Vec<u8>, SpeechError> {
-        let model_path = self.voice_model_path(voice);
+    /// List voice models installed in the voices directory by name
+    async fn installed_voices(&self) -> Vec<(String, PathBuf)> {
+        let Some(dir) = self.voices_dir() else {
+            return Vec::new();
+        };
 
-        // Create temp file for output
-        let output_file = NamedTempFile::with_suffix(".wav").map_err(|e| {
-            SpeechError::SynthesisFailed(format!("Failed to create temp file: {e}"))
-        })?;
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                debug!("Cannot read Piper voices directory {}: {e}", dir.display());
+                return Vec::new();
+            },
+        };
+
+        let mut voices = Vec::new();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != MODEL_EXTENSION) {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                voices.push((name.to_string(), path));
+            }
+        }
+
+        voices
+    }
+
+    /// Read the config Piper expects next to a voice model
+    async fn read_voice_config(model_path: &Path) -> Option<PiperVoiceConfig> {
+        let mut config_path = model_path.as_os_str().to_owned();
+        config_path.push(".json");
+
+        let content = tokio::fs::read_to_string(&config_path).await.ok()?;
+        serde_json::from_str(&content)
+            .inspect_err(|e| warn!("Invalid Piper voice config {config_path:?}: {e}"))
+            .ok()
+    }
+
+    /// Build the Piper command for a model and output file
+    ///
+    /// Default options keep Piper's own noise settings.
+    fn build_command(
+        &self,
+        model_path: &Path,
+        output_path: &Path,
+        options: SynthesisOptions,
+    ) -> Command {
+        // Shorter phonemes speak faster
+        let length_scale = (self.config.length_scale / options.rate).clamp(0.1, 4.0);
 
         let mut cmd = Command::new(self.executable());
 
         cmd.arg("--model")
             .arg(model_path)
             .arg("--output_file")
-            .arg(output_file.path())
+            .arg(output_path)
             .arg("--length_scale")
-            .arg(self.config.length_scale.to_string())
+            .arg(length_scale.to_string())
             .arg("--sentence_silence")
-            .arg(self.config.sentence_silence.to_string())
-            .stdin(Stdio::piped())
+            .arg(self.config.sentence_silence.to_string());
+
+        if (options.pitch - 1.0).abs() >= f32::EPSILON {
+            let noise_scale = (DEFAULT_NOISE_SCALE * options.pitch).clamp(0.0, 1.0);
+            let noise_w = (DEFAULT_NOISE_W * options.pitch).clamp(0.0, 1.0);
+            cmd.arg("--noise_scale")
+                .arg(noise_scale.to_string())
+                .arg("--noise_w")
+                .arg(noise_w.to_string());
+        }
+
+        cmd
+    }
+
+    /// Run Piper to synthesize speech
+    #[instrument(skip(self, text), fields(voice = ?voice, text_len = text.len()))]
+    async fn run_piper(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        options: &SynthesisOptions,
+    ) -> Result<Vec<u8>, SpeechError> {
+        let model_path = self.voice_model_path(voice);
+
+        // Create temp file for output
+        let output_file = NamedTempFile::with_suffix(".wav").map_err(|e| {
+            SpeechError::SynthesisFailed(format!("Failed to create temp file: {e}"))
+        })?;
+
+        let mut cmd = self.build_command(&model_path, output_file.path(), *options);
+        cmd.stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
 
@@ -161,6 +343,33 @@ impl PiperProvider {
         Ok(audio_data)
     }
 
+    /// Synthesize speech and convert it to `format`
+    async fn synthesize_audio(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        format: AudioFormat,
+        options: &SynthesisOptions,
+    ) -> Result<AudioData, SpeechError> {
+        options.validate()?;
+
+        if text.is_empty() {
+            return Err(SpeechError::SynthesisFailed(
+                "Cannot synthesize empty text".to_string(),
+            ));
+        }
+
+        debug!("Synthesizing {} chars with Piper", text.len());
+
+        // Run piper to get WAV
+        let wav_data = self.run_piper(text, voice, options).await?;
+
+        // Convert to requested format
+        let audio_data = self.convert_format(wav_data, format).await?;
+
+        Ok(AudioData::new(audio_data, format))
+    }
+
     /// Convert WAV to the requested format
     async fn convert_format(
         &self,
@@ -195,49 +404,39 @@ impl TextToSpeech for PiperProvider {
         voice: Option<&str>,
         format: AudioFormat,
     ) -> Result<AudioData, SpeechError> {
-        if text.is_empty() {
-            return Err(SpeechError::SynthesisFailed(
-                "Cannot synthesize empty text".to_string(),
-            ));
-        }
-
-        debug!("Synthesizing {} chars with Piper", text.len());
-
-        // Run piper to get WAV
-        let wav_data = self.run_piper(text, voice).await?;
-
-        // Convert to requested format
-        let audio_data = self.convert_format(wav_data, format).await?;
+        self.synthesize_audio(text, voice, format, &SynthesisOptions::default())
+            .await
+    }
 
-        Ok(AudioData::new(audio_data, format))
+    #[instrument(skip(self, text), fields(text_len = text.len(), rate = options.rate, pitch = options.pitch))]
+    async fn synthesize_with_options(
+        &self,
+        text: &str,
+        voice: Option<&str>,
+        options: &SynthesisOptions,
+    ) -> Result<AudioData, SpeechError> {
+        self.synthesize_audio(text, voice, self.config.output_format, options)
+            .await
     }
 
     async fn list_voices(&self) -> Result<Vec<VoiceInfo>, SpeechError> {
-        // Return configured voices
-        let mut voices: Vec<VoiceInfo> = self
-            .config
-            .voices
-            .keys()
-            .map(|name| {
-                // Parse voice info from name (e.g., "de_DE-thorsten-medium")
-                let parts: Vec<&str> = name.split('-').collect();
-                let locale = parts.first().copied().unwrap_or("unknown");
-                let speaker = parts.get(1).copied().unwrap_or("default");
-
-                let mut voice = VoiceInfo::new(name.clone(), format!("{speaker} ({locale})"));
-                voice.languages = vec![locale.replace('_', "-")];
-                voice.description = Some(format!("Piper voice: {name}"));
-                voice
-            })
-            .collect();
-
-        // Add default voice if not already in list
-        if !voices.iter().any(|v| v.id == self.config.default_voice) {
-            let mut default_voice =
-                VoiceInfo::new(self.config.default_voice.clone(), "Default".to_string());
-            default_voice.languages = vec!["en".to_string()];
-            default_voice.description = Some("Default Piper voice".to_string());
-            voices.push(default_voice);
+        // Installed models, overridden by configured voices of the same name
+        let mut models: BTreeMap<String, PathBuf> =
+            self.installed_voices().await.into_iter().collect();
+        models.extend(
+            self.config
+                .voices
+                .iter()
+                .map(|(name, path)| (name.clone(), path.clone())),
+        );
+        models
+            .entry(self.config.default_voice.clone())
+            .or_insert_with(|| self.config.default_model_path.clone());
+
+        let mut voices = Vec::with_capacity(models.len());
+        for (name, model_path) in &models {
+            let config = Self::read_voice_config(model_path).await;
+            voices.push(voice_info(name, config.as_ref()));
         }
 
         Ok(voices)
@@ -303,6 +502,7 @@ mod tests {
             default_model_path: PathBuf::from("/models/de_DE-thorsten-medium.onnx"),
             default_voice: "de_DE-thorsten-medium".to_string(),
             voices,
+            voices_dir: None,
             output_format: AudioFormat::Wav,
             length_scale: 1.0,
             sentence_silence: 0.2,
@@ -357,6 +557,99 @@ mod tests {
         assert!(voices.iter().any(|v| v.id == "en_US-lessac-medium"));
     }
 
+    /// Voices directory with one described and one bare model
+    fn voice_fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("de_DE-thorsten-medium.onnx"), b"").unwrap();
+        std::fs::write(
+            dir.path().join("de_DE-thorsten-medium.onnx.json"),
+            r#"{
+                "dataset": "thorsten",
+                "language": {"code": "de_DE", "family": "de"},
+                "audio": {"sample_rate": 22050, "quality": "high"},
+                "gender": "male"
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("en_GB-alba-low.onnx"), b"").unwrap();
+        std::fs::write(dir.path().join("README.md"), b"voices").unwrap();
+        dir
+    }
+
+    fn fixture_config(dir: &Path) -> LocalTtsConfig {
+        LocalTtsConfig {
+            default_model_path: dir.join("de_DE-thorsten-medium.onnx"),
+            voices: HashMap::new(),
+            ..test_config()
+        }
+    }
+
+    #[tokio::test]
+    async fn list_voices_scans_voices_directory() {
+        let dir = voice_fixture();
+        let provider = PiperProvider::new(fixture_config(dir.path())).unwrap();
+
+        let voices = provider.list_voices().await.unwrap();
+
+        let ids: Vec<&str> = voices.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, vec!["de_DE-thorsten-medium", "en_GB-alba-low"]);
+
+        let thorsten = &voices[0];
+        assert_eq!(thorsten.name, "thorsten (de_DE)");
+        assert_eq!(thorsten.languages, vec!["de-DE"]);
+        assert_eq!(thorsten.gender, Some(VoiceGender::Male));
+        assert_eq!(
+            thorsten.description.as_deref(),
+            Some("Piper voice: de_DE-thorsten-medium (high quality)")
+        );
+
+        // Without a config, details come from the file name
+        let alba = &voices[1];
+        assert_eq!(alba.name, "alba (en_GB)");
+        assert_eq!(alba.languages, vec!["en-GB"]);
+        assert_eq!(alba.gender, None);
+    }
+
+    #[tokio::test]
+    async fn list_voices_uses_explicit_voices_dir() {
+        let dir = voice_fixture();
+        let config = LocalTtsConfig {
+            voices_dir: Some(dir.path().to_path_buf()),
+            ..test_config()
+        };
+        let provider = PiperProvider::new(config).unwrap();
+
+        let voices = provider.list_voices().await.unwrap();
+
+        assert!(voices.iter().any(|v| v.id == "en_GB-alba-low"));
+        assert!(voices.iter().any(|v| v.id == "en_US-lessac-medium"));
+    }
+
+    #[test]
+    fn voice_model_path_finds_installed_voice() {
+        let dir = voice_fixture();
+        let provider = PiperProvider::new(fixture_config(dir.path())).unwrap();
+
+        let path = provider.voice_model_path(Some("en_GB-alba-low"));
+        assert_eq!(path, dir.path().join("en_GB-alba-low.onnx"));
+
+        // Unknown and path-like names fall back to the default model
+        let default = dir.path().join("de_DE-thorsten-medium.onnx");
+        assert_eq!(provider.voice_model_path(Some("missing")), default);
+        assert_eq!(
+            provider.voice_model_path(Some("../en_GB-alba-low")),
+            default
+        );
+    }
+
+    #[test]
+    fn parse_gender_accepts_common_labels() {
+        assert_eq!(parse_gender("Female"), Some(VoiceGender::Female));
+        assert_eq!(parse_gender("m"), Some(VoiceGender::Male));
+        assert_eq!(parse_gender("neutral"), Some(VoiceGender::Neutral));
+        assert_eq!(parse_gender("unknown"), None);
+    }
+
     #[tokio::test]
     async fn is_available_returns_false_when_not_installed() {
         let mut config = test_config();
@@ -365,4 +658,59 @@ mod tests {
 
         assert!(!provider.is_available().await);
     }
+
+    fn arg_value(cmd: &Command, flag: &str) -> Option<String> {
+        let args: Vec<String> = cmd
+            .as_std()
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        args.windows(2).find(|w| w[0] == flag).map(|w| w[1].clone())
+    }
+
+    fn command(options: SynthesisOptions) -> Command {
+        PiperProvider::new(test_config()).unwrap().build_command(
+            Path::new("/models/voice.onnx"),
+            Path::new("/tmp/out.wav"),
+            options,
+        )
+    }
+
+    #[test]
+    fn default_options_keep_configured_arguments() {
+        let cmd = command(SynthesisOptions::default());
+
+        assert_eq!(arg_value(&cmd, "--length_scale").as_deref(), Some("1"));
+        assert_eq!(arg_value(&cmd, "--noise_scale"), None);
+        assert_eq!(arg_value(&cmd, "--noise_w"), None);
+    }
+
+    #[test]
+    fn rate_shortens_length_scale() {
+        let cmd = command(SynthesisOptions::new(2.0, 1.0));
+
+        assert_eq!(arg_value(&cmd, "--length_scale").as_deref(), Some("0.5"));
+    }
+
+    #[test]
+    fn pitch_scales_and_clamps_noise_parameters() {
+        let cmd = command(SynthesisOptions::new(1.0, 0.5));
+        assert_eq!(arg_value(&cmd, "--noise_scale").as_deref(), Some("0.3335"));
+        assert_eq!(arg_value(&cmd, "--noise_w").as_deref(), Some("0.4"));
+
+        let cmd = command(SynthesisOptions::new(1.0, 2.0));
+        assert_eq!(arg_value(&cmd, "--noise_scale").as_deref(), Some("1"));
+        assert_eq!(arg_value(&cmd, "--noise_w").as_deref(), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn out_of_range_options_are_rejected() {
+        let provider = PiperProvider::new(test_config()).unwrap();
+
+        let result = provider
+            .synthesize_with_options("Hallo", None, &SynthesisOptions::new(1.0, 5.0))
+            .await;
+
+        assert!(matches!(result, Err(SpeechError::InvalidInput(_))));
+    }
 }
@@ -21,11 +21,25 @@
 //! # Optional: Install system-wide
 //! sudo cp main /usr/local/bin/whisper-cpp
 //! ```
+//!
+//! # Speaker Diarization
+//!
+//! With `diarize` enabled, whisper.cpp runs with `--tinydiarize` and marks
+//! speaker turns. Turns alternate between "Speaker 1" and "Speaker 2", since
+//! tinydiarize detects changes of speaker but not who is speaking. This
+//! needs a tinydiarize model (`./models/download-ggml-model.sh small.en-tdrz`).
+//!
+//! # Translation
+//!
+//! With `TranscriptionOptions::translate`, whisper.cpp runs with `--translate`
+//! and detects the source language (`-l auto`), which is reported as the
+//! transcription language while the text is English.
 
 use std::path::Path;
 use std::process::Stdio;
 
 use async_trait::async_trait;
+use serde::Deserialize;
 use tempfile::NamedTempFile;
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
@@ -34,7 +48,151 @@ use tracing::{debug, error, instrument, warn};
 use crate::config::LocalSttConfig;
 use crate::error::SpeechError;
 use crate::ports::SpeechToText;
-use crate::types::{AudioData, AudioFormat, Transcription};
+use crate::types::{AudioData, AudioFormat, Segment, Transcription, TranscriptionOptions};
+
+/// Speaker labels used for diarized turns, in order
+const SPEAKERS: [&str; 2] = ["Speaker 1", "Speaker 2"];
+
+/// JSON written by whisper.cpp with `--output-json-full`
+#[derive(Debug, Deserialize)]
+struct WhisperOutput {
+    #[serde(default)]
+    result: Option<WhisperResult>,
+    #[serde(default)]
+    transcription: Vec<WhisperSegment>,
+}
+
+/// Overall result of a whisper.cpp run
+#[derive(Debug, Deserialize)]
+struct WhisperResult {
+    /// Spoken language, detected when run with `-l auto`
+    language: String,
+}
+
+/// A segment of whisper.cpp JSON output
+#[derive(Debug, Clone, Deserialize)]
+struct WhisperSegment {
+    offsets: WhisperOffsets,
+    text: String,
+    /// Set by `--tinydiarize` when the next segment has a different speaker
+    #[serde(default)]
+    speaker_turn_next: bool,
+    /// Decoded tokens with their probabilities
+    #[serde(default)]
+    tokens: Vec<WhisperToken>,
+}
+
+/// A token of a whisper.cpp segment
+#[derive(Debug, Clone, Deserialize)]
+struct WhisperToken {
+    text: String,
+    /// Probability of the token (0.0 - 1.0)
+    p: f32,
+}
+
+impl WhisperToken {
+    /// Check for control tokens such as `[_BEG_]` or `[_TT_150]`
+    fn is_special(&self) -> bool {
+        self.text.starts_with("[_")
+    }
+}
+
+/// Segment boundaries in milliseconds
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct WhisperOffsets {
+    from: u64,
+    to: u64,
+}
+
+/// Parse the JSON output file of whisper.cpp
+fn parse_whisper_json(json: &str) -> Result<WhisperOutput, SpeechError> {
+    serde_json::from_str::<WhisperOutput>(json).map_err(|e| {
+        SpeechError::TranscriptionFailed(format!("Invalid whisper.cpp JSON output: {e}"))
+    })
+}
+
+/// Probabilities of the spoken tokens, used to average the confidence
+fn token_scores(raw: &[WhisperSegment]) -> impl Iterator<Item = f32> + '_ {
+    raw.iter()
+        .flat_map(|segment| &segment.tokens)
+        .filter(|token| !token.is_special())
+        .map(|token| token.p)
+}
+
+/// Build transcript segments from whisper.cpp segments
+///
+/// Without diarization all text becomes a single segment spanning the whole
+/// recording. With diarization, consecutive segments are merged until
+/// whisper.cpp reports a speaker turn. Blank segments are dropped.
+fn assemble_segments(raw: &[WhisperSegment], diarize: bool) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut speaker = 0;
+    let mut turn_pending = false;
+
+    for part in raw {
+        let text = part.text.trim();
+        if text.is_empty() {
+            turn_pending |= diarize && part.speaker_turn_next;
+            continue;
+        }
+
+        // A turn before the first spoken text does not change the speaker
+        if turn_pending && !segments.is_empty() {
+            speaker = (speaker + 1) % SPEAKERS.len();
+        }
+
+        match segments.last_mut() {
+            Some(last) if !diarize || last.speaker.as_deref() == Some(SPEAKERS[speaker]) => {
+                last.text.push(' ');
+                last.text.push_str(text);
+                last.end_ms = part.offsets.to;
+            },
+            _ => {
+                let segment = Segment::new(text, part.offsets.from, part.offsets.to);
+                segments.push(if diarize {
+                    segment.with_speaker(SPEAKERS[speaker])
+                } else {
+                    segment
+                });
+            },
+        }
+
+        turn_pending = diarize && part.speaker_turn_next;
+    }
+
+    segments
+}
+
+/// Transcript text for a set of segments
+///
+/// Labels each segment with its speaker when more than one speaker was
+/// detected, one turn per line.
+fn transcript_text(segments: &[Segment]) -> String {
+    let multiple_speakers = segments
+        .iter()
+        .filter_map(|s| s.speaker.as_deref())
+        .any(|speaker| speaker != SPEAKERS[0]);
+
+    if multiple_speakers {
+        segments
+            .iter()
+            .map(|s| {
+                format!(
+                    "{}: {}",
+                    s.speaker.as_deref().unwrap_or(SPEAKERS[0]),
+                    s.text
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
 
 /// Local STT provider using whisper.cpp
 #[derive(Debug, Clone)]
@@ -71,31 +229,61 @@ impl WhisperCppProvider {
         &self.config.model_path
     }
 
-    /// Run whisper.cpp on an audio file
-    #[instrument(skip(self, audio_path), fields(model = %self.model().display()))]
-    async fn run_whisper(
+    /// Build the whisper.cpp command for an audio file
+    ///
+    /// Without a language hint, the configured default language is used, or
+    /// automatic detection when translating.
+    fn build_command(
         &self,
         audio_path: &Path,
+        output_base: &Path,
         language: Option<&str>,
-    ) -> Result<String, SpeechError> {
+        translate: bool,
+    ) -> Command {
         let mut cmd = Command::new(self.executable());
 
         cmd.arg("-m")
             .arg(self.model())
             .arg("-f")
             .arg(audio_path)
-            .arg("--output-txt")
+            .arg("--output-json-full")
+            .arg("--output-file")
+            .arg(output_base)
             .arg("--no-timestamps")
             .arg("-t")
             .arg(self.config.threads.to_string());
 
+        if self.config.diarize {
+            cmd.arg("--tinydiarize");
+        }
+
+        if translate {
+            cmd.arg("--translate");
+        }
+
         // Add language hint if provided
         if let Some(lang) = language {
             cmd.arg("-l").arg(lang);
+        } else if translate {
+            cmd.arg("-l").arg("auto");
         } else if let Some(ref default_lang) = self.config.default_language {
             cmd.arg("-l").arg(default_lang);
         }
 
+        cmd
+    }
+
+    /// Run whisper.cpp on an audio file and return its parsed output
+    #[instrument(skip(self, audio_path), fields(model = %self.model().display()))]
+    async fn run_whisper(
+        &self,
+        audio_path: &Path,
+        language: Option<&str>,
+        translate: bool,
+    ) -> Result<WhisperOutput, SpeechError> {
+        let output_base = audio_path.with_extension("");
+        let mut cmd = self.build_command(audio_path, &output_base, language, translate);
+
         // Suppress output
         cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
 
@@ -122,16 +310,77 @@ impl WhisperCppProvider {
             )));
         }
 
-        // Read the output text file
-        let txt_path = audio_path.with_extension("txt");
-        let text = tokio::fs::read_to_string(&txt_path).await.map_err(|e| {
+        // Read the JSON output file
+        let json_path = output_base.with_extension("json");
+        let json = tokio::fs::read_to_string(&json_path).await.map_err(|e| {
             SpeechError::TranscriptionFailed(format!("Failed to read transcription output: {e}"))
         })?;
 
         // Clean up output file
-        let _ = tokio::fs::remove_file(&txt_path).await;
+        let _ = tokio::fs::remove_file(&json_path).await;
+
+        parse_whisper_json(&json)
+    }
+
+    /// Transcribe (or translate) audio and assemble the result
+    ///
+    /// The transcription language is the language hint, else the language
+    /// whisper.cpp reports, else the configured default.
+    async fn transcribe_audio(
+        &self,
+        audio: AudioData,
+        language: Option<&str>,
+        translate: bool,
+    ) -> Result<Transcription, SpeechError> {
+        debug!(
+            translate,
+            "Transcribing audio with whisper.cpp, format: {:?}",
+            audio.format()
+        );
 
-        Ok(text.trim().to_string())
+        // Whisper.cpp works best with WAV
+        let needs_conversion = audio.format() != AudioFormat::Wav;
+
+        let audio_to_process = if needs_conversion {
+            // Use the audio converter to convert to WAV
+            let converter = crate::AudioConverter::new();
+            converter.convert(&audio, AudioFormat::Wav).await?
+        } else {
+            audio
+        };
+
+        // Write to temp file
+        let temp_file = self.write_temp_audio(&audio_to_process).await?;
+
+        // Run whisper.cpp
+        let output = self
+            .run_whisper(temp_file.path(), language, translate)
+            .await?;
+
+        // Temp file is automatically cleaned up when dropped
+
+        let segments = assemble_segments(&output.transcription, self.config.diarize);
+        let text = transcript_text(&segments);
+        if text.is_empty() {
+            warn!("whisper.cpp returned empty transcription");
+        }
+
+        let detected = output
+            .result
+            .map(|r| r.language)
+            .filter(|l| !l.is_empty() && l != "auto");
+        let language = language
+            .map(str::to_string)
+            .or(detected)
+            .or_else(|| self.config.default_language.clone());
+
+        let mut transcription = Transcription::new(text)
+            .with_segments(segments)
+            .with_average_confidence(token_scores(&output.transcription));
+        if let Some(language) = language {
+            transcription = transcription.with_language(language);
+        }
+        Ok(transcription)
     }
 
     /// Write audio data to a temporary WAV file for whisper.cpp
@@ -161,10 +410,13 @@ impl WhisperCppProvider {
 
 #[async_trait]
 impl SpeechToText for WhisperCppProvider {
-    #[instrument(skip(self, audio), fields(format = ?audio.format()))]
-    async fn transcribe(&self, audio: AudioData) -> Result<Transcription, SpeechError> {
-        self.transcribe_with_language(audio, self.config.default_language.as_deref().unwrap_or(""))
-            .await
+    #[instrument(skip(self, audio), fields(format = ?audio.format(), translate = options.translate))]
+    async fn transcribe(
+        &self,
+        audio: AudioData,
+        options: &TranscriptionOptions,
+    ) -> Result<Transcription, SpeechError> {
+        self.transcribe_audio(audio, None, options.translate).await
     }
 
     #[instrument(skip(self, audio), fields(format = ?audio.format(), language = %language))]
@@ -173,44 +425,8 @@ impl SpeechToText for WhisperCppProvider {
         audio: AudioData,
         language: &str,
     ) -> Result<Transcription, SpeechError> {
-        debug!(
-            "Transcribing audio with whisper.cpp, format: {:?}",
-            audio.format()
-        );
-
-        // Whisper.cpp works best with WAV
-        let needs_conversion = audio.format() != AudioFormat::Wav;
-
-        let audio_to_process = if needs_conversion {
-            // Use the audio converter to convert to WAV
-            let converter = crate::AudioConverter::new();
-            converter.convert(&audio, AudioFormat::Wav).await?
-        } else {
-            audio
-        };
-
-        // Write to temp file
-        let temp_file = self.write_temp_audio(&audio_to_process).await?;
-
-        // Run whisper.cpp
-        let lang = if language.is_empty() {
-            None
-        } else {
-            Some(language)
-        };
-        let text = self.run_whisper(temp_file.path(), lang).await?;
-
-        // Temp file is automatically cleaned up when dropped
-
-        if text.is_empty() {
-            warn!("whisper.cpp returned empty transcription");
-        }
-
-        let mut transcription = Transcription::new(text);
-        if !language.is_empty() {
-            transcription = transcription.with_language(language);
-        }
-        Ok(transcription)
+        let language = Some(language).filter(|l| !l.is_empty());
+        self.transcribe_audio(audio, language, false).await
     }
 
     async fn is_available(&self) -> bool {
@@ -257,6 +473,7 @@ mod tests {
             model_path: PathBuf::from("/models/ggml-base.bin"),
             threads: 4,
             default_language: Some("en".to_string()),
+            diarize: false,
         }
     }
 
@@ -291,4 +508,219 @@ mod tests {
         // Should return false since executable doesn't exist
         assert!(!provider.is_available().await);
     }
+
+    fn raw(text: &str, from: u64, to: u64, speaker_turn_next: bool) -> WhisperSegment {
+        WhisperSegment {
+            offsets: WhisperOffsets { from, to },
+            text: text.to_string(),
+            speaker_turn_next,
+            tokens: Vec::new(),
+        }
+    }
+
+    fn two_speaker_segments() -> Vec<WhisperSegment> {
+        vec![
+            raw(" Hi, are you coming tonight?", 0, 1800, false),
+            raw(" Dinner is at eight.", 1800, 3200, true),
+            raw(" Yes, I'll be there.", 3400, 4900, true),
+            raw(" Great, see you.", 5000, 6100, false),
+        ]
+    }
+
+    #[test]
+    fn without_diarization_produces_single_segment() {
+        let segments = assemble_segments(&two_speaker_segments(), false);
+
+        assert_eq!(
+            segments,
+            vec![Segment::new(
+                "Hi, are you coming tonight? Dinner is at eight. Yes, I'll be there. Great, see you.",
+                0,
+                6100,
+            )]
+        );
+    }
+
+    #[test]
+    fn diarization_splits_at_speaker_turns() {
+        let segments = assemble_segments(&two_speaker_segments(), true);
+
+        assert_eq!(
+            segments,
+            vec![
+                Segment::new("Hi, are you coming tonight? Dinner is at eight.", 0, 3200)
+                    .with_speaker("Speaker 1"),
+                Segment::new("Yes, I'll be there.", 3400, 4900).with_speaker("Speaker 2"),
+                Segment::new("Great, see you.", 5000, 6100).with_speaker("Speaker 1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn blank_segments_are_dropped_but_keep_turns() {
+        let raw_segments = vec![
+            raw(" Hello.", 0, 1000, true),
+            raw(" ", 1000, 1500, false),
+            raw(" Hi!", 1500, 2000, false),
+        ];
+
+        let segments = assemble_segments(&raw_segments, true);
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].speaker.as_deref(), Some("Speaker 2"));
+        assert_eq!(segments[1].start_ms, 1500);
+        assert!(assemble_segments(&[], false).is_empty());
+    }
+
+    #[test]
+    fn leading_turn_keeps_first_speaker() {
+        let raw_segments = vec![
+            raw(" ", 0, 500, true),
+            raw(" Hello.", 500, 1000, true),
+            raw(" Hi!", 1000, 1500, false),
+        ];
+
+        let segments = assemble_segments(&raw_segments, true);
+
+        assert_eq!(segments[0].speaker.as_deref(), Some("Speaker 1"));
+        assert_eq!(segments[1].speaker.as_deref(), Some("Speaker 2"));
+    }
+
+    #[test]
+    fn transcript_text_labels_multiple_speakers() {
+        let diarized = assemble_segments(&two_speaker_segments(), true);
+        assert_eq!(
+            transcript_text(&diarized),
+            "Speaker 1: Hi, are you coming tonight? Dinner is at eight.\n\
+             Speaker 2: Yes, I'll be there.\n\
+             Speaker 1: Great, see you."
+        );
+
+        let single = assemble_segments(&[raw(" Just me.", 0, 900, false)], true);
+        assert_eq!(transcript_text(&single), "Just me.");
+    }
+
+    #[test]
+    fn parses_whisper_json_output() {
+        let json = r#"{
+            "result": {"language": "en"},
+            "transcription": [
+                {
+                    "timestamps": {"from": "00:00:00,000", "to": "00:00:01,800"},
+                    "offsets": {"from": 0, "to": 1800},
+                    "text": " Hi there.",
+                    "speaker_turn_next": true
+                },
+                {
+                    "timestamps": {"from": "00:00:01,800", "to": "00:00:03,000"},
+                    "offsets": {"from": 1800, "to": 3000},
+                    "text": " Hello."
+                }
+            ]
+        }"#;
+
+        let output = parse_whisper_json(json).unwrap();
+        let parsed = &output.transcription;
+
+        assert_eq!(output.result.unwrap().language, "en");
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed[0].speaker_turn_next);
+        assert!(!parsed[1].speaker_turn_next);
+        assert_eq!(parsed[1].offsets.to, 3000);
+        assert!(parse_whisper_json("not json").is_err());
+    }
+
+    #[test]
+    fn token_scores_skip_control_tokens() {
+        let json = r#"{
+            "transcription": [
+                {
+                    "offsets": {"from": 0, "to": 1800},
+                    "text": " Hi there.",
+                    "tokens": [
+                        {"text": "[_BEG_]", "p": 0.1},
+                        {"text": " Hi", "p": 0.9},
+                        {"text": " there", "p": 0.7},
+                        {"text": "[_TT_90]", "p": 0.2}
+                    ]
+                }
+            ]
+        }"#;
+
+        let output = parse_whisper_json(json).unwrap();
+        let scores: Vec<f32> = token_scores(&output.transcription).collect();
+
+        assert_eq!(scores, vec![0.9, 0.7]);
+    }
+
+    #[test]
+    fn command_requests_full_json_output() {
+        let provider = WhisperCppProvider::new(test_config()).unwrap();
+
+        let args = command_args(&provider.build_command(
+            Path::new("/tmp/a.wav"),
+            Path::new("/tmp/a"),
+            None,
+            false,
+        ));
+
+        assert!(args.contains(&"--output-json-full".to_string()));
+    }
+
+    fn command_args(cmd: &Command) -> Vec<String> {
+        cmd.as_std()
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    fn has_arg_pair(args: &[String], flag: &str, value: &str) -> bool {
+        args.windows(2).any(|w| w[0] == flag && w[1] == value)
+    }
+
+    #[test]
+    fn translate_passes_flag_and_detects_language() {
+        let provider = WhisperCppProvider::new(test_config()).unwrap();
+
+        let args = command_args(&provider.build_command(
+            Path::new("/tmp/a.wav"),
+            Path::new("/tmp/a"),
+            None,
+            true,
+        ));
+
+        assert!(args.contains(&"--translate".to_string()));
+        // The configured default "en" must not override detection
+        assert!(has_arg_pair(&args, "-l", "auto"));
+    }
+
+    #[test]
+    fn translate_keeps_explicit_source_language() {
+        let provider = WhisperCppProvider::new(test_config()).unwrap();
+
+        let args = command_args(&provider.build_command(
+            Path::new("/tmp/a.wav"),
+            Path::new("/tmp/a"),
+            Some("es"),
+            true,
+        ));
+
+        assert!(args.contains(&"--translate".to_string()));
+        assert!(has_arg_pair(&args, "-l", "es"));
+    }
+
+    #[test]
+    fn transcription_without_translate_uses_default_language() {
+        let provider = WhisperCppProvider::new(test_config()).unwrap();
+
+        let args = command_args(&provider.build_command(
+            Path::new("/tmp/a.wav"),
+            Path::new("/tmp/a"),
+            None,
+            false,
+        ));
+
+        assert!(!args.contains(&"--translate".to_string()));
+        assert!(has_arg_pair(&args, "-l", "en"));
+    }
 }
@@ -3,10 +3,13 @@
 //! Contains data structures for audio data, formats, transcriptions, and voice information.
 
 use std::fmt;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::SpeechError;
+
 /// Supported audio formats
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -216,6 +219,83 @@ impl AudioData {
     }
 }
 
+/// Voice adjustments for a speech synthesis request
+///
+/// Both values are relative: `1.0` keeps the configured voice unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SynthesisOptions {
+    /// Speaking rate (2.0 = twice as fast)
+    pub rate: f32,
+    /// Pitch / intonation (above 1.0 = livelier)
+    pub pitch: f32,
+}
+
+impl Default for SynthesisOptions {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            pitch: 1.0,
+        }
+    }
+}
+
+impl SynthesisOptions {
+    /// Accepted `rate` values
+    pub const RATE_RANGE: RangeInclusive<f32> = 0.25..=4.0;
+
+    /// Accepted `pitch` values
+    pub const PITCH_RANGE: RangeInclusive<f32> = 0.5..=2.0;
+
+    /// Create options with the given rate and pitch
+    #[must_use]
+    pub const fn new(rate: f32, pitch: f32) -> Self {
+        Self { rate, pitch }
+    }
+
+    /// Check that rate and pitch are within their ranges
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpeechError::InvalidInput` for out-of-range values.
+    pub fn validate(&self) -> Result<(), SpeechError> {
+        if !Self::RATE_RANGE.contains(&self.rate) {
+            return Err(SpeechError::InvalidInput(format!(
+                "Rate must be between {} and {}, got {}",
+                Self::RATE_RANGE.start(),
+                Self::RATE_RANGE.end(),
+                self.rate
+            )));
+        }
+        if !Self::PITCH_RANGE.contains(&self.pitch) {
+            return Err(SpeechError::InvalidInput(format!(
+                "Pitch must be between {} and {}, got {}",
+                Self::PITCH_RANGE.start(),
+                Self::PITCH_RANGE.end(),
+                self.pitch
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Options for a speech-to-text request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TranscriptionOptions {
+    /// Translate the speech to English instead of transcribing it verbatim
+    ///
+    /// The resulting `Transcription::language` is the detected source
+    /// language, while its text is English.
+    pub translate: bool,
+}
+
+impl TranscriptionOptions {
+    /// Options for translating speech to English
+    #[must_use]
+    pub const fn translate() -> Self {
+        Self { translate: true }
+    }
+}
+
 /// Result of speech-to-text transcription
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transcription {
@@ -229,6 +309,10 @@ pub struct Transcription {
     pub duration_ms: Option<u64>,
     /// Word-level timestamps (if available)
     pub words: Option<Vec<WordTimestamp>>,
+    /// Transcript split into timed segments, attributed to speakers when
+    /// diarization is enabled (empty if the provider reports no timing)
+    #[serde(default)]
+    pub segments: Vec<Segment>,
 }
 
 impl Transcription {
@@ -241,6 +325,7 @@ impl Transcription {
             confidence: None,
             duration_ms: None,
             words: None,
+            segments: Vec::new(),
         }
     }
 
@@ -258,6 +343,26 @@ impl Transcription {
         self
     }
 
+    /// Set the confidence to the average of segment or word scores
+    ///
+    /// Scores are clamped to 0.0 - 1.0 and non-finite scores are ignored.
+    /// Without any usable score the confidence is left unchanged.
+    #[must_use]
+    pub fn with_average_confidence(mut self, scores: impl IntoIterator<Item = f32>) -> Self {
+        let (sum, count) = scores
+            .into_iter()
+            .filter(|s| s.is_finite())
+            .fold((0.0_f32, 0_u32), |(sum, count), s| {
+                (sum + s.clamp(0.0, 1.0), count + 1)
+            });
+        if count > 0 {
+            #[allow(clippy::cast_precision_loss)] // Segment and word counts are small
+            let average = sum / count as f32;
+            self.confidence = Some(average);
+        }
+        self
+    }
+
     /// Set the duration
     #[must_use]
     pub const fn with_duration(mut self, duration_ms: u64) -> Self {
@@ -265,6 +370,13 @@ impl Transcription {
         self
     }
 
+    /// Set the timed segments
+    #[must_use]
+    pub fn with_segments(mut self, segments: Vec<Segment>) -> Self {
+        self.segments = segments;
+        self
+    }
+
     /// Check if transcription is empty
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -272,6 +384,39 @@ impl Transcription {
     }
 }
 
+/// A timed span of a transcript
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Segment {
+    /// Speaker label (e.g. "Speaker 1"), `None` without diarization
+    pub speaker: Option<String>,
+    /// Transcribed text of this span
+    pub text: String,
+    /// Start time in milliseconds
+    pub start_ms: u64,
+    /// End time in milliseconds
+    pub end_ms: u64,
+}
+
+impl Segment {
+    /// Create a segment without a speaker
+    #[must_use]
+    pub fn new(text: impl Into<String>, start_ms: u64, end_ms: u64) -> Self {
+        Self {
+            speaker: None,
+            text: text.into(),
+            start_ms,
+            end_ms,
+        }
+    }
+
+    /// Attribute the segment to a speaker
+    #[must_use]
+    pub fn with_speaker(mut self, speaker: impl Into<String>) -> Self {
+        self.speaker = Some(speaker.into());
+        self
+    }
+}
+
 /// Word-level timestamp from transcription
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WordTimestamp {
@@ -329,6 +474,16 @@ pub enum VoiceGender {
     Neutral,
 }
 
+impl fmt::Display for VoiceGender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Male => write!(f, "male"),
+            Self::Female => write!(f, "female"),
+            Self::Neutral => write!(f, "neutral"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -443,6 +598,25 @@ mod tests {
             assert_eq!(audio.sample_rate(), None);
         }
 
+        #[test]
+        fn with_average_confidence_averages_scores() {
+            let transcription =
+                Transcription::new("Test").with_average_confidence([0.9, 0.5, f32::NAN, 1.4]);
+            let confidence = transcription.confidence.unwrap();
+            assert!((confidence - 0.8).abs() < 1e-6);
+        }
+
+        #[test]
+        fn with_average_confidence_keeps_confidence_without_scores() {
+            let transcription = Transcription::new("Test").with_average_confidence([]);
+            assert!(transcription.confidence.is_none());
+
+            let transcription = Transcription::new("Test")
+                .with_confidence(0.7)
+                .with_average_confidence([f32::NAN]);
+            assert_eq!(transcription.confidence, Some(0.7));
+        }
+
         #[test]
         fn with_duration_sets_duration() {
             let audio = AudioData::new(vec![1, 2, 3], AudioFormat::Opus).with_duration(5000);
@@ -559,4 +733,38 @@ mod tests {
             assert!(voice.gender.is_none());
         }
     }
+
+    mod synthesis_options {
+        use super::*;
+
+        #[test]
+        fn default_keeps_voice_unchanged() {
+            let options = SynthesisOptions::default();
+            assert!((options.rate - 1.0).abs() < f32::EPSILON);
+            assert!((options.pitch - 1.0).abs() < f32::EPSILON);
+            assert!(options.validate().is_ok());
+        }
+
+        #[test]
+        fn accepts_range_bounds() {
+            assert!(SynthesisOptions::new(0.25, 0.5).validate().is_ok());
+            assert!(SynthesisOptions::new(4.0, 2.0).validate().is_ok());
+        }
+
+        #[test]
+        fn rejects_out_of_range_values() {
+            for options in [
+                SynthesisOptions::new(0.1, 1.0),
+                SynthesisOptions::new(4.5, 1.0),
+                SynthesisOptions::new(1.0, 0.2),
+                SynthesisOptions::new(1.0, 3.0),
+                SynthesisOptions::new(f32::NAN, 1.0),
+            ] {
+                assert!(
+                    matches!(options.validate(), Err(SpeechError::InvalidInput(_))),
+                    "{options:?}"
+                );
+            }
+        }
+    }
 }
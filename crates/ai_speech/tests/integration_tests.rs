@@ -4,7 +4,7 @@
 
 use ai_speech::{
     AudioConverter, AudioData, AudioFormat, OpenAISpeechProvider, SpeechConfig, SpeechToText,
-    TextToSpeech,
+    TextToSpeech, TranscriptionOptions,
 };
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -71,7 +71,9 @@ async fn stt_transcription_success() {
     let provider = OpenAISpeechProvider::new(config).expect("Failed to create provider");
 
     let audio = AudioData::new(mock_mp3_audio(), AudioFormat::Mp3);
-    let result = provider.transcribe(audio).await;
+    let result = provider
+        .transcribe(audio, &TranscriptionOptions::default())
+        .await;
 
     assert!(result.is_ok(), "Transcription should succeed");
     let transcription = result.unwrap();
@@ -127,7 +129,9 @@ async fn stt_transcription_api_error() {
     let provider = OpenAISpeechProvider::new(config).expect("Failed to create provider");
 
     let audio = AudioData::new(vec![0x00, 0x01, 0x02], AudioFormat::Mp3);
-    let result = provider.transcribe(audio).await;
+    let result = provider
+        .transcribe(audio, &TranscriptionOptions::default())
+        .await;
 
     assert!(result.is_err(), "Should fail with API error");
 }
@@ -153,7 +157,9 @@ async fn stt_transcription_rate_limited() {
     let provider = OpenAISpeechProvider::new(config).expect("Failed to create provider");
 
     let audio = AudioData::new(mock_mp3_audio(), AudioFormat::Mp3);
-    let result = provider.transcribe(audio).await;
+    let result = provider
+        .transcribe(audio, &TranscriptionOptions::default())
+        .await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -163,6 +169,36 @@ async fn stt_transcription_rate_limited() {
     );
 }
 
+#[tokio::test]
+async fn stt_translation_to_english() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/audio/translations"))
+        .and(header("Authorization", "Bearer test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "text": "Hello, this is a test.",
+            "language": "german",
+            "duration": 1.8
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = test_config(&mock_server.uri());
+    let provider = OpenAISpeechProvider::new(config).expect("Failed to create provider");
+
+    let audio = AudioData::new(mock_mp3_audio(), AudioFormat::Mp3);
+    let result = provider
+        .transcribe(audio, &TranscriptionOptions::translate())
+        .await;
+
+    assert!(result.is_ok(), "Translation should succeed");
+    let transcription = result.unwrap();
+    assert_eq!(transcription.text, "Hello, this is a test.");
+    assert!(transcription.language.is_some());
+}
+
 // ============ TTS (Synthesis) Integration Tests ============
 
 #[tokio::test]
@@ -329,7 +365,7 @@ async fn full_voice_message_flow_text_response() {
 
     // Step 1: Transcribe
     let transcription = provider
-        .transcribe(voice_message_audio)
+        .transcribe(voice_message_audio, &TranscriptionOptions::default())
         .await
         .expect("Transcription failed");
 
@@ -375,7 +411,7 @@ async fn full_voice_message_flow_with_audio_response() {
     // Step 1: Transcribe incoming voice message
     let incoming_audio = AudioData::new(mock_mp3_audio(), AudioFormat::Mp3);
     let transcription = provider
-        .transcribe(incoming_audio)
+        .transcribe(incoming_audio, &TranscriptionOptions::default())
         .await
         .expect("Transcription failed");
 
@@ -413,7 +449,9 @@ async fn handles_network_timeout_gracefully() {
     let provider = OpenAISpeechProvider::new(config).expect("Failed to create provider");
 
     let audio = AudioData::new(mock_mp3_audio(), AudioFormat::Mp3);
-    let result = provider.transcribe(audio).await;
+    let result = provider
+        .transcribe(audio, &TranscriptionOptions::default())
+        .await;
 
     assert!(result.is_err(), "Should timeout");
 }
@@ -436,7 +474,9 @@ async fn handles_empty_transcription_response() {
     let provider = OpenAISpeechProvider::new(config).expect("Failed to create provider");
 
     let audio = AudioData::new(mock_mp3_audio(), AudioFormat::Mp3);
-    let result = provider.transcribe(audio).await;
+    let result = provider
+        .transcribe(audio, &TranscriptionOptions::default())
+        .await;
 
     assert!(result.is_ok());
     let transcription = result.unwrap();
@@ -1,9 +1,12 @@
 //! Mapping of parsed intents to typed `AgentCommand` values.
 
+use std::time::Duration;
+
 use chrono::{NaiveDate, NaiveTime};
-use domain::AgentCommand;
+use domain::{AgentCommand, Recurrence};
 
 use super::{CommandParser, ParsedIntent};
+use crate::date_parser::extract_recurrence_from_text;
 
 impl CommandParser {
     /// Convert parsed intent to `AgentCommand`
@@ -239,6 +242,11 @@ impl CommandParser {
                 only_important: None,
             }),
 
+            "summarize_conversation" => Ok(AgentCommand::SummarizeConversation {
+                conversation_id: None,
+                max_sentences: parsed.count,
+            }),
+
             "draft_email" => {
                 let to_str = parsed.to.as_ref().ok_or("Missing recipient for email")?;
                 let to = domain::EmailAddress::new(to_str)
@@ -288,10 +296,17 @@ impl CommandParser {
                     .as_ref()
                     .ok_or("Missing remind_at time for reminder")?
                     .clone();
+                // Prefer the model's rule; fall back to phrases in the input
+                let recurrence = parsed
+                    .recurrence
+                    .clone()
+                    .filter(|r| r.parse::<Recurrence>().is_ok())
+                    .or_else(|| extract_recurrence_from_text(original_input));
                 Ok(AgentCommand::CreateReminder {
                     title,
                     description: parsed.description.clone(),
                     remind_at,
+                    recurrence,
                 })
             },
 
@@ -329,6 +344,17 @@ impl CommandParser {
                 Ok(AgentCommand::DeleteReminder { reminder_id })
             },
 
+            "set_timer" => {
+                let minutes = parsed
+                    .duration_minutes
+                    .filter(|m| *m > 0)
+                    .ok_or("Missing duration for timer")?;
+                Ok(AgentCommand::SetTimer {
+                    label: parsed.title.clone(),
+                    duration: Duration::from_secs(u64::from(minutes) * 60),
+                })
+            },
+
             "search_transit" => {
                 let to_address = parsed
                     .to_address
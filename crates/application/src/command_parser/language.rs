@@ -0,0 +1,101 @@
+//! Default language used when the input language is ambiguous.
+
+use std::{fmt, str::FromStr};
+
+/// Language assumed for input that could belong to several languages
+///
+/// Quick patterns match German, English, French and Spanish keywords
+/// regardless of this setting; it only steers LLM intent detection for
+/// short or mixed-language input ("Termin meeting demain").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParserLanguage {
+    /// German (default)
+    #[default]
+    German,
+    /// English
+    English,
+    /// French
+    French,
+    /// Spanish
+    Spanish,
+}
+
+impl ParserLanguage {
+    /// All supported languages
+    pub const ALL: [Self; 4] = [Self::German, Self::English, Self::French, Self::Spanish];
+
+    /// ISO 639-1 language code
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::German => "de",
+            Self::English => "en",
+            Self::French => "fr",
+            Self::Spanish => "es",
+        }
+    }
+
+    /// English name of the language, as used in the intent prompt
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::German => "German",
+            Self::English => "English",
+            Self::French => "French",
+            Self::Spanish => "Spanish",
+        }
+    }
+
+    /// Instruction appended to the intent system prompt
+    pub(super) fn prompt_hint(self) -> String {
+        format!(
+            "If the language of the input is ambiguous, assume it is {}.",
+            self.name()
+        )
+    }
+}
+
+impl fmt::Display for ParserLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for ParserLanguage {
+    type Err = String;
+
+    /// Parse a language code ("fr") or English name ("French")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|lang| lang.code().eq_ignore_ascii_case(s) || lang.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unsupported language: {s}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_german() {
+        assert_eq!(ParserLanguage::default(), ParserLanguage::German);
+    }
+
+    #[test]
+    fn parses_codes_and_names() {
+        for lang in ParserLanguage::ALL {
+            assert_eq!(lang.code().parse::<ParserLanguage>().unwrap(), lang);
+            assert_eq!(lang.name().parse::<ParserLanguage>().unwrap(), lang);
+        }
+        assert_eq!(
+            " FR ".parse::<ParserLanguage>().unwrap(),
+            ParserLanguage::French
+        );
+        assert!("it".parse::<ParserLanguage>().is_err());
+    }
+
+    #[test]
+    fn prompt_hint_names_language() {
+        assert!(ParserLanguage::Spanish.prompt_hint().contains("Spanish"));
+    }
+}
@@ -1,15 +1,57 @@
 //! LLM-powered intent detection and JSON parsing.
 
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use domain::AgentCommand;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::Deserialize;
+use serde_json::{Value, json};
 use tracing::{debug, instrument, warn};
 
-use super::{CommandParser, INTENT_SYSTEM_PROMPT, ParsedIntent};
-use crate::{error::ApplicationError, ports::InferencePort};
+use super::{CommandParser, ParsedIntent};
+use crate::{
+    error::ApplicationError,
+    ports::{InferenceJsonExt, InferencePort},
+};
+
+/// Intent JSON requested from the model
+///
+/// A single intent or tool call object, or a non-empty array of them.
+/// Field-level checks happen when the intents are mapped to commands.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+struct IntentResponse(Value);
+
+impl JsonSchema for IntentResponse {
+    fn schema_name() -> Cow<'static, str> {
+        "IntentResponse".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        let intent = json!({
+            "type": "object",
+            "properties": {
+                "intent": { "type": "string" },
+                "tool": { "type": "string" },
+                "arguments": { "type": "object" }
+            },
+            "anyOf": [{ "required": ["intent"] }, { "required": ["tool"] }]
+        });
+        json_schema!({
+            "anyOf": [
+                intent.clone(),
+                { "type": "array", "minItems": 1, "items": intent }
+            ]
+        })
+    }
+}
 
 impl CommandParser {
     /// Parse using LLM for complex commands
+    ///
+    /// The model is asked for schema-constrained JSON and re-prompted when
+    /// its reply does not match. Replies that stay invalid, or that name an
+    /// unknown intent, fall back to [`AgentCommand::Ask`].
     #[instrument(skip(self, inference, input), fields(input_len = input.len()))]
     pub async fn parse_with_llm(
         &self,
@@ -21,30 +63,45 @@ impl CommandParser {
             return Ok(cmd);
         }
 
+        // Then paraphrases of argument-free intents
+        if let Some(cmd) = self.parse_semantic(input).await {
+            return Ok(cmd);
+        }
+
         // Use LLM for intent detection
         debug!("No quick match, using LLM for intent detection");
 
-        let result = inference
-            .generate_with_system(INTENT_SYSTEM_PROMPT, input)
-            .await?;
+        let ask = || AgentCommand::Ask {
+            question: input.to_string(),
+        };
+
+        let response = match inference
+            .generate_json_with_system::<IntentResponse>(&self.intent_system_prompt(), input)
+            .await
+        {
+            Ok(response) => response,
+            Err(ApplicationError::InvalidModelOutput { attempts, reason }) => {
+                warn!(attempts, reason = %reason, "LLM did not return a valid intent");
+                return Ok(ask());
+            },
+            Err(e) => return Err(e),
+        };
 
-        // Try to parse the LLM response as JSON
-        match self.parse_llm_response(&result.content, input) {
+        match self.value_to_commands(response.0, input) {
             Ok(cmd) => {
                 debug!(command = ?cmd, "LLM-parsed command");
                 Ok(cmd)
             },
             Err(e) => {
-                warn!(error = %e, response = %result.content, "Failed to parse LLM intent response");
+                warn!(error = %e, "Failed to map LLM intent response");
                 // Fall back to Ask intent
-                Ok(AgentCommand::Ask {
-                    question: input.to_string(),
-                })
+                Ok(ask())
             },
         }
     }
 
-    /// Parse the LLM response JSON into an `AgentCommand`
+    /// Parse a raw LLM response into an `AgentCommand`
+    #[cfg(test)]
     fn parse_llm_response(
         &self,
         response: &str,
@@ -53,41 +110,62 @@ impl CommandParser {
         // Extract JSON from response (handle markdown code blocks)
         let json_str = Self::extract_json(response);
 
-        let parsed: ParsedIntent =
+        let value: Value =
             serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {e}"))?;
 
-        self.intent_to_command(parsed, original_input)
+        self.value_to_commands(value, original_input)
     }
 
-    /// Extract JSON from potentially markdown-wrapped response
-    fn extract_json(response: &str) -> &str {
-        let response = response.trim();
-
-        // Handle ```json ... ``` blocks
-        if let Some(start) = response.find("```json") {
-            if let Some(end) = response[start + 7..].find("```") {
-                return response[start + 7..start + 7 + end].trim();
-            }
+    /// Convert the LLM response JSON into an `AgentCommand`
+    ///
+    /// An array of intents becomes an [`AgentCommand::Chain`] in array order.
+    /// An object with a `"tool"` key becomes an [`AgentCommand::CallTool`]
+    /// if that tool is registered.
+    fn value_to_commands(
+        &self,
+        value: Value,
+        original_input: &str,
+    ) -> Result<AgentCommand, String> {
+        if let Value::Array(items) = value {
+            let mut steps = items
+                .into_iter()
+                .map(|item| self.value_to_command(item, original_input))
+                .collect::<Result<Vec<_>, _>>()?;
+            return match steps.len() {
+                0 => Err("Empty intent list".to_string()),
+                1 => Ok(steps.remove(0)),
+                _ => Ok(AgentCommand::Chain { steps }),
+            };
         }
 
-        // Handle ``` ... ``` blocks
-        if let Some(start) = response.find("```") {
-            if let Some(end) = response[start + 3..].find("```") {
-                return response[start + 3..start + 3 + end].trim();
-            }
-        }
+        self.value_to_command(value, original_input)
+    }
 
-        // Handle { ... } directly
-        // Ensure start < end to avoid panics with malformed input like "} {"
-        if let Some(start) = response.find('{') {
-            if let Some(end) = response.rfind('}') {
-                if start <= end {
-                    return &response[start..=end];
-                }
+    /// Convert a single intent or tool call object into an `AgentCommand`
+    fn value_to_command(&self, value: Value, original_input: &str) -> Result<AgentCommand, String> {
+        if let Some(name) = value.get("tool").and_then(Value::as_str) {
+            if !self.tool_names.iter().any(|tool| tool == name) {
+                return Err(format!("Unknown tool: {name}"));
             }
+            return Ok(AgentCommand::CallTool {
+                name: name.to_string(),
+                arguments: value
+                    .get("arguments")
+                    .cloned()
+                    .unwrap_or_else(|| Value::Object(serde_json::Map::new())),
+            });
         }
 
-        response
+        let parsed: ParsedIntent =
+            serde_json::from_value(value).map_err(|e| format!("JSON parse error: {e}"))?;
+
+        self.intent_to_command(parsed, original_input)
+    }
+
+    /// Extract JSON from potentially markdown-wrapped response
+    #[cfg(test)]
+    fn extract_json(response: &str) -> &str {
+        crate::ports::extract_json(response)
     }
 }
 
@@ -98,7 +176,10 @@ mod async_tests {
     use mockall::mock;
 
     use super::*;
-    use crate::ports::InferenceResult;
+    use crate::{
+        command_parser::{INTENT_SYSTEM_PROMPT, ParserLanguage},
+        ports::InferenceResult,
+    };
 
     mock! {
         pub InferenceEngine {}
@@ -232,6 +313,94 @@ mod async_tests {
         assert!(matches!(result, AgentCommand::SummarizeInbox { .. }));
     }
 
+    fn reply(content: &str) -> Result<InferenceResult, ApplicationError> {
+        Ok(InferenceResult {
+            content: content.to_string(),
+            model: "test".to_string(),
+            tokens_used: Some(10),
+            latency_ms: 50,
+        })
+    }
+
+    #[tokio::test]
+    async fn parse_with_llm_repairs_invalid_json() {
+        let parser = CommandParser::new();
+        let mut mock = MockInferenceEngine::new();
+        let mut seq = mockall::Sequence::new();
+        mock.expect_generate_with_system()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| reply("Sure! Here are your tasks: list_tasks"));
+        mock.expect_generate_with_system()
+            .withf(|_, msg| msg.contains("rejected") && msg.contains("list_tasks"))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| reply(r#"{"intent":"list_tasks"}"#));
+        let inference: Arc<dyn InferencePort> = Arc::new(mock);
+
+        let result = parser
+            .parse_with_llm(&inference, "what do I still have to do")
+            .await
+            .unwrap();
+        assert!(matches!(result, AgentCommand::ListTasks { .. }));
+    }
+
+    #[tokio::test]
+    async fn parse_with_llm_repairs_schema_violation() {
+        let parser = CommandParser::new();
+        let mut mock = MockInferenceEngine::new();
+        let mut seq = mockall::Sequence::new();
+        mock.expect_generate_with_system()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| reply(r#"{"action":"list_tasks"}"#));
+        mock.expect_generate_with_system()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| reply(r#"{"intent":"list_tasks"}"#));
+        let inference: Arc<dyn InferencePort> = Arc::new(mock);
+
+        let result = parser
+            .parse_with_llm(&inference, "what do I still have to do")
+            .await
+            .unwrap();
+        assert!(matches!(result, AgentCommand::ListTasks { .. }));
+    }
+
+    #[tokio::test]
+    async fn parse_with_llm_gives_up_after_repair_attempts() {
+        let parser = CommandParser::new();
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system()
+            .times(crate::ports::JSON_REPAIR_ATTEMPTS as usize + 1)
+            .returning(|_, _| reply("not json at all"));
+        let inference: Arc<dyn InferencePort> = Arc::new(mock);
+
+        let result = parser
+            .parse_with_llm(&inference, "what do I still have to do")
+            .await
+            .unwrap();
+        let AgentCommand::Ask { question } = result else {
+            unreachable!("Expected Ask command");
+        };
+        assert_eq!(question, "what do I still have to do");
+    }
+
+    #[tokio::test]
+    async fn parse_with_llm_propagates_backend_errors() {
+        let parser = CommandParser::new();
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system()
+            .times(1)
+            .returning(|_, _| Err(ApplicationError::ExternalService("down".to_string())));
+        let inference: Arc<dyn InferencePort> = Arc::new(mock);
+
+        let result = parser
+            .parse_with_llm(&inference, "what do I still have to do")
+            .await;
+        assert!(matches!(result, Err(ApplicationError::ExternalService(_))));
+    }
+
     // Tests for LLM response parsing
 
     #[test]
@@ -468,6 +637,7 @@ mod async_tests {
             title,
             remind_at,
             description,
+            recurrence,
         } = cmd
         else {
             unreachable!("Expected CreateReminder")
@@ -475,6 +645,18 @@ mod async_tests {
         assert_eq!(title, "Call mom");
         assert_eq!(remind_at, "2025-02-20T18:00:00");
         assert!(description.is_none());
+        assert!(recurrence.is_none());
+    }
+
+    #[test]
+    fn parse_llm_response_create_reminder_with_recurrence() {
+        let parser = CommandParser::new();
+        let response = r#"{"intent":"create_reminder","title":"Trash","remind_at":"2025-02-24 07:00","recurrence":"weekly:Mon"}"#;
+        let cmd = parser.parse_llm_response(response, "").unwrap();
+        let AgentCommand::CreateReminder { recurrence, .. } = cmd else {
+            unreachable!("Expected CreateReminder")
+        };
+        assert_eq!(recurrence.as_deref(), Some("weekly:Mon"));
     }
 
     #[test]
@@ -670,6 +852,66 @@ mod async_tests {
         assert!(result.unwrap_err().contains("Missing destination"));
     }
 
+    #[test]
+    fn parse_llm_response_set_timer() {
+        let parser = CommandParser::new();
+        let response = r#"{"intent":"set_timer","duration_minutes":8,"title":"Eier"}"#;
+        let cmd = parser.parse_llm_response(response, "").unwrap();
+        let AgentCommand::SetTimer { label, duration } = cmd else {
+            unreachable!("Expected SetTimer")
+        };
+        assert_eq!(label.as_deref(), Some("Eier"));
+        assert_eq!(duration.as_secs(), 480);
+    }
+
+    #[test]
+    fn parse_llm_response_set_timer_missing_duration() {
+        let parser = CommandParser::new();
+        let response = r#"{"intent":"set_timer"}"#;
+        let result = parser.parse_llm_response(response, "");
+        assert!(result.unwrap_err().contains("Missing duration"));
+    }
+
+    #[test]
+    fn parse_llm_response_intent_array_becomes_chain() {
+        let parser = CommandParser::new();
+        let response = r#"[{"intent":"draft_email","to":"bob@example.com","subject":"Lunch","body":"Lunch tomorrow?"},{"intent":"send_email","draft_id":"latest"}]"#;
+        let cmd = parser.parse_llm_response(response, "").unwrap();
+        let AgentCommand::Chain { steps } = cmd else {
+            unreachable!("Expected Chain")
+        };
+        assert_eq!(steps.len(), 2);
+        assert!(matches!(steps[0], AgentCommand::DraftEmail { .. }));
+        assert!(matches!(
+            &steps[1],
+            AgentCommand::SendEmail { draft_id } if draft_id == "latest"
+        ));
+    }
+
+    #[test]
+    fn parse_llm_response_single_element_array_is_unwrapped() {
+        let parser = CommandParser::new();
+        let response = "```json\n[{\"intent\":\"list_reminders\"}]\n```";
+        let cmd = parser.parse_llm_response(response, "").unwrap();
+        assert!(matches!(cmd, AgentCommand::ListReminders { .. }));
+    }
+
+    #[test]
+    fn parse_llm_response_empty_array_is_error() {
+        let parser = CommandParser::new();
+        let result = parser.parse_llm_response("[]", "");
+        assert_eq!(result.unwrap_err(), "Empty intent list");
+    }
+
+    #[test]
+    fn extract_json_array_with_surrounding_text() {
+        let response = r#"Here you go: [{"intent":"help"}] done"#;
+        assert_eq!(
+            CommandParser::extract_json(response),
+            r#"[{"intent":"help"}]"#
+        );
+    }
+
     #[test]
     fn parse_llm_response_invalid_json() {
         let parser = CommandParser::new();
@@ -714,7 +956,104 @@ mod async_tests {
         assert!(INTENT_SYSTEM_PROMPT.len() > 100);
         assert!(INTENT_SYSTEM_PROMPT.contains("intent"));
         assert!(INTENT_SYSTEM_PROMPT.contains("JSON"));
-        assert!(INTENT_SYSTEM_PROMPT.contains("web_search"));
+        assert!(INTENT_SYSTEM_PROMPT.contains("search_transit"));
+    }
+
+    #[test]
+    fn intent_system_prompt_covers_french_and_spanish() {
+        assert!(INTENT_SYSTEM_PROMPT.contains("Rappelle-moi"));
+        assert!(INTENT_SYSTEM_PROMPT.contains("Recuérdame"));
+
+        let parser = CommandParser::new().with_default_language(ParserLanguage::French);
+        let prompt = parser.intent_system_prompt();
+        assert!(prompt.starts_with(INTENT_SYSTEM_PROMPT));
+        assert!(prompt.ends_with("assume it is French."));
+    }
+
+    struct LookupTool;
+
+    #[async_trait::async_trait]
+    impl crate::tools::Tool for LookupTool {
+        fn name(&self) -> &str {
+            "lookup"
+        }
+
+        fn description(&self) -> &str {
+            "Look something up."
+        }
+
+        fn json_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object", "properties": {"key": {"type": "string"}}})
+        }
+
+        async fn invoke(
+            &self,
+            args: serde_json::Value,
+        ) -> Result<serde_json::Value, ApplicationError> {
+            Ok(args)
+        }
+    }
+
+    fn parser_with_tools() -> CommandParser {
+        let mut tools = crate::tools::ToolRegistry::new();
+        tools.register(Arc::new(LookupTool));
+        CommandParser::new().with_tools(&tools)
+    }
+
+    #[test]
+    fn intent_system_prompt_lists_registered_tools() {
+        let prompt = parser_with_tools().intent_system_prompt();
+        assert!(prompt.starts_with(INTENT_SYSTEM_PROMPT));
+        assert!(prompt.contains("\"lookup\": Look something up."));
+        assert!(
+            !CommandParser::new()
+                .intent_system_prompt()
+                .contains("lookup")
+        );
+    }
+
+    #[test]
+    fn parse_llm_response_tool_call() {
+        let response = r#"{"tool":"lookup","arguments":{"key":"pi"}}"#;
+        let cmd = parser_with_tools()
+            .parse_llm_response(response, "")
+            .unwrap();
+        let AgentCommand::CallTool { name, arguments } = cmd else {
+            unreachable!("Expected CallTool command");
+        };
+        assert_eq!(name, "lookup");
+        assert_eq!(arguments, serde_json::json!({"key": "pi"}));
+    }
+
+    #[test]
+    fn parse_llm_response_tool_call_without_arguments() {
+        let response = r#"{"tool":"lookup"}"#;
+        let cmd = parser_with_tools()
+            .parse_llm_response(response, "")
+            .unwrap();
+        assert!(
+            matches!(cmd, AgentCommand::CallTool { arguments, .. } if arguments == serde_json::json!({}))
+        );
+    }
+
+    #[test]
+    fn parse_llm_response_unregistered_tool_is_error() {
+        let response = r#"{"tool":"lookup","arguments":{}}"#;
+        let result = CommandParser::new().parse_llm_response(response, "");
+        assert_eq!(result.unwrap_err(), "Unknown tool: lookup");
+    }
+
+    #[test]
+    fn parse_llm_response_chain_with_tool_call() {
+        let response = r#"[{"tool":"lookup","arguments":{"key":"a"}},{"intent":"list_tasks"}]"#;
+        let cmd = parser_with_tools()
+            .parse_llm_response(response, "")
+            .unwrap();
+        let AgentCommand::Chain { steps } = cmd else {
+            unreachable!("Expected Chain command");
+        };
+        assert!(matches!(steps[0], AgentCommand::CallTool { .. }));
+        assert!(matches!(steps[1], AgentCommand::ListTasks { .. }));
     }
 
     // =========================================================================
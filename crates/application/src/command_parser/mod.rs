@@ -1,23 +1,40 @@
 //! Command parser - Parse natural language into typed commands
 //!
 //! This module is split into focused sub-modules:
-//! - `quick_patterns`: Fast keyword-based pattern matching (no LLM needed)
+//! - `quick_patterns`: Fast keyword-based pattern matching with typo tolerance (no LLM needed)
 //! - `llm`: LLM-powered intent detection and JSON parsing
 //! - `intent_mapping`: Mapping parsed intents to typed `AgentCommand` values
+//! - `multi_intent`: Splitting compound input into one command per clause
+//! - `language`: Default language for ambiguous input
+//! - `semantic`: Optional embedding-based routing of paraphrases (skips the LLM)
+//!
+//! Registered [`Tool`](crate::tools::Tool)s are appended to the intent prompt
+//! and come back as [`AgentCommand::CallTool`].
+//!
+//! Quick patterns and the intent prompt cover German, English, French and Spanish.
 
 mod intent_mapping;
+mod language;
 mod llm;
+mod multi_intent;
 mod quick_patterns;
+mod semantic;
 
-use std::fmt;
+pub use language::ParserLanguage;
+pub use semantic::{SemanticMatch, SemanticRouter, SemanticRouterConfig};
+
+use std::{fmt, sync::Arc};
 
 use domain::AgentCommand;
 use serde::Deserialize;
 use tracing::debug;
 
+use crate::tools::ToolRegistry;
+
 /// System prompt for intent detection
 pub(super) const INTENT_SYSTEM_PROMPT: &str = r#"You are an intent classifier for a personal assistant.
 Analyze the user input and extract the intent as JSON.
+The input may be in German, English, French or Spanish. Keep titles, questions and queries in the user's language.
 
 Possible intents:
 - "morning_briefing": Request morning briefing (e.g., "What's on today?", "Briefing")
@@ -31,14 +48,15 @@ Possible intents:
 - "list_task_lists": List all available task lists/calendars
 - "create_task_list": Create a new task list (requires: name)
 - "summarize_inbox": Email summary (e.g., "What's new?", "Mails")
+- "summarize_conversation": TL;DR of this chat (optional: count as maximum number of sentences)
 - "draft_email": Draft email (requires: to, body; optional: subject)
 - "send_email": Send email (requires: draft_id)
-- "web_search": Search the internet (requires: query; optional: max_results)
-- "create_reminder": Create a reminder (requires: title, remind_at datetime; optional: description)
+- "create_reminder": Create a reminder (requires: title, remind_at datetime; optional: description, recurrence)
 - "list_reminders": List active reminders (optional: include_done)
 - "snooze_reminder": Snooze a reminder (requires: reminder_id; optional: duration_minutes, default 15)
 - "acknowledge_reminder": Mark reminder done (requires: reminder_id)
 - "delete_reminder": Delete a reminder (requires: reminder_id)
+- "set_timer": Start a countdown timer (requires: duration_minutes; optional: title as label)
 - "search_transit": Search public transit (requires: from, to locations; optional: departure datetime)
 - "list_contacts": List contacts (optional: query to filter)
 - "get_contact": Get contact details (requires: contact_id)
@@ -62,17 +80,17 @@ Reply ONLY with valid JSON:
   "list": "..." (optional, for tasks - target list/calendar name),
   "name": "..." (required for create_task_list),
   "location": "..." (optional, for appointments),
-  "duration_minutes": 60 (optional, for appointments),
+  "duration_minutes": 60 (optional, for appointments; required for set_timer),
   "to": "email@example.com" (optional, for emails),
   "subject": "..." (optional, for emails),
   "body": "..." (optional, for emails),
   "question": "..." (only for ask intent),
   "count": 10 (optional, for inbox),
   "draft_id": "..." (optional, for send_email),
-  "query": "..." (only for web_search intent),
-  "max_results": 5 (optional, for web_search, default 5),
+  "query": "..." (for list_contacts/search_contacts),
   "reminder_id": "..." (for snooze/acknowledge/delete_reminder),
   "remind_at": "YYYY-MM-DD HH:MM" (for create_reminder, when to fire),
+  "recurrence": "daily" | "weekly" | "weekly:Mon" (optional, for repeating create_reminder),
   "include_done": false (optional, for list_reminders),
   "from": "..." (origin address for search_transit),
   "to_address": "..." (destination address for search_transit),
@@ -85,6 +103,8 @@ Reply ONLY with valid JSON:
   "notes": "..." (optional, for create_contact/update_contact)
 }
 
+If the input asks for several steps that build on each other (e.g. draft an email and then send it), reply with a JSON array of these objects in execution order. Use "draft_id": "latest" to refer to a draft created earlier in the same array.
+
 Examples:
 - "Briefing for tomorrow" → {"intent":"morning_briefing","date":"2025-02-02"}
 - "Appointment tomorrow 14:00 Team Meeting" → {"intent":"create_calendar_event","date":"2025-02-02","time":"14:00","title":"Team Meeting"}
@@ -100,14 +120,18 @@ Examples:
 - "What lists do I have?" → {"intent":"list_task_lists"}
 - "Create list Vacation" → {"intent":"create_task_list","name":"Vacation"}
 - "Summarize my mails" → {"intent":"summarize_inbox"}
-- "Search the internet for Rust async patterns" → {"intent":"web_search","query":"Rust async patterns"}
+- "Give me a short recap of our chat in 3 sentences" → {"intent":"summarize_conversation","count":3}
+- "Draft an email to bob@example.com about lunch tomorrow and send it" → [{"intent":"draft_email","to":"bob@example.com","subject":"Lunch","body":"Hi Bob, shall we have lunch tomorrow?"},{"intent":"send_email","draft_id":"latest"}]
 - "Remind me to call mom in 30 minutes" → {"intent":"create_reminder","title":"call mom","remind_at":"2025-01-15 10:30"}
 - "Erinner mich morgen um 9 Uhr an Arzttermin" → {"intent":"create_reminder","title":"Arzttermin","remind_at":"2025-01-16 09:00"}
+- "Erinnere mich jeden Montag um 7 an die Mülltonne" → {"intent":"create_reminder","title":"Mülltonne","remind_at":"2025-01-20 07:00","recurrence":"weekly:Mon"}
 - "What are my reminders?" → {"intent":"list_reminders"}
 - "Zeig meine Erinnerungen" → {"intent":"list_reminders"}
 - "Snooze reminder abc for 15 minutes" → {"intent":"snooze_reminder","reminder_id":"abc","duration_minutes":15}
 - "Reminder abc done" → {"intent":"acknowledge_reminder","reminder_id":"abc"}
 - "Delete reminder xyz" → {"intent":"delete_reminder","reminder_id":"xyz"}
+- "Set a timer for 10 minutes" → {"intent":"set_timer","duration_minutes":10}
+- "Stell einen Timer auf 8 Minuten für die Eier" → {"intent":"set_timer","duration_minutes":8,"title":"die Eier"}
 - "How do I get from Alexanderplatz to TU Berlin?" → {"intent":"search_transit","from":"Alexanderplatz, Berlin","to_address":"TU Berlin"}
 - "ÖPNV von Hauptbahnhof nach Potsdamer Platz um 14:00" → {"intent":"search_transit","from":"Hauptbahnhof Berlin","to_address":"Potsdamer Platz","departure":"2025-01-15 14:00"}
 - "Show my contacts" → {"intent":"list_contacts"}
@@ -120,7 +144,14 @@ Examples:
 - "Delete contact c-456" → {"intent":"delete_contact","contact_id":"c-456"}
 - "Search contacts for engineers" → {"intent":"search_contacts","query":"engineers"}
 - "Suche Kontakte mit Acme" → {"intent":"search_contacts","query":"Acme"}
-- "What's the weather like?" → {"intent":"ask","question":"What's the weather like?"}"#;
+- "Résumé du jour pour demain" → {"intent":"morning_briefing","date":"2025-02-02"}
+- "Rappelle-moi d'appeler maman demain à 9h" → {"intent":"create_reminder","title":"appeler maman","remind_at":"2025-01-16 09:00"}
+- "Montre mes rappels" → {"intent":"list_reminders"}
+- "Comment aller à la Tour Eiffel depuis Gare du Nord ?" → {"intent":"search_transit","from":"Gare du Nord, Paris","to_address":"Tour Eiffel"}
+- "Resumen del día" → {"intent":"morning_briefing"}
+- "Recuérdame llamar a mamá en 30 minutos" → {"intent":"create_reminder","title":"llamar a mamá","remind_at":"2025-01-15 10:30"}
+- "Muestra mis recordatorios" → {"intent":"list_reminders"}
+- "¿Cómo llego a la Puerta del Sol desde Atocha?" → {"intent":"search_transit","from":"Atocha, Madrid","to_address":"Puerta del Sol"}"#;
 
 /// Parsed intent from LLM
 #[derive(Debug, Deserialize)]
@@ -172,6 +203,8 @@ pub(super) struct ParsedIntent {
     #[serde(default)]
     pub remind_at: Option<String>,
     #[serde(default)]
+    pub recurrence: Option<String>,
+    #[serde(default)]
     pub include_done: Option<bool>,
     // Transit fields
     #[serde(default)]
@@ -199,12 +232,23 @@ pub(super) struct ParsedIntent {
 pub struct CommandParser {
     /// Patterns for quick command matching (without LLM)
     quick_patterns: Vec<QuickPattern>,
+    /// Language assumed for ambiguous input
+    default_language: ParserLanguage,
+    /// Prompt section describing the registered tools
+    tool_prompt: Option<String>,
+    /// Names of the registered tools the LLM may call
+    tool_names: Vec<String>,
+    /// Embedding-based router tried before the LLM
+    semantic_router: Option<Arc<SemanticRouter>>,
 }
 
 impl fmt::Debug for CommandParser {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("CommandParser")
             .field("quick_patterns_count", &self.quick_patterns.len())
+            .field("default_language", &self.default_language)
+            .field("tool_names", &self.tool_names)
+            .field("semantic_router", &self.semantic_router.is_some())
             .finish()
     }
 }
@@ -222,11 +266,71 @@ impl CommandParser {
     pub fn new() -> Self {
         Self {
             quick_patterns: Self::build_quick_patterns(),
+            default_language: ParserLanguage::default(),
+            tool_prompt: None,
+            tool_names: Vec::new(),
+            semantic_router: None,
+        }
+    }
+
+    /// Set the language assumed for ambiguous input
+    #[must_use]
+    pub const fn with_default_language(mut self, language: ParserLanguage) -> Self {
+        self.default_language = language;
+        self
+    }
+
+    /// Offer the registered tools to the LLM during intent detection
+    #[must_use]
+    pub fn with_tools(mut self, tools: &ToolRegistry) -> Self {
+        self.tool_prompt = tools.prompt_section();
+        self.tool_names = tools.names().map(str::to_string).collect();
+        self
+    }
+
+    /// Route paraphrased input by embedding similarity before asking the LLM
+    #[must_use]
+    pub fn with_semantic_router(mut self, router: Arc<SemanticRouter>) -> Self {
+        self.semantic_router = Some(router);
+        self
+    }
+
+    /// Language assumed for ambiguous input
+    pub const fn default_language(&self) -> ParserLanguage {
+        self.default_language
+    }
+
+    /// Intent system prompt including the default language hint
+    pub(super) fn intent_system_prompt(&self) -> String {
+        match &self.tool_prompt {
+            Some(tools) => format!(
+                "{INTENT_SYSTEM_PROMPT}\n\n{tools}\n\n{}",
+                self.default_language.prompt_hint()
+            ),
+            None => format!(
+                "{INTENT_SYSTEM_PROMPT}\n\n{}",
+                self.default_language.prompt_hint()
+            ),
         }
     }
 
     /// Try to parse using quick patterns (no LLM needed)
+    ///
+    /// Exact keyword matches win. If none match, close misspellings of
+    /// keywords ("remidners", "breifing") are corrected and the patterns are
+    /// tried once more.
     pub fn parse_quick(&self, input: &str) -> Option<AgentCommand> {
+        if let Some(cmd) = self.match_quick_patterns(input) {
+            return Some(cmd);
+        }
+
+        let corrected = self.correct_typos(input)?;
+        let cmd = self.match_quick_patterns(&corrected)?;
+        debug!(command = ?cmd, "Quick-parsed command after typo correction");
+        Some(cmd)
+    }
+
+    fn match_quick_patterns(&self, input: &str) -> Option<AgentCommand> {
         let lower = input.to_lowercase();
 
         for pattern in &self.quick_patterns {
@@ -278,6 +382,7 @@ mod tests {
             name: None,
             reminder_id: None,
             remind_at: None,
+            recurrence: None,
             include_done: None,
             from: None,
             to_address: None,
@@ -408,6 +513,106 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_quick_matches_common_misspellings() {
+        let parser = CommandParser::new();
+
+        assert!(matches!(
+            parser.parse_quick("show my remidners"),
+            Some(AgentCommand::ListReminders { .. })
+        ));
+        assert!(matches!(
+            parser.parse_quick("zeig meine erinerungen"),
+            Some(AgentCommand::ListReminders { .. })
+        ));
+        assert!(matches!(
+            parser.parse_quick("breifing"),
+            Some(AgentCommand::MorningBriefing { .. })
+        ));
+        assert!(matches!(
+            parser.parse_quick("verison"),
+            Some(AgentCommand::System(domain::SystemCommand::Version))
+        ));
+        assert!(matches!(
+            parser.parse_quick("contcats"),
+            Some(AgentCommand::ListContacts { .. })
+        ));
+        assert!(matches!(
+            parser.parse_quick("statsu"),
+            Some(AgentCommand::System(domain::SystemCommand::Status))
+        ));
+    }
+
+    #[test]
+    fn parse_quick_fuzzy_keeps_rest_of_input() {
+        let parser = CommandParser::new();
+        let cmd = parser
+            .parse_quick("recherchiere Rust async traits")
+            .unwrap();
+        let AgentCommand::WebSearch { query, .. } = cmd else {
+            unreachable!("Expected WebSearch command")
+        };
+        assert_eq!(query, "Rust async traits");
+
+        let cmd = parser.parse_quick("reherchiere Rust async traits").unwrap();
+        let AgentCommand::WebSearch { query, .. } = cmd else {
+            unreachable!("Expected WebSearch command")
+        };
+        assert_eq!(query, "Rust async traits");
+    }
+
+    #[test]
+    fn parse_quick_fuzzy_ignores_unrelated_words() {
+        let parser = CommandParser::new();
+
+        for input in [
+            "hello there",
+            "what is the capital of France",
+            "sing",
+            "helo",
+            "modem",
+            "storage",
+            "tell me a joke about cats",
+        ] {
+            assert!(
+                parser.parse_quick(input).is_none(),
+                "parse_quick({input}) should not match"
+            );
+        }
+    }
+
+    #[test]
+    fn exact_keywords_are_not_corrected() {
+        let parser = CommandParser::new();
+        assert_eq!(parser.correct_typos("show my reminders"), None);
+        assert_eq!(
+            parser.correct_typos("show my remidners?").as_deref(),
+            Some("show my reminders?")
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_transpositions_once() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(
+            quick_patterns::edit_distance(&chars("reminders"), &chars("reminders")),
+            0
+        );
+        assert_eq!(
+            quick_patterns::edit_distance(&chars("remidners"), &chars("reminders")),
+            1
+        );
+        assert_eq!(
+            quick_patterns::edit_distance(&chars("remindrs"), &chars("reminders")),
+            1
+        );
+        assert_eq!(
+            quick_patterns::edit_distance(&chars("kitten"), &chars("sitting")),
+            3
+        );
+        assert_eq!(quick_patterns::edit_distance(&chars(""), &chars("abc")), 3);
+    }
+
     #[test]
     fn parse_quick_preserves_original_case_in_message() {
         let parser = CommandParser::new();
@@ -479,6 +684,49 @@ mod tests {
         assert!(matches!(cmd, AgentCommand::SummarizeInbox { .. }));
     }
 
+    #[test]
+    fn parses_conversation_summary() {
+        let parser = CommandParser::new();
+        for input in [
+            "zusammenfassen",
+            "summarize our chat",
+            "Fasse unsere Unterhaltung zusammen",
+            "tl;dr",
+        ] {
+            let cmd = parser.parse_quick(input).unwrap();
+            assert_eq!(
+                cmd,
+                AgentCommand::SummarizeConversation {
+                    conversation_id: None,
+                    max_sentences: None,
+                },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_conversation_summary_length() {
+        let parser = CommandParser::new();
+        let cmd = parser
+            .parse_quick("Zusammenfassung des Chats in 3 Sätzen")
+            .unwrap();
+        let AgentCommand::SummarizeConversation { max_sentences, .. } = cmd else {
+            unreachable!("Expected SummarizeConversation")
+        };
+        assert_eq!(max_sentences, Some(3));
+    }
+
+    #[test]
+    fn conversation_summary_leaves_other_summaries_alone() {
+        let parser = CommandParser::new();
+        assert!(matches!(
+            parser.parse_quick("Zusammenfassung meiner E-Mails"),
+            None | Some(AgentCommand::SummarizeInbox { .. })
+        ));
+        assert_eq!(parser.parse_quick("summarize this article on rust"), None);
+    }
+
     #[test]
     fn parses_important_mails() {
         let parser = CommandParser::new();
@@ -648,6 +896,190 @@ mod tests {
         assert_eq!(to, "Alexanderplatz");
     }
 
+    #[test]
+    fn parses_timer_for_minutes() {
+        let parser = CommandParser::new();
+        let cmd = parser.parse_quick("set a timer for 5 minutes").unwrap();
+        assert_eq!(
+            cmd,
+            AgentCommand::SetTimer {
+                label: None,
+                duration: std::time::Duration::from_secs(300),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_minute_timer() {
+        let parser = CommandParser::new();
+        let cmd = parser.parse_quick("10 minute timer").unwrap();
+        assert_eq!(
+            cmd,
+            AgentCommand::SetTimer {
+                label: None,
+                duration: std::time::Duration::from_secs(600),
+            }
+        );
+
+        let cmd = parser
+            .parse_quick("start a 10-minute timer for the Pasta")
+            .unwrap();
+        let AgentCommand::SetTimer { label, duration } = cmd else {
+            unreachable!("Expected SetTimer")
+        };
+        assert_eq!(label.as_deref(), Some("the Pasta"));
+        assert_eq!(duration.as_secs(), 600);
+    }
+
+    #[test]
+    fn parses_timer_german() {
+        let parser = CommandParser::new();
+        let cmd = parser
+            .parse_quick("Stell einen Timer auf 10 Minuten")
+            .unwrap();
+        assert_eq!(
+            cmd,
+            AgentCommand::SetTimer {
+                label: None,
+                duration: std::time::Duration::from_secs(600),
+            }
+        );
+
+        let cmd = parser
+            .parse_quick("stelle einen Timer für 1 Stunde 30 Minuten für den Kuchen")
+            .unwrap();
+        let AgentCommand::SetTimer { label, duration } = cmd else {
+            unreachable!("Expected SetTimer")
+        };
+        assert_eq!(label.as_deref(), Some("den Kuchen"));
+        assert_eq!(duration.as_secs(), 5400);
+    }
+
+    #[test]
+    fn timer_without_duration_is_not_quick_matched() {
+        let parser = CommandParser::new();
+        assert!(parser.parse_quick("set a timer").is_none());
+        assert!(parser.parse_quick("how does a timer work").is_none());
+    }
+
+    // --- French and Spanish quick pattern tests ---
+
+    #[test]
+    fn parses_list_reminders_french() {
+        let parser = CommandParser::new();
+        let cmd = parser.parse_quick("montre mes rappels").unwrap();
+        let AgentCommand::ListReminders { include_done } = cmd else {
+            unreachable!("Expected ListReminders")
+        };
+        assert_eq!(include_done, Some(false));
+
+        let cmd = parser.parse_quick("tous mes rappels").unwrap();
+        assert!(matches!(
+            cmd,
+            AgentCommand::ListReminders {
+                include_done: Some(true)
+            }
+        ));
+    }
+
+    #[test]
+    fn parses_list_reminders_spanish() {
+        let parser = CommandParser::new();
+        let cmd = parser.parse_quick("muestra mis recordatorios").unwrap();
+        let AgentCommand::ListReminders { include_done } = cmd else {
+            unreachable!("Expected ListReminders")
+        };
+        assert_eq!(include_done, Some(false));
+
+        let cmd = parser.parse_quick("todos mis recordatorios").unwrap();
+        assert!(matches!(
+            cmd,
+            AgentCommand::ListReminders {
+                include_done: Some(true)
+            }
+        ));
+    }
+
+    #[test]
+    fn parses_briefing_french() {
+        let parser = CommandParser::new();
+        let cmd = parser.parse_quick("Bonjour").unwrap();
+        assert!(matches!(cmd, AgentCommand::MorningBriefing { date: None }));
+
+        let cmd = parser.parse_quick("résumé du jour pour demain").unwrap();
+        let AgentCommand::MorningBriefing { date } = cmd else {
+            unreachable!("Expected MorningBriefing")
+        };
+        assert_eq!(
+            date,
+            Some(chrono::Local::now().date_naive() + chrono::Duration::days(1))
+        );
+    }
+
+    #[test]
+    fn parses_briefing_spanish() {
+        let parser = CommandParser::new();
+        let cmd = parser.parse_quick("Buenos días").unwrap();
+        assert!(matches!(cmd, AgentCommand::MorningBriefing { date: None }));
+
+        let cmd = parser.parse_quick("resumen del día para mañana").unwrap();
+        let AgentCommand::MorningBriefing { date } = cmd else {
+            unreachable!("Expected MorningBriefing")
+        };
+        assert_eq!(
+            date,
+            Some(chrono::Local::now().date_naive() + chrono::Duration::days(1))
+        );
+    }
+
+    #[test]
+    fn parses_transit_french() {
+        let parser = CommandParser::new();
+        let cmd = parser
+            .parse_quick("Comment aller à la Gare de Lyon ?")
+            .unwrap();
+        let AgentCommand::SearchTransit { from, to, .. } = cmd else {
+            unreachable!("Expected SearchTransit")
+        };
+        assert_eq!(to, "Gare de Lyon");
+        assert!(from.is_empty());
+
+        let cmd = parser.parse_quick("itinéraire vers Montmartre").unwrap();
+        let AgentCommand::SearchTransit { to, .. } = cmd else {
+            unreachable!("Expected SearchTransit")
+        };
+        assert_eq!(to, "Montmartre");
+    }
+
+    #[test]
+    fn parses_transit_spanish() {
+        let parser = CommandParser::new();
+        let cmd = parser
+            .parse_quick("¿Cómo llego a la Puerta del Sol?")
+            .unwrap();
+        let AgentCommand::SearchTransit { from, to, .. } = cmd else {
+            unreachable!("Expected SearchTransit")
+        };
+        assert_eq!(to, "Puerta del Sol");
+        assert!(from.is_empty());
+
+        let cmd = parser.parse_quick("como ir al Museo del Prado").unwrap();
+        let AgentCommand::SearchTransit { to, .. } = cmd else {
+            unreachable!("Expected SearchTransit")
+        };
+        assert_eq!(to, "Museo del Prado");
+    }
+
+    #[test]
+    fn default_language_is_configurable() {
+        let parser = CommandParser::new();
+        assert_eq!(parser.default_language(), ParserLanguage::German);
+
+        let parser = CommandParser::new().with_default_language(ParserLanguage::Spanish);
+        assert_eq!(parser.default_language(), ParserLanguage::Spanish);
+        assert!(format!("{parser:?}").contains("Spanish"));
+    }
+
     // --- Contact quick pattern tests ---
 
     #[test]
@@ -717,6 +1149,65 @@ mod tests {
 
     // --- Contact intent mapping tests ---
 
+    fn reminder_intent() -> ParsedIntent {
+        ParsedIntent {
+            intent: "create_reminder".to_string(),
+            title: Some("Pills".to_string()),
+            remind_at: Some("2025-01-20 08:00".to_string()),
+            ..default_parsed_intent()
+        }
+    }
+
+    fn mapped_recurrence(parsed: ParsedIntent, input: &str) -> Option<String> {
+        let cmd = CommandParser::new()
+            .intent_to_command(parsed, input)
+            .unwrap();
+        let AgentCommand::CreateReminder { recurrence, .. } = cmd else {
+            unreachable!("Expected CreateReminder")
+        };
+        recurrence
+    }
+
+    #[test]
+    fn maps_reminder_every_day_to_daily() {
+        let recurrence = mapped_recurrence(
+            reminder_intent(),
+            "Remind me every day at 8 to take my pills",
+        );
+        assert_eq!(recurrence.as_deref(), Some("daily"));
+    }
+
+    #[test]
+    fn maps_reminder_jeden_montag_to_weekly_monday() {
+        let recurrence = mapped_recurrence(
+            reminder_intent(),
+            "Erinnere mich jeden Montag um 8 an die Tabletten",
+        );
+        assert_eq!(recurrence.as_deref(), Some("weekly:Mon"));
+    }
+
+    #[test]
+    fn maps_reminder_weekly_to_weekly() {
+        let recurrence = mapped_recurrence(reminder_intent(), "weekly reminder: pills at 8");
+        assert_eq!(recurrence.as_deref(), Some("weekly"));
+    }
+
+    #[test]
+    fn maps_reminder_prefers_valid_llm_recurrence() {
+        let parsed = ParsedIntent {
+            recurrence: Some("FREQ=WEEKLY;BYDAY=TU".to_string()),
+            ..reminder_intent()
+        };
+        let recurrence = mapped_recurrence(parsed, "every day");
+        assert_eq!(recurrence.as_deref(), Some("FREQ=WEEKLY;BYDAY=TU"));
+
+        let parsed = ParsedIntent {
+            recurrence: Some("fortnightly".to_string()),
+            ..reminder_intent()
+        };
+        assert_eq!(mapped_recurrence(parsed, "pills at 8"), None);
+    }
+
     #[test]
     fn maps_list_contacts_intent() {
         let parser = CommandParser::new();
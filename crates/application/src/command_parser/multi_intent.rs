@@ -0,0 +1,490 @@
+//! Splitting compound input into clauses with one intent each.
+
+use std::sync::Arc;
+
+use domain::AgentCommand;
+use tracing::{debug, instrument};
+
+use super::CommandParser;
+use crate::{error::ApplicationError, ports::InferencePort};
+
+/// Words that join two requests ("remind me ... and show my tasks")
+const CONJUNCTIONS: &[&str] = &["and", "und"];
+
+/// Clauses shorter than this are not split off ("salt and pepper")
+const MIN_CLAUSE_WORDS: usize = 2;
+
+/// Pronouns that make a clause depend on the one before ("... and send it")
+///
+/// Such clauses stay together so the LLM can return them as a chain.
+const BACK_REFERENCES: &[&str] = &["it", "them", "ihn", "sie"];
+
+/// Prefixes of commands whose argument is free text and must not be split
+const VERBATIM_PREFIXES: &[&str] = &["echo ", "sag ", "sage "];
+
+impl CommandParser {
+    /// Parse input that may contain several requests
+    ///
+    /// The input is split on `;` and on the conjunctions "and"/"und", then
+    /// each clause goes through [`parse_with_llm`](Self::parse_with_llm).
+    /// A conjunction split is only kept when every clause maps to a
+    /// recognised intent; otherwise the text around the conjunction is
+    /// parsed as one command, so "remind me to buy bread and milk" stays a
+    /// single reminder. Commands are returned in input order; input without
+    /// a compound structure yields exactly one command.
+    ///
+    /// # Errors
+    /// Returns an error if intent detection fails for any clause
+    #[instrument(skip(self, inference, input), fields(input_len = input.len()))]
+    pub async fn parse_multi(
+        &self,
+        inference: &Arc<dyn InferencePort>,
+        input: &str,
+    ) -> Result<Vec<AgentCommand>, ApplicationError> {
+        if Self::split_clauses(input).len() <= 1 {
+            return Ok(vec![self.parse_with_llm(inference, input).await?]);
+        }
+
+        let parts = Self::split_parts(input);
+        debug!(parts = parts.len(), "Parsing compound input");
+        let mut commands = Vec::with_capacity(parts.len());
+        for part in &parts {
+            commands.extend(self.parse_part(inference, part).await?);
+        }
+        Ok(commands)
+    }
+
+    /// Parse one `;`-separated part, splitting it at conjunctions only if
+    /// every clause has an intent of its own
+    async fn parse_part(
+        &self,
+        inference: &Arc<dyn InferencePort>,
+        part: &str,
+    ) -> Result<Vec<AgentCommand>, ApplicationError> {
+        let clauses = Self::split_on_conjunctions(part);
+        if clauses.len() > 1 {
+            let mut commands = Vec::with_capacity(clauses.len());
+            for clause in &clauses {
+                let command = self.parse_with_llm(inference, clause).await?;
+                if !Self::is_recognised(&command) {
+                    debug!(clause = %clause, "Clause has no intent of its own, not splitting");
+                    break;
+                }
+                commands.push(command);
+            }
+            if commands.len() == clauses.len() {
+                return Ok(commands);
+            }
+        }
+
+        Ok(vec![self.parse_with_llm(inference, part).await?])
+    }
+
+    /// Whether a clause was mapped to an intent rather than falling back to
+    /// a free-form question
+    const fn is_recognised(command: &AgentCommand) -> bool {
+        !matches!(
+            command,
+            AgentCommand::Ask { .. } | AgentCommand::Unknown { .. }
+        )
+    }
+
+    /// Split input into clauses at `;` and at conjunctions
+    ///
+    /// A conjunction only splits when both sides have at least two words, so
+    /// "salt and pepper" stays intact, and not when the second side refers
+    /// back to the first ("draft an email to Bob and send it"). Free-text
+    /// commands such as `echo` are never split.
+    pub fn split_clauses(input: &str) -> Vec<String> {
+        if Self::is_verbatim(input) {
+            return vec![input.trim().to_string()];
+        }
+
+        Self::split_parts(input)
+            .iter()
+            .flat_map(|part| Self::split_on_conjunctions(part))
+            .collect()
+    }
+
+    /// Split input at `;`, leaving free-text commands intact
+    fn split_parts(input: &str) -> Vec<String> {
+        let trimmed = input.trim();
+        if Self::is_verbatim(trimmed) {
+            return vec![trimmed.to_string()];
+        }
+
+        trimmed
+            .split(';')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn is_verbatim(input: &str) -> bool {
+        let lower = input.trim_start().to_lowercase();
+        VERBATIM_PREFIXES.iter().any(|p| lower.starts_with(p))
+    }
+
+    fn split_on_conjunctions(part: &str) -> Vec<String> {
+        let mut clauses: Vec<Vec<&str>> = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        let mut conjunction: Option<&str> = None;
+
+        for word in part.split_whitespace() {
+            if !CONJUNCTIONS.contains(&word.to_lowercase().as_str()) {
+                current.push(word);
+                continue;
+            }
+            Self::push_clause(&mut clauses, std::mem::take(&mut current), conjunction);
+            conjunction = Some(word);
+        }
+        Self::push_clause(&mut clauses, current, conjunction);
+
+        clauses
+            .into_iter()
+            .map(|words| words.join(" ").trim_end_matches(',').to_string())
+            .filter(|clause| !clause.is_empty())
+            .collect()
+    }
+
+    /// Start a new clause, or merge into the previous one if either is too
+    /// short or the new one depends on it
+    fn push_clause<'a>(
+        clauses: &mut Vec<Vec<&'a str>>,
+        words: Vec<&'a str>,
+        conjunction: Option<&'a str>,
+    ) {
+        match (clauses.last_mut(), conjunction) {
+            (Some(previous), Some(conjunction))
+                if words.len() < MIN_CLAUSE_WORDS
+                    || previous.len() < MIN_CLAUSE_WORDS
+                    || Self::refers_back(&words) =>
+            {
+                previous.push(conjunction);
+                previous.extend(words);
+            },
+            _ => clauses.push(words),
+        }
+    }
+
+    fn refers_back(words: &[&str]) -> bool {
+        words.iter().any(|word| {
+            let word = word
+                .trim_matches(|c: char| !c.is_alphabetic())
+                .to_lowercase();
+            BACK_REFERENCES.contains(&word.as_str())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_two_english_clauses() {
+        let clauses =
+            CommandParser::split_clauses("remind me to call mom at 5 and what's the weather");
+        assert_eq!(
+            clauses,
+            vec!["remind me to call mom at 5", "what's the weather"]
+        );
+    }
+
+    #[test]
+    fn splits_three_english_clauses() {
+        let clauses = CommandParser::split_clauses(
+            "show my tasks and summarize my inbox and what's the weather tomorrow",
+        );
+        assert_eq!(
+            clauses,
+            vec![
+                "show my tasks",
+                "summarize my inbox",
+                "what's the weather tomorrow"
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_two_german_clauses() {
+        let clauses =
+            CommandParser::split_clauses("erinnere mich um 17 Uhr an Mama und wie ist das Wetter");
+        assert_eq!(
+            clauses,
+            vec!["erinnere mich um 17 Uhr an Mama", "wie ist das Wetter"]
+        );
+    }
+
+    #[test]
+    fn splits_three_german_clauses() {
+        let clauses = CommandParser::split_clauses(
+            "zeig meine Aufgaben und fasse meine Mails zusammen und wie wird das Wetter",
+        );
+        assert_eq!(
+            clauses,
+            vec![
+                "zeig meine Aufgaben",
+                "fasse meine Mails zusammen",
+                "wie wird das Wetter"
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_on_semicolon() {
+        let clauses = CommandParser::split_clauses("briefing; show my reminders");
+        assert_eq!(clauses, vec!["briefing", "show my reminders"]);
+    }
+
+    #[test]
+    fn short_conjunction_operands_are_kept_together() {
+        let clauses = CommandParser::split_clauses("search the web for salt and pepper");
+        assert_eq!(clauses, vec!["search the web for salt and pepper"]);
+    }
+
+    #[test]
+    fn conjunction_is_case_insensitive() {
+        let clauses = CommandParser::split_clauses("show my tasks AND show my reminders");
+        assert_eq!(clauses.len(), 2);
+    }
+
+    #[test]
+    fn single_clause_is_unchanged() {
+        assert_eq!(
+            CommandParser::split_clauses("  what's on today?  "),
+            vec!["what's on today?"]
+        );
+    }
+
+    #[test]
+    fn dependent_clause_is_kept_together() {
+        let input = "draft an email to bob@example.com about lunch and send it";
+        assert_eq!(CommandParser::split_clauses(input), vec![input]);
+
+        let input = "Entwirf eine Mail an Bob wegen Mittagessen und schick sie ab";
+        assert_eq!(CommandParser::split_clauses(input), vec![input]);
+    }
+
+    #[test]
+    fn echo_is_never_split() {
+        let clauses = CommandParser::split_clauses("echo rock and roll all night long");
+        assert_eq!(clauses, vec!["echo rock and roll all night long"]);
+    }
+
+    mod parse_multi {
+        use mockall::mock;
+
+        use super::*;
+        use crate::ports::InferenceResult;
+
+        mock! {
+            pub InferenceEngine {}
+
+            #[async_trait::async_trait]
+            impl InferencePort for InferenceEngine {
+                async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError>;
+                async fn generate_with_context(&self, conversation: &domain::Conversation) -> Result<InferenceResult, ApplicationError>;
+                async fn generate_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceResult, ApplicationError>;
+                async fn generate_stream(&self, message: &str) -> Result<crate::ports::InferenceStream, ApplicationError>;
+                async fn generate_stream_with_system(&self, system_prompt: &str, message: &str) -> Result<crate::ports::InferenceStream, ApplicationError>;
+                async fn is_healthy(&self) -> bool;
+                fn current_model(&self) -> String;
+                async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError>;
+                async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError>;
+            }
+        }
+
+        /// Inference mock that classifies task clauses as `list_tasks`,
+        /// reminder requests as `create_reminder` and everything else as `ask`
+        fn classifying_inference() -> Arc<dyn InferencePort> {
+            let mut mock = MockInferenceEngine::new();
+            mock.expect_generate_with_system().returning(|_, msg| {
+                let lower = msg.to_lowercase();
+                let content = if lower.contains("tasks") || lower.contains("aufgaben") {
+                    r#"{"intent":"list_tasks"}"#.to_string()
+                } else if lower.contains("remind") || lower.contains("erinnere") {
+                    format!(
+                        r#"{{"intent":"create_reminder","title":"{msg}","remind_at":"2026-01-02T09:00:00"}}"#
+                    )
+                } else {
+                    format!(r#"{{"intent":"ask","question":"{msg}"}}"#)
+                };
+                Ok(InferenceResult {
+                    content,
+                    model: "test".to_string(),
+                    tokens_used: Some(10),
+                    latency_ms: 50,
+                })
+            });
+            Arc::new(mock)
+        }
+
+        #[tokio::test]
+        async fn two_english_clauses_in_order() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(&inference, "show my tasks and show my reminders")
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 2);
+            assert!(matches!(commands[0], AgentCommand::ListTasks { .. }));
+            assert!(matches!(commands[1], AgentCommand::ListReminders { .. }));
+        }
+
+        #[tokio::test]
+        async fn three_english_clauses_in_order() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(&inference, "status; show my tasks and show my reminders")
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 3);
+            assert!(matches!(
+                commands[0],
+                AgentCommand::System(domain::SystemCommand::Status)
+            ));
+            assert!(matches!(commands[1], AgentCommand::ListTasks { .. }));
+            assert!(matches!(commands[2], AgentCommand::ListReminders { .. }));
+        }
+
+        #[tokio::test]
+        async fn two_german_clauses_in_order() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(
+                    &inference,
+                    "zeig meine Erinnerungen und zeig meine Aufgaben",
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 2);
+            assert!(matches!(commands[0], AgentCommand::ListReminders { .. }));
+            assert!(matches!(commands[1], AgentCommand::ListTasks { .. }));
+        }
+
+        #[tokio::test]
+        async fn three_german_clauses_in_order() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(
+                    &inference,
+                    "zeig meine Aufgaben und zeig meine Erinnerungen und erinnere mich morgen an Mama",
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 3);
+            assert!(matches!(commands[0], AgentCommand::ListTasks { .. }));
+            assert!(matches!(commands[1], AgentCommand::ListReminders { .. }));
+            assert!(matches!(commands[2], AgentCommand::CreateReminder { .. }));
+        }
+
+        #[tokio::test]
+        async fn conjunction_inside_an_argument_is_not_split() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(&inference, "remind me to buy bread and milk tomorrow")
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 1);
+            let AgentCommand::CreateReminder { title, .. } = &commands[0] else {
+                unreachable!("Expected CreateReminder command");
+            };
+            assert!(title.contains("bread and milk"));
+        }
+
+        #[tokio::test]
+        async fn german_conjunction_inside_an_argument_is_not_split() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(&inference, "erinnere mich morgen an Brot und Milch holen")
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 1);
+            let AgentCommand::CreateReminder { title, .. } = &commands[0] else {
+                unreachable!("Expected CreateReminder command");
+            };
+            assert!(title.contains("Brot und Milch"));
+        }
+
+        #[tokio::test]
+        async fn clause_without_intent_keeps_the_part_together() {
+            let parser = CommandParser::new();
+            let inference = classifying_inference();
+
+            let commands = parser
+                .parse_multi(
+                    &inference,
+                    "status; remind me to call the plumber and the landlord",
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 2);
+            assert!(matches!(
+                commands[0],
+                AgentCommand::System(domain::SystemCommand::Status)
+            ));
+            let AgentCommand::CreateReminder { title, .. } = &commands[1] else {
+                unreachable!("Expected CreateReminder command");
+            };
+            assert!(title.contains("plumber and the landlord"));
+        }
+
+        #[tokio::test]
+        async fn single_clause_yields_one_command() {
+            let parser = CommandParser::new();
+            let inference: Arc<dyn InferencePort> = Arc::new(MockInferenceEngine::new());
+
+            let commands = parser.parse_multi(&inference, "help").await.unwrap();
+
+            assert_eq!(commands.len(), 1);
+            assert!(matches!(commands[0], AgentCommand::Help { command: None }));
+        }
+
+        #[tokio::test]
+        async fn dependent_steps_become_one_chain() {
+            let parser = CommandParser::new();
+            let mut mock = MockInferenceEngine::new();
+            mock.expect_generate_with_system().times(1).returning(|_, _| {
+                Ok(InferenceResult {
+                    content: r#"[{"intent":"draft_email","to":"bob@example.com","body":"Lunch?"},{"intent":"send_email","draft_id":"latest"}]"#.to_string(),
+                    model: "test".to_string(),
+                    tokens_used: Some(10),
+                    latency_ms: 50,
+                })
+            });
+            let inference: Arc<dyn InferencePort> = Arc::new(mock);
+
+            let commands = parser
+                .parse_multi(&inference, "draft an email to bob@example.com and send it")
+                .await
+                .unwrap();
+
+            assert_eq!(commands.len(), 1);
+            let AgentCommand::Chain { steps } = &commands[0] else {
+                unreachable!("Expected Chain command");
+            };
+            assert_eq!(steps.len(), 2);
+        }
+    }
+}
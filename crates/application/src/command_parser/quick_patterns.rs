@@ -1,9 +1,17 @@
 //! Quick pattern matching for commands that don't need LLM parsing.
 
+use std::time::Duration;
+
 use domain::AgentCommand;
 
 use super::{CommandParser, QuickPattern};
 
+/// Words shorter than this are never fuzzy-matched ("mail" vs "main")
+const MIN_FUZZY_WORD_LEN: usize = 5;
+
+/// Keywords at least this long tolerate two edits, shorter ones only one
+const TWO_EDIT_WORD_LEN: usize = 8;
+
 impl CommandParser {
     /// Build the list of quick-match patterns
     #[allow(clippy::too_many_lines)]
@@ -82,13 +90,30 @@ impl CommandParser {
                     "good morning",
                     "what's on",
                     "what is on",
+                    "bonjour",
+                    "résumé du jour",
+                    "programme du jour",
+                    "buenos días",
+                    "buenos dias",
+                    "resumen del día",
+                    "resumen del dia",
+                    "qué hay hoy",
                 ],
                 builder: |input| {
                     let lower = input.to_lowercase();
+                    let trimmed = lower.trim();
                     if lower.contains("briefing")
-                        || lower == "good morning"
+                        || trimmed == "good morning"
                         || lower.contains("what's on")
                         || lower.contains("what is on today")
+                        || trimmed == "bonjour"
+                        || lower.contains("résumé du jour")
+                        || lower.contains("programme du jour")
+                        || trimmed == "buenos días"
+                        || trimmed == "buenos dias"
+                        || lower.contains("resumen del día")
+                        || lower.contains("resumen del dia")
+                        || lower.contains("qué hay hoy")
                     {
                         // Parse date from input using date_parser
                         let date = crate::date_parser::extract_date_from_text(input);
@@ -115,6 +140,22 @@ impl CommandParser {
                     None
                 },
             },
+            // Conversation summary
+            QuickPattern {
+                keywords: vec![
+                    "zusammenfassen",
+                    "zusammenfassung",
+                    "fass",
+                    "summarize",
+                    "summarise",
+                    "tl;dr",
+                    "tldr",
+                ],
+                builder: |input| {
+                    let lower = input.to_lowercase();
+                    Self::extract_conversation_summary(&lower)
+                },
+            },
             // Web search
             QuickPattern {
                 keywords: vec![
@@ -144,17 +185,29 @@ impl CommandParser {
             },
             // List reminders
             QuickPattern {
-                keywords: vec!["erinnerungen", "reminders", "was steht an"],
+                keywords: vec![
+                    "erinnerungen",
+                    "reminders",
+                    "was steht an",
+                    "rappels",
+                    "recordatorios",
+                ],
                 builder: |input| {
                     let lower = input.to_lowercase();
                     if lower.contains("erinnerungen")
                         || lower.contains("reminders")
                         || lower.contains("was steht an")
+                        || lower.contains("rappels")
+                        || lower.contains("recordatorios")
                     {
                         let include_done = lower.contains("alle")
                             || lower.contains("all")
                             || lower.contains("erledigte")
-                            || lower.contains("completed");
+                            || lower.contains("completed")
+                            || lower.contains("tous")
+                            || lower.contains("terminés")
+                            || lower.contains("todos")
+                            || lower.contains("completados");
                         return Some(AgentCommand::ListReminders {
                             include_done: Some(include_done),
                         });
@@ -162,6 +215,15 @@ impl CommandParser {
                     None
                 },
             },
+            // Countdown timer
+            QuickPattern {
+                keywords: vec!["timer"],
+                builder: |input| {
+                    let lower = input.to_lowercase();
+                    let (duration, label) = Self::extract_timer(&lower, input)?;
+                    Some(AgentCommand::SetTimer { label, duration })
+                },
+            },
             // Transit search
             QuickPattern {
                 keywords: vec![
@@ -175,6 +237,16 @@ impl CommandParser {
                     "fahrt nach",
                     "bahn nach",
                     "bus nach",
+                    "comment aller",
+                    "itinéraire vers",
+                    "itinéraire pour",
+                    "trajet vers",
+                    "cómo llego",
+                    "como llego",
+                    "cómo llegar",
+                    "como llegar",
+                    "cómo ir a",
+                    "como ir a",
                 ],
                 builder: |input| {
                     let lower = input.to_lowercase();
@@ -283,8 +355,33 @@ impl CommandParser {
             "transit to ",
             "directions to ",
             "route to ",
+            "comment aller à la ",
+            "comment aller à ",
+            "comment aller au ",
+            "comment aller en ",
+            "itinéraire vers ",
+            "itinéraire pour ",
+            "trajet vers ",
+            "cómo llego a la ",
+            "como llego a la ",
+            "cómo llego a ",
+            "como llego a ",
+            "cómo llego al ",
+            "como llego al ",
+            "cómo llegar a ",
+            "como llegar a ",
+            "cómo llegar al ",
+            "como llegar al ",
+            "cómo ir a ",
+            "como ir a ",
+            "cómo ir al ",
+            "como ir al ",
         ];
 
+        // Spanish questions open with an inverted question mark
+        let lower = lower.trim_start_matches('¿');
+        let original = original.trim_start_matches('¿');
+
         for prefix in prefixes {
             if lower.starts_with(prefix) {
                 let dest = original[prefix.len()..].trim();
@@ -310,6 +407,124 @@ impl CommandParser {
         None
     }
 
+    /// Extract duration and optional label from a timer request
+    ///
+    /// Handles "set a timer for 10 minutes", "10 minute timer for the pasta",
+    /// "timer 1 hour 30 min" and "stell einen Timer auf 10 Minuten".
+    /// Match "summarize our chat" / "fasse die Unterhaltung zusammen"
+    ///
+    /// Requests about mail are left to the inbox pattern, and anything else
+    /// must name the conversation or be the bare keyword.
+    fn extract_conversation_summary(lower: &str) -> Option<AgentCommand> {
+        let asks_summary = ["zusammenfass", "summarize", "summarise", "tl;dr", "tldr"]
+            .iter()
+            .any(|kw| lower.contains(kw))
+            || (lower.contains("fass") && lower.contains("zusammen"));
+        if !asks_summary
+            || ["mail", "inbox", "posteingang"]
+                .iter()
+                .any(|w| lower.contains(w))
+        {
+            return None;
+        }
+
+        let bare = lower.trim_matches(|c: char| !c.is_alphanumeric() && c != ';');
+        let names_conversation = [
+            "chat",
+            "unterhaltung",
+            "gespräch",
+            "konversation",
+            "conversation",
+            "thread",
+            "verlauf",
+        ]
+        .iter()
+        .any(|w| lower.contains(w));
+        if !names_conversation
+            && !matches!(
+                bare,
+                "zusammenfassen" | "zusammenfassung" | "summarize" | "summarise" | "tl;dr" | "tldr"
+            )
+        {
+            return None;
+        }
+
+        // "in 3 Sätzen" / "in 3 sentences"
+        let words: Vec<&str> = lower.split_whitespace().collect();
+        let max_sentences = words.windows(2).find_map(|pair| {
+            let unit = pair[1].trim_end_matches(|c: char| !c.is_alphabetic());
+            if ["satz", "sätze", "sätzen", "sentence", "sentences"].contains(&unit) {
+                pair[0].parse::<u32>().ok()
+            } else {
+                None
+            }
+        });
+
+        Some(AgentCommand::SummarizeConversation {
+            conversation_id: None,
+            max_sentences,
+        })
+    }
+
+    fn extract_timer(lower: &str, original: &str) -> Option<(Duration, Option<String>)> {
+        let words: Vec<&str> = lower
+            .split_whitespace()
+            .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+            .collect();
+        if !words.contains(&"timer") {
+            return None;
+        }
+
+        let mut secs = 0;
+        let mut end = None;
+        let mut i = 0;
+        while i < words.len() {
+            let Some((amount, unit)) = split_amount(words[i]) else {
+                i += 1;
+                continue;
+            };
+            let (unit, consumed) = if unit.is_empty() {
+                (words.get(i + 1).copied().unwrap_or_default(), 2)
+            } else {
+                (unit, 1)
+            };
+            if let Some(factor) = timer_unit_secs(unit) {
+                secs = amount.saturating_mul(factor).saturating_add(secs);
+                i += consumed;
+                end = Some(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        let end = end?;
+        if secs == 0 {
+            return None;
+        }
+
+        // "... for the pasta" / "... für die Nudeln" after the duration
+        let start = if words.get(end) == Some(&"timer") {
+            end + 1
+        } else {
+            end
+        };
+        let original_words: Vec<&str> = original.split_whitespace().collect();
+        let label = match words.get(start) {
+            Some(&("for" | "für" | "called" | "named")) => {
+                let label = original_words
+                    .get(start + 1..)
+                    .unwrap_or_default()
+                    .join(" ")
+                    .trim_end_matches(|c: char| !c.is_alphanumeric())
+                    .to_string();
+                (!label.is_empty()).then_some(label)
+            },
+            _ => None,
+        };
+
+        Some((Duration::from_secs(secs), label))
+    }
+
     /// Extract search query from input based on matched pattern
     fn extract_search_query(lower: &str, original: &str) -> Option<String> {
         // Patterns with their prefixes to strip
@@ -379,4 +594,124 @@ impl CommandParser {
 
         None
     }
+
+    /// Replace misspelled words with the quick-pattern keyword they resemble
+    ///
+    /// Only single-word keywords take part. Returns `None` when no word was
+    /// corrected, so callers can skip a second matching pass.
+    pub(super) fn correct_typos(&self, input: &str) -> Option<String> {
+        let keywords: Vec<&str> = self
+            .quick_patterns
+            .iter()
+            .flat_map(|pattern| pattern.keywords.iter().copied())
+            .filter(|kw| {
+                kw.chars().count() >= MIN_FUZZY_WORD_LEN && kw.chars().all(char::is_alphabetic)
+            })
+            .collect();
+
+        let mut corrected = false;
+        let words: Vec<String> = input
+            .split_whitespace()
+            .map(|word| {
+                // Keep trailing punctuation ("remidners?") out of the comparison
+                let core = word.trim_end_matches(|c: char| !c.is_alphanumeric());
+                match Self::closest_keyword(core, &keywords) {
+                    Some(keyword) => {
+                        corrected = true;
+                        format!("{keyword}{}", &word[core.len()..])
+                    },
+                    None => word.to_string(),
+                }
+            })
+            .collect();
+
+        corrected.then(|| words.join(" "))
+    }
+
+    /// Find the keyword closest to a word within the allowed edit distance
+    ///
+    /// Exact keywords, short words and words with a different first letter
+    /// are left alone to avoid over-matching.
+    fn closest_keyword<'a>(word: &str, keywords: &[&'a str]) -> Option<&'a str> {
+        let lower: Vec<char> = word.to_lowercase().chars().collect();
+        if lower.len() < MIN_FUZZY_WORD_LEN {
+            return None;
+        }
+
+        let mut best: Option<(usize, &str)> = None;
+        for &keyword in keywords {
+            let candidate: Vec<char> = keyword.chars().collect();
+            if candidate == lower {
+                return None;
+            }
+            if candidate.first() != lower.first() {
+                continue;
+            }
+
+            let max_distance = if candidate.len() >= TWO_EDIT_WORD_LEN {
+                2
+            } else {
+                1
+            };
+            if candidate.len().abs_diff(lower.len()) > max_distance {
+                continue;
+            }
+
+            let distance = edit_distance(&lower, &candidate);
+            if distance <= max_distance && best.is_none_or(|(d, _)| distance < d) {
+                best = Some((distance, keyword));
+            }
+        }
+
+        best.map(|(_, keyword)| keyword)
+    }
+}
+
+/// Split "10", "10min" or "10-minute" into amount and unit suffix
+fn split_amount(word: &str) -> Option<(u64, &str)> {
+    let digits = word
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(word.len());
+    let amount = word[..digits].parse().ok()?;
+    Some((amount, word[digits..].trim_start_matches('-')))
+}
+
+/// Seconds per timer unit (English and German)
+fn timer_unit_secs(unit: &str) -> Option<u64> {
+    match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" | "sek" | "sekunde" | "sekunden" => Some(1),
+        "m" | "min" | "mins" | "minute" | "minutes" | "minuten" => Some(60),
+        "h" | "hr" | "hrs" | "hour" | "hours" | "std" | "stunde" | "stunden" => Some(3600),
+        _ => None,
+    }
+}
+
+/// Levenshtein distance that counts swapping two adjacent characters as one edit
+///
+/// This is the optimal string alignment variant: transposed letters
+/// ("remidners") are the most common typo and would otherwise cost two edits.
+pub(super) fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let width = b.len() + 1;
+    let mut dist = vec![0usize; (a.len() + 1) * width];
+    for i in 0..=a.len() {
+        dist[i * width] = i;
+    }
+    for (j, cell) in dist.iter_mut().enumerate().take(width) {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (dist[(i - 1) * width + j] + 1)
+                .min(dist[i * width + j - 1] + 1)
+                .min(dist[(i - 1) * width + j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(dist[(i - 2) * width + j - 2] + 1);
+            }
+            dist[i * width + j] = best;
+        }
+    }
+
+    dist[a.len() * width + b.len()]
 }
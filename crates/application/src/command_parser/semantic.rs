@@ -0,0 +1,448 @@
+//! Embedding-based routing of paraphrased commands.
+//!
+//! Quick patterns only catch known keywords. The [`SemanticRouter`] embeds a
+//! set of canonical phrases per intent once at startup and routes input whose
+//! embedding is close enough to one of them straight to that intent, without
+//! an LLM call. Only intents that need no extracted arguments are routed this
+//! way; everything else still goes through the LLM.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    sync::Arc,
+};
+
+use domain::AgentCommand;
+use parking_lot::Mutex;
+use tracing::{debug, info, warn};
+
+use super::{CommandParser, ParsedIntent};
+use crate::{error::ApplicationError, ports::EmbeddingPort};
+
+/// Canonical phrases per argument-free intent, in German, English, French and Spanish
+const CANONICAL_PHRASES: &[(&str, &[&str])] = &[
+    (
+        "morning_briefing",
+        &[
+            "Was steht heute an?",
+            "Gib mir mein Morgenbriefing",
+            "What's on for today?",
+            "Give me my morning briefing",
+            "Qu'est-ce qui est prévu aujourd'hui ?",
+            "¿Qué tengo hoy?",
+        ],
+    ),
+    (
+        "summarize_inbox",
+        &[
+            "Habe ich neue E-Mails?",
+            "Fasse meinen Posteingang zusammen",
+            "Did I get any new emails?",
+            "Summarize my inbox",
+            "Ai-je reçu de nouveaux e-mails ?",
+            "¿Tengo correos nuevos?",
+        ],
+    ),
+    (
+        "list_reminders",
+        &[
+            "Welche Erinnerungen habe ich?",
+            "Zeig mir meine Erinnerungen",
+            "What reminders do I have?",
+            "Show me my reminders",
+            "Quels sont mes rappels ?",
+            "¿Cuáles son mis recordatorios?",
+        ],
+    ),
+    (
+        "list_tasks",
+        &[
+            "Was steht auf meiner To-do-Liste?",
+            "Welche Aufgaben sind offen?",
+            "What's on my to-do list?",
+            "Which tasks are still open?",
+            "Quelles tâches me reste-t-il ?",
+            "¿Qué tareas tengo pendientes?",
+        ],
+    ),
+    (
+        "list_contacts",
+        &[
+            "Zeig mir meine Kontakte",
+            "Show me my contacts",
+            "Affiche mes contacts",
+            "Muéstrame mis contactos",
+        ],
+    ),
+    (
+        "summarize_conversation",
+        &[
+            "Fasse unser Gespräch zusammen",
+            "Summarize our conversation so far",
+            "Résume notre conversation",
+            "Resume nuestra conversación",
+        ],
+    ),
+];
+
+/// Configuration for the semantic router
+#[derive(Debug, Clone, Copy)]
+pub struct SemanticRouterConfig {
+    /// Minimum cosine similarity to route input to an intent
+    pub threshold: f32,
+    /// Number of input embeddings kept in memory
+    pub cache_size: usize,
+}
+
+impl Default for SemanticRouterConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.85,
+            cache_size: 256,
+        }
+    }
+}
+
+/// Intent an input was routed to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SemanticMatch {
+    /// Intent name as used in the intent prompt (e.g. `summarize_inbox`)
+    pub intent: &'static str,
+    /// Cosine similarity to the closest canonical phrase
+    pub similarity: f32,
+}
+
+/// Input embeddings, oldest evicted first once full
+#[derive(Default)]
+struct EmbeddingCache {
+    entries: HashMap<String, Arc<[f32]>>,
+    order: VecDeque<String>,
+}
+
+impl EmbeddingCache {
+    fn insert(&mut self, key: &str, vector: Arc<[f32]>, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        if self.entries.insert(key.to_string(), vector).is_none() {
+            self.order.push_back(key.to_string());
+        }
+        while self.order.len() > capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Routes paraphrased input to intents by embedding similarity
+pub struct SemanticRouter {
+    embedding: Arc<dyn EmbeddingPort>,
+    /// Embedded canonical phrases with their intent
+    phrases: Vec<(&'static str, Vec<f32>)>,
+    config: SemanticRouterConfig,
+    cache: Mutex<EmbeddingCache>,
+}
+
+impl fmt::Debug for SemanticRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemanticRouter")
+            .field("model", &self.embedding.model_info().model)
+            .field("phrases", &self.phrases.len())
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SemanticRouter {
+    /// Embed the canonical intent phrases
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the embedding model cannot embed the phrases.
+    pub async fn build(
+        embedding: Arc<dyn EmbeddingPort>,
+        config: SemanticRouterConfig,
+    ) -> Result<Self, ApplicationError> {
+        let (intents, texts): (Vec<&'static str>, Vec<String>) = CANONICAL_PHRASES
+            .iter()
+            .flat_map(|&(intent, phrases)| phrases.iter().map(move |p| (intent, (*p).to_string())))
+            .unzip();
+
+        let embeddings = embedding.embed_batch(&texts).await?;
+        if embeddings.len() != texts.len() {
+            return Err(ApplicationError::Internal(format!(
+                "Expected {} phrase embeddings, got {}",
+                texts.len(),
+                embeddings.len()
+            )));
+        }
+
+        info!(
+            phrases = texts.len(),
+            threshold = config.threshold,
+            "Semantic command routing ready"
+        );
+        Ok(Self {
+            embedding,
+            phrases: intents.into_iter().zip(embeddings).collect(),
+            config,
+            cache: Mutex::new(EmbeddingCache::default()),
+        })
+    }
+
+    /// Minimum cosine similarity to route input to an intent
+    pub const fn threshold(&self) -> f32 {
+        self.config.threshold
+    }
+
+    /// Find the intent closest to `input`, if it is above the threshold
+    ///
+    /// Returns `None` if no phrase is similar enough or the input cannot be
+    /// embedded; callers then fall back to the LLM.
+    pub async fn route(&self, input: &str) -> Option<SemanticMatch> {
+        let key = input.trim().to_lowercase();
+        let vector = match self.input_embedding(&key).await {
+            Ok(vector) => vector,
+            Err(e) => {
+                warn!(error = %e, "Failed to embed input for semantic routing");
+                return None;
+            },
+        };
+
+        let best = self
+            .phrases
+            .iter()
+            .map(|&(intent, ref phrase)| SemanticMatch {
+                intent,
+                similarity: self.embedding.cosine_similarity(&vector, phrase),
+            })
+            .max_by(|a, b| a.similarity.total_cmp(&b.similarity))?;
+
+        debug!(
+            intent = best.intent,
+            similarity = best.similarity,
+            "Closest intent phrase"
+        );
+        (best.similarity >= self.config.threshold).then_some(best)
+    }
+
+    async fn input_embedding(&self, key: &str) -> Result<Arc<[f32]>, ApplicationError> {
+        let cached = self.cache.lock().entries.get(key).cloned();
+        if let Some(vector) = cached {
+            return Ok(vector);
+        }
+
+        let vector: Arc<[f32]> = self.embedding.embed(key).await?.into();
+        self.cache
+            .lock()
+            .insert(key, Arc::clone(&vector), self.config.cache_size);
+        Ok(vector)
+    }
+}
+
+impl CommandParser {
+    /// Try to route the input to an argument-free intent by embedding similarity
+    pub(super) async fn parse_semantic(&self, input: &str) -> Option<AgentCommand> {
+        let router = self.semantic_router.as_ref()?;
+        let matched = router.route(input).await?;
+
+        let parsed: ParsedIntent =
+            serde_json::from_value(serde_json::json!({ "intent": matched.intent })).ok()?;
+        match self.intent_to_command(parsed, input) {
+            Ok(cmd) => {
+                debug!(command = ?cmd, similarity = matched.similarity, "Semantically routed command");
+                Some(cmd)
+            },
+            Err(e) => {
+                warn!(error = %e, intent = matched.intent, "Semantic match did not map to a command");
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use super::*;
+    use crate::ports::{EmbeddingModelInfo, InferencePort, InferenceResult};
+
+    /// Embeds text by topic keyword; unknown topics get a zero vector
+    #[derive(Default)]
+    struct TopicEmbedding {
+        calls: AtomicUsize,
+        fail: bool,
+    }
+
+    impl TopicEmbedding {
+        fn vector(text: &str) -> Vec<f32> {
+            let lower = text.to_lowercase();
+            let topics = [
+                ["mail", "posteingang", "correo"],
+                ["erinnerung", "reminder", "rappel"],
+                ["heute", "today", "aujourd"],
+            ];
+            let mut vector = vec![0.0; topics.len()];
+            for (i, keywords) in topics.iter().enumerate() {
+                if keywords.iter().any(|kw| lower.contains(kw)) {
+                    vector[i] = 1.0;
+                }
+            }
+            vector
+        }
+    }
+
+    #[async_trait]
+    impl EmbeddingPort for TopicEmbedding {
+        async fn embed(&self, text: &str) -> Result<Vec<f32>, ApplicationError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail {
+                return Err(ApplicationError::ExternalService("offline".to_string()));
+            }
+            Ok(Self::vector(text))
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, ApplicationError> {
+            Ok(texts.iter().map(|t| Self::vector(t)).collect())
+        }
+
+        fn model_info(&self) -> EmbeddingModelInfo {
+            EmbeddingModelInfo {
+                model: "topic".to_string(),
+                dimensions: 3,
+                max_tokens: None,
+            }
+        }
+    }
+
+    mock! {
+        pub InferenceEngine {}
+
+        #[async_trait::async_trait]
+        impl InferencePort for InferenceEngine {
+            async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_context(&self, conversation: &domain::Conversation) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_stream(&self, message: &str) -> Result<crate::ports::InferenceStream, ApplicationError>;
+            async fn generate_stream_with_system(&self, system_prompt: &str, message: &str) -> Result<crate::ports::InferenceStream, ApplicationError>;
+            async fn is_healthy(&self) -> bool;
+            fn current_model(&self) -> String;
+            async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError>;
+            async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError>;
+        }
+    }
+
+    async fn router(embedding: Arc<TopicEmbedding>) -> SemanticRouter {
+        SemanticRouter::build(embedding, SemanticRouterConfig::default())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn routes_paraphrase_without_llm() {
+        let parser =
+            CommandParser::new().with_semantic_router(Arc::new(router(Arc::default()).await));
+        // No expectations: any LLM call fails the test
+        let inference: Arc<dyn InferencePort> = Arc::new(MockInferenceEngine::new());
+
+        let cmd = parser
+            .parse_with_llm(&inference, "Did somebody mail me?")
+            .await
+            .unwrap();
+
+        assert!(matches!(cmd, AgentCommand::SummarizeInbox { .. }));
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_llm_below_threshold() {
+        let parser =
+            CommandParser::new().with_semantic_router(Arc::new(router(Arc::default()).await));
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system()
+            .times(1)
+            .returning(|_, _| {
+                Ok(InferenceResult {
+                    content: r#"{"intent": "ask", "question": "Why is the sky blue?"}"#.to_string(),
+                    model: "test".to_string(),
+                    tokens_used: Some(10),
+                    latency_ms: 5,
+                })
+            });
+        let inference: Arc<dyn InferencePort> = Arc::new(mock);
+
+        let cmd = parser
+            .parse_with_llm(&inference, "Why is the sky blue?")
+            .await
+            .unwrap();
+
+        assert!(matches!(cmd, AgentCommand::Ask { .. }));
+    }
+
+    #[tokio::test]
+    async fn threshold_is_configurable() {
+        let strict = SemanticRouter::build(
+            Arc::new(TopicEmbedding::default()),
+            SemanticRouterConfig {
+                threshold: 1.01,
+                cache_size: 16,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(strict.route("Any reminders?").await.is_none());
+        let matched = router(Arc::default())
+            .await
+            .route("Any reminders?")
+            .await
+            .unwrap();
+        assert_eq!(matched.intent, "list_reminders");
+    }
+
+    #[tokio::test]
+    async fn input_embeddings_are_cached() {
+        let embedding = Arc::new(TopicEmbedding::default());
+        let router = router(Arc::clone(&embedding)).await;
+
+        router.route("Was steht heute an?").await.unwrap();
+        router.route("  was steht HEUTE an?").await.unwrap();
+
+        assert_eq!(embedding.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn cache_evicts_oldest_input() {
+        let embedding = Arc::new(TopicEmbedding::default());
+        let router = SemanticRouter::build(
+            Arc::clone(&embedding) as Arc<dyn EmbeddingPort>,
+            SemanticRouterConfig {
+                threshold: 0.85,
+                cache_size: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+        router.route("first mail").await;
+        router.route("second mail").await;
+        router.route("first mail").await;
+
+        assert_eq!(embedding.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn embedding_failure_does_not_route() {
+        let router = router(Arc::new(TopicEmbedding {
+            calls: AtomicUsize::new(0),
+            fail: true,
+        }))
+        .await;
+
+        assert!(router.route("Did somebody mail me?").await.is_none());
+    }
+}
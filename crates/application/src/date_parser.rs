@@ -1,17 +1,29 @@
 //! Natural language date parsing utilities
 //!
 //! Provides fuzzy date parsing for German and English natural language dates.
-//! Supports bilingual input to allow both German and English date expressions.
+//! Simple relative dates ("demain", "mañana") are also understood in French
+//! and Spanish.
 
-use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, Utc, Weekday};
+use domain::Timezone;
 use tracing::debug;
 
+/// Accepted formats for date-times without an explicit offset
+const LOCAL_DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+];
+
 /// Parse a natural language date string into a NaiveDate
 ///
 /// Supports formats like:
 /// - "heute" / "today"
 /// - "morgen" / "tomorrow"
 /// - "übermorgen" / "day after tomorrow"
+/// - "aujourd'hui" / "demain" / "après-demain" (French)
+/// - "hoy" / "mañana" / "pasado mañana" (Spanish)
 /// - "nächsten Montag" / "next Monday"
 /// - "15. Januar" / "January 15"
 /// - "15.01.2025" / "2025-01-15"
@@ -32,6 +44,12 @@ pub fn parse_date(input: &str) -> Option<NaiveDate> {
         return Some(date);
     }
 
+    // Try French and Spanish relative dates
+    if let Some(date) = parse_romance_relative(&input, today) {
+        debug!(input = %input, date = %date, "Parsed French/Spanish relative date");
+        return Some(date);
+    }
+
     // Try weekday patterns
     if let Some(date) = parse_weekday(&input, today) {
         debug!(input = %input, date = %date, "Parsed weekday");
@@ -83,10 +101,29 @@ fn parse_english_relative(input: &str, today: NaiveDate) -> Option<NaiveDate> {
     }
 }
 
+/// Parse French and Spanish relative date expressions
+fn parse_romance_relative(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match input {
+        "aujourd'hui" | "hoy" => Some(today),
+        "demain" | "mañana" | "manana" => Some(today + Duration::days(1)),
+        "après-demain" | "pasado mañana" | "pasado manana" => Some(today + Duration::days(2)),
+        "hier" | "ayer" => Some(today - Duration::days(1)),
+        _ => None,
+    }
+}
+
 /// Parse weekday expressions like "next Monday" or "nächsten Montag" (German)
 fn parse_weekday(input: &str, today: NaiveDate) -> Option<NaiveDate> {
-    // German and English weekdays
-    let weekday = if input.contains("montag") || input.contains("monday") {
+    let weekday = weekday_in(input)?;
+
+    let is_next = input.contains("nächst") || input.contains("next") || input.contains("kommend");
+
+    Some(next_weekday(today, weekday, is_next))
+}
+
+/// Find a German or English weekday name in the input
+fn weekday_in(input: &str) -> Option<Weekday> {
+    if input.contains("montag") || input.contains("monday") {
         Some(Weekday::Mon)
     } else if input.contains("dienstag") || input.contains("tuesday") {
         Some(Weekday::Tue)
@@ -102,11 +139,7 @@ fn parse_weekday(input: &str, today: NaiveDate) -> Option<NaiveDate> {
         Some(Weekday::Sun)
     } else {
         None
-    }?;
-
-    let is_next = input.contains("nächst") || input.contains("next") || input.contains("kommend");
-
-    Some(next_weekday(today, weekday, is_next))
+    }
 }
 
 /// Find the next occurrence of a weekday
@@ -168,10 +201,21 @@ pub fn extract_date_from_text(input: &str) -> Option<NaiveDate> {
     let input = input.to_lowercase();
 
     // Check for common German and English patterns in text
-    if input.contains("für heute") || input.contains("for today") {
+    if input.contains("après-demain") || input.contains("pasado mañana") {
+        return parse_date("day after tomorrow");
+    }
+    if input.contains("für heute")
+        || input.contains("for today")
+        || input.contains("pour aujourd'hui")
+        || input.contains("para hoy")
+    {
         return parse_date("today");
     }
-    if input.contains("für morgen") || input.contains("for tomorrow") {
+    if input.contains("für morgen")
+        || input.contains("for tomorrow")
+        || input.contains("pour demain")
+        || input.contains("para mañana")
+    {
         return parse_date("tomorrow");
     }
     if input.contains("für übermorgen") || input.contains("day after tomorrow") {
@@ -201,6 +245,86 @@ pub fn extract_date_from_text(input: &str) -> Option<NaiveDate> {
     None
 }
 
+/// Detect a repeat rule in free text
+///
+/// Returns the canonical recurrence string understood by
+/// `domain::Recurrence`:
+/// - "every day" / "daily" / "täglich" / "jeden Tag" → `daily`
+/// - "every Monday" / "jeden Montag" / "montags" → `weekly:Mon`
+/// - "every week" / "weekly" / "wöchentlich" / "jede Woche" → `weekly`
+pub fn extract_recurrence_from_text(input: &str) -> Option<String> {
+    let input = input.to_lowercase();
+
+    let daily = [
+        "every day",
+        "everyday",
+        "each day",
+        "daily",
+        "täglich",
+        "jeden tag",
+        "chaque jour",
+        "tous les jours",
+        "cada día",
+        "todos los días",
+    ];
+    if daily.iter().any(|p| input.contains(p)) {
+        return Some("daily".to_string());
+    }
+
+    // "every monday", "jeden montag" or the German adverb "montags"
+    let recurring_weekday = input
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .filter(|w| matches!(w[0], "every" | "each" | "jeden" | "jede"))
+        .find_map(|w| weekday_in(w[1]))
+        .or_else(|| {
+            input
+                .split_whitespace()
+                .map(|w| w.trim_matches(|c: char| !c.is_alphabetic()))
+                .filter(|w| w.ends_with("tags") || *w == "mittwochs")
+                .find_map(weekday_in)
+        });
+    if let Some(weekday) = recurring_weekday {
+        return Some(format!("weekly:{weekday}"));
+    }
+
+    let weekly = [
+        "every week",
+        "each week",
+        "weekly",
+        "wöchentlich",
+        "jede woche",
+        "chaque semaine",
+        "toutes les semaines",
+        "cada semana",
+        "todas las semanas",
+    ];
+    if weekly.iter().any(|p| input.contains(p)) {
+        return Some("weekly".to_string());
+    }
+
+    None
+}
+
+/// Parse a date-time and convert it to UTC
+///
+/// Values with an explicit offset ("2025-01-15T09:00:00+01:00") are taken
+/// as is. Values without one ("2025-01-15 09:00", "2025-01-15T09:00") are
+/// wall-clock times in `timezone`, so "9:00" stays 9:00 local on both sides
+/// of a DST switch.
+pub fn parse_local_datetime(input: &str, timezone: &Timezone) -> Option<DateTime<Utc>> {
+    let input = input.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    LOCAL_DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+        .map(|local| timezone.to_utc(local))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,6 +406,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_french_and_spanish_relative() {
+        let today = Local::now().date_naive();
+        assert_eq!(parse_date("aujourd'hui"), Some(today));
+        assert_eq!(parse_date("demain"), Some(today + Duration::days(1)));
+        assert_eq!(parse_date("après-demain"), Some(today + Duration::days(2)));
+        assert_eq!(parse_date("hoy"), Some(today));
+        assert_eq!(parse_date("Mañana"), Some(today + Duration::days(1)));
+        assert_eq!(parse_date("pasado mañana"), Some(today + Duration::days(2)));
+    }
+
+    #[test]
+    fn extract_date_french_and_spanish() {
+        let today = Local::now().date_naive();
+        assert_eq!(
+            extract_date_from_text("résumé du jour pour demain"),
+            Some(today + Duration::days(1))
+        );
+        assert_eq!(
+            extract_date_from_text("resumen del día para mañana"),
+            Some(today + Duration::days(1))
+        );
+        assert_eq!(
+            extract_date_from_text("briefing pour après-demain"),
+            Some(today + Duration::days(2))
+        );
+    }
+
+    #[test]
+    fn extract_recurrence_daily() {
+        assert_eq!(
+            extract_recurrence_from_text("Remind me every day at 8 to take my pills"),
+            Some("daily".to_string())
+        );
+        assert_eq!(
+            extract_recurrence_from_text("Erinnere mich täglich ans Gießen"),
+            Some("daily".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_recurrence_weekday() {
+        assert_eq!(
+            extract_recurrence_from_text("Erinnere mich jeden Montag um 9 an den Müll"),
+            Some("weekly:Mon".to_string())
+        );
+        assert_eq!(
+            extract_recurrence_from_text("remind me every friday to submit hours"),
+            Some("weekly:Fri".to_string())
+        );
+        assert_eq!(
+            extract_recurrence_from_text("Erinnere mich donnerstags ans Training"),
+            Some("weekly:Thu".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_recurrence_weekly() {
+        assert_eq!(
+            extract_recurrence_from_text("weekly reminder to water the plants"),
+            Some("weekly".to_string())
+        );
+        assert_eq!(
+            extract_recurrence_from_text("Erinnere mich jede Woche an den Bericht"),
+            Some("weekly".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_recurrence_none_for_one_off() {
+        assert_eq!(extract_recurrence_from_text("remind me next monday"), None);
+        assert_eq!(
+            extract_recurrence_from_text("Erinnere mich morgen nachmittags"),
+            None
+        );
+    }
+
+    #[test]
+    fn local_datetime_keeps_wall_clock_across_dst() {
+        let berlin = Timezone::berlin();
+        // Berlin switches to summer time on 2026-03-29
+        let before = parse_local_datetime("2026-03-28 09:00", &berlin).unwrap();
+        let after = parse_local_datetime("2026-03-30T09:00", &berlin).unwrap();
+        assert_eq!(before.to_rfc3339(), "2026-03-28T08:00:00+00:00");
+        assert_eq!(after.to_rfc3339(), "2026-03-30T07:00:00+00:00");
+
+        // Back to winter time on 2026-10-25
+        let after = parse_local_datetime("2026-10-26T09:00:00", &berlin).unwrap();
+        assert_eq!(after.to_rfc3339(), "2026-10-26T08:00:00+00:00");
+    }
+
+    #[test]
+    fn local_datetime_respects_explicit_offset() {
+        let parsed =
+            parse_local_datetime("2026-03-30T09:00:00+00:00", &Timezone::berlin()).unwrap();
+        assert_eq!(parsed.to_rfc3339(), "2026-03-30T09:00:00+00:00");
+    }
+
+    #[test]
+    fn local_datetime_rejects_garbage() {
+        assert!(parse_local_datetime("tomorrow-ish", &Timezone::utc()).is_none());
+    }
+
     #[test]
     fn next_weekday_same_day_not_forced() {
         let monday = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(); // A Monday
@@ -63,6 +63,19 @@ pub enum ApplicationError {
     /// IP address is blocked due to suspicious activity
     #[error("Access blocked: {0}")]
     Blocked(String),
+
+    /// Operation was cancelled, e.g. superseded by a newer request
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    /// The model kept returning output that does not match the requested schema
+    #[error("Invalid model output after {attempts} attempts: {reason}")]
+    InvalidModelOutput {
+        /// Number of generations tried, including repair attempts
+        attempts: u32,
+        /// Why the last output was rejected
+        reason: String,
+    },
 }
 
 impl ApplicationError {
@@ -10,10 +10,14 @@ pub mod error;
 pub mod ports;
 pub mod request_context;
 pub mod services;
+pub mod tools;
 
-pub use command_parser::CommandParser;
-pub use date_parser::{extract_date_from_text, parse_date};
+pub use command_parser::{CommandParser, ParserLanguage, SemanticRouter, SemanticRouterConfig};
+pub use date_parser::{
+    extract_date_from_text, extract_recurrence_from_text, parse_date, parse_local_datetime,
+};
 pub use error::ApplicationError;
 pub use ports::*;
 pub use request_context::RequestContext;
 pub use services::*;
+pub use tools::{Tool, ToolRegistry};
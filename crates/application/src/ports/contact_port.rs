@@ -346,6 +346,53 @@ pub struct AddressbookInfo {
     pub is_default: bool,
 }
 
+/// A vCard entry that could not be imported
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportEntryError {
+    /// Zero-based position of the card in the file
+    pub index: usize,
+    /// Contact name, if the card could be parsed
+    pub name: Option<String>,
+    /// Why the entry failed
+    pub message: String,
+}
+
+/// Outcome of a batch vCard import
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportResult {
+    /// Contacts created
+    pub created: usize,
+    /// Cards skipped because a contact with the same email already exists
+    pub skipped: usize,
+    /// Cards that could not be parsed or created
+    pub failed: usize,
+    /// One error per failed card
+    pub errors: Vec<ImportEntryError>,
+}
+
+impl ImportResult {
+    /// Record a failed entry
+    pub fn record_failure(
+        &mut self,
+        index: usize,
+        name: Option<String>,
+        message: impl Into<String>,
+    ) {
+        self.failed += 1;
+        self.errors.push(ImportEntryError {
+            index,
+            name,
+            message: message.into(),
+        });
+    }
+
+    /// Total number of cards processed
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.created + self.skipped + self.failed
+    }
+}
+
 /// Contact port trait
 ///
 /// Defines operations for contact management via CardDAV.
@@ -381,9 +428,28 @@ pub trait ContactPort: Send + Sync {
     /// Delete a contact
     async fn delete_contact(&self, contact_id: &str) -> Result<(), ContactError>;
 
+    /// Export a contact as vCard 3.0 text (RFC 2426)
+    ///
+    /// Text values are escaped, so the result can be saved as a `.vcf` file.
+    async fn export_vcard(&self, contact_id: &str) -> Result<String, ContactError>;
+
+    /// Import every contact from a multi-card `.vcf` file
+    ///
+    /// Cards whose email matches an existing contact (or an earlier card in
+    /// the same file) are skipped. A malformed card or failed create is
+    /// recorded in the result and does not abort the import.
+    ///
+    /// # Errors
+    ///
+    /// Only fails when the service itself is unreachable before any card is
+    /// processed.
+    async fn import_vcards(&self, vcard_data: &str) -> Result<ImportResult, ContactError>;
+
     /// Search contacts by query string
     ///
-    /// Searches across name, email, phone, and organization fields.
+    /// Searches across name, email, phone, and organization fields. Phone
+    /// numbers match regardless of spacing and `+49`/`0049` prefix style.
+    /// Exact matches are returned first.
     async fn search_contacts(&self, query: &str) -> Result<Vec<ContactSummary>, ContactError>;
 
     /// Check if the contact service is available
@@ -397,6 +463,20 @@ pub trait ContactPort: Send + Sync {
 mod tests {
     use super::*;
 
+    #[test]
+    fn import_result_counts_failures() {
+        let mut result = ImportResult {
+            created: 2,
+            skipped: 1,
+            ..ImportResult::default()
+        };
+        result.record_failure(3, None, "vCard has no FN or N property");
+
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.total(), 4);
+        assert_eq!(result.errors[0].index, 3);
+    }
+
     #[test]
     fn contact_summary_creation() {
         let summary = ContactSummary::new("c-1", "Alice Smith");
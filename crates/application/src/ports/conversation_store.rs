@@ -2,12 +2,15 @@
 //!
 //! Defines the interface for persisting and retrieving conversations.
 
+use std::{fmt::Write as _, str::FromStr};
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::{
-    entities::{ChatMessage, Conversation, ConversationSource},
+    entities::{ChatMessage, Conversation, ConversationSource, MessageRole},
     value_objects::ConversationId,
 };
+use serde::Serialize;
 
 use crate::error::ApplicationError;
 
@@ -72,4 +75,189 @@ pub trait ConversationStore: Send + Sync {
     ///
     /// Returns the number of deleted conversations.
     async fn cleanup_older_than(&self, cutoff: DateTime<Utc>) -> Result<usize, ApplicationError>;
+
+    /// Export a conversation as a transcript in the given format
+    ///
+    /// Returns `None` if the conversation does not exist.
+    async fn export(
+        &self,
+        conversation_id: &ConversationId,
+        format: ExportFormat,
+    ) -> Result<Option<String>, ApplicationError> {
+        match self.get(conversation_id).await? {
+            Some(conversation) => render_transcript(&conversation, format).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Output format for conversation exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// Structured JSON document
+    #[default]
+    Json,
+    /// Human-readable Markdown transcript
+    Markdown,
+}
+
+impl ExportFormat {
+    /// MIME type of the rendered export
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+
+    /// File extension for downloads
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "md" | "markdown" => Ok(Self::Markdown),
+            other => Err(format!("Unknown export format: {other}")),
+        }
+    }
+}
+
+/// JSON export document
+#[derive(Serialize)]
+struct TranscriptExport<'a> {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    messages: Vec<TranscriptMessage<'a>>,
+}
+
+/// One message of a JSON export
+#[derive(Serialize)]
+struct TranscriptMessage<'a> {
+    role: MessageRole,
+    content: &'a str,
+    created_at: DateTime<Utc>,
+}
+
+/// Render a conversation transcript including message roles and timestamps
+fn render_transcript(
+    conversation: &Conversation,
+    format: ExportFormat,
+) -> Result<String, ApplicationError> {
+    match format {
+        ExportFormat::Json => {
+            let export = TranscriptExport {
+                id: conversation.id.to_string(),
+                title: conversation.title.as_deref(),
+                created_at: conversation.created_at,
+                updated_at: conversation.updated_at,
+                messages: conversation
+                    .messages
+                    .iter()
+                    .map(|message| TranscriptMessage {
+                        role: message.role,
+                        content: &message.content,
+                        created_at: message.created_at,
+                    })
+                    .collect(),
+            };
+            serde_json::to_string_pretty(&export)
+                .map_err(|e| ApplicationError::Internal(format!("Export failed: {e}")))
+        },
+        ExportFormat::Markdown => Ok(render_markdown(conversation)),
+    }
+}
+
+/// Markdown transcript with one section per message
+fn render_markdown(conversation: &Conversation) -> String {
+    let mut out = format!(
+        "# {}\n\n",
+        conversation.title.as_deref().unwrap_or("Conversation")
+    );
+    let _ = writeln!(out, "- ID: `{}`", conversation.id);
+    let _ = writeln!(out, "- Started: {}", conversation.created_at.to_rfc3339());
+    let _ = writeln!(out, "- Updated: {}", conversation.updated_at.to_rfc3339());
+
+    for message in &conversation.messages {
+        let role = match message.role {
+            MessageRole::User => "User",
+            MessageRole::Assistant => "Assistant",
+            MessageRole::System => "System",
+        };
+        let _ = write!(
+            out,
+            "\n### {role} — {}\n\n{}\n",
+            message.created_at.to_rfc3339(),
+            message.content.trim()
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_conversation() -> Conversation {
+        let mut conversation = Conversation::new();
+        conversation.title = Some("Trip planning".to_string());
+        conversation.add_user_message("Where should we go?");
+        conversation.add_assistant_message("How about the Alps?");
+        conversation
+    }
+
+    #[test]
+    fn export_format_from_str() {
+        assert_eq!("json".parse::<ExportFormat>(), Ok(ExportFormat::Json));
+        assert_eq!("MD".parse::<ExportFormat>(), Ok(ExportFormat::Markdown));
+        assert_eq!(
+            "markdown".parse::<ExportFormat>(),
+            Ok(ExportFormat::Markdown)
+        );
+        assert!("pdf".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn json_export_contains_roles_and_timestamps() {
+        let conversation = sample_conversation();
+        let json = render_transcript(&conversation, ExportFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["id"], conversation.id.to_string());
+        assert_eq!(value["title"], "Trip planning");
+        let messages = value["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0]["role"], "user");
+        assert_eq!(messages[1]["role"], "assistant");
+        assert_eq!(messages[1]["content"], "How about the Alps?");
+        assert!(messages[0]["created_at"].is_string());
+    }
+
+    #[test]
+    fn markdown_export_contains_roles_and_timestamps() {
+        let conversation = sample_conversation();
+        let markdown = render_transcript(&conversation, ExportFormat::Markdown).unwrap();
+
+        assert!(markdown.starts_with("# Trip planning\n"));
+        let user_heading = format!(
+            "### User — {}",
+            conversation.messages[0].created_at.to_rfc3339()
+        );
+        assert!(markdown.contains(&user_heading));
+        assert!(markdown.contains("### Assistant — "));
+        assert!(markdown.contains("How about the Alps?"));
+    }
 }
@@ -19,6 +19,10 @@ pub struct DatabaseHealth {
     pub pool_size: Option<u32>,
     /// Response time of the health check in milliseconds
     pub response_time_ms: Option<u64>,
+    /// Result of the most recent integrity check (if one has run)
+    pub integrity_ok: Option<bool>,
+    /// Version of the most recently applied schema migration (if known)
+    pub schema_version: Option<i64>,
 }
 
 impl DatabaseHealth {
@@ -30,6 +34,8 @@ impl DatabaseHealth {
             version: None,
             pool_size: None,
             response_time_ms: None,
+            integrity_ok: None,
+            schema_version: None,
         }
     }
 
@@ -41,6 +47,8 @@ impl DatabaseHealth {
             version: Some(version.into()),
             pool_size: None,
             response_time_ms: None,
+            integrity_ok: None,
+            schema_version: None,
         }
     }
 
@@ -52,6 +60,8 @@ impl DatabaseHealth {
             version: None,
             pool_size: None,
             response_time_ms: None,
+            integrity_ok: None,
+            schema_version: None,
         }
     }
 
@@ -68,6 +78,20 @@ impl DatabaseHealth {
         self.pool_size = Some(size);
         self
     }
+
+    /// Add the result of the last integrity check
+    #[must_use]
+    pub const fn with_integrity(mut self, ok: bool) -> Self {
+        self.integrity_ok = Some(ok);
+        self
+    }
+
+    /// Add the current schema version
+    #[must_use]
+    pub const fn with_schema_version(mut self, version: i64) -> Self {
+        self.schema_version = Some(version);
+        self
+    }
 }
 
 /// Port for database health checking operations
@@ -131,4 +155,11 @@ mod tests {
         let health = DatabaseHealth::healthy().with_pool_size(5);
         assert_eq!(health.pool_size, Some(5));
     }
+
+    #[test]
+    fn database_health_with_integrity() {
+        assert!(DatabaseHealth::healthy().integrity_ok.is_none());
+        let health = DatabaseHealth::healthy().with_integrity(false);
+        assert_eq!(health.integrity_ok, Some(false));
+    }
 }
@@ -0,0 +1,23 @@
+//! Degraded mode port
+//!
+//! Lets request handlers check whether inference is in degraded mode
+//! without depending on the adapter that tracks it.
+
+use std::time::Duration;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Read-only view of the inference degraded mode
+#[cfg_attr(test, automock)]
+pub trait DegradedModePort: Send + Sync {
+    /// Whether inference is unavailable, i.e. the circuit is open and the
+    /// retry cooldown has not elapsed yet
+    fn is_unavailable(&self) -> bool;
+
+    /// User-facing message while the service is unavailable
+    fn unavailable_message(&self) -> String;
+
+    /// Cooldown before the primary backend is retried
+    fn retry_cooldown(&self) -> Duration;
+}
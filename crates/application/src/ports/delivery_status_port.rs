@@ -0,0 +1,120 @@
+//! Message delivery status port
+//!
+//! Defines the interface for tracking delivery and read receipts of
+//! outgoing messenger messages.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::value_objects::{DeliveryStatus, MessengerSource, ReminderId};
+#[cfg(test)]
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApplicationError;
+
+/// Delivery state of a single outgoing message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageDelivery {
+    /// Messenger-assigned message ID
+    pub message_id: String,
+    /// Messenger the message was sent through
+    pub source: MessengerSource,
+    /// Recipient phone number, if known
+    pub recipient: Option<String>,
+    /// Current delivery status
+    pub status: DeliveryStatus,
+    /// Error reported by the messenger for failed deliveries
+    pub error: Option<String>,
+    /// Reminder this message notified about, if any
+    pub reminder_id: Option<ReminderId>,
+    /// When the message was first tracked
+    pub created_at: DateTime<Utc>,
+    /// When the status last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A status update reported by a messenger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryUpdate {
+    /// Messenger-assigned message ID
+    pub message_id: String,
+    /// Messenger that reported the update
+    pub source: MessengerSource,
+    /// Reported status
+    pub status: DeliveryStatus,
+    /// Error details for failed deliveries
+    pub error: Option<String>,
+    /// When the messenger observed the status change
+    pub timestamp: DateTime<Utc>,
+}
+
+impl DeliveryUpdate {
+    /// Create an update observed at `timestamp`
+    #[must_use]
+    pub fn new(
+        message_id: impl Into<String>,
+        source: MessengerSource,
+        status: DeliveryStatus,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            message_id: message_id.into(),
+            source,
+            status,
+            error: None,
+            timestamp,
+        }
+    }
+
+    /// Attach error details
+    #[must_use]
+    pub fn with_error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+}
+
+/// A reminder whose latest notification has not reached the recipient
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndeliveredReminder {
+    /// The reminder to notify about again
+    pub reminder_id: ReminderId,
+    /// Messenger the last attempt went through
+    pub source: MessengerSource,
+    /// Recipient of the last attempt
+    pub recipient: Option<String>,
+    /// Number of notifications sent for this reminder so far
+    pub attempts: u32,
+}
+
+/// Port for message delivery status tracking
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeliveryStatusPort: Send + Sync {
+    /// Start tracking an outgoing message as sent
+    ///
+    /// Does not overwrite a status that arrived before the send was recorded.
+    async fn record_sent(
+        &self,
+        message_id: &str,
+        source: MessengerSource,
+        recipient: &str,
+        reminder_id: Option<ReminderId>,
+    ) -> Result<(), ApplicationError>;
+
+    /// Apply a status update reported by a messenger
+    ///
+    /// Updates that would move the status backwards are ignored. Returns
+    /// whether the stored status changed.
+    async fn apply_update(&self, update: &DeliveryUpdate) -> Result<bool, ApplicationError>;
+
+    /// Get the delivery state of a message
+    async fn get(&self, message_id: &str) -> Result<Option<MessageDelivery>, ApplicationError>;
+
+    /// List reminders whose latest notification was sent before `sent_before`
+    /// and has not been delivered
+    async fn undelivered_reminders(
+        &self,
+        sent_before: DateTime<Utc>,
+    ) -> Result<Vec<UndeliveredReminder>, ApplicationError>;
+}
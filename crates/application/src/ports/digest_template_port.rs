@@ -0,0 +1,27 @@
+//! Digest template port
+//!
+//! Defines the interface for formatting the daily digest pushed to
+//! messenger contacts.
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::ApplicationError;
+
+/// Variables available to the daily digest template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestTemplateData {
+    /// Briefing text as generated by the agent (Markdown)
+    pub briefing: String,
+    /// Date the digest is for, formatted for display
+    pub date: String,
+    /// Messenger the digest is sent over (`whatsapp` or `signal`)
+    pub messenger: String,
+}
+
+/// Port for rendering the daily digest
+#[cfg_attr(test, automock)]
+pub trait DigestTemplatePort: Send + Sync {
+    /// Render the message text of a daily digest
+    fn render_daily_digest(&self, data: &DigestTemplateData) -> Result<String, ApplicationError>;
+}
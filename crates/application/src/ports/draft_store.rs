@@ -4,6 +4,7 @@
 //! Drafts are temporary documents that require approval before sending.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use domain::{DraftId, PersistedEmailDraft, UserId};
 #[cfg(test)]
 use mockall::automock;
@@ -75,9 +76,13 @@ pub trait DraftStorePort: Send + Sync {
 
     /// Cleanup expired drafts
     ///
-    /// Removes all drafts that have passed their expiration time.
+    /// Removes all drafts that have passed their expiration time or are
+    /// older than the store's TTL as of `now`.
+    ///
+    /// # Arguments
+    /// * `now` - Reference time for the expiry check
     ///
     /// # Returns
     /// The number of drafts deleted
-    async fn cleanup_expired(&self) -> Result<usize, ApplicationError>;
+    async fn cleanup_expired(&self, now: DateTime<Utc>) -> Result<usize, ApplicationError>;
 }
@@ -99,6 +99,9 @@ pub struct EmailDraft {
     pub subject: String,
     /// Email body (plain text)
     pub body: String,
+    /// Optional HTML alternative of the body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
 }
 
 impl EmailDraft {
@@ -109,9 +112,17 @@ impl EmailDraft {
             cc: Vec::new(),
             subject: subject.into(),
             body: body.into(),
+            html_body: None,
         }
     }
 
+    /// Add an HTML alternative of the body
+    #[must_use]
+    pub fn with_html_body(mut self, html_body: impl Into<String>) -> Self {
+        self.html_body = Some(html_body.into());
+        self
+    }
+
     /// Add a CC recipient
     #[must_use]
     pub fn with_cc(mut self, cc: impl Into<String>) -> Self {
@@ -0,0 +1,45 @@
+//! Email template port
+//!
+//! Defines the interface for rendering email drafts, adding greeting and
+//! signature around the body written by the user or the assistant.
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::ApplicationError;
+
+/// Variables available to the email draft templates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailTemplateData {
+    /// Name the recipient is greeted with
+    pub recipient: String,
+    /// Recipient email address
+    pub recipient_email: String,
+    /// Email subject
+    pub subject: String,
+    /// Email body without greeting and signature
+    pub body: String,
+    /// Name used in the signature
+    pub sender: String,
+    /// CC recipients
+    pub cc: Vec<String>,
+}
+
+/// A rendered email draft
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedEmail {
+    /// Plaintext body, including greeting and signature
+    pub text: String,
+    /// HTML body, if the template set has one
+    pub html: Option<String>,
+}
+
+/// Port for rendering email drafts
+#[cfg_attr(test, automock)]
+pub trait EmailTemplatePort: Send + Sync {
+    /// Render the plaintext and (optionally) HTML version of a draft
+    fn render_email_draft(
+        &self,
+        data: &EmailTemplateData,
+    ) -> Result<RenderedEmail, ApplicationError>;
+}
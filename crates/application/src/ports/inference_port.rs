@@ -1,11 +1,15 @@
 //! Inference port - Interface for LLM inference
 
-use std::pin::Pin;
+use std::{future::Future, pin::Pin};
 
 use async_trait::async_trait;
 use domain::Conversation;
-use futures::Stream;
-use serde::{Deserialize, Serialize};
+use futures::{Stream, StreamExt};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
 
 use crate::error::ApplicationError;
 
@@ -38,6 +42,48 @@ pub struct StreamingChunk {
 pub type InferenceStream =
     Pin<Box<dyn Stream<Item = Result<StreamingChunk, ApplicationError>> + Send>>;
 
+/// Await a generation unless `cancel` fires first
+///
+/// Works with any [`InferencePort`] call. On cancellation the generation
+/// future is dropped, which drops the backend's HTTP request so the model
+/// stops generating instead of finishing a reply nobody reads.
+///
+/// # Errors
+///
+/// Returns [`ApplicationError::Cancelled`] if `cancel` fires first,
+/// otherwise the result of `generation`.
+pub async fn cancellable<T, F>(
+    cancel: &CancellationToken,
+    generation: F,
+) -> Result<T, ApplicationError>
+where
+    F: Future<Output = Result<T, ApplicationError>>,
+{
+    tokio::select! {
+        biased;
+        () = cancel.cancelled() => Err(ApplicationError::Cancelled),
+        result = generation => result,
+    }
+}
+
+/// Stop a streaming response once `cancel` fires
+///
+/// The inner stream is dropped on cancellation, closing its connection, and
+/// the returned stream ends with [`ApplicationError::Cancelled`].
+pub fn cancellable_stream(stream: InferenceStream, cancel: CancellationToken) -> InferenceStream {
+    Box::pin(futures::stream::unfold(
+        Some((stream, cancel)),
+        |state| async move {
+            let (mut stream, cancel) = state?;
+            tokio::select! {
+                biased;
+                () = cancel.cancelled() => Some((Err(ApplicationError::Cancelled), None)),
+                item = stream.next() => item.map(|item| (item, Some((stream, cancel)))),
+            }
+        },
+    ))
+}
+
 /// Port for inference operations
 #[async_trait]
 pub trait InferencePort: Send + Sync {
@@ -67,9 +113,68 @@ pub trait InferencePort: Send + Sync {
         message: &str,
     ) -> Result<InferenceStream, ApplicationError>;
 
+    /// Generate a streaming response within a conversation context
+    ///
+    /// Backends without native streaming chat fall back to
+    /// [`generate_with_context`](Self::generate_with_context) and emit the
+    /// whole response as a single final chunk.
+    async fn generate_stream_with_context(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<InferenceStream, ApplicationError> {
+        let result = self.generate_with_context(conversation).await?;
+        Ok(Box::pin(futures::stream::once(async move {
+            Ok(StreamingChunk {
+                content: result.content,
+                done: true,
+                model: Some(result.model),
+            })
+        })))
+    }
+
+    /// Generate a streaming response within a conversation context using a
+    /// specific model
+    ///
+    /// Backends that cannot select a model per request ignore `model`; the
+    /// `model` field of the final chunk reports the model that answered.
+    async fn generate_stream_with_context_and_model(
+        &self,
+        conversation: &Conversation,
+        model: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        let _ = model;
+        self.generate_stream_with_context(conversation).await
+    }
+
+    /// Generate a response with a specific system prompt, constrained to
+    /// JSON matching `schema`
+    ///
+    /// Backends with a structured output mode (e.g. Ollama's `format`)
+    /// should override this; the default is a plain
+    /// [`generate_with_system`](Self::generate_with_system). Use
+    /// [`InferenceJsonExt`] to get a validated, typed value.
+    async fn generate_structured(
+        &self,
+        system_prompt: &str,
+        message: &str,
+        schema: &Value,
+    ) -> Result<InferenceResult, ApplicationError> {
+        let _ = schema;
+        self.generate_with_system(system_prompt, message).await
+    }
+
     /// Check if the inference backend is healthy
     async fn is_healthy(&self) -> bool;
 
+    /// Load the current model so the first real request isn't slowed down
+    ///
+    /// Backends that load models lazily should override this with the
+    /// cheapest request that forces the load; the default sends a trivial
+    /// generation.
+    async fn warmup(&self) -> Result<(), ApplicationError> {
+        self.generate("Hi").await.map(|_| ())
+    }
+
     /// Get the name of the current model
     fn current_model(&self) -> String;
 
@@ -88,3 +193,506 @@ pub trait InferencePort: Send + Sync {
     /// Result indicating success or failure
     async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError>;
 }
+
+/// Repair prompts sent after an invalid JSON reply before giving up
+pub const JSON_REPAIR_ATTEMPTS: u32 = 2;
+
+/// Extension trait for typed JSON generation
+///
+/// Asks the backend for JSON matching the schema of `T`, validates the reply
+/// against that schema and deserializes it. Invalid replies are sent back
+/// with a repair prompt up to [`JSON_REPAIR_ATTEMPTS`] times.
+#[async_trait]
+pub trait InferenceJsonExt: InferencePort {
+    /// Generate a value of type `T` for a single prompt
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApplicationError::InvalidModelOutput`] if no valid reply was
+    /// produced, or the backend error of a failed generation.
+    async fn generate_json<T>(&self, prompt: &str) -> Result<T, ApplicationError>
+    where
+        T: DeserializeOwned + JsonSchema + Send,
+    {
+        let schema = schemars::schema_for!(T).to_value();
+        let system_prompt = format!("Reply only with JSON matching this schema:\n{schema}");
+        self.generate_json_with_system(&system_prompt, prompt).await
+    }
+
+    /// Generate a value of type `T` with a specific system prompt
+    ///
+    /// The system prompt should describe the expected JSON; the schema is
+    /// only passed to the backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApplicationError::InvalidModelOutput`] if no valid reply was
+    /// produced, or the backend error of a failed generation.
+    async fn generate_json_with_system<T>(
+        &self,
+        system_prompt: &str,
+        prompt: &str,
+    ) -> Result<T, ApplicationError>
+    where
+        T: DeserializeOwned + JsonSchema + Send,
+    {
+        let schema = schemars::schema_for!(T).to_value();
+        let mut message = prompt.to_string();
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+            let result = self
+                .generate_structured(system_prompt, &message, &schema)
+                .await?;
+
+            match parse_json_reply(&result.content, &schema) {
+                Ok(value) => return Ok(value),
+                Err(reason) if attempts > JSON_REPAIR_ATTEMPTS => {
+                    return Err(ApplicationError::InvalidModelOutput { attempts, reason });
+                },
+                Err(reason) => {
+                    warn!(
+                        attempt = attempts,
+                        reason = %reason,
+                        "Invalid JSON reply, asking the model to repair it"
+                    );
+                    message = repair_prompt(prompt, &result.content, &reason);
+                },
+            }
+        }
+    }
+}
+
+impl<I: InferencePort + ?Sized> InferenceJsonExt for I {}
+
+/// Prompt asking the model to fix its previous reply
+fn repair_prompt(prompt: &str, reply: &str, reason: &str) -> String {
+    format!(
+        "{prompt}\n\nYour previous reply was rejected ({reason}):\n{reply}\n\n\
+         Reply again with only the corrected JSON."
+    )
+}
+
+/// Extract, validate and deserialize a JSON reply
+fn parse_json_reply<T: DeserializeOwned>(reply: &str, schema: &Value) -> Result<T, String> {
+    let value: Value =
+        serde_json::from_str(extract_json(reply)).map_err(|e| format!("JSON parse error: {e}"))?;
+    validate_json(&value, schema, schema)?;
+    serde_json::from_value(value).map_err(|e| format!("JSON parse error: {e}"))
+}
+
+/// Extract JSON from potentially markdown-wrapped response
+pub(crate) fn extract_json(response: &str) -> &str {
+    let response = response.trim();
+
+    // Handle ```json ... ``` blocks
+    if let Some(start) = response.find("```json") {
+        if let Some(end) = response[start + 7..].find("```") {
+            return response[start + 7..start + 7 + end].trim();
+        }
+    }
+
+    // Handle ``` ... ``` blocks
+    if let Some(start) = response.find("```") {
+        if let Some(end) = response[start + 3..].find("```") {
+            return response[start + 3..start + 3 + end].trim();
+        }
+    }
+
+    // Handle [ ... ] directly when the array opens before any object
+    if let Some(start) = response.find('[') {
+        if response.find('{').is_none_or(|brace| start < brace) {
+            if let Some(end) = response.rfind(']') {
+                if start <= end {
+                    return &response[start..=end];
+                }
+            }
+        }
+    }
+
+    // Handle { ... } directly
+    // Ensure start < end to avoid panics with malformed input like "} {"
+    if let Some(start) = response.find('{') {
+        if let Some(end) = response.rfind('}') {
+            if start <= end {
+                return &response[start..=end];
+            }
+        }
+    }
+
+    response
+}
+
+/// Validate `value` against a JSON schema
+///
+/// Covers the keywords schemars emits for plain data types: `type`, `enum`,
+/// `const`, `required`, `properties`, `items`, `minItems`, `anyOf`, `oneOf`,
+/// `allOf` and local `$ref`s. Unknown keywords are ignored.
+fn validate_json(value: &Value, schema: &Value, root: &Value) -> Result<(), String> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err("no value is allowed here".to_string()),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .ok_or_else(|| format!("unresolved schema reference {reference}"))?;
+        validate_json(value, target, root)?;
+    }
+
+    if let Some(types) = schema.get("type") {
+        let matches = match types {
+            Value::String(ty) => json_type_matches(value, ty),
+            Value::Array(types) => types
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|ty| json_type_matches(value, ty)),
+            _ => true,
+        };
+        if !matches {
+            return Err(format!("expected {types}, got {value}"));
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            return Err(format!(
+                "{value} is not one of {}",
+                Value::Array(allowed.clone())
+            ));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            return Err(format!("expected {expected}, got {value}"));
+        }
+    }
+
+    if let Value::Object(object) = value {
+        for field in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !object.contains_key(field) {
+                return Err(format!("missing required field \"{field}\""));
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                if let Some(field) = object.get(name) {
+                    validate_json(field, property, root)
+                        .map_err(|e| format!("field \"{name}\": {e}"))?;
+                }
+            }
+        }
+    }
+
+    if let Value::Array(items) = value {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                return Err(format!(
+                    "expected at least {min} items, got {}",
+                    items.len()
+                ));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                validate_json(item, item_schema, root).map_err(|e| format!("item {index}: {e}"))?;
+            }
+        }
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+        for sub in all {
+            validate_json(value, sub, root)?;
+        }
+    }
+    if let Some(any) = schema.get("anyOf").and_then(Value::as_array) {
+        if !any
+            .iter()
+            .any(|sub| validate_json(value, sub, root).is_ok())
+        {
+            return Err(format!("{value} matches none of the allowed shapes"));
+        }
+    }
+    if let Some(one) = schema.get("oneOf").and_then(Value::as_array) {
+        let matching = one
+            .iter()
+            .filter(|sub| validate_json(value, sub, root).is_ok())
+            .count();
+        if matching != 1 {
+            return Err(format!(
+                "{value} must match exactly one allowed shape, matched {matching}"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `value` has the JSON schema type `ty`
+fn json_type_matches(value: &Value, ty: &str) -> bool {
+    match ty {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+    };
+
+    use mockall::mock;
+    use schemars::{Schema, SchemaGenerator, json_schema};
+
+    use super::*;
+
+    mock! {
+        pub Inference {}
+
+        #[async_trait]
+        impl InferencePort for Inference {
+            async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_context(&self, conversation: &Conversation) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn generate_stream_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn generate_structured(&self, system_prompt: &str, message: &str, schema: &Value) -> Result<InferenceResult, ApplicationError>;
+            async fn is_healthy(&self) -> bool;
+            fn current_model(&self) -> String;
+            async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError>;
+            async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError>;
+        }
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Forecast {
+        city: String,
+        days: u32,
+    }
+
+    impl JsonSchema for Forecast {
+        fn schema_name() -> Cow<'static, str> {
+            "Forecast".into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "object",
+                "properties": {
+                    "city": { "type": "string" },
+                    "days": { "type": "integer", "minimum": 0 }
+                },
+                "required": ["city", "days"]
+            })
+        }
+    }
+
+    fn reply(content: &str) -> Result<InferenceResult, ApplicationError> {
+        Ok(InferenceResult {
+            content: content.to_string(),
+            model: "test".to_string(),
+            tokens_used: None,
+            latency_ms: 1,
+        })
+    }
+
+    /// Sets the flag when dropped
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn chunk(content: &str) -> Result<StreamingChunk, ApplicationError> {
+        Ok(StreamingChunk {
+            content: content.to_string(),
+            done: false,
+            model: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn cancellable_passes_through_result() {
+        let cancel = CancellationToken::new();
+        let result = cancellable(&cancel, async { Ok::<_, ApplicationError>(7) }).await;
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn cancellable_drops_generation_when_cancelled() {
+        let cancel = CancellationToken::new();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = DropFlag(Arc::clone(&dropped));
+        let generation = async move {
+            let _guard = guard;
+            std::future::pending::<Result<(), ApplicationError>>().await
+        };
+
+        let task = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { cancellable(&cancel, generation).await }
+        });
+        cancel.cancel();
+
+        assert!(matches!(
+            task.await.unwrap(),
+            Err(ApplicationError::Cancelled)
+        ));
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn cancellable_stream_ends_with_cancelled() {
+        let cancel = CancellationToken::new();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = DropFlag(Arc::clone(&dropped));
+        let inner = futures::stream::iter([chunk("a")])
+            .chain(futures::stream::pending())
+            .map(move |item| {
+                let _guard = &guard;
+                item
+            });
+
+        let mut stream = cancellable_stream(Box::pin(inner), cancel.clone());
+        assert_eq!(stream.next().await.unwrap().unwrap().content, "a");
+
+        cancel.cancel();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ApplicationError::Cancelled))
+        ));
+        assert!(dropped.load(Ordering::SeqCst));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn generate_json_passes_schema_and_parses_reply() {
+        let mut mock = MockInference::new();
+        mock.expect_generate_structured()
+            .withf(|_, _, schema| schema["required"] == serde_json::json!(["city", "days"]))
+            .times(1)
+            .returning(|_, _, _| {
+                reply(
+                    r#"```json
+{"city":"Berlin","days":3}
+```"#,
+                )
+            });
+
+        let forecast: Forecast = mock.generate_json("Forecast for Berlin").await.unwrap();
+        assert_eq!(
+            forecast,
+            Forecast {
+                city: "Berlin".to_string(),
+                days: 3
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn generate_json_repairs_schema_violations() {
+        let mut mock = MockInference::new();
+        let mut seq = mockall::Sequence::new();
+        mock.expect_generate_structured()
+            .withf(|_, message, _| message == "Forecast for Berlin")
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| reply(r#"{"city":"Berlin","days":"three"}"#));
+        mock.expect_generate_structured()
+            .withf(|_, message, _| {
+                message.starts_with("Forecast for Berlin") && message.contains("\"days\"")
+            })
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| reply(r#"{"city":"Berlin","days":3}"#));
+
+        let forecast: Forecast = mock.generate_json("Forecast for Berlin").await.unwrap();
+        assert_eq!(forecast.days, 3);
+    }
+
+    #[tokio::test]
+    async fn generate_json_fails_after_repair_attempts() {
+        let mut mock = MockInference::new();
+        mock.expect_generate_structured()
+            .times(JSON_REPAIR_ATTEMPTS as usize + 1)
+            .returning(|_, _, _| reply(r#"{"city":"Berlin"}"#));
+
+        let result = mock.generate_json::<Forecast>("Forecast for Berlin").await;
+        let Err(ApplicationError::InvalidModelOutput { attempts, reason }) = result else {
+            unreachable!("Expected InvalidModelOutput");
+        };
+        assert_eq!(attempts, JSON_REPAIR_ATTEMPTS + 1);
+        assert!(reason.contains("days"));
+    }
+
+    #[tokio::test]
+    async fn generate_json_propagates_backend_errors() {
+        let mut mock = MockInference::new();
+        mock.expect_generate_structured()
+            .times(1)
+            .returning(|_, _, _| Err(ApplicationError::RateLimited));
+
+        let result = mock.generate_json::<Forecast>("Forecast for Berlin").await;
+        assert!(matches!(result, Err(ApplicationError::RateLimited)));
+    }
+
+    #[test]
+    fn validate_json_checks_types_and_required_fields() {
+        let schema = schemars::schema_for!(Forecast).to_value();
+        let valid = |value: Value| validate_json(&value, &schema, &schema).is_ok();
+
+        assert!(valid(serde_json::json!({"city": "Berlin", "days": 2})));
+        assert!(!valid(serde_json::json!({"city": "Berlin"})));
+        assert!(!valid(serde_json::json!({"city": 1, "days": 2})));
+        assert!(!valid(serde_json::json!({"city": "Berlin", "days": 1.5})));
+        assert!(!valid(serde_json::json!([])));
+    }
+
+    #[test]
+    fn validate_json_handles_combinators_and_refs() {
+        let schema = serde_json::json!({
+            "anyOf": [
+                { "$ref": "#/$defs/Step" },
+                { "type": "array", "minItems": 1, "items": { "$ref": "#/$defs/Step" } }
+            ],
+            "$defs": {
+                "Step": {
+                    "type": "object",
+                    "properties": { "kind": { "enum": ["a", "b"] } },
+                    "required": ["kind"]
+                }
+            }
+        });
+        let valid = |value: Value| validate_json(&value, &schema, &schema).is_ok();
+
+        assert!(valid(serde_json::json!({"kind": "a"})));
+        assert!(valid(serde_json::json!([{"kind": "a"}, {"kind": "b"}])));
+        assert!(!valid(serde_json::json!([])));
+        assert!(!valid(serde_json::json!({"kind": "c"})));
+        assert!(!valid(serde_json::json!([{"kind": "a"}, {}])));
+    }
+}
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 #[cfg(test)]
 use mockall::automock;
 
-use domain::{Memory, MemoryId, MemoryQuery, MemoryType, UserId};
+use domain::{Memory, MemoryId, MemoryQuery, MemoryType, TenantContext, UserId};
 
 use crate::error::ApplicationError;
 
@@ -47,27 +47,64 @@ pub struct MemoryStats {
     pub avg_importance: f32,
 }
 
+impl MemoryStats {
+    /// Compute statistics over an already loaded set of memories
+    #[must_use]
+    pub fn from_memories(memories: &[Memory]) -> Self {
+        let total_count = memories.len();
+        let by_type = MemoryType::all()
+            .iter()
+            .map(|t| (*t, memories.iter().filter(|m| m.memory_type == *t).count()))
+            .collect();
+        let with_embeddings = memories.iter().filter(|m| m.embedding.is_some()).count();
+        #[allow(clippy::cast_precision_loss)]
+        let avg_importance = if total_count > 0 {
+            memories.iter().map(|m| m.importance).sum::<f32>() / total_count as f32
+        } else {
+            0.0
+        };
+
+        Self {
+            total_count,
+            by_type,
+            with_embeddings,
+            avg_importance,
+        }
+    }
+}
+
 /// Port for memory persistence and retrieval
+///
+/// Memories are isolated per tenant: `save` stores the memory's `tenant_id`
+/// and every other operation is filtered by tenant at the query level, so one
+/// tenant can never read, change or delete another tenant's memories.
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait MemoryStore: Send + Sync {
     /// Save a new memory entry
     async fn save(&self, memory: &Memory) -> Result<(), ApplicationError>;
 
-    /// Get a memory by ID
-    async fn get(&self, id: &MemoryId) -> Result<Option<Memory>, ApplicationError>;
+    /// Get a memory of the given tenant by ID
+    async fn get(
+        &self,
+        tenant: &TenantContext,
+        id: &MemoryId,
+    ) -> Result<Option<Memory>, ApplicationError>;
 
-    /// Update an existing memory
-    async fn update(&self, memory: &Memory) -> Result<(), ApplicationError>;
+    /// Update an existing memory of the given tenant
+    async fn update(&self, tenant: &TenantContext, memory: &Memory)
+    -> Result<(), ApplicationError>;
 
-    /// Delete a memory by ID
-    async fn delete(&self, id: &MemoryId) -> Result<(), ApplicationError>;
+    /// Delete a memory of the given tenant by ID
+    async fn delete(&self, tenant: &TenantContext, id: &MemoryId) -> Result<(), ApplicationError>;
 
     /// Find similar memories using vector search
     ///
-    /// Returns memories sorted by similarity score (highest first).
+    /// Only memories of the given tenant are considered. Returns memories
+    /// sorted by similarity score (highest first).
     async fn search_similar(
         &self,
+        tenant: &TenantContext,
         user_id: &UserId,
         embedding: &[f32],
         limit: usize,
@@ -75,36 +112,61 @@ pub trait MemoryStore: Send + Sync {
     ) -> Result<Vec<SimilarMemory>, ApplicationError>;
 
     /// List memories matching the query criteria
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotAuthorized` if the query carries no
+    /// tenant.
     async fn list(&self, query: &MemoryQuery) -> Result<Vec<Memory>, ApplicationError>;
 
     /// List memories by type for a user
     async fn list_by_type(
         &self,
+        tenant: &TenantContext,
         user_id: &UserId,
         memory_type: MemoryType,
         limit: usize,
     ) -> Result<Vec<Memory>, ApplicationError>;
 
-    /// Apply decay to all memories and return IDs of memories below threshold
+    /// Apply decay to all memories of the tenant and return IDs of memories
+    /// below threshold
     ///
     /// This should update importance scores based on time since last access.
-    async fn apply_decay(&self, decay_rate: f32) -> Result<Vec<MemoryId>, ApplicationError>;
+    async fn apply_decay(
+        &self,
+        tenant: &TenantContext,
+        decay_rate: f32,
+    ) -> Result<Vec<MemoryId>, ApplicationError>;
 
-    /// Delete memories below the importance threshold
-    async fn cleanup_below_threshold(&self, threshold: f32) -> Result<usize, ApplicationError>;
+    /// Delete memories of the tenant below the importance threshold
+    async fn cleanup_below_threshold(
+        &self,
+        tenant: &TenantContext,
+        threshold: f32,
+    ) -> Result<usize, ApplicationError>;
 
-    /// Find memories similar to the given one for potential merging
+    /// Find memories of the same tenant similar to the given one for
+    /// potential merging
     async fn find_merge_candidates(
         &self,
         memory: &Memory,
         similarity_threshold: f32,
     ) -> Result<Vec<SimilarMemory>, ApplicationError>;
 
-    /// Get statistics about stored memories for a user
-    async fn stats(&self, user_id: &UserId) -> Result<MemoryStats, ApplicationError>;
+    /// Get statistics about stored memories for a user of the tenant
+    async fn stats(
+        &self,
+        tenant: &TenantContext,
+        user_id: &UserId,
+    ) -> Result<MemoryStats, ApplicationError>;
 
-    /// Record that a memory was accessed (updates accessed_at and access_count)
-    async fn record_access(&self, id: &MemoryId) -> Result<(), ApplicationError>;
+    /// Record that a memory of the tenant was accessed (updates accessed_at
+    /// and access_count)
+    async fn record_access(
+        &self,
+        tenant: &TenantContext,
+        id: &MemoryId,
+    ) -> Result<(), ApplicationError>;
 }
 
 #[cfg(test)]
@@ -132,4 +194,23 @@ mod tests {
         assert_eq!(stats.with_embeddings, 0);
         assert!(stats.avg_importance.abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn memory_stats_from_memories() {
+        let user_id = UserId::new();
+        let memories = vec![
+            Memory::new(user_id, "a", "a", MemoryType::Fact)
+                .with_importance(0.8)
+                .with_embedding(vec![1.0, 0.0]),
+            Memory::new(user_id, "b", "b", MemoryType::Fact).with_importance(0.4),
+            Memory::new(user_id, "c", "c", MemoryType::Preference).with_importance(0.6),
+        ];
+
+        let stats = MemoryStats::from_memories(&memories);
+        assert_eq!(stats.total_count, 3);
+        assert_eq!(stats.with_embeddings, 1);
+        assert!((stats.avg_importance - 0.6).abs() < 0.001);
+        assert!(stats.by_type.contains(&(MemoryType::Fact, 2)));
+        assert!(stats.by_type.contains(&(MemoryType::Preference, 1)));
+    }
 }
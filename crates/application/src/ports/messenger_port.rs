@@ -4,15 +4,33 @@
 //! like WhatsApp and Signal, providing a common interface for sending
 //! text and audio messages.
 
+use std::{
+    fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+    time::Duration,
+};
+
 #[cfg(test)]
 use mockall::automock;
 
 use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
 use domain::{MessengerSource, PhoneNumber};
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use tracing::debug;
 
 use crate::error::ApplicationError;
 
+/// Default number of messages a broadcast sends at the same time
+pub const DEFAULT_BROADCAST_CONCURRENCY: usize = 4;
+
+/// Send attempts per recipient when the platform reports a rate limit
+const BROADCAST_RATE_LIMIT_ATTEMPTS: u32 = 3;
+
+/// Wait after the first rate-limited send, doubled for each further attempt
+const BROADCAST_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(1);
+
 /// An incoming text message from any messaging platform
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncomingTextMessage {
@@ -57,36 +75,114 @@ pub struct OutgoingTextMessage {
     /// Optional: reply to a specific message ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<String>,
+    /// Key under which adapters deduplicate retried sends
+    ///
+    /// Set on creation to a hash of recipient, text and the current minute
+    /// and kept when the message is queued for retry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// What caused the message, e.g. `reminder:<id>`, kept while it is queued
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// Until when the message is still relevant
+    ///
+    /// Queued retries are dropped instead of delivered once this has passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<DateTime<Utc>>,
 }
 
 impl OutgoingTextMessage {
     /// Create a new outgoing text message
     #[must_use]
     pub fn new(recipient: PhoneNumber, text: impl Into<String>) -> Self {
-        Self {
-            recipient,
-            text: text.into(),
-            reply_to: None,
-        }
+        Self::build(recipient, text.into(), None)
     }
 
     /// Create a reply to an incoming text message
     #[must_use]
     pub fn reply_to_text(incoming: &IncomingTextMessage, text: impl Into<String>) -> Self {
-        Self {
-            recipient: incoming.sender.clone(),
-            text: text.into(),
-            reply_to: Some(incoming.message_id.clone()),
-        }
+        Self::build(
+            incoming.sender.clone(),
+            text.into(),
+            Some(incoming.message_id.clone()),
+        )
     }
 
     /// Create a reply to an incoming audio message
     #[must_use]
     pub fn reply_to_audio(incoming: &IncomingAudioMessage, text: impl Into<String>) -> Self {
+        Self::build(
+            incoming.sender.clone(),
+            text.into(),
+            Some(incoming.message_id.clone()),
+        )
+    }
+
+    /// Use a caller-supplied idempotency key instead of the default
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Record what caused the message
+    #[must_use]
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    /// Stop delivering the message after `valid_until`
+    #[must_use]
+    pub const fn with_valid_until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Whether the message is no longer worth delivering at `now`
+    #[must_use]
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.valid_until
+            .is_some_and(|valid_until| now > valid_until)
+    }
+
+    /// The key a retried send of this message is deduplicated by
+    ///
+    /// Messages deserialized without a key fall back to the default key for
+    /// the current minute.
+    #[must_use]
+    pub fn idempotency_key(&self) -> String {
+        self.idempotency_key.clone().unwrap_or_else(|| {
+            Self::default_idempotency_key(&self.recipient, &self.text, Utc::now())
+        })
+    }
+
+    /// Default idempotency key: a hash of recipient, text and minute bucket
+    ///
+    /// The same text to the same recipient within one minute is treated as
+    /// the same message.
+    #[must_use]
+    pub fn default_idempotency_key(
+        recipient: &PhoneNumber,
+        text: &str,
+        at: DateTime<Utc>,
+    ) -> String {
+        let mut hasher = DefaultHasher::new();
+        recipient.as_str().hash(&mut hasher);
+        text.hash(&mut hasher);
+        (at.timestamp() / 60).hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    fn build(recipient: PhoneNumber, text: String, reply_to: Option<String>) -> Self {
+        let key = Self::default_idempotency_key(&recipient, &text, Utc::now());
         Self {
-            recipient: incoming.sender.clone(),
-            text: text.into(),
-            reply_to: Some(incoming.message_id.clone()),
+            recipient,
+            text,
+            reply_to,
+            idempotency_key: Some(key),
+            context: None,
+            valid_until: None,
         }
     }
 }
@@ -132,6 +228,47 @@ impl OutgoingAudioMessage {
     }
 }
 
+/// An outgoing message built from a template registered with the platform
+///
+/// WhatsApp only delivers free-form text within a session window after the
+/// contact's last message; outside of it only pre-approved templates can be
+/// sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingTemplateMessage {
+    /// Recipient's phone number
+    pub recipient: PhoneNumber,
+    /// Template name as registered with the platform
+    pub name: String,
+    /// Template language code (e.g., "en_US")
+    pub language: String,
+    /// Values for the template's body placeholders, in order
+    pub parameters: Vec<String>,
+}
+
+impl OutgoingTemplateMessage {
+    /// Create a template message without placeholder values
+    #[must_use]
+    pub fn new(
+        recipient: PhoneNumber,
+        name: impl Into<String>,
+        language: impl Into<String>,
+    ) -> Self {
+        Self {
+            recipient,
+            name: name.into(),
+            language: language.into(),
+            parameters: Vec::new(),
+        }
+    }
+
+    /// Fill the template's body placeholders
+    #[must_use]
+    pub fn with_parameters(mut self, parameters: Vec<String>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+}
+
 /// Result of downloading audio from a messenger platform
 #[derive(Debug, Clone)]
 pub struct DownloadedAudio {
@@ -141,6 +278,49 @@ pub struct DownloadedAudio {
     pub mime_type: String,
 }
 
+/// Outcome of a broadcast, grouped into sent and failed recipients
+#[derive(Debug)]
+pub struct BroadcastReport {
+    /// Recipients the message was sent to
+    pub sent: Vec<String>,
+    /// Recipients the message could not be sent to, with the reason
+    pub failed: Vec<(String, ApplicationError)>,
+}
+
+impl BroadcastReport {
+    /// Pair the results of [`MessengerPort::broadcast`] with their recipients
+    #[must_use]
+    pub fn new(recipients: &[String], results: Vec<Result<(), ApplicationError>>) -> Self {
+        let mut sent = Vec::new();
+        let mut failed = Vec::new();
+        for (recipient, result) in recipients.iter().zip(results) {
+            match result {
+                Ok(()) => sent.push(recipient.clone()),
+                Err(e) => failed.push((recipient.clone(), e)),
+            }
+        }
+        Self { sent, failed }
+    }
+
+    /// Whether the message reached every recipient
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for BroadcastReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.sent.len() + self.failed.len();
+        write!(f, "sent to {} of {total} recipients", self.sent.len())?;
+        for (i, (recipient, error)) in self.failed.iter().enumerate() {
+            let separator = if i == 0 { "; failed: " } else { ", " };
+            write!(f, "{separator}{recipient} ({error})")?;
+        }
+        Ok(())
+    }
+}
+
 /// Unified port for messaging platform operations
 ///
 /// Implementations of this trait provide messaging capabilities for
@@ -175,6 +355,82 @@ pub trait MessengerPort: Send + Sync {
 
     /// Mark a message as read/processed
     async fn mark_read(&self, message_id: &str) -> Result<(), ApplicationError>;
+
+    /// How long after the contact's last message free-form text is delivered
+    ///
+    /// `None` if the platform has no session window.
+    fn session_window(&self) -> Option<TimeDelta> {
+        None
+    }
+
+    /// Send a message built from a template registered with the platform
+    ///
+    /// Returns the platform's message ID for the sent message. Platforms
+    /// without templates fail with [`ApplicationError::InvalidOperation`].
+    async fn send_template(
+        &self,
+        message: OutgoingTemplateMessage,
+    ) -> Result<String, ApplicationError> {
+        Err(ApplicationError::InvalidOperation(format!(
+            "{} does not support template messages (template '{}')",
+            self.source().display_name(),
+            message.name
+        )))
+    }
+
+    /// Maximum number of messages [`broadcast`](Self::broadcast) sends at the same time
+    fn broadcast_concurrency(&self) -> usize {
+        DEFAULT_BROADCAST_CONCURRENCY
+    }
+
+    /// Send the same text message to several recipients
+    ///
+    /// Returns one result per recipient, in the given order. Invalid and
+    /// non-whitelisted numbers fail without sending; rate-limited sends are
+    /// retried with backoff. Use [`BroadcastReport`] to summarize the results.
+    async fn broadcast(
+        &self,
+        recipients: &[String],
+        text: &str,
+    ) -> Vec<Result<(), ApplicationError>> {
+        futures::stream::iter(recipients)
+            .map(|recipient| send_broadcast_message(self, recipient, text))
+            .buffered(self.broadcast_concurrency().max(1))
+            .collect()
+            .await
+    }
+}
+
+/// Send one message of a broadcast
+async fn send_broadcast_message<M: MessengerPort + ?Sized>(
+    messenger: &M,
+    recipient: &str,
+    text: &str,
+) -> Result<(), ApplicationError> {
+    let phone = PhoneNumber::new(recipient)?;
+    if !messenger.is_whitelisted(&phone).await {
+        return Err(ApplicationError::NotAuthorized(format!(
+            "{phone} is not whitelisted"
+        )));
+    }
+
+    let mut backoff = BROADCAST_RATE_LIMIT_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        match messenger
+            .send_text(OutgoingTextMessage::new(phone.clone(), text))
+            .await
+        {
+            Ok(_) => return Ok(()),
+            Err(ApplicationError::RateLimited) if attempt < BROADCAST_RATE_LIMIT_ATTEMPTS => {
+                debug!(recipient = %phone, attempt, backoff_ms = backoff.as_millis(), "Broadcast rate limited, backing off");
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            },
+            Err(e) => return Err(e),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -300,6 +556,67 @@ mod tests {
             let json = serde_json::to_string(&msg).unwrap();
             assert!(!json.contains("reply_to"));
         }
+
+        #[test]
+        fn default_idempotency_key_uses_minute_bucket() {
+            let phone = test_phone();
+            let at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+            let key = OutgoingTextMessage::default_idempotency_key(&phone, "Hi", at);
+
+            let same_minute = at + chrono::Duration::seconds(10);
+            let next_minute = at + chrono::Duration::seconds(60);
+            assert_eq!(
+                key,
+                OutgoingTextMessage::default_idempotency_key(&phone, "Hi", same_minute)
+            );
+            assert_ne!(
+                key,
+                OutgoingTextMessage::default_idempotency_key(&phone, "Hi", next_minute)
+            );
+            assert_ne!(
+                key,
+                OutgoingTextMessage::default_idempotency_key(&phone, "Hello", at)
+            );
+        }
+
+        #[test]
+        fn idempotency_key_survives_serialization() {
+            let msg = OutgoingTextMessage::new(test_phone(), "Hi").with_idempotency_key("key-1");
+            let json = serde_json::to_string(&msg).unwrap();
+            let restored: OutgoingTextMessage = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.idempotency_key(), "key-1");
+        }
+
+        #[test]
+        fn messages_without_key_get_default() {
+            let json = r#"{"recipient": "+491234567890", "text": "Hi"}"#;
+            let msg: OutgoingTextMessage = serde_json::from_str(json).unwrap();
+            assert!(msg.idempotency_key.is_none());
+            assert_eq!(msg.idempotency_key().len(), 16);
+        }
+
+        #[test]
+        fn validity_window() {
+            let now = Utc::now();
+            let msg = OutgoingTextMessage::new(test_phone(), "Hi");
+            assert!(!msg.is_expired_at(now));
+
+            let msg = msg.with_valid_until(now);
+            assert!(!msg.is_expired_at(now));
+            assert!(msg.is_expired_at(now + chrono::Duration::seconds(1)));
+        }
+
+        #[test]
+        fn context_and_validity_survive_serialization() {
+            let valid_until = Utc::now();
+            let msg = OutgoingTextMessage::new(test_phone(), "Hi")
+                .with_context("reminder:42")
+                .with_valid_until(valid_until);
+            let json = serde_json::to_string(&msg).unwrap();
+            let restored: OutgoingTextMessage = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.context.as_deref(), Some("reminder:42"));
+            assert_eq!(restored.valid_until, Some(valid_until));
+        }
     }
 
     mod outgoing_audio_message_tests {
@@ -355,4 +672,180 @@ mod tests {
             assert_eq!(audio.data, cloned.data);
         }
     }
+
+    mod broadcast_tests {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use parking_lot::Mutex;
+
+        use super::*;
+
+        /// Messenger that records sends and fails for configured numbers
+        #[derive(Default)]
+        struct FakeMessenger {
+            whitelist: Vec<&'static str>,
+            failing: Vec<&'static str>,
+            rate_limits: AtomicUsize,
+            sent: Mutex<Vec<String>>,
+        }
+
+        #[async_trait]
+        impl MessengerPort for FakeMessenger {
+            fn source(&self) -> MessengerSource {
+                MessengerSource::Signal
+            }
+
+            async fn is_available(&self) -> bool {
+                true
+            }
+
+            async fn is_whitelisted(&self, phone: &PhoneNumber) -> bool {
+                self.whitelist.is_empty() || self.whitelist.iter().any(|n| *n == phone.as_str())
+            }
+
+            async fn send_text(
+                &self,
+                message: OutgoingTextMessage,
+            ) -> Result<String, ApplicationError> {
+                if self
+                    .rate_limits
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                    .is_ok()
+                {
+                    return Err(ApplicationError::RateLimited);
+                }
+                if self
+                    .failing
+                    .iter()
+                    .any(|n| *n == message.recipient.as_str())
+                {
+                    return Err(ApplicationError::ExternalService("send failed".to_string()));
+                }
+                self.sent.lock().push(message.recipient.to_string());
+                Ok("1".to_string())
+            }
+
+            async fn send_audio(
+                &self,
+                _message: OutgoingAudioMessage,
+            ) -> Result<String, ApplicationError> {
+                Err(ApplicationError::InvalidOperation(
+                    "audio is not supported by the fake messenger".to_string(),
+                ))
+            }
+
+            async fn download_audio(
+                &self,
+                _media_id: &str,
+            ) -> Result<DownloadedAudio, ApplicationError> {
+                Err(ApplicationError::InvalidOperation(
+                    "audio is not supported by the fake messenger".to_string(),
+                ))
+            }
+
+            async fn mark_read(&self, _message_id: &str) -> Result<(), ApplicationError> {
+                Ok(())
+            }
+        }
+
+        fn recipients(numbers: &[&str]) -> Vec<String> {
+            numbers.iter().map(ToString::to_string).collect()
+        }
+
+        #[tokio::test]
+        async fn sends_to_all_recipients() {
+            let messenger = FakeMessenger::default();
+            let recipients = recipients(&["+491111111111", "+492222222222", "+493333333333"]);
+
+            let results = messenger.broadcast(&recipients, "Dinner's ready").await;
+
+            assert_eq!(results.len(), 3);
+            assert!(results.iter().all(Result::is_ok));
+            let mut sent = messenger.sent.lock().clone();
+            sent.sort();
+            assert_eq!(sent, recipients);
+        }
+
+        #[tokio::test]
+        async fn reports_failures_per_recipient() {
+            let messenger = FakeMessenger {
+                whitelist: vec!["+491111111111", "+492222222222"],
+                failing: vec!["+492222222222"],
+                ..FakeMessenger::default()
+            };
+            let recipients =
+                recipients(&["+491111111111", "+492222222222", "+499999999999", "invalid"]);
+
+            let results = messenger.broadcast(&recipients, "Hi").await;
+
+            assert!(results[0].is_ok());
+            assert!(matches!(
+                results[1],
+                Err(ApplicationError::ExternalService(_))
+            ));
+            assert!(matches!(
+                results[2],
+                Err(ApplicationError::NotAuthorized(_))
+            ));
+            assert!(matches!(results[3], Err(ApplicationError::Domain(_))));
+            assert_eq!(*messenger.sent.lock(), vec!["+491111111111"]);
+
+            let report = BroadcastReport::new(&recipients, results);
+            assert!(!report.is_complete());
+            assert_eq!(report.sent, vec!["+491111111111"]);
+            assert_eq!(report.failed.len(), 3);
+            let summary = report.to_string();
+            assert!(summary.starts_with("sent to 1 of 4 recipients; failed: +492222222222 ("));
+            assert!(summary.contains("+499999999999 (Not authorized"));
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn retries_rate_limited_sends() {
+            let messenger = FakeMessenger {
+                rate_limits: AtomicUsize::new(2),
+                ..FakeMessenger::default()
+            };
+
+            let results = messenger
+                .broadcast(&recipients(&["+491111111111"]), "Hi")
+                .await;
+
+            assert!(results[0].is_ok());
+            assert_eq!(messenger.rate_limits.load(Ordering::SeqCst), 0);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn gives_up_after_repeated_rate_limits() {
+            let messenger = FakeMessenger {
+                rate_limits: AtomicUsize::new(10),
+                ..FakeMessenger::default()
+            };
+
+            let results = messenger
+                .broadcast(&recipients(&["+491111111111"]), "Hi")
+                .await;
+
+            assert!(matches!(results[0], Err(ApplicationError::RateLimited)));
+            assert!(messenger.sent.lock().is_empty());
+        }
+
+        #[test]
+        fn complete_report() {
+            let report = BroadcastReport::new(&recipients(&["+491111111111"]), vec![Ok(())]);
+            assert!(report.is_complete());
+            assert_eq!(report.to_string(), "sent to 1 of 1 recipients");
+        }
+
+        #[tokio::test]
+        async fn templates_are_unsupported_by_default() {
+            let messenger = FakeMessenger::default();
+            let template = OutgoingTemplateMessage::new(test_phone(), "daily_digest", "en_US")
+                .with_parameters(vec!["Friday".to_string()]);
+
+            let result = messenger.send_template(template).await;
+
+            assert!(matches!(result, Err(ApplicationError::InvalidOperation(_))));
+            assert!(messenger.session_window().is_none());
+        }
+    }
 }
@@ -10,8 +10,12 @@ mod calendar_port;
 mod contact_port;
 mod conversation_store;
 mod database_health_port;
+mod degraded_mode_port;
+mod delivery_status_port;
+mod digest_template_port;
 mod draft_store;
 mod email_port;
+mod email_template_port;
 mod embedding_port;
 mod encryption_port;
 mod inference_port;
@@ -19,11 +23,15 @@ mod memory_store;
 mod message_gateway_port;
 mod messenger_port;
 mod model_registry_port;
+mod prompt_template_port;
 mod reminder_port;
+mod retry_queue_port;
 mod secret_store;
 mod speech_port;
 mod suspicious_activity_port;
+mod system_prompt_store;
 mod task_port;
+mod timer_port;
 mod transit_port;
 mod user_profile_store;
 mod weather_port;
@@ -37,23 +45,42 @@ pub use calendar_port::{CalendarError, CalendarEvent, CalendarInfo, CalendarPort
 pub use contact_port::MockContactPort;
 pub use contact_port::{
     AddressbookInfo, ContactDetail, ContactError, ContactPort, ContactSummary, ContactUpdate,
-    NewContact,
+    ImportEntryError, ImportResult, NewContact,
 };
-pub use conversation_store::ConversationStore;
+pub use conversation_store::{ConversationStore, ExportFormat};
 #[cfg(test)]
 pub use database_health_port::MockDatabaseHealthPort;
 pub use database_health_port::{DatabaseHealth, DatabaseHealthPort};
+pub use degraded_mode_port::DegradedModePort;
+#[cfg(test)]
+pub use degraded_mode_port::MockDegradedModePort;
+#[cfg(test)]
+pub use delivery_status_port::MockDeliveryStatusPort;
+pub use delivery_status_port::{
+    DeliveryStatusPort, DeliveryUpdate, MessageDelivery, UndeliveredReminder,
+};
+#[cfg(test)]
+pub use digest_template_port::MockDigestTemplatePort;
+pub use digest_template_port::{DigestTemplateData, DigestTemplatePort};
 pub use draft_store::DraftStorePort;
 #[cfg(test)]
 pub use draft_store::MockDraftStorePort;
 pub use email_port::{EmailDraft, EmailError, EmailPort, EmailSummary};
 #[cfg(test)]
+pub use email_template_port::MockEmailTemplatePort;
+pub use email_template_port::{EmailTemplateData, EmailTemplatePort, RenderedEmail};
+#[cfg(test)]
 pub use embedding_port::MockEmbeddingPort;
 pub use embedding_port::{EmbeddingModelInfo, EmbeddingPort};
 #[cfg(test)]
 pub use encryption_port::MockEncryptionPort;
 pub use encryption_port::{EncryptionPort, NoOpEncryption};
-pub use inference_port::{InferencePort, InferenceResult, InferenceStream, StreamingChunk};
+#[cfg(test)]
+pub(crate) use inference_port::extract_json;
+pub use inference_port::{
+    InferenceJsonExt, InferencePort, InferenceResult, InferenceStream, JSON_REPAIR_ATTEMPTS,
+    StreamingChunk, cancellable, cancellable_stream,
+};
 #[cfg(test)]
 pub use memory_store::MockMemoryStore;
 pub use memory_store::{MemoryStats, MemoryStore, SimilarMemory};
@@ -61,13 +88,20 @@ pub use message_gateway_port::{IncomingMessage, MessageGatewayPort, OutgoingMess
 #[cfg(test)]
 pub use messenger_port::MockMessengerPort;
 pub use messenger_port::{
-    DownloadedAudio, IncomingAudioMessage, IncomingTextMessage, MessengerPort,
-    OutgoingAudioMessage, OutgoingTextMessage,
+    BroadcastReport, DEFAULT_BROADCAST_CONCURRENCY, DownloadedAudio, IncomingAudioMessage,
+    IncomingTextMessage, MessengerPort, OutgoingAudioMessage, OutgoingTemplateMessage,
+    OutgoingTextMessage,
 };
 pub use model_registry_port::{ModelCapabilities, ModelCapability, ModelInfo, ModelRegistryPort};
 #[cfg(test)]
+pub use prompt_template_port::MockPromptTemplatePort;
+pub use prompt_template_port::{PromptContext, PromptTemplatePort};
+#[cfg(test)]
 pub use reminder_port::MockReminderPort;
 pub use reminder_port::{ReminderPort, ReminderQuery};
+#[cfg(test)]
+pub use retry_queue_port::MockRetryQueuePort;
+pub use retry_queue_port::{DeadLetter, QueueStats, QueuedMessage, RetryQueuePort};
 pub use secret_store::{SecretStoreExt, SecretStorePort};
 #[cfg(test)]
 pub use speech_port::MockSpeechPort;
@@ -75,12 +109,19 @@ pub use speech_port::{SpeechPort, SynthesisResult, TranscriptionResult, VoiceCon
 #[cfg(test)]
 pub use suspicious_activity_port::MockSuspiciousActivityPort;
 pub use suspicious_activity_port::{
-    SuspiciousActivityConfig, SuspiciousActivityPort, ViolationRecord, ViolationSummary,
+    BlockedEntry, MANUAL_BLOCK_REASON, SuspiciousActivityConfig, SuspiciousActivityPort,
+    ViolationRecord, ViolationSummary,
 };
 #[cfg(test)]
+pub use system_prompt_store::MockSystemPromptStore;
+pub use system_prompt_store::{PromptOverrideTarget, SystemPromptStore};
+#[cfg(test)]
 pub use task_port::MockTaskPort;
 pub use task_port::{NewTask, Task, TaskListInfo, TaskPort, TaskQuery, TaskStatus, TaskUpdates};
 #[cfg(test)]
+pub use timer_port::MockTimerPort;
+pub use timer_port::TimerPort;
+#[cfg(test)]
 pub use transit_port::MockTransitPort;
 pub use transit_port::{
     TransitConnection, TransitLeg, TransitMode, TransitPort, TransitQuery, format_connections,
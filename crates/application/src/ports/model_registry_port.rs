@@ -20,6 +20,9 @@ pub struct ModelInfo {
     pub capabilities: ModelCapabilities,
     /// Model size/variant
     pub variant: Option<String>,
+    /// Parameter count as reported by the backend (e.g. `1.5B`)
+    #[serde(default)]
+    pub parameter_size: Option<String>,
     /// Whether this model is currently available
     pub available: bool,
 }
@@ -36,10 +39,37 @@ pub struct ModelCapabilities {
     pub embeddings: bool,
     /// Supports code completion
     pub code: bool,
+    /// Supports tool/function calling
+    #[serde(default)]
+    pub tools: bool,
+    /// Accepts image input
+    #[serde(default)]
+    pub vision: bool,
     /// Context window size (tokens)
     pub context_length: Option<u32>,
 }
 
+impl ModelCapabilities {
+    /// Check whether a capability is advertised
+    #[must_use]
+    pub const fn supports(&self, capability: ModelCapability) -> bool {
+        match capability {
+            ModelCapability::TextGeneration => self.text_generation,
+            ModelCapability::Chat => self.chat,
+            ModelCapability::Embeddings => self.embeddings,
+            ModelCapability::Code => self.code,
+            ModelCapability::ToolCalling => self.tools,
+            ModelCapability::Vision => self.vision,
+        }
+    }
+
+    /// Check whether every listed capability is advertised
+    #[must_use]
+    pub fn supports_all(&self, capabilities: &[ModelCapability]) -> bool {
+        capabilities.iter().all(|c| self.supports(*c))
+    }
+}
+
 /// Port for model registry operations
 #[async_trait]
 pub trait ModelRegistryPort: Send + Sync {
@@ -63,15 +93,9 @@ pub trait ModelRegistryPort: Send + Sync {
         capability: ModelCapability,
     ) -> Result<Option<ModelInfo>, ApplicationError> {
         let models = self.list_models().await?;
-        Ok(models.into_iter().find(|m| {
-            m.available
-                && match capability {
-                    ModelCapability::TextGeneration => m.capabilities.text_generation,
-                    ModelCapability::Chat => m.capabilities.chat,
-                    ModelCapability::Embeddings => m.capabilities.embeddings,
-                    ModelCapability::Code => m.capabilities.code,
-                }
-        }))
+        Ok(models
+            .into_iter()
+            .find(|m| m.available && m.capabilities.supports(capability)))
     }
 }
 
@@ -86,6 +110,10 @@ pub enum ModelCapability {
     Embeddings,
     /// Code completion/generation
     Code,
+    /// Tool/function calling
+    ToolCalling,
+    /// Image input
+    Vision,
 }
 
 #[cfg(test)]
@@ -107,9 +135,25 @@ mod tests {
         assert!(!caps.chat);
         assert!(!caps.embeddings);
         assert!(!caps.code);
+        assert!(!caps.tools);
+        assert!(!caps.vision);
         assert!(caps.context_length.is_none());
     }
 
+    #[test]
+    fn model_capabilities_supports_all() {
+        let caps = ModelCapabilities {
+            chat: true,
+            tools: true,
+            ..Default::default()
+        };
+
+        assert!(caps.supports(ModelCapability::ToolCalling));
+        assert!(caps.supports_all(&[ModelCapability::Chat, ModelCapability::ToolCalling]));
+        assert!(!caps.supports_all(&[ModelCapability::Chat, ModelCapability::Vision]));
+        assert!(caps.supports_all(&[]));
+    }
+
     #[test]
     fn model_info_creation() {
         let model = ModelInfo {
@@ -122,6 +166,7 @@ mod tests {
                 ..Default::default()
             },
             variant: Some("8B".to_string()),
+            parameter_size: Some("8.0B".to_string()),
             available: true,
         };
 
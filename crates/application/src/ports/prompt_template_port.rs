@@ -0,0 +1,30 @@
+//! Prompt template port
+//!
+//! Defines the interface for rendering the assistant's system prompt from a
+//! template with per-request variables.
+
+use chrono::NaiveDate;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::ApplicationError;
+
+/// Variables available to the system prompt template
+///
+/// Optional fields are left out of the prompt when unset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptContext {
+    /// Name the assistant addresses the user by
+    pub user_name: Option<String>,
+    /// Today's date in the user's timezone
+    pub today: NaiveDate,
+    /// The user's location, human readable
+    pub location: Option<String>,
+}
+
+/// Port for rendering the system prompt
+#[cfg_attr(test, automock)]
+pub trait PromptTemplatePort: Send + Sync {
+    /// Render the system prompt for the given context
+    fn render_system_prompt(&self, context: &PromptContext) -> Result<String, ApplicationError>;
+}
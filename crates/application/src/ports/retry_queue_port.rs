@@ -0,0 +1,134 @@
+//! Retry queue port
+//!
+//! Outgoing messages that could not be delivered are queued here and
+//! redelivered by a background worker. Messages that exhaust their retries
+//! end up in the dead letter queue, where operators can inspect and requeue
+//! them. Queue statistics let operators watch the backlog grow or drain.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+#[cfg(test)]
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::ApplicationError, ports::OutgoingTextMessage};
+
+/// Retry queue statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueStats {
+    /// Number of pending items
+    pub pending: u64,
+    /// Number of items currently being processed
+    pub in_progress: u64,
+    /// Number of items in dead letter queue
+    pub dead_letter: u64,
+}
+
+impl QueueStats {
+    /// Number of items still waiting to be delivered (pending + in progress)
+    #[must_use]
+    pub const fn backlog(&self) -> u64 {
+        self.pending.saturating_add(self.in_progress)
+    }
+}
+
+/// An outgoing message due for redelivery
+#[derive(Debug, Clone)]
+pub struct QueuedMessage {
+    /// Retry queue item ID
+    pub id: String,
+    /// The message to send
+    pub message: OutgoingTextMessage,
+    /// Delivery attempts made by the retry worker so far
+    pub attempt_count: u32,
+}
+
+/// A permanently failed operation in the dead letter queue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Dead letter ID, used to requeue the item
+    pub id: String,
+    /// ID the item had in the retry queue
+    pub original_id: String,
+    /// Type of operation (e.g. "message")
+    pub operation_type: String,
+    /// Target endpoint or recipient
+    pub target: String,
+    /// Number of attempts made before giving up
+    pub attempt_count: u32,
+    /// Error of the final attempt
+    pub last_error: Option<String>,
+    /// When the item was first queued
+    pub created_at: DateTime<Utc>,
+    /// When the item was moved to the dead letter queue
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Port for the persistent retry queue
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RetryQueuePort: Send + Sync {
+    /// Get current queue statistics
+    async fn queue_stats(&self) -> Result<QueueStats, ApplicationError>;
+
+    /// Queue a message whose delivery failed with `error`
+    ///
+    /// Returns the ID of the queued item.
+    async fn enqueue_message(
+        &self,
+        message: &OutgoingTextMessage,
+        error: &str,
+    ) -> Result<String, ApplicationError>;
+
+    /// Claim up to `limit` messages that are due for redelivery
+    ///
+    /// Claimed messages must be reported back with [`complete`](Self::complete)
+    /// or [`fail`](Self::fail).
+    async fn due_messages(&self, limit: usize) -> Result<Vec<QueuedMessage>, ApplicationError>;
+
+    /// Remove a successfully redelivered item from the queue
+    async fn complete(&self, id: &str) -> Result<(), ApplicationError>;
+
+    /// Record a failed attempt
+    ///
+    /// Returns `true` if the item will be retried, `false` if it exhausted
+    /// its retries and was moved to the dead letter queue.
+    async fn fail(&self, id: &str, error: &str) -> Result<bool, ApplicationError>;
+
+    /// List up to `limit` dead-lettered items, most recently failed first
+    async fn list_dead_letters(&self, limit: usize) -> Result<Vec<DeadLetter>, ApplicationError>;
+
+    /// Move a dead-lettered item back to the retry queue
+    ///
+    /// Returns the ID of the requeued retry item.
+    async fn requeue(&self, dead_letter_id: &str) -> Result<String, ApplicationError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _assert_object_safe(_: &dyn RetryQueuePort) {}
+
+    #[test]
+    fn backlog_sums_pending_and_in_progress() {
+        let stats = QueueStats {
+            pending: 3,
+            in_progress: 2,
+            dead_letter: 7,
+        };
+        assert_eq!(stats.backlog(), 5);
+    }
+
+    #[test]
+    fn queue_stats_serialization() {
+        let stats = QueueStats {
+            pending: 1,
+            in_progress: 0,
+            dead_letter: 4,
+        };
+        let json = serde_json::to_string(&stats).unwrap();
+        let parsed: QueueStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, stats);
+    }
+}
@@ -48,6 +48,14 @@ pub trait SecretStorePort: Send + Sync {
 
     /// Check if the secret store is healthy and accessible
     async fn is_healthy(&self) -> bool;
+
+    /// Read a JSON secret from the backend, bypassing and updating any cache
+    ///
+    /// Used to pick up a rotated secret. Stores without a cache read it as
+    /// usual.
+    async fn refresh_json(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+        self.get_json(path).await
+    }
 }
 
 /// Extension trait for typed secret retrieval
@@ -36,15 +36,18 @@ pub struct SynthesisResult {
 pub struct VoiceConfig {
     /// Voice identifier (e.g., "nova", "alloy")
     pub voice_id: String,
-    /// Speech speed (0.25 - 4.0, default 1.0)
-    pub speed: f32,
+    /// Speaking rate relative to the voice's natural pace (0.25 - 4.0, default 1.0)
+    pub rate: f32,
+    /// Pitch relative to the voice's natural pitch (0.5 - 2.0, default 1.0)
+    pub pitch: f32,
 }
 
 impl Default for VoiceConfig {
     fn default() -> Self {
         Self {
             voice_id: "nova".to_string(),
-            speed: 1.0,
+            rate: 1.0,
+            pitch: 1.0,
         }
     }
 }
@@ -60,6 +63,8 @@ pub struct VoiceInfo {
     pub description: Option<String>,
     /// Language codes this voice supports
     pub languages: Vec<String>,
+    /// Voice gender ("male", "female" or "neutral"), if known
+    pub gender: Option<String>,
 }
 
 /// Port for speech processing operations
@@ -117,7 +122,8 @@ mod tests {
     fn voice_config_default() {
         let config = VoiceConfig::default();
         assert_eq!(config.voice_id, "nova");
-        assert!((config.speed - 1.0).abs() < f32::EPSILON);
+        assert!((config.rate - 1.0).abs() < f32::EPSILON);
+        assert!((config.pitch - 1.0).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -151,6 +157,7 @@ mod tests {
             name: "Nova".to_string(),
             description: Some("A warm voice".to_string()),
             languages: vec!["en".to_string(), "de".to_string()],
+            gender: Some("female".to_string()),
         };
         assert_eq!(info.id, "nova");
         assert_eq!(info.languages.len(), 2);
@@ -228,6 +235,7 @@ mod tests {
                 name: "Nova".to_string(),
                 description: None,
                 languages: vec!["en".to_string()],
+                gender: None,
             }])
         });
 
@@ -41,6 +41,28 @@ impl ViolationRecord {
         self.details = Some(details.into());
         self
     }
+
+    /// Reason stored with a block triggered by this violation
+    #[must_use]
+    pub fn block_reason(&self) -> String {
+        format!("{} ({})", self.category, self.threat_level)
+    }
+}
+
+/// Reason stored with blocks created via [`SuspiciousActivityPort::block_ip`]
+pub const MANUAL_BLOCK_REASON: &str = "manual";
+
+/// An active block of an IP address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockedEntry {
+    /// Blocked IP address
+    pub ip: IpAddr,
+    /// Why the IP was blocked (violation category and level, or `manual`)
+    pub reason: String,
+    /// Number of recorded violations for the IP
+    pub violation_count: u32,
+    /// When the block expires
+    pub blocked_until: DateTime<Utc>,
 }
 
 /// Summary of violations for an identifier
@@ -110,6 +132,16 @@ pub trait SuspiciousActivityPort: Send + Sync {
     /// Get all currently blocked IPs
     async fn get_blocked_ips(&self) -> Vec<IpAddr>;
 
+    /// Get all active blocks with reason, violation count and expiry,
+    /// soonest expiry first
+    async fn list_blocked(&self) -> Vec<BlockedEntry>;
+
+    /// Lift the block of an IP address and forget its violations
+    ///
+    /// Clearing the violations keeps a false positive from re-blocking the
+    /// IP on its next flagged message. Returns `true` if the IP was blocked.
+    async fn unblock(&self, ip: IpAddr) -> bool;
+
     /// Cleanup expired blocks and old violations
     async fn cleanup_expired(&self);
 }
@@ -128,7 +160,7 @@ mod mock {
     #[derive(Debug, Clone, Default)]
     pub struct MockSuspiciousActivityPort {
         violations: Arc<Mutex<HashMap<IpAddr, Vec<ViolationRecord>>>>,
-        blocked: Arc<Mutex<HashMap<IpAddr, DateTime<Utc>>>>,
+        blocked: Arc<Mutex<HashMap<IpAddr, (DateTime<Utc>, String)>>>,
         config: SuspiciousActivityConfig,
     }
 
@@ -177,7 +209,9 @@ mod mock {
                 drop(violations);
                 let expires =
                     Utc::now() + chrono::Duration::seconds(self.config.block_duration_secs as i64);
-                self.blocked.lock().insert(ip, expires);
+                self.blocked
+                    .lock()
+                    .insert(ip, (expires, violation.block_reason()));
             }
         }
 
@@ -186,8 +220,11 @@ mod mock {
             let blocked = self.blocked.lock();
 
             let records = violations.get(&ip);
-            let is_blocked = blocked.get(&ip).is_some_and(|&exp| exp > Utc::now());
-            let block_expires_at = blocked.get(&ip).filter(|&&exp| exp > Utc::now()).copied();
+            let block_expires_at = blocked
+                .get(&ip)
+                .map(|(exp, _)| *exp)
+                .filter(|exp| *exp > Utc::now());
+            let is_blocked = block_expires_at.is_some();
 
             match records {
                 Some(recs) if !recs.is_empty() => {
@@ -218,12 +255,14 @@ mod mock {
             self.blocked
                 .lock()
                 .get(&ip)
-                .is_some_and(|&exp| exp > Utc::now())
+                .is_some_and(|(exp, _)| *exp > Utc::now())
         }
 
         async fn block_ip(&self, ip: IpAddr, duration_secs: u64) {
             let expires = Utc::now() + chrono::Duration::seconds(duration_secs as i64);
-            self.blocked.lock().insert(ip, expires);
+            self.blocked
+                .lock()
+                .insert(ip, (expires, MANUAL_BLOCK_REASON.to_string()));
         }
 
         async fn unblock_ip(&self, ip: IpAddr) {
@@ -239,14 +278,43 @@ mod mock {
             self.blocked
                 .lock()
                 .iter()
-                .filter(|(_, exp)| **exp > now)
+                .filter(|(_, (exp, _))| *exp > now)
                 .map(|(ip, _)| *ip)
                 .collect()
         }
 
+        async fn list_blocked(&self) -> Vec<BlockedEntry> {
+            let now = Utc::now();
+            let violations = self.violations.lock();
+            let mut entries: Vec<BlockedEntry> = self
+                .blocked
+                .lock()
+                .iter()
+                .filter(|(_, (exp, _))| *exp > now)
+                .map(|(ip, (exp, reason))| BlockedEntry {
+                    ip: *ip,
+                    reason: reason.clone(),
+                    violation_count: violations.get(ip).map_or(0, |v| v.len() as u32),
+                    blocked_until: *exp,
+                })
+                .collect();
+            entries.sort_by_key(|entry| entry.blocked_until);
+            entries
+        }
+
+        async fn unblock(&self, ip: IpAddr) -> bool {
+            let was_blocked = self
+                .blocked
+                .lock()
+                .remove(&ip)
+                .is_some_and(|(exp, _)| exp > Utc::now());
+            self.violations.lock().remove(&ip);
+            was_blocked
+        }
+
         async fn cleanup_expired(&self) {
             let now = Utc::now();
-            self.blocked.lock().retain(|_, exp| *exp > now);
+            self.blocked.lock().retain(|_, (exp, _)| *exp > now);
 
             let window_start =
                 now - chrono::Duration::seconds(self.config.violation_window_secs as i64 * 24);
@@ -377,6 +445,39 @@ mod tests {
         assert_eq!(summary.max_threat_level, Some(ThreatLevel::High));
     }
 
+    #[tokio::test]
+    async fn mock_lists_and_lifts_blocks() {
+        let config = SuspiciousActivityConfig {
+            max_violations_before_block: 2,
+            ..Default::default()
+        };
+        let port = MockSuspiciousActivityPort::with_config(config);
+        let ip = test_ip();
+
+        port.record_violation(ip, ViolationRecord::new("injection", ThreatLevel::Medium))
+            .await;
+        port.record_violation(ip, ViolationRecord::new("injection", ThreatLevel::High))
+            .await;
+
+        let blocked = port.list_blocked().await;
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].ip, ip);
+        assert_eq!(blocked[0].reason, "injection (high)");
+        assert_eq!(blocked[0].violation_count, 2);
+
+        assert!(port.unblock(ip).await);
+        assert!(!port.is_blocked(ip).await);
+        assert!(port.list_blocked().await.is_empty());
+        assert_eq!(port.get_violation_summary(ip).await.total_violations, 0);
+        assert!(!port.unblock(ip).await);
+    }
+
+    #[test]
+    fn block_reason_includes_category_and_level() {
+        let record = ViolationRecord::new("PromptInjection", ThreatLevel::Critical);
+        assert_eq!(record.block_reason(), "PromptInjection (critical)");
+    }
+
     #[test]
     fn config_default_values() {
         let config = SuspiciousActivityConfig::default();
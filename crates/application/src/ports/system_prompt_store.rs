@@ -0,0 +1,99 @@
+//! System prompt override storage port
+//!
+//! Defines the interface for persisting per-conversation and per-contact
+//! system prompts that replace the global prompt.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use domain::value_objects::{ConversationId, PhoneNumber};
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::ApplicationError;
+
+/// What a system prompt override applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptOverrideTarget {
+    /// A single conversation
+    Conversation(ConversationId),
+    /// Every conversation with a messenger contact
+    Contact(PhoneNumber),
+}
+
+impl PromptOverrideTarget {
+    /// Kind of target as stored (`conversation` or `contact`)
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Conversation(_) => "conversation",
+            Self::Contact(_) => "contact",
+        }
+    }
+
+    /// Identifier of the target within its kind
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            Self::Conversation(id) => id.to_string(),
+            Self::Contact(phone) => phone.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PromptOverrideTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.kind(), self.key())
+    }
+}
+
+/// Port for system prompt override storage
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SystemPromptStore: Send + Sync {
+    /// Get the override for a target
+    async fn get(&self, target: &PromptOverrideTarget) -> Result<Option<String>, ApplicationError>;
+
+    /// Create or replace the override for a target
+    ///
+    /// The prompt is stored as given; callers validate it first.
+    async fn set(
+        &self,
+        target: &PromptOverrideTarget,
+        prompt: &str,
+    ) -> Result<(), ApplicationError>;
+
+    /// Delete the override for a target
+    ///
+    /// Returns `true` if an override existed.
+    async fn delete(&self, target: &PromptOverrideTarget) -> Result<bool, ApplicationError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversation_target_display() {
+        let id = ConversationId::new();
+        let target = PromptOverrideTarget::Conversation(id);
+
+        assert_eq!(target.kind(), "conversation");
+        assert_eq!(target.to_string(), format!("conversation:{id}"));
+    }
+
+    #[test]
+    fn contact_target_display() {
+        let target = PromptOverrideTarget::Contact(PhoneNumber::new("+491701234567").unwrap());
+
+        assert_eq!(target.kind(), "contact");
+        assert_eq!(target.key(), "+491701234567");
+        assert_eq!(target.to_string(), "contact:+491701234567");
+    }
+
+    #[test]
+    fn trait_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+        assert_send_sync::<dyn SystemPromptStore>();
+    }
+}
@@ -0,0 +1,31 @@
+//! Timer port
+//!
+//! Defines the interface for scheduling one-shot countdown timers that
+//! notify the user when they expire.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::error::ApplicationError;
+
+/// Port for one-shot timers
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TimerPort: Send + Sync {
+    /// Schedule a notification after a delay
+    ///
+    /// # Arguments
+    /// * `delay` - Time until the timer fires
+    /// * `message` - Notification text sent when the timer fires
+    ///
+    /// # Returns
+    /// An identifier for the scheduled timer
+    async fn schedule_once(
+        &self,
+        delay: Duration,
+        message: String,
+    ) -> Result<String, ApplicationError>;
+}
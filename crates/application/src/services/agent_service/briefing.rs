@@ -1,20 +1,46 @@
-//! Morning briefing handler with calendar, email, task, and weather integration
+//! Morning briefing handler with calendar, email, task, weather, reminder,
+//! birthday, and transit integration
 
 use std::fmt::Write as _;
 
-use chrono::Utc;
-use domain::{GeoLocation, TaskItem, UserId};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use domain::{BriefingSection, GeoLocation, TaskItem, Timezone, UserId};
 use tracing::{debug, warn};
 
 use super::{AgentService, ExecutionResult};
 use crate::{
     error::ApplicationError,
-    ports::{Task, TaskPort, WeatherPort},
-    services::briefing_service::WeatherSummary,
+    ports::{ReminderQuery, Task, TaskPort, TransitPort, TransitQuery, WeatherPort},
+    services::briefing_service::{
+        BirthdayBrief, BriefingData, BriefingService, CalendarBrief, DepartureSuggestion,
+        EmailBrief, EmailHighlight, EventSummary, MorningBriefing, ReminderBrief, ReminderSummary,
+        TaskBrief, TransitBrief, WeatherSummary,
+    },
 };
 
+/// Maximum reminders listed in the briefing
+const BRIEFING_MAX_REMINDERS: u32 = 10;
+
+/// Days ahead to look for contact birthdays
+const BRIEFING_BIRTHDAY_DAYS: u32 = 7;
+
+/// Connections listed in the transit section
+const BRIEFING_MAX_CONNECTIONS: u8 = 2;
+
+/// How long before the arrival deadline the departure search starts
+const DEPARTURE_SEARCH_WINDOW_MINUTES: i64 = 120;
+
+/// Connections requested per departure search page
+const DEPARTURE_SEARCH_RESULTS: u8 = 5;
+
+/// Search pages before giving up on finding the latest departure
+const DEPARTURE_SEARCH_PAGES: usize = 3;
+
 impl AgentService {
     /// Handle morning briefing command
+    ///
+    /// Sections follow the user's profile, or the configured default. Enabled
+    /// sections whose backing service isn't configured are skipped.
     pub(super) async fn handle_morning_briefing(
         &self,
         date: Option<chrono::NaiveDate>,
@@ -22,10 +48,6 @@ impl AgentService {
     ) -> Result<ExecutionResult, ApplicationError> {
         use chrono::Local;
 
-        use crate::services::briefing_service::{
-            BriefingService, CalendarBrief, EmailBrief, EmailHighlight, TaskBrief,
-        };
-
         let briefing_date = date.unwrap_or_else(|| Local::now().date_naive());
         let date_str = if date.is_none() {
             "today".to_string()
@@ -33,173 +55,508 @@ impl AgentService {
             briefing_date.format("%Y-%m-%d").to_string()
         };
 
-        // Get user timezone from profile if available
+        // Use provided user_id from request context, fall back to default
+        let effective_user_id = user_id.unwrap_or_default();
+
+        // Get user timezone and sections from profile if available
         let user_timezone = self.get_user_timezone().await;
+        let sections = self.get_briefing_sections(&effective_user_id).await;
+        let briefing_service = BriefingService::new(user_timezone.clone()).with_sections(sections);
+
+        // Transit looks for the first appointment with a location, so the
+        // calendar is fetched for either section
+        let calendar_brief = if briefing_service.is_enabled(BriefingSection::Calendar)
+            || briefing_service.is_enabled(BriefingSection::Transit)
+        {
+            self.collect_calendar_brief(briefing_date).await
+        } else {
+            None
+        };
 
-        // Collect calendar data if service available
-        let calendar_brief = if let Some(ref calendar_svc) = self.calendar_service {
-            match calendar_svc.get_calendar_brief(briefing_date).await {
-                Ok(brief) => brief,
-                Err(e) => {
-                    warn!(error = %e, "Failed to get calendar brief");
-                    CalendarBrief::default()
+        let mut data = BriefingData::default();
+        for section in briefing_service.sections() {
+            match section {
+                BriefingSection::Calendar => data.calendar.clone_from(&calendar_brief),
+                BriefingSection::Tasks => {
+                    data.tasks = self.collect_task_brief(&effective_user_id).await;
+                },
+                BriefingSection::Email => data.email = self.collect_email_brief().await,
+                BriefingSection::Weather => data.weather = self.collect_weather_summary().await,
+                BriefingSection::Reminders => {
+                    data.reminders = self
+                        .collect_reminder_brief(&effective_user_id, briefing_date, &user_timezone)
+                        .await;
+                },
+                BriefingSection::Birthdays => data.birthdays = self.collect_birthday_brief().await,
+                BriefingSection::Transit => {
+                    data.transit = self
+                        .collect_transit_brief(
+                            calendar_brief.as_ref(),
+                            briefing_date,
+                            &user_timezone,
+                        )
+                        .await;
                 },
             }
-        } else {
-            CalendarBrief::default()
-        };
+        }
 
-        // Collect email data if service available
-        let email_brief = if let Some(ref email_svc) = self.email_service {
-            match email_svc.get_inbox_summary(5, false).await {
-                Ok(summary) => EmailBrief {
-                    unread_count: summary.unread_count,
-                    #[allow(clippy::cast_possible_truncation)]
-                    important_count: summary.emails.iter().filter(|e| e.is_starred).count() as u32,
-                    top_senders: summary
-                        .emails
-                        .iter()
-                        .take(3)
-                        .map(|e| e.from.clone())
-                        .collect(),
-                    highlights: summary
-                        .emails
-                        .iter()
-                        .take(3)
-                        .map(|e| EmailHighlight {
-                            from: e.from.clone(),
-                            subject: e.subject.clone(),
-                            preview: e.snippet.clone(),
-                        })
-                        .collect(),
+        let briefing = briefing_service.generate(data);
+
+        // Format briefing response
+        let mut response = format!("☀️ Good morning! Here is your briefing for {date_str}:\n");
+        for section in &briefing.sections {
+            match section {
+                BriefingSection::Calendar => Self::write_calendar_section(&mut response, &briefing),
+                BriefingSection::Tasks => Self::write_task_section(&mut response, &briefing),
+                BriefingSection::Email => Self::write_email_section(&mut response, &briefing),
+                BriefingSection::Weather => Self::write_weather_section(&mut response, &briefing),
+                BriefingSection::Reminders => {
+                    Self::write_reminder_section(&mut response, &briefing);
                 },
-                Err(e) => {
-                    warn!(error = %e, "Failed to get email summary");
-                    EmailBrief::default()
+                BriefingSection::Birthdays => {
+                    Self::write_birthday_section(&mut response, &briefing);
                 },
+                BriefingSection::Transit => Self::write_transit_section(&mut response, &briefing),
             }
-        } else {
-            EmailBrief::default()
+        }
+
+        Ok(ExecutionResult {
+            success: true,
+            response,
+        })
+    }
+
+    /// Get the user's briefing sections from their profile, or the configured default
+    pub(super) async fn get_briefing_sections(&self, user_id: &UserId) -> Vec<BriefingSection> {
+        let Some(ref profile_store) = self.user_profile_store else {
+            return self.default_briefing_sections.clone();
         };
 
-        // Collect task data if service available
-        // Use provided user_id from request context, fall back to default
-        let task_brief = if let Some(ref task_svc) = self.task_service {
-            let effective_user_id = user_id.unwrap_or_default();
-            self.fetch_task_brief(task_svc.as_ref(), &effective_user_id)
+        match profile_store.get(user_id).await {
+            Ok(Some(profile)) => profile.briefing_sections().map_or_else(
+                || self.default_briefing_sections.clone(),
+                <[BriefingSection]>::to_vec,
+            ),
+            Ok(None) => self.default_briefing_sections.clone(),
+            Err(e) => {
+                warn!(error = %e, "Failed to get user profile, using default briefing sections");
+                self.default_briefing_sections.clone()
+            },
+        }
+    }
+
+    async fn collect_calendar_brief(&self, date: chrono::NaiveDate) -> Option<CalendarBrief> {
+        let Some(ref calendar_svc) = self.calendar_service else {
+            debug!("Calendar service not configured, skipping calendar section");
+            return None;
+        };
+
+        match calendar_svc.get_calendar_brief(date).await {
+            Ok(brief) => Some(brief),
+            Err(e) => {
+                warn!(error = %e, "Failed to get calendar brief");
+                Some(CalendarBrief::default())
+            },
+        }
+    }
+
+    async fn collect_email_brief(&self) -> Option<EmailBrief> {
+        let Some(ref email_svc) = self.email_service else {
+            debug!("Email service not configured, skipping email section");
+            return None;
+        };
+
+        match email_svc.get_inbox_summary(5, false).await {
+            Ok(summary) => Some(EmailBrief {
+                unread_count: summary.unread_count,
+                #[allow(clippy::cast_possible_truncation)]
+                important_count: summary.emails.iter().filter(|e| e.is_starred).count() as u32,
+                top_senders: summary
+                    .emails
+                    .iter()
+                    .take(3)
+                    .map(|e| e.from.clone())
+                    .collect(),
+                highlights: summary
+                    .emails
+                    .iter()
+                    .take(3)
+                    .map(|e| EmailHighlight {
+                        from: e.from.clone(),
+                        subject: e.subject.clone(),
+                        preview: e.snippet.clone(),
+                    })
+                    .collect(),
+            }),
+            Err(e) => {
+                warn!(error = %e, "Failed to get email summary");
+                Some(EmailBrief::default())
+            },
+        }
+    }
+
+    async fn collect_task_brief(&self, user_id: &UserId) -> Option<TaskBrief> {
+        let Some(ref task_svc) = self.task_service else {
+            debug!("Task service not configured, skipping tasks section");
+            return None;
+        };
+        Some(self.fetch_task_brief(task_svc.as_ref(), user_id).await)
+    }
+
+    async fn collect_weather_summary(&self) -> Option<WeatherSummary> {
+        let Some(ref weather_svc) = self.weather_service else {
+            debug!("Weather service not configured, skipping weather section");
+            return None;
+        };
+        self.fetch_weather_summary(weather_svc.as_ref()).await
+    }
+
+    /// Collect active reminders due by the end of the briefing day
+    async fn collect_reminder_brief(
+        &self,
+        user_id: &UserId,
+        date: chrono::NaiveDate,
+        timezone: &Timezone,
+    ) -> Option<ReminderBrief> {
+        let Some(ref reminder_svc) = self.reminder_service else {
+            debug!("Reminder service not configured, skipping reminders section");
+            return None;
+        };
+
+        let end_of_day = date
+            .succ_opt()
+            .map(|next| timezone.to_utc(next.and_time(chrono::NaiveTime::MIN)));
+        let query = ReminderQuery {
+            due_before: end_of_day,
+            ..ReminderQuery::active_for_user(*user_id)
+        }
+        .with_limit(BRIEFING_MAX_REMINDERS);
+
+        match reminder_svc.query(&query).await {
+            Ok(mut reminders) => {
+                reminders.sort_by_key(|r| r.remind_at);
+                let tz = timezone.as_chrono_tz();
+                Some(ReminderBrief {
+                    due_today: reminders
+                        .into_iter()
+                        .map(|r| ReminderSummary {
+                            time: r.remind_at.with_timezone(&tz).format("%H:%M").to_string(),
+                            title: r.title,
+                        })
+                        .collect(),
+                })
+            },
+            Err(e) => {
+                warn!(error = %e, "Failed to get reminders for briefing");
+                Some(ReminderBrief::default())
+            },
+        }
+    }
+
+    async fn collect_birthday_brief(&self) -> Option<BirthdayBrief> {
+        if self.contact_service.is_none() {
+            debug!("Contact service not configured, skipping birthdays section");
+            return None;
+        }
+
+        let contacts = self
+            .get_upcoming_birthdays(BRIEFING_BIRTHDAY_DAYS)
+            .await
+            .unwrap_or_default();
+        Some(BirthdayBrief {
+            names: contacts.into_iter().map(|c| c.display_name).collect(),
+            days_ahead: BRIEFING_BIRTHDAY_DAYS,
+        })
+    }
+
+    /// Collect the next connections from home to the first appointment with a location
+    async fn collect_transit_brief(
+        &self,
+        calendar: Option<&CalendarBrief>,
+        date: NaiveDate,
+        timezone: &Timezone,
+    ) -> Option<TransitBrief> {
+        let Some(ref transit_svc) = self.transit_service else {
+            debug!("Transit service not configured, skipping transit section");
+            return None;
+        };
+        let Some(home) = self.home_location else {
+            debug!("No home location configured, skipping transit section");
+            return None;
+        };
+        let Some((event, destination)) = calendar
+            .into_iter()
+            .flat_map(|c| &c.events)
+            .filter(|e| !e.all_day)
+            .find_map(|e| e.location.clone().map(|location| (e, location)))
+        else {
+            debug!("No appointment with a location, skipping transit section");
+            return None;
+        };
+
+        let connections = match transit_svc
+            .find_connections_to_address(&home, &destination, None, BRIEFING_MAX_CONNECTIONS)
+            .await
+        {
+            Ok(connections) => connections,
+            Err(e) => {
+                warn!(error = %e, destination = %destination, "Failed to get connections for briefing");
+                return None;
+            },
+        };
+
+        let departure = match self
+            .briefing_arrival_buffer
+            .and_then(|buffer| arrival_deadline(event, date, timezone, buffer))
+        {
+            Some(arrive_by) if arrive_by > Utc::now() => {
+                Self::suggest_departure(
+                    transit_svc.as_ref(),
+                    &home,
+                    event,
+                    &destination,
+                    arrive_by,
+                    timezone,
+                )
                 .await
-        } else {
-            TaskBrief::default()
+            },
+            _ => None,
         };
 
-        // Collect weather data if service available
-        let weather_summary = if let Some(ref weather_svc) = self.weather_service {
-            self.fetch_weather_summary(weather_svc.as_ref()).await
-        } else {
-            None
+        Some(TransitBrief {
+            destination,
+            connections: connections
+                .iter()
+                .map(crate::ports::TransitConnection::format_summary)
+                .collect(),
+            departure,
+        })
+    }
+
+    /// Find the latest connection from home that arrives by `arrive_by`
+    ///
+    /// Returns None if the destination can't be geocoded or the search
+    /// fails; a suggestion without `leave_by` if no connection is in time.
+    async fn suggest_departure(
+        transit: &dyn TransitPort,
+        home: &GeoLocation,
+        event: &EventSummary,
+        destination: &str,
+        arrive_by: DateTime<Utc>,
+        timezone: &Timezone,
+    ) -> Option<DepartureSuggestion> {
+        let target = match transit.geocode_address(destination).await {
+            Ok(Some(target)) => target,
+            Ok(None) => {
+                debug!(destination = %destination, "Could not geocode appointment location, skipping departure suggestion");
+                return None;
+            },
+            Err(e) => {
+                warn!(error = %e, destination = %destination, "Failed to geocode appointment location");
+                return None;
+            },
         };
 
-        // Generate briefing using BriefingService with user's timezone
-        let briefing_service = BriefingService::new(user_timezone);
-        let briefing = briefing_service.generate_briefing(
-            calendar_brief,
-            email_brief,
-            task_brief,
-            weather_summary,
-        );
+        // Page forward until a connection misses the deadline, keeping the
+        // latest one that still makes it
+        let mut search_from = arrive_by - Duration::minutes(DEPARTURE_SEARCH_WINDOW_MINUTES);
+        let mut leave_by = None;
+        for _ in 0..DEPARTURE_SEARCH_PAGES {
+            let query = TransitQuery::new(*home, target)
+                .with_departure(search_from)
+                .with_max_results(DEPARTURE_SEARCH_RESULTS);
+            let connections = match transit.search_connections(&query).await {
+                Ok(connections) => connections,
+                Err(e) => {
+                    warn!(error = %e, destination = %destination, "Failed to search departure for briefing");
+                    return None;
+                },
+            };
 
-        // Format briefing response
-        let mut response = format!("☀️ Good morning! Here is your briefing for {date_str}:\n\n");
+            if let Some(latest) = BriefingService::latest_departure(&connections, arrive_by) {
+                leave_by = Some(latest.departure_time);
+            }
+            match connections.last() {
+                Some(last) if last.arrival_time <= arrive_by => {
+                    search_from = last.departure_time + Duration::minutes(1);
+                },
+                _ => break,
+            }
+        }
+
+        Some(DepartureSuggestion {
+            event_title: event.title.clone(),
+            event_time: event.start_time.clone(),
+            leave_by: leave_by.map(|time| {
+                time.with_timezone(&timezone.as_chrono_tz())
+                    .format("%H:%M")
+                    .to_string()
+            }),
+        })
+    }
 
-        // Add calendar section
-        response.push_str("📅 **Appointments**\n");
-        if briefing.calendar.event_count == 0 {
+    fn write_calendar_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref calendar) = briefing.calendar else {
+            return;
+        };
+
+        response.push_str("\n📅 **Appointments**\n");
+        if calendar.event_count == 0 {
             response.push_str("No appointments scheduled for today.\n");
-        } else {
+            return;
+        }
+
+        let _ = writeln!(response, "{} appointment(s) today:", calendar.event_count);
+        for event in &calendar.events {
+            if event.all_day {
+                let _ = writeln!(response, "  • {} (all-day)", event.title);
+            } else {
+                let _ = writeln!(response, "  • {} at {}", event.title, event.start_time);
+            }
+        }
+        if !calendar.conflicts.is_empty() {
             let _ = writeln!(
                 response,
-                "{} appointment(s) today:",
-                briefing.calendar.event_count
+                "  ⚠️ {} conflict(s) detected",
+                calendar.conflicts.len()
             );
-            for event in &briefing.calendar.events {
-                if event.all_day {
-                    let _ = writeln!(response, "  • {} (all-day)", event.title);
-                } else {
-                    let _ = writeln!(response, "  • {} at {}", event.title, event.start_time);
-                }
-            }
-            if !briefing.calendar.conflicts.is_empty() {
-                let _ = writeln!(
-                    response,
-                    "  ⚠️ {} conflict(s) detected",
-                    briefing.calendar.conflicts.len()
-                );
-            }
         }
+    }
+
+    fn write_email_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref email) = briefing.email else {
+            return;
+        };
 
-        // Add email section
         response.push_str("\n📧 **Emails**\n");
-        if briefing.email.unread_count == 0 {
+        if email.unread_count == 0 {
             response.push_str("No unread emails.\n");
-        } else {
-            let _ = write!(response, "{} unread email(s)", briefing.email.unread_count);
-            if briefing.email.important_count > 0 {
-                let _ = write!(response, ", {} important", briefing.email.important_count);
-            }
-            response.push('\n');
-            for highlight in &briefing.email.highlights {
-                let _ = writeln!(response, "  • {}: {}", highlight.from, highlight.subject);
-            }
+            return;
         }
 
-        // Add task section if available
-        if briefing.tasks.due_today > 0 || briefing.tasks.overdue > 0 {
-            response.push_str("\n✅ **Tasks**\n");
-            if briefing.tasks.due_today > 0 {
-                let _ = writeln!(response, "{} task(s) due today", briefing.tasks.due_today);
-            }
-            if briefing.tasks.overdue > 0 {
-                let _ = writeln!(response, "⚠️ {} overdue task(s)", briefing.tasks.overdue);
-            }
+        let _ = write!(response, "{} unread email(s)", email.unread_count);
+        if email.important_count > 0 {
+            let _ = write!(response, ", {} important", email.important_count);
         }
+        response.push('\n');
+        for highlight in &email.highlights {
+            let _ = writeln!(response, "  • {}: {}", highlight.from, highlight.subject);
+        }
+    }
 
-        // Add weather section if available
-        if let Some(ref weather) = briefing.weather {
-            response.push_str("\n🌤️ **Weather**\n");
-            let _ = writeln!(
-                response,
-                "{}, {:.0}°C (High: {:.0}°C, Low: {:.0}°C)",
-                weather.condition, weather.temperature, weather.high, weather.low
-            );
+    fn write_task_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref tasks) = briefing.tasks else {
+            return;
+        };
+        if tasks.due_today == 0 && tasks.overdue == 0 {
+            return;
         }
 
-        Ok(ExecutionResult {
-            success: true,
+        response.push_str("\n✅ **Tasks**\n");
+        if tasks.due_today > 0 {
+            let _ = writeln!(response, "{} task(s) due today", tasks.due_today);
+        }
+        if tasks.overdue > 0 {
+            let _ = writeln!(response, "⚠️ {} overdue task(s)", tasks.overdue);
+        }
+    }
+
+    fn write_weather_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref weather) = briefing.weather else {
+            return;
+        };
+
+        response.push_str("\n🌤️ **Weather**\n");
+        let _ = writeln!(
             response,
-        })
+            "{}, {:.0}°C (High: {:.0}°C, Low: {:.0}°C)",
+            weather.condition, weather.temperature, weather.high, weather.low
+        );
+    }
+
+    fn write_reminder_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref reminders) = briefing.reminders else {
+            return;
+        };
+        if reminders.due_today.is_empty() {
+            return;
+        }
+
+        response.push_str("\n🔔 **Reminders**\n");
+        for reminder in &reminders.due_today {
+            let _ = writeln!(response, "  • {} at {}", reminder.title, reminder.time);
+        }
     }
 
-    /// Get the user's timezone from their profile, or default to Europe/Berlin
+    fn write_birthday_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref birthdays) = briefing.birthdays else {
+            return;
+        };
+        if birthdays.names.is_empty() {
+            return;
+        }
+
+        let _ = writeln!(
+            response,
+            "\n🎂 **Birthdays** (next {} days)",
+            birthdays.days_ahead
+        );
+        for name in &birthdays.names {
+            let _ = writeln!(response, "  • {name}");
+        }
+    }
+
+    fn write_transit_section(response: &mut String, briefing: &MorningBriefing) {
+        let Some(ref transit) = briefing.transit else {
+            return;
+        };
+
+        let _ = writeln!(response, "\n🚆 **Transit** to {}", transit.destination);
+        if transit.connections.is_empty() {
+            response.push_str("No connections found.\n");
+        }
+        for connection in &transit.connections {
+            let _ = writeln!(response, "  {connection}");
+        }
+        if let Some(ref departure) = transit.departure {
+            match departure.leave_by {
+                Some(ref leave_by) => {
+                    let _ = writeln!(
+                        response,
+                        "  🚶 Leave by {leave_by} to reach {} at {}",
+                        departure.event_title, departure.event_time
+                    );
+                },
+                None => {
+                    let _ = writeln!(
+                        response,
+                        "  ⚠️ No connection reaches {} by {}",
+                        departure.event_title, departure.event_time
+                    );
+                },
+            }
+        }
+    }
+
+    /// Get the user's timezone from their profile, or the configured default
     ///
     /// For now uses a default user ID since we don't have per-request user context.
     pub(super) async fn get_user_timezone(&self) -> domain::value_objects::Timezone {
-        use domain::value_objects::Timezone;
-
         if let Some(ref profile_store) = self.user_profile_store {
             let default_user_id = UserId::default();
             match profile_store.get(&default_user_id).await {
                 Ok(Some(profile)) => profile.timezone().clone(),
                 Ok(None) => {
                     debug!("User profile not found, using default timezone");
-                    Timezone::berlin()
+                    self.default_timezone.clone()
                 },
                 Err(e) => {
                     warn!(error = %e, "Failed to get user profile, using default timezone");
-                    Timezone::berlin()
+                    self.default_timezone.clone()
                 },
             }
         } else {
-            domain::value_objects::Timezone::berlin()
+            self.default_timezone.clone()
         }
     }
 
@@ -211,7 +568,7 @@ impl AgentService {
         &self,
         task_svc: &dyn TaskPort,
         user_id: &UserId,
-    ) -> crate::services::briefing_service::TaskBrief {
+    ) -> TaskBrief {
         let today = Utc::now().date_naive();
 
         // Fetch tasks due today
@@ -219,7 +576,7 @@ impl AgentService {
             Ok(tasks) => tasks,
             Err(e) => {
                 warn!(error = %e, "Failed to get tasks due today");
-                return crate::services::briefing_service::TaskBrief::default();
+                return TaskBrief::default();
             },
         };
 
@@ -252,7 +609,7 @@ impl AgentService {
             }
         }
 
-        crate::services::briefing_service::TaskBrief {
+        TaskBrief {
             due_today: u32::try_from(domain_today.len()).unwrap_or(0),
             overdue: u32::try_from(domain_overdue.len()).unwrap_or(0),
             high_priority: domain_high_priority
@@ -348,19 +705,35 @@ impl AgentService {
     }
 }
 
+/// Latest arrival time for an appointment, `buffer_minutes` before it starts
+///
+/// Appointment times are `HH:MM` in the user's timezone.
+fn arrival_deadline(
+    event: &EventSummary,
+    date: NaiveDate,
+    timezone: &Timezone,
+    buffer_minutes: u32,
+) -> Option<DateTime<Utc>> {
+    let start = NaiveTime::parse_from_str(&event.start_time, "%H:%M").ok()?;
+    Some(timezone.to_utc(date.and_time(start)) - Duration::minutes(i64::from(buffer_minutes)))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use chrono::{NaiveDate, Utc};
-    use domain::{AgentCommand, GeoLocation, UserId};
+    use domain::{
+        AgentCommand, BriefingSection, GeoLocation, Reminder, ReminderSource, Timezone, UserId,
+    };
 
     use super::super::{AgentService, test_support::MockInferenceEngine};
+    use super::{EventSummary, arrival_deadline};
     use crate::{
         error::ApplicationError,
         ports::{
-            CurrentWeather, DailyForecast, MockWeatherPort, Task, TaskStatus, UserProfileStore,
-            WeatherCondition,
+            CurrentWeather, DailyForecast, MockReminderPort, MockTransitPort, MockWeatherPort,
+            Task, TaskStatus, TransitConnection, UserProfileStore, WeatherCondition,
         },
     };
 
@@ -717,4 +1090,241 @@ mod tests {
         assert!((loc.latitude() - 51.5074).abs() < 0.01);
         assert!((loc.longitude() - (-0.1278)).abs() < 0.01);
     }
+
+    struct SectionsProfileStore(Vec<BriefingSection>);
+
+    #[async_trait::async_trait]
+    impl UserProfileStore for SectionsProfileStore {
+        async fn save(
+            &self,
+            _profile: &domain::entities::UserProfile,
+        ) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn get(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Option<domain::entities::UserProfile>, ApplicationError> {
+            Ok(Some(
+                domain::entities::UserProfile::new(*user_id)
+                    .with_briefing_sections(Some(self.0.clone())),
+            ))
+        }
+
+        async fn delete(&self, _user_id: &UserId) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn update_location(
+            &self,
+            _user_id: &UserId,
+            _location: Option<&GeoLocation>,
+        ) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn update_timezone(
+            &self,
+            _user_id: &UserId,
+            _timezone: &domain::value_objects::Timezone,
+        ) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+    }
+
+    fn sunny_weather_port() -> MockWeatherPort {
+        let mut mock_weather = MockWeatherPort::new();
+        mock_weather.expect_get_weather_summary().returning(|_, _| {
+            Ok((
+                CurrentWeather {
+                    temperature: 18.0,
+                    apparent_temperature: 17.0,
+                    humidity: 50,
+                    wind_speed: 5.0,
+                    condition: WeatherCondition::ClearSky,
+                    observed_at: Utc::now(),
+                },
+                vec![],
+            ))
+        });
+        mock_weather
+    }
+
+    async fn briefing(service: &AgentService) -> String {
+        service
+            .execute_command(&AgentCommand::MorningBriefing { date: None })
+            .await
+            .unwrap()
+            .response
+    }
+
+    #[tokio::test]
+    async fn briefing_skips_sections_without_service() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_weather_service(Arc::new(sunny_weather_port()))
+            .with_default_weather_location(GeoLocation::berlin());
+
+        let response = briefing(&service).await;
+
+        assert!(response.contains("**Weather**"));
+        assert!(!response.contains("**Appointments**"));
+        assert!(!response.contains("**Emails**"));
+    }
+
+    #[tokio::test]
+    async fn briefing_respects_default_sections() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_weather_service(Arc::new(sunny_weather_port()))
+            .with_default_weather_location(GeoLocation::berlin())
+            .with_default_briefing_sections(vec![BriefingSection::Tasks]);
+
+        let response = briefing(&service).await;
+
+        assert!(response.contains("Good morning"));
+        assert!(!response.contains("**Weather**"));
+    }
+
+    #[tokio::test]
+    async fn briefing_uses_profile_section_order() {
+        let mut mock_reminders = MockReminderPort::new();
+        mock_reminders.expect_query().returning(|query| {
+            assert!(query.due_before.is_some());
+            Ok(vec![Reminder::new(
+                UserId::default(),
+                ReminderSource::Custom,
+                "Call mum",
+                Utc::now(),
+            )])
+        });
+
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_weather_service(Arc::new(sunny_weather_port()))
+            .with_default_weather_location(GeoLocation::berlin())
+            .with_reminder_service(Arc::new(mock_reminders))
+            .with_user_profile_store(Arc::new(SectionsProfileStore(vec![
+                BriefingSection::Reminders,
+                BriefingSection::Weather,
+            ])));
+
+        let response = briefing(&service).await;
+
+        let reminders = response.find("**Reminders**").unwrap();
+        let weather = response.find("**Weather**").unwrap();
+        assert!(reminders < weather);
+        assert!(response.contains("Call mum"));
+    }
+
+    fn standup() -> EventSummary {
+        EventSummary {
+            title: "Standup".to_string(),
+            start_time: "09:00".to_string(),
+            end_time: "09:15".to_string(),
+            location: Some("Alexanderplatz 1, Berlin".to_string()),
+            all_day: false,
+        }
+    }
+
+    fn connection(departure: &str, arrival: &str) -> TransitConnection {
+        TransitConnection {
+            departure_time: utc(departure),
+            arrival_time: utc(arrival),
+            duration_minutes: 30,
+            transfers: 0,
+            legs: Vec::new(),
+            delay_info: None,
+        }
+    }
+
+    fn utc(time: &str) -> chrono::DateTime<Utc> {
+        chrono::DateTime::parse_from_rfc3339(&format!("2024-01-15T{time}:00Z"))
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn arrival_deadline_uses_timezone_and_buffer() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let deadline = arrival_deadline(&standup(), date, &Timezone::berlin(), 5).unwrap();
+
+        assert_eq!(deadline, utc("07:55"));
+    }
+
+    #[tokio::test]
+    async fn suggest_departure_picks_latest_connection_in_time() {
+        let mut mock_transit = MockTransitPort::new();
+        mock_transit
+            .expect_geocode_address()
+            .returning(|_| Ok(Some(GeoLocation::berlin())));
+        mock_transit
+            .expect_search_connections()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    connection("08:00", "08:30"),
+                    connection("08:15", "08:45"),
+                    connection("08:30", "09:00"),
+                ])
+            });
+
+        let suggestion = AgentService::suggest_departure(
+            &mock_transit,
+            &GeoLocation::berlin(),
+            &standup(),
+            "Alexanderplatz 1, Berlin",
+            utc("08:55"),
+            &Timezone::utc(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(suggestion.event_title, "Standup");
+        assert_eq!(suggestion.leave_by.as_deref(), Some("08:15"));
+    }
+
+    #[tokio::test]
+    async fn suggest_departure_reports_unreachable_appointment() {
+        let mut mock_transit = MockTransitPort::new();
+        mock_transit
+            .expect_geocode_address()
+            .returning(|_| Ok(Some(GeoLocation::berlin())));
+        mock_transit
+            .expect_search_connections()
+            .returning(|_| Ok(vec![connection("08:40", "09:10")]));
+
+        let suggestion = AgentService::suggest_departure(
+            &mock_transit,
+            &GeoLocation::berlin(),
+            &standup(),
+            "Alexanderplatz 1, Berlin",
+            utc("08:55"),
+            &Timezone::utc(),
+        )
+        .await
+        .unwrap();
+
+        assert!(suggestion.leave_by.is_none());
+    }
+
+    #[tokio::test]
+    async fn suggest_departure_skips_unknown_location() {
+        let mut mock_transit = MockTransitPort::new();
+        mock_transit
+            .expect_geocode_address()
+            .returning(|_| Ok(None));
+        mock_transit.expect_search_connections().never();
+
+        let suggestion = AgentService::suggest_departure(
+            &mock_transit,
+            &GeoLocation::berlin(),
+            &standup(),
+            "Somewhere",
+            utc("08:55"),
+            &Timezone::utc(),
+        )
+        .await;
+
+        assert!(suggestion.is_none());
+    }
 }
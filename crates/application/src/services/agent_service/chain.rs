@@ -0,0 +1,328 @@
+//! Multi-step command chains ("draft an email and send it")
+
+use std::time::Instant;
+
+use domain::{AgentCommand, UserId};
+use futures::future::BoxFuture;
+use tracing::{debug, info, warn};
+
+use super::{AgentService, ApprovalStatus, CommandResult, ExecutionResult};
+use crate::error::ApplicationError;
+
+/// Draft ID that refers to the draft created earlier in the same chain
+const LATEST_DRAFT_ID: &str = "latest";
+
+/// Outcome of running a chain up to its first step that needs approval
+struct ChainRun {
+    /// Responses of the executed steps
+    responses: Vec<String>,
+    /// Whether every executed step succeeded
+    success: bool,
+    /// Number of steps that were executed
+    executed: usize,
+    /// Unexecuted steps, starting with the first one that needs approval
+    pending: Vec<AgentCommand>,
+}
+
+impl AgentService {
+    /// Handle a chain parsed from natural language input
+    ///
+    /// Steps run in order until one needs approval. That step and everything
+    /// after it become the pending command, and the approval prompt lists the
+    /// whole chain so the user consents to all remaining steps at once.
+    pub(super) async fn handle_chain_input(
+        &self,
+        steps: Vec<AgentCommand>,
+        user_id: Option<UserId>,
+        start: Instant,
+    ) -> Result<CommandResult, ApplicationError> {
+        info!(steps = steps.len(), "Executing command chain");
+
+        let run = self.run_chain(&steps, user_id).await?;
+        let mut responses = run.responses;
+
+        let (command, success, approval_status) = if run.pending.is_empty() {
+            let command = AgentCommand::Chain { steps };
+            (command, run.success, ApprovalStatus::NotRequired)
+        } else {
+            debug!(pending = run.pending.len(), "Chain requires approval");
+            responses.push(format_chain_approval(&steps[..run.executed], &run.pending));
+            let command = if run.pending.len() == 1 {
+                run.pending[0].clone()
+            } else {
+                AgentCommand::Chain { steps: run.pending }
+            };
+            (command, false, ApprovalStatus::Pending)
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(CommandResult {
+            command,
+            success,
+            response: responses.join("\n\n"),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            approval_status: Some(approval_status),
+        })
+    }
+
+    /// Execute a chain that needs no approval
+    pub(super) async fn execute_chain(
+        &self,
+        steps: &[AgentCommand],
+        user_id: Option<UserId>,
+    ) -> Result<ExecutionResult, ApplicationError> {
+        let run = self.run_chain(steps, user_id).await?;
+        Ok(ExecutionResult {
+            success: run.success && run.pending.is_empty(),
+            response: run.responses.join("\n\n"),
+        })
+    }
+
+    /// Run steps in order, stopping at the first failure or approval step
+    async fn run_chain(
+        &self,
+        steps: &[AgentCommand],
+        user_id: Option<UserId>,
+    ) -> Result<ChainRun, ApplicationError> {
+        let mut run = ChainRun {
+            responses: Vec::with_capacity(steps.len()),
+            success: true,
+            executed: 0,
+            pending: Vec::new(),
+        };
+        let mut drafted = false;
+
+        for (index, step) in steps.iter().enumerate() {
+            if step.requires_approval() {
+                for rest in &steps[index..] {
+                    run.pending.push(self.resolve_step(rest, drafted).await?);
+                }
+                break;
+            }
+
+            // Boxed: a step is executed through `execute_command_with_user`,
+            // which runs nested chains through this function again
+            let execution: BoxFuture<'_, Result<ExecutionResult, ApplicationError>> =
+                Box::pin(self.execute_command_with_user(step, user_id));
+            run.executed += 1;
+            match execution.await {
+                Ok(result) => {
+                    run.responses.push(result.response);
+                    if !result.success {
+                        run.success = false;
+                        break;
+                    }
+                    drafted |= matches!(step, AgentCommand::DraftEmail { .. });
+                },
+                Err(e) => {
+                    warn!(command = ?step, error = %e, "Chain step failed");
+                    run.responses
+                        .push(format!("❌ {}: {e}", step.description()));
+                    run.success = false;
+                    break;
+                },
+            }
+        }
+
+        Ok(run)
+    }
+
+    /// Replace a `latest` draft reference with the draft created by the chain
+    async fn resolve_step(
+        &self,
+        step: &AgentCommand,
+        drafted: bool,
+    ) -> Result<AgentCommand, ApplicationError> {
+        let AgentCommand::SendEmail { draft_id } = step else {
+            return Ok(step.clone());
+        };
+        let Some(ref draft_store) = self.draft_store else {
+            return Ok(step.clone());
+        };
+        if !drafted || draft_id != LATEST_DRAFT_ID {
+            return Ok(step.clone());
+        }
+
+        let latest = draft_store.list_for_user(&UserId::default(), 1).await?;
+        Ok(latest.first().map_or_else(
+            || step.clone(),
+            |draft| AgentCommand::SendEmail {
+                draft_id: draft.id.to_string(),
+            },
+        ))
+    }
+}
+
+/// Approval prompt listing executed and pending chain steps
+fn format_chain_approval(executed: &[AgentCommand], pending: &[AgentCommand]) -> String {
+    let mut lines = vec!["⚠️ Diese Aktionen erfordern Bestätigung:".to_string()];
+    let steps = executed
+        .iter()
+        .map(|step| ("✅", step))
+        .chain(pending.iter().map(|step| ("⏳", step)));
+    for (number, (marker, step)) in steps.enumerate() {
+        lines.push(format!("{}. {marker} {}", number + 1, step.description()));
+    }
+    lines.push(String::new());
+    lines.push("Bitte bestätige mit 'OK' oder breche ab mit 'Abbrechen'.".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::{AgentCommand, EmailAddress, PersistedEmailDraft, UserId};
+
+    use super::super::{
+        AgentService, ApprovalStatus,
+        test_support::{MockInferenceEngine, mock_inference_result},
+    };
+    use crate::ports::MockDraftStorePort;
+
+    fn draft_step() -> AgentCommand {
+        AgentCommand::DraftEmail {
+            to: EmailAddress::new("bob@example.com").unwrap(),
+            subject: Some("Lunch".to_string()),
+            body: "Lunch tomorrow?".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn draft_and_send_stops_for_approval_with_resolved_draft() {
+        let draft = PersistedEmailDraft::new(
+            UserId::default(),
+            EmailAddress::new("bob@example.com").unwrap(),
+            "Lunch",
+            "Lunch tomorrow?",
+        );
+        let draft_id = draft.id.to_string();
+
+        let mut store = MockDraftStorePort::new();
+        store.expect_save().returning(|d| Ok(d.id));
+        store
+            .expect_list_for_user()
+            .returning(move |_, _| Ok(vec![draft.clone()]));
+
+        let mut inference = MockInferenceEngine::new();
+        inference.expect_generate_with_system().returning(|_, _| {
+            Ok(mock_inference_result(
+                r#"[{"intent":"draft_email","to":"bob@example.com","subject":"Lunch","body":"Lunch tomorrow?"},{"intent":"send_email","draft_id":"latest"}]"#,
+            ))
+        });
+        let service = AgentService::new(Arc::new(inference)).with_draft_store(Arc::new(store));
+
+        let result = service
+            .handle_input("draft an email to bob@example.com about lunch and send it")
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(result.approval_status, Some(ApprovalStatus::Pending));
+        assert_eq!(
+            result.command,
+            AgentCommand::SendEmail {
+                draft_id: draft_id.clone()
+            }
+        );
+        assert!(result.response.contains("Email draft created"));
+        assert!(
+            result
+                .response
+                .contains("1. ✅ Draft email to bob@example.com")
+        );
+        assert!(
+            result
+                .response
+                .contains(&format!("2. ⏳ Send email draft {draft_id}"))
+        );
+    }
+
+    #[tokio::test]
+    async fn pending_steps_after_approval_step_stay_in_chain() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+        let steps = vec![
+            AgentCommand::Echo {
+                message: "first".to_string(),
+            },
+            AgentCommand::SendEmail {
+                draft_id: "d-1".to_string(),
+            },
+            AgentCommand::Echo {
+                message: "last".to_string(),
+            },
+        ];
+
+        let result = service
+            .handle_chain_input(steps, None, std::time::Instant::now())
+            .await
+            .unwrap();
+
+        assert_eq!(result.approval_status, Some(ApprovalStatus::Pending));
+        let AgentCommand::Chain { steps } = &result.command else {
+            unreachable!("Expected remaining chain")
+        };
+        assert_eq!(steps.len(), 2);
+        assert!(result.response.starts_with("🔊 first"));
+        assert!(result.response.contains("3. ⏳ Echo: last"));
+    }
+
+    #[tokio::test]
+    async fn chain_without_approval_runs_every_step() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+        let chain = AgentCommand::Chain {
+            steps: vec![
+                AgentCommand::Echo {
+                    message: "one".to_string(),
+                },
+                AgentCommand::Echo {
+                    message: "two".to_string(),
+                },
+            ],
+        };
+
+        let result = service.execute_command(&chain).await.unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.response, "🔊 one\n\n🔊 two");
+    }
+
+    #[tokio::test]
+    async fn chain_stops_at_first_failed_step() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+        let chain = AgentCommand::Chain {
+            steps: vec![
+                draft_step(),
+                AgentCommand::Echo {
+                    message: "never".to_string(),
+                },
+            ],
+        };
+
+        // No draft store configured, so drafting fails
+        let result = service.execute_command(&chain).await.unwrap();
+
+        assert!(!result.success);
+        assert!(!result.response.contains("never"));
+    }
+
+    #[tokio::test]
+    async fn chain_needing_approval_is_not_executed_directly() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+        let chain = AgentCommand::Chain {
+            steps: vec![
+                draft_step(),
+                AgentCommand::SendEmail {
+                    draft_id: "latest".to_string(),
+                },
+            ],
+        };
+
+        let result = service.execute_command(&chain).await;
+
+        assert!(matches!(
+            result,
+            Err(crate::error::ApplicationError::ApprovalRequired(_))
+        ));
+    }
+}
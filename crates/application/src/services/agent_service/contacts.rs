@@ -342,7 +342,6 @@ impl AgentService {
     }
 
     /// Get upcoming birthdays from contacts (used in morning briefing)
-    #[allow(dead_code)] // Will be wired in briefing
     pub(super) async fn get_upcoming_birthdays(
         &self,
         days: u32,
@@ -0,0 +1,327 @@
+//! Conversation summary handler (TL;DR of a chat thread)
+//!
+//! Long histories are summarized map-reduce style: the transcript is split
+//! into chunks that fit the model context, each chunk is condensed on its
+//! own, and the partial summaries are merged into the final TL;DR.
+
+use domain::{ChatMessage, ConversationId, MessageRole};
+use tracing::{debug, info};
+
+use super::{AgentService, ExecutionResult};
+use crate::error::ApplicationError;
+
+/// Default upper bound for the summary length
+const DEFAULT_MAX_SENTENCES: u32 = 5;
+
+/// Transcript characters sent to the model per request (~3k tokens)
+const MAX_SUMMARY_INPUT_CHARS: usize = 12_000;
+
+/// Reduce rounds before the remaining partial summaries are cut off
+const MAX_REDUCE_ROUNDS: usize = 3;
+
+/// Prompt for the final summary; `{max_sentences}` and `{transcript}` are
+/// substituted before sending
+const SUMMARY_TEMPLATE: &str = "Summarize the following conversation between a user and \
+an assistant in at most {max_sentences} sentences. Focus on decisions, open questions and \
+agreed next steps. Answer in the language of the conversation.\n\n\
+Conversation:\n{transcript}\n\nSummary:";
+
+/// Prompt for condensing one chunk of a long conversation (map step)
+const CHUNK_SUMMARY_TEMPLATE: &str = "The following is one part of a longer conversation \
+between a user and an assistant. Write short notes of everything that may matter for a \
+summary of the whole conversation: topics, facts, decisions and open questions. \
+Answer in the language of the conversation.\n\n\
+Conversation part:\n{transcript}\n\nNotes:";
+
+impl AgentService {
+    /// Handle summarizing a stored conversation
+    pub(super) async fn handle_summarize_conversation(
+        &self,
+        conversation_id: Option<&str>,
+        max_sentences: Option<u32>,
+    ) -> Result<ExecutionResult, ApplicationError> {
+        let Some(ref conversation_store) = self.conversation_store else {
+            return Ok(ExecutionResult {
+                success: false,
+                response: "💬 Conversation history is not configured.".to_string(),
+            });
+        };
+
+        let conversation = match conversation_id {
+            Some(id) => {
+                let id = ConversationId::parse(id).map_err(|_| {
+                    ApplicationError::NotFound(format!("Invalid conversation ID: {id}"))
+                })?;
+                conversation_store.get(&id).await?
+            },
+            None => conversation_store.list_recent(1).await?.into_iter().next(),
+        };
+        let Some(conversation) = conversation else {
+            return Ok(ExecutionResult {
+                success: false,
+                response: "❌ Unterhaltung nicht gefunden.".to_string(),
+            });
+        };
+
+        let lines = transcript_lines(&conversation.messages);
+        if lines.is_empty() {
+            return Ok(ExecutionResult {
+                success: true,
+                response: "💬 Diese Unterhaltung enthält noch keine Nachrichten.".to_string(),
+            });
+        }
+
+        let max_sentences = max_sentences.unwrap_or(DEFAULT_MAX_SENTENCES).max(1);
+        let summary = self.summarize_lines(lines, max_sentences).await?;
+        info!(conversation_id = %conversation.id, "Summarized conversation");
+
+        Ok(ExecutionResult {
+            success: true,
+            response: format!(
+                "📝 *Zusammenfassung* ({} Nachrichten)\n\n{}",
+                conversation.messages.len(),
+                summary.trim()
+            ),
+        })
+    }
+
+    /// Map-reduce summarization that keeps every request within the context budget
+    async fn summarize_lines(
+        &self,
+        mut lines: Vec<String>,
+        max_sentences: u32,
+    ) -> Result<String, ApplicationError> {
+        for round in 0..MAX_REDUCE_ROUNDS {
+            let chunks = chunk_lines(&lines, MAX_SUMMARY_INPUT_CHARS);
+            if chunks.len() <= 1 {
+                break;
+            }
+
+            debug!(
+                round,
+                chunks = chunks.len(),
+                "Condensing conversation chunks"
+            );
+            let mut notes = Vec::with_capacity(chunks.len());
+            for chunk in &chunks {
+                let prompt = CHUNK_SUMMARY_TEMPLATE.replace("{transcript}", chunk);
+                notes.push(self.inference.generate(&prompt).await?.content);
+            }
+            lines = notes;
+        }
+
+        // Anything still over budget after the reduce rounds is cut off
+        let transcript = chunk_lines(&lines, MAX_SUMMARY_INPUT_CHARS)
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        let prompt = SUMMARY_TEMPLATE
+            .replace("{max_sentences}", &max_sentences.to_string())
+            .replace("{transcript}", &transcript);
+        Ok(self.inference.generate(&prompt).await?.content)
+    }
+}
+
+/// One "Role: content" line per user/assistant message
+fn transcript_lines(messages: &[ChatMessage]) -> Vec<String> {
+    messages
+        .iter()
+        .filter_map(|message| {
+            let speaker = match message.role {
+                MessageRole::User => "User",
+                MessageRole::Assistant => "Assistant",
+                MessageRole::System => return None,
+            };
+            Some(format!("{speaker}: {}", message.content.trim()))
+        })
+        .collect()
+}
+
+/// Group lines into chunks of at most `budget` characters
+///
+/// A single line longer than the budget is truncated.
+fn chunk_lines(lines: &[String], budget: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for line in lines {
+        let line: String = line.chars().take(budget).collect();
+        if !current.is_empty() && current.chars().count() + line.chars().count() + 1 > budget {
+            chunks.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&line);
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use domain::{AgentCommand, Conversation};
+    use mockall::mock;
+
+    use super::{
+        super::{
+            AgentService,
+            test_support::{MockInferenceEngine, mock_inference_result},
+        },
+        *,
+    };
+    use crate::ports::ConversationStore;
+
+    mock! {
+        pub ConvStore {}
+
+        #[async_trait::async_trait]
+        impl ConversationStore for ConvStore {
+            async fn save(&self, conversation: &Conversation) -> Result<(), ApplicationError>;
+            async fn get(&self, id: &ConversationId) -> Result<Option<Conversation>, ApplicationError>;
+            async fn get_by_phone_number(&self, source: domain::ConversationSource, phone_number: &str) -> Result<Option<Conversation>, ApplicationError>;
+            async fn update(&self, conversation: &Conversation) -> Result<(), ApplicationError>;
+            async fn delete(&self, id: &ConversationId) -> Result<(), ApplicationError>;
+            async fn add_message(&self, conversation_id: &ConversationId, message: &ChatMessage) -> Result<(), ApplicationError>;
+            async fn list_recent(&self, limit: usize) -> Result<Vec<Conversation>, ApplicationError>;
+            async fn search(&self, query: &str, limit: usize) -> Result<Vec<Conversation>, ApplicationError>;
+            async fn cleanup_older_than(&self, cutoff: chrono::DateTime<chrono::Utc>) -> Result<usize, ApplicationError>;
+        }
+    }
+
+    fn conversation_with(messages: usize, chars_per_message: usize) -> Conversation {
+        let mut conversation = Conversation::new();
+        for i in 0..messages {
+            let content = format!("{i}:{}", "x".repeat(chars_per_message));
+            if i % 2 == 0 {
+                conversation.add_message(ChatMessage::user(content));
+            } else {
+                conversation.add_message(ChatMessage::assistant(content));
+            }
+        }
+        conversation
+    }
+
+    #[test]
+    fn chunk_lines_respects_budget() {
+        let lines: Vec<String> = (0..10).map(|i| format!("line {i}")).collect();
+        let chunks = chunk_lines(&lines, 20);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 20));
+        assert_eq!(chunks.join("\n"), lines.join("\n"));
+    }
+
+    #[test]
+    fn chunk_lines_truncates_oversized_line() {
+        let chunks = chunk_lines(&["ä".repeat(50)], 10);
+        assert_eq!(chunks, vec!["ä".repeat(10)]);
+    }
+
+    #[test]
+    fn transcript_skips_system_messages() {
+        let messages = vec![
+            ChatMessage::system("be nice"),
+            ChatMessage::user("hi"),
+            ChatMessage::assistant("hello"),
+        ];
+        assert_eq!(
+            transcript_lines(&messages),
+            vec!["User: hi", "Assistant: hello"]
+        );
+    }
+
+    #[tokio::test]
+    async fn summarizes_most_recent_conversation_in_one_call() {
+        let conversation = conversation_with(4, 10);
+        let mut store = MockConvStore::new();
+        store
+            .expect_list_recent()
+            .returning(move |_| Ok(vec![conversation.clone()]));
+
+        let mut inference = MockInferenceEngine::new();
+        inference
+            .expect_generate()
+            .withf(|prompt| prompt.contains("at most 2 sentences") && prompt.contains("User: 0:"))
+            .times(1)
+            .returning(|_| Ok(mock_inference_result("We talked about x.")));
+
+        let service =
+            AgentService::new(Arc::new(inference)).with_conversation_store(Arc::new(store));
+        let result = service
+            .execute_command(&AgentCommand::SummarizeConversation {
+                conversation_id: None,
+                max_sentences: Some(2),
+            })
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.response.contains("4 Nachrichten"));
+        assert!(result.response.contains("We talked about x."));
+    }
+
+    #[tokio::test]
+    async fn long_history_is_chunked_then_reduced() {
+        // ~30k characters: three map calls plus one final call
+        let conversation = conversation_with(30, 1_000);
+        let id = conversation.id;
+        let mut store = MockConvStore::new();
+        store
+            .expect_get()
+            .returning(move |_| Ok(Some(conversation.clone())));
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let mut inference = MockInferenceEngine::new();
+        inference.expect_generate().returning(move |prompt| {
+            assert!(prompt.chars().count() < MAX_SUMMARY_INPUT_CHARS + 1_000);
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(mock_inference_result("notes"))
+        });
+
+        let service =
+            AgentService::new(Arc::new(inference)).with_conversation_store(Arc::new(store));
+        let result = service
+            .handle_summarize_conversation(Some(&id.to_string()), None)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn missing_conversation_is_reported() {
+        let mut store = MockConvStore::new();
+        store.expect_get().returning(|_| Ok(None));
+
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_conversation_store(Arc::new(store));
+        let result = service
+            .handle_summarize_conversation(Some(&ConversationId::new().to_string()), None)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.response.contains("nicht gefunden"));
+    }
+
+    #[tokio::test]
+    async fn without_store_reports_not_configured() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+        let result = service
+            .handle_summarize_conversation(None, None)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.response.contains("not configured"));
+    }
+}
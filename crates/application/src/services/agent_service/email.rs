@@ -1,10 +1,10 @@
 //! Email-related handlers: inbox summarization and draft creation
 
 use domain::{EmailAddress, PersistedEmailDraft, UserId};
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use super::{AgentService, ExecutionResult};
-use crate::error::ApplicationError;
+use crate::{error::ApplicationError, ports::EmailTemplateData};
 
 impl AgentService {
     /// Handle inbox summarization command
@@ -48,6 +48,9 @@ impl AgentService {
 
     /// Handle draft email command - create and store the draft
     ///
+    /// With email templates configured, the body is stored with greeting and
+    /// signature, plus an HTML version if the templates provide one.
+    ///
     /// For now uses a default user ID. Future versions will map API keys to users.
     pub(super) async fn handle_draft_email(
         &self,
@@ -71,8 +74,25 @@ impl AgentService {
         };
 
         // Create and save the draft
-        let draft =
+        let mut draft =
             PersistedEmailDraft::new(user_id, to.clone(), subject.clone(), body.to_string());
+        if let Some(ref template) = self.email_template {
+            let data = EmailTemplateData {
+                recipient: to.local_part().to_string(),
+                recipient_email: to.to_string(),
+                subject: subject.clone(),
+                body: body.to_string(),
+                sender: self.get_sender_name(&user_id).await,
+                cc: Vec::new(),
+            };
+            match template.render_email_draft(&data) {
+                Ok(rendered) => {
+                    draft.body = rendered.text;
+                    draft.html_body = rendered.html;
+                },
+                Err(e) => warn!(error = %e, "Failed to render email draft, storing raw body"),
+            }
+        }
         let draft_id = draft.id;
 
         draft_store.save(&draft).await?;
@@ -90,15 +110,35 @@ impl AgentService {
             ),
         })
     }
+
+    /// Name used in the signature of email drafts, from the user profile
+    async fn get_sender_name(&self, user_id: &UserId) -> String {
+        let Some(ref profile_store) = self.user_profile_store else {
+            return String::new();
+        };
+        match profile_store.get(user_id).await {
+            Ok(profile) => profile
+                .and_then(|p| p.name().map(String::from))
+                .unwrap_or_default(),
+            Err(e) => {
+                debug!(error = %e, "Failed to get user profile for email signature");
+                String::new()
+            },
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
-    use domain::{AgentCommand, EmailAddress};
+    use domain::{AgentCommand, EmailAddress, GeoLocation, Timezone, UserId, UserProfile};
 
     use super::super::{AgentService, test_support::MockInferenceEngine};
+    use crate::{
+        error::ApplicationError,
+        ports::{MockDraftStorePort, MockEmailTemplatePort, RenderedEmail, UserProfileStore},
+    };
 
     fn email(addr: &str) -> EmailAddress {
         EmailAddress::new(addr).unwrap()
@@ -178,8 +218,6 @@ mod tests {
 
     #[tokio::test]
     async fn draft_email_with_store_creates_draft() {
-        use crate::ports::MockDraftStorePort;
-
         let mock_inference = MockInferenceEngine::new();
         let mut mock_store = MockDraftStorePort::new();
 
@@ -205,8 +243,6 @@ mod tests {
 
     #[tokio::test]
     async fn draft_email_generates_subject_when_not_provided() {
-        use crate::ports::MockDraftStorePort;
-
         let mock_inference = MockInferenceEngine::new();
         let mut mock_store = MockDraftStorePort::new();
 
@@ -224,10 +260,114 @@ mod tests {
         assert!(result.response.contains("Re: john"));
     }
 
+    struct NamedProfile;
+
+    #[async_trait::async_trait]
+    impl UserProfileStore for NamedProfile {
+        async fn save(&self, _profile: &UserProfile) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn get(&self, user_id: &UserId) -> Result<Option<UserProfile>, ApplicationError> {
+            Ok(Some(
+                UserProfile::new(*user_id).with_name(Some("Alice".to_string())),
+            ))
+        }
+
+        async fn delete(&self, _user_id: &UserId) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn update_location(
+            &self,
+            _user_id: &UserId,
+            _location: Option<&GeoLocation>,
+        ) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn update_timezone(
+            &self,
+            _user_id: &UserId,
+            _timezone: &Timezone,
+        ) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+    }
+
+    fn greeting_template() -> MockEmailTemplatePort {
+        let mut template = MockEmailTemplatePort::new();
+        template.expect_render_email_draft().returning(|data| {
+            Ok(RenderedEmail {
+                text: format!(
+                    "Dear {},\n\n{}\n\nBest regards,\n{}",
+                    data.recipient, data.body, data.sender
+                ),
+                html: Some(format!("<p>Dear {},</p>", data.recipient)),
+            })
+        });
+        template
+    }
+
     #[tokio::test]
-    async fn agent_service_has_draft_store_in_debug() {
-        use crate::ports::MockDraftStorePort;
+    async fn draft_email_is_rendered_with_greeting_and_signature() {
+        let saved = Arc::new(Mutex::new(None));
+        let mut mock_store = MockDraftStorePort::new();
+        let sink = Arc::clone(&saved);
+        mock_store.expect_save().returning(move |draft| {
+            *sink.lock().unwrap() = Some(draft.clone());
+            Ok(draft.id)
+        });
+
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_draft_store(Arc::new(mock_store))
+            .with_email_template(Arc::new(greeting_template()))
+            .with_user_profile_store(Arc::new(NamedProfile));
+
+        let result = service
+            .handle_draft_email(&email("bob@example.com"), Some("Lunch"), "See you at noon.")
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        let draft = saved.lock().unwrap().take().unwrap();
+        assert!(draft.body.starts_with("Dear bob,"));
+        assert!(draft.body.contains("See you at noon."));
+        assert!(draft.body.ends_with("Best regards,\nAlice"));
+        assert_eq!(draft.html_body.as_deref(), Some("<p>Dear bob,</p>"));
+    }
 
+    #[tokio::test]
+    async fn draft_email_keeps_raw_body_when_rendering_fails() {
+        let saved = Arc::new(Mutex::new(None));
+        let mut mock_store = MockDraftStorePort::new();
+        let sink = Arc::clone(&saved);
+        mock_store.expect_save().returning(move |draft| {
+            *sink.lock().unwrap() = Some(draft.clone());
+            Ok(draft.id)
+        });
+        let mut template = MockEmailTemplatePort::new();
+        template
+            .expect_render_email_draft()
+            .returning(|_| Err(ApplicationError::Internal("broken template".to_string())));
+
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_draft_store(Arc::new(mock_store))
+            .with_email_template(Arc::new(template));
+
+        let result = service
+            .handle_draft_email(&email("bob@example.com"), Some("Lunch"), "See you at noon.")
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        let draft = saved.lock().unwrap().take().unwrap();
+        assert_eq!(draft.body, "See you at noon.");
+        assert!(draft.html_body.is_none());
+    }
+
+    #[tokio::test]
+    async fn agent_service_has_draft_store_in_debug() {
         let mock_inference = MockInferenceEngine::new();
         let mock_store = MockDraftStorePort::new();
 
@@ -8,28 +8,38 @@
 //! - [`tasks`]: Task and task list queries
 //! - [`web_search`]: Web search with LLM summarization
 //! - [`transit`]: Public transit connection search
+//! - [`timers`]: One-shot countdown timers
+//! - [`chain`]: Multi-step command chains
+//! - [`conversation_summary`]: TL;DR of stored conversations
+//! - [`tool_calls`]: Dispatch of LLM tool calls to the [`ToolRegistry`]
 
 mod briefing;
+mod chain;
 mod contacts;
+mod conversation_summary;
 mod email;
 mod reminders;
 mod system;
 mod tasks;
+mod timers;
+mod tool_calls;
 mod transit;
 mod web_search;
 
 use std::{fmt, sync::Arc, time::Instant};
 
-use domain::{AgentCommand, GeoLocation, UserId};
+use domain::{AgentCommand, BriefingSection, GeoLocation, Timezone, UserId};
 use tracing::{debug, info, instrument, warn};
 
 use crate::{
-    command_parser::CommandParser,
+    command_parser::{CommandParser, ParserLanguage},
     error::ApplicationError,
     ports::{
-        ContactPort, DraftStorePort, InferencePort, ReminderPort, TaskPort, TransitPort,
-        UserProfileStore, WeatherPort, WebSearchPort,
+        ContactPort, ConversationStore, DraftStorePort, EmailTemplatePort, InferencePort,
+        ReminderPort, TaskPort, TimerPort, TransitPort, UserProfileStore, WeatherPort,
+        WebSearchPort, cancellable,
     },
+    tools::{Tool, ToolRegistry, WebSearchTool},
 };
 
 /// Result of executing an agent command
@@ -70,6 +80,10 @@ pub struct AgentService {
     pub(super) email_service: Option<Arc<super::EmailService>>,
     /// Optional draft store for email draft persistence
     pub(super) draft_store: Option<Arc<dyn DraftStorePort>>,
+    /// Optional templates adding greeting and signature to email drafts
+    pub(super) email_template: Option<Arc<dyn EmailTemplatePort>>,
+    /// Optional conversation store for conversation summaries
+    pub(super) conversation_store: Option<Arc<dyn ConversationStore>>,
     /// Optional user profile store for personalization
     pub(super) user_profile_store: Option<Arc<dyn UserProfileStore>>,
     /// Optional task service for todo/task integration
@@ -80,6 +94,8 @@ pub struct AgentService {
     pub(super) websearch_service: Option<Arc<dyn WebSearchPort>>,
     /// Optional reminder service for reminder management
     pub(super) reminder_service: Option<Arc<dyn ReminderPort>>,
+    /// Optional timer service for countdown timers
+    pub(super) timer_service: Option<Arc<dyn TimerPort>>,
     /// Optional transit service for ÖPNV connections
     pub(super) transit_service: Option<Arc<dyn TransitPort>>,
     /// Optional contact service for contact management (CardDAV)
@@ -88,6 +104,17 @@ pub struct AgentService {
     pub(super) default_weather_location: Option<GeoLocation>,
     /// Home location for transit searches (used when "from" is not specified)
     pub(super) home_location: Option<GeoLocation>,
+    /// Minutes to arrive before the first located appointment; enables the
+    /// briefing's departure suggestion
+    pub(super) briefing_arrival_buffer: Option<u32>,
+    /// Timezone used when the user profile has none
+    pub(super) default_timezone: Timezone,
+    /// Briefing sections used when the user profile has none
+    pub(super) default_briefing_sections: Vec<BriefingSection>,
+    /// Tools the LLM can call
+    pub(super) tools: ToolRegistry,
+    /// Running inputs per messenger sender, superseded by newer ones
+    pub(super) generations: super::InFlightGenerations,
 }
 
 impl fmt::Debug for AgentService {
@@ -97,13 +124,21 @@ impl fmt::Debug for AgentService {
             .field("has_calendar", &self.calendar_service.is_some())
             .field("has_email", &self.email_service.is_some())
             .field("has_draft_store", &self.draft_store.is_some())
+            .field("has_email_template", &self.email_template.is_some())
+            .field("has_conversation_store", &self.conversation_store.is_some())
             .field("has_user_profile", &self.user_profile_store.is_some())
             .field("has_task", &self.task_service.is_some())
             .field("has_weather", &self.weather_service.is_some())
             .field("has_websearch", &self.websearch_service.is_some())
             .field("has_reminder", &self.reminder_service.is_some())
+            .field("has_timer", &self.timer_service.is_some())
             .field("has_transit", &self.transit_service.is_some())
             .field("has_contacts", &self.contact_service.is_some())
+            .field("default_timezone", &self.default_timezone)
+            .field("default_briefing_sections", &self.default_briefing_sections)
+            .field("briefing_arrival_buffer", &self.briefing_arrival_buffer)
+            .field("tools", &self.tools)
+            .field("in_flight", &self.generations.len())
             .finish_non_exhaustive()
     }
 }
@@ -117,15 +152,23 @@ impl AgentService {
             calendar_service: None,
             email_service: None,
             draft_store: None,
+            email_template: None,
+            conversation_store: None,
             user_profile_store: None,
             task_service: None,
             weather_service: None,
             websearch_service: None,
             reminder_service: None,
+            timer_service: None,
             transit_service: None,
             contact_service: None,
             default_weather_location: None,
             home_location: None,
+            briefing_arrival_buffer: None,
+            default_timezone: Timezone::berlin(),
+            default_briefing_sections: BriefingSection::defaults(),
+            tools: ToolRegistry::new(),
+            generations: super::InFlightGenerations::new(),
         }
     }
 
@@ -150,6 +193,20 @@ impl AgentService {
         self
     }
 
+    /// Add templates for rendering email drafts
+    #[must_use]
+    pub fn with_email_template(mut self, template: Arc<dyn EmailTemplatePort>) -> Self {
+        self.email_template = Some(template);
+        self
+    }
+
+    /// Add conversation store for conversation summaries
+    #[must_use]
+    pub fn with_conversation_store(mut self, store: Arc<dyn ConversationStore>) -> Self {
+        self.conversation_store = Some(store);
+        self
+    }
+
     /// Add user profile store for personalization
     #[must_use]
     pub fn with_user_profile_store(mut self, store: Arc<dyn UserProfileStore>) -> Self {
@@ -172,10 +229,12 @@ impl AgentService {
     }
 
     /// Add web search service for internet search capabilities
+    ///
+    /// Also registers the [`WebSearchTool`] so the LLM can search on its own.
     #[must_use]
     pub fn with_websearch_service(mut self, service: Arc<dyn WebSearchPort>) -> Self {
-        self.websearch_service = Some(service);
-        self
+        self.websearch_service = Some(Arc::clone(&service));
+        self.with_tool(Arc::new(WebSearchTool::new(service)))
     }
 
     /// Add reminder service for reminder management
@@ -185,6 +244,13 @@ impl AgentService {
         self
     }
 
+    /// Add timer service for countdown timers
+    #[must_use]
+    pub fn with_timer_service(mut self, service: Arc<dyn TimerPort>) -> Self {
+        self.timer_service = Some(service);
+        self
+    }
+
     /// Add transit service for ÖPNV connection searches
     #[must_use]
     pub fn with_transit_service(mut self, service: Arc<dyn TransitPort>) -> Self {
@@ -213,6 +279,52 @@ impl AgentService {
         self
     }
 
+    /// Suggest in the briefing when to leave for the first located appointment
+    ///
+    /// The suggested connection arrives `arrival_buffer_minutes` before the
+    /// appointment starts.
+    #[must_use]
+    pub const fn with_briefing_departure_suggestion(mut self, arrival_buffer_minutes: u32) -> Self {
+        self.briefing_arrival_buffer = Some(arrival_buffer_minutes);
+        self
+    }
+
+    /// Set the timezone used when the user profile has none
+    #[must_use]
+    pub fn with_default_timezone(mut self, timezone: Timezone) -> Self {
+        self.default_timezone = timezone;
+        self
+    }
+
+    /// Set the briefing sections and their order used when the user profile has none
+    #[must_use]
+    pub fn with_default_briefing_sections(mut self, sections: Vec<BriefingSection>) -> Self {
+        self.default_briefing_sections = BriefingSection::dedup(sections);
+        self
+    }
+
+    /// Set the language assumed when command input is ambiguous
+    #[must_use]
+    pub fn with_default_language(mut self, language: ParserLanguage) -> Self {
+        self.parser = std::mem::take(&mut self.parser).with_default_language(language);
+        self
+    }
+
+    /// Route paraphrased commands by embedding similarity before asking the LLM
+    #[must_use]
+    pub fn with_semantic_router(mut self, router: Arc<SemanticRouter>) -> Self {
+        self.parser = std::mem::take(&mut self.parser).with_semantic_router(router);
+        self
+    }
+
+    /// Register a tool the LLM can call instead of a built-in intent
+    #[must_use]
+    pub fn with_tool(mut self, tool: Arc<dyn Tool>) -> Self {
+        self.tools.register(tool);
+        self.parser = std::mem::take(&mut self.parser).with_tools(&self.tools);
+        self
+    }
+
     /// Parse and execute a command from natural language input
     #[instrument(skip(self, input), fields(input_len = input.len()))]
     pub async fn handle_input(&self, input: &str) -> Result<CommandResult, ApplicationError> {
@@ -231,11 +343,23 @@ impl AgentService {
     ) -> Result<CommandResult, ApplicationError> {
         let start = Instant::now();
 
-        // First, try to parse the command using the LLM
-        let command = self.parser.parse_with_llm(&self.inference, input).await?;
+        // First, try to parse the command(s) using the LLM
+        let mut commands = self.parser.parse_multi(&self.inference, input).await?;
+        if commands.len() > 1 {
+            return self.execute_compound(commands, user_id, start).await;
+        }
+        let Some(command) = commands.pop() else {
+            return Err(ApplicationError::CommandFailed(
+                "No command parsed from input".to_string(),
+            ));
+        };
 
         info!(command = ?command, "Parsed command from input");
 
+        if let AgentCommand::Chain { steps } = command {
+            return self.handle_chain_input(steps, user_id, start).await;
+        }
+
         // Check if approval is required
         if command.requires_approval() {
             debug!(command = ?command, "Command requires approval");
@@ -265,6 +389,99 @@ impl AgentService {
         })
     }
 
+    /// Parse and execute input from a messenger sender
+    ///
+    /// Like [`handle_input_with_user`](Self::handle_input_with_user), but a
+    /// newer input from the same `sender` cancels this one if it is still
+    /// running. The pending inference request is dropped, so the backend
+    /// stops generating a reply nobody is waiting for.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApplicationError::Cancelled`] if a newer input superseded
+    /// this one.
+    #[instrument(skip(self, sender, input, user_id), fields(input_len = input.len()))]
+    pub async fn handle_input_for_sender(
+        &self,
+        sender: &str,
+        input: &str,
+        user_id: Option<UserId>,
+    ) -> Result<CommandResult, ApplicationError> {
+        let generation = self.generations.begin(sender);
+        let result = cancellable(
+            generation.token(),
+            self.handle_input_with_user(input, user_id),
+        )
+        .await;
+
+        if matches!(result, Err(ApplicationError::Cancelled)) {
+            info!("Input superseded by a newer message from the same sender");
+        }
+        result
+    }
+
+    /// Execute the commands of a compound input in order
+    ///
+    /// Responses are concatenated. Commands that require approval are not
+    /// executed; the first of them becomes the result's command so it can go
+    /// through the regular approval flow. A failing command does not stop the
+    /// remaining ones.
+    async fn execute_compound(
+        &self,
+        commands: Vec<AgentCommand>,
+        user_id: Option<UserId>,
+        start: Instant,
+    ) -> Result<CommandResult, ApplicationError> {
+        info!(commands = ?commands, "Parsed compound input");
+
+        let mut responses = Vec::with_capacity(commands.len());
+        let mut success = true;
+        let mut pending: Option<AgentCommand> = None;
+
+        for command in &commands {
+            if command.requires_approval() {
+                debug!(command = ?command, "Command requires approval");
+                responses.push(format!(
+                    "⚠️ Diese Aktion erfordert Bestätigung: {}",
+                    command.description()
+                ));
+                success = false;
+                pending.get_or_insert_with(|| command.clone());
+                continue;
+            }
+
+            match self.execute_command_with_user(command, user_id).await {
+                Ok(result) => {
+                    success &= result.success;
+                    responses.push(result.response);
+                },
+                Err(e) => {
+                    warn!(command = ?command, error = %e, "Compound command failed");
+                    success = false;
+                    responses.push(format!("❌ {}: {e}", command.description()));
+                },
+            }
+        }
+
+        let approval_status = if pending.is_some() {
+            responses.push("Bitte bestätige mit 'OK' oder breche ab mit 'Abbrechen'.".to_string());
+            ApprovalStatus::Pending
+        } else {
+            ApprovalStatus::NotRequired
+        };
+
+        let command = pending.unwrap_or_else(|| commands[0].clone());
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(CommandResult {
+            command,
+            success,
+            response: responses.join("\n\n"),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            approval_status: Some(approval_status),
+        })
+    }
+
     /// Execute a specific command (after parsing/approval)
     #[instrument(skip(self, command))]
     pub async fn execute_command(
@@ -301,6 +518,12 @@ impl AgentService {
 
             AgentCommand::System(sys_cmd) => self.handle_system_command(sys_cmd).await,
 
+            // Chains with an approval step go through the approval flow first
+            AgentCommand::Chain { .. } if command.requires_approval() => {
+                Err(ApplicationError::ApprovalRequired(command.description()))
+            },
+            AgentCommand::Chain { steps } => self.execute_chain(steps, user_id).await,
+
             AgentCommand::Ask { question } => {
                 let response = self.inference.generate(question).await?;
                 Ok(ExecutionResult {
@@ -318,6 +541,14 @@ impl AgentService {
                 only_important,
             } => self.handle_summarize_inbox(*count, *only_important).await,
 
+            AgentCommand::SummarizeConversation {
+                conversation_id,
+                max_sentences,
+            } => {
+                self.handle_summarize_conversation(conversation_id.as_deref(), *max_sentences)
+                    .await
+            },
+
             AgentCommand::Unknown { original_input } => {
                 warn!(input = %original_input, "Unknown command received");
                 Ok(ExecutionResult {
@@ -372,9 +603,15 @@ impl AgentService {
                 title,
                 remind_at,
                 description,
+                recurrence,
             } => {
-                self.handle_create_reminder(title, remind_at, description.as_deref())
-                    .await
+                self.handle_create_reminder(
+                    title,
+                    remind_at,
+                    description.as_deref(),
+                    recurrence.as_deref(),
+                )
+                .await
             },
             AgentCommand::ListReminders { include_done } => {
                 self.handle_list_reminders(*include_done).await
@@ -393,6 +630,11 @@ impl AgentService {
                 self.handle_delete_reminder(reminder_id).await
             },
 
+            // Countdown timer
+            AgentCommand::SetTimer { label, duration } => {
+                self.handle_set_timer(label.as_deref(), *duration).await
+            },
+
             // Transit search
             AgentCommand::SearchTransit {
                 from,
@@ -409,6 +651,11 @@ impl AgentService {
             },
             AgentCommand::GetContact { contact_id } => self.handle_get_contact(contact_id).await,
             AgentCommand::SearchContacts { query } => self.handle_search_contacts(query).await,
+
+            // Tool calls chosen by the LLM
+            AgentCommand::CallTool { name, arguments } => {
+                self.handle_tool_call(name, arguments).await
+            },
         }
     }
 }
@@ -598,10 +845,13 @@ mod async_tests {
     use domain::AgentCommand;
 
     use super::{
-        AgentService,
+        AgentService, ApprovalStatus,
         test_support::{MockInferenceEngine, mock_inference_result},
     };
-    use crate::error::ApplicationError;
+    use crate::{
+        error::ApplicationError,
+        ports::{InferencePort, InferenceResult, InferenceStream},
+    };
 
     #[tokio::test]
     async fn agent_service_new() {
@@ -698,6 +948,44 @@ mod async_tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn handle_input_executes_compound_commands_in_order() {
+        let mock = MockInferenceEngine::new();
+        let service = AgentService::new(Arc::new(mock));
+
+        let result = service.handle_input("version; help").await.unwrap();
+
+        assert!(result.success);
+        assert!(matches!(
+            result.command,
+            AgentCommand::System(domain::SystemCommand::Version)
+        ));
+        let (first, second) = result.response.split_once("\n\n").unwrap();
+        assert!(first.contains(env!("CARGO_PKG_VERSION")));
+        assert!(!second.is_empty());
+    }
+
+    #[tokio::test]
+    async fn handle_input_compound_asks_approval_for_pending_command() {
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system().returning(|_, _| {
+            Ok(mock_inference_result(
+                r#"{"intent":"send_email","draft_id":"draft-123"}"#,
+            ))
+        });
+        let service = AgentService::new(Arc::new(mock));
+
+        let result = service
+            .handle_input("version; schick den Entwurf ab")
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(result.approval_status, Some(ApprovalStatus::Pending));
+        assert!(matches!(result.command, AgentCommand::SendEmail { .. }));
+        assert!(result.response.contains("Bestätigung"));
+    }
+
     #[tokio::test]
     async fn agent_service_debug_output() {
         let mock = MockInferenceEngine::new();
@@ -706,4 +994,122 @@ mod async_tests {
         assert!(debug.contains("AgentService"));
         assert!(debug.contains("parser"));
     }
+
+    /// Inference backend whose intent detection never finishes
+    struct StalledInference;
+
+    #[async_trait::async_trait]
+    impl InferencePort for StalledInference {
+        async fn generate(&self, _message: &str) -> Result<InferenceResult, ApplicationError> {
+            std::future::pending().await
+        }
+
+        async fn generate_with_context(
+            &self,
+            _conversation: &domain::Conversation,
+        ) -> Result<InferenceResult, ApplicationError> {
+            std::future::pending().await
+        }
+
+        async fn generate_with_system(
+            &self,
+            _system_prompt: &str,
+            _message: &str,
+        ) -> Result<InferenceResult, ApplicationError> {
+            std::future::pending().await
+        }
+
+        async fn generate_stream(
+            &self,
+            _message: &str,
+        ) -> Result<InferenceStream, ApplicationError> {
+            std::future::pending().await
+        }
+
+        async fn generate_stream_with_system(
+            &self,
+            _system_prompt: &str,
+            _message: &str,
+        ) -> Result<InferenceStream, ApplicationError> {
+            std::future::pending().await
+        }
+
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+
+        fn current_model(&self) -> String {
+            "stalled".to_string()
+        }
+
+        async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError> {
+            Ok(Vec::new())
+        }
+
+        async fn switch_model(&self, _model_name: &str) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn newer_input_from_same_sender_cancels_running_one() {
+        let service = Arc::new(AgentService::new(Arc::new(StalledInference)));
+
+        let first = tokio::spawn({
+            let service = Arc::clone(&service);
+            async move {
+                service
+                    .handle_input_for_sender(
+                        "+491701234567",
+                        "Erzähl mir eine lange Geschichte",
+                        None,
+                    )
+                    .await
+            }
+        });
+        while service.generations.is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        let second = service
+            .handle_input_for_sender("+491701234567", "echo Schon gut", None)
+            .await
+            .unwrap();
+
+        assert!(second.success);
+        assert!(matches!(
+            first.await.unwrap(),
+            Err(ApplicationError::Cancelled)
+        ));
+        assert!(service.generations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn inputs_from_different_senders_run_independently() {
+        let service = Arc::new(AgentService::new(Arc::new(StalledInference)));
+
+        let first = tokio::spawn({
+            let service = Arc::clone(&service);
+            async move {
+                service
+                    .handle_input_for_sender(
+                        "+491701234567",
+                        "Erzähl mir eine lange Geschichte",
+                        None,
+                    )
+                    .await
+            }
+        });
+        while service.generations.is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        service
+            .handle_input_for_sender("+491709876543", "echo Hallo", None)
+            .await
+            .unwrap();
+
+        assert!(!first.is_finished());
+        first.abort();
+    }
 }
@@ -1,11 +1,12 @@
 //! Reminder CRUD handlers: create, list, snooze, acknowledge, delete
 
 use chrono::Utc;
-use domain::{ReminderId, UserId};
+use domain::{Recurrence, ReminderId, UserId};
 use tracing::info;
 
 use super::{AgentService, ExecutionResult};
 use crate::{
+    date_parser::parse_local_datetime,
     error::ApplicationError,
     ports::ReminderQuery,
     services::{
@@ -21,6 +22,7 @@ impl AgentService {
         title: &str,
         remind_at: &str,
         description: Option<&str>,
+        recurrence: Option<&str>,
     ) -> Result<ExecutionResult, ApplicationError> {
         let Some(ref reminder_service) = self.reminder_service else {
             return Ok(ExecutionResult {
@@ -31,19 +33,16 @@ impl AgentService {
             });
         };
 
-        // Parse the remind_at time
-        let remind_at_time = chrono::DateTime::parse_from_rfc3339(remind_at)
-            .map(|dt| dt.with_timezone(&Utc))
-            .or_else(|_| {
-                chrono::NaiveDateTime::parse_from_str(remind_at, "%Y-%m-%dT%H:%M:%S")
-                    .or_else(|_| chrono::NaiveDateTime::parse_from_str(remind_at, "%Y-%m-%dT%H:%M"))
-                    .map(|ndt| ndt.and_utc())
-            })
-            .map_err(|_| {
-                ApplicationError::CommandFailed(format!(
-                    "Invalid remind_at time format: '{remind_at}'"
-                ))
-            })?;
+        // Times without an offset are wall-clock times in the user's timezone
+        let timezone = self.get_user_timezone().await;
+        let remind_at_time = parse_local_datetime(remind_at, &timezone).ok_or_else(|| {
+            ApplicationError::CommandFailed(format!("Invalid remind_at time format: '{remind_at}'"))
+        })?;
+
+        let recurrence = recurrence
+            .map(str::parse::<Recurrence>)
+            .transpose()
+            .map_err(|e| ApplicationError::CommandFailed(e.to_string()))?;
 
         // Create the reminder
         let mut reminder = domain::Reminder::new(
@@ -55,6 +54,10 @@ impl AgentService {
         if let Some(desc) = description {
             reminder.description = Some(desc.to_string());
         }
+        if let Some(recurrence) = recurrence {
+            reminder = reminder.with_recurrence(recurrence);
+        }
+        let reminder = reminder.with_timezone(timezone);
 
         let reminder_id = reminder.id.to_string();
         reminder_service.save(&reminder).await?;
@@ -211,3 +214,145 @@ impl AgentService {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use chrono::{DateTime, Utc};
+    use domain::{AgentCommand, GeoLocation, Reminder, Timezone, UserId, UserProfile};
+
+    use super::super::{AgentService, test_support::MockInferenceEngine};
+    use crate::{
+        error::ApplicationError,
+        ports::{MockReminderPort, UserProfileStore},
+    };
+
+    struct FixedTimezoneProfile(Timezone);
+
+    #[async_trait::async_trait]
+    impl UserProfileStore for FixedTimezoneProfile {
+        async fn save(&self, _profile: &UserProfile) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn get(&self, _user_id: &UserId) -> Result<Option<UserProfile>, ApplicationError> {
+            Ok(Some(UserProfile::with_defaults(
+                UserId::default(),
+                GeoLocation::berlin(),
+                self.0.clone(),
+            )))
+        }
+
+        async fn delete(&self, _user_id: &UserId) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn update_location(
+            &self,
+            _user_id: &UserId,
+            _location: Option<&GeoLocation>,
+        ) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn update_timezone(
+            &self,
+            _user_id: &UserId,
+            _timezone: &Timezone,
+        ) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+    }
+
+    /// Reminder port that records saved reminders
+    fn recording_store() -> (MockReminderPort, Arc<Mutex<Vec<Reminder>>>) {
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&saved);
+        let mut store = MockReminderPort::new();
+        store.expect_save().returning(move |reminder| {
+            sink.lock().unwrap().push(reminder.clone());
+            Ok(())
+        });
+        (store, saved)
+    }
+
+    async fn create(service: &AgentService, remind_at: &str) {
+        let result = service
+            .execute_command(&AgentCommand::CreateReminder {
+                title: "Standup".to_string(),
+                remind_at: remind_at.to_string(),
+                description: None,
+                recurrence: None,
+            })
+            .await
+            .unwrap();
+        assert!(result.success);
+    }
+
+    fn utc(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[tokio::test]
+    async fn remind_at_is_local_to_profile_timezone_across_dst() {
+        let (store, saved) = recording_store();
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_reminder_service(Arc::new(store))
+            .with_user_profile_store(Arc::new(FixedTimezoneProfile(Timezone::new_york())));
+
+        // New York switches to daylight saving time on 2026-03-08
+        create(&service, "2026-03-07 09:00").await;
+        create(&service, "2026-03-09 09:00").await;
+
+        let saved = saved.lock().unwrap().clone();
+        assert_eq!(saved[0].remind_at, utc("2026-03-07T14:00:00Z"));
+        assert_eq!(saved[1].remind_at, utc("2026-03-09T13:00:00Z"));
+        assert_eq!(saved[0].timezone, Some(Timezone::new_york()));
+    }
+
+    #[tokio::test]
+    async fn remind_at_falls_back_to_default_timezone() {
+        let (store, saved) = recording_store();
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_reminder_service(Arc::new(store))
+            .with_default_timezone(Timezone::london());
+
+        create(&service, "2026-07-01T09:00").await;
+
+        assert_eq!(
+            saved.lock().unwrap()[0].remind_at,
+            utc("2026-07-01T08:00:00Z")
+        );
+    }
+
+    #[tokio::test]
+    async fn recurring_reminder_stays_at_local_time_after_dst() {
+        let (store, saved) = recording_store();
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_reminder_service(Arc::new(store))
+            .with_default_timezone(Timezone::berlin());
+
+        service
+            .execute_command(&AgentCommand::CreateReminder {
+                title: "Pills".to_string(),
+                remind_at: "2026-03-28 09:00".to_string(),
+                description: None,
+                recurrence: Some("daily".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let reminder = saved.lock().unwrap()[0].clone();
+        let next = reminder.recurrence.as_ref().unwrap().next_after_in(
+            reminder.remind_at,
+            reminder.remind_at,
+            reminder.timezone.as_ref().unwrap(),
+        );
+        // 09:00 CET is 08:00 UTC, 09:00 CEST on the next day is 07:00 UTC
+        assert_eq!(reminder.remind_at, utc("2026-03-28T08:00:00Z"));
+        assert_eq!(next, utc("2026-03-29T07:00:00Z"));
+    }
+}
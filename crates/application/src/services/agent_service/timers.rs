@@ -0,0 +1,148 @@
+//! Countdown timer handler
+
+use std::time::Duration;
+
+use tracing::info;
+
+use super::{AgentService, ExecutionResult};
+use crate::error::ApplicationError;
+
+/// Format a timer duration for user-facing messages (e.g. "1h 30min", "45s")
+fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(format!("{hours}h"));
+    }
+    if minutes > 0 {
+        parts.push(format!("{minutes}min"));
+    }
+    if seconds > 0 || parts.is_empty() {
+        parts.push(format!("{seconds}s"));
+    }
+    parts.join(" ")
+}
+
+impl AgentService {
+    /// Handle setting a countdown timer
+    pub(super) async fn handle_set_timer(
+        &self,
+        label: Option<&str>,
+        duration: Duration,
+    ) -> Result<ExecutionResult, ApplicationError> {
+        let Some(ref timer_service) = self.timer_service else {
+            return Ok(ExecutionResult {
+                success: false,
+                response: "⏲️ Timer service not yet configured.".to_string(),
+            });
+        };
+
+        if duration.is_zero() {
+            return Ok(ExecutionResult {
+                success: false,
+                response: "⏲️ Bitte geben Sie eine Dauer für den Timer an.".to_string(),
+            });
+        }
+
+        let formatted = format_duration(duration);
+        let message = label.map_or_else(
+            || format!("⏰ Timer abgelaufen ({formatted})"),
+            |l| format!("⏰ Timer abgelaufen: **{l}** ({formatted})"),
+        );
+
+        let timer_id = timer_service.schedule_once(duration, message).await?;
+        info!(timer_id = %timer_id, delay_secs = duration.as_secs(), "Timer set");
+
+        let response = label.map_or_else(
+            || format!("⏲️ Timer gestellt: {formatted}"),
+            |l| format!("⏲️ Timer **{l}** gestellt: {formatted}"),
+        );
+        Ok(ExecutionResult {
+            success: true,
+            response,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use domain::AgentCommand;
+
+    use super::{
+        super::{AgentService, test_support::MockInferenceEngine},
+        format_duration,
+    };
+    use crate::ports::MockTimerPort;
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_secs(600)), "10min");
+        assert_eq!(format_duration(Duration::from_secs(5400)), "1h 30min");
+        assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+    }
+
+    #[tokio::test]
+    async fn set_timer_without_service_returns_error_message() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+
+        let result = service
+            .execute_command(&AgentCommand::SetTimer {
+                label: None,
+                duration: Duration::from_secs(600),
+            })
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.response.contains("not yet configured"));
+    }
+
+    #[tokio::test]
+    async fn set_timer_schedules_notification() {
+        let mut timer = MockTimerPort::new();
+        timer
+            .expect_schedule_once()
+            .withf(|delay, message| *delay == Duration::from_secs(300) && message.contains("Pasta"))
+            .times(1)
+            .returning(|_, _| Ok("timer_1".to_string()));
+
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_timer_service(Arc::new(timer));
+
+        let result = service
+            .execute_command(&AgentCommand::SetTimer {
+                label: Some("Pasta".to_string()),
+                duration: Duration::from_secs(300),
+            })
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.response.contains("5min"));
+        assert!(result.response.contains("Pasta"));
+    }
+
+    #[tokio::test]
+    async fn set_timer_rejects_zero_duration() {
+        let mut timer = MockTimerPort::new();
+        timer.expect_schedule_once().never();
+
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_timer_service(Arc::new(timer));
+
+        let result = service
+            .execute_command(&AgentCommand::SetTimer {
+                label: None,
+                duration: Duration::ZERO,
+            })
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+    }
+}
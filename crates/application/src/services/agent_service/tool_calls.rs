@@ -0,0 +1,157 @@
+//! Tool call dispatch with LLM-written answers
+
+use serde_json::Value;
+use tracing::{info, warn};
+
+use super::{AgentService, ExecutionResult};
+use crate::error::ApplicationError;
+
+/// Maximum characters of a tool result passed back to the LLM
+const MAX_TOOL_RESULT_CHARS: usize = 8_000;
+
+/// Prompt for turning a tool result into an answer for the user
+const TOOL_ANSWER_TEMPLATE: &str = "You called the tool \"{tool}\" with the arguments {arguments}.\n\n\
+     Tool result (JSON):\n{result}\n\n\
+     Answer the user's request using only this result. Be concise and keep \
+     the language of the arguments. If the result contains sources, cite \
+     them using [number] notation.";
+
+impl AgentService {
+    /// Handle a tool call chosen by the LLM
+    ///
+    /// Invokes the registered tool and lets the LLM phrase the answer from
+    /// its JSON result. Unknown tools and invalid arguments are reported to
+    /// the user instead of failing the request.
+    pub(super) async fn handle_tool_call(
+        &self,
+        name: &str,
+        arguments: &Value,
+    ) -> Result<ExecutionResult, ApplicationError> {
+        info!(tool = %name, "Calling tool");
+
+        let result = match self.tools.invoke(name, arguments.clone()).await {
+            Ok(result) => result,
+            Err(
+                ApplicationError::NotFound(message) | ApplicationError::InvalidOperation(message),
+            ) => {
+                warn!(tool = %name, error = %message, "Tool call rejected");
+                return Ok(ExecutionResult {
+                    success: false,
+                    response: format!("🧰 Tool \"{name}\" could not be called: {message}"),
+                });
+            },
+            Err(e) => return Err(e),
+        };
+
+        let mut result = result.to_string();
+        if let Some((cut, _)) = result.char_indices().nth(MAX_TOOL_RESULT_CHARS) {
+            result.truncate(cut);
+            result.push('…');
+        }
+
+        let prompt = TOOL_ANSWER_TEMPLATE
+            .replace("{tool}", name)
+            .replace("{arguments}", &arguments.to_string())
+            .replace("{result}", &result);
+        let answer = self.inference.generate(&prompt).await?;
+
+        Ok(ExecutionResult {
+            success: true,
+            response: answer.content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use domain::AgentCommand;
+    use serde_json::{Value, json};
+
+    use super::super::{
+        AgentService,
+        test_support::{MockInferenceEngine, mock_inference_result},
+    };
+    use crate::{error::ApplicationError, tools::Tool};
+
+    struct TemperatureTool;
+
+    #[async_trait]
+    impl Tool for TemperatureTool {
+        fn name(&self) -> &str {
+            "temperature"
+        }
+
+        fn description(&self) -> &str {
+            "Current temperature for a city."
+        }
+
+        fn json_schema(&self) -> Value {
+            json!({
+                "type": "object",
+                "properties": {"city": {"type": "string"}},
+                "required": ["city"]
+            })
+        }
+
+        async fn invoke(&self, _args: Value) -> Result<Value, ApplicationError> {
+            Ok(json!({"celsius": 21}))
+        }
+    }
+
+    fn call(arguments: Value) -> AgentCommand {
+        AgentCommand::CallTool {
+            name: "temperature".to_string(),
+            arguments,
+        }
+    }
+
+    #[tokio::test]
+    async fn tool_result_is_answered_by_llm() {
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate()
+            .withf(|prompt| {
+                prompt.contains("\"temperature\"") && prompt.contains("{\"celsius\":21}")
+            })
+            .returning(|_| Ok(mock_inference_result("It is 21 °C in Berlin.")));
+
+        let service = AgentService::new(Arc::new(mock)).with_tool(Arc::new(TemperatureTool));
+        let result = service
+            .execute_command(&call(json!({"city": "Berlin"})))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.response, "It is 21 °C in Berlin.");
+    }
+
+    #[tokio::test]
+    async fn missing_argument_is_reported_without_llm() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_tool(Arc::new(TemperatureTool));
+
+        let result = service.execute_command(&call(json!({}))).await.unwrap();
+
+        assert!(!result.success);
+        assert!(result.response.contains("city"));
+    }
+
+    #[tokio::test]
+    async fn unknown_tool_is_reported() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()));
+
+        let result = service.execute_command(&call(json!({}))).await.unwrap();
+
+        assert!(!result.success);
+        assert!(result.response.contains("Unknown tool"));
+    }
+
+    #[test]
+    fn registered_tools_reach_the_parser() {
+        let service = AgentService::new(Arc::new(MockInferenceEngine::new()))
+            .with_tool(Arc::new(TemperatureTool));
+        assert!(format!("{:?}", service.parser).contains("temperature"));
+    }
+}
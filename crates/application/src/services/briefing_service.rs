@@ -1,11 +1,16 @@
 //! Morning Briefing Service
 //!
 //! Aggregates calendar events and emails into a daily summary.
+//!
+//! Which sections appear, and in what order, is set per service instance
+//! from the user's profile or the configured default.
 
 use chrono::{DateTime, Utc};
-use domain::value_objects::Timezone;
+use domain::{entities::BriefingSection, value_objects::Timezone};
 use serde::{Deserialize, Serialize};
 
+use crate::ports::TransitConnection;
+
 /// Morning briefing data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MorningBriefing {
@@ -13,18 +18,64 @@ pub struct MorningBriefing {
     pub generated_at: DateTime<Utc>,
     /// Date this briefing is for
     pub briefing_date: String,
-    /// Weather summary (optional)
+    /// Sections included in this briefing, in display order
+    pub sections: Vec<BriefingSection>,
+    /// Weather summary
     pub weather: Option<WeatherSummary>,
     /// Calendar events for today
-    pub calendar: CalendarBrief,
+    pub calendar: Option<CalendarBrief>,
     /// Email summary
-    pub email: EmailBrief,
+    pub email: Option<EmailBrief>,
     /// Task summary
-    pub tasks: TaskBrief,
+    pub tasks: Option<TaskBrief>,
+    /// Reminders due today
+    pub reminders: Option<ReminderBrief>,
+    /// Upcoming contact birthdays
+    pub birthdays: Option<BirthdayBrief>,
+    /// Connections to the first appointment with a location
+    pub transit: Option<TransitBrief>,
     /// Natural language summary
     pub summary: String,
 }
 
+/// Data collected for each briefing section
+///
+/// A `None` section is left out of the briefing, e.g. because its backing
+/// service isn't configured.
+#[derive(Debug, Clone, Default)]
+pub struct BriefingData {
+    /// Calendar events for today
+    pub calendar: Option<CalendarBrief>,
+    /// Task summary
+    pub tasks: Option<TaskBrief>,
+    /// Email summary
+    pub email: Option<EmailBrief>,
+    /// Weather summary
+    pub weather: Option<WeatherSummary>,
+    /// Reminders due today
+    pub reminders: Option<ReminderBrief>,
+    /// Upcoming contact birthdays
+    pub birthdays: Option<BirthdayBrief>,
+    /// Connections to the first appointment with a location
+    pub transit: Option<TransitBrief>,
+}
+
+impl BriefingData {
+    /// Check whether data was collected for a section
+    #[must_use]
+    pub const fn has(&self, section: BriefingSection) -> bool {
+        match section {
+            BriefingSection::Calendar => self.calendar.is_some(),
+            BriefingSection::Tasks => self.tasks.is_some(),
+            BriefingSection::Email => self.email.is_some(),
+            BriefingSection::Weather => self.weather.is_some(),
+            BriefingSection::Reminders => self.reminders.is_some(),
+            BriefingSection::Birthdays => self.birthdays.is_some(),
+            BriefingSection::Transit => self.transit.is_some(),
+        }
+    }
+}
+
 /// Weather summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherSummary {
@@ -101,26 +152,86 @@ pub struct TaskBrief {
     pub high_priority: Vec<String>,
 }
 
+/// Reminder portion of briefing
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReminderBrief {
+    /// Reminders due today, earliest first
+    pub due_today: Vec<ReminderSummary>,
+}
+
+/// Summary of a reminder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderSummary {
+    /// Reminder title
+    pub title: String,
+    /// Local time the reminder fires (HH:MM format)
+    pub time: String,
+}
+
+/// Birthday portion of briefing
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BirthdayBrief {
+    /// Names of contacts with a birthday coming up
+    pub names: Vec<String>,
+    /// How many days ahead were checked
+    pub days_ahead: u32,
+}
+
+/// Transit portion of briefing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitBrief {
+    /// Location of the appointment the connections lead to
+    pub destination: String,
+    /// Formatted connection summaries, earliest first
+    pub connections: Vec<String>,
+    /// When to leave to reach the appointment on time, if enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub departure: Option<DepartureSuggestion>,
+}
+
+/// Latest departure that reaches an appointment on time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepartureSuggestion {
+    /// Title of the appointment
+    pub event_title: String,
+    /// Start time of the appointment (HH:MM)
+    pub event_time: String,
+    /// Latest departure time (HH:MM), None if no connection arrives in time
+    pub leave_by: Option<String>,
+}
+
 /// Briefing service for generating morning summaries
 #[derive(Debug, Clone)]
 pub struct BriefingService {
     /// User's timezone
     pub timezone: Timezone,
+    /// Enabled sections in display order
+    sections: Vec<BriefingSection>,
 }
 
 impl Default for BriefingService {
     fn default() -> Self {
-        Self {
-            timezone: Timezone::utc(),
-        }
+        Self::new(Timezone::utc())
     }
 }
 
 impl BriefingService {
-    /// Create a new briefing service with a timezone
+    /// Create a new briefing service with a timezone and all sections enabled
     #[must_use]
-    pub const fn new(timezone: Timezone) -> Self {
-        Self { timezone }
+    pub fn new(timezone: Timezone) -> Self {
+        Self {
+            timezone,
+            sections: BriefingSection::defaults(),
+        }
+    }
+
+    /// Set which sections appear and in what order
+    ///
+    /// Repeated sections are dropped.
+    #[must_use]
+    pub fn with_sections(mut self, sections: Vec<BriefingSection>) -> Self {
+        self.sections = BriefingSection::dedup(sections);
+        self
     }
 
     /// Create a new briefing service with an offset (hours from UTC)
@@ -140,7 +251,7 @@ impl BriefingService {
             -5 => Timezone::new_york(),
             _ => Timezone::utc(),
         };
-        Self { timezone: tz }
+        Self::new(tz)
     }
 
     /// Get the configured timezone
@@ -149,9 +260,61 @@ impl BriefingService {
         &self.timezone
     }
 
-    /// Generate a morning briefing
+    /// Get the enabled sections in display order
+    #[must_use]
+    pub fn sections(&self) -> &[BriefingSection] {
+        &self.sections
+    }
+
+    /// Check whether a section is enabled
+    #[must_use]
+    pub fn is_enabled(&self, section: BriefingSection) -> bool {
+        self.sections.contains(&section)
+    }
+
+    /// Generate a morning briefing from the collected section data
     ///
-    /// This combines calendar, email, and task data into a summary.
+    /// Only enabled sections with data are included, in the configured order.
+    #[must_use]
+    pub fn generate(&self, data: BriefingData) -> MorningBriefing {
+        let now = Utc::now();
+        let briefing_date = now.format("%Y-%m-%d").to_string();
+
+        let sections: Vec<BriefingSection> = self
+            .sections
+            .iter()
+            .copied()
+            .filter(|s| data.has(*s))
+            .collect();
+        let included = |section: BriefingSection| sections.contains(&section);
+
+        let mut briefing = MorningBriefing {
+            generated_at: now,
+            briefing_date,
+            sections: Vec::new(),
+            weather: data.weather.filter(|_| included(BriefingSection::Weather)),
+            calendar: data
+                .calendar
+                .filter(|_| included(BriefingSection::Calendar)),
+            email: data.email.filter(|_| included(BriefingSection::Email)),
+            tasks: data.tasks.filter(|_| included(BriefingSection::Tasks)),
+            reminders: data
+                .reminders
+                .filter(|_| included(BriefingSection::Reminders)),
+            birthdays: data
+                .birthdays
+                .filter(|_| included(BriefingSection::Birthdays)),
+            transit: data.transit.filter(|_| included(BriefingSection::Transit)),
+            summary: String::new(),
+        };
+        briefing.sections = sections;
+        briefing.summary = Self::generate_summary(&briefing);
+        briefing
+    }
+
+    /// Generate a morning briefing from calendar, email, task and weather data
+    ///
+    /// Convenience wrapper around [`Self::generate`] for the four core sections.
     #[must_use]
     pub fn generate_briefing(
         &self,
@@ -160,42 +323,95 @@ impl BriefingService {
         tasks: TaskBrief,
         weather: Option<WeatherSummary>,
     ) -> MorningBriefing {
-        let now = Utc::now();
-        let briefing_date = now.format("%Y-%m-%d").to_string();
-
-        let summary = self.generate_summary(&calendar, &email, &tasks, weather.as_ref());
-
-        MorningBriefing {
-            generated_at: now,
-            briefing_date,
+        self.generate(BriefingData {
+            calendar: Some(calendar),
+            tasks: Some(tasks),
+            email: Some(email),
             weather,
-            calendar,
-            email,
-            tasks,
-            summary,
-        }
+            ..BriefingData::default()
+        })
     }
 
-    /// Generate natural language summary
-    #[allow(clippy::unused_self)]
-    fn generate_summary(
-        &self,
-        calendar: &CalendarBrief,
-        email: &EmailBrief,
-        tasks: &TaskBrief,
-        weather: Option<&WeatherSummary>,
-    ) -> String {
+    /// Generate natural language summary, one part per section
+    fn generate_summary(briefing: &MorningBriefing) -> String {
         let mut parts = Vec::new();
 
-        // Weather summary
-        if let Some(w) = weather {
-            parts.push(format!(
-                "Today's weather: {} with a high of {:.0}°C.",
-                w.condition, w.high
-            ));
+        for section in &briefing.sections {
+            match section {
+                BriefingSection::Calendar => {
+                    if let Some(calendar) = &briefing.calendar {
+                        Self::summarize_calendar(calendar, &mut parts);
+                    }
+                },
+                BriefingSection::Tasks => {
+                    if let Some(tasks) = &briefing.tasks {
+                        Self::summarize_tasks(tasks, &mut parts);
+                    }
+                },
+                BriefingSection::Email => {
+                    if let Some(email) = &briefing.email {
+                        Self::summarize_email(email, &mut parts);
+                    }
+                },
+                BriefingSection::Weather => {
+                    if let Some(w) = &briefing.weather {
+                        parts.push(format!(
+                            "Today's weather: {} with a high of {:.0}°C.",
+                            w.condition, w.high
+                        ));
+                    }
+                },
+                BriefingSection::Reminders => {
+                    if let Some(reminders) = &briefing.reminders {
+                        match reminders.due_today.as_slice() {
+                            [] => {},
+                            [only] => parts.push(format!(
+                                "You have 1 reminder today: {} at {}.",
+                                only.title, only.time
+                            )),
+                            [first, ..] => parts.push(format!(
+                                "You have {} reminders today. First: {} at {}.",
+                                reminders.due_today.len(),
+                                first.title,
+                                first.time
+                            )),
+                        }
+                    }
+                },
+                BriefingSection::Birthdays => {
+                    let names = briefing.birthdays.as_ref().map(|b| &b.names);
+                    if let Some(names) = names.filter(|n| !n.is_empty()) {
+                        parts.push(format!("Upcoming birthdays: {}.", names.join(", ")));
+                    }
+                },
+                BriefingSection::Transit => {
+                    let departure = briefing.transit.as_ref().and_then(|t| t.departure.as_ref());
+                    if let Some(departure) = departure {
+                        parts.push(departure.leave_by.as_ref().map_or_else(
+                            || format!("No connection reaches {} in time.", departure.event_title),
+                            |leave_by| {
+                                format!(
+                                    "Leave by {leave_by} to reach {} at {}.",
+                                    departure.event_title, departure.event_time
+                                )
+                            },
+                        ));
+                    }
+                    let next = briefing
+                        .transit
+                        .as_ref()
+                        .and_then(|t| t.connections.first().map(|c| (&t.destination, c)));
+                    if let Some((destination, connection)) = next {
+                        parts.push(format!("Next connection to {destination}: {connection}."));
+                    }
+                },
+            }
         }
 
-        // Calendar summary
+        parts.join(" ")
+    }
+
+    fn summarize_calendar(calendar: &CalendarBrief, parts: &mut Vec<String>) {
         match calendar.event_count {
             0 => parts.push("Your calendar is clear today.".to_string()),
             1 => {
@@ -218,7 +434,16 @@ impl BriefingService {
             },
         }
 
-        // Email summary
+        // Conflicts warning
+        if !calendar.conflicts.is_empty() {
+            parts.push(format!(
+                "⚠️ Calendar conflict detected: {}",
+                calendar.conflicts.join(", ")
+            ));
+        }
+    }
+
+    fn summarize_email(email: &EmailBrief, parts: &mut Vec<String>) {
         if email.unread_count > 0 {
             let important_note = if email.important_count > 0 {
                 format!(", {} marked important", email.important_count)
@@ -230,8 +455,9 @@ impl BriefingService {
                 email.unread_count, important_note
             ));
         }
+    }
 
-        // Task summary
+    fn summarize_tasks(tasks: &TaskBrief, parts: &mut Vec<String>) {
         if tasks.due_today > 0 || tasks.overdue > 0 {
             let mut task_parts = Vec::new();
             if tasks.due_today > 0 {
@@ -242,16 +468,6 @@ impl BriefingService {
             }
             parts.push(format!("Tasks: {}.", task_parts.join(", ")));
         }
-
-        // Conflicts warning
-        if !calendar.conflicts.is_empty() {
-            parts.push(format!(
-                "⚠️ Calendar conflict detected: {}",
-                calendar.conflicts.join(", ")
-            ));
-        }
-
-        parts.join(" ")
     }
 
     /// Format time from ISO 8601 to HH:MM
@@ -267,6 +483,18 @@ impl BriefingService {
         iso_time.to_string()
     }
 
+    /// Pick the connection that departs latest while arriving by `arrive_by`
+    #[must_use]
+    pub fn latest_departure(
+        connections: &[TransitConnection],
+        arrive_by: DateTime<Utc>,
+    ) -> Option<&TransitConnection> {
+        connections
+            .iter()
+            .filter(|c| c.arrival_time <= arrive_by)
+            .max_by_key(|c| c.departure_time)
+    }
+
     /// Check for calendar conflicts (overlapping events)
     #[must_use]
     pub fn detect_conflicts(events: &[EventSummary]) -> Vec<String> {
@@ -639,4 +867,178 @@ mod tests {
         assert!(briefing.summary.contains("1 event"));
         assert!(briefing.summary.contains("Dentist Appointment"));
     }
+
+    fn sunny() -> WeatherSummary {
+        WeatherSummary {
+            temperature: 18.0,
+            condition: "Sunny".to_string(),
+            high: 22.0,
+            low: 12.0,
+        }
+    }
+
+    #[test]
+    fn generate_follows_configured_section_order() {
+        let service = BriefingService::new(Timezone::utc())
+            .with_sections(vec![BriefingSection::Calendar, BriefingSection::Weather]);
+
+        let briefing = service.generate(BriefingData {
+            calendar: Some(CalendarBrief::default()),
+            weather: Some(sunny()),
+            ..BriefingData::default()
+        });
+
+        assert_eq!(
+            briefing.sections,
+            vec![BriefingSection::Calendar, BriefingSection::Weather]
+        );
+        let calendar_pos = briefing.summary.find("calendar is clear").unwrap();
+        let weather_pos = briefing.summary.find("Sunny").unwrap();
+        assert!(calendar_pos < weather_pos);
+    }
+
+    #[test]
+    fn generate_leaves_out_disabled_sections() {
+        let service = BriefingService::new(Timezone::utc())
+            .with_sections(vec![BriefingSection::Weather, BriefingSection::Tasks]);
+
+        let briefing = service.generate(BriefingData {
+            calendar: Some(CalendarBrief::default()),
+            email: Some(EmailBrief {
+                unread_count: 4,
+                ..EmailBrief::default()
+            }),
+            weather: Some(sunny()),
+            ..BriefingData::default()
+        });
+
+        assert_eq!(briefing.sections, vec![BriefingSection::Weather]);
+        assert!(briefing.calendar.is_none());
+        assert!(briefing.email.is_none());
+        assert!(!briefing.summary.contains("calendar"));
+        assert!(!briefing.summary.contains("unread"));
+        assert!(briefing.summary.contains("Sunny"));
+    }
+
+    #[test]
+    fn generate_skips_sections_without_data() {
+        let service = BriefingService::default();
+        let briefing = service.generate(BriefingData {
+            tasks: Some(TaskBrief::default()),
+            ..BriefingData::default()
+        });
+
+        assert_eq!(briefing.sections, vec![BriefingSection::Tasks]);
+        assert!(briefing.weather.is_none());
+    }
+
+    #[test]
+    fn generate_summarizes_reminders_birthdays_and_transit() {
+        let service = BriefingService::default().with_sections(vec![
+            BriefingSection::Transit,
+            BriefingSection::Birthdays,
+            BriefingSection::Reminders,
+        ]);
+
+        let briefing = service.generate(BriefingData {
+            reminders: Some(ReminderBrief {
+                due_today: vec![ReminderSummary {
+                    title: "Call mum".to_string(),
+                    time: "18:00".to_string(),
+                }],
+            }),
+            birthdays: Some(BirthdayBrief {
+                names: vec!["Anna".to_string(), "Ben".to_string()],
+                days_ahead: 7,
+            }),
+            transit: Some(TransitBrief {
+                destination: "Office".to_string(),
+                connections: vec!["08:12 → 08:40".to_string()],
+                departure: None,
+            }),
+            ..BriefingData::default()
+        });
+
+        assert!(
+            briefing
+                .summary
+                .contains("1 reminder today: Call mum at 18:00")
+        );
+        assert!(briefing.summary.contains("Upcoming birthdays: Anna, Ben."));
+        assert!(briefing.summary.starts_with("Next connection to Office"));
+    }
+
+    fn connection(departure: &str, arrival: &str) -> TransitConnection {
+        let at = |time: &str| {
+            DateTime::parse_from_rfc3339(&format!("2024-01-15T{time}:00Z"))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        TransitConnection {
+            departure_time: at(departure),
+            arrival_time: at(arrival),
+            duration_minutes: 30,
+            transfers: 0,
+            legs: Vec::new(),
+            delay_info: None,
+        }
+    }
+
+    #[test]
+    fn latest_departure_arrives_in_time() {
+        let connections = vec![
+            connection("07:40", "08:10"),
+            connection("08:10", "08:40"),
+            connection("08:25", "08:58"),
+        ];
+        let arrive_by = DateTime::parse_from_rfc3339("2024-01-15T08:55:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let latest = BriefingService::latest_departure(&connections, arrive_by).unwrap();
+        assert_eq!(latest.departure_time.format("%H:%M").to_string(), "08:10");
+    }
+
+    #[test]
+    fn latest_departure_none_when_unreachable() {
+        let connections = vec![connection("08:25", "08:58")];
+        let arrive_by = DateTime::parse_from_rfc3339("2024-01-15T08:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert!(BriefingService::latest_departure(&connections, arrive_by).is_none());
+    }
+
+    #[test]
+    fn generate_summarizes_departure_suggestion() {
+        let service = BriefingService::default().with_sections(vec![BriefingSection::Transit]);
+
+        let briefing = service.generate(BriefingData {
+            transit: Some(TransitBrief {
+                destination: "Office".to_string(),
+                connections: Vec::new(),
+                departure: Some(DepartureSuggestion {
+                    event_title: "Standup".to_string(),
+                    event_time: "09:00".to_string(),
+                    leave_by: Some("08:12".to_string()),
+                }),
+            }),
+            ..BriefingData::default()
+        });
+
+        assert_eq!(
+            briefing.summary,
+            "Leave by 08:12 to reach Standup at 09:00."
+        );
+    }
+
+    #[test]
+    fn with_sections_drops_repeats() {
+        let service = BriefingService::default()
+            .with_sections(vec![BriefingSection::Email, BriefingSection::Email]);
+
+        assert_eq!(service.sections(), [BriefingSection::Email]);
+        assert!(service.is_enabled(BriefingSection::Email));
+        assert!(!service.is_enabled(BriefingSection::Calendar));
+    }
 }
@@ -1,22 +1,76 @@
 //! Chat service - Conversation handling with optional persistence
 //!
 //! This service provides both stateless single-message chat and stateful
-//! conversation handling with automatic message truncation.
+//! conversation handling with automatic message truncation. With rolling
+//! summarization enabled, old turns are condensed into a summary instead of
+//! being dropped. A prompt template, if configured, renders the system prompt
+//! per request with the user's name, today's date and location. Stored
+//! overrides for a conversation or messenger contact take precedence over
+//! both.
 
 use std::{fmt, sync::Arc, time::Instant};
 
-use domain::{ChatMessage, Conversation, ConversationId, MessageMetadata, MessageRole};
-use tracing::{debug, info, instrument};
+use chrono::Utc;
+use domain::{
+    ChatMessage, Conversation, ConversationId, MessageMetadata, MessageRole, UserId,
+    entities::UserProfile, value_objects::Timezone,
+};
+use futures::StreamExt;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
 
 use crate::{
     error::ApplicationError,
-    ports::{ConversationStore, InferencePort, InferenceStream},
+    ports::{
+        ConversationStore, InferencePort, InferenceStream, PromptContext, PromptOverrideTarget,
+        PromptTemplatePort, SystemPromptStore, UserProfileStore, cancellable, cancellable_stream,
+    },
+    services::{GenerationGuard, InFlightGenerations},
 };
 
 /// Maximum number of messages to retain in a conversation (FIFO truncation).
 /// System prompt is always preserved.
 pub const MAX_CONVERSATION_MESSAGES: usize = 50;
 
+/// Prompt for condensing old turns; `{previous}` and `{transcript}` are
+/// substituted before sending
+const SUMMARY_PROMPT: &str = "You maintain the long-term memory of a conversation between \
+a user and an assistant. Merge the previous summary and the following messages into one \
+concise summary of at most 10 sentences. Keep facts about the user, decisions, open tasks \
+and anything the assistant promised. Answer in the language of the conversation.\n\n\
+Previous summary:\n{previous}\n\nMessages:\n{transcript}\n\nSummary:";
+
+/// Rolling summarization settings
+///
+/// Once a conversation holds more than `threshold` user/assistant messages,
+/// all but the `keep_recent` newest are summarized into
+/// [`Conversation::summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SummarizationConfig {
+    /// Message count that triggers summarization
+    pub threshold: usize,
+    /// Newest messages kept verbatim
+    pub keep_recent: usize,
+}
+
+impl SummarizationConfig {
+    /// Create a config; `keep_recent` is clamped below `threshold`
+    #[must_use]
+    pub fn new(threshold: usize, keep_recent: usize) -> Self {
+        let threshold = threshold.max(2);
+        Self {
+            threshold,
+            keep_recent: keep_recent.clamp(1, threshold - 1),
+        }
+    }
+}
+
+impl Default for SummarizationConfig {
+    fn default() -> Self {
+        Self::new(40, 10)
+    }
+}
+
 /// Service for handling chat conversations
 ///
 /// Supports both stateless single-message chat and stateful conversation handling
@@ -25,6 +79,12 @@ pub struct ChatService {
     inference: Arc<dyn InferencePort>,
     conversation_store: Option<Arc<dyn ConversationStore>>,
     system_prompt: Option<String>,
+    summarization: Option<SummarizationConfig>,
+    prompt_template: Option<Arc<dyn PromptTemplatePort>>,
+    user_profile_store: Option<Arc<dyn UserProfileStore>>,
+    prompt_overrides: Option<Arc<dyn SystemPromptStore>>,
+    default_timezone: Timezone,
+    generations: InFlightGenerations,
 }
 
 impl fmt::Debug for ChatService {
@@ -32,10 +92,55 @@ impl fmt::Debug for ChatService {
         f.debug_struct("ChatService")
             .field("system_prompt", &self.system_prompt)
             .field("has_conversation_store", &self.conversation_store.is_some())
+            .field("summarization", &self.summarization)
+            .field("has_prompt_template", &self.prompt_template.is_some())
+            .field("has_user_profile", &self.user_profile_store.is_some())
+            .field("has_prompt_overrides", &self.prompt_overrides.is_some())
+            .field("default_timezone", &self.default_timezone)
+            .field("in_flight", &self.generations.len())
             .finish_non_exhaustive()
     }
 }
 
+/// Streamed reply that is written to the conversation once complete
+struct PendingReply {
+    inner: InferenceStream,
+    conversation: Conversation,
+    store: Arc<dyn ConversationStore>,
+    is_new: bool,
+    started: Instant,
+    content: String,
+    _generation: Option<GenerationGuard>,
+}
+
+impl PendingReply {
+    /// Append the accumulated reply to the conversation and persist it
+    async fn persist(&mut self, model: Option<String>) {
+        #[allow(clippy::cast_possible_truncation)]
+        let latency = self.started.elapsed().as_millis() as u64;
+
+        let response = ChatMessage::assistant(&self.content).with_metadata(MessageMetadata {
+            model,
+            tokens: None,
+            latency_ms: Some(latency),
+            external_id: None,
+        });
+        self.conversation.add_message(response);
+
+        let conv_id = self.conversation.id;
+        let result = if self.is_new {
+            self.store.save(&self.conversation).await
+        } else {
+            self.store.update(&self.conversation).await
+        };
+
+        match result {
+            Ok(()) => debug!(conv_id = %conv_id, latency_ms = latency, "Streamed reply persisted"),
+            Err(e) => warn!(conv_id = %conv_id, error = %e, "Failed to persist streamed reply"),
+        }
+    }
+}
+
 impl ChatService {
     /// Create a new chat service (stateless mode)
     pub fn new(inference: Arc<dyn InferencePort>) -> Self {
@@ -43,6 +148,12 @@ impl ChatService {
             inference,
             conversation_store: None,
             system_prompt: None,
+            summarization: None,
+            prompt_template: None,
+            user_profile_store: None,
+            prompt_overrides: None,
+            default_timezone: Timezone::utc(),
+            generations: InFlightGenerations::new(),
         }
     }
 
@@ -55,6 +166,12 @@ impl ChatService {
             inference,
             conversation_store: Some(store),
             system_prompt: None,
+            summarization: None,
+            prompt_template: None,
+            user_profile_store: None,
+            prompt_overrides: None,
+            default_timezone: Timezone::utc(),
+            generations: InFlightGenerations::new(),
         }
     }
 
@@ -67,6 +184,12 @@ impl ChatService {
             inference,
             conversation_store: None,
             system_prompt: Some(prompt.into()),
+            summarization: None,
+            prompt_template: None,
+            user_profile_store: None,
+            prompt_overrides: None,
+            default_timezone: Timezone::utc(),
+            generations: InFlightGenerations::new(),
         }
     }
 
@@ -80,6 +203,12 @@ impl ChatService {
             inference,
             conversation_store: Some(store),
             system_prompt: Some(system_prompt.into()),
+            summarization: None,
+            prompt_template: None,
+            user_profile_store: None,
+            prompt_overrides: None,
+            default_timezone: Timezone::utc(),
+            generations: InFlightGenerations::new(),
         }
     }
 
@@ -93,13 +222,57 @@ impl ChatService {
         self.system_prompt = Some(prompt.into());
     }
 
+    /// Summarize old turns of contextual conversations instead of dropping them
+    #[must_use]
+    pub const fn with_summarization(mut self, config: SummarizationConfig) -> Self {
+        self.summarization = Some(config);
+        self
+    }
+
+    /// Render the system prompt from a template instead of using it verbatim
+    ///
+    /// The static system prompt remains the fallback if rendering fails.
+    #[must_use]
+    pub fn with_prompt_template(mut self, template: Arc<dyn PromptTemplatePort>) -> Self {
+        self.prompt_template = Some(template);
+        self
+    }
+
+    /// Set the user profile store used for prompt template variables
+    #[must_use]
+    pub fn with_user_profile_store(mut self, store: Arc<dyn UserProfileStore>) -> Self {
+        self.user_profile_store = Some(store);
+        self
+    }
+
+    /// Consult stored per-conversation and per-contact system prompts
+    ///
+    /// An override for the conversation wins over one for its messenger
+    /// contact; without either, the global (or templated) prompt is used.
+    #[must_use]
+    pub fn with_prompt_overrides(mut self, store: Arc<dyn SystemPromptStore>) -> Self {
+        self.prompt_overrides = Some(store);
+        self
+    }
+
+    /// Set the timezone for today's date when the user has no profile
+    #[must_use]
+    pub fn with_default_timezone(mut self, timezone: Timezone) -> Self {
+        self.default_timezone = timezone;
+        self
+    }
+
     /// Handle a single chat message (stateless)
     #[instrument(skip(self, message), fields(message_len = message.len()))]
     pub async fn chat(&self, message: &str) -> Result<ChatMessage, ApplicationError> {
         let start = Instant::now();
 
-        let result = match &self.system_prompt {
-            Some(system) => self.inference.generate_with_system(system, message).await?,
+        let result = match self.system_prompt_for(None).await {
+            Some(system) => {
+                self.inference
+                    .generate_with_system(&system, message)
+                    .await?
+            },
             None => self.inference.generate(message).await?,
         };
 
@@ -117,6 +290,7 @@ impl ChatService {
             model: Some(result.model),
             tokens: result.tokens_used,
             latency_ms: Some(latency),
+            external_id: None,
         });
 
         Ok(response)
@@ -146,6 +320,7 @@ impl ChatService {
             model: Some(result.model),
             tokens: result.tokens_used,
             latency_ms: Some(latency),
+            external_id: None,
         });
 
         Ok(response)
@@ -171,16 +346,34 @@ impl ChatService {
     /// Returns a stream of chunks that can be forwarded directly to SSE
     #[instrument(skip(self, message), fields(message_len = message.len()))]
     pub async fn chat_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError> {
-        match &self.system_prompt {
+        match self.system_prompt_for(None).await {
             Some(system) => {
                 self.inference
-                    .generate_stream_with_system(system, message)
+                    .generate_stream_with_system(&system, message)
                     .await
             },
             None => self.inference.generate_stream(message).await,
         }
     }
 
+    /// Handle a streaming chat message (stateless) answered by a specific model
+    #[instrument(skip(self, message), fields(message_len = message.len()))]
+    pub async fn chat_stream_with_model(
+        &self,
+        message: &str,
+        model: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        let mut conversation = match self.system_prompt_for(None).await {
+            Some(system) => Conversation::with_system_prompt(system),
+            None => Conversation::new(),
+        };
+        conversation.add_user_message(message);
+
+        self.inference
+            .generate_stream_with_context_and_model(&conversation, model)
+            .await
+    }
+
     /// Handle a chat message with optional conversation context.
     ///
     /// If `conversation_id` is provided and the conversation exists, continues it.
@@ -191,11 +384,27 @@ impl ChatService {
     /// The system prompt (if any) is always preserved during truncation.
     ///
     /// Returns a tuple of (response message, conversation_id).
-    #[instrument(skip(self, message, conversation_id), fields(message_len = message.len(), conv_id = ?conversation_id))]
     pub async fn chat_with_context(
         &self,
         message: &str,
         conversation_id: Option<&str>,
+    ) -> Result<(ChatMessage, ConversationId), ApplicationError> {
+        self.chat_with_context_for_user(message, conversation_id, None)
+            .await
+    }
+
+    /// Handle a chat message with conversation context on behalf of a user
+    ///
+    /// Like [`chat_with_context`](Self::chat_with_context); conversations
+    /// created by this call are owned by `user_id` (the default user if `None`).
+    /// A newer request from the same user cancels this one while its reply is
+    /// still being generated, returning [`ApplicationError::Cancelled`].
+    #[instrument(skip(self, message, conversation_id), fields(message_len = message.len(), conv_id = ?conversation_id))]
+    pub async fn chat_with_context_for_user(
+        &self,
+        message: &str,
+        conversation_id: Option<&str>,
+        user_id: Option<UserId>,
     ) -> Result<(ChatMessage, ConversationId), ApplicationError> {
         let store = self.conversation_store.as_ref().ok_or_else(|| {
             ApplicationError::Configuration(
@@ -203,44 +412,26 @@ impl ChatService {
             )
         })?;
 
-        // Resolve or create conversation
-        let (mut conversation, is_new) = if let Some(id_str) = conversation_id {
-            let conv_id = ConversationId::parse(id_str).map_err(|e| {
-                ApplicationError::InvalidOperation(format!("Invalid conversation ID: {e}"))
-            })?;
-            store.get(&conv_id).await?.map_or_else(
-                || {
-                    // Create new conversation with the provided ID
-                    let mut conv = self
-                        .system_prompt
-                        .as_ref()
-                        .map_or_else(Conversation::new, Conversation::with_system_prompt);
-                    // Override the auto-generated ID with the provided one
-                    conv.id = conv_id;
-                    (conv, true)
-                },
-                |conv| (conv, false),
-            )
-        } else {
-            // Create new conversation with auto-generated ID
-            let conv = self
-                .system_prompt
-                .as_ref()
-                .map_or_else(Conversation::new, Conversation::with_system_prompt);
-            (conv, true)
-        };
+        let (mut conversation, is_new) = self
+            .resolve_conversation(store.as_ref(), conversation_id, user_id)
+            .await?;
 
         let conv_id = conversation.id;
 
         // Add user message
         conversation.add_user_message(message);
 
-        // Apply FIFO truncation if needed (preserve system prompt)
+        // Condense old turns, then apply FIFO truncation as a hard cap
+        // (preserve system prompt)
+        self.summarize_old_turns(&mut conversation).await;
         Self::truncate_conversation(&mut conversation);
 
-        // Generate response
+        // Generate response; a newer request from the same user cancels it
+        let (generation, cancel) = self.begin_generation(user_id);
         let start = Instant::now();
-        let result = self.inference.generate_with_context(&conversation).await?;
+        let result =
+            cancellable(&cancel, self.inference.generate_with_context(&conversation)).await?;
+        drop(generation);
 
         #[allow(clippy::cast_possible_truncation)]
         let latency = start.elapsed().as_millis() as u64;
@@ -260,6 +451,7 @@ impl ChatService {
             model: Some(result.model),
             tokens: result.tokens_used,
             latency_ms: Some(latency),
+            external_id: None,
         });
 
         // Add assistant response to conversation
@@ -276,6 +468,300 @@ impl ChatService {
         Ok((response, conv_id))
     }
 
+    /// Handle a streaming chat message with conversation context.
+    ///
+    /// Resolves the conversation like [`chat_with_context`](Self::chat_with_context)
+    /// and returns the response stream together with the conversation ID.
+    /// The assistant reply is persisted once the final chunk has been
+    /// streamed; a stream that is dropped early leaves the conversation as it
+    /// was before the request.
+    pub async fn chat_stream_with_context(
+        &self,
+        message: &str,
+        conversation_id: Option<&str>,
+    ) -> Result<(InferenceStream, ConversationId), ApplicationError> {
+        self.chat_stream_with_context_for_user(message, conversation_id, None)
+            .await
+    }
+
+    /// Handle a streaming chat message with conversation context on behalf of a user
+    ///
+    /// Like [`chat_stream_with_context`](Self::chat_stream_with_context);
+    /// conversations created by this call are owned by `user_id`. A newer
+    /// request from the same user ends this stream with
+    /// [`ApplicationError::Cancelled`].
+    pub async fn chat_stream_with_context_for_user(
+        &self,
+        message: &str,
+        conversation_id: Option<&str>,
+        user_id: Option<UserId>,
+    ) -> Result<(InferenceStream, ConversationId), ApplicationError> {
+        self.chat_stream_with_context_and_model(message, conversation_id, user_id, None)
+            .await
+    }
+
+    /// Handle a streaming chat message with conversation context, optionally
+    /// answered by a specific model
+    ///
+    /// Like [`chat_stream_with_context_for_user`](Self::chat_stream_with_context_for_user);
+    /// with `model`, the reply is generated by that model instead of the
+    /// current one.
+    #[instrument(skip(self, message, conversation_id), fields(message_len = message.len(), conv_id = ?conversation_id))]
+    pub async fn chat_stream_with_context_and_model(
+        &self,
+        message: &str,
+        conversation_id: Option<&str>,
+        user_id: Option<UserId>,
+        model: Option<&str>,
+    ) -> Result<(InferenceStream, ConversationId), ApplicationError> {
+        let store = Arc::clone(self.conversation_store.as_ref().ok_or_else(|| {
+            ApplicationError::Configuration(
+                "Conversation store not configured for contextual chat".to_string(),
+            )
+        })?);
+
+        let (mut conversation, is_new) = self
+            .resolve_conversation(store.as_ref(), conversation_id, user_id)
+            .await?;
+        let conv_id = conversation.id;
+
+        conversation.add_user_message(message);
+        self.summarize_old_turns(&mut conversation).await;
+        Self::truncate_conversation(&mut conversation);
+
+        let (generation, cancel) = self.begin_generation(user_id);
+        let inner = cancellable(&cancel, async {
+            match model {
+                Some(model) => {
+                    self.inference
+                        .generate_stream_with_context_and_model(&conversation, model)
+                        .await
+                },
+                None => {
+                    self.inference
+                        .generate_stream_with_context(&conversation)
+                        .await
+                },
+            }
+        })
+        .await?;
+
+        let pending = PendingReply {
+            inner: cancellable_stream(inner, cancel),
+            conversation,
+            store,
+            is_new,
+            started: Instant::now(),
+            content: String::new(),
+            _generation: generation,
+        };
+
+        let stream = futures::stream::unfold(Some(pending), |pending| async move {
+            let mut pending = pending?;
+            let item = pending.inner.next().await?;
+
+            let finished_model = match &item {
+                Ok(chunk) => {
+                    pending.content.push_str(&chunk.content);
+                    chunk.done.then(|| chunk.model.clone())
+                },
+                Err(_) => None,
+            };
+
+            match finished_model {
+                Some(model) => {
+                    pending.persist(model).await;
+                    Some((item, None))
+                },
+                None => Some((item, Some(pending))),
+            }
+        });
+
+        Ok((Box::pin(stream), conv_id))
+    }
+
+    /// Register a generation for `user_id`, cancelling the user's previous one
+    ///
+    /// Requests without a user are never cancelled.
+    fn begin_generation(
+        &self,
+        user_id: Option<UserId>,
+    ) -> (Option<GenerationGuard>, CancellationToken) {
+        match user_id {
+            Some(user) => {
+                let generation = self.generations.begin(&user.to_string());
+                let cancel = generation.token().clone();
+                (Some(generation), cancel)
+            },
+            None => (None, CancellationToken::new()),
+        }
+    }
+
+    /// Load the conversation with the given ID, or start a new one
+    ///
+    /// Returns the conversation and whether it still has to be saved (as
+    /// opposed to updated). New conversations are owned by `user_id`; a
+    /// conversation of another user is reported as not found.
+    async fn resolve_conversation(
+        &self,
+        store: &dyn ConversationStore,
+        conversation_id: Option<&str>,
+        user_id: Option<UserId>,
+    ) -> Result<(Conversation, bool), ApplicationError> {
+        let owner = user_id.unwrap_or_default();
+        let system_prompt = self.system_prompt_for(user_id).await;
+        let (mut conv, is_new) = if let Some(id_str) = conversation_id {
+            let conv_id = ConversationId::parse(id_str).map_err(|e| {
+                ApplicationError::InvalidOperation(format!("Invalid conversation ID: {e}"))
+            })?;
+            match store.get(&conv_id).await? {
+                Some(conv) if !conv.is_owned_by(&owner) => {
+                    warn!(
+                        conversation_id = %conv_id,
+                        user_id = %owner,
+                        "Conversation access by non-owner rejected"
+                    );
+                    return Err(ApplicationError::NotFound(format!(
+                        "Conversation {id_str} not found"
+                    )));
+                },
+                Some(mut conv) => {
+                    // Keep templated prompts current (e.g. the date) across turns
+                    if self.prompt_template.is_some() && conv.system_prompt.is_some() {
+                        if let Some(prompt) = system_prompt {
+                            conv.system_prompt = Some(prompt);
+                        }
+                    }
+                    (conv, false)
+                },
+                None => {
+                    // Create new conversation with the provided ID
+                    let mut conv = system_prompt
+                        .map_or_else(Conversation::new, Conversation::with_system_prompt);
+                    // Override the auto-generated ID with the provided one
+                    conv.id = conv_id;
+                    (conv.with_user_id(owner), true)
+                },
+            }
+        } else {
+            // Create new conversation with auto-generated ID
+            let conv =
+                system_prompt.map_or_else(Conversation::new, Conversation::with_system_prompt);
+            (conv.with_user_id(owner), true)
+        };
+
+        if let Some(prompt) = self.prompt_override_for(&conv).await {
+            conv.system_prompt = Some(prompt);
+        }
+        Ok((conv, is_new))
+    }
+
+    /// Stored system prompt override for the conversation or its contact
+    async fn prompt_override_for(&self, conversation: &Conversation) -> Option<String> {
+        let store = self.prompt_overrides.as_ref()?;
+        lookup_prompt_override(store.as_ref(), conversation).await
+    }
+
+    /// System prompt for a request on behalf of `user_id`
+    ///
+    /// Renders the prompt template if one is configured, otherwise (or if
+    /// rendering fails) returns the static system prompt.
+    async fn system_prompt_for(&self, user_id: Option<UserId>) -> Option<String> {
+        let Some(ref template) = self.prompt_template else {
+            return self.system_prompt.clone();
+        };
+
+        let context = self.prompt_context(user_id.unwrap_or_default()).await;
+        match template.render_system_prompt(&context) {
+            Ok(prompt) => Some(prompt),
+            Err(e) => {
+                warn!(error = %e, "Failed to render system prompt template, using static prompt");
+                self.system_prompt.clone()
+            },
+        }
+    }
+
+    /// Template variables from the user's profile; missing fields stay unset
+    async fn prompt_context(&self, user_id: UserId) -> PromptContext {
+        let profile = match self.user_profile_store {
+            Some(ref store) => store.get(&user_id).await.unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to load user profile for system prompt");
+                None
+            }),
+            None => None,
+        };
+        let timezone = profile
+            .as_ref()
+            .map_or(&self.default_timezone, UserProfile::timezone);
+
+        PromptContext {
+            user_name: profile
+                .as_ref()
+                .and_then(UserProfile::name)
+                .map(str::to_string),
+            today: Utc::now()
+                .with_timezone(&timezone.as_chrono_tz())
+                .date_naive(),
+            location: profile
+                .as_ref()
+                .and_then(UserProfile::location)
+                .map(|location| location.to_string()),
+        }
+    }
+
+    /// Condense the oldest turns into the conversation summary
+    ///
+    /// Does nothing unless summarization is enabled and the conversation is
+    /// over the threshold. If the model fails, the turns are left in place
+    /// and FIFO truncation takes over.
+    async fn summarize_old_turns(&self, conversation: &mut Conversation) {
+        let Some(config) = self.summarization else {
+            return;
+        };
+        let turns: Vec<&ChatMessage> = conversation
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .collect();
+        if turns.len() <= config.threshold {
+            return;
+        }
+
+        let count = turns.len() - config.keep_recent;
+        let transcript = turns[..count]
+            .iter()
+            .map(|m| {
+                let speaker = if m.role == MessageRole::User {
+                    "User"
+                } else {
+                    "Assistant"
+                };
+                format!("{speaker}: {}", m.content.trim())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let prompt = SUMMARY_PROMPT
+            .replace("{previous}", conversation.summary.as_deref().unwrap_or("-"))
+            .replace("{transcript}", &transcript);
+
+        match self.inference.generate(&prompt).await {
+            Ok(result) => {
+                let removed = conversation.compact(count, result.content.trim());
+                debug!(
+                    conv_id = %conversation.id,
+                    removed,
+                    remaining = conversation.messages.len(),
+                    "Old turns summarized"
+                );
+            },
+            Err(e) => warn!(
+                conv_id = %conversation.id,
+                error = %e,
+                "Failed to summarize old turns, falling back to truncation"
+            ),
+        }
+    }
+
     /// Apply FIFO truncation to a conversation.
     ///
     /// Removes the oldest messages (excluding system role messages) when the
@@ -309,12 +795,43 @@ impl ChatService {
         );
     }
 }
+
+/// Stored system prompt override for a conversation or its contact
+///
+/// The conversation's own override wins over its contact's. Lookup failures
+/// are logged and treated as "no override".
+pub(crate) async fn lookup_prompt_override(
+    store: &dyn SystemPromptStore,
+    conversation: &Conversation,
+) -> Option<String> {
+    let targets = std::iter::once(PromptOverrideTarget::Conversation(conversation.id)).chain(
+        conversation
+            .phone_number
+            .clone()
+            .map(PromptOverrideTarget::Contact),
+    );
+
+    for target in targets {
+        match store.get(&target).await {
+            Ok(Some(prompt)) => {
+                debug!(target = %target, "Using system prompt override");
+                return Some(prompt);
+            },
+            Ok(None) => {},
+            Err(e) => {
+                warn!(target = %target, error = %e, "Failed to load system prompt override")
+            },
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use mockall::mock;
 
     use super::*;
-    use crate::ports::InferenceResult;
+    use crate::ports::{InferenceResult, MockSystemPromptStore};
 
     mock! {
         pub InferenceEngine {}
@@ -326,6 +843,8 @@ mod tests {
             async fn generate_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceResult, ApplicationError>;
             async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError>;
             async fn generate_stream_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn generate_stream_with_context(&self, conversation: &Conversation) -> Result<InferenceStream, ApplicationError>;
+            async fn generate_stream_with_context_and_model(&self, conversation: &Conversation, model: &str) -> Result<InferenceStream, ApplicationError>;
             async fn is_healthy(&self) -> bool;
             fn current_model(&self) -> String;
             async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError>;
@@ -390,6 +909,109 @@ mod tests {
         assert_eq!(result.content, "System response");
     }
 
+    struct FixedProfileStore(UserProfile);
+
+    #[async_trait::async_trait]
+    impl UserProfileStore for FixedProfileStore {
+        async fn save(&self, _: &UserProfile) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn get(&self, _: &UserId) -> Result<Option<UserProfile>, ApplicationError> {
+            Ok(Some(self.0.clone()))
+        }
+
+        async fn delete(&self, _: &UserId) -> Result<bool, ApplicationError> {
+            Ok(false)
+        }
+
+        async fn update_location(
+            &self,
+            _: &UserId,
+            _: Option<&domain::GeoLocation>,
+        ) -> Result<bool, ApplicationError> {
+            Ok(false)
+        }
+
+        async fn update_timezone(
+            &self,
+            _: &UserId,
+            _: &Timezone,
+        ) -> Result<bool, ApplicationError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn templated_system_prompt_uses_profile() {
+        let profile = UserProfile::with_defaults(
+            UserId::default(),
+            domain::GeoLocation::berlin(),
+            Timezone::berlin(),
+        )
+        .with_name(Some("Anna".to_string()));
+
+        let mut template = crate::ports::MockPromptTemplatePort::new();
+        template
+            .expect_render_system_prompt()
+            .withf(|ctx| {
+                ctx.user_name.as_deref() == Some("Anna")
+                    && ctx.location.as_deref() == Some("52.520000, 13.405000")
+            })
+            .returning(|ctx| {
+                Ok(format!(
+                    "Hello {}",
+                    ctx.user_name.clone().unwrap_or_default()
+                ))
+            });
+
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system()
+            .withf(|system, _| system == "Hello Anna")
+            .returning(|_, _| Ok(mock_inference_result("Hi")));
+
+        let service = ChatService::with_system_prompt(Arc::new(mock), "Static")
+            .with_prompt_template(Arc::new(template))
+            .with_user_profile_store(Arc::new(FixedProfileStore(profile)));
+        let result = service.chat("Hello").await.unwrap();
+
+        assert_eq!(result.content, "Hi");
+    }
+
+    #[tokio::test]
+    async fn templated_system_prompt_without_profile_leaves_fields_unset() {
+        let mut template = crate::ports::MockPromptTemplatePort::new();
+        template
+            .expect_render_system_prompt()
+            .withf(|ctx| ctx.user_name.is_none() && ctx.location.is_none())
+            .returning(|_| Ok("Rendered".to_string()));
+
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system()
+            .withf(|system, _| system == "Rendered")
+            .returning(|_, _| Ok(mock_inference_result("Hi")));
+
+        let service = ChatService::new(Arc::new(mock)).with_prompt_template(Arc::new(template));
+        assert!(service.chat("Hello").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn failed_template_falls_back_to_static_prompt() {
+        let mut template = crate::ports::MockPromptTemplatePort::new();
+        template
+            .expect_render_system_prompt()
+            .returning(|_| Err(ApplicationError::Internal("broken template".to_string())));
+
+        let mut mock = MockInferenceEngine::new();
+        mock.expect_generate_with_system()
+            .withf(|system, _| system == "Static")
+            .returning(|_, _| Ok(mock_inference_result("Hi")));
+
+        let service = ChatService::with_system_prompt(Arc::new(mock), "Static")
+            .with_prompt_template(Arc::new(template));
+        assert!(service.chat("Hello").await.is_ok());
+    }
+
     #[tokio::test]
     async fn chat_response_has_metadata() {
         let mut mock = MockInferenceEngine::new();
@@ -520,6 +1142,62 @@ mod tests {
         assert!(!conv_id.to_string().is_empty());
     }
 
+    #[tokio::test]
+    async fn chat_with_context_for_user_sets_owner_of_new_conversation() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_with_context()
+            .returning(|_| Ok(mock_inference_result("Hello!")));
+
+        let owner = UserId::new();
+        let mut mock_store = MockConvStore::new();
+        mock_store
+            .expect_save()
+            .withf(move |conv| conv.user_id == owner)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+
+        service
+            .chat_with_context_for_user("Hi", None, Some(owner))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn chat_with_context_for_user_rejects_other_users_conversation() {
+        let foreign_conv = Conversation::new().with_user_id(UserId::new());
+        let id_str = foreign_conv.id.to_string();
+
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference.expect_generate_with_context().never();
+        mock_inference.expect_generate_stream_with_context().never();
+
+        let mut mock_store = MockConvStore::new();
+        mock_store
+            .expect_get()
+            .returning(move |_| Ok(Some(foreign_conv.clone())));
+        mock_store.expect_save().never();
+        mock_store.expect_update().never();
+        mock_store.expect_add_message().never();
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+        let intruder = Some(UserId::new());
+
+        let result = service
+            .chat_with_context_for_user("Hi", Some(&id_str), intruder)
+            .await;
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+
+        let result = service
+            .chat_stream_with_context_for_user("Hi", Some(&id_str), intruder)
+            .await;
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
     #[tokio::test]
     async fn chat_with_context_continues_existing_conversation() {
         let existing_conv = Conversation::new();
@@ -550,6 +1228,218 @@ mod tests {
         assert_eq!(returned_id.to_string(), id_str);
     }
 
+    #[tokio::test]
+    async fn conversation_override_replaces_global_prompt() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_with_context()
+            .withf(|conv| conv.system_prompt.as_deref() == Some("Be formal."))
+            .returning(|_| Ok(mock_inference_result("Good day.")));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store.expect_get().returning(|_| Ok(None));
+        mock_store
+            .expect_save()
+            .withf(|conv| conv.system_prompt.as_deref() == Some("Be formal."))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let conv_id = ConversationId::new();
+        let mut overrides = MockSystemPromptStore::new();
+        overrides
+            .expect_get()
+            .withf(move |target| *target == PromptOverrideTarget::Conversation(conv_id))
+            .returning(|_| Ok(Some("Be formal.".to_string())));
+
+        let service = ChatService::with_all(
+            Arc::new(mock_inference),
+            Arc::new(mock_store),
+            "Global prompt",
+        )
+        .with_prompt_overrides(Arc::new(overrides));
+
+        service
+            .chat_with_context("Hello", Some(&conv_id.to_string()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn contact_override_applies_to_messenger_conversation() {
+        let phone = domain::PhoneNumber::new("+491701234567").unwrap();
+        let mut existing =
+            Conversation::for_messenger(domain::ConversationSource::Signal, phone.clone());
+        existing.system_prompt = Some("Global prompt".to_string());
+        let id_str = existing.id.to_string();
+
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_with_context()
+            .withf(|conv| conv.system_prompt.as_deref() == Some("Be casual."))
+            .returning(|_| Ok(mock_inference_result("Hey!")));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store
+            .expect_get()
+            .returning(move |_| Ok(Some(existing.clone())));
+        mock_store.expect_update().returning(|_| Ok(()));
+
+        let mut overrides = MockSystemPromptStore::new();
+        overrides
+            .expect_get()
+            .returning(move |target| match target {
+                PromptOverrideTarget::Contact(p) if *p == phone => {
+                    Ok(Some("Be casual.".to_string()))
+                },
+                _ => Ok(None),
+            });
+
+        let service = ChatService::with_all(
+            Arc::new(mock_inference),
+            Arc::new(mock_store),
+            "Global prompt",
+        )
+        .with_prompt_overrides(Arc::new(overrides));
+
+        service
+            .chat_with_context("Hi", Some(&id_str))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_override_lookup_keeps_global_prompt() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_with_context()
+            .withf(|conv| conv.system_prompt.as_deref() == Some("Global prompt"))
+            .returning(|_| Ok(mock_inference_result("Hello!")));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store.expect_save().returning(|_| Ok(()));
+
+        let mut overrides = MockSystemPromptStore::new();
+        overrides
+            .expect_get()
+            .returning(|_| Err(ApplicationError::Internal("db down".to_string())));
+
+        let service = ChatService::with_all(
+            Arc::new(mock_inference),
+            Arc::new(mock_store),
+            "Global prompt",
+        )
+        .with_prompt_overrides(Arc::new(overrides));
+
+        service.chat_with_context("Hi", None).await.unwrap();
+    }
+
+    fn conversation_with_turns(turns: usize) -> Conversation {
+        let mut conv = Conversation::with_system_prompt("Be brief.");
+        for i in 0..turns {
+            if i % 2 == 0 {
+                conv.add_user_message(format!("question {i}"));
+            } else {
+                conv.add_assistant_message(format!("answer {i}"));
+            }
+        }
+        conv
+    }
+
+    #[test]
+    fn summarization_config_keeps_fewer_than_threshold() {
+        let config = SummarizationConfig::new(4, 10);
+        assert_eq!(config.threshold, 4);
+        assert_eq!(config.keep_recent, 3);
+
+        let config = SummarizationConfig::new(0, 0);
+        assert_eq!(config.threshold, 2);
+        assert_eq!(config.keep_recent, 1);
+    }
+
+    #[tokio::test]
+    async fn chat_with_context_summarizes_old_turns() {
+        let existing = conversation_with_turns(4);
+        let id_str = existing.id.to_string();
+
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate()
+            .withf(|prompt| {
+                prompt.contains("User: question 0")
+                    && prompt.contains("User: question 2")
+                    && !prompt.contains("answer 3")
+            })
+            .times(1)
+            .returning(|_| Ok(mock_inference_result("The user asked two questions.")));
+        mock_inference
+            .expect_generate_with_context()
+            .withf(|conv| {
+                conv.summary.as_deref() == Some("The user asked two questions.")
+                    && conv.messages.len() == 2
+            })
+            .returning(|_| Ok(mock_inference_result("Sure!")));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store
+            .expect_get()
+            .returning(move |_| Ok(Some(existing.clone())));
+        mock_store
+            .expect_update()
+            .withf(|conv| {
+                conv.summary.is_some()
+                    && conv
+                        .messages
+                        .iter()
+                        .map(|m| m.content.as_str())
+                        .collect::<Vec<_>>()
+                        == ["answer 3", "Next", "Sure!"]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store))
+                .with_summarization(SummarizationConfig::new(4, 2));
+
+        service
+            .chat_with_context("Next", Some(&id_str))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_summary_keeps_turns() {
+        let existing = conversation_with_turns(4);
+        let id_str = existing.id.to_string();
+
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate()
+            .returning(|_| Err(ApplicationError::Inference("Failed".to_string())));
+        mock_inference
+            .expect_generate_with_context()
+            .returning(|_| Ok(mock_inference_result("Sure!")));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store
+            .expect_get()
+            .returning(move |_| Ok(Some(existing.clone())));
+        mock_store
+            .expect_update()
+            .withf(|conv| conv.summary.is_none() && conv.messages.len() == 6)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store))
+                .with_summarization(SummarizationConfig::new(4, 2));
+
+        service
+            .chat_with_context("Next", Some(&id_str))
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn chat_with_context_creates_conversation_with_provided_id_if_not_found() {
         let new_id = ConversationId::new();
@@ -599,6 +1489,207 @@ mod tests {
         assert!(matches!(result, Err(ApplicationError::InvalidOperation(_))));
     }
 
+    fn chunk_stream(chunks: &[(&str, bool)]) -> InferenceStream {
+        let chunks: Vec<_> = chunks
+            .iter()
+            .map(|(content, done)| {
+                Ok(crate::ports::StreamingChunk {
+                    content: (*content).to_string(),
+                    done: *done,
+                    model: done.then(|| "test-model".to_string()),
+                })
+            })
+            .collect();
+        Box::pin(futures::stream::iter(chunks))
+    }
+
+    #[tokio::test]
+    async fn chat_stream_with_context_persists_reply_after_final_chunk() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_stream_with_context()
+            .withf(|conv| conv.messages.last().is_some_and(|m| m.content == "Hi"))
+            .returning(|_| Ok(chunk_stream(&[("Hello ", false), ("world", true)])));
+
+        let saved = Arc::new(std::sync::Mutex::new(None));
+        let saved_clone = Arc::clone(&saved);
+        let mut mock_store = MockConvStore::new();
+        mock_store.expect_save().times(1).returning(move |conv| {
+            *saved_clone.lock().unwrap() = Some(conv.clone());
+            Ok(())
+        });
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+
+        let (stream, conv_id) = service.chat_stream_with_context("Hi", None).await.unwrap();
+        let chunks: Vec<_> = stream.collect().await;
+
+        assert_eq!(chunks.len(), 2);
+        let saved = saved.lock().unwrap().clone().unwrap();
+        assert_eq!(saved.id, conv_id);
+        let reply = saved.messages.last().unwrap();
+        assert_eq!(reply.role, MessageRole::Assistant);
+        assert_eq!(reply.content, "Hello world");
+        assert_eq!(
+            reply.metadata.as_ref().and_then(|m| m.model.as_deref()),
+            Some("test-model")
+        );
+    }
+
+    #[tokio::test]
+    async fn newer_stream_for_same_user_cancels_previous() {
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_stream_with_context()
+            .returning(move |_| {
+                // The first reply never finishes on its own
+                if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    let stream: InferenceStream = Box::pin(
+                        chunk_stream(&[("Thinking", false)]).chain(futures::stream::pending()),
+                    );
+                    Ok(stream)
+                } else {
+                    Ok(chunk_stream(&[("Hello", true)]))
+                }
+            });
+
+        let mut mock_store = MockConvStore::new();
+        mock_store.expect_save().times(1).returning(|_| Ok(()));
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+        let user = Some(UserId::new());
+
+        let (mut first, _) = service
+            .chat_stream_with_context_for_user("Write an essay", None, user)
+            .await
+            .unwrap();
+        assert_eq!(first.next().await.unwrap().unwrap().content, "Thinking");
+
+        let (second, _) = service
+            .chat_stream_with_context_for_user("Never mind", None, user)
+            .await
+            .unwrap();
+        assert!(matches!(
+            first.next().await,
+            Some(Err(ApplicationError::Cancelled))
+        ));
+        assert!(first.next().await.is_none());
+
+        let chunks: Vec<_> = second.collect().await;
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn chat_stream_with_context_uses_requested_model() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_stream_with_context_and_model()
+            .withf(|conv, model| {
+                model == "llama3.2" && conv.messages.last().is_some_and(|m| m.content == "Hi")
+            })
+            .times(1)
+            .returning(|_, _| Ok(chunk_stream(&[("Hello", true)])));
+        mock_inference.expect_generate_stream_with_context().never();
+
+        let mut mock_store = MockConvStore::new();
+        mock_store.expect_save().times(1).returning(|_| Ok(()));
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+
+        let (stream, _) = service
+            .chat_stream_with_context_and_model("Hi", None, None, Some("llama3.2"))
+            .await
+            .unwrap();
+        let chunks: Vec<_> = stream.collect().await;
+
+        assert_eq!(chunks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn chat_stream_with_model_sends_system_prompt_and_message() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_stream_with_context_and_model()
+            .withf(|conv, model| {
+                model == "llama3.2"
+                    && conv.system_prompt.as_deref() == Some("Be brief")
+                    && conv.messages.last().is_some_and(|m| m.content == "Hi")
+            })
+            .returning(|_, _| Ok(chunk_stream(&[("Hello", true)])));
+
+        let service = ChatService::with_system_prompt(Arc::new(mock_inference), "Be brief");
+
+        let stream = service
+            .chat_stream_with_model("Hi", "llama3.2")
+            .await
+            .unwrap();
+        let chunks: Vec<_> = stream.collect().await;
+
+        assert_eq!(chunks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn chat_stream_with_context_updates_existing_conversation() {
+        let existing_conv = Conversation::new();
+        let id_str = existing_conv.id.to_string();
+
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_stream_with_context()
+            .returning(|_| Ok(chunk_stream(&[("Again", true)])));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store
+            .expect_get()
+            .returning(move |_| Ok(Some(existing_conv.clone())));
+        mock_store.expect_update().times(1).returning(|_| Ok(()));
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+
+        let (stream, conv_id) = service
+            .chat_stream_with_context("More", Some(&id_str))
+            .await
+            .unwrap();
+        let _: Vec<_> = stream.collect().await;
+
+        assert_eq!(conv_id.to_string(), id_str);
+    }
+
+    #[tokio::test]
+    async fn chat_stream_with_context_does_not_persist_abandoned_stream() {
+        let mut mock_inference = MockInferenceEngine::new();
+        mock_inference
+            .expect_generate_stream_with_context()
+            .returning(|_| Ok(chunk_stream(&[("Hello ", false), ("world", true)])));
+
+        let mut mock_store = MockConvStore::new();
+        mock_store.expect_save().never();
+
+        let service =
+            ChatService::with_conversation_store(Arc::new(mock_inference), Arc::new(mock_store));
+
+        let (mut stream, _) = service.chat_stream_with_context("Hi", None).await.unwrap();
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.content, "Hello ");
+        drop(stream);
+    }
+
+    #[tokio::test]
+    async fn chat_stream_with_context_fails_without_store() {
+        let mock_inference = MockInferenceEngine::new();
+        let service = ChatService::new(Arc::new(mock_inference));
+
+        let result = service.chat_stream_with_context("Hi", None).await;
+
+        assert!(matches!(result, Err(ApplicationError::Configuration(_))));
+    }
+
     #[test]
     fn truncate_conversation_does_nothing_under_limit() {
         let mut conv = Conversation::new();
@@ -0,0 +1,691 @@
+//! Daily digest service
+//!
+//! Pushes the morning briefing to the owner's messenger numbers. Numbers
+//! that turned the digest off, are in their quiet hours, or already wrote
+//! to the assistant this morning are skipped. On platforms with a
+//! session window (WhatsApp), contacts whose last message is older than the
+//! window get a pre-approved template message instead of the briefing.
+
+use std::{collections::HashSet, fmt, sync::Arc};
+
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+use domain::{AgentCommand, MessengerSource, PhoneNumber, Timezone, entities::ConversationSource};
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    ports::{
+        ConversationStore, DeliveryStatusPort, DigestTemplateData, DigestTemplatePort,
+        MessengerPort, OutgoingTemplateMessage, OutgoingTextMessage,
+    },
+    services::AgentService,
+};
+
+/// Context attached to digest messages while they are queued for retry
+pub const DIGEST_MESSAGE_CONTEXT: &str = "daily_digest";
+
+/// Daily window in which no digest is pushed
+///
+/// Wraps around midnight if `start` is later than `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    /// First minute of the quiet hours
+    pub start: NaiveTime,
+    /// First minute after the quiet hours
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Create quiet hours from `start` (inclusive) to `end` (exclusive)
+    #[must_use]
+    pub const fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether `time` falls into the quiet hours
+    #[must_use]
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Template sent instead of the digest outside the session window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestTemplate {
+    /// Template name as registered with the platform
+    pub name: String,
+    /// Template language code (e.g., "en_US")
+    pub language: String,
+}
+
+/// Configuration for the daily digest
+#[derive(Debug, Clone, Default)]
+pub struct DailyDigestConfig {
+    /// The owner's phone numbers the digest is pushed to
+    ///
+    /// The briefing contains the owner's calendar and mail; never list
+    /// other contacts here.
+    pub recipients: Vec<String>,
+    /// Normalized phone numbers that turned the digest off
+    pub disabled: HashSet<String>,
+    /// Daily window in which no digest is pushed
+    pub quiet_hours: Option<QuietHours>,
+    /// Timezone for quiet hours and "this morning"
+    pub timezone: Timezone,
+    /// Template sent to contacts outside the session window
+    ///
+    /// Without one, these contacts are skipped.
+    pub template: Option<DigestTemplate>,
+}
+
+/// What happened to the digest of one recipient
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DigestOutcome {
+    /// Briefing sent as text
+    Sent {
+        /// Platform message ID
+        message_id: String,
+    },
+    /// Template sent, as the contact is outside the session window
+    TemplateSent {
+        /// Platform message ID
+        message_id: String,
+    },
+    /// Contact turned the digest off
+    Disabled,
+    /// Run fell into the quiet hours
+    QuietHours,
+    /// Contact already wrote to the assistant this morning
+    AlreadyInteracted,
+    /// Contact is outside the session window and no template is configured
+    OutsideSessionWindow,
+    /// Digest could not be sent
+    Failed(String),
+}
+
+impl DigestOutcome {
+    /// Whether a message was sent
+    #[must_use]
+    pub const fn is_sent(&self) -> bool {
+        matches!(self, Self::Sent { .. } | Self::TemplateSent { .. })
+    }
+}
+
+/// Outcome of one digest run, per recipient
+#[derive(Debug, Clone, Default)]
+pub struct DigestReport {
+    /// Recipients in configured order, with what happened to their digest
+    pub deliveries: Vec<(String, DigestOutcome)>,
+}
+
+impl DigestReport {
+    /// Number of recipients a message was sent to
+    #[must_use]
+    pub fn sent(&self) -> usize {
+        self.deliveries
+            .iter()
+            .filter(|(_, outcome)| outcome.is_sent())
+            .count()
+    }
+
+    /// Number of recipients the digest failed for
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.deliveries
+            .iter()
+            .filter(|(_, outcome)| matches!(outcome, DigestOutcome::Failed(_)))
+            .count()
+    }
+
+    /// Number of recipients that were skipped
+    #[must_use]
+    pub fn skipped(&self) -> usize {
+        self.deliveries.len() - self.sent() - self.failed()
+    }
+}
+
+impl fmt::Display for DigestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sent to {} of {} recipients, {} skipped, {} failed",
+            self.sent(),
+            self.deliveries.len(),
+            self.skipped(),
+            self.failed()
+        )
+    }
+}
+
+/// Service that pushes the morning briefing to the owner's messenger numbers
+///
+/// The briefing is the default user's, generated once per run when the
+/// first recipient is due. Recipients must therefore be the owner's own
+/// numbers.
+pub struct DailyDigestService {
+    agent_service: Arc<AgentService>,
+    messenger: Arc<dyn MessengerPort>,
+    config: DailyDigestConfig,
+    template_engine: Option<Arc<dyn DigestTemplatePort>>,
+    conversation_store: Option<Arc<dyn ConversationStore>>,
+    delivery_status: Option<Arc<dyn DeliveryStatusPort>>,
+}
+
+impl fmt::Debug for DailyDigestService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DailyDigestService")
+            .field("config", &self.config)
+            .field("source", &self.messenger.source())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DailyDigestService {
+    /// Create a new daily digest service
+    #[must_use]
+    pub fn new(
+        agent_service: Arc<AgentService>,
+        messenger: Arc<dyn MessengerPort>,
+        config: DailyDigestConfig,
+    ) -> Self {
+        Self {
+            agent_service,
+            messenger,
+            config,
+            template_engine: None,
+            conversation_store: None,
+            delivery_status: None,
+        }
+    }
+
+    /// Format the digest with a template instead of sending the raw briefing
+    #[must_use]
+    pub fn with_template_engine(mut self, template_engine: Arc<dyn DigestTemplatePort>) -> Self {
+        self.template_engine = Some(template_engine);
+        self
+    }
+
+    /// Look up contacts' last messages to skip those who already interacted
+    ///
+    /// Also decides whether a contact is within the messenger's session
+    /// window. Without a store, every contact counts as outside of it.
+    #[must_use]
+    pub fn with_conversation_store(mut self, store: Arc<dyn ConversationStore>) -> Self {
+        self.conversation_store = Some(store);
+        self
+    }
+
+    /// Record sent digests for delivery tracking
+    #[must_use]
+    pub fn with_delivery_status(mut self, delivery_status: Arc<dyn DeliveryStatusPort>) -> Self {
+        self.delivery_status = Some(delivery_status);
+        self
+    }
+
+    /// Push the digest to every recipient
+    pub async fn run(&self) -> DigestReport {
+        self.run_at(Utc::now()).await
+    }
+
+    /// Push the digest as if it were `now`
+    #[instrument(skip(self), fields(recipients = self.config.recipients.len()))]
+    pub async fn run_at(&self, now: DateTime<Utc>) -> DigestReport {
+        let local = now.with_timezone(&self.config.timezone.as_chrono_tz());
+        let morning = self
+            .config
+            .timezone
+            .to_utc(local.date_naive().and_time(NaiveTime::MIN));
+        let quiet = self
+            .config
+            .quiet_hours
+            .is_some_and(|hours| hours.contains(local.time()));
+        let date = local.format("%A, %Y-%m-%d").to_string();
+
+        let mut briefing = None;
+        let mut report = DigestReport::default();
+        for recipient in &self.config.recipients {
+            let outcome = if quiet {
+                DigestOutcome::QuietHours
+            } else {
+                self.deliver(recipient, now, morning, &date, &mut briefing)
+                    .await
+            };
+
+            match outcome {
+                DigestOutcome::Failed(ref e) => {
+                    warn!(recipient = %recipient, error = %e, "Failed to send daily digest");
+                },
+                ref outcome => {
+                    debug!(recipient = %recipient, outcome = ?outcome, "Daily digest processed");
+                },
+            }
+            report.deliveries.push((recipient.clone(), outcome));
+        }
+
+        info!(report = %report, "📬 Daily digest run finished");
+        report
+    }
+
+    /// Send the digest to one recipient, generating the briefing on first use
+    async fn deliver(
+        &self,
+        recipient: &str,
+        now: DateTime<Utc>,
+        morning: DateTime<Utc>,
+        date: &str,
+        briefing: &mut Option<Result<String, String>>,
+    ) -> DigestOutcome {
+        let phone = match PhoneNumber::normalize(recipient) {
+            Ok(phone) => phone,
+            Err(e) => return DigestOutcome::Failed(e.to_string()),
+        };
+        if self.config.disabled.contains(phone.as_str()) {
+            return DigestOutcome::Disabled;
+        }
+        if !self.messenger.is_whitelisted(&phone).await {
+            return DigestOutcome::Failed(format!("{phone} is not whitelisted"));
+        }
+
+        let last_message = self.last_user_message_at(&phone).await;
+        if last_message.is_some_and(|at| at >= morning) {
+            return DigestOutcome::AlreadyInteracted;
+        }
+
+        let in_session = match (self.messenger.session_window(), last_message) {
+            (None, _) => true,
+            (Some(window), Some(at)) => now - at < window,
+            (Some(_), None) => false,
+        };
+        if !in_session {
+            return match self.config.template {
+                Some(ref template) => self.send_template(&phone, template, date).await,
+                None => DigestOutcome::OutsideSessionWindow,
+            };
+        }
+
+        let generated = match briefing.take() {
+            Some(generated) => generated,
+            None => self.render_briefing(date).await,
+        };
+        let text = match briefing.insert(generated).clone() {
+            Ok(text) => text,
+            Err(e) => return DigestOutcome::Failed(e),
+        };
+
+        let message = OutgoingTextMessage::new(phone.clone(), text)
+            .with_context(DIGEST_MESSAGE_CONTEXT)
+            .with_valid_until(morning + TimeDelta::days(1));
+        match self.messenger.send_text(message).await {
+            Ok(message_id) => {
+                self.record_sent(&message_id, &phone).await;
+                DigestOutcome::Sent { message_id }
+            },
+            Err(e) => DigestOutcome::Failed(e.to_string()),
+        }
+    }
+
+    /// Send the configured template with the date as its only parameter
+    async fn send_template(
+        &self,
+        phone: &PhoneNumber,
+        template: &DigestTemplate,
+        date: &str,
+    ) -> DigestOutcome {
+        let message =
+            OutgoingTemplateMessage::new(phone.clone(), &template.name, &template.language)
+                .with_parameters(vec![date.to_string()]);
+        match self.messenger.send_template(message).await {
+            Ok(message_id) => {
+                self.record_sent(&message_id, phone).await;
+                DigestOutcome::TemplateSent { message_id }
+            },
+            Err(e) => DigestOutcome::Failed(e.to_string()),
+        }
+    }
+
+    /// Generate the briefing and format it for the messenger
+    async fn render_briefing(&self, date: &str) -> Result<String, String> {
+        let briefing = self
+            .agent_service
+            .execute_command(&AgentCommand::MorningBriefing { date: None })
+            .await
+            .map_err(|e| format!("Briefing generation failed: {e}"))?
+            .response;
+
+        let Some(ref engine) = self.template_engine else {
+            return Ok(briefing);
+        };
+        let data = DigestTemplateData {
+            briefing,
+            date: date.to_string(),
+            messenger: self.messenger.source().config_key().to_string(),
+        };
+        engine
+            .render_daily_digest(&data)
+            .map_err(|e| format!("Digest template failed: {e}"))
+    }
+
+    /// When the contact last wrote to the assistant, if known
+    async fn last_user_message_at(&self, phone: &PhoneNumber) -> Option<DateTime<Utc>> {
+        let store = self.conversation_store.as_ref()?;
+        let source = match self.messenger.source() {
+            MessengerSource::WhatsApp => ConversationSource::WhatsApp,
+            MessengerSource::Signal => ConversationSource::Signal,
+        };
+
+        match store.get_by_phone_number(source, phone.as_str()).await {
+            Ok(conversation) => conversation
+                .as_ref()
+                .and_then(|c| c.last_user_message())
+                .map(|message| message.created_at),
+            Err(e) => {
+                warn!(recipient = %phone, error = %e, "Failed to load conversation for daily digest");
+                None
+            },
+        }
+    }
+
+    /// Start delivery tracking for a sent digest
+    async fn record_sent(&self, message_id: &str, phone: &PhoneNumber) {
+        let Some(ref delivery_status) = self.delivery_status else {
+            return;
+        };
+        if let Err(e) = delivery_status
+            .record_sent(message_id, self.messenger.source(), phone.as_str(), None)
+            .await
+        {
+            warn!(message_id, error = %e, "Failed to record daily digest delivery");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use domain::{ChatMessage, Conversation, ConversationId};
+    use mockall::mock;
+
+    use super::*;
+    use crate::{
+        error::ApplicationError,
+        ports::{
+            InferenceResult, InferenceStream, MockDeliveryStatusPort, MockDigestTemplatePort,
+            MockMessengerPort,
+        },
+    };
+
+    mock! {
+        pub InferenceEngine {}
+
+        #[async_trait::async_trait]
+        impl crate::ports::InferencePort for InferenceEngine {
+            async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_context(&self, conversation: &Conversation) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn generate_stream_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn is_healthy(&self) -> bool;
+            fn current_model(&self) -> String;
+            async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError>;
+            async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError>;
+        }
+    }
+
+    mock! {
+        pub ConvStore {}
+
+        #[async_trait::async_trait]
+        impl ConversationStore for ConvStore {
+            async fn save(&self, conversation: &Conversation) -> Result<(), ApplicationError>;
+            async fn get(&self, id: &ConversationId) -> Result<Option<Conversation>, ApplicationError>;
+            async fn get_by_phone_number(&self, source: ConversationSource, phone_number: &str) -> Result<Option<Conversation>, ApplicationError>;
+            async fn update(&self, conversation: &Conversation) -> Result<(), ApplicationError>;
+            async fn delete(&self, id: &ConversationId) -> Result<(), ApplicationError>;
+            async fn add_message(&self, conversation_id: &ConversationId, message: &ChatMessage) -> Result<(), ApplicationError>;
+            async fn list_recent(&self, limit: usize) -> Result<Vec<Conversation>, ApplicationError>;
+            async fn search(&self, query: &str, limit: usize) -> Result<Vec<Conversation>, ApplicationError>;
+            async fn cleanup_older_than(&self, cutoff: DateTime<Utc>) -> Result<usize, ApplicationError>;
+        }
+    }
+
+    const RECIPIENT: &str = "+491701234567";
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 7, 0, 0).unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn config() -> DailyDigestConfig {
+        DailyDigestConfig {
+            recipients: vec![RECIPIENT.to_string()],
+            timezone: Timezone::utc(),
+            ..DailyDigestConfig::default()
+        }
+    }
+
+    fn mock_messenger(source: MessengerSource, window: Option<TimeDelta>) -> MockMessengerPort {
+        let mut messenger = MockMessengerPort::new();
+        messenger.expect_source().return_const(source);
+        messenger.expect_is_whitelisted().returning(|_| true);
+        messenger.expect_session_window().return_const(window);
+        messenger
+    }
+
+    fn service(messenger: MockMessengerPort, config: DailyDigestConfig) -> DailyDigestService {
+        let agent = AgentService::new(Arc::new(MockInferenceEngine::new()));
+        DailyDigestService::new(Arc::new(agent), Arc::new(messenger), config)
+    }
+
+    /// Store holding one WhatsApp conversation whose user wrote at `at`
+    fn store_with_message_at(at: DateTime<Utc>) -> MockConvStore {
+        let mut store = MockConvStore::new();
+        store
+            .expect_get_by_phone_number()
+            .withf(|source, phone| *source == ConversationSource::WhatsApp && phone == RECIPIENT)
+            .returning(move |_, _| {
+                let mut conversation = Conversation::for_messenger(
+                    ConversationSource::WhatsApp,
+                    PhoneNumber::new(RECIPIENT).unwrap(),
+                );
+                let mut message = ChatMessage::user("Hi");
+                message.created_at = at;
+                conversation.add_message(message);
+                Ok(Some(conversation))
+            });
+        store
+    }
+
+    #[test]
+    fn quiet_hours_wrap_around_midnight() {
+        let night = QuietHours::new(time(22, 0), time(7, 0));
+        assert!(night.contains(time(23, 30)));
+        assert!(night.contains(time(6, 59)));
+        assert!(!night.contains(time(7, 0)));
+
+        let lunch = QuietHours::new(time(12, 0), time(13, 0));
+        assert!(lunch.contains(time(12, 30)));
+        assert!(!lunch.contains(time(13, 0)));
+    }
+
+    #[tokio::test]
+    async fn sends_briefing_and_records_delivery() {
+        let mut messenger = mock_messenger(MessengerSource::Signal, None);
+        messenger
+            .expect_send_text()
+            .withf(|message| {
+                message.recipient.as_str() == RECIPIENT
+                    && message.text.contains("Good morning")
+                    && message.context.as_deref() == Some(DIGEST_MESSAGE_CONTEXT)
+            })
+            .times(1)
+            .returning(|_| Ok("msg-1".to_string()));
+        let mut delivery = MockDeliveryStatusPort::new();
+        delivery
+            .expect_record_sent()
+            .withf(|message_id, source, recipient, reminder_id| {
+                message_id == "msg-1"
+                    && *source == MessengerSource::Signal
+                    && recipient == RECIPIENT
+                    && reminder_id.is_none()
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = service(messenger, config()).with_delivery_status(Arc::new(delivery));
+        let report = service.run_at(now()).await;
+
+        assert_eq!(
+            report.deliveries,
+            [(
+                RECIPIENT.to_string(),
+                DigestOutcome::Sent {
+                    message_id: "msg-1".to_string()
+                }
+            )]
+        );
+        assert_eq!(
+            report.to_string(),
+            "sent to 1 of 1 recipients, 0 skipped, 0 failed"
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_disabled_recipients_and_quiet_hours() {
+        let other = "+491709876543";
+        let mut config = config();
+        config.recipients.push(other.to_string());
+        config.disabled.insert(other.to_string());
+
+        let mut messenger = mock_messenger(MessengerSource::Signal, None);
+        messenger.expect_send_text().never();
+        let report = service(messenger, config.clone()).run_at(now()).await;
+        assert_eq!(report.deliveries[1].1, DigestOutcome::Disabled);
+
+        config.quiet_hours = Some(QuietHours::new(time(22, 0), time(8, 0)));
+        let mut messenger = mock_messenger(MessengerSource::Signal, None);
+        messenger.expect_send_text().never();
+        let report = service(messenger, config).run_at(now()).await;
+
+        assert_eq!(report.sent(), 0);
+        assert_eq!(report.deliveries[0].1, DigestOutcome::QuietHours);
+        assert_eq!(report.deliveries[1].1, DigestOutcome::QuietHours);
+    }
+
+    #[tokio::test]
+    async fn skips_contact_who_wrote_this_morning() {
+        let mut messenger = mock_messenger(MessengerSource::WhatsApp, Some(TimeDelta::hours(24)));
+        messenger.expect_send_text().never();
+        let store = store_with_message_at(now() - TimeDelta::minutes(30));
+
+        let report = service(messenger, config())
+            .with_conversation_store(Arc::new(store))
+            .run_at(now())
+            .await;
+
+        assert_eq!(report.deliveries[0].1, DigestOutcome::AlreadyInteracted);
+    }
+
+    #[tokio::test]
+    async fn sends_text_within_session_window() {
+        let mut messenger = mock_messenger(MessengerSource::WhatsApp, Some(TimeDelta::hours(24)));
+        messenger
+            .expect_send_text()
+            .times(1)
+            .returning(|_| Ok("wamid.1".to_string()));
+        messenger.expect_send_template().never();
+        let store = store_with_message_at(now() - TimeDelta::hours(12));
+
+        let report = service(messenger, config())
+            .with_conversation_store(Arc::new(store))
+            .run_at(now())
+            .await;
+
+        assert_eq!(report.sent(), 1);
+    }
+
+    #[tokio::test]
+    async fn sends_template_outside_session_window() {
+        let mut config = config();
+        config.template = Some(DigestTemplate {
+            name: "daily_digest".to_string(),
+            language: "en_US".to_string(),
+        });
+        let mut messenger = mock_messenger(MessengerSource::WhatsApp, Some(TimeDelta::hours(24)));
+        messenger.expect_send_text().never();
+        messenger
+            .expect_send_template()
+            .withf(|message| {
+                message.name == "daily_digest"
+                    && message.language == "en_US"
+                    && message.parameters == ["Friday, 2026-10-16"]
+            })
+            .times(1)
+            .returning(|_| Ok("wamid.2".to_string()));
+        let store = store_with_message_at(now() - TimeDelta::days(2));
+
+        let report = service(messenger, config)
+            .with_conversation_store(Arc::new(store))
+            .run_at(now())
+            .await;
+
+        assert_eq!(
+            report.deliveries[0].1,
+            DigestOutcome::TemplateSent {
+                message_id: "wamid.2".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_contact_outside_session_window_without_template() {
+        let mut messenger = mock_messenger(MessengerSource::WhatsApp, Some(TimeDelta::hours(24)));
+        messenger.expect_send_text().never();
+        messenger.expect_send_template().never();
+
+        let report = service(messenger, config()).run_at(now()).await;
+
+        assert_eq!(report.deliveries[0].1, DigestOutcome::OutsideSessionWindow);
+    }
+
+    #[tokio::test]
+    async fn formats_briefing_with_template_engine() {
+        let mut engine = MockDigestTemplatePort::new();
+        engine
+            .expect_render_daily_digest()
+            .withf(|data| data.messenger == "signal" && data.date == "Friday, 2026-10-16")
+            .times(1)
+            .returning(|_| Ok("📬 Your digest".to_string()));
+        let mut messenger = mock_messenger(MessengerSource::Signal, None);
+        messenger
+            .expect_send_text()
+            .withf(|message| message.text == "📬 Your digest")
+            .times(1)
+            .returning(|_| Ok("msg-1".to_string()));
+
+        let report = service(messenger, config())
+            .with_template_engine(Arc::new(engine))
+            .run_at(now())
+            .await;
+
+        assert_eq!(report.sent(), 1);
+    }
+
+    #[tokio::test]
+    async fn reports_failed_sends() {
+        let mut messenger = mock_messenger(MessengerSource::Signal, None);
+        messenger
+            .expect_send_text()
+            .returning(|_| Err(ApplicationError::ExternalService("down".to_string())));
+
+        let report = service(messenger, config()).run_at(now()).await;
+
+        assert_eq!(report.failed(), 1);
+        assert!(matches!(report.deliveries[0].1, DigestOutcome::Failed(_)));
+    }
+}
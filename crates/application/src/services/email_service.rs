@@ -4,6 +4,7 @@
 
 use std::{fmt, sync::Arc};
 
+use domain::PersistedEmailDraft;
 use tracing::{debug, info, instrument};
 
 use crate::{
@@ -194,6 +195,22 @@ impl EmailService {
         self.email_port.send_email(draft).await.map_err(map_error)
     }
 
+    /// Send a stored draft
+    ///
+    /// The plaintext body is always sent; an HTML version of the draft is
+    /// attached as the preferred alternative.
+    #[instrument(skip(self, draft), fields(draft_id = %draft.id))]
+    pub async fn send_draft(
+        &self,
+        draft: &PersistedEmailDraft,
+    ) -> Result<String, ApplicationError> {
+        let mut email = EmailDraft::new(draft.to.as_str(), &draft.subject, &draft.body);
+        email.cc = draft.cc.iter().map(|cc| cc.as_str().to_string()).collect();
+        email.html_body.clone_from(&draft.html_body);
+
+        self.send_email(&email).await
+    }
+
     /// Mark an email as read
     #[instrument(skip(self))]
     pub async fn mark_read(&self, email_id: &str) -> Result<(), ApplicationError> {
@@ -280,7 +297,10 @@ fn map_error(err: EmailError) -> ApplicationError {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicU32, Ordering},
+    };
 
     use async_trait::async_trait;
 
@@ -289,6 +309,7 @@ mod tests {
     struct MockEmailPort {
         emails: Vec<EmailSummary>,
         unread_count: AtomicU32,
+        sent: Mutex<Vec<EmailDraft>>,
     }
 
     impl MockEmailPort {
@@ -296,6 +317,7 @@ mod tests {
             Self {
                 emails,
                 unread_count: AtomicU32::new(unread),
+                sent: Mutex::new(Vec::new()),
             }
         }
     }
@@ -330,7 +352,8 @@ mod tests {
             Ok(())
         }
 
-        async fn send_email(&self, _draft: &EmailDraft) -> Result<String, EmailError> {
+        async fn send_email(&self, draft: &EmailDraft) -> Result<String, EmailError> {
+            self.sent.lock().unwrap().push(draft.clone());
             Ok("msg-123".to_string())
         }
 
@@ -487,6 +510,38 @@ mod tests {
         assert_eq!(result, "msg-123");
     }
 
+    #[tokio::test]
+    async fn send_draft_sends_both_parts() {
+        let email_port = Arc::new(MockEmailPort::new(vec![], 0));
+        let service = EmailService::new(Arc::clone(&email_port) as _, Arc::new(MockInference));
+        let to = domain::EmailAddress::new("to@example.com").unwrap();
+        let cc = domain::EmailAddress::new("cc@example.com").unwrap();
+        let draft = PersistedEmailDraft::new(domain::UserId::new(), to, "Subject", "Dear Bob,")
+            .with_cc(cc)
+            .with_html_body("<p>Dear Bob,</p>");
+
+        let result = service.send_draft(&draft).await.unwrap();
+
+        assert_eq!(result, "msg-123");
+        let sent = email_port.sent.lock().unwrap();
+        assert_eq!(sent[0].to, "to@example.com");
+        assert_eq!(sent[0].cc, vec!["cc@example.com"]);
+        assert_eq!(sent[0].body, "Dear Bob,");
+        assert_eq!(sent[0].html_body.as_deref(), Some("<p>Dear Bob,</p>"));
+    }
+
+    #[tokio::test]
+    async fn send_plaintext_draft() {
+        let email_port = Arc::new(MockEmailPort::new(vec![], 0));
+        let service = EmailService::new(Arc::clone(&email_port) as _, Arc::new(MockInference));
+        let to = domain::EmailAddress::new("to@example.com").unwrap();
+        let draft = PersistedEmailDraft::new(domain::UserId::new(), to, "Subject", "Body");
+
+        service.send_draft(&draft).await.unwrap();
+
+        assert!(email_port.sent.lock().unwrap()[0].html_body.is_none());
+    }
+
     #[tokio::test]
     async fn is_available_returns_true() {
         let email_port = Arc::new(MockEmailPort::new(vec![], 0));
@@ -2,20 +2,32 @@
 //!
 //! Provides comprehensive health checks for all external services,
 //! with configurable timeouts and individual service status reporting.
+//! Services that respond slower than the configured threshold are reported
+//! as degraded, and the last error of each service is kept across checks.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use tokio::time::timeout;
 use tracing::{debug, instrument, warn};
 
-use crate::ports::{CalendarPort, DatabaseHealthPort, EmailPort, InferencePort, WeatherPort};
+use crate::ports::{
+    CalendarPort, DatabaseHealth, DatabaseHealthPort, EmailPort, InferencePort, QueueStats,
+    RetryQueuePort, WeatherPort,
+};
 
 /// Default global timeout for health checks in seconds
 const DEFAULT_HEALTH_CHECK_TIMEOUT_SECS: u64 = 5;
 
+/// Default latency above which a responding service is degraded
+const DEFAULT_DEGRADED_LATENCY_MS: u64 = 1000;
+
+/// Services the server cannot handle requests without
+pub const CRITICAL_SERVICES: &[&str] = &["inference", "database"];
+
 /// Configuration for health check behavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthConfig {
@@ -26,17 +38,26 @@ pub struct HealthConfig {
     /// Service-specific timeout overrides in seconds
     #[serde(default)]
     pub service_timeouts: HashMap<String, u64>,
+
+    /// Check latency in milliseconds above which a service is degraded (default: 1000)
+    #[serde(default = "default_degraded_latency")]
+    pub degraded_latency_ms: u64,
 }
 
 const fn default_global_timeout() -> u64 {
     DEFAULT_HEALTH_CHECK_TIMEOUT_SECS
 }
 
+const fn default_degraded_latency() -> u64 {
+    DEFAULT_DEGRADED_LATENCY_MS
+}
+
 impl Default for HealthConfig {
     fn default() -> Self {
         Self {
             global_timeout_secs: default_global_timeout(),
             service_timeouts: HashMap::new(),
+            degraded_latency_ms: default_degraded_latency(),
         }
     }
 }
@@ -54,11 +75,25 @@ impl HealthConfig {
     }
 }
 
+/// State of an individual service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceState {
+    /// The service responds normally
+    Up,
+    /// The service responds, but slowly
+    Degraded,
+    /// The service is unavailable, failing or not configured
+    Down,
+}
+
 /// Status of an individual service
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceHealth {
-    /// Whether the service is healthy
+    /// Whether the service is healthy (up or degraded)
     pub healthy: bool,
+    /// State of the service
+    pub status: ServiceState,
     /// Optional additional information (e.g., model name, version)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub info: Option<String>,
@@ -68,6 +103,9 @@ pub struct ServiceHealth {
     /// Error message if unhealthy
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Most recent error, kept after the service recovers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
 }
 
 impl ServiceHealth {
@@ -76,9 +114,11 @@ impl ServiceHealth {
     pub const fn healthy() -> Self {
         Self {
             healthy: true,
+            status: ServiceState::Up,
             info: None,
             response_time_ms: None,
             error: None,
+            last_error: None,
         }
     }
 
@@ -87,32 +127,45 @@ impl ServiceHealth {
     pub fn healthy_with_info(info: impl Into<String>) -> Self {
         Self {
             healthy: true,
+            status: ServiceState::Up,
             info: Some(info.into()),
             response_time_ms: None,
             error: None,
+            last_error: None,
+        }
+    }
+
+    /// Create a status for a service that responds but is impaired
+    #[must_use]
+    pub fn degraded(reason: impl Into<String>) -> Self {
+        Self {
+            healthy: true,
+            status: ServiceState::Degraded,
+            info: Some(reason.into()),
+            response_time_ms: None,
+            error: None,
+            last_error: None,
         }
     }
 
     /// Create an unhealthy status
     #[must_use]
     pub fn unhealthy(error: impl Into<String>) -> Self {
+        let error = error.into();
         Self {
             healthy: false,
+            status: ServiceState::Down,
             info: None,
             response_time_ms: None,
-            error: Some(error.into()),
+            error: Some(error.clone()),
+            last_error: Some(error),
         }
     }
 
     /// Create an unhealthy status due to timeout
     #[must_use]
     pub fn timeout() -> Self {
-        Self {
-            healthy: false,
-            info: None,
-            response_time_ms: None,
-            error: Some("Health check timed out".to_string()),
-        }
+        Self::unhealthy("Health check timed out")
     }
 
     /// Create a status for an unconfigured/disabled service
@@ -120,9 +173,11 @@ impl ServiceHealth {
     pub fn unconfigured() -> Self {
         Self {
             healthy: false,
+            status: ServiceState::Down,
             info: Some("Service not configured".to_string()),
             response_time_ms: None,
             error: None,
+            last_error: None,
         }
     }
 
@@ -134,6 +189,46 @@ impl ServiceHealth {
     }
 }
 
+/// Aggregate status across a set of services
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// All services are healthy
+    Healthy,
+    /// Some, but not all, services are healthy
+    Degraded,
+    /// No service is healthy
+    Unhealthy,
+}
+
+impl HealthStatus {
+    /// Aggregate the status of individual services
+    ///
+    /// An empty set of services is considered healthy. A degraded service
+    /// makes the aggregate degraded.
+    #[must_use]
+    pub fn aggregate<'a>(services: impl IntoIterator<Item = &'a ServiceHealth>) -> Self {
+        let (up, healthy, total) =
+            services
+                .into_iter()
+                .fold((0usize, 0usize, 0usize), |(up, healthy, total), service| {
+                    (
+                        up + usize::from(service.status == ServiceState::Up),
+                        healthy + usize::from(service.healthy),
+                        total + 1,
+                    )
+                });
+
+        if up == total {
+            Self::Healthy
+        } else if healthy == 0 {
+            Self::Unhealthy
+        } else {
+            Self::Degraded
+        }
+    }
+}
+
 /// Comprehensive health report for all services
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthReport {
@@ -141,6 +236,9 @@ pub struct HealthReport {
     pub healthy: bool,
     /// Individual service statuses
     pub services: HashMap<String, ServiceHealth>,
+    /// Retry queue statistics (if a retry queue is configured)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue: Option<QueueStats>,
     /// Timestamp of the health check
     pub checked_at: chrono::DateTime<chrono::Utc>,
 }
@@ -155,15 +253,40 @@ impl HealthReport {
         Self {
             healthy,
             services,
+            queue: None,
             checked_at: chrono::Utc::now(),
         }
     }
 
+    /// Attach retry queue statistics
+    ///
+    /// Queue statistics are informational and do not affect `healthy`.
+    #[must_use]
+    pub const fn with_queue_stats(mut self, stats: QueueStats) -> Self {
+        self.queue = Some(stats);
+        self
+    }
+
     /// Get status of a specific service
     #[must_use]
     pub fn service_status(&self, name: &str) -> Option<&ServiceHealth> {
         self.services.get(name)
     }
+
+    /// Aggregate status of the services in this report
+    #[must_use]
+    pub fn status(&self) -> HealthStatus {
+        HealthStatus::aggregate(self.services.values())
+    }
+
+    /// Whether none of the [`CRITICAL_SERVICES`] in this report is down
+    #[must_use]
+    pub fn critical_healthy(&self) -> bool {
+        CRITICAL_SERVICES
+            .iter()
+            .filter_map(|name| self.services.get(*name))
+            .all(|service| service.healthy)
+    }
 }
 
 /// Service for aggregating health checks across all external services
@@ -174,6 +297,9 @@ pub struct HealthService {
     email: Option<Arc<dyn EmailPort>>,
     calendar: Option<Arc<dyn CalendarPort>>,
     weather: Option<Arc<dyn WeatherPort>>,
+    retry_queue: Option<Arc<dyn RetryQueuePort>>,
+    /// Most recent error per service
+    last_errors: Mutex<HashMap<&'static str, String>>,
 }
 
 impl std::fmt::Debug for HealthService {
@@ -185,7 +311,8 @@ impl std::fmt::Debug for HealthService {
             .field("email", &self.email.is_some())
             .field("calendar", &self.calendar.is_some())
             .field("weather", &self.weather.is_some())
-            .finish()
+            .field("retry_queue", &self.retry_queue.is_some())
+            .finish_non_exhaustive()
     }
 }
 
@@ -200,6 +327,8 @@ impl HealthService {
             email: None,
             calendar: None,
             weather: None,
+            retry_queue: None,
+            last_errors: Mutex::new(HashMap::new()),
         }
     }
 
@@ -238,31 +367,143 @@ impl HealthService {
         self
     }
 
+    /// Add retry queue for backlog reporting
+    #[must_use]
+    pub fn with_retry_queue(mut self, retry_queue: Arc<dyn RetryQueuePort>) -> Self {
+        self.retry_queue = Some(retry_queue);
+        self
+    }
+
     /// Check health of all configured services
     #[instrument(skip(self))]
     pub async fn check_all(&self) -> HealthReport {
-        let mut services = HashMap::new();
+        // Checks run concurrently, each bounded by its own timeout
+        let (inference, database, email, calendar, weather, queue) = tokio::join!(
+            self.check_inference(),
+            self.check_database(),
+            self.check_email(),
+            self.check_calendar(),
+            self.check_weather(),
+            self.queue_stats(),
+        );
+
+        let services = HashMap::from([
+            ("inference".to_string(), inference),
+            ("database".to_string(), database),
+            ("email".to_string(), email),
+            ("calendar".to_string(), calendar),
+            ("weather".to_string(), weather),
+        ]);
 
-        // Check inference (always required)
-        services.insert("inference".to_string(), self.check_inference().await);
+        let report = HealthReport::new(services);
+        match queue {
+            Some(stats) => report.with_queue_stats(stats),
+            None => report,
+        }
+    }
+
+    /// Check health of configured services only
+    ///
+    /// Unlike [`check_all`](Self::check_all), services without a port are
+    /// omitted instead of being reported as unhealthy. Checks run
+    /// concurrently, each bounded by its own timeout.
+    #[instrument(skip(self))]
+    pub async fn check_configured(&self) -> HealthReport {
+        let (inference, database, email, calendar, weather) = tokio::join!(
+            self.check_inference(),
+            self.check_database(),
+            self.check_email(),
+            self.check_calendar(),
+            self.check_weather(),
+        );
 
-        // Check database (optional but important)
-        services.insert("database".to_string(), self.check_database().await);
+        let mut services = HashMap::new();
+        services.insert("inference".to_string(), inference);
+        for (name, configured, status) in [
+            ("database", self.database.is_some(), database),
+            ("email", self.email.is_some(), email),
+            ("calendar", self.calendar.is_some(), calendar),
+            ("weather", self.weather.is_some(), weather),
+        ] {
+            if configured {
+                services.insert(name.to_string(), status);
+            }
+        }
 
-        // Check optional services
-        services.insert("email".to_string(), self.check_email().await);
+        HealthReport::new(services)
+    }
 
-        services.insert("calendar".to_string(), self.check_calendar().await);
+    /// Check health of the [`CRITICAL_SERVICES`] only
+    ///
+    /// The database is omitted if none is configured.
+    #[instrument(skip(self))]
+    pub async fn check_critical(&self) -> HealthReport {
+        let (inference, database) = tokio::join!(self.check_inference(), self.check_database());
 
-        services.insert("weather".to_string(), self.check_weather().await);
+        let mut services = HashMap::from([("inference".to_string(), inference)]);
+        if self.database.is_some() {
+            services.insert("database".to_string(), database);
+        }
 
         HealthReport::new(services)
     }
 
+    /// Apply the degraded threshold and track the last error of a service
+    fn finish(&self, service: &'static str, mut health: ServiceHealth) -> ServiceHealth {
+        let slow = health
+            .response_time_ms
+            .filter(|ms| *ms > self.config.degraded_latency_ms);
+        if let (ServiceState::Up, Some(ms)) = (health.status, slow) {
+            warn!(service, response_time_ms = ms, "Service responds slowly");
+            health.status = ServiceState::Degraded;
+        }
+
+        let mut last_errors = self.last_errors.lock();
+        if let Some(ref error) = health.error {
+            last_errors.insert(service, error.clone());
+        }
+        health.last_error = last_errors.get(service).cloned();
+        health
+    }
+
+    /// Get retry queue statistics
+    ///
+    /// Returns `None` if no retry queue is configured or the query fails or times out.
+    #[instrument(skip(self))]
+    pub async fn queue_stats(&self) -> Option<QueueStats> {
+        let retry_queue = self.retry_queue.as_ref()?;
+        let timeout_duration = self.config.timeout_for_service("retry_queue");
+
+        match timeout(timeout_duration, retry_queue.queue_stats()).await {
+            Ok(Ok(stats)) => {
+                if stats.dead_letter > 0 {
+                    debug!(
+                        dead_letter = stats.dead_letter,
+                        "Retry queue has dead-lettered items"
+                    );
+                }
+                Some(stats)
+            },
+            Ok(Err(e)) => {
+                warn!(error = %e, "Failed to get retry queue statistics");
+                None
+            },
+            Err(_) => {
+                warn!("Retry queue statistics check timed out");
+                None
+            },
+        }
+    }
+
     /// Check only the inference engine health
     #[instrument(skip(self))]
-    #[allow(clippy::option_if_let_else)]
     pub async fn check_inference(&self) -> ServiceHealth {
+        let health = self.probe_inference().await;
+        self.finish("inference", health)
+    }
+
+    #[allow(clippy::option_if_let_else)]
+    async fn probe_inference(&self) -> ServiceHealth {
         let timeout_duration = self.config.timeout_for_service("inference");
         let start = std::time::Instant::now();
 
@@ -290,8 +531,12 @@ impl HealthService {
 
     /// Check database health
     #[instrument(skip(self))]
-    #[allow(clippy::option_if_let_else)]
     pub async fn check_database(&self) -> ServiceHealth {
+        let health = self.probe_database().await;
+        self.finish("database", health)
+    }
+
+    async fn probe_database(&self) -> ServiceHealth {
         let Some(ref database) = self.database else {
             return ServiceHealth::unconfigured();
         };
@@ -299,29 +544,80 @@ impl HealthService {
         let timeout_duration = self.config.timeout_for_service("database");
         let start = std::time::Instant::now();
 
-        let result = timeout(timeout_duration, database.is_available()).await;
+        // One round-trip covers reachability and the last integrity check
+        let Ok(result) = timeout(timeout_duration, database.check_health()).await else {
+            warn!("Database health check timed out");
+            return ServiceHealth::timeout();
+        };
 
-        if let Ok(available) = result {
-            // SAFETY: Response time in milliseconds will never exceed u64::MAX in practice.
-            #[allow(clippy::cast_possible_truncation)]
-            let response_time = start.elapsed().as_millis() as u64;
-            if available {
+        // SAFETY: Response time in milliseconds will never exceed u64::MAX in practice.
+        #[allow(clippy::cast_possible_truncation)]
+        let response_time = start.elapsed().as_millis() as u64;
+        let health = match result {
+            Ok(health) if health.reachable => health,
+            Ok(_) => {
+                warn!(response_time_ms = response_time, "Database unhealthy");
+                return ServiceHealth::unhealthy("Database unavailable")
+                    .with_response_time(response_time);
+            },
+            Err(e) => {
+                warn!(response_time_ms = response_time, error = %e, "Database unhealthy");
+                return ServiceHealth::unhealthy("Database unavailable")
+                    .with_response_time(response_time);
+            },
+        };
+
+        // Surface the last integrity check, if the adapter tracks one
+        match health.integrity_ok {
+            Some(false) => {
+                warn!(
+                    response_time_ms = response_time,
+                    "Database integrity check failed"
+                );
+                ServiceHealth::unhealthy("Database integrity check failed")
+                    .with_response_time(response_time)
+            },
+            Some(true) => {
+                debug!(response_time_ms = response_time, "Database healthy");
+                ServiceHealth::healthy_with_info("integrity: ok").with_response_time(response_time)
+            },
+            None => {
                 debug!(response_time_ms = response_time, "Database healthy");
                 ServiceHealth::healthy().with_response_time(response_time)
-            } else {
-                warn!(response_time_ms = response_time, "Database unhealthy");
-                ServiceHealth::unhealthy("Database unavailable").with_response_time(response_time)
-            }
-        } else {
-            warn!("Database health check timed out");
-            ServiceHealth::timeout()
+            },
+        }
+    }
+
+    /// Get detailed database information (version, schema version, ...)
+    ///
+    /// Returns `None` if no database is configured or the check fails or times out.
+    #[instrument(skip(self))]
+    pub async fn database_details(&self) -> Option<DatabaseHealth> {
+        let database = self.database.as_ref()?;
+        let timeout_duration = self.config.timeout_for_service("database");
+
+        match timeout(timeout_duration, database.check_health()).await {
+            Ok(Ok(health)) => Some(health),
+            Ok(Err(e)) => {
+                warn!(error = %e, "Failed to get database details");
+                None
+            },
+            Err(_) => {
+                warn!("Database details check timed out");
+                None
+            },
         }
     }
 
     /// Check email service health
     #[instrument(skip(self))]
-    #[allow(clippy::option_if_let_else)]
     pub async fn check_email(&self) -> ServiceHealth {
+        let health = self.probe_email().await;
+        self.finish("email", health)
+    }
+
+    #[allow(clippy::option_if_let_else)]
+    async fn probe_email(&self) -> ServiceHealth {
         let Some(ref email) = self.email else {
             return ServiceHealth::unconfigured();
         };
@@ -351,8 +647,13 @@ impl HealthService {
 
     /// Check calendar service health
     #[instrument(skip(self))]
-    #[allow(clippy::option_if_let_else)]
     pub async fn check_calendar(&self) -> ServiceHealth {
+        let health = self.probe_calendar().await;
+        self.finish("calendar", health)
+    }
+
+    #[allow(clippy::option_if_let_else)]
+    async fn probe_calendar(&self) -> ServiceHealth {
         let Some(ref calendar) = self.calendar else {
             return ServiceHealth::unconfigured();
         };
@@ -385,8 +686,13 @@ impl HealthService {
 
     /// Check weather service health
     #[instrument(skip(self))]
-    #[allow(clippy::option_if_let_else)]
     pub async fn check_weather(&self) -> ServiceHealth {
+        let health = self.probe_weather().await;
+        self.finish("weather", health)
+    }
+
+    #[allow(clippy::option_if_let_else)]
+    async fn probe_weather(&self) -> ServiceHealth {
         let Some(ref weather) = self.weather else {
             return ServiceHealth::unconfigured();
         };
@@ -610,6 +916,64 @@ mod tests {
         assert!(status.info.as_ref().unwrap().contains("not configured"));
     }
 
+    #[tokio::test]
+    async fn health_service_check_database_reports_failed_integrity() {
+        use crate::ports::{DatabaseHealth, MockDatabaseHealthPort};
+
+        let mut database = MockDatabaseHealthPort::new();
+        database
+            .expect_check_health()
+            .returning(|| Ok(DatabaseHealth::healthy().with_integrity(false)));
+
+        let service =
+            HealthService::new(create_mock_inference(true)).with_database(Arc::new(database));
+
+        let status = service.check_database().await;
+        assert!(!status.healthy);
+        assert!(status.error.unwrap().contains("integrity"));
+    }
+
+    #[tokio::test]
+    async fn health_service_check_database_reports_passed_integrity() {
+        use crate::ports::{DatabaseHealth, MockDatabaseHealthPort};
+
+        let mut database = MockDatabaseHealthPort::new();
+        database.expect_is_available().never();
+        database
+            .expect_check_health()
+            .times(1)
+            .returning(|| Ok(DatabaseHealth::healthy().with_integrity(true)));
+
+        let service =
+            HealthService::new(create_mock_inference(true)).with_database(Arc::new(database));
+
+        let status = service.check_database().await;
+        assert!(status.healthy);
+        assert_eq!(status.info.as_deref(), Some("integrity: ok"));
+    }
+
+    #[tokio::test]
+    async fn health_service_database_details_returns_schema_version() {
+        use crate::ports::MockDatabaseHealthPort;
+
+        let mut database = MockDatabaseHealthPort::new();
+        database
+            .expect_check_health()
+            .returning(|| Ok(DatabaseHealth::healthy().with_schema_version(12)));
+
+        let service =
+            HealthService::new(create_mock_inference(true)).with_database(Arc::new(database));
+
+        let details = service.database_details().await.unwrap();
+        assert_eq!(details.schema_version, Some(12));
+    }
+
+    #[tokio::test]
+    async fn health_service_database_details_none_without_database() {
+        let service = HealthService::new(create_mock_inference(true));
+        assert!(service.database_details().await.is_none());
+    }
+
     #[tokio::test]
     async fn health_service_check_all() {
         let inference = create_mock_inference(true);
@@ -620,6 +984,219 @@ mod tests {
         assert!(report.services.contains_key("email"));
         assert!(report.services.contains_key("calendar"));
         assert!(report.services.contains_key("weather"));
+        assert!(report.queue.is_none());
+    }
+
+    #[tokio::test]
+    async fn health_service_check_all_includes_queue_stats() {
+        use crate::ports::MockRetryQueuePort;
+
+        let mut retry_queue = MockRetryQueuePort::new();
+        retry_queue.expect_queue_stats().returning(|| {
+            Ok(QueueStats {
+                pending: 4,
+                in_progress: 1,
+                dead_letter: 2,
+            })
+        });
+
+        let service =
+            HealthService::new(create_mock_inference(true)).with_retry_queue(Arc::new(retry_queue));
+
+        let report = service.check_all().await;
+        let queue = report.queue.unwrap();
+        assert_eq!(queue.backlog(), 5);
+        assert_eq!(queue.dead_letter, 2);
+        // Dead letters are reported but do not make the system unhealthy
+        assert!(!report.services.contains_key("retry_queue"));
+    }
+
+    #[tokio::test]
+    async fn health_service_queue_stats_none_on_error() {
+        use crate::ports::MockRetryQueuePort;
+
+        let mut retry_queue = MockRetryQueuePort::new();
+        retry_queue
+            .expect_queue_stats()
+            .returning(|| Err(ApplicationError::Internal("db gone".to_string())));
+
+        let service =
+            HealthService::new(create_mock_inference(true)).with_retry_queue(Arc::new(retry_queue));
+
+        assert!(service.queue_stats().await.is_none());
+    }
+
+    #[test]
+    fn health_status_aggregate() {
+        let healthy = ServiceHealth::healthy();
+        let unhealthy = ServiceHealth::unhealthy("down");
+
+        assert_eq!(
+            HealthStatus::aggregate([&healthy, &healthy]),
+            HealthStatus::Healthy
+        );
+        assert_eq!(
+            HealthStatus::aggregate([&healthy, &unhealthy]),
+            HealthStatus::Degraded
+        );
+        assert_eq!(
+            HealthStatus::aggregate([&unhealthy, &unhealthy]),
+            HealthStatus::Unhealthy
+        );
+        assert_eq!(HealthStatus::aggregate([]), HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn health_status_aggregate_with_degraded_service() {
+        let healthy = ServiceHealth::healthy();
+        let degraded = ServiceHealth::degraded("slow");
+
+        assert_eq!(
+            HealthStatus::aggregate([&healthy, &degraded]),
+            HealthStatus::Degraded
+        );
+    }
+
+    #[test]
+    fn health_report_critical_healthy_ignores_optional_services() {
+        let report = HealthReport::new(HashMap::from([
+            ("inference".to_string(), ServiceHealth::healthy()),
+            ("database".to_string(), ServiceHealth::healthy()),
+            ("email".to_string(), ServiceHealth::unhealthy("down")),
+        ]));
+        assert!(report.critical_healthy());
+
+        let report = HealthReport::new(HashMap::from([
+            ("inference".to_string(), ServiceHealth::healthy()),
+            ("database".to_string(), ServiceHealth::unhealthy("locked")),
+        ]));
+        assert!(!report.critical_healthy());
+    }
+
+    #[test]
+    fn health_service_reports_slow_service_as_degraded() {
+        let service = HealthService::new(create_mock_inference(true));
+
+        let fast = service.finish("weather", ServiceHealth::healthy().with_response_time(20));
+        let slow = service.finish("weather", ServiceHealth::healthy().with_response_time(1500));
+
+        assert_eq!(fast.status, ServiceState::Up);
+        assert!(slow.healthy);
+        assert_eq!(slow.status, ServiceState::Degraded);
+    }
+
+    #[tokio::test]
+    async fn health_service_keeps_last_error_after_recovery() {
+        use crate::ports::MockWeatherPort;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let available = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&available);
+        let mut weather = MockWeatherPort::new();
+        weather
+            .expect_is_available()
+            .returning(move || flag.load(Ordering::SeqCst));
+        let service =
+            HealthService::new(create_mock_inference(true)).with_weather(Arc::new(weather));
+
+        let down = service.check_weather().await;
+        assert_eq!(down.status, ServiceState::Down);
+        assert_eq!(
+            down.last_error.as_deref(),
+            Some("Weather service unavailable")
+        );
+
+        available.store(true, Ordering::SeqCst);
+        let up = service.check_weather().await;
+        assert_eq!(up.status, ServiceState::Up);
+        assert!(up.error.is_none());
+        assert_eq!(
+            up.last_error.as_deref(),
+            Some("Weather service unavailable")
+        );
+    }
+
+    #[tokio::test]
+    async fn health_service_check_critical_omits_unconfigured_database() {
+        let service = HealthService::new(create_mock_inference(true));
+
+        let report = service.check_critical().await;
+
+        assert_eq!(report.services.len(), 1);
+        assert!(report.critical_healthy());
+    }
+
+    #[test]
+    fn health_status_serializes_lowercase() {
+        let json = serde_json::to_string(&HealthStatus::Degraded).unwrap();
+        assert_eq!(json, r#""degraded""#);
+    }
+
+    #[tokio::test]
+    async fn health_service_check_configured_omits_unconfigured_services() {
+        let service = HealthService::new(create_mock_inference(true));
+
+        let report = service.check_configured().await;
+
+        assert_eq!(report.services.len(), 1);
+        assert!(report.services.contains_key("inference"));
+        assert_eq!(report.status(), HealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn health_service_check_configured_reports_degraded() {
+        use crate::ports::{MockDatabaseHealthPort, MockWeatherPort};
+
+        let mut database = MockDatabaseHealthPort::new();
+        database
+            .expect_check_health()
+            .returning(|| Ok(DatabaseHealth::healthy()));
+        let mut weather = MockWeatherPort::new();
+        weather.expect_is_available().returning(|| false);
+
+        let service = HealthService::new(create_mock_inference(true))
+            .with_database(Arc::new(database))
+            .with_weather(Arc::new(weather));
+
+        let report = service.check_configured().await;
+
+        assert_eq!(report.services.len(), 3);
+        assert_eq!(report.status(), HealthStatus::Degraded);
+        let weather = report.service_status("weather").unwrap();
+        assert!(!weather.healthy);
+        assert_eq!(
+            weather.error.as_deref(),
+            Some("Weather service unavailable")
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn health_service_check_configured_respects_service_timeout() {
+        struct SlowDatabase;
+
+        #[async_trait::async_trait]
+        impl DatabaseHealthPort for SlowDatabase {
+            async fn is_available(&self) -> bool {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                true
+            }
+
+            async fn check_health(&self) -> Result<DatabaseHealth, ApplicationError> {
+                Ok(DatabaseHealth::healthy())
+            }
+        }
+
+        let mut config = HealthConfig::default();
+        config.service_timeouts.insert("database".to_string(), 1);
+        let service = HealthService::new(create_mock_inference(true))
+            .with_config(config)
+            .with_database(Arc::new(SlowDatabase));
+
+        let report = service.check_configured().await;
+
+        let database = report.service_status("database").unwrap();
+        assert!(database.error.as_ref().unwrap().contains("timed out"));
+        assert_eq!(report.status(), HealthStatus::Degraded);
     }
 
     #[test]
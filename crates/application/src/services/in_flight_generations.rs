@@ -0,0 +1,142 @@
+//! Tracking of in-flight generations per sender
+//!
+//! When a user sends a new message while an earlier reply is still being
+//! generated, the earlier reply is no longer wanted. [`InFlightGenerations`]
+//! hands out a [`CancellationToken`] per sender and cancels the previous one
+//! as soon as a newer generation begins.
+
+use std::{collections::HashMap, sync::Arc};
+
+use parking_lot::Mutex;
+use tokio_util::sync::CancellationToken;
+
+/// Latest generation per sender
+#[derive(Debug, Default)]
+struct Registry {
+    next_id: u64,
+    active: HashMap<String, (u64, CancellationToken)>,
+}
+
+/// Cancels superseded generations, keyed by sender
+///
+/// Cloning is cheap; clones share the same registry.
+#[derive(Debug, Clone, Default)]
+pub struct InFlightGenerations {
+    registry: Arc<Mutex<Registry>>,
+}
+
+impl InFlightGenerations {
+    /// Create an empty tracker
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Begin a generation for `sender`, cancelling the previous one
+    ///
+    /// The returned guard unregisters the generation when dropped.
+    #[must_use]
+    pub fn begin(&self, sender: &str) -> GenerationGuard {
+        let token = CancellationToken::new();
+        let mut registry = self.registry.lock();
+        registry.next_id += 1;
+        let id = registry.next_id;
+        if let Some((_, previous)) = registry
+            .active
+            .insert(sender.to_string(), (id, token.clone()))
+        {
+            previous.cancel();
+        }
+        drop(registry);
+
+        GenerationGuard {
+            registry: Arc::clone(&self.registry),
+            sender: sender.to_string(),
+            id,
+            token,
+        }
+    }
+
+    /// Number of generations currently running
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.registry.lock().active.len()
+    }
+
+    /// Whether no generation is running
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Registration of a running generation
+#[derive(Debug)]
+pub struct GenerationGuard {
+    registry: Arc<Mutex<Registry>>,
+    sender: String,
+    id: u64,
+    token: CancellationToken,
+}
+
+impl GenerationGuard {
+    /// Token that fires when a newer generation for the same sender begins
+    #[must_use]
+    pub const fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+impl Drop for GenerationGuard {
+    fn drop(&mut self) {
+        let mut registry = self.registry.lock();
+        // A newer generation may already have replaced this one
+        if registry
+            .active
+            .get(&self.sender)
+            .is_some_and(|(id, _)| *id == self.id)
+        {
+            registry.active.remove(&self.sender);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newer_generation_cancels_previous() {
+        let generations = InFlightGenerations::new();
+        let first = generations.begin("+491701234567");
+        let second = generations.begin("+491701234567");
+
+        assert!(first.token().is_cancelled());
+        assert!(!second.token().is_cancelled());
+        assert_eq!(generations.len(), 1);
+    }
+
+    #[test]
+    fn senders_are_independent() {
+        let generations = InFlightGenerations::new();
+        let alice = generations.begin("alice");
+        let bob = generations.begin("bob");
+
+        assert!(!alice.token().is_cancelled());
+        assert!(!bob.token().is_cancelled());
+        assert_eq!(generations.len(), 2);
+    }
+
+    #[test]
+    fn dropping_superseded_guard_keeps_newer_registration() {
+        let generations = InFlightGenerations::new();
+        let first = generations.begin("alice");
+        let second = generations.begin("alice");
+
+        drop(first);
+        assert_eq!(generations.len(), 1);
+
+        drop(second);
+        assert!(generations.is_empty());
+    }
+}
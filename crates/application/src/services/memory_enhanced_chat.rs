@@ -6,10 +6,11 @@
 
 use std::sync::Arc;
 
-use domain::{ChatMessage, Conversation, Memory, MemoryType, UserId};
+use domain::{ChatMessage, Conversation, Memory, MemoryType, TenantContext, UserId};
 use tracing::{debug, instrument, warn};
 
 use crate::{
+    RequestContext,
     error::ApplicationError,
     ports::{EmbeddingPort, EncryptionPort, InferencePort, MemoryStore, SimilarMemory},
     services::MemoryService,
@@ -45,6 +46,8 @@ impl Default for MemoryEnhancedChatConfig {
 /// Chat service enhanced with memory capabilities
 ///
 /// Provides automatic context retrieval (RAG) and learning from interactions.
+/// Per-request operations take the caller's [`RequestContext`] and only see
+/// memories of its tenant.
 ///
 /// # Examples
 ///
@@ -56,7 +59,7 @@ impl Default for MemoryEnhancedChatConfig {
 /// );
 ///
 /// // Chat with automatic RAG and learning
-/// let response = chat.chat(&user_id, "What is the capital of France?").await?;
+/// let response = chat.chat(&ctx, "What is the capital of France?").await?;
 /// ```
 pub struct MemoryEnhancedChat<S, E, C>
 where
@@ -121,15 +124,21 @@ where
     /// 1. Retrieves relevant context from memory (RAG)
     /// 2. Generates response with injected context
     /// 3. Stores the interaction as a memory
-    #[instrument(skip(self, message), fields(user_id = %user_id, message_len = message.len()))]
+    #[instrument(
+        skip(self, ctx, message),
+        fields(user_id = %ctx.user_id(), tenant_id = %ctx.tenant_id(), message_len = message.len())
+    )]
     pub async fn chat(
         &self,
-        user_id: &UserId,
+        ctx: &RequestContext,
         message: &str,
     ) -> Result<ChatMessage, ApplicationError> {
+        let memory = self.memory_for(ctx);
+        let user_id = ctx.user_id();
+
         // Step 1: Retrieve relevant context (RAG)
         let context = if self.config.enable_rag {
-            self.retrieve_context(user_id, message).await?
+            memory.retrieve_context(&user_id, message).await?
         } else {
             Vec::new()
         };
@@ -147,7 +156,7 @@ where
 
         // Step 4: Learn from the interaction
         if self.config.enable_learning {
-            self.learn_from_interaction(user_id, message, &response.content)
+            self.learn_from_interaction(&memory, &user_id, message, &response.content)
                 .await?;
         }
 
@@ -161,16 +170,22 @@ where
     }
 
     /// Chat within a conversation context with memory enhancement
-    #[instrument(skip(self, conversation, message), fields(user_id = %user_id, conv_id = %conversation.id))]
+    #[instrument(
+        skip(self, ctx, conversation, message),
+        fields(user_id = %ctx.user_id(), tenant_id = %ctx.tenant_id(), conv_id = %conversation.id)
+    )]
     pub async fn chat_in_conversation(
         &self,
-        user_id: &UserId,
+        ctx: &RequestContext,
         conversation: &Conversation,
         message: &str,
     ) -> Result<ChatMessage, ApplicationError> {
+        let memory = self.memory_for(ctx);
+        let user_id = ctx.user_id();
+
         // Retrieve context
         let context = if self.config.enable_rag {
-            self.retrieve_context(user_id, message).await?
+            memory.retrieve_context(&user_id, message).await?
         } else {
             Vec::new()
         };
@@ -178,10 +193,15 @@ where
         // Build enhanced conversation with memory context
         let mut enhanced_conv = conversation.clone();
 
-        // Add memory context as a system message if we have relevant memories
+        // Add memory context as a system message if we have relevant memories;
+        // the conversation's own prompt (e.g. an override) wins over the config
         if !context.is_empty() {
             let context_text = MemoryService::<S, E, C>::format_context_for_prompt(&context);
-            let system_with_context = match &self.config.system_prompt {
+            let base = conversation
+                .system_prompt
+                .as_ref()
+                .or(self.config.system_prompt.as_ref());
+            let system_with_context = match base {
                 Some(s) => format!("{s}\n\n{context_text}"),
                 None => context_text,
             };
@@ -198,20 +218,18 @@ where
 
         // Learn from interaction
         if self.config.enable_learning {
-            self.learn_from_interaction(user_id, message, &response.content)
+            self.learn_from_interaction(&memory, &user_id, message, &response.content)
                 .await?;
         }
 
         Ok(response_message)
     }
 
-    /// Retrieve relevant context for a query
-    async fn retrieve_context(
-        &self,
-        user_id: &UserId,
-        query: &str,
-    ) -> Result<Vec<SimilarMemory>, ApplicationError> {
-        self.memory_service.retrieve_context(user_id, query).await
+    /// The memory service scoped to the tenant of the request
+    fn memory_for(&self, ctx: &RequestContext) -> MemoryService<S, E, C> {
+        self.memory_service
+            .clone()
+            .with_tenant(TenantContext::new(ctx.tenant_id()))
     }
 
     /// Build system prompt with memory context
@@ -233,6 +251,7 @@ where
     /// Learn from a user-assistant interaction
     async fn learn_from_interaction(
         &self,
+        memory_service: &MemoryService<S, E, C>,
         user_id: &UserId,
         question: &str,
         answer: &str,
@@ -252,7 +271,7 @@ where
         let memory = Memory::new(*user_id, content, summary, MemoryType::Context)
             .with_importance(self.config.default_importance);
 
-        match self.memory_service.store(memory).await {
+        match memory_service.store(memory).await {
             Ok(mem) => {
                 debug!(memory_id = %mem.id, "Learned from interaction");
             },
@@ -289,45 +308,45 @@ where
     /// Manually store a fact in memory
     pub async fn remember_fact(
         &self,
-        user_id: &UserId,
+        ctx: &RequestContext,
         fact: &str,
         importance: f32,
     ) -> Result<Memory, ApplicationError> {
-        self.memory_service
-            .store_fact(*user_id, fact, importance)
+        self.memory_for(ctx)
+            .store_fact(ctx.user_id(), fact, importance)
             .await
     }
 
     /// Manually store a user preference
     pub async fn remember_preference(
         &self,
-        user_id: &UserId,
+        ctx: &RequestContext,
         preference: &str,
         importance: f32,
     ) -> Result<Memory, ApplicationError> {
-        self.memory_service
-            .store_preference(*user_id, preference, importance)
+        self.memory_for(ctx)
+            .store_preference(ctx.user_id(), preference, importance)
             .await
     }
 
     /// Manually store a correction
     pub async fn remember_correction(
         &self,
-        user_id: &UserId,
+        ctx: &RequestContext,
         correction: &str,
         importance: f32,
     ) -> Result<Memory, ApplicationError> {
-        self.memory_service
-            .store_correction(*user_id, correction, importance)
+        self.memory_for(ctx)
+            .store_correction(ctx.user_id(), correction, importance)
             .await
     }
 
-    /// Get memory statistics for a user
+    /// Get memory statistics for the requesting user
     pub async fn memory_stats(
         &self,
-        user_id: &UserId,
+        ctx: &RequestContext,
     ) -> Result<crate::ports::MemoryStats, ApplicationError> {
-        self.memory_service.stats(user_id).await
+        self.memory_for(ctx).stats(&ctx.user_id()).await
     }
 
     /// Apply memory decay
@@ -356,6 +375,7 @@ mod tests {
     use super::*;
     use crate::ports::{MockEmbeddingPort, MockMemoryStore, NoOpEncryption, SimilarMemory};
     use crate::services::MemoryServiceConfig;
+    use domain::TenantId;
 
     #[test]
     fn test_config_default() {
@@ -787,8 +807,8 @@ mod tests {
         };
 
         let chat = MemoryEnhancedChat::new(inference, memory_service, config);
-        let user_id = UserId::new();
-        let response = chat.chat(&user_id, "Hello").await.unwrap();
+        let ctx = RequestContext::new(UserId::new(), TenantId::default());
+        let response = chat.chat(&ctx, "Hello").await.unwrap();
 
         assert!(!response.content.is_empty());
     }
@@ -804,8 +824,8 @@ mod tests {
         };
 
         let chat = MemoryEnhancedChat::new(inference, memory_service, config);
-        let user_id = UserId::new();
-        let response = chat.chat(&user_id, "Test message").await.unwrap();
+        let ctx = RequestContext::new(UserId::new(), TenantId::default());
+        let response = chat.chat(&ctx, "Test message").await.unwrap();
 
         assert!(response.content.contains("System response"));
     }
@@ -821,10 +841,10 @@ mod tests {
         };
 
         let chat = MemoryEnhancedChat::new(inference, memory_service, config);
-        let user_id = UserId::new();
+        let ctx = RequestContext::new(UserId::new(), TenantId::default());
 
         let response = chat
-            .chat(&user_id, "A longer message for testing purposes")
+            .chat(&ctx, "A longer message for testing purposes")
             .await
             .unwrap();
         assert!(!response.content.is_empty());
@@ -841,11 +861,11 @@ mod tests {
         };
 
         let chat = MemoryEnhancedChat::new(inference, memory_service, config);
-        let user_id = UserId::new();
+        let ctx = RequestContext::new(UserId::new(), TenantId::default());
         let conversation = Conversation::new();
 
         let response = chat
-            .chat_in_conversation(&user_id, &conversation, "Hello")
+            .chat_in_conversation(&ctx, &conversation, "Hello")
             .await
             .unwrap();
         assert!(response.content.contains("Context response"));
@@ -863,11 +883,11 @@ mod tests {
         };
 
         let chat = MemoryEnhancedChat::new(inference, memory_service, config);
-        let user_id = UserId::new();
+        let ctx = RequestContext::new(UserId::new(), TenantId::default());
         let conversation = Conversation::new();
 
         let response = chat
-            .chat_in_conversation(&user_id, &conversation, "Test")
+            .chat_in_conversation(&ctx, &conversation, "Test")
             .await
             .unwrap();
         assert!(!response.content.is_empty());
@@ -879,14 +899,18 @@ mod tests {
 
         // Setup mock with expectation
         let mut mock_store = MockMemoryStore::new();
-        mock_store.expect_stats().returning(|_| {
-            Ok(crate::ports::MemoryStats {
-                total_count: 5,
-                by_type: vec![],
-                avg_importance: 0.5,
-                with_embeddings: 3,
-            })
-        });
+        let tenant_id = TenantId::new();
+        mock_store
+            .expect_stats()
+            .withf(move |tenant, _| tenant.tenant_id() == tenant_id)
+            .returning(|_, _| {
+                Ok(crate::ports::MemoryStats {
+                    total_count: 5,
+                    by_type: vec![],
+                    avg_importance: 0.5,
+                    with_embeddings: 3,
+                })
+            });
 
         let store = Arc::new(mock_store);
         let embedding = Arc::new(MockEmbeddingPort::new());
@@ -899,9 +923,9 @@ mod tests {
         let config = MemoryEnhancedChatConfig::default();
 
         let chat = MemoryEnhancedChat::new(inference, memory_service, config);
-        let user_id = UserId::new();
+        let ctx = RequestContext::new(UserId::new(), tenant_id);
 
-        let stats = chat.memory_stats(&user_id).await.unwrap();
+        let stats = chat.memory_stats(&ctx).await.unwrap();
         assert_eq!(stats.total_count, 5);
     }
 
@@ -911,7 +935,7 @@ mod tests {
 
         // Setup mock with expectation
         let mut mock_store = MockMemoryStore::new();
-        mock_store.expect_apply_decay().returning(|_| Ok(vec![]));
+        mock_store.expect_apply_decay().returning(|_, _| Ok(vec![]));
 
         let store = Arc::new(mock_store);
         let embedding = Arc::new(MockEmbeddingPort::new());
@@ -937,7 +961,7 @@ mod tests {
         let mut mock_store = MockMemoryStore::new();
         mock_store
             .expect_cleanup_below_threshold()
-            .returning(|_| Ok(0));
+            .returning(|_, _| Ok(0));
 
         let store = Arc::new(mock_store);
         let embedding = Arc::new(MockEmbeddingPort::new());
@@ -4,11 +4,11 @@
 //! - Memory storage with encryption
 //! - Embedding generation for semantic search
 //! - RAG-based context retrieval
-//! - Memory importance scoring and decay
+//! - Memory importance scoring, decay and consolidation
 
 use std::sync::Arc;
 
-use domain::{Memory, MemoryId, MemoryQuery, MemoryType, UserId};
+use domain::{Memory, MemoryId, MemoryQuery, MemoryType, TenantContext, UserId, cosine_similarity};
 use tracing::{debug, info, instrument, warn};
 
 use crate::{
@@ -46,8 +46,24 @@ impl Default for MemoryServiceConfig {
     }
 }
 
+/// Outcome of a decay and consolidation run
+#[derive(Debug, Clone, Default)]
+pub struct ConsolidationReport {
+    /// Statistics across all users of the tenant before the run
+    pub before: MemoryStats,
+    /// Statistics across all users of the tenant after the run
+    pub after: MemoryStats,
+    /// Near-duplicate memories folded into another entry
+    pub merged: usize,
+    /// Memories deleted for falling below `min_importance`
+    pub pruned: usize,
+}
+
 /// Memory service for storing and retrieving AI knowledge
 ///
+/// All memories are stored and searched within the service's tenant, which
+/// defaults to the single-tenant context.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -74,6 +90,7 @@ where
     embedding: Arc<E>,
     encryption: Arc<C>,
     config: MemoryServiceConfig,
+    tenant: TenantContext,
 }
 
 impl<S, E, C> Clone for MemoryService<S, E, C>
@@ -88,6 +105,7 @@ where
             embedding: Arc::clone(&self.embedding),
             encryption: Arc::clone(&self.encryption),
             config: self.config.clone(),
+            tenant: self.tenant,
         }
     }
 }
@@ -101,6 +119,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MemoryService")
             .field("config", &self.config)
+            .field("tenant", &self.tenant)
             .finish_non_exhaustive()
     }
 }
@@ -113,7 +132,7 @@ where
 {
     /// Create a new memory service
     #[must_use]
-    pub const fn new(
+    pub fn new(
         store: Arc<S>,
         embedding: Arc<E>,
         encryption: Arc<C>,
@@ -124,11 +143,27 @@ where
             embedding,
             encryption,
             config,
+            tenant: TenantContext::single_tenant(),
         }
     }
 
+    /// Scope all memory operations to the given tenant
+    #[must_use]
+    pub const fn with_tenant(mut self, tenant: TenantContext) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    /// The tenant this service stores and searches memories in
+    #[must_use]
+    pub const fn tenant(&self) -> &TenantContext {
+        &self.tenant
+    }
+
     /// Store a new memory with automatic embedding and optional encryption
     ///
+    /// The memory is assigned to the service's tenant.
+    ///
     /// # Arguments
     ///
     /// * `memory` - The memory to store
@@ -138,7 +173,7 @@ where
     /// The stored memory with its ID
     #[instrument(skip(self, memory), fields(memory_id, user_id = %memory.user_id))]
     pub async fn store(&self, memory: Memory) -> Result<Memory, ApplicationError> {
-        let mut memory = memory;
+        let mut memory = memory.with_tenant(self.tenant.tenant_id());
 
         // Generate embedding for semantic search
         let embedding = self.embedding.embed(&memory.content).await?;
@@ -157,7 +192,13 @@ where
         if let Some(ref emb) = memory.embedding {
             let similar = self
                 .store
-                .search_similar(&memory.user_id, emb, 1, self.config.merge_threshold)
+                .search_similar(
+                    &self.tenant,
+                    &memory.user_id,
+                    emb,
+                    1,
+                    self.config.merge_threshold,
+                )
                 .await?;
 
             if let Some(existing) = similar.first() {
@@ -204,6 +245,7 @@ where
         let similar = self
             .store
             .search_similar(
+                &self.tenant,
                 user_id,
                 &query_embedding,
                 self.config.rag_limit,
@@ -215,7 +257,7 @@ where
         let mut results = Vec::with_capacity(similar.len());
         for mut sim in similar {
             // Record that this memory was accessed (for decay calculation)
-            if let Err(e) = self.store.record_access(&sim.memory.id).await {
+            if let Err(e) = self.store.record_access(&self.tenant, &sim.memory.id).await {
                 warn!(memory_id = %sim.memory.id, error = %e, "Failed to record access");
             }
 
@@ -332,7 +374,7 @@ where
     /// Get a specific memory by ID
     #[instrument(skip(self))]
     pub async fn get(&self, id: &MemoryId) -> Result<Option<Memory>, ApplicationError> {
-        let memory = self.store.get(id).await?;
+        let memory = self.store.get(&self.tenant, id).await?;
 
         // Decrypt if needed
         if let Some(mut mem) = memory {
@@ -353,12 +395,15 @@ where
     /// Delete a memory
     #[instrument(skip(self))]
     pub async fn delete(&self, id: &MemoryId) -> Result<(), ApplicationError> {
-        self.store.delete(id).await
+        self.store.delete(&self.tenant, id).await
     }
 
     /// List memories with optional filtering
+    ///
+    /// The query is always scoped to the service's tenant.
     #[instrument(skip(self))]
     pub async fn list(&self, query: MemoryQuery) -> Result<Vec<Memory>, ApplicationError> {
+        let query = query.for_tenant(self.tenant.tenant_id());
         let memories = self.store.list(&query).await?;
 
         // Decrypt all memories if needed
@@ -385,7 +430,10 @@ where
     /// Returns IDs of memories that fell below threshold.
     #[instrument(skip(self))]
     pub async fn apply_decay(&self) -> Result<Vec<MemoryId>, ApplicationError> {
-        let affected = self.store.apply_decay(self.config.decay_factor).await?;
+        let affected = self
+            .store
+            .apply_decay(&self.tenant, self.config.decay_factor)
+            .await?;
         debug!(count = affected.len(), "Applied decay to memories");
         Ok(affected)
     }
@@ -395,15 +443,65 @@ where
     pub async fn cleanup_low_importance(&self) -> Result<usize, ApplicationError> {
         let deleted = self
             .store
-            .cleanup_below_threshold(self.config.min_importance)
+            .cleanup_below_threshold(&self.tenant, self.config.min_importance)
             .await?;
         info!(deleted, "Cleaned up low importance memories");
         Ok(deleted)
     }
 
+    /// Decay, deduplicate and prune the whole memory store
+    ///
+    /// Applies `decay_factor` to every memory, then folds memories of the
+    /// same user and type whose embeddings are at least `merge_threshold`
+    /// similar into the most important one of the group. Finally removes
+    /// everything below `min_importance`. Merging only ever happens within
+    /// the service's tenant.
+    #[instrument(skip(self))]
+    pub async fn consolidate(&self) -> Result<ConsolidationReport, ApplicationError> {
+        let tenant_query = MemoryQuery::new().for_tenant(self.tenant.tenant_id());
+        let before = MemoryStats::from_memories(&self.store.list(&tenant_query).await?);
+
+        self.apply_decay().await?;
+
+        let memories = self.store.list(&tenant_query).await?;
+        let mut merged = 0;
+        for group in duplicate_groups(&memories, self.config.merge_threshold) {
+            let Some((keep, absorbed)) = group.split_first() else {
+                continue;
+            };
+            let mut survivor = memories[*keep].clone();
+            for &index in absorbed {
+                absorb(&mut survivor, &memories[index]);
+            }
+            self.store.update(&self.tenant, &survivor).await?;
+            for &index in absorbed {
+                self.store.delete(&self.tenant, &memories[index].id).await?;
+            }
+            merged += absorbed.len();
+            debug!(memory_id = %survivor.id, absorbed = absorbed.len(), "Consolidated memories");
+        }
+
+        let pruned = self.cleanup_low_importance().await?;
+        let after = MemoryStats::from_memories(&self.store.list(&tenant_query).await?);
+
+        info!(
+            before = before.total_count,
+            after = after.total_count,
+            merged,
+            pruned,
+            "Consolidated memory store"
+        );
+        Ok(ConsolidationReport {
+            before,
+            after,
+            merged,
+            pruned,
+        })
+    }
+
     /// Get memory statistics for a user
     pub async fn stats(&self, user_id: &UserId) -> Result<MemoryStats, ApplicationError> {
-        self.store.stats(user_id).await
+        self.store.stats(&self.tenant, user_id).await
     }
 
     /// Format context for injection into prompts
@@ -475,13 +573,77 @@ where
             merged.summary = encrypted_summary;
         }
 
-        self.store.update(&merged).await?;
+        self.store.update(&self.tenant, &merged).await?;
         debug!(memory_id = %merged.id, "Merged memories");
 
         Ok(merged)
     }
 }
 
+/// Group near-duplicate memories for consolidation
+///
+/// Only memories of the same user and type with embeddings are compared.
+/// Each returned group has at least two entries and starts with the most
+/// important memory, which survives the merge.
+fn duplicate_groups(memories: &[Memory], threshold: f32) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..memories.len())
+        .filter(|&i| memories[i].embedding.is_some())
+        .collect();
+    order.sort_by(|&a, &b| memories[b].importance.total_cmp(&memories[a].importance));
+
+    let mut grouped = vec![false; memories.len()];
+    let mut groups = Vec::new();
+    for (pos, &head) in order.iter().enumerate() {
+        if grouped[head] {
+            continue;
+        }
+        let anchor = &memories[head];
+        let Some(anchor_embedding) = anchor.embedding.as_deref() else {
+            continue;
+        };
+
+        let mut group = vec![head];
+        for &candidate in &order[pos + 1..] {
+            let other = &memories[candidate];
+            if grouped[candidate]
+                || other.user_id != anchor.user_id
+                || other.memory_type != anchor.memory_type
+            {
+                continue;
+            }
+            let similar = other
+                .embedding
+                .as_deref()
+                .is_some_and(|e| cosine_similarity(anchor_embedding, e) >= threshold);
+            if similar {
+                grouped[candidate] = true;
+                group.push(candidate);
+            }
+        }
+
+        if group.len() > 1 {
+            groups.push(group);
+        }
+    }
+    groups
+}
+
+/// Fold a duplicate into the surviving memory
+///
+/// The survivor keeps its content and embedding; importance, tags and
+/// access history are combined.
+fn absorb(survivor: &mut Memory, duplicate: &Memory) {
+    survivor.importance = survivor.importance.max(duplicate.importance);
+    for tag in &duplicate.tags {
+        if !survivor.tags.contains(tag) {
+            survivor.tags.push(tag.clone());
+        }
+    }
+    survivor.access_count = survivor.access_count.saturating_add(duplicate.access_count);
+    survivor.accessed_at = survivor.accessed_at.max(duplicate.accessed_at);
+    survivor.created_at = survivor.created_at.min(duplicate.created_at);
+}
+
 /// Create a simple summary from content
 fn summarize(content: &str) -> String {
     const MAX_SUMMARY_LEN: usize = 200;
@@ -497,6 +659,7 @@ fn summarize(content: &str) -> String {
 mod tests {
     use super::*;
     use crate::ports::{MockEmbeddingPort, MockMemoryStore, NoOpEncryption};
+    use domain::{TenantAware, TenantId};
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -706,25 +869,53 @@ mod tests {
             Ok(())
         }
 
-        async fn get(&self, id: &MemoryId) -> Result<Option<Memory>, ApplicationError> {
-            Ok(self.memories.lock().unwrap().get(id).cloned())
-        }
-
-        async fn update(&self, memory: &Memory) -> Result<(), ApplicationError> {
-            self.memories
+        async fn get(
+            &self,
+            tenant: &TenantContext,
+            id: &MemoryId,
+        ) -> Result<Option<Memory>, ApplicationError> {
+            Ok(self
+                .memories
                 .lock()
                 .unwrap()
-                .insert(memory.id, memory.clone());
+                .get(id)
+                .filter(|m| m.belongs_to_context(tenant))
+                .cloned())
+        }
+
+        async fn update(
+            &self,
+            tenant: &TenantContext,
+            memory: &Memory,
+        ) -> Result<(), ApplicationError> {
+            let mut memories = self.memories.lock().unwrap();
+            if memories
+                .get(&memory.id)
+                .is_some_and(|m| m.belongs_to_context(tenant))
+            {
+                memories.insert(memory.id, memory.clone());
+            }
             Ok(())
         }
 
-        async fn delete(&self, id: &MemoryId) -> Result<(), ApplicationError> {
-            self.memories.lock().unwrap().remove(id);
+        async fn delete(
+            &self,
+            tenant: &TenantContext,
+            id: &MemoryId,
+        ) -> Result<(), ApplicationError> {
+            let mut memories = self.memories.lock().unwrap();
+            if memories
+                .get(id)
+                .is_some_and(|m| m.belongs_to_context(tenant))
+            {
+                memories.remove(id);
+            }
             Ok(())
         }
 
         async fn search_similar(
             &self,
+            _tenant: &TenantContext,
             _user_id: &UserId,
             _embedding: &[f32],
             _limit: usize,
@@ -735,9 +926,13 @@ mod tests {
         }
 
         async fn list(&self, query: &MemoryQuery) -> Result<Vec<Memory>, ApplicationError> {
+            let tenant_id = query
+                .tenant_id
+                .ok_or_else(|| ApplicationError::NotAuthorized("no tenant".to_string()))?;
             let memories = self.memories.lock().unwrap();
             let mut result: Vec<Memory> = memories
                 .values()
+                .filter(|m| m.tenant_id == tenant_id)
                 .filter(|m| query.user_id.is_none_or(|uid| m.user_id == uid))
                 .filter(|m| query.min_importance.is_none_or(|min| m.importance >= min))
                 .cloned()
@@ -750,6 +945,7 @@ mod tests {
 
         async fn list_by_type(
             &self,
+            tenant: &TenantContext,
             user_id: &UserId,
             memory_type: MemoryType,
             limit: usize,
@@ -757,6 +953,7 @@ mod tests {
             let memories = self.memories.lock().unwrap();
             let result: Vec<Memory> = memories
                 .values()
+                .filter(|m| m.belongs_to_context(tenant))
                 .filter(|m| m.user_id == *user_id && m.memory_type == memory_type)
                 .take(limit)
                 .cloned()
@@ -764,10 +961,17 @@ mod tests {
             Ok(result)
         }
 
-        async fn apply_decay(&self, decay_rate: f32) -> Result<Vec<MemoryId>, ApplicationError> {
+        async fn apply_decay(
+            &self,
+            tenant: &TenantContext,
+            decay_rate: f32,
+        ) -> Result<Vec<MemoryId>, ApplicationError> {
             let mut memories = self.memories.lock().unwrap();
             let mut below_threshold = Vec::new();
-            for memory in memories.values_mut() {
+            for memory in memories
+                .values_mut()
+                .filter(|m| m.belongs_to_context(tenant))
+            {
                 memory.importance *= decay_rate;
                 if memory.importance < 0.1 {
                     below_threshold.push(memory.id);
@@ -776,10 +980,14 @@ mod tests {
             Ok(below_threshold)
         }
 
-        async fn cleanup_below_threshold(&self, threshold: f32) -> Result<usize, ApplicationError> {
+        async fn cleanup_below_threshold(
+            &self,
+            tenant: &TenantContext,
+            threshold: f32,
+        ) -> Result<usize, ApplicationError> {
             let mut memories = self.memories.lock().unwrap();
             let before = memories.len();
-            memories.retain(|_, m| m.importance >= threshold);
+            memories.retain(|_, m| !m.belongs_to_context(tenant) || m.importance >= threshold);
             Ok(before - memories.len())
         }
 
@@ -791,11 +999,15 @@ mod tests {
             Ok(vec![])
         }
 
-        async fn stats(&self, user_id: &UserId) -> Result<MemoryStats, ApplicationError> {
+        async fn stats(
+            &self,
+            tenant: &TenantContext,
+            user_id: &UserId,
+        ) -> Result<MemoryStats, ApplicationError> {
             let memories = self.memories.lock().unwrap();
             let user_memories: Vec<_> = memories
                 .values()
-                .filter(|m| m.user_id == *user_id)
+                .filter(|m| m.belongs_to_context(tenant) && m.user_id == *user_id)
                 .collect();
 
             let total_count = user_memories.len();
@@ -818,9 +1030,19 @@ mod tests {
             })
         }
 
-        async fn record_access(&self, id: &MemoryId) -> Result<(), ApplicationError> {
+        async fn record_access(
+            &self,
+            tenant: &TenantContext,
+            id: &MemoryId,
+        ) -> Result<(), ApplicationError> {
             self.access_log.lock().unwrap().push(*id);
-            if let Some(memory) = self.memories.lock().unwrap().get_mut(id) {
+            if let Some(memory) = self
+                .memories
+                .lock()
+                .unwrap()
+                .get_mut(id)
+                .filter(|m| m.belongs_to_context(tenant))
+            {
                 memory.record_access();
             }
             Ok(())
@@ -1013,6 +1235,29 @@ mod tests {
         assert!(memories[0].importance >= 0.5);
     }
 
+    #[tokio::test]
+    async fn test_list_is_scoped_to_tenant() {
+        let service_a = setup_testable_service().with_tenant(TenantContext::new(TenantId::new()));
+        let service_b = service_a
+            .clone()
+            .with_tenant(TenantContext::new(TenantId::new()));
+        let user_id = UserId::new();
+
+        let stored = service_b
+            .store_fact(user_id, "Tenant B secret", 0.9)
+            .await
+            .unwrap();
+        assert!(stored.belongs_to_context(service_b.tenant()));
+
+        let query = MemoryQuery::new().for_user(user_id);
+        assert!(service_a.list(query.clone()).await.unwrap().is_empty());
+        assert_eq!(service_b.list(query).await.unwrap().len(), 1);
+
+        assert!(service_a.get(&stored.id).await.unwrap().is_none());
+        service_a.delete(&stored.id).await.unwrap();
+        assert!(service_b.get(&stored.id).await.unwrap().is_some());
+    }
+
     #[tokio::test]
     async fn test_apply_decay() {
         let service = setup_testable_service();
@@ -1052,6 +1297,106 @@ mod tests {
         assert!((stats.avg_importance - 0.7).abs() < 0.01);
     }
 
+    #[test]
+    fn duplicate_groups_uses_cosine_similarity() {
+        let user_id = UserId::new();
+        let memories = vec![
+            Memory::new(user_id, "a", "a", MemoryType::Fact)
+                .with_importance(0.4)
+                .with_embedding(vec![1.0, 0.0, 0.0]),
+            Memory::new(user_id, "b", "b", MemoryType::Fact)
+                .with_importance(0.9)
+                .with_embedding(vec![0.99, 0.1, 0.0]),
+            Memory::new(user_id, "c", "c", MemoryType::Fact)
+                .with_importance(0.5)
+                .with_embedding(vec![0.0, 1.0, 0.0]),
+        ];
+        assert!(
+            cosine_similarity(
+                memories[0].embedding.as_deref().unwrap(),
+                memories[1].embedding.as_deref().unwrap()
+            ) > 0.85
+        );
+
+        let groups = duplicate_groups(&memories, 0.85);
+        // Most important memory leads the group, orthogonal one stays alone
+        assert_eq!(groups, vec![vec![1, 0]]);
+    }
+
+    #[test]
+    fn duplicate_groups_respects_user_and_type() {
+        let user_id = UserId::new();
+        let embedding = vec![0.5, 0.5, 0.5];
+        let memories = vec![
+            Memory::new(user_id, "a", "a", MemoryType::Fact).with_embedding(embedding.clone()),
+            Memory::new(user_id, "b", "b", MemoryType::Preference)
+                .with_embedding(embedding.clone()),
+            Memory::new(UserId::new(), "c", "c", MemoryType::Fact)
+                .with_embedding(embedding.clone()),
+            Memory::new(user_id, "d", "d", MemoryType::Fact),
+        ];
+
+        assert!(duplicate_groups(&memories, 0.85).is_empty());
+    }
+
+    #[test]
+    fn absorb_combines_metadata() {
+        let user_id = UserId::new();
+        let mut survivor = Memory::new(user_id, "keep", "keep", MemoryType::Fact)
+            .with_importance(0.5)
+            .with_tags(vec!["a".to_string()]);
+        survivor.access_count = 2;
+        let mut duplicate = Memory::new(user_id, "drop", "drop", MemoryType::Fact)
+            .with_importance(0.7)
+            .with_tags(vec!["a".to_string(), "b".to_string()]);
+        duplicate.access_count = 3;
+
+        absorb(&mut survivor, &duplicate);
+
+        assert_eq!(survivor.content, "keep");
+        assert!((survivor.importance - 0.7).abs() < 0.001);
+        assert_eq!(survivor.tags, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(survivor.access_count, 5);
+    }
+
+    #[tokio::test]
+    async fn test_consolidate_merges_and_prunes() {
+        let service = setup_testable_service();
+        let user_id = UserId::new();
+
+        // SimpleEmbedding returns the same vector for every text
+        service.store_fact(user_id, "Likes tea", 0.8).await.unwrap();
+        service
+            .store_fact(user_id, "Enjoys tea", 0.6)
+            .await
+            .unwrap();
+        service
+            .store_preference(user_id, "Prefers tea", 0.6)
+            .await
+            .unwrap();
+        let faint =
+            Memory::new(user_id, "Faint", "Faint", MemoryType::Context).with_importance(0.1);
+        service.store(faint).await.unwrap();
+
+        let report = service.consolidate().await.unwrap();
+
+        assert_eq!(report.before.total_count, 4);
+        assert_eq!(report.merged, 1);
+        assert_eq!(report.pruned, 1);
+        assert_eq!(report.after.total_count, 2);
+        assert!(report.after.by_type.contains(&(MemoryType::Fact, 1)));
+
+        let memories = service
+            .list(MemoryQuery::new().for_user(user_id))
+            .await
+            .unwrap();
+        let fact = memories
+            .iter()
+            .find(|m| m.memory_type == MemoryType::Fact)
+            .unwrap();
+        assert!((fact.importance - 0.8 * 0.95).abs() < 0.001);
+    }
+
     #[tokio::test]
     async fn test_retrieve_context_empty() {
         let service = setup_testable_service();
@@ -7,15 +7,22 @@ use std::sync::Arc;
 
 use domain::{
     entities::{ChatMessage, Conversation, ConversationSource},
-    value_objects::{PhoneNumber, UserId},
+    value_objects::{PhoneNumber, TenantId, UserId},
 };
 use tracing::{debug, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::{
+    RequestContext,
     error::ApplicationError,
-    ports::{ConversationStore, EmbeddingPort, EncryptionPort, InferencePort, MemoryStore},
-    services::{MemoryEnhancedChat, MemoryEnhancedChatConfig, MemoryService},
+    ports::{
+        ConversationStore, EmbeddingPort, EncryptionPort, InferencePort, MemoryStore,
+        SystemPromptStore,
+    },
+    services::{
+        MemoryEnhancedChat, MemoryEnhancedChatConfig, MemoryService,
+        chat_service::lookup_prompt_override,
+    },
 };
 
 /// Configuration for messenger chat service
@@ -91,6 +98,7 @@ where
 {
     memory_chat: MemoryEnhancedChat<S, E, C>,
     conversation_store: Arc<dyn ConversationStore>,
+    prompt_overrides: Option<Arc<dyn SystemPromptStore>>,
     config: MessengerChatConfig,
 }
 
@@ -104,6 +112,7 @@ where
         Self {
             memory_chat: self.memory_chat.clone(),
             conversation_store: Arc::clone(&self.conversation_store),
+            prompt_overrides: self.prompt_overrides.clone(),
             config: self.config.clone(),
         }
     }
@@ -154,10 +163,21 @@ where
         Self {
             memory_chat,
             conversation_store,
+            prompt_overrides: None,
             config,
         }
     }
 
+    /// Use per-conversation and per-contact system prompt overrides
+    ///
+    /// A stored override replaces the configured system prompt for the
+    /// sender's conversation.
+    #[must_use]
+    pub fn with_prompt_overrides(mut self, store: Arc<dyn SystemPromptStore>) -> Self {
+        self.prompt_overrides = Some(store);
+        self
+    }
+
     /// Process a message from a messenger platform
     ///
     /// This method:
@@ -194,10 +214,14 @@ where
         let (mut conversation, is_new) = self
             .get_or_create_conversation(source, phone_number)
             .await?;
+        if let Some(ref store) = self.prompt_overrides {
+            if let Some(prompt) = lookup_prompt_override(store.as_ref(), &conversation).await {
+                conversation.system_prompt = Some(prompt);
+            }
+        }
 
-        // Create user ID from phone number for memory context
-        // Use UUID v5 (namespace-based) for deterministic ID from phone number
-        let user_id = phone_number_to_user_id(phone_number);
+        // Derive a deterministic user ID from the phone number for memory context
+        let ctx = messenger_request_context(phone_number);
 
         // Add the user message
         let user_message = ChatMessage::user(message);
@@ -206,7 +230,7 @@ where
         // Generate response using memory-enhanced chat
         let response = self
             .memory_chat
-            .chat_in_conversation(&user_id, &conversation, message)
+            .chat_in_conversation(&ctx, &conversation, message)
             .await?;
 
         // Add response to conversation
@@ -243,8 +267,8 @@ where
         phone_number: &str,
         message: &str,
     ) -> Result<MessengerChatResponse, ApplicationError> {
-        let user_id = phone_number_to_user_id(phone_number);
-        let response = self.memory_chat.chat(&user_id, message).await?;
+        let ctx = messenger_request_context(phone_number);
+        let response = self.memory_chat.chat(&ctx, message).await?;
 
         Ok(MessengerChatResponse {
             message: response,
@@ -351,6 +375,14 @@ where
     }
 }
 
+/// Build the request context for a messenger sender
+///
+/// Messenger bridges serve the single-tenant deployment, so every sender
+/// belongs to the default tenant.
+fn messenger_request_context(phone_number: &str) -> RequestContext {
+    RequestContext::new(phone_number_to_user_id(phone_number), TenantId::default())
+}
+
 /// Convert a phone number to a deterministic UserId
 ///
 /// Uses a hash-based approach to create a consistent user ID from a phone number.
@@ -374,7 +406,117 @@ fn phone_number_to_user_id(phone_number: &str) -> UserId {
 
 #[cfg(test)]
 mod tests {
+    use domain::value_objects::ConversationId;
+    use mockall::mock;
+
     use super::*;
+    use crate::{
+        ports::{
+            InferenceResult, InferenceStream, MockEmbeddingPort, MockMemoryStore,
+            MockSystemPromptStore, NoOpEncryption, PromptOverrideTarget,
+        },
+        services::MemoryServiceConfig,
+    };
+
+    mock! {
+        pub InferenceEngine {}
+
+        #[async_trait::async_trait]
+        impl InferencePort for InferenceEngine {
+            async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_context(&self, conversation: &Conversation) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceResult, ApplicationError>;
+            async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn generate_stream_with_system(&self, system_prompt: &str, message: &str) -> Result<InferenceStream, ApplicationError>;
+            async fn is_healthy(&self) -> bool;
+            fn current_model(&self) -> String;
+            async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError>;
+            async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError>;
+        }
+    }
+
+    mock! {
+        pub ConvStore {}
+
+        #[async_trait::async_trait]
+        impl ConversationStore for ConvStore {
+            async fn save(&self, conversation: &Conversation) -> Result<(), ApplicationError>;
+            async fn get(&self, id: &ConversationId) -> Result<Option<Conversation>, ApplicationError>;
+            async fn get_by_phone_number(&self, source: ConversationSource, phone_number: &str) -> Result<Option<Conversation>, ApplicationError>;
+            async fn update(&self, conversation: &Conversation) -> Result<(), ApplicationError>;
+            async fn delete(&self, id: &ConversationId) -> Result<(), ApplicationError>;
+            async fn add_message(&self, conversation_id: &ConversationId, message: &ChatMessage) -> Result<(), ApplicationError>;
+            async fn list_recent(&self, limit: usize) -> Result<Vec<Conversation>, ApplicationError>;
+            async fn search(&self, query: &str, limit: usize) -> Result<Vec<Conversation>, ApplicationError>;
+            async fn cleanup_older_than(&self, cutoff: chrono::DateTime<chrono::Utc>) -> Result<usize, ApplicationError>;
+        }
+    }
+
+    fn memory_service() -> MemoryService<MockMemoryStore, MockEmbeddingPort, NoOpEncryption> {
+        let config = MemoryServiceConfig {
+            enable_encryption: false,
+            ..Default::default()
+        };
+        MemoryService::new(
+            Arc::new(MockMemoryStore::new()),
+            Arc::new(MockEmbeddingPort::new()),
+            Arc::new(NoOpEncryption),
+            config,
+        )
+    }
+
+    #[tokio::test]
+    async fn chat_applies_contact_prompt_override() {
+        let mut inference = MockInferenceEngine::new();
+        inference
+            .expect_generate_with_context()
+            .withf(|conversation| conversation.system_prompt.as_deref() == Some("Be casual."))
+            .times(1)
+            .returning(|_| {
+                Ok(InferenceResult {
+                    content: "Hey!".to_string(),
+                    model: "test-model".to_string(),
+                    tokens_used: Some(5),
+                    latency_ms: 10,
+                })
+            });
+
+        let mut store = MockConvStore::new();
+        store
+            .expect_get_by_phone_number()
+            .returning(|_, _| Ok(None));
+        store.expect_save().times(1).returning(|_| Ok(()));
+
+        let mut overrides = MockSystemPromptStore::new();
+        overrides.expect_get().returning(|target| match target {
+            PromptOverrideTarget::Contact(phone) if phone.as_str() == "+491701234567" => {
+                Ok(Some("Be casual.".to_string()))
+            },
+            _ => Ok(None),
+        });
+
+        let config = MessengerChatConfig {
+            enable_rag: false,
+            enable_learning: false,
+            system_prompt: Some("Be formal.".to_string()),
+            ..Default::default()
+        };
+        let service = MessengerChatService::new(
+            Arc::new(inference),
+            memory_service(),
+            Arc::new(store),
+            config,
+        )
+        .with_prompt_overrides(Arc::new(overrides));
+
+        let response = service
+            .chat(ConversationSource::Signal, "+491701234567", "Hi")
+            .await
+            .unwrap();
+
+        assert_eq!(response.message.content, "Hey!");
+        assert!(response.is_new_conversation);
+    }
 
     #[test]
     fn config_default() {
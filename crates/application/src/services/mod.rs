@@ -6,8 +6,10 @@ mod briefing_service;
 mod calendar_service;
 mod chat_service;
 mod conversation_context;
+mod daily_digest_service;
 mod email_service;
 mod health_service;
+mod in_flight_generations;
 pub mod location_helper;
 mod memory_enhanced_chat;
 mod memory_service;
@@ -21,27 +23,38 @@ mod voice_message_service;
 pub use agent_service::{AgentService, ApprovalStatus, CommandResult, ExecutionResult};
 pub use approval_service::ApprovalService;
 pub use briefing_service::{
-    BriefingService, CalendarBrief, EmailBrief, EmailHighlight, EventSummary, MorningBriefing,
-    TaskBrief, WeatherSummary,
+    BirthdayBrief, BriefingData, BriefingService, CalendarBrief, DepartureSuggestion, EmailBrief,
+    EmailHighlight, EventSummary, MorningBriefing, ReminderBrief, ReminderSummary, TaskBrief,
+    TransitBrief, WeatherSummary,
 };
 pub use calendar_service::CalendarService;
-pub use chat_service::{ChatService, MAX_CONVERSATION_MESSAGES};
+pub use chat_service::{ChatService, MAX_CONVERSATION_MESSAGES, SummarizationConfig};
 pub use conversation_context::{
     ConversationCacheStats, ConversationContextConfig, ConversationContextService,
 };
+pub use daily_digest_service::{
+    DIGEST_MESSAGE_CONTEXT, DailyDigestConfig, DailyDigestService, DigestOutcome, DigestReport,
+    DigestTemplate, QuietHours,
+};
 pub use email_service::{EmailService, InboxSummary};
-pub use health_service::{HealthConfig, HealthReport, HealthService, ServiceHealth};
+pub use health_service::{
+    CRITICAL_SERVICES, HealthConfig, HealthReport, HealthService, HealthStatus, ServiceHealth,
+    ServiceState,
+};
+pub use in_flight_generations::{GenerationGuard, InFlightGenerations};
 pub use location_helper::{
     format_location_with_coords_link, format_location_with_link, generate_maps_link,
     generate_maps_link_coords,
 };
 pub use memory_enhanced_chat::{MemoryEnhancedChat, MemoryEnhancedChatConfig};
-pub use memory_service::{MemoryService, MemoryServiceConfig};
+pub use memory_service::{ConsolidationReport, MemoryService, MemoryServiceConfig};
 pub use messenger_chat_service::{
     MessengerChatConfig, MessengerChatResponse, MessengerChatService,
 };
 pub use notification_service::{NotificationConfig, NotificationService, ReminderNotification};
-pub use prompt_sanitizer::{PromptSanitizer, PromptSecurityConfig, SecuritySensitivity};
+pub use prompt_sanitizer::{
+    MAX_SYSTEM_PROMPT_CHARS, PromptSanitizer, PromptSecurityConfig, SecuritySensitivity,
+};
 pub use reminder_formatter::{
     BriefingEvent, MorningBriefingData, format_acknowledge_confirmation,
     format_calendar_event_reminder, format_calendar_task_reminder, format_custom_reminder,
@@ -2,15 +2,19 @@
 //!
 //! Orchestrates the processing of due reminders: polls for due reminders,
 //! formats them with optional transit connections, and prepares
-//! notifications ready to send via any messenger.
+//! notifications ready to send via any messenger. With delivery tracking
+//! attached, notifications that never reached the recipient are prepared
+//! again for a bounded number of attempts.
 
 use std::sync::Arc;
 
-use domain::entities::{Reminder, ReminderSource};
+use chrono::Utc;
+use domain::entities::{Reminder, ReminderSource, ReminderStatus};
+use domain::value_objects::{MessengerSource, PhoneNumber};
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::error::ApplicationError;
-use crate::ports::{ReminderPort, TransitPort};
+use crate::ports::{DeliveryStatusPort, OutgoingTextMessage, ReminderPort, TransitPort};
 use crate::services::reminder_formatter;
 
 /// A formatted notification ready to be sent
@@ -33,6 +37,13 @@ pub struct NotificationConfig {
     pub home_longitude: Option<f64>,
     /// Maximum number of transit options to show
     pub max_transit_options: u8,
+    /// Minutes to wait for a delivery receipt before sending again
+    pub redelivery_after_minutes: u32,
+    /// Maximum number of notifications sent per reminder
+    pub max_delivery_attempts: u32,
+    /// Minutes after the reminder fired during which a queued notification
+    /// is still delivered
+    pub message_validity_minutes: u32,
 }
 
 impl Default for NotificationConfig {
@@ -42,6 +53,9 @@ impl Default for NotificationConfig {
             home_latitude: None,
             home_longitude: None,
             max_transit_options: 3,
+            redelivery_after_minutes: 10,
+            max_delivery_attempts: 3,
+            message_validity_minutes: 60,
         }
     }
 }
@@ -58,6 +72,7 @@ impl NotificationConfig {
 pub struct NotificationService<R: ReminderPort> {
     reminder_port: Arc<R>,
     transit_port: Option<Arc<dyn TransitPort>>,
+    delivery_status: Option<Arc<dyn DeliveryStatusPort>>,
     config: NotificationConfig,
 }
 
@@ -66,6 +81,7 @@ impl<R: ReminderPort> std::fmt::Debug for NotificationService<R> {
         f.debug_struct("NotificationService")
             .field("config", &self.config)
             .field("has_transit", &self.transit_port.is_some())
+            .field("has_delivery_tracking", &self.delivery_status.is_some())
             .finish_non_exhaustive()
     }
 }
@@ -76,6 +92,7 @@ impl<R: ReminderPort> NotificationService<R> {
         Self {
             reminder_port,
             transit_port: None,
+            delivery_status: None,
             config,
         }
     }
@@ -87,6 +104,130 @@ impl<R: ReminderPort> NotificationService<R> {
         self
     }
 
+    /// Attach delivery tracking to retry undelivered notifications
+    #[must_use]
+    pub fn with_delivery_tracking(mut self, delivery_status: Arc<dyn DeliveryStatusPort>) -> Self {
+        self.delivery_status = Some(delivery_status);
+        self
+    }
+
+    /// Build the message that delivers a notification to `recipient`
+    ///
+    /// The message names the reminder as its context and expires
+    /// `message_validity_minutes` after the reminder fired, or when the event
+    /// starts if that is earlier, so a late retry never announces a past event.
+    #[must_use]
+    pub fn message_for(
+        &self,
+        notification: &ReminderNotification,
+        recipient: PhoneNumber,
+    ) -> OutgoingTextMessage {
+        let reminder = &notification.reminder;
+        let expires = reminder.remind_at
+            + chrono::Duration::minutes(i64::from(self.config.message_validity_minutes));
+        let valid_until = reminder
+            .event_time
+            .map_or(expires, |event_time| event_time.min(expires));
+
+        OutgoingTextMessage::new(recipient, notification.message.clone())
+            .with_context(format!("reminder:{}", reminder.id))
+            .with_valid_until(valid_until)
+    }
+
+    /// Record that a notification was handed to the messenger
+    ///
+    /// `message_id` is the ID returned by the messenger, which later
+    /// delivery and read receipts refer to. Does nothing without delivery
+    /// tracking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delivery store fails.
+    pub async fn record_sent(
+        &self,
+        notification: &ReminderNotification,
+        source: MessengerSource,
+        recipient: &str,
+        message_id: &str,
+    ) -> Result<(), ApplicationError> {
+        let Some(delivery_status) = &self.delivery_status else {
+            return Ok(());
+        };
+        delivery_status
+            .record_sent(
+                message_id,
+                source,
+                recipient,
+                Some(notification.reminder.id),
+            )
+            .await
+    }
+
+    /// Prepare notifications again for reminders that were not delivered
+    ///
+    /// Picks reminders whose latest notification got no delivery receipt
+    /// within `redelivery_after_minutes`, up to `max_delivery_attempts`
+    /// notifications per reminder. Reminders that were acknowledged,
+    /// snoozed or rescheduled in the meantime are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delivery store cannot be queried.
+    #[instrument(skip(self))]
+    pub async fn process_undelivered_reminders(
+        &self,
+    ) -> Result<Vec<ReminderNotification>, ApplicationError> {
+        let Some(delivery_status) = &self.delivery_status else {
+            return Ok(Vec::new());
+        };
+
+        let cutoff =
+            Utc::now() - chrono::Duration::minutes(i64::from(self.config.redelivery_after_minutes));
+        let undelivered = delivery_status.undelivered_reminders(cutoff).await?;
+        let mut notifications = Vec::new();
+
+        for pending in undelivered {
+            if pending.attempts >= self.config.max_delivery_attempts {
+                debug!(
+                    reminder_id = %pending.reminder_id,
+                    attempts = pending.attempts,
+                    "Giving up on undelivered reminder"
+                );
+                continue;
+            }
+
+            let reminder = match self.reminder_port.get(&pending.reminder_id).await {
+                Ok(Some(reminder)) if reminder.status == ReminderStatus::Sent => reminder,
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!(
+                        reminder_id = %pending.reminder_id,
+                        error = %e,
+                        "Failed to load undelivered reminder"
+                    );
+                    continue;
+                },
+            };
+
+            match self.format_notification(&reminder).await {
+                Ok(message) => notifications.push(ReminderNotification { reminder, message }),
+                Err(e) => warn!(
+                    reminder_id = %pending.reminder_id,
+                    error = %e,
+                    "Failed to format reminder notification"
+                ),
+            }
+        }
+
+        if !notifications.is_empty() {
+            info!(
+                count = notifications.len(),
+                "Retrying undelivered reminders"
+            );
+        }
+        Ok(notifications)
+    }
+
     /// Process all due reminders and return formatted notifications
     ///
     /// This method:
@@ -215,7 +356,9 @@ mod tests {
     use domain::value_objects::UserId;
 
     use super::*;
-    use crate::ports::{MockReminderPort, MockTransitPort};
+    use crate::ports::{
+        MockDeliveryStatusPort, MockReminderPort, MockTransitPort, UndeliveredReminder,
+    };
 
     fn make_due_reminder(title: &str) -> Reminder {
         Reminder::new(
@@ -270,6 +413,51 @@ mod tests {
         assert!(result[0].message.contains("⏰"));
     }
 
+    #[tokio::test]
+    async fn weekly_reminder_fires_again_after_acknowledge() {
+        use std::sync::Mutex;
+
+        let stored = Arc::new(Mutex::new(
+            make_due_reminder("Take out trash")
+                .with_recurrence(domain::Recurrence::Weekly(Vec::new())),
+        ));
+
+        let mut mock_port = MockReminderPort::new();
+        let due_view = Arc::clone(&stored);
+        mock_port.expect_get_due_reminders().returning(move || {
+            let reminder = due_view.lock().unwrap().clone();
+            Ok(if reminder.is_due() {
+                vec![reminder]
+            } else {
+                vec![]
+            })
+        });
+        let update_view = Arc::clone(&stored);
+        mock_port.expect_update().returning(move |r| {
+            *update_view.lock().unwrap() = r.clone();
+            Ok(())
+        });
+
+        let service = NotificationService::new(Arc::new(mock_port), NotificationConfig::default());
+
+        // First occurrence fires once
+        assert_eq!(service.process_due_reminders().await.unwrap().len(), 1);
+        assert!(service.process_due_reminders().await.unwrap().is_empty());
+
+        // Acknowledging re-arms it one week later
+        let first_at = stored.lock().unwrap().remind_at;
+        stored.lock().unwrap().acknowledge();
+        let next_at = stored.lock().unwrap().remind_at;
+        assert_eq!(next_at, first_at + chrono::Duration::days(7));
+        assert!(service.process_due_reminders().await.unwrap().is_empty());
+
+        // Once the next occurrence is due it fires again
+        stored.lock().unwrap().remind_at = Utc::now() - chrono::Duration::minutes(1);
+        let fired = service.process_due_reminders().await.unwrap();
+        assert_eq!(fired.len(), 1);
+        assert!(fired[0].message.contains("Take out trash"));
+    }
+
     #[tokio::test]
     async fn process_event_reminder_without_transit() {
         let reminder = make_event_reminder("Meeting", "TU Berlin");
@@ -373,4 +561,164 @@ mod tests {
 
         assert!(!service.should_fetch_transit(&reminder));
     }
+
+    fn undelivered(reminder: &Reminder, attempts: u32) -> UndeliveredReminder {
+        UndeliveredReminder {
+            reminder_id: reminder.id,
+            source: MessengerSource::Signal,
+            recipient: Some("+491701234567".to_string()),
+            attempts,
+        }
+    }
+
+    #[tokio::test]
+    async fn undelivered_reminders_without_tracking() {
+        let service = NotificationService::new(
+            Arc::new(MockReminderPort::new()),
+            NotificationConfig::default(),
+        );
+
+        assert!(
+            service
+                .process_undelivered_reminders()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn undelivered_reminder_is_prepared_again() {
+        let mut reminder = make_due_reminder("Call mom");
+        reminder.mark_sent();
+        let pending = undelivered(&reminder, 1);
+
+        let mut mock_delivery = MockDeliveryStatusPort::new();
+        mock_delivery
+            .expect_undelivered_reminders()
+            .returning(move |_| Ok(vec![pending.clone()]));
+        let mut mock_port = MockReminderPort::new();
+        mock_port
+            .expect_get()
+            .returning(move |_| Ok(Some(reminder.clone())));
+
+        let service = NotificationService::new(Arc::new(mock_port), NotificationConfig::default())
+            .with_delivery_tracking(Arc::new(mock_delivery));
+
+        let result = service.process_undelivered_reminders().await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].message.contains("Call mom"));
+    }
+
+    #[tokio::test]
+    async fn undelivered_reminder_respects_attempt_limit_and_status() {
+        let mut exhausted = make_due_reminder("Exhausted");
+        exhausted.mark_sent();
+        let mut acknowledged = make_due_reminder("Acknowledged");
+        acknowledged.mark_sent();
+        acknowledged.acknowledge();
+        let pending = vec![undelivered(&exhausted, 3), undelivered(&acknowledged, 1)];
+
+        let mut mock_delivery = MockDeliveryStatusPort::new();
+        mock_delivery
+            .expect_undelivered_reminders()
+            .returning(move |_| Ok(pending.clone()));
+        let mut mock_port = MockReminderPort::new();
+        mock_port
+            .expect_get()
+            .times(1)
+            .returning(move |_| Ok(Some(acknowledged.clone())));
+
+        let service = NotificationService::new(Arc::new(mock_port), NotificationConfig::default())
+            .with_delivery_tracking(Arc::new(mock_delivery));
+
+        assert!(
+            service
+                .process_undelivered_reminders()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn record_sent_links_message_to_reminder() {
+        let reminder = make_due_reminder("Buy groceries");
+        let reminder_id = reminder.id;
+
+        let mut mock_delivery = MockDeliveryStatusPort::new();
+        mock_delivery
+            .expect_record_sent()
+            .withf(move |message_id, source, recipient, id| {
+                message_id == "wamid.1"
+                    && *source == MessengerSource::WhatsApp
+                    && recipient == "+491701234567"
+                    && *id == Some(reminder_id)
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = NotificationService::new(
+            Arc::new(MockReminderPort::new()),
+            NotificationConfig::default(),
+        )
+        .with_delivery_tracking(Arc::new(mock_delivery));
+        let notification = ReminderNotification {
+            reminder,
+            message: "Buy groceries".to_string(),
+        };
+
+        service
+            .record_sent(
+                &notification,
+                MessengerSource::WhatsApp,
+                "+491701234567",
+                "wamid.1",
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn message_for_carries_reminder_context_and_validity() {
+        let service = NotificationService::new(
+            Arc::new(MockReminderPort::new()),
+            NotificationConfig::default(),
+        );
+        let reminder = make_due_reminder("Buy groceries");
+        let expected_until = reminder.remind_at + chrono::Duration::minutes(60);
+        let notification = ReminderNotification {
+            reminder: reminder.clone(),
+            message: "Buy groceries".to_string(),
+        };
+
+        let message =
+            service.message_for(&notification, PhoneNumber::new("+491701234567").unwrap());
+
+        assert_eq!(message.text, "Buy groceries");
+        assert_eq!(message.context, Some(format!("reminder:{}", reminder.id)));
+        assert_eq!(message.valid_until, Some(expected_until));
+    }
+
+    #[test]
+    fn message_for_event_expires_at_event_start() {
+        let service = NotificationService::new(
+            Arc::new(MockReminderPort::new()),
+            NotificationConfig {
+                message_validity_minutes: 24 * 60,
+                ..Default::default()
+            },
+        );
+        let reminder = make_event_reminder("Meeting", "Office");
+        let event_time = reminder.event_time;
+        let notification = ReminderNotification {
+            reminder,
+            message: "Meeting".to_string(),
+        };
+
+        let message =
+            service.message_for(&notification, PhoneNumber::new("+491701234567").unwrap());
+
+        assert_eq!(message.valid_until, event_time);
+    }
 }
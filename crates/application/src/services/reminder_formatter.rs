@@ -100,6 +100,11 @@ pub fn format_custom_reminder(reminder: &Reminder) -> String {
         }
     }
 
+    // Repeat rule
+    if let Some(ref recurrence) = reminder.recurrence {
+        parts.push(format!("🔁 Wiederholung: {recurrence}"));
+    }
+
     // Location
     if let Some(ref location) = reminder.location {
         parts.push(String::new());
@@ -204,14 +209,22 @@ pub fn format_snooze_confirmation(reminder: &Reminder, new_time: DateTime<Utc>)
     format!(
         "💤 *Verschoben:* {}\n⏰ Neue Erinnerung: {}",
         reminder.title,
-        format_event_time(new_time)
+        format_reminder_time(reminder, new_time, EVENT_TIME_FORMAT)
     )
 }
 
 /// Format an acknowledgement confirmation
 #[must_use]
 pub fn format_acknowledge_confirmation(reminder: &Reminder) -> String {
-    format!("✅ *Erledigt:* {}", reminder.title)
+    if reminder.is_recurring() {
+        format!(
+            "✅ *Erledigt:* {}\n🔁 Nächste Erinnerung: {}",
+            reminder.title,
+            format_reminder_time(reminder, reminder.remind_at, "%d.%m.%Y %H:%M")
+        )
+    } else {
+        format!("✅ *Erledigt:* {}", reminder.title)
+    }
 }
 
 /// Format a list of active reminders
@@ -230,9 +243,11 @@ pub fn format_reminder_list(reminders: &[Reminder]) -> String {
             ReminderSource::CalendarTask => "📋",
             ReminderSource::Custom => "⏰",
         };
-        let time_str = reminder
-            .event_time
-            .map_or_else(|| format_event_time(reminder.remind_at), format_event_time);
+        let time_str = format_reminder_time(
+            reminder,
+            reminder.event_time.unwrap_or(reminder.remind_at),
+            EVENT_TIME_FORMAT,
+        );
         parts.push(format!("  {source_emoji} {time_str} — {}", reminder.title));
     }
 
@@ -241,10 +256,24 @@ pub fn format_reminder_list(reminders: &[Reminder]) -> String {
 
 // ── Time formatting helpers ─────────────────────────────────────
 
+/// Display format for event and reminder times (German locale style)
+const EVENT_TIME_FORMAT: &str = "%d.%m. %H:%M Uhr";
+
 /// Format a datetime for display in messages (German locale style)
 #[must_use]
 pub fn format_event_time(dt: DateTime<Utc>) -> String {
-    dt.format("%d.%m. %H:%M Uhr").to_string()
+    dt.format(EVENT_TIME_FORMAT).to_string()
+}
+
+/// Format a time in the reminder's timezone (UTC when unset)
+fn format_reminder_time(reminder: &Reminder, dt: DateTime<Utc>, format: &str) -> String {
+    match reminder.timezone {
+        Some(ref timezone) => dt
+            .with_timezone(&timezone.as_chrono_tz())
+            .format(format)
+            .to_string(),
+        None => dt.format(format).to_string(),
+    }
 }
 
 /// Format a human-readable "time until" string
@@ -460,6 +489,24 @@ mod tests {
         assert!(output.contains("✅ *Erledigt:* Done task"));
     }
 
+    #[test]
+    fn format_acknowledge_confirmation_recurring_shows_next() {
+        let mut r = make_reminder(ReminderSource::Custom, "Water plants")
+            .with_recurrence(domain::Recurrence::Daily);
+        r.acknowledge();
+        let output = format_acknowledge_confirmation(&r);
+        assert!(output.contains("✅ *Erledigt:* Water plants"));
+        assert!(output.contains("🔁 Nächste Erinnerung:"));
+    }
+
+    #[test]
+    fn format_custom_with_recurrence() {
+        let reminder = make_reminder(ReminderSource::Custom, "Trash")
+            .with_recurrence(domain::Recurrence::Weekly(vec![chrono::Weekday::Mon]));
+        let output = format_custom_reminder(&reminder);
+        assert!(output.contains("🔁 Wiederholung: weekly:Mon"));
+    }
+
     #[test]
     fn format_reminder_list_empty() {
         let output = format_reminder_list(&[]);
@@ -480,6 +527,16 @@ mod tests {
         assert!(output.contains("Groceries"));
     }
 
+    #[test]
+    fn format_reminder_list_uses_reminder_timezone() {
+        use chrono::TimeZone;
+        let remind_at = Utc.with_ymd_and_hms(2026, 7, 1, 7, 0, 0).unwrap();
+        let reminder = Reminder::new(UserId::new(), ReminderSource::Custom, "Pills", remind_at)
+            .with_timezone(domain::Timezone::berlin());
+        let output = format_reminder_list(&[reminder]);
+        assert!(output.contains("01.07. 09:00 Uhr"));
+    }
+
     #[test]
     fn format_event_time_format() {
         use chrono::TimeZone;
@@ -6,6 +6,9 @@
 //! 3. Process text through AI
 //! 4. Synthesize response audio (TTS)
 //! 5. Return audio response
+//!
+//! Transcriptions below the configured confidence threshold skip step 3
+//! and answer with a request to repeat the message.
 
 use std::{fmt, sync::Arc, time::Instant};
 
@@ -15,10 +18,13 @@ use tracing::{debug, info, instrument, warn};
 
 use crate::{
     error::ApplicationError,
-    ports::{SpeechPort, SynthesisResult, TranscriptionResult, VoiceConfig},
+    ports::{SpeechPort, SynthesisResult, TranscriptionResult, VoiceConfig, VoiceInfo},
     services::ChatService,
 };
 
+/// Reply sent instead of an AI response when the transcription is unreliable
+pub const REPEAT_REQUEST: &str = "I didn't catch that clearly, could you repeat?";
+
 /// Configuration for voice message processing
 #[derive(Debug, Clone)]
 pub struct VoiceMessageConfig {
@@ -28,10 +34,15 @@ pub struct VoiceMessageConfig {
     pub default_voice: String,
     /// Speech speed (0.25 - 4.0)
     pub speech_speed: f32,
+    /// Speech pitch (0.5 - 2.0)
+    pub speech_pitch: f32,
     /// Output audio format for TTS
     pub output_format: AudioFormat,
     /// Language hint for transcription (e.g., "en", "de")
     pub language_hint: Option<String>,
+    /// Minimum transcription confidence (0.0 - 1.0) to act on a message;
+    /// transcriptions without a confidence score always pass
+    pub min_confidence: Option<f32>,
 }
 
 impl Default for VoiceMessageConfig {
@@ -40,8 +51,10 @@ impl Default for VoiceMessageConfig {
             mirror_response_format: true,
             default_voice: "nova".to_string(),
             speech_speed: 1.0,
+            speech_pitch: 1.0,
             output_format: AudioFormat::Opus,
             language_hint: None,
+            min_confidence: None,
         }
     }
 }
@@ -53,6 +66,10 @@ pub struct VoiceMessageResult {
     pub voice_message: VoiceMessage,
     /// Transcribed text from the user's audio
     pub transcription: String,
+    /// Confidence of the transcription (0.0 - 1.0), if the provider reports one
+    pub confidence: Option<f32>,
+    /// Whether the user was asked to repeat because confidence was too low
+    pub low_confidence: bool,
     /// AI-generated text response
     pub response_text: String,
     /// Synthesized audio response (if mirror_response_format is true)
@@ -153,22 +170,34 @@ impl VoiceMessageService {
             "Transcription complete"
         );
 
-        // Step 2: Process through AI
-        info!("Processing transcription through AI");
-        voice_message.start_processing();
+        // Step 2: Process through AI, unless the transcription is too
+        // unreliable to act on
+        let low_confidence = self.is_low_confidence(transcription.confidence);
+        let ai_response = if low_confidence {
+            info!(
+                confidence = ?transcription.confidence,
+                threshold = ?self.config.min_confidence,
+                "Transcription confidence too low, asking user to repeat"
+            );
+            REPEAT_REQUEST.to_string()
+        } else {
+            info!("Processing transcription through AI");
+            voice_message.start_processing();
 
-        let ai_response = match self.chat_service.chat(&transcription.text).await {
-            Ok(response) => response.content,
-            Err(e) => {
-                warn!(error = %e, "AI processing failed");
-                voice_message.mark_failed(format!("AI processing failed: {e}"));
-                return Err(e);
-            },
-        };
+            let ai_response = match self.chat_service.chat(&transcription.text).await {
+                Ok(response) => response.content,
+                Err(e) => {
+                    warn!(error = %e, "AI processing failed");
+                    voice_message.mark_failed(format!("AI processing failed: {e}"));
+                    return Err(e);
+                },
+            };
 
-        voice_message.complete_processing();
+            voice_message.complete_processing();
 
-        debug!(response_len = ai_response.len(), "AI response generated");
+            debug!(response_len = ai_response.len(), "AI response generated");
+            ai_response
+        };
 
         // Step 3: Synthesize response audio (if configured)
         let response_audio = if self.config.mirror_response_format {
@@ -205,12 +234,22 @@ impl VoiceMessageService {
         Ok(VoiceMessageResult {
             voice_message,
             transcription: transcription.text,
+            confidence: transcription.confidence,
+            low_confidence,
             response_text: ai_response,
             response_audio,
             processing_time_ms,
         })
     }
 
+    /// Check whether a transcription confidence falls below the threshold
+    fn is_low_confidence(&self, confidence: Option<f32>) -> bool {
+        match (confidence, self.config.min_confidence) {
+            (Some(confidence), Some(threshold)) => confidence < threshold,
+            _ => false,
+        }
+    }
+
     /// Transcribe audio to text
     #[instrument(skip(self, audio_data), fields(format = %format))]
     pub async fn transcribe(
@@ -232,7 +271,8 @@ impl VoiceMessageService {
     pub async fn synthesize(&self, text: &str) -> Result<SynthesisResult, ApplicationError> {
         let voice_config = VoiceConfig {
             voice_id: self.config.default_voice.clone(),
-            speed: self.config.speech_speed,
+            rate: self.config.speech_speed,
+            pitch: self.config.speech_pitch,
         };
 
         self.speech_port
@@ -240,6 +280,11 @@ impl VoiceMessageService {
             .await
     }
 
+    /// List the voices available for synthesis
+    pub async fn list_voices(&self) -> Result<Vec<VoiceInfo>, ApplicationError> {
+        self.speech_port.list_voices().await
+    }
+
     /// Check if the speech service is available
     pub async fn is_available(&self) -> bool {
         self.speech_port.is_available().await
@@ -342,8 +387,10 @@ mod tests {
         assert!(config.mirror_response_format);
         assert_eq!(config.default_voice, "nova");
         assert!((config.speech_speed - 1.0).abs() < f32::EPSILON);
+        assert!((config.speech_pitch - 1.0).abs() < f32::EPSILON);
         assert_eq!(config.output_format, AudioFormat::Opus);
         assert!(config.language_hint.is_none());
+        assert!(config.min_confidence.is_none());
     }
 
     #[test]
@@ -395,6 +442,26 @@ mod tests {
         assert!(service.is_available().await);
     }
 
+    #[tokio::test]
+    async fn service_lists_voices() {
+        let mut mock_speech = MockSpeechPort::new();
+        mock_speech.expect_list_voices().returning(|| {
+            Ok(vec![VoiceInfo {
+                id: "de_DE-thorsten-medium".to_string(),
+                name: "thorsten (de_DE)".to_string(),
+                description: None,
+                languages: vec!["de-DE".to_string()],
+                gender: Some("male".to_string()),
+            }])
+        });
+
+        let service = VoiceMessageService::new(Arc::new(mock_speech), create_mock_chat_service());
+
+        let voices = service.list_voices().await.unwrap();
+        assert_eq!(voices.len(), 1);
+        assert_eq!(voices[0].id, "de_DE-thorsten-medium");
+    }
+
     #[tokio::test]
     async fn transcribe_delegates_to_port() {
         let mut mock_speech = MockSpeechPort::new();
@@ -524,6 +591,87 @@ mod tests {
         assert!(result.response_audio.is_none());
     }
 
+    fn confidence_service(confidence: Option<f32>) -> VoiceMessageService {
+        let mut mock_speech = MockSpeechPort::new();
+
+        mock_speech.expect_transcribe().returning(move |_, _, _| {
+            Ok(TranscriptionResult {
+                text: "Turn on the ... lights?".to_string(),
+                detected_language: Some("en".to_string()),
+                confidence,
+                duration_ms: Some(1500),
+            })
+        });
+
+        let config = VoiceMessageConfig {
+            mirror_response_format: false,
+            min_confidence: Some(0.5),
+            ..Default::default()
+        };
+
+        VoiceMessageService::with_config(Arc::new(mock_speech), create_mock_chat_service(), config)
+    }
+
+    #[tokio::test]
+    async fn process_voice_message_low_confidence_asks_to_repeat() {
+        let service = confidence_service(Some(0.2));
+
+        let result = service
+            .process_voice_message(
+                vec![0, 1, 2, 3],
+                AudioFormat::Opus,
+                ConversationId::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(result.low_confidence);
+        assert_eq!(result.confidence, Some(0.2));
+        assert_eq!(result.response_text, REPEAT_REQUEST);
+        assert_eq!(
+            result.voice_message.status,
+            VoiceMessageStatus::ResponseReady
+        );
+    }
+
+    #[tokio::test]
+    async fn process_voice_message_high_confidence_proceeds() {
+        let service = confidence_service(Some(0.9));
+
+        let result = service
+            .process_voice_message(
+                vec![0, 1, 2, 3],
+                AudioFormat::Opus,
+                ConversationId::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.low_confidence);
+        assert_eq!(result.confidence, Some(0.9));
+        assert!(result.response_text.contains("voice message"));
+    }
+
+    #[tokio::test]
+    async fn process_voice_message_without_confidence_proceeds() {
+        let service = confidence_service(None);
+
+        let result = service
+            .process_voice_message(
+                vec![0, 1, 2, 3],
+                AudioFormat::Opus,
+                ConversationId::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.low_confidence);
+        assert!(result.response_text.contains("voice message"));
+    }
+
     #[tokio::test]
     async fn process_voice_message_transcription_failure() {
         let mut mock_speech = MockSpeechPort::new();
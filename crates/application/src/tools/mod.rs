@@ -0,0 +1,259 @@
+//! Tools the agent can call on behalf of the model
+//!
+//! A [`Tool`] describes itself with a name, a description and a JSON schema
+//! for its arguments. The [`ToolRegistry`] renders these descriptions into
+//! the intent prompt, so the model can answer with a tool call instead of a
+//! hardcoded intent, and dispatches the calls it returns.
+//!
+//! Built-in tools:
+//! - [`WeatherTool`]: Current conditions and forecast via [`WeatherPort`](crate::ports::WeatherPort)
+//! - [`WebSearchTool`]: Internet search via [`WebSearchPort`](crate::ports::WebSearchPort)
+
+mod weather;
+mod web_search;
+
+use std::{collections::BTreeMap, fmt, sync::Arc};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+use crate::error::ApplicationError;
+
+pub use weather::WeatherTool;
+pub use web_search::WebSearchTool;
+
+/// A capability the model can invoke with JSON arguments
+#[async_trait]
+pub trait Tool: Send + Sync {
+    /// Unique name the model uses to call the tool (snake_case)
+    fn name(&self) -> &str;
+
+    /// One-line description shown to the model
+    fn description(&self) -> &str;
+
+    /// JSON schema of the arguments object
+    fn json_schema(&self) -> Value;
+
+    /// Run the tool
+    ///
+    /// # Arguments
+    /// * `args` - Arguments object, already checked against the schema's
+    ///   `required` list
+    ///
+    /// # Returns
+    /// Structured result that is handed back to the model
+    async fn invoke(&self, args: Value) -> Result<Value, ApplicationError>;
+}
+
+/// Registered tools, keyed by name
+#[derive(Clone, Default)]
+pub struct ToolRegistry {
+    tools: BTreeMap<String, Arc<dyn Tool>>,
+}
+
+impl fmt::Debug for ToolRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToolRegistry")
+            .field("tools", &self.tools.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ToolRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a tool, replacing any tool with the same name
+    pub fn register(&mut self, tool: Arc<dyn Tool>) {
+        self.tools.insert(tool.name().to_string(), tool);
+    }
+
+    /// Look up a tool by name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn Tool>> {
+        self.tools.get(name)
+    }
+
+    /// Names of all registered tools, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tools.keys().map(String::as_str)
+    }
+
+    /// Whether no tools are registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    /// Number of registered tools
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tools.len()
+    }
+
+    /// Prompt section that presents the tools and the call format to the model
+    ///
+    /// Returns `None` if no tools are registered.
+    #[must_use]
+    pub fn prompt_section(&self) -> Option<String> {
+        if self.tools.is_empty() {
+            return None;
+        }
+
+        let tools = self
+            .tools
+            .values()
+            .map(|tool| {
+                format!(
+                    "- \"{}\": {} Arguments (JSON schema): {}",
+                    tool.name(),
+                    tool.description(),
+                    tool.json_schema()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(format!(
+            "Tools (prefer a tool over the intents above when one fits):\n{tools}\n\n\
+             To call a tool, reply ONLY with: {{\"tool\": \"<tool_name>\", \"arguments\": {{...}}}}"
+        ))
+    }
+
+    /// Check the arguments against the tool schema and invoke the tool
+    ///
+    /// # Errors
+    /// - [`ApplicationError::NotFound`] if no tool with that name is registered
+    /// - [`ApplicationError::InvalidOperation`] if the arguments are not an
+    ///   object or a required argument is missing
+    /// - Any error returned by the tool itself
+    #[instrument(skip(self, args))]
+    pub async fn invoke(&self, name: &str, args: Value) -> Result<Value, ApplicationError> {
+        let tool = self
+            .get(name)
+            .ok_or_else(|| ApplicationError::NotFound(format!("Unknown tool: {name}")))?;
+
+        let args = match args {
+            Value::Null => Value::Object(serde_json::Map::new()),
+            other => other,
+        };
+        validate_arguments(&tool.json_schema(), &args)
+            .map_err(|e| ApplicationError::InvalidOperation(format!("{name}: {e}")))?;
+
+        debug!(tool = %name, "Invoking tool");
+        tool.invoke(args).await
+    }
+}
+
+/// Minimal schema check: the arguments must be an object with all
+/// `required` properties present
+fn validate_arguments(schema: &Value, args: &Value) -> Result<(), String> {
+    let Some(object) = args.as_object() else {
+        return Err("arguments must be a JSON object".to_string());
+    };
+
+    let missing: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter(|key| object.get(*key).is_none_or(Value::is_null))
+        .collect();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "missing required argument(s): {}",
+            missing.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    struct EchoTool;
+
+    #[async_trait]
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn description(&self) -> &str {
+            "Return the given text."
+        }
+
+        fn json_schema(&self) -> Value {
+            json!({
+                "type": "object",
+                "properties": {"text": {"type": "string"}},
+                "required": ["text"]
+            })
+        }
+
+        async fn invoke(&self, args: Value) -> Result<Value, ApplicationError> {
+            Ok(json!({"echo": args["text"]}))
+        }
+    }
+
+    fn registry() -> ToolRegistry {
+        let mut registry = ToolRegistry::new();
+        registry.register(Arc::new(EchoTool));
+        registry
+    }
+
+    #[test]
+    fn empty_registry_has_no_prompt_section() {
+        let registry = ToolRegistry::new();
+        assert!(registry.is_empty());
+        assert!(registry.prompt_section().is_none());
+    }
+
+    #[test]
+    fn prompt_section_lists_tools_with_schema() {
+        let section = registry().prompt_section().unwrap();
+        assert!(section.contains("\"echo\": Return the given text."));
+        assert!(section.contains("\"required\":[\"text\"]"));
+        assert!(section.contains("\"tool\""));
+    }
+
+    #[tokio::test]
+    async fn invoke_dispatches_by_name() {
+        let result = registry()
+            .invoke("echo", json!({"text": "hi"}))
+            .await
+            .unwrap();
+        assert_eq!(result, json!({"echo": "hi"}));
+    }
+
+    #[tokio::test]
+    async fn invoke_unknown_tool_is_not_found() {
+        let result = registry().invoke("nope", json!({})).await;
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn invoke_rejects_missing_required_argument() {
+        let result = registry().invoke("echo", Value::Null).await;
+        let Err(ApplicationError::InvalidOperation(message)) = result else {
+            unreachable!("Expected InvalidOperation");
+        };
+        assert!(message.contains("text"));
+    }
+
+    #[tokio::test]
+    async fn invoke_rejects_non_object_arguments() {
+        let result = registry().invoke("echo", json!(["hi"])).await;
+        assert!(matches!(result, Err(ApplicationError::InvalidOperation(_))));
+    }
+}
@@ -0,0 +1,171 @@
+//! Weather tool
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::value_objects::GeoLocation;
+use serde_json::{Value, json};
+
+use super::Tool;
+use crate::{error::ApplicationError, ports::WeatherPort};
+
+/// Default number of forecast days
+const DEFAULT_FORECAST_DAYS: u8 = 1;
+
+/// Maximum number of forecast days
+const MAX_FORECAST_DAYS: u8 = 7;
+
+/// Current weather and forecast for a location
+pub struct WeatherTool {
+    port: Arc<dyn WeatherPort>,
+    default_location: Option<GeoLocation>,
+}
+
+impl std::fmt::Debug for WeatherTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeatherTool")
+            .field("default_location", &self.default_location)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WeatherTool {
+    /// Create a new weather tool
+    #[must_use]
+    pub fn new(port: Arc<dyn WeatherPort>) -> Self {
+        Self {
+            port,
+            default_location: None,
+        }
+    }
+
+    /// Location used when the model passes no coordinates
+    #[must_use]
+    pub const fn with_default_location(mut self, location: GeoLocation) -> Self {
+        self.default_location = Some(location);
+        self
+    }
+
+    fn location(&self, args: &Value) -> Result<GeoLocation, ApplicationError> {
+        match (
+            args.get("latitude").and_then(Value::as_f64),
+            args.get("longitude").and_then(Value::as_f64),
+        ) {
+            (Some(latitude), Some(longitude)) => GeoLocation::new(latitude, longitude)
+                .map_err(|e| ApplicationError::InvalidOperation(e.to_string())),
+            _ => self.default_location.ok_or_else(|| {
+                ApplicationError::InvalidOperation(
+                    "No location given and no default location configured".to_string(),
+                )
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for WeatherTool {
+    fn name(&self) -> &str {
+        "get_weather"
+    }
+
+    fn description(&self) -> &str {
+        "Current weather and daily forecast. Omit the coordinates to use the user's home location."
+    }
+
+    fn json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "latitude": {"type": "number", "minimum": -90, "maximum": 90},
+                "longitude": {"type": "number", "minimum": -180, "maximum": 180},
+                "days": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": MAX_FORECAST_DAYS,
+                    "default": DEFAULT_FORECAST_DAYS,
+                    "description": "Number of forecast days, 0 for current conditions only"
+                }
+            }
+        })
+    }
+
+    async fn invoke(&self, args: Value) -> Result<Value, ApplicationError> {
+        let location = self.location(&args)?;
+        let days = args
+            .get("days")
+            .and_then(Value::as_u64)
+            .map_or(DEFAULT_FORECAST_DAYS, |d| {
+                u8::try_from(d.min(u64::from(MAX_FORECAST_DAYS))).unwrap_or(MAX_FORECAST_DAYS)
+            });
+
+        let current = self.port.get_current_weather(&location).await?;
+        let forecast = if days == 0 {
+            Vec::new()
+        } else {
+            self.port.get_forecast(&location, days).await?
+        };
+
+        Ok(json!({
+            "location": location,
+            "current": current,
+            "forecast": forecast,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::ports::{CurrentWeather, MockWeatherPort, WeatherCondition};
+
+    fn current() -> CurrentWeather {
+        CurrentWeather {
+            temperature: 21.5,
+            apparent_temperature: 20.0,
+            humidity: 40,
+            wind_speed: 10.0,
+            condition: WeatherCondition::ClearSky,
+            observed_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn uses_default_location_without_coordinates() {
+        let mut port = MockWeatherPort::new();
+        port.expect_get_current_weather()
+            .withf(|loc| (loc.latitude() - 52.52).abs() < f64::EPSILON)
+            .returning(|_| Ok(current()));
+        port.expect_get_forecast().times(0);
+
+        let tool = WeatherTool::new(Arc::new(port)).with_default_location(GeoLocation::berlin());
+        let result = tool.invoke(json!({"days": 0})).await.unwrap();
+
+        assert_eq!(result["current"]["temperature"], json!(21.5));
+        assert_eq!(result["forecast"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn fails_without_any_location() {
+        let tool = WeatherTool::new(Arc::new(MockWeatherPort::new()));
+        let result = tool.invoke(json!({})).await;
+        assert!(matches!(result, Err(ApplicationError::InvalidOperation(_))));
+    }
+
+    #[tokio::test]
+    async fn clamps_forecast_days() {
+        let mut port = MockWeatherPort::new();
+        port.expect_get_current_weather()
+            .returning(|_| Ok(current()));
+        port.expect_get_forecast()
+            .withf(|_, days| *days == MAX_FORECAST_DAYS)
+            .returning(|_, _| Ok(vec![]));
+
+        let tool = WeatherTool::new(Arc::new(port));
+        let result = tool
+            .invoke(json!({"latitude": 48.1, "longitude": 11.6, "days": 30}))
+            .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,144 @@
+//! Web search tool
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+use super::Tool;
+use crate::{
+    error::ApplicationError,
+    ports::{SearchOptions, WebSearchPort},
+};
+
+/// Default number of search results
+const DEFAULT_MAX_RESULTS: u32 = 5;
+
+/// Upper bound for requested search results
+const MAX_RESULTS_LIMIT: u32 = 10;
+
+/// Internet search with cited results
+pub struct WebSearchTool {
+    port: Arc<dyn WebSearchPort>,
+}
+
+impl std::fmt::Debug for WebSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSearchTool")
+            .field("provider", &self.port.provider_name())
+            .finish()
+    }
+}
+
+impl WebSearchTool {
+    /// Create a new web search tool
+    #[must_use]
+    pub fn new(port: Arc<dyn WebSearchPort>) -> Self {
+        Self { port }
+    }
+}
+
+#[async_trait]
+impl Tool for WebSearchTool {
+    fn name(&self) -> &str {
+        "web_search"
+    }
+
+    fn description(&self) -> &str {
+        "Search the internet for current information, news or facts."
+    }
+
+    fn json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string", "description": "Search query"},
+                "max_results": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": MAX_RESULTS_LIMIT,
+                    "default": DEFAULT_MAX_RESULTS
+                }
+            },
+            "required": ["query"]
+        })
+    }
+
+    async fn invoke(&self, args: Value) -> Result<Value, ApplicationError> {
+        let query = args
+            .get("query")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .ok_or_else(|| {
+                ApplicationError::InvalidOperation("query must be a non-empty string".to_string())
+            })?;
+        let max_results =
+            args.get("max_results")
+                .and_then(Value::as_u64)
+                .map_or(DEFAULT_MAX_RESULTS, |n| {
+                    u32::try_from(n)
+                        .unwrap_or(MAX_RESULTS_LIMIT)
+                        .clamp(1, MAX_RESULTS_LIMIT)
+                });
+
+        let options = SearchOptions::new().with_max_results(max_results);
+        let response = self.port.search(query, Some(options)).await?;
+
+        Ok(json!({
+            "query": response.query,
+            "provider": response.provider,
+            "results": response.results,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::entities::{SearchResult, WebSearchResponse};
+
+    use super::*;
+    use crate::ports::MockWebSearchPort;
+
+    #[tokio::test]
+    async fn returns_results_with_sources() {
+        let mut port = MockWebSearchPort::new();
+        port.expect_search()
+            .withf(|query, options| {
+                query == "rust 2024"
+                    && options.as_ref().and_then(|o| o.max_results) == Some(MAX_RESULTS_LIMIT)
+            })
+            .returning(|query, _| {
+                Ok(WebSearchResponse::new(
+                    query.to_string(),
+                    vec![SearchResult::new(
+                        "Rust 2024".to_string(),
+                        "https://blog.rust-lang.org".to_string(),
+                        "The 2024 edition".to_string(),
+                        "blog.rust-lang.org".to_string(),
+                        1,
+                    )],
+                    "brave".to_string(),
+                ))
+            });
+
+        let tool = WebSearchTool::new(Arc::new(port));
+        let result = tool
+            .invoke(json!({"query": " rust 2024 ", "max_results": 50}))
+            .await
+            .unwrap();
+
+        assert_eq!(result["provider"], json!("brave"));
+        assert_eq!(
+            result["results"][0]["url"],
+            json!("https://blog.rust-lang.org")
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_blank_query() {
+        let tool = WebSearchTool::new(Arc::new(MockWebSearchPort::new()));
+        let result = tool.invoke(json!({"query": "  "})).await;
+        assert!(matches!(result, Err(ApplicationError::InvalidOperation(_))));
+    }
+}
@@ -1,5 +1,7 @@
 //! Agent commands - Strongly typed representations of user intents
 
+use std::time::Duration;
+
 use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
 
@@ -119,6 +121,14 @@ pub enum AgentCommand {
         only_important: Option<bool>,
     },
 
+    /// Summarize a chat conversation (TL;DR)
+    SummarizeConversation {
+        /// Conversation to summarize (defaults to the most recent one)
+        conversation_id: Option<String>,
+        /// Upper bound for the summary length (defaults to 5)
+        max_sentences: Option<u32>,
+    },
+
     /// Draft an email
     DraftEmail {
         /// Recipient email address
@@ -157,6 +167,9 @@ pub enum AgentCommand {
         remind_at: String,
         /// Optional description with more details
         description: Option<String>,
+        /// Repeat rule (`daily`, `weekly`, `weekly:Mon` or an RRULE)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        recurrence: Option<String>,
     },
 
     /// List active reminders
@@ -185,6 +198,14 @@ pub enum AgentCommand {
         reminder_id: String,
     },
 
+    /// Start a countdown timer that notifies the user when it expires
+    SetTimer {
+        /// Optional label (e.g. "Pasta")
+        label: Option<String>,
+        /// Time until the timer fires
+        duration: Duration,
+    },
+
     /// Search for public transit connections
     SearchTransit {
         /// Origin address or stop name
@@ -251,6 +272,26 @@ pub enum AgentCommand {
         query: String,
     },
 
+    /// Invoke a registered tool with model-provided arguments
+    ///
+    /// Only read-only tools are offered to the model, so tool calls never
+    /// need approval.
+    CallTool {
+        /// Registered tool name (e.g. "get_weather")
+        name: String,
+        /// Arguments as a JSON object matching the tool's schema
+        arguments: serde_json::Value,
+    },
+
+    /// Several commands to run in order ("draft an email and send it")
+    ///
+    /// Needs approval if any step does, so the user consents to the whole
+    /// chain at once.
+    Chain {
+        /// Steps in execution order
+        steps: Vec<Self>,
+    },
+
     /// System-level commands
     System(SystemCommand),
 
@@ -291,7 +332,10 @@ pub enum SystemCommand {
 
 impl AgentCommand {
     /// Check if this command requires user approval before execution
-    pub const fn requires_approval(&self) -> bool {
+    pub fn requires_approval(&self) -> bool {
+        if let Self::Chain { steps } = self {
+            return steps.iter().any(Self::requires_approval);
+        }
         matches!(
             self,
             Self::SendEmail { .. }
@@ -384,6 +428,12 @@ impl AgentCommand {
             Self::SummarizeInbox { count, .. } => {
                 format!("Summarize inbox (last {} emails)", count.unwrap_or(10))
             },
+            Self::SummarizeConversation {
+                conversation_id, ..
+            } => conversation_id.as_ref().map_or_else(
+                || "Summarize the current conversation".to_string(),
+                |id| format!("Summarize conversation {id}"),
+            ),
             Self::DraftEmail { to, subject, .. } => {
                 let subj = subject.as_deref().unwrap_or("(no subject)");
                 format!("Draft email to {to} - {subj}")
@@ -401,10 +451,14 @@ impl AgentCommand {
                 format!("Web search: {preview}... (max {results} results)")
             },
             Self::CreateReminder {
-                title, remind_at, ..
-            } => {
-                format!("Create reminder '{title}' at {remind_at}")
-            },
+                title,
+                remind_at,
+                recurrence,
+                ..
+            } => recurrence.as_ref().map_or_else(
+                || format!("Create reminder '{title}' at {remind_at}"),
+                |rule| format!("Create reminder '{title}' at {remind_at} ({rule})"),
+            ),
             Self::ListReminders { include_done } => {
                 if include_done == &Some(true) {
                     "List all reminders (including done)".to_string()
@@ -425,6 +479,13 @@ impl AgentCommand {
             Self::DeleteReminder { reminder_id } => {
                 format!("Delete reminder {reminder_id}")
             },
+            Self::SetTimer { label, duration } => {
+                let mins = duration.as_secs() / 60;
+                label.as_ref().map_or_else(
+                    || format!("Set timer for {mins}min"),
+                    |l| format!("Set timer '{l}' for {mins}min"),
+                )
+            },
             Self::SearchTransit { from, to, .. } => {
                 format!("Search transit: {from} → {to}")
             },
@@ -462,6 +523,12 @@ impl AgentCommand {
                     format!("Switch to model: {model_name}")
                 },
             },
+            Self::CallTool { name, .. } => format!("Tool call: {name}"),
+            Self::Chain { steps } => steps
+                .iter()
+                .map(Self::description)
+                .collect::<Vec<_>>()
+                .join(" → "),
             Self::Echo { message } => format!("Echo: {message}"),
             Self::Help { command } => command.as_ref().map_or_else(
                 || "General help".to_string(),
@@ -489,6 +556,57 @@ mod tests {
         assert!(cmd.requires_approval());
     }
 
+    #[test]
+    fn tool_call_does_not_require_approval() {
+        let cmd = AgentCommand::CallTool {
+            name: "get_weather".to_string(),
+            arguments: serde_json::json!({"days": 2}),
+        };
+        assert!(!cmd.requires_approval());
+        assert_eq!(cmd.description(), "Tool call: get_weather");
+    }
+
+    #[test]
+    fn chain_requires_approval_if_any_step_does() {
+        let draft = AgentCommand::DraftEmail {
+            to: EmailAddress::new("bob@example.com").unwrap(),
+            subject: Some("Lunch".to_string()),
+            body: "Lunch tomorrow?".to_string(),
+        };
+        let send = AgentCommand::SendEmail {
+            draft_id: "latest".to_string(),
+        };
+
+        let chain = AgentCommand::Chain {
+            steps: vec![draft.clone(), send],
+        };
+        assert!(chain.requires_approval());
+
+        let chain = AgentCommand::Chain {
+            steps: vec![draft, AgentCommand::ListTaskLists],
+        };
+        assert!(!chain.requires_approval());
+    }
+
+    #[test]
+    fn chain_description_lists_steps() {
+        let chain = AgentCommand::Chain {
+            steps: vec![
+                AgentCommand::Echo {
+                    message: "hi".to_string(),
+                },
+                AgentCommand::SendEmail {
+                    draft_id: "d-1".to_string(),
+                },
+            ],
+        };
+        assert_eq!(chain.description(), "Echo: hi → Send email draft d-1");
+
+        let json = serde_json::to_string(&chain).unwrap();
+        let parsed: AgentCommand = serde_json::from_str(&json).unwrap();
+        assert_eq!(chain, parsed);
+    }
+
     #[test]
     fn create_calendar_event_requires_approval() {
         let cmd = AgentCommand::CreateCalendarEvent {
@@ -581,6 +699,15 @@ mod tests {
         assert!(!cmd.requires_approval());
     }
 
+    #[test]
+    fn set_timer_does_not_require_approval() {
+        let cmd = AgentCommand::SetTimer {
+            label: None,
+            duration: Duration::from_secs(600),
+        };
+        assert!(!cmd.requires_approval());
+    }
+
     #[test]
     fn unknown_does_not_require_approval() {
         let cmd = AgentCommand::Unknown {
@@ -1292,6 +1419,70 @@ mod tests {
         assert_eq!(cmd.description(), "Search contacts: Acme Corp");
     }
 
+    #[test]
+    fn set_timer_description() {
+        let cmd = AgentCommand::SetTimer {
+            label: Some("Pasta".to_string()),
+            duration: Duration::from_secs(600),
+        };
+        assert_eq!(cmd.description(), "Set timer 'Pasta' for 10min");
+
+        let cmd = AgentCommand::SetTimer {
+            label: None,
+            duration: Duration::from_secs(300),
+        };
+        assert_eq!(cmd.description(), "Set timer for 5min");
+    }
+
+    #[test]
+    fn set_timer_serializes_and_deserializes() {
+        let cmd = AgentCommand::SetTimer {
+            label: Some("Tea".to_string()),
+            duration: Duration::from_secs(180),
+        };
+        let json = serde_json::to_string(&cmd).unwrap();
+        let parsed: AgentCommand = serde_json::from_str(&json).unwrap();
+        assert_eq!(cmd, parsed);
+    }
+
+    #[test]
+    fn create_reminder_description_mentions_recurrence() {
+        let cmd = AgentCommand::CreateReminder {
+            title: "Gym".to_string(),
+            remind_at: "2026-02-02T18:00:00".to_string(),
+            description: None,
+            recurrence: Some("weekly:Mon".to_string()),
+        };
+        assert_eq!(
+            cmd.description(),
+            "Create reminder 'Gym' at 2026-02-02T18:00:00 (weekly:Mon)"
+        );
+
+        let json = serde_json::to_string(&cmd).unwrap();
+        let parsed: AgentCommand = serde_json::from_str(&json).unwrap();
+        assert_eq!(cmd, parsed);
+    }
+
+    #[test]
+    fn summarize_conversation_description_and_serde() {
+        let cmd = AgentCommand::SummarizeConversation {
+            conversation_id: None,
+            max_sentences: Some(3),
+        };
+        assert_eq!(cmd.description(), "Summarize the current conversation");
+        assert!(!cmd.requires_approval());
+
+        let cmd = AgentCommand::SummarizeConversation {
+            conversation_id: Some("conv-1".to_string()),
+            max_sentences: None,
+        };
+        assert_eq!(cmd.description(), "Summarize conversation conv-1");
+
+        let json = serde_json::to_string(&cmd).unwrap();
+        let parsed: AgentCommand = serde_json::from_str(&json).unwrap();
+        assert_eq!(cmd, parsed);
+    }
+
     #[test]
     fn list_contacts_serializes_and_deserializes() {
         let cmd = AgentCommand::ListContacts {
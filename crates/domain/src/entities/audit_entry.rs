@@ -6,6 +6,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{PromptAnalysisResult, SecurityThreat};
+
 /// Type of audit event
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -50,6 +52,26 @@ impl std::fmt::Display for AuditEventType {
     }
 }
 
+impl std::str::FromStr for AuditEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "authentication" => Ok(Self::Authentication),
+            "authorization" => Ok(Self::Authorization),
+            "command_execution" => Ok(Self::CommandExecution),
+            "approval" => Ok(Self::Approval),
+            "config_change" => Ok(Self::ConfigChange),
+            "system" => Ok(Self::System),
+            "data_access" => Ok(Self::DataAccess),
+            "integration" => Ok(Self::Integration),
+            "security" => Ok(Self::Security),
+            "prompt_injection" => Ok(Self::PromptInjection),
+            other => Err(format!("Unknown audit event type: {other}")),
+        }
+    }
+}
+
 /// Audit log entry recording a system event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditEntry {
@@ -252,6 +274,42 @@ impl AuditBuilder {
             .with_details(format!("{threat_level}: {details}"))
     }
 
+    /// Log the threats found by prompt security analysis
+    ///
+    /// The entry is a failure when the request was blocked. Details hold a
+    /// JSON summary of every threat (category, level, matched pattern, position).
+    pub fn prompt_threats_detected(
+        ip: Option<IpAddr>,
+        analysis: &PromptAnalysisResult,
+    ) -> AuditEntry {
+        #[derive(Serialize)]
+        struct ThreatSummary<'a> {
+            blocked: bool,
+            risk_score: f32,
+            threats: &'a [SecurityThreat],
+        }
+
+        let entry = if analysis.should_block {
+            AuditEntry::failure(AuditEventType::PromptInjection, "threat_detected")
+        } else {
+            AuditEntry::success(AuditEventType::PromptInjection, "threat_detected")
+        };
+        let entry = match analysis.threat_categories().first() {
+            Some(category) => entry.with_resource("threat", category.to_string()),
+            None => entry,
+        };
+        let entry = entry.with_json_details(&ThreatSummary {
+            blocked: analysis.should_block,
+            risk_score: analysis.risk_score,
+            threats: &analysis.threats,
+        });
+
+        match ip {
+            Some(ip) => entry.with_ip_address(ip),
+            None => entry,
+        }
+    }
+
     /// Log blocked suspicious activity
     pub fn suspicious_activity_blocked(ip: IpAddr, reason: &str) -> AuditEntry {
         AuditEntry::failure(AuditEventType::Security, "suspicious_blocked")
@@ -273,6 +331,28 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn event_type_round_trips_through_str() {
+        for event_type in [
+            AuditEventType::Authentication,
+            AuditEventType::Authorization,
+            AuditEventType::CommandExecution,
+            AuditEventType::Approval,
+            AuditEventType::ConfigChange,
+            AuditEventType::System,
+            AuditEventType::DataAccess,
+            AuditEventType::Integration,
+            AuditEventType::Security,
+            AuditEventType::PromptInjection,
+        ] {
+            assert_eq!(
+                event_type.to_string().parse::<AuditEventType>().unwrap(),
+                event_type
+            );
+        }
+        assert!("login".parse::<AuditEventType>().is_err());
+    }
+
     #[test]
     fn create_success_entry() {
         let entry = AuditEntry::success(AuditEventType::CommandExecution, "test_action");
@@ -416,6 +496,58 @@ mod tests {
         assert!(entry.details.as_ref().unwrap().contains("high"));
     }
 
+    #[test]
+    fn audit_builder_prompt_threats_detected() {
+        use crate::entities::{ThreatCategory, ThreatLevel};
+
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 7));
+        let analysis = PromptAnalysisResult::with_threats(
+            vec![
+                SecurityThreat::new(
+                    ThreatCategory::PromptInjection,
+                    ThreatLevel::High,
+                    "ignore previous instructions",
+                    0.9,
+                )
+                .with_position(4),
+            ],
+            120,
+        );
+
+        let entry = AuditBuilder::prompt_threats_detected(Some(ip), &analysis);
+
+        assert!(!entry.success);
+        assert_eq!(entry.event_type, AuditEventType::PromptInjection);
+        assert_eq!(entry.action, "threat_detected");
+        assert_eq!(entry.ip_address, Some(ip));
+        assert_eq!(entry.resource_id, Some("prompt_injection".to_string()));
+        let details: serde_json::Value =
+            serde_json::from_str(entry.details.as_deref().unwrap()).unwrap();
+        assert_eq!(details["blocked"], true);
+        assert_eq!(details["threats"][0]["threat_level"], "high");
+        assert_eq!(details["threats"][0]["position"], 4);
+    }
+
+    #[test]
+    fn audit_builder_prompt_threats_not_blocked_is_success() {
+        use crate::entities::{ThreatCategory, ThreatLevel};
+
+        let analysis = PromptAnalysisResult::with_threats(
+            vec![SecurityThreat::new(
+                ThreatCategory::EncodingAttack,
+                ThreatLevel::Low,
+                "base64",
+                0.5,
+            )],
+            10,
+        );
+
+        let entry = AuditBuilder::prompt_threats_detected(None, &analysis);
+
+        assert!(entry.success);
+        assert!(entry.ip_address.is_none());
+    }
+
     #[test]
     fn audit_builder_suspicious_blocked() {
         let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 50));
@@ -2,6 +2,8 @@
 //!
 //! Structures for morning briefings with calendar, email, tasks, and weather.
 
+use std::fmt;
+
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -407,6 +409,97 @@ impl Default for MorningBriefing {
     }
 }
 
+/// A section of the morning briefing
+///
+/// Users choose which sections appear and in what order; see
+/// [`BriefingSection::DEFAULT_ORDER`] for the order used when nothing is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BriefingSection {
+    /// Today's appointments
+    Calendar,
+    /// Tasks due today and overdue tasks
+    Tasks,
+    /// Unread and important emails
+    Email,
+    /// Current weather and forecast
+    Weather,
+    /// Reminders due today
+    Reminders,
+    /// Upcoming birthdays of contacts
+    Birthdays,
+    /// Connections to the first appointment with a location
+    Transit,
+}
+
+impl BriefingSection {
+    /// All sections in the default briefing order
+    pub const DEFAULT_ORDER: [Self; 7] = [
+        Self::Calendar,
+        Self::Tasks,
+        Self::Email,
+        Self::Weather,
+        Self::Reminders,
+        Self::Birthdays,
+        Self::Transit,
+    ];
+
+    /// Get the storage representation
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Calendar => "calendar",
+            Self::Tasks => "tasks",
+            Self::Email => "email",
+            Self::Weather => "weather",
+            Self::Reminders => "reminders",
+            Self::Birthdays => "birthdays",
+            Self::Transit => "transit",
+        }
+    }
+
+    /// Get the default section list
+    #[must_use]
+    pub fn defaults() -> Vec<Self> {
+        Self::DEFAULT_ORDER.to_vec()
+    }
+
+    /// Remove repeated sections, keeping the first occurrence
+    #[must_use]
+    pub fn dedup(sections: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::new();
+        for section in sections {
+            if !result.contains(&section) {
+                result.push(section);
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Display for BriefingSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for BriefingSection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "calendar" => Ok(Self::Calendar),
+            "tasks" => Ok(Self::Tasks),
+            "email" => Ok(Self::Email),
+            "weather" => Ok(Self::Weather),
+            "reminders" => Ok(Self::Reminders),
+            "birthdays" => Ok(Self::Birthdays),
+            "transit" => Ok(Self::Transit),
+            other => Err(format!("Unknown briefing section: {other}")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -510,6 +603,34 @@ mod tests {
         assert_eq!(deserialized.briefing_date, briefing.briefing_date);
     }
 
+    #[test]
+    fn briefing_section_roundtrips_through_str() {
+        for section in BriefingSection::DEFAULT_ORDER {
+            assert_eq!(section.as_str().parse::<BriefingSection>(), Ok(section));
+        }
+        assert!("news".parse::<BriefingSection>().is_err());
+    }
+
+    #[test]
+    fn briefing_section_serializes_snake_case() {
+        let json = serde_json::to_string(&[BriefingSection::Weather, BriefingSection::Email])
+            .expect("serialize");
+        assert_eq!(json, r#"["weather","email"]"#);
+    }
+
+    #[test]
+    fn briefing_section_dedup_keeps_first_occurrence() {
+        let sections = BriefingSection::dedup([
+            BriefingSection::Weather,
+            BriefingSection::Calendar,
+            BriefingSection::Weather,
+        ]);
+        assert_eq!(
+            sections,
+            vec![BriefingSection::Weather, BriefingSection::Calendar]
+        );
+    }
+
     // === Additional tests for coverage ===
 
     #[test]
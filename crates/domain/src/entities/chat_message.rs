@@ -39,7 +39,7 @@ pub struct ChatMessage {
 }
 
 /// Optional metadata about a message
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MessageMetadata {
     /// Model that generated this response
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -50,6 +50,10 @@ pub struct MessageMetadata {
     /// Generation latency in milliseconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latency_ms: Option<u64>,
+    /// Messenger ID of the message (e.g., the Signal timestamp), used to
+    /// apply edits and deletes made in the messenger
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
 }
 
 impl ChatMessage {
@@ -102,6 +106,20 @@ impl ChatMessage {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Set the messenger ID of the message
+    #[must_use]
+    pub fn with_external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(MessageMetadata::default)
+            .external_id = Some(external_id.into());
+        self
+    }
+
+    /// Get the messenger ID of the message, if any
+    pub fn external_id(&self) -> Option<&str> {
+        self.metadata.as_ref()?.external_id.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +175,7 @@ mod tests {
             model: Some("qwen2.5".to_string()),
             tokens: Some(10),
             latency_ms: Some(100),
+            external_id: None,
         };
         let msg = ChatMessage::assistant("Response").with_metadata(metadata);
         assert!(msg.metadata.is_some());
@@ -166,6 +185,17 @@ mod tests {
         assert_eq!(meta.latency_ms, Some(100));
     }
 
+    #[test]
+    fn external_id_roundtrips_through_json() {
+        let msg = ChatMessage::user("Hello").with_external_id("1700000000000");
+        assert_eq!(msg.external_id(), Some("1700000000000"));
+
+        let json = serde_json::to_string(&msg).unwrap();
+        let restored: ChatMessage = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.external_id(), Some("1700000000000"));
+        assert!(ChatMessage::user("Hi").external_id().is_none());
+    }
+
     #[test]
     fn message_role_serializes_correctly() {
         let msg = ChatMessage::user("Hello");
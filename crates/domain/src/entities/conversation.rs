@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use super::{ChatMessage, MessageRole};
-use crate::value_objects::{ConversationId, PhoneNumber};
+use crate::value_objects::{ConversationId, PhoneNumber, UserId};
 
 /// Source of the conversation (how the user initiated it)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -88,6 +88,12 @@ pub struct Conversation {
     /// Only set for WhatsApp and Signal conversations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_number: Option<PhoneNumber>,
+    /// User who owns the conversation
+    #[serde(default)]
+    pub user_id: UserId,
+    /// Rolling summary of earlier turns that are no longer kept verbatim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
 }
 
 impl Conversation {
@@ -104,6 +110,8 @@ impl Conversation {
             persisted_message_count: 0,
             source: ConversationSource::default(),
             phone_number: None,
+            user_id: UserId::default(),
+            summary: None,
         }
     }
 
@@ -141,6 +149,19 @@ impl Conversation {
         self
     }
 
+    /// Set the user who owns the conversation
+    #[must_use]
+    pub const fn with_user_id(mut self, user_id: UserId) -> Self {
+        self.user_id = user_id;
+        self
+    }
+
+    /// Check whether the conversation belongs to the given user
+    #[must_use]
+    pub fn is_owned_by(&self, user_id: &UserId) -> bool {
+        self.user_id == *user_id
+    }
+
     /// Add a message to the conversation.
     ///
     /// Automatically assigns the next sequence number to the message.
@@ -187,6 +208,64 @@ impl Conversation {
         self.messages.is_empty()
     }
 
+    /// Replace the oldest user/assistant messages with a rolling summary
+    ///
+    /// Removes up to `count` of the oldest non-system messages and stores
+    /// `summary` (which should already cover any previous summary) in their
+    /// place. System messages are kept. Returns the number of removed messages.
+    pub fn compact(&mut self, count: usize, summary: impl Into<String>) -> usize {
+        let mut removed = 0;
+        self.messages.retain(|m| {
+            if removed < count && m.role != MessageRole::System {
+                removed += 1;
+                false
+            } else {
+                true
+            }
+        });
+        self.summary = Some(summary.into());
+        self.persisted_message_count = self.persisted_message_count.min(self.messages.len());
+        self.updated_at = Utc::now();
+        removed
+    }
+
+    /// Replace the content of the message with the given messenger ID
+    ///
+    /// Returns `false` if no message has that ID.
+    pub fn edit_message(&mut self, external_id: &str, content: impl Into<String>) -> bool {
+        let Some(message) = self
+            .messages
+            .iter_mut()
+            .find(|m| m.external_id() == Some(external_id))
+        else {
+            return false;
+        };
+
+        message.content = content.into();
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Remove the message with the given messenger ID
+    ///
+    /// Returns `false` if no message has that ID.
+    pub fn remove_message(&mut self, external_id: &str) -> bool {
+        let Some(index) = self
+            .messages
+            .iter()
+            .position(|m| m.external_id() == Some(external_id))
+        else {
+            return false;
+        };
+
+        self.messages.remove(index);
+        if index < self.persisted_message_count {
+            self.persisted_message_count -= 1;
+        }
+        self.updated_at = Utc::now();
+        true
+    }
+
     /// Set the conversation title
     pub fn set_title(&mut self, title: impl Into<String>) {
         self.title = Some(title.into());
@@ -252,6 +331,62 @@ mod tests {
         assert_eq!(conv.message_count(), 0);
     }
 
+    #[test]
+    fn new_conversation_belongs_to_default_user() {
+        let conv = Conversation::new();
+        assert!(conv.is_owned_by(&UserId::default()));
+
+        let owner = UserId::new();
+        let conv = Conversation::new().with_user_id(owner);
+        assert!(conv.is_owned_by(&owner));
+        assert!(!conv.is_owned_by(&UserId::default()));
+    }
+
+    #[test]
+    fn compact_replaces_oldest_turns_with_summary() {
+        let mut conv = Conversation::new();
+        conv.add_message(ChatMessage::system("Be brief."));
+        conv.add_user_message("First question");
+        conv.add_assistant_message("First answer");
+        conv.add_user_message("Second question");
+
+        let removed = conv.compact(2, "Asked a first question.");
+
+        assert_eq!(removed, 2);
+        assert_eq!(conv.summary.as_deref(), Some("Asked a first question."));
+        assert_eq!(conv.messages.len(), 2);
+        assert_eq!(conv.messages[0].role, MessageRole::System);
+        assert_eq!(conv.messages[1].content, "Second question");
+    }
+
+    #[test]
+    fn edit_message_replaces_content_by_external_id() {
+        let mut conv = Conversation::new();
+        conv.add_message(ChatMessage::user("Remind me at 5").with_external_id("1001"));
+        conv.add_assistant_message("Reminder set for 5:00.");
+
+        assert!(conv.edit_message("1001", "Remind me at 6"));
+        assert_eq!(conv.messages[0].content, "Remind me at 6");
+        assert_eq!(conv.messages[0].external_id(), Some("1001"));
+        assert!(!conv.edit_message("9999", "Unknown"));
+    }
+
+    #[test]
+    fn remove_message_drops_message_and_adjusts_persisted_count() {
+        let mut conv = Conversation::new();
+        conv.add_message(ChatMessage::user("Oops, wrong chat").with_external_id("1001"));
+        conv.add_assistant_message("Sure!");
+        conv.mark_messages_persisted();
+        conv.add_user_message("Next");
+
+        assert!(conv.remove_message("1001"));
+        assert_eq!(conv.message_count(), 2);
+        assert_eq!(conv.messages[0].content, "Sure!");
+        assert_eq!(conv.persisted_message_count, 1);
+        assert_eq!(conv.unpersisted_messages()[0].content, "Next");
+        assert!(!conv.remove_message("1001"));
+    }
+
     #[test]
     fn messages_can_be_added() {
         let mut conv = Conversation::new();
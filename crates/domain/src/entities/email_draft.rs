@@ -23,6 +23,9 @@ pub struct PersistedEmailDraft {
     pub subject: String,
     /// Email body (plain text)
     pub body: String,
+    /// Optional HTML version of the body
+    #[serde(default)]
+    pub html_body: Option<String>,
     /// When the draft was created
     pub created_at: DateTime<Utc>,
     /// When the draft expires (for automatic cleanup)
@@ -45,6 +48,7 @@ impl PersistedEmailDraft {
             cc: Vec::new(),
             subject: subject.into(),
             body: body.into(),
+            html_body: None,
             created_at: now,
             expires_at: now + Duration::days(DEFAULT_DRAFT_TTL_DAYS),
         }
@@ -66,6 +70,7 @@ impl PersistedEmailDraft {
             cc: Vec::new(),
             subject: subject.into(),
             body: body.into(),
+            html_body: None,
             created_at: now,
             expires_at: now + ttl,
         }
@@ -85,6 +90,13 @@ impl PersistedEmailDraft {
         self
     }
 
+    /// Attach an HTML version of the body
+    #[must_use]
+    pub fn with_html_body(mut self, html_body: impl Into<String>) -> Self {
+        self.html_body = Some(html_body.into());
+        self
+    }
+
     /// Check if the draft has expired
     pub fn is_expired(&self) -> bool {
         Utc::now() > self.expires_at
@@ -154,6 +166,15 @@ mod tests {
         assert_eq!(draft.cc, vec![cc1, cc2]);
     }
 
+    #[test]
+    fn new_draft_is_plaintext_only() {
+        let draft = PersistedEmailDraft::new(test_user_id(), test_email(), "Subject", "Body");
+        assert!(draft.html_body.is_none());
+
+        let draft = draft.with_html_body("<p>Body</p>");
+        assert_eq!(draft.html_body.as_deref(), Some("<p>Body</p>"));
+    }
+
     #[test]
     fn new_draft_is_not_expired() {
         let draft = PersistedEmailDraft::new(test_user_id(), test_email(), "Subject", "Body");
@@ -197,6 +218,7 @@ mod tests {
         assert_eq!(draft.id, parsed.id);
         assert_eq!(draft.to, parsed.to);
         assert_eq!(draft.cc, parsed.cc);
+        assert!(parsed.html_body.is_none());
     }
 
     #[test]
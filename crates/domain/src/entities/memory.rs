@@ -3,7 +3,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::value_objects::{ConversationId, MemoryId, UserId};
+use crate::value_objects::{ConversationId, MemoryId, TenantAware, TenantId, UserId};
 
 /// Type of memory content
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -58,6 +58,9 @@ impl std::fmt::Display for MemoryType {
 pub struct Memory {
     /// Unique identifier for this memory
     pub id: MemoryId,
+    /// Tenant this memory is isolated to
+    #[serde(default)]
+    pub tenant_id: TenantId,
     /// User who owns this memory
     pub user_id: UserId,
     /// Optional conversation this memory originated from
@@ -106,6 +109,7 @@ impl Memory {
         let now = Utc::now();
         Self {
             id: MemoryId::new(),
+            tenant_id: TenantId::default(),
             user_id,
             conversation_id: None,
             content: content.into(),
@@ -120,6 +124,13 @@ impl Memory {
         }
     }
 
+    /// Set the tenant this memory belongs to
+    #[must_use]
+    pub const fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
     /// Set the conversation this memory originated from
     #[must_use]
     pub const fn with_conversation(mut self, conversation_id: ConversationId) -> Self {
@@ -230,9 +241,17 @@ impl Memory {
     }
 }
 
+impl TenantAware for Memory {
+    fn tenant_id(&self) -> TenantId {
+        self.tenant_id
+    }
+}
+
 /// Query parameters for searching memories
 #[derive(Debug, Clone, Default)]
 pub struct MemoryQuery {
+    /// Tenant to search in; stores reject queries without one
+    pub tenant_id: Option<TenantId>,
     /// User to search memories for
     pub user_id: Option<UserId>,
     /// Optional conversation filter
@@ -256,6 +275,13 @@ impl MemoryQuery {
         Self::default()
     }
 
+    /// Scope the query to a tenant
+    #[must_use]
+    pub const fn for_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
     /// Filter by user
     #[must_use]
     pub const fn for_user(mut self, user_id: UserId) -> Self {
@@ -444,12 +470,26 @@ mod tests {
         assert_eq!(with_embedding.embedding_dimensions(), Some(384));
     }
 
+    #[test]
+    fn memory_defaults_to_single_tenant() {
+        let memory = Memory::new(test_user_id(), "c", "s", MemoryType::Fact);
+        assert!(memory.tenant_id.is_default());
+
+        let tenant_id = TenantId::new();
+        let scoped = memory.with_tenant(tenant_id);
+        assert!(scoped.belongs_to(tenant_id));
+        assert!(!scoped.belongs_to(TenantId::default()));
+    }
+
     #[test]
     fn memory_query_builder() {
         let user_id = test_user_id();
         let conversation_id = ConversationId::new();
 
+        let tenant_id = TenantId::new();
+
         let query = MemoryQuery::new()
+            .for_tenant(tenant_id)
             .for_user(user_id)
             .in_conversation(conversation_id)
             .of_types(vec![MemoryType::Fact, MemoryType::Preference])
@@ -458,6 +498,7 @@ mod tests {
             .limit(10)
             .search("test query");
 
+        assert_eq!(query.tenant_id, Some(tenant_id));
         assert_eq!(query.user_id, Some(user_id));
         assert_eq!(query.conversation_id, Some(conversation_id));
         assert_eq!(query.memory_types.as_ref().unwrap().len(), 2);
@@ -16,7 +16,8 @@ mod web_search;
 pub use approval_request::{ApprovalError, ApprovalRequest, ApprovalStatus};
 pub use audit_entry::{AuditBuilder, AuditEntry, AuditEventType};
 pub use briefing::{
-    CalendarBrief, CalendarItem, EmailBrief, MorningBriefing, TaskBrief, TaskItem, WeatherSummary,
+    BriefingSection, CalendarBrief, CalendarItem, EmailBrief, MorningBriefing, TaskBrief, TaskItem,
+    WeatherSummary,
 };
 pub use chat_message::{ChatMessage, MessageMetadata, MessageRole};
 pub use conversation::{Conversation, ConversationSource};
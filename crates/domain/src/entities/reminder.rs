@@ -3,7 +3,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::value_objects::{ReminderId, UserId};
+use crate::value_objects::{Recurrence, ReminderId, Timezone, UserId};
 
 /// Source of the reminder (what triggered its creation)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -117,6 +117,15 @@ pub struct Reminder {
     pub snooze_count: u8,
     /// Maximum allowed snooze count
     pub max_snooze: u8,
+    /// Repeat rule; recurring reminders re-arm when acknowledged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+    /// Timezone the reminder was scheduled in
+    ///
+    /// Recurring reminders keep their local time of day in this zone (UTC
+    /// when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<Timezone>,
     /// When this reminder was created
     pub created_at: DateTime<Utc>,
     /// When this reminder was last updated
@@ -146,6 +155,8 @@ impl Reminder {
             status: ReminderStatus::Pending,
             snooze_count: 0,
             max_snooze: 3,
+            recurrence: None,
+            timezone: None,
             created_at: now,
             updated_at: now,
         }
@@ -186,6 +197,26 @@ impl Reminder {
         self
     }
 
+    /// Set a recurrence rule
+    #[must_use]
+    pub fn with_recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Set the timezone the reminder was scheduled in
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Check if this reminder repeats
+    #[must_use]
+    pub const fn is_recurring(&self) -> bool {
+        self.recurrence.is_some()
+    }
+
     /// Check if this reminder is due (ready to fire)
     #[must_use]
     pub fn is_due(&self) -> bool {
@@ -205,9 +236,23 @@ impl Reminder {
     }
 
     /// Acknowledge this reminder (user marked as done)
+    ///
+    /// Recurring reminders are re-armed for their next occurrence instead of
+    /// becoming terminal; the event time (if any) moves by the same offset.
     pub fn acknowledge(&mut self) {
-        self.status = ReminderStatus::Acknowledged;
-        self.updated_at = Utc::now();
+        let now = Utc::now();
+        if let Some(recurrence) = &self.recurrence {
+            let timezone = self.timezone.clone().unwrap_or_default();
+            let next = recurrence.next_after_in(self.remind_at, now, &timezone);
+            let shift = next - self.remind_at;
+            self.remind_at = next;
+            self.event_time = self.event_time.map(|et| et + shift);
+            self.status = ReminderStatus::Pending;
+            self.snooze_count = 0;
+        } else {
+            self.status = ReminderStatus::Acknowledged;
+        }
+        self.updated_at = now;
     }
 
     /// Cancel this reminder
@@ -340,6 +385,45 @@ mod tests {
         assert!(reminder.status.is_terminal());
     }
 
+    #[test]
+    fn acknowledge_recurring_rearms_next_occurrence() {
+        let remind_at = Utc::now() - Duration::minutes(5);
+        let mut reminder =
+            Reminder::new(sample_user_id(), ReminderSource::Custom, "Gym", remind_at)
+                .with_event_time(remind_at + Duration::minutes(30))
+                .with_recurrence(Recurrence::Weekly(Vec::new()));
+        reminder.mark_sent();
+
+        reminder.acknowledge();
+
+        assert_eq!(reminder.status, ReminderStatus::Pending);
+        assert_eq!(reminder.remind_at, remind_at + Duration::days(7));
+        assert_eq!(
+            reminder.event_time,
+            Some(remind_at + Duration::days(7) + Duration::minutes(30))
+        );
+    }
+
+    #[test]
+    fn acknowledge_recurring_keeps_local_time_in_timezone() {
+        use chrono::{TimeZone, Timelike};
+
+        // 09:00 CET, before the spring DST switch
+        let remind_at = Utc.with_ymd_and_hms(2026, 3, 20, 8, 0, 0).unwrap();
+        let mut reminder =
+            Reminder::new(sample_user_id(), ReminderSource::Custom, "Pills", remind_at)
+                .with_recurrence(Recurrence::Daily)
+                .with_timezone(Timezone::berlin());
+
+        reminder.acknowledge();
+
+        let local = reminder
+            .remind_at
+            .with_timezone(&Timezone::berlin().as_chrono_tz());
+        assert_eq!((local.hour(), local.minute()), (9, 0));
+        assert!(reminder.remind_at > Utc::now());
+    }
+
     #[test]
     fn cancel() {
         let mut reminder =
@@ -473,7 +557,8 @@ mod tests {
             Utc::now(),
         )
         .with_description("Testing serialization")
-        .with_location("Home");
+        .with_location("Home")
+        .with_recurrence(Recurrence::Daily);
 
         let json = serde_json::to_string(&reminder).unwrap();
         let deserialized: Reminder = serde_json::from_str(&json).unwrap();
@@ -485,5 +570,6 @@ mod tests {
             Some("Testing serialization")
         );
         assert_eq!(deserialized.location.as_deref(), Some("Home"));
+        assert_eq!(deserialized.recurrence, Some(Recurrence::Daily));
     }
 }
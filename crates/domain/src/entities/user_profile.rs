@@ -5,17 +5,26 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::value_objects::{GeoLocation, Timezone, UserId};
+use crate::{
+    entities::BriefingSection,
+    value_objects::{GeoLocation, Timezone, UserId},
+};
 
 /// User profile with location and preferences
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserProfile {
     /// Unique user identifier
     id: UserId,
+    /// Name the assistant addresses the user by
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
     /// User's current location (for weather, etc.)
     location: Option<GeoLocation>,
     /// User's timezone
     timezone: Timezone,
+    /// Morning briefing sections in display order (None = configured default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    briefing_sections: Option<Vec<BriefingSection>>,
     /// When the profile was created
     created_at: DateTime<Utc>,
     /// When the profile was last updated
@@ -29,8 +38,10 @@ impl UserProfile {
         let now = Utc::now();
         Self {
             id,
+            name: None,
             location: None,
             timezone: Timezone::default(),
+            briefing_sections: None,
             created_at: now,
             updated_at: now,
         }
@@ -42,8 +53,10 @@ impl UserProfile {
         let now = Utc::now();
         Self {
             id,
+            name: None,
             location: Some(location),
             timezone,
+            briefing_sections: None,
             created_at: now,
             updated_at: now,
         }
@@ -60,19 +73,45 @@ impl UserProfile {
     ) -> Self {
         Self {
             id,
+            name: None,
             location,
             timezone,
+            briefing_sections: None,
             created_at,
             updated_at,
         }
     }
 
+    /// Set the name the assistant addresses the user by
+    ///
+    /// Blank names are treated as unset.
+    #[must_use]
+    pub fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name.filter(|n| !n.trim().is_empty());
+        self
+    }
+
+    /// Set the morning briefing sections and their order
+    ///
+    /// Repeated sections are dropped; `None` falls back to the configured default.
+    #[must_use]
+    pub fn with_briefing_sections(mut self, sections: Option<Vec<BriefingSection>>) -> Self {
+        self.briefing_sections = sections.map(BriefingSection::dedup);
+        self
+    }
+
     /// Get the user ID
     #[must_use]
     pub const fn id(&self) -> UserId {
         self.id
     }
 
+    /// Get the user's name, if set
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Get the user's location
     #[must_use]
     pub const fn location(&self) -> Option<GeoLocation> {
@@ -85,6 +124,12 @@ impl UserProfile {
         &self.timezone
     }
 
+    /// Get the morning briefing sections, if the user chose any
+    #[must_use]
+    pub fn briefing_sections(&self) -> Option<&[BriefingSection]> {
+        self.briefing_sections.as_deref()
+    }
+
     /// Get the creation timestamp
     #[must_use]
     pub const fn created_at(&self) -> DateTime<Utc> {
@@ -109,12 +154,24 @@ impl UserProfile {
         self.updated_at = Utc::now();
     }
 
+    /// Update the user's name
+    pub fn update_name(&mut self, name: impl Into<String>) {
+        self.name = Some(name.into()).filter(|n| !n.trim().is_empty());
+        self.updated_at = Utc::now();
+    }
+
     /// Update the user's timezone
     pub fn update_timezone(&mut self, timezone: Timezone) {
         self.timezone = timezone;
         self.updated_at = Utc::now();
     }
 
+    /// Update the morning briefing sections and their order
+    pub fn update_briefing_sections(&mut self, sections: Option<Vec<BriefingSection>>) {
+        self.briefing_sections = sections.map(BriefingSection::dedup);
+        self.updated_at = Utc::now();
+    }
+
     /// Check if the profile has a location set
     #[must_use]
     pub const fn has_location(&self) -> bool {
@@ -208,6 +265,37 @@ mod tests {
         assert_eq!(profile.updated_at(), updated);
     }
 
+    #[test]
+    fn test_name_is_optional() {
+        let mut profile = UserProfile::new(UserId::new());
+        assert!(profile.name().is_none());
+
+        profile.update_name("Anna");
+        assert_eq!(profile.name(), Some("Anna"));
+
+        let profile = profile.with_name(Some("   ".to_string()));
+        assert!(profile.name().is_none());
+    }
+
+    #[test]
+    fn test_briefing_sections_default_to_unset() {
+        let mut profile = UserProfile::new(UserId::new());
+        assert!(profile.briefing_sections().is_none());
+
+        profile.update_briefing_sections(Some(vec![
+            BriefingSection::Calendar,
+            BriefingSection::Weather,
+            BriefingSection::Calendar,
+        ]));
+        assert_eq!(
+            profile.briefing_sections(),
+            Some([BriefingSection::Calendar, BriefingSection::Weather].as_slice())
+        );
+
+        let profile = profile.with_briefing_sections(None);
+        assert!(profile.briefing_sections().is_none());
+    }
+
     #[test]
     fn test_serialization() {
         let profile =
@@ -0,0 +1,121 @@
+//! Delivery status value object
+//!
+//! Tracks how far an outgoing message has progressed on the messenger side.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Delivery state of an outgoing message
+///
+/// Updates from WhatsApp and Signal may arrive out of order, so a status
+/// only ever moves forward: `Sent` → `Delivered` → `Read`. `Failed` can only
+/// replace `Sent`; a later delivery or read receipt overrides it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Accepted by the messenger, not yet confirmed on the device
+    #[default]
+    Sent,
+    /// Delivered to the recipient's device
+    Delivered,
+    /// Read by the recipient
+    Read,
+    /// Delivery failed permanently
+    Failed,
+}
+
+impl DeliveryStatus {
+    /// Check whether an update to `next` moves this status forward
+    #[must_use]
+    pub const fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Sent, Self::Delivered | Self::Read | Self::Failed)
+                | (Self::Delivered, Self::Read)
+                | (Self::Failed, Self::Delivered | Self::Read)
+        )
+    }
+
+    /// Check whether the recipient's device has received the message
+    #[must_use]
+    pub const fn is_delivered(self) -> bool {
+        matches!(self, Self::Delivered | Self::Read)
+    }
+
+    /// Get the storage representation
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Sent => "sent",
+            Self::Delivered => "delivered",
+            Self::Read => "read",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sent" => Ok(Self::Sent),
+            "delivered" => Ok(Self::Delivered),
+            "read" => Ok(Self::Read),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!("Unknown delivery status: {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_only_moves_forward() {
+        assert!(DeliveryStatus::Sent.can_transition_to(DeliveryStatus::Delivered));
+        assert!(DeliveryStatus::Sent.can_transition_to(DeliveryStatus::Read));
+        assert!(DeliveryStatus::Delivered.can_transition_to(DeliveryStatus::Read));
+
+        assert!(!DeliveryStatus::Read.can_transition_to(DeliveryStatus::Delivered));
+        assert!(!DeliveryStatus::Delivered.can_transition_to(DeliveryStatus::Sent));
+        assert!(!DeliveryStatus::Read.can_transition_to(DeliveryStatus::Read));
+    }
+
+    #[test]
+    fn failed_only_replaces_sent() {
+        assert!(DeliveryStatus::Sent.can_transition_to(DeliveryStatus::Failed));
+        assert!(!DeliveryStatus::Delivered.can_transition_to(DeliveryStatus::Failed));
+        assert!(!DeliveryStatus::Read.can_transition_to(DeliveryStatus::Failed));
+        assert!(DeliveryStatus::Failed.can_transition_to(DeliveryStatus::Read));
+        assert!(!DeliveryStatus::Failed.can_transition_to(DeliveryStatus::Sent));
+    }
+
+    #[test]
+    fn roundtrips_through_str() {
+        for status in [
+            DeliveryStatus::Sent,
+            DeliveryStatus::Delivered,
+            DeliveryStatus::Read,
+            DeliveryStatus::Failed,
+        ] {
+            assert_eq!(status.as_str().parse::<DeliveryStatus>(), Ok(status));
+        }
+        assert!("bounced".parse::<DeliveryStatus>().is_err());
+    }
+
+    #[test]
+    fn serializes_to_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&DeliveryStatus::Delivered).unwrap(),
+            "\"delivered\""
+        );
+    }
+}
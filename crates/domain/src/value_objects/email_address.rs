@@ -1,6 +1,8 @@
 //! Email address value object with validation
 //!
 //! Provides a validated email address type that ensures proper format.
+//! Internationalized domains (IDN) are kept in their Unicode display form
+//! and punycode-encoded for transport via [`EmailAddress::to_ascii`].
 //!
 //! # Examples
 //!
@@ -15,6 +17,10 @@
 //! let email = EmailAddress::new("User@Example.COM").unwrap();
 //! assert_eq!(email.as_str(), "user@example.com");
 //!
+//! // Internationalized domains are punycode-encoded for transport
+//! let email = EmailAddress::new("user+news@münchen.de").unwrap();
+//! assert_eq!(email.to_ascii(), "user+news@xn--mnchen-3ya.de");
+//!
 //! // Invalid emails are rejected
 //! assert!(EmailAddress::new("invalid").is_err());
 //! ```
@@ -22,10 +28,24 @@
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
-use validator::Validate;
 
 use crate::errors::DomainError;
 
+/// Maximum length of the local part in bytes (RFC 5321)
+const MAX_LOCAL_PART_LEN: usize = 64;
+
+/// Maximum length of the ASCII domain (RFC 1035)
+const MAX_DOMAIN_LEN: usize = 253;
+
+/// Maximum length of a single domain label (RFC 1035)
+const MAX_LABEL_LEN: usize = 63;
+
+/// Maximum length of a complete address (RFC 5321 path limit)
+const MAX_ADDRESS_LEN: usize = 254;
+
+/// Punctuation allowed in an unquoted local part (RFC 5322 `atext`)
+const LOCAL_PART_SPECIALS: &str = "!#$%&'*+/=?^_`{|}~-";
+
 /// A validated email address
 ///
 /// # Examples
@@ -37,16 +57,19 @@ use crate::errors::DomainError;
 /// assert_eq!(email.local_part(), "user");
 /// assert_eq!(email.domain(), "example.com");
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct EmailAddress {
-    #[validate(email)]
     value: String,
 }
 
 impl EmailAddress {
     /// Create a new email address, validating the format
     ///
+    /// The address is lowercased, so addresses differing only in case
+    /// compare equal. Use [`EmailAddress::canonical`] to also ignore
+    /// plus-addressing tags.
+    ///
     /// # Examples
     ///
     /// ```
@@ -60,17 +83,28 @@ impl EmailAddress {
     ///
     /// Returns an error if the email format is invalid.
     pub fn new(email: impl Into<String>) -> Result<Self, DomainError> {
-        let value = email.into().trim().to_lowercase();
-
-        let candidate = Self { value };
-        candidate
-            .validate()
-            .map_err(|e| DomainError::InvalidEmailAddress(e.to_string()))?;
+        let email = email.into();
+        let (local, domain) = email
+            .trim()
+            .rsplit_once('@')
+            .ok_or_else(|| invalid("missing @"))?;
+
+        validate_local_part(local)?;
+        let local = local.to_lowercase();
+        let domain = domain.to_lowercase();
+        let ascii_domain = to_ascii_domain(&domain)?;
+        if local.len() + 1 + ascii_domain.len() > MAX_ADDRESS_LEN {
+            return Err(invalid("address is too long"));
+        }
 
-        Ok(candidate)
+        Ok(Self {
+            value: format!("{local}@{domain}"),
+        })
     }
 
     /// Get the email address as a string slice
+    ///
+    /// This is the display form; internationalized domains stay in Unicode.
     pub fn as_str(&self) -> &str {
         &self.value
     }
@@ -86,7 +120,7 @@ impl EmailAddress {
     /// assert_eq!(email.local_part(), "user");
     /// ```
     pub fn local_part(&self) -> &str {
-        self.value.split('@').next().unwrap_or("")
+        self.value.rsplit_once('@').map_or("", |(local, _)| local)
     }
 
     /// Get the domain part (after @)
@@ -100,8 +134,127 @@ impl EmailAddress {
     /// assert_eq!(email.domain(), "example.com");
     /// ```
     pub fn domain(&self) -> &str {
-        self.value.split('@').nth(1).unwrap_or("")
+        self.value.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    /// Get the plus-addressing tag, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::EmailAddress;
+    ///
+    /// let email = EmailAddress::new("user+news@example.com").unwrap();
+    /// assert_eq!(email.tag(), Some("news"));
+    /// ```
+    pub fn tag(&self) -> Option<&str> {
+        self.local_part().split_once('+').map(|(_, tag)| tag)
+    }
+
+    /// Address with the domain punycode-encoded, for SMTP transport
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::EmailAddress;
+    ///
+    /// let email = EmailAddress::new("info@bücher.de").unwrap();
+    /// assert_eq!(email.to_ascii(), "info@xn--bcher-kva.de");
+    /// ```
+    #[must_use]
+    pub fn to_ascii(&self) -> String {
+        // The domain was validated on construction
+        let domain = to_ascii_domain(self.domain()).unwrap_or_else(|_| self.domain().to_string());
+        format!("{}@{domain}", self.local_part())
+    }
+
+    /// Canonical form for deduplication
+    ///
+    /// Strips the plus-addressing tag, so `User+News@example.com` and
+    /// `user@example.com` compare equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use domain::EmailAddress;
+    ///
+    /// let a = EmailAddress::new("User+News@example.com").unwrap();
+    /// let b = EmailAddress::new("user@Example.com").unwrap();
+    /// assert_eq!(a.canonical(), b.canonical());
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> Self {
+        let local = self.local_part();
+        let base = local.split_once('+').map_or(local, |(base, _)| base);
+        Self {
+            value: format!("{base}@{}", self.domain()),
+        }
+    }
+}
+
+fn invalid(reason: &str) -> DomainError {
+    DomainError::InvalidEmailAddress(reason.to_string())
+}
+
+/// Check an unquoted local part (dot-atom)
+fn validate_local_part(local: &str) -> Result<(), DomainError> {
+    if local.is_empty() {
+        return Err(invalid("empty local part"));
+    }
+    if local.len() > MAX_LOCAL_PART_LEN {
+        return Err(invalid("local part is too long"));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err(invalid("misplaced dot in local part"));
+    }
+    if let Some(c) = local
+        .chars()
+        .find(|&c| !(c.is_ascii_alphanumeric() || c == '.' || LOCAL_PART_SPECIALS.contains(c)))
+    {
+        return Err(DomainError::InvalidEmailAddress(format!(
+            "invalid character '{c}' in local part"
+        )));
+    }
+    // A tag needs an address to belong to
+    if local.starts_with('+') {
+        return Err(invalid("empty address before plus tag"));
+    }
+    Ok(())
+}
+
+/// Validate a lowercased domain and convert it to its ASCII (punycode) form
+fn to_ascii_domain(domain: &str) -> Result<String, DomainError> {
+    let ascii = idna::domain_to_ascii(domain).map_err(|_| invalid("domain cannot be encoded"))?;
+
+    let labels: Vec<&str> = ascii.split('.').collect();
+    if labels.len() < 2 {
+        return Err(invalid("domain needs at least two labels"));
+    }
+    for label in &labels {
+        if label.is_empty() {
+            return Err(invalid("empty domain label"));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(invalid("domain label starts or ends with '-'"));
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(invalid("invalid character in domain"));
+        }
+        if label.len() > MAX_LABEL_LEN {
+            return Err(invalid("domain label is too long"));
+        }
     }
+
+    if labels
+        .last()
+        .is_some_and(|tld| tld.chars().all(|c| c.is_ascii_digit()))
+    {
+        return Err(invalid("top-level domain must not be numeric"));
+    }
+    if ascii.len() > MAX_DOMAIN_LEN {
+        return Err(invalid("domain is too long"));
+    }
+    Ok(ascii)
 }
 
 impl fmt::Display for EmailAddress {
@@ -140,6 +293,108 @@ mod tests {
     fn email_is_normalized_to_lowercase() {
         let email = EmailAddress::new("User@Example.COM").unwrap();
         assert_eq!(email.as_str(), "user@example.com");
+        assert_eq!(email, EmailAddress::new("user@example.com").unwrap());
+    }
+
+    #[test]
+    fn idn_domain_keeps_display_form() {
+        let email = EmailAddress::new("user+tag@München.de").unwrap();
+        assert_eq!(email.as_str(), "user+tag@münchen.de");
+        assert_eq!(email.domain(), "münchen.de");
+        assert_eq!(email.to_ascii(), "user+tag@xn--mnchen-3ya.de");
+    }
+
+    #[test]
+    fn idn_domains_are_punycode_encoded() {
+        for (address, ascii) in [
+            ("a@bücher.de", "a@xn--bcher-kva.de"),
+            ("a@ü.de", "a@xn--tda.de"),
+            ("a@例え.jp", "a@xn--r8jz45g.jp"),
+        ] {
+            assert_eq!(EmailAddress::new(address).unwrap().to_ascii(), ascii);
+        }
+    }
+
+    #[test]
+    fn ascii_address_is_unchanged_for_transport() {
+        let email = EmailAddress::new("user@example.com").unwrap();
+        assert_eq!(email.to_ascii(), "user@example.com");
+    }
+
+    #[test]
+    fn plus_tag_is_accepted() {
+        let email = EmailAddress::new("user+news@example.com").unwrap();
+        assert_eq!(email.local_part(), "user+news");
+        assert_eq!(email.tag(), Some("news"));
+        assert_eq!(EmailAddress::new("user@example.com").unwrap().tag(), None);
+    }
+
+    #[test]
+    fn canonical_strips_tag_and_case() {
+        let tagged = EmailAddress::new("Andreas+Shop@Proton.me").unwrap();
+        let plain = EmailAddress::new("andreas@proton.me").unwrap();
+        assert_ne!(tagged, plain);
+        assert_eq!(tagged.canonical(), plain.canonical());
+        assert_eq!(tagged.canonical().as_str(), "andreas@proton.me");
+    }
+
+    #[test]
+    fn canonical_keeps_idn_domain() {
+        let email = EmailAddress::new("a+b@bücher.de").unwrap();
+        assert_eq!(email.canonical().as_str(), "a@bücher.de");
+    }
+
+    #[test]
+    fn rfc_style_local_parts_are_accepted() {
+        for address in [
+            "first.last@example.com",
+            "o'brien@example.ie",
+            "x_y-z@example.org",
+            "a!#$%&*/=?^`{|}~@example.com",
+            "1234567890@example.com",
+            "user@sub.domain.example.co.uk",
+            "user@xn--mnchen-3ya.de",
+        ] {
+            assert!(EmailAddress::new(address).is_ok(), "{address}");
+        }
+    }
+
+    #[test]
+    fn obviously_invalid_addresses_are_rejected() {
+        for address in [
+            "",
+            "@",
+            "user@",
+            "user@localhost",
+            "user@example..com",
+            "user@.example.com",
+            "user@-example.com",
+            "user@example-.com",
+            "user@example.123",
+            "user@exa mple.com",
+            ".user@example.com",
+            "user.@example.com",
+            "us..er@example.com",
+            "us er@example.com",
+            "user(comment)@example.com",
+            "+tag@example.com",
+            "user@@example.com",
+            "üser@example.com",
+        ] {
+            assert!(EmailAddress::new(address).is_err(), "{address:?}");
+        }
+    }
+
+    #[test]
+    fn overlong_parts_are_rejected() {
+        let local = "a".repeat(65);
+        assert!(EmailAddress::new(format!("{local}@example.com")).is_err());
+
+        let label = "a".repeat(64);
+        assert!(EmailAddress::new(format!("user@{label}.com")).is_err());
+
+        let domain = format!("{}.com", ["abcdefghij"; 25].join("."));
+        assert!(EmailAddress::new(format!("user@{domain}")).is_err());
     }
 
     #[test]
@@ -247,6 +502,19 @@ mod proptest_tests {
             }
         }
 
+        #[test]
+        fn canonical_is_idempotent(
+            local in valid_local_part(),
+            tag in "[a-z0-9]{1,8}",
+            domain in valid_domain()
+        ) {
+            if let Ok(email) = EmailAddress::new(format!("{local}+{tag}@{domain}")) {
+                let canonical = email.canonical();
+                prop_assert_eq!(canonical.tag(), None);
+                prop_assert_eq!(canonical.canonical(), canonical);
+            }
+        }
+
         #[test]
         fn email_roundtrips_through_display(
             local in valid_local_part(),
@@ -3,14 +3,17 @@
 mod approval_id;
 mod contact_id;
 mod conversation_id;
+mod delivery_status;
 mod draft_id;
 mod email_address;
 mod geo_location;
 mod humidity;
 mod memory_id;
 mod messenger_source;
+mod money;
 mod phone_number;
 mod priority;
+mod recurrence;
 mod reminder_id;
 mod task_status;
 pub mod tenant;
@@ -21,14 +24,17 @@ mod user_id;
 pub use approval_id::ApprovalId;
 pub use contact_id::ContactId;
 pub use conversation_id::ConversationId;
+pub use delivery_status::DeliveryStatus;
 pub use draft_id::DraftId;
 pub use email_address::EmailAddress;
 pub use geo_location::{GeoLocation, InvalidCoordinates};
 pub use humidity::{Humidity, InvalidHumidity};
 pub use memory_id::MemoryId;
 pub use messenger_source::MessengerSource;
+pub use money::{Currency, Money, MoneyError};
 pub use phone_number::PhoneNumber;
 pub use priority::Priority;
+pub use recurrence::{InvalidRecurrence, Recurrence};
 pub use reminder_id::ReminderId;
 pub use task_status::TaskStatus;
 pub use tenant::{TenantAware, TenantContext, TenantFilter};
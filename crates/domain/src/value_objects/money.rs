@@ -0,0 +1,607 @@
+//! Money value object
+//!
+//! Represents an amount of money in integer minor units (e.g. cents) with an
+//! ISO 4217 currency, so sums never suffer from floating point rounding.
+//!
+//! # Examples
+//!
+//! ```
+//! use domain::value_objects::{Currency, Money};
+//!
+//! let coffee: Money = "3,20 €".parse().expect("valid amount");
+//! let cake: Money = "EUR 4.50".parse().expect("valid amount");
+//! let total = coffee.checked_add(cake).expect("same currency");
+//!
+//! assert_eq!(total.minor_units(), 770);
+//! assert_eq!(total.currency(), Currency::Eur);
+//! assert_eq!(total.to_string(), "7,70 €");
+//!
+//! // Different currencies cannot be added
+//! let lunch: Money = "$12.50".parse().expect("valid amount");
+//! assert!(total.checked_add(lunch).is_err());
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors for money parsing and arithmetic
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The currency code or symbol is not supported
+    #[error("unknown currency: '{0}'")]
+    UnknownCurrency(String),
+
+    /// The amount could not be parsed
+    #[error("invalid amount: '{0}'")]
+    InvalidAmount(String),
+
+    /// Arithmetic on amounts in different currencies
+    #[error("currency mismatch: {0} and {1}")]
+    CurrencyMismatch(Currency, Currency),
+
+    /// The result does not fit into the minor unit range
+    #[error("amount overflow")]
+    Overflow,
+}
+
+/// ISO 4217 currency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    /// Euro
+    Eur,
+    /// US dollar
+    Usd,
+    /// Pound sterling
+    Gbp,
+    /// Swiss franc
+    Chf,
+    /// Japanese yen
+    Jpy,
+    /// Swedish krona
+    Sek,
+    /// Norwegian krone
+    Nok,
+    /// Danish krone
+    Dkk,
+    /// Polish złoty
+    Pln,
+    /// Czech koruna
+    Czk,
+}
+
+impl Currency {
+    /// All supported currencies
+    pub const ALL: [Self; 10] = [
+        Self::Eur,
+        Self::Usd,
+        Self::Gbp,
+        Self::Chf,
+        Self::Jpy,
+        Self::Sek,
+        Self::Nok,
+        Self::Dkk,
+        Self::Pln,
+        Self::Czk,
+    ];
+
+    /// ISO 4217 alphabetic code
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Eur => "EUR",
+            Self::Usd => "USD",
+            Self::Gbp => "GBP",
+            Self::Chf => "CHF",
+            Self::Jpy => "JPY",
+            Self::Sek => "SEK",
+            Self::Nok => "NOK",
+            Self::Dkk => "DKK",
+            Self::Pln => "PLN",
+            Self::Czk => "CZK",
+        }
+    }
+
+    /// Display symbol, falling back to the code where a symbol is ambiguous
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Eur => "€",
+            Self::Usd => "$",
+            Self::Gbp => "£",
+            Self::Jpy => "¥",
+            Self::Pln => "zł",
+            Self::Czk => "Kč",
+            Self::Chf | Self::Sek | Self::Nok | Self::Dkk => self.code(),
+        }
+    }
+
+    /// Number of digits after the decimal separator (ISO 4217 exponent)
+    #[must_use]
+    pub const fn minor_digits(self) -> u32 {
+        match self {
+            Self::Jpy => 0,
+            _ => 2,
+        }
+    }
+
+    /// Look up a currency by ISO code or symbol (case-insensitive)
+    #[must_use]
+    pub fn from_code_or_symbol(s: &str) -> Option<Self> {
+        let s = s.trim();
+        Self::ALL.into_iter().find(|currency| {
+            currency.code().eq_ignore_ascii_case(s) || currency.symbol().eq_ignore_ascii_case(s)
+        })
+    }
+
+    const fn minor_factor(self) -> i64 {
+        10_i64.pow(self.minor_digits())
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_code_or_symbol(s).ok_or_else(|| MoneyError::UnknownCurrency(s.to_string()))
+    }
+}
+
+/// Number formatting conventions for [`Money::format_locale`]
+#[derive(Debug, Clone, Copy)]
+struct NumberStyle {
+    decimal: char,
+    group: char,
+    symbol_first: bool,
+}
+
+impl NumberStyle {
+    /// Conventional style for a language tag ("de", "en-GB", ...)
+    fn for_locale(locale: &str) -> Option<Self> {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Self {
+                decimal: '.',
+                group: ',',
+                symbol_first: true,
+            }),
+            "de" | "es" | "it" | "nl" => Some(Self {
+                decimal: ',',
+                group: '.',
+                symbol_first: false,
+            }),
+            "fr" | "pl" | "cs" | "sv" | "nb" | "da" => Some(Self {
+                decimal: ',',
+                group: '\u{a0}',
+                symbol_first: false,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Style customary in the currency's home market
+    const fn for_currency(currency: Currency) -> Self {
+        match currency {
+            Currency::Usd | Currency::Gbp | Currency::Jpy => Self {
+                decimal: '.',
+                group: ',',
+                symbol_first: true,
+            },
+            Currency::Chf => Self {
+                decimal: '.',
+                group: '\'',
+                symbol_first: true,
+            },
+            Currency::Eur => Self {
+                decimal: ',',
+                group: '.',
+                symbol_first: false,
+            },
+            Currency::Sek | Currency::Nok | Currency::Dkk | Currency::Pln | Currency::Czk => Self {
+                decimal: ',',
+                group: '\u{a0}',
+                symbol_first: false,
+            },
+        }
+    }
+}
+
+/// An amount of money in minor units of a currency
+///
+/// # Examples
+///
+/// ```
+/// use domain::value_objects::{Currency, Money};
+///
+/// let price = Money::from_minor(1250, Currency::Usd);
+/// assert_eq!(price.to_string(), "$12.50");
+/// assert_eq!(price.format_locale("de"), "12,50 $");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Money {
+    /// Amount in minor units (cents for EUR/USD, yen for JPY)
+    amount_minor: i64,
+    /// Currency of the amount
+    currency: Currency,
+}
+
+impl Money {
+    /// Create an amount from minor units (e.g. `1250` cents = 12.50)
+    #[must_use]
+    pub const fn from_minor(amount_minor: i64, currency: Currency) -> Self {
+        Self {
+            amount_minor,
+            currency,
+        }
+    }
+
+    /// Zero in the given currency
+    #[must_use]
+    pub const fn zero(currency: Currency) -> Self {
+        Self::from_minor(0, currency)
+    }
+
+    /// Amount in minor units
+    #[must_use]
+    pub const fn minor_units(self) -> i64 {
+        self.amount_minor
+    }
+
+    /// Currency of the amount
+    #[must_use]
+    pub const fn currency(self) -> Currency {
+        self.currency
+    }
+
+    /// Whether the amount is below zero
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.amount_minor < 0
+    }
+
+    /// Whether the amount is zero
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.amount_minor == 0
+    }
+
+    /// Add two amounts of the same currency
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurrencyMismatch` for different currencies and `Overflow` if
+    /// the sum does not fit into the minor unit range.
+    pub fn checked_add(self, other: Self) -> Result<Self, MoneyError> {
+        self.ensure_same_currency(other)?;
+        self.amount_minor
+            .checked_add(other.amount_minor)
+            .map(|amount| Self::from_minor(amount, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Subtract an amount of the same currency
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurrencyMismatch` for different currencies and `Overflow` if
+    /// the difference does not fit into the minor unit range.
+    pub fn checked_sub(self, other: Self) -> Result<Self, MoneyError> {
+        self.ensure_same_currency(other)?;
+        self.amount_minor
+            .checked_sub(other.amount_minor)
+            .map(|amount| Self::from_minor(amount, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Format with the number conventions of a language tag ("de", "en-US")
+    ///
+    /// Unknown languages fall back to the currency's own conventions, as
+    /// used by [`Display`](fmt::Display).
+    #[must_use]
+    pub fn format_locale(self, locale: &str) -> String {
+        let style = NumberStyle::for_locale(locale)
+            .unwrap_or_else(|| NumberStyle::for_currency(self.currency));
+        self.format_with(style)
+    }
+
+    fn ensure_same_currency(self, other: Self) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch(self.currency, other.currency))
+        }
+    }
+
+    fn format_with(self, style: NumberStyle) -> String {
+        let factor = self.currency.minor_factor().unsigned_abs();
+        let abs = self.amount_minor.unsigned_abs();
+        let major = group_digits(&(abs / factor).to_string(), style.group);
+
+        let mut number = major;
+        if self.currency.minor_digits() > 0 {
+            let width = self.currency.minor_digits() as usize;
+            number.push(style.decimal);
+            number.push_str(&format!("{:0width$}", abs % factor));
+        }
+
+        let sign = if self.is_negative() { "-" } else { "" };
+        let symbol = self.currency.symbol();
+        let symbol_is_code = symbol == self.currency.code();
+        match (style.symbol_first, symbol_is_code) {
+            (true, false) => format!("{sign}{symbol}{number}"),
+            (true, true) => format!("{sign}{symbol} {number}"),
+            (false, _) => format!("{sign}{number} {symbol}"),
+        }
+    }
+}
+
+/// Insert a group separator every three digits
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_with(NumberStyle::for_currency(self.currency)))
+    }
+}
+
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    /// Parse amounts like "12,50 €", "$12.50", "EUR 1.234,56" or "-3.20 CHF"
+    ///
+    /// Either `.` or `,` may be the decimal separator; a separator followed
+    /// by exactly three digits is treated as a thousands separator unless
+    /// the other separator appears after it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || MoneyError::InvalidAmount(s.to_string());
+
+        let is_number_char =
+            |c: char| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+' | '\'');
+        let currency_text: String = s
+            .chars()
+            .filter(|c| !is_number_char(*c) && !c.is_whitespace())
+            .collect();
+        let number: String = s
+            .chars()
+            .filter(|c| is_number_char(*c) && *c != '\'')
+            .collect();
+
+        if currency_text.is_empty() {
+            return Err(MoneyError::UnknownCurrency(s.to_string()));
+        }
+        let currency = currency_text.parse::<Currency>()?;
+
+        let negative = match (number.matches('-').count(), number.matches('+').count()) {
+            (0, 0 | 1) => false,
+            (1, 0) => true,
+            _ => return Err(invalid()),
+        };
+        if !number.starts_with(['-', '+']) && number.contains(['-', '+']) {
+            return Err(invalid());
+        }
+        let number = number.trim_start_matches(['-', '+']);
+
+        let (integer, fraction) =
+            split_decimal(number, currency.minor_digits()).ok_or_else(invalid)?;
+        if integer.is_empty() || !integer.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let width = currency.minor_digits() as usize;
+        let fraction = format!("{fraction:0<width$}");
+        let major: i64 = integer.parse().map_err(|_| MoneyError::Overflow)?;
+        let minor: i64 = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse().map_err(|_| invalid())?
+        };
+
+        let amount = major
+            .checked_mul(currency.minor_factor())
+            .and_then(|major| major.checked_add(minor))
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::from_minor(
+            if negative { -amount } else { amount },
+            currency,
+        ))
+    }
+}
+
+/// Split a number into integer digits (separators removed) and fraction
+/// digits, deciding which separator is the decimal one
+fn split_decimal(number: &str, minor_digits: u32) -> Option<(String, &str)> {
+    let strip = |part: &str| {
+        part.chars()
+            .filter(|c| !matches!(c, '.' | ','))
+            .collect::<String>()
+    };
+
+    let Some(last) = number.rfind(['.', ',']) else {
+        return Some((number.to_string(), ""));
+    };
+    let (head, tail) = (&number[..last], &number[last + 1..]);
+    let separator = &number[last..=last];
+    let mixed = head.contains(['.', ',']) && !head.contains(separator);
+    let repeated = head.contains(separator);
+
+    let is_decimal = mixed || !(repeated || tail.len() == 3);
+
+    if is_decimal {
+        if tail.is_empty() || tail.len() > minor_digits as usize {
+            return None;
+        }
+        Some((strip(head), tail))
+    } else {
+        if tail.len() != 3 {
+            return None;
+        }
+        Some((strip(number), ""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eur(minor: i64) -> Money {
+        Money::from_minor(minor, Currency::Eur)
+    }
+
+    #[test]
+    fn parse_european_format() {
+        assert_eq!("12,50 €".parse::<Money>().unwrap(), eur(1250));
+        assert_eq!("1.234,56 €".parse::<Money>().unwrap(), eur(123_456));
+        assert_eq!("EUR 3,2".parse::<Money>().unwrap(), eur(320));
+        assert_eq!("5 €".parse::<Money>().unwrap(), eur(500));
+    }
+
+    #[test]
+    fn parse_symbol_first_format() {
+        let usd = "$12.50".parse::<Money>().unwrap();
+        assert_eq!(usd, Money::from_minor(1250, Currency::Usd));
+
+        let gbp = "£1,234.5".parse::<Money>().unwrap();
+        assert_eq!(gbp, Money::from_minor(123_450, Currency::Gbp));
+    }
+
+    #[test]
+    fn parse_thousands_separator_only() {
+        assert_eq!("1.234 €".parse::<Money>().unwrap(), eur(123_400));
+        assert_eq!("12.505 €".parse::<Money>().unwrap(), eur(1_250_500));
+        assert_eq!(
+            "$1,000,000".parse::<Money>().unwrap().minor_units(),
+            100_000_000
+        );
+    }
+
+    #[test]
+    fn parse_codes_and_negative_amounts() {
+        let chf = "CHF -1'234.50".parse::<Money>().unwrap();
+        assert_eq!(chf, Money::from_minor(-123_450, Currency::Chf));
+
+        let sek = "-99,90 sek".parse::<Money>().unwrap();
+        assert_eq!(sek, Money::from_minor(-9990, Currency::Sek));
+    }
+
+    #[test]
+    fn parse_currency_without_minor_units() {
+        let jpy = "¥1,500".parse::<Money>().unwrap();
+        assert_eq!(jpy, Money::from_minor(1500, Currency::Jpy));
+        assert!("¥15.50".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn parse_rejects_invalid_input() {
+        assert!(matches!(
+            "12.50".parse::<Money>(),
+            Err(MoneyError::UnknownCurrency(_))
+        ));
+        assert!(matches!(
+            "12.50 XYZ".parse::<Money>(),
+            Err(MoneyError::UnknownCurrency(_))
+        ));
+        assert!("12,5050 €".parse::<Money>().is_err());
+        assert!("€".parse::<Money>().is_err());
+        assert!("1-2 €".parse::<Money>().is_err());
+        assert!("12, €".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn parse_overflow() {
+        assert_eq!(
+            "$99999999999999999999".parse::<Money>(),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(eur(1250).checked_add(eur(250)).unwrap(), eur(1500));
+        assert_eq!(eur(250).checked_sub(eur(1250)).unwrap(), eur(-1000));
+        assert!(eur(-1000).is_negative());
+        assert!(Money::zero(Currency::Eur).is_zero());
+    }
+
+    #[test]
+    fn checked_arithmetic_errors() {
+        let usd = Money::from_minor(100, Currency::Usd);
+        assert_eq!(
+            eur(100).checked_add(usd),
+            Err(MoneyError::CurrencyMismatch(Currency::Eur, Currency::Usd))
+        );
+        assert_eq!(eur(i64::MAX).checked_add(eur(1)), Err(MoneyError::Overflow));
+        assert_eq!(eur(i64::MIN).checked_sub(eur(1)), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn display_uses_currency_conventions() {
+        assert_eq!(eur(123_456).to_string(), "1.234,56 €");
+        assert_eq!(eur(-5).to_string(), "-0,05 €");
+        assert_eq!(Money::from_minor(1250, Currency::Usd).to_string(), "$12.50");
+        assert_eq!(
+            Money::from_minor(123_450, Currency::Chf).to_string(),
+            "CHF 1'234.50"
+        );
+        assert_eq!(Money::from_minor(1500, Currency::Jpy).to_string(), "¥1,500");
+        assert_eq!(
+            Money::from_minor(9990, Currency::Pln).to_string(),
+            "99,90 zł"
+        );
+    }
+
+    #[test]
+    fn format_locale() {
+        let amount = eur(123_456);
+        assert_eq!(amount.format_locale("en-US"), "€1,234.56");
+        assert_eq!(amount.format_locale("de"), "1.234,56 €");
+        assert_eq!(amount.format_locale("fr_FR"), "1\u{a0}234,56 €");
+        assert_eq!(amount.format_locale("xx"), amount.to_string());
+        assert_eq!(
+            Money::from_minor(1000, Currency::Sek).format_locale("en"),
+            "SEK 10.00"
+        );
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for money in [
+            eur(123_456),
+            Money::from_minor(-1250, Currency::Usd),
+            Money::from_minor(123_450, Currency::Chf),
+            Money::from_minor(1500, Currency::Jpy),
+            Money::from_minor(1_234_567, Currency::Czk),
+        ] {
+            assert_eq!(money.to_string().parse::<Money>().unwrap(), money);
+        }
+    }
+
+    #[test]
+    fn serialization() {
+        let json = serde_json::to_string(&eur(1250)).unwrap();
+        assert_eq!(json, r#"{"amount_minor":1250,"currency":"EUR"}"#);
+        let back: Money = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, eur(1250));
+    }
+}
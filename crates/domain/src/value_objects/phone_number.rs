@@ -6,6 +6,43 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::DomainError;
 
+/// Characters users commonly put between digit groups
+const SEPARATORS: [char; 8] = [' ', '\u{a0}', '-', '.', '/', '(', ')', '\t'];
+
+/// Allowed length of the national significant number per country code
+///
+/// Country codes are prefix-free, so at most one entry matches a number.
+/// Countries not listed fall back to the generic E.164 length check.
+const COUNTRY_LENGTHS: &[(&str, usize, usize)] = &[
+    ("1", 10, 10),
+    ("7", 10, 10),
+    ("30", 10, 10),
+    ("31", 9, 9),
+    ("32", 8, 9),
+    ("33", 9, 9),
+    ("34", 9, 9),
+    ("36", 8, 9),
+    ("39", 6, 11),
+    ("40", 9, 9),
+    ("41", 9, 9),
+    ("43", 4, 13),
+    ("44", 9, 10),
+    ("45", 8, 8),
+    ("46", 7, 13),
+    ("47", 8, 8),
+    ("48", 9, 9),
+    ("49", 6, 13),
+    ("351", 9, 9),
+    ("352", 4, 11),
+    ("353", 7, 9),
+    ("358", 5, 12),
+    ("420", 9, 9),
+    ("421", 9, 9),
+];
+
+/// Country code whose national numbers keep their leading zero (Italy)
+const LEADING_ZERO_COUNTRY: &str = "39";
+
 /// A validated phone number in E.164 format (e.g., +491234567890)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -45,6 +82,71 @@ impl PhoneNumber {
         Ok(Self { value })
     }
 
+    /// Normalize user input to strict E.164 format
+    ///
+    /// More lenient than [`PhoneNumber::new`] about the input and stricter
+    /// about the result:
+    /// - Strips spaces, dashes, dots, slashes and parentheses
+    /// - Drops a trunk prefix written as `(0)`, e.g. `+49 (0)151 …`
+    /// - Converts the international `00` prefix to `+`
+    /// - Checks the number length against the country code
+    ///
+    /// Two inputs for the same number always normalize to the same value,
+    /// so normalized numbers can be compared directly.
+    ///
+    /// # Errors
+    /// Returns [`DomainError::InvalidPhoneNumber`] if the input has no
+    /// international prefix, contains other characters, keeps the trunk
+    /// prefix `0` after the country code, or has an invalid length.
+    pub fn normalize(input: &str) -> Result<Self, DomainError> {
+        let compact = input.trim().replace("(0)", "").replace(SEPARATORS, "");
+        let digits = if let Some(rest) = compact.strip_prefix('+') {
+            rest
+        } else if let Some(rest) = compact.strip_prefix("00") {
+            rest
+        } else {
+            return Err(DomainError::InvalidPhoneNumber(
+                "Phone number must start with + or 00".to_string(),
+            ));
+        };
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(DomainError::InvalidPhoneNumber(
+                "Phone number must contain only digits after the prefix".to_string(),
+            ));
+        }
+
+        match COUNTRY_LENGTHS
+            .iter()
+            .find(|(code, _, _)| digits.starts_with(code))
+        {
+            Some(&(code, min, max)) => {
+                let national = &digits[code.len()..];
+                if national.starts_with('0') && code != LEADING_ZERO_COUNTRY {
+                    return Err(DomainError::InvalidPhoneNumber(format!(
+                        "Phone number must not keep the trunk prefix 0 after +{code}"
+                    )));
+                }
+                if !(min..=max).contains(&national.len()) {
+                    return Err(DomainError::InvalidPhoneNumber(format!(
+                        "Phone numbers for +{code} must have {min}-{max} digits after the country code"
+                    )));
+                }
+            },
+            None => {
+                if digits.len() < 7 || digits.len() > 15 {
+                    return Err(DomainError::InvalidPhoneNumber(
+                        "Phone number must have 7-15 digits".to_string(),
+                    ));
+                }
+            },
+        }
+
+        Ok(Self {
+            value: format!("+{digits}"),
+        })
+    }
+
     /// Get the phone number as a string slice (E.164 format)
     pub fn as_str(&self) -> &str {
         &self.value
@@ -153,6 +255,68 @@ mod tests {
         assert_eq!(phone.as_str(), "+491234567890");
     }
 
+    #[test]
+    fn normalize_german_mobile_formats() {
+        let inputs = [
+            "+4915123456789",
+            "+49 151 23456789",
+            "+49 (0)151 23456789",
+            "+49-151-234-567-89",
+            "0049 151 23456789",
+            "004915123456789",
+            "+49 (151) 234 567 89",
+            "+49/151/23456789",
+            " +49.151.2345.6789 ",
+        ];
+        for input in inputs {
+            let phone = PhoneNumber::normalize(input).unwrap();
+            assert_eq!(phone.as_str(), "+4915123456789", "input: {input}");
+        }
+    }
+
+    #[test]
+    fn normalize_short_german_mobile() {
+        let phone = PhoneNumber::normalize("0049 160 1234567").unwrap();
+        assert_eq!(phone.as_str(), "+491601234567");
+    }
+
+    #[test]
+    fn normalize_rejects_national_format() {
+        assert!(PhoneNumber::normalize("0151 23456789").is_err());
+    }
+
+    #[test]
+    fn normalize_rejects_trunk_zero_after_country_code() {
+        assert!(PhoneNumber::normalize("+49 0151 23456789").is_err());
+        assert!(PhoneNumber::normalize("0049 0151 23456789").is_err());
+    }
+
+    #[test]
+    fn normalize_keeps_italian_leading_zero() {
+        let phone = PhoneNumber::normalize("+39 06 1234 5678").unwrap();
+        assert_eq!(phone.as_str(), "+390612345678");
+    }
+
+    #[test]
+    fn normalize_validates_length_per_country() {
+        assert!(PhoneNumber::normalize("+49 151").is_err());
+        assert!(PhoneNumber::normalize("+49 151 2345 6789 0123").is_err());
+        assert!(PhoneNumber::normalize("+1 234 567 890").is_err());
+        assert!(PhoneNumber::normalize("+1 234 567 8900").is_ok());
+    }
+
+    #[test]
+    fn normalize_unknown_country_uses_generic_length() {
+        assert!(PhoneNumber::normalize("+86 138 0013 8000").is_ok());
+        assert!(PhoneNumber::normalize("+86 1380").is_err());
+    }
+
+    #[test]
+    fn normalize_rejects_letters() {
+        assert!(PhoneNumber::normalize("+49 151 CALLME").is_err());
+        assert!(PhoneNumber::normalize("+").is_err());
+    }
+
     #[test]
     fn too_long_number_is_rejected() {
         assert!(PhoneNumber::new("+12345678901234567890").is_err());
@@ -255,6 +419,13 @@ mod proptest_tests {
             prop_assert!(result.is_err());
         }
 
+        #[test]
+        fn normalize_is_idempotent(number in "[1-9][0-9]{9,10}") {
+            let phone = PhoneNumber::normalize(&format!("0049 {number}")).unwrap();
+            let again = PhoneNumber::normalize(phone.as_str()).unwrap();
+            prop_assert_eq!(phone, again);
+        }
+
         #[test]
         fn german_number_detection(number in "[0-9]{8,11}") {
             let german_str = format!("+49{number}");
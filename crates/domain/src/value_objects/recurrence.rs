@@ -0,0 +1,294 @@
+//! Recurrence value object
+//!
+//! Describes how a reminder repeats. Accepts a simple form (`daily`,
+//! `weekly`, `weekly:Mon,Thu`) or the matching RRULE subset
+//! (`FREQ=DAILY`, `FREQ=WEEKLY;BYDAY=MO,TH`).
+//!
+//! # Examples
+//!
+//! ```
+//! use domain::value_objects::Recurrence;
+//!
+//! let weekly: Recurrence = "FREQ=WEEKLY;BYDAY=MO".parse().expect("valid rule");
+//! assert_eq!(weekly.to_string(), "weekly:Mon");
+//!
+//! assert!("hourly".parse::<Recurrence>().is_err());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::Timezone;
+
+/// Error returned when a recurrence rule cannot be parsed
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("invalid recurrence: '{0}' (expected daily, weekly, weekly:Mon,... or FREQ=DAILY/WEEKLY)")]
+pub struct InvalidRecurrence(String);
+
+/// How often a reminder repeats
+///
+/// Occurrences keep the wall-clock time of the previous occurrence in the
+/// timezone they are computed for (UTC unless given).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Recurrence {
+    /// Every day
+    Daily,
+    /// Every week on the given weekdays
+    ///
+    /// An empty list repeats on the weekday of the previous occurrence.
+    Weekly(Vec<Weekday>),
+}
+
+impl Recurrence {
+    /// First occurrence after both `previous` and `now`
+    ///
+    /// Missed occurrences (e.g. a reminder acknowledged days late) are skipped.
+    #[must_use]
+    pub fn next_after(&self, previous: DateTime<Utc>, now: DateTime<Utc>) -> DateTime<Utc> {
+        self.next_after_in(previous, now, &Timezone::utc())
+    }
+
+    /// First occurrence after both `previous` and `now`, in local time
+    ///
+    /// The local time of day is kept across DST changes, so a 09:00
+    /// reminder stays at 09:00 in `timezone` all year.
+    #[must_use]
+    pub fn next_after_in(
+        &self,
+        previous: DateTime<Utc>,
+        now: DateTime<Utc>,
+        timezone: &Timezone,
+    ) -> DateTime<Utc> {
+        let tz = timezone.as_chrono_tz();
+        let local_previous = previous.with_timezone(&tz).naive_local();
+        let today = now.with_timezone(&tz).date_naive();
+
+        // Jump over whole missed days first, keeping the time of day
+        let mut date = local_previous.date().max(today - Duration::days(1));
+
+        loop {
+            date += Duration::days(1);
+            let candidate = timezone.to_utc(date.and_time(local_previous.time()));
+            if candidate > now && self.matches(date.weekday(), local_previous.weekday()) {
+                return candidate;
+            }
+        }
+    }
+
+    fn matches(&self, day: Weekday, anchor: Weekday) -> bool {
+        match self {
+            Self::Daily => true,
+            Self::Weekly(days) if days.is_empty() => day == anchor,
+            Self::Weekly(days) => days.contains(&day),
+        }
+    }
+
+    fn parse_rrule(rule: &str) -> Option<Self> {
+        let mut freq = None;
+        let mut days = Vec::new();
+
+        for part in rule.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=')?;
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => freq = Some(value.trim().to_ascii_uppercase()),
+                "BYDAY" => {
+                    for code in value.split(',') {
+                        days.push(weekday_from_rrule(code.trim())?);
+                    }
+                },
+                "INTERVAL" if value.trim() == "1" => {},
+                _ => return None,
+            }
+        }
+
+        match freq?.as_str() {
+            "DAILY" if days.is_empty() => Some(Self::Daily),
+            "WEEKLY" => Some(Self::Weekly(days)),
+            _ => None,
+        }
+    }
+}
+
+fn weekday_from_rrule(code: &str) -> Option<Weekday> {
+    match code.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = InvalidRecurrence;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let lower = trimmed.to_lowercase();
+        let rule = lower.strip_prefix("rrule:").unwrap_or(&lower);
+
+        let parsed = if rule.starts_with("freq=") {
+            Self::parse_rrule(rule)
+        } else {
+            match rule.split_once(':') {
+                None if rule == "daily" => Some(Self::Daily),
+                None if rule == "weekly" => Some(Self::Weekly(Vec::new())),
+                Some(("weekly", days)) => days
+                    .split(',')
+                    .map(|d| d.trim().parse::<Weekday>().ok())
+                    .collect::<Option<Vec<_>>>()
+                    .map(Self::Weekly),
+                _ => None,
+            }
+        };
+
+        parsed.ok_or_else(|| InvalidRecurrence(trimmed.to_string()))
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Daily => write!(f, "daily"),
+            Self::Weekly(days) if days.is_empty() => write!(f, "weekly"),
+            Self::Weekly(days) => {
+                let days: Vec<String> = days.iter().map(ToString::to_string).collect();
+                write!(f, "weekly:{}", days.join(","))
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = InvalidRecurrence;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Recurrence> for String {
+    fn from(value: Recurrence) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_simple_forms() {
+        assert_eq!("daily".parse(), Ok(Recurrence::Daily));
+        assert_eq!("Weekly".parse(), Ok(Recurrence::Weekly(Vec::new())));
+        assert_eq!(
+            "weekly:Mon,thu".parse(),
+            Ok(Recurrence::Weekly(vec![Weekday::Mon, Weekday::Thu]))
+        );
+    }
+
+    #[test]
+    fn parses_rrule_subset() {
+        assert_eq!("FREQ=DAILY".parse(), Ok(Recurrence::Daily));
+        assert_eq!(
+            "RRULE:FREQ=WEEKLY;BYDAY=MO,FR;INTERVAL=1".parse(),
+            Ok(Recurrence::Weekly(vec![Weekday::Mon, Weekday::Fri]))
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_rules() {
+        assert!("hourly".parse::<Recurrence>().is_err());
+        assert!("weekly:Someday".parse::<Recurrence>().is_err());
+        assert!("FREQ=MONTHLY".parse::<Recurrence>().is_err());
+        assert!("FREQ=WEEKLY;INTERVAL=2".parse::<Recurrence>().is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for rule in ["daily", "weekly", "weekly:Mon,Wed"] {
+            let parsed: Recurrence = rule.parse().unwrap();
+            assert_eq!(parsed.to_string(), rule);
+        }
+    }
+
+    #[test]
+    fn daily_next_is_following_day() {
+        let previous = at(2026, 2, 2, 9);
+        let next = Recurrence::Daily.next_after(previous, at(2026, 2, 2, 10));
+        assert_eq!(next, at(2026, 2, 3, 9));
+    }
+
+    #[test]
+    fn weekly_next_keeps_weekday() {
+        // 2026-02-02 is a Monday
+        let previous = at(2026, 2, 2, 9);
+        let next = Recurrence::Weekly(Vec::new()).next_after(previous, at(2026, 2, 2, 9));
+        assert_eq!(next, at(2026, 2, 9, 9));
+    }
+
+    #[test]
+    fn weekly_with_days_picks_next_listed_day() {
+        let rule = Recurrence::Weekly(vec![Weekday::Mon, Weekday::Thu]);
+        let next = rule.next_after(at(2026, 2, 2, 9), at(2026, 2, 2, 9));
+        assert_eq!(next, at(2026, 2, 5, 9));
+    }
+
+    #[test]
+    fn skips_missed_occurrences() {
+        let previous = at(2026, 2, 2, 9);
+        let next = Recurrence::Daily.next_after(previous, at(2026, 2, 10, 12));
+        assert_eq!(next, at(2026, 2, 11, 9));
+
+        let next = Recurrence::Weekly(Vec::new()).next_after(previous, at(2026, 2, 20, 12));
+        assert_eq!(next, at(2026, 2, 23, 9));
+    }
+
+    #[test]
+    fn daily_keeps_local_time_across_dst() {
+        let berlin = Timezone::berlin();
+        // 09:00 CET on Saturday before the spring switch (2026-03-29)
+        let previous = at(2026, 3, 28, 8);
+        let next = Recurrence::Daily.next_after_in(previous, previous, &berlin);
+        let next = Recurrence::Daily.next_after_in(next, next, &berlin);
+        // 09:00 CEST on Monday
+        assert_eq!(next, at(2026, 3, 30, 7));
+
+        // And back in autumn (switch on 2026-10-25)
+        let previous = at(2026, 10, 24, 7);
+        let next = Recurrence::Daily.next_after_in(previous, previous, &berlin);
+        assert_eq!(next, at(2026, 10, 25, 8));
+    }
+
+    #[test]
+    fn weekly_uses_local_weekday() {
+        // Monday 00:30 in Berlin is still Sunday in UTC
+        let berlin = Timezone::berlin();
+        let previous = at(2026, 2, 1, 23) + Duration::minutes(30);
+        let next =
+            Recurrence::Weekly(vec![Weekday::Mon]).next_after_in(previous, previous, &berlin);
+        assert_eq!(next, previous + Duration::weeks(1));
+    }
+
+    #[test]
+    fn serializes_as_string() {
+        let json = serde_json::to_string(&Recurrence::Weekly(vec![Weekday::Mon])).unwrap();
+        assert_eq!(json, "\"weekly:Mon\"");
+        let parsed: Recurrence = serde_json::from_str("\"daily\"").unwrap();
+        assert_eq!(parsed, Recurrence::Daily);
+        assert!(serde_json::from_str::<Recurrence>("\"yearly\"").is_err());
+    }
+}
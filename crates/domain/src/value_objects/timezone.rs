@@ -19,6 +19,7 @@
 //! assert!(Timezone::try_new("Invalid/Timezone").is_err());
 //! ```
 
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -102,6 +103,37 @@ impl Timezone {
         chrono_tz::Tz::from_str(&self.0).expect("timezone was validated on construction")
     }
 
+    /// Interpret a wall-clock time in this timezone and convert it to UTC
+    ///
+    /// Times that occur twice when clocks fall back resolve to the first
+    /// occurrence. Times skipped when clocks spring forward are moved forward
+    /// by one hour (02:30 becomes 03:30 on the switch day).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use domain::value_objects::Timezone;
+    ///
+    /// let local = NaiveDate::from_ymd_opt(2026, 7, 1)
+    ///     .unwrap()
+    ///     .and_hms_opt(9, 0, 0)
+    ///     .unwrap();
+    /// let utc = Timezone::berlin().to_utc(local);
+    /// assert_eq!(utc.to_rfc3339(), "2026-07-01T07:00:00+00:00");
+    /// ```
+    #[must_use]
+    pub fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        let tz = self.as_chrono_tz();
+        tz.from_local_datetime(&local)
+            .earliest()
+            .or_else(|| {
+                tz.from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })
+            .map_or_else(|| local.and_utc(), |dt| dt.with_timezone(&Utc))
+    }
+
     /// Check if this is a UTC timezone
     ///
     /// # Examples
@@ -211,6 +243,47 @@ mod tests {
         assert!(!Timezone::berlin().is_utc());
     }
 
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|date| date.and_hms_opt(h, min, 0))
+            .expect("valid local time")
+    }
+
+    #[test]
+    fn test_to_utc_follows_dst() {
+        let tz = Timezone::berlin();
+        // CET (+01:00) in winter, CEST (+02:00) in summer
+        assert_eq!(
+            tz.to_utc(local(2026, 3, 28, 9, 0)).to_rfc3339(),
+            "2026-03-28T08:00:00+00:00"
+        );
+        assert_eq!(
+            tz.to_utc(local(2026, 3, 30, 9, 0)).to_rfc3339(),
+            "2026-03-30T07:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_to_utc_dst_gap_and_overlap() {
+        let tz = Timezone::berlin();
+        // 02:30 does not exist on 2026-03-29 and moves to 03:30 CEST
+        assert_eq!(
+            tz.to_utc(local(2026, 3, 29, 2, 30)).to_rfc3339(),
+            "2026-03-29T01:30:00+00:00"
+        );
+        // 02:30 happens twice on 2026-10-25; the first one (CEST) wins
+        assert_eq!(
+            tz.to_utc(local(2026, 10, 25, 2, 30)).to_rfc3339(),
+            "2026-10-25T00:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_to_utc_in_utc_is_identity() {
+        let naive = local(2026, 6, 1, 12, 0);
+        assert_eq!(Timezone::utc().to_utc(naive), naive.and_utc());
+    }
+
     #[test]
     fn test_timezone_display() {
         let tz = Timezone::berlin();
@@ -0,0 +1,186 @@
+//! Bulkhead pattern for external service calls
+//!
+//! Caps the number of concurrent in-flight calls to a single integration.
+//! When all slots are taken, further calls are rejected immediately with
+//! [`BulkheadFull`] instead of queueing, so a slow upstream cannot pile up
+//! waiting requests and exhaust the server.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use infrastructure::adapters::Bulkhead;
+//!
+//! let bulkhead = Bulkhead::new("transit", 4);
+//! let result = bulkhead.call(|| async {
+//!     external_service.call().await
+//! }).await;
+//! ```
+
+use std::{future::Future, sync::Arc};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+/// Error returned when a bulkhead has no free slot
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Bulkhead full for service '{service_name}': {max_concurrent} calls already in flight")]
+pub struct BulkheadFull {
+    /// Name of the service
+    pub service_name: String,
+    /// Configured concurrency limit
+    pub max_concurrent: usize,
+}
+
+/// A slot in a bulkhead, released when dropped
+#[derive(Debug)]
+pub struct BulkheadPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Concurrency limiter for a single integration
+///
+/// Cloning is cheap; clones share the same slots.
+#[derive(Debug, Clone)]
+pub struct Bulkhead {
+    name: String,
+    max_concurrent: usize,
+    slots: Arc<Semaphore>,
+}
+
+impl Bulkhead {
+    /// Create a bulkhead allowing `max_concurrent` calls at once
+    ///
+    /// A limit of zero is raised to one.
+    #[must_use]
+    pub fn new(name: impl Into<String>, max_concurrent: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            name: name.into(),
+            max_concurrent,
+            slots: Arc::new(Semaphore::new(max_concurrent)),
+        }
+    }
+
+    /// Get the service name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the configured concurrency limit
+    #[must_use]
+    pub const fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Number of calls currently in flight
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.max_concurrent - self.slots.available_permits()
+    }
+
+    /// Take a slot without waiting
+    ///
+    /// # Errors
+    ///
+    /// Returns `BulkheadFull` if all slots are taken.
+    pub fn try_acquire(&self) -> Result<BulkheadPermit, BulkheadFull> {
+        Arc::clone(&self.slots)
+            .try_acquire_owned()
+            .map(|permit| BulkheadPermit { _permit: permit })
+            .map_err(|_| {
+                warn!(
+                    service = %self.name,
+                    max_concurrent = self.max_concurrent,
+                    "Bulkhead full, rejecting call"
+                );
+                BulkheadFull {
+                    service_name: self.name.clone(),
+                    max_concurrent: self.max_concurrent,
+                }
+            })
+    }
+
+    /// Execute an operation while holding a slot
+    ///
+    /// # Errors
+    ///
+    /// Returns `BulkheadFull` without running the operation if all slots
+    /// are taken.
+    pub async fn call<F, Fut, T>(&self, f: F) -> Result<T, BulkheadFull>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let _permit = self.try_acquire()?;
+        Ok(f().await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn zero_limit_is_raised_to_one() {
+        let bulkhead = Bulkhead::new("test", 0);
+        assert_eq!(bulkhead.max_concurrent(), 1);
+        assert!(bulkhead.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn permit_is_released_on_drop() {
+        let bulkhead = Bulkhead::new("test", 1);
+        let permit = bulkhead.try_acquire().unwrap();
+        assert_eq!(bulkhead.in_flight(), 1);
+        assert!(bulkhead.try_acquire().is_err());
+
+        drop(permit);
+        assert_eq!(bulkhead.in_flight(), 0);
+        assert!(bulkhead.try_acquire().is_ok());
+    }
+
+    #[tokio::test]
+    async fn saturated_bulkhead_rejects_excess_calls_fast() {
+        let bulkhead = Bulkhead::new("transit", 2);
+        // Calls in flight block until the gate is opened
+        let gate = Arc::new(Semaphore::new(0));
+
+        let mut in_flight = Vec::new();
+        for _ in 0..2 {
+            let bulkhead = bulkhead.clone();
+            let gate = Arc::clone(&gate);
+            in_flight.push(tokio::spawn(async move {
+                bulkhead
+                    .call(|| async move { gate.acquire().await.map(|_| ()).is_ok() })
+                    .await
+            }));
+        }
+        while bulkhead.in_flight() < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        let rejected = tokio::time::timeout(
+            Duration::from_millis(50),
+            bulkhead.call(|| async { "should not run" }),
+        )
+        .await
+        .expect("rejection must not wait for a slot");
+        assert_eq!(
+            rejected,
+            Err(BulkheadFull {
+                service_name: "transit".to_string(),
+                max_concurrent: 2,
+            })
+        );
+
+        gate.add_permits(2);
+        for call in in_flight {
+            assert_eq!(call.await.unwrap(), Ok(true));
+        }
+        assert_eq!(bulkhead.in_flight(), 0);
+        assert_eq!(bulkhead.call(|| async { 42 }).await, Ok(42));
+    }
+}
@@ -16,6 +16,11 @@ use tracing::{debug, info, instrument};
 
 use crate::cache::llm_cache_key;
 
+/// Temperature used in cache keys for requests without an explicit one
+///
+/// Matches the default sampling temperature of the inference configuration.
+const DEFAULT_TEMPERATURE: f32 = 0.7;
+
 /// Cached response stored in cache
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CachedResponse {
@@ -164,7 +169,7 @@ impl<I: InferencePort, C: CachePort> InferencePort for CachedInferenceAdapter<I,
         let cache_key = llm_cache_key(
             message,
             self.inner.current_model().as_str(),
-            0.7, // default temperature
+            DEFAULT_TEMPERATURE,
         );
 
         // Check cache first
@@ -191,18 +196,24 @@ impl<I: InferencePort, C: CachePort> InferencePort for CachedInferenceAdapter<I,
         conversation: &Conversation,
     ) -> Result<InferenceResult, ApplicationError> {
         // Create cache key from conversation content hash
-        // Include all messages to capture full context
+        // Include the summary and all messages to capture full context
         let context_str = conversation
-            .messages
+            .summary
             .iter()
-            .map(|m| format!("{:?}:{}", m.role, m.content))
+            .map(|summary| format!("Summary:{summary}"))
+            .chain(
+                conversation
+                    .messages
+                    .iter()
+                    .map(|m| format!("{:?}:{}", m.role, m.content)),
+            )
             .collect::<Vec<_>>()
             .join("|");
 
         let cache_key = llm_cache_key(
             &context_str,
             self.inner.current_model().as_str(),
-            0.7, // default temperature
+            DEFAULT_TEMPERATURE,
         );
 
         // Check cache first
@@ -231,7 +242,11 @@ impl<I: InferencePort, C: CachePort> InferencePort for CachedInferenceAdapter<I,
     ) -> Result<InferenceResult, ApplicationError> {
         // Include system prompt in cache key
         let combined = format!("{system_prompt}|{message}");
-        let cache_key = llm_cache_key(&combined, self.inner.current_model().as_str(), 0.7);
+        let cache_key = llm_cache_key(
+            &combined,
+            self.inner.current_model().as_str(),
+            DEFAULT_TEMPERATURE,
+        );
 
         // Check cache first
         if let Some(cached) = self.get_cached(&cache_key).await {
@@ -255,6 +270,40 @@ impl<I: InferencePort, C: CachePort> InferencePort for CachedInferenceAdapter<I,
         Ok(result)
     }
 
+    #[instrument(skip(self, system_prompt, message, schema), fields(cached = tracing::field::Empty))]
+    async fn generate_structured(
+        &self,
+        system_prompt: &str,
+        message: &str,
+        schema: &serde_json::Value,
+    ) -> Result<InferenceResult, ApplicationError> {
+        // Include system prompt and schema in cache key
+        let combined = format!("{system_prompt}|{message}|{schema}");
+        let cache_key = llm_cache_key(
+            &combined,
+            self.inner.current_model().as_str(),
+            DEFAULT_TEMPERATURE,
+        );
+
+        if let Some(cached) = self.get_cached(&cache_key).await {
+            tracing::Span::current().record("cached", true);
+            info!("Returning cached structured response");
+            return Ok(cached.to_result(true));
+        }
+
+        tracing::Span::current().record("cached", false);
+
+        let result = self
+            .inner
+            .generate_structured(system_prompt, message, schema)
+            .await?;
+
+        let is_stable = is_stable_system_prompt(system_prompt);
+        self.cache_response(&cache_key, &result, is_stable).await;
+
+        Ok(result)
+    }
+
     async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError> {
         // Streaming responses are not cached (would require buffering full response)
         // The non-streaming equivalent should be used for cacheable queries
@@ -272,10 +321,34 @@ impl<I: InferencePort, C: CachePort> InferencePort for CachedInferenceAdapter<I,
             .await
     }
 
+    async fn generate_stream_with_context(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<InferenceStream, ApplicationError> {
+        // Streaming responses are not cached
+        self.inner.generate_stream_with_context(conversation).await
+    }
+
+    async fn generate_stream_with_context_and_model(
+        &self,
+        conversation: &Conversation,
+        model: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        // Streaming responses are not cached
+        self.inner
+            .generate_stream_with_context_and_model(conversation, model)
+            .await
+    }
+
     async fn is_healthy(&self) -> bool {
         self.inner.is_healthy().await
     }
 
+    /// Bypasses the cache so the model is actually loaded
+    async fn warmup(&self) -> Result<(), ApplicationError> {
+        self.inner.warmup().await
+    }
+
     fn current_model(&self) -> String {
         self.inner.current_model()
     }
@@ -0,0 +1,311 @@
+//! Caching secret store - Decorator that adds a TTL cache to any `SecretStorePort`
+//!
+//! Caches `get_json` results so repeated secret resolution (e.g. on SIGHUP
+//! config reload) does not hit Vault every time. Cached values are held as
+//! `SecretString` and zeroized when evicted.
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use application::{error::ApplicationError, ports::SecretStorePort};
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, SecretString};
+use tokio::sync::RwLock;
+use tracing::{debug, instrument};
+
+/// Cached JSON secret, serialized so it can be zeroized on eviction
+struct CachedSecret {
+    value: SecretString,
+    expires_at: Instant,
+}
+
+impl CachedSecret {
+    fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+/// Caching decorator for secret stores
+///
+/// Only `get_json` is cached; `get_secret`, `exists` and `is_healthy` are
+/// passed through. Errors are never cached.
+pub struct CachingSecretStore {
+    inner: Arc<dyn SecretStorePort>,
+    ttl: Duration,
+    cache: RwLock<HashMap<String, CachedSecret>>,
+}
+
+impl std::fmt::Debug for CachingSecretStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachingSecretStore")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CachingSecretStore {
+    /// Wrap a secret store with a cache of the given TTL
+    pub fn new(inner: Arc<dyn SecretStorePort>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the configured TTL
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Drop the cached value for a path
+    pub async fn invalidate(&self, path: &str) {
+        if self.cache.write().await.remove(path).is_some() {
+            debug!(path = %path, "Invalidated cached secret");
+        }
+    }
+
+    /// Re-read a secret from the inner store, replacing any cached value
+    ///
+    /// Use after rotating a secret so the new value is served immediately
+    /// instead of after the TTL. On error the stale entry stays evicted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the inner store's error if the secret cannot be read.
+    #[instrument(skip(self))]
+    pub async fn refresh(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+        self.invalidate(path).await;
+        let value = self.inner.get_json(path).await?;
+        self.store_json(path, &value).await;
+        debug!("Refreshed cached secret");
+        Ok(value)
+    }
+
+    /// Drop all cached values
+    pub async fn invalidate_all(&self) {
+        let mut cache = self.cache.write().await;
+        debug!(count = cache.len(), "Invalidating all cached secrets");
+        cache.clear();
+    }
+
+    /// Number of cached entries, including expired ones not yet evicted
+    pub async fn len(&self) -> usize {
+        self.cache.read().await.len()
+    }
+
+    /// Whether the cache is empty
+    pub async fn is_empty(&self) -> bool {
+        self.cache.read().await.is_empty()
+    }
+
+    async fn cached_json(&self, path: &str) -> Option<serde_json::Value> {
+        let cache = self.cache.read().await;
+        let entry = cache.get(path).filter(|entry| !entry.is_expired())?;
+        serde_json::from_str(entry.value.expose_secret()).ok()
+    }
+
+    async fn store_json(&self, path: &str, value: &serde_json::Value) {
+        let Ok(serialized) = serde_json::to_string(value) else {
+            return;
+        };
+
+        let mut cache = self.cache.write().await;
+        // Evict expired entries so their values are zeroized promptly
+        cache.retain(|_, entry| !entry.is_expired());
+        cache.insert(
+            path.to_string(),
+            CachedSecret {
+                value: SecretString::from(serialized),
+                expires_at: Instant::now() + self.ttl,
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl SecretStorePort for CachingSecretStore {
+    async fn get_secret(&self, key: &str) -> Result<String, ApplicationError> {
+        self.inner.get_secret(key).await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_json(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+        if let Some(value) = self.cached_json(path).await {
+            debug!("Secret cache hit");
+            return Ok(value);
+        }
+
+        let value = self.inner.get_json(path).await?;
+        self.store_json(path, &value).await;
+        Ok(value)
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, ApplicationError> {
+        self.inner.exists(key).await
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.inner.is_healthy().await
+    }
+
+    async fn refresh_json(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+        self.refresh(path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Store that counts `get_json` calls
+    #[derive(Debug, Default)]
+    struct CountingStore {
+        json_reads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SecretStorePort for CountingStore {
+        async fn get_secret(&self, _key: &str) -> Result<String, ApplicationError> {
+            Ok("secret".to_string())
+        }
+        async fn get_json(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+            self.json_reads.fetch_add(1, Ordering::SeqCst);
+            if path.ends_with("missing") {
+                return Err(ApplicationError::NotFound(path.to_string()));
+            }
+            Ok(serde_json::json!({ "password": "hunter2" }))
+        }
+        async fn exists(&self, _key: &str) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+    }
+
+    fn caching(ttl: Duration) -> (Arc<CountingStore>, CachingSecretStore) {
+        let inner = Arc::new(CountingStore::default());
+        let store = CachingSecretStore::new(Arc::clone(&inner) as Arc<dyn SecretStorePort>, ttl);
+        (inner, store)
+    }
+
+    #[tokio::test]
+    async fn reads_within_ttl_hit_inner_store_once() {
+        let (inner, store) = caching(Duration::from_secs(60));
+
+        let first = store.get_json("pisovereign/caldav").await.unwrap();
+        let second = store.get_json("pisovereign/caldav").await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(second["password"], "hunter2");
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn expired_entries_are_refetched() {
+        let (inner, store) = caching(Duration::ZERO);
+
+        store.get_json("pisovereign/caldav").await.unwrap();
+        store.get_json("pisovereign/caldav").await.unwrap();
+
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn paths_are_cached_separately() {
+        let (inner, store) = caching(Duration::from_secs(60));
+
+        store.get_json("pisovereign/caldav").await.unwrap();
+        store.get_json("pisovereign/proton").await.unwrap();
+
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 2);
+        assert_eq!(store.len().await, 2);
+    }
+
+    #[tokio::test]
+    async fn invalidate_forces_refetch() {
+        let (inner, store) = caching(Duration::from_secs(60));
+
+        store.get_json("pisovereign/caldav").await.unwrap();
+        store.invalidate("pisovereign/caldav").await;
+        store.get_json("pisovereign/caldav").await.unwrap();
+
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn refresh_refetches_and_recaches() {
+        let (inner, store) = caching(Duration::from_secs(60));
+
+        store.get_json("pisovereign/caldav").await.unwrap();
+        let refreshed = store.refresh("pisovereign/caldav").await.unwrap();
+        store.get_json("pisovereign/caldav").await.unwrap();
+
+        assert_eq!(refreshed["password"], "hunter2");
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn port_refresh_bypasses_cache_for_one_path() {
+        let (inner, store) = caching(Duration::from_secs(60));
+        let port: &dyn SecretStorePort = &store;
+
+        port.get_json("pisovereign/caldav").await.unwrap();
+        port.get_json("pisovereign/proton").await.unwrap();
+        port.refresh_json("pisovereign/caldav").await.unwrap();
+        port.get_json("pisovereign/caldav").await.unwrap();
+        port.get_json("pisovereign/proton").await.unwrap();
+
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 3);
+        assert_eq!(store.len().await, 2);
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_is_not_cached() {
+        let (inner, store) = caching(Duration::from_secs(60));
+
+        assert!(store.refresh("pisovereign/missing").await.is_err());
+
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 1);
+        assert!(store.is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn invalidate_all_clears_cache() {
+        let (_, store) = caching(Duration::from_secs(60));
+
+        store.get_json("pisovereign/caldav").await.unwrap();
+        store.get_json("pisovereign/proton").await.unwrap();
+        store.invalidate_all().await;
+
+        assert!(store.is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let (inner, store) = caching(Duration::from_secs(60));
+
+        assert!(store.get_json("pisovereign/missing").await.is_err());
+        assert!(store.get_json("pisovereign/missing").await.is_err());
+
+        assert_eq!(inner.json_reads.load(Ordering::SeqCst), 2);
+        assert!(store.is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn other_methods_pass_through() {
+        let (_, store) = caching(Duration::from_secs(60));
+
+        assert_eq!(store.get_secret("key").await.unwrap(), "secret");
+        assert!(store.exists("key").await.unwrap());
+        assert!(store.is_healthy().await);
+        assert!(store.is_empty().await);
+    }
+}
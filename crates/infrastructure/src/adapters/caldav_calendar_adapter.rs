@@ -6,22 +6,38 @@ use chrono::{DateTime, NaiveDate, Utc};
 use integration_caldav::{
     CalDavClient, CalDavConfig, CalDavError, CalendarEvent as CalDavEvent, HttpCalDavClient,
 };
-use tracing::{debug, instrument, warn};
+use std::{future::Future, sync::Arc, time::Duration};
 
-use super::{CircuitBreaker, CircuitBreakerConfig};
+use parking_lot::RwLock;
+use secrecy::{ExposeSecret, SecretString};
+use tracing::{debug, info, instrument, warn};
+
+use super::{CircuitBreaker, CircuitBreakerConfig, SharedSecret};
+use crate::retry::with_timeout;
+
+/// CalDAV client together with the rotating password it was built with
+struct ClientState {
+    client: Arc<HttpCalDavClient>,
+    password: Option<Arc<SecretString>>,
+}
 
 /// Adapter for CalDAV calendar servers
 pub struct CalDavCalendarAdapter {
-    client: HttpCalDavClient,
+    config: CalDavConfig,
+    state: RwLock<ClientState>,
+    password: Option<SharedSecret>,
     default_calendar: Option<String>,
     circuit_breaker: Option<CircuitBreaker>,
+    timeout: Option<Duration>,
 }
 
 impl std::fmt::Debug for CalDavCalendarAdapter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CalDavCalendarAdapter")
-            .field("client", &self.client)
+            .field("client", &self.state.read().client)
+            .field("rotating_password", &self.password.is_some())
             .field("default_calendar", &self.default_calendar)
+            .field("timeout", &self.timeout)
             .field(
                 "circuit_breaker",
                 &self
@@ -37,11 +53,17 @@ impl CalDavCalendarAdapter {
     /// Create a new adapter with the given configuration
     pub fn new(config: CalDavConfig) -> Result<Self, CalendarError> {
         let default_calendar = config.calendar_path.clone();
-        let client = HttpCalDavClient::new(config).map_err(Self::map_error)?;
+        let client = Arc::new(HttpCalDavClient::new(config.clone()).map_err(Self::map_error)?);
         Ok(Self {
-            client,
+            config,
+            state: RwLock::new(ClientState {
+                client,
+                password: None,
+            }),
+            password: None,
             default_calendar,
             circuit_breaker: None,
+            timeout: None,
         })
     }
 
@@ -63,6 +85,15 @@ impl CalDavCalendarAdapter {
         Self::new(config)
     }
 
+    /// Read the server password from a shared secret
+    ///
+    /// When the secret is rotated, the next call uses the new password.
+    #[must_use]
+    pub fn with_shared_password(mut self, password: SharedSecret) -> Self {
+        self.password = Some(password);
+        self
+    }
+
     /// Enable circuit breaker with default configuration
     #[must_use]
     pub fn with_circuit_breaker(mut self) -> Self {
@@ -77,6 +108,72 @@ impl CalDavCalendarAdapter {
         self
     }
 
+    /// Give up on a single CalDAV call after `timeout`
+    ///
+    /// Bounds hanging requests (e.g. a PROPFIND that never answers) below
+    /// the HTTP client timeout.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Await a CalDAV call, bounded by the configured timeout
+    async fn bounded<T>(
+        &self,
+        call: impl Future<Output = Result<T, CalDavError>>,
+    ) -> Result<T, CalendarError> {
+        let result = match self.timeout {
+            Some(timeout) => with_timeout(timeout, call)
+                .await
+                .map_err(|_| CalendarError::ServiceUnavailable)?,
+            None => call.await,
+        };
+        result.map_err(Self::map_error)
+    }
+
+    /// Current CalDAV client, rebuilt if the shared password was rotated
+    ///
+    /// If the rebuild fails, the previous client is kept and the rebuild is
+    /// retried on the next call.
+    fn client(&self) -> Arc<HttpCalDavClient> {
+        let Some(ref password) = self.password else {
+            return Arc::clone(&self.state.read().client);
+        };
+
+        let current = password.current();
+        {
+            let state = self.state.read();
+            if state
+                .password
+                .as_ref()
+                .is_some_and(|used| Arc::ptr_eq(used, &current))
+            {
+                return Arc::clone(&state.client);
+            }
+        }
+
+        let mut config = self.config.clone();
+        config.password = current.expose_secret().to_string();
+        let client = match HttpCalDavClient::new(config) {
+            Ok(client) => Arc::new(client),
+            Err(e) => {
+                warn!(error = %e, "Failed to rebuild CalDAV client with rotated password");
+                return Arc::clone(&self.state.read().client);
+            },
+        };
+
+        let mut state = self.state.write();
+        if state.password.is_some() {
+            info!("CalDAV password rotated, using new credentials");
+        }
+        *state = ClientState {
+            client: Arc::clone(&client),
+            password: Some(current),
+        };
+        client
+    }
+
     /// Check if circuit breaker is blocking requests
     fn is_circuit_open(&self) -> bool {
         self.circuit_breaker
@@ -148,11 +245,7 @@ impl CalDavCalendarAdapter {
             return Ok(cal.clone());
         }
 
-        let calendars = self
-            .client
-            .list_calendars()
-            .await
-            .map_err(Self::map_error)?;
+        let calendars = self.bounded(self.client().list_calendars()).await?;
 
         calendars
             .first()
@@ -182,11 +275,7 @@ impl CalendarPort for CalDavCalendarAdapter {
         self.check_circuit()?;
         debug!("Listing calendars from CalDAV");
 
-        let calendars = self
-            .client
-            .list_calendars()
-            .await
-            .map_err(Self::map_error)?;
+        let calendars = self.bounded(self.client().list_calendars()).await?;
 
         Ok(calendars
             .into_iter()
@@ -212,10 +301,8 @@ impl CalendarPort for CalDavCalendarAdapter {
         let (start, end) = format_date_for_caldav(date);
 
         let events = self
-            .client
-            .get_events(&calendar, &start, &end)
-            .await
-            .map_err(Self::map_error)?;
+            .bounded(self.client().get_events(&calendar, &start, &end))
+            .await?;
 
         Ok(events.iter().map(Self::convert_event).collect())
     }
@@ -234,10 +321,8 @@ impl CalendarPort for CalDavCalendarAdapter {
         let end_str = end.to_rfc3339();
 
         let events = self
-            .client
-            .get_events(&calendar, &start_str, &end_str)
-            .await
-            .map_err(Self::map_error)?;
+            .bounded(self.client().get_events(&calendar, &start_str, &end_str))
+            .await?;
 
         Ok(events.iter().map(Self::convert_event).collect())
     }
@@ -254,10 +339,8 @@ impl CalendarPort for CalDavCalendarAdapter {
         let end = (now + chrono::Duration::days(365)).to_rfc3339();
 
         let events = self
-            .client
-            .get_events(&calendar, &start, &end)
-            .await
-            .map_err(Self::map_error)?;
+            .bounded(self.client().get_events(&calendar, &start, &end))
+            .await?;
 
         events
             .iter()
@@ -274,10 +357,8 @@ impl CalendarPort for CalDavCalendarAdapter {
         let calendar = self.get_default_calendar().await?;
         let caldav_event = Self::convert_new_event(event);
 
-        self.client
-            .create_event(&calendar, &caldav_event)
+        self.bounded(self.client().create_event(&calendar, &caldav_event))
             .await
-            .map_err(Self::map_error)
     }
 
     #[instrument(skip(self, event), fields(circuit = %self.circuit_state_desc()))]
@@ -289,10 +370,8 @@ impl CalendarPort for CalDavCalendarAdapter {
         let mut caldav_event = Self::convert_new_event(event);
         caldav_event.id = event_id.to_string();
 
-        self.client
-            .update_event(&calendar, &caldav_event)
+        self.bounded(self.client().update_event(&calendar, &caldav_event))
             .await
-            .map_err(Self::map_error)
     }
 
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
@@ -302,10 +381,8 @@ impl CalendarPort for CalDavCalendarAdapter {
 
         let calendar = self.get_default_calendar().await?;
 
-        self.client
-            .delete_event(&calendar, event_id)
+        self.bounded(self.client().delete_event(&calendar, event_id))
             .await
-            .map_err(Self::map_error)
     }
 
     async fn is_available(&self) -> bool {
@@ -315,7 +392,7 @@ impl CalendarPort for CalDavCalendarAdapter {
             return false;
         }
         // Try to list calendars as a health check
-        self.client.list_calendars().await.is_ok()
+        self.bounded(self.client().list_calendars()).await.is_ok()
     }
 
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
@@ -328,10 +405,11 @@ impl CalendarPort for CalDavCalendarAdapter {
         let end = now + chrono::Duration::days(7); // Look a week ahead
 
         let events = self
-            .client
-            .get_events(&calendar, &now.to_rfc3339(), &end.to_rfc3339())
-            .await
-            .map_err(Self::map_error)?;
+            .bounded(
+                self.client()
+                    .get_events(&calendar, &now.to_rfc3339(), &end.to_rfc3339()),
+            )
+            .await?;
 
         // Find the next event by start time
         let mut sorted: Vec<_> = events.iter().collect();
@@ -362,6 +440,49 @@ mod tests {
         assert!(format!("{adapter:?}").contains("CalDavCalendarAdapter"));
     }
 
+    #[test]
+    fn rotated_password_is_used_on_next_call() {
+        let password = SharedSecret::new(SecretString::from("old-password"));
+        let adapter = CalDavCalendarAdapter::new(test_config())
+            .unwrap()
+            .with_shared_password(password.clone());
+
+        let first = adapter.client();
+        assert!(Arc::ptr_eq(&first, &adapter.client()));
+
+        password.replace(SecretString::from("new-password"));
+
+        let second = adapter.client();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(
+            adapter
+                .state
+                .read()
+                .password
+                .as_ref()
+                .unwrap()
+                .expose_secret(),
+            "new-password"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hanging_call_times_out() {
+        let adapter = CalDavCalendarAdapter::new(test_config())
+            .unwrap()
+            .with_timeout(Duration::from_secs(3));
+
+        let result = adapter
+            .bounded(std::future::pending::<Result<(), CalDavError>>())
+            .await;
+        assert!(matches!(result, Err(CalendarError::ServiceUnavailable)));
+
+        let result = adapter
+            .bounded(async { Ok::<_, CalDavError>(vec!["home".to_string()]) })
+            .await;
+        assert_eq!(result.unwrap(), ["home"]);
+    }
+
     #[test]
     fn adapter_with_server() {
         let adapter = CalDavCalendarAdapter::with_server(
@@ -1,16 +1,18 @@
 //! CardDAV Contact adapter — Implements `ContactPort` using `integration_carddav`
 
+use std::collections::HashSet;
+
 use application::ports::{
     AddressbookInfo, ContactDetail, ContactError, ContactPort, ContactSummary, ContactUpdate,
-    NewContact,
+    ImportResult, NewContact,
 };
 use async_trait::async_trait;
 use chrono::Datelike;
 use integration_carddav::{
     CardDavClient, CardDavConfig, CardDavError, Contact as CardDavContact, ContactEmail,
-    ContactPhone, HttpCardDavClient,
+    ContactPhone, HttpCardDavClient, client::parse_vcards,
 };
-use tracing::{debug, instrument, warn};
+use tracing::{debug, info, instrument, warn};
 
 use super::{CircuitBreaker, CircuitBreakerConfig};
 
@@ -246,6 +248,15 @@ impl CardDavContactAdapter {
 
         contact
     }
+
+    /// Lowercased email addresses of a contact, used for deduplication.
+    fn email_keys(contact: &CardDavContact) -> impl Iterator<Item = String> + '_ {
+        contact
+            .emails
+            .iter()
+            .map(|e| e.value.trim().to_lowercase())
+            .filter(|e| !e.is_empty())
+    }
 }
 
 #[async_trait]
@@ -405,6 +416,74 @@ impl ContactPort for CardDavContactAdapter {
             .map_err(Self::map_error)
     }
 
+    #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
+    async fn export_vcard(&self, contact_id: &str) -> Result<String, ContactError> {
+        self.check_circuit()?;
+        debug!(contact_id, "Exporting contact from CardDAV as vCard");
+
+        let addressbook = self.get_default_addressbook().await?;
+
+        self.client
+            .export_vcard(&addressbook, contact_id)
+            .await
+            .map_err(Self::map_error)
+    }
+
+    #[instrument(skip(self, vcard_data), fields(circuit = %self.circuit_state_desc()))]
+    async fn import_vcards(&self, vcard_data: &str) -> Result<ImportResult, ContactError> {
+        self.check_circuit()?;
+        debug!(bytes = vcard_data.len(), "Importing vCards into CardDAV");
+
+        let addressbook = self.get_default_addressbook().await?;
+
+        let existing = self
+            .client
+            .get_contacts(&addressbook)
+            .await
+            .map_err(Self::map_error)?;
+        let mut known_emails: HashSet<String> =
+            existing.iter().flat_map(Self::email_keys).collect();
+
+        let mut result = ImportResult::default();
+        for (index, parsed) in parse_vcards(vcard_data).into_iter().enumerate() {
+            let contact = match parsed {
+                Ok(contact) => contact,
+                Err(e) => {
+                    result.record_failure(index, None, e.to_string());
+                    continue;
+                },
+            };
+
+            if Self::email_keys(&contact).any(|email| known_emails.contains(&email)) {
+                debug!(index, "Skipping vCard with existing email");
+                result.skipped += 1;
+                continue;
+            }
+
+            match self.client.create_contact(&addressbook, &contact).await {
+                Ok(_) => {
+                    known_emails.extend(Self::email_keys(&contact));
+                    result.created += 1;
+                },
+                Err(e) => {
+                    result.record_failure(
+                        index,
+                        Some(contact.full_name()),
+                        Self::map_error(e).to_string(),
+                    );
+                },
+            }
+        }
+
+        info!(
+            created = result.created,
+            skipped = result.skipped,
+            failed = result.failed,
+            "vCard import finished"
+        );
+        Ok(result)
+    }
+
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
     async fn search_contacts(&self, query: &str) -> Result<Vec<ContactSummary>, ContactError> {
         self.check_circuit()?;
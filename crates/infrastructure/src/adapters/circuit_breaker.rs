@@ -9,6 +9,12 @@
 //! - **Open**: Service is down, requests fail fast without calling the service
 //! - **Half-Open**: Testing if the service has recovered
 //!
+//! # Observability
+//!
+//! Every state transition is published as a [`CircuitBreakerEvent`] on a
+//! process-wide channel, see [`subscribe_circuit_events`].
+//! [`CircuitBreaker::stats`] returns a snapshot of a single breaker.
+//!
 //! # Persistence
 //!
 //! Circuit breaker state can be persisted to a file so that the state
@@ -29,11 +35,34 @@
 use std::{
     fmt,
     path::{Path, PathBuf},
+    sync::LazyLock,
     time::{Duration, Instant, SystemTime},
 };
 
+use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// Number of state change events buffered for slow subscribers
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Process-wide channel for state changes of all circuit breakers
+static EVENTS: LazyLock<broadcast::Sender<CircuitBreakerEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_CHANNEL_CAPACITY).0);
+
+/// Subscribe to state changes of all circuit breakers
+///
+/// Only events published after subscribing are received.
+#[must_use]
+pub fn subscribe_circuit_events() -> broadcast::Receiver<CircuitBreakerEvent> {
+    EVENTS.subscribe()
+}
+
+/// Publish a state change; dropped if nobody is subscribed
+pub(crate) fn publish_event(event: CircuitBreakerEvent) {
+    let _ = EVENTS.send(event);
+}
 
 /// Configuration for a circuit breaker
 #[derive(Debug, Clone)]
@@ -103,6 +132,18 @@ pub enum CircuitState {
     HalfOpen,
 }
 
+impl CircuitState {
+    /// Stable identifier used for persistence and metric labels
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+}
+
 impl fmt::Display for CircuitState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -113,6 +154,30 @@ impl fmt::Display for CircuitState {
     }
 }
 
+/// State change of a circuit breaker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitBreakerEvent {
+    /// Name of the circuit breaker
+    pub name: String,
+    /// State before the transition
+    pub from: CircuitState,
+    /// State after the transition
+    pub to: CircuitState,
+    /// When the transition happened
+    pub at: DateTime<Utc>,
+}
+
+/// Snapshot of a circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerStats {
+    /// Current state
+    pub state: CircuitState,
+    /// Consecutive failures since the last success or transition
+    pub failure_count: u32,
+    /// When the state last changed, `None` if it never did in this process
+    pub last_transition: Option<DateTime<Utc>>,
+}
+
 /// Serializable state for persistence
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PersistedCircuitState {
@@ -139,11 +204,7 @@ impl PersistedCircuitState {
 
         Self {
             name: name.to_string(),
-            state: match state.state {
-                CircuitState::Closed => "closed".to_string(),
-                CircuitState::Open => "open".to_string(),
-                CircuitState::HalfOpen => "half_open".to_string(),
-            },
+            state: state.state.as_str().to_string(),
             failure_count: state.failure_count,
             success_count: state.success_count,
             opened_at_secs,
@@ -179,6 +240,7 @@ impl PersistedCircuitState {
             success_count: self.success_count,
             opened_at,
             opened_at_system,
+            last_transition: None,
         }
     }
 }
@@ -210,6 +272,36 @@ struct CircuitBreakerState {
     opened_at: Option<Instant>,
     /// SystemTime version for persistence (None when closed)
     opened_at_system: Option<SystemTime>,
+    /// When the state last changed
+    last_transition: Option<DateTime<Utc>>,
+}
+
+impl CircuitBreakerState {
+    /// Fresh closed state
+    const fn closed() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            failure_count: 0,
+            success_count: 0,
+            opened_at: None,
+            opened_at_system: None,
+            last_transition: None,
+        }
+    }
+
+    /// Move to `to` and describe the transition
+    fn transition(&mut self, name: &str, to: CircuitState) -> CircuitBreakerEvent {
+        let at = Utc::now();
+        let event = CircuitBreakerEvent {
+            name: name.to_string(),
+            from: self.state,
+            to,
+            at,
+        };
+        self.state = to;
+        self.last_transition = Some(at);
+        event
+    }
 }
 
 /// Circuit breaker wrapper for external service calls
@@ -247,6 +339,7 @@ impl Clone for CircuitBreaker {
                 success_count: state.success_count,
                 opened_at: state.opened_at,
                 opened_at_system: state.opened_at_system,
+                last_transition: state.last_transition,
             }),
         }
     }
@@ -266,13 +359,7 @@ impl CircuitBreaker {
             name: name.into(),
             config,
             persistence_path: None,
-            state: RwLock::new(CircuitBreakerState {
-                state: CircuitState::Closed,
-                failure_count: 0,
-                success_count: 0,
-                opened_at: None,
-                opened_at_system: None,
-            }),
+            state: RwLock::new(CircuitBreakerState::closed()),
         }
     }
 
@@ -302,13 +389,7 @@ impl CircuitBreaker {
                 error = %e,
                 "No existing state found, starting fresh"
             );
-            CircuitBreakerState {
-                state: CircuitState::Closed,
-                failure_count: 0,
-                success_count: 0,
-                opened_at: None,
-                opened_at_system: None,
-            }
+            CircuitBreakerState::closed()
         });
 
         tracing::info!(
@@ -388,25 +469,45 @@ impl CircuitBreaker {
     /// Returns the current state of the circuit breaker
     #[must_use]
     pub fn state(&self) -> CircuitState {
-        let mut state = self.state.write();
-
-        // Check if we should transition from Open to HalfOpen
-        if state.state == CircuitState::Open {
-            if let Some(opened_at) = state.opened_at {
-                let elapsed = opened_at.elapsed();
-                if elapsed >= Duration::from_secs(self.config.half_open_timeout_secs) {
-                    tracing::debug!(
-                        service = %self.name,
-                        elapsed_secs = elapsed.as_secs(),
-                        "Circuit transitioning from Open to HalfOpen"
-                    );
-                    state.state = CircuitState::HalfOpen;
-                    state.success_count = 0;
+        let mut event = None;
+        let current = {
+            let mut state = self.state.write();
+
+            // Check if we should transition from Open to HalfOpen
+            if state.state == CircuitState::Open {
+                if let Some(opened_at) = state.opened_at {
+                    let elapsed = opened_at.elapsed();
+                    if elapsed >= Duration::from_secs(self.config.half_open_timeout_secs) {
+                        tracing::debug!(
+                            service = %self.name,
+                            elapsed_secs = elapsed.as_secs(),
+                            "Circuit transitioning from Open to HalfOpen"
+                        );
+                        event = Some(state.transition(&self.name, CircuitState::HalfOpen));
+                        state.success_count = 0;
+                    }
                 }
             }
+
+            state.state
+        };
+
+        if let Some(event) = event {
+            publish_event(event);
         }
+        current
+    }
 
-        state.state
+    /// Returns a snapshot of the state, failure count and last transition
+    #[must_use]
+    pub fn stats(&self) -> CircuitBreakerStats {
+        let state = self.state();
+        let inner = self.state.read();
+        CircuitBreakerStats {
+            state,
+            failure_count: inner.failure_count,
+            last_transition: inner.last_transition,
+        }
     }
 
     /// Returns true if the circuit is closed (normal operation)
@@ -423,7 +524,7 @@ impl CircuitBreaker {
 
     /// Records a successful call
     fn on_success(&self) {
-        let mut state_changed = false;
+        let mut event = None;
         {
             let mut state = self.state.write();
             state.failure_count = 0;
@@ -437,25 +538,25 @@ impl CircuitBreaker {
                             successes = state.success_count,
                             "Circuit transitioning from HalfOpen to Closed"
                         );
-                        state.state = CircuitState::Closed;
+                        event = Some(state.transition(&self.name, CircuitState::Closed));
                         state.success_count = 0;
                         state.opened_at = None;
                         state.opened_at_system = None;
-                        state_changed = true;
                     }
                 },
                 CircuitState::Closed | CircuitState::Open => {},
             }
         }
 
-        if state_changed {
+        if let Some(event) = event {
             self.save_state();
+            publish_event(event);
         }
     }
 
     /// Records a failed call
     fn on_failure(&self) {
-        let mut state_changed = false;
+        let mut event = None;
         {
             let mut state = self.state.write();
             state.failure_count += 1;
@@ -469,11 +570,10 @@ impl CircuitBreaker {
                             failures = state.failure_count,
                             "Circuit transitioning from Closed to Open"
                         );
-                        state.state = CircuitState::Open;
+                        event = Some(state.transition(&self.name, CircuitState::Open));
                         state.opened_at = Some(Instant::now());
                         state.opened_at_system = Some(SystemTime::now());
                         state.failure_count = 0;
-                        state_changed = true;
                     }
                 },
                 CircuitState::HalfOpen => {
@@ -481,18 +581,18 @@ impl CircuitBreaker {
                         service = %self.name,
                         "Circuit transitioning from HalfOpen to Open after failure"
                     );
-                    state.state = CircuitState::Open;
+                    event = Some(state.transition(&self.name, CircuitState::Open));
                     state.opened_at = Some(Instant::now());
                     state.opened_at_system = Some(SystemTime::now());
                     state.failure_count = 0;
-                    state_changed = true;
                 },
                 CircuitState::Open => {},
             }
         }
 
-        if state_changed {
+        if let Some(event) = event {
             self.save_state();
+            publish_event(event);
         }
     }
 
@@ -814,6 +914,80 @@ mod tests {
         assert!(result.unwrap_err().is_service_error());
     }
 
+    /// Transitions of the breaker called `name` received so far
+    fn transitions(
+        rx: &mut broadcast::Receiver<CircuitBreakerEvent>,
+        name: &str,
+    ) -> Vec<(CircuitState, CircuitState)> {
+        let mut seen = Vec::new();
+        loop {
+            match rx.try_recv() {
+                Ok(event) if event.name == name => seen.push((event.from, event.to)),
+                Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => {},
+                Err(_) => return seen,
+            }
+        }
+    }
+
+    #[test]
+    fn events_follow_open_half_open_closed_cycle() {
+        let mut rx = subscribe_circuit_events();
+        let cb = CircuitBreaker::with_config("events-cycle", CircuitBreakerConfig::custom(2, 1, 0));
+
+        let _: Result<(), _> = cb.call_sync(|| Err::<(), _>("fail1"));
+        assert_eq!(cb.stats().failure_count, 1);
+        let _: Result<(), _> = cb.call_sync(|| Err::<(), _>("fail2"));
+
+        // Zero timeout: the next state check moves to half-open
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
+        let _: Result<&str, _> = cb.call_sync(|| Ok::<_, &str>("ok"));
+        assert!(cb.is_closed());
+
+        assert_eq!(
+            transitions(&mut rx, "events-cycle"),
+            [
+                (CircuitState::Closed, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Closed),
+            ]
+        );
+    }
+
+    #[test]
+    fn half_open_failure_reopens() {
+        let mut rx = subscribe_circuit_events();
+        let cb =
+            CircuitBreaker::with_config("events-reopen", CircuitBreakerConfig::custom(1, 1, 0));
+
+        let _: Result<(), _> = cb.call_sync(|| Err::<(), _>("fail"));
+        let _: Result<(), _> = cb.call_sync(|| Err::<(), _>("probe failed"));
+
+        assert_eq!(
+            transitions(&mut rx, "events-reopen"),
+            [
+                (CircuitState::Closed, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Open),
+            ]
+        );
+    }
+
+    #[test]
+    fn stats_track_last_transition() {
+        let cb = CircuitBreaker::with_config("stats", CircuitBreakerConfig::custom(1, 1, 60));
+        let initial = cb.stats();
+        assert_eq!(initial.state, CircuitState::Closed);
+        assert!(initial.last_transition.is_none());
+
+        let before = Utc::now();
+        let _: Result<(), _> = cb.call_sync(|| Err::<(), _>("fail"));
+
+        let stats = cb.stats();
+        assert_eq!(stats.state, CircuitState::Open);
+        assert_eq!(stats.failure_count, 0);
+        assert!(stats.last_transition.is_some_and(|at| at >= before));
+    }
+
     #[test]
     fn persistence_saves_and_loads_state() {
         // Create a temp file
@@ -2,12 +2,16 @@
 //!
 //! Provides graceful degradation when the primary inference backend (Hailo) is unavailable.
 //! Falls back to cached responses or user-friendly error messages.
+//!
+//! Entering and leaving degraded mode is published as circuit breaker
+//! events under the name [`DEGRADED_MODE_CIRCUIT`].
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use chrono::Utc;
 use domain::Conversation;
 use futures::stream;
 use parking_lot::RwLock;
@@ -16,9 +20,16 @@ use tracing::{debug, info, warn};
 
 use application::{
     ApplicationError,
-    ports::{InferencePort, InferenceResult, InferenceStream, StreamingChunk},
+    ports::{DegradedModePort, InferencePort, InferenceResult, InferenceStream, StreamingChunk},
+};
+
+use super::circuit_breaker::{
+    CircuitBreakerEvent, CircuitBreakerStats, CircuitState, publish_event,
 };
 
+/// Circuit name used in state change events of the degraded mode adapter
+pub const DEGRADED_MODE_CIRCUIT: &str = "inference";
+
 /// Configuration for degraded mode behavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DegradedModeConfig {
@@ -100,25 +111,43 @@ pub struct DegradedModeStats {
     pub status: ServiceStatus,
 }
 
+/// Degraded mode state and settings
+///
+/// Extends [`DegradedModePort`] with the status details and config updates
+/// needed when wiring the server; handlers only see the port.
+pub trait DegradedModeMonitor: DegradedModePort {
+    /// Current service status
+    ///
+    /// `Unavailable` while the circuit is open (degraded and within the
+    /// retry cooldown), `Degraded` while the primary backend is being probed.
+    fn service_status(&self) -> ServiceStatus;
+
+    /// Replace the thresholds, cooldown and message, e.g. after a config reload
+    ///
+    /// The current degraded state and failure counts are kept.
+    fn update_config(&self, config: DegradedModeConfig);
+}
+
 /// Degraded mode inference adapter
 ///
 /// Wraps a primary inference adapter and provides graceful degradation
 /// when the primary backend becomes unavailable.
 pub struct DegradedInferenceAdapter<I: InferencePort> {
     inner: Arc<I>,
-    config: DegradedModeConfig,
+    config: RwLock<DegradedModeConfig>,
     is_degraded: AtomicBool,
     consecutive_failures: AtomicU64,
     consecutive_successes: AtomicU64,
     last_failure_time: RwLock<Option<Instant>>,
     stats: RwLock<DegradedModeStats>,
+    circuit: RwLock<CircuitBreakerStats>,
 }
 
 impl<I: InferencePort> std::fmt::Debug for DegradedInferenceAdapter<I> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DegradedInferenceAdapter")
             .field("is_degraded", &self.is_degraded.load(Ordering::Relaxed))
-            .field("config", &self.config)
+            .field("config", &*self.config.read())
             .finish_non_exhaustive()
     }
 }
@@ -128,12 +157,17 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
     pub fn new(inner: Arc<I>, config: DegradedModeConfig) -> Self {
         Self {
             inner,
-            config,
+            config: RwLock::new(config),
             is_degraded: AtomicBool::new(false),
             consecutive_failures: AtomicU64::new(0),
             consecutive_successes: AtomicU64::new(0),
             last_failure_time: RwLock::new(None),
             stats: RwLock::new(DegradedModeStats::default()),
+            circuit: RwLock::new(CircuitBreakerStats {
+                state: CircuitState::Closed,
+                failure_count: 0,
+                last_transition: None,
+            }),
         }
     }
 
@@ -157,17 +191,55 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
         self.stats.read().clone()
     }
 
+    /// Get the degraded mode state as circuit breaker statistics
+    ///
+    /// Degraded mode maps to `Open` during the retry cooldown and to
+    /// `HalfOpen` while the primary backend is being probed.
+    pub fn circuit_stats(&self) -> CircuitBreakerStats {
+        // Moves an expired cooldown to half-open
+        self.should_retry_primary();
+
+        let mut stats = *self.circuit.read();
+        stats.failure_count =
+            u32::try_from(self.consecutive_failures.load(Ordering::Relaxed)).unwrap_or(u32::MAX);
+        stats
+    }
+
+    /// Record a circuit state change and publish it
+    fn transition(&self, to: CircuitState) {
+        let event = {
+            let mut circuit = self.circuit.write();
+            if circuit.state == to {
+                return;
+            }
+            let at = Utc::now();
+            let event = CircuitBreakerEvent {
+                name: DEGRADED_MODE_CIRCUIT.to_string(),
+                from: circuit.state,
+                to,
+                at,
+            };
+            circuit.state = to;
+            circuit.last_transition = Some(at);
+            event
+        };
+        publish_event(event);
+    }
+
     /// Check if we should retry the primary backend
     fn should_retry_primary(&self) -> bool {
         if !self.is_degraded() {
             return true;
         }
 
-        let last_failure = self.last_failure_time.read();
-        last_failure.is_none_or(|time| {
+        let cooled_down = self.last_failure_time.read().is_none_or(|time| {
             let elapsed = time.elapsed();
-            elapsed >= Duration::from_secs(self.config.retry_cooldown_secs)
-        })
+            elapsed >= self.retry_cooldown()
+        });
+        if cooled_down {
+            self.transition(CircuitState::HalfOpen);
+        }
+        cooled_down
     }
 
     /// Record a successful operation
@@ -175,7 +247,7 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
         self.consecutive_failures.store(0, Ordering::Relaxed);
         let successes = self.consecutive_successes.fetch_add(1, Ordering::Relaxed) + 1;
 
-        if self.is_degraded() && successes >= u64::from(self.config.success_threshold) {
+        if self.is_degraded() && successes >= u64::from(self.config.read().success_threshold) {
             info!(
                 "Exiting degraded mode after {} consecutive successes",
                 successes
@@ -183,6 +255,7 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
             self.is_degraded.store(false, Ordering::Relaxed);
             self.consecutive_successes.store(0, Ordering::Relaxed);
             self.stats.write().status = ServiceStatus::Healthy;
+            self.transition(CircuitState::Closed);
         }
 
         // Update stats
@@ -195,7 +268,7 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
         let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
         *self.last_failure_time.write() = Some(Instant::now());
 
-        if !self.is_degraded() && failures >= u64::from(self.config.failure_threshold) {
+        if !self.is_degraded() && failures >= u64::from(self.config.read().failure_threshold) {
             warn!(
                 "Entering degraded mode after {} consecutive failures",
                 failures
@@ -203,6 +276,10 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
             self.is_degraded.store(true, Ordering::Relaxed);
             self.stats.write().status = ServiceStatus::Degraded;
         }
+        if self.is_degraded() {
+            // Also re-opens after a failed half-open probe
+            self.transition(CircuitState::Open);
+        }
 
         // Update stats
         let mut stats = self.stats.write();
@@ -214,7 +291,7 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
     fn fallback_response(&self) -> InferenceResult {
         self.stats.write().fallback_responses += 1;
         InferenceResult {
-            content: self.config.unavailable_message.clone(),
+            content: self.unavailable_message(),
             model: "fallback".to_string(),
             tokens_used: None,
             latency_ms: 0,
@@ -223,7 +300,7 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
 
     /// Generate a fallback streaming response
     fn fallback_stream(&self) -> InferenceStream {
-        let message = self.config.unavailable_message.clone();
+        let message = self.unavailable_message();
         self.stats.write().fallback_responses += 1;
         Box::pin(stream::once(async move {
             Ok(StreamingChunk {
@@ -248,7 +325,8 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
             Err(e) => {
                 self.record_failure();
 
-                if self.config.enabled && self.is_degraded() {
+                let enabled = self.config.read().enabled;
+                if enabled && self.is_degraded() {
                     debug!("Using fallback response due to degraded mode");
                     Ok(fallback())
                 } else {
@@ -259,6 +337,34 @@ impl<I: InferencePort + 'static> DegradedInferenceAdapter<I> {
     }
 }
 
+impl<I: InferencePort + 'static> DegradedModeMonitor for DegradedInferenceAdapter<I> {
+    fn service_status(&self) -> ServiceStatus {
+        if self.should_retry_primary() {
+            self.status()
+        } else {
+            ServiceStatus::Unavailable
+        }
+    }
+
+    fn update_config(&self, config: DegradedModeConfig) {
+        *self.config.write() = config;
+    }
+}
+
+impl<I: InferencePort + 'static> DegradedModePort for DegradedInferenceAdapter<I> {
+    fn is_unavailable(&self) -> bool {
+        self.service_status() == ServiceStatus::Unavailable
+    }
+
+    fn unavailable_message(&self) -> String {
+        self.config.read().unavailable_message.clone()
+    }
+
+    fn retry_cooldown(&self) -> Duration {
+        Duration::from_secs(self.config.read().retry_cooldown_secs)
+    }
+}
+
 #[async_trait]
 impl<I: InferencePort + 'static> InferencePort for DegradedInferenceAdapter<I> {
     async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError> {
@@ -299,6 +405,23 @@ impl<I: InferencePort + 'static> InferencePort for DegradedInferenceAdapter<I> {
         self.handle_result(result, || self.fallback_response())
     }
 
+    async fn generate_structured(
+        &self,
+        system_prompt: &str,
+        message: &str,
+        schema: &serde_json::Value,
+    ) -> Result<InferenceResult, ApplicationError> {
+        if !self.should_retry_primary() {
+            return Ok(self.fallback_response());
+        }
+
+        let result = self
+            .inner
+            .generate_structured(system_prompt, message, schema)
+            .await;
+        self.handle_result(result, || self.fallback_response())
+    }
+
     async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError> {
         if !self.should_retry_primary() {
             return Ok(self.fallback_stream());
@@ -324,6 +447,34 @@ impl<I: InferencePort + 'static> InferencePort for DegradedInferenceAdapter<I> {
         self.handle_result(result, || self.fallback_stream())
     }
 
+    async fn generate_stream_with_context(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<InferenceStream, ApplicationError> {
+        if !self.should_retry_primary() {
+            return Ok(self.fallback_stream());
+        }
+
+        let result = self.inner.generate_stream_with_context(conversation).await;
+        self.handle_result(result, || self.fallback_stream())
+    }
+
+    async fn generate_stream_with_context_and_model(
+        &self,
+        conversation: &Conversation,
+        model: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        if !self.should_retry_primary() {
+            return Ok(self.fallback_stream());
+        }
+
+        let result = self
+            .inner
+            .generate_stream_with_context_and_model(conversation, model)
+            .await;
+        self.handle_result(result, || self.fallback_stream())
+    }
+
     async fn is_healthy(&self) -> bool {
         if self.is_degraded() {
             // In degraded mode, check periodically
@@ -342,6 +493,11 @@ impl<I: InferencePort + 'static> InferencePort for DegradedInferenceAdapter<I> {
         self.inner.is_healthy().await
     }
 
+    /// Warm up the primary backend; the outcome does not affect degraded mode
+    async fn warmup(&self) -> Result<(), ApplicationError> {
+        self.inner.warmup().await
+    }
+
     fn current_model(&self) -> String {
         if self.is_degraded() {
             "fallback (degraded)".to_string()
@@ -362,7 +518,8 @@ impl<I: InferencePort + 'static> InferencePort for DegradedInferenceAdapter<I> {
             },
             Err(e) => {
                 self.record_failure();
-                if self.config.enabled && self.is_degraded() {
+                let enabled = self.config.read().enabled;
+                if enabled && self.is_degraded() {
                     Ok(vec!["fallback".to_string()])
                 } else {
                     Err(e)
@@ -603,6 +760,100 @@ mod tests {
         assert!(chunk.done);
     }
 
+    #[tokio::test]
+    async fn test_monitor_reports_unavailable_during_cooldown() {
+        let mock = Arc::new(MockInference::new());
+        let config = DegradedModeConfig {
+            failure_threshold: 1,
+            retry_cooldown_secs: 60,
+            unavailable_message: "Back soon".to_string(),
+            ..Default::default()
+        };
+        let adapter = DegradedInferenceAdapter::new(Arc::clone(&mock), config);
+        assert_eq!(adapter.service_status(), ServiceStatus::Healthy);
+        assert!(!adapter.is_unavailable());
+
+        mock.set_fail(true);
+        let _ = adapter.generate("test").await;
+
+        assert_eq!(adapter.service_status(), ServiceStatus::Unavailable);
+        assert!(adapter.is_unavailable());
+        assert_eq!(adapter.unavailable_message(), "Back soon");
+        assert_eq!(adapter.retry_cooldown(), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_update_config_changes_threshold_and_message() {
+        let mock = Arc::new(MockInference::new());
+        let adapter = DegradedInferenceAdapter::new(
+            Arc::clone(&mock),
+            DegradedModeConfig {
+                failure_threshold: 5,
+                ..Default::default()
+            },
+        );
+
+        adapter.update_config(DegradedModeConfig {
+            failure_threshold: 1,
+            retry_cooldown_secs: 60,
+            unavailable_message: "Reloaded".to_string(),
+            ..Default::default()
+        });
+        mock.set_fail(true);
+        let result = adapter.generate("test").await.unwrap();
+
+        assert!(adapter.is_degraded());
+        assert_eq!(result.content, "Reloaded");
+        assert_eq!(adapter.retry_cooldown(), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_monitor_reports_degraded_after_cooldown() {
+        let mock = Arc::new(MockInference::new());
+        let config = DegradedModeConfig {
+            failure_threshold: 1,
+            retry_cooldown_secs: 0,
+            ..Default::default()
+        };
+        let adapter = DegradedInferenceAdapter::new(Arc::clone(&mock), config);
+
+        mock.set_fail(true);
+        let _ = adapter.generate("test").await;
+
+        // Cooldown elapsed: the primary is probed again, so requests go through
+        assert_eq!(adapter.service_status(), ServiceStatus::Degraded);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_stats_follow_degraded_mode() {
+        let mock = Arc::new(MockInference::new());
+        let config = DegradedModeConfig {
+            failure_threshold: 1,
+            success_threshold: 1,
+            retry_cooldown_secs: 0,
+            ..Default::default()
+        };
+        let adapter = DegradedInferenceAdapter::new(Arc::clone(&mock), config);
+        assert_eq!(adapter.circuit_stats().state, CircuitState::Closed);
+        assert!(adapter.circuit_stats().last_transition.is_none());
+
+        mock.set_fail(true);
+        let _ = adapter.generate("test").await;
+        assert_eq!(adapter.circuit.read().state, CircuitState::Open);
+
+        // Cooldown elapsed: the next check probes the primary
+        let stats = adapter.circuit_stats();
+        assert_eq!(stats.state, CircuitState::HalfOpen);
+        assert_eq!(stats.failure_count, 1);
+
+        mock.set_fail(false);
+        let _ = adapter.generate("test").await;
+        let stats = adapter.circuit_stats();
+        assert_eq!(stats.state, CircuitState::Closed);
+        assert_eq!(stats.failure_count, 0);
+        assert!(stats.last_transition.is_some());
+    }
+
     #[test]
     fn test_service_status_default() {
         assert_eq!(ServiceStatus::default(), ServiceStatus::Healthy);
@@ -3,7 +3,7 @@
 //! Provides authenticated encryption for sensitive memory content using
 //! the XChaCha20-Poly1305 AEAD cipher.
 
-use std::path::Path;
+use std::{io::Write, path::Path};
 
 use application::{error::ApplicationError, ports::EncryptionPort};
 use async_trait::async_trait;
@@ -11,7 +11,7 @@ use chacha20poly1305::{
     XChaCha20Poly1305,
     aead::{Aead, KeyInit, OsRng, rand_core::RngCore},
 };
-use tracing::{debug, instrument, warn};
+use tracing::{debug, info, instrument, warn};
 
 /// Nonce size for XChaCha20-Poly1305 (24 bytes)
 const NONCE_SIZE: usize = 24;
@@ -38,6 +38,9 @@ impl std::fmt::Debug for ChaChaEncryptionAdapter {
 }
 
 impl ChaChaEncryptionAdapter {
+    /// Size of the nonce prepended to every ciphertext
+    pub const NONCE_SIZE: usize = NONCE_SIZE;
+
     /// Create a new encryption adapter with the given key
     ///
     /// # Errors
@@ -73,6 +76,42 @@ impl ChaChaEncryptionAdapter {
         Self::new(&key)
     }
 
+    /// Create from a key file, generating and persisting a new key if missing
+    ///
+    /// A generated key file is readable by the owner only (mode `0600` on
+    /// Unix). An existing file is never overwritten.
+    pub fn from_key_file_or_generate(path: &Path) -> Result<Self, ApplicationError> {
+        if path.exists() {
+            return Self::from_key_file(path);
+        }
+
+        let key = Self::generate_key();
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        match options.open(path) {
+            Ok(mut file) => file.write_all(&key).and_then(|()| file.sync_all()),
+            // Created concurrently by another process; use its key
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                return Self::from_key_file(path);
+            },
+            Err(e) => Err(e),
+        }
+        .map_err(|e| {
+            ApplicationError::Configuration(format!(
+                "Failed to write encryption key file '{}': {e}",
+                path.display()
+            ))
+        })?;
+        info!(path = %path.display(), "Generated new encryption key file");
+
+        Self::new(&key)
+    }
+
     /// Generate a new random encryption key
     #[must_use]
     pub fn generate_key() -> [u8; KEY_SIZE] {
@@ -266,4 +305,30 @@ mod tests {
         let adapter = create_test_adapter();
         assert!(adapter.is_enabled());
     }
+
+    #[tokio::test]
+    async fn key_file_is_generated_once_and_reused() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("conversation.key");
+
+        let first = ChaChaEncryptionAdapter::from_key_file_or_generate(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap().len(), KEY_SIZE);
+
+        let second = ChaChaEncryptionAdapter::from_key_file_or_generate(&path).unwrap();
+        let encrypted = first.encrypt(b"secret").await.unwrap();
+        assert_eq!(second.decrypt(&encrypted).await.unwrap(), b"secret");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn generated_key_file_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("conversation.key");
+        ChaChaEncryptionAdapter::from_key_file_or_generate(&path).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
 }
@@ -0,0 +1,201 @@
+//! Idempotent outbound sends
+//!
+//! Messenger adapters remember the idempotency keys of recently sent
+//! messages together with the platform message ID. A retried send with a
+//! known key returns the original ID instead of delivering the message a
+//! second time.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use infrastructure::adapters::SentMessageCache;
+//!
+//! let sent = SentMessageCache::default();
+//! let message_id = sent
+//!     .send_once(message.idempotency_key(), || client.send(&message))
+//!     .await?;
+//! ```
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use application::error::ApplicationError;
+use moka::future::Cache;
+use tracing::debug;
+
+/// How long a sent key is remembered by default
+const DEFAULT_TTL: Duration = Duration::from_secs(600);
+
+/// Maximum number of remembered keys by default
+const DEFAULT_CAPACITY: u64 = 1024;
+
+/// TTL store of recently sent idempotency keys
+///
+/// Cloning is cheap; clones share the same entries. Only successful sends
+/// are recorded, so a failed send can be retried under the same key.
+/// Concurrent sends with the same key are coalesced into a single send.
+#[derive(Debug, Clone)]
+pub struct SentMessageCache {
+    sent: Cache<String, String>,
+}
+
+impl SentMessageCache {
+    /// Create a cache remembering up to `capacity` keys for `ttl`
+    #[must_use]
+    pub fn new(ttl: Duration, capacity: u64) -> Self {
+        Self {
+            sent: Cache::builder()
+                .time_to_live(ttl)
+                .max_capacity(capacity)
+                .build(),
+        }
+    }
+
+    /// Run `send` unless a message with the same key was sent recently
+    ///
+    /// Returns the platform message ID of the original send for duplicates.
+    /// Callers racing on the same key wait for the in-flight send and share
+    /// its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `send`; nothing is recorded in that case.
+    pub async fn send_once<F, Fut>(&self, key: String, send: F) -> Result<String, ApplicationError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, ApplicationError>>,
+    {
+        let entry = self
+            .sent
+            .entry(key)
+            .or_try_insert_with(send())
+            .await
+            .map_err(|e| {
+                Arc::try_unwrap(e)
+                    .unwrap_or_else(|shared| ApplicationError::ExternalService(shared.to_string()))
+            })?;
+
+        if !entry.is_fresh() {
+            debug!(key = %entry.key(), message_id = %entry.value(), "Skipping duplicate send");
+        }
+        Ok(entry.into_value())
+    }
+}
+
+impl Default for SentMessageCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL, DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    async fn counted_send(calls: &AtomicUsize) -> Result<String, ApplicationError> {
+        let n = calls.fetch_add(1, Ordering::SeqCst);
+        Ok(format!("msg-{n}"))
+    }
+
+    #[tokio::test]
+    async fn same_key_sends_once() {
+        let cache = SentMessageCache::default();
+        let calls = AtomicUsize::new(0);
+
+        let first = cache
+            .send_once("key".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+        let second = cache
+            .send_once("key".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first, "msg-0");
+        assert_eq!(second, first);
+    }
+
+    #[tokio::test]
+    async fn different_keys_send_separately() {
+        let cache = SentMessageCache::default();
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .send_once("a".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+        cache
+            .send_once("b".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_send_is_not_recorded() {
+        let cache = SentMessageCache::default();
+        let calls = AtomicUsize::new(0);
+
+        let result = cache
+            .send_once("key".to_string(), || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(ApplicationError::RateLimited)
+            })
+            .await;
+        assert!(matches!(result, Err(ApplicationError::RateLimited)));
+
+        cache
+            .send_once("key".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_sends_with_same_key_send_once() {
+        let cache = SentMessageCache::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let cache = cache.clone();
+                let calls = Arc::clone(&calls);
+                tokio::spawn(async move {
+                    cache
+                        .send_once("key".to_string(), || async {
+                            tokio::time::sleep(Duration::from_millis(20)).await;
+                            counted_send(&calls).await
+                        })
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), "msg-0");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn key_expires_after_ttl() {
+        let cache = SentMessageCache::new(Duration::from_millis(50), 16);
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .send_once("key".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cache
+            .send_once("key".to_string(), || counted_send(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
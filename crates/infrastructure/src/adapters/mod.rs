@@ -3,20 +3,27 @@
 //! Adapters connect application ports to concrete implementations.
 
 mod api_key_hasher;
+mod bulkhead;
 mod cached_inference_adapter;
+mod caching_secret_store;
 mod caldav_calendar_adapter;
 mod carddav_contact_adapter;
 mod circuit_breaker;
 mod degraded_inference;
 mod encryption_adapter;
 mod env_secret_store;
+mod idempotency;
 mod model_registry_adapter;
+mod ollama_embedding_adapter;
 mod ollama_inference_adapter;
 mod proton_email_adapter;
+mod queued_messenger;
+mod shared_secret;
 mod signal_adapter;
 mod speech_adapter;
 mod suspicious_activity_adapter;
 mod task_adapter;
+mod timer_adapter;
 mod transit_adapter;
 mod vault_secret_store;
 mod weather_adapter;
@@ -24,24 +31,33 @@ mod websearch_adapter;
 mod whatsapp_adapter;
 
 pub use api_key_hasher::{ApiKeyHashError, ApiKeyHasher};
+pub use bulkhead::{Bulkhead, BulkheadFull, BulkheadPermit};
 pub use cached_inference_adapter::CachedInferenceAdapter;
+pub use caching_secret_store::CachingSecretStore;
 pub use caldav_calendar_adapter::CalDavCalendarAdapter;
 pub use carddav_contact_adapter::CardDavContactAdapter;
 pub use circuit_breaker::{
-    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitOpenError, CircuitState,
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitBreakerEvent,
+    CircuitBreakerStats, CircuitOpenError, CircuitState, subscribe_circuit_events,
 };
 pub use degraded_inference::{
-    DegradedInferenceAdapter, DegradedModeConfig, DegradedModeStats, ServiceStatus,
+    DEGRADED_MODE_CIRCUIT, DegradedInferenceAdapter, DegradedModeConfig, DegradedModeMonitor,
+    DegradedModeStats, ServiceStatus,
 };
 pub use encryption_adapter::ChaChaEncryptionAdapter;
 pub use env_secret_store::EnvSecretStore;
-pub use model_registry_adapter::OllamaModelRegistryAdapter;
+pub use idempotency::SentMessageCache;
+pub use model_registry_adapter::{OllamaModelRegistryAdapter, OllamaModelRegistryConfig};
+pub use ollama_embedding_adapter::OllamaEmbeddingAdapter;
 pub use ollama_inference_adapter::OllamaInferenceAdapter;
 pub use proton_email_adapter::ProtonEmailAdapter;
+pub use queued_messenger::QueuedMessenger;
+pub use shared_secret::SharedSecret;
 pub use signal_adapter::SignalMessengerAdapter;
 pub use speech_adapter::SpeechAdapter;
 pub use suspicious_activity_adapter::InMemorySuspiciousActivityTracker;
 pub use task_adapter::TaskAdapter;
+pub use timer_adapter::SchedulerTimerAdapter;
 pub use transit_adapter::TransitAdapter;
 pub use vault_secret_store::{ChainedSecretStore, VaultConfig, VaultSecretStore};
 pub use weather_adapter::WeatherAdapter;
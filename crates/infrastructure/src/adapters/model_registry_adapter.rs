@@ -1,4 +1,9 @@
 //! Model registry adapter - Implements ModelRegistryPort using Ollama API
+//!
+//! Models are listed via `/v1/models`; capabilities, context window and
+//! parameter size come from `/api/show` where the backend supports it.
+//! Backends without `/api/show` (hailo-ollama) fall back to estimates from
+//! the model name.
 
 use application::error::ApplicationError;
 use application::ports::{ModelCapabilities, ModelInfo, ModelRegistryPort};
@@ -6,6 +11,7 @@ use async_trait::async_trait;
 use parking_lot::RwLock;
 use reqwest::Client;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::{debug, instrument};
@@ -39,11 +45,26 @@ struct ModelCache {
     fetched_at: Instant,
 }
 
+/// Model details from `/api/show`
+#[derive(Debug, Clone, Default)]
+struct ModelDetails {
+    parameter_size: Option<String>,
+    context_length: Option<u32>,
+    /// Capability names (`completion`, `tools`, `vision`, `embedding`,
+    /// `insert`); `None` on Ollama versions that do not report them
+    capabilities: Option<Vec<String>>,
+    /// Model families; vision models include a `clip` projector
+    families: Vec<String>,
+}
+
 /// Adapter for model registry using Ollama API
 pub struct OllamaModelRegistryAdapter {
     client: Client,
     config: OllamaModelRegistryConfig,
     cache: Arc<RwLock<Option<ModelCache>>>,
+    /// Details per model ID; they only change when a model is re-pulled,
+    /// so they outlive the list cache and are dropped on refresh
+    details: Arc<RwLock<HashMap<String, ModelDetails>>>,
     circuit_breaker: Option<CircuitBreaker>,
 }
 
@@ -84,6 +105,7 @@ impl OllamaModelRegistryAdapter {
             client,
             config,
             cache: Arc::new(RwLock::new(None)),
+            details: Arc::new(RwLock::new(HashMap::new())),
             circuit_breaker: None,
         })
     }
@@ -136,6 +158,7 @@ impl OllamaModelRegistryAdapter {
     fn clear_cache(&self) {
         let mut cache = self.cache.write();
         *cache = None;
+        self.details.write().clear();
     }
 
     /// Fetch models from the API
@@ -162,11 +185,106 @@ impl OllamaModelRegistryAdapter {
             .await
             .map_err(|e| ApplicationError::Internal(format!("Failed to parse response: {e}")))?;
 
-        let models = api_response.data.iter().map(Self::convert_model).collect();
+        let details = futures::future::join_all(
+            api_response
+                .data
+                .iter()
+                .map(|model| self.model_details(&model.id)),
+        )
+        .await;
+
+        let models = api_response
+            .data
+            .iter()
+            .zip(details)
+            .map(|(model, details)| {
+                let mut info = Self::convert_model(model);
+                if let Some(ref details) = details {
+                    Self::apply_details(&mut info, details);
+                }
+                info
+            })
+            .collect();
 
         Ok(models)
     }
 
+    /// Get the details of a model, from the cache or `/api/show`
+    ///
+    /// Returns `None` if the backend cannot describe the model; failures
+    /// are not cached so the next list fetch retries.
+    async fn model_details(&self, model_id: &str) -> Option<ModelDetails> {
+        let cached = self.details.read().get(model_id).cloned();
+        if cached.is_some() {
+            return cached;
+        }
+
+        match self.fetch_details(model_id).await {
+            Ok(details) => {
+                self.details
+                    .write()
+                    .insert(model_id.to_string(), details.clone());
+                Some(details)
+            },
+            Err(e) => {
+                debug!(model = %model_id, error = %e, "Model details unavailable, using estimates");
+                None
+            },
+        }
+    }
+
+    /// Query `/api/show` for a model
+    async fn fetch_details(&self, model_id: &str) -> Result<ModelDetails, ApplicationError> {
+        let url = format!("{}/api/show", self.config.base_url);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&serde_json::json!({ "model": model_id }))
+            .send()
+            .await
+            .map_err(|e| ApplicationError::ExternalService(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(ApplicationError::ExternalService(format!(
+                "API returned {}",
+                response.status()
+            )));
+        }
+
+        let show: OllamaShowResponse = response
+            .json()
+            .await
+            .map_err(|e| ApplicationError::Internal(format!("Failed to parse response: {e}")))?;
+
+        Ok(show.into_details())
+    }
+
+    /// Replace estimated capabilities with what the backend reported
+    fn apply_details(info: &mut ModelInfo, details: &ModelDetails) {
+        let capabilities = &mut info.capabilities;
+
+        if details.context_length.is_some() {
+            capabilities.context_length = details.context_length;
+        }
+        info.parameter_size.clone_from(&details.parameter_size);
+
+        match details.capabilities {
+            Some(ref reported) => {
+                let has = |name: &str| reported.iter().any(|c| c == name);
+                capabilities.text_generation = has("completion");
+                capabilities.chat = has("completion");
+                capabilities.embeddings = has("embedding");
+                capabilities.tools = has("tools");
+                capabilities.vision = has("vision");
+                capabilities.code = capabilities.code || has("insert");
+            },
+            None => {
+                capabilities.vision = details.families.iter().any(|f| f == "clip");
+            },
+        }
+    }
+
     /// Convert API model to ModelInfo
     fn convert_model(model: &OllamaModel) -> ModelInfo {
         // Parse model name to extract variant
@@ -184,9 +302,12 @@ impl OllamaModelRegistryAdapter {
                 chat: true,
                 embeddings: model.id.contains("embed"),
                 code: model.id.contains("code") || model.id.contains("starcoder"),
+                tools: false,
+                vision: false,
                 context_length: Some(context_length),
             },
             variant,
+            parameter_size: None,
             available: true,
         }
     }
@@ -303,6 +424,48 @@ struct OllamaModel {
     owned_by: String,
 }
 
+/// Ollama `/api/show` response (fields used for capabilities)
+#[derive(Debug, Default, Deserialize)]
+struct OllamaShowResponse {
+    #[serde(default)]
+    details: OllamaShowDetails,
+    /// GGUF metadata, e.g. `general.architecture` and `llama.context_length`
+    #[serde(default)]
+    model_info: HashMap<String, serde_json::Value>,
+    /// Reported since Ollama 0.6
+    #[serde(default)]
+    capabilities: Option<Vec<String>>,
+}
+
+/// Ollama `/api/show` model details
+#[derive(Debug, Default, Deserialize)]
+struct OllamaShowDetails {
+    #[serde(default)]
+    parameter_size: Option<String>,
+    #[serde(default)]
+    families: Option<Vec<String>>,
+}
+
+impl OllamaShowResponse {
+    /// Context window from the `<architecture>.context_length` metadata key
+    fn context_length(&self) -> Option<u32> {
+        let architecture = self.model_info.get("general.architecture")?.as_str()?;
+        self.model_info
+            .get(&format!("{architecture}.context_length"))?
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+    }
+
+    fn into_details(self) -> ModelDetails {
+        ModelDetails {
+            context_length: self.context_length(),
+            parameter_size: self.details.parameter_size,
+            capabilities: self.capabilities,
+            families: self.details.families.unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -422,4 +585,95 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<OllamaModelRegistryAdapter>();
     }
+
+    fn show_response(json: serde_json::Value) -> ModelDetails {
+        serde_json::from_value::<OllamaShowResponse>(json)
+            .unwrap()
+            .into_details()
+    }
+
+    #[test]
+    fn show_response_details() {
+        let details = show_response(serde_json::json!({
+            "details": {"family": "qwen2", "parameter_size": "1.5B"},
+            "model_info": {
+                "general.architecture": "qwen2",
+                "qwen2.context_length": 32768
+            },
+            "capabilities": ["completion", "tools"]
+        }));
+
+        assert_eq!(details.parameter_size.as_deref(), Some("1.5B"));
+        assert_eq!(details.context_length, Some(32_768));
+        assert_eq!(
+            details.capabilities,
+            Some(vec!["completion".to_string(), "tools".to_string()])
+        );
+    }
+
+    #[test]
+    fn apply_details_overrides_estimates() {
+        let api_model = OllamaModel {
+            id: "llama3.2-vision:11b".to_string(),
+            object: "model".to_string(),
+            owned_by: "library".to_string(),
+        };
+        let details = show_response(serde_json::json!({
+            "details": {"parameter_size": "10.7B"},
+            "model_info": {
+                "general.architecture": "mllama",
+                "mllama.context_length": 131072
+            },
+            "capabilities": ["completion", "vision"]
+        }));
+
+        let mut model = OllamaModelRegistryAdapter::convert_model(&api_model);
+        OllamaModelRegistryAdapter::apply_details(&mut model, &details);
+
+        assert_eq!(model.parameter_size.as_deref(), Some("10.7B"));
+        assert_eq!(model.capabilities.context_length, Some(131_072));
+        assert!(model.capabilities.chat);
+        assert!(model.capabilities.vision);
+        assert!(!model.capabilities.tools);
+        assert!(!model.capabilities.embeddings);
+    }
+
+    #[test]
+    fn apply_details_embedding_model() {
+        let api_model = OllamaModel {
+            id: "bge-m3".to_string(),
+            object: "model".to_string(),
+            owned_by: "library".to_string(),
+        };
+        let details = show_response(serde_json::json!({
+            "capabilities": ["embedding"]
+        }));
+
+        let mut model = OllamaModelRegistryAdapter::convert_model(&api_model);
+        OllamaModelRegistryAdapter::apply_details(&mut model, &details);
+
+        assert!(model.capabilities.embeddings);
+        assert!(!model.capabilities.chat);
+        assert!(!model.capabilities.text_generation);
+        // Context estimate is kept when the backend reports none
+        assert_eq!(model.capabilities.context_length, Some(4_096));
+    }
+
+    #[test]
+    fn apply_details_without_capability_list() {
+        let api_model = OllamaModel {
+            id: "llava:7b".to_string(),
+            object: "model".to_string(),
+            owned_by: "library".to_string(),
+        };
+        let details = show_response(serde_json::json!({
+            "details": {"families": ["llama", "clip"]}
+        }));
+
+        let mut model = OllamaModelRegistryAdapter::convert_model(&api_model);
+        OllamaModelRegistryAdapter::apply_details(&mut model, &details);
+
+        assert!(model.capabilities.vision);
+        assert!(model.capabilities.chat);
+    }
 }
@@ -0,0 +1,82 @@
+//! Ollama embedding adapter - Implements EmbeddingPort using ai_core
+
+use ai_core::{EmbeddingConfig, InferenceError, OllamaEmbeddingEngine};
+use application::{
+    error::ApplicationError,
+    ports::{EmbeddingModelInfo, EmbeddingPort},
+};
+use async_trait::async_trait;
+
+/// Adapter for Ollama-compatible embedding models
+#[derive(Debug)]
+pub struct OllamaEmbeddingAdapter {
+    engine: OllamaEmbeddingEngine,
+}
+
+impl OllamaEmbeddingAdapter {
+    /// Create a new adapter with the given configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client fails to initialize.
+    pub fn new(config: EmbeddingConfig) -> Result<Self, ApplicationError> {
+        let engine = OllamaEmbeddingEngine::new(config).map_err(Self::map_error)?;
+        Ok(Self { engine })
+    }
+
+    /// Convert ai_core error to application error
+    fn map_error(e: InferenceError) -> ApplicationError {
+        match e {
+            InferenceError::RateLimited => ApplicationError::RateLimited,
+            InferenceError::ConnectionFailed(msg) => {
+                ApplicationError::ExternalService(format!("Ollama connection failed: {msg}"))
+            },
+            InferenceError::Timeout(ms) => {
+                ApplicationError::ExternalService(format!("Embedding timeout after {ms}ms"))
+            },
+            other => ApplicationError::Inference(other.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingPort for OllamaEmbeddingAdapter {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, ApplicationError> {
+        self.engine.embed(text).await.map_err(Self::map_error)
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, ApplicationError> {
+        self.engine
+            .embed_batch(texts)
+            .await
+            .map_err(Self::map_error)
+    }
+
+    fn model_info(&self) -> EmbeddingModelInfo {
+        EmbeddingModelInfo {
+            model: self.engine.model().to_string(),
+            dimensions: self.engine.dimensions(),
+            max_tokens: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn model_info_reflects_config() {
+        let adapter = OllamaEmbeddingAdapter::new(EmbeddingConfig::nomic_embed_text()).unwrap();
+        let info = adapter.model_info();
+        assert_eq!(info.model, "nomic-embed-text");
+        assert_eq!(info.dimensions, 384);
+    }
+
+    #[test]
+    fn timeout_maps_to_external_service() {
+        let err = OllamaEmbeddingAdapter::map_error(InferenceError::Timeout(500));
+        assert!(matches!(err, ApplicationError::ExternalService(_)));
+        assert!(err.is_retryable());
+    }
+}
@@ -18,6 +18,9 @@ use tracing::{debug, info, instrument, warn};
 
 use super::{CircuitBreaker, CircuitBreakerConfig};
 
+/// Prompt used to force the model to load
+const WARMUP_PROMPT: &str = "Hi";
+
 /// Adapter for Ollama-compatible inference servers
 #[derive(Debug)]
 pub struct OllamaInferenceAdapter {
@@ -86,21 +89,56 @@ impl OllamaInferenceAdapter {
             .is_some_and(CircuitBreaker::is_open)
     }
 
-    /// Get circuit breaker state description for logging
-    fn circuit_state_desc(&self) -> &'static str {
-        match &self.circuit_breaker {
-            Some(cb) if cb.is_open() => "open",
-            Some(cb) if cb.is_closed() => "closed",
-            Some(_) => "half-open",
-            None => "disabled",
+    /// Build an inference request from a conversation
+    ///
+    /// The conversation's own system prompt takes precedence over the
+    /// adapter's configured one. A rolling summary of earlier turns follows
+    /// as a separate system message.
+    fn context_request(&self, conversation: &Conversation) -> InferenceRequest {
+        let mut messages: Vec<ai_core::ports::InferenceMessage> = Vec::new();
+
+        // Add system prompt if configured
+        if let Some(system) = conversation
+            .system_prompt
+            .as_ref()
+            .or(self.system_prompt.as_ref())
+        {
+            messages.push(ai_core::ports::InferenceMessage {
+                role: "system".to_string(),
+                content: system.clone(),
+            });
+        }
+
+        // Add summary of turns that are no longer kept verbatim
+        if let Some(summary) = &conversation.summary {
+            messages.push(ai_core::ports::InferenceMessage {
+                role: "system".to_string(),
+                content: format!("Summary of the earlier conversation:\n{summary}"),
+            });
+        }
+
+        // Add conversation messages
+        for msg in &conversation.messages {
+            messages.push(ai_core::ports::InferenceMessage::from(msg));
+        }
+
+        InferenceRequest {
+            messages,
+            model: None,
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            seed: None,
+            format: None,
+            stream: false,
         }
     }
-}
 
-#[async_trait]
-impl InferencePort for OllamaInferenceAdapter {
-    #[instrument(skip(self, message), fields(message_len = message.len(), circuit = %self.circuit_state_desc()))]
-    async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError> {
+    /// Stream a conversation request built by [`Self::context_request`]
+    async fn stream_context_request(
+        &self,
+        request: InferenceRequest,
+    ) -> Result<InferenceStream, ApplicationError> {
         // Fast-fail if circuit is open
         if self.is_circuit_open() {
             warn!("Ollama inference circuit breaker is open, failing fast");
@@ -110,13 +148,43 @@ impl InferencePort for OllamaInferenceAdapter {
             ));
         }
 
-        let start = Instant::now();
+        let request = request.streaming();
 
-        #[allow(clippy::option_if_let_else)]
-        let request = match &self.system_prompt {
-            Some(system) => InferenceRequest::with_system(system, message),
-            None => InferenceRequest::simple(message),
-        };
+        let stream = self
+            .engine
+            .generate_stream(request)
+            .await
+            .map_err(Self::map_error)?;
+
+        // Map ai_core::StreamingChunk to application::StreamingChunk
+        let mapped_stream = stream.map(|result| {
+            result
+                .map(|chunk| StreamingChunk {
+                    content: chunk.content,
+                    done: chunk.done,
+                    model: chunk.model,
+                })
+                .map_err(|e| ApplicationError::Inference(e.to_string()))
+        });
+
+        Ok(Box::pin(mapped_stream))
+    }
+
+    /// Run a single non-streaming request through the circuit breaker
+    async fn generate_request(
+        &self,
+        request: InferenceRequest,
+    ) -> Result<InferenceResult, ApplicationError> {
+        // Fast-fail if circuit is open
+        if self.is_circuit_open() {
+            warn!("Ollama inference circuit breaker is open, failing fast");
+            return Err(ApplicationError::ExternalService(
+                "Ollama inference service temporarily unavailable (circuit breaker open)"
+                    .to_string(),
+            ));
+        }
+
+        let start = Instant::now();
 
         let response = match &self.circuit_breaker {
             Some(cb) => {
@@ -143,13 +211,6 @@ impl InferencePort for OllamaInferenceAdapter {
         #[allow(clippy::cast_possible_truncation)]
         let latency_ms = start.elapsed().as_millis() as u64;
 
-        debug!(
-            model = %response.model,
-            tokens = ?response.usage.as_ref().map(|u| u.total_tokens),
-            latency_ms = latency_ms,
-            "Inference completed"
-        );
-
         Ok(InferenceResult {
             content: response.content,
             model: response.model,
@@ -158,11 +219,21 @@ impl InferencePort for OllamaInferenceAdapter {
         })
     }
 
-    #[instrument(skip(self, conversation), fields(conv_id = %conversation.id, circuit = %self.circuit_state_desc()))]
-    async fn generate_with_context(
-        &self,
-        conversation: &Conversation,
-    ) -> Result<InferenceResult, ApplicationError> {
+    /// Get circuit breaker state description for logging
+    fn circuit_state_desc(&self) -> &'static str {
+        match &self.circuit_breaker {
+            Some(cb) if cb.is_open() => "open",
+            Some(cb) if cb.is_closed() => "closed",
+            Some(_) => "half-open",
+            None => "disabled",
+        }
+    }
+}
+
+#[async_trait]
+impl InferencePort for OllamaInferenceAdapter {
+    #[instrument(skip(self, message), fields(message_len = message.len(), circuit = %self.circuit_state_desc()))]
+    async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError> {
         // Fast-fail if circuit is open
         if self.is_circuit_open() {
             warn!("Ollama inference circuit breaker is open, failing fast");
@@ -174,32 +245,10 @@ impl InferencePort for OllamaInferenceAdapter {
 
         let start = Instant::now();
 
-        // Build messages from conversation
-        let mut messages: Vec<ai_core::ports::InferenceMessage> = Vec::new();
-
-        // Add system prompt if configured
-        if let Some(system) = conversation
-            .system_prompt
-            .as_ref()
-            .or(self.system_prompt.as_ref())
-        {
-            messages.push(ai_core::ports::InferenceMessage {
-                role: "system".to_string(),
-                content: system.clone(),
-            });
-        }
-
-        // Add conversation messages
-        for msg in &conversation.messages {
-            messages.push(ai_core::ports::InferenceMessage::from(msg));
-        }
-
-        let request = InferenceRequest {
-            messages,
-            model: None,
-            max_tokens: None,
-            temperature: None,
-            stream: false,
+        #[allow(clippy::option_if_let_else)]
+        let request = match &self.system_prompt {
+            Some(system) => InferenceRequest::with_system(system, message),
+            None => InferenceRequest::simple(message),
         };
 
         let response = match &self.circuit_breaker {
@@ -227,6 +276,13 @@ impl InferencePort for OllamaInferenceAdapter {
         #[allow(clippy::cast_possible_truncation)]
         let latency_ms = start.elapsed().as_millis() as u64;
 
+        debug!(
+            model = %response.model,
+            tokens = ?response.usage.as_ref().map(|u| u.total_tokens),
+            latency_ms = latency_ms,
+            "Inference completed"
+        );
+
         Ok(InferenceResult {
             content: response.content,
             model: response.model,
@@ -235,11 +291,10 @@ impl InferencePort for OllamaInferenceAdapter {
         })
     }
 
-    #[instrument(skip(self, system_prompt, message), fields(circuit = %self.circuit_state_desc()))]
-    async fn generate_with_system(
+    #[instrument(skip(self, conversation), fields(conv_id = %conversation.id, circuit = %self.circuit_state_desc()))]
+    async fn generate_with_context(
         &self,
-        system_prompt: &str,
-        message: &str,
+        conversation: &Conversation,
     ) -> Result<InferenceResult, ApplicationError> {
         // Fast-fail if circuit is open
         if self.is_circuit_open() {
@@ -252,7 +307,7 @@ impl InferencePort for OllamaInferenceAdapter {
 
         let start = Instant::now();
 
-        let request = InferenceRequest::with_system(system_prompt, message);
+        let request = self.context_request(conversation);
 
         let response = match &self.circuit_breaker {
             Some(cb) => {
@@ -287,6 +342,30 @@ impl InferencePort for OllamaInferenceAdapter {
         })
     }
 
+    #[instrument(skip(self, system_prompt, message), fields(circuit = %self.circuit_state_desc()))]
+    async fn generate_with_system(
+        &self,
+        system_prompt: &str,
+        message: &str,
+    ) -> Result<InferenceResult, ApplicationError> {
+        self.generate_request(InferenceRequest::with_system(system_prompt, message))
+            .await
+    }
+
+    /// Passes the schema as Ollama's `format` so decoding is constrained to it
+    #[instrument(skip(self, system_prompt, message, schema), fields(circuit = %self.circuit_state_desc()))]
+    async fn generate_structured(
+        &self,
+        system_prompt: &str,
+        message: &str,
+        schema: &serde_json::Value,
+    ) -> Result<InferenceResult, ApplicationError> {
+        self.generate_request(
+            InferenceRequest::with_system(system_prompt, message).with_json_schema(schema.clone()),
+        )
+        .await
+    }
+
     #[instrument(skip(self, message), fields(message_len = message.len(), circuit = %self.circuit_state_desc()))]
     async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError> {
         // Fast-fail if circuit is open
@@ -363,6 +442,25 @@ impl InferencePort for OllamaInferenceAdapter {
         Ok(Box::pin(mapped_stream))
     }
 
+    #[instrument(skip(self, conversation), fields(conv_id = %conversation.id, circuit = %self.circuit_state_desc()))]
+    async fn generate_stream_with_context(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<InferenceStream, ApplicationError> {
+        self.stream_context_request(self.context_request(conversation))
+            .await
+    }
+
+    #[instrument(skip(self, conversation), fields(conv_id = %conversation.id, circuit = %self.circuit_state_desc()))]
+    async fn generate_stream_with_context_and_model(
+        &self,
+        conversation: &Conversation,
+        model: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        self.stream_context_request(self.context_request(conversation).with_model(model))
+            .await
+    }
+
     async fn is_healthy(&self) -> bool {
         // If circuit breaker is open, report as unhealthy
         if self.is_circuit_open() {
@@ -372,6 +470,22 @@ impl InferencePort for OllamaInferenceAdapter {
         self.engine.health_check().await.unwrap_or(false)
     }
 
+    /// Ask for a single token, which makes the server load the model
+    #[instrument(skip(self), fields(model = %self.engine.default_model()))]
+    async fn warmup(&self) -> Result<(), ApplicationError> {
+        let start = Instant::now();
+        let request = InferenceRequest::simple(WARMUP_PROMPT).with_max_tokens(1);
+        self.engine
+            .generate(request)
+            .await
+            .map_err(|e| ApplicationError::Inference(format!("Model warmup failed: {e}")))?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let latency_ms = start.elapsed().as_millis() as u64;
+        info!(latency_ms, "Model warmed up");
+        Ok(())
+    }
+
     fn current_model(&self) -> String {
         self.engine.default_model()
     }
@@ -434,11 +548,34 @@ mod tests {
             temperature: 0.7,
             top_p: 0.9,
             system_prompt: None,
+            warmup_on_start: true,
         };
         // Just test that the config can be created
         assert_eq!(config.default_model, "test-model");
     }
 
+    #[test]
+    fn context_request_includes_summary_after_system_prompt() {
+        let adapter = OllamaInferenceAdapter::with_defaults()
+            .unwrap()
+            .with_system_prompt("Be brief.");
+        let mut conversation = Conversation::new();
+        conversation.add_user_message("Old question");
+        conversation.compact(1, "The user asked an old question.");
+        conversation.add_user_message("New question");
+
+        let request = adapter.context_request(&conversation);
+
+        let roles: Vec<&str> = request.messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, ["system", "system", "user"]);
+        assert!(
+            request.messages[1]
+                .content
+                .contains("The user asked an old question.")
+        );
+        assert_eq!(request.messages[2].content, "New question");
+    }
+
     #[test]
     fn inference_config_default_model() {
         let config = InferenceConfig::hailo_qwen();
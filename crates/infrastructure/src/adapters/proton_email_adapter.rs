@@ -2,24 +2,41 @@
 
 use application::ports::{EmailDraft, EmailError, EmailPort, EmailSummary};
 use async_trait::async_trait;
+use domain::EmailAddress;
 use integration_proton::{
     EmailComposition, EmailSummary as ProtonEmailSummary, ProtonBridgeClient, ProtonClient,
     ProtonConfig, ProtonError,
 };
-use tracing::{debug, instrument, warn};
+use std::{future::Future, sync::Arc, time::Duration};
 
-use super::{CircuitBreaker, CircuitBreakerConfig};
+use parking_lot::RwLock;
+use secrecy::{ExposeSecret, SecretString};
+use tracing::{debug, info, instrument, warn};
+
+use super::{CircuitBreaker, CircuitBreakerConfig, SharedSecret};
+use crate::retry::with_timeout;
+
+/// Bridge client together with the rotating password it was built with
+struct ClientState {
+    client: Arc<ProtonBridgeClient>,
+    password: Option<Arc<SecretString>>,
+}
 
 /// Adapter for Proton Mail via Proton Bridge
 pub struct ProtonEmailAdapter {
-    client: ProtonBridgeClient,
+    config: ProtonConfig,
+    state: RwLock<ClientState>,
+    password: Option<SharedSecret>,
     circuit_breaker: Option<CircuitBreaker>,
+    timeout: Option<Duration>,
 }
 
 impl std::fmt::Debug for ProtonEmailAdapter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ProtonEmailAdapter")
-            .field("client", &self.client)
+            .field("client", &self.state.read().client)
+            .field("rotating_password", &self.password.is_some())
+            .field("timeout", &self.timeout)
             .field(
                 "circuit_breaker",
                 &self
@@ -34,10 +51,16 @@ impl std::fmt::Debug for ProtonEmailAdapter {
 impl ProtonEmailAdapter {
     /// Create a new adapter with the given configuration
     pub fn new(config: ProtonConfig) -> Self {
-        let client = ProtonBridgeClient::new(config);
+        let client = Arc::new(ProtonBridgeClient::new(config.clone()));
         Self {
-            client,
+            config,
+            state: RwLock::new(ClientState {
+                client,
+                password: None,
+            }),
+            password: None,
             circuit_breaker: None,
+            timeout: None,
         }
     }
 
@@ -56,6 +79,16 @@ impl ProtonEmailAdapter {
         Self::new(config)
     }
 
+    /// Read the Bridge password from a shared secret
+    ///
+    /// When the secret is rotated, the next call reconnects with the new
+    /// password.
+    #[must_use]
+    pub fn with_shared_password(mut self, password: SharedSecret) -> Self {
+        self.password = Some(password);
+        self
+    }
+
     /// Enable circuit breaker with default configuration
     #[must_use]
     pub fn with_circuit_breaker(mut self) -> Self {
@@ -70,6 +103,61 @@ impl ProtonEmailAdapter {
         self
     }
 
+    /// Give up on a single Bridge call after `timeout`
+    ///
+    /// Bounds IMAP/SMTP sessions that stall without closing the connection.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Await a Bridge call, bounded by the configured timeout
+    async fn bounded<T>(
+        &self,
+        call: impl Future<Output = Result<T, ProtonError>>,
+    ) -> Result<T, EmailError> {
+        let result = match self.timeout {
+            Some(timeout) => with_timeout(timeout, call)
+                .await
+                .map_err(|_| EmailError::ServiceUnavailable)?,
+            None => call.await,
+        };
+        result.map_err(Self::map_error)
+    }
+
+    /// Current Bridge client, rebuilt if the shared password was rotated
+    fn client(&self) -> Arc<ProtonBridgeClient> {
+        let Some(ref password) = self.password else {
+            return Arc::clone(&self.state.read().client);
+        };
+
+        let current = password.current();
+        {
+            let state = self.state.read();
+            if state
+                .password
+                .as_ref()
+                .is_some_and(|used| Arc::ptr_eq(used, &current))
+            {
+                return Arc::clone(&state.client);
+            }
+        }
+
+        let mut config = self.config.clone();
+        config.password = current.expose_secret().to_string();
+        let client = Arc::new(ProtonBridgeClient::new(config));
+        let mut state = self.state.write();
+        if state.password.is_some() {
+            info!("Proton Bridge password rotated, using new credentials");
+        }
+        *state = ClientState {
+            client: Arc::clone(&client),
+            password: Some(current),
+        };
+        client
+    }
+
     /// Check if circuit breaker is blocking requests
     fn is_circuit_open(&self) -> bool {
         self.circuit_breaker
@@ -110,6 +198,14 @@ impl ProtonEmailAdapter {
         }
     }
 
+    /// Recipient in transport form, with internationalized domains
+    /// punycode-encoded
+    ///
+    /// Addresses that don't parse are passed through for Bridge to judge.
+    fn transport_address(address: &str) -> String {
+        EmailAddress::new(address).map_or_else(|_| address.to_string(), |a| a.to_ascii())
+    }
+
     /// Convert ProtonEmailSummary to port EmailSummary
     fn convert_summary(summary: &ProtonEmailSummary) -> EmailSummary {
         EmailSummary::new(&summary.id, &summary.from, &summary.subject)
@@ -126,11 +222,7 @@ impl EmailPort for ProtonEmailAdapter {
         self.check_circuit()?;
         debug!(count, "Getting inbox from Proton");
 
-        let emails = self
-            .client
-            .get_inbox(count)
-            .await
-            .map_err(Self::map_error)?;
+        let emails = self.bounded(self.client().get_inbox(count)).await?;
 
         Ok(emails.iter().map(Self::convert_summary).collect())
     }
@@ -145,10 +237,8 @@ impl EmailPort for ProtonEmailAdapter {
         debug!(mailbox, count, "Getting mailbox from Proton");
 
         let emails = self
-            .client
-            .get_mailbox(mailbox, count)
-            .await
-            .map_err(Self::map_error)?;
+            .bounded(self.client().get_mailbox(mailbox, count))
+            .await?;
 
         Ok(emails.iter().map(Self::convert_summary).collect())
     }
@@ -156,34 +246,25 @@ impl EmailPort for ProtonEmailAdapter {
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
     async fn get_unread_count(&self) -> Result<u32, EmailError> {
         self.check_circuit()?;
-        self.client
-            .get_unread_count()
-            .await
-            .map_err(Self::map_error)
+        self.bounded(self.client().get_unread_count()).await
     }
 
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
     async fn mark_read(&self, email_id: &str) -> Result<(), EmailError> {
         self.check_circuit()?;
-        self.client
-            .mark_read(email_id)
-            .await
-            .map_err(Self::map_error)
+        self.bounded(self.client().mark_read(email_id)).await
     }
 
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
     async fn mark_unread(&self, email_id: &str) -> Result<(), EmailError> {
         self.check_circuit()?;
-        self.client
-            .mark_unread(email_id)
-            .await
-            .map_err(Self::map_error)
+        self.bounded(self.client().mark_unread(email_id)).await
     }
 
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
     async fn delete(&self, email_id: &str) -> Result<(), EmailError> {
         self.check_circuit()?;
-        self.client.delete(email_id).await.map_err(Self::map_error)
+        self.bounded(self.client().delete(email_id)).await
     }
 
     #[instrument(skip(self, draft), fields(circuit = %self.circuit_state_desc()))]
@@ -191,16 +272,17 @@ impl EmailPort for ProtonEmailAdapter {
         self.check_circuit()?;
         debug!(to = %draft.to, subject = %draft.subject, "Sending email via Proton");
 
-        let mut composition = EmailComposition::new(&draft.to, &draft.subject, &draft.body);
+        let to = Self::transport_address(&draft.to);
+        let mut composition = EmailComposition::new(&to, &draft.subject, &draft.body);
 
         for cc in &draft.cc {
-            composition = composition.with_cc(cc);
+            composition = composition.with_cc(Self::transport_address(cc));
+        }
+        if let Some(ref html) = draft.html_body {
+            composition = composition.with_html_body(html);
         }
 
-        self.client
-            .send_email(&composition)
-            .await
-            .map_err(Self::map_error)
+        self.bounded(self.client().send_email(&composition)).await
     }
 
     async fn is_available(&self) -> bool {
@@ -209,13 +291,15 @@ impl EmailPort for ProtonEmailAdapter {
             debug!("Proton email unavailable: circuit breaker open");
             return false;
         }
-        self.client.check_connection().await.unwrap_or(false)
+        self.bounded(self.client().check_connection())
+            .await
+            .unwrap_or(false)
     }
 
     #[instrument(skip(self), fields(circuit = %self.circuit_state_desc()))]
     async fn list_mailboxes(&self) -> Result<Vec<String>, EmailError> {
         self.check_circuit()?;
-        self.client.list_mailboxes().await.map_err(Self::map_error)
+        self.bounded(self.client().list_mailboxes()).await
     }
 }
 
@@ -248,6 +332,53 @@ mod tests {
         assert!(format!("{adapter:?}").contains("ProtonEmailAdapter"));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn hanging_call_times_out() {
+        let adapter = ProtonEmailAdapter::new(test_config()).with_timeout(Duration::from_secs(3));
+
+        let result = adapter
+            .bounded(std::future::pending::<Result<u32, ProtonError>>())
+            .await;
+        assert!(matches!(result, Err(EmailError::ServiceUnavailable)));
+
+        let result = adapter.bounded(async { Ok::<_, ProtonError>(7) }).await;
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[test]
+    fn rotated_password_is_used_on_next_call() {
+        let password = SharedSecret::new(SecretString::from("old-password"));
+        let adapter = ProtonEmailAdapter::new(test_config()).with_shared_password(password.clone());
+
+        let first = adapter.client();
+        assert_eq!(
+            adapter
+                .state
+                .read()
+                .password
+                .as_ref()
+                .unwrap()
+                .expose_secret(),
+            "old-password"
+        );
+        assert!(Arc::ptr_eq(&first, &adapter.client()));
+
+        password.replace(SecretString::from("new-password"));
+
+        let second = adapter.client();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(
+            adapter
+                .state
+                .read()
+                .password
+                .as_ref()
+                .unwrap()
+                .expose_secret(),
+            "new-password"
+        );
+    }
+
     #[test]
     fn map_error_auth_failed() {
         let err = ProtonEmailAdapter::map_error(ProtonError::AuthenticationFailed);
@@ -314,4 +445,16 @@ mod tests {
         let adapter = ProtonEmailAdapter::new(config);
         assert!(!adapter.is_available().await);
     }
+
+    #[test]
+    fn transport_address_encodes_idn_domain() {
+        assert_eq!(
+            ProtonEmailAdapter::transport_address("user+tag@münchen.de"),
+            "user+tag@xn--mnchen-3ya.de"
+        );
+        assert_eq!(
+            ProtonEmailAdapter::transport_address("Name <user@example.com>"),
+            "Name <user@example.com>"
+        );
+    }
 }
@@ -0,0 +1,240 @@
+//! Queued messenger - Decorator that hands failed text sends to the retry queue
+//!
+//! When a send fails transiently (rate limit, unreachable API or daemon), the
+//! message is stored in the retry queue together with its context and
+//! validity window, and the retry worker delivers it later. The caller still
+//! receives the original error.
+
+use std::sync::Arc;
+
+use application::{
+    error::ApplicationError,
+    ports::{
+        DownloadedAudio, MessengerPort, OutgoingAudioMessage, OutgoingTemplateMessage,
+        OutgoingTextMessage, RetryQueuePort,
+    },
+};
+use async_trait::async_trait;
+use chrono::TimeDelta;
+use domain::{MessengerSource, PhoneNumber};
+use tracing::{info, warn};
+
+/// Messenger decorator that queues transiently failed text sends
+///
+/// Audio and template sends, downloads and read receipts are passed through
+/// unchanged.
+/// The retry worker must use the inner messenger, so that a failed retry is
+/// not queued a second time.
+pub struct QueuedMessenger {
+    inner: Arc<dyn MessengerPort>,
+    retry_queue: Arc<dyn RetryQueuePort>,
+}
+
+impl std::fmt::Debug for QueuedMessenger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueuedMessenger")
+            .field("source", &self.inner.source())
+            .finish_non_exhaustive()
+    }
+}
+
+impl QueuedMessenger {
+    /// Wrap a messenger so failed text sends go to `retry_queue`
+    pub fn new(inner: Arc<dyn MessengerPort>, retry_queue: Arc<dyn RetryQueuePort>) -> Self {
+        Self { inner, retry_queue }
+    }
+}
+
+#[async_trait]
+impl MessengerPort for QueuedMessenger {
+    fn source(&self) -> MessengerSource {
+        self.inner.source()
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    async fn is_whitelisted(&self, phone: &PhoneNumber) -> bool {
+        self.inner.is_whitelisted(phone).await
+    }
+
+    async fn send_text(&self, message: OutgoingTextMessage) -> Result<String, ApplicationError> {
+        let error = match self.inner.send_text(message.clone()).await {
+            Ok(message_id) => return Ok(message_id),
+            Err(e) if e.is_retryable() => e,
+            Err(e) => return Err(e),
+        };
+
+        match self
+            .retry_queue
+            .enqueue_message(&message, &error.to_string())
+            .await
+        {
+            Ok(id) => info!(
+                id = %id,
+                recipient = %message.recipient,
+                context = message.context.as_deref().unwrap_or("-"),
+                error = %error,
+                "Queued failed message for redelivery"
+            ),
+            Err(e) => warn!(
+                recipient = %message.recipient,
+                error = %e,
+                "Failed to queue message for redelivery"
+            ),
+        }
+        Err(error)
+    }
+
+    fn session_window(&self) -> Option<TimeDelta> {
+        self.inner.session_window()
+    }
+
+    async fn send_template(
+        &self,
+        message: OutgoingTemplateMessage,
+    ) -> Result<String, ApplicationError> {
+        self.inner.send_template(message).await
+    }
+
+    async fn send_audio(&self, message: OutgoingAudioMessage) -> Result<String, ApplicationError> {
+        self.inner.send_audio(message).await
+    }
+
+    async fn download_audio(&self, media_id: &str) -> Result<DownloadedAudio, ApplicationError> {
+        self.inner.download_audio(media_id).await
+    }
+
+    async fn mark_read(&self, message_id: &str) -> Result<(), ApplicationError> {
+        self.inner.mark_read(message_id).await
+    }
+
+    fn broadcast_concurrency(&self) -> usize {
+        self.inner.broadcast_concurrency()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use application::ports::{DeadLetter, QueueStats, QueuedMessage};
+
+    use super::*;
+
+    struct FailingMessenger {
+        error: fn() -> ApplicationError,
+    }
+
+    #[async_trait]
+    impl MessengerPort for FailingMessenger {
+        fn source(&self) -> MessengerSource {
+            MessengerSource::Signal
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        async fn is_whitelisted(&self, _: &PhoneNumber) -> bool {
+            true
+        }
+
+        async fn send_text(&self, _: OutgoingTextMessage) -> Result<String, ApplicationError> {
+            Err((self.error)())
+        }
+
+        async fn send_audio(&self, _: OutgoingAudioMessage) -> Result<String, ApplicationError> {
+            Err((self.error)())
+        }
+
+        async fn download_audio(&self, _: &str) -> Result<DownloadedAudio, ApplicationError> {
+            Err((self.error)())
+        }
+
+        async fn mark_read(&self, _: &str) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingQueue {
+        queued: Mutex<Vec<OutgoingTextMessage>>,
+    }
+
+    #[async_trait]
+    impl RetryQueuePort for RecordingQueue {
+        async fn queue_stats(&self) -> Result<QueueStats, ApplicationError> {
+            Ok(QueueStats::default())
+        }
+
+        async fn enqueue_message(
+            &self,
+            message: &OutgoingTextMessage,
+            _: &str,
+        ) -> Result<String, ApplicationError> {
+            self.queued.lock().unwrap().push(message.clone());
+            Ok("item-1".to_string())
+        }
+
+        async fn due_messages(&self, _: usize) -> Result<Vec<QueuedMessage>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn complete(&self, _: &str) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn fail(&self, _: &str, _: &str) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn list_dead_letters(&self, _: usize) -> Result<Vec<DeadLetter>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn requeue(&self, _: &str) -> Result<String, ApplicationError> {
+            Ok("item-1".to_string())
+        }
+    }
+
+    fn message() -> OutgoingTextMessage {
+        OutgoingTextMessage::new(PhoneNumber::new("+491701234567").unwrap(), "Hello")
+            .with_context("reminder:1")
+    }
+
+    #[tokio::test]
+    async fn transient_failure_is_queued() {
+        let queue = Arc::new(RecordingQueue::default());
+        let messenger = QueuedMessenger::new(
+            Arc::new(FailingMessenger {
+                error: || ApplicationError::ExternalService("daemon unavailable".to_string()),
+            }),
+            Arc::clone(&queue) as Arc<dyn RetryQueuePort>,
+        );
+
+        let result = messenger.send_text(message()).await;
+
+        assert!(matches!(result, Err(ApplicationError::ExternalService(_))));
+        let queued = queue.queued.lock().unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].context.as_deref(), Some("reminder:1"));
+    }
+
+    #[tokio::test]
+    async fn permanent_failure_is_not_queued() {
+        let queue = Arc::new(RecordingQueue::default());
+        let messenger = QueuedMessenger::new(
+            Arc::new(FailingMessenger {
+                error: || ApplicationError::NotAuthorized("not whitelisted".to_string()),
+            }),
+            Arc::clone(&queue) as Arc<dyn RetryQueuePort>,
+        );
+
+        let result = messenger.send_text(message()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotAuthorized(_))));
+        assert!(queue.queued.lock().unwrap().is_empty());
+    }
+}
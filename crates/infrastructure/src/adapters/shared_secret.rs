@@ -0,0 +1,88 @@
+//! Shared secret - Hot-swappable credential shared between config reload and adapters
+//!
+//! Adapters hold a [`SharedSecret`] instead of a plain password. When a
+//! secret is rotated in Vault, the config reload replaces the value and
+//! adapters pick it up on their next call without a restart.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use secrecy::{ExposeSecret, SecretString};
+
+/// A secret value that can be replaced at runtime
+///
+/// Clones share the same value. The secret is never included in `Debug`
+/// output.
+#[derive(Clone)]
+pub struct SharedSecret {
+    inner: Arc<RwLock<Arc<SecretString>>>,
+}
+
+impl std::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedSecret([REDACTED])")
+    }
+}
+
+impl SharedSecret {
+    /// Create a shared secret with an initial value
+    pub fn new(value: SecretString) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Arc::new(value))),
+        }
+    }
+
+    /// Current value
+    ///
+    /// The returned `Arc` changes identity whenever the value is replaced, so
+    /// callers can detect a rotation with [`Arc::ptr_eq`].
+    #[must_use]
+    pub fn current(&self) -> Arc<SecretString> {
+        Arc::clone(&self.inner.read())
+    }
+
+    /// Replace the value if it differs from the current one
+    ///
+    /// Returns `true` if the secret was rotated.
+    pub fn replace(&self, value: SecretString) -> bool {
+        let mut current = self.inner.write();
+        if current.expose_secret() == value.expose_secret() {
+            return false;
+        }
+        *current = Arc::new(value);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_rotates_value_for_all_clones() {
+        let secret = SharedSecret::new(SecretString::from("old"));
+        let clone = secret.clone();
+        let before = clone.current();
+
+        assert!(secret.replace(SecretString::from("new")));
+
+        let after = clone.current();
+        assert_eq!(after.expose_secret(), "new");
+        assert!(!Arc::ptr_eq(&before, &after));
+    }
+
+    #[test]
+    fn replace_with_same_value_is_noop() {
+        let secret = SharedSecret::new(SecretString::from("same"));
+        let before = secret.current();
+
+        assert!(!secret.replace(SecretString::from("same")));
+        assert!(Arc::ptr_eq(&before, &secret.current()));
+    }
+
+    #[test]
+    fn debug_redacts_value() {
+        let secret = SharedSecret::new(SecretString::from("hunter2"));
+        assert!(!format!("{secret:?}").contains("hunter2"));
+    }
+}
@@ -14,12 +14,16 @@ use integration_signal::{SignalClient, SignalClientConfig, SignalError};
 use tokio::fs;
 use tracing::{debug, instrument, warn};
 
+use super::SentMessageCache;
+
 /// Adapter that implements `MessengerPort` using `SignalClient`
 pub struct SignalMessengerAdapter {
     /// The underlying Signal client
     client: SignalClient,
     /// Temporary directory for audio files
     temp_dir: String,
+    /// Recently sent idempotency keys
+    sent: SentMessageCache,
 }
 
 impl SignalMessengerAdapter {
@@ -32,6 +36,7 @@ impl SignalMessengerAdapter {
                 .join("pisovereign-signal")
                 .to_string_lossy()
                 .to_string(),
+            sent: SentMessageCache::default(),
         }
     }
 
@@ -44,6 +49,7 @@ impl SignalMessengerAdapter {
                 .join("pisovereign-signal")
                 .to_string_lossy()
                 .to_string(),
+            sent: SentMessageCache::default(),
         }
     }
 
@@ -60,6 +66,57 @@ impl SignalMessengerAdapter {
         &self.client
     }
 
+    /// Send a text message without deduplication
+    async fn deliver_text(
+        &self,
+        message: &OutgoingTextMessage,
+    ) -> Result<String, ApplicationError> {
+        let result = if let Some(ref reply_to) = message.reply_to {
+            // Try to parse the reply_to as a timestamp (Signal uses timestamps as message IDs)
+            if let Ok(timestamp) = reply_to.parse::<i64>() {
+                self.client
+                    .send_text_reply(
+                        message.recipient.as_str(),
+                        &message.text,
+                        timestamp,
+                        message.recipient.as_str(), // Reply author is the recipient
+                    )
+                    .await
+            } else {
+                // If not a valid timestamp, send without reply
+                warn!(reply_to = %reply_to, "Invalid reply_to timestamp, sending without reply");
+                self.client
+                    .send_text(message.recipient.as_str(), &message.text)
+                    .await
+            }
+        } else {
+            self.client
+                .send_text(message.recipient.as_str(), &message.text)
+                .await
+        };
+
+        let send_result = result.map_err(|e| match e {
+            SignalError::NotRegistered(account) => {
+                ApplicationError::Configuration(format!("Signal account not registered: {account}"))
+            },
+            SignalError::SendFailed(msg) => {
+                ApplicationError::ExternalService(format!("Signal send failed: {msg}"))
+            },
+            SignalError::Connection(msg) => {
+                ApplicationError::ExternalService(format!("Signal connection failed: {msg}"))
+            },
+            SignalError::SignalCli { ref message, .. } if is_rate_limit_message(message) => {
+                ApplicationError::RateLimited
+            },
+            e => ApplicationError::ExternalService(format!("Signal error: {e}")),
+        })?;
+
+        // Signal uses timestamp as message ID
+        let message_id = send_result.timestamp.to_string();
+        debug!(message_id = %message_id, "Signal text message sent");
+        Ok(message_id)
+    }
+
     /// Ensure the temp directory exists
     async fn ensure_temp_dir(&self) -> Result<(), ApplicationError> {
         fs::create_dir_all(&self.temp_dir)
@@ -120,47 +177,9 @@ impl MessengerPort for SignalMessengerAdapter {
 
     #[instrument(skip(self, message), fields(recipient = %message.recipient))]
     async fn send_text(&self, message: OutgoingTextMessage) -> Result<String, ApplicationError> {
-        let result = if let Some(ref reply_to) = message.reply_to {
-            // Try to parse the reply_to as a timestamp (Signal uses timestamps as message IDs)
-            if let Ok(timestamp) = reply_to.parse::<i64>() {
-                self.client
-                    .send_text_reply(
-                        message.recipient.as_str(),
-                        &message.text,
-                        timestamp,
-                        message.recipient.as_str(), // Reply author is the recipient
-                    )
-                    .await
-            } else {
-                // If not a valid timestamp, send without reply
-                warn!(reply_to = %reply_to, "Invalid reply_to timestamp, sending without reply");
-                self.client
-                    .send_text(message.recipient.as_str(), &message.text)
-                    .await
-            }
-        } else {
-            self.client
-                .send_text(message.recipient.as_str(), &message.text)
-                .await
-        };
-
-        let send_result = result.map_err(|e| match e {
-            SignalError::NotRegistered(account) => {
-                ApplicationError::Configuration(format!("Signal account not registered: {account}"))
-            },
-            SignalError::SendFailed(msg) => {
-                ApplicationError::ExternalService(format!("Signal send failed: {msg}"))
-            },
-            SignalError::Connection(msg) => {
-                ApplicationError::ExternalService(format!("Signal connection failed: {msg}"))
-            },
-            e => ApplicationError::ExternalService(format!("Signal error: {e}")),
-        })?;
-
-        // Signal uses timestamp as message ID
-        let message_id = send_result.timestamp.to_string();
-        debug!(message_id = %message_id, "Signal text message sent");
-        Ok(message_id)
+        self.sent
+            .send_once(message.idempotency_key(), || self.deliver_text(&message))
+            .await
     }
 
     #[instrument(skip(self, message), fields(recipient = %message.recipient, audio_size = message.audio_data.len()))]
@@ -235,6 +254,17 @@ impl MessengerPort for SignalMessengerAdapter {
 
         Ok(())
     }
+
+    fn broadcast_concurrency(&self) -> usize {
+        // Requests share one signal-cli connection and are sent one at a time
+        1
+    }
+}
+
+/// Whether a signal-cli error message reports a rate limit
+fn is_rate_limit_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("rate limit") || message.contains("ratelimit")
 }
 
 /// Convert MIME type to file extension
@@ -264,6 +294,15 @@ fn extension_to_mime(ext: &str) -> &str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn rate_limit_messages_are_recognized() {
+        assert!(is_rate_limit_message(
+            "Failed to send message due to rate limiting"
+        ));
+        assert!(is_rate_limit_message("RateLimitException: 413"));
+        assert!(!is_rate_limit_message("Unregistered user"));
+    }
+
     #[test]
     fn mime_to_extension_maps_correctly() {
         assert_eq!(mime_to_extension("audio/ogg"), "ogg");
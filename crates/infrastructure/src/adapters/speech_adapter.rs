@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use ai_speech::{
     AudioConverter, AudioData, AudioFormat as AiAudioFormat, OpenAISpeechProvider, SpeechConfig,
-    SpeechError, SpeechToText, TextToSpeech,
+    SpeechError, SpeechToText, SynthesisOptions, TextToSpeech, TranscriptionOptions,
 };
 use application::error::ApplicationError;
 use application::ports::{
@@ -12,18 +12,20 @@ use application::ports::{
 };
 use async_trait::async_trait;
 use domain::entities::AudioFormat;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
 /// Adapter for speech services using ai_speech crate
 pub struct SpeechAdapter {
     provider: Arc<OpenAISpeechProvider>,
     converter: Arc<AudioConverter>,
+    loudness_target_lufs: Option<f64>,
 }
 
 impl std::fmt::Debug for SpeechAdapter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SpeechAdapter")
             .field("provider", &"OpenAISpeechProvider")
+            .field("loudness_target_lufs", &self.loudness_target_lufs)
             .finish()
     }
 }
@@ -35,12 +37,14 @@ impl SpeechAdapter {
     ///
     /// Returns an error if the provider fails to initialize.
     pub fn new(config: SpeechConfig) -> Result<Self, ApplicationError> {
+        let loudness_target_lufs = config.loudness_target_lufs;
         let provider = OpenAISpeechProvider::new(config)
             .map_err(|e: SpeechError| ApplicationError::Configuration(e.to_string()))?;
 
         Ok(Self {
             provider: Arc::new(provider),
             converter: Arc::new(AudioConverter::new()),
+            loudness_target_lufs,
         })
     }
 
@@ -53,15 +57,33 @@ impl SpeechAdapter {
         config: SpeechConfig,
         ffmpeg_path: impl Into<String>,
     ) -> Result<Self, ApplicationError> {
+        let loudness_target_lufs = config.loudness_target_lufs;
         let provider = OpenAISpeechProvider::new(config)
             .map_err(|e: SpeechError| ApplicationError::Configuration(e.to_string()))?;
 
         Ok(Self {
             provider: Arc::new(provider),
             converter: Arc::new(AudioConverter::with_ffmpeg_path(ffmpeg_path)),
+            loudness_target_lufs,
         })
     }
 
+    /// Normalize loudness if a target is configured
+    ///
+    /// Normalization is best effort: on failure the audio is used as is.
+    async fn normalize_loudness(&self, audio: AudioData) -> AudioData {
+        let Some(target) = self.loudness_target_lufs else {
+            return audio;
+        };
+        match self.converter.normalize_loudness(&audio, target).await {
+            Ok(normalized) => normalized,
+            Err(e) => {
+                warn!(error = %e, "Loudness normalization failed, using original audio");
+                audio
+            },
+        }
+    }
+
     /// Convert domain AudioFormat to ai_speech AudioFormat
     const fn domain_to_ai_format(format: AudioFormat) -> AiAudioFormat {
         match format {
@@ -95,6 +117,9 @@ impl SpeechAdapter {
             SpeechError::InvalidAudio(e) => {
                 ApplicationError::InvalidOperation(format!("Invalid audio: {e}"))
             },
+            SpeechError::InvalidInput(e) => {
+                ApplicationError::InvalidOperation(format!("Invalid input: {e}"))
+            },
             SpeechError::AudioTooLong {
                 duration_ms,
                 max_ms,
@@ -159,6 +184,8 @@ impl SpeechPort for SpeechAdapter {
                 .map_err(Self::map_error)?
         };
 
+        let audio_for_whisper = self.normalize_loudness(audio_for_whisper).await;
+
         // Perform transcription (with or without language hint)
         let transcription: ai_speech::Transcription = match language_hint {
             Some(ref lang) => self
@@ -168,7 +195,7 @@ impl SpeechPort for SpeechAdapter {
                 .map_err(Self::map_error)?,
             None => self
                 .provider
-                .transcribe(audio_for_whisper)
+                .transcribe(audio_for_whisper, &TranscriptionOptions::default())
                 .await
                 .map_err(Self::map_error)?,
         };
@@ -196,13 +223,17 @@ impl SpeechPort for SpeechAdapter {
     ) -> Result<SynthesisResult, ApplicationError> {
         // Map voice config
         let voice_id = voice.as_ref().map(|v| v.voice_id.as_str());
+        let options = voice.as_ref().map_or_else(SynthesisOptions::default, |v| {
+            SynthesisOptions::new(v.rate, v.pitch)
+        });
 
         // Perform synthesis
         let audio: AudioData = self
             .provider
-            .synthesize(&text, voice_id)
+            .synthesize_with_options(&text, voice_id, &options)
             .await
             .map_err(Self::map_error)?;
+        let audio = self.normalize_loudness(audio).await;
 
         let format = Self::ai_to_domain_format(audio.format());
         let duration_ms = audio.duration_ms();
@@ -236,6 +267,7 @@ impl SpeechPort for SpeechAdapter {
                 name: v.name,
                 description: v.description,
                 languages: v.languages,
+                gender: v.gender.map(|g| g.to_string()),
             })
             .collect())
     }
@@ -318,6 +350,39 @@ mod tests {
         );
     }
 
+    fn adapter_with_loudness_target(ffmpeg_path: &str) -> SpeechAdapter {
+        let config = SpeechConfig {
+            openai_api_key: Some("test-key".to_string()),
+            loudness_target_lufs: Some(-16.0),
+            ..Default::default()
+        };
+        SpeechAdapter::with_ffmpeg_path(config, ffmpeg_path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn failed_normalization_keeps_original_audio() {
+        let adapter = adapter_with_loudness_target("/nonexistent/ffmpeg");
+        let audio = AudioData::new(vec![0, 1, 2, 3], AiAudioFormat::Opus);
+
+        let result = adapter.normalize_loudness(audio.clone()).await;
+
+        assert_eq!(result.data(), audio.data());
+    }
+
+    #[tokio::test]
+    async fn normalization_is_skipped_without_target() {
+        let config = SpeechConfig {
+            openai_api_key: Some("test-key".to_string()),
+            ..Default::default()
+        };
+        let adapter = SpeechAdapter::new(config).unwrap();
+        let audio = AudioData::new(vec![0, 1, 2, 3], AiAudioFormat::Wav);
+
+        let result = adapter.normalize_loudness(audio.clone()).await;
+
+        assert_eq!(result.data(), audio.data());
+    }
+
     #[test]
     fn error_mapping_configuration() {
         let err = SpeechAdapter::map_error(SpeechError::Configuration("bad config".to_string()));
@@ -331,6 +396,12 @@ mod tests {
         assert!(matches!(err, ApplicationError::ExternalService(_)));
     }
 
+    #[test]
+    fn error_mapping_invalid_input() {
+        let err = SpeechAdapter::map_error(SpeechError::InvalidInput("rate 9.0".to_string()));
+        assert!(matches!(err, ApplicationError::InvalidOperation(_)));
+    }
+
     #[test]
     fn error_mapping_rate_limited() {
         let err = SpeechAdapter::map_error(SpeechError::RateLimited);
@@ -8,7 +8,8 @@ use std::net::IpAddr;
 use std::sync::Arc;
 
 use application::ports::{
-    SuspiciousActivityConfig, SuspiciousActivityPort, ViolationRecord, ViolationSummary,
+    BlockedEntry, MANUAL_BLOCK_REASON, SuspiciousActivityConfig, SuspiciousActivityPort,
+    ViolationRecord, ViolationSummary,
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -21,6 +22,7 @@ use tracing::{debug, info, warn};
 struct IpViolationData {
     violations: Vec<ViolationRecord>,
     blocked_until: Option<DateTime<Utc>>,
+    block_reason: Option<String>,
 }
 
 /// In-memory implementation of suspicious activity tracking
@@ -109,6 +111,7 @@ impl SuspiciousActivityPort for InMemorySuspiciousActivityTracker {
         if should_block {
             let expires = self.calculate_block_expiration();
             entry.blocked_until = Some(expires);
+            entry.block_reason = Some(violation.block_reason());
             warn!(
                 ip = %ip,
                 category = %violation.category,
@@ -170,6 +173,7 @@ impl SuspiciousActivityPort for InMemorySuspiciousActivityTracker {
         let entry = data.entry(ip).or_default();
         let expires = Utc::now() + chrono::Duration::seconds(duration_secs as i64);
         entry.blocked_until = Some(expires);
+        entry.block_reason = Some(MANUAL_BLOCK_REASON.to_string());
         info!(ip = %ip, expires_at = %expires, "IP manually blocked");
     }
 
@@ -177,6 +181,7 @@ impl SuspiciousActivityPort for InMemorySuspiciousActivityTracker {
         let mut data = self.data.write();
         if let Some(entry) = data.get_mut(&ip) {
             entry.blocked_until = None;
+            entry.block_reason = None;
             info!(ip = %ip, "IP manually unblocked");
         }
     }
@@ -198,6 +203,40 @@ impl SuspiciousActivityPort for InMemorySuspiciousActivityTracker {
             .collect()
     }
 
+    async fn list_blocked(&self) -> Vec<BlockedEntry> {
+        let data = self.data.read();
+        let now = Utc::now();
+        let mut entries: Vec<BlockedEntry> = data
+            .iter()
+            .filter_map(|(ip, entry)| {
+                let blocked_until = entry.blocked_until.filter(|exp| *exp > now)?;
+                Some(BlockedEntry {
+                    ip: *ip,
+                    reason: entry
+                        .block_reason
+                        .clone()
+                        .unwrap_or_else(|| MANUAL_BLOCK_REASON.to_string()),
+                    violation_count: entry.violations.len() as u32,
+                    blocked_until,
+                })
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.blocked_until);
+        entries
+    }
+
+    async fn unblock(&self, ip: IpAddr) -> bool {
+        let mut data = self.data.write();
+        let was_blocked = data
+            .remove(&ip)
+            .and_then(|entry| entry.blocked_until)
+            .is_some_and(|exp| exp > Utc::now());
+        if was_blocked {
+            info!(ip = %ip, "IP unblocked and violations cleared");
+        }
+        was_blocked
+    }
+
     async fn cleanup_expired(&self) {
         let mut data = self.data.write();
         let now = Utc::now();
@@ -206,6 +245,7 @@ impl SuspiciousActivityPort for InMemorySuspiciousActivityTracker {
         for entry in data.values_mut() {
             if entry.blocked_until.is_some_and(|exp| exp <= now) {
                 entry.blocked_until = None;
+                entry.block_reason = None;
             }
         }
 
@@ -364,6 +404,63 @@ mod tests {
         assert_eq!(summary.max_threat_level, Some(ThreatLevel::High));
     }
 
+    #[tokio::test]
+    async fn list_blocked_reports_reason_and_count() {
+        let tracker = InMemorySuspiciousActivityTracker::new(test_config());
+        let auto_ip = test_ip();
+        let manual_ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+        for _ in 0..3 {
+            tracker
+                .record_violation(
+                    auto_ip,
+                    ViolationRecord::new("PromptInjection", ThreatLevel::High),
+                )
+                .await;
+        }
+        tracker.block_ip(manual_ip, 60).await;
+
+        let blocked = tracker.list_blocked().await;
+        assert_eq!(blocked.len(), 2);
+
+        // Soonest expiry first
+        assert_eq!(blocked[0].ip, manual_ip);
+        assert_eq!(blocked[0].reason, MANUAL_BLOCK_REASON);
+        assert_eq!(blocked[0].violation_count, 0);
+
+        assert_eq!(blocked[1].ip, auto_ip);
+        assert_eq!(blocked[1].reason, "PromptInjection (high)");
+        assert_eq!(blocked[1].violation_count, 3);
+        assert!(blocked[1].blocked_until > Utc::now());
+    }
+
+    #[tokio::test]
+    async fn unblock_clears_violations() {
+        let tracker = InMemorySuspiciousActivityTracker::new(test_config());
+        let ip = test_ip();
+
+        for _ in 0..3 {
+            tracker
+                .record_violation(ip, ViolationRecord::new("test", ThreatLevel::Medium))
+                .await;
+        }
+        assert!(tracker.unblock(ip).await);
+        assert!(!tracker.is_blocked(ip).await);
+        assert!(tracker.list_blocked().await.is_empty());
+
+        // A single new violation must not re-block immediately
+        tracker
+            .record_violation(ip, ViolationRecord::new("test", ThreatLevel::Medium))
+            .await;
+        assert!(!tracker.is_blocked(ip).await);
+
+        assert!(
+            !tracker
+                .unblock(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 9)))
+                .await
+        );
+    }
+
     #[tokio::test]
     async fn cleanup_removes_expired() {
         let tracker = InMemorySuspiciousActivityTracker::new(test_config());
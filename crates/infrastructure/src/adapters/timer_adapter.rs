@@ -0,0 +1,103 @@
+//! Timer adapter - Implements TimerPort using the task scheduler
+
+use std::{sync::Arc, time::Duration};
+
+use application::{error::ApplicationError, ports::TimerPort};
+use async_trait::async_trait;
+use tracing::{instrument, warn};
+use uuid::Uuid;
+
+use crate::{scheduled_tasks::NotificationCallback, scheduler::TaskScheduler};
+
+/// Adapter that schedules timers as one-shot scheduler tasks
+///
+/// When a timer fires, its message is handed to the notification callback
+/// (the same callback used for reminders and briefings).
+pub struct SchedulerTimerAdapter {
+    scheduler: Arc<TaskScheduler>,
+    send_callback: NotificationCallback,
+}
+
+impl std::fmt::Debug for SchedulerTimerAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SchedulerTimerAdapter")
+            .field("scheduler_running", &self.scheduler.is_running())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SchedulerTimerAdapter {
+    /// Create a new timer adapter
+    pub fn new(scheduler: Arc<TaskScheduler>, send_callback: NotificationCallback) -> Self {
+        Self {
+            scheduler,
+            send_callback,
+        }
+    }
+}
+
+#[async_trait]
+impl TimerPort for SchedulerTimerAdapter {
+    #[instrument(skip(self, message))]
+    async fn schedule_once(
+        &self,
+        delay: Duration,
+        message: String,
+    ) -> Result<String, ApplicationError> {
+        let timer_id = format!("timer_{}", Uuid::new_v4());
+        let send = Arc::clone(&self.send_callback);
+        let name = timer_id.clone();
+
+        self.scheduler
+            .add_once(&timer_id, delay, async move {
+                send(message).await.inspect_err(|e| {
+                    warn!(timer = %name, error = %e, "Failed to send timer notification");
+                })
+            })
+            .await
+            .map_err(|e| ApplicationError::Internal(format!("Failed to schedule timer: {e}")))?;
+
+        Ok(timer_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::scheduler::SchedulerConfig;
+
+    #[tokio::test]
+    async fn timer_sends_notification_when_it_fires() {
+        let scheduler = Arc::new(
+            TaskScheduler::new(SchedulerConfig::default())
+                .await
+                .unwrap(),
+        );
+        let sent = Arc::new(AtomicUsize::new(0));
+        let sent_clone = Arc::clone(&sent);
+        let callback: NotificationCallback = Arc::new(move |message: String| {
+            let sent = Arc::clone(&sent_clone);
+            Box::pin(async move {
+                assert_eq!(message, "⏰ Tea");
+                sent.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+        });
+
+        let adapter = SchedulerTimerAdapter::new(Arc::clone(&scheduler), callback);
+        let timer_id = adapter
+            .schedule_once(Duration::from_millis(100), "⏰ Tea".to_string())
+            .await
+            .unwrap();
+        assert!(timer_id.starts_with("timer_"));
+        assert!(scheduler.get_task_stats(&timer_id).is_some());
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+        assert!(scheduler.get_task_stats(&timer_id).is_none());
+
+        scheduler.stop().await.unwrap();
+    }
+}
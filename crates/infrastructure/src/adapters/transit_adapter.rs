@@ -6,18 +6,21 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::value_objects::GeoLocation;
 use integration_transit::{
-    GeocodingClient, HafasTransitClient, NominatimGeocodingClient, TransitClient,
+    GeocodingClient, GeocodingError, HafasTransitClient, NominatimGeocodingClient, TransitClient,
     TransitMode as IntegrationMode,
 };
 use tracing::{debug, instrument, warn};
 
-use super::{CircuitBreaker, CircuitBreakerConfig};
+use super::{Bulkhead, BulkheadPermit, CircuitBreaker, CircuitBreakerConfig};
+use crate::cache::{NegativeCache, NegativeKind, generate_cache_key};
 
 /// Adapter for public transit services using HAFAS (transport.rest) and Nominatim
 pub struct TransitAdapter {
     transit_client: HafasTransitClient,
     geocoding_client: NominatimGeocodingClient,
     circuit_breaker: Option<CircuitBreaker>,
+    bulkhead: Option<Bulkhead>,
+    negative_cache: Option<NegativeCache>,
 }
 
 impl std::fmt::Debug for TransitAdapter {
@@ -29,6 +32,11 @@ impl std::fmt::Debug for TransitAdapter {
                 "circuit_breaker",
                 &self.circuit_breaker.as_ref().map(CircuitBreaker::name),
             )
+            .field(
+                "max_concurrent",
+                &self.bulkhead.as_ref().map(Bulkhead::max_concurrent),
+            )
+            .field("negative_cache", &self.negative_cache.is_some())
             .finish()
     }
 }
@@ -47,9 +55,18 @@ impl TransitAdapter {
             transit_client,
             geocoding_client,
             circuit_breaker: None,
+            bulkhead: None,
+            negative_cache: None,
         }
     }
 
+    /// Remember failed geocoding lookups so they aren't retried immediately
+    #[must_use]
+    pub fn with_negative_cache(mut self, cache: NegativeCache) -> Self {
+        self.negative_cache = Some(cache);
+        self
+    }
+
     /// Enable circuit breaker with default configuration
     #[must_use]
     pub fn with_circuit_breaker(mut self) -> Self {
@@ -64,6 +81,13 @@ impl TransitAdapter {
         self
     }
 
+    /// Limit the number of concurrent requests to the transit services
+    #[must_use]
+    pub fn with_bulkhead(mut self, max_concurrent: usize) -> Self {
+        self.bulkhead = Some(Bulkhead::new("transit", max_concurrent));
+        self
+    }
+
     /// Check circuit and return error if open
     fn check_circuit(&self) -> Result<(), ApplicationError> {
         if let Some(ref cb) = self.circuit_breaker {
@@ -76,6 +100,44 @@ impl TransitAdapter {
         Ok(())
     }
 
+    /// Take a bulkhead slot, failing fast if all are in use
+    fn acquire_slot(&self) -> Result<Option<BulkheadPermit>, ApplicationError> {
+        self.bulkhead
+            .as_ref()
+            .map(Bulkhead::try_acquire)
+            .transpose()
+            .map_err(|e| ApplicationError::ExternalService(e.to_string()))
+    }
+
+    /// Geocode an address, consulting the negative cache first
+    ///
+    /// Returns the failure message if the address could not be resolved.
+    async fn geocode(&self, address: &str) -> Result<GeoLocation, String> {
+        let key = generate_cache_key("geocode:failed", &[&address.trim().to_lowercase()]);
+        let cached = match &self.negative_cache {
+            Some(cache) => cache.get(&key).await,
+            None => None,
+        };
+        if let Some(entry) = cached {
+            return Err(entry.message);
+        }
+
+        let _slot = self.acquire_slot().map_err(|e| e.to_string())?;
+        match self.geocoding_client.geocode(address).await {
+            Ok(location) => Ok(location),
+            Err(e) => {
+                if let Some(cache) = &self.negative_cache {
+                    let kind = match e {
+                        GeocodingError::AddressNotFound(_) => NegativeKind::NotFound,
+                        _ => NegativeKind::Transient,
+                    };
+                    cache.remember(&key, kind, e.to_string()).await;
+                }
+                Err(e.to_string())
+            },
+        }
+    }
+
     /// Convert an integration transit mode to app-layer transit mode
     const fn convert_mode(mode: IntegrationMode) -> TransitMode {
         match mode {
@@ -101,6 +163,7 @@ impl TransitPort for TransitAdapter {
         query: &TransitQuery,
     ) -> Result<Vec<TransitConnection>, ApplicationError> {
         self.check_circuit()?;
+        let slot = self.acquire_slot()?;
 
         let result = self
             .transit_client
@@ -116,6 +179,7 @@ impl TransitPort for TransitAdapter {
             .map_err(|e| {
                 ApplicationError::ExternalService(format!("Transit search failed: {e}"))
             })?;
+        drop(slot);
 
         let connections = result
             .journeys
@@ -174,14 +238,10 @@ impl TransitPort for TransitAdapter {
     ) -> Result<Vec<TransitConnection>, ApplicationError> {
         debug!(%to_address, "Geocoding destination address");
 
-        let to_location = self
-            .geocoding_client
-            .geocode(to_address)
-            .await
-            .map_err(|e| {
-                warn!(%to_address, %e, "Failed to geocode address");
-                ApplicationError::ExternalService(format!("Failed to geocode '{to_address}': {e}"))
-            })?;
+        let to_location = self.geocode(to_address).await.map_err(|e| {
+            warn!(%to_address, %e, "Failed to geocode address");
+            ApplicationError::ExternalService(format!("Failed to geocode '{to_address}': {e}"))
+        })?;
 
         let query = TransitQuery {
             from: *from,
@@ -200,7 +260,7 @@ impl TransitPort for TransitAdapter {
     ) -> Result<Option<GeoLocation>, ApplicationError> {
         debug!(%address, "Geocoding address");
 
-        match self.geocoding_client.geocode(address).await {
+        match self.geocode(address).await {
             Ok(location) => Ok(Some(location)),
             Err(e) => {
                 warn!(%address, %e, "Failed to geocode address");
@@ -241,4 +301,27 @@ mod tests {
             TransitMode::NationalExpress
         );
     }
+
+    #[tokio::test]
+    async fn search_fails_fast_when_bulkhead_is_full() {
+        let transit_config = integration_transit::TransitConfig::default();
+        let geocoding_config = integration_transit::NominatimConfig::default();
+        let adapter = TransitAdapter::new(
+            HafasTransitClient::new(&transit_config).unwrap(),
+            NominatimGeocodingClient::new(&geocoding_config).unwrap(),
+        )
+        .with_bulkhead(1);
+        let _held = adapter.bulkhead.as_ref().unwrap().try_acquire().unwrap();
+
+        let query = TransitQuery {
+            from: GeoLocation::new(52.52, 13.405).unwrap(),
+            to: GeoLocation::new(52.5163, 13.3777).unwrap(),
+            departure: None,
+            max_results: 1,
+        };
+        let err = adapter.search_connections(&query).await.unwrap_err();
+        assert!(
+            matches!(err, ApplicationError::ExternalService(ref msg) if msg.contains("Bulkhead full"))
+        );
+    }
 }
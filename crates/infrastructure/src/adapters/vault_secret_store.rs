@@ -1,19 +1,39 @@
 //! HashiCorp Vault secret store adapter
 //!
 //! Retrieves secrets from HashiCorp Vault using the KV v2 secrets engine.
-//! Supports AppRole and token-based authentication.
-
-use std::sync::Arc;
+//! Supports AppRole and token-based authentication. Tokens with a limited
+//! TTL are renewed by a background task (see
+//! [`VaultSecretStore::spawn_token_renewal`]); AppRole logins are repeated
+//! when renewal fails or Vault answers with `403 Forbidden`.
+
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use application::{error::ApplicationError, ports::SecretStorePort};
 use async_trait::async_trait;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, instrument, warn};
 use vaultrs::{
-    client::{VaultClient, VaultClientSettingsBuilder},
+    api::AuthInfo,
+    client::{Client, VaultClient, VaultClientSettingsBuilder},
+    error::ClientError,
     kv2,
 };
 
+/// Renewal interval used when the token TTL cannot be determined
+const DEFAULT_RENEW_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Lower bound for the renewal interval derived from the token TTL
+const MIN_RENEW_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Delay before retrying after a failed renewal
+const RENEW_RETRY_DELAY: Duration = Duration::from_secs(30);
+
 /// Configuration for Vault connection
 #[derive(Debug, Clone)]
 pub struct VaultConfig {
@@ -31,6 +51,8 @@ pub struct VaultConfig {
     pub namespace: Option<String>,
     /// Connection timeout in seconds
     pub timeout_secs: u64,
+    /// Token renewal interval in seconds (default: two thirds of the token TTL)
+    pub renew_interval_secs: Option<u64>,
 }
 
 impl Default for VaultConfig {
@@ -43,6 +65,7 @@ impl Default for VaultConfig {
             mount_path: "secret".to_string(),
             namespace: None,
             timeout_secs: 30,
+            renew_interval_secs: None,
         }
     }
 }
@@ -88,6 +111,37 @@ impl VaultConfig {
         self.namespace = Some(namespace.into());
         self
     }
+
+    /// Set a fixed token renewal interval
+    #[must_use]
+    pub const fn with_renew_interval_secs(mut self, secs: u64) -> Self {
+        self.renew_interval_secs = Some(secs);
+        self
+    }
+
+    /// Whether AppRole credentials are configured
+    #[must_use]
+    pub const fn uses_approle(&self) -> bool {
+        self.role_id.is_some() && self.secret_id.is_some()
+    }
+}
+
+/// Compute the delay until the next token renewal
+///
+/// Returns `None` for tokens that never expire (TTL of zero). A configured
+/// interval takes precedence over the TTL-derived one.
+fn renewal_delay(configured_secs: Option<u64>, lease_secs: Option<u64>) -> Option<Duration> {
+    match (configured_secs, lease_secs) {
+        (_, Some(0)) => None,
+        (Some(secs), _) => Some(Duration::from_secs(secs).max(MIN_RENEW_INTERVAL)),
+        (None, Some(lease)) => Some(Duration::from_secs(lease * 2 / 3).max(MIN_RENEW_INTERVAL)),
+        (None, None) => Some(DEFAULT_RENEW_INTERVAL),
+    }
+}
+
+/// Whether a Vault error is a `403 Forbidden` (expired or revoked token)
+const fn is_forbidden(error: &ClientError) -> bool {
+    matches!(error, ClientError::APIError { code: 403, .. })
 }
 
 /// Secret store that reads from HashiCorp Vault
@@ -95,6 +149,10 @@ pub struct VaultSecretStore {
     client: Arc<RwLock<VaultClient>>,
     mount_path: String,
     config: VaultConfig,
+    /// Token TTL in seconds from the last login or renewal, if known
+    lease_secs: Mutex<Option<u64>>,
+    /// Cleared when the token could neither be renewed nor re-acquired
+    token_valid: AtomicBool,
 }
 
 impl std::fmt::Debug for VaultSecretStore {
@@ -102,6 +160,7 @@ impl std::fmt::Debug for VaultSecretStore {
         f.debug_struct("VaultSecretStore")
             .field("mount_path", &self.mount_path)
             .field("config", &self.config)
+            .field("token_valid", &self.token_valid.load(Ordering::Relaxed))
             .field("client", &"VaultClient { ... }")
             .finish()
     }
@@ -113,12 +172,22 @@ impl VaultSecretStore {
     /// # Errors
     /// Returns an error if the Vault client cannot be created
     pub async fn new(config: VaultConfig) -> Result<Self, ApplicationError> {
-        let client = Self::create_client(&config)?;
-
-        // If using AppRole, authenticate
-        if config.role_id.is_some() && config.secret_id.is_some() {
-            Self::authenticate_approle(&client, &config).await?;
-        }
+        let mut client = Self::create_client(&config)?;
+
+        // If using AppRole, authenticate; otherwise look up the static token's TTL
+        let lease_secs = if config.uses_approle() {
+            let auth = Self::authenticate_approle(&client, &config).await?;
+            client.set_token(&auth.client_token);
+            Some(auth.lease_duration)
+        } else {
+            match vaultrs::token::lookup_self(&client).await {
+                Ok(info) => Some(info.ttl),
+                Err(e) => {
+                    debug!(error = %e, "Could not look up Vault token TTL");
+                    None
+                },
+            }
+        };
 
         info!(address = %config.address, "Connected to Vault");
 
@@ -126,9 +195,134 @@ impl VaultSecretStore {
             client: Arc::new(RwLock::new(client)),
             mount_path: config.mount_path.clone(),
             config,
+            lease_secs: Mutex::new(lease_secs),
+            token_valid: AtomicBool::new(true),
+        })
+    }
+
+    /// Spawn a background task that renews the Vault token before it expires
+    ///
+    /// Tokens without a TTL (e.g. root tokens) are not renewed. If renewal
+    /// fails and AppRole credentials are configured, the task logs in again.
+    /// While the token is invalid, lookups fail fast so a
+    /// [`ChainedSecretStore`] falls through to the next store.
+    pub fn spawn_token_renewal(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let store = Arc::clone(self);
+
+        tokio::spawn(async move {
+            let Some(mut delay) = store.next_renewal_delay() else {
+                debug!("Vault token does not expire, token renewal disabled");
+                return;
+            };
+
+            loop {
+                tokio::time::sleep(delay).await;
+
+                delay = if store.renew_token().await.is_ok() {
+                    match store.next_renewal_delay() {
+                        Some(next) => next,
+                        None => return,
+                    }
+                } else {
+                    RENEW_RETRY_DELAY.min(delay)
+                };
+            }
         })
     }
 
+    /// Delay until the next renewal based on configuration and the last TTL
+    fn next_renewal_delay(&self) -> Option<Duration> {
+        let lease_secs = *self
+            .lease_secs
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        renewal_delay(self.config.renew_interval_secs, lease_secs)
+    }
+
+    /// Record a successful login or renewal
+    fn record_lease(&self, lease_secs: u64) {
+        *self
+            .lease_secs
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(lease_secs);
+        self.token_valid.store(true, Ordering::Relaxed);
+    }
+
+    /// Renew the current token, logging in again via AppRole if that fails
+    ///
+    /// # Errors
+    /// Returns an error if the token could neither be renewed nor re-acquired
+    pub async fn renew_token(&self) -> Result<(), ApplicationError> {
+        let renewed = {
+            let client = self.client.read().await;
+            vaultrs::token::renew_self(&*client, None).await
+        };
+
+        match renewed {
+            Ok(auth) => {
+                debug!(lease_secs = auth.lease_duration, "Renewed Vault token");
+                self.record_lease(auth.lease_duration);
+                Ok(())
+            },
+            Err(e) if self.config.uses_approle() => {
+                debug!(error = %e, "Vault token renewal failed, logging in again");
+                self.reauthenticate().await
+            },
+            Err(e) => {
+                warn!(error = %e, "Vault token renewal failed");
+                self.token_valid.store(false, Ordering::Relaxed);
+                Err(ApplicationError::ExternalService(format!(
+                    "Vault token renewal failed: {e}"
+                )))
+            },
+        }
+    }
+
+    /// Log in again using AppRole and replace the client token
+    async fn reauthenticate(&self) -> Result<(), ApplicationError> {
+        let mut client = self.client.write().await;
+
+        match Self::authenticate_approle(&client, &self.config).await {
+            Ok(auth) => {
+                client.set_token(&auth.client_token);
+                debug!(
+                    lease_secs = auth.lease_duration,
+                    "Re-authenticated with Vault"
+                );
+                self.record_lease(auth.lease_duration);
+                Ok(())
+            },
+            Err(e) => {
+                warn!(error = %e, "Vault re-authentication failed");
+                self.token_valid.store(false, Ordering::Relaxed);
+                Err(e)
+            },
+        }
+    }
+
+    /// Handle a `403 Forbidden` by logging in again
+    ///
+    /// Returns `true` if the request should be retried.
+    async fn recover_from_forbidden(&self, error: &ClientError) -> bool {
+        if !is_forbidden(error) || !self.config.uses_approle() {
+            return false;
+        }
+
+        debug!("Vault returned 403, re-authenticating");
+        self.reauthenticate().await.is_ok()
+    }
+
+    /// Fail fast while the token is known to be invalid
+    fn ensure_token_valid(&self) -> Result<(), ApplicationError> {
+        if self.token_valid.load(Ordering::Relaxed) {
+            Ok(())
+        } else {
+            Err(ApplicationError::ExternalService(
+                "Vault token expired and could not be renewed".to_string(),
+            ))
+        }
+    }
+
     /// Create the Vault client
     fn create_client(config: &VaultConfig) -> Result<VaultClient, ApplicationError> {
         let mut settings_builder = VaultClientSettingsBuilder::default();
@@ -155,7 +349,7 @@ impl VaultSecretStore {
     async fn authenticate_approle(
         client: &VaultClient,
         config: &VaultConfig,
-    ) -> Result<(), ApplicationError> {
+    ) -> Result<AuthInfo, ApplicationError> {
         let role_id = config.role_id.as_ref().ok_or_else(|| {
             ApplicationError::Configuration("AppRole role_id not configured".to_string())
         })?;
@@ -163,7 +357,7 @@ impl VaultSecretStore {
             ApplicationError::Configuration("AppRole secret_id not configured".to_string())
         })?;
 
-        vaultrs::auth::approle::login(client, "approle", role_id, secret_id)
+        let auth = vaultrs::auth::approle::login(client, "approle", role_id, secret_id)
             .await
             .map_err(|e| {
                 error!(error = %e, "AppRole authentication failed");
@@ -171,7 +365,7 @@ impl VaultSecretStore {
             })?;
 
         info!("Successfully authenticated with Vault using AppRole");
-        Ok(())
+        Ok(auth)
     }
 
     /// Parse a path to extract mount and secret path
@@ -207,21 +401,27 @@ impl VaultSecretStore {
 impl SecretStorePort for VaultSecretStore {
     #[instrument(skip(self))]
     async fn get_secret(&self, key: &str) -> Result<String, ApplicationError> {
+        self.ensure_token_valid()?;
         let (mount, path) = self.parse_path(key);
-        let client = self.client.read().await;
 
         debug!(mount = %mount, path = %path, "Fetching secret from Vault");
 
         // Try to read as a simple key-value where the value is stored under "value" key
-        let secret: std::collections::HashMap<String, String> =
-            kv2::read(&*client, &mount, &path).await.map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("not found") {
-                    ApplicationError::NotFound(format!("Secret not found: {key}"))
-                } else {
-                    error!(error = %e, "Failed to read secret from Vault");
-                    ApplicationError::ExternalService(format!("Vault read failed: {e}"))
-                }
-            })?;
+        let mut result = kv2::read(&*self.client.read().await, &mount, &path).await;
+        if let Err(e) = &result {
+            if self.recover_from_forbidden(e).await {
+                result = kv2::read(&*self.client.read().await, &mount, &path).await;
+            }
+        }
+
+        let secret: std::collections::HashMap<String, String> = result.map_err(|e| {
+            if e.to_string().contains("404") || e.to_string().contains("not found") {
+                ApplicationError::NotFound(format!("Secret not found: {key}"))
+            } else {
+                error!(error = %e, "Failed to read secret from Vault");
+                ApplicationError::ExternalService(format!("Vault read failed: {e}"))
+            }
+        })?;
 
         // Try common key names
         secret
@@ -235,30 +435,42 @@ impl SecretStorePort for VaultSecretStore {
 
     #[instrument(skip(self))]
     async fn get_json(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+        self.ensure_token_valid()?;
         let (mount, secret_path) = self.parse_path(path);
-        let client = self.client.read().await;
 
         debug!(mount = %mount, path = %secret_path, "Fetching JSON secret from Vault");
 
-        kv2::read(&*client, &mount, &secret_path)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("not found") {
-                    ApplicationError::NotFound(format!("Secret not found: {path}"))
-                } else {
-                    error!(error = %e, "Failed to read secret from Vault");
-                    ApplicationError::ExternalService(format!("Vault read failed: {e}"))
-                }
-            })
+        let mut result = kv2::read(&*self.client.read().await, &mount, &secret_path).await;
+        if let Err(e) = &result {
+            if self.recover_from_forbidden(e).await {
+                result = kv2::read(&*self.client.read().await, &mount, &secret_path).await;
+            }
+        }
+
+        result.map_err(|e| {
+            if e.to_string().contains("404") || e.to_string().contains("not found") {
+                ApplicationError::NotFound(format!("Secret not found: {path}"))
+            } else {
+                error!(error = %e, "Failed to read secret from Vault");
+                ApplicationError::ExternalService(format!("Vault read failed: {e}"))
+            }
+        })
     }
 
     #[instrument(skip(self))]
     async fn exists(&self, key: &str) -> Result<bool, ApplicationError> {
+        self.ensure_token_valid()?;
         let (mount, path) = self.parse_path(key);
-        let client = self.client.read().await;
 
         // Try to read metadata (doesn't retrieve the actual secret)
-        match kv2::read_metadata(&*client, &mount, &path).await {
+        let mut result = kv2::read_metadata(&*self.client.read().await, &mount, &path).await;
+        if let Err(e) = &result {
+            if self.recover_from_forbidden(e).await {
+                result = kv2::read_metadata(&*self.client.read().await, &mount, &path).await;
+            }
+        }
+
+        match result {
             Ok(_) => Ok(true),
             Err(e) if e.to_string().contains("404") => Ok(false),
             Err(e) => Err(ApplicationError::ExternalService(format!(
@@ -320,6 +532,7 @@ impl SecretStorePort for ChainedSecretStore {
                 Ok(value) => return Ok(value),
                 Err(ApplicationError::NotFound(_)) => {},
                 Err(e) => {
+                    debug!(error = %e, "Secret store failed, trying next store");
                     last_error = Some(e);
                 },
             }
@@ -338,6 +551,7 @@ impl SecretStorePort for ChainedSecretStore {
                 Ok(value) => return Ok(value),
                 Err(ApplicationError::NotFound(_)) => {},
                 Err(e) => {
+                    debug!(error = %e, "Secret store failed, trying next store");
                     last_error = Some(e);
                 },
             }
@@ -349,12 +563,20 @@ impl SecretStorePort for ChainedSecretStore {
     }
 
     async fn exists(&self, key: &str) -> Result<bool, ApplicationError> {
+        let mut last_error = None;
+
         for store in &self.stores {
-            if store.exists(key).await? {
-                return Ok(true);
+            match store.exists(key).await {
+                Ok(true) => return Ok(true),
+                Ok(false) => {},
+                Err(e) => {
+                    debug!(error = %e, "Secret store failed, trying next store");
+                    last_error = Some(e);
+                },
             }
         }
-        Ok(false)
+
+        last_error.map_or(Ok(false), Err)
     }
 
     async fn is_healthy(&self) -> bool {
@@ -394,6 +616,104 @@ mod tests {
         assert_eq!(config.secret_id, Some("my-secret-id".to_string()));
     }
 
+    #[test]
+    fn vault_config_with_renew_interval() {
+        let config =
+            VaultConfig::new("https://vault.example.com:8200").with_renew_interval_secs(600);
+
+        assert_eq!(config.renew_interval_secs, Some(600));
+        assert!(!config.uses_approle());
+        assert!(config.with_approle("role", "secret").uses_approle());
+    }
+
+    #[test]
+    fn renewal_delay_uses_two_thirds_of_ttl() {
+        assert_eq!(
+            renewal_delay(None, Some(3600)),
+            Some(Duration::from_secs(2400))
+        );
+    }
+
+    #[test]
+    fn renewal_delay_prefers_configured_interval() {
+        assert_eq!(
+            renewal_delay(Some(60), Some(3600)),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn renewal_delay_disabled_for_non_expiring_tokens() {
+        assert_eq!(renewal_delay(None, Some(0)), None);
+        assert_eq!(renewal_delay(Some(60), Some(0)), None);
+    }
+
+    #[test]
+    fn renewal_delay_defaults_when_ttl_unknown() {
+        assert_eq!(renewal_delay(None, None), Some(DEFAULT_RENEW_INTERVAL));
+    }
+
+    #[test]
+    fn renewal_delay_has_lower_bound() {
+        assert_eq!(renewal_delay(None, Some(3)), Some(MIN_RENEW_INTERVAL));
+        assert_eq!(renewal_delay(Some(1), None), Some(MIN_RENEW_INTERVAL));
+    }
+
+    #[test]
+    fn forbidden_error_is_detected() {
+        let forbidden = ClientError::APIError {
+            code: 403,
+            errors: vec!["permission denied".to_string()],
+        };
+        let not_found = ClientError::APIError {
+            code: 404,
+            errors: vec![],
+        };
+
+        assert!(is_forbidden(&forbidden));
+        assert!(!is_forbidden(&not_found));
+    }
+
+    #[tokio::test]
+    async fn invalid_token_falls_back_to_next_store() {
+        #[derive(Debug)]
+        struct StaticStore;
+
+        #[async_trait]
+        impl SecretStorePort for StaticStore {
+            async fn get_secret(&self, _key: &str) -> Result<String, ApplicationError> {
+                Ok("from_env".to_string())
+            }
+            async fn get_json(&self, _path: &str) -> Result<serde_json::Value, ApplicationError> {
+                Err(ApplicationError::NotFound(String::new()))
+            }
+            async fn exists(&self, _key: &str) -> Result<bool, ApplicationError> {
+                Ok(true)
+            }
+            async fn is_healthy(&self) -> bool {
+                true
+            }
+        }
+
+        // Nothing listens on port 1, so the TTL lookup fails and is ignored
+        let vault = VaultSecretStore::new(VaultConfig::new("http://127.0.0.1:1").with_token("t"))
+            .await
+            .unwrap();
+        vault.token_valid.store(false, Ordering::Relaxed);
+
+        assert!(matches!(
+            vault.get_secret("whatsapp/token").await,
+            Err(ApplicationError::ExternalService(_))
+        ));
+
+        let chained = ChainedSecretStore::new(vec![Arc::new(vault), Arc::new(StaticStore)]);
+        assert_eq!(
+            chained.get_secret("whatsapp/token").await.unwrap(),
+            "from_env"
+        );
+        assert!(chained.exists("whatsapp/token").await.unwrap());
+    }
+
     #[test]
     fn parse_path_simple() {
         // Cannot test parse_path directly without creating VaultSecretStore,
@@ -4,6 +4,7 @@ use application::error::ApplicationError;
 use application::ports::{CurrentWeather, DailyForecast, WeatherCondition, WeatherPort};
 use async_trait::async_trait;
 use domain::value_objects::GeoLocation;
+use integration_http::SharedCorrelatedClient;
 use integration_weather::{
     CurrentWeather as IntegrationCurrent, DailyForecast as IntegrationDaily, OpenMeteoClient,
     WeatherClient, WeatherCondition as IntegrationCondition, WeatherConfig, WeatherError,
@@ -11,11 +12,13 @@ use integration_weather::{
 use tracing::{debug, instrument};
 
 use super::{CircuitBreaker, CircuitBreakerConfig};
+use crate::cache::{NegativeCache, NegativeKind, generate_cache_key};
 
 /// Adapter for weather services using Open-Meteo API
 pub struct WeatherAdapter {
     client: OpenMeteoClient,
     circuit_breaker: Option<CircuitBreaker>,
+    negative_cache: Option<NegativeCache>,
 }
 
 impl std::fmt::Debug for WeatherAdapter {
@@ -26,6 +29,7 @@ impl std::fmt::Debug for WeatherAdapter {
                 "circuit_breaker",
                 &self.circuit_breaker.as_ref().map(CircuitBreaker::name),
             )
+            .field("negative_cache", &self.negative_cache.is_some())
             .finish()
     }
 }
@@ -42,6 +46,7 @@ impl WeatherAdapter {
         Ok(Self {
             client,
             circuit_breaker: None,
+            negative_cache: None,
         })
     }
 
@@ -56,9 +61,23 @@ impl WeatherAdapter {
         Ok(Self {
             client,
             circuit_breaker: None,
+            negative_cache: None,
         })
     }
 
+    /// Create with default configuration and a shared HTTP client
+    ///
+    /// Requests made through the shared client carry the `X-Request-Id`
+    /// of the incoming request that triggered them.
+    #[must_use]
+    pub fn with_http_client(client: SharedCorrelatedClient) -> Self {
+        Self {
+            client: OpenMeteoClient::with_http_client(WeatherConfig::default(), client),
+            circuit_breaker: None,
+            negative_cache: None,
+        }
+    }
+
     /// Enable circuit breaker with default configuration
     #[must_use]
     pub fn with_circuit_breaker(mut self) -> Self {
@@ -73,6 +92,47 @@ impl WeatherAdapter {
         self
     }
 
+    /// Remember failed lookups per location so they aren't retried immediately
+    #[must_use]
+    pub fn with_negative_cache(mut self, cache: NegativeCache) -> Self {
+        self.negative_cache = Some(cache);
+        self
+    }
+
+    /// Negative cache key for a location, rounded to about 10 m
+    fn negative_cache_key(location: &GeoLocation) -> String {
+        let coordinates = format!("{:.4},{:.4}", location.latitude(), location.longitude());
+        generate_cache_key("weather:failed", &[&coordinates])
+    }
+
+    /// Return the remembered failure for a location, if any
+    async fn cached_failure(&self, location: &GeoLocation) -> Result<(), ApplicationError> {
+        let Some(cache) = &self.negative_cache else {
+            return Ok(());
+        };
+        match cache.get(&Self::negative_cache_key(location)).await {
+            Some(entry) if entry.kind == NegativeKind::NotFound => {
+                Err(ApplicationError::InvalidOperation(entry.message))
+            },
+            Some(entry) => Err(ApplicationError::ExternalService(entry.message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Remember a failed lookup for a location
+    async fn remember_failure(&self, location: &GeoLocation, err: &WeatherError) {
+        let Some(cache) = &self.negative_cache else {
+            return;
+        };
+        let kind = match err {
+            WeatherError::InvalidCoordinates => NegativeKind::NotFound,
+            _ => NegativeKind::Transient,
+        };
+        cache
+            .remember(&Self::negative_cache_key(location), kind, err.to_string())
+            .await;
+    }
+
     /// Check circuit and return error if open
     fn check_circuit(&self) -> Result<(), ApplicationError> {
         if let Some(ref cb) = self.circuit_breaker {
@@ -161,12 +221,16 @@ impl WeatherPort for WeatherAdapter {
         location: &GeoLocation,
     ) -> Result<CurrentWeather, ApplicationError> {
         self.check_circuit()?;
+        self.cached_failure(location).await?;
 
         let result = self
             .client
             .get_current(location.latitude(), location.longitude())
-            .await
-            .map_err(Self::map_error);
+            .await;
+        if let Err(e) = &result {
+            self.remember_failure(location, e).await;
+        }
+        let result = result.map_err(Self::map_error);
 
         match &result {
             Ok(current) => {
@@ -191,12 +255,16 @@ impl WeatherPort for WeatherAdapter {
         days: u8,
     ) -> Result<Vec<DailyForecast>, ApplicationError> {
         self.check_circuit()?;
+        self.cached_failure(location).await?;
 
         let result = self
             .client
             .get_forecast(location.latitude(), location.longitude(), days)
-            .await
-            .map_err(Self::map_error);
+            .await;
+        if let Err(e) = &result {
+            self.remember_failure(location, e).await;
+        }
+        let result = result.map_err(Self::map_error);
 
         match &result {
             Ok(forecast) => {
@@ -241,6 +309,13 @@ mod tests {
         assert!(adapter.unwrap().circuit_breaker.is_none());
     }
 
+    #[test]
+    fn with_http_client_creates_adapter() {
+        let client = integration_http::create_shared_client().unwrap();
+        let adapter = WeatherAdapter::with_http_client(client);
+        assert!(adapter.circuit_breaker.is_none());
+    }
+
     #[test]
     fn with_circuit_breaker() {
         let adapter = WeatherAdapter::new().unwrap().with_circuit_breaker();
@@ -6,18 +6,20 @@ use application::error::ApplicationError;
 use application::ports::{SearchOptions, WebSearchPort};
 use async_trait::async_trait;
 use domain::entities::{SearchResult, WebSearchResponse};
+use integration_http::SharedCorrelatedClient;
 use integration_websearch::{
     SearchProvider, SearchResult as IntegrationResult, WebSearchClient, WebSearchConfig,
     WebSearchError, WebSearchResponse as IntegrationResponse,
 };
 use tracing::{debug, instrument};
 
-use super::{CircuitBreaker, CircuitBreakerConfig};
+use super::{Bulkhead, CircuitBreaker, CircuitBreakerConfig};
 
 /// Adapter for web search services using Brave and DuckDuckGo
 pub struct WebSearchAdapter {
     client: Arc<WebSearchClient>,
     circuit_breaker: Option<CircuitBreaker>,
+    bulkhead: Option<Bulkhead>,
 }
 
 impl std::fmt::Debug for WebSearchAdapter {
@@ -29,6 +31,10 @@ impl std::fmt::Debug for WebSearchAdapter {
                 "circuit_breaker",
                 &self.circuit_breaker.as_ref().map(CircuitBreaker::name),
             )
+            .field(
+                "max_concurrent",
+                &self.bulkhead.as_ref().map(Bulkhead::max_concurrent),
+            )
             .finish()
     }
 }
@@ -45,6 +51,28 @@ impl WebSearchAdapter {
         Ok(Self {
             client,
             circuit_breaker: None,
+            bulkhead: None,
+        })
+    }
+
+    /// Create with the given configuration and a shared HTTP client
+    ///
+    /// Brave requests made through the shared client carry the
+    /// `X-Request-Id` of the incoming request that triggered them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client fails to initialize.
+    pub fn with_http_client(
+        config: WebSearchConfig,
+        client: SharedCorrelatedClient,
+    ) -> Result<Self, ApplicationError> {
+        let client = WebSearchClient::with_http_client(config, client)
+            .map_err(|e| ApplicationError::Internal(e.to_string()))?;
+        Ok(Self {
+            client: Arc::new(client),
+            circuit_breaker: None,
+            bulkhead: None,
         })
     }
 
@@ -71,6 +99,13 @@ impl WebSearchAdapter {
         self
     }
 
+    /// Limit the number of concurrent searches
+    #[must_use]
+    pub fn with_bulkhead(mut self, max_concurrent: usize) -> Self {
+        self.bulkhead = Some(Bulkhead::new("websearch", max_concurrent));
+        self
+    }
+
     /// Check circuit and return error if open
     fn check_circuit(&self) -> Result<(), ApplicationError> {
         if let Some(ref cb) = self.circuit_breaker {
@@ -142,6 +177,12 @@ impl WebSearchPort for WebSearchAdapter {
         options: Option<SearchOptions>,
     ) -> Result<WebSearchResponse, ApplicationError> {
         self.check_circuit()?;
+        let _slot = self
+            .bulkhead
+            .as_ref()
+            .map(Bulkhead::try_acquire)
+            .transpose()
+            .map_err(|e| ApplicationError::ExternalService(e.to_string()))?;
 
         let max_results = options.as_ref().and_then(|o| o.max_results).unwrap_or(5) as usize;
 
@@ -400,6 +441,17 @@ mod error_path_tests {
         assert!(adapter.circuit_breaker.is_some());
     }
 
+    #[tokio::test]
+    async fn search_fails_fast_when_bulkhead_is_full() {
+        let adapter = WebSearchAdapter::with_defaults().unwrap().with_bulkhead(1);
+        let _held = adapter.bulkhead.as_ref().unwrap().try_acquire().unwrap();
+
+        let err = adapter.search("rust", None).await.unwrap_err();
+        assert!(
+            matches!(err, ApplicationError::ExternalService(ref msg) if msg.contains("Bulkhead full"))
+        );
+    }
+
     #[tokio::test]
     async fn is_available_true_when_no_circuit_breaker() {
         // Without circuit breaker, availability depends on actual health check
@@ -6,19 +6,33 @@ use std::sync::Arc;
 
 use application::error::ApplicationError;
 use application::ports::{
-    DownloadedAudio, MessengerPort, OutgoingAudioMessage, OutgoingTextMessage,
+    DownloadedAudio, MessengerPort, OutgoingAudioMessage, OutgoingTemplateMessage,
+    OutgoingTextMessage,
 };
 use async_trait::async_trait;
+use chrono::TimeDelta;
 use domain::{MessengerSource, PhoneNumber};
-use integration_whatsapp::{WhatsAppClient, WhatsAppClientConfig, WhatsAppError};
+use integration_whatsapp::{
+    TemplateComponent, WhatsAppClient, WhatsAppClientConfig, WhatsAppError,
+};
 use tracing::{debug, instrument};
 
+use super::SentMessageCache;
+
+/// Cloud API error codes for application, account, throughput and pair rate limits
+const RATE_LIMIT_ERROR_CODES: [i32; 5] = [4, 80_007, 130_429, 131_048, 131_056];
+
+/// Hours after the contact's last message during which free-form text is delivered
+const SESSION_WINDOW_HOURS: i64 = 24;
+
 /// Adapter that implements `MessengerPort` using `WhatsAppClient`
 pub struct WhatsAppMessengerAdapter {
     /// The underlying WhatsApp client
     client: WhatsAppClient,
     /// Whitelisted phone numbers (empty = allow all)
     whitelist: Arc<Vec<String>>,
+    /// Recently sent idempotency keys
+    sent: SentMessageCache,
 }
 
 impl WhatsAppMessengerAdapter {
@@ -31,6 +45,7 @@ impl WhatsAppMessengerAdapter {
         Ok(Self {
             client,
             whitelist: Arc::new(Vec::new()),
+            sent: SentMessageCache::default(),
         })
     }
 
@@ -46,9 +61,38 @@ impl WhatsAppMessengerAdapter {
         Ok(Self {
             client,
             whitelist: Arc::new(whitelist),
+            sent: SentMessageCache::default(),
         })
     }
 
+    /// Send a text message without deduplication
+    async fn deliver_text(
+        &self,
+        message: &OutgoingTextMessage,
+    ) -> Result<String, ApplicationError> {
+        let response = self
+            .client
+            .send_message(message.recipient.as_str(), &message.text)
+            .await
+            .map_err(|e| match e {
+                WhatsAppError::Api { code, .. } if RATE_LIMIT_ERROR_CODES.contains(&code) => {
+                    ApplicationError::RateLimited
+                },
+                e => ApplicationError::ExternalService(format!("WhatsApp send failed: {e}")),
+            })?;
+
+        let message_id = response
+            .messages
+            .first()
+            .map(|m| m.id.clone())
+            .ok_or_else(|| {
+                ApplicationError::ExternalService("No message ID in response".to_string())
+            })?;
+
+        debug!(message_id = %message_id, "WhatsApp text message sent");
+        Ok(message_id)
+    }
+
     /// Get a reference to the underlying client for advanced operations
     #[must_use]
     pub const fn client(&self) -> &WhatsAppClient {
@@ -81,11 +125,43 @@ impl MessengerPort for WhatsAppMessengerAdapter {
 
     #[instrument(skip(self, message), fields(recipient = %message.recipient))]
     async fn send_text(&self, message: OutgoingTextMessage) -> Result<String, ApplicationError> {
+        self.sent
+            .send_once(message.idempotency_key(), || self.deliver_text(&message))
+            .await
+    }
+
+    fn session_window(&self) -> Option<TimeDelta> {
+        Some(TimeDelta::hours(SESSION_WINDOW_HOURS))
+    }
+
+    #[instrument(skip(self, message), fields(recipient = %message.recipient, template = %message.name))]
+    async fn send_template(
+        &self,
+        message: OutgoingTemplateMessage,
+    ) -> Result<String, ApplicationError> {
+        let components = if message.parameters.is_empty() {
+            Vec::new()
+        } else {
+            vec![TemplateComponent::body(message.parameters)]
+        };
+
         let response = self
             .client
-            .send_message(message.recipient.as_str(), &message.text)
+            .send_template(
+                message.recipient.as_str(),
+                &message.name,
+                &message.language,
+                &components,
+            )
             .await
-            .map_err(|e| ApplicationError::ExternalService(format!("WhatsApp send failed: {e}")))?;
+            .map_err(|e| match e {
+                WhatsAppError::Api { code, .. } if RATE_LIMIT_ERROR_CODES.contains(&code) => {
+                    ApplicationError::RateLimited
+                },
+                e => {
+                    ApplicationError::ExternalService(format!("WhatsApp template send failed: {e}"))
+                },
+            })?;
 
         let message_id = response
             .messages
@@ -95,7 +171,7 @@ impl MessengerPort for WhatsAppMessengerAdapter {
                 ApplicationError::ExternalService("No message ID in response".to_string())
             })?;
 
-        debug!(message_id = %message_id, "WhatsApp text message sent");
+        debug!(message_id = %message_id, "WhatsApp template message sent");
         Ok(message_id)
     }
 
@@ -4,13 +4,16 @@
 //! - `MokaCache`: High-performance in-memory cache with TTL support
 //! - `RedbCache`: Embedded persistent cache for durability (replaced Sled)
 //! - `MultiLayerCache`: Combines L1 (Moka) and L2 (Redb) with write-through
+//! - `NegativeCache`: Short-lived memory of failed external lookups
 
 mod moka_cache;
 mod multi_layer_cache;
+mod negative_cache;
 mod redb_cache;
 
 pub use moka_cache::MokaCache;
 pub use multi_layer_cache::MultiLayerCache;
+pub use negative_cache::{NegativeCache, NegativeEntry, NegativeKind};
 pub use redb_cache::RedbCache;
 
 // Re-export for backwards compatibility during migration
@@ -38,11 +41,27 @@ pub fn generate_cache_key(prefix: &str, components: &[&str]) -> String {
 /// only occur for semantically equivalent requests.
 #[must_use]
 pub fn llm_cache_key(prompt: &str, model: &str, temperature: f32) -> String {
-    // Quantize temperature to avoid floating point comparison issues
-    let temp_str = format!("{temperature:.2}");
+    let temp_str = quantize_temperature(temperature);
     generate_cache_key("llm", &[prompt, model, &temp_str])
 }
 
+/// Quantize a temperature to two decimal places for use in cache keys
+///
+/// Avoids floating point comparison issues and maps values that are
+/// equivalent for sampling (`-0.0`, values rounding to zero) to the same
+/// string. Non-finite values get a key of their own.
+fn quantize_temperature(temperature: f32) -> String {
+    if !temperature.is_finite() {
+        return "invalid".to_string();
+    }
+    let quantized = format!("{temperature:.2}");
+    if quantized == "-0.00" {
+        "0.00".to_string()
+    } else {
+        quantized
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +121,12 @@ mod tests {
         let key2 = llm_cache_key("Hello", "gpt-4", 0.7001);
         assert_eq!(key1, key2);
     }
+
+    #[test]
+    fn llm_cache_key_normalizes_zero_temperature() {
+        let key = llm_cache_key("Hello", "gpt-4", 0.0);
+        assert_eq!(key, llm_cache_key("Hello", "gpt-4", -0.0));
+        assert_eq!(key, llm_cache_key("Hello", "gpt-4", -0.001));
+        assert_ne!(key, llm_cache_key("Hello", "gpt-4", f32::NAN));
+    }
 }
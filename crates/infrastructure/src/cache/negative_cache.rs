@@ -0,0 +1,182 @@
+//! Negative result caching
+//!
+//! Remembers failed external lookups for a short time so that repeated
+//! requests for a nonexistent address or an unreachable service don't hit
+//! the upstream API again immediately.
+
+use std::{sync::Arc, time::Duration};
+
+use application::ports::{CachePort, CachePortExt};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Transient failures are cached for this fraction of the not-found TTL
+const TRANSIENT_TTL_DIVISOR: u32 = 10;
+
+/// Why a lookup failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NegativeKind {
+    /// The lookup succeeded but there is no result (e.g. unknown address)
+    NotFound,
+    /// The upstream service failed (timeout, connection error, rate limit)
+    Transient,
+}
+
+/// A remembered failed lookup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NegativeEntry {
+    /// Why the lookup failed
+    pub kind: NegativeKind,
+    /// Error message of the original failure
+    pub message: String,
+    /// When the entry stops short-circuiting lookups
+    ///
+    /// Stored in the entry because not every cache backend honors
+    /// per-entry TTLs.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Cache for failed lookups on top of a [`CachePort`]
+///
+/// Genuine not-found results are kept for the configured TTL; transient
+/// errors only for a tenth of it (at least one second), so an outage
+/// doesn't hide a recovered service for long.
+#[derive(Debug, Clone)]
+pub struct NegativeCache {
+    cache: Arc<dyn CachePort>,
+    not_found_ttl: Duration,
+    transient_ttl: Duration,
+}
+
+impl NegativeCache {
+    /// Create a negative cache keeping not-found results for `not_found_ttl`
+    #[must_use]
+    pub fn new(cache: Arc<dyn CachePort>, not_found_ttl: Duration) -> Self {
+        let transient_ttl = (not_found_ttl / TRANSIENT_TTL_DIVISOR).max(Duration::from_secs(1));
+        Self {
+            cache,
+            not_found_ttl,
+            transient_ttl: transient_ttl.min(not_found_ttl),
+        }
+    }
+
+    /// Override the TTL for transient failures
+    #[must_use]
+    pub const fn with_transient_ttl(mut self, ttl: Duration) -> Self {
+        self.transient_ttl = ttl;
+        self
+    }
+
+    /// TTL used for entries of the given kind
+    #[must_use]
+    pub const fn ttl(&self, kind: NegativeKind) -> Duration {
+        match kind {
+            NegativeKind::NotFound => self.not_found_ttl,
+            NegativeKind::Transient => self.transient_ttl,
+        }
+    }
+
+    /// Look up a remembered failure that has not expired yet
+    ///
+    /// Cache errors are logged and treated as a miss.
+    pub async fn get(&self, key: &str) -> Option<NegativeEntry> {
+        match self.cache.get::<NegativeEntry>(key).await {
+            Ok(Some(entry)) if entry.expires_at > Utc::now() => {
+                debug!(key = %key, kind = ?entry.kind, "Negative cache hit");
+                Some(entry)
+            },
+            Ok(_) => None,
+            Err(e) => {
+                warn!(error = %e, key = %key, "Negative cache read error");
+                None
+            },
+        }
+    }
+
+    /// Remember a failed lookup
+    ///
+    /// Cache errors are logged and otherwise ignored.
+    pub async fn remember(&self, key: &str, kind: NegativeKind, message: impl Into<String>) {
+        let ttl = self.ttl(kind);
+        if ttl.is_zero() {
+            return;
+        }
+        let Ok(expires_in) = chrono::Duration::from_std(ttl) else {
+            return;
+        };
+
+        let entry = NegativeEntry {
+            kind,
+            message: message.into(),
+            expires_at: Utc::now() + expires_in,
+        };
+        if let Err(e) = self.cache.set(key, &entry, ttl).await {
+            warn!(error = %e, key = %key, "Negative cache write error");
+        } else {
+            debug!(key = %key, ?kind, ttl_secs = ttl.as_secs(), "Cached failed lookup");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::MokaCache;
+
+    fn negative_cache(ttl_secs: u64) -> NegativeCache {
+        NegativeCache::new(Arc::new(MokaCache::new()), Duration::from_secs(ttl_secs))
+    }
+
+    #[test]
+    fn transient_ttl_is_much_shorter() {
+        let cache = negative_cache(300);
+        assert_eq!(cache.ttl(NegativeKind::NotFound), Duration::from_secs(300));
+        assert_eq!(cache.ttl(NegativeKind::Transient), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn transient_ttl_has_lower_bound() {
+        let cache = negative_cache(5);
+        assert_eq!(cache.ttl(NegativeKind::Transient), Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn remember_and_get() {
+        let cache = negative_cache(300);
+        cache
+            .remember("geo:nowhere", NegativeKind::NotFound, "Address not found")
+            .await;
+
+        let entry = cache.get("geo:nowhere").await.unwrap();
+        assert_eq!(entry.kind, NegativeKind::NotFound);
+        assert_eq!(entry.message, "Address not found");
+        assert!(cache.get("geo:elsewhere").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn expired_entry_is_ignored() {
+        let backend = Arc::new(MokaCache::new());
+        let cache = NegativeCache::new(backend.clone(), Duration::from_secs(300));
+        let stale = NegativeEntry {
+            kind: NegativeKind::Transient,
+            message: "timed out".to_string(),
+            expires_at: Utc::now() - chrono::Duration::seconds(1),
+        };
+        backend
+            .set("weather:x", &stale, Duration::from_secs(300))
+            .await
+            .unwrap();
+
+        assert!(cache.get("weather:x").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn zero_ttl_disables_caching() {
+        let cache = negative_cache(300).with_transient_ttl(Duration::ZERO);
+        cache
+            .remember("weather:x", NegativeKind::Transient, "timed out")
+            .await;
+        assert!(cache.get("weather:x").await.is_none());
+    }
+}
@@ -0,0 +1,449 @@
+//! Port decorators that route calls through a [`ChaosController`].
+//!
+//! Health checks (`is_healthy`, `is_available`) and model metadata pass
+//! through unchanged so faults show up as failed requests, not as a
+//! misconfigured service.
+
+use std::sync::Arc;
+
+use application::{
+    error::ApplicationError,
+    ports::{
+        CalendarError, CalendarEvent, CalendarInfo, CalendarPort, CurrentWeather, DailyForecast,
+        InferencePort, InferenceResult, InferenceStream, NewEvent, WeatherPort,
+    },
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::{Conversation, value_objects::GeoLocation};
+
+use super::{ChaosController, ChaosTarget, InjectedError};
+
+impl From<InjectedError> for ApplicationError {
+    fn from(err: InjectedError) -> Self {
+        match err {
+            InjectedError::RateLimited => Self::RateLimited,
+            other => Self::ExternalService(other.to_string()),
+        }
+    }
+}
+
+impl From<InjectedError> for CalendarError {
+    fn from(err: InjectedError) -> Self {
+        match err {
+            InjectedError::ConnectionRefused
+            | InjectedError::ConnectionReset
+            | InjectedError::Timeout(_) => Self::ServiceUnavailable,
+            other => Self::OperationFailed(other.to_string()),
+        }
+    }
+}
+
+/// Inference port with fault injection
+pub struct ChaosInferenceAdapter {
+    inner: Arc<dyn InferencePort>,
+    chaos: Arc<ChaosController>,
+}
+
+impl std::fmt::Debug for ChaosInferenceAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChaosInferenceAdapter")
+            .field("chaos", &self.chaos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChaosInferenceAdapter {
+    /// Wrap an inference port
+    pub fn new(inner: Arc<dyn InferencePort>, chaos: Arc<ChaosController>) -> Self {
+        Self { inner, chaos }
+    }
+}
+
+#[async_trait]
+impl InferencePort for ChaosInferenceAdapter {
+    async fn generate(&self, message: &str) -> Result<InferenceResult, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate",
+                self.inner.generate(message),
+            )
+            .await
+    }
+
+    async fn generate_with_context(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<InferenceResult, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_with_context",
+                self.inner.generate_with_context(conversation),
+            )
+            .await
+    }
+
+    async fn generate_with_system(
+        &self,
+        system_prompt: &str,
+        message: &str,
+    ) -> Result<InferenceResult, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_with_system",
+                self.inner.generate_with_system(system_prompt, message),
+            )
+            .await
+    }
+
+    async fn generate_structured(
+        &self,
+        system_prompt: &str,
+        message: &str,
+        schema: &serde_json::Value,
+    ) -> Result<InferenceResult, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_structured",
+                self.inner
+                    .generate_structured(system_prompt, message, schema),
+            )
+            .await
+    }
+
+    async fn generate_stream(&self, message: &str) -> Result<InferenceStream, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_stream",
+                self.inner.generate_stream(message),
+            )
+            .await
+    }
+
+    async fn generate_stream_with_system(
+        &self,
+        system_prompt: &str,
+        message: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_stream_with_system",
+                self.inner
+                    .generate_stream_with_system(system_prompt, message),
+            )
+            .await
+    }
+
+    async fn generate_stream_with_context(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<InferenceStream, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_stream_with_context",
+                self.inner.generate_stream_with_context(conversation),
+            )
+            .await
+    }
+
+    async fn generate_stream_with_context_and_model(
+        &self,
+        conversation: &Conversation,
+        model: &str,
+    ) -> Result<InferenceStream, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Inference,
+                "generate_stream_with_context_and_model",
+                self.inner
+                    .generate_stream_with_context_and_model(conversation, model),
+            )
+            .await
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.inner.is_healthy().await
+    }
+
+    async fn warmup(&self) -> Result<(), ApplicationError> {
+        self.inner.warmup().await
+    }
+
+    fn current_model(&self) -> String {
+        self.inner.current_model()
+    }
+
+    async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError> {
+        self.inner.list_available_models().await
+    }
+
+    async fn switch_model(&self, model_name: &str) -> Result<(), ApplicationError> {
+        self.inner.switch_model(model_name).await
+    }
+}
+
+/// Weather port with fault injection
+pub struct ChaosWeatherAdapter {
+    inner: Arc<dyn WeatherPort>,
+    chaos: Arc<ChaosController>,
+}
+
+impl std::fmt::Debug for ChaosWeatherAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChaosWeatherAdapter")
+            .field("chaos", &self.chaos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChaosWeatherAdapter {
+    /// Wrap a weather port
+    pub fn new(inner: Arc<dyn WeatherPort>, chaos: Arc<ChaosController>) -> Self {
+        Self { inner, chaos }
+    }
+}
+
+#[async_trait]
+impl WeatherPort for ChaosWeatherAdapter {
+    async fn get_current_weather(
+        &self,
+        location: &GeoLocation,
+    ) -> Result<CurrentWeather, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Weather,
+                "get_current_weather",
+                self.inner.get_current_weather(location),
+            )
+            .await
+    }
+
+    async fn get_forecast(
+        &self,
+        location: &GeoLocation,
+        days: u8,
+    ) -> Result<Vec<DailyForecast>, ApplicationError> {
+        self.chaos
+            .run(
+                ChaosTarget::Weather,
+                "get_forecast",
+                self.inner.get_forecast(location, days),
+            )
+            .await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+/// Calendar port with fault injection
+pub struct ChaosCalendarAdapter {
+    inner: Arc<dyn CalendarPort>,
+    chaos: Arc<ChaosController>,
+}
+
+impl std::fmt::Debug for ChaosCalendarAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChaosCalendarAdapter")
+            .field("chaos", &self.chaos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChaosCalendarAdapter {
+    /// Wrap a calendar port
+    pub fn new(inner: Arc<dyn CalendarPort>, chaos: Arc<ChaosController>) -> Self {
+        Self { inner, chaos }
+    }
+}
+
+#[async_trait]
+impl CalendarPort for ChaosCalendarAdapter {
+    async fn list_calendars(&self) -> Result<Vec<CalendarInfo>, CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "list_calendars",
+                self.inner.list_calendars(),
+            )
+            .await
+    }
+
+    async fn get_events_for_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<CalendarEvent>, CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "get_events_for_date",
+                self.inner.get_events_for_date(date),
+            )
+            .await
+    }
+
+    async fn get_events_in_range(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>, CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "get_events_in_range",
+                self.inner.get_events_in_range(start, end),
+            )
+            .await
+    }
+
+    async fn get_event(&self, event_id: &str) -> Result<CalendarEvent, CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "get_event",
+                self.inner.get_event(event_id),
+            )
+            .await
+    }
+
+    async fn create_event(&self, event: &NewEvent) -> Result<String, CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "create_event",
+                self.inner.create_event(event),
+            )
+            .await
+    }
+
+    async fn update_event(&self, event_id: &str, event: &NewEvent) -> Result<(), CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "update_event",
+                self.inner.update_event(event_id, event),
+            )
+            .await
+    }
+
+    async fn delete_event(&self, event_id: &str) -> Result<(), CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "delete_event",
+                self.inner.delete_event(event_id),
+            )
+            .await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    async fn get_next_event(&self) -> Result<Option<CalendarEvent>, CalendarError> {
+        self.chaos
+            .run(
+                ChaosTarget::Caldav,
+                "get_next_event",
+                self.inner.get_next_event(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::chaos::{FaultPolicy, FaultType};
+
+    /// Weather port that counts forecast calls
+    #[derive(Debug, Default)]
+    struct CountingWeather {
+        forecasts: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl WeatherPort for CountingWeather {
+        async fn get_current_weather(
+            &self,
+            _location: &GeoLocation,
+        ) -> Result<CurrentWeather, ApplicationError> {
+            Err(ApplicationError::NotFound("weather".to_string()))
+        }
+        async fn get_forecast(
+            &self,
+            _location: &GeoLocation,
+            _days: u8,
+        ) -> Result<Vec<DailyForecast>, ApplicationError> {
+            self.forecasts.fetch_add(1, Ordering::SeqCst);
+            Ok(Vec::new())
+        }
+        async fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn location() -> GeoLocation {
+        GeoLocation::new(52.52, 13.405).unwrap()
+    }
+
+    #[tokio::test]
+    async fn weather_passes_through_without_policy() {
+        let inner = Arc::new(CountingWeather::default());
+        let adapter =
+            ChaosWeatherAdapter::new(Arc::clone(&inner) as Arc<dyn WeatherPort>, Arc::default());
+
+        assert!(
+            adapter
+                .get_forecast(&location(), 3)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(inner.forecasts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn weather_fault_skips_inner_call() {
+        let inner = Arc::new(CountingWeather::default());
+        let chaos = Arc::new(ChaosController::new());
+        chaos.set_policy(
+            ChaosTarget::Weather,
+            FaultPolicy::always(FaultType::ConnectionRefused),
+        );
+        let adapter = ChaosWeatherAdapter::new(Arc::clone(&inner) as Arc<dyn WeatherPort>, chaos);
+
+        let err = adapter.get_forecast(&location(), 3).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::ExternalService(_)));
+        assert_eq!(inner.forecasts.load(Ordering::SeqCst), 0);
+        assert!(adapter.is_available().await);
+    }
+
+    #[test]
+    fn injected_rate_limit_maps_to_rate_limited() {
+        let err = ApplicationError::from(InjectedError::RateLimited);
+        assert!(matches!(err, ApplicationError::RateLimited));
+    }
+
+    #[test]
+    fn injected_connection_errors_make_calendar_unavailable() {
+        assert!(matches!(
+            CalendarError::from(InjectedError::ConnectionReset),
+            CalendarError::ServiceUnavailable
+        ));
+        assert!(matches!(
+            CalendarError::from(InjectedError::Generic("boom".to_string())),
+            CalendarError::OperationFailed(_)
+        ));
+    }
+}
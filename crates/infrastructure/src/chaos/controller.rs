@@ -0,0 +1,306 @@
+//! Runtime control of fault injection for game days.
+//!
+//! The controller holds one [`FaultInjector`] per [`ChaosTarget`]. Chaos
+//! adapters ask it for a fault on every call; policies are installed and
+//! cleared at runtime (e.g. from an admin endpoint).
+
+use std::{collections::HashMap, fmt, future::Future, str::FromStr};
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{ChaosStats, FaultInjector, FaultPolicy, FaultType, InjectedError};
+
+/// Adapter that faults can be injected into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChaosTarget {
+    /// LLM inference backend
+    Inference,
+    /// Weather service
+    Weather,
+    /// CalDAV calendar
+    Caldav,
+}
+
+impl ChaosTarget {
+    /// All targets, in display order
+    pub const ALL: [Self; 3] = [Self::Inference, Self::Weather, Self::Caldav];
+
+    /// Stable name used in logs and URLs
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Inference => "inference",
+            Self::Weather => "weather",
+            Self::Caldav => "caldav",
+        }
+    }
+}
+
+impl fmt::Display for ChaosTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ChaosTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|target| target.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown chaos target: {s}"))
+    }
+}
+
+/// Active policy and statistics for one target
+#[derive(Debug, Clone, Serialize)]
+pub struct ChaosTargetStatus {
+    /// Target the policy applies to
+    pub target: ChaosTarget,
+    /// Installed policy
+    pub policy: FaultPolicy,
+    /// Injection statistics since the policy was installed
+    pub stats: ChaosStats,
+    /// Faults left before the limit is reached (if limited)
+    pub remaining_faults: Option<u64>,
+}
+
+/// Shared registry of fault policies per target
+#[derive(Debug, Default)]
+pub struct ChaosController {
+    injectors: RwLock<HashMap<ChaosTarget, Mutex<FaultInjector>>>,
+}
+
+impl ChaosController {
+    /// Create a controller with no active policies
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install a policy for a target, replacing any previous one
+    pub fn set_policy(&self, target: ChaosTarget, policy: FaultPolicy) {
+        warn!(
+            target = %target,
+            fault_rate = policy.fault_rate,
+            fault = ?policy.fault_type,
+            enabled = policy.enabled,
+            "🔥 CHAOS: fault injection policy installed"
+        );
+        self.injectors
+            .write()
+            .insert(target, Mutex::new(FaultInjector::from_policy(policy)));
+    }
+
+    /// Remove the policy for a target
+    ///
+    /// Returns `true` if a policy was installed.
+    pub fn clear(&self, target: ChaosTarget) -> bool {
+        let removed = self.injectors.write().remove(&target).is_some();
+        if removed {
+            warn!(target = %target, "🔥 CHAOS: fault injection policy removed");
+        }
+        removed
+    }
+
+    /// Remove all policies
+    pub fn clear_all(&self) {
+        let mut injectors = self.injectors.write();
+        if !injectors.is_empty() {
+            warn!(
+                count = injectors.len(),
+                "🔥 CHAOS: all fault injection policies removed"
+            );
+        }
+        injectors.clear();
+    }
+
+    /// Whether any enabled policy is installed
+    pub fn is_active(&self) -> bool {
+        self.injectors
+            .read()
+            .values()
+            .any(|injector| injector.lock().policy().enabled)
+    }
+
+    /// Policies and statistics of all targets with an installed policy
+    pub fn status(&self) -> Vec<ChaosTargetStatus> {
+        let injectors = self.injectors.read();
+        ChaosTarget::ALL
+            .into_iter()
+            .filter_map(|target| {
+                let injector = injectors.get(&target)?.lock();
+                Some(ChaosTargetStatus {
+                    target,
+                    policy: injector.policy().clone(),
+                    stats: injector.stats().clone(),
+                    remaining_faults: injector.remaining_faults(),
+                })
+            })
+            .collect()
+    }
+
+    /// Select the fault for one call, if any
+    pub fn next_fault(&self, target: ChaosTarget, operation: &str) -> Option<FaultType> {
+        let injectors = self.injectors.read();
+        let mut injector = injectors.get(&target)?.lock();
+        if !injector.policy().should_target(operation) {
+            return None;
+        }
+
+        let fault = injector.maybe_inject()?;
+        warn!(target = %target, operation, fault = ?fault, "🔥 CHAOS: injecting fault");
+        Some(fault)
+    }
+
+    /// Run an operation, injecting a fault according to the target's policy
+    pub async fn run<F, T, E>(
+        &self,
+        target: ChaosTarget,
+        operation: &str,
+        future: F,
+    ) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<InjectedError>,
+    {
+        let fault = self.next_fault(target, operation);
+        FaultInjector::apply(fault, future).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::chaos::LatencyDistribution;
+
+    #[test]
+    fn target_round_trips_through_str() {
+        for target in ChaosTarget::ALL {
+            assert_eq!(target.as_str().parse::<ChaosTarget>().unwrap(), target);
+        }
+        assert_eq!(
+            "CalDAV".parse::<ChaosTarget>().unwrap(),
+            ChaosTarget::Caldav
+        );
+        assert!("email".parse::<ChaosTarget>().is_err());
+    }
+
+    #[test]
+    fn new_controller_is_inactive() {
+        let controller = ChaosController::new();
+        assert!(!controller.is_active());
+        assert!(controller.status().is_empty());
+        assert!(
+            controller
+                .next_fault(ChaosTarget::Inference, "generate")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn policy_only_affects_its_target() {
+        let controller = ChaosController::new();
+        controller.set_policy(
+            ChaosTarget::Weather,
+            FaultPolicy::always(FaultType::ConnectionRefused),
+        );
+
+        assert!(controller.is_active());
+        assert!(
+            controller
+                .next_fault(ChaosTarget::Weather, "get_forecast")
+                .is_some()
+        );
+        assert!(
+            controller
+                .next_fault(ChaosTarget::Caldav, "get_event")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn policy_respects_target_operations() {
+        let controller = ChaosController::new();
+        controller.set_policy(
+            ChaosTarget::Inference,
+            FaultPolicy::always(FaultType::RateLimited).with_targets(vec!["generate".to_string()]),
+        );
+
+        assert!(
+            controller
+                .next_fault(ChaosTarget::Inference, "generate")
+                .is_some()
+        );
+        assert!(
+            controller
+                .next_fault(ChaosTarget::Inference, "generate_stream")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn clear_removes_policy() {
+        let controller = ChaosController::new();
+        controller.set_policy(
+            ChaosTarget::Caldav,
+            FaultPolicy::always(FaultType::default()),
+        );
+
+        assert!(controller.clear(ChaosTarget::Caldav));
+        assert!(!controller.clear(ChaosTarget::Caldav));
+        assert!(!controller.is_active());
+    }
+
+    #[test]
+    fn status_reports_stats() {
+        let controller = ChaosController::new();
+        controller.set_policy(
+            ChaosTarget::Inference,
+            FaultPolicy::always(FaultType::default()).with_max_faults(2),
+        );
+        controller.next_fault(ChaosTarget::Inference, "generate");
+
+        let status = controller.status();
+        assert_eq!(status.len(), 1);
+        assert_eq!(status[0].target, ChaosTarget::Inference);
+        assert_eq!(status[0].stats.faults_injected, 1);
+        assert_eq!(status[0].remaining_faults, Some(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn run_injects_latency() {
+        let controller = ChaosController::new();
+        controller.set_policy(
+            ChaosTarget::Weather,
+            FaultPolicy::latency(
+                1.0,
+                LatencyDistribution::constant(Duration::from_millis(250)),
+            ),
+        );
+
+        let start = tokio::time::Instant::now();
+        let result: Result<u32, InjectedError> = controller
+            .run(ChaosTarget::Weather, "get_forecast", async { Ok(7) })
+            .await;
+
+        assert_eq!(result.unwrap(), 7);
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn run_injects_error() {
+        let controller = ChaosController::new();
+        controller.set_policy(ChaosTarget::Caldav, FaultPolicy::error(1.0, "boom"));
+
+        let result: Result<u32, InjectedError> = controller
+            .run(ChaosTarget::Caldav, "get_event", async { Ok(7) })
+            .await;
+
+        assert!(matches!(result, Err(InjectedError::Generic(msg)) if msg == "boom"));
+    }
+}
@@ -1,7 +1,8 @@
 //! Fault injector for chaos engineering.
 //!
 //! This module provides the core fault injection functionality that can be used
-//! to simulate various failure modes in tests.
+//! to simulate various failure modes in tests and, with the `chaos` feature,
+//! during game days.
 
 use std::future::Future;
 use std::io;
@@ -143,6 +144,27 @@ impl FaultInjector {
         }
     }
 
+    /// Create a fault injector honoring the policy's cooldown and fault limit
+    pub fn from_policy(policy: FaultPolicy) -> Self {
+        let config = FaultInjectorConfig {
+            enabled: policy.enabled,
+            cooldown: policy.cooldown,
+        };
+        let context = policy.max_faults.map_or_else(ChaosContext::new, |max| {
+            ChaosContext::with_max_faults(u64::try_from(max).unwrap_or(u64::MAX))
+        });
+        Self {
+            config,
+            policy,
+            context,
+        }
+    }
+
+    /// Get the active policy
+    pub const fn policy(&self) -> &FaultPolicy {
+        &self.policy
+    }
+
     /// Create a disabled fault injector (no-op)
     pub fn disabled() -> Self {
         Self {
@@ -236,7 +258,20 @@ impl FaultInjector {
         F: Future<Output = Result<T, E>>,
         E: From<InjectedError>,
     {
-        if let Some(fault_type) = self.maybe_inject() {
+        let fault = self.maybe_inject();
+        Self::apply(fault, operation).await
+    }
+
+    /// Run an operation under a previously selected fault
+    ///
+    /// Separate from [`wrap`](Self::wrap) so callers sharing an injector can
+    /// select the fault under a lock and await the operation without it.
+    pub async fn apply<F, T, E>(fault: Option<FaultType>, operation: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<InjectedError>,
+    {
+        if let Some(fault_type) = fault {
             match &fault_type {
                 FaultType::Latency(dist) => {
                     // Add latency, then execute
@@ -538,6 +573,26 @@ mod tests {
         assert!(debug.contains("FaultInjector"));
     }
 
+    #[test]
+    fn injector_from_policy_applies_limit_and_cooldown() {
+        let policy = FaultPolicy::always(FaultType::RateLimited)
+            .with_max_faults(3)
+            .with_cooldown(Duration::from_secs(60));
+        let mut injector = FaultInjector::from_policy(policy);
+
+        assert_eq!(injector.remaining_faults(), Some(3));
+        assert!(injector.maybe_inject().is_some());
+        // Second call falls within the cooldown
+        assert!(injector.maybe_inject().is_none());
+    }
+
+    #[test]
+    fn injector_from_disabled_policy_never_injects() {
+        let mut injector = FaultInjector::from_policy(FaultPolicy::never());
+        assert!(injector.maybe_inject().is_none());
+        assert!(!injector.policy().enabled);
+    }
+
     #[test]
     fn injector_with_config() {
         let config = FaultInjectorConfig::enabled().with_cooldown(Duration::from_secs(1));
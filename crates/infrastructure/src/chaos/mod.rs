@@ -9,6 +9,10 @@
 //! - `FaultInjector`: Intercepts calls and injects faults based on configuration
 //! - `FaultPolicy`: Defines what kinds of faults to inject and how often
 //! - `ChaosContext`: Tracks fault injection state and statistics
+//! - `ChaosController`: Runtime registry of policies per adapter
+//! - `Chaos*Adapter`: Port decorators for inference, weather and CalDAV
+//!
+//! Compiled for tests and, for game days, behind the `chaos` cargo feature.
 //!
 //! # Example
 //!
@@ -29,10 +33,14 @@
 //! }).await;
 //! ```
 
+mod adapters;
 mod chaos_context;
+mod controller;
 mod fault_injector;
 mod fault_policy;
 
+pub use adapters::{ChaosCalendarAdapter, ChaosInferenceAdapter, ChaosWeatherAdapter};
 pub use chaos_context::{ChaosContext, ChaosStats, InjectionResult};
-pub use fault_injector::{FaultInjector, FaultInjectorConfig};
+pub use controller::{ChaosController, ChaosTarget, ChaosTargetStatus};
+pub use fault_injector::{FaultInjector, FaultInjectorConfig, InjectedError};
 pub use fault_policy::{FaultPolicy, FaultType, LatencyDistribution};
@@ -34,6 +34,13 @@ pub struct CacheConfig {
     /// Maximum number of entries in L1 (in-memory) cache
     #[serde(default = "default_l1_max_entries")]
     pub l1_max_entries: u64,
+
+    /// TTL in seconds for failed weather and geocoding lookups (default: 5 minutes)
+    ///
+    /// Applies to genuine not-found results; transient errors are cached for
+    /// a tenth of it. Set to 0 to disable negative caching.
+    #[serde(default = "default_cache_ttl_short")]
+    pub negative_cache_ttl_secs: u64,
 }
 
 const fn default_cache_ttl_short() -> u64 {
@@ -62,6 +69,7 @@ impl Default for CacheConfig {
             ttl_llm_dynamic_secs: default_cache_ttl_medium(),
             ttl_llm_stable_secs: default_cache_ttl_long(),
             l1_max_entries: default_l1_max_entries(),
+            negative_cache_ttl_secs: default_cache_ttl_short(),
         }
     }
 }
@@ -96,4 +104,14 @@ impl CacheConfig {
     pub const fn ttl_llm_stable(&self) -> std::time::Duration {
         std::time::Duration::from_secs(self.ttl_llm_stable_secs)
     }
+
+    /// Get the negative cache TTL as a Duration, `None` if disabled
+    #[must_use]
+    pub const fn negative_cache_ttl(&self) -> Option<std::time::Duration> {
+        if self.enabled && self.negative_cache_ttl_secs > 0 {
+            Some(std::time::Duration::from_secs(self.negative_cache_ttl_secs))
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,54 @@
+//! Conversation handling configuration.
+
+use application::SummarizationConfig;
+use serde::{Deserialize, Serialize};
+
+use super::default_true;
+
+/// Contextual chat configuration
+///
+/// Long conversations are condensed with rolling summarization: once more
+/// than `summarize_after_messages` messages have accumulated, all but the
+/// `summary_keep_recent` newest are summarized by the model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationAppConfig {
+    /// Summarize old turns instead of dropping them (default: true)
+    #[serde(default = "default_true")]
+    pub summarization_enabled: bool,
+
+    /// Message count that triggers summarization (default: 40)
+    #[serde(default = "default_summarize_after_messages")]
+    pub summarize_after_messages: usize,
+
+    /// Newest messages kept verbatim when summarizing (default: 10)
+    #[serde(default = "default_summary_keep_recent")]
+    pub summary_keep_recent: usize,
+}
+
+const fn default_summarize_after_messages() -> usize {
+    40
+}
+
+const fn default_summary_keep_recent() -> usize {
+    10
+}
+
+impl Default for ConversationAppConfig {
+    fn default() -> Self {
+        Self {
+            summarization_enabled: true,
+            summarize_after_messages: default_summarize_after_messages(),
+            summary_keep_recent: default_summary_keep_recent(),
+        }
+    }
+}
+
+impl ConversationAppConfig {
+    /// Summarization settings for the chat service, `None` if disabled
+    #[must_use]
+    pub fn summarization(&self) -> Option<SummarizationConfig> {
+        self.summarization_enabled.then(|| {
+            SummarizationConfig::new(self.summarize_after_messages, self.summary_keep_recent)
+        })
+    }
+}
@@ -18,6 +18,24 @@ pub struct DatabaseConfig {
     /// Whether to run pending migrations on startup (default: true)
     #[serde(default = "default_true")]
     pub run_migrations: bool,
+
+    /// Run periodic integrity checks and VACUUM (default: true)
+    #[serde(default = "default_true")]
+    pub maintenance_enabled: bool,
+
+    /// Interval between maintenance runs in hours (default: 168 = weekly)
+    #[serde(default = "default_maintenance_interval_hours")]
+    pub maintenance_interval_hours: u64,
+
+    /// Days after which email drafts are removed by the daily cleanup
+    /// (default: 7)
+    #[serde(default = "default_draft_ttl_days")]
+    pub draft_ttl_days: u32,
+
+    /// Directory with `pisovereign-cli backup` files to restore from when
+    /// the integrity check fails at startup (disabled if unset)
+    #[serde(default)]
+    pub restore_backup_dir: Option<String>,
 }
 
 fn default_db_path() -> String {
@@ -28,12 +46,25 @@ const fn default_max_connections() -> u32 {
     5
 }
 
+const fn default_maintenance_interval_hours() -> u64 {
+    168
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn default_draft_ttl_days() -> u32 {
+    domain::DEFAULT_DRAFT_TTL_DAYS as u32
+}
+
 impl Default for DatabaseConfig {
     fn default() -> Self {
         Self {
             path: default_db_path(),
             max_connections: default_max_connections(),
             run_migrations: true,
+            maintenance_enabled: true,
+            maintenance_interval_hours: default_maintenance_interval_hours(),
+            draft_ttl_days: default_draft_ttl_days(),
+            restore_backup_dir: None,
         }
     }
 }
@@ -129,6 +129,10 @@ pub struct WebSearchAppConfig {
     /// Cache TTL in minutes for search results
     #[serde(default = "default_websearch_cache_ttl")]
     pub cache_ttl_minutes: u32,
+
+    /// Maximum concurrent searches; further searches fail fast (default: 8)
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
 }
 
 const fn default_websearch_max_results() -> u32 {
@@ -147,6 +151,10 @@ const fn default_websearch_cache_ttl() -> u32 {
     30
 }
 
+const fn default_max_concurrent_requests() -> usize {
+    8
+}
+
 impl Default for WebSearchAppConfig {
     fn default() -> Self {
         Self {
@@ -159,6 +167,7 @@ impl Default for WebSearchAppConfig {
             language: None,
             rate_limit_rpm: None,
             cache_ttl_minutes: default_websearch_cache_ttl(),
+            max_concurrent_requests: default_max_concurrent_requests(),
         }
     }
 }
@@ -407,7 +416,7 @@ const fn default_smtp_port() -> u16 {
 }
 
 /// TLS configuration for Proton Bridge connections
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProtonTlsAppConfig {
     /// Verify TLS certificates (default: true)
     #[serde(default = "default_true")]
@@ -426,6 +435,16 @@ fn default_min_tls() -> String {
     "1.2".to_string()
 }
 
+impl Default for ProtonTlsAppConfig {
+    fn default() -> Self {
+        Self {
+            verify_certificates: true,
+            min_tls_version: default_min_tls(),
+            ca_cert_path: None,
+        }
+    }
+}
+
 impl ProtonAppConfig {
     /// Convert to `integration_proton`'s `ProtonConfig`
     #[must_use]
@@ -478,10 +497,23 @@ pub struct TransitAppConfig {
     #[serde(default = "default_transit_cache_ttl")]
     pub cache_ttl_minutes: u32,
 
+    /// Maximum concurrent requests; further requests fail fast (default: 8)
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+
     /// Include transit info in location-based reminders (default: true)
     #[serde(default = "default_true")]
     pub include_in_reminders: bool,
 
+    /// Suggest in the morning briefing when to leave for the first
+    /// appointment with a location (default: false)
+    #[serde(default)]
+    pub suggest_departure_in_briefing: bool,
+
+    /// Minutes to arrive before the appointment starts (default: 5)
+    #[serde(default = "default_transit_arrival_buffer")]
+    pub arrival_buffer_minutes: u32,
+
     /// Include bus connections (default: true)
     #[serde(default = "default_true")]
     pub products_bus: bool,
@@ -527,6 +559,10 @@ const fn default_transit_cache_ttl() -> u32 {
     5
 }
 
+const fn default_transit_arrival_buffer() -> u32 {
+    5
+}
+
 impl Default for TransitAppConfig {
     fn default() -> Self {
         Self {
@@ -534,7 +570,10 @@ impl Default for TransitAppConfig {
             timeout_secs: default_transit_timeout(),
             max_results: default_transit_max_results(),
             cache_ttl_minutes: default_transit_cache_ttl(),
+            max_concurrent_requests: default_max_concurrent_requests(),
             include_in_reminders: true,
+            suggest_departure_in_briefing: false,
+            arrival_buffer_minutes: default_transit_arrival_buffer(),
             products_bus: true,
             products_suburban: true,
             products_subway: true,
@@ -1,5 +1,6 @@
 //! Memory/Knowledge storage, Embedding, and Reminder configurations.
 
+use domain::BriefingSection;
 use serde::{Deserialize, Serialize};
 
 use super::default_true;
@@ -55,9 +56,17 @@ pub struct MemoryAppConfig {
     #[serde(default = "default_encryption_key_path")]
     pub encryption_key_path: String,
 
+    /// Interval between decay and consolidation runs in hours (default: 24)
+    #[serde(default = "default_consolidation_interval_hours")]
+    pub consolidation_interval_hours: u64,
+
     /// Embedding model configuration
     #[serde(default)]
     pub embedding: EmbeddingAppConfig,
+
+    /// Embedding-based command routing
+    #[serde(default)]
+    pub semantic_routing: SemanticRoutingAppConfig,
 }
 
 impl Default for MemoryAppConfig {
@@ -73,7 +82,9 @@ impl Default for MemoryAppConfig {
             decay_factor: default_decay_factor(),
             enable_encryption: true,
             encryption_key_path: default_encryption_key_path(),
+            consolidation_interval_hours: default_consolidation_interval_hours(),
             embedding: EmbeddingAppConfig::default(),
+            semantic_routing: SemanticRoutingAppConfig::default(),
         }
     }
 }
@@ -104,7 +115,48 @@ impl Default for EmbeddingAppConfig {
     }
 }
 
+/// Embedding-based command routing configuration
+///
+/// Routes paraphrases of simple commands (briefing, inbox, reminders, ...)
+/// by similarity to canonical phrases, skipping the LLM call. Uses the
+/// embedding model configured above.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticRoutingAppConfig {
+    /// Enable semantic routing (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Minimum cosine similarity to route without the LLM (0.0-1.0, default: 0.85)
+    #[serde(default = "default_semantic_threshold")]
+    pub threshold: f32,
+
+    /// Number of input embeddings kept in memory (default: 256)
+    #[serde(default = "default_semantic_cache_size")]
+    pub cache_size: usize,
+}
+
+impl Default for SemanticRoutingAppConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: default_semantic_threshold(),
+            cache_size: default_semantic_cache_size(),
+        }
+    }
+}
+
 impl MemoryAppConfig {
+    /// Semantic router settings, `None` if semantic routing is disabled
+    #[must_use]
+    pub fn semantic_router_config(&self) -> Option<application::SemanticRouterConfig> {
+        self.semantic_routing
+            .enabled
+            .then(|| application::SemanticRouterConfig {
+                threshold: self.semantic_routing.threshold,
+                cache_size: self.semantic_routing.cache_size,
+            })
+    }
+
     /// Convert to `MemoryServiceConfig`
     #[must_use]
     pub const fn to_memory_service_config(&self) -> application::MemoryServiceConfig {
@@ -146,6 +198,10 @@ impl MemoryAppConfig {
 }
 
 // Default value functions for memory config
+const fn default_consolidation_interval_hours() -> u64 {
+    24
+}
+
 const fn default_rag_limit() -> usize {
     5
 }
@@ -182,6 +238,14 @@ const fn default_embedding_timeout() -> u64 {
     30000
 }
 
+const fn default_semantic_threshold() -> f32 {
+    0.85
+}
+
+const fn default_semantic_cache_size() -> usize {
+    256
+}
+
 // ==============================
 // Reminder Configuration
 // ==============================
@@ -219,6 +283,11 @@ pub struct ReminderAppConfig {
     /// Enable morning briefing (default: true)
     #[serde(default = "default_true")]
     pub morning_briefing_enabled: bool,
+
+    /// Morning briefing sections in display order, used when a user's
+    /// profile doesn't choose its own (default: all sections)
+    #[serde(default = "BriefingSection::defaults")]
+    pub briefing_sections: Vec<BriefingSection>,
 }
 
 const fn default_max_snooze() -> u8 {
@@ -255,6 +324,7 @@ impl Default for ReminderAppConfig {
             caldav_sync_interval_minutes: default_caldav_sync_interval(),
             morning_briefing_time: default_morning_briefing_time(),
             morning_briefing_enabled: true,
+            briefing_sections: BriefingSection::defaults(),
         }
     }
 }
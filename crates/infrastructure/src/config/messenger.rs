@@ -1,5 +1,11 @@
-//! Messenger configuration: WhatsApp, Signal, conversation persistence.
+//! Messenger configuration: WhatsApp, Signal, conversation persistence,
+//! daily digest.
 
+use std::collections::HashMap;
+
+use application::services::{DailyDigestConfig, DigestTemplate, QuietHours};
+use chrono::NaiveTime;
+use domain::{PhoneNumber, Timezone};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 
@@ -137,6 +143,12 @@ pub struct SignalConfig {
     #[serde(default = "default_poll_interval_secs")]
     pub poll_interval_secs: u64,
 
+    /// Answer voice messages with a spoken reply (default: true)
+    ///
+    /// When disabled, or if speech synthesis fails, replies are sent as text.
+    #[serde(default = "default_true")]
+    pub voice_replies: bool,
+
     /// Conversation persistence configuration
     #[serde(default)]
     pub persistence: MessengerPersistenceConfig,
@@ -164,6 +176,7 @@ impl std::fmt::Debug for SignalConfig {
             .field("whitelist", &format!("[{} entries]", self.whitelist.len()))
             .field("auto_poll", &self.auto_poll)
             .field("poll_interval_secs", &self.poll_interval_secs)
+            .field("voice_replies", &self.voice_replies)
             .field("persistence", &self.persistence)
             .finish()
     }
@@ -179,6 +192,7 @@ impl Default for SignalConfig {
             whitelist: Vec::new(),
             auto_poll: true,
             poll_interval_secs: default_poll_interval_secs(),
+            voice_replies: true,
             persistence: MessengerPersistenceConfig::default(),
         }
     }
@@ -241,3 +255,114 @@ impl Default for MessengerPersistenceConfig {
         }
     }
 }
+
+/// Daily digest configuration
+///
+/// Pushes the morning briefing to the owner's own numbers on the active
+/// messenger. Opt-in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestAppConfig {
+    /// Push the daily digest (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The owner's phone numbers the digest is pushed to
+    ///
+    /// The briefing contains the owner's calendar and mail, so it is never
+    /// pushed to other contacts. The numbers must also be whitelisted.
+    #[serde(default)]
+    pub recipients: Vec<String>,
+
+    /// When to send, as 6-field cron expression evaluated in UTC
+    /// (default: the scheduler's morning briefing time, 07:00)
+    #[serde(default)]
+    pub schedule: Option<String>,
+
+    /// Start of the quiet hours (HH:MM in the default timezone)
+    #[serde(default)]
+    pub quiet_hours_start: Option<String>,
+
+    /// End of the quiet hours (HH:MM in the default timezone)
+    #[serde(default)]
+    pub quiet_hours_end: Option<String>,
+
+    /// WhatsApp template sent to contacts outside the 24-hour session window
+    ///
+    /// The template gets the date as its only body parameter. Without one,
+    /// these contacts are skipped.
+    #[serde(default)]
+    pub whatsapp_template: Option<String>,
+
+    /// Language code of the WhatsApp template (default: "en_US")
+    #[serde(default = "default_digest_template_language")]
+    pub whatsapp_template_language: String,
+
+    /// Per-contact settings, keyed by phone number
+    #[serde(default)]
+    pub users: HashMap<String, DigestUserConfig>,
+}
+
+/// Daily digest settings of one contact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestUserConfig {
+    /// Push the daily digest to this contact (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_digest_template_language() -> String {
+    "en_US".to_string()
+}
+
+impl Default for DigestAppConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            recipients: Vec::new(),
+            schedule: None,
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+            whatsapp_template: None,
+            whatsapp_template_language: default_digest_template_language(),
+            users: HashMap::new(),
+        }
+    }
+}
+
+impl DigestAppConfig {
+    /// Quiet hours, if both start and end are set and valid
+    #[must_use]
+    pub fn quiet_hours(&self) -> Option<QuietHours> {
+        let start = parse_time(self.quiet_hours_start.as_deref()?)?;
+        let end = parse_time(self.quiet_hours_end.as_deref()?)?;
+        Some(QuietHours::new(start, end))
+    }
+
+    /// Convert to the application layer config
+    #[must_use]
+    pub fn to_digest_config(&self, timezone: Timezone) -> DailyDigestConfig {
+        let disabled = self
+            .users
+            .iter()
+            .filter(|(_, user)| !user.enabled)
+            .filter_map(|(phone, _)| PhoneNumber::normalize(phone).ok())
+            .map(|phone| phone.as_str().to_string())
+            .collect();
+
+        DailyDigestConfig {
+            recipients: self.recipients.clone(),
+            disabled,
+            quiet_hours: self.quiet_hours(),
+            timezone,
+            template: self.whatsapp_template.as_ref().map(|name| DigestTemplate {
+                name: name.clone(),
+                language: self.whatsapp_template_language.clone(),
+            }),
+        }
+    }
+}
+
+/// Parse an HH:MM time of day
+pub(super) fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
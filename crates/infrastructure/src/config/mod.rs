@@ -4,13 +4,16 @@
 //! - `server`: HTTP server settings
 //! - `security`: Authentication, rate limiting, TLS
 //! - `cache`: Cache TTL configuration
-//! - `messenger`: WhatsApp, Signal, persistence
+//! - `conversation`: Contextual chat summarization
+//! - `messenger`: WhatsApp, Signal, persistence, daily digest
 //! - `database`: SQLite database settings
 //! - `integrations`: Weather, web search, CalDAV, Proton, transit
 //! - `resilience`: Telemetry, retry, degraded mode, health
 //! - `memory`: Memory/RAG, embeddings, reminders
+//! - `validate`: Load-time checks of enum-like fields and ranges
 
 mod cache;
+mod conversation;
 mod database;
 mod integrations;
 mod memory;
@@ -18,6 +21,7 @@ mod messenger;
 mod resilience;
 mod security;
 mod server;
+mod validate;
 mod vault;
 
 use ai_core::InferenceConfig;
@@ -26,44 +30,68 @@ use application::ports::SecretStorePort;
 use domain::MessengerSource;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, path::Path};
 use tracing::{debug, info, warn};
 
 pub use cache::CacheConfig;
+pub use conversation::ConversationAppConfig;
 pub use database::DatabaseConfig;
 pub use integrations::{
     CalDavAppConfig, CardDavAppConfig, GeoLocationConfig, ProtonAppConfig, ProtonTlsAppConfig,
     TransitAppConfig, WeatherConfig, WebSearchAppConfig,
 };
-pub use memory::{EmbeddingAppConfig, MemoryAppConfig, ReminderAppConfig};
-pub use messenger::{MessengerPersistenceConfig, SignalConfig, WhatsAppConfig};
+pub use memory::{
+    EmbeddingAppConfig, MemoryAppConfig, ReminderAppConfig, SemanticRoutingAppConfig,
+};
+pub use messenger::{
+    DigestAppConfig, DigestUserConfig, MessengerPersistenceConfig, SignalConfig, WhatsAppConfig,
+};
 pub use resilience::{DegradedModeAppConfig, HealthAppConfig, RetryAppConfig, TelemetryAppConfig};
-pub use security::{ApiKeyEntry, PromptSecurityConfig, SecurityConfig};
-pub use server::ServerConfig;
+pub use security::{ApiKeyEntry, JwtAuthConfig, PromptSecurityConfig, SecurityConfig};
+pub use server::{CorsPolicy, CorsRouteConfig, ServerConfig};
+pub use validate::{ConfigProblem, ConfigValidationError};
 pub use vault::VaultAppConfig;
 
+/// Environment variable selecting the environment and its overlay file
+pub const ENVIRONMENT_VAR: &str = "PISOVEREIGN_ENVIRONMENT";
+
 /// Shared default for boolean `true` fields across config structs
 pub(crate) const fn default_true() -> bool {
     true
 }
 
-/// Application environment (development or production)
+/// Application environment (development, staging or production)
 ///
-/// Controls security validation strictness and default behaviors.
+/// Controls security validation strictness and default behaviors. Staging
+/// is validated like production.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Environment {
     /// Development environment - relaxed security warnings
     #[default]
+    #[serde(alias = "dev")]
     Development,
+    /// Staging environment - production settings before a release
+    #[serde(alias = "stage")]
+    Staging,
     /// Production environment - strict security validation
+    #[serde(alias = "prod")]
     Production,
 }
 
+impl Environment {
+    /// Whether production rules (strict validation, JSON logs) apply
+    #[must_use]
+    pub const fn is_production_like(self) -> bool {
+        matches!(self, Self::Staging | Self::Production)
+    }
+}
+
 impl fmt::Display for Environment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Development => write!(f, "development"),
+            Self::Staging => write!(f, "staging"),
             Self::Production => write!(f, "production"),
         }
     }
@@ -75,9 +103,10 @@ impl std::str::FromStr for Environment {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "development" | "dev" => Ok(Self::Development),
+            "staging" | "stage" => Ok(Self::Staging),
             "production" | "prod" => Ok(Self::Production),
             _ => Err(format!(
-                "Invalid environment: {s}. Use 'development' or 'production'"
+                "Invalid environment: {s}. Use 'development', 'staging' or 'production'"
             )),
         }
     }
@@ -140,6 +169,21 @@ pub struct AppConfig {
     #[serde(default)]
     pub messenger: MessengerSelection,
 
+    /// Language assumed for ambiguous command input: "de", "en", "fr" or "es"
+    ///
+    /// Commands are understood in all four languages; this only breaks ties
+    /// during intent detection. Defaults to German.
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Server default IANA timezone (e.g. "Europe/Berlin")
+    ///
+    /// Times like "9:00" in reminders are read in the user's profile
+    /// timezone, or in this one when the profile has none. Defaults to
+    /// Europe/Berlin.
+    #[serde(default)]
+    pub timezone: Option<String>,
+
     /// Server configuration
     #[serde(default)]
     pub server: ServerConfig,
@@ -172,6 +216,14 @@ pub struct AppConfig {
     #[serde(default)]
     pub cache: CacheConfig,
 
+    /// Conversation configuration (rolling summarization)
+    #[serde(default)]
+    pub conversation: ConversationAppConfig,
+
+    /// Template configuration (custom `templates_dir`, e.g. for the system prompt)
+    #[serde(default)]
+    pub templates: crate::templates::TemplateConfig,
+
     /// Weather configuration (optional)
     #[serde(default)]
     pub weather: Option<WeatherConfig>,
@@ -225,30 +277,117 @@ pub struct AppConfig {
     #[serde(default)]
     pub reminder: Option<ReminderAppConfig>,
 
+    /// Daily digest configuration (optional, pushes the morning briefing)
+    #[serde(default)]
+    pub digest: Option<DigestAppConfig>,
+
     /// Vault secret store configuration (optional)
     #[serde(default)]
     pub vault: VaultAppConfig,
 }
 
 impl AppConfig {
+    /// Default language for the command parser
+    ///
+    /// Unknown values fall back to German with a warning.
+    #[must_use]
+    pub fn parser_language(&self) -> application::ParserLanguage {
+        self.language
+            .as_deref()
+            .map_or_else(application::ParserLanguage::default, |language| {
+                language.parse().unwrap_or_else(|e| {
+                    warn!(error = %e, "Unsupported default language, using German");
+                    application::ParserLanguage::default()
+                })
+            })
+    }
+
+    /// Server default timezone
+    ///
+    /// Unknown values fall back to Europe/Berlin with a warning.
+    #[must_use]
+    pub fn default_timezone(&self) -> domain::Timezone {
+        self.timezone
+            .as_deref()
+            .map_or_else(domain::Timezone::berlin, |timezone| {
+                domain::Timezone::try_new(timezone).unwrap_or_else(|e| {
+                    warn!(error = %e, "Invalid default timezone, using Europe/Berlin");
+                    domain::Timezone::berlin()
+                })
+            })
+    }
+
     /// Load configuration from environment and optional file
     pub fn load() -> Result<Self, config::ConfigError> {
-        let builder = config::Config::builder()
+        Self::load_from(None)
+    }
+
+    /// Load configuration from environment and a config file
+    ///
+    /// With `None`, reads `config.toml` (or another `config.*` format) from
+    /// the working directory if present. An explicitly given file must exist.
+    ///
+    /// Sources are layered, later ones winning:
+    /// 1. Built-in defaults
+    /// 2. The base file, e.g. `config.toml`
+    /// 3. The environment overlay next to it, e.g. `config.production.toml`,
+    ///    if present. The environment comes from `PISOVEREIGN_ENVIRONMENT` or
+    ///    else the base file's `environment` field; without either, no
+    ///    overlay is loaded.
+    /// 4. `PISOVEREIGN_*` environment variables
+    pub fn load_from(path: Option<&Path>) -> Result<Self, config::ConfigError> {
+        Self::load_layered(path, None)
+    }
+
+    /// Layer defaults, base file, environment overlay and environment variables
+    ///
+    /// `vars` replaces the process environment, so tests need not modify it.
+    fn load_layered(
+        path: Option<&Path>,
+        vars: Option<config::Map<String, String>>,
+    ) -> Result<Self, config::ConfigError> {
+        let base = path.map_or_else(
+            || config::File::with_name("config").required(false),
+            |path| config::File::from(path).required(true),
+        );
+
+        let selected = match vars {
+            Some(ref vars) => vars.get(ENVIRONMENT_VAR).cloned(),
+            None => std::env::var(ENVIRONMENT_VAR).ok(),
+        };
+        let selected = match selected {
+            Some(name) => Some(name),
+            None => config::Config::builder()
+                .add_source(base.clone())
+                .build()?
+                .get_string("environment")
+                .ok(),
+        };
+
+        let mut builder = config::Config::builder()
             // Start with defaults
             .set_default("server.host", "0.0.0.0")?
             .set_default("server.port", 3000)?
             .set_default("inference.base_url", "http://localhost:11434")?
             .set_default("inference.default_model", "qwen2.5-1.5b-instruct")?
             // Load from file if exists
-            .add_source(config::File::with_name("config").required(false))
-            // Override with environment variables (e.g., PISOVEREIGN_SERVER_PORT)
+            .add_source(base);
+
+        if let Some(name) = selected {
+            let environment: Environment = name.parse().map_err(config::ConfigError::Message)?;
+            debug!(environment = %environment, "Layering environment config overlay");
+            builder = builder.add_source(overlay_file(path, environment));
+        }
+
+        // Override with environment variables (e.g., PISOVEREIGN_SERVER_PORT)
+        let config = builder
             .add_source(
                 config::Environment::with_prefix("PISOVEREIGN")
                     .separator("_")
-                    .try_parsing(true),
-            );
-
-        let config = builder.build()?;
+                    .try_parsing(true)
+                    .source(vars),
+            )
+            .build()?;
         config.try_deserialize()
     }
 
@@ -446,10 +585,199 @@ impl AppConfig {
     }
 }
 
+/// Optional overlay file for `environment` next to the base file
+///
+/// `config.toml` becomes `config.production.toml`; the default base file
+/// `config` matches `config.production.*` in any supported format.
+fn overlay_file(
+    base: Option<&Path>,
+    environment: Environment,
+) -> config::File<config::FileSourceFile, config::FileFormat> {
+    let Some(base) = base else {
+        return config::File::with_name(&format!("config.{environment}")).required(false);
+    };
+
+    let stem = base.file_stem().unwrap_or_default().to_string_lossy();
+    let name = base.extension().map_or_else(
+        || format!("{stem}.{environment}"),
+        |extension| format!("{stem}.{environment}.{}", extension.to_string_lossy()),
+    );
+    config::File::from(base.with_file_name(name)).required(false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn load_from_explicit_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("custom.toml");
+        std::fs::write(&path, "[server]\nport = 4123\n").unwrap();
+
+        let config = AppConfig::load_from(Some(&path)).unwrap();
+        assert_eq!(config.server.port, 4123);
+    }
+
+    fn write_config(dir: &Path, name: &str, content: &str) -> std::path::PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> config::Map<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn overlay_selected_by_environment_field_wins_over_base() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(
+            dir.path(),
+            "config.toml",
+            "environment = \"production\"\n[server]\nport = 4000\nhost = \"127.0.0.1\"\n",
+        );
+        write_config(
+            dir.path(),
+            "config.production.toml",
+            "[server]\nport = 5000\n",
+        );
+
+        let config = AppConfig::load_layered(Some(&base), Some(vars(&[]))).unwrap();
+
+        assert_eq!(config.server.port, 5000);
+        assert_eq!(config.server.host, "127.0.0.1");
+    }
+
+    #[test]
+    fn overlay_selected_by_environment_variable() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(dir.path(), "config.toml", "[server]\nport = 4000\n");
+        write_config(
+            dir.path(),
+            "config.production.toml",
+            "[server]\nport = 5000\n",
+        );
+        write_config(
+            dir.path(),
+            "config.development.toml",
+            "[server]\nport = 6000\n",
+        );
+
+        let config = AppConfig::load_layered(
+            Some(&base),
+            Some(vars(&[("PISOVEREIGN_ENVIRONMENT", "production")])),
+        )
+        .unwrap();
+
+        assert_eq!(config.server.port, 5000);
+        assert_eq!(config.environment, Some(Environment::Production));
+    }
+
+    #[test]
+    fn staging_overlay_selected_by_environment_variable() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(dir.path(), "config.toml", "[server]\nport = 4000\n");
+        write_config(dir.path(), "config.staging.toml", "[server]\nport = 7000\n");
+
+        let config = AppConfig::load_layered(
+            Some(&base),
+            Some(vars(&[("PISOVEREIGN_ENVIRONMENT", "staging")])),
+        )
+        .unwrap();
+
+        assert_eq!(config.server.port, 7000);
+        assert_eq!(config.environment, Some(Environment::Staging));
+    }
+
+    #[test]
+    fn overlay_name_is_normalized() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(dir.path(), "config.toml", "[server]\nport = 4000\n");
+        write_config(
+            dir.path(),
+            "config.production.toml",
+            "[server]\nport = 5000\n",
+        );
+
+        let config = AppConfig::load_layered(
+            Some(&base),
+            Some(vars(&[("PISOVEREIGN_ENVIRONMENT", "prod")])),
+        )
+        .unwrap();
+
+        assert_eq!(config.server.port, 5000);
+        assert_eq!(config.environment, Some(Environment::Production));
+    }
+
+    #[test]
+    fn environment_variable_wins_over_overlay() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(
+            dir.path(),
+            "config.toml",
+            "environment = \"production\"\n[server]\nport = 4000\n",
+        );
+        write_config(
+            dir.path(),
+            "config.production.toml",
+            "[server]\nport = 5000\n",
+        );
+
+        let config = AppConfig::load_layered(
+            Some(&base),
+            Some(vars(&[("PISOVEREIGN_SERVER_PORT", "7000")])),
+        )
+        .unwrap();
+
+        assert_eq!(config.server.port, 7000);
+    }
+
+    #[test]
+    fn missing_overlay_keeps_base() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(
+            dir.path(),
+            "config.toml",
+            "environment = \"development\"\n[server]\nport = 4000\n",
+        );
+
+        let config = AppConfig::load_layered(Some(&base), Some(vars(&[]))).unwrap();
+
+        assert_eq!(config.server.port, 4000);
+    }
+
+    #[test]
+    fn invalid_environment_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write_config(dir.path(), "config.toml", "[server]\nport = 4000\n");
+
+        let result = AppConfig::load_layered(
+            Some(&base),
+            Some(vars(&[("PISOVEREIGN_ENVIRONMENT", "staging")])),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn overlay_file_name_keeps_extension() {
+        let path = Path::new("/etc/pisovereign/config.toml");
+        let file = overlay_file(Some(path), Environment::Production);
+        assert!(format!("{file:?}").contains("config.production.toml"));
+    }
+
+    #[test]
+    fn load_from_missing_explicit_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.toml");
+
+        assert!(AppConfig::load_from(Some(&path)).is_err());
+    }
+
     // Environment tests
     #[test]
     fn environment_default_is_development() {
@@ -460,6 +788,7 @@ mod tests {
     #[test]
     fn environment_display() {
         assert_eq!(format!("{}", Environment::Development), "development");
+        assert_eq!(format!("{}", Environment::Staging), "staging");
         assert_eq!(format!("{}", Environment::Production), "production");
     }
 
@@ -481,6 +810,22 @@ mod tests {
             "prod".parse::<Environment>().unwrap(),
             Environment::Production
         );
+        assert_eq!(
+            "staging".parse::<Environment>().unwrap(),
+            Environment::Staging
+        );
+        assert_eq!(
+            "stage".parse::<Environment>().unwrap(),
+            Environment::Staging
+        );
+        assert!("test".parse::<Environment>().is_err());
+    }
+
+    #[test]
+    fn staging_is_production_like() {
+        assert!(!Environment::Development.is_production_like());
+        assert!(Environment::Staging.is_production_like());
+        assert!(Environment::Production.is_production_like());
     }
 
     #[test]
@@ -616,6 +961,60 @@ mod tests {
         assert_eq!(config.host, "127.0.0.1");
         assert_eq!(config.port, 3000);
         assert!(config.cors_enabled);
+        assert_eq!(config.request_timeout_secs, 120);
+    }
+
+    #[test]
+    fn server_config_request_timeout_deserialization() {
+        let config: ServerConfig = serde_json::from_str(r#"{"request_timeout_secs":15}"#).unwrap();
+        assert_eq!(config.request_timeout_secs, 15);
+
+        let config: ServerConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.request_timeout_secs, 120);
+    }
+
+    #[test]
+    fn cors_policy_uses_longest_matching_route() {
+        let config: ServerConfig = toml::from_str(
+            r#"
+            allowed_origins = ["https://app.example.com"]
+            cors_max_age_secs = 300
+
+            [[cors_routes]]
+            path_prefix = "/webhook"
+            enabled = false
+
+            [[cors_routes]]
+            path_prefix = "/v1"
+            allow_credentials = true
+
+            [[cors_routes]]
+            path_prefix = "/v1/public"
+            allowed_origins = ["*"]
+            allow_credentials = true
+            max_age_secs = 60
+            "#,
+        )
+        .unwrap();
+
+        let webhook = config.cors_policy("/webhook/whatsapp");
+        assert!(!webhook.allows_origin("https://app.example.com"));
+
+        let spa = config.cors_policy("/v1/chat");
+        assert!(spa.allows_origin("https://app.example.com"));
+        assert!(!spa.allows_origin("https://evil.example"));
+        assert!(spa.allows_credentials());
+        assert_eq!(spa.max_age_secs, 300);
+
+        // Wildcard origins never get credentials
+        let public = config.cors_policy("/v1/public/status");
+        assert!(public.allows_origin("https://evil.example"));
+        assert!(!public.allows_credentials());
+        assert_eq!(public.max_age_secs, 60);
+
+        let other = config.cors_policy("/health");
+        assert!(other.allows_origin("https://app.example.com"));
+        assert!(!other.allows_credentials());
     }
 
     #[test]
@@ -623,7 +1022,9 @@ mod tests {
         let config = SecurityConfig::default();
         assert!(config.whitelisted_phones.is_empty());
         assert!(config.api_keys.is_empty());
+        assert!(config.admin_user_ids.is_empty());
         assert!(config.trusted_proxies.is_empty());
+        assert!(config.metrics_allowed_ips.is_empty());
         assert!(config.rate_limit_enabled);
         assert_eq!(config.rate_limit_rpm, 60);
     }
@@ -680,10 +1081,12 @@ mod tests {
         config.api_keys.push(ApiKeyEntry {
             hash: "$argon2id$v=19$m=19456,t=2,p=1$valid".to_string(),
             user_id: "user1".to_string(),
+            scopes: Vec::new(),
         });
         config.api_keys.push(ApiKeyEntry {
             hash: "plaintext-key".to_string(),
             user_id: "user2".to_string(),
+            scopes: Vec::new(),
         });
         assert_eq!(config.count_plaintext_keys(), 1);
     }
@@ -695,6 +1098,116 @@ mod tests {
         assert_eq!(config.trusted_proxies.len(), 2);
     }
 
+    #[test]
+    fn security_config_metrics_allowed_ips() {
+        let json = r#"{"metrics_allowed_ips":["10.0.0.5","::1"]}"#;
+        let config: SecurityConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.metrics_allowed_ips.len(), 2);
+    }
+
+    #[test]
+    fn parser_language_defaults_to_german() {
+        let config = AppConfig::default();
+        assert_eq!(
+            config.parser_language(),
+            application::ParserLanguage::German
+        );
+    }
+
+    #[test]
+    fn parser_language_from_config() {
+        let config: AppConfig = serde_json::from_str(r#"{"language":"fr"}"#).unwrap();
+        assert_eq!(
+            config.parser_language(),
+            application::ParserLanguage::French
+        );
+
+        let config: AppConfig = serde_json::from_str(r#"{"language":"klingon"}"#).unwrap();
+        assert_eq!(
+            config.parser_language(),
+            application::ParserLanguage::German
+        );
+    }
+
+    #[test]
+    fn default_timezone_from_config() {
+        assert_eq!(
+            AppConfig::default().default_timezone(),
+            domain::Timezone::berlin()
+        );
+
+        let config: AppConfig = serde_json::from_str(r#"{"timezone":"America/New_York"}"#).unwrap();
+        assert_eq!(config.default_timezone(), domain::Timezone::new_york());
+
+        let config: AppConfig = serde_json::from_str(r#"{"timezone":"Mars/Olympus"}"#).unwrap();
+        assert_eq!(config.default_timezone(), domain::Timezone::berlin());
+    }
+
+    #[test]
+    fn digest_config_from_toml() {
+        let config: AppConfig = toml::from_str(
+            r#"
+            [digest]
+            enabled = true
+            recipients = ["+491701234567"]
+            quiet_hours_start = "22:00"
+            quiet_hours_end = "07:30"
+            whatsapp_template = "daily_digest"
+
+            [digest.users."+49 170 1234567"]
+            enabled = false
+            "#,
+        )
+        .unwrap();
+        let digest = config
+            .digest
+            .unwrap()
+            .to_digest_config(domain::Timezone::berlin());
+
+        assert_eq!(digest.recipients, ["+491701234567"]);
+        assert!(
+            DigestAppConfig::default()
+                .to_digest_config(domain::Timezone::berlin())
+                .recipients
+                .is_empty()
+        );
+        assert!(digest.disabled.contains("+491701234567"));
+        let quiet_hours = digest.quiet_hours.unwrap();
+        assert_eq!(quiet_hours.end.to_string(), "07:30:00");
+        let template = digest.template.unwrap();
+        assert_eq!(template.name, "daily_digest");
+        assert_eq!(template.language, "en_US");
+    }
+
+    #[test]
+    fn conversation_summarization_from_config() {
+        let summarization = AppConfig::default().conversation.summarization().unwrap();
+        assert_eq!(summarization.threshold, 40);
+        assert_eq!(summarization.keep_recent, 10);
+
+        let config: AppConfig = serde_json::from_str(
+            r#"{"conversation":{"summarize_after_messages":20,"summary_keep_recent":4}}"#,
+        )
+        .unwrap();
+        let summarization = config.conversation.summarization().unwrap();
+        assert_eq!(summarization.threshold, 20);
+        assert_eq!(summarization.keep_recent, 4);
+
+        let config: AppConfig =
+            serde_json::from_str(r#"{"conversation":{"summarization_enabled":false}}"#).unwrap();
+        assert!(config.conversation.summarization().is_none());
+    }
+
+    #[test]
+    fn security_config_admin_user_ids() {
+        let json = r#"{"admin_user_ids":["550e8400-e29b-41d4-a716-446655440000"]}"#;
+        let config: SecurityConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.admin_user_ids,
+            vec!["550e8400-e29b-41d4-a716-446655440000"]
+        );
+    }
+
     #[test]
     fn config_has_debug_impl() {
         let config = AppConfig::default();
@@ -757,6 +1270,14 @@ mod tests {
         assert_eq!(config.server.host, "127.0.0.1");
     }
 
+    #[test]
+    fn security_config_rate_limit_per_user() {
+        let json = r#"{"rate_limit_per_user":true}"#;
+        let config: SecurityConfig = serde_json::from_str(json).unwrap();
+        assert!(config.rate_limit_per_user);
+        assert!(!SecurityConfig::default().rate_limit_per_user);
+    }
+
     #[test]
     fn security_config_rate_limit_disabled() {
         let json = r#"{"rate_limit_enabled":false,"rate_limit_rpm":120}"#;
@@ -801,6 +1322,25 @@ mod tests {
         assert_eq!(config.ttl_long().as_secs(), 24 * 60 * 60);
         assert_eq!(config.ttl_llm_dynamic().as_secs(), 60 * 60);
         assert_eq!(config.ttl_llm_stable().as_secs(), 24 * 60 * 60);
+        assert_eq!(
+            config.negative_cache_ttl(),
+            Some(std::time::Duration::from_secs(5 * 60))
+        );
+    }
+
+    #[test]
+    fn cache_config_negative_ttl_disabled() {
+        let config = CacheConfig {
+            negative_cache_ttl_secs: 0,
+            ..CacheConfig::default()
+        };
+        assert!(config.negative_cache_ttl().is_none());
+
+        let config = CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        };
+        assert!(config.negative_cache_ttl().is_none());
     }
 
     #[test]
@@ -850,6 +1390,7 @@ mod tests {
         config.api_keys.push(ApiKeyEntry {
             hash: "$argon2id$v=19$m=19456,t=2,p=1$test".to_string(),
             user_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            scopes: Vec::new(),
         });
         let json = serde_json::to_string(&config).unwrap();
         assert!(json.contains("api_keys"));
@@ -864,6 +1405,7 @@ mod tests {
         config.api_keys.push(ApiKeyEntry {
             hash: "$argon2id$test".to_string(),
             user_id: "user".to_string(),
+            scopes: Vec::new(),
         });
         assert!(config.has_api_keys());
     }
@@ -880,6 +1422,7 @@ mod tests {
         assert!(config.language.is_none());
         assert!(config.rate_limit_rpm.is_none());
         assert_eq!(config.cache_ttl_minutes, 30);
+        assert_eq!(config.max_concurrent_requests, 8);
     }
 
     #[test]
@@ -904,6 +1447,7 @@ mod tests {
             language: Some("en".to_string()),
             rate_limit_rpm: Some(30),
             cache_ttl_minutes: 15,
+            max_concurrent_requests: 4,
         };
 
         let integration_config = config.to_websearch_config();
@@ -976,6 +1520,7 @@ mod tests {
         assert!((config.min_importance - 0.1).abs() < 0.001);
         assert!((config.decay_factor - 0.95).abs() < 0.001);
         assert!(config.enable_encryption);
+        assert_eq!(config.consolidation_interval_hours, 24);
     }
 
     #[test]
@@ -1028,6 +1573,24 @@ mod tests {
         assert_eq!(embedding_config.timeout_ms, 60000);
     }
 
+    #[test]
+    fn memory_config_semantic_routing_disabled_by_default() {
+        let config = MemoryAppConfig::default();
+        assert!(config.semantic_router_config().is_none());
+
+        let config = MemoryAppConfig {
+            semantic_routing: SemanticRoutingAppConfig {
+                enabled: true,
+                threshold: 0.9,
+                cache_size: 32,
+            },
+            ..Default::default()
+        };
+        let router_config = config.semantic_router_config().unwrap();
+        assert!((router_config.threshold - 0.9).abs() < 0.001);
+        assert_eq!(router_config.cache_size, 32);
+    }
+
     #[test]
     fn embedding_config_default() {
         let config = EmbeddingAppConfig::default();
@@ -1081,17 +1644,31 @@ mod tests {
         let config = HealthAppConfig {
             global_timeout_secs: 30,
             inference_timeout_secs: Some(5),
+            database_timeout_secs: Some(3),
             email_timeout_secs: Some(10),
             calendar_timeout_secs: Some(8),
             weather_timeout_secs: Some(15),
+            degraded_latency_ms: 750,
         };
         let health_config = config.to_health_config();
         assert_eq!(health_config.global_timeout_secs, 30);
-        assert_eq!(health_config.service_timeouts.len(), 4);
+        assert_eq!(health_config.degraded_latency_ms, 750);
+        assert_eq!(health_config.service_timeouts.len(), 5);
+        assert_eq!(health_config.service_timeouts.get("database"), Some(&3));
         assert_eq!(health_config.service_timeouts.get("inference"), Some(&5));
         assert_eq!(health_config.service_timeouts.get("email"), Some(&10));
     }
 
+    #[test]
+    fn health_config_service_call_timeouts() {
+        let config = HealthAppConfig {
+            email_timeout_secs: Some(10),
+            ..HealthAppConfig::default()
+        };
+        assert_eq!(config.email_timeout(), std::time::Duration::from_secs(10));
+        assert_eq!(config.calendar_timeout(), std::time::Duration::from_secs(5));
+    }
+
     #[test]
     fn degraded_mode_config_default() {
         let config = DegradedModeAppConfig::default();
@@ -1179,6 +1756,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prompt_security_config_allowlist_and_report_only() {
+        let config: PromptSecurityConfig = toml::from_str(
+            r#"
+            allowlist = ["ignore previous errors"]
+            report_only = true
+            "#,
+        )
+        .unwrap();
+        assert!(config.report_only);
+
+        let converted = config.to_prompt_security_config();
+        assert_eq!(converted.allowlist, vec!["ignore previous errors"]);
+        assert!(converted.report_only);
+
+        let defaults = PromptSecurityConfig::default();
+        assert!(defaults.allowlist.is_empty());
+        assert!(!defaults.report_only);
+    }
+
     #[test]
     fn prompt_security_config_to_suspicious_activity_config() {
         let config = PromptSecurityConfig {
@@ -1211,6 +1808,10 @@ mod tests {
         assert_eq!(config.path, "pisovereign.db");
         assert_eq!(config.max_connections, 5);
         assert!(config.run_migrations);
+        assert!(config.maintenance_enabled);
+        assert_eq!(config.maintenance_interval_hours, 168);
+        assert_eq!(config.draft_ttl_days, 7);
+        assert!(config.restore_backup_dir.is_none());
     }
 
     #[test]
@@ -1219,12 +1820,20 @@ mod tests {
             path: "custom.db".to_string(),
             max_connections: 10,
             run_migrations: false,
+            maintenance_enabled: false,
+            maintenance_interval_hours: 24,
+            draft_ttl_days: 3,
+            restore_backup_dir: Some("/var/backups".to_string()),
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: DatabaseConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.path, "custom.db");
         assert_eq!(parsed.max_connections, 10);
         assert!(!parsed.run_migrations);
+        assert!(!parsed.maintenance_enabled);
+        assert_eq!(parsed.maintenance_interval_hours, 24);
+        assert_eq!(parsed.draft_ttl_days, 3);
+        assert_eq!(parsed.restore_backup_dir.as_deref(), Some("/var/backups"));
     }
 
     #[test]
@@ -1237,6 +1846,7 @@ mod tests {
             whitelist: vec!["+11111111111".to_string()],
             auto_poll: true,
             poll_interval_secs: 2,
+            voice_replies: false,
             persistence: MessengerPersistenceConfig::default(),
         };
         let json = serde_json::to_string(&config).unwrap();
@@ -1248,6 +1858,7 @@ mod tests {
         assert_eq!(parsed.whitelist.len(), 1);
         assert!(parsed.auto_poll);
         assert_eq!(parsed.poll_interval_secs, 2);
+        assert!(!parsed.voice_replies);
         assert!(parsed.persistence.enabled);
     }
 
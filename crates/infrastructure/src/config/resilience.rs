@@ -1,5 +1,7 @@
 //! Resilience configurations: Telemetry, Retry, Degraded Mode, Health checks.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use super::default_true;
@@ -133,6 +135,20 @@ pub struct DegradedModeAppConfig {
     pub success_threshold: u32,
 }
 
+impl DegradedModeAppConfig {
+    /// Convert to the degraded mode adapter's `DegradedModeConfig`
+    #[must_use]
+    pub fn to_degraded_mode_config(&self) -> crate::adapters::DegradedModeConfig {
+        crate::adapters::DegradedModeConfig {
+            enabled: self.enabled,
+            unavailable_message: self.unavailable_message.clone(),
+            retry_cooldown_secs: self.retry_cooldown_secs,
+            failure_threshold: self.failure_threshold,
+            success_threshold: self.success_threshold,
+        }
+    }
+}
+
 fn default_unavailable_message() -> String {
     "I'm currently experiencing technical difficulties. Please try again in a moment.".to_string()
 }
@@ -175,6 +191,10 @@ pub struct HealthAppConfig {
     /// Inference engine health check timeout in seconds (overrides global)
     pub inference_timeout_secs: Option<u64>,
 
+    /// Database health check timeout in seconds (overrides global)
+    #[serde(default)]
+    pub database_timeout_secs: Option<u64>,
+
     /// Email service health check timeout in seconds (overrides global)
     pub email_timeout_secs: Option<u64>,
 
@@ -183,25 +203,50 @@ pub struct HealthAppConfig {
 
     /// Weather service health check timeout in seconds (overrides global)
     pub weather_timeout_secs: Option<u64>,
+
+    /// Check latency in milliseconds above which a service is reported as degraded
+    #[serde(default = "default_health_degraded_latency")]
+    pub degraded_latency_ms: u64,
 }
 
 const fn default_health_global_timeout() -> u64 {
     5
 }
 
+const fn default_health_degraded_latency() -> u64 {
+    1000
+}
+
 impl Default for HealthAppConfig {
     fn default() -> Self {
         Self {
             global_timeout_secs: default_health_global_timeout(),
             inference_timeout_secs: None,
+            database_timeout_secs: None,
             email_timeout_secs: None,
             calendar_timeout_secs: None,
             weather_timeout_secs: None,
+            degraded_latency_ms: default_health_degraded_latency(),
         }
     }
 }
 
 impl HealthAppConfig {
+    /// Timeout for email service calls (service override or global)
+    #[must_use]
+    pub fn email_timeout(&self) -> Duration {
+        Duration::from_secs(self.email_timeout_secs.unwrap_or(self.global_timeout_secs))
+    }
+
+    /// Timeout for calendar service calls (service override or global)
+    #[must_use]
+    pub fn calendar_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.calendar_timeout_secs
+                .unwrap_or(self.global_timeout_secs),
+        )
+    }
+
     /// Convert to `application::HealthConfig`
     #[must_use]
     pub fn to_health_config(&self) -> application::HealthConfig {
@@ -212,6 +257,9 @@ impl HealthAppConfig {
         if let Some(t) = self.inference_timeout_secs {
             service_timeouts.insert("inference".to_string(), t);
         }
+        if let Some(t) = self.database_timeout_secs {
+            service_timeouts.insert("database".to_string(), t);
+        }
         if let Some(t) = self.email_timeout_secs {
             service_timeouts.insert("email".to_string(), t);
         }
@@ -225,6 +273,7 @@ impl HealthAppConfig {
         application::HealthConfig {
             global_timeout_secs: self.global_timeout_secs,
             service_timeouts,
+            degraded_latency_ms: self.degraded_latency_ms,
         }
     }
 }
@@ -2,6 +2,7 @@
 
 use std::net::IpAddr;
 
+use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 
 use super::default_true;
@@ -18,6 +19,74 @@ pub struct ApiKeyEntry {
 
     /// User ID associated with this API key
     pub user_id: String,
+
+    /// Scopes granted to this key, e.g. `chat:read` or `admin`
+    ///
+    /// An empty list grants full access, so keys configured before scopes
+    /// existed keep working unchanged.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// JWT bearer token authentication
+///
+/// Tokens are validated with RS256 against the keys published at
+/// `jwks_url`, or with HS256 against `secret`. At least one must be set.
+/// The `sub` claim must be a user ID (UUID).
+///
+/// Example in config.toml:
+/// ```toml
+/// [security.jwt]
+/// jwks_url = "https://id.example.com/.well-known/jwks.json"
+/// issuer = "https://id.example.com"
+/// audience = "pisovereign"
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct JwtAuthConfig {
+    /// JWKS endpoint of the identity provider (RS256)
+    #[serde(default)]
+    pub jwks_url: Option<String>,
+
+    /// Shared secret for HS256 tokens (sensitive - uses `SecretString`)
+    #[serde(default, skip_serializing)]
+    pub secret: Option<SecretString>,
+
+    /// Required `iss` claim
+    #[serde(default)]
+    pub issuer: Option<String>,
+
+    /// Required `aud` claim
+    #[serde(default)]
+    pub audience: Option<String>,
+
+    /// How often the JWKS is fetched again, in seconds (default: 3600)
+    #[serde(default = "default_jwks_refresh")]
+    pub jwks_refresh_secs: u64,
+
+    /// Allowed clock skew for `exp` and `nbf`, in seconds (default: 60)
+    #[serde(default = "default_jwt_leeway")]
+    pub leeway_secs: u64,
+}
+
+impl std::fmt::Debug for JwtAuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtAuthConfig")
+            .field("jwks_url", &self.jwks_url)
+            .field("secret", &self.secret.as_ref().map(|_| "[REDACTED]"))
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .field("jwks_refresh_secs", &self.jwks_refresh_secs)
+            .field("leeway_secs", &self.leeway_secs)
+            .finish()
+    }
+}
+
+const fn default_jwks_refresh() -> u64 {
+    3600
+}
+
+const fn default_jwt_leeway() -> u64 {
+    60
 }
 
 /// Security configuration
@@ -37,10 +106,25 @@ pub struct SecurityConfig {
     /// [[security.api_keys]]
     /// hash = "$argon2id$v=19$m=19456,t=2,p=1$..."
     /// user_id = "550e8400-e29b-41d4-a716-446655440000"
+    /// scopes = ["chat:read", "contacts:read"]  # optional, omit for full access
     /// ```
     #[serde(default)]
     pub api_keys: Vec<ApiKeyEntry>,
 
+    /// User IDs allowed to call admin endpoints (e.g. the audit log export)
+    ///
+    /// Must match the `user_id` of an API key entry. When authentication is
+    /// disabled, admin endpoints are open like every other route.
+    #[serde(default)]
+    pub admin_user_ids: Vec<String>,
+
+    /// JWT bearer token authentication (optional)
+    ///
+    /// Works alongside `api_keys`: tokens that look like a JWT are
+    /// validated here, everything else as an API key.
+    #[serde(default)]
+    pub jwt: Option<JwtAuthConfig>,
+
     /// Trusted proxy IP addresses for X-Forwarded-For header validation
     ///
     /// Only IPs in this list are trusted to set X-Forwarded-For headers.
@@ -48,14 +132,28 @@ pub struct SecurityConfig {
     #[serde(default)]
     pub trusted_proxies: Vec<IpAddr>,
 
+    /// IP addresses allowed to scrape the metrics endpoints without an API key
+    ///
+    /// Matched against the direct connection IP only. When empty, the metrics
+    /// endpoints require an API key like every other authenticated route.
+    #[serde(default)]
+    pub metrics_allowed_ips: Vec<IpAddr>,
+
     /// Enable rate limiting
     #[serde(default = "default_true")]
     pub rate_limit_enabled: bool,
 
-    /// Requests per minute per IP
+    /// Requests per minute per IP (or per user with `rate_limit_per_user`)
     #[serde(default = "default_rate_limit")]
     pub rate_limit_rpm: u32,
 
+    /// Rate limit authenticated requests per user instead of per client IP
+    ///
+    /// Useful when several users share a proxy or NAT. Unauthenticated
+    /// requests are still limited per IP.
+    #[serde(default)]
+    pub rate_limit_per_user: bool,
+
     /// Rate limiter cleanup interval in seconds (default: 300 = 5 minutes)
     #[serde(default = "default_cleanup_interval")]
     pub rate_limit_cleanup_interval_secs: u64,
@@ -102,9 +200,13 @@ impl Default for SecurityConfig {
         Self {
             whitelisted_phones: Vec::new(),
             api_keys: Vec::new(),
+            admin_user_ids: Vec::new(),
+            jwt: None,
             trusted_proxies: Vec::new(),
+            metrics_allowed_ips: Vec::new(),
             rate_limit_enabled: true,
             rate_limit_rpm: default_rate_limit(),
+            rate_limit_per_user: false,
             rate_limit_cleanup_interval_secs: default_cleanup_interval(),
             rate_limit_cleanup_max_age_secs: default_cleanup_max_age(),
             tls_verify_certs: true,
@@ -132,6 +234,12 @@ impl SecurityConfig {
     pub fn has_api_keys(&self) -> bool {
         !self.api_keys.is_empty()
     }
+
+    /// Check if JWT authentication is configured
+    #[must_use]
+    pub const fn has_jwt(&self) -> bool {
+        self.jwt.is_some()
+    }
 }
 
 /// Prompt security configuration for AI input validation
@@ -170,6 +278,19 @@ pub struct PromptSecurityConfig {
     /// Custom patterns to detect (in addition to built-in patterns)
     #[serde(default)]
     pub custom_patterns: Vec<String>,
+
+    /// Phrases that are never flagged (e.g. "ignore previous errors")
+    ///
+    /// A detection is skipped when the matched pattern overlaps one of these
+    /// phrases. Matching is case-insensitive.
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+
+    /// Log detections without blocking requests or recording violations
+    ///
+    /// Useful for tuning `sensitivity` and `allowlist` before enforcing.
+    #[serde(default)]
+    pub report_only: bool,
 }
 
 fn default_sensitivity() -> String {
@@ -199,6 +320,8 @@ impl Default for PromptSecurityConfig {
             block_duration_secs: default_block_duration(),
             auto_block_on_critical: true,
             custom_patterns: Vec::new(),
+            allowlist: Vec::new(),
+            report_only: false,
         }
     }
 }
@@ -222,6 +345,8 @@ impl PromptSecurityConfig {
             sensitivity: self.sensitivity_level(),
             block_on_detection: self.block_on_detection,
             min_confidence: self.sensitivity_level().confidence_threshold(),
+            allowlist: self.allowlist.clone(),
+            report_only: self.report_only,
         }
     }
 
@@ -23,6 +23,15 @@ pub struct ServerConfig {
     #[serde(default)]
     pub allowed_origins: Vec<String>,
 
+    /// How long browsers may cache CORS preflight responses, in seconds
+    #[serde(default = "default_cors_max_age")]
+    pub cors_max_age_secs: u64,
+
+    /// CORS policies for route groups, overriding the global settings for
+    /// paths under their prefix (longest prefix wins)
+    #[serde(default)]
+    pub cors_routes: Vec<CorsRouteConfig>,
+
     /// Graceful shutdown timeout in seconds
     #[serde(default)]
     pub shutdown_timeout_secs: Option<u64>,
@@ -38,6 +47,105 @@ pub struct ServerConfig {
     /// Maximum body size for JSON requests in bytes (default: 1MB)
     #[serde(default = "default_max_body_json")]
     pub max_body_size_json_bytes: usize,
+
+    /// Maximum time in seconds to handle a request before responding with 503
+    /// (default: 120). Does not apply to the SSE streaming endpoint.
+    #[serde(default = "default_request_timeout")]
+    pub request_timeout_secs: u64,
+}
+
+/// CORS policy for a group of routes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorsRouteConfig {
+    /// Path prefix the policy applies to, e.g. `/webhook`
+    pub path_prefix: String,
+
+    /// Send CORS headers for these routes (false = same-origin only)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Allowed origins, inheriting `server.allowed_origins` when unset
+    #[serde(default)]
+    pub allowed_origins: Option<Vec<String>>,
+
+    /// Allow cookies and `Authorization` headers on cross-origin requests
+    #[serde(default)]
+    pub allow_credentials: bool,
+
+    /// Preflight max age, inheriting `server.cors_max_age_secs` when unset
+    #[serde(default)]
+    pub max_age_secs: Option<u64>,
+}
+
+/// Effective CORS settings for a request path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CorsPolicy<'a> {
+    /// Whether CORS headers are sent at all
+    pub enabled: bool,
+    /// Allowed origins; empty or `*` allows any origin
+    pub allowed_origins: &'a [String],
+    /// Whether credentials were requested for these routes
+    pub allow_credentials: bool,
+    /// Preflight max age in seconds
+    pub max_age_secs: u64,
+}
+
+impl CorsPolicy<'_> {
+    /// Whether any origin is accepted
+    #[must_use]
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins.is_empty() || self.allowed_origins.iter().any(|o| o == "*")
+    }
+
+    /// Whether `origin` may make cross-origin requests
+    #[must_use]
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.enabled
+            && (self.allows_any_origin() || self.allowed_origins.iter().any(|o| o == origin))
+    }
+
+    /// Whether credentials are allowed
+    ///
+    /// Never for policies accepting any origin: browsers reject credentials
+    /// with a wildcard origin, and echoing arbitrary origins would expose
+    /// credentialed responses to every site.
+    #[must_use]
+    pub fn allows_credentials(&self) -> bool {
+        self.enabled && self.allow_credentials && !self.allows_any_origin()
+    }
+}
+
+impl ServerConfig {
+    /// CORS policy for a request path
+    ///
+    /// Uses the `cors_routes` entry with the longest matching prefix, or the
+    /// global `allowed_origins` without credentials.
+    #[must_use]
+    pub fn cors_policy(&self, path: &str) -> CorsPolicy<'_> {
+        let route = self
+            .cors_routes
+            .iter()
+            .filter(|route| path.starts_with(&route.path_prefix))
+            .max_by_key(|route| route.path_prefix.len());
+
+        match route {
+            Some(route) => CorsPolicy {
+                enabled: route.enabled,
+                allowed_origins: route
+                    .allowed_origins
+                    .as_deref()
+                    .unwrap_or(&self.allowed_origins),
+                allow_credentials: route.allow_credentials,
+                max_age_secs: route.max_age_secs.unwrap_or(self.cors_max_age_secs),
+            },
+            None => CorsPolicy {
+                enabled: self.cors_enabled,
+                allowed_origins: &self.allowed_origins,
+                allow_credentials: false,
+                max_age_secs: self.cors_max_age_secs,
+            },
+        }
+    }
 }
 
 fn default_host() -> String {
@@ -52,6 +160,14 @@ const fn default_max_body_json() -> usize {
     1024 * 1024 // 1MB
 }
 
+const fn default_request_timeout() -> u64 {
+    120
+}
+
+const fn default_cors_max_age() -> u64 {
+    600 // 10 minutes
+}
+
 const fn default_port() -> u16 {
     3000
 }
@@ -67,10 +183,13 @@ impl Default for ServerConfig {
             port: default_port(),
             cors_enabled: true,
             allowed_origins: Vec::new(),
+            cors_max_age_secs: default_cors_max_age(),
+            cors_routes: Vec::new(),
             shutdown_timeout_secs: Some(30),
             log_format: default_log_format(),
             max_body_size_audio_bytes: default_max_body_audio(),
             max_body_size_json_bytes: default_max_body_json(),
+            request_timeout_secs: default_request_timeout(),
         }
     }
 }
@@ -0,0 +1,437 @@
+//! Load-time validation of configuration values
+//!
+//! Deserialization accepts any string for enum-like fields and any number
+//! for ranges. [`AppConfig::validate`] checks them and reports every invalid
+//! value at once, so a broken config can be fixed in one pass.
+
+use std::fmt;
+
+use super::{AppConfig, messenger::parse_time};
+
+/// Accepted `websearch.safe_search` levels
+const SAFE_SEARCH_LEVELS: &[&str] = &["off", "moderate", "strict"];
+
+/// Accepted `prompt_security.sensitivity` levels
+const SENSITIVITY_LEVELS: &[&str] = &["low", "medium", "high"];
+
+/// Accepted minimum TLS versions
+const TLS_VERSIONS: &[&str] = &["1.2", "1.3"];
+
+/// Accepted `server.log_format` values
+const LOG_FORMATS: &[&str] = &["json", "text"];
+
+/// A single invalid configuration value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// Dotted path of the field, e.g. `server.port`
+    pub field: String,
+    /// What is wrong with the value
+    pub message: String,
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Configuration values that failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigValidationError {
+    /// All problems found, in field order
+    pub problems: Vec<ConfigProblem>,
+}
+
+impl fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid configuration value(s)", self.problems.len())?;
+        for problem in &self.problems {
+            write!(f, "\n  - {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigValidationError {}
+
+/// Collects problems while walking the config
+#[derive(Default)]
+struct Problems(Vec<ConfigProblem>);
+
+impl Problems {
+    fn push(&mut self, field: &str, message: String) {
+        self.0.push(ConfigProblem {
+            field: field.to_string(),
+            message,
+        });
+    }
+
+    /// Value must be one of `allowed` (case-insensitive)
+    fn one_of(&mut self, field: &str, value: &str, allowed: &[&str]) {
+        if !allowed.iter().any(|a| a.eq_ignore_ascii_case(value)) {
+            self.push(
+                field,
+                format!("\"{value}\" is not one of: {}", allowed.join(", ")),
+            );
+        }
+    }
+
+    fn port(&mut self, field: &str, value: u16) {
+        if value == 0 {
+            self.push(field, "port must be between 1 and 65535".to_string());
+        }
+    }
+
+    fn positive(&mut self, field: &str, value: u64) {
+        if value == 0 {
+            self.push(field, "must be greater than 0".to_string());
+        }
+    }
+
+    /// Each entry must be `*` or a bare `scheme://host[:port]` origin
+    fn origins(&mut self, field: &str, origins: &[String]) {
+        for origin in origins.iter().filter(|o| !is_valid_origin(o)) {
+            self.push(
+                field,
+                format!("\"{origin}\" is not an origin like https://app.example.com"),
+            );
+        }
+    }
+
+    fn ratio(&mut self, field: &str, value: f64) {
+        if !(0.0..=1.0).contains(&value) {
+            self.push(field, format!("{value} is not between 0.0 and 1.0"));
+        }
+    }
+}
+
+/// Whether `origin` is `*` or an HTTP(S) origin without path
+fn is_valid_origin(origin: &str) -> bool {
+    if origin == "*" {
+        return true;
+    }
+    let Some((scheme, rest)) = origin.split_once("://") else {
+        return false;
+    };
+    let host = match rest.rsplit_once(':') {
+        Some((host, port)) if port.parse::<u16>().is_ok() => host,
+        _ => rest,
+    };
+    matches!(scheme, "http" | "https") && !host.is_empty() && !host.contains(['/', '?', '#', ' '])
+}
+
+impl AppConfig {
+    /// Check enum-like string fields and numeric ranges
+    ///
+    /// Only sections that are present are checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns all invalid values found.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let mut p = Problems::default();
+
+        p.port("server.port", self.server.port);
+        p.one_of("server.log_format", &self.server.log_format, LOG_FORMATS);
+        p.positive(
+            "server.request_timeout_secs",
+            self.server.request_timeout_secs,
+        );
+        p.origins("server.allowed_origins", &self.server.allowed_origins);
+        for (i, route) in self.server.cors_routes.iter().enumerate() {
+            if !route.path_prefix.starts_with('/') {
+                p.push(
+                    &format!("server.cors_routes[{i}].path_prefix"),
+                    format!("\"{}\" must start with /", route.path_prefix),
+                );
+            }
+            if let Some(ref origins) = route.allowed_origins {
+                p.origins(&format!("server.cors_routes[{i}].allowed_origins"), origins);
+            }
+        }
+
+        p.one_of(
+            "security.min_tls_version",
+            &self.security.min_tls_version,
+            TLS_VERSIONS,
+        );
+        p.positive(
+            "security.connection_timeout_secs",
+            self.security.connection_timeout_secs,
+        );
+        p.one_of(
+            "prompt_security.sensitivity",
+            &self.prompt_security.sensitivity,
+            SENSITIVITY_LEVELS,
+        );
+
+        if let Some(ref jwt) = self.security.jwt {
+            if jwt.jwks_url.is_none() && jwt.secret.is_none() {
+                p.push(
+                    "security.jwt",
+                    "either jwks_url or secret must be set".to_string(),
+                );
+            }
+            p.positive("security.jwt.jwks_refresh_secs", jwt.jwks_refresh_secs);
+        }
+
+        p.positive("signal.timeout_ms", self.signal.timeout_ms);
+        p.positive("vault.timeout_secs", self.vault.timeout_secs);
+
+        if let Some(ref weather) = self.weather {
+            p.positive("weather.timeout_secs", weather.timeout_secs);
+        }
+        if let Some(ref websearch) = self.websearch {
+            p.one_of(
+                "websearch.safe_search",
+                &websearch.safe_search,
+                SAFE_SEARCH_LEVELS,
+            );
+            p.positive("websearch.timeout_secs", websearch.timeout_secs);
+        }
+        if let Some(ref caldav) = self.caldav {
+            p.positive("caldav.timeout_secs", caldav.timeout_secs);
+        }
+        if let Some(ref carddav) = self.carddav {
+            p.positive("carddav.timeout_secs", carddav.timeout_secs);
+        }
+        if let Some(ref proton) = self.proton {
+            p.port("proton.imap_port", proton.imap_port);
+            p.port("proton.smtp_port", proton.smtp_port);
+            p.one_of(
+                "proton.tls.min_tls_version",
+                &proton.tls.min_tls_version,
+                TLS_VERSIONS,
+            );
+        }
+        if let Some(ref transit) = self.transit {
+            p.positive("transit.timeout_secs", transit.timeout_secs);
+        }
+        if let Some(ref health) = self.health {
+            p.positive("health.global_timeout_secs", health.global_timeout_secs);
+        }
+        if let Some(ratio) = self.telemetry.as_ref().and_then(|t| t.sample_ratio) {
+            p.ratio("telemetry.sample_ratio", ratio);
+        }
+        if let Some(ref digest) = self.digest {
+            for (field, value) in [
+                ("digest.quiet_hours_start", &digest.quiet_hours_start),
+                ("digest.quiet_hours_end", &digest.quiet_hours_end),
+            ] {
+                if let Some(value) = value {
+                    if parse_time(value).is_none() {
+                        p.push(field, format!("\"{value}\" is not a HH:MM time"));
+                    }
+                }
+            }
+            if digest.quiet_hours_start.is_some() != digest.quiet_hours_end.is_some() {
+                p.push(
+                    "digest.quiet_hours_end",
+                    "quiet_hours_start and quiet_hours_end must be set together".to_string(),
+                );
+            }
+            if let Some(ref schedule) = digest.schedule {
+                if let Err(e) = schedule.parse::<cron::Schedule>() {
+                    p.push("digest.schedule", format!("\"{schedule}\": {e}"));
+                }
+            }
+        }
+        if let Some(ref memory) = self.memory {
+            p.ratio("memory.rag_threshold", f64::from(memory.rag_threshold));
+            p.ratio("memory.merge_threshold", f64::from(memory.merge_threshold));
+            p.ratio("memory.min_importance", f64::from(memory.min_importance));
+            p.ratio("memory.decay_factor", f64::from(memory.decay_factor));
+            p.positive("memory.embedding.timeout_ms", memory.embedding.timeout_ms);
+            p.ratio(
+                "memory.semantic_routing.threshold",
+                f64::from(memory.semantic_routing.threshold),
+            );
+        }
+
+        if p.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigValidationError { problems: p.0 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        DigestAppConfig, MemoryAppConfig, ProtonAppConfig, ProtonTlsAppConfig, WebSearchAppConfig,
+    };
+
+    fn fields(config: &AppConfig) -> Vec<String> {
+        config
+            .validate()
+            .unwrap_err()
+            .problems
+            .into_iter()
+            .map(|p| p.field)
+            .collect()
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(AppConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn unknown_enum_values_are_rejected() {
+        let mut config = AppConfig::default();
+        config.server.log_format = "xml".to_string();
+        config.security.min_tls_version = "1.5".to_string();
+        config.prompt_security.sensitivity = "paranoid".to_string();
+        config.websearch = Some(WebSearchAppConfig {
+            safe_search: "banana".to_string(),
+            ..WebSearchAppConfig::default()
+        });
+
+        assert_eq!(
+            fields(&config),
+            [
+                "server.log_format",
+                "security.min_tls_version",
+                "prompt_security.sensitivity",
+                "websearch.safe_search",
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_values_are_case_insensitive() {
+        let mut config = AppConfig::default();
+        config.prompt_security.sensitivity = "HIGH".to_string();
+        config.server.log_format = "JSON".to_string();
+
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn out_of_range_numbers_are_rejected() {
+        let mut config = AppConfig::default();
+        config.server.port = 0;
+        config.server.request_timeout_secs = 0;
+        config.proton = Some(ProtonAppConfig {
+            imap_host: "127.0.0.1".to_string(),
+            imap_port: 1143,
+            smtp_host: "127.0.0.1".to_string(),
+            smtp_port: 0,
+            email: "me@proton.me".to_string(),
+            password: "secret".into(),
+            tls: ProtonTlsAppConfig::default(),
+        });
+        config.memory = Some(MemoryAppConfig {
+            rag_threshold: 1.5,
+            decay_factor: -0.1,
+            ..MemoryAppConfig::default()
+        });
+
+        assert_eq!(
+            fields(&config),
+            [
+                "server.port",
+                "server.request_timeout_secs",
+                "proton.smtp_port",
+                "memory.rag_threshold",
+                "memory.decay_factor",
+            ]
+        );
+    }
+
+    #[test]
+    fn digest_times_and_schedule_are_checked() {
+        let mut config = AppConfig::default();
+        config.digest = Some(DigestAppConfig {
+            enabled: true,
+            schedule: Some("every morning".to_string()),
+            quiet_hours_start: Some("25:00".to_string()),
+            ..DigestAppConfig::default()
+        });
+
+        assert_eq!(
+            fields(&config),
+            [
+                "digest.quiet_hours_start",
+                "digest.quiet_hours_end",
+                "digest.schedule",
+            ]
+        );
+
+        config.digest = Some(DigestAppConfig {
+            enabled: true,
+            schedule: Some("0 30 6 * * *".to_string()),
+            quiet_hours_start: Some("22:00".to_string()),
+            quiet_hours_end: Some("07:00".to_string()),
+            ..DigestAppConfig::default()
+        });
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn jwt_needs_a_key_source() {
+        let mut config = AppConfig::default();
+        config.security.jwt = Some(toml::from_str("issuer = \"https://id.example.com\"").unwrap());
+        assert_eq!(fields(&config), ["security.jwt"]);
+
+        config.security.jwt =
+            Some(toml::from_str("jwks_url = \"https://id.example.com/jwks.json\"").unwrap());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn proton_without_tls_section_is_valid() {
+        let mut config = AppConfig::default();
+        config.proton =
+            Some(toml::from_str("email = \"me@proton.me\"\npassword = \"secret\"\n").unwrap());
+
+        assert!(config.validate().is_ok());
+        let tls = &config.proton.unwrap().tls;
+        assert!(tls.verify_certificates);
+        assert_eq!(tls.min_tls_version, "1.2");
+    }
+
+    #[test]
+    fn error_lists_every_problem() {
+        let mut config = AppConfig::default();
+        config.server.log_format = "xml".to_string();
+        config.security.min_tls_version = "1.5".to_string();
+
+        let message = config.validate().unwrap_err().to_string();
+
+        assert!(message.starts_with("2 invalid configuration value(s)"));
+        assert!(message.contains("server.log_format: \"xml\" is not one of: json, text"));
+        assert!(message.contains("security.min_tls_version: \"1.5\" is not one of: 1.2, 1.3"));
+    }
+
+    #[test]
+    fn cors_origins_must_be_bare_origins() {
+        let mut config = AppConfig::default();
+        config.server.allowed_origins = vec![
+            "https://app.example.com".to_string(),
+            "http://localhost:5173".to_string(),
+            "*".to_string(),
+            "app.example.com".to_string(),
+            "https://app.example.com/".to_string(),
+        ];
+        config.server.cors_routes = vec![crate::config::CorsRouteConfig {
+            path_prefix: "webhook".to_string(),
+            enabled: false,
+            allowed_origins: Some(vec!["ftp://files.example.com".to_string()]),
+            allow_credentials: false,
+            max_age_secs: None,
+        }];
+
+        assert_eq!(
+            fields(&config),
+            [
+                "server.allowed_origins",
+                "server.allowed_origins",
+                "server.cors_routes[0].path_prefix",
+                "server.cors_routes[0].allowed_origins",
+            ]
+        );
+    }
+}
@@ -52,6 +52,19 @@ pub struct VaultAppConfig {
     #[serde(default = "default_timeout_secs")]
     pub timeout_secs: u64,
 
+    /// Token renewal interval in seconds (default: two thirds of the token TTL)
+    #[serde(default)]
+    pub renew_interval_secs: Option<u64>,
+
+    /// How long resolved secrets are cached in seconds (0 disables caching)
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+
+    /// Interval in seconds for re-resolving secrets to pick up rotations
+    /// (0 disables the background refresh)
+    #[serde(default = "default_secret_refresh_interval_secs")]
+    pub secret_refresh_interval_secs: u64,
+
     /// Enable environment variable fallback via `ChainedSecretStore`
     #[serde(default = "super::default_true")]
     pub env_fallback: bool,
@@ -77,6 +90,14 @@ const fn default_timeout_secs() -> u64 {
     5
 }
 
+const fn default_cache_ttl_secs() -> u64 {
+    300
+}
+
+const fn default_secret_refresh_interval_secs() -> u64 {
+    3600
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn default_env_prefix() -> Option<String> {
     Some(String::from("PISOVEREIGN"))
@@ -94,6 +115,9 @@ impl Default for VaultAppConfig {
             secret_prefix: default_secret_prefix(),
             namespace: None,
             timeout_secs: default_timeout_secs(),
+            renew_interval_secs: None,
+            cache_ttl_secs: default_cache_ttl_secs(),
+            secret_refresh_interval_secs: default_secret_refresh_interval_secs(),
             env_fallback: true,
             env_prefix: default_env_prefix(),
         }
@@ -107,6 +131,7 @@ impl VaultAppConfig {
         let mut config = VaultConfig::new(&self.address);
         config.mount_path.clone_from(&self.mount_path);
         config.timeout_secs = self.timeout_secs;
+        config.renew_interval_secs = self.renew_interval_secs;
 
         if let Some(ref token) = self.token {
             config.token = Some(token.expose_secret().to_string());
@@ -143,6 +168,9 @@ mod tests {
         assert_eq!(config.mount_path, "secret");
         assert_eq!(config.secret_prefix, "pisovereign");
         assert_eq!(config.timeout_secs, 5);
+        assert!(config.renew_interval_secs.is_none());
+        assert_eq!(config.cache_ttl_secs, 300);
+        assert_eq!(config.secret_refresh_interval_secs, 3600);
         assert!(config.env_fallback);
         assert_eq!(config.env_prefix, Some("PISOVEREIGN".to_string()));
     }
@@ -190,6 +218,14 @@ mod tests {
         assert_eq!(vault_config.secret_id.as_deref(), Some("secret-456"));
     }
 
+    #[test]
+    fn to_vault_config_with_renew_interval() {
+        let config: VaultAppConfig = toml::from_str("renew_interval_secs = 900").unwrap();
+
+        let vault_config = config.to_vault_config();
+        assert_eq!(vault_config.renew_interval_secs, Some(900));
+    }
+
     #[test]
     fn secret_path_construction() {
         let config = VaultAppConfig::default();
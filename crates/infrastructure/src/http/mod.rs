@@ -1,12 +1,12 @@
 //! HTTP utilities and clients with correlation support
 //!
 //! This module provides HTTP client utilities that automatically propagate
-//! request correlation IDs (X-Request-Id) through outgoing requests.
+//! request correlation IDs (X-Request-Id) through outgoing requests. The
+//! implementation lives in the `integration_http` crate so integration
+//! clients can share it; it is re-exported here for adapters.
 
-mod correlated_client;
-
-pub use correlated_client::{
+pub use integration_http::{
     CorrelatedClientConfig, CorrelatedHttpClient, CorrelatedRequestBuilder, RequestBuilderExt,
     RequestIdProvider, SharedCorrelatedClient, X_REQUEST_ID, create_shared_client,
-    create_shared_client_with_config,
+    create_shared_client_with_config, current_request_id, scope_request_id,
 };
@@ -6,7 +6,7 @@
 
 pub mod adapters;
 pub mod cache;
-#[cfg(test)]
+#[cfg(any(test, feature = "chaos"))]
 pub mod chaos;
 pub mod config;
 pub mod http;
@@ -22,19 +22,27 @@ pub mod validation;
 
 pub use adapters::*;
 pub use ai_speech::SpeechConfig;
-pub use cache::{MokaCache, MultiLayerCache, RedbCache, generate_cache_key, llm_cache_key};
+pub use cache::{
+    MokaCache, MultiLayerCache, NegativeCache, RedbCache, generate_cache_key, llm_cache_key,
+};
 pub use config::{
-    ApiKeyEntry, AppConfig, CalDavAppConfig, DatabaseConfig, DegradedModeAppConfig, Environment,
-    MessengerPersistenceConfig, MessengerSelection, ProtonAppConfig, RetryAppConfig,
-    SecurityConfig, ServerConfig, SignalConfig, TelemetryAppConfig, VaultAppConfig, WeatherConfig,
-    WhatsAppConfig,
+    ApiKeyEntry, AppConfig, CalDavAppConfig, CorsRouteConfig, DatabaseConfig,
+    DegradedModeAppConfig, Environment, JwtAuthConfig, MessengerPersistenceConfig,
+    MessengerSelection, ProtonAppConfig, RetryAppConfig, SecurityConfig, ServerConfig,
+    SignalConfig, TelemetryAppConfig, VaultAppConfig, WeatherConfig, WhatsAppConfig,
+};
+pub use http::{
+    CorrelatedClientConfig, CorrelatedHttpClient, RequestIdProvider, SharedCorrelatedClient,
+    X_REQUEST_ID, scope_request_id,
 };
-pub use http::{CorrelatedClientConfig, CorrelatedHttpClient, RequestIdProvider, X_REQUEST_ID};
 pub use persistence::{
-    AsyncConversationStore, AsyncDatabase, AsyncDatabaseConfig, SqliteDatabaseHealth,
-    SqliteDraftStore,
+    AsyncConversationStore, AsyncDatabase, AsyncDatabaseConfig, MigrationInfo,
+    SqliteDatabaseHealth, SqliteDraftStore,
+};
+pub use retry::{
+    JitterStrategy, RetryConfig, RetryResult, Retryable, TimeoutError, retry, with_retry,
+    with_retry_or_else, with_timeout,
 };
-pub use retry::{RetryConfig, RetryResult, Retryable, retry, with_retry};
 pub use scheduler::{
     SchedulerConfig, SchedulerError, TaskBuilder, TaskEvent, TaskScheduler, TaskStats, TaskStatus,
     schedules,
@@ -5,7 +5,11 @@
 //! Migrations are managed via sqlx's `migrate!()` macro using SQL
 //! files in the workspace `migrations/` directory.
 
-use std::{path::Path, str::FromStr};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use sqlx::{
     SqlitePool,
@@ -24,6 +28,33 @@ pub enum AsyncDatabaseError {
 
     #[error("Configuration error: {0}")]
     Config(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid backup: {0}")]
+    InvalidBackup(String),
+}
+
+/// File name prefix of backups created by `pisovereign-cli backup`
+const BACKUP_FILE_PREFIX: &str = "pisovereign_backup_";
+
+/// A schema migration bundled with the application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationInfo {
+    /// Migration version (the numeric prefix of the SQL file)
+    pub version: i64,
+    /// Human-readable description derived from the file name
+    pub description: String,
+}
+
+impl From<&sqlx::migrate::Migration> for MigrationInfo {
+    fn from(migration: &sqlx::migrate::Migration) -> Self {
+        Self {
+            version: migration.version,
+            description: migration.description.to_string(),
+        }
+    }
 }
 
 /// Configuration for async database connection
@@ -166,15 +197,7 @@ impl AsyncDatabase {
         migrator: &sqlx::migrate::Migrator,
     ) -> Result<(), AsyncDatabaseError> {
         // Check if sqlx is already managing this database
-        let has_sqlx_table: bool = sqlx::query_scalar(
-            "SELECT COUNT(*) > 0 FROM sqlite_master \
-             WHERE type='table' AND name='_sqlx_migrations'",
-        )
-        .fetch_one(&self.pool)
-        .await
-        .unwrap_or(false);
-
-        if has_sqlx_table {
+        if has_migrations_table(&self.pool).await {
             return Ok(());
         }
 
@@ -229,6 +252,169 @@ impl AsyncDatabase {
         Ok(())
     }
 
+    /// All forward migrations bundled with this binary, in version order
+    #[must_use]
+    pub fn available_migrations() -> Vec<MigrationInfo> {
+        let migrator = sqlx::migrate!("../../migrations");
+        migrator
+            .migrations
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .map(MigrationInfo::from)
+            .collect()
+    }
+
+    /// Migrations that [`migrate`](Self::migrate) would apply, without applying them
+    ///
+    /// Legacy databases tracked via `PRAGMA user_version` are treated as if
+    /// they had already been adopted, so the result matches what a real run
+    /// would execute. The database is not modified.
+    #[instrument(skip(self))]
+    pub async fn pending_migrations(&self) -> Result<Vec<MigrationInfo>, AsyncDatabaseError> {
+        let applied = self.applied_migration_versions().await?;
+
+        Ok(Self::available_migrations()
+            .into_iter()
+            .filter(|m| !applied.contains(&m.version))
+            .collect())
+    }
+
+    /// Version of the most recently applied migration (0 for an empty database)
+    #[instrument(skip(self))]
+    pub async fn current_schema_version(&self) -> Result<i64, AsyncDatabaseError> {
+        Ok(schema_version(&self.pool).await?)
+    }
+
+    /// Versions of all successfully applied migrations
+    async fn applied_migration_versions(&self) -> Result<HashSet<i64>, AsyncDatabaseError> {
+        if has_migrations_table(&self.pool).await {
+            let versions: Vec<i64> =
+                sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success = true")
+                    .fetch_all(&self.pool)
+                    .await?;
+            return Ok(versions.into_iter().collect());
+        }
+
+        // Legacy databases: everything up to user_version would be adopted
+        let user_version: i64 = sqlx::query_scalar("SELECT * FROM pragma_user_version")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(Self::available_migrations()
+            .into_iter()
+            .map(|m| m.version)
+            .filter(|version| *version <= user_version)
+            .collect())
+    }
+
+    /// Run `PRAGMA integrity_check` over the whole database
+    ///
+    /// Returns `true` if SQLite reports `ok`. Any reported problems are
+    /// logged and result in `false`.
+    #[instrument(skip(self))]
+    pub async fn integrity_check(&self) -> Result<bool, AsyncDatabaseError> {
+        let results: Vec<String> = sqlx::query_scalar("PRAGMA integrity_check")
+            .fetch_all(&self.pool)
+            .await?;
+
+        let ok = results.len() == 1 && results[0] == "ok";
+        if ok {
+            debug!("Database integrity check passed");
+        } else {
+            for problem in &results {
+                warn!(problem = %problem, "Database integrity problem");
+            }
+        }
+        Ok(ok)
+    }
+
+    /// Rebuild the database file with `VACUUM`, reclaiming free pages
+    #[instrument(skip(self))]
+    pub async fn vacuum(&self) -> Result<(), AsyncDatabaseError> {
+        sqlx::query("VACUUM").execute(&self.pool).await?;
+        info!("Database vacuumed");
+        Ok(())
+    }
+
+    /// Reclaim free pages with `PRAGMA incremental_vacuum`
+    ///
+    /// Frees up to `pages` pages (all free pages if `None`). Only has an
+    /// effect when the database uses `auto_vacuum = INCREMENTAL`.
+    #[instrument(skip(self))]
+    pub async fn incremental_vacuum(&self, pages: Option<u32>) -> Result<(), AsyncDatabaseError> {
+        let statement = pages.map_or_else(
+            || "PRAGMA incremental_vacuum".to_string(),
+            |n| format!("PRAGMA incremental_vacuum({n})"),
+        );
+        sqlx::query(&statement).execute(&self.pool).await?;
+        debug!(?pages, "Incremental vacuum completed");
+        Ok(())
+    }
+
+    /// Find the most recent backup in `backup_dir`
+    ///
+    /// Backups are recognized by the `pisovereign_backup_<timestamp>.db`
+    /// naming used by the CLI; the timestamp format sorts chronologically.
+    pub fn latest_backup(
+        backup_dir: impl AsRef<Path>,
+    ) -> Result<Option<PathBuf>, AsyncDatabaseError> {
+        let mut latest: Option<PathBuf> = None;
+        for entry in std::fs::read_dir(backup_dir)? {
+            let path = entry?.path();
+            let is_backup = path.extension().is_some_and(|ext| ext == "db")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(BACKUP_FILE_PREFIX));
+            if is_backup && latest.as_ref().is_none_or(|current| path > *current) {
+                latest = Some(path);
+            }
+        }
+        Ok(latest)
+    }
+
+    /// Replace the database file at `db_path` with the latest backup
+    ///
+    /// Must only be called while no pool is open on `db_path`. The backup is
+    /// copied next to the database and integrity-checked before it is
+    /// renamed over the live file, so a failed restore leaves the database
+    /// untouched. The replaced database and its WAL and shared-memory files
+    /// are moved aside with a `.corrupt-<timestamp>` suffix rather than
+    /// deleted. Returns the restored backup, or `None` if the directory
+    /// contains no backups.
+    #[instrument(skip_all)]
+    pub async fn restore_latest_backup(
+        db_path: impl AsRef<Path>,
+        backup_dir: impl AsRef<Path>,
+    ) -> Result<Option<PathBuf>, AsyncDatabaseError> {
+        let db_path = db_path.as_ref();
+        let Some(backup) = Self::latest_backup(backup_dir)? else {
+            warn!("No database backup available to restore");
+            return Ok(None);
+        };
+
+        let staging = staging_path(db_path);
+        let result = async {
+            tokio::fs::copy(&backup, &staging).await?;
+            verify_backup(&staging).await?;
+            quarantine_database(db_path).await?;
+            tokio::fs::rename(&staging, db_path).await?;
+            Ok::<_, AsyncDatabaseError>(())
+        }
+        .await;
+        if let Err(e) = result {
+            let _ = tokio::fs::remove_file(&staging).await;
+            return Err(e);
+        }
+
+        warn!(
+            backup = %backup.display(),
+            database = %db_path.display(),
+            "Database restored from backup"
+        );
+        Ok(Some(backup))
+    }
+
     /// Close all connections in the pool
     pub async fn close(&self) {
         self.pool.close().await;
@@ -236,6 +422,104 @@ impl AsyncDatabase {
     }
 }
 
+/// Staging file for a restore, next to the database so the final rename is atomic
+fn staging_path(database: &Path) -> PathBuf {
+    let name = database
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("pisovereign.db");
+    database.with_file_name(format!(".{name}.restore"))
+}
+
+/// SQLite sidecar file (`-wal`, `-shm`) of a database
+fn sidecar_path(database: &Path, suffix: &str) -> PathBuf {
+    let mut path = database.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Run `PRAGMA integrity_check` on a staged backup without modifying it
+async fn verify_backup(path: &Path) -> Result<(), AsyncDatabaseError> {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .read_only(true)
+        .immutable(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await?;
+    let results: Result<Vec<String>, _> = sqlx::query_scalar("PRAGMA integrity_check")
+        .fetch_all(&pool)
+        .await;
+    pool.close().await;
+
+    let results = results.map_err(|e| {
+        AsyncDatabaseError::InvalidBackup(format!("not a valid SQLite database: {e}"))
+    })?;
+    if results.len() == 1 && results[0] == "ok" {
+        debug!("Backup integrity check passed");
+        Ok(())
+    } else {
+        Err(AsyncDatabaseError::InvalidBackup(format!(
+            "integrity check failed: {}",
+            results.join("; ")
+        )))
+    }
+}
+
+/// Move the database and its sidecar files aside for later inspection
+///
+/// The sidecars keep their suffixes on the moved file, so the quarantined
+/// copy can still be opened with its WAL content.
+async fn quarantine_database(database: &Path) -> Result<(), AsyncDatabaseError> {
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    let mut quarantine = database.as_os_str().to_owned();
+    quarantine.push(format!(".corrupt-{timestamp}"));
+    let quarantine = PathBuf::from(quarantine);
+
+    for suffix in ["", "-wal", "-shm"] {
+        let from = sidecar_path(database, suffix);
+        let to = sidecar_path(&quarantine, suffix);
+        match tokio::fs::rename(&from, &to).await {
+            Ok(()) => {
+                warn!(from = %from.display(), to = %to.display(), "Moved corrupt database file aside")
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {},
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+/// Whether sqlx is already tracking migrations in this database
+async fn has_migrations_table(pool: &SqlitePool) -> bool {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) > 0 FROM sqlite_master \
+         WHERE type='table' AND name='_sqlx_migrations'",
+    )
+    .fetch_one(pool)
+    .await
+    .unwrap_or(false)
+}
+
+/// Current schema version of the database behind `pool`
+///
+/// Reads the highest successfully applied sqlx migration, falling back to
+/// the legacy `PRAGMA user_version` for databases sqlx has not adopted yet.
+pub(crate) async fn schema_version(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
+    if has_migrations_table(pool).await {
+        return sqlx::query_scalar(
+            "SELECT COALESCE(MAX(version), 0) FROM _sqlx_migrations WHERE success = true",
+        )
+        .fetch_one(pool)
+        .await;
+    }
+
+    sqlx::query_scalar("SELECT * FROM pragma_user_version")
+        .fetch_one(pool)
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,6 +561,7 @@ mod tests {
             "memories",
             "memory_embeddings",
             "reminders",
+            "message_deliveries",
         ];
 
         for table in &tables {
@@ -328,6 +613,124 @@ mod tests {
         assert!(config.foreign_keys);
     }
 
+    #[tokio::test]
+    async fn integrity_check_passes_on_fresh_database() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        assert!(db.integrity_check().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn vacuum_and_incremental_vacuum_succeed() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        db.vacuum().await.unwrap();
+        db.incremental_vacuum(None).await.unwrap();
+        db.incremental_vacuum(Some(10)).await.unwrap();
+    }
+
+    #[test]
+    fn latest_backup_picks_newest_timestamp() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "pisovereign_backup_20250101_000000.db",
+            "pisovereign_backup_20250301_120000.db",
+            "pisovereign_backup_20250201_000000.db",
+            "unrelated.db",
+            "pisovereign_backup_20990101_000000.txt",
+        ] {
+            std::fs::write(dir.path().join(name), b"").unwrap();
+        }
+
+        let latest = AsyncDatabase::latest_backup(dir.path()).unwrap().unwrap();
+        assert!(latest.ends_with("pisovereign_backup_20250301_120000.db"));
+    }
+
+    /// Write a migrated database file to `path`
+    async fn create_database_file(path: &Path) {
+        let db = AsyncDatabase::new(&AsyncDatabaseConfig::file(path))
+            .await
+            .unwrap();
+        db.migrate().await.unwrap();
+        db.close().await;
+    }
+
+    /// Files in `dir` whose name contains `.corrupt-`
+    fn quarantined_files(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.contains(".corrupt-"))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn restore_latest_backup_replaces_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("live.db");
+        let backups = dir.path().join("backups");
+        std::fs::create_dir(&backups).unwrap();
+        let backup = backups.join("pisovereign_backup_20250101_000000.db");
+        create_database_file(&backup).await;
+
+        std::fs::write(&db_path, b"corrupted").unwrap();
+        std::fs::write(sidecar_path(&db_path, "-wal"), b"stale").unwrap();
+
+        let restored = AsyncDatabase::restore_latest_backup(&db_path, &backups)
+            .await
+            .unwrap();
+        assert_eq!(restored, Some(backup.clone()));
+        assert_eq!(
+            std::fs::read(&db_path).unwrap(),
+            std::fs::read(&backup).unwrap()
+        );
+        assert!(!sidecar_path(&db_path, "-wal").exists());
+        assert!(!staging_path(&db_path).exists());
+
+        // The corrupt database is kept, together with its WAL file
+        let quarantined = quarantined_files(dir.path());
+        assert_eq!(quarantined.len(), 2);
+        assert!(quarantined[0].starts_with("live.db.corrupt-"));
+        assert!(quarantined[1].ends_with("-wal"));
+        let corrupt = dir.path().join(&quarantined[0]);
+        assert_eq!(std::fs::read(corrupt).unwrap(), b"corrupted");
+    }
+
+    #[tokio::test]
+    async fn restore_rejects_invalid_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("live.db");
+        let backups = dir.path().join("backups");
+        std::fs::create_dir(&backups).unwrap();
+        std::fs::write(&db_path, b"original").unwrap();
+        std::fs::write(
+            backups.join("pisovereign_backup_20250101_000000.db"),
+            b"not a database",
+        )
+        .unwrap();
+
+        let result = AsyncDatabase::restore_latest_backup(&db_path, &backups).await;
+        assert!(matches!(result, Err(AsyncDatabaseError::InvalidBackup(_))));
+        assert_eq!(std::fs::read(&db_path).unwrap(), b"original");
+        assert!(!staging_path(&db_path).exists());
+        assert!(quarantined_files(dir.path()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn restore_without_backups_is_noop() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("live.db");
+        std::fs::write(&db_path, b"original").unwrap();
+
+        let restored = AsyncDatabase::restore_latest_backup(&db_path, dir.path())
+            .await
+            .unwrap();
+        assert!(restored.is_none());
+        assert_eq!(std::fs::read(&db_path).unwrap(), b"original");
+    }
+
     #[tokio::test]
     async fn adopt_legacy_database_noop_on_fresh() {
         let db = AsyncDatabase::in_memory().await.unwrap();
@@ -346,4 +749,50 @@ mod tests {
         .unwrap();
         assert!(!has_table);
     }
+
+    #[tokio::test]
+    async fn fresh_database_has_all_migrations_pending() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+
+        let pending = db.pending_migrations().await.unwrap();
+        assert_eq!(pending, AsyncDatabase::available_migrations());
+        assert!(!pending.is_empty());
+        assert_eq!(db.current_schema_version().await.unwrap(), 0);
+
+        // Dry run must not start tracking migrations
+        assert!(!has_migrations_table(db.pool()).await);
+    }
+
+    #[tokio::test]
+    async fn migrated_database_has_no_pending_migrations() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+
+        assert!(db.pending_migrations().await.unwrap().is_empty());
+
+        let latest = AsyncDatabase::available_migrations()
+            .last()
+            .map(|m| m.version)
+            .unwrap();
+        assert_eq!(db.current_schema_version().await.unwrap(), latest);
+    }
+
+    #[tokio::test]
+    async fn legacy_database_reports_versions_above_user_version() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        sqlx::query("PRAGMA user_version = 3")
+            .execute(db.pool())
+            .await
+            .unwrap();
+
+        let pending = db.pending_migrations().await.unwrap();
+        assert!(pending.iter().all(|m| m.version > 3));
+        assert_eq!(db.current_schema_version().await.unwrap(), 3);
+    }
+
+    #[test]
+    fn available_migrations_are_sorted() {
+        let migrations = AsyncDatabase::available_migrations();
+        assert!(migrations.windows(2).all(|w| w[0].version < w[1].version));
+    }
 }
@@ -3,22 +3,58 @@
 //! Provides async conversation persistence using sqlx with SQLite.
 //! Implements the same interface as the blocking version but with
 //! true async operations.
+//!
+//! When an [`EncryptionPort`] is attached, message content is encrypted
+//! before insert and stored as a ciphertext column plus nonce; the plaintext
+//! `content` column is left empty. Rows written before encryption was enabled
+//! are still readable and can be migrated with
+//! [`AsyncConversationStore::rotate_encryption_key`].
+
+use std::sync::Arc;
 
-use application::{error::ApplicationError, ports::ConversationStore};
+use application::{
+    error::ApplicationError,
+    ports::{ConversationStore, EncryptionPort},
+};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::{
     ChatMessage, Conversation, ConversationId, ConversationSource, MessageMetadata, MessageRole,
-    PhoneNumber,
+    PhoneNumber, UserId,
 };
 use sqlx::SqlitePool;
-use tracing::{debug, instrument};
+use tracing::{debug, info, instrument};
 use uuid::Uuid;
 
+use crate::adapters::ChaChaEncryptionAdapter;
+
+/// Nonce size of the XChaCha20-Poly1305 output stored alongside each message
+const NONCE_SIZE: usize = ChaChaEncryptionAdapter::NONCE_SIZE;
+
 /// Async conversation store using sqlx
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct AsyncConversationStore {
     pool: SqlitePool,
+    encryption: Option<Arc<dyn EncryptionPort>>,
+}
+
+impl std::fmt::Debug for AsyncConversationStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncConversationStore")
+            .field("pool", &self.pool)
+            .field("encrypted", &self.encryption.is_some())
+            .finish()
+    }
+}
+
+/// Message content as stored in the `messages` table
+struct StoredContent {
+    /// Plaintext content (empty when encrypted)
+    content: String,
+    /// Ciphertext without the nonce
+    ciphertext: Option<Vec<u8>>,
+    /// Nonce used to encrypt the ciphertext
+    nonce: Option<Vec<u8>>,
 }
 
 /// Mask a phone number before persisting or logging.
@@ -46,7 +82,255 @@ impl AsyncConversationStore {
     /// Create a new async conversation store
     #[must_use]
     pub const fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            encryption: None,
+        }
+    }
+
+    /// Encrypt message content at rest using the given encryption port
+    ///
+    /// Ports that report `is_enabled() == false` are ignored, so a
+    /// `NoOpEncryption` keeps content in plaintext.
+    #[must_use]
+    pub fn with_encryption(mut self, encryption: Arc<dyn EncryptionPort>) -> Self {
+        self.encryption = encryption.is_enabled().then_some(encryption);
+        self
+    }
+
+    /// Whether message content is encrypted before insert
+    #[must_use]
+    pub const fn is_encrypted(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    /// Prepare message content for storage, encrypting it if enabled
+    async fn encode_content(&self, content: &str) -> Result<StoredContent, ApplicationError> {
+        match &self.encryption {
+            Some(encryption) => encrypt_content(encryption.as_ref(), content).await,
+            None => Ok(StoredContent {
+                content: content.to_string(),
+                ciphertext: None,
+                nonce: None,
+            }),
+        }
+    }
+
+    /// Recover message content from its stored representation
+    async fn decode_content(
+        &self,
+        content: String,
+        ciphertext: Option<Vec<u8>>,
+        nonce: Option<Vec<u8>>,
+    ) -> Result<String, ApplicationError> {
+        decrypt_content(self.encryption.as_deref(), content, ciphertext, nonce).await
+    }
+
+    /// Recover the conversation summary from its stored representation
+    async fn decode_summary(
+        &self,
+        summary: Option<String>,
+        ciphertext: Option<Vec<u8>>,
+        nonce: Option<Vec<u8>>,
+    ) -> Result<Option<String>, ApplicationError> {
+        if summary.is_none() && ciphertext.is_none() {
+            return Ok(None);
+        }
+        self.decode_content(summary.unwrap_or_default(), ciphertext, nonce)
+            .await
+            .map(Some)
+    }
+
+    /// Decrypt message content and summaries of joined rows in place
+    ///
+    /// The summary is only decoded on the first row of each conversation,
+    /// which is the one the conversation is built from.
+    async fn decode_joined_rows(
+        &self,
+        rows: &mut [ConversationWithMessageRow],
+    ) -> Result<(), ApplicationError> {
+        let mut previous_id: Option<String> = None;
+        for row in rows.iter_mut() {
+            if previous_id.as_deref() != Some(row.id.as_str()) {
+                previous_id = Some(row.id.clone());
+                if row.summary_ciphertext.is_some() {
+                    row.summary = self
+                        .decode_summary(
+                            row.summary.take(),
+                            row.summary_ciphertext.take(),
+                            row.summary_nonce.take(),
+                        )
+                        .await?;
+                }
+            }
+            if row.msg_ciphertext.is_some() {
+                let plaintext = self
+                    .decode_content(
+                        row.msg_content.take().unwrap_or_default(),
+                        row.msg_ciphertext.take(),
+                        row.msg_nonce.take(),
+                    )
+                    .await?;
+                row.msg_content = Some(plaintext);
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert message rows into domain messages, decrypting content
+    async fn messages_from_rows(
+        &self,
+        message_rows: Vec<MessageRow>,
+    ) -> Result<Vec<ChatMessage>, ApplicationError> {
+        let mut messages = Vec::with_capacity(message_rows.len());
+        for (idx, msg_row) in message_rows.into_iter().enumerate() {
+            let metadata: Option<MessageMetadata> = msg_row
+                .metadata
+                .as_deref()
+                .and_then(|s| serde_json::from_str(s).ok());
+            let content = self
+                .decode_content(
+                    msg_row.content,
+                    msg_row.content_ciphertext,
+                    msg_row.content_nonce,
+                )
+                .await?;
+
+            // Note: Conversations never realistically exceed u32::MAX messages
+            #[allow(clippy::cast_possible_truncation)]
+            let seq = (idx as u32) + 1;
+            messages.push(ChatMessage {
+                id: Self::parse_uuid(&msg_row.id)?,
+                role: Self::parse_role(&msg_row.role)?,
+                content,
+                created_at: parse_datetime(&msg_row.created_at)?,
+                sequence_number: seq,
+                metadata,
+            });
+        }
+        Ok(messages)
+    }
+
+    /// Insert a single message row
+    async fn insert_message<'e, E>(
+        &self,
+        executor: E,
+        conversation_id: &ConversationId,
+        message: &ChatMessage,
+    ) -> Result<(), ApplicationError>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let metadata_json = message
+            .metadata
+            .as_ref()
+            .and_then(|m| serde_json::to_string(m).ok());
+        let stored = self.encode_content(&message.content).await?;
+
+        sqlx::query(
+            r"
+            INSERT INTO messages
+                (id, conversation_id, role, content, created_at, metadata,
+                 content_ciphertext, content_nonce)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ",
+        )
+        .bind(message.id.to_string())
+        .bind(conversation_id.to_string())
+        .bind(Self::role_to_str(message.role))
+        .bind(stored.content)
+        .bind(message.created_at.to_rfc3339())
+        .bind(metadata_json)
+        .bind(stored.ciphertext)
+        .bind(stored.nonce)
+        .execute(executor)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    /// Re-encrypt all stored messages with a new encryption key
+    ///
+    /// Conversation summaries are re-encrypted along with the messages.
+    /// Every message is decrypted with the store's current key (plaintext
+    /// rows are read as-is) and re-encrypted with `new_encryption` inside a
+    /// single transaction. Afterwards the store must be recreated with the
+    /// new key via [`Self::with_encryption`]. Returns the number of
+    /// re-encrypted messages.
+    #[instrument(skip(self, new_encryption))]
+    pub async fn rotate_encryption_key(
+        &self,
+        new_encryption: &dyn EncryptionPort,
+    ) -> Result<usize, ApplicationError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let rows: Vec<(String, String, Option<Vec<u8>>, Option<Vec<u8>>)> =
+            sqlx::query_as("SELECT id, content, content_ciphertext, content_nonce FROM messages")
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+
+        let mut rotated = 0usize;
+        for (id, content, ciphertext, nonce) in rows {
+            let plaintext = self.decode_content(content, ciphertext, nonce).await?;
+            let stored = encrypt_content(new_encryption, &plaintext).await?;
+
+            sqlx::query(
+                r"
+                UPDATE messages
+                SET content = $1, content_ciphertext = $2, content_nonce = $3
+                WHERE id = $4
+                ",
+            )
+            .bind(stored.content)
+            .bind(stored.ciphertext)
+            .bind(stored.nonce)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+            rotated += 1;
+        }
+
+        let summaries: Vec<(String, String, Option<Vec<u8>>, Option<Vec<u8>>)> = sqlx::query_as(
+            r"
+            SELECT id, COALESCE(summary, ''), summary_ciphertext, summary_nonce
+            FROM conversations
+            WHERE summary IS NOT NULL OR summary_ciphertext IS NOT NULL
+            ",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let rotated_summaries = summaries.len();
+        for (id, summary, ciphertext, nonce) in summaries {
+            let plaintext = self.decode_content(summary, ciphertext, nonce).await?;
+            let stored = encrypt_content(new_encryption, &plaintext).await?;
+
+            sqlx::query(
+                r"
+                UPDATE conversations
+                SET summary = $1, summary_ciphertext = $2, summary_nonce = $3
+                WHERE id = $4
+                ",
+            )
+            .bind(stored.content)
+            .bind(stored.ciphertext)
+            .bind(stored.nonce)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        info!(
+            rotated,
+            rotated_summaries, "Re-encrypted conversation messages with new key"
+        );
+        Ok(rotated)
     }
 
     /// Parse a role string into `MessageRole`
@@ -87,6 +371,17 @@ impl AsyncConversationStore {
             .map_err(|e| ApplicationError::Internal(format!("Invalid conversation source: {e}")))
     }
 
+    /// Parse the conversation owner; rows from before ownership tracking
+    /// have no owner and belong to the default user
+    fn parse_owner(s: Option<&str>) -> Result<UserId, ApplicationError> {
+        s.map(|s| {
+            UserId::parse(s)
+                .map_err(|e| ApplicationError::Internal(format!("Invalid user ID: {e}")))
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+    }
+
     /// Build conversations from joined rows, grouping by conversation ID
     ///
     /// Expects rows ordered by conversation (e.g. `updated_at DESC`) then by
@@ -116,6 +411,10 @@ impl AsyncConversationStore {
                         updated_at: row.updated_at.clone(),
                         source: row.source.clone(),
                         phone_number: row.phone_number.clone(),
+                        user_id: row.user_id.clone(),
+                        summary: row.summary.clone(),
+                        summary_ciphertext: None,
+                        summary_nonce: None,
                     },
                     Vec::new(),
                 )
@@ -165,6 +464,8 @@ impl AsyncConversationStore {
                     persisted_message_count: message_count,
                     source: Self::parse_source(&conv_row.source)?,
                     phone_number: conv_row.phone_number.and_then(|p| PhoneNumber::new(p).ok()),
+                    user_id: Self::parse_owner(conv_row.user_id.as_deref())?,
+                    summary: conv_row.summary,
                 })
             })
             .collect()
@@ -178,18 +479,31 @@ impl ConversationStore for AsyncConversationStore {
         let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
 
         let masked_phone_number = conversation.phone_number.as_ref().map(mask_phone_number);
+        let (summary, summary_ciphertext, summary_nonce) = match &conversation.summary {
+            Some(summary) => {
+                let stored = self.encode_content(summary).await?;
+                (Some(stored.content), stored.ciphertext, stored.nonce)
+            },
+            None => (None, None, None),
+        };
 
         // Upsert conversation
         sqlx::query(
             r"
-            INSERT INTO conversations (id, title, system_prompt, created_at, updated_at, source, phone_number)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO conversations
+                (id, title, system_prompt, created_at, updated_at, source, phone_number, user_id,
+                 summary, summary_ciphertext, summary_nonce)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ON CONFLICT(id) DO UPDATE SET
                 title = excluded.title,
                 system_prompt = excluded.system_prompt,
                 updated_at = excluded.updated_at,
                 source = excluded.source,
-                phone_number = excluded.phone_number
+                phone_number = excluded.phone_number,
+                user_id = excluded.user_id,
+                summary = excluded.summary,
+                summary_ciphertext = excluded.summary_ciphertext,
+                summary_nonce = excluded.summary_nonce
             ",
         )
         .bind(conversation.id.to_string())
@@ -199,6 +513,10 @@ impl ConversationStore for AsyncConversationStore {
         .bind(conversation.updated_at.to_rfc3339())
         .bind(conversation.source.as_str())
         .bind(masked_phone_number)
+        .bind(conversation.user_id.to_string())
+        .bind(summary)
+        .bind(summary_ciphertext)
+        .bind(summary_nonce)
         .execute(&mut *tx)
         .await
         .map_err(map_sqlx_error)?;
@@ -212,26 +530,8 @@ impl ConversationStore for AsyncConversationStore {
 
         // Insert all messages
         for message in &conversation.messages {
-            let metadata_json = message
-                .metadata
-                .as_ref()
-                .and_then(|m| serde_json::to_string(m).ok());
-
-            sqlx::query(
-                r"
-                INSERT INTO messages (id, conversation_id, role, content, created_at, metadata)
-                VALUES ($1, $2, $3, $4, $5, $6)
-                ",
-            )
-            .bind(message.id.to_string())
-            .bind(conversation.id.to_string())
-            .bind(Self::role_to_str(message.role))
-            .bind(&message.content)
-            .bind(message.created_at.to_rfc3339())
-            .bind(metadata_json)
-            .execute(&mut *tx)
-            .await
-            .map_err(map_sqlx_error)?;
+            self.insert_message(&mut *tx, &conversation.id, message)
+                .await?;
         }
 
         tx.commit().await.map_err(map_sqlx_error)?;
@@ -244,7 +544,8 @@ impl ConversationStore for AsyncConversationStore {
         // Fetch conversation
         let conv_row: Option<ConversationRow> = sqlx::query_as(
             r"
-            SELECT id, title, system_prompt, created_at, updated_at, source, phone_number
+            SELECT id, title, system_prompt, created_at, updated_at, source, phone_number, user_id,
+                   summary, summary_ciphertext, summary_nonce
             FROM conversations WHERE id = $1
             ",
         )
@@ -261,7 +562,7 @@ impl ConversationStore for AsyncConversationStore {
         // Fetch messages
         let message_rows: Vec<MessageRow> = sqlx::query_as(
             r"
-            SELECT id, role, content, created_at, metadata
+            SELECT id, role, content, created_at, metadata, content_ciphertext, content_nonce
             FROM messages WHERE conversation_id = $1
             ORDER BY created_at ASC
             ",
@@ -272,25 +573,10 @@ impl ConversationStore for AsyncConversationStore {
         .map_err(map_sqlx_error)?;
 
         // Convert to domain types
-        let mut messages = Vec::with_capacity(message_rows.len());
-        for (idx, msg_row) in message_rows.into_iter().enumerate() {
-            let metadata: Option<MessageMetadata> = msg_row
-                .metadata
-                .as_deref()
-                .and_then(|s| serde_json::from_str(s).ok());
-
-            // Note: Conversations never realistically exceed u32::MAX messages
-            #[allow(clippy::cast_possible_truncation)]
-            let seq = (idx as u32) + 1;
-            messages.push(ChatMessage {
-                id: Self::parse_uuid(&msg_row.id)?,
-                role: Self::parse_role(&msg_row.role)?,
-                content: msg_row.content,
-                created_at: parse_datetime(&msg_row.created_at)?,
-                sequence_number: seq,
-                metadata,
-            });
-        }
+        let messages = self.messages_from_rows(message_rows).await?;
+        let summary = self
+            .decode_summary(row.summary, row.summary_ciphertext, row.summary_nonce)
+            .await?;
 
         let message_count = messages.len();
         let conversation = Conversation {
@@ -304,6 +590,8 @@ impl ConversationStore for AsyncConversationStore {
             persisted_message_count: message_count,
             source: Self::parse_source(&row.source)?,
             phone_number: row.phone_number.and_then(|p| PhoneNumber::new(p).ok()),
+            user_id: Self::parse_owner(row.user_id.as_deref())?,
+            summary,
         };
 
         debug!("Conversation loaded");
@@ -318,7 +606,8 @@ impl ConversationStore for AsyncConversationStore {
     ) -> Result<Option<Conversation>, ApplicationError> {
         let row: Option<ConversationRow> = sqlx::query_as(
             r"
-            SELECT id, title, system_prompt, created_at, updated_at, source, phone_number
+            SELECT id, title, system_prompt, created_at, updated_at, source, phone_number, user_id,
+                   summary, summary_ciphertext, summary_nonce
             FROM conversations
             WHERE source = $1 AND phone_number = $2
             ORDER BY updated_at DESC
@@ -339,7 +628,7 @@ impl ConversationStore for AsyncConversationStore {
         // Load messages for the conversation
         let message_rows: Vec<MessageRow> = sqlx::query_as(
             r"
-            SELECT id, role, content, created_at, metadata
+            SELECT id, role, content, created_at, metadata, content_ciphertext, content_nonce
             FROM messages WHERE conversation_id = $1
             ORDER BY created_at ASC
             ",
@@ -349,25 +638,10 @@ impl ConversationStore for AsyncConversationStore {
         .await
         .map_err(map_sqlx_error)?;
 
-        let mut messages = Vec::with_capacity(message_rows.len());
-        for (idx, msg_row) in message_rows.into_iter().enumerate() {
-            let metadata: Option<MessageMetadata> = msg_row
-                .metadata
-                .as_deref()
-                .and_then(|s| serde_json::from_str(s).ok());
-
-            // Note: Conversations never realistically exceed u32::MAX messages
-            #[allow(clippy::cast_possible_truncation)]
-            let seq = (idx as u32) + 1;
-            messages.push(ChatMessage {
-                id: Self::parse_uuid(&msg_row.id)?,
-                role: Self::parse_role(&msg_row.role)?,
-                content: msg_row.content,
-                created_at: parse_datetime(&msg_row.created_at)?,
-                sequence_number: seq,
-                metadata,
-            });
-        }
+        let messages = self.messages_from_rows(message_rows).await?;
+        let summary = self
+            .decode_summary(row.summary, row.summary_ciphertext, row.summary_nonce)
+            .await?;
 
         let message_count = messages.len();
         let conversation = Conversation {
@@ -380,6 +654,8 @@ impl ConversationStore for AsyncConversationStore {
             persisted_message_count: message_count,
             source: Self::parse_source(&row.source)?,
             phone_number: row.phone_number.and_then(|p| PhoneNumber::new(p).ok()),
+            user_id: Self::parse_owner(row.user_id.as_deref())?,
+            summary,
         };
 
         debug!("Conversation loaded by phone number");
@@ -410,26 +686,8 @@ impl ConversationStore for AsyncConversationStore {
         conversation_id: &ConversationId,
         message: &ChatMessage,
     ) -> Result<(), ApplicationError> {
-        let metadata_json = message
-            .metadata
-            .as_ref()
-            .and_then(|m| serde_json::to_string(m).ok());
-
-        sqlx::query(
-            r"
-            INSERT INTO messages (id, conversation_id, role, content, created_at, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            ",
-        )
-        .bind(message.id.to_string())
-        .bind(conversation_id.to_string())
-        .bind(Self::role_to_str(message.role))
-        .bind(&message.content)
-        .bind(message.created_at.to_rfc3339())
-        .bind(metadata_json)
-        .execute(&self.pool)
-        .await
-        .map_err(map_sqlx_error)?;
+        self.insert_message(&self.pool, conversation_id, message)
+            .await?;
 
         // Update conversation's updated_at
         sqlx::query("UPDATE conversations SET updated_at = $1 WHERE id = $2")
@@ -456,26 +714,8 @@ impl ConversationStore for AsyncConversationStore {
         let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
 
         for message in messages {
-            let metadata_json = message
-                .metadata
-                .as_ref()
-                .and_then(|m| serde_json::to_string(m).ok());
-
-            sqlx::query(
-                r"
-                INSERT INTO messages (id, conversation_id, role, content, created_at, metadata)
-                VALUES ($1, $2, $3, $4, $5, $6)
-                ",
-            )
-            .bind(message.id.to_string())
-            .bind(conversation_id.to_string())
-            .bind(Self::role_to_str(message.role))
-            .bind(&message.content)
-            .bind(message.created_at.to_rfc3339())
-            .bind(metadata_json)
-            .execute(&mut *tx)
-            .await
-            .map_err(map_sqlx_error)?;
+            self.insert_message(&mut *tx, conversation_id, message)
+                .await?;
         }
 
         // Update conversation's updated_at once at the end
@@ -496,12 +736,14 @@ impl ConversationStore for AsyncConversationStore {
     #[instrument(skip(self))]
     async fn list_recent(&self, limit: usize) -> Result<Vec<Conversation>, ApplicationError> {
         // Single JOIN query instead of N+1 (one query per conversation)
-        let rows: Vec<ConversationWithMessageRow> = sqlx::query_as(
+        let mut rows: Vec<ConversationWithMessageRow> = sqlx::query_as(
             r"
             SELECT c.id, c.title, c.system_prompt, c.created_at, c.updated_at,
-                   c.source, c.phone_number,
+                   c.source, c.phone_number, c.user_id,
+                   c.summary, c.summary_ciphertext, c.summary_nonce,
                    m.id AS msg_id, m.role AS msg_role, m.content AS msg_content,
-                   m.created_at AS msg_created_at, m.metadata AS msg_metadata
+                   m.created_at AS msg_created_at, m.metadata AS msg_metadata,
+                   m.content_ciphertext AS msg_ciphertext, m.content_nonce AS msg_nonce
             FROM conversations c
             LEFT JOIN messages m ON m.conversation_id = c.id
             WHERE c.id IN (
@@ -515,6 +757,7 @@ impl ConversationStore for AsyncConversationStore {
         .await
         .map_err(map_sqlx_error)?;
 
+        self.decode_joined_rows(&mut rows).await?;
         let conversations = Self::build_conversations_from_joined_rows(rows)?;
         debug!(count = conversations.len(), "Listed recent conversations");
         Ok(conversations)
@@ -528,13 +771,17 @@ impl ConversationStore for AsyncConversationStore {
     ) -> Result<Vec<Conversation>, ApplicationError> {
         let search_pattern = format!("%{query}%");
 
+        // Encrypted message bodies have an empty `content` column, so for
+        // encrypted stores only conversation titles can match.
         // Single JOIN query: find matching conversations and load their messages in one pass
-        let rows: Vec<ConversationWithMessageRow> = sqlx::query_as(
+        let mut rows: Vec<ConversationWithMessageRow> = sqlx::query_as(
             r"
             SELECT c.id, c.title, c.system_prompt, c.created_at, c.updated_at,
-                   c.source, c.phone_number,
+                   c.source, c.phone_number, c.user_id,
+                   c.summary, c.summary_ciphertext, c.summary_nonce,
                    m.id AS msg_id, m.role AS msg_role, m.content AS msg_content,
-                   m.created_at AS msg_created_at, m.metadata AS msg_metadata
+                   m.created_at AS msg_created_at, m.metadata AS msg_metadata,
+                   m.content_ciphertext AS msg_ciphertext, m.content_nonce AS msg_nonce
             FROM conversations c
             LEFT JOIN messages m ON m.conversation_id = c.id
             WHERE c.id IN (
@@ -554,6 +801,7 @@ impl ConversationStore for AsyncConversationStore {
         .await
         .map_err(map_sqlx_error)?;
 
+        self.decode_joined_rows(&mut rows).await?;
         let conversations = Self::build_conversations_from_joined_rows(rows)?;
 
         debug!(
@@ -591,6 +839,10 @@ struct ConversationRow {
     updated_at: String,
     source: String,
     phone_number: Option<String>,
+    user_id: Option<String>,
+    summary: Option<String>,
+    summary_ciphertext: Option<Vec<u8>>,
+    summary_nonce: Option<Vec<u8>>,
 }
 
 /// Row type for message queries
@@ -601,6 +853,8 @@ struct MessageRow {
     content: String,
     created_at: String,
     metadata: Option<String>,
+    content_ciphertext: Option<Vec<u8>>,
+    content_nonce: Option<Vec<u8>>,
 }
 
 /// Combined row for JOIN queries (conversations + messages in one pass)
@@ -614,12 +868,61 @@ struct ConversationWithMessageRow {
     updated_at: String,
     source: String,
     phone_number: Option<String>,
+    user_id: Option<String>,
+    summary: Option<String>,
+    summary_ciphertext: Option<Vec<u8>>,
+    summary_nonce: Option<Vec<u8>>,
     // Message fields (nullable for conversations without messages)
     msg_id: Option<String>,
     msg_role: Option<String>,
     msg_content: Option<String>,
     msg_created_at: Option<String>,
     msg_metadata: Option<String>,
+    msg_ciphertext: Option<Vec<u8>>,
+    msg_nonce: Option<Vec<u8>>,
+}
+
+/// Encrypt message content, splitting the port output into nonce and ciphertext
+async fn encrypt_content(
+    encryption: &dyn EncryptionPort,
+    content: &str,
+) -> Result<StoredContent, ApplicationError> {
+    let mut sealed = encryption.encrypt(content.as_bytes()).await?;
+    if sealed.len() < NONCE_SIZE {
+        return Err(ApplicationError::Internal(
+            "Encrypted message is shorter than its nonce".to_string(),
+        ));
+    }
+    let ciphertext = sealed.split_off(NONCE_SIZE);
+
+    Ok(StoredContent {
+        content: String::new(),
+        ciphertext: Some(ciphertext),
+        nonce: Some(sealed),
+    })
+}
+
+/// Decrypt stored message content, passing plaintext rows through unchanged
+async fn decrypt_content(
+    encryption: Option<&dyn EncryptionPort>,
+    content: String,
+    ciphertext: Option<Vec<u8>>,
+    nonce: Option<Vec<u8>>,
+) -> Result<String, ApplicationError> {
+    let (Some(ciphertext), Some(mut sealed)) = (ciphertext, nonce) else {
+        return Ok(content);
+    };
+    let Some(encryption) = encryption else {
+        return Err(ApplicationError::Configuration(
+            "Stored message is encrypted but no encryption key is configured".to_string(),
+        ));
+    };
+
+    sealed.extend_from_slice(&ciphertext);
+    let plaintext = encryption.decrypt(&sealed).await?;
+    String::from_utf8(plaintext).map_err(|e| {
+        ApplicationError::Internal(format!("Decrypted message is not valid UTF-8: {e}"))
+    })
 }
 
 /// Parse an RFC3339 datetime string
@@ -658,6 +961,53 @@ mod tests {
         assert_eq!(loaded.unwrap().id, conv.id);
     }
 
+    #[tokio::test]
+    async fn owner_roundtrip() {
+        let (_db, store) = setup_test_db().await;
+
+        let owner = UserId::new();
+        let conv = Conversation::new().with_user_id(owner);
+        store.save(&conv).await.unwrap();
+
+        let loaded = store.get(&conv.id).await.unwrap().unwrap();
+        assert_eq!(loaded.user_id, owner);
+
+        let recent = store.list_recent(1).await.unwrap();
+        assert_eq!(recent[0].user_id, owner);
+    }
+
+    #[tokio::test]
+    async fn summary_roundtrip() {
+        let (_db, store) = setup_test_db().await;
+
+        let mut conv = Conversation::new();
+        conv.add_user_message("Hello");
+        conv.compact(1, "The user said hello.");
+        store.save(&conv).await.unwrap();
+
+        let loaded = store.get(&conv.id).await.unwrap().unwrap();
+        assert_eq!(loaded.summary.as_deref(), Some("The user said hello."));
+        assert!(loaded.messages.is_empty());
+
+        let recent = store.list_recent(1).await.unwrap();
+        assert_eq!(recent[0].summary.as_deref(), Some("The user said hello."));
+    }
+
+    #[tokio::test]
+    async fn missing_owner_reads_as_default_user() {
+        let (_db, store) = setup_test_db().await;
+
+        let conv = Conversation::new().with_user_id(UserId::new());
+        store.save(&conv).await.unwrap();
+        sqlx::query("UPDATE conversations SET user_id = NULL")
+            .execute(&store.pool)
+            .await
+            .unwrap();
+
+        let loaded = store.get(&conv.id).await.unwrap().unwrap();
+        assert_eq!(loaded.user_id, UserId::default());
+    }
+
     #[tokio::test]
     async fn get_nonexistent_returns_none() {
         let (_db, store) = setup_test_db().await;
@@ -735,4 +1085,193 @@ mod tests {
         assert_eq!(loaded.messages.len(), 1);
         assert_eq!(loaded.messages[0].content, "Hello");
     }
+
+    mod encryption {
+        use super::*;
+
+        fn adapter() -> Arc<dyn EncryptionPort> {
+            let key = ChaChaEncryptionAdapter::generate_key();
+            Arc::new(ChaChaEncryptionAdapter::new(&key).unwrap())
+        }
+
+        #[tokio::test]
+        async fn encrypted_roundtrip() {
+            let (_db, store) = setup_test_db().await;
+            let store = store.with_encryption(adapter());
+            assert!(store.is_encrypted());
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("My PIN is 4711");
+            conv.add_assistant_message("Noted.");
+            store.save(&conv).await.unwrap();
+            store
+                .add_message(&conv.id, &ChatMessage::user("And the door code is 0815"))
+                .await
+                .unwrap();
+
+            let loaded = store.get(&conv.id).await.unwrap().unwrap();
+            assert_eq!(loaded.messages.len(), 3);
+            assert_eq!(loaded.messages[0].content, "My PIN is 4711");
+            assert_eq!(loaded.messages[2].content, "And the door code is 0815");
+
+            let recent = store.list_recent(10).await.unwrap();
+            assert_eq!(recent[0].messages[0].content, "My PIN is 4711");
+        }
+
+        #[tokio::test]
+        async fn encrypted_rows_do_not_contain_plaintext() {
+            let (db, store) = setup_test_db().await;
+            let store = store.with_encryption(adapter());
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("top secret plaintext");
+            store.save(&conv).await.unwrap();
+
+            let rows: Vec<(String, Vec<u8>, Vec<u8>)> =
+                sqlx::query_as("SELECT content, content_ciphertext, content_nonce FROM messages")
+                    .fetch_all(db.pool())
+                    .await
+                    .unwrap();
+            assert_eq!(rows.len(), 1);
+
+            let (content, ciphertext, nonce) = &rows[0];
+            assert!(content.is_empty());
+            assert_eq!(nonce.len(), NONCE_SIZE);
+            let needle = b"top secret plaintext";
+            assert!(!ciphertext.windows(needle.len()).any(|w| w == needle));
+        }
+
+        #[tokio::test]
+        async fn database_file_does_not_contain_plaintext() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("encrypted.db");
+            let config = AsyncDatabaseConfig {
+                wal_mode: false,
+                ..AsyncDatabaseConfig::file(&path)
+            };
+            let db = AsyncDatabase::new(&config).await.unwrap();
+            db.migrate().await.unwrap();
+            let store = AsyncConversationStore::new(db.pool().clone()).with_encryption(adapter());
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("needle-in-the-database");
+            store.save(&conv).await.unwrap();
+            db.close().await;
+
+            let bytes = std::fs::read(&path).unwrap();
+            let needle = b"needle-in-the-database";
+            assert!(!bytes.windows(needle.len()).any(|w| w == needle));
+        }
+
+        #[tokio::test]
+        async fn encrypted_summary_roundtrip() {
+            let (db, store) = setup_test_db().await;
+            let store = store.with_encryption(adapter());
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("Hi");
+            conv.compact(0, "secret summary");
+            conv.add_assistant_message("Hello");
+            store.save(&conv).await.unwrap();
+
+            let (summary, ciphertext): (Option<String>, Option<Vec<u8>>) =
+                sqlx::query_as("SELECT summary, summary_ciphertext FROM conversations")
+                    .fetch_one(db.pool())
+                    .await
+                    .unwrap();
+            assert_eq!(summary.as_deref(), Some(""));
+            let needle = b"secret summary";
+            assert!(
+                !ciphertext
+                    .unwrap()
+                    .windows(needle.len())
+                    .any(|w| w == needle)
+            );
+
+            let loaded = store.get(&conv.id).await.unwrap().unwrap();
+            assert_eq!(loaded.summary.as_deref(), Some("secret summary"));
+            let recent = store.list_recent(1).await.unwrap();
+            assert_eq!(recent[0].summary.as_deref(), Some("secret summary"));
+        }
+
+        #[tokio::test]
+        async fn plaintext_rows_remain_readable() {
+            let (_db, store) = setup_test_db().await;
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("written before encryption");
+            store.save(&conv).await.unwrap();
+
+            let store = store.with_encryption(adapter());
+            let loaded = store.get(&conv.id).await.unwrap().unwrap();
+            assert_eq!(loaded.messages[0].content, "written before encryption");
+        }
+
+        #[tokio::test]
+        async fn encrypted_rows_require_key() {
+            let (db, store) = setup_test_db().await;
+            let encrypted = store.with_encryption(adapter());
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("hidden");
+            encrypted.save(&conv).await.unwrap();
+
+            let plain = AsyncConversationStore::new(db.pool().clone());
+            assert!(plain.get(&conv.id).await.is_err());
+        }
+
+        #[tokio::test]
+        async fn rotate_encryption_key_reencrypts_rows() {
+            let (db, store) = setup_test_db().await;
+            let old_store = store.with_encryption(adapter());
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("rotate me");
+            conv.add_assistant_message("done");
+            conv.summary = Some("rotated summary".to_string());
+            old_store.save(&conv).await.unwrap();
+
+            let new_key = adapter();
+            let rotated = old_store
+                .rotate_encryption_key(new_key.as_ref())
+                .await
+                .unwrap();
+            assert_eq!(rotated, 2);
+
+            // The old key can no longer decrypt the rows
+            assert!(old_store.get(&conv.id).await.is_err());
+
+            let new_store = AsyncConversationStore::new(db.pool().clone()).with_encryption(new_key);
+            let loaded = new_store.get(&conv.id).await.unwrap().unwrap();
+            assert_eq!(loaded.messages[0].content, "rotate me");
+            assert_eq!(loaded.messages[1].content, "done");
+            assert_eq!(loaded.summary.as_deref(), Some("rotated summary"));
+        }
+
+        #[tokio::test]
+        async fn rotate_encrypts_plaintext_rows() {
+            let (db, store) = setup_test_db().await;
+
+            let mut conv = Conversation::new();
+            conv.add_user_message("legacy plaintext");
+            store.save(&conv).await.unwrap();
+
+            let key = adapter();
+            store.rotate_encryption_key(key.as_ref()).await.unwrap();
+
+            let content: String = sqlx::query_scalar("SELECT content FROM messages")
+                .fetch_one(db.pool())
+                .await
+                .unwrap();
+            assert!(content.is_empty());
+
+            let loaded = store
+                .with_encryption(key)
+                .get(&conv.id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(loaded.messages[0].content, "legacy plaintext");
+        }
+    }
 }
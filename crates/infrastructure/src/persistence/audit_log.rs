@@ -265,19 +265,9 @@ impl AuditLogPort for SqliteAuditLog {
     }
 }
 
-/// Parse event type from string
+/// Parse event type from string, treating unknown values as system events
 fn parse_event_type(s: &str) -> AuditEventType {
-    match s {
-        "authentication" => AuditEventType::Authentication,
-        "authorization" => AuditEventType::Authorization,
-        "command_execution" => AuditEventType::CommandExecution,
-        "approval" => AuditEventType::Approval,
-        "config_change" => AuditEventType::ConfigChange,
-        "data_access" => AuditEventType::DataAccess,
-        "integration" => AuditEventType::Integration,
-        "security" => AuditEventType::Security,
-        _ => AuditEventType::System,
-    }
+    s.parse().unwrap_or(AuditEventType::System)
 }
 
 #[cfg(test)]
@@ -518,6 +508,10 @@ mod tests {
         assert_eq!(parse_event_type("data_access"), AuditEventType::DataAccess);
         assert_eq!(parse_event_type("integration"), AuditEventType::Integration);
         assert_eq!(parse_event_type("security"), AuditEventType::Security);
+        assert_eq!(
+            parse_event_type("prompt_injection"),
+            AuditEventType::PromptInjection
+        );
         assert_eq!(parse_event_type("unknown"), AuditEventType::System);
     }
 
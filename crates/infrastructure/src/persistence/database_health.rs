@@ -1,24 +1,49 @@
 //! SQLite database health adapter
 //!
 //! Implements the `DatabaseHealthPort` for SQLite databases using sqlx.
+//! The result of the last scheduled integrity check is kept alongside the
+//! pool so health reports can surface corruption without re-running the
+//! (expensive) check on every request.
+
+use std::sync::Arc;
 
 use application::error::ApplicationError;
 use application::ports::{DatabaseHealth, DatabaseHealthPort};
 use async_trait::async_trait;
+use parking_lot::RwLock;
 use sqlx::SqlitePool;
 use tracing::{debug, instrument, warn};
 
+use super::async_connection::schema_version;
+
 /// SQLite database health adapter
+///
+/// Clones share the recorded integrity status.
 #[derive(Debug, Clone)]
 pub struct SqliteDatabaseHealth {
     pool: SqlitePool,
+    integrity_ok: Arc<RwLock<Option<bool>>>,
 }
 
 impl SqliteDatabaseHealth {
     /// Create a new database health adapter with the given pool
     #[must_use]
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            integrity_ok: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Record the outcome of an integrity check for health reporting
+    pub fn record_integrity(&self, ok: bool) {
+        *self.integrity_ok.write() = Some(ok);
+    }
+
+    /// Outcome of the most recent integrity check, if any
+    #[must_use]
+    pub fn last_integrity(&self) -> Option<bool> {
+        *self.integrity_ok.read()
     }
 }
 
@@ -71,11 +96,18 @@ impl DatabaseHealthPort for SqliteDatabaseHealth {
             "Database health check passed"
         );
 
-        Ok(
-            DatabaseHealth::healthy_with_version(format!("SQLite {version}"))
-                .with_pool_size(pool_size)
-                .with_response_time(response_time_ms),
-        )
+        let mut health = DatabaseHealth::healthy_with_version(format!("SQLite {version}"))
+            .with_pool_size(pool_size)
+            .with_response_time(response_time_ms);
+        if let Some(ok) = self.last_integrity() {
+            health = health.with_integrity(ok);
+        }
+        match schema_version(&self.pool).await {
+            Ok(version) => health = health.with_schema_version(version),
+            Err(e) => warn!(error = %e, "Failed to read schema version"),
+        }
+
+        Ok(health)
     }
 }
 
@@ -124,6 +156,23 @@ mod tests {
         assert!(result.response_time_ms.is_some());
     }
 
+    #[tokio::test]
+    async fn check_health_reports_recorded_integrity() {
+        let (_db, health) = setup().await;
+        assert!(health.check_health().await.unwrap().integrity_ok.is_none());
+
+        health.clone().record_integrity(false);
+        let result = health.check_health().await.unwrap();
+        assert_eq!(result.integrity_ok, Some(false));
+    }
+
+    #[tokio::test]
+    async fn check_health_includes_schema_version() {
+        let (_db, health) = setup().await;
+        let result = health.check_health().await.unwrap();
+        assert!(result.schema_version.is_some_and(|v| v > 0));
+    }
+
     #[test]
     fn debug_impl_works() {
         // SqliteDatabaseHealth derives Debug, so this should just work
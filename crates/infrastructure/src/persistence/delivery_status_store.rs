@@ -0,0 +1,417 @@
+//! SQLite message delivery status store
+//!
+//! Implements the `DeliveryStatusPort` using sqlx.
+
+use application::{
+    error::ApplicationError,
+    ports::{DeliveryStatusPort, DeliveryUpdate, MessageDelivery, UndeliveredReminder},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::value_objects::{DeliveryStatus, MessengerSource, ReminderId};
+use sqlx::SqlitePool;
+use tracing::{debug, instrument};
+
+use super::error::map_sqlx_error;
+
+/// Attempts before giving up on a concurrently modified row
+const MAX_UPDATE_ATTEMPTS: usize = 3;
+
+/// SQLite-based message delivery status store
+#[derive(Debug, Clone)]
+pub struct SqliteDeliveryStatusStore {
+    pool: SqlitePool,
+}
+
+impl SqliteDeliveryStatusStore {
+    /// Create a new SQLite delivery status store
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn fetch_status(&self, message_id: &str) -> Result<Option<String>, ApplicationError> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT status FROM message_deliveries WHERE message_id = $1")
+                .bind(message_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+        Ok(row.map(|(status,)| status))
+    }
+}
+
+/// Row type for delivery queries
+#[derive(sqlx::FromRow)]
+struct DeliveryRow {
+    message_id: String,
+    source: String,
+    recipient: Option<String>,
+    status: String,
+    error: Option<String>,
+    reminder_id: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl DeliveryRow {
+    #[allow(clippy::wrong_self_convention)]
+    fn to_delivery(self) -> Result<MessageDelivery, ApplicationError> {
+        Ok(MessageDelivery {
+            message_id: self.message_id,
+            source: parse_source(&self.source)?,
+            recipient: self.recipient,
+            status: parse_status(&self.status)?,
+            error: self.error,
+            reminder_id: self
+                .reminder_id
+                .as_deref()
+                .map(parse_reminder_id)
+                .transpose()?,
+            created_at: parse_timestamp(&self.created_at),
+            updated_at: parse_timestamp(&self.updated_at),
+        })
+    }
+}
+
+/// Row type for undelivered reminder queries
+#[derive(sqlx::FromRow)]
+struct UndeliveredRow {
+    reminder_id: String,
+    source: String,
+    recipient: Option<String>,
+    attempts: i64,
+}
+
+fn parse_source(s: &str) -> Result<MessengerSource, ApplicationError> {
+    MessengerSource::from_config(s)
+        .ok_or_else(|| ApplicationError::Internal(format!("Invalid messenger source: {s}")))
+}
+
+fn parse_status(s: &str) -> Result<DeliveryStatus, ApplicationError> {
+    s.parse().map_err(ApplicationError::Internal)
+}
+
+fn parse_reminder_id(s: &str) -> Result<ReminderId, ApplicationError> {
+    ReminderId::parse(s)
+        .map_err(|e| ApplicationError::Internal(format!("Invalid reminder_id: {e}")))
+}
+
+fn parse_timestamp(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc))
+}
+
+#[async_trait]
+impl DeliveryStatusPort for SqliteDeliveryStatusStore {
+    #[instrument(skip(self, recipient), fields(source = %source))]
+    async fn record_sent(
+        &self,
+        message_id: &str,
+        source: MessengerSource,
+        recipient: &str,
+        reminder_id: Option<ReminderId>,
+    ) -> Result<(), ApplicationError> {
+        let now = Utc::now().to_rfc3339();
+
+        // A receipt may have been stored first; keep its status and only
+        // fill in what the send knows.
+        sqlx::query(
+            "INSERT INTO message_deliveries
+                 (message_id, source, recipient, status, reminder_id, created_at, updated_at)
+             VALUES ($1, $2, $3, 'sent', $4, $5, $5)
+             ON CONFLICT(message_id) DO UPDATE SET
+                 recipient = COALESCE(message_deliveries.recipient, excluded.recipient),
+                 reminder_id = COALESCE(message_deliveries.reminder_id, excluded.reminder_id)",
+        )
+        .bind(message_id)
+        .bind(source.config_key())
+        .bind(recipient)
+        .bind(reminder_id.map(|id| id.to_string()))
+        .bind(&now)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        debug!("Recorded sent message");
+        Ok(())
+    }
+
+    #[instrument(skip(self, update), fields(message_id = %update.message_id, status = %update.status))]
+    async fn apply_update(&self, update: &DeliveryUpdate) -> Result<bool, ApplicationError> {
+        let timestamp = update.timestamp.to_rfc3339();
+
+        for _ in 0..MAX_UPDATE_ATTEMPTS {
+            let Some(current) = self.fetch_status(&update.message_id).await? else {
+                let result = sqlx::query(
+                    "INSERT INTO message_deliveries
+                         (message_id, source, status, error, created_at, updated_at)
+                     VALUES ($1, $2, $3, $4, $5, $5)
+                     ON CONFLICT(message_id) DO NOTHING",
+                )
+                .bind(&update.message_id)
+                .bind(update.source.config_key())
+                .bind(update.status.as_str())
+                .bind(update.error.as_deref())
+                .bind(&timestamp)
+                .execute(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+
+                if result.rows_affected() > 0 {
+                    debug!("Stored status for untracked message");
+                    return Ok(true);
+                }
+                continue;
+            };
+
+            if !parse_status(&current)?.can_transition_to(update.status) {
+                debug!(current = %current, "Ignoring stale status update");
+                return Ok(false);
+            }
+
+            // Only apply if nobody changed the status since it was read
+            let result = sqlx::query(
+                "UPDATE message_deliveries
+                 SET status = $1, error = $2, updated_at = $3
+                 WHERE message_id = $4 AND status = $5",
+            )
+            .bind(update.status.as_str())
+            .bind(update.error.as_deref())
+            .bind(&timestamp)
+            .bind(&update.message_id)
+            .bind(&current)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+            if result.rows_affected() > 0 {
+                debug!(previous = %current, "Updated delivery status");
+                return Ok(true);
+            }
+        }
+
+        Err(ApplicationError::Internal(format!(
+            "Delivery status of {} changed concurrently",
+            update.message_id
+        )))
+    }
+
+    #[instrument(skip(self))]
+    async fn get(&self, message_id: &str) -> Result<Option<MessageDelivery>, ApplicationError> {
+        let row: Option<DeliveryRow> = sqlx::query_as(
+            "SELECT message_id, source, recipient, status, error, reminder_id, created_at, updated_at
+             FROM message_deliveries WHERE message_id = $1",
+        )
+        .bind(message_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(DeliveryRow::to_delivery).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn undelivered_reminders(
+        &self,
+        sent_before: DateTime<Utc>,
+    ) -> Result<Vec<UndeliveredReminder>, ApplicationError> {
+        // Latest attempt per reminder, skipping reminders any attempt reached
+        let rows: Vec<UndeliveredRow> = sqlx::query_as(
+            "SELECT d.reminder_id, d.source, d.recipient, latest.attempts
+             FROM message_deliveries d
+             JOIN (
+                 SELECT reminder_id, COUNT(*) AS attempts, MAX(created_at) AS created_at
+                 FROM message_deliveries
+                 WHERE reminder_id IS NOT NULL
+                 GROUP BY reminder_id
+             ) latest ON d.reminder_id = latest.reminder_id AND d.created_at = latest.created_at
+             WHERE d.status IN ('sent', 'failed')
+               AND d.created_at < $1
+               AND NOT EXISTS (
+                   SELECT 1 FROM message_deliveries r
+                   WHERE r.reminder_id = d.reminder_id AND r.status IN ('delivered', 'read')
+               )
+             ORDER BY d.created_at",
+        )
+        .bind(sent_before.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let reminders = rows
+            .into_iter()
+            .map(|row| {
+                Ok(UndeliveredReminder {
+                    reminder_id: parse_reminder_id(&row.reminder_id)?,
+                    source: parse_source(&row.source)?,
+                    recipient: row.recipient,
+                    attempts: u32::try_from(row.attempts).unwrap_or(u32::MAX),
+                })
+            })
+            .collect::<Result<Vec<_>, ApplicationError>>()?;
+
+        debug!(count = reminders.len(), "Found undelivered reminders");
+        Ok(reminders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::persistence::async_connection::AsyncDatabase;
+
+    async fn setup() -> (AsyncDatabase, SqliteDeliveryStatusStore) {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let store = SqliteDeliveryStatusStore::new(db.pool().clone());
+        (db, store)
+    }
+
+    fn update(message_id: &str, status: DeliveryStatus) -> DeliveryUpdate {
+        DeliveryUpdate::new(message_id, MessengerSource::WhatsApp, status, Utc::now())
+    }
+
+    #[tokio::test]
+    async fn record_sent_and_get() {
+        let (_db, store) = setup().await;
+        let reminder_id = ReminderId::new();
+
+        store
+            .record_sent(
+                "wamid.1",
+                MessengerSource::WhatsApp,
+                "+491701234567",
+                Some(reminder_id),
+            )
+            .await
+            .unwrap();
+
+        let delivery = store.get("wamid.1").await.unwrap().unwrap();
+        assert_eq!(delivery.status, DeliveryStatus::Sent);
+        assert_eq!(delivery.source, MessengerSource::WhatsApp);
+        assert_eq!(delivery.recipient.as_deref(), Some("+491701234567"));
+        assert_eq!(delivery.reminder_id, Some(reminder_id));
+        assert!(store.get("wamid.unknown").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn status_progresses_and_never_regresses() {
+        let (_db, store) = setup().await;
+        store
+            .record_sent("wamid.1", MessengerSource::WhatsApp, "+491701234567", None)
+            .await
+            .unwrap();
+
+        assert!(
+            store
+                .apply_update(&update("wamid.1", DeliveryStatus::Read))
+                .await
+                .unwrap()
+        );
+        // Delivered arrives after read
+        assert!(
+            !store
+                .apply_update(&update("wamid.1", DeliveryStatus::Delivered))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !store
+                .apply_update(&update("wamid.1", DeliveryStatus::Failed))
+                .await
+                .unwrap()
+        );
+
+        let delivery = store.get("wamid.1").await.unwrap().unwrap();
+        assert_eq!(delivery.status, DeliveryStatus::Read);
+    }
+
+    #[tokio::test]
+    async fn failed_update_keeps_error() {
+        let (_db, store) = setup().await;
+        store
+            .record_sent("wamid.1", MessengerSource::WhatsApp, "+491701234567", None)
+            .await
+            .unwrap();
+
+        let failed = update("wamid.1", DeliveryStatus::Failed).with_error("Message undeliverable");
+        assert!(store.apply_update(&failed).await.unwrap());
+
+        let delivery = store.get("wamid.1").await.unwrap().unwrap();
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.error.as_deref(), Some("Message undeliverable"));
+    }
+
+    #[tokio::test]
+    async fn receipt_before_send_is_kept() {
+        let (_db, store) = setup().await;
+        let reminder_id = ReminderId::new();
+
+        assert!(
+            store
+                .apply_update(&update("wamid.1", DeliveryStatus::Delivered))
+                .await
+                .unwrap()
+        );
+        store
+            .record_sent(
+                "wamid.1",
+                MessengerSource::WhatsApp,
+                "+491701234567",
+                Some(reminder_id),
+            )
+            .await
+            .unwrap();
+
+        let delivery = store.get("wamid.1").await.unwrap().unwrap();
+        assert_eq!(delivery.status, DeliveryStatus::Delivered);
+        assert_eq!(delivery.recipient.as_deref(), Some("+491701234567"));
+        assert_eq!(delivery.reminder_id, Some(reminder_id));
+    }
+
+    #[tokio::test]
+    async fn undelivered_reminders_uses_latest_attempt() {
+        let (_db, store) = setup().await;
+        let pending = ReminderId::new();
+        let delivered = ReminderId::new();
+
+        for (message_id, reminder_id) in [("m1", pending), ("m2", pending), ("m3", delivered)] {
+            store
+                .record_sent(
+                    message_id,
+                    MessengerSource::Signal,
+                    "+491701234567",
+                    Some(reminder_id),
+                )
+                .await
+                .unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        store
+            .apply_update(&DeliveryUpdate::new(
+                "m3",
+                MessengerSource::Signal,
+                DeliveryStatus::Delivered,
+                Utc::now(),
+            ))
+            .await
+            .unwrap();
+
+        let undelivered = store
+            .undelivered_reminders(Utc::now() + Duration::seconds(1))
+            .await
+            .unwrap();
+        assert_eq!(undelivered.len(), 1);
+        assert_eq!(undelivered[0].reminder_id, pending);
+        assert_eq!(undelivered[0].source, MessengerSource::Signal);
+        assert_eq!(undelivered[0].attempts, 2);
+
+        let too_recent = store
+            .undelivered_reminders(Utc::now() - Duration::minutes(5))
+            .await
+            .unwrap();
+        assert!(too_recent.is_empty());
+    }
+}
@@ -4,8 +4,8 @@
 
 use application::{error::ApplicationError, ports::DraftStorePort};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use domain::{DraftId, EmailAddress, PersistedEmailDraft, UserId};
+use chrono::{DateTime, Duration, Utc};
+use domain::{DEFAULT_DRAFT_TTL_DAYS, DraftId, EmailAddress, PersistedEmailDraft, UserId};
 use sqlx::SqlitePool;
 use tracing::{debug, instrument};
 use uuid::Uuid;
@@ -16,13 +16,24 @@ use super::error::map_sqlx_error;
 #[derive(Debug, Clone)]
 pub struct SqliteDraftStore {
     pool: SqlitePool,
+    ttl: Duration,
 }
 
 impl SqliteDraftStore {
-    /// Create a new SQLite draft store
+    /// Create a new SQLite draft store with the default TTL
     #[must_use]
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            ttl: Duration::days(DEFAULT_DRAFT_TTL_DAYS),
+        }
+    }
+
+    /// Set the maximum draft age enforced by cleanup
+    #[must_use]
+    pub fn with_ttl_days(mut self, days: u32) -> Self {
+        self.ttl = Duration::days(i64::from(days));
+        self
     }
 }
 
@@ -35,6 +46,7 @@ struct DraftRow {
     cc: Option<String>,
     subject: String,
     body: String,
+    html_body: Option<String>,
     created_at: String,
     expires_at: String,
 }
@@ -71,6 +83,7 @@ impl DraftRow {
             cc,
             subject: self.subject,
             body: self.body,
+            html_body: self.html_body,
             created_at,
             expires_at,
         }
@@ -95,8 +108,8 @@ impl DraftStorePort for SqliteDraftStore {
         };
 
         sqlx::query(
-            "INSERT INTO email_drafts (id, user_id, to_address, cc, subject, body, created_at, expires_at)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            "INSERT INTO email_drafts (id, user_id, to_address, cc, subject, body, html_body, created_at, expires_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
         )
         .bind(draft.id.to_string())
         .bind(draft.user_id.to_string())
@@ -104,6 +117,7 @@ impl DraftStorePort for SqliteDraftStore {
         .bind(&cc_str)
         .bind(&draft.subject)
         .bind(&draft.body)
+        .bind(&draft.html_body)
         .bind(draft.created_at.to_rfc3339())
         .bind(draft.expires_at.to_rfc3339())
         .execute(&self.pool)
@@ -117,7 +131,7 @@ impl DraftStorePort for SqliteDraftStore {
     #[instrument(skip(self), fields(draft_id = %id))]
     async fn get(&self, id: &DraftId) -> Result<Option<PersistedEmailDraft>, ApplicationError> {
         let row: Option<DraftRow> = sqlx::query_as(
-            "SELECT id, user_id, to_address, cc, subject, body, created_at, expires_at
+            "SELECT id, user_id, to_address, cc, subject, body, html_body, created_at, expires_at
              FROM email_drafts WHERE id = $1",
         )
         .bind(id.to_string())
@@ -136,7 +150,7 @@ impl DraftStorePort for SqliteDraftStore {
         user_id: &UserId,
     ) -> Result<Option<PersistedEmailDraft>, ApplicationError> {
         let row: Option<DraftRow> = sqlx::query_as(
-            "SELECT id, user_id, to_address, cc, subject, body, created_at, expires_at
+            "SELECT id, user_id, to_address, cc, subject, body, html_body, created_at, expires_at
              FROM email_drafts WHERE id = $1 AND user_id = $2",
         )
         .bind(id.to_string())
@@ -171,14 +185,16 @@ impl DraftStorePort for SqliteDraftStore {
     }
 
     #[instrument(skip(self))]
-    async fn cleanup_expired(&self) -> Result<usize, ApplicationError> {
-        let now = Utc::now().to_rfc3339();
-
-        let result = sqlx::query("DELETE FROM email_drafts WHERE expires_at <= $1")
-            .bind(&now)
-            .execute(&self.pool)
-            .await
-            .map_err(map_sqlx_error)?;
+    async fn cleanup_expired(&self, now: DateTime<Utc>) -> Result<usize, ApplicationError> {
+        let cutoff = (now - self.ttl).to_rfc3339();
+
+        let result =
+            sqlx::query("DELETE FROM email_drafts WHERE expires_at <= $1 OR created_at <= $2")
+                .bind(now.to_rfc3339())
+                .bind(&cutoff)
+                .execute(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
 
         #[allow(clippy::cast_possible_truncation)]
         let deleted = result.rows_affected() as usize;
@@ -198,7 +214,7 @@ impl SqliteDraftStore {
 
         #[allow(clippy::cast_possible_wrap)]
         let rows: Vec<DraftRow> = sqlx::query_as(
-            "SELECT id, user_id, to_address, cc, subject, body, created_at, expires_at
+            "SELECT id, user_id, to_address, cc, subject, body, html_body, created_at, expires_at
              FROM email_drafts
              WHERE user_id = $1 AND expires_at > $2
              ORDER BY created_at DESC
@@ -219,7 +235,6 @@ impl SqliteDraftStore {
 mod tests {
     use super::*;
     use crate::persistence::async_connection::AsyncDatabase;
-    use chrono::Duration;
 
     async fn setup() -> (AsyncDatabase, SqliteDraftStore) {
         let db = AsyncDatabase::in_memory().await.unwrap();
@@ -263,6 +278,20 @@ mod tests {
         assert_eq!(retrieved.body, "Test body");
     }
 
+    #[tokio::test]
+    async fn html_body_roundtrips() {
+        let (_db, store) = setup().await;
+        let draft =
+            PersistedEmailDraft::new(test_user_id(), email("r@example.com"), "Test", "Body")
+                .with_html_body("<p>Body</p>");
+
+        store.save(&draft).await.unwrap();
+
+        let retrieved = store.get(&draft.id).await.unwrap().unwrap();
+        assert_eq!(retrieved.body, "Body");
+        assert_eq!(retrieved.html_body.as_deref(), Some("<p>Body</p>"));
+    }
+
     #[tokio::test]
     async fn get_nonexistent_draft_returns_none() {
         let (_db, store) = setup().await;
@@ -394,10 +423,31 @@ mod tests {
             store.save(&expired).await.unwrap();
         }
 
-        let deleted = store.cleanup_expired().await.unwrap();
+        let deleted = store.cleanup_expired(Utc::now()).await.unwrap();
         assert_eq!(deleted, 3);
     }
 
+    #[tokio::test]
+    async fn cleanup_removes_only_drafts_past_ttl() {
+        let (db, _) = setup().await;
+        let store = SqliteDraftStore::new(db.pool().clone()).with_ttl_days(2);
+        let user_id = test_user_id();
+
+        let fresh = PersistedEmailDraft::new(user_id, email("r@example.com"), "Fresh", "Body");
+        store.save(&fresh).await.unwrap();
+
+        // Still within its own expiry, but older than the configured TTL
+        let mut stale = PersistedEmailDraft::new(user_id, email("r@example.com"), "Stale", "Body");
+        stale.created_at = Utc::now() - Duration::days(3);
+        let stale_id = stale.id;
+        store.save(&stale).await.unwrap();
+
+        let deleted = store.cleanup_expired(Utc::now()).await.unwrap();
+        assert_eq!(deleted, 1);
+        assert!(store.get(&fresh.id).await.unwrap().is_some());
+        assert!(store.get(&stale_id).await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn draft_with_cc_recipients() {
         let (_db, store) = setup().await;
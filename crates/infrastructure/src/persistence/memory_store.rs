@@ -9,7 +9,9 @@ use application::{
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use domain::{Memory, MemoryId, MemoryQuery, MemoryType, UserId, cosine_similarity};
+use domain::{
+    Memory, MemoryId, MemoryQuery, MemoryType, TenantContext, TenantId, UserId, cosine_similarity,
+};
 use sqlx::SqlitePool;
 use tracing::{debug, instrument};
 
@@ -33,6 +35,7 @@ impl SqliteMemoryStore {
 #[derive(sqlx::FromRow)]
 struct MemoryRow {
     id: String,
+    tenant_id: String,
     user_id: String,
     conversation_id: Option<String>,
     content: String,
@@ -50,6 +53,7 @@ impl MemoryRow {
     #[allow(clippy::wrong_self_convention)]
     fn to_memory(self) -> Memory {
         let id = MemoryId::parse(&self.id).unwrap_or_else(|_| MemoryId::new());
+        let tenant_id = TenantId::parse(&self.tenant_id).unwrap_or_default();
         let user_id = UserId::parse(&self.user_id).unwrap_or_else(|_| UserId::new());
         let conversation_id = self
             .conversation_id
@@ -72,6 +76,7 @@ impl MemoryRow {
 
         let mut memory = Memory::new(user_id, self.content, self.summary, memory_type)
             .with_id(id)
+            .with_tenant(tenant_id)
             .with_importance(importance)
             .with_tags(tags)
             .with_created_at(created_at)
@@ -106,14 +111,15 @@ struct EmbeddingRow {
     importance: f64,
 }
 
-const SELECT_MEMORY: &str = "SELECT m.id, m.user_id, m.conversation_id, m.content, m.summary, \
-                              m.importance, m.memory_type, m.tags, m.created_at, m.accessed_at, \
-                              m.access_count, e.embedding
-                              FROM memories m
-                              LEFT JOIN memory_embeddings e ON m.id = e.memory_id";
+const SELECT_MEMORY: &str =
+    "SELECT m.id, m.tenant_id, m.user_id, m.conversation_id, m.content, m.summary, \
+     m.importance, m.memory_type, m.tags, m.created_at, m.accessed_at, \
+     m.access_count, e.embedding
+     FROM memories m
+     LEFT JOIN memory_embeddings e ON m.id = e.memory_id";
 
 const SELECT_MEMORY_INNER: &str =
-    "SELECT m.id, m.user_id, m.conversation_id, m.content, m.summary, \
+    "SELECT m.id, m.tenant_id, m.user_id, m.conversation_id, m.content, m.summary, \
      m.importance, m.memory_type, m.tags, m.created_at, m.accessed_at, \
      m.access_count, e.embedding
      FROM memories m
@@ -127,11 +133,12 @@ impl MemoryStore for SqliteMemoryStore {
             .map_err(|e| ApplicationError::Internal(format!("Failed to serialize tags: {e}")))?;
 
         sqlx::query(
-            "INSERT INTO memories (id, user_id, conversation_id, content, summary, importance, \
-             memory_type, tags, created_at, accessed_at, access_count)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+            "INSERT INTO memories (id, tenant_id, user_id, conversation_id, content, summary, \
+             importance, memory_type, tags, created_at, accessed_at, access_count)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
         )
         .bind(memory.id.to_string())
+        .bind(memory.tenant_id.to_string())
         .bind(memory.user_id.to_string())
         .bind(memory.conversation_id.map(|c| c.to_string()))
         .bind(&memory.content)
@@ -169,11 +176,16 @@ impl MemoryStore for SqliteMemoryStore {
         Ok(())
     }
 
-    #[instrument(skip(self), fields(memory_id = %id))]
-    async fn get(&self, id: &MemoryId) -> Result<Option<Memory>, ApplicationError> {
-        let sql = format!("{SELECT_MEMORY} WHERE m.id = $1");
+    #[instrument(skip(self, tenant), fields(tenant_id = %tenant.tenant_id(), memory_id = %id))]
+    async fn get(
+        &self,
+        tenant: &TenantContext,
+        id: &MemoryId,
+    ) -> Result<Option<Memory>, ApplicationError> {
+        let sql = format!("{SELECT_MEMORY} WHERE m.id = $1 AND m.tenant_id = $2");
         let row: Option<MemoryRow> = sqlx::query_as(&sql)
             .bind(id.to_string())
+            .bind(tenant.tenant_id().to_string())
             .fetch_optional(&self.pool)
             .await
             .map_err(map_sqlx_error)?;
@@ -181,15 +193,22 @@ impl MemoryStore for SqliteMemoryStore {
         Ok(row.map(MemoryRow::to_memory))
     }
 
-    #[instrument(skip(self, memory), fields(memory_id = %memory.id))]
-    async fn update(&self, memory: &Memory) -> Result<(), ApplicationError> {
+    #[instrument(
+        skip(self, tenant, memory),
+        fields(tenant_id = %tenant.tenant_id(), memory_id = %memory.id)
+    )]
+    async fn update(
+        &self,
+        tenant: &TenantContext,
+        memory: &Memory,
+    ) -> Result<(), ApplicationError> {
         let tags_json = serde_json::to_string(&memory.tags)
             .map_err(|e| ApplicationError::Internal(format!("Failed to serialize tags: {e}")))?;
 
-        sqlx::query(
+        let result = sqlx::query(
             "UPDATE memories SET content = $1, summary = $2, importance = $3, memory_type = $4,
              tags = $5, accessed_at = $6, access_count = $7
-             WHERE id = $8",
+             WHERE id = $8 AND tenant_id = $9",
         )
         .bind(&memory.content)
         .bind(&memory.summary)
@@ -199,10 +218,17 @@ impl MemoryStore for SqliteMemoryStore {
         .bind(memory.accessed_at.to_rfc3339())
         .bind(memory.access_count)
         .bind(memory.id.to_string())
+        .bind(tenant.tenant_id().to_string())
         .execute(&self.pool)
         .await
         .map_err(map_sqlx_error)?;
 
+        // Never touch the embedding of a memory owned by another tenant
+        if result.rows_affected() == 0 {
+            debug!("No memory of the tenant to update");
+            return Ok(());
+        }
+
         // Upsert embedding if present
         if let Some(ref embedding) = memory.embedding {
             let embedding_bytes = embedding_to_bytes(embedding);
@@ -226,20 +252,26 @@ impl MemoryStore for SqliteMemoryStore {
         Ok(())
     }
 
-    #[instrument(skip(self), fields(memory_id = %id))]
-    async fn delete(&self, id: &MemoryId) -> Result<(), ApplicationError> {
+    #[instrument(skip(self, tenant), fields(tenant_id = %tenant.tenant_id(), memory_id = %id))]
+    async fn delete(&self, tenant: &TenantContext, id: &MemoryId) -> Result<(), ApplicationError> {
         let id_str = id.to_string();
+        let tenant_str = tenant.tenant_id().to_string();
 
         // Delete embedding first
-        sqlx::query("DELETE FROM memory_embeddings WHERE memory_id = $1")
-            .bind(&id_str)
-            .execute(&self.pool)
-            .await
-            .map_err(map_sqlx_error)?;
+        sqlx::query(
+            "DELETE FROM memory_embeddings WHERE memory_id IN \
+             (SELECT id FROM memories WHERE id = $1 AND tenant_id = $2)",
+        )
+        .bind(&id_str)
+        .bind(&tenant_str)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
 
         // Delete memory
-        sqlx::query("DELETE FROM memories WHERE id = $1")
+        sqlx::query("DELETE FROM memories WHERE id = $1 AND tenant_id = $2")
             .bind(&id_str)
+            .bind(&tenant_str)
             .execute(&self.pool)
             .await
             .map_err(map_sqlx_error)?;
@@ -248,9 +280,13 @@ impl MemoryStore for SqliteMemoryStore {
         Ok(())
     }
 
-    #[instrument(skip(self, embedding), fields(user_id = %user_id, limit = limit))]
+    #[instrument(
+        skip(self, tenant, embedding),
+        fields(tenant_id = %tenant.tenant_id(), user_id = %user_id, limit = limit)
+    )]
     async fn search_similar(
         &self,
+        tenant: &TenantContext,
         user_id: &UserId,
         embedding: &[f32],
         limit: usize,
@@ -262,8 +298,9 @@ impl MemoryStore for SqliteMemoryStore {
             "SELECT e.memory_id, e.embedding, m.importance
              FROM memory_embeddings e
              INNER JOIN memories m ON e.memory_id = m.id
-             WHERE m.user_id = $1",
+             WHERE m.tenant_id = $1 AND m.user_id = $2",
         )
+        .bind(tenant.tenant_id().to_string())
         .bind(user_id.to_string())
         .fetch_all(&self.pool)
         .await
@@ -303,12 +340,13 @@ impl MemoryStore for SqliteMemoryStore {
         let placeholders: String = candidates
             .iter()
             .enumerate()
-            .map(|(i, _)| format!("${}", i + 1))
+            .map(|(i, _)| format!("${}", i + 2))
             .collect::<Vec<_>>()
             .join(", ");
-        let sql = format!("{SELECT_MEMORY_INNER} WHERE m.id IN ({placeholders})");
+        let sql =
+            format!("{SELECT_MEMORY_INNER} WHERE m.tenant_id = $1 AND m.id IN ({placeholders})");
 
-        let mut query = sqlx::query_as::<_, MemoryRow>(&sql);
+        let mut query = sqlx::query_as::<_, MemoryRow>(&sql).bind(tenant.tenant_id().to_string());
         for (id, _) in &candidates {
             query = query.bind(id);
         }
@@ -338,8 +376,12 @@ impl MemoryStore for SqliteMemoryStore {
 
     #[instrument(skip(self, query))]
     async fn list(&self, query: &MemoryQuery) -> Result<Vec<Memory>, ApplicationError> {
-        let mut sql = format!("{SELECT_MEMORY} WHERE 1=1");
-        let mut binds: Vec<String> = Vec::new();
+        let tenant_id = query.tenant_id.ok_or_else(|| {
+            ApplicationError::NotAuthorized("memory query requires a tenant context".to_string())
+        })?;
+
+        let mut sql = format!("{SELECT_MEMORY} WHERE m.tenant_id = $1");
+        let mut binds: Vec<String> = vec![tenant_id.to_string()];
 
         if let Some(ref user_id) = query.user_id {
             binds.push(user_id.to_string());
@@ -397,14 +439,24 @@ impl MemoryStore for SqliteMemoryStore {
         Ok(memories)
     }
 
-    #[instrument(skip(self), fields(user_id = %user_id, memory_type = ?memory_type, limit = limit))]
+    #[instrument(
+        skip(self, tenant),
+        fields(
+            tenant_id = %tenant.tenant_id(),
+            user_id = %user_id,
+            memory_type = ?memory_type,
+            limit = limit
+        )
+    )]
     async fn list_by_type(
         &self,
+        tenant: &TenantContext,
         user_id: &UserId,
         memory_type: MemoryType,
         limit: usize,
     ) -> Result<Vec<Memory>, ApplicationError> {
         let query = MemoryQuery::new()
+            .for_tenant(tenant.tenant_id())
             .for_user(*user_id)
             .of_types(vec![memory_type])
             .limit(limit);
@@ -412,13 +464,20 @@ impl MemoryStore for SqliteMemoryStore {
         self.list(&query).await
     }
 
-    #[instrument(skip(self), fields(decay_rate = decay_rate))]
-    async fn apply_decay(&self, decay_rate: f32) -> Result<Vec<MemoryId>, ApplicationError> {
-        let rows: Vec<DecayRow> =
-            sqlx::query_as("SELECT id, importance, accessed_at, access_count FROM memories")
-                .fetch_all(&self.pool)
-                .await
-                .map_err(map_sqlx_error)?;
+    #[instrument(skip(self, tenant), fields(tenant_id = %tenant.tenant_id(), decay_rate = decay_rate))]
+    async fn apply_decay(
+        &self,
+        tenant: &TenantContext,
+        decay_rate: f32,
+    ) -> Result<Vec<MemoryId>, ApplicationError> {
+        let tenant_str = tenant.tenant_id().to_string();
+        let rows: Vec<DecayRow> = sqlx::query_as(
+            "SELECT id, importance, accessed_at, access_count FROM memories WHERE tenant_id = $1",
+        )
+        .bind(&tenant_str)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
 
         let mut below_threshold = Vec::new();
 
@@ -441,9 +500,10 @@ impl MemoryStore for SqliteMemoryStore {
             let access_boost = (row.access_count as f32 * 0.01).min(0.1);
             new_importance = (new_importance + access_boost).min(1.0);
 
-            sqlx::query("UPDATE memories SET importance = $1 WHERE id = $2")
+            sqlx::query("UPDATE memories SET importance = $1 WHERE id = $2 AND tenant_id = $3")
                 .bind(f64::from(new_importance))
                 .bind(&row.id)
+                .bind(&tenant_str)
                 .execute(&self.pool)
                 .await
                 .map_err(map_sqlx_error)?;
@@ -459,21 +519,29 @@ impl MemoryStore for SqliteMemoryStore {
         Ok(below_threshold)
     }
 
-    #[instrument(skip(self), fields(threshold = threshold))]
-    async fn cleanup_below_threshold(&self, threshold: f32) -> Result<usize, ApplicationError> {
+    #[instrument(skip(self, tenant), fields(tenant_id = %tenant.tenant_id(), threshold = threshold))]
+    async fn cleanup_below_threshold(
+        &self,
+        tenant: &TenantContext,
+        threshold: f32,
+    ) -> Result<usize, ApplicationError> {
+        let tenant_str = tenant.tenant_id().to_string();
+
         // Delete embeddings for memories below threshold first
         sqlx::query(
             "DELETE FROM memory_embeddings WHERE memory_id IN \
-             (SELECT id FROM memories WHERE importance < $1)",
+             (SELECT id FROM memories WHERE importance < $1 AND tenant_id = $2)",
         )
         .bind(f64::from(threshold))
+        .bind(&tenant_str)
         .execute(&self.pool)
         .await
         .map_err(map_sqlx_error)?;
 
         // Delete memories below threshold
-        let result = sqlx::query("DELETE FROM memories WHERE importance < $1")
+        let result = sqlx::query("DELETE FROM memories WHERE importance < $1 AND tenant_id = $2")
             .bind(f64::from(threshold))
+            .bind(&tenant_str)
             .execute(&self.pool)
             .await
             .map_err(map_sqlx_error)?;
@@ -491,29 +559,45 @@ impl MemoryStore for SqliteMemoryStore {
         similarity_threshold: f32,
     ) -> Result<Vec<SimilarMemory>, ApplicationError> {
         if let Some(ref embedding) = memory.embedding {
-            self.search_similar(&memory.user_id, embedding, 10, similarity_threshold)
-                .await
+            let tenant = TenantContext::new(memory.tenant_id);
+            self.search_similar(
+                &tenant,
+                &memory.user_id,
+                embedding,
+                10,
+                similarity_threshold,
+            )
+            .await
         } else {
             Ok(Vec::new())
         }
     }
 
-    #[instrument(skip(self), fields(user_id = %user_id))]
-    async fn stats(&self, user_id: &UserId) -> Result<MemoryStats, ApplicationError> {
+    #[instrument(skip(self, tenant), fields(tenant_id = %tenant.tenant_id(), user_id = %user_id))]
+    async fn stats(
+        &self,
+        tenant: &TenantContext,
+        user_id: &UserId,
+    ) -> Result<MemoryStats, ApplicationError> {
+        let tenant_str = tenant.tenant_id().to_string();
         let user_id_str = user_id.to_string();
 
-        let total_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM memories WHERE user_id = $1")
-                .bind(&user_id_str)
-                .fetch_one(&self.pool)
-                .await
-                .map_err(map_sqlx_error)?;
+        let total_count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM memories WHERE tenant_id = $1 AND user_id = $2",
+        )
+        .bind(&tenant_str)
+        .bind(&user_id_str)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
 
         let mut by_type = Vec::new();
         for memory_type in MemoryType::all() {
             let count: i64 = sqlx::query_scalar(
-                "SELECT COUNT(*) FROM memories WHERE user_id = $1 AND memory_type = $2",
+                "SELECT COUNT(*) FROM memories \
+                 WHERE tenant_id = $1 AND user_id = $2 AND memory_type = $3",
             )
+            .bind(&tenant_str)
             .bind(&user_id_str)
             .bind(memory_type_to_str(*memory_type))
             .fetch_one(&self.pool)
@@ -527,16 +611,19 @@ impl MemoryStore for SqliteMemoryStore {
         let with_embeddings: i64 = sqlx::query_scalar(
             "SELECT COUNT(*) FROM memories m
              INNER JOIN memory_embeddings e ON m.id = e.memory_id
-             WHERE m.user_id = $1",
+             WHERE m.tenant_id = $1 AND m.user_id = $2",
         )
+        .bind(&tenant_str)
         .bind(&user_id_str)
         .fetch_one(&self.pool)
         .await
         .map_err(map_sqlx_error)?;
 
         let avg_importance: f64 = sqlx::query_scalar(
-            "SELECT COALESCE(AVG(importance), 0.0) FROM memories WHERE user_id = $1",
+            "SELECT COALESCE(AVG(importance), 0.0) FROM memories \
+             WHERE tenant_id = $1 AND user_id = $2",
         )
+        .bind(&tenant_str)
         .bind(&user_id_str)
         .fetch_one(&self.pool)
         .await
@@ -551,13 +638,19 @@ impl MemoryStore for SqliteMemoryStore {
         })
     }
 
-    #[instrument(skip(self), fields(memory_id = %id))]
-    async fn record_access(&self, id: &MemoryId) -> Result<(), ApplicationError> {
+    #[instrument(skip(self, tenant), fields(tenant_id = %tenant.tenant_id(), memory_id = %id))]
+    async fn record_access(
+        &self,
+        tenant: &TenantContext,
+        id: &MemoryId,
+    ) -> Result<(), ApplicationError> {
         sqlx::query(
-            "UPDATE memories SET accessed_at = $1, access_count = access_count + 1 WHERE id = $2",
+            "UPDATE memories SET accessed_at = $1, access_count = access_count + 1 \
+             WHERE id = $2 AND tenant_id = $3",
         )
         .bind(Utc::now().to_rfc3339())
         .bind(id.to_string())
+        .bind(tenant.tenant_id().to_string())
         .execute(&self.pool)
         .await
         .map_err(map_sqlx_error)?;
@@ -648,11 +741,12 @@ mod tests {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
         let memory = make_memory(user_id);
         let id = memory.id;
 
         store.save(&memory).await.unwrap();
-        let found = store.get(&id).await.unwrap();
+        let found = store.get(&tenant, &id).await.unwrap();
         assert!(found.is_some());
         let found = found.unwrap();
         assert_eq!(found.content, "Test content");
@@ -664,11 +758,12 @@ mod tests {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
         let memory = make_memory(user_id).with_embedding(vec![0.1, 0.2, 0.3]);
         let id = memory.id;
 
         store.save(&memory).await.unwrap();
-        let found = store.get(&id).await.unwrap().unwrap();
+        let found = store.get(&tenant, &id).await.unwrap().unwrap();
         assert!(found.embedding.is_some());
         let emb = found.embedding.unwrap();
         assert_eq!(emb.len(), 3);
@@ -680,13 +775,14 @@ mod tests {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
         let mut memory = make_memory(user_id);
         store.save(&memory).await.unwrap();
 
         memory = memory.with_importance(0.9);
-        store.update(&memory).await.unwrap();
+        store.update(&tenant, &memory).await.unwrap();
 
-        let found = store.get(&memory.id).await.unwrap().unwrap();
+        let found = store.get(&tenant, &memory.id).await.unwrap().unwrap();
         assert!((found.importance - 0.9).abs() < 0.01);
     }
 
@@ -695,12 +791,13 @@ mod tests {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
         let memory = make_memory(user_id);
         let id = memory.id;
 
         store.save(&memory).await.unwrap();
-        store.delete(&id).await.unwrap();
-        assert!(store.get(&id).await.unwrap().is_none());
+        store.delete(&tenant, &id).await.unwrap();
+        assert!(store.get(&tenant, &id).await.unwrap().is_none());
     }
 
     #[tokio::test]
@@ -713,11 +810,24 @@ mod tests {
             store.save(&make_memory(user_id)).await.unwrap();
         }
 
-        let query = MemoryQuery::new().for_user(user_id);
+        let query = MemoryQuery::new()
+            .for_tenant(TenantId::default())
+            .for_user(user_id);
         let results = store.list(&query).await.unwrap();
         assert_eq!(results.len(), 3);
     }
 
+    #[tokio::test]
+    async fn list_without_tenant_is_rejected() {
+        let (db, store) = setup().await;
+        let user_id = UserId::new();
+        ensure_user(&db, &user_id).await;
+        store.save(&make_memory(user_id)).await.unwrap();
+
+        let result = store.list(&MemoryQuery::new().for_user(user_id)).await;
+        assert!(matches!(result, Err(ApplicationError::NotAuthorized(_))));
+    }
+
     #[tokio::test]
     async fn list_by_type() {
         let (db, store) = setup().await;
@@ -734,7 +844,12 @@ mod tests {
         store.save(&pref).await.unwrap();
 
         let facts = store
-            .list_by_type(&user_id, MemoryType::Fact, 10)
+            .list_by_type(
+                &TenantContext::single_tenant(),
+                &user_id,
+                MemoryType::Fact,
+                10,
+            )
             .await
             .unwrap();
         assert_eq!(facts.len(), 1);
@@ -755,7 +870,13 @@ mod tests {
 
         let query_emb = vec![1.0, 0.0, 0.0];
         let results = store
-            .search_similar(&user_id, &query_emb, 10, 0.5)
+            .search_similar(
+                &TenantContext::single_tenant(),
+                &user_id,
+                &query_emb,
+                10,
+                0.5,
+            )
             .await
             .unwrap();
 
@@ -763,16 +884,86 @@ mod tests {
         assert!(results.len() >= 2);
     }
 
+    #[tokio::test]
+    async fn tenants_cannot_read_each_others_memories() {
+        let (db, store) = setup().await;
+        let user_id = UserId::new();
+        ensure_user(&db, &user_id).await;
+        let tenant_a = TenantContext::new(TenantId::new());
+        let tenant_b = TenantContext::new(TenantId::new());
+
+        let memory_b = make_memory(user_id)
+            .with_tenant(tenant_b.tenant_id())
+            .with_embedding(vec![1.0, 0.0, 0.0]);
+        store.save(&memory_b).await.unwrap();
+
+        let similar = store
+            .search_similar(&tenant_a, &user_id, &[1.0, 0.0, 0.0], 10, 0.0)
+            .await
+            .unwrap();
+        assert!(similar.is_empty());
+
+        let listed = store
+            .list(&MemoryQuery::new().for_tenant(tenant_a.tenant_id()))
+            .await
+            .unwrap();
+        assert!(listed.is_empty());
+
+        let by_type = store
+            .list_by_type(&tenant_a, &user_id, MemoryType::Fact, 10)
+            .await
+            .unwrap();
+        assert!(by_type.is_empty());
+
+        let candidates = store
+            .find_merge_candidates(&memory_b.clone().with_tenant(tenant_a.tenant_id()), 0.0)
+            .await
+            .unwrap();
+        assert!(candidates.is_empty());
+
+        assert!(store.get(&tenant_a, &memory_b.id).await.unwrap().is_none());
+        let stats = store.stats(&tenant_a, &user_id).await.unwrap();
+        assert_eq!(stats.total_count, 0);
+
+        // Writes from another tenant leave the memory untouched
+        store
+            .update(&tenant_a, &memory_b.clone().with_importance(0.1))
+            .await
+            .unwrap();
+        store.record_access(&tenant_a, &memory_b.id).await.unwrap();
+        store.apply_decay(&tenant_a, 1.0).await.unwrap();
+        assert_eq!(
+            store.cleanup_below_threshold(&tenant_a, 1.0).await.unwrap(),
+            0
+        );
+        store.delete(&tenant_a, &memory_b.id).await.unwrap();
+
+        let untouched = store.get(&tenant_b, &memory_b.id).await.unwrap().unwrap();
+        assert!((untouched.importance - memory_b.importance).abs() < 0.01);
+        assert_eq!(untouched.access_count, 0);
+        assert!(untouched.embedding.is_some());
+
+        // The owning tenant still sees its memory
+        let own = store
+            .search_similar(&tenant_b, &user_id, &[1.0, 0.0, 0.0], 10, 0.0)
+            .await
+            .unwrap();
+        assert_eq!(own.len(), 1);
+        assert_eq!(own[0].memory.id, memory_b.id);
+        assert_eq!(own[0].memory.tenant_id, tenant_b.tenant_id());
+    }
+
     #[tokio::test]
     async fn stats() {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
 
         store.save(&make_memory(user_id)).await.unwrap();
         store.save(&make_memory(user_id)).await.unwrap();
 
-        let stats = store.stats(&user_id).await.unwrap();
+        let stats = store.stats(&tenant, &user_id).await.unwrap();
         assert_eq!(stats.total_count, 2);
     }
 
@@ -781,13 +972,14 @@ mod tests {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
         let memory = make_memory(user_id);
         let id = memory.id;
 
         store.save(&memory).await.unwrap();
-        store.record_access(&id).await.unwrap();
+        store.record_access(&tenant, &id).await.unwrap();
 
-        let found = store.get(&id).await.unwrap().unwrap();
+        let found = store.get(&tenant, &id).await.unwrap().unwrap();
         assert_eq!(found.access_count, 1);
     }
 
@@ -796,13 +988,14 @@ mod tests {
         let (db, store) = setup().await;
         let user_id = UserId::new();
         ensure_user(&db, &user_id).await;
+        let tenant = TenantContext::single_tenant();
 
         let low = make_memory(user_id).with_importance(0.01);
         let high = make_memory(user_id).with_importance(0.9);
         store.save(&low).await.unwrap();
         store.save(&high).await.unwrap();
 
-        let deleted = store.cleanup_below_threshold(0.1).await.unwrap();
+        let deleted = store.cleanup_below_threshold(&tenant, 0.1).await.unwrap();
         assert_eq!(deleted, 1);
     }
 
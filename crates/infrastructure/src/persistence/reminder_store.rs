@@ -9,7 +9,7 @@ use application::{
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::entities::{Reminder, ReminderSource, ReminderStatus};
-use domain::value_objects::{ReminderId, UserId};
+use domain::value_objects::{Recurrence, ReminderId, Timezone, UserId};
 use sqlx::SqlitePool;
 use tracing::{debug, instrument};
 use uuid::Uuid;
@@ -45,6 +45,8 @@ struct ReminderRow {
     status: String,
     snooze_count: i32,
     max_snooze: i32,
+    recurrence: Option<String>,
+    timezone: Option<String>,
     created_at: String,
     updated_at: String,
 }
@@ -64,6 +66,8 @@ impl ReminderRow {
         });
         let remind_at = DateTime::parse_from_rfc3339(&self.remind_at)
             .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
+        let recurrence = self.recurrence.and_then(|r| r.parse::<Recurrence>().ok());
+        let timezone = self.timezone.and_then(|tz| Timezone::try_new(tz).ok());
         let created_at = DateTime::parse_from_rfc3339(&self.created_at)
             .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
         let updated_at = DateTime::parse_from_rfc3339(&self.updated_at)
@@ -83,6 +87,8 @@ impl ReminderRow {
             status,
             snooze_count: self.snooze_count as u8,
             max_snooze: self.max_snooze as u8,
+            recurrence,
+            timezone,
             created_at,
             updated_at,
         }
@@ -91,7 +97,7 @@ impl ReminderRow {
 
 const SELECT_REMINDER: &str = "SELECT id, user_id, source, source_id, title, description, \
                                 event_time, remind_at, location, status, \
-                                snooze_count, max_snooze, created_at, updated_at
+                                snooze_count, max_snooze, recurrence, timezone, created_at, updated_at
                                 FROM reminders";
 
 #[async_trait]
@@ -102,8 +108,8 @@ impl ReminderPort for SqliteReminderStore {
             "INSERT INTO reminders (
                 id, user_id, source, source_id, title, description,
                 event_time, remind_at, location, status,
-                snooze_count, max_snooze, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+                snooze_count, max_snooze, recurrence, timezone, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)",
         )
         .bind(reminder.id.to_string())
         .bind(reminder.user_id.to_string())
@@ -117,6 +123,8 @@ impl ReminderPort for SqliteReminderStore {
         .bind(status_to_str(reminder.status))
         .bind(i32::from(reminder.snooze_count))
         .bind(i32::from(reminder.max_snooze))
+        .bind(reminder.recurrence.as_ref().map(ToString::to_string))
+        .bind(reminder.timezone.as_ref().map(Timezone::as_str))
         .bind(reminder.created_at.to_rfc3339())
         .bind(reminder.updated_at.to_rfc3339())
         .execute(&self.pool)
@@ -162,8 +170,9 @@ impl ReminderPort for SqliteReminderStore {
             "UPDATE reminders SET
                 title = $1, description = $2, event_time = $3,
                 remind_at = $4, location = $5, status = $6,
-                snooze_count = $7, max_snooze = $8, updated_at = $9
-             WHERE id = $10",
+                snooze_count = $7, max_snooze = $8, recurrence = $9,
+                timezone = $10, updated_at = $11
+             WHERE id = $12",
         )
         .bind(&reminder.title)
         .bind(&reminder.description)
@@ -173,6 +182,8 @@ impl ReminderPort for SqliteReminderStore {
         .bind(status_to_str(reminder.status))
         .bind(i32::from(reminder.snooze_count))
         .bind(i32::from(reminder.max_snooze))
+        .bind(reminder.recurrence.as_ref().map(ToString::to_string))
+        .bind(reminder.timezone.as_ref().map(Timezone::as_str))
         .bind(reminder.updated_at.to_rfc3339())
         .bind(reminder.id.to_string())
         .execute(&self.pool)
@@ -458,6 +469,49 @@ mod tests {
         assert_eq!(updated.status, ReminderStatus::Sent);
     }
 
+    #[tokio::test]
+    async fn recurrence_roundtrip_and_rearm() {
+        let (_db, store) = setup().await;
+        let mut reminder = Reminder::new(
+            test_user_id(),
+            ReminderSource::Custom,
+            "Standup",
+            Utc::now() - Duration::minutes(1),
+        )
+        .with_recurrence(Recurrence::Weekly(vec![chrono::Weekday::Mon]));
+        store.save(&reminder).await.unwrap();
+
+        let r = store.get(&reminder.id).await.unwrap().unwrap();
+        assert_eq!(
+            r.recurrence,
+            Some(Recurrence::Weekly(vec![chrono::Weekday::Mon]))
+        );
+
+        reminder.acknowledge();
+        store.update(&reminder).await.unwrap();
+
+        let r = store.get(&reminder.id).await.unwrap().unwrap();
+        assert_eq!(r.status, ReminderStatus::Pending);
+        assert!(r.remind_at > Utc::now());
+        assert!(r.is_recurring());
+    }
+
+    #[tokio::test]
+    async fn timezone_roundtrip() {
+        let (_db, store) = setup().await;
+        let reminder = Reminder::new(
+            test_user_id(),
+            ReminderSource::Custom,
+            "Standup",
+            Utc::now() + Duration::hours(1),
+        )
+        .with_timezone(Timezone::berlin());
+        store.save(&reminder).await.unwrap();
+
+        let r = store.get(&reminder.id).await.unwrap().unwrap();
+        assert_eq!(r.timezone, Some(Timezone::berlin()));
+    }
+
     #[tokio::test]
     async fn delete_reminder() {
         let (_db, store) = setup().await;
@@ -18,6 +18,11 @@
 //!                         └─────────────────┘     └─────────────────┘
 //! ```
 
+use application::{
+    error::ApplicationError,
+    ports::{DeadLetter, OutgoingTextMessage, QueuedMessage, RetryQueuePort},
+};
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
@@ -27,6 +32,11 @@ use uuid::Uuid;
 
 use crate::retry::RetryConfig;
 
+pub use application::ports::QueueStats;
+
+/// Operation type of queued outgoing messenger messages
+pub const MESSAGE_OPERATION: &str = "message";
+
 /// Error type for retry queue operations
 #[derive(Debug, Error)]
 pub enum RetryQueueError {
@@ -47,6 +57,15 @@ pub enum RetryQueueError {
     InvalidTransition { from: String, to: String },
 }
 
+impl From<RetryQueueError> for ApplicationError {
+    fn from(err: RetryQueueError) -> Self {
+        match err {
+            RetryQueueError::NotFound(id) => Self::NotFound(format!("Queue item not found: {id}")),
+            other => Self::Internal(other.to_string()),
+        }
+    }
+}
+
 /// Status of a retry queue item
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -178,6 +197,13 @@ impl RetryItem {
         self
     }
 
+    /// Record the error of the attempt that caused the item to be queued
+    #[must_use]
+    pub fn with_last_error(mut self, error: impl Into<String>) -> Self {
+        self.last_error = Some(error.into());
+        self
+    }
+
     /// Calculate next retry delay using exponential backoff
     #[allow(
         clippy::cast_precision_loss,
@@ -310,6 +336,21 @@ pub struct DeadLetterItem {
     pub tenant_id: Option<String>,
 }
 
+impl From<DeadLetterItem> for DeadLetter {
+    fn from(item: DeadLetterItem) -> Self {
+        Self {
+            id: item.id,
+            original_id: item.original_id,
+            operation_type: item.operation_type,
+            target: item.target,
+            attempt_count: item.attempt_count,
+            last_error: item.last_error,
+            created_at: item.created_at,
+            failed_at: item.failed_at,
+        }
+    }
+}
+
 /// Persistent retry queue store backed by SQLite (via sqlx)
 #[derive(Clone)]
 pub struct RetryQueueStore {
@@ -375,8 +416,26 @@ impl RetryQueueStore {
 
     /// Fetch items due for retry, marking them as in-progress
     #[instrument(skip(self))]
-    #[allow(clippy::cast_possible_wrap)]
     pub async fn fetch_due_items(&self, limit: usize) -> Result<Vec<RetryItem>, RetryQueueError> {
+        self.claim_due_items(None, limit).await
+    }
+
+    /// Fetch items of one operation type due for retry, marking them as in-progress
+    #[instrument(skip(self))]
+    pub async fn fetch_due_items_of_type(
+        &self,
+        operation_type: &str,
+        limit: usize,
+    ) -> Result<Vec<RetryItem>, RetryQueueError> {
+        self.claim_due_items(Some(operation_type), limit).await
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    async fn claim_due_items(
+        &self,
+        operation_type: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<RetryItem>, RetryQueueError> {
         let now = Utc::now().to_rfc3339();
 
         let rows: Vec<RetryRow> = sqlx::query_as(
@@ -385,10 +444,12 @@ impl RetryQueueStore {
                     correlation_id, user_id, tenant_id
              FROM retry_queue
              WHERE status = 'pending' AND next_retry_at <= $1
+               AND ($2 IS NULL OR operation_type = $2)
              ORDER BY next_retry_at ASC
-             LIMIT $2",
+             LIMIT $3",
         )
         .bind(&now)
+        .bind(operation_type)
         .bind(limit as i64)
         .fetch_all(&self.pool)
         .await?;
@@ -503,6 +564,7 @@ impl RetryQueueStore {
         error_message: &str,
     ) -> Result<(), RetryQueueError> {
         let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
 
         #[allow(clippy::cast_possible_wrap)]
         let _dlq_result = sqlx::query(
@@ -523,14 +585,15 @@ impl RetryQueueStore {
         .bind(&item.correlation_id)
         .bind(&item.user_id)
         .bind(&item.tenant_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
         sqlx::query("DELETE FROM retry_queue WHERE id = $1")
             .bind(&item.id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
+        tx.commit().await?;
         Ok(())
     }
 
@@ -578,9 +641,9 @@ impl RetryQueueStore {
         })
     }
 
-    /// Get items from the dead letter queue
+    /// List items in the dead letter queue, most recently failed first
     #[allow(clippy::cast_possible_wrap)]
-    pub async fn get_dead_letter_items(
+    pub async fn list_dead_letters(
         &self,
         limit: usize,
     ) -> Result<Vec<DeadLetterItem>, RetryQueueError> {
@@ -598,22 +661,31 @@ impl RetryQueueStore {
         Ok(rows.into_iter().map(DlqRow::to_item).collect())
     }
 
-    /// Requeue an item from the dead letter queue
+    /// Move a dead-lettered item back to the retry queue
+    ///
+    /// The item keeps its original ID, payload and tracing context, is due
+    /// immediately and gets a fresh retry budget (`max_retries` from the
+    /// store's retry config, attempt count reset to zero). The last error is
+    /// kept for reference until the next attempt overwrites it.
+    ///
+    /// Returns the ID of the requeued retry item.
     #[instrument(skip(self))]
-    pub async fn requeue_from_dlq(&self, dlq_id: &str) -> Result<String, RetryQueueError> {
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn reprocess_dead_letter(&self, dlq_id: &str) -> Result<String, RetryQueueError> {
+        let mut tx = self.pool.begin().await?;
+
         let row: DlqRow = sqlx::query_as(
             "SELECT id, original_id, operation_type, payload, target, attempt_count,
                     last_error, created_at, failed_at, correlation_id, user_id, tenant_id
              FROM dead_letter_queue WHERE id = $1",
         )
         .bind(dlq_id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await?
         .ok_or_else(|| RetryQueueError::NotFound(dlq_id.to_string()))?;
 
         let dlq_item = row.to_item();
         let now = Utc::now();
-        let new_id = Uuid::new_v4().to_string();
 
         sqlx::query(
             "INSERT INTO retry_queue (
@@ -622,30 +694,36 @@ impl RetryQueueStore {
                 correlation_id, user_id, tenant_id
             ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
         )
-        .bind(&new_id)
+        .bind(&dlq_item.original_id)
         .bind(&dlq_item.operation_type)
         .bind(&dlq_item.payload)
         .bind(&dlq_item.target)
         .bind(0_i32)
-        .bind(5_i32)
-        .bind(now.to_rfc3339())
-        .bind("pending")
-        .bind(Option::<String>::None)
+        .bind(self.retry_config.max_retries as i32)
         .bind(now.to_rfc3339())
+        .bind(RetryStatus::Pending.to_string())
+        .bind(&dlq_item.last_error)
+        .bind(dlq_item.created_at.to_rfc3339())
         .bind(now.to_rfc3339())
         .bind(&dlq_item.correlation_id)
         .bind(&dlq_item.user_id)
         .bind(&dlq_item.tenant_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
         sqlx::query("DELETE FROM dead_letter_queue WHERE id = $1")
             .bind(dlq_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
-        info!(dlq_id = %dlq_id, new_id = %new_id, "Requeued item from DLQ");
-        Ok(new_id)
+        tx.commit().await?;
+
+        info!(
+            dlq_id = %dlq_id,
+            id = %dlq_item.original_id,
+            "Reprocessing item from dead letter queue"
+        );
+        Ok(dlq_item.original_id)
     }
 
     /// Clean up old completed/cancelled items older than retention period
@@ -669,15 +747,64 @@ impl RetryQueueStore {
     }
 }
 
-/// Queue statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QueueStats {
-    /// Number of pending items
-    pub pending: u64,
-    /// Number of items currently being processed
-    pub in_progress: u64,
-    /// Number of items in dead letter queue
-    pub dead_letter: u64,
+#[async_trait]
+impl RetryQueuePort for RetryQueueStore {
+    async fn queue_stats(&self) -> Result<QueueStats, ApplicationError> {
+        Ok(self.get_stats().await?)
+    }
+
+    async fn enqueue_message(
+        &self,
+        message: &OutgoingTextMessage,
+        error: &str,
+    ) -> Result<String, ApplicationError> {
+        let payload = serde_json::to_string(message).map_err(RetryQueueError::from)?;
+        let item = RetryItem::new(MESSAGE_OPERATION, payload, message.recipient.to_string())
+            .with_max_retries(self.retry_config.max_retries)
+            .with_last_error(error);
+        Ok(self.enqueue(item).await?)
+    }
+
+    async fn due_messages(&self, limit: usize) -> Result<Vec<QueuedMessage>, ApplicationError> {
+        let items = self
+            .fetch_due_items_of_type(MESSAGE_OPERATION, limit)
+            .await?;
+
+        let mut messages = Vec::with_capacity(items.len());
+        for item in items {
+            match serde_json::from_str::<OutgoingTextMessage>(&item.payload) {
+                Ok(message) => messages.push(QueuedMessage {
+                    id: item.id,
+                    message,
+                    attempt_count: item.attempt_count,
+                }),
+                Err(e) => {
+                    // Retrying cannot fix a corrupt payload
+                    warn!(id = %item.id, error = %e, "Dead-lettering message with invalid payload");
+                    self.move_to_dlq(&item, &format!("Invalid message payload: {e}"))
+                        .await?;
+                },
+            }
+        }
+        Ok(messages)
+    }
+
+    async fn complete(&self, id: &str) -> Result<(), ApplicationError> {
+        Ok(self.mark_completed(id).await?)
+    }
+
+    async fn fail(&self, id: &str, error: &str) -> Result<bool, ApplicationError> {
+        Ok(self.mark_failed(id, error).await?)
+    }
+
+    async fn list_dead_letters(&self, limit: usize) -> Result<Vec<DeadLetter>, ApplicationError> {
+        let items = Self::list_dead_letters(self, limit).await?;
+        Ok(items.into_iter().map(DeadLetter::from).collect())
+    }
+
+    async fn requeue(&self, dead_letter_id: &str) -> Result<String, ApplicationError> {
+        Ok(self.reprocess_dead_letter(dead_letter_id).await?)
+    }
 }
 
 /// Parse ISO8601 datetime string
@@ -689,6 +816,7 @@ fn parse_datetime(s: &str) -> DateTime<Utc> {
 mod tests {
     use super::*;
     use crate::persistence::async_connection::AsyncDatabase;
+    use crate::retry::JitterStrategy;
 
     async fn setup() -> (AsyncDatabase, RetryQueueStore) {
         let db = AsyncDatabase::in_memory().await.unwrap();
@@ -740,6 +868,7 @@ mod tests {
                 max_retries: 5,
                 jitter_enabled: false,
                 jitter_factor: 0.0,
+                jitter: JitterStrategy::None,
             },
         );
 
@@ -770,30 +899,176 @@ mod tests {
         assert_eq!(stats.pending, 0);
         assert_eq!(stats.dead_letter, 1);
 
-        let dlq_items = store.get_dead_letter_items(10).await.unwrap();
+        let dlq_items = store.list_dead_letters(10).await.unwrap();
         assert_eq!(dlq_items.len(), 1);
         assert_eq!(dlq_items[0].original_id, id);
     }
 
     #[tokio::test]
-    async fn requeue_from_dlq() {
+    async fn reprocess_dead_letter() {
         let (_db, store) = setup().await;
 
-        let item = RetryItem::new("webhook", "{}", "https://example.com").with_max_retries(1);
+        let item = RetryItem::new("webhook", "{}", "https://example.com")
+            .with_max_retries(1)
+            .with_correlation_id("corr-1");
         let id = store.enqueue(item).await.unwrap();
 
         let _ = store.fetch_due_items(10).await.unwrap();
         store.mark_failed(&id, "Error").await.unwrap();
 
-        let dlq_items = store.get_dead_letter_items(10).await.unwrap();
+        let dlq_items = store.list_dead_letters(10).await.unwrap();
         let dlq_id = &dlq_items[0].id;
 
-        let new_id = store.requeue_from_dlq(dlq_id).await.unwrap();
-        assert_ne!(new_id, id);
+        let requeued_id = store.reprocess_dead_letter(dlq_id).await.unwrap();
+        assert_eq!(requeued_id, id);
 
         let stats = store.get_stats().await.unwrap();
         assert_eq!(stats.pending, 1);
         assert_eq!(stats.dead_letter, 0);
+
+        let due = store.fetch_due_items(10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].attempt_count, 0);
+        assert_eq!(due[0].max_retries, RetryConfig::default().max_retries);
+        assert_eq!(due[0].correlation_id.as_deref(), Some("corr-1"));
+        assert_eq!(due[0].last_error.as_deref(), Some("Error"));
+    }
+
+    #[tokio::test]
+    async fn reprocess_unknown_dead_letter() {
+        let (_db, store) = setup().await;
+
+        let result = store.reprocess_dead_letter("missing").await;
+        assert!(matches!(result, Err(RetryQueueError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn list_dead_letters_respects_limit() {
+        let (_db, store) = setup().await;
+
+        for _ in 0..3 {
+            let item = RetryItem::new("webhook", "{}", "https://example.com").with_max_retries(1);
+            let id = store.enqueue(item).await.unwrap();
+            let _ = store.fetch_due_items(10).await.unwrap();
+            store.mark_failed(&id, "Error").await.unwrap();
+        }
+
+        assert_eq!(store.list_dead_letters(2).await.unwrap().len(), 2);
+        assert_eq!(store.list_dead_letters(10).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn queue_stats_via_port() {
+        let (_db, store) = setup().await;
+        store
+            .enqueue(RetryItem::new("webhook", "{}", "https://example.com"))
+            .await
+            .unwrap();
+
+        let stats = RetryQueuePort::queue_stats(&store).await.unwrap();
+        assert_eq!(stats.pending, 1);
+        assert_eq!(stats.dead_letter, 0);
+    }
+
+    fn without_backoff(max_retries: u32) -> RetryConfig {
+        RetryConfig {
+            initial_delay_ms: 0,
+            max_delay_ms: 0,
+            multiplier: 1.0,
+            max_retries,
+            jitter_enabled: false,
+            jitter_factor: 0.0,
+            jitter: JitterStrategy::None,
+        }
+    }
+
+    fn test_message() -> OutgoingTextMessage {
+        OutgoingTextMessage::new(
+            domain::PhoneNumber::new("+491701234567").unwrap(),
+            "Your reminder",
+        )
+    }
+
+    #[tokio::test]
+    async fn message_exhausting_retries_is_dead_lettered() {
+        let (db, _) = setup().await;
+        let store = RetryQueueStore::with_config(db.pool().clone(), without_backoff(3));
+        let id = store
+            .enqueue_message(&test_message(), "Signal daemon unavailable")
+            .await
+            .unwrap();
+
+        for attempt in 1..=3 {
+            let due = store.due_messages(10).await.unwrap();
+            assert_eq!(due.len(), 1);
+            assert_eq!(due[0].id, id);
+            assert_eq!(due[0].message.text, "Your reminder");
+            let will_retry = store
+                .fail(&id, &format!("attempt {attempt} failed"))
+                .await
+                .unwrap();
+            assert_eq!(will_retry, attempt < 3);
+        }
+
+        assert!(store.due_messages(10).await.unwrap().is_empty());
+        let dead = RetryQueuePort::list_dead_letters(&store, 10).await.unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].original_id, id);
+        assert_eq!(dead[0].operation_type, MESSAGE_OPERATION);
+        assert_eq!(dead[0].target, "+491701234567");
+        assert_eq!(dead[0].attempt_count, 3);
+        assert_eq!(dead[0].last_error.as_deref(), Some("attempt 3 failed"));
+    }
+
+    #[tokio::test]
+    async fn requeued_message_is_redelivered() {
+        let (db, _) = setup().await;
+        let store = RetryQueueStore::with_config(db.pool().clone(), without_backoff(1));
+        let id = store
+            .enqueue_message(&test_message(), "timeout")
+            .await
+            .unwrap();
+        let _ = store.due_messages(10).await.unwrap();
+        assert!(!store.fail(&id, "timeout").await.unwrap());
+
+        let dead = RetryQueuePort::list_dead_letters(&store, 10).await.unwrap();
+        assert_eq!(store.requeue(&dead[0].id).await.unwrap(), id);
+
+        let due = store.due_messages(10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].attempt_count, 0);
+        store.complete(&id).await.unwrap();
+        assert_eq!(store.get_stats().await.unwrap(), QueueStats::default());
+    }
+
+    #[tokio::test]
+    async fn requeue_unknown_dead_letter_is_not_found() {
+        let (_db, store) = setup().await;
+
+        let result = store.requeue("missing").await;
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn due_messages_skips_other_operations_and_dead_letters_corrupt_payloads() {
+        let (_db, store) = setup().await;
+        store
+            .enqueue(RetryItem::new("webhook", "{}", "https://example.com"))
+            .await
+            .unwrap();
+        store
+            .enqueue(RetryItem::new(
+                MESSAGE_OPERATION,
+                "not json",
+                "+491701234567",
+            ))
+            .await
+            .unwrap();
+
+        assert!(store.due_messages(10).await.unwrap().is_empty());
+        let stats = store.get_stats().await.unwrap();
+        assert_eq!(stats.pending, 1);
+        assert_eq!(stats.dead_letter, 1);
     }
 
     #[tokio::test]
@@ -7,7 +7,8 @@
 use std::net::IpAddr;
 
 use application::ports::{
-    SuspiciousActivityConfig, SuspiciousActivityPort, ViolationRecord, ViolationSummary,
+    BlockedEntry, MANUAL_BLOCK_REASON, SuspiciousActivityConfig, SuspiciousActivityPort,
+    ViolationRecord, ViolationSummary,
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -95,11 +96,13 @@ impl SuspiciousActivityPort for SqliteSuspiciousActivityTracker {
         if should_block {
             let expires = self.calculate_block_expiration();
             if let Err(e) = sqlx::query(
-                "INSERT INTO ip_blocks (ip, blocked_until) VALUES ($1, $2)
-                 ON CONFLICT(ip) DO UPDATE SET blocked_until = excluded.blocked_until",
+                "INSERT INTO ip_blocks (ip, blocked_until, reason) VALUES ($1, $2, $3)
+                 ON CONFLICT(ip) DO UPDATE SET
+                   blocked_until = excluded.blocked_until, reason = excluded.reason",
             )
             .bind(&ip_str)
             .bind(expires.to_rfc3339())
+            .bind(violation.block_reason())
             .execute(&self.pool)
             .await
             {
@@ -240,11 +243,13 @@ impl SuspiciousActivityPort for SqliteSuspiciousActivityTracker {
         let expires = Utc::now() + chrono::Duration::seconds(duration_secs as i64);
 
         if let Err(e) = sqlx::query(
-            "INSERT INTO ip_blocks (ip, blocked_until) VALUES ($1, $2)
-             ON CONFLICT(ip) DO UPDATE SET blocked_until = excluded.blocked_until",
+            "INSERT INTO ip_blocks (ip, blocked_until, reason) VALUES ($1, $2, $3)
+             ON CONFLICT(ip) DO UPDATE SET
+               blocked_until = excluded.blocked_until, reason = excluded.reason",
         )
         .bind(&ip_str)
         .bind(expires.to_rfc3339())
+        .bind(MANUAL_BLOCK_REASON)
         .execute(&self.pool)
         .await
         {
@@ -300,6 +305,43 @@ impl SuspiciousActivityPort for SqliteSuspiciousActivityTracker {
             .collect()
     }
 
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    async fn list_blocked(&self) -> Vec<BlockedEntry> {
+        let now = Utc::now().to_rfc3339();
+
+        let rows: Vec<(String, String, Option<String>, i64)> = sqlx::query_as(
+            "SELECT b.ip, b.blocked_until, b.reason,
+                    (SELECT COUNT(*) FROM security_violations v WHERE v.ip = b.ip)
+             FROM ip_blocks b WHERE b.blocked_until > $1
+             ORDER BY b.blocked_until ASC",
+        )
+        .bind(&now)
+        .fetch_all(&self.pool)
+        .await
+        .unwrap_or_default();
+
+        rows.into_iter()
+            .filter_map(|(ip_str, until, reason, count)| {
+                Some(BlockedEntry {
+                    ip: ip_str.parse().ok()?,
+                    reason: reason.unwrap_or_else(|| MANUAL_BLOCK_REASON.to_string()),
+                    violation_count: count as u32,
+                    blocked_until: DateTime::parse_from_rfc3339(&until)
+                        .ok()?
+                        .with_timezone(&Utc),
+                })
+            })
+            .collect()
+    }
+
+    async fn unblock(&self, ip: IpAddr) -> bool {
+        let was_blocked = self.is_blocked(ip).await;
+        self.unblock_ip(ip).await;
+        // Forget the history so the next flagged message does not re-block
+        self.clear_violations(ip).await;
+        was_blocked
+    }
+
     #[allow(clippy::cast_possible_wrap)]
     async fn cleanup_expired(&self) {
         let now = Utc::now();
@@ -454,6 +496,50 @@ mod tests {
         assert!(blocked.is_empty());
     }
 
+    #[tokio::test]
+    async fn list_blocked_reports_reason_and_count() {
+        let tracker = setup().await;
+        let auto_ip = test_ip();
+        let manual_ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+        for _ in 0..3 {
+            tracker
+                .record_violation(
+                    auto_ip,
+                    ViolationRecord::new("PromptInjection", ThreatLevel::High),
+                )
+                .await;
+        }
+        tracker.block_ip(manual_ip, 60).await;
+
+        let blocked = tracker.list_blocked().await;
+        assert_eq!(blocked.len(), 2);
+        assert_eq!(blocked[0].ip, manual_ip);
+        assert_eq!(blocked[0].reason, MANUAL_BLOCK_REASON);
+        assert_eq!(blocked[0].violation_count, 0);
+        assert_eq!(blocked[1].ip, auto_ip);
+        assert_eq!(blocked[1].reason, "PromptInjection (high)");
+        assert_eq!(blocked[1].violation_count, 3);
+    }
+
+    #[tokio::test]
+    async fn unblock_lifts_block_and_clears_violations() {
+        let tracker = setup().await;
+        let ip = test_ip();
+
+        for _ in 0..3 {
+            tracker
+                .record_violation(ip, ViolationRecord::new("test", ThreatLevel::Medium))
+                .await;
+        }
+
+        assert!(tracker.unblock(ip).await);
+        assert!(!tracker.is_blocked(ip).await);
+        assert!(tracker.list_blocked().await.is_empty());
+        assert_eq!(tracker.get_violation_summary(ip).await.total_violations, 0);
+        assert!(!tracker.unblock(ip).await);
+    }
+
     #[tokio::test]
     async fn violation_summary_for_unknown_ip() {
         let tracker = setup().await;
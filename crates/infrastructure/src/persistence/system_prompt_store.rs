@@ -0,0 +1,156 @@
+//! SQLite system prompt override store implementation
+//!
+//! Implements the `SystemPromptStore` port using sqlx.
+
+use application::{
+    error::ApplicationError,
+    ports::{PromptOverrideTarget, SystemPromptStore},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use tracing::{debug, instrument};
+
+use super::error::map_sqlx_error;
+
+/// SQLite-based system prompt override store
+#[derive(Debug, Clone)]
+pub struct SqliteSystemPromptStore {
+    pool: SqlitePool,
+}
+
+impl SqliteSystemPromptStore {
+    /// Create a new SQLite system prompt override store
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SystemPromptStore for SqliteSystemPromptStore {
+    #[instrument(skip(self), fields(target = %target))]
+    async fn get(&self, target: &PromptOverrideTarget) -> Result<Option<String>, ApplicationError> {
+        let prompt: Option<String> = sqlx::query_scalar(
+            "SELECT prompt FROM system_prompt_overrides
+             WHERE target_kind = $1 AND target_key = $2",
+        )
+        .bind(target.kind())
+        .bind(target.key())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        debug!(found = prompt.is_some(), "Retrieved system prompt override");
+        Ok(prompt)
+    }
+
+    #[instrument(skip(self, prompt), fields(target = %target, prompt_len = prompt.len()))]
+    async fn set(
+        &self,
+        target: &PromptOverrideTarget,
+        prompt: &str,
+    ) -> Result<(), ApplicationError> {
+        let now = Utc::now().to_rfc3339();
+
+        sqlx::query(
+            "INSERT INTO system_prompt_overrides (target_kind, target_key, prompt, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $4)
+             ON CONFLICT(target_kind, target_key) DO UPDATE SET
+                 prompt = excluded.prompt,
+                 updated_at = excluded.updated_at",
+        )
+        .bind(target.kind())
+        .bind(target.key())
+        .bind(prompt)
+        .bind(&now)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        debug!("Saved system prompt override");
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(target = %target))]
+    async fn delete(&self, target: &PromptOverrideTarget) -> Result<bool, ApplicationError> {
+        let result = sqlx::query(
+            "DELETE FROM system_prompt_overrides WHERE target_kind = $1 AND target_key = $2",
+        )
+        .bind(target.kind())
+        .bind(target.key())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let deleted = result.rows_affected() > 0;
+        debug!(deleted, "Deleted system prompt override");
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::value_objects::{ConversationId, PhoneNumber};
+
+    use super::*;
+    use crate::persistence::async_connection::AsyncDatabase;
+
+    async fn setup() -> (AsyncDatabase, SqliteSystemPromptStore) {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let store = SqliteSystemPromptStore::new(db.pool().clone());
+        (db, store)
+    }
+
+    #[tokio::test]
+    async fn set_and_get_override() {
+        let (_db, store) = setup().await;
+        let target = PromptOverrideTarget::Conversation(ConversationId::new());
+
+        assert_eq!(store.get(&target).await.unwrap(), None);
+
+        store.set(&target, "Be formal.").await.unwrap();
+        assert_eq!(
+            store.get(&target).await.unwrap().as_deref(),
+            Some("Be formal.")
+        );
+    }
+
+    #[tokio::test]
+    async fn set_replaces_existing_override() {
+        let (_db, store) = setup().await;
+        let target = PromptOverrideTarget::Contact(PhoneNumber::new("+491701234567").unwrap());
+
+        store.set(&target, "Be formal.").await.unwrap();
+        store.set(&target, "Be casual.").await.unwrap();
+
+        assert_eq!(
+            store.get(&target).await.unwrap().as_deref(),
+            Some("Be casual.")
+        );
+    }
+
+    #[tokio::test]
+    async fn targets_are_independent() {
+        let (_db, store) = setup().await;
+        let contact = PromptOverrideTarget::Contact(PhoneNumber::new("+491701234567").unwrap());
+        let conversation = PromptOverrideTarget::Conversation(ConversationId::new());
+
+        store.set(&contact, "Be casual.").await.unwrap();
+
+        assert_eq!(store.get(&conversation).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn delete_override() {
+        let (_db, store) = setup().await;
+        let target = PromptOverrideTarget::Contact(PhoneNumber::new("+491701234567").unwrap());
+
+        store.set(&target, "Be casual.").await.unwrap();
+
+        assert!(store.delete(&target).await.unwrap());
+        assert!(!store.delete(&target).await.unwrap());
+        assert_eq!(store.get(&target).await.unwrap(), None);
+    }
+}
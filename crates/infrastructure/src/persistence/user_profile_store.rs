@@ -6,7 +6,7 @@ use application::{error::ApplicationError, ports::UserProfileStore};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::{
-    entities::UserProfile,
+    entities::{BriefingSection, UserProfile},
     value_objects::{GeoLocation, Timezone, UserId},
 };
 use sqlx::SqlitePool;
@@ -32,9 +32,11 @@ impl SqliteUserProfileStore {
 #[derive(sqlx::FromRow)]
 struct ProfileRow {
     user_id: String,
+    display_name: Option<String>,
     latitude: Option<f64>,
     longitude: Option<f64>,
     timezone: String,
+    briefing_sections: Option<String>,
     created_at: String,
     updated_at: String,
 }
@@ -65,12 +67,38 @@ impl ProfileRow {
         let updated_at = DateTime::parse_from_rfc3339(&self.updated_at)
             .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
 
-        Ok(UserProfile::restore(
-            user_id, location, timezone, created_at, updated_at,
-        ))
+        let briefing_sections = self.briefing_sections.as_deref().map(parse_sections);
+
+        Ok(
+            UserProfile::restore(user_id, location, timezone, created_at, updated_at)
+                .with_name(self.display_name)
+                .with_briefing_sections(briefing_sections),
+        )
     }
 }
 
+/// Parse a comma-separated section list, skipping unknown entries
+fn parse_sections(value: &str) -> Vec<BriefingSection> {
+    value
+        .split(',')
+        .filter(|s| !s.trim().is_empty())
+        .filter_map(|s| {
+            s.parse::<BriefingSection>()
+                .inspect_err(|e| tracing::warn!(error = %e, "Ignoring stored briefing section"))
+                .ok()
+        })
+        .collect()
+}
+
+/// Format a section list for storage
+fn format_sections(sections: &[BriefingSection]) -> String {
+    sections
+        .iter()
+        .map(|s| s.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 #[async_trait]
 impl UserProfileStore for SqliteUserProfileStore {
     #[instrument(skip(self, profile), fields(user_id = %profile.id()))]
@@ -81,18 +109,22 @@ impl UserProfileStore for SqliteUserProfileStore {
         });
 
         sqlx::query(
-            "INSERT INTO user_profiles (user_id, latitude, longitude, timezone, created_at, updated_at)
-             VALUES ($1, $2, $3, $4, $5, $5)
+            "INSERT INTO user_profiles (user_id, display_name, latitude, longitude, timezone, briefing_sections, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $7)
              ON CONFLICT(user_id) DO UPDATE SET
+                 display_name = excluded.display_name,
                  latitude = excluded.latitude,
                  longitude = excluded.longitude,
                  timezone = excluded.timezone,
+                 briefing_sections = excluded.briefing_sections,
                  updated_at = excluded.updated_at",
         )
         .bind(profile.id().to_string())
+        .bind(profile.name())
         .bind(latitude)
         .bind(longitude)
         .bind(profile.timezone().as_str())
+        .bind(profile.briefing_sections().map(format_sections))
         .bind(&now)
         .execute(&self.pool)
         .await
@@ -105,7 +137,7 @@ impl UserProfileStore for SqliteUserProfileStore {
     #[instrument(skip(self), fields(user_id = %user_id))]
     async fn get(&self, user_id: &UserId) -> Result<Option<UserProfile>, ApplicationError> {
         let row: Option<ProfileRow> = sqlx::query_as(
-            "SELECT user_id, latitude, longitude, timezone, created_at, updated_at
+            "SELECT user_id, display_name, latitude, longitude, timezone, briefing_sections, created_at, updated_at
              FROM user_profiles WHERE user_id = $1",
         )
         .bind(user_id.to_string())
@@ -227,9 +259,52 @@ mod tests {
 
         let retrieved = store.get(&profile.id()).await.unwrap().unwrap();
         assert!(retrieved.location().is_none());
+        assert!(retrieved.name().is_none());
         assert!(retrieved.timezone().is_utc());
     }
 
+    #[tokio::test]
+    async fn name_roundtrip() {
+        let (_db, store) = setup().await;
+
+        let profile = UserProfile::new(UserId::new()).with_name(Some("Anna".to_string()));
+        store.save(&profile).await.unwrap();
+
+        let retrieved = store.get(&profile.id()).await.unwrap().unwrap();
+        assert_eq!(retrieved.name(), Some("Anna"));
+    }
+
+    #[tokio::test]
+    async fn briefing_sections_roundtrip() {
+        let (_db, store) = setup().await;
+
+        let profile = UserProfile::new(UserId::new());
+        store.save(&profile).await.unwrap();
+        let retrieved = store.get(&profile.id()).await.unwrap().unwrap();
+        assert!(retrieved.briefing_sections().is_none());
+
+        let profile = profile.with_briefing_sections(Some(vec![
+            BriefingSection::Weather,
+            BriefingSection::Calendar,
+        ]));
+        store.save(&profile).await.unwrap();
+
+        let retrieved = store.get(&profile.id()).await.unwrap().unwrap();
+        assert_eq!(
+            retrieved.briefing_sections(),
+            Some([BriefingSection::Weather, BriefingSection::Calendar].as_slice())
+        );
+    }
+
+    #[test]
+    fn parse_sections_skips_unknown_entries() {
+        assert_eq!(
+            parse_sections("email, news,weather"),
+            vec![BriefingSection::Email, BriefingSection::Weather]
+        );
+        assert!(parse_sections("").is_empty());
+    }
+
     #[tokio::test]
     async fn update_existing_profile() {
         let (_db, store) = setup().await;
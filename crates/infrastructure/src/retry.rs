@@ -3,6 +3,14 @@
 //! Provides a configurable retry mechanism for fallible operations,
 //! with exponential backoff and jitter to prevent thundering herd.
 //!
+//! Besides the proportional jitter controlled by `jitter_factor`, a
+//! [`JitterStrategy`] can be selected to spread retries from many clients
+//! across the whole backoff window (full jitter) or its upper half
+//! (equal jitter).
+//!
+//! [`with_timeout`] bounds a single call, so a hanging connection fails
+//! with a [`TimeoutError`] instead of wedging the request.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -20,6 +28,37 @@ use std::future::Future;
 use std::time::Duration;
 use tracing::{debug, warn};
 
+/// Randomization strategy applied to each computed backoff delay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JitterStrategy {
+    /// No strategy; only the proportional `jitter_factor` jitter applies
+    #[default]
+    None,
+    /// Random delay in `[0, computed]`
+    Full,
+    /// Random delay in `[computed / 2, computed]`
+    Equal,
+}
+
+impl JitterStrategy {
+    /// Apply the strategy to a computed delay in milliseconds
+    #[must_use]
+    pub fn apply(self, delay_ms: f64) -> f64 {
+        if delay_ms <= 0.0 {
+            return 0.0;
+        }
+        match self {
+            Self::None => delay_ms,
+            Self::Full => rand::rng().random_range(0.0..=delay_ms),
+            Self::Equal => {
+                let half = delay_ms / 2.0;
+                half + rand::rng().random_range(0.0..=half)
+            },
+        }
+    }
+}
+
 /// Configuration for retry behavior with exponential backoff
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetryConfig {
@@ -46,6 +85,13 @@ pub struct RetryConfig {
     /// Maximum jitter factor (0.0 to 1.0, default: 0.1 = 10%)
     #[serde(default = "default_jitter_factor")]
     pub jitter_factor: f64,
+
+    /// Jitter strategy applied to each computed delay (default: none)
+    ///
+    /// When set to `Full` or `Equal`, it replaces the proportional
+    /// `jitter_factor` jitter.
+    #[serde(default)]
+    pub jitter: JitterStrategy,
 }
 
 const fn default_initial_delay() -> u64 {
@@ -81,6 +127,7 @@ impl Default for RetryConfig {
             max_retries: default_max_retries(),
             jitter_enabled: default_true(),
             jitter_factor: default_jitter_factor(),
+            jitter: JitterStrategy::None,
         }
     }
 }
@@ -101,6 +148,7 @@ impl RetryConfig {
             max_retries,
             jitter_enabled: true,
             jitter_factor: 0.1,
+            jitter: JitterStrategy::None,
         }
     }
 
@@ -114,6 +162,7 @@ impl RetryConfig {
             max_retries: 3,
             jitter_enabled: true,
             jitter_factor: 0.1,
+            jitter: JitterStrategy::None,
         }
     }
 
@@ -127,6 +176,7 @@ impl RetryConfig {
             max_retries: 5,
             jitter_enabled: true,
             jitter_factor: 0.2,
+            jitter: JitterStrategy::None,
         }
     }
 
@@ -140,6 +190,7 @@ impl RetryConfig {
             max_retries: 10,
             jitter_enabled: true,
             jitter_factor: 0.15,
+            jitter: JitterStrategy::None,
         }
     }
 
@@ -150,10 +201,19 @@ impl RetryConfig {
         self
     }
 
+    /// Select a jitter strategy for computed delays
+    #[must_use]
+    pub const fn with_jitter_strategy(mut self, jitter: JitterStrategy) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
     /// Calculate the delay for a given attempt number (0-indexed)
     ///
     /// Uses exponential backoff: delay = initial_delay * multiplier^attempt
     /// Capped at max_delay, with optional jitter to prevent thundering herd.
+    /// A `Full` or `Equal` jitter strategy takes precedence over the
+    /// proportional jitter, and disabling jitter disables both.
     #[must_use]
     #[allow(
         clippy::cast_precision_loss,
@@ -167,7 +227,9 @@ impl RetryConfig {
         let base_delay = (self.initial_delay_ms as f64) * self.multiplier.powi(attempt as i32);
         let capped_delay = base_delay.min(self.max_delay_ms as f64);
 
-        let final_delay = if self.jitter_enabled {
+        let final_delay = if self.jitter_enabled && self.jitter != JitterStrategy::None {
+            self.jitter.apply(capped_delay)
+        } else if self.jitter_enabled {
             let jitter_range = capped_delay * self.jitter_factor;
             let jitter = rand::rng().random_range(-jitter_range..=jitter_range);
             (capped_delay + jitter).max(0.0)
@@ -202,6 +264,8 @@ pub struct RetryResult<T, E> {
     pub attempts: u32,
     /// Total time spent including retries
     pub total_duration: Duration,
+    /// Whether the value was produced by a fallback after all attempts failed
+    pub fallback_used: bool,
 }
 
 impl<T, E> RetryResult<T, E> {
@@ -276,6 +340,7 @@ where
                     result: Ok(value),
                     attempts,
                     total_duration: start.elapsed(),
+                    fallback_used: false,
                 };
             },
             Err(err) => {
@@ -291,6 +356,7 @@ where
                         result: Err(err),
                         attempts,
                         total_duration: start.elapsed(),
+                        fallback_used: false,
                     };
                 }
 
@@ -305,6 +371,7 @@ where
                         result: Err(err),
                         attempts,
                         total_duration: start.elapsed(),
+                        fallback_used: false,
                     };
                 }
 
@@ -323,6 +390,77 @@ where
     }
 }
 
+/// Execute an async operation with retry logic, falling back on failure
+///
+/// Behaves like [`with_retry`], but when the operation ultimately fails
+/// (retries exhausted or a non-retryable error) `fallback` is invoked with
+/// the last error to produce a degraded value, e.g. last-known-good data.
+/// The returned `RetryResult` has `fallback_used` set in that case.
+pub async fn with_retry_or_else<F, Fut, T, E, FB>(
+    config: &RetryConfig,
+    operation: F,
+    fallback: FB,
+) -> RetryResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Retryable + std::fmt::Display,
+    FB: FnOnce(&E) -> T,
+{
+    let outcome = with_retry(config, operation).await;
+
+    match outcome.result {
+        Ok(value) => RetryResult {
+            result: Ok(value),
+            ..outcome
+        },
+        Err(err) => {
+            warn!(
+                attempts = outcome.attempts,
+                error = %err,
+                "Operation failed, using fallback value"
+            );
+            RetryResult {
+                result: Ok(fallback(&err)),
+                attempts: outcome.attempts,
+                total_duration: outcome.total_duration,
+                fallback_used: true,
+            }
+        },
+    }
+}
+
+/// Error returned when an operation did not complete in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Operation timed out after {}ms", .timeout.as_millis())]
+pub struct TimeoutError {
+    /// The timeout that elapsed
+    pub timeout: Duration,
+}
+
+impl Retryable for TimeoutError {
+    fn is_retryable(&self) -> bool {
+        true
+    }
+}
+
+/// Await `future`, giving up after `duration`
+///
+/// The future is dropped when the timeout fires.
+///
+/// # Errors
+///
+/// Returns a [`TimeoutError`] if the future does not complete in time.
+pub async fn with_timeout<F: Future>(
+    duration: Duration,
+    future: F,
+) -> Result<F::Output, TimeoutError> {
+    tokio::time::timeout(duration, future).await.map_err(|_| {
+        warn!(timeout_ms = duration.as_millis(), "Operation timed out");
+        TimeoutError { timeout: duration }
+    })
+}
+
 /// Execute an async operation with retry logic, returning only the Result
 ///
 /// This is a convenience wrapper around `with_retry` that discards metadata.
@@ -359,6 +497,22 @@ mod tests {
         }
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn with_timeout_fires_for_pending_future() {
+        let result = with_timeout(Duration::from_secs(5), std::future::pending::<()>()).await;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.timeout, Duration::from_secs(5));
+        assert!(err.is_retryable());
+        assert!(err.to_string().contains("5000ms"));
+    }
+
+    #[tokio::test]
+    async fn with_timeout_passes_fast_future_through() {
+        let result = with_timeout(Duration::from_secs(5), async { 42 }).await;
+        assert_eq!(result, Ok(42));
+    }
+
     #[test]
     fn config_default_values() {
         let config = RetryConfig::default();
@@ -552,12 +706,91 @@ mod tests {
         assert_eq!(result.unwrap(), 42);
     }
 
+    #[tokio::test]
+    async fn with_retry_or_else_succeeds_before_exhaustion() {
+        let config = RetryConfig::fast().without_jitter();
+        let call_count = Arc::new(AtomicU32::new(0));
+
+        let result = with_retry_or_else(
+            &config,
+            || {
+                let count = Arc::clone(&call_count);
+                async move {
+                    let calls = count.fetch_add(1, Ordering::SeqCst) + 1;
+                    if calls < 2 {
+                        Err(TestError {
+                            message: "temporary failure".to_string(),
+                            retryable: true,
+                        })
+                    } else {
+                        Ok(42)
+                    }
+                }
+            },
+            |_| 0,
+        )
+        .await;
+
+        assert!(!result.fallback_used);
+        assert_eq!(result.attempts, 2);
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn with_retry_or_else_invokes_fallback_after_exhaustion() {
+        let config = RetryConfig::new(10, 100, 2.0, 2).without_jitter();
+        let call_count = Arc::new(AtomicU32::new(0));
+
+        let result = with_retry_or_else(
+            &config,
+            || {
+                let count = Arc::clone(&call_count);
+                async move {
+                    count.fetch_add(1, Ordering::SeqCst);
+                    Err::<String, _>(TestError {
+                        message: "always fails".to_string(),
+                        retryable: true,
+                    })
+                }
+            },
+            |err| format!("cached ({err})"),
+        )
+        .await;
+
+        assert!(result.fallback_used);
+        assert_eq!(result.attempts, 3);
+        assert_eq!(call_count.load(Ordering::SeqCst), 3);
+        assert_eq!(result.unwrap(), "cached (always fails)");
+    }
+
+    #[tokio::test]
+    async fn with_retry_or_else_falls_back_on_non_retryable() {
+        let config = RetryConfig::default();
+
+        let result = with_retry_or_else(
+            &config,
+            || async {
+                Err::<i32, _>(TestError {
+                    message: "permanent failure".to_string(),
+                    retryable: false,
+                })
+            },
+            |_| -1,
+        )
+        .await;
+
+        assert!(result.fallback_used);
+        assert_eq!(result.attempts, 1);
+        assert_eq!(result.unwrap(), -1);
+    }
+
     #[test]
     fn retry_result_is_ok() {
         let result: RetryResult<i32, TestError> = RetryResult {
             result: Ok(42),
             attempts: 1,
             total_duration: Duration::from_millis(10),
+            fallback_used: false,
         };
         assert!(result.is_ok());
         assert!(!result.is_err());
@@ -572,6 +805,7 @@ mod tests {
             }),
             attempts: 1,
             total_duration: Duration::from_millis(10),
+            fallback_used: false,
         };
         assert!(!result.is_ok());
         assert!(result.is_err());
@@ -583,6 +817,7 @@ mod tests {
             result: Ok(42),
             attempts: 2,
             total_duration: Duration::from_millis(100),
+            fallback_used: false,
         };
         let inner = result.into_result();
         assert_eq!(inner.unwrap(), 42);
@@ -623,6 +858,7 @@ mod tests {
             result: Ok(42),
             attempts: 1,
             total_duration: Duration::from_millis(10),
+            fallback_used: false,
         };
         let debug = format!("{result:?}");
         assert!(debug.contains("RetryResult"));
@@ -635,6 +871,7 @@ mod tests {
             result: Ok(42),
             attempts: 1,
             total_duration: Duration::from_millis(10),
+            fallback_used: false,
         };
         assert_eq!(result.unwrap(), 42);
     }
@@ -649,6 +886,7 @@ mod tests {
             }),
             attempts: 1,
             total_duration: Duration::from_millis(10),
+            fallback_used: false,
         };
         let _ = result.unwrap();
     }
@@ -671,6 +909,7 @@ mod tests {
             max_retries: 3,
             jitter_enabled: true,
             jitter_factor: 0.1, // 10% jitter
+            jitter: JitterStrategy::None,
         };
 
         // Take multiple samples and verify they're within expected range
@@ -684,6 +923,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn full_jitter_stays_within_bounds() {
+        let config =
+            RetryConfig::new(1000, 1000, 1.0, 3).with_jitter_strategy(JitterStrategy::Full);
+
+        for _ in 0..50 {
+            let delay_ms = config.delay_for_attempt(0).as_millis();
+            assert!(delay_ms <= 1000, "delay_ms={delay_ms} out of range");
+        }
+    }
+
+    #[test]
+    fn equal_jitter_stays_within_bounds() {
+        let config =
+            RetryConfig::new(1000, 1000, 1.0, 3).with_jitter_strategy(JitterStrategy::Equal);
+
+        for _ in 0..50 {
+            let delay_ms = config.delay_for_attempt(0).as_millis();
+            assert!(
+                (500..=1000).contains(&delay_ms),
+                "delay_ms={delay_ms} out of range"
+            );
+        }
+    }
+
+    #[test]
+    fn jitter_strategy_respects_cap() {
+        let config =
+            RetryConfig::new(100, 2000, 2.0, 10).with_jitter_strategy(JitterStrategy::Equal);
+
+        for _ in 0..20 {
+            let delay_ms = config.delay_for_attempt(8).as_millis();
+            assert!(
+                (1000..=2000).contains(&delay_ms),
+                "delay_ms={delay_ms} out of range"
+            );
+        }
+    }
+
+    #[test]
+    fn jitter_strategy_ignored_without_jitter() {
+        let config = RetryConfig::default()
+            .with_jitter_strategy(JitterStrategy::Full)
+            .without_jitter();
+
+        assert_eq!(config.delay_for_attempt(1).as_millis(), 200);
+    }
+
+    #[test]
+    fn jitter_strategy_defaults_to_none() {
+        let config: RetryConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.jitter, JitterStrategy::None);
+
+        let config: RetryConfig = serde_json::from_str(r#"{"jitter":"full"}"#).unwrap();
+        assert_eq!(config.jitter, JitterStrategy::Full);
+    }
+
     #[test]
     fn test_error_retryable_impl() {
         let retryable_err = TestError {
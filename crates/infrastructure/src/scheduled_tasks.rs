@@ -4,10 +4,14 @@
 //! - Reminder checker (every minute)
 //! - CalDAV sync (every 15 minutes)
 //! - Morning briefing (daily at 7 AM)
+//! - Daily digest push to messenger contacts (daily at 7 AM)
 
 use std::sync::Arc;
 
-use application::{ports::ReminderPort, services::NotificationService};
+use application::{
+    ports::ReminderPort,
+    services::{DailyDigestService, NotificationService},
+};
 use futures::future::BoxFuture;
 use tracing::{debug, error, info};
 
@@ -17,6 +21,8 @@ pub const REMINDER_CHECKER_TASK: &str = "reminder_checker";
 pub const MORNING_BRIEFING_TASK: &str = "morning_briefing";
 /// Task name for CalDAV sync
 pub const CALDAV_SYNC_TASK: &str = "caldav_sync";
+/// Task name for the daily digest push
+pub const DAILY_DIGEST_TASK: &str = "daily_digest";
 
 /// Callback type for sending notifications
 pub type NotificationCallback =
@@ -121,6 +127,33 @@ pub fn create_morning_briefing_task(
     }
 }
 
+/// Create a daily digest task closure
+///
+/// This task pushes the morning briefing to every whitelisted messenger
+/// contact. It fails if the digest could not be sent to any of them, so that
+/// the scheduler's task stats show the failure. Designed to run on
+/// [`TaskBuilder::morning_briefing_cron`](crate::TaskBuilder).
+pub fn create_daily_digest_task(
+    digest_service: Arc<DailyDigestService>,
+) -> impl Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync + 'static {
+    move || {
+        let service = Arc::clone(&digest_service);
+
+        Box::pin(async move {
+            info!("Pushing daily digest");
+
+            let report = service.run().await;
+            if report.failed() > 0 {
+                error!(report = %report, "Daily digest failed for some recipients");
+                return Err(format!("Daily digest {report}"));
+            }
+
+            info!(report = %report, "Daily digest pushed");
+            Ok(())
+        })
+    }
+}
+
 /// CalDAV sync callback type
 pub type CalDavSyncCallback =
     Arc<dyn Fn() -> BoxFuture<'static, Result<u32, String>> + Send + Sync>;
@@ -259,6 +292,7 @@ mod tests {
         assert_ne!(REMINDER_CHECKER_TASK, MORNING_BRIEFING_TASK);
         assert_ne!(MORNING_BRIEFING_TASK, CALDAV_SYNC_TASK);
         assert_ne!(REMINDER_CHECKER_TASK, CALDAV_SYNC_TASK);
+        assert_ne!(MORNING_BRIEFING_TASK, DAILY_DIGEST_TASK);
     }
 
     #[test]
@@ -14,10 +14,11 @@ use std::{
         Arc,
         atomic::{AtomicBool, AtomicU64, Ordering},
     },
+    time::Duration,
 };
 
 use chrono::{DateTime, Utc};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use thiserror::Error;
 use tokio::sync::{Mutex as AsyncMutex, mpsc};
 use tokio_cron_scheduler::{Job, JobScheduler, JobSchedulerError};
@@ -234,6 +235,9 @@ impl Default for SchedulerConfig {
     }
 }
 
+/// Schedule shown in [`TaskStats::cron_expression`] for one-shot tasks
+pub const ONE_SHOT_SCHEDULE: &str = "@once";
+
 /// Predefined cron expressions for common schedules
 pub mod schedules {
     /// Every minute
@@ -394,37 +398,7 @@ impl TaskScheduler {
                     }
                 }
 
-                debug!(task = %name, "Starting scheduled task");
-                let start = std::time::Instant::now();
-                let result = task_future.await;
-                let duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
-
-                let (success, error) = match result {
-                    Ok(()) => {
-                        if let Some(metadata) = tasks.read().get(&name) {
-                            metadata.record_success(duration_ms);
-                        }
-                        info!(task = %name, duration_ms, "Task completed successfully");
-                        (true, None)
-                    },
-                    Err(e) => {
-                        if let Some(metadata) = tasks.read().get(&name) {
-                            metadata.record_failure(e.clone(), duration_ms);
-                        }
-                        error!(task = %name, error = %e, duration_ms, "Task failed");
-                        (false, Some(e))
-                    },
-                };
-
-                // Send event notification
-                let event = TaskEvent {
-                    task_name: name,
-                    success,
-                    error,
-                    duration_ms,
-                    completed_at: Utc::now(),
-                };
-                let _ = event_tx.try_send(event);
+                run_task(name, &tasks, &event_tx, task_future).await;
             })
         })
         .map_err(|e| SchedulerError::InvalidCronExpression(e.to_string()))?;
@@ -444,6 +418,63 @@ impl TaskScheduler {
         Ok(())
     }
 
+    /// Add a task that runs once after a delay
+    ///
+    /// The task is removed from the scheduler after it ran. Like
+    /// [`add_task`](Self::add_task), it can be removed or paused before then.
+    #[instrument(skip(self, task))]
+    pub async fn add_once<Fut>(
+        &self,
+        name: &str,
+        delay: Duration,
+        task: Fut,
+    ) -> Result<(), SchedulerError>
+    where
+        Fut: std::future::Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let name_clone = name.to_string();
+        let tasks = Arc::clone(&self.tasks);
+        let event_tx = self.event_tx.clone();
+        let task = Arc::new(Mutex::new(Some(task)));
+
+        let job = Job::new_one_shot_async(delay, move |_uuid, _lock| {
+            let name = name_clone.clone();
+            let tasks = Arc::clone(&tasks);
+            let event_tx = event_tx.clone();
+            let task_future = task.lock().take();
+
+            Box::pin(async move {
+                let Some(task_future) = task_future else {
+                    return;
+                };
+                let paused = tasks
+                    .read()
+                    .get(&name)
+                    .is_some_and(|metadata| metadata.paused.load(Ordering::Relaxed));
+
+                if paused {
+                    debug!(task = %name, "Task is paused, skipping execution");
+                } else {
+                    run_task(name.clone(), &tasks, &event_tx, task_future).await;
+                }
+                tasks.write().remove(&name);
+            })
+        })?;
+
+        let job_id = job.guid();
+        self.scheduler.lock().await.add(job).await?;
+
+        let metadata = Arc::new(TaskMetadata::new(
+            name.to_string(),
+            ONE_SHOT_SCHEDULE.to_string(),
+            job_id,
+        ));
+        self.tasks.write().insert(name.to_string(), metadata);
+
+        info!(task = %name, delay_secs = delay.as_secs(), "One-shot task scheduled");
+        Ok(())
+    }
+
     /// Remove a scheduled task
     #[instrument(skip(self))]
     pub async fn remove_task(&self, name: &str) -> Result<(), SchedulerError> {
@@ -511,6 +542,48 @@ impl TaskScheduler {
     }
 }
 
+/// Run a task, record its outcome and publish a [`TaskEvent`]
+async fn run_task<Fut>(
+    name: String,
+    tasks: &RwLock<HashMap<String, Arc<TaskMetadata>>>,
+    event_tx: &mpsc::Sender<TaskEvent>,
+    task_future: Fut,
+) where
+    Fut: std::future::Future<Output = Result<(), String>>,
+{
+    debug!(task = %name, "Starting scheduled task");
+    let start = std::time::Instant::now();
+    let result = task_future.await;
+    let duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let (success, error) = match result {
+        Ok(()) => {
+            if let Some(metadata) = tasks.read().get(&name) {
+                metadata.record_success(duration_ms);
+            }
+            info!(task = %name, duration_ms, "Task completed successfully");
+            (true, None)
+        },
+        Err(e) => {
+            if let Some(metadata) = tasks.read().get(&name) {
+                metadata.record_failure(e.clone(), duration_ms);
+            }
+            error!(task = %name, error = %e, duration_ms, "Task failed");
+            (false, Some(e))
+        },
+    };
+
+    // Send event notification
+    let event = TaskEvent {
+        task_name: name,
+        success,
+        error,
+        duration_ms,
+        completed_at: Utc::now(),
+    };
+    let _ = event_tx.try_send(event);
+}
+
 /// Builder for creating common scheduled tasks
 #[derive(Debug)]
 pub struct TaskBuilder {
@@ -824,6 +897,33 @@ mod tests {
         scheduler.stop().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_add_once_runs_once_and_is_removed() {
+        let scheduler = TaskScheduler::new(SchedulerConfig::default())
+            .await
+            .unwrap();
+        let mut receiver = scheduler.take_event_receiver().unwrap();
+
+        scheduler
+            .add_once("once-task", Duration::from_millis(100), async { Ok(()) })
+            .await
+            .unwrap();
+        let stats = scheduler.get_task_stats("once-task").unwrap();
+        assert_eq!(stats.cron_expression, ONE_SHOT_SCHEDULE);
+
+        let event = tokio::time::timeout(Duration::from_secs(3), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.task_name, "once-task");
+        assert!(event.success);
+
+        sleep(Duration::from_millis(100)).await;
+        assert!(scheduler.get_task_stats("once-task").is_none());
+
+        scheduler.stop().await.unwrap();
+    }
+
     #[test]
     fn test_task_builder() {
         let builder = TaskBuilder::new()
@@ -5,6 +5,11 @@
 //! - WhatsApp message templates
 //! - Calendar event summaries
 //! - Weather reports
+//! - The assistant's system prompt
+//! - The daily digest pushed to messenger contacts
+//!
+//! Custom filters: `linebreaksbr`, `truncate_words` and `strip_markdown`
+//! (Markdown to messenger-friendly text).
 //!
 //! # Template Locations
 //!
@@ -27,14 +32,26 @@
 //! let email = engine.render("email/draft.txt", &ctx)?;
 //! ```
 
+use application::{
+    error::ApplicationError,
+    ports::{
+        DigestTemplateData, DigestTemplatePort, EmailTemplateData, EmailTemplatePort,
+        PromptContext, PromptTemplatePort, RenderedEmail,
+    },
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
 use tera::{Context, Tera, Value};
 use thiserror::Error;
 use tracing::{debug, info};
 
+mod watch;
+
 /// Error type for template operations
 #[derive(Debug, Error)]
 pub enum TemplateError {
@@ -137,6 +154,9 @@ pub struct WeatherReportData {
     /// Forecast for coming days
     #[serde(default)]
     pub forecast: Vec<ForecastDay>,
+    /// Locale for template selection and number formatting (e.g., `de`)
+    #[serde(default)]
+    pub locale: Option<String>,
 }
 
 /// Forecast for a single day
@@ -172,6 +192,9 @@ pub struct CalendarEventData {
     /// Attendees
     #[serde(default)]
     pub attendees: Vec<String>,
+    /// Locale for template selection (e.g., `de`)
+    #[serde(default)]
+    pub locale: Option<String>,
 }
 
 /// Assistant response template data
@@ -188,6 +211,11 @@ pub struct AssistantResponseData {
     /// Approval command if applicable
     #[serde(default)]
     pub approval_command: Option<String>,
+    /// Messenger the response is sent to (`"signal"`, `"whatsapp"`)
+    ///
+    /// When set, Markdown in `content` is converted for the messenger.
+    #[serde(default)]
+    pub messenger: Option<String>,
 }
 
 /// Template engine configuration
@@ -204,24 +232,58 @@ pub struct TemplateConfig {
     /// Whether to auto-escape HTML by default
     #[serde(default = "default_true")]
     pub auto_escape: bool,
+
+    /// Whether to reload custom templates when their files change
+    #[serde(default)]
+    pub watch: bool,
+
+    /// How often to check custom templates for changes (milliseconds)
+    #[serde(default = "default_watch_interval_ms")]
+    pub watch_interval_ms: u64,
 }
 
 const fn default_true() -> bool {
     true
 }
 
+const fn default_watch_interval_ms() -> u64 {
+    1000
+}
+
 impl Default for TemplateConfig {
     fn default() -> Self {
         Self {
             templates_dir: None,
             use_embedded_fallback: true,
             auto_escape: true,
+            watch: false,
+            watch_interval_ms: default_watch_interval_ms(),
         }
     }
 }
 
+/// Locale of the unsuffixed templates
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Languages that use a decimal comma
+const DECIMAL_COMMA_LANGUAGES: &[&str] =
+    &["cs", "da", "de", "es", "fr", "it", "nb", "nl", "pl", "sv"];
+
+/// Template name of the assistant's system prompt
+pub const SYSTEM_PROMPT_TEMPLATE: &str = "assistant/system_prompt.txt";
+
+/// Name of the daily digest template, overridable via `templates_dir`
+pub const DAILY_DIGEST_TEMPLATE: &str = "digest/daily.txt";
+
 /// Embedded templates - compiled into the binary
 mod embedded {
+    pub const SYSTEM_PROMPT: &str = r"You are PiSovereign, a helpful AI assistant. On Raspberry Pi you run on the Hailo-10H NPU, on Mac you use Metal GPU acceleration. You are friendly, precise, and help with everyday tasks like email, calendar, and information lookup.
+
+Today is {{ date }}.
+{% if user_name %}The user's name is {{ user_name }}.
+{% endif %}{% if location %}The user is located at {{ location }}.
+{% endif %}";
+
     pub const EMAIL_DRAFT: &str = r#"To: {{ recipient_email }}
 Subject: {{ subject }}
 {% if cc %}Cc: {{ cc | join(sep=", ") }}
@@ -230,8 +292,8 @@ Dear {{ recipient }},
 
 {{ body }}
 
-Best regards,
-{{ sender }}
+Best regards,{% if sender %}
+{{ sender }}{% endif %}
 "#;
 
     pub const EMAIL_DRAFT_HTML: &str = r#"<!DOCTYPE html>
@@ -247,7 +309,7 @@ Best regards,
     <p>Dear {{ recipient }},</p>
     {{ body | linebreaksbr }}
     <div class="signature">
-        <p>Best regards,<br>{{ sender }}</p>
+        <p>Best regards,{% if sender %}<br>{{ sender }}{% endif %}</p>
     </div>
 </body>
 </html>
@@ -255,14 +317,27 @@ Best regards,
 
     pub const WEATHER_REPORT: &str = r"{{ emoji }} Weather for {{ location }}
 
-🌡️ Temperature: {{ temperature }}°{{ unit }}
+🌡️ Temperature: {{ temperature | format_number(locale=locale) }}°{{ unit }}
 💧 Humidity: {{ humidity }}%
-💨 Wind: {{ wind_speed }} km/h
+💨 Wind: {{ wind_speed | format_number(locale=locale) }} km/h
 📝 Condition: {{ condition }}
 {% if forecast %}
 📅 Forecast:
 {% for day in forecast %}
-  {{ day.day }}: {{ day.emoji }} {{ day.high }}°/{{ day.low }}° - {{ day.condition }}
+  {{ day.day }}: {{ day.emoji }} {{ day.high | format_number(locale=locale) }}°/{{ day.low | format_number(locale=locale) }}° - {{ day.condition }}
+{% endfor %}
+{% endif %}";
+
+    pub const WEATHER_REPORT_DE: &str = r"{{ emoji }} Wetter für {{ location }}
+
+🌡️ Temperatur: {{ temperature | format_number(locale=locale) }} °{{ unit }}
+💧 Luftfeuchtigkeit: {{ humidity }} %
+💨 Wind: {{ wind_speed | format_number(locale=locale) }} km/h
+📝 Wetterlage: {{ condition }}
+{% if forecast %}
+📅 Vorhersage:
+{% for day in forecast %}
+  {{ day.day }}: {{ day.emoji }} {{ day.high | format_number(locale=locale) }}°/{{ day.low | format_number(locale=locale) }}° – {{ day.condition }}
 {% endfor %}
 {% endif %}";
 
@@ -277,7 +352,18 @@ Best regards,
 👥 Attendees: {{ attendees | join(sep=", ") }}
 {% endif %}"#;
 
-    pub const ASSISTANT_RESPONSE: &str = r"{{ content }}
+    #[allow(clippy::needless_raw_string_hashes)]
+    pub const CALENDAR_EVENT_DE: &str = r#"📅 {{ title }}
+
+🕐 {{ start_time }} - {{ end_time }}
+{% if location %}📍 {{ location }}
+{% endif %}{% if description %}
+📝 {{ description }}
+{% endif %}{% if attendees %}
+👥 Teilnehmer: {{ attendees | join(sep=", ") }}
+{% endif %}"#;
+
+    pub const ASSISTANT_RESPONSE: &str = r"{% if messenger %}{{ content | strip_markdown(style=messenger) }}{% else %}{{ content }}{% endif %}
 {% if suggestions %}
 💡 Suggestions:
 {% for suggestion in suggestions %}
@@ -288,6 +374,10 @@ Best regards,
 {% if approval_command %}Use: {{ approval_command }}{% endif %}
 {% endif %}";
 
+    pub const DAILY_DIGEST: &str = r"{{ briefing | strip_markdown(style=messenger) | trim }}
+
+📬 Daily digest for {{ date }}. Reply to ask about any item.";
+
     pub const APPROVAL_REQUEST: &str = r#"🔐 Approval Required
 
 Command: {{ command }}
@@ -303,7 +393,10 @@ Reply with:
 /// Template engine using Tera
 #[derive(Clone)]
 pub struct TemplateEngine {
-    tera: Arc<Tera>,
+    /// Swapped as a whole when templates are reloaded
+    tera: Arc<RwLock<Arc<Tera>>>,
+    /// Custom template files, tracked when watching is enabled
+    files: Option<Arc<watch::TemplateFiles>>,
     config: TemplateConfig,
 }
 
@@ -339,12 +432,20 @@ impl TemplateEngine {
             .map_err(|e| TemplateError::Compile(e.to_string()))?;
         tera.add_raw_template("weather/report.txt", embedded::WEATHER_REPORT)
             .map_err(|e| TemplateError::Compile(e.to_string()))?;
+        tera.add_raw_template("weather/report.de.txt", embedded::WEATHER_REPORT_DE)
+            .map_err(|e| TemplateError::Compile(e.to_string()))?;
         tera.add_raw_template("calendar/event.txt", embedded::CALENDAR_EVENT)
             .map_err(|e| TemplateError::Compile(e.to_string()))?;
+        tera.add_raw_template("calendar/event.de.txt", embedded::CALENDAR_EVENT_DE)
+            .map_err(|e| TemplateError::Compile(e.to_string()))?;
         tera.add_raw_template("assistant/response.txt", embedded::ASSISTANT_RESPONSE)
             .map_err(|e| TemplateError::Compile(e.to_string()))?;
         tera.add_raw_template("assistant/approval.txt", embedded::APPROVAL_REQUEST)
             .map_err(|e| TemplateError::Compile(e.to_string()))?;
+        tera.add_raw_template(SYSTEM_PROMPT_TEMPLATE, embedded::SYSTEM_PROMPT)
+            .map_err(|e| TemplateError::Compile(e.to_string()))?;
+        tera.add_raw_template(DAILY_DIGEST_TEMPLATE, embedded::DAILY_DIGEST)
+            .map_err(|e| TemplateError::Compile(e.to_string()))?;
 
         // Load custom templates from directory if specified
         if let Some(ref dir) = config.templates_dir {
@@ -353,14 +454,20 @@ impl TemplateEngine {
                 let pattern = format!("{dir}/**/*");
                 match Tera::parse(&pattern) {
                     Ok(custom_tera) => {
-                        // Merge custom templates by re-parsing them
+                        // Add the template sources so that custom templates can
+                        // use variables; they override embedded ones by name
                         for name in custom_tera.get_template_names() {
-                            if let Ok(template) = custom_tera.render(name, &Context::new()) {
-                                debug!(template = %name, "Loaded custom template");
-                                // For custom templates, we add them as raw templates
-                                if let Err(e) = tera.add_raw_template(name, &template) {
-                                    debug!(error = %e, "Failed to add custom template {name}");
-                                }
+                            match std::fs::read_to_string(path.join(name)) {
+                                Ok(source) => {
+                                    if let Err(e) = tera.add_raw_template(name, &source) {
+                                        debug!(error = %e, "Failed to add custom template {name}");
+                                    } else {
+                                        debug!(template = %name, "Loaded custom template");
+                                    }
+                                },
+                                Err(e) => {
+                                    debug!(error = %e, "Failed to read custom template {name}");
+                                },
                             }
                         }
                         info!(dir = %dir, "Loaded custom templates");
@@ -378,20 +485,52 @@ impl TemplateEngine {
         // Register custom filters
         tera.register_filter("linebreaksbr", linebreaksbr_filter);
         tera.register_filter("truncate_words", truncate_words_filter);
+        tera.register_filter("strip_markdown", strip_markdown_filter);
+        tera.register_filter("format_number", format_number_filter);
+
+        let tera = Arc::new(RwLock::new(Arc::new(tera)));
+        let files = config
+            .templates_dir
+            .as_deref()
+            .filter(|dir| config.watch && Path::new(dir).is_dir())
+            .map(|dir| Arc::new(watch::TemplateFiles::new(dir)));
+        if let Some(ref files) = files {
+            watch::spawn_watcher(
+                Arc::downgrade(&tera),
+                Arc::clone(files),
+                Duration::from_millis(config.watch_interval_ms.max(1)),
+            );
+        }
 
         Ok(Self {
-            tera: Arc::new(tera),
+            tera,
+            files,
             config,
         })
     }
 
+    /// Current set of parsed templates
+    fn tera(&self) -> Arc<Tera> {
+        Arc::clone(&self.tera.read())
+    }
+
+    /// Reload changed custom templates now instead of waiting for the watcher
+    ///
+    /// Returns the number of reloaded templates. Templates with errors keep
+    /// their previous version. Does nothing unless `watch` is enabled.
+    pub fn reload(&self) -> usize {
+        self.files
+            .as_ref()
+            .map_or(0, |files| watch::reload(&self.tera, files))
+    }
+
     /// Render a template with the given context
     pub fn render(
         &self,
         template_name: &str,
         context: &TemplateContext,
     ) -> Result<String, TemplateError> {
-        self.tera
+        self.tera()
             .render(template_name, &context.inner)
             .map_err(TemplateError::from)
     }
@@ -420,7 +559,34 @@ impl TemplateEngine {
         self.render(template, &ctx)
     }
 
+    /// Pick the template variant for a locale
+    ///
+    /// For `weather/report.txt` and locale `de-AT`, tries
+    /// `weather/report.de-at.txt`, then `weather/report.de.txt`, then the
+    /// template itself. Returns the template name and the normalized locale.
+    fn localized(&self, name: &str, locale: Option<&str>) -> (String, String) {
+        let locale = locale
+            .map(|l| l.trim().replace('_', "-").to_ascii_lowercase())
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+        let language = locale.split('-').next().unwrap_or(DEFAULT_LOCALE);
+
+        if let Some((stem, extension)) = name.rsplit_once('.') {
+            let tera = self.tera();
+            for tag in [locale.as_str(), language] {
+                let candidate = format!("{stem}.{tag}.{extension}");
+                if tera.get_template(&candidate).is_ok() {
+                    return (candidate, locale);
+                }
+            }
+        }
+        (name.to_string(), locale)
+    }
+
     /// Render a weather report
+    ///
+    /// Uses the `weather/report.<locale>.txt` variant if one exists for
+    /// `data.locale`.
     pub fn render_weather_report(&self, data: &WeatherReportData) -> Result<String, TemplateError> {
         let mut ctx = TemplateContext::new();
         ctx.insert("location", &data.location);
@@ -432,10 +598,15 @@ impl TemplateEngine {
         ctx.insert("wind_speed", &data.wind_speed);
         ctx.insert("forecast", &data.forecast);
 
-        self.render("weather/report.txt", &ctx)
+        let (template, locale) = self.localized("weather/report.txt", data.locale.as_deref());
+        ctx.insert("locale", &locale);
+        self.render(&template, &ctx)
     }
 
     /// Render a calendar event summary
+    ///
+    /// Uses the `calendar/event.<locale>.txt` variant if one exists for
+    /// `data.locale`.
     pub fn render_calendar_event(&self, data: &CalendarEventData) -> Result<String, TemplateError> {
         let mut ctx = TemplateContext::new();
         ctx.insert("title", &data.title);
@@ -445,7 +616,9 @@ impl TemplateEngine {
         ctx.insert("description", &data.description);
         ctx.insert("attendees", &data.attendees);
 
-        self.render("calendar/event.txt", &ctx)
+        let (template, locale) = self.localized("calendar/event.txt", data.locale.as_deref());
+        ctx.insert("locale", &locale);
+        self.render(&template, &ctx)
     }
 
     /// Render an assistant response
@@ -458,6 +631,7 @@ impl TemplateEngine {
         ctx.insert("suggestions", &data.suggestions);
         ctx.insert("requires_approval", &data.requires_approval);
         ctx.insert("approval_command", &data.approval_command);
+        ctx.insert("messenger", &data.messenger);
 
         self.render("assistant/response.txt", &ctx)
     }
@@ -479,19 +653,110 @@ impl TemplateEngine {
         self.render("assistant/approval.txt", &ctx)
     }
 
+    /// Render the assistant's system prompt
+    ///
+    /// Unset profile fields are passed as empty values so that the template
+    /// can leave them out.
+    pub fn render_system_prompt(&self, context: &PromptContext) -> Result<String, TemplateError> {
+        let mut ctx = TemplateContext::new();
+        ctx.insert("user_name", &context.user_name);
+        ctx.insert("date", &context.today.format("%A, %Y-%m-%d").to_string());
+        ctx.insert("location", &context.location);
+
+        Ok(self
+            .render(SYSTEM_PROMPT_TEMPLATE, &ctx)?
+            .trim()
+            .to_string())
+    }
+
+    /// Render the daily digest pushed to messenger contacts
+    pub fn render_daily_digest(&self, data: &DigestTemplateData) -> Result<String, TemplateError> {
+        let mut ctx = TemplateContext::new();
+        ctx.insert("briefing", &data.briefing);
+        ctx.insert("date", &data.date);
+        ctx.insert("messenger", &data.messenger);
+
+        self.render(DAILY_DIGEST_TEMPLATE, &ctx)
+    }
+
     /// Check if a template exists
     #[must_use]
     pub fn template_exists(&self, name: &str) -> bool {
-        self.tera.get_template_names().any(|n| n == name)
+        self.tera().get_template_names().any(|n| n == name)
     }
 
     /// List all available template names
     #[must_use]
-    pub fn list_templates(&self) -> Vec<&str> {
-        self.tera.get_template_names().collect()
+    pub fn list_templates(&self) -> Vec<String> {
+        self.tera()
+            .get_template_names()
+            .map(ToString::to_string)
+            .collect()
     }
 }
 
+impl PromptTemplatePort for TemplateEngine {
+    fn render_system_prompt(&self, context: &PromptContext) -> Result<String, ApplicationError> {
+        Self::render_system_prompt(self, context)
+            .map_err(|e| ApplicationError::Internal(e.to_string()))
+    }
+}
+
+impl DigestTemplatePort for TemplateEngine {
+    fn render_daily_digest(&self, data: &DigestTemplateData) -> Result<String, ApplicationError> {
+        Self::render_daily_digest(self, data).map_err(|e| ApplicationError::Internal(e.to_string()))
+    }
+}
+
+impl EmailTemplatePort for TemplateEngine {
+    fn render_email_draft(
+        &self,
+        data: &EmailTemplateData,
+    ) -> Result<RenderedEmail, ApplicationError> {
+        let data = EmailDraftData {
+            recipient: data.recipient.clone(),
+            recipient_email: data.recipient_email.clone(),
+            subject: data.subject.clone(),
+            body: data.body.clone(),
+            sender: data.sender.clone(),
+            cc: data.cc.clone(),
+            attachments: Vec::new(),
+        };
+
+        let text = Self::render_email_draft(self, &data, false)
+            .map_err(|e| ApplicationError::Internal(e.to_string()))?;
+        let html = match Self::render_email_draft(self, &data, true) {
+            Ok(html) => Some(html),
+            Err(e) => {
+                debug!(error = %e, "HTML email draft not rendered, storing plaintext only");
+                None
+            },
+        };
+
+        Ok(RenderedEmail {
+            text: strip_header_lines(&text),
+            html,
+        })
+    }
+}
+
+/// Header lines the plaintext draft template starts with
+const EMAIL_HEADER_PREFIXES: &[&str] = &["To:", "Subject:", "Cc:"];
+
+/// Drop the header preview of a rendered plaintext draft
+///
+/// Recipient and subject are sent as real headers, so only the message
+/// from the greeting on is kept.
+fn strip_header_lines(text: &str) -> String {
+    let mut lines = text.lines().peekable();
+    while lines
+        .next_if(|line| EMAIL_HEADER_PREFIXES.iter().any(|p| line.starts_with(p)))
+        .is_some()
+    {}
+    while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+    lines.collect::<Vec<_>>().join("\n").trim_end().to_string()
+}
+
 /// Custom filter: Convert newlines to <br> tags
 fn linebreaksbr_filter(value: &Value, _args: &HashMap<String, Value>) -> tera::Result<Value> {
     let s = value
@@ -518,6 +783,161 @@ fn truncate_words_filter(value: &Value, args: &HashMap<String, Value>) -> tera::
     Ok(Value::String(format!("{truncated}...")))
 }
 
+/// Custom filter: Format a number with the locale's decimal separator
+///
+/// Arguments: `decimals` (default 1) and `locale` (default `en`).
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_number_filter(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let number = value
+        .as_f64()
+        .ok_or_else(|| tera::Error::msg("format_number requires a number"))?;
+
+    let decimals = args
+        .get("decimals")
+        .and_then(Value::as_u64)
+        .map_or(1, |d| d.min(6) as usize);
+    let language = args
+        .get("locale")
+        .and_then(Value::as_str)
+        .and_then(|l| l.split(['-', '_']).next())
+        .unwrap_or(DEFAULT_LOCALE)
+        .to_ascii_lowercase();
+
+    let formatted = format!("{number:.decimals$}");
+    Ok(Value::String(
+        if DECIMAL_COMMA_LANGUAGES.contains(&language.as_str()) {
+            formatted.replace('.', ",")
+        } else {
+            formatted
+        },
+    ))
+}
+
+/// Custom filter: Convert Markdown to messenger-friendly text
+///
+/// Headers, bullets and emphasis become plain text. With
+/// `style="whatsapp"`, bold and italic use WhatsApp's `*bold*` and
+/// `_italic_` formatting instead of being dropped.
+fn strip_markdown_filter(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let s = value
+        .as_str()
+        .ok_or_else(|| tera::Error::msg("strip_markdown requires a string"))?;
+
+    let whatsapp = args
+        .get("style")
+        .and_then(Value::as_str)
+        .is_some_and(|style| style.eq_ignore_ascii_case("whatsapp"));
+
+    Ok(Value::String(strip_markdown(s, whatsapp)))
+}
+
+/// Convert Markdown line by line; fenced code is kept verbatim
+fn strip_markdown(text: &str, whatsapp: bool) -> String {
+    let mut in_code = false;
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        let heading = trimmed[level..].strip_prefix(' ');
+
+        if let (1..=6, Some(heading)) = (level, heading) {
+            let heading = strip_emphasis(heading.trim().trim_end_matches('#').trim_end(), false);
+            lines.push(if whatsapp {
+                format!("*{heading}*")
+            } else {
+                heading
+            });
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| trimmed.strip_prefix(*bullet))
+        {
+            lines.push(format!("{indent}• {}", strip_emphasis(item, whatsapp)));
+        } else {
+            lines.push(strip_emphasis(line, whatsapp));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Replace `**bold**`, `__bold__`, `*italic*` and `_italic_` markers
+fn strip_emphasis(text: &str, whatsapp: bool) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let marker = chars[i];
+        if marker == '*' || marker == '_' {
+            let width = if chars.get(i + 1) == Some(&marker) {
+                2
+            } else {
+                1
+            };
+            let start = i + width;
+            // `_` inside words (snake_case) is not emphasis
+            let at_boundary = marker == '*' || i == 0 || !chars[i - 1].is_alphanumeric();
+            let opens = chars.get(start).is_some_and(|c| !c.is_whitespace());
+
+            let closing = if at_boundary && opens {
+                find_closing_marker(&chars, start, marker, width)
+            } else {
+                None
+            };
+
+            if let Some(end) = closing {
+                let inner: String = chars[start..end].iter().collect();
+                let inner = strip_emphasis(&inner, whatsapp);
+                let wrap = match (whatsapp, width) {
+                    (false, _) => "",
+                    (true, 2) => "*",
+                    (true, _) => "_",
+                };
+                out.push_str(wrap);
+                out.push_str(&inner);
+                out.push_str(wrap);
+                i = end + width;
+                continue;
+            }
+        }
+        out.push(marker);
+        i += 1;
+    }
+
+    out
+}
+
+/// Find the closing emphasis marker, skipping markers of a different width
+fn find_closing_marker(chars: &[char], start: usize, marker: char, width: usize) -> Option<usize> {
+    let mut j = start + 1;
+    while j + width <= chars.len() {
+        if chars[j] != marker {
+            j += 1;
+            continue;
+        }
+        let run = chars[j..].iter().take_while(|&&c| c == marker).count();
+        let closes = run == width
+            && !chars[j - 1].is_whitespace()
+            && (marker == '*' || chars.get(j + width).is_none_or(|c| !c.is_alphanumeric()));
+        if closes {
+            return Some(j);
+        }
+        j += run;
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -551,6 +971,46 @@ mod tests {
         assert!(email.contains("Alice"));
     }
 
+    #[test]
+    fn email_template_port_renders_greeting_and_signature() {
+        let engine = TemplateEngine::new().unwrap();
+        let data = EmailTemplateData {
+            recipient: "John".to_string(),
+            recipient_email: "john@example.com".to_string(),
+            subject: "Meeting Tomorrow".to_string(),
+            body: "Let's discuss the project.".to_string(),
+            sender: "Alice".to_string(),
+            cc: vec!["bob@example.com".to_string()],
+        };
+
+        let rendered = EmailTemplatePort::render_email_draft(&engine, &data).unwrap();
+
+        assert_eq!(
+            rendered.text,
+            "Dear John,\n\nLet's discuss the project.\n\nBest regards,\nAlice"
+        );
+        let html = rendered.html.unwrap();
+        assert!(html.contains("<p>Dear John,</p>"));
+        assert!(html.contains("Best regards,<br>Alice"));
+    }
+
+    #[test]
+    fn email_signature_without_sender_name() {
+        let engine = TemplateEngine::new().unwrap();
+        let data = EmailTemplateData {
+            recipient: "John".to_string(),
+            recipient_email: "john@example.com".to_string(),
+            subject: "Hi".to_string(),
+            body: "Hello.".to_string(),
+            sender: String::new(),
+            cc: vec![],
+        };
+
+        let rendered = EmailTemplatePort::render_email_draft(&engine, &data).unwrap();
+
+        assert!(rendered.text.ends_with("Best regards,"));
+    }
+
     #[test]
     fn test_weather_report_rendering() {
         let engine = TemplateEngine::new().unwrap();
@@ -564,6 +1024,7 @@ mod tests {
             humidity: 65,
             wind_speed: 15.0,
             forecast: vec![],
+            locale: None,
         };
 
         let result = engine.render_weather_report(&data);
@@ -574,6 +1035,116 @@ mod tests {
         assert!(report.contains("⛅"));
     }
 
+    fn localized_weather(locale: Option<&str>) -> WeatherReportData {
+        WeatherReportData {
+            location: "Berlin".to_string(),
+            temperature: 22.5,
+            unit: "C".to_string(),
+            condition: "Sunny".to_string(),
+            emoji: "☀️".to_string(),
+            humidity: 40,
+            wind_speed: 12.0,
+            forecast: vec![ForecastDay {
+                day: "Mo".to_string(),
+                high: 24.0,
+                low: 13.5,
+                condition: "Sunny".to_string(),
+                emoji: "☀️".to_string(),
+            }],
+            locale: locale.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_weather_report_in_en_and_de() {
+        let engine = TemplateEngine::new().unwrap();
+
+        let en = engine
+            .render_weather_report(&localized_weather(Some("en")))
+            .unwrap();
+        assert!(en.contains("Weather for Berlin"));
+        assert!(en.contains("Temperature: 22.5°C"));
+        assert!(en.contains("Wind: 12.0 km/h"));
+        assert!(en.contains("24.0°/13.5°"));
+
+        let de = engine
+            .render_weather_report(&localized_weather(Some("de")))
+            .unwrap();
+        assert!(de.contains("Wetter für Berlin"));
+        assert!(de.contains("Temperatur: 22,5 °C"));
+        assert!(de.contains("Luftfeuchtigkeit: 40 %"));
+        assert!(de.contains("24,0°/13,5°"));
+        assert!(de.contains("Vorhersage"));
+    }
+
+    #[test]
+    fn test_weather_report_locale_falls_back() {
+        let engine = TemplateEngine::new().unwrap();
+
+        // Region falls back to the language template
+        let at = engine
+            .render_weather_report(&localized_weather(Some("de_AT")))
+            .unwrap();
+        assert!(at.contains("Wetter für Berlin"));
+
+        // Unknown locales use the default template with their number format
+        let fr = engine
+            .render_weather_report(&localized_weather(Some("fr-FR")))
+            .unwrap();
+        assert!(fr.contains("Weather for Berlin"));
+        assert!(fr.contains("22,5°C"));
+
+        let default = engine
+            .render_weather_report(&localized_weather(None))
+            .unwrap();
+        assert!(default.contains("22.5°C"));
+    }
+
+    #[test]
+    fn test_calendar_event_in_de() {
+        let engine = TemplateEngine::new().unwrap();
+
+        let data = CalendarEventData {
+            title: "Teamrunde".to_string(),
+            start_time: "10:00".to_string(),
+            end_time: "11:00".to_string(),
+            location: None,
+            description: None,
+            attendees: vec!["Alice".to_string()],
+            locale: Some("de".to_string()),
+        };
+
+        let event = engine.render_calendar_event(&data).unwrap();
+        assert!(event.contains("Teilnehmer: Alice"));
+    }
+
+    #[test]
+    fn test_format_number_filter() {
+        let format = |number: f64, args: &[(&str, Value)]| {
+            let args = args
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), v.clone()))
+                .collect();
+            format_number_filter(&Value::from(number), &args)
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(format(1.23456, &[]), "1.2");
+        assert_eq!(format(1.23456, &[("decimals", Value::from(2))]), "1.23");
+        assert_eq!(format(-0.5, &[("locale", Value::from("de-DE"))]), "-0,5");
+        assert_eq!(
+            format(
+                1234.4,
+                &[("locale", Value::from("en")), ("decimals", Value::from(0))]
+            ),
+            "1234"
+        );
+        assert!(format_number_filter(&Value::from("x"), &HashMap::new()).is_err());
+    }
+
     #[test]
     fn test_calendar_event_rendering() {
         let engine = TemplateEngine::new().unwrap();
@@ -585,6 +1156,7 @@ mod tests {
             location: Some("Conference Room A".to_string()),
             description: Some("Weekly sync".to_string()),
             attendees: vec!["Alice".to_string(), "Bob".to_string()],
+            locale: None,
         };
 
         let result = engine.render_calendar_event(&data);
@@ -604,6 +1176,7 @@ mod tests {
             suggestions: vec!["Option A".to_string(), "Option B".to_string()],
             requires_approval: false,
             approval_command: None,
+            messenger: None,
         };
 
         let result = engine.render_assistant_response(&data);
@@ -613,6 +1186,40 @@ mod tests {
         assert!(response.contains("Option A"));
     }
 
+    #[test]
+    fn test_assistant_response_for_messenger_strips_markdown() {
+        let engine = TemplateEngine::new().unwrap();
+
+        let data = AssistantResponseData {
+            content: "## Result\n**Done** with *care*".to_string(),
+            suggestions: vec![],
+            requires_approval: false,
+            approval_command: None,
+            messenger: Some("signal".to_string()),
+        };
+
+        let response = engine.render_assistant_response(&data).unwrap();
+        assert!(response.starts_with("Result\nDone with care"));
+    }
+
+    #[test]
+    fn test_daily_digest_strips_markdown_for_messenger() {
+        let engine = TemplateEngine::new().unwrap();
+
+        let data = DigestTemplateData {
+            briefing: "☀️ Good morning!\n\n**📅 Calendar**\n- Standup at 09:00\n".to_string(),
+            date: "Friday, 2026-10-16".to_string(),
+            messenger: "whatsapp".to_string(),
+        };
+
+        let digest = engine.render_daily_digest(&data).unwrap();
+        assert!(digest.contains("*📅 Calendar*"));
+        assert!(!digest.contains("**"));
+        assert!(
+            digest.ends_with("Daily digest for Friday, 2026-10-16. Reply to ask about any item.")
+        );
+    }
+
     #[test]
     fn test_approval_request_rendering() {
         let engine = TemplateEngine::new().unwrap();
@@ -635,9 +1242,9 @@ mod tests {
         let engine = TemplateEngine::new().unwrap();
         let templates = engine.list_templates();
 
-        assert!(templates.contains(&"email/draft.txt"));
-        assert!(templates.contains(&"weather/report.txt"));
-        assert!(templates.contains(&"calendar/event.txt"));
+        assert!(templates.iter().any(|t| t == "email/draft.txt"));
+        assert!(templates.iter().any(|t| t == "weather/report.txt"));
+        assert!(templates.iter().any(|t| t == "calendar/event.txt"));
     }
 
     #[test]
@@ -703,6 +1310,60 @@ mod tests {
         assert!(result.as_str().unwrap().ends_with("..."));
     }
 
+    fn strip(text: &str, style: Option<&str>) -> String {
+        let mut args = HashMap::new();
+        if let Some(style) = style {
+            args.insert("style".to_string(), Value::String(style.to_string()));
+        }
+        strip_markdown_filter(&Value::String(text.to_string()), &args)
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_strip_markdown_headers() {
+        assert_eq!(
+            strip("# Title\n### Sub ###\ntext", None),
+            "Title\nSub\ntext"
+        );
+        assert_eq!(strip("## **Plan**", Some("whatsapp")), "*Plan*");
+        assert_eq!(strip("#hashtag", None), "#hashtag");
+    }
+
+    #[test]
+    fn test_strip_markdown_lists() {
+        assert_eq!(
+            strip("- one\n* two\n  + nested\n1. first", None),
+            "• one\n• two\n  • nested\n1. first"
+        );
+    }
+
+    #[test]
+    fn test_strip_markdown_emphasis() {
+        assert_eq!(
+            strip("**bold**, *italic*, __strong__ and _em_", None),
+            "bold, italic, strong and em"
+        );
+        assert_eq!(
+            strip("**bold** and *italic*", Some("whatsapp")),
+            "*bold* and _italic_"
+        );
+    }
+
+    #[test]
+    fn test_strip_markdown_keeps_literal_markers() {
+        assert_eq!(strip("2 * 3 = 6", None), "2 * 3 = 6");
+        assert_eq!(strip("use snake_case_names", None), "use snake_case_names");
+        assert_eq!(strip("**unclosed", None), "**unclosed");
+    }
+
+    #[test]
+    fn test_strip_markdown_keeps_code_blocks() {
+        assert_eq!(strip("```\n# not a header\n```", None), "# not a header");
+    }
+
     #[test]
     fn test_template_config_default() {
         let config = TemplateConfig::default();
@@ -807,6 +1468,7 @@ mod tests {
                     emoji: "☁️".to_string(),
                 },
             ],
+            locale: None,
         };
 
         let result = engine.render_weather_report(&data);
@@ -828,6 +1490,7 @@ mod tests {
             location: None,
             description: None,
             attendees: vec![],
+            locale: None,
         };
 
         let result = engine.render_calendar_event(&data);
@@ -846,6 +1509,7 @@ mod tests {
             suggestions: vec![],
             requires_approval: true,
             approval_command: Some("approve 123".to_string()),
+            messenger: None,
         };
 
         let result = engine.render_assistant_response(&data);
@@ -918,6 +1582,7 @@ mod tests {
             humidity: 50,
             wind_speed: 10.0,
             forecast: vec![],
+            locale: None,
         };
         assert!(format!("{weather:?}").contains("WeatherReportData"));
 
@@ -928,6 +1593,7 @@ mod tests {
             location: None,
             description: None,
             attendees: vec![],
+            locale: None,
         };
         assert!(format!("{calendar:?}").contains("CalendarEventData"));
 
@@ -936,6 +1602,7 @@ mod tests {
             suggestions: vec![],
             requires_approval: false,
             approval_command: None,
+            messenger: None,
         };
         assert!(format!("{response:?}").contains("AssistantResponseData"));
     }
@@ -959,6 +1626,116 @@ mod tests {
         assert!(!inner.contains_key("any_key"));
     }
 
+    fn prompt_context(user_name: Option<&str>, location: Option<&str>) -> PromptContext {
+        PromptContext {
+            user_name: user_name.map(str::to_string),
+            today: chrono::NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+            location: location.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_system_prompt_includes_context() {
+        let engine = TemplateEngine::new().unwrap();
+        let prompt = engine
+            .render_system_prompt(&prompt_context(Some("Anna"), Some("52.520000, 13.405000")))
+            .unwrap();
+
+        assert!(prompt.starts_with("You are PiSovereign"));
+        assert!(prompt.contains("Today is Friday, 2026-10-16."));
+        assert!(prompt.contains("The user's name is Anna."));
+        assert!(prompt.contains("52.520000, 13.405000"));
+    }
+
+    #[test]
+    fn test_system_prompt_omits_missing_profile_fields() {
+        let engine = TemplateEngine::new().unwrap();
+        let prompt = engine
+            .render_system_prompt(&prompt_context(None, None))
+            .unwrap();
+
+        assert!(prompt.ends_with("Today is Friday, 2026-10-16."));
+        assert!(!prompt.contains("name"));
+        assert!(!prompt.contains("located"));
+        assert!(!prompt.contains("None"));
+    }
+
+    #[test]
+    fn test_custom_system_prompt_overrides_embedded() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("assistant")).unwrap();
+        std::fs::write(
+            dir.path().join(SYSTEM_PROMPT_TEMPLATE),
+            "Hi{% if user_name %} {{ user_name }}{% endif %}, it is {{ date }}.",
+        )
+        .unwrap();
+
+        let engine = TemplateEngine::with_config(TemplateConfig {
+            templates_dir: Some(dir.path().to_string_lossy().into_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+        let prompt = engine
+            .render_system_prompt(&prompt_context(Some("Anna"), None))
+            .unwrap();
+
+        assert_eq!(prompt, "Hi Anna, it is Friday, 2026-10-16.");
+    }
+
+    fn watched_engine(dir: &tempfile::TempDir, interval_ms: u64) -> TemplateEngine {
+        TemplateEngine::with_config(TemplateConfig {
+            templates_dir: Some(dir.path().to_string_lossy().into_owned()),
+            watch: true,
+            watch_interval_ms: interval_ms,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_watch_reloads_edited_template() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("greeting.txt"), "Hello {{ name }}").unwrap();
+        let engine = watched_engine(&dir, 50);
+
+        let mut ctx = TemplateContext::new();
+        ctx.insert("name", "Anna");
+        assert_eq!(engine.render("greeting.txt", &ctx).unwrap(), "Hello Anna");
+
+        std::fs::write(dir.path().join("greeting.txt"), "Good morning, {{ name }}!").unwrap();
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let mut output = engine.render("greeting.txt", &ctx).unwrap();
+        while output != "Good morning, Anna!" && std::time::Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(20));
+            output = engine.render("greeting.txt", &ctx).unwrap();
+        }
+        assert_eq!(output, "Good morning, Anna!");
+    }
+
+    #[test]
+    fn test_reload_keeps_last_good_template_on_error() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("greeting.txt"), "Hello {{ name }}").unwrap();
+        // Long interval so only the explicit reload picks up the changes
+        let engine = watched_engine(&dir, 60_000);
+
+        std::fs::write(dir.path().join("greeting.txt"), "Hello {{ name").unwrap();
+        std::fs::write(dir.path().join("farewell.txt"), "Bye {{ name }}").unwrap();
+        assert_eq!(engine.reload(), 1);
+
+        let mut ctx = TemplateContext::new();
+        ctx.insert("name", "Anna");
+        assert_eq!(engine.render("greeting.txt", &ctx).unwrap(), "Hello Anna");
+        assert_eq!(engine.render("farewell.txt", &ctx).unwrap(), "Bye Anna");
+    }
+
+    #[test]
+    fn test_reload_without_watch_is_noop() {
+        let engine = TemplateEngine::new().unwrap();
+        assert_eq!(engine.reload(), 0);
+    }
+
     #[test]
     fn test_render_nonexistent_template() {
         let engine = TemplateEngine::new().unwrap();
@@ -973,6 +1750,8 @@ mod tests {
             templates_dir: Some("/custom/templates".to_string()),
             use_embedded_fallback: false,
             auto_escape: false,
+            watch: true,
+            watch_interval_ms: 250,
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: TemplateConfig = serde_json::from_str(&json).unwrap();
@@ -1016,6 +1795,7 @@ mod tests {
                 condition: "Clear".to_string(),
                 emoji: "🌤️".to_string(),
             }],
+            locale: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: WeatherReportData = serde_json::from_str(&json).unwrap();
@@ -1032,6 +1812,7 @@ mod tests {
             location: Some("Room A".to_string()),
             description: Some("Important meeting".to_string()),
             attendees: vec!["Alice".to_string(), "Bob".to_string()],
+            locale: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: CalendarEventData = serde_json::from_str(&json).unwrap();
@@ -1046,6 +1827,7 @@ mod tests {
             suggestions: vec!["Option 1".to_string(), "Option 2".to_string()],
             requires_approval: true,
             approval_command: Some("approve 123".to_string()),
+            messenger: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: AssistantResponseData = serde_json::from_str(&json).unwrap();
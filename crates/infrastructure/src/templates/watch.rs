@@ -0,0 +1,157 @@
+//! Hot reload of custom templates
+//!
+//! Polls the templates directory for changed files and swaps a re-parsed
+//! [`Tera`] instance into the engine. Templates that fail to parse keep their
+//! last good version.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, SystemTime};
+
+use parking_lot::{Mutex, RwLock};
+use tera::Tera;
+use tracing::{debug, info, warn};
+
+/// Modification time and size, to detect changes on coarse-grained clocks
+type FileStamp = (SystemTime, u64);
+
+/// Custom template files and their last seen state
+#[derive(Debug)]
+pub(super) struct TemplateFiles {
+    dir: PathBuf,
+    seen: Mutex<HashMap<String, FileStamp>>,
+}
+
+impl TemplateFiles {
+    /// Track the templates in `dir`, taking the current files as loaded
+    pub(super) fn new(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        let seen = scan(&dir).into_iter().collect();
+        Self {
+            dir,
+            seen: Mutex::new(seen),
+        }
+    }
+
+    /// Templates that were added or modified since the last call
+    ///
+    /// Returns template names (relative paths with `/`) and their sources.
+    pub(super) fn changed(&self) -> Vec<(String, String)> {
+        let mut seen = self.seen.lock();
+        let mut changed = Vec::new();
+
+        for (name, stamp) in scan(&self.dir) {
+            if seen.get(&name) == Some(&stamp) {
+                continue;
+            }
+            match std::fs::read_to_string(self.dir.join(&name)) {
+                Ok(source) => {
+                    seen.insert(name.clone(), stamp);
+                    changed.push((name, source));
+                },
+                Err(e) => debug!(template = %name, error = %e, "Failed to read template"),
+            }
+        }
+
+        changed
+    }
+}
+
+/// Apply changed templates to the shared Tera instance
+///
+/// Each template is added to a copy first, so a template with errors is
+/// skipped without affecting the others. Returns the number of templates
+/// that were reloaded.
+pub(super) fn reload(tera: &RwLock<Arc<Tera>>, files: &TemplateFiles) -> usize {
+    let changed = files.changed();
+    if changed.is_empty() {
+        return 0;
+    }
+
+    let mut next = Tera::clone(&tera.read());
+    let mut reloaded = 0;
+    for (name, source) in changed {
+        let mut candidate = next.clone();
+        match candidate.add_raw_template(&name, &source) {
+            Ok(()) => {
+                next = candidate;
+                reloaded += 1;
+                info!(template = %name, "Reloaded template");
+            },
+            Err(e) => {
+                warn!(template = %name, error = %e, "Template has errors, keeping previous version");
+            },
+        }
+    }
+
+    if reloaded > 0 {
+        *tera.write() = Arc::new(next);
+    }
+    reloaded
+}
+
+/// Spawn a thread that reloads changed templates every `interval`
+///
+/// The thread stops once the template engine has been dropped.
+pub(super) fn spawn_watcher(
+    tera: Weak<RwLock<Arc<Tera>>>,
+    files: Arc<TemplateFiles>,
+    interval: Duration,
+) {
+    let dir = files.dir.display().to_string();
+    let spawned = std::thread::Builder::new()
+        .name("template-watcher".to_string())
+        .spawn(move || {
+            loop {
+                std::thread::sleep(interval);
+                let Some(tera) = tera.upgrade() else {
+                    debug!("Template engine dropped, stopping watcher");
+                    break;
+                };
+                reload(&tera, &files);
+            }
+        });
+
+    match spawned {
+        Ok(_) => info!(dir = %dir, interval_ms = interval.as_millis(), "Watching templates"),
+        Err(e) => warn!(error = %e, "Failed to start template watcher"),
+    }
+}
+
+/// All template files below `dir`, keyed by their template name
+fn scan(dir: &Path) -> Vec<(String, FileStamp)> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // Skip hidden files such as editor swap files
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let (Ok(modified), Ok(relative)) = (metadata.modified(), path.strip_prefix(dir)) else {
+                continue;
+            };
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((name, (modified, metadata.len())));
+        }
+    }
+
+    files
+}
@@ -4,7 +4,7 @@
 
 use chrono::{DateTime, Utc};
 use domain::entities::{ChatMessage, Conversation, ConversationSource, MessageRole};
-use domain::value_objects::ConversationId;
+use domain::value_objects::{ConversationId, UserId};
 use uuid::Uuid;
 
 /// Builder for creating test conversations.
@@ -102,6 +102,8 @@ impl TestConversation {
             persisted_message_count: 0,
             source: ConversationSource::Http,
             phone_number: None,
+            user_id: UserId::default(),
+            summary: None,
         };
         // If we have messages, mark them as not yet persisted
         // (caller can call mark_messages_persisted() if needed)
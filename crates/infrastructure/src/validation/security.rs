@@ -115,7 +115,9 @@ impl SecurityValidator {
     #[must_use]
     pub fn validate(config: &AppConfig) -> Vec<SecurityWarning> {
         let mut warnings = Vec::new();
-        let is_production = config.environment == Some(Environment::Production);
+        let is_production = config
+            .environment
+            .is_some_and(Environment::is_production_like);
 
         // Check TLS certificate verification
         Self::check_tls_verification(config, is_production, &mut warnings);
@@ -146,7 +148,9 @@ impl SecurityValidator {
     /// Returns `true` if the server should refuse to start.
     #[must_use]
     pub fn should_block_startup(config: &AppConfig, warnings: &[SecurityWarning]) -> bool {
-        let is_production = config.environment == Some(Environment::Production);
+        let is_production = config
+            .environment
+            .is_some_and(Environment::is_production_like);
         let has_critical = warnings.iter().any(SecurityWarning::is_critical);
         let allow_insecure = std::env::var("PISOVEREIGN_ALLOW_INSECURE_CONFIG")
             .map(|v| v == "true" || v == "1")
@@ -227,6 +231,22 @@ impl SecurityValidator {
                 "Specify allowed_origins in production to restrict cross-origin requests",
             ));
         }
+
+        // Browsers reject credentials with a wildcard origin, so they are
+        // never sent for such routes
+        for route in &config.server.cors_routes {
+            let policy = config.server.cors_policy(&route.path_prefix);
+            if route.allow_credentials && route.enabled && policy.allows_any_origin() {
+                warnings.push(SecurityWarning::warning(
+                    "SEC010",
+                    format!(
+                        "CORS credentials for '{}' are ignored because any origin is allowed",
+                        route.path_prefix
+                    ),
+                    "List explicit allowed_origins for routes with allow_credentials",
+                ));
+            }
+        }
     }
 
     fn check_plaintext_secrets(
@@ -340,6 +360,7 @@ mod tests {
         config.security.api_keys = vec![crate::ApiKeyEntry {
             hash: "$argon2id$v=19$m=19456,t=2,p=1$test".to_string(),
             user_id: "test-user".to_string(),
+            scopes: Vec::new(),
         }];
 
         let warnings = SecurityValidator::validate(&config);
@@ -378,6 +399,32 @@ mod tests {
         assert!(warnings.iter().any(|w| w.code == "SEC002"));
     }
 
+    #[test]
+    fn validate_warns_on_wildcard_cors_with_credentials() {
+        let mut config = create_test_config();
+        config.server.cors_routes = vec![credentialed_route("/v1", vec!["*".to_string()])];
+
+        let warnings = SecurityValidator::validate(&config);
+        assert!(warnings.iter().any(|w| w.code == "SEC010"));
+
+        config.server.cors_routes = vec![credentialed_route(
+            "/v1",
+            vec!["https://app.example.com".to_string()],
+        )];
+        let warnings = SecurityValidator::validate(&config);
+        assert!(!warnings.iter().any(|w| w.code == "SEC010"));
+    }
+
+    fn credentialed_route(prefix: &str, origins: Vec<String>) -> crate::config::CorsRouteConfig {
+        crate::config::CorsRouteConfig {
+            path_prefix: prefix.to_string(),
+            enabled: true,
+            allowed_origins: Some(origins),
+            allow_credentials: true,
+            max_age_secs: None,
+        }
+    }
+
     #[test]
     fn validate_critical_cors_in_production() {
         let config = create_production_config();
@@ -394,6 +441,7 @@ mod tests {
         config.security.api_keys.push(crate::config::ApiKeyEntry {
             hash: "plaintext-not-hashed".to_string(),
             user_id: "user1".to_string(),
+            scopes: Vec::new(),
         });
 
         let warnings = SecurityValidator::validate(&config);
@@ -408,6 +456,7 @@ mod tests {
         config.security.api_keys.push(crate::config::ApiKeyEntry {
             hash: "plaintext-not-hashed".to_string(),
             user_id: "user1".to_string(),
+            scopes: Vec::new(),
         });
 
         let warnings = SecurityValidator::validate(&config);
@@ -422,6 +471,7 @@ mod tests {
         config.security.api_keys.push(crate::config::ApiKeyEntry {
             hash: "$argon2id$v=19$m=19456,t=2,p=1$abc$def".to_string(),
             user_id: "user1".to_string(),
+            scopes: Vec::new(),
         });
 
         let warnings = SecurityValidator::validate(&config);
@@ -444,6 +494,7 @@ mod tests {
         config.security.api_keys.push(crate::config::ApiKeyEntry {
             hash: "$argon2id$v=19$m=19456,t=2,p=1$abc$def".to_string(),
             user_id: "user1".to_string(),
+            scopes: Vec::new(),
         });
 
         let warnings = SecurityValidator::validate(&config);
@@ -478,6 +529,17 @@ mod tests {
         assert!(SecurityValidator::should_block_startup(&config, &warnings));
     }
 
+    #[test]
+    fn should_block_startup_in_staging_with_critical() {
+        let config = AppConfig {
+            environment: Some(Environment::Staging),
+            ..Default::default()
+        };
+        let warnings = vec![SecurityWarning::critical("TEST", "Test critical", "Fix it")];
+
+        assert!(SecurityValidator::should_block_startup(&config, &warnings));
+    }
+
     #[test]
     fn should_not_block_startup_in_development() {
         let config = create_test_config();
@@ -501,6 +563,7 @@ mod tests {
         config.security.api_keys.push(crate::config::ApiKeyEntry {
             hash: "plaintext-key".to_string(),
             user_id: "user1".to_string(),
+            scopes: Vec::new(),
         });
 
         let warnings = SecurityValidator::validate(&config);
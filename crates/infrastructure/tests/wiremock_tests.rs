@@ -8,6 +8,8 @@
 //! - Weather/WhatsApp/Ollama API mocking
 //! - Encryption and API key hashing
 //! - Degraded inference mode
+//! - Ollama model registry details
+//! - CardDAV batch contact import
 
 #![allow(
     dead_code,
@@ -32,8 +34,8 @@ use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use infrastructure::{
-    CircuitBreakerConfig, CorrelatedClientConfig, CorrelatedHttpClient, RetryConfig,
-    SecurityValidator, SecurityWarning, WarningSeverity, X_REQUEST_ID,
+    CircuitBreakerConfig, CorrelatedClientConfig, CorrelatedHttpClient, JitterStrategy,
+    RetryConfig, SecurityValidator, SecurityWarning, WarningSeverity, X_REQUEST_ID,
 };
 
 // ============================================================================
@@ -627,6 +629,7 @@ mod proptest_tests {
                 max_retries: 10,
                 jitter_enabled: true,
                 jitter_factor,
+                jitter: JitterStrategy::None,
             };
 
             // Run multiple times to test jitter bounds
@@ -957,6 +960,101 @@ mod weather_adapter_tests {
     }
 }
 
+// ============================================================================
+// Negative Cache Tests (Wiremock)
+// ============================================================================
+
+mod negative_cache_tests {
+    use super::*;
+    use application::ports::{TransitPort, WeatherPort};
+    use domain::value_objects::GeoLocation;
+    use infrastructure::{
+        MokaCache, NegativeCache,
+        adapters::{TransitAdapter, WeatherAdapter},
+    };
+    use integration_transit::{
+        HafasTransitClient, NominatimConfig, NominatimGeocodingClient, TransitConfig,
+    };
+    use integration_weather::WeatherConfig;
+    use std::sync::Arc;
+
+    fn negative_cache() -> NegativeCache {
+        NegativeCache::new(Arc::new(MokaCache::new()), Duration::from_secs(300))
+    }
+
+    fn transit_adapter(geocoding_url: String) -> TransitAdapter {
+        let geocoding_config = NominatimConfig {
+            base_url: geocoding_url,
+            ..NominatimConfig::for_testing()
+        };
+        TransitAdapter::new(
+            HafasTransitClient::new(&TransitConfig::default()).unwrap(),
+            NominatimGeocodingClient::new(&geocoding_config).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn unknown_address_is_not_geocoded_twice() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let adapter = transit_adapter(mock_server.uri()).with_negative_cache(negative_cache());
+
+        let first = adapter.geocode_address("Nowhere Street 99").await.unwrap();
+        let second = adapter.geocode_address("nowhere street 99 ").await.unwrap();
+
+        assert!(first.is_none());
+        assert!(second.is_none());
+    }
+
+    #[tokio::test]
+    async fn geocoding_retries_without_negative_cache() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let adapter = transit_adapter(mock_server.uri());
+
+        assert!(adapter.geocode_address("Nowhere").await.unwrap().is_none());
+        assert!(adapter.geocode_address("Nowhere").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn weather_failure_short_circuits_second_lookup() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/forecast"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = WeatherConfig {
+            base_url: mock_server.uri(),
+            ..WeatherConfig::default()
+        };
+        let adapter = WeatherAdapter::with_config(config)
+            .unwrap()
+            .with_negative_cache(negative_cache());
+        let location = GeoLocation::new(52.52, 13.405).unwrap();
+
+        let first = adapter.get_current_weather(&location).await.unwrap_err();
+        let second = adapter.get_forecast(&location, 3).await.unwrap_err();
+
+        assert!(first.to_string().contains("503"));
+        assert!(second.to_string().contains("503"));
+    }
+}
+
 // ============================================================================
 // WhatsApp Adapter Tests (Wiremock)
 // ============================================================================
@@ -1440,6 +1538,253 @@ mod ollama_adapter_tests {
     }
 }
 
+// ============================================================================
+// Inference Cancellation Tests
+// ============================================================================
+
+mod inference_cancellation_tests {
+    use super::*;
+    use ai_core::InferenceConfig;
+    use application::error::ApplicationError;
+    use application::ports::{InferencePort, cancellable, cancellable_stream};
+    use futures::StreamExt;
+    use infrastructure::OllamaInferenceAdapter;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+    use tokio::task::JoinHandle;
+    use tokio_util::sync::CancellationToken;
+
+    /// Backend that streams one NDJSON chunk and then keeps generating
+    ///
+    /// The returned task ends once the client closes the connection.
+    async fn streaming_backend() -> (String, oneshot::Receiver<()>, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (sent_tx, sent_rx) = oneshot::channel();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 8192];
+            socket.read(&mut buf).await.unwrap();
+
+            let line = concat!(
+                r#"{"model":"test-model","message":{"role":"assistant","content":"Once"},"#,
+                r#""done":false}"#,
+                "\n"
+            );
+            let response = format!(
+                "HTTP/1.1 200 OK\r\n\
+                 Content-Type: application/x-ndjson\r\n\
+                 Transfer-Encoding: chunked\r\n\r\n\
+                 {:x}\r\n{line}\r\n",
+                line.len()
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            sent_tx.send(()).unwrap();
+            while socket.read(&mut buf).await.unwrap_or(0) > 0 {}
+        });
+
+        (format!("http://{addr}"), sent_rx, server)
+    }
+
+    fn adapter_for(base_url: String) -> OllamaInferenceAdapter {
+        let config = InferenceConfig {
+            base_url,
+            ..InferenceConfig::default()
+        };
+        OllamaInferenceAdapter::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn cancelled_generation_closes_backend_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (accepted_tx, accepted_rx) = tokio::sync::oneshot::channel();
+
+        // Accept the request and never answer, like a model busy generating.
+        // The task ends once the client closes the connection.
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 8192];
+            socket.read(&mut buf).await.unwrap();
+            accepted_tx.send(()).unwrap();
+            while socket.read(&mut buf).await.unwrap_or(0) > 0 {}
+        });
+
+        let config = InferenceConfig {
+            base_url: format!("http://{addr}"),
+            ..InferenceConfig::default()
+        };
+        let adapter = OllamaInferenceAdapter::new(config).unwrap();
+        let cancel = CancellationToken::new();
+        let generation = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { cancellable(&cancel, adapter.generate("Tell me a long story")).await }
+        });
+
+        accepted_rx.await.unwrap();
+        cancel.cancel();
+
+        assert!(matches!(
+            generation.await.unwrap(),
+            Err(ApplicationError::Cancelled)
+        ));
+        tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .expect("backend connection was not closed")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn dropped_stream_closes_backend_connection() {
+        let (base_url, sent, server) = streaming_backend().await;
+        let adapter = adapter_for(base_url);
+
+        let mut stream = adapter
+            .generate_stream("Tell me a long story")
+            .await
+            .unwrap();
+        sent.await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap().content, "Once");
+        drop(stream);
+
+        tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .expect("backend connection was not closed")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn cancelled_stream_closes_backend_connection() {
+        let (base_url, sent, server) = streaming_backend().await;
+        let adapter = adapter_for(base_url);
+        let cancel = CancellationToken::new();
+
+        let inner = adapter
+            .generate_stream("Tell me a long story")
+            .await
+            .unwrap();
+        let mut stream = cancellable_stream(inner, cancel.clone());
+        sent.await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap().content, "Once");
+        cancel.cancel();
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ApplicationError::Cancelled))
+        ));
+        // The outer stream is still held; the token alone aborted the request
+        tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .expect("backend connection was not closed")
+            .unwrap();
+        assert!(stream.next().await.is_none());
+    }
+}
+
+// ============================================================================
+// Model Warmup Tests
+// ============================================================================
+
+mod model_warmup_tests {
+    use super::*;
+    use ai_core::InferenceConfig;
+    use application::ports::InferencePort;
+    use infrastructure::OllamaInferenceAdapter;
+    use wiremock::matchers::body_partial_json;
+
+    fn adapter_for(server: &MockServer) -> OllamaInferenceAdapter {
+        let config = InferenceConfig {
+            base_url: server.uri(),
+            ..InferenceConfig::default()
+        };
+        OllamaInferenceAdapter::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn warmup_requests_a_single_token() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .and(body_partial_json(serde_json::json!({
+                "options": {"num_predict": 1}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "model": "qwen2.5-1.5b-instruct",
+                "message": {"role": "assistant", "content": "Hello"},
+                "done": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert!(adapter_for(&mock_server).warmup().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn warmup_reports_backend_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("model not found"))
+            .mount(&mock_server)
+            .await;
+
+        assert!(adapter_for(&mock_server).warmup().await.is_err());
+    }
+}
+
+// ============================================================================
+// Structured Output Tests
+// ============================================================================
+
+mod structured_output_tests {
+    use super::*;
+    use ai_core::InferenceConfig;
+    use application::ports::InferencePort;
+    use infrastructure::OllamaInferenceAdapter;
+    use wiremock::matchers::body_partial_json;
+
+    #[tokio::test]
+    async fn generate_structured_sends_schema_as_format() {
+        let mock_server = MockServer::start().await;
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {"intent": {"type": "string"}},
+            "required": ["intent"]
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .and(body_partial_json(
+                serde_json::json!({ "format": schema.clone() }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "model": "qwen2.5-1.5b-instruct",
+                "message": {"role": "assistant", "content": "{\"intent\":\"help\"}"},
+                "done": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = InferenceConfig {
+            base_url: mock_server.uri(),
+            ..InferenceConfig::default()
+        };
+        let adapter = OllamaInferenceAdapter::new(config).unwrap();
+
+        let result = adapter
+            .generate_structured("Classify the intent", "help me", &schema)
+            .await
+            .unwrap();
+        assert_eq!(result.content, r#"{"intent":"help"}"#);
+    }
+}
+
 // ============================================================================
 // Circuit Breaker Integration Tests
 // ============================================================================
@@ -1763,3 +2108,205 @@ mod api_key_hasher_extended_tests {
         assert!(ApiKeyHasher::is_hashed("$argon2$")); // Generic
     }
 }
+
+// ============================================================================
+// CardDAV Contact Import Tests
+// ============================================================================
+
+mod carddav_import_tests {
+    use super::*;
+    use application::ports::ContactPort;
+    use infrastructure::adapters::CardDavContactAdapter;
+    use integration_carddav::CardDavConfig;
+
+    const ADDRESSBOOK: &str = "/addressbooks/testuser/default";
+
+    fn adapter(server_url: &str) -> CardDavContactAdapter {
+        CardDavContactAdapter::new(CardDavConfig {
+            server_url: server_url.to_string(),
+            username: "testuser".to_string(),
+            password: "testpass".to_string(),
+            addressbook_path: Some(ADDRESSBOOK.to_string()),
+            verify_certs: false,
+            timeout_secs: 5,
+        })
+        .unwrap()
+    }
+
+    fn existing_contacts_response() -> String {
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<D:multistatus xmlns:D="DAV:" xmlns:card="urn:ietf:params:xml:ns:carddav">
+<D:response>
+  <D:propstat>
+    <D:prop>
+      <card:address-data>BEGIN:VCARD
+VERSION:3.0
+UID:existing
+FN:Max Mustermann
+EMAIL:max@example.com
+END:VCARD</card:address-data>
+    </D:prop>
+    <D:status>HTTP/1.1 200 OK</D:status>
+  </D:propstat>
+</D:response>
+</D:multistatus>"#
+            .to_string()
+    }
+
+    const IMPORT_FILE: &str = "BEGIN:VCARD\r\nVERSION:3.0\r\nUID:new-1\r\nFN:Erika Muster\r\nEMAIL:erika@example.com\r\nEND:VCARD\r\n\
+BEGIN:VCARD\r\nVERSION:3.0\r\nUID:dup\r\nFN:Max M.\r\nEMAIL:MAX@example.com\r\nEND:VCARD\r\n\
+BEGIN:VCARD\r\nVERSION:3.0\r\nEMAIL:broken@example.com\r\nEND:VCARD\r\n\
+BEGIN:VCARD\r\nVERSION:3.0\r\nUID:new-2\r\nFN:Ben Berg\r\nEND:VCARD\r\n\
+BEGIN:VCARD\r\nVERSION:3.0\r\nUID:new-3\r\nFN:Erika Again\r\nEMAIL:erika@example.com\r\nEND:VCARD\r\n";
+
+    async fn mount_existing(server: &MockServer) {
+        Mock::given(method("REPORT"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(existing_contacts_response()))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn import_counts_created_skipped_and_failed() {
+        let server = MockServer::start().await;
+        mount_existing(&server).await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let result = adapter(&server.uri())
+            .import_vcards(IMPORT_FILE)
+            .await
+            .unwrap();
+
+        assert_eq!(result.created, 2);
+        // Existing email (case-insensitive) and a repeat within the file
+        assert_eq!(result.skipped, 2);
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.total(), 5);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].index, 2);
+        assert!(result.errors[0].message.contains("FN or N"));
+    }
+
+    #[tokio::test]
+    async fn failed_create_does_not_abort_import() {
+        let server = MockServer::start().await;
+        mount_existing(&server).await;
+        Mock::given(method("PUT"))
+            .and(path(format!("{ADDRESSBOOK}/new-1.vcf")))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(201))
+            .mount(&server)
+            .await;
+
+        let result = adapter(&server.uri())
+            .import_vcards(IMPORT_FILE)
+            .await
+            .unwrap();
+
+        // new-1 fails, so the later card with the same email is created
+        assert_eq!(result.created, 2);
+        assert_eq!(result.skipped, 1);
+        assert_eq!(result.failed, 2);
+        assert_eq!(result.errors[0].index, 0);
+        assert_eq!(result.errors[0].name.as_deref(), Some("Erika Muster"));
+    }
+}
+
+// ============================================================================
+// Ollama Model Registry Tests
+// ============================================================================
+
+mod model_registry_tests {
+    use super::*;
+    use application::ports::ModelRegistryPort;
+    use infrastructure::adapters::{OllamaModelRegistryAdapter, OllamaModelRegistryConfig};
+    use wiremock::matchers::body_partial_json;
+
+    fn adapter(base_url: &str) -> OllamaModelRegistryAdapter {
+        OllamaModelRegistryAdapter::with_config(OllamaModelRegistryConfig {
+            base_url: base_url.to_string(),
+            timeout: Duration::from_secs(5),
+            // Always refetch the list to exercise the details cache
+            cache_ttl: Duration::ZERO,
+        })
+        .unwrap()
+    }
+
+    async fn mount_models(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/v1/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"id": "qwen2.5:1.5b"}, {"id": "nomic-embed-text"}]
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn list_models_reports_show_details() {
+        let server = MockServer::start().await;
+        mount_models(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/api/show"))
+            .and(body_partial_json(
+                serde_json::json!({"model": "qwen2.5:1.5b"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "details": {"family": "qwen2", "parameter_size": "1.5B"},
+                "model_info": {
+                    "general.architecture": "qwen2",
+                    "qwen2.context_length": 32768
+                },
+                "capabilities": ["completion", "tools"]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/show"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let registry = adapter(&server.uri());
+        let models = registry.list_models().await.unwrap();
+        // Second listing hits the details cache for qwen
+        registry.list_models().await.unwrap();
+
+        let qwen = &models[0];
+        assert_eq!(qwen.parameter_size.as_deref(), Some("1.5B"));
+        assert_eq!(qwen.capabilities.context_length, Some(32_768));
+        assert!(qwen.capabilities.tools);
+        assert!(!qwen.capabilities.vision);
+
+        // No details: name-based estimates
+        let embed = &models[1];
+        assert!(embed.capabilities.embeddings);
+        assert!(embed.parameter_size.is_none());
+    }
+
+    #[tokio::test]
+    async fn refresh_drops_cached_details() {
+        let server = MockServer::start().await;
+        mount_models(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/api/show"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "capabilities": ["completion"]
+            })))
+            .expect(4)
+            .mount(&server)
+            .await;
+
+        let registry = adapter(&server.uri());
+        registry.list_models().await.unwrap();
+        registry.refresh().await.unwrap();
+    }
+}
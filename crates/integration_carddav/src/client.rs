@@ -118,7 +118,19 @@ pub trait CardDavClient: Send + Sync {
     async fn delete_contact(&self, addressbook: &str, contact_id: &str)
     -> Result<(), CardDavError>;
 
+    /// Export a contact as vCard 3.0 text
+    ///
+    /// The vCard is rebuilt from the parsed contact, so values are escaped
+    /// consistently regardless of how the server stored them.
+    async fn export_vcard(
+        &self,
+        addressbook: &str,
+        contact_id: &str,
+    ) -> Result<String, CardDavError>;
+
     /// Search contacts by query (client-side filtering)
+    ///
+    /// Matches name, email, phone and organization; exact matches come first.
     async fn search_contacts(
         &self,
         addressbook: &str,
@@ -389,19 +401,19 @@ pub fn parse_vcard(vcard_data: &str) -> Result<Contact, CardDavError> {
         };
 
         match prop_name.as_str() {
-            "UID" => id = value.to_string(),
-            "FN" => display_name = Some(value.to_string()),
+            "UID" => id = unescape_vcard_text(value),
+            "FN" => display_name = Some(unescape_vcard_text(value)),
             "N" => {
                 // N:Last;First;Middle;Prefix;Suffix
-                let parts: Vec<&str> = value.split(';').collect();
+                let parts = split_vcard_value(value, ';');
                 if let Some(ln) = parts.first() {
                     if !ln.is_empty() {
-                        last_name = Some((*ln).to_string());
+                        last_name = Some(ln.clone());
                     }
                 }
                 if let Some(fn_part) = parts.get(1) {
                     if !fn_part.is_empty() {
-                        first_name = Some((*fn_part).to_string());
+                        first_name = Some(fn_part.clone());
                     }
                 }
             },
@@ -409,46 +421,33 @@ pub fn parse_vcard(vcard_data: &str) -> Result<Contact, CardDavError> {
                 let type_label = extract_type_param(params);
                 emails.push(ContactEmail {
                     type_label,
-                    value: value.to_string(),
+                    value: unescape_vcard_text(value),
                 });
             },
             "TEL" => {
                 let type_label = extract_type_param(params);
                 phones.push(ContactPhone {
                     type_label,
-                    value: value.to_string(),
+                    value: unescape_vcard_text(value),
                 });
             },
             "ORG" => {
-                organization = Some(value.replace(';', ", "));
+                // ORG:Name;Unit;Subunit
+                organization = Some(split_vcard_value(value, ';').join(", "));
             },
             "TITLE" => {
-                title = Some(value.to_string());
+                title = Some(unescape_vcard_text(value));
             },
             "ADR" => {
                 // ADR:PO Box;Ext Addr;Street;City;State;Postal;Country
                 let type_label = extract_type_param(params);
-                let parts: Vec<&str> = value.split(';').collect();
-                let street = parts
-                    .get(2)
-                    .filter(|s| !s.is_empty())
-                    .map(|s| (*s).to_string());
-                let city = parts
-                    .get(3)
-                    .filter(|s| !s.is_empty())
-                    .map(|s| (*s).to_string());
-                let state = parts
-                    .get(4)
-                    .filter(|s| !s.is_empty())
-                    .map(|s| (*s).to_string());
-                let postal_code = parts
-                    .get(5)
-                    .filter(|s| !s.is_empty())
-                    .map(|s| (*s).to_string());
-                let country = parts
-                    .get(6)
-                    .filter(|s| !s.is_empty())
-                    .map(|s| (*s).to_string());
+                let parts = split_vcard_value(value, ';');
+                let part = |i: usize| parts.get(i).filter(|s| !s.is_empty()).cloned();
+                let street = part(2);
+                let city = part(3);
+                let state = part(4);
+                let postal_code = part(5);
+                let country = part(6);
 
                 addresses.push(ContactAddress {
                     type_label,
@@ -463,7 +462,7 @@ pub fn parse_vcard(vcard_data: &str) -> Result<Contact, CardDavError> {
                 birthday = parse_vcard_date(value);
             },
             "NOTE" => {
-                notes = Some(value.to_string());
+                notes = Some(unescape_vcard_text(value));
             },
             "PHOTO" => {
                 // Only handle URL-based photos
@@ -476,7 +475,11 @@ pub fn parse_vcard(vcard_data: &str) -> Result<Contact, CardDavError> {
                 }
             },
             "CATEGORIES" => {
-                categories.extend(value.split(',').map(|s| s.trim().to_string()));
+                categories.extend(
+                    split_vcard_value(value, ',')
+                        .into_iter()
+                        .map(|s| s.trim().to_string()),
+                );
             },
             "REV" => {
                 last_modified = parse_vcard_datetime(value);
@@ -508,6 +511,53 @@ pub fn parse_vcard(vcard_data: &str) -> Result<Contact, CardDavError> {
     })
 }
 
+/// Parse every card in a multi-card `.vcf` file
+///
+/// Returns one result per `BEGIN:VCARD` block in file order, so a malformed
+/// card does not hide the ones after it. A card fails when it is not closed
+/// by `END:VCARD` or carries no name (`FN` or `N`). Text outside of cards is
+/// ignored.
+#[must_use]
+pub fn parse_vcards(vcard_data: &str) -> Vec<Result<Contact, CardDavError>> {
+    let mut results = Vec::new();
+    let mut card: Option<Vec<String>> = None;
+
+    for line in unfold_vcard_lines(vcard_data) {
+        let trimmed = line.trim();
+        if trimmed.eq_ignore_ascii_case("BEGIN:VCARD") {
+            if card.replace(Vec::new()).is_some() {
+                results.push(Err(unterminated_card()));
+            }
+        } else if trimmed.eq_ignore_ascii_case("END:VCARD") {
+            if let Some(lines) = card.take() {
+                results.push(parse_card_lines(&lines));
+            }
+        } else if let Some(lines) = card.as_mut() {
+            lines.push(line);
+        }
+    }
+    if card.is_some() {
+        results.push(Err(unterminated_card()));
+    }
+
+    results
+}
+
+fn unterminated_card() -> CardDavError {
+    CardDavError::ParseError("vCard is missing END:VCARD".to_string())
+}
+
+/// Parse the unfolded property lines of a single card
+fn parse_card_lines(lines: &[String]) -> Result<Contact, CardDavError> {
+    let contact = parse_vcard(&lines.join("\r\n"))?;
+    if contact.full_name().is_empty() {
+        return Err(CardDavError::ParseError(
+            "vCard has no FN or N property".to_string(),
+        ));
+    }
+    Ok(contact)
+}
+
 /// Build a vCard 3.0 string from a Contact
 pub fn build_vcard(contact: &Contact) -> String {
     let mut vcard = String::with_capacity(512);
@@ -516,11 +566,11 @@ pub fn build_vcard(contact: &Contact) -> String {
     vcard.push_str("PRODID:-//PiSovereign//CardDAV Client//EN\r\n");
 
     // UID
-    vcard.push_str(&format!("UID:{}\r\n", contact.id));
+    vcard.push_str(&format!("UID:{}\r\n", escape_vcard_text(&contact.id)));
 
     // N (structured name)
-    let last = contact.last_name.as_deref().unwrap_or("");
-    let first = contact.first_name.as_deref().unwrap_or("");
+    let last = escape_vcard_text(contact.last_name.as_deref().unwrap_or(""));
+    let first = escape_vcard_text(contact.first_name.as_deref().unwrap_or(""));
     vcard.push_str(&format!("N:{last};{first};;;\r\n"));
 
     // FN (formatted/display name)
@@ -528,35 +578,37 @@ pub fn build_vcard(contact: &Contact) -> String {
     if fn_value.is_empty() {
         vcard.push_str("FN:Unknown\r\n");
     } else {
-        vcard.push_str(&format!("FN:{fn_value}\r\n"));
+        vcard.push_str(&format!("FN:{}\r\n", escape_vcard_text(&fn_value)));
     }
 
     // Emails
     for email in &contact.emails {
+        let value = escape_vcard_text(&email.value);
         if let Some(ref t) = email.type_label {
-            vcard.push_str(&format!("EMAIL;TYPE={t}:{}\r\n", email.value));
+            vcard.push_str(&format!("EMAIL;TYPE={t}:{value}\r\n"));
         } else {
-            vcard.push_str(&format!("EMAIL:{}\r\n", email.value));
+            vcard.push_str(&format!("EMAIL:{value}\r\n"));
         }
     }
 
     // Phones
     for phone in &contact.phones {
+        let value = escape_vcard_text(&phone.value);
         if let Some(ref t) = phone.type_label {
-            vcard.push_str(&format!("TEL;TYPE={t}:{}\r\n", phone.value));
+            vcard.push_str(&format!("TEL;TYPE={t}:{value}\r\n"));
         } else {
-            vcard.push_str(&format!("TEL:{}\r\n", phone.value));
+            vcard.push_str(&format!("TEL:{value}\r\n"));
         }
     }
 
     // Organization
     if let Some(ref org) = contact.organization {
-        vcard.push_str(&format!("ORG:{org}\r\n"));
+        vcard.push_str(&format!("ORG:{}\r\n", escape_vcard_text(org)));
     }
 
     // Title
     if let Some(ref title) = contact.title {
-        vcard.push_str(&format!("TITLE:{title}\r\n"));
+        vcard.push_str(&format!("TITLE:{}\r\n", escape_vcard_text(title)));
     }
 
     // Addresses
@@ -565,11 +617,12 @@ pub fn build_vcard(contact: &Contact) -> String {
             .type_label
             .as_ref()
             .map_or(String::new(), |t| format!(";TYPE={t}"));
-        let street = addr.street.as_deref().unwrap_or("");
-        let city = addr.city.as_deref().unwrap_or("");
-        let state = addr.state.as_deref().unwrap_or("");
-        let postal = addr.postal_code.as_deref().unwrap_or("");
-        let country = addr.country.as_deref().unwrap_or("");
+        let part = |value: &Option<String>| escape_vcard_text(value.as_deref().unwrap_or(""));
+        let street = part(&addr.street);
+        let city = part(&addr.city);
+        let state = part(&addr.state);
+        let postal = part(&addr.postal_code);
+        let country = part(&addr.country);
         vcard.push_str(&format!(
             "ADR{type_part}:;;{street};{city};{state};{postal};{country}\r\n"
         ));
@@ -582,7 +635,7 @@ pub fn build_vcard(contact: &Contact) -> String {
 
     // Notes
     if let Some(ref notes) = contact.notes {
-        vcard.push_str(&format!("NOTE:{notes}\r\n"));
+        vcard.push_str(&format!("NOTE:{}\r\n", escape_vcard_text(notes)));
     }
 
     // Photo URL
@@ -592,7 +645,12 @@ pub fn build_vcard(contact: &Contact) -> String {
 
     // Categories
     if !contact.categories.is_empty() {
-        vcard.push_str(&format!("CATEGORIES:{}\r\n", contact.categories.join(",")));
+        let categories: Vec<String> = contact
+            .categories
+            .iter()
+            .map(|c| escape_vcard_text(c))
+            .collect();
+        vcard.push_str(&format!("CATEGORIES:{}\r\n", categories.join(",")));
     }
 
     // Revision (last modified)
@@ -606,6 +664,61 @@ pub fn build_vcard(contact: &Contact) -> String {
     vcard
 }
 
+/// Escape a text value for a vCard property (RFC 2426, section 4)
+///
+/// Backslashes, commas and semicolons are prefixed with a backslash and
+/// line breaks are written as `\n`, so a value never ends the property line
+/// or splits a structured value.
+#[must_use]
+pub fn escape_vcard_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ',' => escaped.push_str("\\,"),
+            ';' => escaped.push_str("\\;"),
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                escaped.push_str("\\n");
+            },
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverse [`escape_vcard_text`]
+#[must_use]
+pub fn unescape_vcard_text(value: &str) -> String {
+    split_escaped(value, None).concat()
+}
+
+/// Split a structured value on unescaped `separator` and unescape each part
+fn split_vcard_value(value: &str, separator: char) -> Vec<String> {
+    split_escaped(value, Some(separator))
+}
+
+fn split_escaped(value: &str, separator: Option<char>) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n' | 'N') => current.push('\n'),
+                Some(escaped) => current.push(escaped),
+                None => current.push('\\'),
+            },
+            c if Some(c) == separator => parts.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
 /// Unfold continuation lines in vCard data (RFC 2425 line folding)
 fn unfold_vcard_lines(data: &str) -> Vec<String> {
     let mut lines = Vec::new();
@@ -929,6 +1042,16 @@ impl CardDavClient for HttpCardDavClient {
         }
     }
 
+    #[instrument(skip(self), fields(addressbook = %addressbook, contact_id = %contact_id))]
+    async fn export_vcard(
+        &self,
+        addressbook: &str,
+        contact_id: &str,
+    ) -> Result<String, CardDavError> {
+        let contact = self.get_contact(addressbook, contact_id).await?;
+        Ok(build_vcard(&contact))
+    }
+
     #[instrument(skip(self), fields(addressbook = %addressbook, query = %query))]
     async fn search_contacts(
         &self,
@@ -937,11 +1060,7 @@ impl CardDavClient for HttpCardDavClient {
     ) -> Result<Vec<Contact>, CardDavError> {
         // Client-side filtering since Baïkal doesn't reliably support server-side search
         let all_contacts = self.get_contacts(addressbook).await?;
-        let results = all_contacts
-            .into_iter()
-            .filter(|c| c.matches_query(query))
-            .collect();
-        Ok(results)
+        Ok(crate::contact::search_contacts(all_contacts, query))
     }
 }
 
@@ -1167,6 +1286,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_vcards_multiple_cards() {
+        let data = "BEGIN:VCARD\r\nVERSION:3.0\r\nUID:a\r\nFN:Anna\r\nEMAIL:anna@example.com\r\nEND:VCARD\r\n\
+                    BEGIN:VCARD\r\nVERSION:3.0\r\nUID:b\r\nN:Berg;Ben;;;\r\nEND:VCARD\r\n\
+                    BEGIN:VCARD\r\nVERSION:3.0\r\nUID:c\r\nFN:Carla\r\nEND:VCARD\r\n";
+        let contacts: Vec<Contact> = parse_vcards(data)
+            .into_iter()
+            .map(|r| r.expect("parse"))
+            .collect();
+        assert_eq!(contacts.len(), 3);
+        assert_eq!(contacts[0].id, "a");
+        assert_eq!(contacts[0].primary_email(), Some("anna@example.com"));
+        assert_eq!(contacts[1].full_name(), "Ben Berg");
+        assert_eq!(contacts[2].full_name(), "Carla");
+    }
+
+    #[test]
+    fn parse_vcards_reports_malformed_entries_individually() {
+        let data = "BEGIN:VCARD\nVERSION:3.0\nFN:First\nEND:VCARD\n\
+                    BEGIN:VCARD\nVERSION:3.0\nEMAIL:nobody@example.com\nEND:VCARD\n\
+                    BEGIN:VCARD\nVERSION:3.0\nFN:Unterminated\n\
+                    BEGIN:VCARD\nVERSION:3.0\nFN:Last\nEND:VCARD\n";
+        let results = parse_vcards(data);
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().expect("first").full_name(), "First");
+        assert!(matches!(results[1], Err(CardDavError::ParseError(_))));
+        assert!(matches!(results[2], Err(CardDavError::ParseError(_))));
+        assert_eq!(results[3].as_ref().expect("last").full_name(), "Last");
+    }
+
+    #[test]
+    fn parse_vcards_ignores_text_outside_cards() {
+        assert!(parse_vcards("").is_empty());
+        let results = parse_vcards("garbage\nBEGIN:VCARD\nFN:Only\nEND:VCARD\ntrailer\n");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+
     // === vCard Building Tests ===
 
     #[test]
@@ -1254,6 +1411,71 @@ mod tests {
         assert_eq!(parsed.categories, original.categories);
     }
 
+    #[test]
+    fn escape_vcard_text_escapes_special_characters() {
+        assert_eq!(escape_vcard_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+        assert_eq!(escape_vcard_text("line1\r\nline2"), "line1\\nline2");
+        assert_eq!(escape_vcard_text("plain"), "plain");
+    }
+
+    #[test]
+    fn unescape_reverses_escape() {
+        for value in ["a,b;c", "C:\\path\\n", "first\nsecond", "trailing\\", ""] {
+            assert_eq!(unescape_vcard_text(&escape_vcard_text(value)), value);
+        }
+        assert_eq!(unescape_vcard_text("one\\Ntwo"), "one\ntwo");
+    }
+
+    #[test]
+    fn build_vcard_escapes_values() {
+        let contact = Contact::new("uid-esc", "Doe, John")
+            .with_last_name("Doe; Jr.")
+            .with_first_name("John")
+            .with_notes("Line one\nLine two, with comma; and semicolon");
+        let vcard = build_vcard(&contact);
+
+        assert!(vcard.contains("FN:Doe\\, John\r\n"));
+        assert!(vcard.contains("N:Doe\\; Jr.;John;;;\r\n"));
+        assert!(vcard.contains("NOTE:Line one\\nLine two\\, with comma\\; and semicolon\r\n"));
+        // Every property stays on a single line
+        assert!(vcard.lines().all(|l| l.is_empty() || l.contains(':')));
+    }
+
+    #[test]
+    fn escaped_values_roundtrip() {
+        let original = Contact::new("uid-rt-esc", "Müller, Anna")
+            .with_first_name("Anna")
+            .with_last_name("Müller; geb. Schmidt")
+            .with_organization("Foo, Bar & Co.; R&D")
+            .with_title("Head of QA, Berlin")
+            .with_address(
+                ContactAddress::new(Some("work".to_string()))
+                    .with_street("Hauptstr. 1; Hinterhaus")
+                    .with_city("Berlin, Mitte"),
+            )
+            .with_notes("Met at FOSDEM\nLikes tea, not coffee; \\o/")
+            .with_category("work, important")
+            .with_category("friends");
+
+        let parsed = parse_vcard(&build_vcard(&original)).expect("parse roundtrip");
+
+        assert_eq!(parsed.display_name, original.display_name);
+        assert_eq!(parsed.first_name, original.first_name);
+        assert_eq!(parsed.last_name, original.last_name);
+        assert_eq!(parsed.organization, original.organization);
+        assert_eq!(parsed.title, original.title);
+        assert_eq!(parsed.addresses, original.addresses);
+        assert_eq!(parsed.notes, original.notes);
+        assert_eq!(parsed.categories, original.categories);
+    }
+
+    #[test]
+    fn parse_vcard_unescapes_note() {
+        let vcard = "BEGIN:VCARD\r\nVERSION:3.0\r\nUID:n\r\nFN:Test\r\nNOTE:a\\, b\\; c\\nd\r\nEND:VCARD\r\n";
+        let contact = parse_vcard(vcard).expect("parse");
+        assert_eq!(contact.notes.as_deref(), Some("a, b; c\nd"));
+    }
+
     // === XML Extraction Tests ===
 
     #[test]
@@ -213,19 +213,96 @@ impl Contact {
     /// Check whether this contact matches a search query (case-insensitive)
     #[must_use]
     pub fn matches_query(&self, query: &str) -> bool {
-        let q = query.to_lowercase();
-        let name_match = self.full_name().to_lowercase().contains(&q);
-        let email_match = self
-            .emails
-            .iter()
-            .any(|e| e.value.to_lowercase().contains(&q));
-        let phone_match = self.phones.iter().any(|p| p.value.contains(&q));
-        let org_match = self
-            .organization
-            .as_ref()
-            .is_some_and(|o| o.to_lowercase().contains(&q));
-        name_match || email_match || phone_match || org_match
+        self.match_rank(query).is_some()
     }
+
+    /// How well this contact matches a search query
+    ///
+    /// Name, email addresses and organization are compared case-insensitively.
+    /// Queries that look like phone numbers are compared against the phone
+    /// numbers after [`normalize_phone`], so `+49 30 1234` and `0049301234`
+    /// are the same number. Returns `None` if nothing matches.
+    #[must_use]
+    pub fn match_rank(&self, query: &str) -> Option<MatchRank> {
+        let q = query.trim().to_lowercase();
+        if q.is_empty() {
+            return None;
+        }
+
+        let texts = std::iter::once(self.full_name())
+            .chain(self.emails.iter().map(|e| e.value.clone()))
+            .chain(self.organization.clone())
+            .map(|t| t.to_lowercase());
+        let mut best = texts.filter_map(|t| MatchRank::of(&t, &q)).min();
+
+        if is_phone_query(&q) {
+            let nq = normalize_phone(&q);
+            let phone_best = self
+                .phones
+                .iter()
+                .filter_map(|p| MatchRank::of(&normalize_phone(&p.value), &nq))
+                .min();
+            best = best.into_iter().chain(phone_best).min();
+        }
+        best
+    }
+}
+
+/// Quality of a search match; exact matches sort first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchRank {
+    /// The whole field equals the query
+    Exact,
+    /// The query occurs somewhere in the field
+    Partial,
+}
+
+impl MatchRank {
+    fn of(field: &str, query: &str) -> Option<Self> {
+        if field == query {
+            Some(Self::Exact)
+        } else if field.contains(query) {
+            Some(Self::Partial)
+        } else {
+            None
+        }
+    }
+}
+
+/// Keep the contacts matching `query`, exact matches first
+///
+/// The order within each rank is preserved.
+#[must_use]
+pub fn search_contacts(contacts: Vec<Contact>, query: &str) -> Vec<Contact> {
+    let mut ranked: Vec<(MatchRank, Contact)> = contacts
+        .into_iter()
+        .filter_map(|c| c.match_rank(query).map(|rank| (rank, c)))
+        .collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+    ranked.into_iter().map(|(_, c)| c).collect()
+}
+
+/// Normalize a phone number for comparison
+///
+/// Keeps only digits and writes a leading `+` as the `00` international
+/// prefix, so `+49 (30) 1234` becomes `0049301234`.
+#[must_use]
+pub fn normalize_phone(value: &str) -> String {
+    let value = value.trim();
+    let mut normalized = String::with_capacity(value.len() + 1);
+    if value.starts_with('+') {
+        normalized.push_str("00");
+    }
+    normalized.extend(value.chars().filter(char::is_ascii_digit));
+    normalized
+}
+
+/// Whether a query consists only of phone number characters
+fn is_phone_query(query: &str) -> bool {
+    query.chars().any(|c| c.is_ascii_digit())
+        && query
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | ' ' | '-' | '/' | '(' | ')' | '.'))
 }
 
 impl ContactAddress {
@@ -422,6 +499,63 @@ mod tests {
         assert!(contact.matches_query("ACME"));
     }
 
+    #[test]
+    fn matches_query_by_partial_phone_ignores_formatting() {
+        let contact = Contact::new("uid-1", "Max").with_phone("+49 30 1234 567", None);
+        assert!(contact.matches_query("301234"));
+        assert!(contact.matches_query("30 1234"));
+        assert!(contact.matches_query("0049 30"));
+        assert!(!contact.matches_query("301299"));
+    }
+
+    #[test]
+    fn phone_country_code_formats_are_equivalent() {
+        assert_eq!(normalize_phone("+49 30 1234"), "0049301234");
+        assert_eq!(normalize_phone("0049301234"), "0049301234");
+        assert_eq!(normalize_phone("+49 (30) 12-34"), "0049301234");
+
+        let contact = Contact::new("uid-1", "Max").with_phone("+49 30 1234", None);
+        assert_eq!(contact.match_rank("0049301234"), Some(MatchRank::Exact));
+    }
+
+    #[test]
+    fn text_query_does_not_match_phone() {
+        let contact = Contact::new("uid-1", "Max").with_phone("+49 30 1234", None);
+        assert!(!contact.matches_query("abc"));
+        assert!(!contact.matches_query("  "));
+    }
+
+    #[test]
+    fn search_by_email_domain() {
+        let contacts = vec![
+            Contact::new("uid-1", "Max").with_email("max@example.com", None),
+            Contact::new("uid-2", "Erika").with_email("erika@other.org", None),
+            Contact::new("uid-3", "Hans").with_email("hans@example.com", None),
+        ];
+
+        let ids: Vec<_> = search_contacts(contacts, "@example.com")
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, ["uid-1", "uid-3"]);
+    }
+
+    #[test]
+    fn search_ranks_exact_matches_first() {
+        let contacts = vec![
+            Contact::new("uid-1", "Anna Berg"),
+            Contact::new("uid-2", "Max").with_organization("Anna"),
+            Contact::new("uid-3", "Annabelle"),
+            Contact::new("uid-4", "anna"),
+        ];
+
+        let ids: Vec<_> = search_contacts(contacts, "Anna")
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, ["uid-2", "uid-4", "uid-1", "uid-3"]);
+    }
+
     #[test]
     fn matches_query_no_match() {
         let contact = Contact::new("uid-1", "Max Mustermann");
@@ -8,4 +8,6 @@ pub mod client;
 pub mod contact;
 
 pub use client::{CardDavClient, CardDavConfig, CardDavError, HttpCardDavClient};
-pub use contact::{Contact, ContactAddress, ContactEmail, ContactPhone};
+pub use contact::{
+    Contact, ContactAddress, ContactEmail, ContactPhone, MatchRank, normalize_phone,
+};
@@ -0,0 +1,62 @@
+//! Ambient request ID for the current task
+//!
+//! The HTTP layer runs each incoming request inside [`scope_request_id`] so
+//! that outgoing requests made anywhere further down the call stack can
+//! pick up the same `X-Request-Id` without threading it through every port.
+//!
+//! The ID is stored in a tokio task-local and therefore does not cross
+//! `tokio::spawn` boundaries; spawned work has to be scoped explicitly.
+
+use std::future::Future;
+
+use uuid::Uuid;
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: Uuid;
+}
+
+/// Run `future` with `request_id` as the ambient request ID
+pub async fn scope_request_id<F>(request_id: Uuid, future: F) -> F::Output
+where
+    F: Future,
+{
+    CURRENT_REQUEST_ID.scope(request_id, future).await
+}
+
+/// The ambient request ID of the current task, if any
+#[must_use]
+pub fn current_request_id() -> Option<Uuid> {
+    CURRENT_REQUEST_ID.try_with(|id| *id).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn no_request_id_outside_scope() {
+        assert!(current_request_id().is_none());
+    }
+
+    #[tokio::test]
+    async fn request_id_available_inside_scope() {
+        let id = Uuid::new_v4();
+        let seen = scope_request_id(id, async { current_request_id() }).await;
+        assert_eq!(seen, Some(id));
+        assert!(current_request_id().is_none());
+    }
+
+    #[tokio::test]
+    async fn nested_scopes_use_innermost_id() {
+        let outer = Uuid::new_v4();
+        let inner = Uuid::new_v4();
+
+        let seen = scope_request_id(outer, async {
+            let nested = scope_request_id(inner, async { current_request_id() }).await;
+            (nested, current_request_id())
+        })
+        .await;
+
+        assert_eq!(seen, (Some(inner), Some(outer)));
+    }
+}
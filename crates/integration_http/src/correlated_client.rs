@@ -2,11 +2,13 @@
 //!
 //! Provides a wrapper around `reqwest::Client` that automatically adds
 //! `X-Request-Id` headers to outgoing requests for distributed tracing.
+//! Requests without an explicit ID use the ambient one set via
+//! [`scope_request_id`](crate::scope_request_id), if any.
 //!
 //! # Examples
 //!
 //! ```ignore
-//! use infrastructure::http::CorrelatedHttpClient;
+//! use integration_http::CorrelatedHttpClient;
 //! use uuid::Uuid;
 //!
 //! let client = CorrelatedHttpClient::new();
@@ -30,6 +32,8 @@ use reqwest::{
 use tracing::{debug, instrument};
 use uuid::Uuid;
 
+use crate::context::current_request_id;
+
 /// Header name for request correlation ID
 pub const X_REQUEST_ID: &str = "x-request-id";
 
@@ -45,12 +49,6 @@ impl RequestIdProvider for Uuid {
     }
 }
 
-impl RequestIdProvider for application::RequestContext {
-    fn request_id(&self) -> Uuid {
-        self.request_id()
-    }
-}
-
 /// Configuration for the correlated HTTP client
 #[derive(Debug, Clone)]
 pub struct CorrelatedClientConfig {
@@ -224,7 +222,8 @@ impl CorrelatedRequestBuilder {
 
     /// Attach a request ID for correlation
     ///
-    /// The ID will be sent as an `X-Request-Id` header.
+    /// The ID will be sent as an `X-Request-Id` header. Overrides the
+    /// ambient request ID of the current task.
     #[must_use]
     pub fn with_request_id(mut self, id: &impl RequestIdProvider) -> Self {
         self.request_id = Some(id.request_id());
@@ -306,8 +305,8 @@ impl CorrelatedRequestBuilder {
     pub async fn send(self) -> Result<Response, reqwest::Error> {
         let mut builder = self.inner;
 
-        // Add request ID header if provided
-        if let Some(request_id) = self.request_id {
+        // Add request ID header if provided, falling back to the ambient one
+        if let Some(request_id) = self.request_id.or_else(current_request_id) {
             builder = builder.header(X_REQUEST_ID, request_id.to_string());
             debug!(request_id = %request_id, "Sending correlated HTTP request");
         }
@@ -0,0 +1,18 @@
+#![forbid(unsafe_code)]
+//! Shared HTTP plumbing for PiSovereign integrations
+//!
+//! Provides a `reqwest` wrapper that adds `X-Request-Id` headers to outgoing
+//! requests, so calls to external services can be correlated with the
+//! incoming request that caused them. Integration crates accept a
+//! [`SharedCorrelatedClient`] and fall back to a default one when none is
+//! injected.
+
+mod context;
+mod correlated_client;
+
+pub use context::{current_request_id, scope_request_id};
+pub use correlated_client::{
+    CorrelatedClientConfig, CorrelatedHttpClient, CorrelatedRequestBuilder, RequestBuilderExt,
+    RequestIdProvider, SharedCorrelatedClient, X_REQUEST_ID, create_shared_client,
+    create_shared_client_with_config,
+};
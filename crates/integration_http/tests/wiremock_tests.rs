@@ -0,0 +1,65 @@
+//! Integration tests for request ID propagation using wiremock
+
+use integration_http::{CorrelatedHttpClient, X_REQUEST_ID, scope_request_id};
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, Request, ResponseTemplate,
+    matchers::{header, method},
+};
+
+#[tokio::test]
+async fn ambient_request_id_is_sent() {
+    let mock_server = MockServer::start().await;
+    let request_id = Uuid::new_v4();
+
+    Mock::given(method("GET"))
+        .and(header(X_REQUEST_ID, request_id.to_string().as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CorrelatedHttpClient::new().unwrap();
+    let response = scope_request_id(request_id, client.get(mock_server.uri()).send())
+        .await
+        .unwrap();
+
+    assert!(response.status().is_success());
+}
+
+#[tokio::test]
+async fn explicit_request_id_overrides_ambient() {
+    let mock_server = MockServer::start().await;
+    let ambient = Uuid::new_v4();
+    let explicit = Uuid::new_v4();
+
+    Mock::given(method("GET"))
+        .and(header(X_REQUEST_ID, explicit.to_string().as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CorrelatedHttpClient::new().unwrap();
+    let request = client.get(mock_server.uri()).with_request_id(&explicit);
+    let response = scope_request_id(ambient, request.send()).await.unwrap();
+
+    assert!(response.status().is_success());
+}
+
+#[tokio::test]
+async fn no_header_outside_request_scope() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    let client = CorrelatedHttpClient::new().unwrap();
+    client.get(mock_server.uri()).send().await.unwrap();
+
+    let requests: Vec<Request> = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(!requests[0].headers.contains_key(X_REQUEST_ID));
+}
@@ -329,6 +329,9 @@ pub struct EmailComposition {
     pub subject: String,
     /// Email body (plain text)
     pub body: String,
+    /// Optional HTML alternative of the body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
 }
 
 impl EmailComposition {
@@ -339,9 +342,17 @@ impl EmailComposition {
             cc: Vec::new(),
             subject: subject.into(),
             body: body.into(),
+            html_body: None,
         }
     }
 
+    /// Sets an HTML alternative of the body
+    #[must_use]
+    pub fn with_html_body(mut self, html_body: impl Into<String>) -> Self {
+        self.html_body = Some(html_body.into());
+        self
+    }
+
     /// Adds a CC recipient
     #[must_use]
     pub fn with_cc(mut self, cc: impl Into<String>) -> Self {
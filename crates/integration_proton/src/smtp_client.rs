@@ -15,6 +15,14 @@ use tracing::{debug, error, instrument, trace};
 
 use crate::{EmailComposition, ProtonConfig, ProtonError, TlsConfig};
 
+/// Content headers of the plain text body
+const TEXT_PART_HEADERS: &str =
+    "Content-Type: text/plain; charset=utf-8\r\nContent-Transfer-Encoding: 8bit\r\n";
+
+/// Content headers of the HTML body
+const HTML_PART_HEADERS: &str =
+    "Content-Type: text/html; charset=utf-8\r\nContent-Transfer-Encoding: 8bit\r\n";
+
 /// SMTP client for Proton Bridge
 ///
 /// Manages SMTP connections for sending emails through Proton Bridge.
@@ -60,6 +68,9 @@ impl ProtonSmtpClient {
     }
 
     /// Builds the email content in RFC 5322 format
+    ///
+    /// Emails with an HTML body are sent as `multipart/alternative` with the
+    /// plain text part first.
     fn build_email_content(&self, email: &EmailComposition, message_id: &str) -> String {
         let date = chrono::Utc::now().format("%a, %d %b %Y %H:%M:%S +0000");
 
@@ -69,9 +80,7 @@ impl ProtonSmtpClient {
              Subject: {}\r\n\
              Date: {}\r\n\
              Message-ID: {}\r\n\
-             MIME-Version: 1.0\r\n\
-             Content-Type: text/plain; charset=utf-8\r\n\
-             Content-Transfer-Encoding: 8bit\r\n",
+             MIME-Version: 1.0\r\n",
             self.config.email, email.to, email.subject, date, message_id
         );
 
@@ -80,8 +89,19 @@ impl ProtonSmtpClient {
             let _ = writeln!(headers, "Cc: {}\r", email.cc.join(", "));
         }
 
-        // Add blank line separator and body
-        format!("{headers}\r\n{}", email.body)
+        let Some(ref html) = email.html_body else {
+            // Add blank line separator and body
+            return format!("{headers}{TEXT_PART_HEADERS}\r\n{}", email.body);
+        };
+
+        let boundary = format!("=_{}", uuid::Uuid::new_v4().simple());
+        format!(
+            "{headers}Content-Type: multipart/alternative; boundary=\"{boundary}\"\r\n\r\n\
+             --{boundary}\r\n{TEXT_PART_HEADERS}\r\n{}\r\n\
+             --{boundary}\r\n{HTML_PART_HEADERS}\r\n{html}\r\n\
+             --{boundary}--\r\n",
+            email.body
+        )
     }
 
     /// Sends the email via SMTP
@@ -405,6 +425,33 @@ mod tests {
         assert!(content.contains("Cc: cc1@example.com, cc2@example.com"));
     }
 
+    #[test]
+    fn build_email_content_plain_text_only() {
+        let client = ProtonSmtpClient::new(test_config());
+        let email = EmailComposition::new("recipient@example.com", "Test Subject", "Hello World");
+
+        let content = client.build_email_content(&email, "<123@test.local>");
+
+        assert!(content.contains("Content-Type: text/plain; charset=utf-8"));
+        assert!(!content.contains("multipart"));
+    }
+
+    #[test]
+    fn build_email_content_with_html_alternative() {
+        let client = ProtonSmtpClient::new(test_config());
+        let email = EmailComposition::new("recipient@example.com", "Test Subject", "Hello World")
+            .with_html_body("<p>Hello World</p>");
+
+        let content = client.build_email_content(&email, "<123@test.local>");
+
+        assert!(content.contains("Content-Type: multipart/alternative; boundary="));
+        let text = content.find("Content-Type: text/plain").unwrap();
+        let html = content.find("Content-Type: text/html").unwrap();
+        assert!(text < html);
+        assert!(content.contains("<p>Hello World</p>"));
+        assert!(content.trim_end().ends_with("--"));
+    }
+
     #[tokio::test]
     async fn check_connection_fails_for_unavailable_server() {
         let config = ProtonConfig {
@@ -424,6 +471,7 @@ mod tests {
             cc: vec!["cc1@example.com".to_string()],
             subject: "Test Subject".to_string(),
             body: "Hello, World!".to_string(),
+            html_body: None,
         };
 
         assert_eq!(email.to, "recipient@example.com");
@@ -7,6 +7,7 @@ use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use domain::PhoneNumber;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::UnixStream;
 use tokio::sync::Mutex;
@@ -14,8 +15,8 @@ use tracing::{debug, error, instrument, warn};
 
 use crate::error::SignalError;
 use crate::types::{
-    Attachment, Envelope, JsonRpcRequest, JsonRpcResponse, ReceiptType, ReceiveParams, SendParams,
-    SendReceiptParams, SendResult, SignalClientConfig,
+    Attachment, Envelope, JsonRpcRequest, JsonRpcResponse, ReceiptType, ReceiveParams,
+    RemoteDeleteParams, SendParams, SendReceiptParams, SendResult, SignalClientConfig,
 };
 
 /// Client for communicating with signal-cli JSON-RPC daemon
@@ -55,7 +56,12 @@ impl SignalClient {
             config,
             connection: Mutex::new(None),
             request_id: AtomicU64::new(1),
-            whitelisted_phones: Arc::new(whitelist),
+            whitelisted_phones: Arc::new(
+                whitelist
+                    .iter()
+                    .map(|phone| normalize_phone(phone))
+                    .collect(),
+            ),
         }
     }
 
@@ -71,12 +77,9 @@ impl SignalClient {
         if self.whitelisted_phones.is_empty() {
             return true; // Empty whitelist = allow all
         }
-        // Normalize phone numbers for comparison
-        let normalized = phone.trim().replace([' ', '-', '(', ')'], "");
-        self.whitelisted_phones.iter().any(|w| {
-            let w_normalized = w.trim().replace([' ', '-', '(', ')'], "");
-            w_normalized == normalized
-        })
+        // Whitelist entries are normalized on construction
+        let normalized = normalize_phone(phone);
+        self.whitelisted_phones.iter().any(|w| *w == normalized)
     }
 
     /// Check if signal-cli daemon is available
@@ -136,6 +139,32 @@ impl SignalClient {
         self.call_method("send", params).await
     }
 
+    /// Replace the text of a previously sent message
+    ///
+    /// signal-cli sends edits through `send` with an `editTimestamp`; the
+    /// returned timestamp identifies the edited version.
+    #[instrument(skip(self, new_text), fields(recipient = %recipient, target = target_timestamp))]
+    pub async fn edit_message(
+        &self,
+        recipient: &str,
+        target_timestamp: i64,
+        new_text: &str,
+    ) -> Result<SendResult, SignalError> {
+        let params = SendParams::text(recipient, new_text).with_edit(target_timestamp);
+        self.call_method("send", params).await
+    }
+
+    /// Delete a previously sent message for everyone (remote delete)
+    #[instrument(skip(self), fields(recipient = %recipient, target = target_timestamp))]
+    pub async fn delete_message(
+        &self,
+        recipient: &str,
+        target_timestamp: i64,
+    ) -> Result<SendResult, SignalError> {
+        let params = RemoteDeleteParams::new(recipient, target_timestamp);
+        self.call_method("remoteDelete", params).await
+    }
+
     /// Mark messages as read
     #[instrument(skip(self, timestamps), fields(recipient = %recipient, count = timestamps.len()))]
     pub async fn send_read_receipt(
@@ -257,6 +286,17 @@ impl std::fmt::Debug for SignalClient {
     }
 }
 
+/// Normalize a phone number to E.164 for whitelist comparison
+///
+/// Senders that are not valid phone numbers (e.g., UUIDs) are only
+/// stripped of separators.
+fn normalize_phone(phone: &str) -> String {
+    PhoneNumber::normalize(phone).map_or_else(
+        |_| phone.trim().replace([' ', '-', '(', ')'], ""),
+        |phone| phone.as_str().to_string(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +340,16 @@ mod tests {
         assert!(client.is_whitelisted("+1-234-567-8900"));
     }
 
+    #[test]
+    fn whitelist_matches_german_mobile_formats() {
+        let whitelist = vec!["+49 151 23456789".to_string()];
+        let client = SignalClient::with_whitelist(test_config(), whitelist);
+        assert!(client.is_whitelisted("+4915123456789"));
+        assert!(client.is_whitelisted("0049 151 23456789"));
+        assert!(client.is_whitelisted("+49 (0)151 2345 6789"));
+        assert!(!client.is_whitelisted("+4915123456780"));
+    }
+
     #[test]
     fn debug_format() {
         let client = SignalClient::new(test_config());
@@ -48,9 +48,10 @@ mod types;
 pub use client::SignalClient;
 pub use error::SignalError;
 pub use types::{
-    Attachment, DataMessage, Envelope, JsonRpcError, JsonRpcRequest, JsonRpcResponse, Quote,
-    ReceiptType, ReceiveParams, SendParams, SendReceiptParams, SendResult, SendResultItem,
-    SignalClientConfig, SyncMessage, TypingMessage,
+    Attachment, DataMessage, EditMessage, Envelope, JsonRpcError, JsonRpcRequest, JsonRpcResponse,
+    Quote, ReceiptMessage, ReceiptType, ReceiveParams, RemoteDelete, RemoteDeleteParams,
+    SendParams, SendReceiptParams, SendResult, SendResultItem, SignalClientConfig, SyncMessage,
+    TypingMessage,
 };
 
 #[cfg(test)]
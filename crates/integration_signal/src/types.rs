@@ -144,6 +144,9 @@ pub struct SendParams {
     /// Quote author
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quote_author: Option<String>,
+    /// Timestamp of a previously sent message this one replaces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit_timestamp: Option<i64>,
 }
 
 impl SendParams {
@@ -156,6 +159,7 @@ impl SendParams {
             attachment: Vec::new(),
             quote_timestamp: None,
             quote_author: None,
+            edit_timestamp: None,
         }
     }
 
@@ -168,6 +172,7 @@ impl SendParams {
             attachment: vec![path.into()],
             quote_timestamp: None,
             quote_author: None,
+            edit_timestamp: None,
         }
     }
 
@@ -178,6 +183,34 @@ impl SendParams {
         self.quote_author = Some(author.into());
         self
     }
+
+    /// Send as an edit of the message sent at `target_timestamp`
+    #[must_use]
+    pub const fn with_edit(mut self, target_timestamp: i64) -> Self {
+        self.edit_timestamp = Some(target_timestamp);
+        self
+    }
+}
+
+/// Parameters for the `remoteDelete` method
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteDeleteParams {
+    /// Recipient of the original message
+    pub recipient: Vec<String>,
+    /// Timestamp of the message to delete
+    pub target_timestamp: i64,
+}
+
+impl RemoteDeleteParams {
+    /// Create params deleting the message sent to `recipient` at `target_timestamp`
+    #[must_use]
+    pub fn new(recipient: impl Into<String>, target_timestamp: i64) -> Self {
+        Self {
+            recipient: vec![recipient.into()],
+            target_timestamp,
+        }
+    }
 }
 
 /// Parameters for the `sendReceipt` method
@@ -267,6 +300,8 @@ pub struct Envelope {
     pub receipt_message: Option<ReceiptMessage>,
     /// Sync message (from linked devices)
     pub sync_message: Option<SyncMessage>,
+    /// Edit of a previously sent message
+    pub edit_message: Option<EditMessage>,
 }
 
 impl Envelope {
@@ -281,6 +316,14 @@ impl Envelope {
     pub const fn is_data_message(&self) -> bool {
         self.data_message.is_some()
     }
+
+    /// Target timestamp and new text, if this envelope edits a text message
+    #[must_use]
+    pub fn edited_text(&self) -> Option<(i64, &str)> {
+        let edit = self.edit_message.as_ref()?;
+        let body = edit.data_message.body.as_deref()?;
+        Some((edit.target_sent_timestamp, body))
+    }
 }
 
 /// Data message content
@@ -300,6 +343,8 @@ pub struct DataMessage {
     pub expires_in_seconds: Option<i64>,
     /// View-once flag
     pub view_once: Option<bool>,
+    /// Remote delete of a previously sent message
+    pub remote_delete: Option<RemoteDelete>,
 }
 
 impl DataMessage {
@@ -314,6 +359,30 @@ impl DataMessage {
     pub fn has_attachments(&self) -> bool {
         !self.attachments.is_empty()
     }
+
+    /// Timestamp of the message this one deletes, if it is a remote delete
+    #[must_use]
+    pub fn deleted_timestamp(&self) -> Option<i64> {
+        self.remote_delete.as_ref().map(|d| d.timestamp)
+    }
+}
+
+/// Remote delete reference
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteDelete {
+    /// Timestamp of the deleted message
+    pub timestamp: i64,
+}
+
+/// Edit of a previously sent message
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditMessage {
+    /// Timestamp of the message being edited
+    pub target_sent_timestamp: i64,
+    /// Replacement message content
+    pub data_message: DataMessage,
 }
 
 /// Attachment in a data message
@@ -508,6 +577,22 @@ mod tests {
             assert_eq!(params.attachment, vec!["/path/to/file"]);
         }
 
+        #[test]
+        fn with_edit_serializes_edit_timestamp() {
+            let params = SendParams::text("+1234567890", "Fixed").with_edit(12345);
+            let json = serde_json::to_value(&params).unwrap();
+            assert_eq!(json["editTimestamp"], 12345);
+            assert_eq!(json["message"], "Fixed");
+        }
+
+        #[test]
+        fn remote_delete_serializes_target_timestamp() {
+            let params = RemoteDeleteParams::new("+1234567890", 12345);
+            let json = serde_json::to_value(&params).unwrap();
+            assert_eq!(json["recipient"], serde_json::json!(["+1234567890"]));
+            assert_eq!(json["targetTimestamp"], 12345);
+        }
+
         #[test]
         fn with_reply_sets_quote_fields() {
             let params = SendParams::text("+1234567890", "Reply").with_reply(12345, "+0987654321");
@@ -530,6 +615,7 @@ mod tests {
                 typing_message: None,
                 receipt_message: None,
                 sync_message: None,
+                edit_message: None,
             };
             assert_eq!(envelope.sender(), Some("+1234567890"));
         }
@@ -548,10 +634,12 @@ mod tests {
                     quote: None,
                     expires_in_seconds: None,
                     view_once: None,
+                    remote_delete: None,
                 }),
                 typing_message: None,
                 receipt_message: None,
                 sync_message: None,
+                edit_message: None,
             };
             assert!(envelope.is_data_message());
         }
@@ -560,6 +648,43 @@ mod tests {
     mod data_message_tests {
         use super::*;
 
+        #[test]
+        fn parses_remote_delete_envelope() {
+            let json = r#"{
+                "source": "+1234567890",
+                "timestamp": 1700000001000,
+                "dataMessage": {
+                    "timestamp": 1700000001000,
+                    "remoteDelete": {"timestamp": 1700000000000}
+                }
+            }"#;
+            let envelope: Envelope = serde_json::from_str(json).unwrap();
+            let msg = envelope.data_message.unwrap();
+            assert_eq!(msg.deleted_timestamp(), Some(1_700_000_000_000));
+            assert!(!msg.has_text());
+        }
+
+        #[test]
+        fn parses_edit_envelope() {
+            let json = r#"{
+                "source": "+1234567890",
+                "timestamp": 1700000002000,
+                "editMessage": {
+                    "targetSentTimestamp": 1700000000000,
+                    "dataMessage": {"timestamp": 1700000002000, "body": "Corrected"}
+                }
+            }"#;
+            let envelope: Envelope = serde_json::from_str(json).unwrap();
+            assert!(envelope.data_message.is_none());
+            assert_eq!(
+                envelope.edited_text(),
+                Some((1_700_000_000_000, "Corrected"))
+            );
+            let edit = envelope.edit_message.unwrap();
+            assert_eq!(edit.target_sent_timestamp, 1_700_000_000_000);
+            assert_eq!(edit.data_message.body.as_deref(), Some("Corrected"));
+        }
+
         #[test]
         fn has_text_true_when_body_present() {
             let msg = DataMessage {
@@ -569,6 +694,7 @@ mod tests {
                 quote: None,
                 expires_in_seconds: None,
                 view_once: None,
+                remote_delete: None,
             };
             assert!(msg.has_text());
         }
@@ -582,6 +708,7 @@ mod tests {
                 quote: None,
                 expires_in_seconds: None,
                 view_once: None,
+                remote_delete: None,
             };
             assert!(!msg.has_text());
         }
@@ -604,6 +731,7 @@ mod tests {
                 quote: None,
                 expires_in_seconds: None,
                 view_once: None,
+                remote_delete: None,
             };
             assert!(msg.has_attachments());
         }
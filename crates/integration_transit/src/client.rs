@@ -3,11 +3,11 @@
 //! Provides journey planning, stop search, and nearby-stop lookup
 //! using the public [v6.db.transport.rest](https://v6.db.transport.rest) API.
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use integration_http::{CorrelatedClientConfig, CorrelatedHttpClient, SharedCorrelatedClient};
 use serde::Deserialize;
 use tracing::{debug, instrument, warn};
 
@@ -47,7 +47,7 @@ pub trait TransitClient: Send + Sync {
 /// HAFAS-based transit client using the transport.rest API
 #[derive(Debug)]
 pub struct HafasTransitClient {
-    client: Client,
+    client: SharedCorrelatedClient,
     config: TransitConfig,
 }
 
@@ -58,16 +58,30 @@ impl HafasTransitClient {
     ///
     /// Returns an error if the HTTP client cannot be initialized.
     pub fn new(config: &TransitConfig) -> Result<Self, TransitError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
-            .user_agent("PiSovereign/1.0")
-            .build()
-            .map_err(|e| TransitError::ConnectionFailed(e.to_string()))?;
+        let client = CorrelatedHttpClient::with_config(
+            CorrelatedClientConfig::default()
+                .with_timeout(Duration::from_secs(config.timeout_secs)),
+        )
+        .map_err(|e| TransitError::ConnectionFailed(e.to_string()))?;
 
-        Ok(Self {
+        Ok(Self::with_http_client(config, Arc::new(client)))
+    }
+
+    /// Create a new HAFAS transit client using a shared HTTP client
+    ///
+    /// Outgoing requests carry the ambient `X-Request-Id`. The configured
+    /// timeout is applied per request.
+    #[must_use]
+    pub fn with_http_client(config: &TransitConfig, client: SharedCorrelatedClient) -> Self {
+        Self {
             client,
             config: config.clone(),
-        })
+        }
+    }
+
+    /// Per-request timeout from the configuration
+    const fn timeout(&self) -> Duration {
+        Duration::from_secs(self.config.timeout_secs)
     }
 
     /// Build product query parameters based on config
@@ -207,6 +221,7 @@ impl TransitClient for HafasTransitClient {
             .client
             .get(&url)
             .query(&params)
+            .timeout(self.timeout())
             .send()
             .await
             .map_err(|e| {
@@ -272,6 +287,7 @@ impl TransitClient for HafasTransitClient {
             .client
             .get(&url)
             .query(&params)
+            .timeout(self.timeout())
             .send()
             .await
             .map_err(|e| {
@@ -324,6 +340,7 @@ impl TransitClient for HafasTransitClient {
             .client
             .get(&url)
             .query(&params)
+            .timeout(self.timeout())
             .send()
             .await
             .map_err(|e| {
@@ -353,7 +370,12 @@ impl TransitClient for HafasTransitClient {
 
     async fn is_healthy(&self) -> bool {
         let url = format!("{}/locations?query=test&results=1", self.config.base_url);
-        self.client.get(&url).send().await.is_ok()
+        self.client
+            .get(&url)
+            .timeout(self.timeout())
+            .send()
+            .await
+            .is_ok()
     }
 }
 
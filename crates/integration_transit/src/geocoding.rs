@@ -3,6 +3,9 @@
 //! Converts free-form address strings to geographic coordinates using
 //! the [Nominatim](https://nominatim.openstreetmap.org) API (OpenStreetMap).
 //!
+//! Reverse geocoding turns coordinates into a short place name such as
+//! "Berlin Mitte".
+//!
 //! Implements rate limiting (max 1 request/second per Nominatim usage policy)
 //! and result caching (24h TTL) to minimize API calls.
 
@@ -128,6 +131,8 @@ pub struct NominatimGeocodingClient {
     client: Client,
     config: NominatimConfig,
     cache: Cache<String, (f64, f64)>,
+    /// Place names by rounded coordinates; `None` for places without address
+    reverse_cache: Cache<String, Option<String>>,
     last_request: Arc<Mutex<Instant>>,
 }
 
@@ -154,11 +159,16 @@ impl NominatimGeocodingClient {
             .max_capacity(1000)
             .time_to_live(cache_ttl)
             .build();
+        let reverse_cache = Cache::builder()
+            .max_capacity(1000)
+            .time_to_live(cache_ttl)
+            .build();
 
         Ok(Self {
             client,
             config: config.clone(),
             cache,
+            reverse_cache,
             last_request: Arc::new(Mutex::new(Instant::now() - Duration::from_secs(2))),
         })
     }
@@ -174,6 +184,78 @@ impl NominatimGeocodingClient {
         }
         *last = Instant::now();
     }
+
+    /// Convert a location to a short, human-readable place name
+    ///
+    /// Returns the locality with its district (e.g., "Berlin Mitte"), falling
+    /// back to the full address. Results are cached by coordinates rounded to
+    /// about 10 m, including places without an address such as the open sea.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeocodingError::AddressNotFound`] if there is no address at
+    /// the location, or another error if the request fails.
+    #[instrument(skip(self, loc), fields(lat = loc.latitude(), lon = loc.longitude()))]
+    pub async fn reverse(&self, loc: &GeoLocation) -> Result<String, GeocodingError> {
+        let cache_key = format!("{:.4},{:.4}", loc.latitude(), loc.longitude());
+        if let Some(cached) = self.reverse_cache.get(&cache_key).await {
+            debug!(%cache_key, "Reverse geocoding cache hit");
+            return cached.ok_or(GeocodingError::AddressNotFound(cache_key));
+        }
+
+        self.rate_limit().await;
+
+        let url = format!("{}/reverse", self.config.base_url);
+        let params = [
+            ("lat", loc.latitude().to_string()),
+            ("lon", loc.longitude().to_string()),
+            ("format", "jsonv2".to_string()),
+            ("addressdetails", "1".to_string()),
+            ("accept-language", "de,en".to_string()),
+        ];
+
+        debug!(%cache_key, "Reverse geocoding");
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    GeocodingError::Timeout
+                } else {
+                    GeocodingError::ConnectionFailed(e.to_string())
+                }
+            })?;
+
+        if !response.status().is_success() {
+            return Err(GeocodingError::RequestFailed(format!(
+                "HTTP {}",
+                response.status()
+            )));
+        }
+
+        let result: NominatimReverseResult = response
+            .json()
+            .await
+            .map_err(|e| GeocodingError::ParseError(e.to_string()))?;
+
+        // Nominatim answers with an error object for the open sea
+        let label = result.error.as_ref().map_or_else(
+            || result.label(),
+            |error| {
+                debug!(%cache_key, %error, "No address at location");
+                None
+            },
+        );
+
+        self.reverse_cache
+            .insert(cache_key.clone(), label.clone())
+            .await;
+        label.ok_or(GeocodingError::AddressNotFound(cache_key))
+    }
 }
 
 #[async_trait]
@@ -257,53 +339,14 @@ impl GeocodingClient for NominatimGeocodingClient {
         GeoLocation::new(lat, lon).map_err(|e| GeocodingError::ParseError(e.to_string()))
     }
 
-    #[instrument(skip(self))]
     async fn reverse_geocode(
         &self,
         latitude: f64,
         longitude: f64,
     ) -> Result<String, GeocodingError> {
-        self.rate_limit().await;
-
-        let url = format!("{}/reverse", self.config.base_url);
-        let params = [
-            ("lat", latitude.to_string()),
-            ("lon", longitude.to_string()),
-            ("format", "jsonv2".to_string()),
-            ("accept-language", "de,en".to_string()),
-        ];
-
-        debug!(%latitude, %longitude, "Reverse geocoding");
-
-        let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    GeocodingError::Timeout
-                } else {
-                    GeocodingError::ConnectionFailed(e.to_string())
-                }
-            })?;
-
-        if !response.status().is_success() {
-            return Err(GeocodingError::RequestFailed(format!(
-                "HTTP {}",
-                response.status()
-            )));
-        }
-
-        let result: NominatimResult = response
-            .json()
-            .await
+        let loc = GeoLocation::new(latitude, longitude)
             .map_err(|e| GeocodingError::ParseError(e.to_string()))?;
-
-        result
-            .display_name
-            .ok_or_else(|| GeocodingError::AddressNotFound(format!("{latitude},{longitude}")))
+        self.reverse(&loc).await
     }
 }
 
@@ -312,7 +355,63 @@ impl GeocodingClient for NominatimGeocodingClient {
 struct NominatimResult {
     lat: String,
     lon: String,
+}
+
+/// Raw Nominatim reverse geocoding response
+#[derive(Debug, Deserialize)]
+struct NominatimReverseResult {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
     display_name: Option<String>,
+    #[serde(default)]
+    address: Option<NominatimAddress>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Address details of a reverse geocoding result
+#[derive(Debug, Default, Deserialize)]
+struct NominatimAddress {
+    city: Option<String>,
+    town: Option<String>,
+    village: Option<String>,
+    municipality: Option<String>,
+    hamlet: Option<String>,
+    suburb: Option<String>,
+    city_district: Option<String>,
+    borough: Option<String>,
+    quarter: Option<String>,
+    county: Option<String>,
+    state: Option<String>,
+}
+
+impl NominatimReverseResult {
+    /// Short place name: "Locality District", the locality, or a fallback
+    fn label(&self) -> Option<String> {
+        let first = |fields: &[&Option<String>]| {
+            fields
+                .iter()
+                .filter_map(|f| f.as_deref())
+                .map(str::trim)
+                .find(|f| !f.is_empty())
+                .map(str::to_string)
+        };
+        let none = NominatimAddress::default();
+        let a = self.address.as_ref().unwrap_or(&none);
+
+        let locality = first(&[&a.city, &a.town, &a.village, &a.municipality, &a.hamlet]);
+        let district = first(&[&a.suburb, &a.city_district, &a.borough, &a.quarter]);
+
+        match (locality, district) {
+            (Some(locality), Some(district)) if locality != district => {
+                Some(format!("{locality} {district}"))
+            },
+            (Some(locality), _) => Some(locality),
+            (None, Some(district)) => Some(district),
+            (None, None) => first(&[&self.name, &a.county, &a.state, &self.display_name]),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -351,7 +450,6 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].lat, "52.52");
         assert_eq!(results[0].lon, "13.37");
-        assert_eq!(results[0].display_name.as_deref(), Some("Berlin"));
     }
 
     #[test]
@@ -361,6 +459,45 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    fn reverse_result(json: &str) -> NominatimReverseResult {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_reverse_label_with_district() {
+        let result = reverse_result(
+            r#"{"display_name": "Unter den Linden, Mitte, Berlin, Deutschland",
+                "address": {"road": "Unter den Linden", "suburb": "Mitte",
+                            "borough": "Mitte", "city": "Berlin"}}"#,
+        );
+        assert_eq!(result.label().as_deref(), Some("Berlin Mitte"));
+    }
+
+    #[test]
+    fn test_reverse_label_without_district() {
+        let result = reverse_result(r#"{"address": {"village": "Kleinmachnow"}}"#);
+        assert_eq!(result.label().as_deref(), Some("Kleinmachnow"));
+    }
+
+    #[test]
+    fn test_reverse_label_falls_back_to_region() {
+        let result = reverse_result(
+            r#"{"name": "", "display_name": "Nationalpark, Bayern",
+                "address": {"county": "Landkreis Berchtesgadener Land", "state": "Bayern"}}"#,
+        );
+        assert_eq!(
+            result.label().as_deref(),
+            Some("Landkreis Berchtesgadener Land")
+        );
+    }
+
+    #[test]
+    fn test_reverse_ocean_response() {
+        let result = reverse_result(r#"{"error": "Unable to geocode"}"#);
+        assert!(result.error.is_some());
+        assert!(result.label().is_none());
+    }
+
     #[test]
     fn test_nominatim_config_serialization() {
         let config = NominatimConfig::default();
@@ -1,9 +1,15 @@
 //! Integration tests for the transit client (wiremock-based)
 
-use wiremock::matchers::{method, path, query_param};
+use uuid::Uuid;
+use wiremock::matchers::{header, header_exists, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
-use integration_transit::{HafasTransitClient, TransitClient, TransitConfig};
+use domain::value_objects::GeoLocation;
+use integration_http::{create_shared_client, scope_request_id};
+use integration_transit::{
+    GeocodingClient, GeocodingError, HafasTransitClient, NominatimConfig, NominatimGeocodingClient,
+    TransitClient, TransitConfig,
+};
 
 fn config_for_mock(base_url: &str) -> TransitConfig {
     TransitConfig {
@@ -200,3 +206,108 @@ async fn test_empty_journeys_response() {
 
     assert!(result.journeys.is_empty());
 }
+
+#[tokio::test]
+async fn test_request_id_propagated_through_shared_client() {
+    let server = MockServer::start().await;
+    let request_id = Uuid::new_v4();
+
+    Mock::given(method("GET"))
+        .and(path("/journeys"))
+        .and(header("x-request-id", request_id.to_string().as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{ "journeys": [] }"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = config_for_mock(&server.uri());
+    let client = HafasTransitClient::with_http_client(&config, create_shared_client().unwrap());
+
+    let result = scope_request_id(
+        request_id,
+        client.search_journeys(52.52, 13.41, 52.50, 13.33, None, 3),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_default_client_propagates_request_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/locations"))
+        .and(header_exists("x-request-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = config_for_mock(&server.uri());
+    let client = HafasTransitClient::new(&config).unwrap();
+
+    let stops = scope_request_id(Uuid::new_v4(), client.search_stops("Alexanderplatz", 5))
+        .await
+        .unwrap();
+    assert!(stops.is_empty());
+}
+
+fn geocoding_config_for_mock(base_url: &str) -> NominatimConfig {
+    NominatimConfig {
+        base_url: base_url.to_string(),
+        ..NominatimConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_reverse_geocoding_returns_place_name_and_caches() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/reverse"))
+        .and(query_param("addressdetails", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{
+                "display_name": "Unter den Linden, Mitte, Berlin, 10117, Deutschland",
+                "address": {"road": "Unter den Linden", "suburb": "Mitte", "city": "Berlin"}
+            }"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = geocoding_config_for_mock(&server.uri());
+    let client = NominatimGeocodingClient::new(&config).unwrap();
+    let location = GeoLocation::new(52.517, 13.389).unwrap();
+
+    assert_eq!(client.reverse(&location).await.unwrap(), "Berlin Mitte");
+    // Served from the cache, no second request
+    assert_eq!(
+        client.reverse_geocode(52.517_01, 13.389_02).await.unwrap(),
+        "Berlin Mitte"
+    );
+}
+
+#[tokio::test]
+async fn test_reverse_geocoding_ocean_is_not_found() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/reverse"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(r#"{"error": "Unable to geocode"}"#),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = geocoding_config_for_mock(&server.uri());
+    let client = NominatimGeocodingClient::new(&config).unwrap();
+    let atlantic = GeoLocation::new(40.0, -40.0).unwrap();
+
+    for _ in 0..2 {
+        let result = client.reverse(&atlantic).await;
+        assert!(matches!(result, Err(GeocodingError::AddressNotFound(_))));
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! HTTP client for the Open-Meteo Weather API.
 
+use std::{sync::Arc, time::Duration};
+
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, TimeZone, Utc};
-use reqwest::Client;
+use integration_http::{CorrelatedClientConfig, CorrelatedHttpClient, SharedCorrelatedClient};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, instrument};
@@ -111,7 +113,7 @@ pub trait WeatherClient: Send + Sync {
 /// Open-Meteo HTTP client implementation
 #[derive(Debug)]
 pub struct OpenMeteoClient {
-    client: Client,
+    client: SharedCorrelatedClient,
     config: WeatherConfig,
 }
 
@@ -122,12 +124,22 @@ impl OpenMeteoClient {
     ///
     /// Returns an error if the HTTP client cannot be initialized.
     pub fn new(config: WeatherConfig) -> Result<Self, WeatherError> {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(config.timeout_secs))
-            .build()
-            .map_err(|e| WeatherError::ConnectionFailed(e.to_string()))?;
+        let client = CorrelatedHttpClient::with_config(
+            CorrelatedClientConfig::default()
+                .with_timeout(Duration::from_secs(config.timeout_secs)),
+        )
+        .map_err(|e| WeatherError::ConnectionFailed(e.to_string()))?;
 
-        Ok(Self { client, config })
+        Ok(Self::with_http_client(config, Arc::new(client)))
+    }
+
+    /// Create a new Open-Meteo client using a shared HTTP client
+    ///
+    /// Outgoing requests carry the ambient `X-Request-Id`. The configured
+    /// timeout is applied per request.
+    #[must_use]
+    pub const fn with_http_client(config: WeatherConfig, client: SharedCorrelatedClient) -> Self {
+        Self { client, config }
     }
 
     /// Create a new client with default configuration
@@ -279,6 +291,7 @@ impl WeatherClient for OpenMeteoClient {
         let response = self
             .client
             .get(&url)
+            .timeout(Duration::from_secs(self.config.timeout_secs))
             .send()
             .await
             .map_err(|e| WeatherError::RequestFailed(e.to_string()))?;
@@ -321,6 +334,7 @@ impl WeatherClient for OpenMeteoClient {
         let response = self
             .client
             .get(&url)
+            .timeout(Duration::from_secs(self.config.timeout_secs))
             .send()
             .await
             .map_err(|e| WeatherError::RequestFailed(e.to_string()))?;
@@ -3,10 +3,12 @@
 //! These tests verify the weather client's behavior against a mock HTTP server,
 //! ensuring proper handling of various response scenarios.
 
+use integration_http::{create_shared_client, scope_request_id};
 use integration_weather::{OpenMeteoClient, WeatherClient, WeatherConfig, WeatherError};
+use uuid::Uuid;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path, query_param},
+    matchers::{header, method, path, query_param},
 };
 
 /// Sample Open-Meteo API response for testing
@@ -325,3 +327,29 @@ async fn test_forecast_days_parameter() {
 
     assert!(result.is_ok(), "Expected success, got: {result:?}");
 }
+
+#[tokio::test]
+async fn test_request_id_propagated_through_shared_client() {
+    let mock_server = MockServer::start().await;
+    let request_id = Uuid::new_v4();
+
+    Mock::given(method("GET"))
+        .and(path("/forecast"))
+        .and(header("x-request-id", request_id.to_string().as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(sample_weather_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = WeatherConfig {
+        base_url: mock_server.uri(),
+        timeout_secs: 5,
+        ..Default::default()
+    };
+    let shared = create_shared_client().expect("Failed to create shared client");
+    let client = OpenMeteoClient::with_http_client(config, shared);
+
+    let result = scope_request_id(request_id, client.get_current(52.52, 13.405)).await;
+
+    assert!(result.is_ok(), "Expected success, got: {result:?}");
+}
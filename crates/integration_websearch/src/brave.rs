@@ -3,8 +3,11 @@
 //! Client for the Brave Search API (<https://brave.com/search/api/>).
 
 use async_trait::async_trait;
-use reqwest::Client;
-use std::time::{Duration, Instant};
+use integration_http::{CorrelatedClientConfig, CorrelatedHttpClient, SharedCorrelatedClient};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tracing::{debug, instrument, warn};
 
 use crate::{
@@ -52,7 +55,8 @@ mod api {
 /// Brave Search API client
 #[derive(Debug)]
 pub struct BraveSearchClient {
-    client: Client,
+    client: SharedCorrelatedClient,
+    timeout: Duration,
     api_key: String,
     base_url: String,
     safe_search: String,
@@ -66,17 +70,34 @@ impl BraveSearchClient {
     ///
     /// Returns an error if the API key is missing or HTTP client cannot be created.
     pub fn new(config: &WebSearchConfig) -> Result<Self, WebSearchError> {
+        let timeout = Duration::from_secs(config.timeout_secs);
+        let client = CorrelatedHttpClient::with_config(
+            CorrelatedClientConfig::default().with_timeout(timeout),
+        )
+        .map_err(|e| WebSearchError::ConnectionFailed(e.to_string()))?;
+
+        Self::with_http_client(config, Arc::new(client))
+    }
+
+    /// Create a new Brave Search client using a shared HTTP client
+    ///
+    /// Outgoing requests carry the ambient `X-Request-Id`. The configured
+    /// timeout is applied per request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API key is missing.
+    pub fn with_http_client(
+        config: &WebSearchConfig,
+        client: SharedCorrelatedClient,
+    ) -> Result<Self, WebSearchError> {
         let api_key = config.brave_api_key.clone().ok_or_else(|| {
             WebSearchError::ConfigurationError("Brave API key is required".to_string())
         })?;
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
-            .build()
-            .map_err(|e| WebSearchError::ConnectionFailed(e.to_string()))?;
-
         Ok(Self {
             client,
+            timeout: Duration::from_secs(config.timeout_secs),
             api_key,
             base_url: config.brave_base_url.clone(),
             safe_search: config.safe_search.clone(),
@@ -147,11 +168,14 @@ impl SearchProvider for BraveSearchClient {
             .get(&url)
             .header("X-Subscription-Token", &self.api_key)
             .header("Accept", "application/json")
+            .timeout(self.timeout)
             .send()
             .await
             .map_err(|e| {
                 if e.is_timeout() {
-                    WebSearchError::Timeout { timeout_secs: 30 }
+                    WebSearchError::Timeout {
+                        timeout_secs: self.timeout.as_secs(),
+                    }
                 } else if e.is_connect() {
                     WebSearchError::ConnectionFailed(e.to_string())
                 } else {
@@ -42,6 +42,7 @@ pub use provider::SearchProvider;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use integration_http::SharedCorrelatedClient;
 use tracing::{debug, info, warn};
 
 /// Combined web search client with fallback support
@@ -62,8 +63,31 @@ impl WebSearchClient {
     ///
     /// Returns an error if the HTTP clients cannot be initialized.
     pub fn new(config: WebSearchConfig) -> Result<Self, WebSearchError> {
+        Self::build(config, None)
+    }
+
+    /// Create a new web search client whose Brave requests go through a
+    /// shared HTTP client, propagating the `X-Request-Id` header
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP clients cannot be initialized.
+    pub fn with_http_client(
+        config: WebSearchConfig,
+        client: SharedCorrelatedClient,
+    ) -> Result<Self, WebSearchError> {
+        Self::build(config, Some(client))
+    }
+
+    fn build(
+        config: WebSearchConfig,
+        client: Option<SharedCorrelatedClient>,
+    ) -> Result<Self, WebSearchError> {
         let brave = if config.brave_api_key.is_some() {
-            Some(BraveSearchClient::new(&config)?)
+            Some(match client {
+                Some(client) => BraveSearchClient::with_http_client(&config, client)?,
+                None => BraveSearchClient::new(&config)?,
+            })
         } else {
             warn!("No Brave API key configured, using DuckDuckGo only");
             None
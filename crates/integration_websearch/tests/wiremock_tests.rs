@@ -3,10 +3,12 @@
 //! These tests mock HTTP responses to verify client behavior without
 //! making actual API calls.
 
+use integration_http::{create_shared_client, scope_request_id};
 use integration_websearch::{
     BraveSearchClient, DuckDuckGoClient, SearchProvider, WebSearchClient, WebSearchConfig,
     WebSearchError,
 };
+use uuid::Uuid;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
     matchers::{header, method, query_param},
@@ -439,3 +441,30 @@ async fn test_response_footnotes() {
     assert!(footnotes.contains("https://www.rust-lang.org/"));
     assert!(footnotes.contains("https://doc.rust-lang.org/book/"));
 }
+
+#[tokio::test]
+async fn test_brave_propagates_request_id() {
+    let mock_server = MockServer::start().await;
+    let request_id = Uuid::new_v4();
+
+    Mock::given(method("GET"))
+        .and(header("x-request-id", request_id.to_string().as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(brave_success_response()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = WebSearchConfig {
+        brave_api_key: Some("test-key".to_string()),
+        brave_base_url: format!("{}/res/v1", mock_server.uri()),
+        ..Default::default()
+    };
+
+    let client =
+        WebSearchClient::with_http_client(config, create_shared_client().unwrap()).unwrap();
+    let response = scope_request_id(request_id, client.search("rust", 5))
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 2);
+}
@@ -1,7 +1,9 @@
 //! WhatsApp client for sending messages
 //!
-//! Uses the Meta Graph API to send WhatsApp messages, including text and audio.
+//! Uses the Meta Graph API to send WhatsApp messages, including text, audio,
+//! templates and reactions.
 
+use domain::PhoneNumber;
 use reqwest::Client;
 use reqwest::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
@@ -37,6 +39,9 @@ pub enum WhatsAppError {
 
     #[error("Media upload failed: {0}")]
     MediaUploadFailed(String),
+
+    #[error("Invalid template: {0}")]
+    InvalidTemplate(String),
 }
 
 /// WhatsApp client configuration
@@ -164,6 +169,242 @@ struct AudioContent {
     id: String,
 }
 
+/// Reaction message send request
+#[derive(Debug, Serialize)]
+struct SendReactionRequest {
+    messaging_product: &'static str,
+    recipient_type: &'static str,
+    to: String,
+    #[serde(rename = "type")]
+    msg_type: &'static str,
+    reaction: ReactionContent,
+}
+
+#[derive(Debug, Serialize)]
+struct ReactionContent {
+    message_id: String,
+    /// Empty string removes a previously sent reaction
+    emoji: String,
+}
+
+impl SendReactionRequest {
+    fn new(phone: &str, message_id: &str, emoji: &str) -> Self {
+        Self {
+            messaging_product: "whatsapp",
+            recipient_type: "individual",
+            to: phone.to_string(),
+            msg_type: "reaction",
+            reaction: ReactionContent {
+                message_id: message_id.to_string(),
+                emoji: emoji.to_string(),
+            },
+        }
+    }
+}
+
+/// Maximum number of buttons a template may define
+const MAX_TEMPLATE_BUTTONS: u8 = 10;
+
+/// A component of an approved message template with its variable values
+///
+/// Parameters replace the `{{1}}`, `{{2}}`, ... placeholders of the
+/// template in order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TemplateComponent {
+    /// Header variables (text headers have exactly one)
+    Header { parameters: Vec<TemplateParameter> },
+    /// Body variables
+    Body { parameters: Vec<TemplateParameter> },
+    /// Dynamic part of a button, addressed by its position in the template
+    Button {
+        sub_type: ButtonSubType,
+        #[serde(serialize_with = "serialize_index")]
+        index: u8,
+        parameters: Vec<TemplateParameter>,
+    },
+}
+
+impl TemplateComponent {
+    /// Body component with text parameters
+    #[must_use]
+    pub fn body<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::Body {
+            parameters: values.into_iter().map(TemplateParameter::text).collect(),
+        }
+    }
+
+    /// Header component with a single text parameter
+    #[must_use]
+    pub fn header(value: impl Into<String>) -> Self {
+        Self::Header {
+            parameters: vec![TemplateParameter::text(value)],
+        }
+    }
+
+    /// URL button whose link suffix is filled in
+    #[must_use]
+    pub fn url_button(index: u8, suffix: impl Into<String>) -> Self {
+        Self::Button {
+            sub_type: ButtonSubType::Url,
+            index,
+            parameters: vec![TemplateParameter::text(suffix)],
+        }
+    }
+
+    /// Quick reply button with the payload returned when it is tapped
+    #[must_use]
+    pub fn quick_reply_button(index: u8, payload: impl Into<String>) -> Self {
+        Self::Button {
+            sub_type: ButtonSubType::QuickReply,
+            index,
+            parameters: vec![TemplateParameter::Payload {
+                payload: payload.into(),
+            }],
+        }
+    }
+}
+
+/// Value substituted into a template placeholder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TemplateParameter {
+    /// Plain text value
+    Text { text: String },
+    /// Quick reply payload
+    Payload { payload: String },
+}
+
+impl TemplateParameter {
+    /// Text parameter
+    #[must_use]
+    pub fn text(value: impl Into<String>) -> Self {
+        Self::Text { text: value.into() }
+    }
+}
+
+/// Kind of template button
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonSubType {
+    /// Call-to-action button with a dynamic URL suffix
+    Url,
+    /// Button that sends a payload back to the webhook
+    QuickReply,
+}
+
+/// The Graph API expects the button index as a string
+fn serialize_index<S: serde::Serializer>(index: &u8, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(index)
+}
+
+/// Check that the components can form a valid template message
+fn validate_template_components(components: &[TemplateComponent]) -> Result<(), WhatsAppError> {
+    let mut headers = 0;
+    let mut bodies = 0;
+    let mut button_indexes = Vec::new();
+
+    for component in components {
+        match component {
+            TemplateComponent::Header { parameters } => {
+                headers += 1;
+                if parameters.len() != 1 {
+                    return Err(WhatsAppError::InvalidTemplate(format!(
+                        "header needs exactly one parameter, got {}",
+                        parameters.len()
+                    )));
+                }
+            },
+            TemplateComponent::Body { parameters } => {
+                bodies += 1;
+                if parameters.is_empty() {
+                    return Err(WhatsAppError::InvalidTemplate(
+                        "body component without parameters".to_string(),
+                    ));
+                }
+            },
+            TemplateComponent::Button {
+                index, parameters, ..
+            } => {
+                if *index >= MAX_TEMPLATE_BUTTONS {
+                    return Err(WhatsAppError::InvalidTemplate(format!(
+                        "button index {index} out of range (max {})",
+                        MAX_TEMPLATE_BUTTONS - 1
+                    )));
+                }
+                if button_indexes.contains(index) {
+                    return Err(WhatsAppError::InvalidTemplate(format!(
+                        "duplicate button index {index}"
+                    )));
+                }
+                if parameters.len() != 1 {
+                    return Err(WhatsAppError::InvalidTemplate(format!(
+                        "button {index} needs exactly one parameter, got {}",
+                        parameters.len()
+                    )));
+                }
+                button_indexes.push(*index);
+            },
+        }
+    }
+
+    if headers > 1 || bodies > 1 {
+        return Err(WhatsAppError::InvalidTemplate(
+            "at most one header and one body component allowed".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Template message send request
+#[derive(Debug, Serialize)]
+struct SendTemplateRequest<'a> {
+    messaging_product: &'static str,
+    recipient_type: &'static str,
+    to: String,
+    #[serde(rename = "type")]
+    msg_type: &'static str,
+    template: TemplateContent<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct TemplateContent<'a> {
+    name: &'a str,
+    language: TemplateLanguage<'a>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    components: &'a [TemplateComponent],
+}
+
+#[derive(Debug, Serialize)]
+struct TemplateLanguage<'a> {
+    code: &'a str,
+}
+
+impl<'a> SendTemplateRequest<'a> {
+    fn new(
+        phone: &str,
+        template_name: &'a str,
+        language: &'a str,
+        components: &'a [TemplateComponent],
+    ) -> Self {
+        Self {
+            messaging_product: "whatsapp",
+            recipient_type: "individual",
+            to: phone.to_string(),
+            msg_type: "template",
+            template: TemplateContent {
+                name: template_name,
+                language: TemplateLanguage { code: language },
+                components,
+            },
+        }
+    }
+}
+
 impl WhatsAppClient {
     /// Create a new WhatsApp client
     pub fn new(config: WhatsAppClientConfig) -> Result<Self, WhatsAppError> {
@@ -236,12 +477,19 @@ impl WhatsAppClient {
     }
 
     /// Check if a phone number is whitelisted
+    ///
+    /// Numbers are compared in normalized E.164 form. Entries that are not
+    /// full international numbers match as a suffix.
     pub fn is_whitelisted(&self, phone: &str, whitelist: &[String]) -> bool {
         if whitelist.is_empty() {
             // Empty whitelist means all numbers are allowed
             return true;
         }
-        whitelist.iter().any(|w| phone == w || phone.ends_with(w))
+        let phone = normalize_phone(phone);
+        whitelist.iter().any(|w| {
+            let w = normalize_phone(w);
+            phone == w || phone.ends_with(&w)
+        })
     }
 
     /// Verify webhook signature (wrapper around webhook::verify_signature)
@@ -471,6 +719,94 @@ impl WhatsAppClient {
         }
     }
 
+    /// Send an approved template message
+    ///
+    /// Templates are the only way to message a user outside the 24-hour
+    /// session window. `language` is the template's language code (e.g.
+    /// `en_US`) and `components` fill in its header, body and button
+    /// variables.
+    #[instrument(skip(self, components), fields(to = %to, template = %template_name))]
+    pub async fn send_template(
+        &self,
+        to: &str,
+        template_name: &str,
+        language: &str,
+        components: &[TemplateComponent],
+    ) -> Result<SendMessageResponse, WhatsAppError> {
+        // Validate phone number format
+        if !to.starts_with('+') || to.len() < 10 {
+            return Err(WhatsAppError::InvalidPhoneNumber(to.to_string()));
+        }
+        if template_name.is_empty() || language.is_empty() {
+            return Err(WhatsAppError::InvalidTemplate(
+                "template name and language are required".to_string(),
+            ));
+        }
+        validate_template_components(components)?;
+
+        let phone = to.trim_start_matches('+');
+        let request = SendTemplateRequest::new(phone, template_name, language, components);
+
+        debug!(phone = %phone, components = components.len(), "Sending template message");
+
+        let response = self
+            .client
+            .post(format!("{}/messages", self.base_url))
+            .bearer_auth(&self.config.access_token)
+            .json(&request)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(response.json().await?)
+        } else {
+            let error: ApiErrorResponse = response.json().await?;
+            Err(WhatsAppError::Api {
+                code: error.error.code,
+                message: error.error.message,
+            })
+        }
+    }
+
+    /// React to a message with an emoji
+    ///
+    /// Passing an empty `emoji` removes a previously sent reaction.
+    #[instrument(skip(self), fields(to = %to, message_id = %message_id))]
+    pub async fn send_reaction(
+        &self,
+        to: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> Result<SendMessageResponse, WhatsAppError> {
+        // Validate phone number format
+        if !to.starts_with('+') || to.len() < 10 {
+            return Err(WhatsAppError::InvalidPhoneNumber(to.to_string()));
+        }
+
+        let phone = to.trim_start_matches('+');
+        let request = SendReactionRequest::new(phone, message_id, emoji);
+
+        debug!(phone = %phone, removal = emoji.is_empty(), "Sending reaction");
+
+        let response = self
+            .client
+            .post(format!("{}/messages", self.base_url))
+            .bearer_auth(&self.config.access_token)
+            .json(&request)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(response.json().await?)
+        } else {
+            let error: ApiErrorResponse = response.json().await?;
+            Err(WhatsAppError::Api {
+                code: error.error.code,
+                message: error.error.message,
+            })
+        }
+    }
+
     /// Mark a message as read
     ///
     /// This shows the sender that the message has been read (blue checkmarks).
@@ -510,6 +846,17 @@ impl WhatsAppClient {
     }
 }
 
+/// Normalize a phone number to E.164 for whitelist comparison
+///
+/// Partial numbers (e.g., whitelist suffixes) are only stripped of
+/// separators.
+fn normalize_phone(phone: &str) -> String {
+    PhoneNumber::normalize(phone).map_or_else(
+        |_| phone.trim().replace([' ', '-', '(', ')'], ""),
+        |phone| phone.as_str().to_string(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -589,6 +936,15 @@ mod tests {
             let whitelist = vec!["1234567890".to_string()];
             assert!(client.is_whitelisted("+491234567890", &whitelist));
         }
+
+        #[test]
+        fn whitelist_matches_german_mobile_formats() {
+            let client = WhatsAppClient::new(test_config()).unwrap();
+            let whitelist = vec!["0049 151 23456789".to_string()];
+            assert!(client.is_whitelisted("+4915123456789", &whitelist));
+            assert!(client.is_whitelisted("+49 (0)151-2345-6789", &whitelist));
+            assert!(!client.is_whitelisted("+4915123456780", &whitelist));
+        }
     }
 
     mod signature_tests {
@@ -654,6 +1010,13 @@ mod tests {
             let err = WhatsAppError::MediaUploadFailed("too large".to_string());
             assert!(err.to_string().contains("too large"));
         }
+
+        #[test]
+        fn error_display_invalid_template() {
+            let err = WhatsAppError::InvalidTemplate("duplicate button index 0".to_string());
+            assert!(err.to_string().contains("template"));
+            assert!(err.to_string().contains("duplicate button"));
+        }
     }
 
     mod message_validation_tests {
@@ -684,6 +1047,163 @@ mod tests {
             let result = client.send_audio_message("+123", "media-123").await;
             assert!(matches!(result, Err(WhatsAppError::InvalidPhoneNumber(_))));
         }
+
+        #[tokio::test]
+        async fn send_reaction_validates_phone_format() {
+            let client = WhatsAppClient::new(test_config()).unwrap();
+
+            let result = client.send_reaction("491234567890", "wamid.1", "👍").await;
+            assert!(matches!(result, Err(WhatsAppError::InvalidPhoneNumber(_))));
+        }
+    }
+
+    mod template_tests {
+        use super::*;
+
+        #[test]
+        fn serializes_body_and_url_button_parameters() {
+            let components = vec![
+                TemplateComponent::body(["Alice", "tomorrow 9:00"]),
+                TemplateComponent::url_button(0, "booking/42"),
+            ];
+            let request = SendTemplateRequest::new(
+                "491234567890",
+                "appointment_reminder",
+                "en_US",
+                &components,
+            );
+            let json = serde_json::to_value(&request).unwrap();
+
+            assert_eq!(
+                json,
+                serde_json::json!({
+                    "messaging_product": "whatsapp",
+                    "recipient_type": "individual",
+                    "to": "491234567890",
+                    "type": "template",
+                    "template": {
+                        "name": "appointment_reminder",
+                        "language": {"code": "en_US"},
+                        "components": [
+                            {
+                                "type": "body",
+                                "parameters": [
+                                    {"type": "text", "text": "Alice"},
+                                    {"type": "text", "text": "tomorrow 9:00"}
+                                ]
+                            },
+                            {
+                                "type": "button",
+                                "sub_type": "url",
+                                "index": "0",
+                                "parameters": [{"type": "text", "text": "booking/42"}]
+                            }
+                        ]
+                    }
+                })
+            );
+        }
+
+        #[test]
+        fn serializes_header_and_quick_reply() {
+            let components = vec![
+                TemplateComponent::header("Daily briefing"),
+                TemplateComponent::quick_reply_button(1, "snooze"),
+            ];
+            let json = serde_json::to_value(&components).unwrap();
+
+            assert_eq!(json[0]["type"], "header");
+            assert_eq!(json[0]["parameters"][0]["text"], "Daily briefing");
+            assert_eq!(json[1]["sub_type"], "quick_reply");
+            assert_eq!(json[1]["index"], "1");
+            assert_eq!(json[1]["parameters"][0]["payload"], "snooze");
+        }
+
+        #[test]
+        fn omits_components_when_empty() {
+            let request = SendTemplateRequest::new("491234567890", "hello_world", "en_US", &[]);
+            let json = serde_json::to_value(&request).unwrap();
+            assert!(json["template"].get("components").is_none());
+        }
+
+        #[test]
+        fn validation_accepts_typical_template() {
+            let components = vec![
+                TemplateComponent::header("Hi"),
+                TemplateComponent::body(["a", "b"]),
+                TemplateComponent::url_button(0, "x"),
+                TemplateComponent::quick_reply_button(1, "y"),
+            ];
+            assert!(validate_template_components(&components).is_ok());
+        }
+
+        #[test]
+        fn validation_rejects_bad_component_counts() {
+            let two_bodies = vec![
+                TemplateComponent::body(["a"]),
+                TemplateComponent::body(["b"]),
+            ];
+            let empty_body = vec![TemplateComponent::body(Vec::<String>::new())];
+            let two_header_params = vec![TemplateComponent::Header {
+                parameters: vec![TemplateParameter::text("a"), TemplateParameter::text("b")],
+            }];
+            let duplicate_button = vec![
+                TemplateComponent::url_button(0, "a"),
+                TemplateComponent::url_button(0, "b"),
+            ];
+            let button_out_of_range = vec![TemplateComponent::url_button(10, "a")];
+
+            for components in [
+                two_bodies,
+                empty_body,
+                two_header_params,
+                duplicate_button,
+                button_out_of_range,
+            ] {
+                assert!(matches!(
+                    validate_template_components(&components),
+                    Err(WhatsAppError::InvalidTemplate(_))
+                ));
+            }
+        }
+
+        #[tokio::test]
+        async fn send_template_requires_name_and_language() {
+            let client = WhatsAppClient::new(test_config()).unwrap();
+
+            let result = client
+                .send_template("+491234567890", "", "en_US", &[])
+                .await;
+            assert!(matches!(result, Err(WhatsAppError::InvalidTemplate(_))));
+        }
+    }
+
+    mod reaction_request_tests {
+        use super::*;
+
+        #[test]
+        fn serializes_reaction_message() {
+            let request = SendReactionRequest::new("491234567890", "wamid.ABC", "👍");
+            let json = serde_json::to_value(&request).unwrap();
+
+            assert_eq!(
+                json,
+                serde_json::json!({
+                    "messaging_product": "whatsapp",
+                    "recipient_type": "individual",
+                    "to": "491234567890",
+                    "type": "reaction",
+                    "reaction": {"message_id": "wamid.ABC", "emoji": "👍"}
+                })
+            );
+        }
+
+        #[test]
+        fn empty_emoji_is_sent_to_remove_reaction() {
+            let request = SendReactionRequest::new("491234567890", "wamid.ABC", "");
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(json["reaction"]["emoji"], "");
+        }
     }
 
     mod downloaded_media_tests {
@@ -2,13 +2,17 @@
 //! WhatsApp integration
 //!
 //! Handles WhatsApp Business API webhooks and message sending.
-//! Supports both text and audio (voice) messages.
+//! Supports text, audio (voice) and reaction messages.
 
 pub mod client;
 pub mod webhook;
 
-pub use client::{WhatsAppClient, WhatsAppClientConfig, WhatsAppError};
+pub use client::{
+    ButtonSubType, TemplateComponent, TemplateParameter, WhatsAppClient, WhatsAppClientConfig,
+    WhatsAppError,
+};
 pub use webhook::{
-    AudioMessage, IncomingMessage, WebhookConfig, WebhookPayload, extract_all_messages,
-    extract_audio_messages, extract_messages, verify_signature,
+    AudioMessage, IncomingMessage, ReactionMessage, WebhookConfig, WebhookPayload,
+    extract_all_messages, extract_audio_messages, extract_messages, extract_status_updates,
+    verify_signature,
 };
@@ -1,8 +1,11 @@
 //! WhatsApp webhook handler
 //!
 //! Receives and validates webhook requests from WhatsApp Business API.
-//! Supports both text and audio (voice) messages.
+//! Supports text, audio (voice) and reaction messages.
 
+use application::ports::DeliveryUpdate;
+use chrono::{DateTime, Utc};
+use domain::value_objects::{DeliveryStatus, MessengerSource};
 use hmac::{Hmac, Mac};
 use serde::Deserialize;
 use sha2::Sha256;
@@ -65,6 +68,8 @@ pub struct WebhookMessage {
     pub text: Option<TextMessage>,
     #[serde(default)]
     pub audio: Option<AudioMessage>,
+    #[serde(default)]
+    pub reaction: Option<ReactionMessage>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -84,12 +89,30 @@ pub struct AudioMessage {
     pub voice: bool,
 }
 
+/// Reaction to a previously sent message
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReactionMessage {
+    /// ID of the message that was reacted to
+    pub message_id: String,
+    /// Reaction emoji; absent or empty when the reaction was removed
+    #[serde(default)]
+    pub emoji: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WebhookStatus {
     pub id: String,
     pub status: String,
     pub timestamp: String,
     pub recipient_id: String,
+    #[serde(default)]
+    pub errors: Vec<WebhookStatusError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookStatusError {
+    pub code: i64,
+    pub title: String,
 }
 
 /// Extracted message types from webhook
@@ -109,6 +132,12 @@ pub enum IncomingMessage {
         mime_type: String,
         is_voice: bool,
     },
+    /// Emoji reaction to a message; an empty emoji means it was removed
+    Reaction {
+        from: String,
+        message_id: String,
+        emoji: String,
+    },
 }
 
 impl IncomingMessage {
@@ -116,15 +145,21 @@ impl IncomingMessage {
     #[must_use]
     pub fn from(&self) -> &str {
         match self {
-            Self::Text { from, .. } | Self::Audio { from, .. } => from,
+            Self::Text { from, .. } | Self::Audio { from, .. } | Self::Reaction { from, .. } => {
+                from
+            },
         }
     }
 
     /// Get the message ID
+    ///
+    /// For reactions this is the ID of the message that was reacted to.
     #[must_use]
     pub fn message_id(&self) -> &str {
         match self {
-            Self::Text { message_id, .. } | Self::Audio { message_id, .. } => message_id,
+            Self::Text { message_id, .. }
+            | Self::Audio { message_id, .. }
+            | Self::Reaction { message_id, .. } => message_id,
         }
     }
 
@@ -145,6 +180,12 @@ impl IncomingMessage {
     pub const fn is_voice(&self) -> bool {
         matches!(self, Self::Audio { is_voice: true, .. })
     }
+
+    /// Check if this is a reaction
+    #[must_use]
+    pub const fn is_reaction(&self) -> bool {
+        matches!(self, Self::Reaction { .. })
+    }
 }
 
 /// Verify webhook signature
@@ -200,9 +241,9 @@ pub fn extract_messages(payload: &WebhookPayload) -> Vec<(String, String, String
     messages
 }
 
-/// Extract all messages (text and audio) from a webhook payload
+/// Extract all messages (text, audio and reactions) from a webhook payload
 ///
-/// Returns a list of `IncomingMessage` variants for both text and audio messages.
+/// Returns a list of `IncomingMessage` variants for text, audio and reaction messages.
 pub fn extract_all_messages(payload: &WebhookPayload) -> Vec<IncomingMessage> {
     let mut messages = Vec::new();
 
@@ -231,6 +272,15 @@ pub fn extract_all_messages(payload: &WebhookPayload) -> Vec<IncomingMessage> {
                                 });
                             }
                         },
+                        "reaction" => {
+                            if let Some(reaction) = &message.reaction {
+                                messages.push(IncomingMessage::Reaction {
+                                    from: message.from.clone(),
+                                    message_id: reaction.message_id.clone(),
+                                    emoji: reaction.emoji.clone().unwrap_or_default(),
+                                });
+                            }
+                        },
                         _ => {
                             // Ignore other message types (image, video, etc.)
                         },
@@ -251,6 +301,53 @@ pub fn extract_audio_messages(payload: &WebhookPayload) -> Vec<IncomingMessage>
         .collect()
 }
 
+/// Extract delivery status updates for sent messages from a webhook payload
+///
+/// Unknown status values are skipped. Timestamps are Unix seconds; an
+/// unparsable one falls back to the current time.
+pub fn extract_status_updates(payload: &WebhookPayload) -> Vec<DeliveryUpdate> {
+    let mut updates = Vec::new();
+
+    for entry in &payload.entry {
+        for change in &entry.changes {
+            if change.field != "messages" {
+                continue;
+            }
+            for status in &change.value.statuses {
+                let delivery_status = match status.status.as_str() {
+                    "sent" => DeliveryStatus::Sent,
+                    "delivered" => DeliveryStatus::Delivered,
+                    "read" => DeliveryStatus::Read,
+                    "failed" => DeliveryStatus::Failed,
+                    other => {
+                        warn!(status = %other, "Ignoring unknown WhatsApp message status");
+                        continue;
+                    },
+                };
+                let timestamp = status
+                    .timestamp
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                    .unwrap_or_else(Utc::now);
+
+                let mut update = DeliveryUpdate::new(
+                    status.id.clone(),
+                    MessengerSource::WhatsApp,
+                    delivery_status,
+                    timestamp,
+                );
+                if let Some(error) = status.errors.first() {
+                    update = update.with_error(format!("{} ({})", error.title, error.code));
+                }
+                updates.push(update);
+            }
+        }
+    }
+
+    updates
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +383,7 @@ mod tests {
                 body: body.to_string(),
             }),
             audio: None,
+            reaction: None,
         }
     }
 
@@ -306,6 +404,7 @@ mod tests {
                 mime_type: "audio/ogg; codecs=opus".to_string(),
                 voice: is_voice,
             }),
+            reaction: None,
         }
     }
 
@@ -357,6 +456,7 @@ mod tests {
                 msg_type: "image".to_string(),
                 text: None,
                 audio: None,
+                reaction: None,
             }]);
 
             let messages = extract_messages(&payload);
@@ -399,7 +499,7 @@ mod tests {
                     assert_eq!(media_id, "media-id-456");
                     assert!(is_voice);
                 },
-                IncomingMessage::Text { .. } => unreachable!("Expected Audio message"),
+                _ => unreachable!("Expected Audio message"),
             }
         }
 
@@ -416,7 +516,7 @@ mod tests {
                 IncomingMessage::Audio { is_voice, .. } => {
                     assert!(!is_voice);
                 },
-                IncomingMessage::Text { .. } => unreachable!("Expected Audio message"),
+                _ => unreachable!("Expected Audio message"),
             }
         }
 
@@ -444,7 +544,7 @@ mod tests {
                 IncomingMessage::Audio { mime_type, .. } => {
                     assert_eq!(mime_type, "audio/ogg; codecs=opus");
                 },
-                IncomingMessage::Text { .. } => unreachable!("Expected Audio message"),
+                _ => unreachable!("Expected Audio message"),
             }
         }
     }
@@ -675,6 +775,60 @@ mod tests {
             assert!(messages[0].is_voice());
         }
 
+        #[test]
+        fn webhook_reaction_deserialization() {
+            let json = r#"{
+                "object": "whatsapp_business_account",
+                "entry": [{
+                    "id": "123",
+                    "changes": [{
+                        "field": "messages",
+                        "value": {
+                            "messaging_product": "whatsapp",
+                            "metadata": {
+                                "display_phone_number": "+1234567890",
+                                "phone_number_id": "123"
+                            },
+                            "messages": [
+                                {
+                                    "from": "+491234567890",
+                                    "id": "wamid.reaction1",
+                                    "timestamp": "1234567890",
+                                    "type": "reaction",
+                                    "reaction": {"message_id": "wamid.original", "emoji": "👍"}
+                                },
+                                {
+                                    "from": "+491234567890",
+                                    "id": "wamid.reaction2",
+                                    "timestamp": "1234567891",
+                                    "type": "reaction",
+                                    "reaction": {"message_id": "wamid.original"}
+                                }
+                            ]
+                        }
+                    }]
+                }]
+            }"#;
+
+            let payload: WebhookPayload = serde_json::from_str(json).unwrap();
+            let messages = extract_all_messages(&payload);
+            assert_eq!(messages.len(), 2);
+            assert!(messages.iter().all(IncomingMessage::is_reaction));
+            assert_eq!(messages[0].from(), "+491234567890");
+            assert_eq!(messages[0].message_id(), "wamid.original");
+
+            match (&messages[0], &messages[1]) {
+                (
+                    IncomingMessage::Reaction { emoji: added, .. },
+                    IncomingMessage::Reaction { emoji: removed, .. },
+                ) => {
+                    assert_eq!(added, "👍");
+                    assert!(removed.is_empty());
+                },
+                _ => unreachable!("Expected Reaction messages"),
+            }
+        }
+
         #[test]
         fn webhook_status_deserialization() {
             let json = r#"{
@@ -705,5 +859,59 @@ mod tests {
             assert_eq!(statuses.len(), 1);
             assert_eq!(statuses[0].status, "delivered");
         }
+
+        #[test]
+        fn extract_status_updates_maps_statuses() {
+            let json = r#"{
+                "object": "whatsapp_business_account",
+                "entry": [{
+                    "id": "123",
+                    "changes": [{
+                        "field": "messages",
+                        "value": {
+                            "messaging_product": "whatsapp",
+                            "metadata": {
+                                "display_phone_number": "+1234567890",
+                                "phone_number_id": "123"
+                            },
+                            "statuses": [
+                                {
+                                    "id": "msg1",
+                                    "status": "read",
+                                    "timestamp": "1700000000",
+                                    "recipient_id": "+49123"
+                                },
+                                {
+                                    "id": "msg2",
+                                    "status": "failed",
+                                    "timestamp": "1700000060",
+                                    "recipient_id": "+49123",
+                                    "errors": [{"code": 131026, "title": "Message undeliverable"}]
+                                },
+                                {
+                                    "id": "msg3",
+                                    "status": "deleted",
+                                    "timestamp": "1700000120",
+                                    "recipient_id": "+49123"
+                                }
+                            ]
+                        }
+                    }]
+                }]
+            }"#;
+
+            let payload: WebhookPayload = serde_json::from_str(json).unwrap();
+            let updates = extract_status_updates(&payload);
+
+            assert_eq!(updates.len(), 2);
+            assert_eq!(updates[0].message_id, "msg1");
+            assert_eq!(updates[0].status, DeliveryStatus::Read);
+            assert_eq!(updates[0].timestamp.timestamp(), 1_700_000_000);
+            assert_eq!(updates[1].status, DeliveryStatus::Failed);
+            assert_eq!(
+                updates[1].error.as_deref(),
+                Some("Message undeliverable (131026)")
+            );
+        }
     }
 }
@@ -1,7 +1,8 @@
-//! SQLite database backup functionality
+//! SQLite database backup and restore functionality
 //!
 //! Provides online backup capabilities using SQLite's backup API,
-//! with optional upload to S3-compatible storage.
+//! with optional upload to S3-compatible storage, and restoring a backup
+//! from a local file or S3 with integrity verification.
 
 use std::path::{Path, PathBuf};
 use std::time::Instant;
@@ -42,6 +43,88 @@ pub struct BackupResult {
     pub s3_url: Option<String>,
 }
 
+/// Tables whose row counts are reported after a restore
+const RESTORE_REPORT_TABLES: &[&str] = &[
+    "conversations",
+    "messages",
+    "approval_requests",
+    "audit_log",
+    "memories",
+    "reminders",
+    "user_profiles",
+    "retry_queue",
+];
+
+/// Location of a backup to restore
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestoreSource {
+    /// Local backup file
+    Local(PathBuf),
+    /// Object in an S3-compatible bucket
+    S3 {
+        /// Bucket name
+        bucket: String,
+        /// Object key within the bucket
+        key: String,
+    },
+}
+
+impl RestoreSource {
+    /// Parse a local path or an `s3://bucket/key` URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for S3 URLs without a bucket or key.
+    pub fn parse(input: &str) -> Result<Self> {
+        let Some(location) = input.strip_prefix("s3://") else {
+            return Ok(Self::Local(PathBuf::from(input)));
+        };
+
+        match location.split_once('/') {
+            Some((bucket, key)) if !bucket.is_empty() && !key.is_empty() => Ok(Self::S3 {
+                bucket: bucket.to_string(),
+                key: key.to_string(),
+            }),
+            _ => anyhow::bail!("Invalid S3 URL, expected s3://bucket/key: {input}"),
+        }
+    }
+}
+
+impl std::fmt::Display for RestoreSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local(path) => write!(f, "{}", path.display()),
+            Self::S3 { bucket, key } => write!(f, "s3://{bucket}/{key}"),
+        }
+    }
+}
+
+/// S3 connection settings used when restoring from S3
+///
+/// The bucket is taken from the `s3://` URL.
+#[derive(Debug, Clone)]
+pub struct S3Credentials {
+    /// S3 region (e.g., "us-east-1", "eu-central-1")
+    pub region: String,
+    /// Custom S3 endpoint (for MinIO, Backblaze B2, etc.)
+    pub endpoint: Option<String>,
+    /// S3 access key (from env if not provided)
+    pub access_key: Option<String>,
+    /// S3 secret key (from env if not provided)
+    pub secret_key: Option<String>,
+}
+
+/// Result of a restore operation
+#[derive(Debug)]
+pub struct RestoreResult {
+    /// Size of the restored database in bytes
+    pub size_bytes: u64,
+    /// Row counts of the main tables present in the backup
+    pub table_counts: Vec<(String, u64)>,
+    /// Duration of the restore operation
+    pub duration_ms: u64,
+}
+
 /// Perform an online backup of the SQLite database
 ///
 /// Uses SQLite's backup API to create a consistent snapshot while
@@ -174,6 +257,45 @@ async fn upload_to_s3(local_path: &Path, config: &S3Config) -> Result<String> {
         "Uploading backup to S3"
     );
 
+    let bucket = open_bucket(config)?;
+
+    // Generate S3 key
+    let filename = local_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("backup.db");
+    let s3_key = config.prefix.as_ref().map_or_else(
+        || filename.to_string(),
+        |prefix| format!("{prefix}/{filename}"),
+    );
+
+    // Read file
+    let content = tokio::fs::read(local_path)
+        .await
+        .context("Failed to read backup file")?;
+
+    // Upload
+    let response = bucket
+        .put_object(&s3_key, &content)
+        .await
+        .context("S3 upload failed")?;
+
+    if response.status_code() != 200 {
+        anyhow::bail!(
+            "S3 upload returned status {}: {}",
+            response.status_code(),
+            String::from_utf8_lossy(response.as_slice())
+        );
+    }
+
+    let s3_url = format!("s3://{}/{}", config.bucket, s3_key);
+    info!(url = %s3_url, "Backup uploaded to S3");
+
+    Ok(s3_url)
+}
+
+/// Create a bucket handle from the S3 configuration
+fn open_bucket(config: &S3Config) -> Result<Box<Bucket>> {
     // Build credentials
     let credentials = if config.access_key.is_some() && config.secret_key.is_some() {
         Credentials::new(
@@ -207,39 +329,245 @@ async fn upload_to_s3(local_path: &Path, config: &S3Config) -> Result<String> {
         .context("Failed to create S3 bucket handle")?
         .with_path_style(); // Required for MinIO and some S3-compatible services
 
-    // Generate S3 key
-    let filename = local_path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("backup.db");
-    let s3_key = config.prefix.as_ref().map_or_else(
-        || filename.to_string(),
-        |prefix| format!("{prefix}/{filename}"),
+    Ok(bucket)
+}
+
+/// Restore a backup over the target database
+///
+/// The backup is staged next to the target, verified with
+/// `PRAGMA integrity_check` and then atomically renamed over the target.
+/// Leftover WAL files of the old database are removed so they are not
+/// replayed into the restored one.
+///
+/// # Arguments
+///
+/// * `source` - Local backup file or S3 object
+/// * `target` - Database file to replace
+/// * `credentials` - S3 settings (required for S3 sources)
+/// * `force` - Replace an existing target, even if it appears to be in use
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The backup cannot be read or downloaded
+/// - The backup is not a valid SQLite database or fails the integrity check
+/// - The target already exists and `force` is not set
+/// - The target cannot be replaced
+pub async fn restore_database(
+    source: &RestoreSource,
+    target: &Path,
+    credentials: Option<&S3Credentials>,
+    force: bool,
+) -> Result<RestoreResult> {
+    let start = Instant::now();
+
+    if !force && target.exists() {
+        if database_in_use(target).await? {
+            anyhow::bail!(
+                "Database {} is in use (is the server running?). Stop the server or pass --force",
+                target.display()
+            );
+        }
+        anyhow::bail!(
+            "Database {} already exists. Pass --force to overwrite it",
+            target.display()
+        );
+    }
+
+    let staging = staging_path(target);
+    info!(
+        source = %source,
+        target = %target.display(),
+        "Starting database restore"
     );
 
-    // Read file
-    let content = tokio::fs::read(local_path)
-        .await
-        .context("Failed to read backup file")?;
+    let result = async {
+        stage_backup(source, &staging, credentials).await?;
+        let table_counts = verify_backup(&staging).await?;
+        replace_database(&staging, target).await?;
+        Ok::<_, anyhow::Error>(table_counts)
+    }
+    .await;
+
+    let table_counts = match result {
+        Ok(counts) => counts,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&staging).await;
+            return Err(e);
+        },
+    };
 
-    // Upload
-    let response = bucket
-        .put_object(&s3_key, &content)
+    let size_bytes = tokio::fs::metadata(target)
         .await
-        .context("S3 upload failed")?;
+        .context("Failed to get restored database metadata")?
+        .len();
 
-    if response.status_code() != 200 {
-        anyhow::bail!(
-            "S3 upload returned status {}: {}",
-            response.status_code(),
-            String::from_utf8_lossy(response.as_slice())
-        );
+    #[allow(clippy::cast_possible_truncation)]
+    let duration_ms = start.elapsed().as_millis() as u64;
+
+    info!(size_bytes = size_bytes, "Restore completed");
+
+    Ok(RestoreResult {
+        size_bytes,
+        table_counts,
+        duration_ms,
+    })
+}
+
+/// Staging file for a restore, next to the target so the final rename is atomic
+fn staging_path(target: &Path) -> PathBuf {
+    let name = target
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("pisovereign.db");
+    target.with_file_name(format!(".{name}.restore"))
+}
+
+/// SQLite sidecar file (`-wal`, `-shm`) of a database
+fn sidecar_path(database: &Path, suffix: &str) -> PathBuf {
+    let mut path = database.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Check whether another process holds the database open
+///
+/// A connected server keeps the `-shm` file of a WAL database around and
+/// may hold locks; both are treated as "in use". Targets that cannot be
+/// inspected at all (e.g. corrupted files) are not.
+async fn database_in_use(database: &Path) -> Result<bool> {
+    if !database.exists() {
+        return Ok(false);
+    }
+    if sidecar_path(database, "-shm").exists() {
+        return Ok(true);
     }
 
-    let s3_url = format!("s3://{}/{}", config.bucket, s3_key);
-    info!(url = %s3_url, "Backup uploaded to S3");
+    let database = database.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let conn =
+            rusqlite::Connection::open(&database).context("Failed to open target database")?;
+        conn.busy_timeout(std::time::Duration::ZERO)?;
+        match conn.execute_batch("BEGIN EXCLUSIVE; ROLLBACK;") {
+            Ok(()) => Ok(false),
+            Err(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(
+                    e.code,
+                    rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+                ) =>
+            {
+                Ok(true)
+            },
+            // An unreadable target (e.g. corrupted) is not in use by anyone
+            Err(e) => {
+                warn!(error = %e, "Could not check target database lock");
+                Ok(false)
+            },
+        }
+    })
+    .await
+    .context("Lock check task panicked")?
+}
 
-    Ok(s3_url)
+/// Copy or download the backup to the staging path
+async fn stage_backup(
+    source: &RestoreSource,
+    staging: &Path,
+    credentials: Option<&S3Credentials>,
+) -> Result<()> {
+    match source {
+        RestoreSource::Local(path) => {
+            tokio::fs::copy(path, staging)
+                .await
+                .with_context(|| format!("Failed to read backup {}", path.display()))?;
+        },
+        RestoreSource::S3 { bucket, key } => {
+            let credentials = credentials.context("S3 settings required for s3:// sources")?;
+            let bucket = open_bucket(&S3Config {
+                bucket: bucket.clone(),
+                region: credentials.region.clone(),
+                endpoint: credentials.endpoint.clone(),
+                access_key: credentials.access_key.clone(),
+                secret_key: credentials.secret_key.clone(),
+                prefix: None,
+            })?;
+
+            info!(source = %source, "Downloading backup from S3");
+            let response = bucket.get_object(key).await.context("S3 download failed")?;
+            if response.status_code() != 200 {
+                anyhow::bail!(
+                    "S3 download returned status {}: {}",
+                    response.status_code(),
+                    String::from_utf8_lossy(response.as_slice())
+                );
+            }
+
+            tokio::fs::write(staging, response.as_slice())
+                .await
+                .context("Failed to write downloaded backup")?;
+        },
+    }
+
+    Ok(())
+}
+
+/// Verify the staged backup and collect row counts of the main tables
+async fn verify_backup(path: &Path) -> Result<Vec<(String, u64)>> {
+    let path = path.to_path_buf();
+
+    tokio::task::spawn_blocking(move || {
+        use rusqlite::{Connection, OpenFlags};
+
+        let conn = Connection::open_with_flags(&path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .context("Failed to open backup")?;
+
+        let status: String = conn
+            .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+            .context("Backup is not a valid SQLite database")?;
+        if status != "ok" {
+            anyhow::bail!("Backup failed integrity check: {status}");
+        }
+
+        let mut counts = Vec::new();
+        for table in RESTORE_REPORT_TABLES {
+            let exists: bool = conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+                [table],
+                |row| row.get(0),
+            )?;
+            if exists {
+                let count: i64 =
+                    conn.query_row(&format!("SELECT COUNT(*) FROM \"{table}\""), [], |row| {
+                        row.get(0)
+                    })?;
+                counts.push(((*table).to_string(), u64::try_from(count).unwrap_or(0)));
+            }
+        }
+
+        debug!(tables = counts.len(), "Backup verified");
+        Ok(counts)
+    })
+    .await
+    .context("Verification task panicked")?
+}
+
+/// Atomically move the staged backup over the target
+async fn replace_database(staging: &Path, target: &Path) -> Result<()> {
+    // Stale WAL content of the old database must not be applied to the new one
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = sidecar_path(target, suffix);
+        match tokio::fs::remove_file(&sidecar).await {
+            Ok(()) => debug!(path = %sidecar.display(), "Removed stale SQLite sidecar file"),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {},
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to remove {}", sidecar.display()));
+            },
+        }
+    }
+
+    tokio::fs::rename(staging, target)
+        .await
+        .with_context(|| format!("Failed to replace {}", target.display()))
 }
 
 /// Delete old local backups, keeping the most recent N
@@ -347,6 +675,170 @@ mod tests {
         );
     }
 
+    fn create_backup_with_conversations(path: &Path, rows: usize) {
+        let conn = rusqlite::Connection::open(path).unwrap();
+        conn.execute("CREATE TABLE conversations (id TEXT PRIMARY KEY)", [])
+            .unwrap();
+        for i in 0..rows {
+            conn.execute(
+                "INSERT INTO conversations (id) VALUES (?1)",
+                [i.to_string()],
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn restore_source_parses_local_and_s3() {
+        assert_eq!(
+            RestoreSource::parse("backups/pisovereign.db").unwrap(),
+            RestoreSource::Local(PathBuf::from("backups/pisovereign.db"))
+        );
+        assert_eq!(
+            RestoreSource::parse("s3://my-bucket/daily/backup.db").unwrap(),
+            RestoreSource::S3 {
+                bucket: "my-bucket".to_string(),
+                key: "daily/backup.db".to_string(),
+            }
+        );
+        assert!(RestoreSource::parse("s3://my-bucket").is_err());
+        assert!(RestoreSource::parse("s3:///key").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_restore_replaces_target_and_counts_rows() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_path = temp_dir.path().join("backup.db");
+        let target = temp_dir.path().join("pisovereign.db");
+        create_backup_with_conversations(&backup_path, 3);
+        tokio::fs::write(&target, b"old contents").await.unwrap();
+
+        let result = restore_database(&RestoreSource::Local(backup_path), &target, None, true)
+            .await
+            .unwrap();
+
+        assert!(result.size_bytes > 0);
+        assert_eq!(result.table_counts, vec![("conversations".to_string(), 3)]);
+        assert!(!staging_path(&target).exists());
+
+        let conn = rusqlite::Connection::open(&target).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM conversations", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_invalid_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_path = temp_dir.path().join("backup.db");
+        let target = temp_dir.path().join("pisovereign.db");
+        tokio::fs::write(&backup_path, b"definitely not sqlite")
+            .await
+            .unwrap();
+        create_backup_with_conversations(&target, 1);
+
+        let result =
+            restore_database(&RestoreSource::Local(backup_path), &target, None, true).await;
+
+        assert!(result.is_err());
+        assert!(!staging_path(&target).exists());
+        // Target is untouched
+        let conn = rusqlite::Connection::open(&target).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM conversations", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_restore_into_missing_target_needs_no_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_path = temp_dir.path().join("backup.db");
+        let target = temp_dir.path().join("pisovereign.db");
+        create_backup_with_conversations(&backup_path, 1);
+
+        let result = restore_database(&RestoreSource::Local(backup_path), &target, None, false)
+            .await
+            .unwrap();
+
+        assert_eq!(result.table_counts, vec![("conversations".to_string(), 1)]);
+        assert!(target.exists());
+    }
+
+    #[tokio::test]
+    async fn test_restore_refuses_existing_database_unless_forced() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_path = temp_dir.path().join("backup.db");
+        let target = temp_dir.path().join("pisovereign.db");
+        create_backup_with_conversations(&backup_path, 2);
+        create_backup_with_conversations(&target, 1);
+
+        let refused = restore_database(
+            &RestoreSource::Local(backup_path.clone()),
+            &target,
+            None,
+            false,
+        )
+        .await;
+        let message = refused.unwrap_err().to_string();
+        assert!(message.contains("already exists"));
+        assert!(message.contains("--force"));
+        assert!(!staging_path(&target).exists());
+
+        let conn = rusqlite::Connection::open(&target).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM conversations", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+        drop(conn);
+
+        let forced = restore_database(&RestoreSource::Local(backup_path), &target, None, true)
+            .await
+            .unwrap();
+        assert_eq!(forced.table_counts, vec![("conversations".to_string(), 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_restore_refuses_locked_database_unless_forced() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_path = temp_dir.path().join("backup.db");
+        let target = temp_dir.path().join("pisovereign.db");
+        create_backup_with_conversations(&backup_path, 2);
+        create_backup_with_conversations(&target, 1);
+
+        // Simulate a running server holding a write lock
+        let holder = rusqlite::Connection::open(&target).unwrap();
+        holder.execute_batch("BEGIN EXCLUSIVE;").unwrap();
+
+        let refused = restore_database(
+            &RestoreSource::Local(backup_path.clone()),
+            &target,
+            None,
+            false,
+        )
+        .await;
+        assert!(refused.unwrap_err().to_string().contains("--force"));
+
+        let forced = restore_database(&RestoreSource::Local(backup_path), &target, None, true)
+            .await
+            .unwrap();
+        assert_eq!(forced.table_counts, vec![("conversations".to_string(), 2)]);
+        drop(holder);
+    }
+
+    #[tokio::test]
+    async fn test_restore_requires_credentials_for_s3() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("pisovereign.db");
+        let source = RestoreSource::parse("s3://bucket/backup.db").unwrap();
+
+        let result = restore_database(&source, &target, None, false).await;
+
+        assert!(result.is_err());
+        assert!(!target.exists());
+    }
+
     #[tokio::test]
     async fn test_cleanup_keeps_recent_backups() {
         let temp_dir = TempDir::new().unwrap();
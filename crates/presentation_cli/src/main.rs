@@ -6,14 +6,19 @@
 #![allow(clippy::print_stdout)]
 
 mod backup;
+mod migrate_db;
 mod migrate_keys;
+mod ping;
+mod queue;
+mod repl;
+mod validate_config;
 
 use std::fs;
 use std::path::PathBuf;
 
 use clap::{Parser, Subcommand, ValueEnum};
-use infrastructure::ApiKeyHasher;
-use presentation_http::ApiDoc;
+use infrastructure::{ApiKeyHasher, AppConfig, WarningSeverity};
+use presentation_http::{ApiDoc, ServeOptions};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use utoipa::OpenApi;
 
@@ -30,6 +35,23 @@ struct Cli {
     command: Commands,
 }
 
+/// Retry queue actions
+#[derive(Subcommand)]
+enum QueueAction {
+    /// List dead-lettered items, most recently failed first
+    List {
+        /// Maximum number of items to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Move a dead-lettered item back to the retry queue
+    Replay {
+        /// Dead letter ID (as shown by `queue list`)
+        id: String,
+    },
+}
+
 /// Output format for the OpenAPI specification
 #[derive(Debug, Clone, Copy, ValueEnum, Default)]
 enum OpenApiFormat {
@@ -42,6 +64,21 @@ enum OpenApiFormat {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Run the HTTP server
+    ///
+    /// Starts the same server as the `pisovereign-server` binary.
+    ///
+    /// Example: pisovereign-cli serve --config /etc/pisovereign/config.toml --port 8080
+    Serve {
+        /// Configuration file (default: config.toml in the working directory)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Port to listen on (overrides server.port)
+        #[arg(short, long)]
+        port: Option<u16>,
+    },
+
     /// Check system status
     Status {
         /// Server URL
@@ -59,6 +96,17 @@ enum Commands {
         url: String,
     },
 
+    /// Start an interactive chat session
+    ///
+    /// Streams replies and keeps the conversation across turns and restarts.
+    /// Meta-commands: /reset (new conversation), /models, /model NAME, /quit.
+    /// Ctrl-C exits.
+    Repl {
+        /// Server URL
+        #[arg(short, long, default_value = "http://localhost:3000")]
+        url: String,
+    },
+
     /// Execute a command
     Command {
         /// Command input
@@ -151,6 +199,48 @@ enum Commands {
         keep_local: usize,
     },
 
+    /// Restore the database from a backup
+    ///
+    /// Verifies the backup with an integrity check and atomically replaces
+    /// the target database. An existing database is only overwritten with
+    /// --force.
+    ///
+    /// Example: pisovereign-cli restore --input s3://my-bucket/backups/pisovereign_backup.db
+    Restore {
+        /// Backup to restore: local file or s3://bucket/key URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Database file to restore into (default: pisovereign.db)
+        #[arg(
+            short,
+            long,
+            visible_alias = "database",
+            default_value = "pisovereign.db"
+        )]
+        output: PathBuf,
+
+        /// Overwrite an existing database, even if it appears to be in use
+        #[arg(long)]
+        force: bool,
+
+        /// S3 region (e.g., "us-east-1", "eu-central-1")
+        #[arg(long, default_value = "us-east-1")]
+        s3_region: String,
+
+        /// Custom S3 endpoint URL (for MinIO, Backblaze B2, etc.)
+        #[arg(long)]
+        s3_endpoint: Option<String>,
+
+        /// S3 access key (uses AWS_ACCESS_KEY_ID env var if not provided)
+        #[arg(long, env = "AWS_ACCESS_KEY_ID")]
+        s3_access_key: Option<String>,
+
+        /// S3 secret key (uses AWS_SECRET_ACCESS_KEY env var if not provided)
+        #[arg(long, env = "AWS_SECRET_ACCESS_KEY")]
+        s3_secret_key: Option<String>,
+    },
+
     /// Check system health (used by Docker healthcheck)
     Health {
         /// Server URL
@@ -158,27 +248,101 @@ enum Commands {
         url: String,
     },
 
+    /// Apply pending database schema migrations
+    ///
+    /// With --dry-run, lists the migrations that would be applied without
+    /// touching the database. Useful to validate upgrades on a staging system.
+    ///
+    /// Example: pisovereign-cli migrate --database pisovereign.db --dry-run
+    Migrate {
+        /// Path to the database (default: pisovereign.db)
+        #[arg(short, long, default_value = "pisovereign.db")]
+        database: PathBuf,
+
+        /// List pending migrations without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Inspect the retry queue and replay dead-lettered items
+    ///
+    /// Operations that exhausted their retries end up in the dead letter
+    /// queue. Replaying moves them back to the retry queue with a fresh
+    /// retry budget.
+    ///
+    /// Example: pisovereign-cli queue list
+    /// Example: pisovereign-cli queue replay 4f6c2a0e-...
+    Queue {
+        /// Path to the database (default: pisovereign.db)
+        #[arg(short, long, default_value = "pisovereign.db", global = true)]
+        database: PathBuf,
+
+        #[command(subcommand)]
+        action: QueueAction,
+    },
+
     /// Migrate plaintext API keys to secure Argon2 hashes
     ///
-    /// Reads a configuration file, finds all plaintext API keys in legacy formats
-    /// (api_key, api_key_users), and converts them to the new [[security.api_keys]]
-    /// format with Argon2id hashes.
+    /// Reads a configuration file, finds all plaintext API keys (`hash` values
+    /// in [[security.api_keys]] that are not Argon2 hashes, and the legacy
+    /// api_key / api_key_users formats), and hashes them with Argon2id.
+    /// Comments are preserved unless legacy formats need to be converted.
+    ///
+    /// Nothing is written unless --write is given.
     ///
-    /// Example: pisovereign-cli migrate-keys --input config.toml --dry-run
-    /// Example: pisovereign-cli migrate-keys --input config.toml --output config.toml
+    /// Example: pisovereign-cli migrate-keys --input config.toml
+    /// Example: pisovereign-cli migrate-keys --input config.toml --write
     MigrateKeys {
         /// Path to input configuration file
         #[arg(short, long)]
         input: PathBuf,
 
-        /// Path to output configuration file (will overwrite if same as input)
+        /// Path to output configuration file (default: overwrite the input)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
-        /// Show what would be changed without writing output
+        /// Write the migrated configuration (otherwise only preview changes)
         #[arg(long)]
+        write: bool,
+
+        /// Preview changes without writing (the default; kept for compatibility)
+        #[arg(long, hide = true, conflicts_with = "write")]
         dry_run: bool,
     },
+
+    /// Check that the configured integrations are reachable
+    ///
+    /// Builds the weather, CalDAV, Proton, web search and transit clients
+    /// from the configuration and probes each of them. Exits non-zero if any
+    /// service is unreachable.
+    ///
+    /// Example: pisovereign-cli ping
+    /// Example: pisovereign-cli ping --service caldav
+    Ping {
+        /// Only probe this service (weather, caldav, proton, websearch, transit)
+        #[arg(short, long)]
+        service: Option<String>,
+
+        /// Configuration file (default: config.toml in the working directory)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+
+    /// Validate a configuration file
+    ///
+    /// Runs the security checks performed at server startup plus integration
+    /// checks, verifies that referenced paths (database directory,
+    /// templates_dir, Signal socket) exist, and prints the findings grouped by
+    /// severity. Exits non-zero if the server would refuse to start, so it can
+    /// be used in CI.
+    ///
+    /// Example: pisovereign-cli validate-config --config /etc/pisovereign/config.toml
+    #[command(alias = "config-check")]
+    ValidateConfig {
+        /// Configuration file (default: config.toml in the working directory)
+        #[arg(short, long, alias = "file")]
+        config: Option<PathBuf>,
+    },
 }
 
 /// Determine log filter level from verbosity count
@@ -201,6 +365,18 @@ fn endpoint_url(base_url: &str, path: &str) -> String {
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
+    // The server sets up its own logging from the configuration
+    if let Commands::Serve { config, port } = cli.command {
+        let mut options = ServeOptions::default();
+        if let Some(config) = config {
+            options = options.with_config_path(config);
+        }
+        if let Some(port) = port {
+            options = options.with_port(port);
+        }
+        return presentation_http::bootstrap(options).await;
+    }
+
     // Set up logging based on verbosity
     let filter = log_filter_from_verbosity(cli.verbose);
 
@@ -212,6 +388,9 @@ async fn main() -> anyhow::Result<()> {
     let client = reqwest::Client::new();
 
     match cli.command {
+        // Handled above, before logging is initialized
+        Commands::Serve { .. } => {},
+
         Commands::Status { url } => {
             let resp = client
                 .get(endpoint_url(&url, "/ready"))
@@ -244,6 +423,11 @@ async fn main() -> anyhow::Result<()> {
             }
         },
 
+        Commands::Repl { url } => {
+            let mut repl = repl::Repl::new(client, url, repl::default_session_file())?;
+            repl.run().await?;
+        },
+
         Commands::Command { input, url } => {
             println!("⚡ Executing: {input}");
 
@@ -400,6 +584,54 @@ async fn main() -> anyhow::Result<()> {
             }
         },
 
+        Commands::Restore {
+            input,
+            output,
+            force,
+            s3_region,
+            s3_endpoint,
+            s3_access_key,
+            s3_secret_key,
+        } => {
+            let source = match backup::RestoreSource::parse(&input) {
+                Ok(source) => source,
+                Err(e) => {
+                    println!("❌ {e}");
+                    std::process::exit(1);
+                },
+            };
+            let credentials = backup::S3Credentials {
+                region: s3_region,
+                endpoint: s3_endpoint,
+                access_key: s3_access_key,
+                secret_key: s3_secret_key,
+            };
+
+            println!("🗄️  Starting database restore...");
+            println!("   Source: {source}");
+            println!("   Target: {}", output.display());
+
+            match backup::restore_database(&source, &output, Some(&credentials), force).await {
+                Ok(result) => {
+                    println!("✅ Restore completed successfully!");
+                    #[allow(clippy::cast_precision_loss)]
+                    let size_mb = result.size_bytes as f64 / 1_048_576.0;
+                    println!("   📊 Size: {size_mb:.2} MB");
+                    println!("   ⏱️  Duration: {}ms", result.duration_ms);
+                    if !result.table_counts.is_empty() {
+                        println!("   📋 Rows:");
+                        for (table, count) in &result.table_counts {
+                            println!("      {table:<20} {count}");
+                        }
+                    }
+                },
+                Err(e) => {
+                    println!("❌ Restore failed: {e:#}");
+                    std::process::exit(1);
+                },
+            }
+        },
+
         Commands::Health { url } => match client.get(endpoint_url(&url, "/ready")).send().await {
             Ok(resp) if resp.status().is_success() => {
                 println!("✅ Healthy");
@@ -415,10 +647,86 @@ async fn main() -> anyhow::Result<()> {
             },
         },
 
+        Commands::Migrate { database, dry_run } => {
+            println!("🗄️  Database: {}", database.display());
+
+            match migrate_db::migrate_database(&database, dry_run).await {
+                Ok(report) => {
+                    println!("   📌 Schema version: {}", report.previous_version);
+
+                    if report.pending.is_empty() {
+                        println!("✅ Schema is up to date");
+                    } else {
+                        let verb = if dry_run { "Pending" } else { "Applied" };
+                        println!("   📋 {verb} migrations ({}):", report.pending.len());
+                        for migration in &report.pending {
+                            println!("      {:>4}  {}", migration.version, migration.description);
+                        }
+
+                        if dry_run {
+                            println!();
+                            println!("Run without --dry-run to apply migrations.");
+                        } else {
+                            println!("✅ Migrated schema to version {}", report.current_version);
+                        }
+                    }
+                },
+                Err(e) => {
+                    println!("❌ Migration failed: {e}");
+                    std::process::exit(1);
+                },
+            }
+        },
+
+        Commands::Queue { database, action } => match action {
+            QueueAction::List { limit } => match queue::list_dead_letters(&database, limit).await {
+                Ok(report) => {
+                    println!("📬 Retry Queue:");
+                    println!("   ⏳ Pending: {}", report.stats.pending);
+                    println!("   🔄 In progress: {}", report.stats.in_progress);
+                    println!("   💀 Dead letters: {}", report.stats.dead_letter);
+
+                    if report.items.is_empty() {
+                        println!();
+                        println!("✅ Dead letter queue is empty");
+                    } else {
+                        println!();
+                        for item in &report.items {
+                            println!(
+                                "{}  {}  {} -> {} ({} attempts)",
+                                item.id,
+                                item.failed_at.format("%Y-%m-%d %H:%M:%S"),
+                                item.operation_type,
+                                item.target,
+                                item.attempt_count
+                            );
+                            if let Some(error) = &item.last_error {
+                                println!("      ❌ {error}");
+                            }
+                        }
+                    }
+                },
+                Err(e) => {
+                    println!("❌ Failed to read retry queue: {e:#}");
+                    std::process::exit(1);
+                },
+            },
+            QueueAction::Replay { id } => match queue::replay_dead_letter(&database, &id).await {
+                Ok(requeued) => {
+                    println!("✅ Requeued dead letter {id} as retry item {requeued}");
+                },
+                Err(e) => {
+                    println!("❌ {e:#}");
+                    std::process::exit(1);
+                },
+            },
+        },
+
         Commands::MigrateKeys {
             input,
             output,
-            dry_run,
+            write,
+            dry_run: _,
         } => {
             println!("🔄 Migrating API keys from: {}", input.display());
 
@@ -427,13 +735,21 @@ async fn main() -> anyhow::Result<()> {
                 std::process::exit(1);
             }
 
-            match migrate_keys::migrate_config(&input, dry_run) {
+            match migrate_keys::migrate_config(&input) {
                 Ok(result) => {
                     println!();
                     println!("📊 Migration Summary:");
-                    println!("   ✅ Migrated: {}", result.migrated);
-                    println!("   ⏭️  Already hashed: {}", result.already_hashed);
-                    println!("   ❌ Failed: {}", result.failed);
+                    println!("                 Before   After");
+                    println!(
+                        "   🔓 Plaintext  {:>6}  {:>6}",
+                        result.plaintext_before(),
+                        result.plaintext_after()
+                    );
+                    println!(
+                        "   🔒 Hashed     {:>6}  {:>6}",
+                        result.hashed_before(),
+                        result.hashed_after()
+                    );
 
                     if result.failed > 0 {
                         println!();
@@ -441,7 +757,21 @@ async fn main() -> anyhow::Result<()> {
                         std::process::exit(1);
                     }
 
-                    if !dry_run {
+                    if result.migrated == 0 {
+                        println!();
+                        println!("✅ No plaintext keys found, nothing to do");
+                        return Ok(());
+                    }
+
+                    if !result.comments_preserved {
+                        println!();
+                        println!(
+                            "⚠️  Legacy key settings were converted; comments and formatting \
+                             will not be preserved."
+                        );
+                    }
+
+                    if write {
                         let output_path = output.as_ref().unwrap_or(&input);
                         match fs::write(output_path, &result.output) {
                             Ok(()) => {
@@ -453,14 +783,16 @@ async fn main() -> anyhow::Result<()> {
                                 std::process::exit(1);
                             },
                         }
-                    } else if result.migrated > 0 {
+                    } else {
                         println!();
                         println!("📋 Preview of migrated configuration:");
                         println!("────────────────────────────────────────");
                         println!("{}", result.output);
                         println!("────────────────────────────────────────");
                         println!();
-                        println!("Run without --dry-run to apply changes.");
+                        println!(
+                            "Dry run - no changes written. Run with --write to apply changes."
+                        );
                     }
                 },
                 Err(e) => {
@@ -469,6 +801,103 @@ async fn main() -> anyhow::Result<()> {
                 },
             }
         },
+
+        Commands::Ping { service, config } => {
+            let app_config = match AppConfig::load_from(config.as_deref()) {
+                Ok(app_config) => app_config,
+                Err(e) => {
+                    println!("❌ Failed to load configuration: {e}");
+                    std::process::exit(1);
+                },
+            };
+
+            let probes =
+                match ping::select(ping::configured_probes(&app_config), service.as_deref()) {
+                    Ok(probes) => probes,
+                    Err(e) => {
+                        println!("❌ {e}");
+                        std::process::exit(1);
+                    },
+                };
+            if probes.is_empty() {
+                println!("ℹ️  No integrations configured");
+                return Ok(());
+            }
+
+            println!("📡 Pinging {} integration(s)...", probes.len());
+            println!();
+            let results = ping::ping(probes).await;
+            print!("{}", ping::render(&results));
+
+            let failed = results.iter().filter(|r| !r.is_ok()).count();
+            println!();
+            if failed > 0 {
+                println!(
+                    "❌ {failed} of {} integration(s) unreachable",
+                    results.len()
+                );
+                std::process::exit(1);
+            }
+            println!("✅ All integrations reachable");
+        },
+
+        Commands::ValidateConfig { config } => {
+            let source = config
+                .as_ref()
+                .map_or_else(|| "config.toml".to_string(), |p| p.display().to_string());
+            println!("🔍 Validating configuration: {source}");
+
+            let app_config = match AppConfig::load_from(config.as_deref()) {
+                Ok(app_config) => app_config,
+                Err(e) => {
+                    println!("❌ Failed to load configuration: {e}");
+                    std::process::exit(1);
+                },
+            };
+
+            if let Err(e) = app_config.validate() {
+                println!();
+                println!("🛑 Invalid values ({}):", e.problems.len());
+                for problem in &e.problems {
+                    println!("   {}: {}", problem.field, problem.message);
+                }
+                println!();
+                println!("❌ Configuration is invalid");
+                std::process::exit(1);
+            }
+
+            let report = validate_config::validate_config(&app_config);
+            for (severity, icon) in [
+                (WarningSeverity::Critical, "🛑"),
+                (WarningSeverity::Warning, "⚠️ "),
+                (WarningSeverity::Info, "ℹ️ "),
+            ] {
+                let warnings: Vec<_> = report.with_severity(severity).collect();
+                if warnings.is_empty() {
+                    continue;
+                }
+
+                println!();
+                println!("{icon} {severity} ({}):", warnings.len());
+                for warning in warnings {
+                    println!("   {}: {}", warning.code, warning.message);
+                    println!("      → {}", warning.recommendation);
+                }
+            }
+
+            println!();
+            if report.blocks_startup {
+                println!("❌ Critical issues would block server startup");
+                std::process::exit(1);
+            } else if report.warnings.is_empty() {
+                println!("✅ Configuration is valid");
+            } else {
+                println!(
+                    "✅ Configuration is usable ({} finding(s))",
+                    report.warnings.len()
+                );
+            }
+        },
     }
 
     Ok(())
@@ -530,4 +959,132 @@ mod tests {
             "https://secure.example.com/v1/system/models"
         );
     }
+
+    #[test]
+    fn serve_parses_overrides() {
+        let cli = Cli::try_parse_from([
+            "pisovereign-cli",
+            "serve",
+            "--config",
+            "/etc/pisovereign/config.toml",
+            "--port",
+            "8080",
+        ])
+        .unwrap();
+
+        match cli.command {
+            Commands::Serve { config, port } => {
+                assert_eq!(config, Some(PathBuf::from("/etc/pisovereign/config.toml")));
+                assert_eq!(port, Some(8080));
+            },
+            _ => panic!("expected serve command"),
+        }
+    }
+
+    #[test]
+    fn queue_replay_parses_id_and_database() {
+        let cli = Cli::try_parse_from([
+            "pisovereign-cli",
+            "queue",
+            "replay",
+            "abc-123",
+            "--database",
+            "app.db",
+        ])
+        .unwrap();
+
+        match cli.command {
+            Commands::Queue {
+                database,
+                action: QueueAction::Replay { id },
+            } => {
+                assert_eq!(database, PathBuf::from("app.db"));
+                assert_eq!(id, "abc-123");
+            },
+            _ => panic!("expected queue replay command"),
+        }
+    }
+
+    #[test]
+    fn migrate_keys_is_dry_run_by_default() {
+        let cli =
+            Cli::try_parse_from(["pisovereign-cli", "migrate-keys", "-i", "config.toml"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::MigrateKeys { write: false, .. }
+        ));
+
+        let cli = Cli::try_parse_from([
+            "pisovereign-cli",
+            "migrate-keys",
+            "-i",
+            "config.toml",
+            "--write",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::MigrateKeys { write: true, .. }
+        ));
+
+        assert!(
+            Cli::try_parse_from([
+                "pisovereign-cli",
+                "migrate-keys",
+                "-i",
+                "config.toml",
+                "--write",
+                "--dry-run",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn validate_config_parses_optional_path() {
+        let cli = Cli::try_parse_from(["pisovereign-cli", "validate-config"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::ValidateConfig { config: None }
+        ));
+
+        let cli =
+            Cli::try_parse_from(["pisovereign-cli", "validate-config", "-c", "prod.toml"]).unwrap();
+        match cli.command {
+            Commands::ValidateConfig { config } => {
+                assert_eq!(config, Some(PathBuf::from("prod.toml")));
+            },
+            _ => panic!("expected validate-config command"),
+        }
+    }
+
+    #[test]
+    fn config_check_is_an_alias_for_validate_config() {
+        let cli = Cli::try_parse_from(["pisovereign-cli", "config-check", "--file", "prod.toml"])
+            .unwrap();
+        match cli.command {
+            Commands::ValidateConfig { config } => {
+                assert_eq!(config, Some(PathBuf::from("prod.toml")));
+            },
+            _ => panic!("expected validate-config command"),
+        }
+    }
+
+    #[test]
+    fn ping_parses_optional_service() {
+        let cli = Cli::try_parse_from(["pisovereign-cli", "ping"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Ping {
+                service: None,
+                config: None
+            }
+        ));
+
+        let cli = Cli::try_parse_from(["pisovereign-cli", "ping", "--service", "caldav"]).unwrap();
+        match cli.command {
+            Commands::Ping { service, .. } => assert_eq!(service.as_deref(), Some("caldav")),
+            _ => panic!("expected ping command"),
+        }
+    }
 }
@@ -0,0 +1,105 @@
+//! Database schema migration command
+//!
+//! Applies the bundled SQL migrations to a database file, or with
+//! `--dry-run` only reports which migrations would be applied.
+
+use std::path::Path;
+
+use infrastructure::{
+    AsyncDatabase, AsyncDatabaseConfig, MigrationInfo, persistence::AsyncDatabaseError,
+};
+
+/// Outcome of a migration run
+#[derive(Debug)]
+pub struct MigrationReport {
+    /// Schema version before the run
+    pub previous_version: i64,
+    /// Schema version after the run (unchanged for dry runs)
+    pub current_version: i64,
+    /// Migrations that were (or, for dry runs, would be) applied
+    pub pending: Vec<MigrationInfo>,
+}
+
+/// Migrate the database at `database`, or only report pending migrations
+///
+/// A dry run never modifies the database. If the file does not exist yet,
+/// a dry run reports every bundled migration without creating it.
+pub async fn migrate_database(
+    database: &Path,
+    dry_run: bool,
+) -> Result<MigrationReport, AsyncDatabaseError> {
+    if dry_run && !database.exists() {
+        return Ok(MigrationReport {
+            previous_version: 0,
+            current_version: 0,
+            pending: AsyncDatabase::available_migrations(),
+        });
+    }
+
+    let db = AsyncDatabase::new(&AsyncDatabaseConfig::file(database)).await?;
+    let previous_version = db.current_schema_version().await?;
+    let pending = db.pending_migrations().await?;
+
+    if !dry_run && !pending.is_empty() {
+        db.migrate().await?;
+    }
+
+    let current_version = db.current_schema_version().await?;
+    db.close().await;
+
+    Ok(MigrationReport {
+        previous_version,
+        current_version,
+        pending,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn dry_run_on_missing_database_does_not_create_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.db");
+
+        let report = migrate_database(&path, true).await.unwrap();
+
+        assert!(!path.exists());
+        assert_eq!(report.previous_version, 0);
+        assert_eq!(report.pending, AsyncDatabase::available_migrations());
+    }
+
+    #[tokio::test]
+    async fn dry_run_leaves_schema_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fresh.db");
+        AsyncDatabase::new(&AsyncDatabaseConfig::file(&path))
+            .await
+            .unwrap()
+            .close()
+            .await;
+
+        let report = migrate_database(&path, true).await.unwrap();
+        assert!(!report.pending.is_empty());
+        assert_eq!(report.current_version, 0);
+
+        let again = migrate_database(&path, true).await.unwrap();
+        assert_eq!(again.pending, report.pending);
+    }
+
+    #[tokio::test]
+    async fn migrate_applies_pending_migrations() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.db");
+
+        let report = migrate_database(&path, false).await.unwrap();
+        let latest = report.pending.last().map(|m| m.version).unwrap();
+        assert_eq!(report.previous_version, 0);
+        assert_eq!(report.current_version, latest);
+
+        let second = migrate_database(&path, false).await.unwrap();
+        assert!(second.pending.is_empty());
+        assert_eq!(second.current_version, latest);
+    }
+}
@@ -1,24 +1,30 @@
 //! API key migration utilities
 //!
 //! Converts plaintext API keys in configuration files to secure Argon2id hashes.
-//! This module supports batch migration with dry-run capability.
+//! Plaintext values in `[[security.api_keys]]` entries are replaced in place so
+//! comments and formatting survive; legacy formats (`api_key`,
+//! `api_key_users`) require restructuring and are rewritten as a whole.
 //!
 //! # Usage
 //!
 //! ```bash
-//! # Dry-run to see what would change
-//! pisovereign-cli migrate-keys --input config.toml --dry-run
+//! # Preview what would change (default)
+//! pisovereign-cli migrate-keys --input config.toml
 //!
 //! # Actually migrate the keys
-//! pisovereign-cli migrate-keys --input config.toml --output config.toml
+//! pisovereign-cli migrate-keys --input config.toml --write
 //! ```
 
 use std::fs;
+use std::ops::Range;
 use std::path::Path;
 
 use infrastructure::{ApiKeyEntry, ApiKeyHasher};
 use thiserror::Error;
 
+/// Table header of the API key entries
+const API_KEYS_HEADER: &str = "[[security.api_keys]]";
+
 /// Errors that can occur during key migration
 #[derive(Debug, Error)]
 pub enum MigrationError {
@@ -44,25 +50,208 @@ pub struct MigrationResult {
     pub migrated: usize,
     /// Number of keys that failed to migrate
     pub failed: usize,
+    /// Whether comments and formatting of the input were preserved
+    pub comments_preserved: bool,
     /// The migrated configuration content
     pub output: String,
 }
 
+impl MigrationResult {
+    /// Number of plaintext keys before the migration
+    pub const fn plaintext_before(&self) -> usize {
+        self.migrated + self.failed
+    }
+
+    /// Number of plaintext keys after the migration
+    pub const fn plaintext_after(&self) -> usize {
+        self.failed
+    }
+
+    /// Number of hashed keys before the migration
+    pub const fn hashed_before(&self) -> usize {
+        self.already_hashed
+    }
+
+    /// Number of hashed keys after the migration
+    pub const fn hashed_after(&self) -> usize {
+        self.already_hashed + self.migrated
+    }
+}
+
 /// Migrate API keys from plaintext to hashed format
 ///
+/// Only computes the migrated content; writing it is left to the caller.
+///
 /// # Arguments
 ///
 /// * `input_path` - Path to the input configuration file
-/// * `dry_run` - If true, don't write output, just show what would change
 ///
 /// # Returns
 ///
 /// Migration result with statistics and the migrated content
-pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResult, MigrationError> {
+pub fn migrate_config(input_path: &Path) -> Result<MigrationResult, MigrationError> {
     let content = fs::read_to_string(input_path)?;
-    let mut config: toml::Table = toml::from_str(&content)?;
+    migrate_content(&content)
+}
 
+/// Migrate API keys in configuration content
+fn migrate_content(content: &str) -> Result<MigrationResult, MigrationError> {
+    let config: toml::Table = toml::from_str(content)?;
     let hasher = ApiKeyHasher::new();
+
+    let in_place = if has_legacy_keys(&config) {
+        None
+    } else {
+        migrate_in_place(content, &hasher)
+    };
+
+    match in_place {
+        Some(result) => Ok(result),
+        None => rewrite_config(config, &hasher),
+    }
+}
+
+/// Check for legacy `api_key` / `api_key_users` settings
+fn has_legacy_keys(config: &toml::Table) -> bool {
+    config
+        .get("security")
+        .and_then(toml::Value::as_table)
+        .is_some_and(|security| {
+            security.contains_key("api_key") || security.contains_key("api_key_users")
+        })
+}
+
+/// Replace plaintext `hash` values in `[[security.api_keys]]` entries in place
+///
+/// Returns `None` if the entries are not laid out as one `hash = "..."` line
+/// per table (e.g. inline tables), in which case the caller rewrites the
+/// whole file instead.
+fn migrate_in_place(content: &str, hasher: &ApiKeyHasher) -> Option<MigrationResult> {
+    let mut output = String::with_capacity(content.len());
+    let mut notes = Vec::new();
+    let mut in_api_keys = false;
+    let mut index = 0;
+    let mut already_hashed = 0;
+    let mut migrated = 0;
+    let mut failed = 0;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            in_api_keys = trimmed.starts_with(API_KEYS_HEADER);
+            if in_api_keys {
+                index += 1;
+            }
+        } else if in_api_keys {
+            if let Some(value) = hash_value(line) {
+                if ApiKeyHasher::is_hashed(&value) {
+                    already_hashed += 1;
+                    notes.push(format!("  ⏭️  Already hashed: api_keys[{}]", index - 1));
+                } else if !value.is_empty() {
+                    let span = quoted_value_span(line)?;
+                    match hasher.hash(&value) {
+                        Ok(hash) => {
+                            output.push_str(&line[..span.start]);
+                            output.push('"');
+                            output.push_str(&hash);
+                            output.push('"');
+                            output.push_str(&line[span.end..]);
+                            migrated += 1;
+                            notes.push(format!(
+                                "  ✅ Hashed plaintext key: api_keys[{}]",
+                                index - 1
+                            ));
+                            continue;
+                        },
+                        Err(e) => {
+                            failed += 1;
+                            notes.push(format!("  ❌ Failed to hash api_keys[{}]: {e}", index - 1));
+                        },
+                    }
+                }
+            }
+        }
+        output.push_str(line);
+    }
+
+    // Make sure every entry was seen; otherwise fall back to a full rewrite
+    let parsed: toml::Table = toml::from_str(&output).ok()?;
+    let entries = parsed
+        .get("security")
+        .and_then(|security| security.get("api_keys"))
+        .and_then(toml::Value::as_array)
+        .map_or(0, Vec::len);
+    if entries != already_hashed + migrated + failed + entries_without_hash(&parsed) {
+        return None;
+    }
+
+    for note in notes {
+        println!("{note}");
+    }
+
+    Some(MigrationResult {
+        already_hashed,
+        migrated,
+        failed,
+        comments_preserved: true,
+        output,
+    })
+}
+
+/// Count `api_keys` entries without a `hash` value
+fn entries_without_hash(config: &toml::Table) -> usize {
+    config
+        .get("security")
+        .and_then(|security| security.get("api_keys"))
+        .and_then(toml::Value::as_array)
+        .map_or(0, |entries| {
+            entries
+                .iter()
+                .filter(|entry| {
+                    entry
+                        .get("hash")
+                        .and_then(toml::Value::as_str)
+                        .is_none_or(str::is_empty)
+                })
+                .count()
+        })
+}
+
+/// Parse the value of a `hash = "..."` line
+fn hash_value(line: &str) -> Option<String> {
+    let (key, _) = line.split_once('=')?;
+    if key.trim() != "hash" {
+        return None;
+    }
+
+    let table: toml::Table = toml::from_str(line.trim()).ok()?;
+    table.get("hash")?.as_str().map(ToString::to_string)
+}
+
+/// Byte range of the quoted value (including quotes) in a `key = "..."` line
+fn quoted_value_span(line: &str) -> Option<Range<usize>> {
+    let after_eq = line.find('=')? + 1;
+    let start = after_eq + (line[after_eq..].len() - line[after_eq..].trim_start().len());
+    let rest = &line[start..];
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+
+    let mut escaped = false;
+    for (i, c) in rest.char_indices().skip(1) {
+        if c == quote && !escaped {
+            return Some(start..start + i + 1);
+        }
+        escaped = quote == '"' && c == '\\' && !escaped;
+    }
+    None
+}
+
+/// Rebuild the configuration with all keys in `[[security.api_keys]]` format
+///
+/// Comments and formatting are lost.
+fn rewrite_config(
+    mut config: toml::Table,
+    hasher: &ApiKeyHasher,
+) -> Result<MigrationResult, MigrationError> {
     let mut already_hashed = 0;
     let mut migrated = 0;
     let mut failed = 0;
@@ -82,6 +271,7 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
                                 new_api_keys.push(ApiKeyEntry {
                                     hash,
                                     user_id: default_user_id.to_string(),
+                                    scopes: Vec::new(),
                                 });
                                 migrated += 1;
                                 println!(
@@ -107,6 +297,7 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
                                 new_api_keys.push(ApiKeyEntry {
                                     hash: key.clone(),
                                     user_id: user_id_str.to_string(),
+                                    scopes: Vec::new(),
                                 });
                                 already_hashed += 1;
                                 println!("  ⏭️  Already hashed: {}", &key[..20.min(key.len())]);
@@ -117,6 +308,7 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
                                         new_api_keys.push(ApiKeyEntry {
                                             hash,
                                             user_id: user_id_str.to_string(),
+                                            scopes: Vec::new(),
                                         });
                                         migrated += 1;
                                         println!(
@@ -149,12 +341,22 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
                                 .get("user_id")
                                 .and_then(|v| v.as_str())
                                 .unwrap_or_default();
+                            let scopes: Vec<String> = entry_table
+                                .get("scopes")
+                                .and_then(|v| v.as_array())
+                                .map(|a| {
+                                    a.iter()
+                                        .filter_map(|s| s.as_str().map(str::to_string))
+                                        .collect()
+                                })
+                                .unwrap_or_default();
 
                             if ApiKeyHasher::is_hashed(hash) {
                                 // Already properly hashed
                                 new_api_keys.push(ApiKeyEntry {
                                     hash: hash.to_string(),
                                     user_id: user_id.to_string(),
+                                    scopes: scopes.clone(),
                                 });
                                 already_hashed += 1;
                                 println!("  ⏭️  Already hashed: {}", &hash[..20.min(hash.len())]);
@@ -165,6 +367,7 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
                                         new_api_keys.push(ApiKeyEntry {
                                             hash: new_hash,
                                             user_id: user_id.to_string(),
+                                            scopes,
                                         });
                                         migrated += 1;
                                         println!("  ✅ Migrated plaintext hash → proper hash");
@@ -193,6 +396,19 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
                             "user_id".to_string(),
                             toml::Value::String(entry.user_id.clone()),
                         );
+                        if !entry.scopes.is_empty() {
+                            table.insert(
+                                "scopes".to_string(),
+                                toml::Value::Array(
+                                    entry
+                                        .scopes
+                                        .iter()
+                                        .cloned()
+                                        .map(toml::Value::String)
+                                        .collect(),
+                                ),
+                            );
+                        }
                         toml::Value::Table(table)
                     })
                     .collect();
@@ -203,14 +419,11 @@ pub fn migrate_config(input_path: &Path, dry_run: bool) -> Result<MigrationResul
 
     let output = toml::to_string_pretty(&config)?;
 
-    if dry_run {
-        println!("\n📋 Dry run - no changes written");
-    }
-
     Ok(MigrationResult {
         already_hashed,
         migrated,
         failed,
+        comments_preserved: false,
         output,
     })
 }
@@ -235,7 +448,7 @@ api_key = "sk-secret-key-12345"
 rate_limit_enabled = true
 "#;
         let file = create_temp_config(config);
-        let result = migrate_config(file.path(), true).unwrap();
+        let result = migrate_config(file.path()).unwrap();
 
         assert_eq!(result.migrated, 1);
         assert_eq!(result.already_hashed, 0);
@@ -256,7 +469,7 @@ rate_limit_enabled = true
 "sk-user2" = "550e8400-e29b-41d4-a716-446655440002"
 "#;
         let file = create_temp_config(config);
-        let result = migrate_config(file.path(), true).unwrap();
+        let result = migrate_config(file.path()).unwrap();
 
         assert_eq!(result.migrated, 2);
         assert_eq!(result.already_hashed, 0);
@@ -282,13 +495,42 @@ user_id = "550e8400-e29b-41d4-a716-446655440001"
 "#
         );
         let file = create_temp_config(&config);
-        let result = migrate_config(file.path(), true).unwrap();
+        let result = migrate_config(file.path()).unwrap();
 
         assert_eq!(result.migrated, 0);
         assert_eq!(result.already_hashed, 1);
         assert_eq!(result.failed, 0);
     }
 
+    #[test]
+    fn legacy_rewrite_keeps_scopes() {
+        let hash = ApiKeyHasher::new().hash("sk-dashboard").unwrap();
+        let config = format!(
+            r#"
+[security]
+api_key = "sk-legacy"
+
+[[security.api_keys]]
+hash = "{hash}"
+user_id = "550e8400-e29b-41d4-a716-446655440001"
+scopes = ["chat:read", "contacts:read"]
+"#
+        );
+        let file = create_temp_config(&config);
+        let result = migrate_config(file.path()).unwrap();
+
+        assert_eq!(result.migrated, 1);
+        assert_eq!(result.already_hashed, 1);
+        let parsed: toml::Table = toml::from_str(&result.output).unwrap();
+        let keys = parsed["security"]["api_keys"].as_array().unwrap();
+        let scoped = keys.iter().find(|k| k.get("scopes").is_some()).unwrap();
+        assert_eq!(
+            scoped["scopes"].as_array().unwrap().len(),
+            2,
+            "scopes must survive the rewrite"
+        );
+    }
+
     #[test]
     fn preserve_other_config_sections() {
         let config = r#"
@@ -305,7 +547,7 @@ rate_limit_rpm = 60
 base_url = "http://localhost:11434"
 "#;
         let file = create_temp_config(config);
-        let result = migrate_config(file.path(), true).unwrap();
+        let result = migrate_config(file.path()).unwrap();
 
         assert!(result.output.contains("[server]"));
         assert!(result.output.contains("host = \"0.0.0.0\""));
@@ -320,10 +562,111 @@ base_url = "http://localhost:11434"
 rate_limit_enabled = true
 ";
         let file = create_temp_config(config);
-        let result = migrate_config(file.path(), true).unwrap();
+        let result = migrate_config(file.path()).unwrap();
 
         assert_eq!(result.migrated, 0);
         assert_eq!(result.already_hashed, 0);
         assert_eq!(result.failed, 0);
     }
+
+    #[test]
+    fn hash_plaintext_api_keys_in_place_preserving_comments() {
+        let hash = ApiKeyHasher::new().hash("sk-hashed").unwrap();
+        let config = format!(
+            r#"# PiSovereign configuration
+[security]
+# Keys for the mobile app
+rate_limit_enabled = true
+
+[[security.api_keys]]
+hash = "sk-plaintext" # rotated 2025-01
+user_id = "550e8400-e29b-41d4-a716-446655440001"
+
+[[security.api_keys]]
+hash = '{hash}'
+user_id = "550e8400-e29b-41d4-a716-446655440002"
+
+[server]
+port = 3000 # default
+"#
+        );
+        let file = create_temp_config(&config);
+        let result = migrate_config(file.path()).unwrap();
+
+        assert_eq!(result.migrated, 1);
+        assert_eq!(result.already_hashed, 1);
+        assert!(result.comments_preserved);
+        assert!(result.output.starts_with("# PiSovereign configuration\n"));
+        assert!(result.output.contains("# Keys for the mobile app"));
+        assert!(result.output.contains("\" # rotated 2025-01\n"));
+        assert!(result.output.contains("port = 3000 # default"));
+        assert!(result.output.contains(&format!("hash = '{hash}'")));
+        assert!(!result.output.contains("sk-plaintext"));
+
+        let parsed: toml::Table = toml::from_str(&result.output).unwrap();
+        let keys = parsed["security"]["api_keys"].as_array().unwrap();
+        let migrated_hash = keys[0]["hash"].as_str().unwrap();
+        assert!(
+            ApiKeyHasher::new()
+                .verify("sk-plaintext", migrated_hash)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn inline_api_key_tables_fall_back_to_rewrite() {
+        let config = r#"
+[security]
+api_keys = [{ hash = "sk-inline", user_id = "550e8400-e29b-41d4-a716-446655440001" }]
+"#;
+        let file = create_temp_config(config);
+        let result = migrate_config(file.path()).unwrap();
+
+        assert_eq!(result.migrated, 1);
+        assert!(!result.comments_preserved);
+        assert!(!result.output.contains("sk-inline"));
+    }
+
+    #[test]
+    fn legacy_keys_are_rewritten_without_comments() {
+        let config = r#"
+# comment
+[security]
+api_key = "sk-legacy"
+"#;
+        let file = create_temp_config(config);
+        let result = migrate_config(file.path()).unwrap();
+
+        assert!(!result.comments_preserved);
+        assert!(!result.output.contains("# comment"));
+    }
+
+    #[test]
+    fn before_after_counts() {
+        let result = MigrationResult {
+            already_hashed: 1,
+            migrated: 2,
+            failed: 1,
+            comments_preserved: true,
+            output: String::new(),
+        };
+
+        assert_eq!(result.plaintext_before(), 3);
+        assert_eq!(result.plaintext_after(), 1);
+        assert_eq!(result.hashed_before(), 1);
+        assert_eq!(result.hashed_after(), 3);
+    }
+
+    #[test]
+    fn quoted_value_span_handles_quotes_and_comments() {
+        let line = r#"hash = "a\"b" # note"#;
+        let span = quoted_value_span(line).unwrap();
+        assert_eq!(&line[span], r#""a\"b""#);
+
+        let line = "  hash='literal'\n";
+        let span = quoted_value_span(line).unwrap();
+        assert_eq!(&line[span], "'literal'");
+
+        assert!(quoted_value_span("hash = 42").is_none());
+    }
 }
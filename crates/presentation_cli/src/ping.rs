@@ -0,0 +1,314 @@
+//! Integration connectivity checks
+//!
+//! Builds the external service clients from the configuration and probes
+//! each of them, so reachability can be confirmed before going live.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use application::ports::{CalendarPort, EmailPort, TransitPort, WeatherPort, WebSearchPort};
+use infrastructure::{
+    AppConfig,
+    adapters::{
+        CalDavCalendarAdapter, ProtonEmailAdapter, TransitAdapter, WeatherAdapter, WebSearchAdapter,
+    },
+};
+
+/// Services that can be probed, in display order
+pub const SERVICES: [&str; 5] = ["weather", "caldav", "proton", "websearch", "transit"];
+
+/// Upper bound for a single probe
+const PROBE_TIMEOUT: Duration = Duration::from_secs(15);
+
+type ProbeFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// A pending reachability check for one service
+pub struct Probe {
+    service: &'static str,
+    check: ProbeFuture,
+}
+
+impl Probe {
+    /// Create a probe from a check that resolves to `Err` with a reason on failure
+    pub fn new(
+        service: &'static str,
+        check: impl Future<Output = Result<(), String>> + Send + 'static,
+    ) -> Self {
+        Self {
+            service,
+            check: Box::pin(check),
+        }
+    }
+
+    /// Create a probe from an availability check
+    fn available(
+        service: &'static str,
+        check: impl Future<Output = bool> + Send + 'static,
+    ) -> Self {
+        Self::new(service, async move {
+            if check.await {
+                Ok(())
+            } else {
+                Err("not reachable".to_string())
+            }
+        })
+    }
+
+    /// Create a probe for a client that could not be constructed
+    fn failed(service: &'static str, error: impl Display) -> Self {
+        let error = error.to_string();
+        Self::new(service, async move { Err(error) })
+    }
+
+    /// Service name
+    pub const fn service(&self) -> &'static str {
+        self.service
+    }
+}
+
+/// Outcome of a single probe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PingResult {
+    /// Service name
+    pub service: &'static str,
+    /// Time until the probe completed or timed out
+    pub latency: Duration,
+    /// Failure reason, `None` if the service is reachable
+    pub error: Option<String>,
+}
+
+impl PingResult {
+    /// Whether the service is reachable
+    pub const fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Build probes for all integrations enabled in the configuration
+pub fn configured_probes(config: &AppConfig) -> Vec<Probe> {
+    let mut probes = Vec::new();
+
+    if config.weather.is_some() {
+        probes.push(match WeatherAdapter::new() {
+            Ok(adapter) => Probe::available("weather", async move { adapter.is_available().await }),
+            Err(e) => Probe::failed("weather", e),
+        });
+    }
+
+    if let Some(caldav) = &config.caldav {
+        probes.push(
+            match CalDavCalendarAdapter::new(caldav.to_caldav_config()) {
+                Ok(adapter) => {
+                    Probe::available("caldav", async move { adapter.is_available().await })
+                },
+                Err(e) => Probe::failed("caldav", e),
+            },
+        );
+    }
+
+    if let Some(proton) = &config.proton {
+        let adapter = ProtonEmailAdapter::new(proton.to_proton_config());
+        probes.push(Probe::available("proton", async move {
+            adapter.is_available().await
+        }));
+    }
+
+    if let Some(websearch) = &config.websearch {
+        probes.push(
+            match WebSearchAdapter::new(websearch.to_websearch_config()) {
+                Ok(adapter) => {
+                    Probe::available("websearch", async move { adapter.is_available().await })
+                },
+                Err(e) => Probe::failed("websearch", e),
+            },
+        );
+    }
+
+    if let Some(transit) = &config.transit {
+        let clients = (
+            integration_transit::HafasTransitClient::new(&transit.to_transit_config()),
+            integration_transit::NominatimGeocodingClient::new(
+                &integration_transit::NominatimConfig::default(),
+            ),
+        );
+        probes.push(match clients {
+            (Ok(transit_client), Ok(geocoding_client)) => {
+                let adapter = TransitAdapter::new(transit_client, geocoding_client);
+                Probe::available("transit", async move { adapter.is_available().await })
+            },
+            (Err(e), _) => Probe::failed("transit", e),
+            (_, Err(e)) => Probe::failed("transit", e),
+        });
+    }
+
+    probes
+}
+
+/// Keep only the probe for `service`, if given
+///
+/// # Errors
+///
+/// Returns an error if the service is unknown or not configured.
+pub fn select(probes: Vec<Probe>, service: Option<&str>) -> anyhow::Result<Vec<Probe>> {
+    let Some(service) = service else {
+        return Ok(probes);
+    };
+
+    let service = service.trim().to_lowercase();
+    if !SERVICES.contains(&service.as_str()) {
+        anyhow::bail!(
+            "Unknown service '{service}', expected one of: {}",
+            SERVICES.join(", ")
+        );
+    }
+
+    let selected: Vec<_> = probes
+        .into_iter()
+        .filter(|probe| probe.service == service)
+        .collect();
+    if selected.is_empty() {
+        anyhow::bail!("Service '{service}' is not configured");
+    }
+    Ok(selected)
+}
+
+/// Run all probes concurrently
+pub async fn ping(probes: Vec<Probe>) -> Vec<PingResult> {
+    let handles: Vec<_> = probes
+        .into_iter()
+        .map(|probe| {
+            let service = probe.service;
+            let handle = tokio::spawn(async move {
+                let start = Instant::now();
+                let outcome = tokio::time::timeout(PROBE_TIMEOUT, probe.check).await;
+                let error = match outcome {
+                    Ok(Ok(())) => None,
+                    Ok(Err(e)) => Some(e),
+                    Err(_) => Some(format!("timed out after {}s", PROBE_TIMEOUT.as_secs())),
+                };
+                PingResult {
+                    service,
+                    latency: start.elapsed(),
+                    error,
+                }
+            });
+            (service, handle)
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for (service, handle) in handles {
+        results.push(handle.await.unwrap_or_else(|e| PingResult {
+            service,
+            latency: Duration::ZERO,
+            error: Some(format!("probe panicked: {e}")),
+        }));
+    }
+    results
+}
+
+/// Render the results as a table
+pub fn render(results: &[PingResult]) -> String {
+    let mut out = format!(
+        "   {:<11} {:<6} {:>9}  {}\n",
+        "Service", "Status", "Latency", "Details"
+    );
+    for result in results {
+        let (status, details) = match &result.error {
+            None => ("✅", ""),
+            Some(error) => ("❌", error.as_str()),
+        };
+        out.push_str(
+            format!(
+                "   {:<11} {:<5} {:>7}ms  {details}",
+                result.service,
+                status,
+                result.latency.as_millis()
+            )
+            .trim_end(),
+        );
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slow_ok(service: &'static str) -> Probe {
+        Probe::new(service, async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(())
+        })
+    }
+
+    #[tokio::test]
+    async fn all_healthy() {
+        let probes = vec![slow_ok("weather"), slow_ok("caldav"), slow_ok("transit")];
+
+        let results = ping(probes).await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(PingResult::is_ok));
+        assert!(
+            results
+                .iter()
+                .all(|r| r.latency >= Duration::from_millis(20))
+        );
+
+        let table = render(&results);
+        assert_eq!(table.matches('✅').count(), 3);
+        assert!(!table.contains('❌'));
+    }
+
+    #[tokio::test]
+    async fn partial_failure() {
+        let probes = vec![
+            slow_ok("weather"),
+            Probe::available("caldav", async { false }),
+            Probe::failed("proton", "invalid bridge address"),
+        ];
+
+        let results = ping(probes).await;
+
+        let services: Vec<_> = results.iter().map(|r| r.service).collect();
+        assert_eq!(services, ["weather", "caldav", "proton"]);
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].error.as_deref(), Some("not reachable"));
+        assert_eq!(results[2].error.as_deref(), Some("invalid bridge address"));
+
+        let table = render(&results);
+        assert_eq!(table.matches('✅').count(), 1);
+        assert_eq!(table.matches('❌').count(), 2);
+        assert!(
+            table
+                .lines()
+                .any(|l| l.contains("proton") && l.ends_with("invalid bridge address"))
+        );
+    }
+
+    #[test]
+    fn select_single_service() {
+        let probes = vec![slow_ok("weather"), slow_ok("caldav")];
+        let selected = select(probes, Some("CalDAV")).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].service(), "caldav");
+    }
+
+    #[test]
+    fn select_rejects_unknown_and_unconfigured() {
+        let err = select(vec![slow_ok("weather")], Some("fax")).unwrap_err();
+        assert!(err.to_string().contains("Unknown service"));
+
+        let err = select(vec![slow_ok("weather")], Some("transit")).unwrap_err();
+        assert!(err.to_string().contains("not configured"));
+    }
+
+    #[test]
+    fn configured_probes_empty_without_integrations() {
+        assert!(configured_probes(&AppConfig::default()).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Retry queue administration
+//!
+//! Lists dead-lettered operations and moves them back to the retry queue
+//! so they are picked up again by the retry worker.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use infrastructure::{
+    AsyncDatabase, AsyncDatabaseConfig,
+    persistence::{DeadLetterItem, QueueStats, RetryQueueStore},
+};
+
+/// Dead letter listing with overall queue statistics
+#[derive(Debug)]
+pub struct DeadLetterReport {
+    /// Current queue statistics
+    pub stats: QueueStats,
+    /// Most recently failed items
+    pub items: Vec<DeadLetterItem>,
+}
+
+/// Open the retry queue of an existing, fully migrated database
+async fn open_store(database: &Path) -> Result<(AsyncDatabase, RetryQueueStore)> {
+    if !database.exists() {
+        bail!("Database not found: {}", database.display());
+    }
+
+    let db = AsyncDatabase::new(&AsyncDatabaseConfig::file(database))
+        .await
+        .with_context(|| format!("Failed to open database {}", database.display()))?;
+
+    let pending = db.pending_migrations().await?;
+    if !pending.is_empty() {
+        db.close().await;
+        bail!(
+            "Database schema is outdated ({} pending migrations), run `pisovereign-cli migrate` first",
+            pending.len()
+        );
+    }
+
+    let store = RetryQueueStore::new(db.pool().clone());
+    Ok((db, store))
+}
+
+/// List up to `limit` dead-lettered items, most recently failed first
+pub async fn list_dead_letters(database: &Path, limit: usize) -> Result<DeadLetterReport> {
+    let (db, store) = open_store(database).await?;
+
+    let result = async {
+        let stats = store.get_stats().await?;
+        let items = store.list_dead_letters(limit).await?;
+        Ok(DeadLetterReport { stats, items })
+    }
+    .await;
+
+    db.close().await;
+    result
+}
+
+/// Move a dead-lettered item back to the retry queue
+///
+/// Returns the ID of the requeued retry item.
+pub async fn replay_dead_letter(database: &Path, id: &str) -> Result<String> {
+    let (db, store) = open_store(database).await?;
+    let result = store
+        .reprocess_dead_letter(id)
+        .await
+        .with_context(|| format!("Failed to replay dead letter {id}"));
+    db.close().await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::persistence::RetryItem;
+
+    use super::*;
+
+    async fn dead_lettered_database(path: &Path) -> String {
+        let db = AsyncDatabase::new(&AsyncDatabaseConfig::file(path))
+            .await
+            .unwrap();
+        db.migrate().await.unwrap();
+
+        let store = RetryQueueStore::new(db.pool().clone());
+        let item = RetryItem::new("webhook", "{}", "https://example.com").with_max_retries(1);
+        let id = store.enqueue(item).await.unwrap();
+        let _ = store.fetch_due_items(10).await.unwrap();
+        store.mark_failed(&id, "Connection refused").await.unwrap();
+        db.close().await;
+        id
+    }
+
+    #[tokio::test]
+    async fn list_and_replay_dead_letter() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queue.db");
+        let original_id = dead_lettered_database(&path).await;
+
+        let report = list_dead_letters(&path, 10).await.unwrap();
+        assert_eq!(report.stats.dead_letter, 1);
+        assert_eq!(report.items.len(), 1);
+        assert_eq!(
+            report.items[0].last_error.as_deref(),
+            Some("Connection refused")
+        );
+
+        let requeued = replay_dead_letter(&path, &report.items[0].id)
+            .await
+            .unwrap();
+        assert_eq!(requeued, original_id);
+
+        let after = list_dead_letters(&path, 10).await.unwrap();
+        assert_eq!(after.stats.dead_letter, 0);
+        assert_eq!(after.stats.pending, 1);
+    }
+
+    #[tokio::test]
+    async fn replay_unknown_id_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queue.db");
+        dead_lettered_database(&path).await;
+
+        assert!(replay_dead_letter(&path, "missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn missing_database_is_not_created() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.db");
+
+        assert!(list_dead_letters(&path, 10).await.is_err());
+        assert!(!path.exists());
+    }
+}
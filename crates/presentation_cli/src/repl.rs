@@ -0,0 +1,506 @@
+//! Interactive chat REPL
+//!
+//! Streams replies from `POST /v1/chat/stream` and keeps a conversation ID
+//! across turns. The ID is stored in a file in the temp directory so a new
+//! REPL session continues the previous thread until `/reset` is used.
+//! `/model <name>` answers the following turns with another model.
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, bail};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::endpoint_url;
+
+/// File name of the persisted conversation ID
+const SESSION_FILE_NAME: &str = "pisovereign-repl-conversation";
+
+/// SSE payload marking the end of a successful stream
+const DONE_MARKER: &str = "[DONE]";
+
+/// Default location of the persisted conversation ID
+pub fn default_session_file() -> PathBuf {
+    std::env::temp_dir().join(SESSION_FILE_NAME)
+}
+
+/// A line entered at the prompt
+#[derive(Debug, PartialEq, Eq)]
+enum ReplInput {
+    /// Message to send to the assistant
+    Message(String),
+    /// Start a new conversation
+    Reset,
+    /// List available models
+    Models,
+    /// Answer with the given model, or the server's current model if `None`
+    Model(Option<String>),
+    /// Leave the REPL
+    Quit,
+    /// Unrecognized meta-command
+    Unknown(String),
+    /// Blank line
+    Empty,
+}
+
+impl ReplInput {
+    fn parse(line: &str) -> Self {
+        let line = line.trim();
+        match line {
+            "" => Self::Empty,
+            "/reset" => Self::Reset,
+            "/models" => Self::Models,
+            "/quit" | "/exit" => Self::Quit,
+            "/model" => Self::Model(None),
+            _ if line.starts_with("/model ") => {
+                Self::Model(Some(line["/model ".len()..].trim().to_string()))
+            },
+            _ if line.starts_with('/') => Self::Unknown(line.to_string()),
+            _ => Self::Message(line.to_string()),
+        }
+    }
+}
+
+/// Read stdin lines on a dedicated thread
+///
+/// A pending read on Tokio's stdin keeps the runtime from shutting down, so
+/// the REPL could not exit on Ctrl-C until Enter is pressed.
+fn spawn_stdin_reader() -> mpsc::Receiver<std::io::Result<String>> {
+    let (tx, rx) = mpsc::channel(1);
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lines() {
+            if tx.blocking_send(line).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+/// Load the persisted conversation ID, or start a new conversation
+///
+/// Unreadable or invalid session files are replaced.
+fn load_or_create_conversation(path: &Path) -> Result<Uuid> {
+    let existing = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| Uuid::parse_str(content.trim()).ok());
+
+    match existing {
+        Some(id) => Ok(id),
+        None => new_conversation(path),
+    }
+}
+
+/// Start a new conversation and persist its ID
+fn new_conversation(path: &Path) -> Result<Uuid> {
+    let id = Uuid::new_v4();
+    fs::write(path, id.to_string())
+        .with_context(|| format!("Failed to write session file {}", path.display()))?;
+    Ok(id)
+}
+
+/// Incremental parser for `text/event-stream` bodies
+///
+/// Only `data:` fields are of interest; comments (keep-alives) and other
+/// fields are skipped.
+#[derive(Debug, Default)]
+struct SseParser {
+    buffer: Vec<u8>,
+}
+
+impl SseParser {
+    /// Feed raw bytes and return the data of every completed event
+    ///
+    /// Bytes are buffered until an event is complete, so multi-byte
+    /// characters split across network chunks are decoded correctly.
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer
+            .extend(bytes.iter().copied().filter(|&b| b != b'\r'));
+
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+            let data: Vec<&str> = block
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect();
+            if !data.is_empty() {
+                events.push(data.join("\n"));
+            }
+        }
+        events
+    }
+}
+
+/// Statistics for a single streamed reply
+#[derive(Debug, Default)]
+struct TurnStats {
+    /// Number of content chunks received (roughly one token each)
+    chunks: usize,
+    /// Time until the first chunk arrived
+    first_chunk: Option<Duration>,
+    /// Time until the stream completed
+    total: Duration,
+    /// Model reported in the final chunk
+    model: Option<String>,
+}
+
+impl std::fmt::Display for TurnStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "⏱️  {}ms", self.total.as_millis())?;
+        if let Some(first) = self.first_chunk {
+            write!(f, " (first token {}ms)", first.as_millis())?;
+        }
+        write!(f, " · ~{} tokens", self.chunks)?;
+        if let Some(model) = &self.model {
+            write!(f, " · {model}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Interactive chat session against a running server
+pub struct Repl {
+    client: reqwest::Client,
+    url: String,
+    session_file: PathBuf,
+    conversation_id: Uuid,
+    model: Option<String>,
+}
+
+impl Repl {
+    /// Create a session, continuing the conversation stored in `session_file`
+    pub fn new(client: reqwest::Client, url: String, session_file: PathBuf) -> Result<Self> {
+        let conversation_id = load_or_create_conversation(&session_file)?;
+        Ok(Self {
+            client,
+            url,
+            session_file,
+            conversation_id,
+            model: None,
+        })
+    }
+
+    /// Read lines from stdin until `/quit`, Ctrl-C or end of input
+    pub async fn run(&mut self) -> Result<()> {
+        println!("💬 PiSovereign REPL ({})", self.url);
+        println!("   Conversation: {}", self.conversation_id);
+        println!("   Commands: /reset, /models, /model <name>, /quit");
+        println!();
+
+        let mut lines = spawn_stdin_reader();
+        loop {
+            print!("you › ");
+            std::io::stdout().flush()?;
+
+            let line = tokio::select! {
+                line = lines.recv() => line,
+                _ = tokio::signal::ctrl_c() => None,
+            };
+            let Some(line) = line.transpose()? else {
+                println!();
+                break;
+            };
+
+            match ReplInput::parse(&line) {
+                ReplInput::Empty => {},
+                ReplInput::Quit => break,
+                ReplInput::Reset => {
+                    self.conversation_id = new_conversation(&self.session_file)?;
+                    println!("🔄 New conversation: {}", self.conversation_id);
+                },
+                ReplInput::Models => {
+                    if let Err(e) = self.print_models().await {
+                        println!("❌ {e:#}");
+                    }
+                },
+                ReplInput::Model(model) => self.select_model(model).await,
+                ReplInput::Unknown(command) => {
+                    println!(
+                        "❓ Unknown command {command} (try /reset, /models, /model <name>, /quit)"
+                    );
+                },
+                ReplInput::Message(message) => {
+                    let result = tokio::select! {
+                        result = self.send(&message) => result,
+                        _ = tokio::signal::ctrl_c() => {
+                            println!("\n⏹️  Interrupted");
+                            break;
+                        },
+                    };
+                    match result {
+                        Ok(stats) => println!("\n{stats}\n"),
+                        Err(e) => println!("\n❌ {e:#}\n"),
+                    }
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Switch the model used for the following turns
+    ///
+    /// The name is checked against the models reported by the server; if
+    /// they cannot be listed, the name is used as given.
+    async fn select_model(&mut self, model: Option<String>) {
+        let Some(model) = model else {
+            self.model = None;
+            println!("📦 Using the server's current model");
+            return;
+        };
+
+        match self.available_models().await {
+            Ok(available) if !available.contains(&model) => {
+                println!(
+                    "❌ Unknown model {model} (available: {})",
+                    available.join(", ")
+                );
+                return;
+            },
+            Ok(_) => {},
+            Err(e) => println!("⚠️  Could not verify model: {e:#}"),
+        }
+
+        println!("📦 Using model {model}");
+        self.model = Some(model);
+    }
+
+    /// Send a message and print the reply as it streams in
+    async fn send(&self, message: &str) -> Result<TurnStats> {
+        let started = Instant::now();
+        let mut response = self
+            .client
+            .post(endpoint_url(&self.url, "/v1/chat/stream"))
+            .json(&serde_json::json!({
+                "message": message,
+                "conversation_id": self.conversation_id.to_string(),
+                "model": self.model,
+            }))
+            .send()
+            .await
+            .context("Failed to reach server")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!("Server returned {status}: {body}");
+        }
+
+        print!("🤖 ");
+        let mut stats = TurnStats::default();
+        let mut parser = SseParser::default();
+        let mut completed = false;
+
+        'stream: while let Some(bytes) = response.chunk().await? {
+            for data in parser.push(&bytes) {
+                if data == DONE_MARKER {
+                    completed = true;
+                    break 'stream;
+                }
+
+                let chunk: serde_json::Value =
+                    serde_json::from_str(&data).context("Malformed stream chunk")?;
+                if let Some(content) = chunk
+                    .get("content")
+                    .and_then(|v| v.as_str())
+                    .filter(|content| !content.is_empty())
+                {
+                    stats.first_chunk.get_or_insert_with(|| started.elapsed());
+                    stats.chunks += 1;
+                    print!("{content}");
+                    std::io::stdout().flush()?;
+                }
+                if let Some(model) = chunk.get("model").and_then(|v| v.as_str()) {
+                    stats.model = Some(model.to_string());
+                }
+            }
+        }
+
+        if !completed {
+            bail!("Stream ended before completion");
+        }
+
+        stats.total = started.elapsed();
+        Ok(stats)
+    }
+
+    /// Fetch the model list from the server
+    async fn fetch_models(&self) -> Result<serde_json::Value> {
+        Ok(self
+            .client
+            .get(endpoint_url(&self.url, "/v1/system/models"))
+            .send()
+            .await
+            .context("Failed to reach server")?
+            .error_for_status()?
+            .json::<serde_json::Value>()
+            .await?)
+    }
+
+    /// Names of the models available on the server
+    async fn available_models(&self) -> Result<Vec<String>> {
+        Ok(model_names(&self.fetch_models().await?))
+    }
+
+    /// Print the models reported by the server
+    async fn print_models(&self) -> Result<()> {
+        let resp = self.fetch_models().await?;
+
+        let current = self
+            .model
+            .as_deref()
+            .or_else(|| resp.get("current").and_then(|v| v.as_str()))
+            .unwrap_or("");
+        println!("📦 Available Models:");
+        for name in model_names(&resp) {
+            let marker = if name == current { "→" } else { " " };
+            println!("   {marker} {name}");
+        }
+        Ok(())
+    }
+}
+
+/// Model names from a `/v1/system/models` response
+fn model_names(resp: &serde_json::Value) -> Vec<String> {
+    resp.get("available")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .map(|model| {
+            model
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or("?")
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_meta_commands() {
+        assert_eq!(ReplInput::parse("/reset"), ReplInput::Reset);
+        assert_eq!(ReplInput::parse(" /models "), ReplInput::Models);
+        assert_eq!(ReplInput::parse("/quit"), ReplInput::Quit);
+        assert_eq!(ReplInput::parse("/exit"), ReplInput::Quit);
+        assert_eq!(
+            ReplInput::parse("/model  llama3.2 "),
+            ReplInput::Model(Some("llama3.2".to_string()))
+        );
+        assert_eq!(ReplInput::parse("/model"), ReplInput::Model(None));
+        assert_eq!(
+            ReplInput::parse("/modelx"),
+            ReplInput::Unknown("/modelx".to_string())
+        );
+        assert_eq!(
+            ReplInput::parse("/help"),
+            ReplInput::Unknown("/help".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_messages_and_blank_lines() {
+        assert_eq!(
+            ReplInput::parse("  Hello there \n"),
+            ReplInput::Message("Hello there".to_string())
+        );
+        assert_eq!(ReplInput::parse("   "), ReplInput::Empty);
+    }
+
+    #[test]
+    fn sse_parser_handles_split_events() {
+        let mut parser = SseParser::default();
+
+        assert!(parser.push(b"data: {\"content\":\"He").is_empty());
+        let events = parser.push(b"llo\"}\n\n: keep-alive\n\ndata: [DONE]\n\n");
+
+        assert_eq!(events, vec![r#"{"content":"Hello"}"#, DONE_MARKER]);
+    }
+
+    #[test]
+    fn sse_parser_handles_split_multibyte_characters() {
+        let mut parser = SseParser::default();
+        let bytes = "data: 15°C\n\n".as_bytes();
+        let split = bytes.iter().position(|&b| b == 0xC2).unwrap() + 1;
+
+        assert!(parser.push(&bytes[..split]).is_empty());
+        assert_eq!(parser.push(&bytes[split..]), vec!["15°C"]);
+    }
+
+    #[test]
+    fn sse_parser_handles_crlf() {
+        let mut parser = SseParser::default();
+        let events = parser.push(b"data:one\r\n\r\n");
+        assert_eq!(events, vec!["one"]);
+    }
+
+    #[test]
+    fn conversation_id_persists_across_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SESSION_FILE_NAME);
+
+        let first = load_or_create_conversation(&path).unwrap();
+        let second = load_or_create_conversation(&path).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn reset_replaces_persisted_conversation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SESSION_FILE_NAME);
+
+        let first = load_or_create_conversation(&path).unwrap();
+        let reset = new_conversation(&path).unwrap();
+
+        assert_ne!(first, reset);
+        assert_eq!(load_or_create_conversation(&path).unwrap(), reset);
+    }
+
+    #[test]
+    fn invalid_session_file_starts_new_conversation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SESSION_FILE_NAME);
+        fs::write(&path, "not-a-uuid").unwrap();
+
+        let id = load_or_create_conversation(&path).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), id.to_string());
+    }
+
+    #[test]
+    fn model_names_from_response() {
+        let resp = serde_json::json!({
+            "current": "qwen",
+            "available": [{"name": "qwen"}, {"name": "llama3.2"}],
+        });
+        assert_eq!(model_names(&resp), vec!["qwen", "llama3.2"]);
+        assert!(model_names(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn turn_stats_display() {
+        let stats = TurnStats {
+            chunks: 12,
+            first_chunk: Some(Duration::from_millis(80)),
+            total: Duration::from_millis(950),
+            model: Some("qwen".to_string()),
+        };
+
+        assert_eq!(
+            stats.to_string(),
+            "⏱️  950ms (first token 80ms) · ~12 tokens · qwen"
+        );
+    }
+}
@@ -0,0 +1,301 @@
+//! Configuration validation
+//!
+//! Runs the same security validation the server performs at startup and adds
+//! integration checks for settings that are accepted by the server but leave
+//! a feature silently disabled or degraded, or point at missing paths.
+
+use std::path::Path;
+
+use infrastructure::{
+    AppConfig, SecurityValidator, SecurityWarning, WarningSeverity,
+    config::{MessengerSelection, WebSearchAppConfig},
+};
+
+/// Result of validating a configuration
+#[derive(Debug)]
+pub struct ValidationReport {
+    /// All warnings, critical first
+    pub warnings: Vec<SecurityWarning>,
+    /// Whether the server would refuse to start with this configuration
+    pub blocks_startup: bool,
+}
+
+impl ValidationReport {
+    /// Warnings of the given severity
+    pub fn with_severity(
+        &self,
+        severity: WarningSeverity,
+    ) -> impl Iterator<Item = &SecurityWarning> {
+        self.warnings.iter().filter(move |w| w.severity == severity)
+    }
+}
+
+/// Validate a loaded configuration
+pub fn validate_config(config: &AppConfig) -> ValidationReport {
+    let security_warnings = SecurityValidator::validate(config);
+    let blocks_startup = SecurityValidator::should_block_startup(config, &security_warnings);
+
+    let mut warnings = security_warnings;
+    check_messenger(config, &mut warnings);
+    check_websearch(config.websearch.as_ref(), &mut warnings);
+    check_paths(config, &mut warnings);
+    warnings.sort_by(|a, b| b.severity.cmp(&a.severity));
+
+    ValidationReport {
+        warnings,
+        blocks_startup,
+    }
+}
+
+fn check_messenger(config: &AppConfig, warnings: &mut Vec<SecurityWarning>) {
+    match config.messenger {
+        MessengerSelection::WhatsApp => {
+            if config.whatsapp.access_token.is_none() || config.whatsapp.phone_number_id.is_none() {
+                warnings.push(SecurityWarning::warning(
+                    "CFG001",
+                    "WhatsApp selected but access_token or phone_number_id is missing",
+                    "Set whatsapp.access_token and whatsapp.phone_number_id, or set messenger = \"none\"",
+                ));
+            }
+        },
+        MessengerSelection::Signal => {
+            if config.signal.phone_number.trim().is_empty() {
+                warnings.push(SecurityWarning::warning(
+                    "CFG002",
+                    "Signal selected but signal.phone_number is empty",
+                    "Set signal.phone_number to the number registered with signal-cli",
+                ));
+            }
+        },
+        MessengerSelection::None => {},
+    }
+}
+
+fn check_websearch(websearch: Option<&WebSearchAppConfig>, warnings: &mut Vec<SecurityWarning>) {
+    let Some(websearch) = websearch else {
+        return;
+    };
+
+    let has_api_key = websearch
+        .api_key
+        .as_deref()
+        .is_some_and(|key| !key.trim().is_empty());
+    if has_api_key {
+        return;
+    }
+
+    if websearch.fallback_enabled {
+        warnings.push(SecurityWarning::info(
+            "CFG003",
+            "Web search has no api_key, so only DuckDuckGo will be used",
+            "Set websearch.api_key to a Brave Search API key for better results",
+        ));
+    } else {
+        warnings.push(SecurityWarning::warning(
+            "CFG004",
+            "Web search has no api_key and the DuckDuckGo fallback is disabled",
+            "Set websearch.api_key or enable websearch.fallback_enabled",
+        ));
+    }
+}
+
+fn check_paths(config: &AppConfig, warnings: &mut Vec<SecurityWarning>) {
+    // The database file is created on first start, but not its directory
+    if config.database.path != ":memory:" {
+        let dir = Path::new(&config.database.path)
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        if !dir.is_dir() {
+            warnings.push(SecurityWarning::warning(
+                "CFG005",
+                format!("Database directory {} does not exist", dir.display()),
+                "Create the directory or change database.path",
+            ));
+        }
+    }
+
+    let missing_templates_dir = config
+        .templates
+        .templates_dir
+        .as_deref()
+        .filter(|dir| !Path::new(dir).is_dir());
+    if let Some(templates_dir) = missing_templates_dir {
+        warnings.push(SecurityWarning::warning(
+            "CFG006",
+            format!("Templates directory {templates_dir} does not exist"),
+            "Create the directory or remove templates.templates_dir to use the built-in templates",
+        ));
+    }
+
+    if config.messenger == MessengerSelection::Signal
+        && !Path::new(&config.signal.socket_path).exists()
+    {
+        warnings.push(SecurityWarning::warning(
+            "CFG007",
+            format!("Signal socket {} does not exist", config.signal.socket_path),
+            "Start signal-cli in daemon mode or fix signal.socket_path",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::config::Environment;
+
+    use super::*;
+
+    fn codes(report: &ValidationReport) -> Vec<&str> {
+        report.warnings.iter().map(|w| w.code.as_str()).collect()
+    }
+
+    fn websearch(api_key: Option<&str>, fallback_enabled: bool) -> WebSearchAppConfig {
+        WebSearchAppConfig {
+            api_key: api_key.map(ToString::to_string),
+            fallback_enabled,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn signal_without_phone_number_is_reported() {
+        let config = AppConfig {
+            messenger: MessengerSelection::Signal,
+            ..Default::default()
+        };
+
+        let report = validate_config(&config);
+
+        assert!(codes(&report).contains(&"CFG002"));
+        assert!(!report.blocks_startup);
+    }
+
+    #[test]
+    fn whatsapp_without_credentials_is_reported() {
+        let config = AppConfig {
+            messenger: MessengerSelection::WhatsApp,
+            ..Default::default()
+        };
+
+        assert!(codes(&validate_config(&config)).contains(&"CFG001"));
+    }
+
+    #[test]
+    fn disabled_messenger_is_not_reported() {
+        let config = AppConfig {
+            messenger: MessengerSelection::None,
+            ..Default::default()
+        };
+
+        let report = validate_config(&config);
+
+        assert!(!codes(&report).contains(&"CFG001"));
+        assert!(!codes(&report).contains(&"CFG002"));
+    }
+
+    #[test]
+    fn websearch_without_api_key_is_duckduckgo_only() {
+        let config = AppConfig {
+            websearch: Some(websearch(None, true)),
+            ..Default::default()
+        };
+
+        let report = validate_config(&config);
+
+        let warning = report.warnings.iter().find(|w| w.code == "CFG003").unwrap();
+        assert_eq!(warning.severity, WarningSeverity::Info);
+    }
+
+    #[test]
+    fn websearch_without_api_key_or_fallback_is_a_warning() {
+        let config = AppConfig {
+            websearch: Some(websearch(Some("  "), false)),
+            ..Default::default()
+        };
+
+        assert!(codes(&validate_config(&config)).contains(&"CFG004"));
+    }
+
+    #[test]
+    fn websearch_with_api_key_is_not_reported() {
+        let config = AppConfig {
+            websearch: Some(websearch(Some("brave-key"), true)),
+            ..Default::default()
+        };
+
+        let report = validate_config(&config);
+
+        assert!(!codes(&report).contains(&"CFG003"));
+        assert!(!codes(&report).contains(&"CFG004"));
+    }
+
+    #[test]
+    fn missing_paths_are_reported() {
+        let mut config = AppConfig {
+            messenger: MessengerSelection::Signal,
+            ..Default::default()
+        };
+        config.database.path = "/nonexistent/pisovereign/app.db".to_string();
+        config.templates.templates_dir = Some("/nonexistent/templates".to_string());
+        config.signal.socket_path = "/nonexistent/signal.sock".to_string();
+
+        let report = validate_config(&config);
+
+        assert!(codes(&report).contains(&"CFG005"));
+        assert!(codes(&report).contains(&"CFG006"));
+        assert!(codes(&report).contains(&"CFG007"));
+        assert!(!report.blocks_startup);
+    }
+
+    #[test]
+    fn existing_paths_are_not_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("signal.sock");
+        std::fs::write(&socket, "").unwrap();
+
+        let mut config = AppConfig {
+            messenger: MessengerSelection::Signal,
+            ..Default::default()
+        };
+        config.database.path = dir.path().join("app.db").to_string_lossy().into_owned();
+        config.templates.templates_dir = Some(dir.path().to_string_lossy().into_owned());
+        config.signal.socket_path = socket.to_string_lossy().into_owned();
+
+        let report = validate_config(&config);
+
+        for code in ["CFG005", "CFG006", "CFG007"] {
+            assert!(!codes(&report).contains(&code), "unexpected {code}");
+        }
+    }
+
+    #[test]
+    fn relative_database_path_uses_working_directory() {
+        let mut config = AppConfig::default();
+        config.database.path = "pisovereign.db".to_string();
+
+        assert!(!codes(&validate_config(&config)).contains(&"CFG005"));
+    }
+
+    #[test]
+    fn critical_production_issue_blocks_startup() {
+        let mut config = AppConfig {
+            environment: Some(Environment::Production),
+            ..Default::default()
+        };
+        config.security.tls_verify_certs = false;
+
+        let report = validate_config(&config);
+
+        assert!(report.blocks_startup);
+        assert_eq!(report.warnings[0].severity, WarningSeverity::Critical);
+        assert!(report.with_severity(WarningSeverity::Critical).count() > 0);
+    }
+
+    #[test]
+    fn development_config_does_not_block_startup() {
+        let mut config = AppConfig::default();
+        config.security.tls_verify_certs = false;
+
+        assert!(!validate_config(&config).blocks_startup);
+    }
+}
@@ -0,0 +1,76 @@
+//! End-to-end tests for `pisovereign-cli validate-config`
+//!
+//! Runs the built binary against configuration files written to a temporary
+//! directory and checks the exit status and printed findings.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn validate(config: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pisovereign-cli"))
+        .arg("validate-config")
+        .arg("--config")
+        .arg(config)
+        .env_remove("PISOVEREIGN_ALLOW_INSECURE_CONFIG")
+        .output()
+        .expect("failed to run pisovereign-cli")
+}
+
+#[test]
+fn insecure_production_config_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    std::fs::write(
+        &config,
+        r#"environment = "production"
+messenger = "none"
+
+[security]
+tls_verify_certs = false
+
+[templates]
+templates_dir = "/nonexistent/pisovereign/templates"
+"#,
+    )
+    .unwrap();
+
+    let output = validate(&config);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success(), "expected failure:\n{stdout}");
+    assert!(stdout.contains("CRITICAL"), "{stdout}");
+    assert!(stdout.contains("SEC001"), "{stdout}");
+    assert!(stdout.contains("CFG006"), "{stdout}");
+    assert!(stdout.contains("would block server startup"), "{stdout}");
+}
+
+#[test]
+fn development_config_passes_with_warnings() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    std::fs::write(
+        &config,
+        r#"messenger = "none"
+
+[security]
+tls_verify_certs = false
+"#,
+    )
+    .unwrap();
+
+    let output = validate(&config);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "expected success:\n{stdout}");
+    assert!(stdout.contains("SEC001"), "{stdout}");
+}
+
+#[test]
+fn missing_config_file_fails() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = validate(&dir.path().join("missing.toml"));
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Failed to load configuration"));
+}
@@ -5,7 +5,11 @@
 
 #![allow(clippy::expect_used)]
 
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use application::{
     AgentService, ChatService,
@@ -227,6 +231,15 @@ fn create_benchmark_state() -> AppState {
         conversation_store: None,
         secret_store: None,
         contact_service: None,
+        degraded_mode: None,
+        audit_log: None,
+        delivery_status: None,
+        retry_queue: None,
+        system_prompt_store: None,
+        model_registry: None,
+        shutdown: None,
+        warmup: None,
+        started_at: Instant::now(),
         config: presentation_http::ReloadableConfig::new(AppConfig::default()),
         metrics: Arc::new(MetricsCollector::new()),
     }
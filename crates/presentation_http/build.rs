@@ -0,0 +1,23 @@
+//! Build script embedding the git commit for `/v1/system/info`
+
+use std::process::Command;
+
+fn main() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty());
+
+    if let Some(commit) = commit {
+        println!("cargo:rustc-env=PISOVEREIGN_GIT_COMMIT={commit}");
+    }
+
+    // Re-run when HEAD moves; paths are relative to this crate
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+    println!("cargo:rerun-if-changed=../../.git/refs/heads");
+    println!("cargo:rerun-if-env-changed=PISOVEREIGN_GIT_COMMIT");
+}
@@ -0,0 +1,1681 @@
+//! Server bootstrap
+//!
+//! Builds the application from configuration and runs the HTTP server.
+//! Shared by the `pisovereign-server` binary and `pisovereign-cli serve`.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    ApiKeyAuthLayer, HttpMetricsLayer, JwtAuthLayer, JwtValidator, LiveSettings, RateLimiterConfig,
+    RateLimiterLayer, ReloadableConfig, RequestIdLayer, RotatingSecrets, SecurityHeadersLayer,
+    TimeoutLayer,
+    handlers::metrics::MetricsCollector,
+    middleware::{RATELIMIT_LIMIT, RATELIMIT_REMAINING, RATELIMIT_RESET},
+    routes, spawn_circuit_breaker_metrics_task, spawn_cleanup_task, spawn_config_reload_handler,
+    spawn_conversation_cleanup_task, spawn_database_maintenance_task, spawn_draft_cleanup_task,
+    spawn_jwks_refresh_task, spawn_live_settings_task, spawn_memory_consolidation_task,
+    spawn_model_warmup_task, spawn_retry_worker_task, spawn_secret_refresh_task,
+    spawn_signal_polling_task,
+    state::AppState,
+};
+use application::{
+    AgentService, ApprovalService, ChatService, HealthService, MemoryService, SemanticRouter,
+    VoiceMessageConfig, VoiceMessageService,
+    ports::{
+        AuditLogPort, CalendarPort, ContactPort, ConversationStore, DatabaseHealthPort,
+        DegradedModePort, DeliveryStatusPort, DraftStorePort, EmailPort, EmailTemplatePort,
+        EncryptionPort, InferencePort, MessengerPort, ModelRegistryPort, NoOpEncryption,
+        ReminderPort, RetryQueuePort, SecretStorePort, SpeechPort, SuspiciousActivityPort,
+        SystemPromptStore, TransitPort, UserProfileStore, WeatherPort,
+    },
+    services::{DailyDigestService, PromptSanitizer},
+    tools::WeatherTool,
+};
+use axum::http::{HeaderValue, Method, header};
+use infrastructure::{
+    AppConfig, DegradedModeAppConfig, MessengerSelection, MokaCache, NegativeCache,
+    OllamaInferenceAdapter, SchedulerConfig, SecurityValidator, TaskBuilder, TaskScheduler,
+    TemplateEngine,
+    adapters::{
+        CachingSecretStore, CalDavCalendarAdapter, CardDavContactAdapter, ChaChaEncryptionAdapter,
+        ChainedSecretStore, DegradedInferenceAdapter, DegradedModeConfig, DegradedModeMonitor,
+        EnvSecretStore, InMemorySuspiciousActivityTracker, OllamaEmbeddingAdapter,
+        OllamaModelRegistryAdapter, OllamaModelRegistryConfig, ProtonEmailAdapter, QueuedMessenger,
+        SharedSecret, SignalMessengerAdapter, SpeechAdapter, TransitAdapter, VaultSecretStore,
+        WeatherAdapter, WhatsAppMessengerAdapter, subscribe_circuit_events,
+    },
+    config::CorsPolicy,
+    http::create_shared_client,
+    persistence::{
+        AsyncConversationStore, AsyncDatabase, AsyncDatabaseConfig, AsyncDatabaseError,
+        RetryQueueStore, SqliteApprovalQueue, SqliteAuditLog, SqliteDatabaseHealth,
+        SqliteDeliveryStatusStore, SqliteDraftStore, SqliteMemoryStore, SqliteReminderStore,
+        SqliteSystemPromptStore, SqliteUserProfileStore,
+    },
+    scheduled_tasks::{DAILY_DIGEST_TASK, create_daily_digest_task},
+    telemetry::{TelemetryConfig, init_telemetry},
+};
+use integration_signal::{SignalClient, SignalClientConfig};
+use integration_whatsapp::WhatsAppClientConfig;
+use secrecy::ExposeSecret;
+use std::{net::SocketAddr, path::PathBuf};
+use tokio::{net::TcpListener, signal, sync::watch};
+use tower_http::{
+    cors::{AllowCredentials, AllowHeaders, AllowOrigin, CorsLayer, MaxAge},
+    limit::RequestBodyLimitLayer,
+    trace::TraceLayer,
+};
+use tracing::{debug, error, info, warn};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Static system prompt, used when the prompt template cannot be rendered
+const SYSTEM_PROMPT: &str = "You are PiSovereign, a helpful AI assistant. On Raspberry Pi \
+    you run on the Hailo-10H NPU, on Mac you use Metal GPU acceleration. You are friendly, \
+    precise, and help with everyday tasks like email, calendar, and information lookup.";
+
+/// Overrides applied on top of the loaded configuration
+#[derive(Debug, Clone, Default)]
+pub struct ServeOptions {
+    /// Configuration file to load instead of `config.toml` in the working directory
+    pub config_path: Option<PathBuf>,
+    /// Port to listen on instead of `server.port`
+    pub port: Option<u16>,
+}
+
+impl ServeOptions {
+    /// Load configuration from the given file
+    #[must_use]
+    pub fn with_config_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_path = Some(path.into());
+        self
+    }
+
+    /// Listen on the given port
+    #[must_use]
+    pub const fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Load the application configuration and apply the overrides
+    ///
+    /// Without an explicit config file, a missing or invalid `config.toml`
+    /// falls back to defaults. An explicitly requested file must load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the explicitly requested config file cannot be
+    /// loaded, or if any value fails [`AppConfig::validate`].
+    pub fn load_config(&self) -> anyhow::Result<AppConfig> {
+        let mut config = match &self.config_path {
+            Some(path) => AppConfig::load_from(Some(path)).map_err(|e| {
+                anyhow::anyhow!("Failed to load config from {}: {e}", path.display())
+            })?,
+            None => AppConfig::load().unwrap_or_else(|e| {
+                // Can't log yet, print to stderr (allowed before tracing init)
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Warning: Failed to load config, using defaults: {e}");
+                }
+                AppConfig::default()
+            }),
+        };
+
+        if let Some(port) = self.port {
+            config.server.port = port;
+        }
+
+        config
+            .validate()
+            .map_err(|e| anyhow::anyhow!("Invalid configuration: {e}"))?;
+
+        Ok(config)
+    }
+}
+
+/// Initialize the tracing subscriber based on configuration
+///
+/// In production and staging, defaults to JSON format for structured logging
+/// suitable for log aggregation (Loki, Elasticsearch, etc.). Does nothing if
+/// the embedding binary already installed a subscriber.
+fn init_tracing(log_format: &str, environment: Option<infrastructure::config::Environment>) {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        "pisovereign_server=debug,presentation_http::bootstrap=debug,tower_http=debug".into()
+    });
+
+    // Determine effective log format:
+    // - Use explicit config value if not "text" (the default)
+    // - In production, default to JSON unless explicitly set to "text"
+    let use_json = if log_format == "json" {
+        true
+    } else if log_format != "text" {
+        // Unknown format, treat as JSON for safety
+        true
+    } else {
+        // log_format == "text" - check if production or staging
+        environment.is_some_and(infrastructure::config::Environment::is_production_like)
+    };
+
+    if use_json {
+        // JSON format for production/structured logging
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_target(true)
+                    .with_file(true)
+                    .with_line_number(true)
+                    .with_thread_ids(true)
+                    .with_span_list(true),
+            )
+            .try_init()
+            .ok();
+    } else {
+        // Human-readable text format for development
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(tracing_subscriber::fmt::layer())
+            .try_init()
+            .ok();
+    }
+}
+
+/// Build the application from configuration and serve HTTP until shutdown
+///
+/// Wires all adapters and services into an [`AppState`], spawns the
+/// background tasks, applies the middleware stack and runs the server until
+/// SIGINT/SIGTERM. Shared by the `pisovereign-server` binary and
+/// `pisovereign-cli serve`.
+///
+/// # Errors
+///
+/// Returns an error if the configuration, inference adapter or listener
+/// cannot be set up, or the server fails.
+#[allow(clippy::too_many_lines)]
+pub async fn bootstrap(options: ServeOptions) -> anyhow::Result<()> {
+    // Load configuration first to determine log format
+    let mut initial_config = options.load_config()?;
+
+    // Initialize tracing with configured format
+    init_tracing(
+        &initial_config.server.log_format,
+        initial_config.environment,
+    );
+
+    info!("🤖 PiSovereign v{} starting...", env!("CARGO_PKG_VERSION"));
+
+    // Rotating secrets left empty in config come from the store and are
+    // refreshed from it; record them before resolution fills them in
+    let secret_store_paths = RotatingSecrets::store_paths(&initial_config);
+
+    // Initialize Vault secret store and resolve secrets into config
+    let secret_store: Option<Arc<dyn SecretStorePort>> = if initial_config.vault.enabled {
+        info!(
+            address = %initial_config.vault.address,
+            "Initializing Vault secret store"
+        );
+
+        match initialize_secret_store(&initial_config).await {
+            Ok(store) => {
+                if let Err(e) = initial_config.resolve_secrets(store.as_ref()).await {
+                    warn!(error = %e, "Secret resolution completed with errors");
+                }
+                Some(store)
+            },
+            Err(e) => {
+                warn!(error = %e, "Failed to initialize Vault — continuing without secrets");
+                None
+            },
+        }
+    } else {
+        debug!("Vault integration disabled");
+        None
+    };
+
+    // Validate security configuration
+    let security_warnings = SecurityValidator::validate(&initial_config);
+    if !security_warnings.is_empty() {
+        SecurityValidator::log_warnings(&security_warnings);
+
+        if SecurityValidator::should_block_startup(&initial_config, &security_warnings) {
+            error!(
+                "🛑 Startup blocked due to critical security issues in production mode. \
+                 Set PISOVEREIGN_ALLOW_INSECURE_CONFIG=true to override (not recommended)."
+            );
+            std::process::exit(1);
+        }
+    }
+
+    // Configure error response detail exposure based on environment
+    // In production, we hide implementation details to prevent information leakage
+    let is_production = matches!(
+        initial_config.environment,
+        Some(infrastructure::config::Environment::Production)
+    );
+    crate::error::set_expose_internal_errors(!is_production);
+    if is_production {
+        info!("🔒 Production mode: error details will be sanitized");
+    }
+
+    info!(
+        host = %initial_config.server.host,
+        port = %initial_config.server.port,
+        model = %initial_config.inference.default_model,
+        "Configuration loaded"
+    );
+
+    // Security check: validate API keys are properly hashed
+    // In release builds with plaintext keys, startup is blocked by SecurityValidator above
+    // This provides an additional warning for development mode
+    let plaintext_count = initial_config.security.count_plaintext_keys();
+    if plaintext_count > 0 {
+        warn!(
+            count = plaintext_count,
+            "⚠️ SECURITY WARNING: {} API key(s) are not properly hashed with Argon2. \
+             Run 'pisovereign-cli migrate-keys' to convert them to secure hashes.",
+            plaintext_count
+        );
+    }
+
+    // Initialize OpenTelemetry if configured
+    let _telemetry_guard = initial_config
+        .telemetry
+        .as_ref()
+        .filter(|c| c.enabled)
+        .and_then(|otel_config| {
+            let telemetry_config = TelemetryConfig {
+                enabled: true,
+                endpoint: otel_config.otlp_endpoint.clone(),
+                service_name: "pisovereign".to_string(),
+                sampling_ratio: otel_config.sample_ratio.unwrap_or(1.0),
+                ..TelemetryConfig::default()
+            };
+            match init_telemetry(&telemetry_config) {
+                Ok(guard) => {
+                    info!(
+                        endpoint = %telemetry_config.endpoint,
+                        "📊 OpenTelemetry initialized"
+                    );
+                    Some(guard)
+                },
+                Err(e) => {
+                    warn!(error = %e, "⚠️ Failed to initialize telemetry, continuing without");
+                    None
+                },
+            }
+        });
+
+    // Adapter passwords that are swapped in place when secrets are rotated
+    let proton_password = initial_config
+        .proton
+        .as_ref()
+        .map(|config| SharedSecret::new(config.password.clone()));
+    let caldav_password = initial_config
+        .caldav
+        .as_ref()
+        .map(|config| SharedSecret::new(config.password.clone()));
+
+    // Create reloadable config and spawn SIGHUP handler
+    let mut reloadable_config = ReloadableConfig::new(initial_config.clone());
+    if let Some(ref path) = options.config_path {
+        reloadable_config = reloadable_config.with_config_path(path.clone());
+    }
+    if let Some(ref store) = secret_store {
+        let mut rotating = RotatingSecrets::new().with_store_paths(secret_store_paths);
+        if let Some(ref secret) = proton_password {
+            rotating = rotating.with_proton(secret.clone());
+        }
+        if let Some(ref secret) = caldav_password {
+            rotating = rotating.with_caldav(secret.clone());
+        }
+
+        // Periodically re-read rotating secrets so rotations in Vault take effect
+        let refresh_secs = initial_config.vault.secret_refresh_interval_secs;
+        if refresh_secs > 0 && rotating.has_store_paths() {
+            // Detached: runs for the lifetime of the server
+            let _secret_refresh_handle = spawn_secret_refresh_task(
+                Arc::clone(store),
+                rotating.clone(),
+                Duration::from_secs(refresh_secs),
+            );
+        }
+
+        reloadable_config = reloadable_config
+            .with_secret_store(Arc::clone(store))
+            .with_rotating_secrets(rotating);
+    }
+    let reloadable_config = spawn_config_reload_handler(reloadable_config);
+
+    // Fault injection for game days; policies are installed at runtime
+    #[cfg(feature = "chaos")]
+    let chaos = {
+        warn!("🔥 CHAOS: fault injection is compiled in; manage it via /v1/admin/chaos");
+        Arc::new(infrastructure::chaos::ChaosController::new())
+    };
+
+    // Subscribe before any adapter is built so no transition is missed
+    let circuit_events = subscribe_circuit_events();
+
+    // Initialize inference adapter with degraded mode wrapper
+    let ollama_adapter = OllamaInferenceAdapter::new(initial_config.inference.clone())
+        .map_err(|e| anyhow::anyhow!("Failed to initialize inference: {e}"))?;
+    let inference_backend = Arc::new(ollama_adapter);
+
+    // Model capabilities for /v1/system/models
+    let model_registry: Option<Arc<dyn ModelRegistryPort>> =
+        match OllamaModelRegistryAdapter::with_config(OllamaModelRegistryConfig {
+            base_url: initial_config.inference.base_url.clone(),
+            ..OllamaModelRegistryConfig::default()
+        }) {
+            Ok(adapter) => Some(Arc::new(adapter.with_circuit_breaker())),
+            Err(e) => {
+                warn!(error = %e, "Model registry unavailable, listing model names only");
+                None
+            },
+        };
+    // Inside the degraded mode wrapper so injected faults trip its circuit
+    #[cfg(feature = "chaos")]
+    let inference_backend = Arc::new(infrastructure::chaos::ChaosInferenceAdapter::new(
+        inference_backend,
+        Arc::clone(&chaos),
+    ));
+
+    // Configure degraded mode from config or use defaults
+    let degraded_config = initial_config.degraded_mode.as_ref().map_or_else(
+        DegradedModeConfig::default,
+        DegradedModeAppConfig::to_degraded_mode_config,
+    );
+
+    let degraded_adapter = Arc::new(DegradedInferenceAdapter::new(
+        inference_backend,
+        degraded_config,
+    ));
+    info!("🛡️ Degraded mode adapter initialized");
+
+    let degraded_status: Arc<dyn DegradedModePort> = degraded_adapter.clone();
+    let degraded_mode: Arc<dyn DegradedModeMonitor> = degraded_adapter.clone();
+    let inference: Arc<dyn InferencePort> = degraded_adapter;
+
+    // Load the model in the background; /ready waits until this finished
+    let warmup = initial_config.inference.warmup_on_start.then(|| {
+        let (warmup_tx, warmup_rx) = watch::channel(false);
+        // Detached: ends after a single warmup attempt
+        let _warmup_handle = spawn_model_warmup_task(Arc::clone(&inference), warmup_tx);
+        warmup_rx
+    });
+
+    // Shared HTTP client for integrations; propagates X-Request-Id to
+    // outgoing calls. Integrations fall back to their own client if unset.
+    let http_client = match create_shared_client() {
+        Ok(client) => Some(client),
+        Err(e) => {
+            warn!(error = %e, "⚠️ Failed to create shared HTTP client");
+            None
+        },
+    };
+
+    // Per-call timeouts for integrations that can hang on a stalled connection
+    let health_config = initial_config.health.clone().unwrap_or_default();
+
+    // Failed weather and geocoding lookups are remembered briefly
+    let negative_cache = initial_config.cache.negative_cache_ttl().map(|ttl| {
+        debug!(ttl_secs = ttl.as_secs(), "Negative lookup cache enabled");
+        NegativeCache::new(Arc::new(MokaCache::new()), ttl)
+    });
+
+    // Initialize optional weather adapter
+    let weather_port: Option<Arc<dyn WeatherPort>> =
+        initial_config.weather.as_ref().and_then(|_| {
+            let adapter = match &http_client {
+                Some(client) => Ok(WeatherAdapter::with_http_client(Arc::clone(client))),
+                None => WeatherAdapter::new(),
+            };
+            match adapter {
+                Ok(adapter) => {
+                    let mut adapter = adapter.with_circuit_breaker();
+                    if let Some(cache) = &negative_cache {
+                        adapter = adapter.with_negative_cache(cache.clone());
+                    }
+                    info!("🌤️ Weather adapter initialized");
+                    Some(Arc::new(adapter) as Arc<dyn WeatherPort>)
+                },
+                Err(e) => {
+                    warn!(error = %e, "⚠️ Failed to initialize weather adapter");
+                    None
+                },
+            }
+        });
+
+    // Initialize optional CalDAV calendar adapter
+    let calendar_port: Option<Arc<dyn CalendarPort>> =
+        initial_config.caldav.as_ref().and_then(|config| {
+            match CalDavCalendarAdapter::new(config.to_caldav_config()) {
+                Ok(mut adapter) => {
+                    if let Some(ref secret) = caldav_password {
+                        adapter = adapter.with_shared_password(secret.clone());
+                    }
+                    let adapter = adapter
+                        .with_timeout(health_config.calendar_timeout())
+                        .with_circuit_breaker();
+                    info!("📅 CalDAV calendar adapter initialized");
+                    Some(Arc::new(adapter) as Arc<dyn CalendarPort>)
+                },
+                Err(e) => {
+                    warn!(error = %e, "⚠️ Failed to initialize CalDAV adapter");
+                    None
+                },
+            }
+        });
+
+    #[cfg(feature = "chaos")]
+    let (weather_port, calendar_port) = {
+        use infrastructure::chaos::{ChaosCalendarAdapter, ChaosWeatherAdapter};
+        (
+            weather_port.map(|port| {
+                Arc::new(ChaosWeatherAdapter::new(port, Arc::clone(&chaos))) as Arc<dyn WeatherPort>
+            }),
+            calendar_port.map(|port| {
+                Arc::new(ChaosCalendarAdapter::new(port, Arc::clone(&chaos)))
+                    as Arc<dyn CalendarPort>
+            }),
+        )
+    };
+
+    // Initialize optional CardDAV contact adapter
+    let contact_port: Option<Arc<dyn ContactPort>> =
+        initial_config.carddav.as_ref().and_then(|config| {
+            match CardDavContactAdapter::new(config.to_carddav_config()) {
+                Ok(adapter) => {
+                    info!("📇 CardDAV contact adapter initialized");
+                    Some(Arc::new(adapter.with_circuit_breaker()) as Arc<dyn ContactPort>)
+                },
+                Err(e) => {
+                    warn!(error = %e, "⚠️ Failed to initialize CardDAV contact adapter");
+                    None
+                },
+            }
+        });
+
+    // Initialize optional Proton email adapter
+    let email_port: Option<Arc<dyn EmailPort>> = initial_config.proton.as_ref().map(|config| {
+        let mut adapter = ProtonEmailAdapter::new(config.to_proton_config());
+        if let Some(ref secret) = proton_password {
+            adapter = adapter.with_shared_password(secret.clone());
+        }
+        let adapter = adapter
+            .with_timeout(health_config.email_timeout())
+            .with_circuit_breaker();
+        info!("📧 Proton email adapter initialized");
+        Arc::new(adapter) as Arc<dyn EmailPort>
+    });
+
+    // Initialize optional transit adapter
+    let transit_port: Option<Arc<dyn TransitPort>> =
+        initial_config.transit.as_ref().and_then(|config| {
+            let transit_config = config.to_transit_config();
+            let geocoding_config = integration_transit::NominatimConfig::default();
+
+            let transit_client = http_client.as_ref().map_or_else(
+                || integration_transit::HafasTransitClient::new(&transit_config),
+                |client| {
+                    Ok(integration_transit::HafasTransitClient::with_http_client(
+                        &transit_config,
+                        Arc::clone(client),
+                    ))
+                },
+            );
+
+            match (
+                transit_client,
+                integration_transit::NominatimGeocodingClient::new(&geocoding_config),
+            ) {
+                (Ok(transit_client), Ok(geocoding_client)) => {
+                    let mut adapter = TransitAdapter::new(transit_client, geocoding_client)
+                        .with_circuit_breaker()
+                        .with_bulkhead(config.max_concurrent_requests);
+                    if let Some(cache) = &negative_cache {
+                        adapter = adapter.with_negative_cache(cache.clone());
+                    }
+                    info!("🚇 Transit adapter initialized");
+                    Some(Arc::new(adapter) as Arc<dyn TransitPort>)
+                },
+                (Err(e), _) => {
+                    warn!(error = %e, "⚠️ Failed to initialize transit client");
+                    None
+                },
+                (_, Err(e)) => {
+                    warn!(error = %e, "⚠️ Failed to initialize geocoding client");
+                    None
+                },
+            }
+        });
+
+    // Load the conversation encryption key if the active messenger stores messages encrypted
+    let conversation_encryption = load_conversation_encryption(&initial_config)?;
+
+    // Initialize async database
+    let (
+        approval_service,
+        audit_log,
+        conversation_store,
+        database_health_port,
+        delivery_status,
+        draft_store,
+        reminder_port,
+        retry_queue,
+        system_prompt_store,
+        user_profile_store,
+    ) = {
+        match open_database(&initial_config.database).await {
+            Ok(db) => match db.migrate().await {
+                Ok(()) => {
+                    let pool = db.pool().clone();
+                    let approval_queue = Arc::new(SqliteApprovalQueue::new(pool.clone()));
+                    let audit_log: Arc<dyn AuditLogPort> =
+                        Arc::new(SqliteAuditLog::new(pool.clone()));
+                    let approval_service =
+                        ApprovalService::new(approval_queue, Arc::clone(&audit_log));
+                    let mut async_conversation_store = AsyncConversationStore::new(pool.clone());
+                    if let Some(encryption) = conversation_encryption {
+                        async_conversation_store =
+                            async_conversation_store.with_encryption(encryption);
+                    }
+                    let conversation_store: Arc<dyn ConversationStore> =
+                        Arc::new(async_conversation_store);
+                    let sqlite_health = SqliteDatabaseHealth::new(pool.clone());
+                    if initial_config.database.maintenance_enabled {
+                        // Detached: runs for the lifetime of the server
+                        let _maintenance_handle = spawn_database_maintenance_task(
+                            db.clone(),
+                            sqlite_health.clone(),
+                            Some(Duration::from_secs(
+                                initial_config
+                                    .database
+                                    .maintenance_interval_hours
+                                    .saturating_mul(3600),
+                            )),
+                        );
+                        info!(
+                            interval_hours = initial_config.database.maintenance_interval_hours,
+                            "🧰 Database maintenance enabled"
+                        );
+                    }
+                    let database_health: Arc<dyn DatabaseHealthPort> = Arc::new(sqlite_health);
+                    let retry_queue: Arc<dyn RetryQueuePort> =
+                        Arc::new(RetryQueueStore::new(pool.clone()));
+                    let draft_store: Arc<dyn DraftStorePort> = Arc::new(
+                        SqliteDraftStore::new(pool.clone())
+                            .with_ttl_days(initial_config.database.draft_ttl_days),
+                    );
+                    // Detached: runs for the lifetime of the server
+                    let _draft_cleanup_handle =
+                        spawn_draft_cleanup_task(Arc::clone(&draft_store), None);
+                    info!(
+                        ttl_days = initial_config.database.draft_ttl_days,
+                        "🗑️ Email draft cleanup enabled"
+                    );
+                    if let Some(memory) = initial_config.memory.as_ref().filter(|m| m.enabled) {
+                        match OllamaEmbeddingAdapter::new(
+                            memory.to_embedding_config(&initial_config.inference.base_url),
+                        ) {
+                            Ok(embedding) => {
+                                // Consolidation never reads memory content, so no key is needed
+                                let memory_service = Arc::new(MemoryService::new(
+                                    Arc::new(SqliteMemoryStore::new(pool.clone())),
+                                    Arc::new(embedding),
+                                    Arc::new(NoOpEncryption),
+                                    memory.to_memory_service_config(),
+                                ));
+                                // Detached: runs for the lifetime of the server
+                                let _consolidation_handle = spawn_memory_consolidation_task(
+                                    memory_service,
+                                    Some(Duration::from_secs(
+                                        memory.consolidation_interval_hours.saturating_mul(3600),
+                                    )),
+                                );
+                                info!(
+                                    interval_hours = memory.consolidation_interval_hours,
+                                    "🧠 Memory consolidation enabled"
+                                );
+                            },
+                            Err(e) => warn!(
+                                error = %e,
+                                "⚠️ Failed to create embedding adapter, memory consolidation disabled"
+                            ),
+                        }
+                    }
+                    let user_profile_store: Arc<dyn UserProfileStore> =
+                        Arc::new(SqliteUserProfileStore::new(pool.clone()));
+                    let system_prompt_store: Arc<dyn SystemPromptStore> =
+                        Arc::new(SqliteSystemPromptStore::new(pool.clone()));
+                    let delivery_status: Arc<dyn DeliveryStatusPort> =
+                        Arc::new(SqliteDeliveryStatusStore::new(pool.clone()));
+                    let reminder_store: Arc<dyn ReminderPort> =
+                        Arc::new(SqliteReminderStore::new(pool));
+                    info!(
+                        "✅ Database initialized with conversation, approval, and reminder stores"
+                    );
+                    (
+                        Some(Arc::new(approval_service)),
+                        Some(audit_log),
+                        Some(conversation_store),
+                        Some(database_health),
+                        Some(delivery_status),
+                        Some(draft_store),
+                        Some(reminder_store),
+                        Some(retry_queue),
+                        Some(system_prompt_store),
+                        Some(user_profile_store),
+                    )
+                },
+                Err(e) => {
+                    warn!(
+                        error = %e,
+                        "⚠️ Failed to run database migrations, persistence features disabled"
+                    );
+                    (None, None, None, None, None, None, None, None, None, None)
+                },
+            },
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    "⚠️ Failed to initialize database, persistence features disabled"
+                );
+                (None, None, None, None, None, None, None, None, None, None)
+            },
+        }
+    };
+
+    // Initialize services
+    let mut chat_service = conversation_store.as_ref().map_or_else(
+        || {
+            warn!("⚠️ ChatService running without conversation persistence");
+            ChatService::with_system_prompt(Arc::clone(&inference), SYSTEM_PROMPT)
+        },
+        |store| ChatService::with_all(Arc::clone(&inference), Arc::clone(store), SYSTEM_PROMPT),
+    );
+    if let Some(summarization) = initial_config.conversation.summarization() {
+        chat_service = chat_service.with_summarization(summarization);
+        info!(
+            threshold = summarization.threshold,
+            keep_recent = summarization.keep_recent,
+            "💬 Rolling conversation summarization enabled"
+        );
+    }
+    chat_service = chat_service.with_default_timezone(initial_config.default_timezone());
+    let template_engine = match TemplateEngine::with_config(initial_config.templates.clone()) {
+        Ok(engine) => {
+            chat_service = chat_service.with_prompt_template(Arc::new(engine.clone()));
+            info!("📝 System prompt rendered from template");
+            Some(engine)
+        },
+        Err(e) => {
+            warn!(error = %e, "⚠️ Failed to load templates, using static system prompt");
+            None
+        },
+    };
+    if let Some(ref store) = user_profile_store {
+        chat_service = chat_service.with_user_profile_store(Arc::clone(store));
+    }
+    if let Some(ref store) = system_prompt_store {
+        chat_service = chat_service.with_prompt_overrides(Arc::clone(store));
+    }
+    let chat_service = Arc::new(chat_service);
+
+    // Initialize voice message service if speech config is provided
+    let voice_message_service: Option<Arc<VoiceMessageService>> =
+        initial_config.speech.as_ref().and_then(|speech_config| {
+            match SpeechAdapter::new(speech_config.clone()) {
+                Ok(adapter) => {
+                    let speech_port: Arc<dyn SpeechPort> = Arc::new(adapter);
+                    let config = VoiceMessageConfig {
+                        min_confidence: speech_config.min_transcription_confidence,
+                        ..VoiceMessageConfig::default()
+                    };
+                    let service = VoiceMessageService::with_config(
+                        speech_port,
+                        Arc::clone(&chat_service),
+                        config,
+                    );
+                    info!("🎙️ VoiceMessageService initialized with speech support");
+                    Some(Arc::new(service))
+                },
+                Err(e) => {
+                    warn!(error = %e, "⚠️ Failed to initialize speech adapter");
+                    None
+                },
+            }
+        });
+
+    let agent_service = build_agent_service(
+        Arc::clone(&inference),
+        &initial_config,
+        AgentPorts {
+            reminder: reminder_port,
+            transit: transit_port,
+            contacts: contact_port.clone(),
+            weather: weather_port.clone(),
+            draft_store,
+            user_profile_store,
+            email_template: template_engine
+                .clone()
+                .map(|engine| Arc::new(engine) as Arc<dyn EmailTemplatePort>),
+        },
+    )
+    .await;
+
+    // Initialize metrics collector
+    let metrics = Arc::new(MetricsCollector::new());
+    // Detached: runs for the lifetime of the server
+    let _circuit_metrics_handle =
+        spawn_circuit_breaker_metrics_task(circuit_events, Arc::clone(&metrics));
+
+    // Build HealthService with all available ports
+    let mut health_service =
+        HealthService::new(Arc::clone(&inference)).with_config(health_config.to_health_config());
+    if let Some(ref database) = database_health_port {
+        health_service = health_service.with_database(Arc::clone(database));
+    }
+    if let Some(ref email) = email_port {
+        health_service = health_service.with_email(Arc::clone(email));
+    }
+    if let Some(ref calendar) = calendar_port {
+        health_service = health_service.with_calendar(Arc::clone(calendar));
+    }
+    if let Some(ref weather) = weather_port {
+        health_service = health_service.with_weather(Arc::clone(weather));
+    }
+    if let Some(ref retry_queue) = retry_queue {
+        health_service = health_service.with_retry_queue(Arc::clone(retry_queue));
+    }
+    info!("❤️ HealthService initialized with all available ports");
+
+    // Initialize messenger adapter based on configuration
+    let (messenger_adapter, signal_client): (
+        Option<Arc<dyn MessengerPort>>,
+        Option<Arc<SignalClient>>,
+    ) = match initial_config.messenger {
+        MessengerSelection::WhatsApp => {
+            // Initialize WhatsApp messenger adapter
+            if let (Some(access_token), Some(phone_number_id)) = (
+                initial_config.whatsapp.access_token.as_ref(),
+                initial_config.whatsapp.phone_number_id.as_ref(),
+            ) {
+                let client_config = WhatsAppClientConfig {
+                    access_token: access_token.expose_secret().to_string(),
+                    phone_number_id: phone_number_id.clone(),
+                    app_secret: initial_config
+                        .whatsapp
+                        .app_secret
+                        .as_ref()
+                        .map(|s| s.expose_secret().to_string())
+                        .unwrap_or_default(),
+                    verify_token: initial_config
+                        .whatsapp
+                        .verify_token
+                        .clone()
+                        .unwrap_or_default(),
+                    signature_required: initial_config.whatsapp.signature_required,
+                    api_version: initial_config.whatsapp.api_version.clone(),
+                };
+
+                match WhatsAppMessengerAdapter::with_whitelist(
+                    client_config,
+                    initial_config.whatsapp.whitelist.clone(),
+                ) {
+                    Ok(adapter) => {
+                        info!("📱 WhatsApp messenger adapter initialized");
+                        (Some(Arc::new(adapter) as Arc<dyn MessengerPort>), None)
+                    },
+                    Err(e) => {
+                        warn!(error = %e, "⚠️ Failed to initialize WhatsApp adapter");
+                        (None, None)
+                    },
+                }
+            } else {
+                warn!(
+                    "⚠️ WhatsApp selected but not fully configured (missing access_token or phone_number_id)"
+                );
+                (None, None)
+            }
+        },
+        MessengerSelection::Signal => {
+            // Initialize Signal messenger adapter
+            if initial_config.signal.phone_number.is_empty() {
+                warn!("⚠️ Signal selected but not configured (missing phone_number)");
+                (None, None)
+            } else {
+                let client_config = SignalClientConfig {
+                    phone_number: initial_config.signal.phone_number.clone(),
+                    socket_path: initial_config.signal.socket_path.clone(),
+                    data_path: initial_config.signal.data_path.clone(),
+                    timeout_ms: initial_config.signal.timeout_ms,
+                };
+
+                let signal_client = Arc::new(SignalClient::with_whitelist(
+                    client_config.clone(),
+                    initial_config.signal.whitelist.clone(),
+                ));
+
+                let adapter = SignalMessengerAdapter::with_whitelist(
+                    client_config,
+                    initial_config.signal.whitelist.clone(),
+                );
+                info!("📱 Signal messenger adapter initialized");
+                (
+                    Some(Arc::new(adapter) as Arc<dyn MessengerPort>),
+                    Some(signal_client),
+                )
+            }
+        },
+        MessengerSelection::None => {
+            info!("📵 No messenger integration configured");
+            (None, None)
+        },
+    };
+
+    // Initialize prompt security services if enabled
+    let (prompt_sanitizer, suspicious_activity_tracker): (
+        Option<Arc<PromptSanitizer>>,
+        Option<Arc<dyn SuspiciousActivityPort>>,
+    ) = if initial_config.prompt_security.enabled {
+        let sanitizer = PromptSanitizer::with_config(
+            initial_config.prompt_security.to_prompt_security_config(),
+        );
+        let tracker = InMemorySuspiciousActivityTracker::new(
+            initial_config
+                .prompt_security
+                .to_suspicious_activity_config(),
+        );
+        info!(
+            sensitivity = %initial_config.prompt_security.sensitivity,
+            report_only = initial_config.prompt_security.report_only,
+            allowlisted_phrases = initial_config.prompt_security.allowlist.len(),
+            "🛡️ Prompt security enabled"
+        );
+        (
+            Some(Arc::new(sanitizer)),
+            Some(Arc::new(tracker) as Arc<dyn SuspiciousActivityPort>),
+        )
+    } else {
+        info!("⚠️ Prompt security disabled");
+        (None, None)
+    };
+
+    // Spawn conversation cleanup task if retention is configured
+    let _conversation_cleanup_handle = if let Some(ref store) = conversation_store {
+        // Get retention days from the active messenger's persistence config
+        let retention_days = match initial_config.messenger {
+            MessengerSelection::WhatsApp => initial_config.whatsapp.persistence.retention_days,
+            MessengerSelection::Signal => initial_config.signal.persistence.retention_days,
+            MessengerSelection::None => None,
+        };
+
+        retention_days.map_or_else(
+            || {
+                debug!("Conversation retention not configured, cleanup task disabled");
+                None
+            },
+            |days| {
+                info!(
+                    retention_days = days,
+                    "🗑️ Conversation retention cleanup enabled"
+                );
+                Some(spawn_conversation_cleanup_task(
+                    Arc::clone(store),
+                    days,
+                    None, // Use default 1-hour interval
+                ))
+            },
+        )
+    } else {
+        None
+    };
+
+    // Redeliver outgoing messages that failed to send. The worker uses the
+    // raw adapter; everything else sends through the queueing decorator.
+    let messenger_adapter = match (&retry_queue, messenger_adapter) {
+        (Some(queue), Some(messenger)) => {
+            // Detached: runs for the lifetime of the server
+            let _retry_worker_handle = spawn_retry_worker_task(
+                Arc::clone(queue),
+                Arc::clone(&messenger),
+                Arc::clone(&metrics),
+                None,
+            );
+            info!("📮 Retry queue worker enabled");
+            Some(Arc::new(QueuedMessenger::new(messenger, Arc::clone(queue)))
+                as Arc<dyn MessengerPort>)
+        },
+        (_, messenger) => messenger,
+    };
+
+    // Wrap agent_service in Arc before state creation so we can share it
+    let agent_service = Arc::new(agent_service);
+
+    // Spawn Signal auto-polling task if enabled
+    let _signal_polling_handle = if initial_config.signal.auto_poll {
+        if let Some(ref sc) = signal_client {
+            info!(
+                interval_secs = initial_config.signal.poll_interval_secs,
+                "📡 Signal auto-polling enabled"
+            );
+            Some(spawn_signal_polling_task(
+                Arc::clone(sc),
+                Arc::clone(&agent_service),
+                conversation_store.clone(),
+                voice_message_service.clone(),
+                initial_config.signal.voice_replies,
+                delivery_status.clone(),
+                retry_queue.clone(),
+                Duration::from_secs(initial_config.signal.poll_interval_secs),
+            ))
+        } else {
+            debug!("Signal auto-polling enabled but no Signal client — skipping");
+            None
+        }
+    } else {
+        debug!("Signal auto-polling disabled");
+        None
+    };
+
+    // Push the morning briefing to whitelisted contacts; the scheduler runs
+    // its jobs for as long as it is kept alive
+    let _digest_scheduler = match messenger_adapter {
+        Some(ref messenger) => {
+            start_daily_digest(
+                &initial_config,
+                Arc::clone(&agent_service),
+                Arc::clone(messenger),
+                template_engine,
+                conversation_store.clone(),
+                delivery_status.clone(),
+            )
+            .await
+        },
+        None => None,
+    };
+
+    // Notifies long-lived connections (WebSockets) of graceful shutdown
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+    // Create app state with reloadable config
+    let state = AppState {
+        chat_service: Arc::clone(&chat_service),
+        agent_service,
+        approval_service,
+        health_service: Some(Arc::new(health_service)),
+        voice_message_service,
+        config: reloadable_config.clone(),
+        metrics,
+        messenger_adapter,
+        signal_client,
+        prompt_sanitizer,
+        suspicious_activity_tracker,
+        conversation_store,
+        secret_store,
+        contact_service: contact_port,
+        degraded_mode: Some(degraded_status),
+        audit_log,
+        delivery_status,
+        retry_queue,
+        system_prompt_store,
+        model_registry,
+        shutdown: Some(shutdown_rx),
+        warmup,
+        started_at: Instant::now(),
+    };
+
+    let http_metrics_layer = HttpMetricsLayer::new(Arc::clone(&state.metrics));
+
+    // Build router
+    let app = routes::create_router(state);
+    #[cfg(feature = "chaos")]
+    let app = app.layer(axum::Extension(chaos));
+
+    // Origins are read from the reloadable config on every request
+    if initial_config.server.allowed_origins.is_empty() {
+        // Development mode: allow all origins
+        warn!(
+            "⚠️ CORS configured to allow ANY origin - not recommended for production. \
+             Set 'server.allowed_origins' in config.toml to restrict access."
+        );
+    }
+    let cors_layer = reloadable_cors_layer(reloadable_config.clone());
+
+    // Configure rate limiter with trusted proxy support
+    let rate_limiter = RateLimiterLayer::new(&RateLimiterConfig {
+        enabled: initial_config.security.rate_limit_enabled,
+        requests_per_minute: initial_config.security.rate_limit_rpm,
+        trusted_proxies: initial_config.security.trusted_proxies.clone(),
+        per_user: initial_config.security.rate_limit_per_user,
+    });
+
+    // Apply reloaded rate limit and degraded mode settings without restart
+    // Detached: runs for the lifetime of the server
+    let _live_settings_handle = spawn_live_settings_task(
+        reloadable_config,
+        LiveSettings::new()
+            .with_rate_limiter(rate_limiter.state())
+            .with_degraded_mode(degraded_mode),
+    );
+
+    // Spawn rate limiter cleanup task
+    let rate_limiter_state = rate_limiter.state();
+    let _cleanup_handle = spawn_cleanup_task(
+        rate_limiter_state,
+        Duration::from_secs(initial_config.security.rate_limit_cleanup_interval_secs),
+        Duration::from_secs(initial_config.security.rate_limit_cleanup_max_age_secs),
+    );
+
+    // Configure API key auth from hashed API keys. With JWT auth configured,
+    // an empty key list must not disable authentication.
+    let auth_layer =
+        if initial_config.security.api_keys.is_empty() && !initial_config.security.has_jwt() {
+            ApiKeyAuthLayer::disabled()
+        } else {
+            ApiKeyAuthLayer::from_api_keys(initial_config.security.api_keys.clone())
+                .require_credentials()
+        }
+        .allow_ips_for_paths(
+            vec!["/metrics".to_string()],
+            &initial_config.security.metrics_allowed_ips,
+        )
+        .with_admin_users(&initial_config.security.admin_user_ids);
+    if !initial_config.security.metrics_allowed_ips.is_empty() {
+        info!(
+            count = initial_config.security.metrics_allowed_ips.len(),
+            "📊 Metrics endpoints open to allowlisted IPs"
+        );
+    }
+
+    // JWT auth runs in front of API key auth; non-JWT credentials fall through
+    let jwt_layer = match initial_config.security.jwt {
+        Some(ref jwt_config) => {
+            let validator = Arc::new(JwtValidator::from_config(jwt_config));
+            if let Some(jwks) = validator.jwks() {
+                let _jwks_refresh_handle = spawn_jwks_refresh_task(
+                    Arc::clone(jwks),
+                    Duration::from_secs(jwt_config.jwks_refresh_secs),
+                );
+            }
+            info!(
+                jwks = jwt_config.jwks_url.is_some(),
+                shared_secret = jwt_config.secret.is_some(),
+                "🔑 JWT authentication enabled"
+            );
+            JwtAuthLayer::new(validator)
+                .with_api_key_fallback()
+                .with_admin_users(&initial_config.security.admin_user_ids)
+        },
+        None => JwtAuthLayer::disabled(),
+    };
+
+    // Add middleware (order matters: first added = outermost)
+    // Request ID layer is outermost to ensure all logs have the correlation ID
+    // Body limit is applied early to reject oversized requests fast
+    // Security headers is innermost to ensure they're always added
+    let app = app
+        .layer(RequestIdLayer::new())
+        .layer(TraceLayer::new_for_http())
+        .layer(http_metrics_layer)
+        .layer(cors_layer)
+        .layer(TimeoutLayer::new(Duration::from_secs(
+            initial_config.server.request_timeout_secs,
+        )))
+        .layer(RequestBodyLimitLayer::new(
+            initial_config.server.max_body_size_json_bytes,
+        ))
+        .layer(rate_limiter)
+        .layer(auth_layer)
+        .layer(jwt_layer)
+        .layer(SecurityHeadersLayer::new());
+
+    info!(
+        max_body_size_bytes = initial_config.server.max_body_size_json_bytes,
+        "📦 Request body size limit enabled"
+    );
+    info!(
+        timeout_secs = initial_config.server.request_timeout_secs,
+        "⏱️ Request timeout enabled"
+    );
+    info!("🔒 Security headers middleware enabled");
+
+    // Start server
+    let addr = format!(
+        "{}:{}",
+        initial_config.server.host, initial_config.server.port
+    );
+    let listener = TcpListener::bind(&addr).await?;
+
+    info!("🚀 Server listening on http://{}", addr);
+    info!("📚 API docs: http://{}/health", addr);
+    info!("🔄 SIGHUP for config reload is enabled (Unix only)");
+
+    // Graceful shutdown configuration
+    let shutdown_timeout =
+        Duration::from_secs(initial_config.server.shutdown_timeout_secs.unwrap_or(30));
+
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal(shutdown_timeout, shutdown_tx))
+    .await?;
+
+    info!("👋 Server shutdown complete");
+
+    Ok(())
+}
+
+/// Wait for shutdown signals (SIGINT, SIGTERM) and handle graceful shutdown
+///
+/// Open WebSocket connections are notified through `shutdown_tx` so they can
+/// close cleanly; axum does not drain upgraded connections on its own.
+#[allow(clippy::expect_used)]
+async fn shutdown_signal(timeout: Duration, shutdown_tx: watch::Sender<bool>) {
+    let ctrl_c = async {
+        // Log error but continue waiting - this is a best-effort signal handler
+        if let Err(e) = signal::ctrl_c().await {
+            tracing::error!("Failed to install Ctrl+C handler: {}", e);
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        // unwrap is acceptable here as failure to install signal handler is unrecoverable
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            },
+            Err(e) => {
+                tracing::error!("Failed to install SIGTERM handler: {}", e);
+                std::future::pending::<()>().await;
+            },
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {
+            info!("📥 Received Ctrl+C, initiating graceful shutdown...");
+        }
+        () = terminate => {
+            info!("📥 Received SIGTERM, initiating graceful shutdown...");
+        }
+    }
+
+    shutdown_tx.send_replace(true);
+    info!("⏳ Waiting up to {:?} for connections to close...", timeout);
+    // Note: The actual connection draining is handled by axum's graceful_shutdown
+}
+
+/// Open the database, restoring the latest backup if it is corrupted
+///
+/// The startup integrity check only runs when `restore_backup_dir` is
+/// configured, as it reads the whole file.
+async fn open_database(
+    config: &infrastructure::config::DatabaseConfig,
+) -> Result<AsyncDatabase, AsyncDatabaseError> {
+    let db_config = AsyncDatabaseConfig::file(&config.path);
+    let db = AsyncDatabase::new(&db_config).await?;
+
+    let Some(ref backup_dir) = config.restore_backup_dir else {
+        return Ok(db);
+    };
+    match db.integrity_check().await {
+        Ok(true) => return Ok(db),
+        Ok(false) => {},
+        // Only a confirmed corruption replaces the database file
+        Err(e) => {
+            warn!(
+                error = %e,
+                "⚠️ Database integrity check could not run, keeping the existing database"
+            );
+            return Ok(db);
+        },
+    }
+
+    error!("🚨 CRITICAL: Database integrity check failed at startup");
+    db.close().await;
+    match AsyncDatabase::restore_latest_backup(&config.path, backup_dir).await {
+        Ok(Some(backup)) => {
+            warn!(backup = %backup.display(), "♻️ Database restored from latest backup");
+        },
+        Ok(None) => {
+            warn!(
+                backup_dir = %backup_dir,
+                "⚠️ No backup available, continuing with the existing database"
+            );
+        },
+        Err(e) => error!(error = %e, "Failed to restore database backup"),
+    }
+
+    AsyncDatabase::new(&db_config).await
+}
+
+/// CORS layer that applies the current config's policy for each route
+///
+/// The policy for the request path (see `ServerConfig::cors_policy`) is read
+/// on every request, so changes to `server.allowed_origins`,
+/// `server.cors_routes` and `server.cors_max_age_secs` apply on reload.
+fn reloadable_cors_layer(config: ReloadableConfig) -> CorsLayer {
+    let origin_config = config.clone();
+    let credentials_config = config.clone();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(move |origin, parts| {
+            origin_allowed(&origin_config.load().server.cors_policy(parts.uri.path()), origin)
+        }))
+        .allow_credentials(AllowCredentials::predicate(move |_, parts| {
+            credentials_config
+                .load()
+                .server
+                .cors_policy(parts.uri.path())
+                .allows_credentials()
+        }))
+        .max_age(MaxAge::dynamic(move |_, parts| {
+            Duration::from_secs(config.load().server.cors_policy(parts.uri.path()).max_age_secs)
+        }))
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        // Mirrored rather than `*`, which browsers ignore on credentialed requests
+        .allow_headers(AllowHeaders::mirror_request())
+        // Let browser clients read their quota
+        .expose_headers([
+            RATELIMIT_LIMIT,
+            RATELIMIT_REMAINING,
+            RATELIMIT_RESET,
+            header::RETRY_AFTER,
+        ])
+}
+
+/// Whether the policy accepts `origin`
+fn origin_allowed(policy: &CorsPolicy<'_>, origin: &HeaderValue) -> bool {
+    origin
+        .to_str()
+        .is_ok_and(|origin| policy.allows_origin(origin))
+}
+
+/// Schedule the daily digest push if it is enabled
+///
+/// Recipients are the owner's numbers from `digest.recipients`. Returns the
+/// scheduler running the push, or `None` if the digest is disabled or could
+/// not be scheduled.
+async fn start_daily_digest(
+    config: &AppConfig,
+    agent_service: Arc<AgentService>,
+    messenger: Arc<dyn MessengerPort>,
+    template_engine: Option<TemplateEngine>,
+    conversation_store: Option<Arc<dyn ConversationStore>>,
+    delivery_status: Option<Arc<dyn DeliveryStatusPort>>,
+) -> Option<TaskScheduler> {
+    let digest = config.digest.as_ref().filter(|digest| digest.enabled)?;
+    if digest.recipients.is_empty() {
+        warn!("⚠️ Daily digest enabled but digest.recipients is empty");
+        return None;
+    }
+
+    let recipient_count = digest.recipients.len();
+    let mut service = DailyDigestService::new(
+        agent_service,
+        messenger,
+        digest.to_digest_config(config.default_timezone()),
+    );
+    if let Some(engine) = template_engine {
+        service = service.with_template_engine(Arc::new(engine));
+    }
+    if let Some(store) = conversation_store {
+        service = service.with_conversation_store(store);
+    }
+    if let Some(delivery_status) = delivery_status {
+        service = service.with_delivery_status(delivery_status);
+    }
+
+    let schedule = digest.schedule.as_ref().map_or_else(
+        || TaskBuilder::new().morning_briefing_cron,
+        |cron| {
+            TaskBuilder::new()
+                .morning_briefing_schedule(cron)
+                .morning_briefing_cron
+        },
+    );
+    let scheduler = match TaskScheduler::new(SchedulerConfig::default()).await {
+        Ok(scheduler) => scheduler,
+        Err(e) => {
+            warn!(error = %e, "⚠️ Failed to start scheduler, daily digest disabled");
+            return None;
+        },
+    };
+    if let Err(e) = scheduler
+        .add_task(
+            DAILY_DIGEST_TASK,
+            &schedule,
+            create_daily_digest_task(Arc::new(service)),
+        )
+        .await
+    {
+        warn!(error = %e, "⚠️ Failed to schedule daily digest");
+        return None;
+    }
+
+    info!(
+        schedule = %schedule,
+        recipients = recipient_count,
+        "📬 Daily digest scheduled"
+    );
+    Some(scheduler)
+}
+
+/// Load the encryption key for conversation storage
+///
+/// Encryption is enabled through the active messenger's persistence config and
+/// shares the key file of the memory system. Returns `None` when encryption is
+/// disabled. A key that cannot be loaded fails startup rather than silently
+/// storing messages unencrypted.
+fn load_conversation_encryption(
+    config: &AppConfig,
+) -> anyhow::Result<Option<Arc<dyn EncryptionPort>>> {
+    let enabled = match config.messenger {
+        MessengerSelection::WhatsApp => config.whatsapp.persistence.enable_encryption,
+        MessengerSelection::Signal => config.signal.persistence.enable_encryption,
+        MessengerSelection::None => false,
+    };
+    if !enabled {
+        debug!("Conversation encryption disabled");
+        return Ok(None);
+    }
+
+    let key_path = config.memory.as_ref().map_or_else(
+        || infrastructure::config::MemoryAppConfig::default().encryption_key_path,
+        |memory| memory.encryption_key_path.clone(),
+    );
+
+    let adapter =
+        ChaChaEncryptionAdapter::from_key_file_or_generate(std::path::Path::new(&key_path))
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Conversation encryption is enabled but the key could not be loaded: {e}"
+                )
+            })?;
+    info!("🔐 Conversation messages will be encrypted at rest");
+    Ok(Some(Arc::new(adapter)))
+}
+
+/// Optional ports the agent service is wired with
+///
+/// Commands whose port is missing answer with a "not configured" message.
+#[derive(Default)]
+pub struct AgentPorts {
+    /// Reminder storage
+    pub reminder: Option<Arc<dyn ReminderPort>>,
+    /// Public transit routing
+    pub transit: Option<Arc<dyn TransitPort>>,
+    /// Contact lookup
+    pub contacts: Option<Arc<dyn ContactPort>>,
+    /// Weather forecasts, exposed as a tool
+    pub weather: Option<Arc<dyn WeatherPort>>,
+    /// Persistent email drafts for `draft_email` and `send_email`
+    pub draft_store: Option<Arc<dyn DraftStorePort>>,
+    /// Per-user profiles (timezone, language)
+    pub user_profile_store: Option<Arc<dyn UserProfileStore>>,
+    /// Templates for rendering email drafts
+    pub email_template: Option<Arc<dyn EmailTemplatePort>>,
+}
+
+/// Build the agent service from configuration and the available ports
+pub async fn build_agent_service(
+    inference: Arc<dyn InferencePort>,
+    config: &AppConfig,
+    ports: AgentPorts,
+) -> AgentService {
+    let mut agent_service = AgentService::new(inference)
+        .with_default_language(config.parser_language())
+        .with_default_timezone(config.default_timezone());
+    if let Some(ref reminder_config) = config.reminder {
+        agent_service =
+            agent_service.with_default_briefing_sections(reminder_config.briefing_sections.clone());
+    }
+    if let Some(reminder) = ports.reminder {
+        agent_service = agent_service.with_reminder_service(reminder);
+        info!("📋 AgentService configured with reminder support");
+    }
+    if let Some(transit) = ports.transit {
+        agent_service = agent_service.with_transit_service(transit);
+        info!("🚇 AgentService configured with transit support");
+    }
+    // Get home location from transit config for route calculations
+    let home_location = config
+        .transit
+        .as_ref()
+        .and_then(|t| t.home_location.as_ref())
+        .and_then(infrastructure::config::GeoLocationConfig::to_geo_location);
+    if let Some(location) = home_location {
+        agent_service = agent_service.with_home_location(location);
+        info!("🏠 AgentService configured with home location");
+    }
+    if let Some(transit_config) = config
+        .transit
+        .as_ref()
+        .filter(|t| t.suggest_departure_in_briefing)
+    {
+        agent_service =
+            agent_service.with_briefing_departure_suggestion(transit_config.arrival_buffer_minutes);
+        info!("🚶 AgentService configured with briefing departure suggestions");
+    }
+    if let Some(contacts) = ports.contacts {
+        agent_service = agent_service.with_contact_service(contacts);
+        info!("📇 AgentService configured with contact support");
+    }
+    if let Some(store) = ports.draft_store {
+        agent_service = agent_service.with_draft_store(store);
+        info!("📝 AgentService configured with persistent email drafts");
+    }
+    if let Some(template) = ports.email_template {
+        agent_service = agent_service.with_email_template(template);
+        info!("✉️ AgentService configured with email draft templates");
+    }
+    if let Some(store) = ports.user_profile_store {
+        agent_service = agent_service.with_user_profile_store(store);
+    }
+    if let Some(weather) = ports.weather {
+        let mut tool = WeatherTool::new(weather);
+        let default_location = config
+            .weather
+            .as_ref()
+            .and_then(|w| w.default_location.as_ref())
+            .and_then(infrastructure::config::GeoLocationConfig::to_geo_location)
+            .or(home_location);
+        if let Some(location) = default_location {
+            tool = tool.with_default_location(location);
+        }
+        agent_service = agent_service.with_tool(Arc::new(tool));
+        info!("🌤️ AgentService configured with weather tool");
+    }
+    if let Some(router) = build_semantic_router(config).await {
+        agent_service = agent_service.with_semantic_router(router);
+        info!("🧭 AgentService configured with semantic command routing");
+    }
+    agent_service
+}
+
+/// Build the embedding-based semantic command router
+///
+/// Routing is opt-in through `[memory.semantic_routing]` and reuses the
+/// embedding model of the memory system. Canonical intent phrases are embedded
+/// once here; returns `None` when routing is disabled or embedding fails, in
+/// which case every command goes through the LLM parser.
+async fn build_semantic_router(config: &AppConfig) -> Option<Arc<SemanticRouter>> {
+    let memory = config.memory.as_ref()?;
+    let router_config = memory.semantic_router_config()?;
+    let embedding_config = memory.to_embedding_config(&config.inference.base_url);
+
+    let adapter = match OllamaEmbeddingAdapter::new(embedding_config) {
+        Ok(adapter) => adapter,
+        Err(e) => {
+            warn!(error = %e, "⚠️ Failed to create embedding adapter, semantic routing disabled");
+            return None;
+        },
+    };
+
+    match SemanticRouter::build(Arc::new(adapter), router_config).await {
+        Ok(router) => Some(Arc::new(router)),
+        Err(e) => {
+            warn!(
+                error = %e,
+                "⚠️ Failed to embed canonical intents, semantic routing disabled"
+            );
+            None
+        },
+    }
+}
+
+/// Initialize the secret store based on Vault configuration
+///
+/// Creates a `ChainedSecretStore` that tries Vault first, then falls back
+/// to environment variables (if `env_fallback` is enabled). Resolved secrets
+/// are cached for `cache_ttl_secs`.
+async fn initialize_secret_store(
+    config: &AppConfig,
+) -> Result<Arc<dyn SecretStorePort>, anyhow::Error> {
+    let vault_config = config.vault.to_vault_config();
+    let vault_store = Arc::new(VaultSecretStore::new(vault_config).await?);
+    let _renewal_handle = vault_store.spawn_token_renewal();
+
+    let store: Arc<dyn SecretStorePort> = if config.vault.env_fallback {
+        let env_prefix = config.vault.env_prefix.as_deref().unwrap_or("PISOVEREIGN");
+        let env_store = EnvSecretStore::with_prefix(env_prefix);
+
+        info!("Secret store: Vault → environment variable fallback chain");
+        Arc::new(ChainedSecretStore::new(vec![
+            vault_store,
+            Arc::new(env_store),
+        ]))
+    } else {
+        info!("Secret store: Vault only (no fallback)");
+        vault_store
+    };
+
+    if config.vault.cache_ttl_secs == 0 {
+        return Ok(store);
+    }
+
+    info!(
+        ttl_secs = config.vault.cache_ttl_secs,
+        "Secret cache enabled"
+    );
+    Ok(Arc::new(CachingSecretStore::new(
+        store,
+        Duration::from_secs(config.vault.cache_ttl_secs),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_options_default_has_no_overrides() {
+        let options = ServeOptions::default();
+        assert!(options.config_path.is_none());
+        assert!(options.port.is_none());
+    }
+
+    #[test]
+    fn serve_options_port_override() {
+        let config = ServeOptions::default()
+            .with_port(4321)
+            .load_config()
+            .unwrap();
+        assert_eq!(config.server.port, 4321);
+    }
+
+    #[test]
+    fn serve_options_missing_config_file_fails() {
+        let result = ServeOptions::default()
+            .with_config_path("/nonexistent/pisovereign.toml")
+            .load_config();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn origin_allowed_matches_configured_origins() {
+        let origin = HeaderValue::from_static("https://example.com");
+        let other = HeaderValue::from_static("https://evil.example");
+        let mut server = infrastructure::ServerConfig {
+            allowed_origins: vec!["https://example.com".to_string()],
+            ..Default::default()
+        };
+
+        assert!(origin_allowed(&server.cors_policy("/v1/chat"), &origin));
+        assert!(!origin_allowed(&server.cors_policy("/v1/chat"), &other));
+
+        server.allowed_origins.clear();
+        assert!(origin_allowed(&server.cors_policy("/v1/chat"), &other));
+    }
+
+    #[tokio::test]
+    async fn cors_layer_applies_route_policies() {
+        use axum::{Router, body::Body, http::Request, routing::get};
+        use tower::ServiceExt;
+
+        let config = AppConfig {
+            server: infrastructure::ServerConfig {
+                allowed_origins: vec!["https://app.example.com".to_string()],
+                cors_max_age_secs: 900,
+                cors_routes: vec![
+                    infrastructure::CorsRouteConfig {
+                        path_prefix: "/webhook".to_string(),
+                        enabled: false,
+                        allowed_origins: None,
+                        allow_credentials: false,
+                        max_age_secs: None,
+                    },
+                    infrastructure::CorsRouteConfig {
+                        path_prefix: "/v1".to_string(),
+                        enabled: true,
+                        allowed_origins: None,
+                        allow_credentials: true,
+                        max_age_secs: None,
+                    },
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let app = Router::new()
+            .route("/v1/chat", get(|| async { "ok" }))
+            .route("/webhook/whatsapp", get(|| async { "ok" }))
+            .layer(reloadable_cors_layer(ReloadableConfig::new(config)));
+        let preflight = |uri: &str| {
+            Request::builder()
+                .method(Method::OPTIONS)
+                .uri(uri)
+                .header(header::ORIGIN, "https://app.example.com")
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let spa = app.clone().oneshot(preflight("/v1/chat")).await.unwrap();
+        let headers = spa.headers();
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://app.example.com"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(headers[header::ACCESS_CONTROL_MAX_AGE], "900");
+
+        let webhook = app.oneshot(preflight("/webhook/whatsapp")).await.unwrap();
+        assert!(
+            !webhook
+                .headers()
+                .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+    }
+}
@@ -1,23 +1,332 @@
 //! Hot-reloadable configuration support
 //!
 //! Provides SIGHUP signal handling for runtime configuration reload
-//! without server restart.
+//! without server restart. Rotated secrets are pushed into the adapters
+//! through [`RotatingSecrets`] on every reload, and [`LiveSettings`] updates
+//! running components such as the rate limiter. Only the fields listed in
+//! [`HOT_RELOADABLE_FIELDS`] take effect without a restart.
 
-use std::sync::Arc;
+use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
 
+use application::ports::SecretStorePort;
 use arc_swap::ArcSwap;
-use infrastructure::AppConfig;
+use infrastructure::{
+    AppConfig, SharedSecret,
+    adapters::{DegradedModeConfig, DegradedModeMonitor},
+    config::DegradedModeAppConfig,
+};
+use secrecy::{ExposeSecret, SecretString};
+use serde_json::Value;
 use tokio::sync::watch;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
+
+use crate::middleware::RateLimiterState;
+
+/// Config fields (or whole sections) that take effect on reload
+///
+/// Everything else, e.g. `server.port` or `database.path`, is read once at
+/// startup and needs a restart. Secrets resolved from the secret store are
+/// handled by [`RotatingSecrets`].
+pub const HOT_RELOADABLE_FIELDS: &[&str] = &[
+    "security.rate_limit_rpm",
+    "server.allowed_origins",
+    "server.cors_routes",
+    "server.cors_max_age_secs",
+    "degraded_mode",
+    "signal.voice_replies",
+    "whatsapp.verify_token",
+    "whatsapp.signature_required",
+];
+
+/// Whether a changed field takes effect without a restart
+#[must_use]
+pub fn is_hot_reloadable(field: &str) -> bool {
+    HOT_RELOADABLE_FIELDS.iter().any(|hot| {
+        field == *hot
+            || field
+                .strip_prefix(hot)
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// Dotted paths of the config values that differ between two configs
+///
+/// Secrets are not serialized and therefore never reported.
+#[must_use]
+pub fn changed_fields(old: &AppConfig, new: &AppConfig) -> Vec<String> {
+    let (Ok(old), Ok(new)) = (serde_json::to_value(old), serde_json::to_value(new)) else {
+        return Vec::new();
+    };
+    let mut changed = Vec::new();
+    diff_values("", &old, &new, &mut changed);
+    changed
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, changed: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => diff_values(&field, old, new, changed),
+                    _ => changed.push(field),
+                }
+            }
+        },
+        _ if old != new => changed.push(path.to_string()),
+        _ => {},
+    }
+}
+
+/// Running components that are updated in place after a reload
+///
+/// CORS settings are not listed here; the CORS layer reads them from the
+/// [`ReloadableConfig`] on every request.
+#[derive(Clone, Default)]
+pub struct LiveSettings {
+    rate_limiter: Option<Arc<RateLimiterState>>,
+    degraded_mode: Option<Arc<dyn DegradedModeMonitor>>,
+}
+
+impl std::fmt::Debug for LiveSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveSettings")
+            .field("rate_limiter", &self.rate_limiter)
+            .field("degraded_mode", &self.degraded_mode.is_some())
+            .finish()
+    }
+}
+
+impl LiveSettings {
+    /// Create an empty set of live settings
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update the rate limit from `security.rate_limit_rpm`
+    #[must_use]
+    pub fn with_rate_limiter(mut self, state: Arc<RateLimiterState>) -> Self {
+        self.rate_limiter = Some(state);
+        self
+    }
+
+    /// Update thresholds, cooldown and message from `degraded_mode`
+    #[must_use]
+    pub fn with_degraded_mode(mut self, monitor: Arc<dyn DegradedModeMonitor>) -> Self {
+        self.degraded_mode = Some(monitor);
+        self
+    }
+
+    /// Apply the hot-reloadable values of `config` to the components
+    pub fn apply(&self, config: &AppConfig) {
+        if let Some(state) = &self.rate_limiter {
+            let rpm = config.security.rate_limit_rpm;
+            if state.requests_per_minute() != rpm {
+                state.set_requests_per_minute(rpm);
+                info!(requests_per_minute = rpm, "Rate limit updated");
+            }
+        }
+        if let Some(monitor) = &self.degraded_mode {
+            monitor.update_config(config.degraded_mode.as_ref().map_or_else(
+                DegradedModeConfig::default,
+                DegradedModeAppConfig::to_degraded_mode_config,
+            ));
+        }
+    }
+}
+
+/// Spawn a background task that applies [`LiveSettings`] after every reload
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+pub fn spawn_live_settings_task(
+    config: ReloadableConfig,
+    settings: LiveSettings,
+) -> tokio::task::JoinHandle<()> {
+    let mut changes = config.subscribe();
+    tokio::spawn(async move {
+        while changes.changed().await.is_ok() {
+            debug!("Applying reloaded configuration to running components");
+            settings.apply(&config.load());
+        }
+    })
+}
+
+/// Secrets shared with adapters that are replaced on reload
+///
+/// Secrets resolved from the secret store are also re-read periodically by
+/// [`refresh`](Self::refresh), see [`store_paths`](Self::store_paths).
+#[derive(Debug, Clone, Default)]
+pub struct RotatingSecrets {
+    proton: Option<SharedSecret>,
+    caldav: Option<SharedSecret>,
+    /// Secret store paths re-read on refresh, by secret name
+    store_paths: Vec<(&'static str, String)>,
+}
+
+impl RotatingSecrets {
+    /// Create an empty set of rotating secrets
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rotate the Proton Bridge password
+    #[must_use]
+    pub fn with_proton(mut self, secret: SharedSecret) -> Self {
+        self.proton = Some(secret);
+        self
+    }
+
+    /// Rotate the CalDAV password
+    #[must_use]
+    pub fn with_caldav(mut self, secret: SharedSecret) -> Self {
+        self.caldav = Some(secret);
+        self
+    }
+
+    /// Secret store paths of the rotating secrets that `config` leaves empty
+    ///
+    /// Call before resolving secrets into `config`: passwords set in
+    /// config.toml or the environment take precedence over the store and are
+    /// not refreshed from it.
+    #[must_use]
+    pub fn store_paths(config: &AppConfig) -> Vec<(&'static str, String)> {
+        let prefix = &config.vault.secret_prefix;
+        [
+            ("proton", config.proton.as_ref().map(|c| &c.password)),
+            ("caldav", config.caldav.as_ref().map(|c| &c.password)),
+        ]
+        .into_iter()
+        .filter(|(_, password)| password.is_some_and(|p| p.expose_secret().is_empty()))
+        .map(|(name, _)| (name, format!("{prefix}/{name}")))
+        .collect()
+    }
+
+    /// Re-read these secret store paths on [`refresh`](Self::refresh)
+    #[must_use]
+    pub fn with_store_paths(mut self, paths: Vec<(&'static str, String)>) -> Self {
+        self.store_paths = paths;
+        self
+    }
+
+    /// Whether [`refresh`](Self::refresh) has any secret to re-read
+    #[must_use]
+    pub fn has_store_paths(&self) -> bool {
+        self.store_paths
+            .iter()
+            .any(|(name, _)| self.shared(name).is_some())
+    }
+
+    fn shared(&self, name: &str) -> Option<&SharedSecret> {
+        match name {
+            "proton" => self.proton.as_ref(),
+            "caldav" => self.caldav.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Re-read the store paths and push changed passwords into the adapters
+    ///
+    /// Each path goes through [`SecretStorePort::refresh_json`], which
+    /// bypasses and updates the secret cache for that path only. Failed or
+    /// empty lookups keep the current value. Returns the number of secrets
+    /// that changed.
+    pub async fn refresh(&self, store: &dyn SecretStorePort) -> usize {
+        let mut rotated = 0;
+        for (name, path) in &self.store_paths {
+            let Some(shared) = self.shared(name) else {
+                continue;
+            };
+            let json = match store.refresh_json(path).await {
+                Ok(json) => json,
+                Err(e) => {
+                    warn!(path = %path, error = %e, "Failed to refresh secret, keeping current value");
+                    continue;
+                },
+            };
+            let Some(password) = json
+                .get("password")
+                .and_then(Value::as_str)
+                .filter(|password| !password.is_empty())
+            else {
+                continue;
+            };
+            if shared.replace(SecretString::from(password.to_owned())) {
+                info!(secret = *name, "🔑 Secret rotated");
+                rotated += 1;
+            }
+        }
+        rotated
+    }
+
+    /// Push the secrets of a freshly resolved config into the adapters
+    ///
+    /// Empty values are ignored so a failed lookup never wipes a working
+    /// credential. Returns the number of secrets that changed.
+    pub fn apply(&self, config: &AppConfig) -> usize {
+        let candidates = [
+            (
+                "proton",
+                self.proton.as_ref(),
+                config.proton.as_ref().map(|c| &c.password),
+            ),
+            (
+                "caldav",
+                self.caldav.as_ref(),
+                config.caldav.as_ref().map(|c| &c.password),
+            ),
+        ];
+
+        let mut rotated = 0;
+        for (name, shared, value) in candidates {
+            let (Some(shared), Some(value)) = (shared, value) else {
+                continue;
+            };
+            if value.expose_secret().is_empty() {
+                continue;
+            }
+            if shared.replace(value.clone()) {
+                info!(secret = name, "🔑 Secret rotated");
+                rotated += 1;
+            }
+        }
+        rotated
+    }
+}
 
 /// A wrapper around `AppConfig` that supports atomic reload via SIGHUP
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ReloadableConfig {
     inner: Arc<ArcSwap<AppConfig>>,
     /// Notifier for config change events
     notify: watch::Sender<u64>,
     /// Receiver for config change events
     receiver: watch::Receiver<u64>,
+    /// Explicit config file to reload from (default: `config.toml` lookup)
+    config_path: Option<PathBuf>,
+    /// Secret store used to resolve secrets into the reloaded config
+    secret_store: Option<Arc<dyn SecretStorePort>>,
+    /// Adapter secrets updated after each reload
+    rotating_secrets: Option<RotatingSecrets>,
+}
+
+impl std::fmt::Debug for ReloadableConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReloadableConfig")
+            .field("inner", &self.inner)
+            .field("notify", &self.notify)
+            .field("receiver", &self.receiver)
+            .field("config_path", &self.config_path)
+            .field("secret_store", &self.secret_store.is_some())
+            .field("rotating_secrets", &self.rotating_secrets)
+            .finish()
+    }
 }
 
 impl ReloadableConfig {
@@ -29,9 +338,33 @@ impl ReloadableConfig {
             inner: Arc::new(ArcSwap::new(Arc::new(config))),
             notify,
             receiver,
+            config_path: None,
+            secret_store: None,
+            rotating_secrets: None,
         }
     }
 
+    /// Reload from the given config file instead of the default lookup
+    #[must_use]
+    pub fn with_config_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_path = Some(path.into());
+        self
+    }
+
+    /// Resolve secrets from the given store on every reload
+    #[must_use]
+    pub fn with_secret_store(mut self, store: Arc<dyn SecretStorePort>) -> Self {
+        self.secret_store = Some(store);
+        self
+    }
+
+    /// Push rotated secrets into the adapters on every reload
+    #[must_use]
+    pub fn with_rotating_secrets(mut self, secrets: RotatingSecrets) -> Self {
+        self.rotating_secrets = Some(secrets);
+        self
+    }
+
     /// Get the current configuration
     #[must_use]
     pub fn load(&self) -> Arc<AppConfig> {
@@ -40,16 +373,47 @@ impl ReloadableConfig {
 
     /// Reload configuration from disk
     ///
+    /// Secrets are resolved from the secret store, if one is configured,
+    /// through its cache; [`RotatingSecrets::refresh`] keeps the cached
+    /// rotating secrets current. A config that
+    /// fails validation is rejected and the current one kept. The log line
+    /// lists which changed fields were applied and which need a restart.
     /// Returns `true` if the reload was successful
-    pub fn reload(&self) -> bool {
-        match AppConfig::load() {
-            Ok(new_config) => {
-                let old_config = self.inner.swap(Arc::new(new_config));
+    pub async fn reload(&self) -> bool {
+        let loaded = AppConfig::load_from(self.config_path.as_deref())
+            .map_err(|e| e.to_string())
+            .and_then(|config| {
+                config.validate().map_err(|e| e.to_string())?;
+                Ok(config)
+            });
+        match loaded {
+            Ok(mut new_config) => {
+                if let Some(store) = &self.secret_store {
+                    if let Err(e) = new_config.resolve_secrets(store.as_ref()).await {
+                        warn!(error = %e, "Secret resolution on reload completed with errors");
+                    }
+                }
+                let secrets_rotated = self
+                    .rotating_secrets
+                    .as_ref()
+                    .map_or(0, |secrets| secrets.apply(&new_config));
+                let (applied, restart_required): (Vec<_>, Vec<_>) =
+                    changed_fields(&self.load(), &new_config)
+                        .into_iter()
+                        .partition(|field| is_hot_reloadable(field));
+                self.inner.store(Arc::new(new_config));
                 info!(
-                    old_host = %old_config.server.host,
-                    old_port = %old_config.server.port,
+                    applied = ?applied,
+                    restart_required = ?restart_required,
+                    secrets_rotated,
                     "Configuration reloaded successfully"
                 );
+                if !restart_required.is_empty() {
+                    warn!(
+                        fields = ?restart_required,
+                        "Changed settings take effect after a restart"
+                    );
+                }
                 // Notify watchers (increment version)
                 let version = *self.notify.borrow() + 1;
                 if self.notify.send(version).is_err() {
@@ -91,7 +455,7 @@ pub fn spawn_config_reload_handler(config: ReloadableConfig) -> ReloadableConfig
         loop {
             sighup.recv().await;
             info!("📥 Received SIGHUP, reloading configuration...");
-            if config_clone.reload() {
+            if config_clone.reload().await {
                 info!("✅ Configuration reload complete");
             } else {
                 warn!("⚠️ Configuration reload failed, keeping previous config");
@@ -111,6 +475,8 @@ pub fn spawn_config_reload_handler(config: ReloadableConfig) -> ReloadableConfig
 
 #[cfg(test)]
 mod tests {
+    use infrastructure::config::{CalDavAppConfig, ProtonAppConfig, ProtonTlsAppConfig};
+
     use super::*;
 
     #[test]
@@ -155,4 +521,131 @@ mod tests {
         receiver.changed().await.ok();
         assert_eq!(*receiver.borrow(), 1);
     }
+
+    #[tokio::test]
+    async fn reload_from_missing_config_path_keeps_previous() {
+        let mut config = AppConfig::default();
+        config.server.port = 8080;
+        let reloadable =
+            ReloadableConfig::new(config).with_config_path("/nonexistent/pisovereign.toml");
+
+        assert!(!reloadable.reload().await);
+        assert_eq!(reloadable.load().server.port, 8080);
+    }
+
+    #[tokio::test]
+    async fn reload_rejects_invalid_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[server]\nport = 9090\nlog_format = \"xml\"\n").unwrap();
+        let mut config = AppConfig::default();
+        config.server.port = 8080;
+        let reloadable = ReloadableConfig::new(config).with_config_path(&path);
+
+        assert!(!reloadable.reload().await);
+        assert_eq!(reloadable.load().server.port, 8080);
+    }
+
+    #[test]
+    fn changed_fields_lists_dotted_paths() {
+        let old = AppConfig::default();
+        let mut new = AppConfig::default();
+        new.server.port = 9090;
+        new.security.rate_limit_rpm = 5;
+        new.degraded_mode = Some(DegradedModeAppConfig::default());
+
+        assert_eq!(
+            changed_fields(&old, &new),
+            ["degraded_mode", "security.rate_limit_rpm", "server.port"]
+        );
+        assert!(changed_fields(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn hot_reloadable_fields_match_by_prefix() {
+        assert!(is_hot_reloadable("security.rate_limit_rpm"));
+        assert!(is_hot_reloadable("degraded_mode.failure_threshold"));
+        assert!(!is_hot_reloadable("security.rate_limit_rpm_burst"));
+        assert!(!is_hot_reloadable("server.port"));
+        assert!(!is_hot_reloadable("database.path"));
+    }
+
+    #[tokio::test]
+    async fn reload_applies_live_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[security]\nrate_limit_rpm = 5\n").unwrap();
+        let reloadable = ReloadableConfig::new(AppConfig::default()).with_config_path(&path);
+        let rate_limiter = Arc::new(RateLimiterState::new(60));
+        let handle = spawn_live_settings_task(
+            reloadable.clone(),
+            LiveSettings::new().with_rate_limiter(Arc::clone(&rate_limiter)),
+        );
+
+        assert!(reloadable.reload().await);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        handle.abort();
+
+        assert_eq!(rate_limiter.requests_per_minute(), 5);
+    }
+
+    fn config_with_passwords(proton: &str, caldav: &str) -> AppConfig {
+        AppConfig {
+            proton: Some(ProtonAppConfig {
+                imap_host: "127.0.0.1".to_string(),
+                imap_port: 1143,
+                smtp_host: "127.0.0.1".to_string(),
+                smtp_port: 1025,
+                email: "me@proton.me".to_string(),
+                password: proton.into(),
+                tls: ProtonTlsAppConfig::default(),
+            }),
+            caldav: Some(CalDavAppConfig {
+                server_url: "https://cal.example.com".to_string(),
+                username: "me".to_string(),
+                password: caldav.into(),
+                calendar_path: None,
+                verify_certs: true,
+                timeout_secs: 30,
+            }),
+            ..AppConfig::default()
+        }
+    }
+
+    #[test]
+    fn rotating_secrets_swap_changed_values() {
+        let proton = SharedSecret::new("old-proton".into());
+        let caldav = SharedSecret::new("caldav".into());
+        let secrets = RotatingSecrets::new()
+            .with_proton(proton.clone())
+            .with_caldav(caldav.clone());
+
+        let rotated = secrets.apply(&config_with_passwords("new-proton", "caldav"));
+
+        assert_eq!(rotated, 1);
+        assert_eq!(proton.current().expose_secret(), "new-proton");
+        assert_eq!(caldav.current().expose_secret(), "caldav");
+    }
+
+    #[test]
+    fn store_paths_skip_passwords_set_in_config() {
+        let paths = RotatingSecrets::store_paths(&config_with_passwords("", "from-file"));
+
+        assert_eq!(
+            paths,
+            vec![(
+                "proton",
+                format!("{}/proton", AppConfig::default().vault.secret_prefix)
+            )]
+        );
+    }
+
+    #[test]
+    fn rotating_secrets_ignore_empty_values() {
+        let proton = SharedSecret::new("proton".into());
+        let secrets = RotatingSecrets::new().with_proton(proton.clone());
+
+        assert_eq!(secrets.apply(&config_with_passwords("", "")), 0);
+        assert_eq!(proton.current().expose_secret(), "proton");
+    }
 }
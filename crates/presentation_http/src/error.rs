@@ -6,9 +6,10 @@
 use application::ApplicationError;
 use axum::{
     Json,
-    http::StatusCode,
+    http::{HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
 };
+use domain::entities::{PromptAnalysisResult, SecurityThreat};
 use serde::Serialize;
 use std::sync::atomic::{AtomicBool, Ordering};
 use thiserror::Error;
@@ -110,6 +111,15 @@ pub enum ApiError {
     #[error("Forbidden: {0}")]
     Forbidden(String),
 
+    /// Authenticated API key lacks the scope a route requires
+    ///
+    /// The scope name is not sensitive and is always returned in `details`.
+    #[error("Insufficient scope: {required}")]
+    InsufficientScope {
+        /// Scope the route requires, e.g. `chat:write`
+        required: String,
+    },
+
     #[error("Not found: {0}")]
     NotFound(String),
 
@@ -119,6 +129,29 @@ pub enum ApiError {
     #[error("Service unavailable: {0}")]
     ServiceUnavailable(String),
 
+    /// Inference backend is in degraded mode with the circuit open
+    ///
+    /// The message is user-facing configuration and is never sanitized.
+    #[error("Service degraded: {message}")]
+    Degraded {
+        /// Configured unavailable message
+        message: String,
+        /// Seconds until the backend is retried (`Retry-After`)
+        retry_after_secs: u64,
+    },
+
+    /// Request blocked by prompt security analysis
+    ///
+    /// The analysis is only included in responses while internal error
+    /// details are exposed (development mode).
+    #[error("Forbidden: {message}")]
+    PromptBlocked {
+        /// Refusal shown to the client
+        message: String,
+        /// Findings that caused the block
+        analysis: Box<PromptAnalysisResult>,
+    },
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -133,11 +166,72 @@ pub struct ErrorResponse {
     /// Additional error details
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    /// Prompt security findings (development mode only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityDetails>,
 }
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, code, message, details) = match &self {
+/// Prompt security findings for a blocked request
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SecurityDetails {
+    /// Highest threat level among the findings
+    pub threat_level: Option<String>,
+    /// Overall risk score (0.0 - 1.0)
+    pub risk_score: f32,
+    /// Detected threat categories
+    pub categories: Vec<String>,
+    /// Individual findings
+    pub threats: Vec<SecurityThreatDetail>,
+}
+
+/// A single prompt security finding
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SecurityThreatDetail {
+    /// Threat category (e.g. `prompt_injection`)
+    pub category: String,
+    /// Threat level (`low`, `medium`, `high`, `critical`)
+    pub threat_level: String,
+    /// Pattern that matched the input
+    pub matched_pattern: String,
+    /// Offset of the match in the normalized input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+}
+
+impl From<&SecurityThreat> for SecurityThreatDetail {
+    fn from(threat: &SecurityThreat) -> Self {
+        Self {
+            category: threat.category.to_string(),
+            threat_level: threat.threat_level.to_string(),
+            matched_pattern: threat.matched_pattern.clone(),
+            position: threat.position,
+        }
+    }
+}
+
+impl From<&PromptAnalysisResult> for SecurityDetails {
+    fn from(analysis: &PromptAnalysisResult) -> Self {
+        Self {
+            threat_level: analysis.highest_threat_level().map(|l| l.to_string()),
+            risk_score: analysis.risk_score,
+            categories: analysis
+                .threat_categories()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            threats: analysis
+                .threats
+                .iter()
+                .map(SecurityThreatDetail::from)
+                .collect(),
+        }
+    }
+}
+
+impl ApiError {
+    /// Status code, error code, sanitized message and details
+    fn parts(&self) -> (StatusCode, &'static str, String, Option<String>) {
+        match self {
             Self::BadRequest(msg) => (
                 StatusCode::BAD_REQUEST,
                 "bad_request",
@@ -153,7 +247,7 @@ impl IntoResponse for ApiError {
                 };
                 (StatusCode::UNAUTHORIZED, "unauthorized", sanitized, None)
             },
-            Self::Forbidden(msg) => {
+            Self::Forbidden(msg) | Self::PromptBlocked { message: msg, .. } => {
                 // Forbidden messages can hint at security policy but not leak details
                 let sanitized = if should_expose_details() {
                     msg.clone()
@@ -162,6 +256,12 @@ impl IntoResponse for ApiError {
                 };
                 (StatusCode::FORBIDDEN, "forbidden", sanitized, None)
             },
+            Self::InsufficientScope { required } => (
+                StatusCode::FORBIDDEN,
+                "insufficient_scope",
+                "API key lacks the required scope".to_string(),
+                Some(required.clone()),
+            ),
             Self::NotFound(msg) => (
                 StatusCode::NOT_FOUND,
                 "not_found",
@@ -188,6 +288,12 @@ impl IntoResponse for ApiError {
                     None,
                 )
             },
+            Self::Degraded { message, .. } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "service_degraded",
+                message.clone(),
+                None,
+            ),
             Self::Internal(msg) => {
                 // Internal errors should never leak details in production
                 let details = if should_expose_details() {
@@ -202,15 +308,50 @@ impl IntoResponse for ApiError {
                     details,
                 )
             },
-        };
+        }
+    }
 
-        let body = ErrorResponse {
+    /// Sanitized error body, as sent in HTTP responses
+    ///
+    /// Used by transports that report errors without an HTTP response
+    /// (e.g. WebSocket frames).
+    #[must_use]
+    pub fn to_error_response(&self) -> ErrorResponse {
+        let (_, code, message, details) = self.parts();
+        ErrorResponse {
             error: message,
             code: code.to_string(),
             details,
-        };
+            security: self.security_details(should_expose_details()),
+        }
+    }
 
-        (status, Json(body)).into_response()
+    /// Prompt security findings, if this error carries them and they may be exposed
+    fn security_details(&self, expose_details: bool) -> Option<SecurityDetails> {
+        match self {
+            Self::PromptBlocked { analysis, .. } if expose_details => {
+                Some(SecurityDetails::from(analysis.as_ref()))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.parts().0;
+        let body = self.to_error_response();
+
+        let mut response = (status, Json(body)).into_response();
+        if let Self::Degraded {
+            retry_after_secs, ..
+        } = self
+        {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+        }
+        response
     }
 }
 
@@ -230,6 +371,12 @@ impl From<ApplicationError> for ApiError {
             },
             ApplicationError::NotFound(msg) => Self::NotFound(msg),
             ApplicationError::InvalidOperation(msg) => Self::BadRequest(msg),
+            ApplicationError::Cancelled => {
+                Self::ServiceUnavailable("Request was cancelled".to_string())
+            },
+            err @ ApplicationError::InvalidModelOutput { .. } => {
+                Self::ServiceUnavailable(err.to_string())
+            },
             ApplicationError::Configuration(msg)
             | ApplicationError::CommandFailed(msg)
             | ApplicationError::Internal(msg) => Self::Internal(msg),
@@ -271,6 +418,19 @@ mod tests {
         assert_eq!(err.to_string(), "Service unavailable: inference down");
     }
 
+    #[test]
+    fn api_error_degraded_sets_retry_after_and_keeps_message() {
+        let err = ApiError::Degraded {
+            message: "Back in a moment".to_string(),
+            retry_after_secs: 30,
+        };
+
+        let response = err.into_response();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+    }
+
     #[test]
     fn api_error_internal_message() {
         let err = ApiError::Internal("unexpected".to_string());
@@ -283,6 +443,7 @@ mod tests {
             error: "Bad request".to_string(),
             code: "bad_request".to_string(),
             details: None,
+            security: None,
         };
         let json = serde_json::to_string(&resp).unwrap();
         assert!(json.contains("error"));
@@ -296,6 +457,7 @@ mod tests {
             error: "Internal error".to_string(),
             code: "internal_error".to_string(),
             details: Some("stack trace".to_string()),
+            security: None,
         };
         let json = serde_json::to_string(&resp).unwrap();
         assert!(json.contains("details"));
@@ -465,6 +627,68 @@ mod tests {
         set_expose_internal_errors(true);
     }
 
+    fn blocked_error() -> ApiError {
+        use domain::entities::{ThreatCategory, ThreatLevel};
+
+        let analysis = PromptAnalysisResult::with_threats(
+            vec![
+                SecurityThreat::new(
+                    ThreatCategory::PromptInjection,
+                    ThreatLevel::High,
+                    "ignore previous instructions",
+                    0.9,
+                )
+                .with_position(0),
+            ],
+            50,
+        );
+        ApiError::PromptBlocked {
+            message: "Request blocked due to security policy violation".to_string(),
+            analysis: Box::new(analysis),
+        }
+    }
+
+    #[test]
+    fn prompt_blocked_is_forbidden() {
+        let err = blocked_error();
+        assert_eq!(err.parts().0, StatusCode::FORBIDDEN);
+        assert_eq!(err.parts().1, "forbidden");
+    }
+
+    #[test]
+    fn prompt_blocked_exposes_analysis_in_development() {
+        let details = blocked_error().security_details(true).unwrap();
+        assert_eq!(details.threat_level.as_deref(), Some("high"));
+        assert_eq!(details.categories, vec!["prompt_injection"]);
+        assert_eq!(details.threats.len(), 1);
+        assert_eq!(
+            details.threats[0].matched_pattern,
+            "ignore previous instructions"
+        );
+        assert_eq!(details.threats[0].position, Some(0));
+    }
+
+    #[test]
+    fn prompt_blocked_redacts_analysis_in_production() {
+        assert!(blocked_error().security_details(false).is_none());
+        assert!(
+            ApiError::Forbidden("nope".to_string())
+                .security_details(true)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn insufficient_scope_names_required_scope() {
+        let err = ApiError::InsufficientScope {
+            required: "chat:write".to_string(),
+        };
+        let (status, code, _, details) = err.parts();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(code, "insufficient_scope");
+        assert_eq!(details.as_deref(), Some("chat:write"));
+    }
+
     #[test]
     fn unauthorized_error_generic_in_production() {
         set_expose_internal_errors(false);
@@ -0,0 +1,359 @@
+//! Audit log handlers
+//!
+//! Admin-only endpoints for compliance reviews of the audit trail (approval
+//! grants, command executions, security events). Results are always bounded:
+//! the JSON listing is paginated and the CSV export is capped.
+
+use std::{fmt::Write as _, sync::Arc};
+
+use application::ports::{AuditLogPort, AuditQuery};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use domain::{AuditEntry, AuditEventType};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    error::ApiError, handlers::common::require_admin, middleware::AdminAccess, state::AppState,
+};
+
+/// Default page size for the JSON listing
+const DEFAULT_LIMIT: u32 = 100;
+
+/// Maximum page size for the JSON listing
+const MAX_LIMIT: u32 = 1000;
+
+/// Maximum number of rows in a CSV export
+const MAX_EXPORT_ROWS: u32 = 10_000;
+
+/// Audit log query parameters
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct AuditLogQuery {
+    /// Filter by event type (e.g. `approval`, `command_execution`)
+    pub event_type: Option<String>,
+    /// Only entries at or after this time (RFC 3339)
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries at or before this time (RFC 3339)
+    pub to: Option<DateTime<Utc>>,
+    /// Maximum number of entries (default: 100, max: 1000; export max: 10000)
+    pub limit: Option<u32>,
+    /// Number of entries to skip (default: 0)
+    pub offset: Option<u32>,
+}
+
+impl AuditLogQuery {
+    /// Validate the parameters and build a storage query with the given page size cap
+    fn to_audit_query(&self, max_limit: u32) -> Result<AuditQuery, ApiError> {
+        if self.from.zip(self.to).is_some_and(|(from, to)| from > to) {
+            return Err(ApiError::BadRequest(
+                "'from' must not be after 'to'".to_string(),
+            ));
+        }
+
+        let mut query = AuditQuery::new()
+            .with_limit(self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, max_limit))
+            .with_offset(self.offset.unwrap_or(0));
+        if let Some(event_type) = &self.event_type {
+            let event_type = event_type
+                .parse::<AuditEventType>()
+                .map_err(ApiError::BadRequest)?;
+            query = query.with_event_type(event_type);
+        }
+        query.from = self.from;
+        query.to = self.to;
+        Ok(query)
+    }
+}
+
+/// Audit log entry for API responses
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "id": 42,
+    "timestamp": "2026-02-06T10:30:00Z",
+    "event_type": "approval",
+    "actor": null,
+    "resource_type": "approval",
+    "resource_id": "550e8400-e29b-41d4-a716-446655440000",
+    "action": "approve",
+    "details": null,
+    "ip_address": null,
+    "success": true,
+    "request_id": null
+}))]
+pub struct AuditEntryResponse {
+    /// Database ID
+    pub id: Option<i64>,
+    /// When the event occurred (ISO 8601)
+    pub timestamp: String,
+    /// Event category
+    pub event_type: String,
+    /// Who performed the action
+    pub actor: Option<String>,
+    /// Type of the affected resource
+    pub resource_type: Option<String>,
+    /// ID of the affected resource
+    pub resource_id: Option<String>,
+    /// Action performed
+    pub action: String,
+    /// Additional details
+    pub details: Option<String>,
+    /// Client IP address
+    pub ip_address: Option<String>,
+    /// Whether the action succeeded
+    pub success: bool,
+    /// Correlation ID of the originating request
+    pub request_id: Option<String>,
+}
+
+impl From<AuditEntry> for AuditEntryResponse {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            timestamp: entry.timestamp.to_rfc3339(),
+            event_type: entry.event_type.to_string(),
+            actor: entry.actor,
+            resource_type: entry.resource_type,
+            resource_id: entry.resource_id,
+            action: entry.action,
+            details: entry.details,
+            ip_address: entry.ip_address.map(|ip| ip.to_string()),
+            success: entry.success,
+            request_id: entry.request_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+/// Paginated audit log response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// Entries of this page, newest first
+    pub entries: Vec<AuditEntryResponse>,
+    /// Total number of entries matching the filters
+    pub total: u64,
+    /// Page size used
+    pub limit: u32,
+    /// Offset used
+    pub offset: u32,
+}
+
+fn audit_log(state: &AppState) -> Result<&Arc<dyn AuditLogPort>, ApiError> {
+    state
+        .audit_log
+        .as_ref()
+        .ok_or_else(|| ApiError::ServiceUnavailable("Audit log not configured".to_string()))
+}
+
+/// Query the audit log
+///
+/// GET /v1/system/audit
+#[utoipa::path(
+    get,
+    path = "/v1/system/audit",
+    tag = "system",
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "Page of audit log entries", body = AuditLogResponse),
+        (status = 400, description = "Invalid filter", body = crate::error::ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 503, description = "Audit log not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn query(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Query(params): Query<AuditLogQuery>,
+) -> Result<Json<AuditLogResponse>, ApiError> {
+    require_admin(admin)?;
+    let audit_log = audit_log(&state)?;
+    let query = params.to_audit_query(MAX_LIMIT)?;
+
+    let entries = audit_log.query(&query).await?;
+    let total = audit_log.count(&query).await?;
+
+    Ok(Json(AuditLogResponse {
+        entries: entries.into_iter().map(AuditEntryResponse::from).collect(),
+        total,
+        limit: query.limit.unwrap_or(DEFAULT_LIMIT),
+        offset: query.offset.unwrap_or(0),
+    }))
+}
+
+/// Export the audit log as CSV
+///
+/// GET /v1/system/audit/export
+#[utoipa::path(
+    get,
+    path = "/v1/system/audit/export",
+    tag = "system",
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "Audit log entries as CSV", content_type = "text/csv"),
+        (status = 400, description = "Invalid filter", body = crate::error::ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 503, description = "Audit log not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn export(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Query(params): Query<AuditLogQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(admin)?;
+    let audit_log = audit_log(&state)?;
+    let query = params.to_audit_query(MAX_EXPORT_ROWS)?;
+
+    let entries = audit_log.query(&query).await?;
+    info!(rows = entries.len(), "📋 Audit log exported");
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"audit_log.csv\"",
+            ),
+        ],
+        render_csv(&entries),
+    ))
+}
+
+/// Render entries as RFC 4180 CSV with a header row
+fn render_csv(entries: &[AuditEntry]) -> String {
+    let mut csv = String::from(
+        "id,timestamp,event_type,actor,resource_type,resource_id,action,details,ip_address,success,request_id\r\n",
+    );
+    for entry in entries {
+        let fields = [
+            entry.id.map(|id| id.to_string()).unwrap_or_default(),
+            entry.timestamp.to_rfc3339(),
+            entry.event_type.to_string(),
+            entry.actor.clone().unwrap_or_default(),
+            entry.resource_type.clone().unwrap_or_default(),
+            entry.resource_id.clone().unwrap_or_default(),
+            entry.action.clone(),
+            entry.details.clone().unwrap_or_default(),
+            entry
+                .ip_address
+                .map(|ip| ip.to_string())
+                .unwrap_or_default(),
+            entry.success.to_string(),
+            entry
+                .request_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        ];
+        let row: Vec<String> = fields.iter().map(String::as_str).map(csv_field).collect();
+        let _ = write!(csv, "{}\r\n", row.join(","));
+    }
+    csv
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use domain::AuditBuilder;
+
+    use super::*;
+
+    #[test]
+    fn query_defaults_and_caps_limit() {
+        let query = AuditLogQuery::default().to_audit_query(MAX_LIMIT).unwrap();
+        assert_eq!(query.limit, Some(DEFAULT_LIMIT));
+        assert_eq!(query.offset, Some(0));
+
+        let params = AuditLogQuery {
+            limit: Some(50_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            params.to_audit_query(MAX_LIMIT).unwrap().limit,
+            Some(MAX_LIMIT)
+        );
+        assert_eq!(
+            params.to_audit_query(MAX_EXPORT_ROWS).unwrap().limit,
+            Some(MAX_EXPORT_ROWS)
+        );
+    }
+
+    #[test]
+    fn query_parses_event_type() {
+        let params = AuditLogQuery {
+            event_type: Some("approval".to_string()),
+            ..Default::default()
+        };
+        let query = params.to_audit_query(MAX_LIMIT).unwrap();
+        assert_eq!(query.event_type, Some(AuditEventType::Approval));
+    }
+
+    #[test]
+    fn unknown_event_type_is_rejected() {
+        let params = AuditLogQuery {
+            event_type: Some("login".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            params.to_audit_query(MAX_LIMIT),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn inverted_time_range_is_rejected() {
+        let now = Utc::now();
+        let params = AuditLogQuery {
+            from: Some(now),
+            to: Some(now - chrono::Duration::hours(1)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            params.to_audit_query(MAX_LIMIT),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn csv_field_escapes_special_characters() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn csv_has_header_and_one_row_per_entry() {
+        let entries = vec![
+            AuditBuilder::approval_granted("abc"),
+            AuditBuilder::rate_limited(IpAddr::V4(Ipv4Addr::LOCALHOST))
+                .with_details("too many, slow down"),
+        ];
+
+        let csv = render_csv(&entries);
+        let lines: Vec<&str> = csv.split("\r\n").filter(|l| !l.is_empty()).collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("id,timestamp,event_type"));
+        assert!(lines[1].contains(",approval,"));
+        assert!(lines[2].contains("\"too many, slow down\""));
+        assert!(lines[2].contains("127.0.0.1"));
+    }
+}
@@ -0,0 +1,281 @@
+//! Chaos engineering admin handlers
+//!
+//! Only compiled with the `chaos` feature. Lets operators install fault
+//! policies for the inference, weather and CalDAV adapters during game days.
+//! The routes are not part of the OpenAPI document.
+
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use axum::{Extension, Json, extract::Path, http::StatusCode};
+use infrastructure::chaos::{
+    ChaosController, ChaosTarget, ChaosTargetStatus, FaultPolicy, FaultType, LatencyDistribution,
+};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use validator::Validate;
+
+use crate::{
+    error::ApiError,
+    handlers::common::require_admin,
+    middleware::{AdminAccess, ValidatedJson},
+};
+
+/// Fault to inject
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FaultSpec {
+    /// Fail with an error message
+    Error {
+        /// Error message (defaults to "Injected fault")
+        #[serde(default)]
+        message: Option<String>,
+    },
+    /// Delay the call by a uniformly distributed latency, then run it
+    Latency {
+        /// Minimum added latency in milliseconds
+        min_ms: u64,
+        /// Maximum added latency in milliseconds
+        max_ms: u64,
+    },
+    /// Delay the call by a normally distributed latency, then run it
+    NormalLatency {
+        /// Mean added latency in milliseconds
+        mean_ms: u64,
+        /// Standard deviation in milliseconds
+        std_dev_ms: u64,
+    },
+    /// Wait, then fail with a timeout
+    Timeout {
+        /// Time before the timeout error in milliseconds
+        after_ms: u64,
+    },
+    /// Fail as if the connection was refused
+    ConnectionRefused,
+    /// Fail as if the connection was reset
+    ConnectionReset,
+    /// Fail as if rate limited
+    RateLimited,
+}
+
+impl FaultSpec {
+    fn into_fault_type(self) -> Result<FaultType, ApiError> {
+        Ok(match self {
+            Self::Error { message } => {
+                FaultType::Error(message.unwrap_or_else(|| "Injected fault".to_string()))
+            },
+            Self::Latency { min_ms, max_ms } => {
+                if min_ms > max_ms {
+                    return Err(ApiError::BadRequest(
+                        "min_ms must not exceed max_ms".to_string(),
+                    ));
+                }
+                FaultType::Latency(LatencyDistribution::uniform(
+                    Duration::from_millis(min_ms),
+                    Duration::from_millis(max_ms),
+                ))
+            },
+            Self::NormalLatency {
+                mean_ms,
+                std_dev_ms,
+            } => FaultType::Latency(LatencyDistribution::normal(
+                Duration::from_millis(mean_ms),
+                Duration::from_millis(std_dev_ms),
+            )),
+            Self::Timeout { after_ms } => FaultType::Timeout(Duration::from_millis(after_ms)),
+            Self::ConnectionRefused => FaultType::ConnectionRefused,
+            Self::ConnectionReset => FaultType::ConnectionReset,
+            Self::RateLimited => FaultType::RateLimited,
+        })
+    }
+}
+
+/// Request body for installing a fault policy
+#[derive(Debug, Deserialize, Validate)]
+pub struct FaultPolicyRequest {
+    /// Probability of injecting the fault per call (0.0 to 1.0)
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub fault_rate: f64,
+    /// Fault to inject
+    pub fault: FaultSpec,
+    /// Only inject into these port methods (all if empty)
+    #[serde(default)]
+    pub operations: Vec<String>,
+    /// Stop after this many faults
+    #[serde(default)]
+    pub max_faults: Option<usize>,
+    /// Minimum time between faults in milliseconds
+    #[serde(default)]
+    pub cooldown_ms: Option<u64>,
+}
+
+impl FaultPolicyRequest {
+    fn into_policy(self) -> Result<FaultPolicy, ApiError> {
+        let mut policy = FaultPolicy::with_rate(self.fault_rate)
+            .with_fault_type(self.fault.into_fault_type()?)
+            .with_targets(self.operations);
+        if let Some(max) = self.max_faults {
+            policy = policy.with_max_faults(max);
+        }
+        if let Some(cooldown_ms) = self.cooldown_ms {
+            policy = policy.with_cooldown(Duration::from_millis(cooldown_ms));
+        }
+        Ok(policy)
+    }
+}
+
+/// Current chaos state
+#[derive(Debug, Serialize)]
+pub struct ChaosStatusResponse {
+    /// Whether any fault policy is active
+    pub active: bool,
+    /// Installed policies with their statistics
+    pub targets: Vec<ChaosTargetStatus>,
+}
+
+fn controller(
+    extension: Option<Extension<Arc<ChaosController>>>,
+) -> Result<Arc<ChaosController>, ApiError> {
+    extension
+        .map(|Extension(controller)| controller)
+        .ok_or_else(|| ApiError::ServiceUnavailable("Chaos controller not configured".to_string()))
+}
+
+fn parse_target(target: &str) -> Result<ChaosTarget, ApiError> {
+    ChaosTarget::from_str(target).map_err(ApiError::NotFound)
+}
+
+fn status_of(controller: &ChaosController) -> ChaosStatusResponse {
+    ChaosStatusResponse {
+        active: controller.is_active(),
+        targets: controller.status(),
+    }
+}
+
+/// Show installed fault policies and their statistics
+///
+/// GET /v1/admin/chaos
+pub async fn status(
+    chaos: Option<Extension<Arc<ChaosController>>>,
+    admin: Option<Extension<AdminAccess>>,
+) -> Result<Json<ChaosStatusResponse>, ApiError> {
+    require_admin(admin)?;
+    let controller = controller(chaos)?;
+    Ok(Json(status_of(&controller)))
+}
+
+/// Install a fault policy for one adapter
+///
+/// PUT /v1/admin/chaos/:target
+pub async fn set_policy(
+    chaos: Option<Extension<Arc<ChaosController>>>,
+    admin: Option<Extension<AdminAccess>>,
+    Path(target): Path<String>,
+    ValidatedJson(body): ValidatedJson<FaultPolicyRequest>,
+) -> Result<Json<ChaosStatusResponse>, ApiError> {
+    require_admin(admin)?;
+    let controller = controller(chaos)?;
+    let target = parse_target(&target)?;
+
+    controller.set_policy(target, body.into_policy()?);
+    warn!(target = %target, "🔥 CHAOS: fault injection enabled via admin endpoint");
+
+    Ok(Json(status_of(&controller)))
+}
+
+/// Remove the fault policy of one adapter
+///
+/// DELETE /v1/admin/chaos/:target
+pub async fn clear_policy(
+    chaos: Option<Extension<Arc<ChaosController>>>,
+    admin: Option<Extension<AdminAccess>>,
+    Path(target): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    require_admin(admin)?;
+    let controller = controller(chaos)?;
+    let target = parse_target(&target)?;
+
+    if controller.clear(target) {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::NotFound(format!(
+            "No fault policy installed for {target}"
+        )))
+    }
+}
+
+/// Remove all fault policies
+///
+/// DELETE /v1/admin/chaos
+pub async fn clear_all(
+    chaos: Option<Extension<Arc<ChaosController>>>,
+    admin: Option<Extension<AdminAccess>>,
+) -> Result<StatusCode, ApiError> {
+    require_admin(admin)?;
+    controller(chaos)?.clear_all();
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(fault: FaultSpec) -> FaultPolicyRequest {
+        FaultPolicyRequest {
+            fault_rate: 0.5,
+            fault,
+            operations: vec!["generate".to_string()],
+            max_faults: Some(10),
+            cooldown_ms: Some(100),
+        }
+    }
+
+    #[test]
+    fn latency_request_builds_uniform_policy() {
+        let policy = request(FaultSpec::Latency {
+            min_ms: 100,
+            max_ms: 500,
+        })
+        .into_policy()
+        .unwrap();
+
+        assert!(policy.enabled);
+        assert_eq!(policy.target_operations, vec!["generate"]);
+        assert_eq!(policy.max_faults, Some(10));
+        assert_eq!(policy.cooldown, Some(Duration::from_millis(100)));
+        let FaultType::Latency(latency) = policy.fault_type else {
+            unreachable!("Expected latency fault");
+        };
+        assert_eq!(latency.min, Duration::from_millis(100));
+        assert_eq!(latency.max, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn inverted_latency_range_is_rejected() {
+        let result = request(FaultSpec::Latency {
+            min_ms: 500,
+            max_ms: 100,
+        })
+        .into_policy();
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn fault_spec_deserializes_tagged() {
+        let spec: FaultSpec =
+            serde_json::from_str(r#"{"type":"timeout","after_ms":2000}"#).unwrap();
+        assert!(matches!(spec, FaultSpec::Timeout { after_ms: 2000 }));
+
+        let spec: FaultSpec = serde_json::from_str(r#"{"type":"error"}"#).unwrap();
+        let FaultType::Error(message) = spec.into_fault_type().unwrap() else {
+            unreachable!("Expected error fault");
+        };
+        assert_eq!(message, "Injected fault");
+    }
+
+    #[test]
+    fn unknown_target_is_not_found() {
+        assert!(matches!(parse_target("email"), Err(ApiError::NotFound(_))));
+        assert_eq!(parse_target("inference").unwrap(), ChaosTarget::Inference);
+    }
+}
@@ -1,22 +1,35 @@
 //! Chat handlers
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use application::{
+    RequestContext,
+    error::ApplicationError,
+    ports::{InferenceStream, StreamingChunk, cancellable_stream},
+};
 use axum::{
     Extension, Json,
     extract::State,
+    http::{HeaderMap, HeaderValue},
     response::sse::{Event, Sse},
 };
-use domain::entities::ThreatLevel;
-use futures::{StreamExt, stream::Stream};
+use domain::entities::{AuditBuilder, ThreatLevel};
+use futures::{
+    StreamExt,
+    stream::{self, Stream},
+};
+use infrastructure::scope_request_id;
 use serde::{Deserialize, Serialize};
-use tracing::{info, instrument, warn};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, debug, info, instrument, warn};
 use utoipa::ToSchema;
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::ApiError,
-    middleware::{ClientIp, ValidatedJson},
+    middleware::{ClientIp, RequestId, ValidatedJson},
     state::AppState,
 };
 
@@ -80,7 +93,9 @@ pub struct ChatResponse {
 /// Performs prompt security analysis and IP blocking checks
 ///
 /// Returns `Ok(())` if the request is allowed, or an `ApiError` if blocked.
-async fn check_prompt_security(
+/// Detected threats are written to the audit log; a blocked prompt returns
+/// [`ApiError::PromptBlocked`] carrying the analysis.
+pub(super) async fn check_prompt_security(
     state: &AppState,
     message: &str,
     client_ip: Option<std::net::IpAddr>,
@@ -131,8 +146,20 @@ async fn check_prompt_security(
 
         // Handle suspicious activity
         if !analysis.threats.is_empty() {
-            // Record violation for tracking
-            if let (Some(tracker), Some(ip)) = (&state.suspicious_activity_tracker, client_ip) {
+            // Audit every detection, including report-only and production mode
+            if let Some(audit_log) = &state.audit_log {
+                let entry = AuditBuilder::prompt_threats_detected(client_ip, &analysis);
+                if let Err(e) = audit_log.log(&entry).await {
+                    warn!(error = %e, "Failed to write prompt security audit entry");
+                }
+            }
+
+            // Record violation for tracking (report-only mode never blocks IPs)
+            let tracker = state
+                .suspicious_activity_tracker
+                .as_ref()
+                .filter(|_| !sanitizer.is_report_only());
+            if let (Some(tracker), Some(ip)) = (tracker, client_ip) {
                 let highest_level = analysis.highest_threat_level().unwrap_or(ThreatLevel::Low);
 
                 let violation = ViolationRecord::new(
@@ -161,9 +188,10 @@ async fn check_prompt_security(
             // Block if the analysis determined we should block
             if analysis.should_block {
                 state.metrics.record_security_block();
-                return Err(ApiError::Forbidden(
-                    "Request blocked due to security policy violation".to_string(),
-                ));
+                return Err(ApiError::PromptBlocked {
+                    message: "Request blocked due to security policy violation".to_string(),
+                    analysis: Box::new(analysis),
+                });
             }
         }
     }
@@ -186,13 +214,14 @@ async fn check_prompt_security(
         (status = 400, description = "Invalid request", body = crate::error::ErrorResponse),
         (status = 403, description = "Security policy violation", body = crate::error::ErrorResponse),
         (status = 429, description = "Rate limited", body = crate::error::ErrorResponse),
-        (status = 503, description = "Service unavailable", body = crate::error::ErrorResponse)
+        (status = 503, description = "Service unavailable or in degraded mode (with `Retry-After`)", body = crate::error::ErrorResponse)
     ),
     security(("api_key" = []))
 )]
-#[instrument(skip(state, request, client_ip), fields(message_len = request.message.len(), conv_id = ?request.conversation_id))]
+#[instrument(skip(state, ctx, request, client_ip), fields(message_len = request.message.len(), conv_id = ?request.conversation_id))]
 pub async fn chat(
     State(state): State<AppState>,
+    ctx: Option<Extension<RequestContext>>,
     client_ip: Option<Extension<ClientIp>>,
     ValidatedJson(request): ValidatedJson<ChatRequest>,
 ) -> Result<Json<ChatResponse>, ApiError> {
@@ -201,11 +230,30 @@ pub async fn chat(
 
     // Perform security checks before processing
     check_prompt_security(&state, &request.message, ip).await?;
+    super::common::ensure_inference_available(&state)?;
 
-    let (response, conv_id) = state
+    let user_id = ctx.map(|Extension(c)| c.user_id());
+    let started = Instant::now();
+    let result = state
         .chat_service
-        .chat_with_context(&request.message, request.conversation_id.as_deref())
-        .await?;
+        .chat_with_context_for_user(
+            &request.message,
+            request.conversation_id.as_deref(),
+            user_id,
+        )
+        .await;
+    let tokens = result
+        .as_ref()
+        .ok()
+        .and_then(|(response, _)| response.metadata.as_ref())
+        .and_then(|m| m.tokens)
+        .map_or(0, u64::from);
+    state.metrics.record_inference(
+        result.is_ok(),
+        u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX),
+        tokens,
+    );
+    let (response, conv_id) = result?;
 
     let metadata = response.metadata.as_ref();
 
@@ -220,7 +268,7 @@ pub async fn chat(
 
 /// Streaming chat request
 #[derive(Debug, Deserialize, Validate, ToSchema)]
-#[schema(example = json!({"message": "Tell me a story about a robot"}))]
+#[schema(example = json!({"message": "Tell me a story about a robot", "conversation_id": null}))]
 pub struct StreamChatRequest {
     /// User message to process
     #[validate(length(
@@ -231,59 +279,179 @@ pub struct StreamChatRequest {
     #[validate(custom(function = "validate_not_empty_trimmed"))]
     #[schema(min_length = 1, max_length = 10000)]
     pub message: String,
+    /// Optional conversation ID for context.
+    /// If provided, the exchange is appended to that conversation (which is
+    /// created if it doesn't exist yet) once the stream completes.
+    /// If not provided, the request is handled statelessly.
+    #[serde(default)]
+    pub conversation_id: Option<String>,
+    /// Optional model to answer this request instead of the current one
+    #[serde(default)]
+    #[validate(length(max = 200, message = "Model name must be at most 200 characters"))]
+    pub model: Option<String>,
+}
+
+/// Response header carrying the conversation ID of a contextual stream
+pub const CONVERSATION_ID_HEADER: &str = "X-Conversation-Id";
+
+/// Final SSE event payload signalling the end of a successful stream
+pub const STREAM_DONE_MARKER: &str = "[DONE]";
+
+/// Number of chunks buffered between the inference task and the SSE response
+const STREAM_CHANNEL_CAPACITY: usize = 32;
+
+/// Drive an inference stream on its own task and forward chunks over a channel
+///
+/// The task runs inside the request ID scope so outgoing calls made while
+/// polling the stream stay correlated. When `cancel` fires or the receiver
+/// is dropped (client disconnect), the task stops and drops the inference
+/// stream, aborting the underlying backend request.
+pub(super) fn spawn_stream_forwarder(
+    inference_stream: InferenceStream,
+    request_id: Option<Uuid>,
+    cancel: CancellationToken,
+) -> mpsc::Receiver<Result<StreamingChunk, ApplicationError>> {
+    let (tx, rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+    let mut inference_stream = cancellable_stream(inference_stream, cancel);
+
+    let forward = async move {
+        loop {
+            tokio::select! {
+                () = tx.closed() => {
+                    debug!("Client disconnected, cancelling inference stream");
+                    break;
+                },
+                next = inference_stream.next() => {
+                    let Some(item) = next else { break };
+                    let finished = match &item {
+                        Ok(chunk) => chunk.done,
+                        Err(ApplicationError::Cancelled) => {
+                            debug!("Connection closed, cancelling inference stream");
+                            true
+                        },
+                        Err(_) => true,
+                    };
+                    if tx.send(item).await.is_err() || finished {
+                        break;
+                    }
+                },
+            }
+        }
+    }
+    .in_current_span();
+
+    match request_id {
+        Some(id) => tokio::spawn(scope_request_id(id, forward)),
+        None => tokio::spawn(forward),
+    };
+
+    rx
 }
 
 /// Handle a streaming chat request via SSE
 ///
 /// Streams chunks from the LLM directly to the client as SSE events.
-/// Each event contains a JSON payload with `content`, `done`, and optionally `model`.
+/// Each event contains a JSON-encoded `StreamingChunk` (`content`, `done`, and
+/// optionally `model`); a successful stream ends with a `[DONE]` event.
+/// Dropping the connection cancels the in-flight inference.
+///
+/// With a `conversation_id`, the conversation ID is echoed in the
+/// `X-Conversation-Id` response header. An optional `model` answers this
+/// request with the given model instead of the current one.
 #[utoipa::path(
     post,
     path = "/v1/chat/stream",
     tag = "chat",
     request_body = StreamChatRequest,
     responses(
-        (status = 200, description = "SSE stream of chat chunks", content_type = "text/event-stream"),
+        (status = 200, description = "SSE stream of chat chunks terminated by a `[DONE]` event", content_type = "text/event-stream"),
         (status = 400, description = "Invalid request", body = crate::error::ErrorResponse),
         (status = 403, description = "Security policy violation", body = crate::error::ErrorResponse),
         (status = 429, description = "Rate limited", body = crate::error::ErrorResponse),
-        (status = 503, description = "Service unavailable", body = crate::error::ErrorResponse)
+        (status = 503, description = "Service unavailable or in degraded mode (with `Retry-After`)", body = crate::error::ErrorResponse)
     ),
     security(("api_key" = []))
 )]
-#[instrument(skip(state, request, client_ip), fields(message_len = request.message.len()))]
+#[instrument(skip(state, ctx, request, client_ip, request_id), fields(message_len = request.message.len()))]
 pub async fn chat_stream(
     State(state): State<AppState>,
+    ctx: Option<Extension<RequestContext>>,
     client_ip: Option<Extension<ClientIp>>,
+    request_id: Option<Extension<RequestId>>,
     ValidatedJson(request): ValidatedJson<StreamChatRequest>,
-) -> Result<Sse<impl Stream<Item = Result<Event, ApiError>>>, ApiError> {
+) -> Result<(HeaderMap, Sse<impl Stream<Item = Result<Event, ApiError>>>), ApiError> {
     // Extract client IP from extension
     let ip = client_ip.map(|Extension(ClientIp(ip))| ip);
 
     // Perform security checks before processing
     check_prompt_security(&state, &request.message, ip).await?;
+    super::common::ensure_inference_available(&state)?;
 
     // Get streaming response from LLM
-    let inference_stream = state.chat_service.chat_stream(&request.message).await?;
-
-    // Map inference chunks to SSE events
-    let sse_stream = inference_stream.map(|result| {
-        result
-            .map(|chunk| {
-                let json = serde_json::json!({
-                    "content": chunk.content,
-                    "done": chunk.done,
-                    "model": chunk.model,
-                });
-                Event::default().data(json.to_string())
-            })
-            .map_err(ApiError::from)
+    let model = request
+        .model
+        .as_deref()
+        .map(str::trim)
+        .filter(|model| !model.is_empty());
+    let mut headers = HeaderMap::new();
+    let inference_stream = match (request.conversation_id.as_deref(), model) {
+        (Some(conversation_id), model) => {
+            let (stream, conv_id) = state
+                .chat_service
+                .chat_stream_with_context_and_model(
+                    &request.message,
+                    Some(conversation_id),
+                    ctx.map(|Extension(c)| c.user_id()),
+                    model,
+                )
+                .await?;
+            if let Ok(value) = HeaderValue::from_str(&conv_id.to_string()) {
+                headers.insert(CONVERSATION_ID_HEADER, value);
+            }
+            stream
+        },
+        (None, Some(model)) => {
+            state
+                .chat_service
+                .chat_stream_with_model(&request.message, model)
+                .await?
+        },
+        (None, None) => state.chat_service.chat_stream(&request.message).await?,
+    };
+
+    // Tied to the connection: axum drops the SSE body when the client goes
+    // away, which drops the guard and aborts the backend request
+    let connection = CancellationToken::new();
+    let disconnect_guard = connection.clone().drop_guard();
+    let rx = spawn_stream_forwarder(
+        inference_stream,
+        request_id.map(|Extension(id)| id.as_uuid()),
+        connection,
+    );
+
+    // Map inference chunks to SSE events, terminated by the done marker
+    let chunks = stream::unfold((rx, disconnect_guard), |(mut rx, guard)| async move {
+        rx.recv().await.map(|item| (item, (rx, guard)))
     });
-
-    Ok(Sse::new(sse_stream).keep_alive(
-        axum::response::sse::KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text("keep-alive"),
+    let sse_stream = chunks
+        .map(|result| {
+            result.map_err(ApiError::from).and_then(|chunk| {
+                Event::default()
+                    .json_data(&chunk)
+                    .map_err(|e| ApiError::Internal(e.to_string()))
+            })
+        })
+        .chain(stream::once(async {
+            Ok(Event::default().data(STREAM_DONE_MARKER))
+        }));
+
+    Ok((
+        headers,
+        Sse::new(sse_stream).keep_alive(
+            axum::response::sse::KeepAlive::new()
+                .interval(Duration::from_secs(15))
+                .text("keep-alive"),
+        ),
     ))
 }
 
@@ -366,12 +534,30 @@ mod tests {
         let json = r#"{"message": "Stream this"}"#;
         let request: StreamChatRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.message, "Stream this");
+        assert!(request.conversation_id.is_none());
+    }
+
+    #[test]
+    fn stream_chat_request_with_conversation_id() {
+        let json = r#"{"message": "Go on", "conversation_id": "abc123"}"#;
+        let request: StreamChatRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.conversation_id, Some("abc123".to_string()));
+        assert!(request.model.is_none());
+    }
+
+    #[test]
+    fn stream_chat_request_with_model() {
+        let json = r#"{"message": "Hi", "model": "llama3.2"}"#;
+        let request: StreamChatRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.model.as_deref(), Some("llama3.2"));
     }
 
     #[test]
     fn stream_chat_request_debug() {
         let request = StreamChatRequest {
             message: "Test".to_string(),
+            conversation_id: None,
+            model: None,
         };
         let debug = format!("{request:?}");
         assert!(debug.contains("StreamChatRequest"));
@@ -394,4 +580,103 @@ mod tests {
         };
         assert!(!request.message.trim().is_empty());
     }
+
+    struct DropFlag(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    fn chunk(content: &str, done: bool) -> StreamingChunk {
+        StreamingChunk {
+            content: content.to_string(),
+            done,
+            model: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_forwarder_forwards_until_done() {
+        let inference: InferenceStream = Box::pin(stream::iter(vec![
+            Ok(chunk("Hel", false)),
+            Ok(chunk("lo", true)),
+            Ok(chunk("ignored", false)),
+        ]));
+
+        let mut rx = spawn_stream_forwarder(inference, None, CancellationToken::new());
+
+        assert_eq!(rx.recv().await.unwrap().unwrap().content, "Hel");
+        assert_eq!(rx.recv().await.unwrap().unwrap().content, "lo");
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn stream_forwarder_polls_within_request_scope() {
+        let request_id = Uuid::now_v7();
+        let inference: InferenceStream = Box::pin(stream::once(async {
+            let id = infrastructure::http::current_request_id()
+                .map(|id| id.to_string())
+                .unwrap_or_default();
+            Ok(chunk(&id, true))
+        }));
+
+        let mut rx = spawn_stream_forwarder(inference, Some(request_id), CancellationToken::new());
+
+        let received = rx.recv().await.unwrap().unwrap();
+        assert_eq!(received.content, request_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn stream_forwarder_cancels_inference_on_disconnect() {
+        let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let guard = DropFlag(std::sync::Arc::clone(&dropped));
+        let inference: InferenceStream = Box::pin(
+            stream::iter(vec![Ok(chunk("Hel", false))])
+                .chain(stream::pending())
+                .map(move |item| {
+                    let _guard = &guard;
+                    item
+                }),
+        );
+
+        let mut rx = spawn_stream_forwarder(inference, None, CancellationToken::new());
+        assert_eq!(rx.recv().await.unwrap().unwrap().content, "Hel");
+        drop(rx);
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !dropped.load(std::sync::atomic::Ordering::SeqCst) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("inference stream should be dropped after disconnect");
+    }
+
+    #[tokio::test]
+    async fn stream_forwarder_cancels_inference_on_token() {
+        let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let guard = DropFlag(std::sync::Arc::clone(&dropped));
+        let inference: InferenceStream = Box::pin(
+            stream::iter(vec![Ok(chunk("Hel", false))])
+                .chain(stream::pending())
+                .map(move |item| {
+                    let _guard = &guard;
+                    item
+                }),
+        );
+        let cancel = CancellationToken::new();
+
+        let mut rx = spawn_stream_forwarder(inference, None, cancel.clone());
+        assert_eq!(rx.recv().await.unwrap().unwrap().content, "Hel");
+        cancel.cancel();
+
+        // The receiver is still alive; the token alone ends the stream
+        assert!(matches!(
+            rx.recv().await,
+            Some(Err(ApplicationError::Cancelled))
+        ));
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+    }
 }
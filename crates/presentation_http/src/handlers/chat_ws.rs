@@ -0,0 +1,341 @@
+//! WebSocket chat handler
+//!
+//! Persistent bidirectional alternative to `POST /v1/chat/stream` for clients
+//! that keep a connection open (e.g. mobile apps). Each text frame carries a
+//! JSON chat request; the reply is streamed back as JSON frames.
+//!
+//! The connection keeps its own conversation: messages without a
+//! `conversation_id` continue the conversation of the previous message.
+
+use std::time::{Duration, Instant};
+
+use application::RequestContext;
+use axum::{
+    Extension,
+    body::Bytes,
+    extract::{
+        State,
+        ws::{CloseFrame, Message, Utf8Bytes, WebSocket, WebSocketUpgrade, close_code},
+    },
+    response::Response,
+};
+use serde::Serialize;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
+use validator::Validate;
+
+use super::chat::{StreamChatRequest, check_prompt_security, spawn_stream_forwarder};
+use crate::{
+    error::{ApiError, ErrorResponse},
+    middleware::{ClientIp, RequestId},
+    state::AppState,
+};
+
+/// Interval between server pings
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Connections silent for longer than this are considered dead
+const IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Frame sent from the server to the client
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WsServerMessage {
+    /// Part of the assistant reply
+    Chunk {
+        /// Generated text
+        content: String,
+        /// Whether this is the final chunk
+        done: bool,
+        /// Model that produced the reply (final chunk only)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        model: Option<String>,
+    },
+    /// The reply is complete and stored in the conversation
+    Done {
+        /// Conversation the exchange was appended to
+        conversation_id: String,
+    },
+    /// The message could not be processed; the connection stays open
+    Error(ErrorResponse),
+}
+
+/// Outcome of streaming one reply
+enum Flow {
+    Continue,
+    Close,
+}
+
+/// Connection metadata captured at upgrade time
+#[derive(Debug, Clone, Copy)]
+struct Peer {
+    ip: Option<std::net::IpAddr>,
+    request_id: Option<uuid::Uuid>,
+    user_id: Option<domain::UserId>,
+}
+
+/// Upgrade to a WebSocket chat connection
+///
+/// Client frames are JSON objects `{"message": "...", "conversation_id": "..."}`.
+/// The server answers with `chunk` frames, followed by a `done` frame carrying
+/// the conversation ID, or an `error` frame. The server pings every 30
+/// seconds and closes the connection with `1001 Going Away` on shutdown.
+#[utoipa::path(
+    get,
+    path = "/v1/chat/ws",
+    tag = "chat",
+    responses(
+        (status = 101, description = "Switching to the WebSocket chat protocol"),
+        (status = 400, description = "Not a WebSocket upgrade request", body = crate::error::ErrorResponse),
+        (status = 429, description = "Rate limited", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn chat_ws(
+    State(state): State<AppState>,
+    ctx: Option<Extension<RequestContext>>,
+    client_ip: Option<Extension<ClientIp>>,
+    request_id: Option<Extension<RequestId>>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let peer = Peer {
+        ip: client_ip.map(|Extension(ClientIp(ip))| ip),
+        request_id: request_id.map(|Extension(id)| id.as_uuid()),
+        user_id: ctx.map(|Extension(c)| c.user_id()),
+    };
+
+    ws.on_upgrade(move |socket| handle_socket(socket, state, peer))
+}
+
+#[instrument(skip(socket, state, peer), fields(client_ip = ?peer.ip))]
+async fn handle_socket(mut socket: WebSocket, state: AppState, peer: Peer) {
+    info!("WebSocket chat connection opened");
+
+    let mut shutdown = state.shutdown.clone();
+    let mut conversation_id: Option<String> = None;
+    let mut last_seen = Instant::now();
+    let mut ping =
+        tokio::time::interval_at(tokio::time::Instant::now() + PING_INTERVAL, PING_INTERVAL);
+
+    loop {
+        tokio::select! {
+            () = shutdown_requested(&mut shutdown) => {
+                close(&mut socket, close_code::AWAY, "Server shutting down").await;
+                break;
+            },
+            _ = ping.tick() => {
+                if last_seen.elapsed() > IDLE_TIMEOUT {
+                    debug!("WebSocket peer stopped responding, closing");
+                    close(&mut socket, close_code::AWAY, "Idle timeout").await;
+                    break;
+                }
+                if socket.send(Message::Ping(Bytes::new())).await.is_err() {
+                    break;
+                }
+            },
+            received = socket.recv() => {
+                last_seen = Instant::now();
+                match received {
+                    Some(Ok(Message::Text(text))) => {
+                        let flow = handle_message(
+                            &mut socket,
+                            &state,
+                            peer,
+                            text.as_str(),
+                            &mut conversation_id,
+                            &mut shutdown,
+                        )
+                        .await;
+                        if matches!(flow, Flow::Close) {
+                            break;
+                        }
+                    },
+                    Some(Ok(Message::Binary(_))) => {
+                        let error = ApiError::BadRequest(
+                            "Binary frames are not supported".to_string(),
+                        );
+                        let frame = WsServerMessage::Error(error.to_error_response());
+                        if send(&mut socket, &frame).await.is_err() {
+                            break;
+                        }
+                    },
+                    // Pings are answered automatically
+                    Some(Ok(Message::Ping(_) | Message::Pong(_))) => {},
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                }
+            },
+        }
+    }
+
+    info!("WebSocket chat connection closed");
+}
+
+/// Process one client frame and stream the reply
+async fn handle_message(
+    socket: &mut WebSocket,
+    state: &AppState,
+    peer: Peer,
+    text: &str,
+    conversation_id: &mut Option<String>,
+    shutdown: &mut Option<watch::Receiver<bool>>,
+) -> Flow {
+    let stream = match start_reply(state, peer, text, conversation_id).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            debug!(error = %e, "Rejected WebSocket chat message");
+            return match send(socket, &WsServerMessage::Error(e.to_error_response())).await {
+                Ok(()) => Flow::Continue,
+                Err(()) => Flow::Close,
+            };
+        },
+    };
+
+    // Aborts the backend request as soon as this reply is abandoned, e.g.
+    // on a failed send or shutdown
+    let reply = CancellationToken::new();
+    let _abandon_guard = reply.clone().drop_guard();
+    let mut rx = spawn_stream_forwarder(stream, peer.request_id, reply);
+
+    loop {
+        let item = tokio::select! {
+            () = shutdown_requested(shutdown) => {
+                close(socket, close_code::AWAY, "Server shutting down").await;
+                return Flow::Close;
+            },
+            item = rx.recv() => item,
+        };
+
+        let frame = match item {
+            Some(Ok(chunk)) => WsServerMessage::Chunk {
+                content: chunk.content,
+                done: chunk.done,
+                model: chunk.model,
+            },
+            Some(Err(e)) => WsServerMessage::Error(ApiError::from(e).to_error_response()),
+            None => WsServerMessage::Done {
+                conversation_id: conversation_id.clone().unwrap_or_default(),
+            },
+        };
+        let finished = !matches!(frame, WsServerMessage::Chunk { .. });
+
+        // Dropping `rx` on a failed send cancels the inference
+        if send(socket, &frame).await.is_err() {
+            return Flow::Close;
+        }
+        if finished {
+            return Flow::Continue;
+        }
+    }
+}
+
+/// Validate a chat frame and start the contextual inference stream
+async fn start_reply(
+    state: &AppState,
+    peer: Peer,
+    text: &str,
+    conversation_id: &mut Option<String>,
+) -> Result<application::ports::InferenceStream, ApiError> {
+    let request: StreamChatRequest = serde_json::from_str(text)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid chat message: {e}")))?;
+    request
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    check_prompt_security(state, &request.message, peer.ip).await?;
+    super::common::ensure_inference_available(state)?;
+
+    let requested = request.conversation_id.or_else(|| conversation_id.clone());
+    let (stream, conv_id) = state
+        .chat_service
+        .chat_stream_with_context_for_user(&request.message, requested.as_deref(), peer.user_id)
+        .await?;
+    *conversation_id = Some(conv_id.to_string());
+
+    Ok(stream)
+}
+
+/// Send a JSON frame
+async fn send(socket: &mut WebSocket, message: &WsServerMessage) -> Result<(), ()> {
+    let json = serde_json::to_string(message).map_err(|e| {
+        warn!(error = %e, "Failed to serialize WebSocket frame");
+    })?;
+    socket
+        .send(Message::Text(json.into()))
+        .await
+        .map_err(|e| debug!(error = %e, "WebSocket send failed"))
+}
+
+/// Send a close frame, ignoring errors from an already closed socket
+async fn close(socket: &mut WebSocket, code: u16, reason: &'static str) {
+    let frame = CloseFrame {
+        code,
+        reason: Utf8Bytes::from_static(reason),
+    };
+    if let Err(e) = socket.send(Message::Close(Some(frame))).await {
+        debug!(error = %e, "Failed to send WebSocket close frame");
+    }
+}
+
+/// Resolve once graceful shutdown has started; never resolves without a signal
+async fn shutdown_requested(shutdown: &mut Option<watch::Receiver<bool>>) {
+    let signalled = match shutdown {
+        Some(rx) => rx.wait_for(|stopping| *stopping).await.is_ok(),
+        None => false,
+    };
+    if !signalled {
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_frame_serialization() {
+        let frame = WsServerMessage::Chunk {
+            content: "Hel".to_string(),
+            done: false,
+            model: None,
+        };
+
+        let json: serde_json::Value = serde_json::to_value(&frame).unwrap();
+        assert_eq!(json["type"], "chunk");
+        assert_eq!(json["content"], "Hel");
+        assert!(json.get("model").is_none());
+    }
+
+    #[test]
+    fn error_frame_flattens_error_response() {
+        let error = ApiError::BadRequest("Invalid chat message".to_string());
+        let frame = WsServerMessage::Error(error.to_error_response());
+
+        let json: serde_json::Value = serde_json::to_value(&frame).unwrap();
+        assert_eq!(json["type"], "error");
+        assert_eq!(json["code"], "bad_request");
+        assert_eq!(json["error"], "Invalid chat message");
+    }
+
+    #[tokio::test]
+    async fn shutdown_requested_resolves_on_signal() {
+        let (tx, rx) = watch::channel(false);
+        let mut shutdown = Some(rx);
+
+        tx.send_replace(true);
+
+        tokio::time::timeout(Duration::from_secs(1), shutdown_requested(&mut shutdown))
+            .await
+            .expect("shutdown should be observed");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown_requested_pends_without_signal() {
+        let mut shutdown = None;
+
+        let result =
+            tokio::time::timeout(Duration::from_secs(1), shutdown_requested(&mut shutdown)).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use tracing::instrument;
 use utoipa::ToSchema;
 
-use crate::{error::ApiError, state::AppState};
+use crate::{error::ApiError, middleware::ClientIp, state::AppState};
 
 /// Command execution request
 #[derive(Debug, Deserialize, ToSchema)]
@@ -49,21 +49,27 @@ pub struct ExecuteCommandResponse {
     responses(
         (status = 200, description = "Command executed", body = ExecuteCommandResponse),
         (status = 400, description = "Invalid request", body = crate::error::ErrorResponse),
+        (status = 403, description = "Security policy violation", body = crate::error::ErrorResponse),
         (status = 429, description = "Rate limited", body = crate::error::ErrorResponse),
-        (status = 503, description = "Service unavailable", body = crate::error::ErrorResponse)
+        (status = 503, description = "Service unavailable or in degraded mode (with `Retry-After`)", body = crate::error::ErrorResponse)
     ),
     security(("api_key" = []))
 )]
-#[instrument(skip(state, ctx, request), fields(input_len = request.input.len()))]
+#[instrument(skip(state, ctx, client_ip, request), fields(input_len = request.input.len()))]
 pub async fn execute_command(
     State(state): State<AppState>,
     ctx: Option<Extension<RequestContext>>,
+    client_ip: Option<Extension<ClientIp>>,
     Json(request): Json<ExecuteCommandRequest>,
 ) -> Result<Json<ExecuteCommandResponse>, ApiError> {
     if request.input.trim().is_empty() {
         return Err(ApiError::BadRequest("Input cannot be empty".to_string()));
     }
 
+    let ip = client_ip.map(|Extension(ClientIp(ip))| ip);
+    super::chat::check_prompt_security(&state, &request.input, ip).await?;
+    super::common::ensure_inference_available(&state)?;
+
     // Extract user ID from request context for user-specific operations
     let user_id = ctx.map(|Extension(c)| c.user_id());
 
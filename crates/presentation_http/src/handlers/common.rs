@@ -1,16 +1,51 @@
 //! Shared helper functions for HTTP handlers
 //!
-//! Eliminates duplication between signal, whatsapp, commands, and approvals handlers.
+//! Eliminates duplication between signal, whatsapp, chat, commands, and approvals handlers.
 
-use domain::entities::AudioFormat;
+use std::sync::Arc;
+
+use application::ports::{ConversationStore, OutgoingTextMessage, RetryQueuePort};
+use axum::Extension;
+use domain::entities::{AudioFormat, ConversationSource};
 use domain::value_objects::ConversationId;
-use domain::{AgentCommand, SystemCommand};
+use domain::{AgentCommand, PhoneNumber, SystemCommand};
+use tracing::{debug, info, warn};
+
+use crate::{error::ApiError, middleware::AdminAccess, state::AppState};
+
+/// Reject the request unless the auth middleware granted admin access
+pub fn require_admin(admin: Option<Extension<AdminAccess>>) -> Result<(), ApiError> {
+    admin
+        .map(|_| ())
+        .ok_or_else(|| ApiError::Forbidden("Admin access required".to_string()))
+}
+
+/// Reject the request while the inference circuit is open
+///
+/// Returns `ApiError::Degraded` (503 with `Retry-After`) instead of letting
+/// the request fall through to the generic fallback response.
+pub fn ensure_inference_available(state: &AppState) -> Result<(), ApiError> {
+    let Some(monitor) = &state.degraded_mode else {
+        return Ok(());
+    };
+
+    if monitor.is_unavailable() {
+        debug!("Rejecting request while inference is in degraded mode");
+        return Err(ApiError::Degraded {
+            message: monitor.unavailable_message(),
+            retry_after_secs: monitor.retry_cooldown().as_secs(),
+        });
+    }
+
+    Ok(())
+}
 
 /// Get the snake_case type name of an `AgentCommand` for metrics/logging
 pub fn command_type_name(command: &AgentCommand) -> String {
     match command {
         AgentCommand::MorningBriefing { .. } => "morning_briefing",
         AgentCommand::SummarizeInbox { .. } => "summarize_inbox",
+        AgentCommand::SummarizeConversation { .. } => "summarize_conversation",
         AgentCommand::Ask { .. } => "ask",
         AgentCommand::DraftEmail { .. } => "draft_email",
         AgentCommand::SendEmail { .. } => "send_email",
@@ -23,6 +58,7 @@ pub fn command_type_name(command: &AgentCommand) -> String {
         AgentCommand::DeleteTask { .. } => "delete_task",
         AgentCommand::ListTaskLists => "list_task_lists",
         AgentCommand::CreateTaskList { .. } => "create_task_list",
+        AgentCommand::Chain { .. } => "chain",
         AgentCommand::Echo { .. } => "echo",
         AgentCommand::Help { .. } => "help",
         AgentCommand::System(sys) => match sys {
@@ -39,6 +75,7 @@ pub fn command_type_name(command: &AgentCommand) -> String {
         AgentCommand::SnoozeReminder { .. } => "snooze_reminder",
         AgentCommand::AcknowledgeReminder { .. } => "acknowledge_reminder",
         AgentCommand::DeleteReminder { .. } => "delete_reminder",
+        AgentCommand::SetTimer { .. } => "set_timer",
         AgentCommand::SearchTransit { .. } => "search_transit",
         AgentCommand::ListContacts { .. } => "list_contacts",
         AgentCommand::GetContact { .. } => "get_contact",
@@ -46,6 +83,7 @@ pub fn command_type_name(command: &AgentCommand) -> String {
         AgentCommand::UpdateContact { .. } => "update_contact",
         AgentCommand::DeleteContact { .. } => "delete_contact",
         AgentCommand::SearchContacts { .. } => "search_contacts",
+        AgentCommand::CallTool { .. } => "call_tool",
     }
     .to_string()
 }
@@ -77,23 +115,112 @@ pub fn conversation_id_from_phone(channel: &str, phone: &str) -> ConversationId
     ConversationId::from_uuid(uuid::Uuid::from_bytes(bytes))
 }
 
+/// Queue a reply that could not be sent for redelivery by the retry worker
+///
+/// Does nothing without a retry queue; the reply is then lost as before.
+pub async fn queue_failed_reply(
+    retry_queue: Option<&Arc<dyn RetryQueuePort>>,
+    recipient: &str,
+    text: &str,
+    error: &str,
+) {
+    let Some(retry_queue) = retry_queue else {
+        return;
+    };
+    let phone = match PhoneNumber::new(recipient) {
+        Ok(phone) => phone,
+        Err(e) => {
+            warn!(error = %e, "Cannot queue reply for redelivery: invalid recipient");
+            return;
+        },
+    };
+
+    match retry_queue
+        .enqueue_message(&OutgoingTextMessage::new(phone, text), error)
+        .await
+    {
+        Ok(id) => info!(id = %id, "Queued failed reply for redelivery"),
+        Err(e) => warn!(error = %e, "Failed to queue reply for redelivery"),
+    }
+}
+
+/// Apply an edit or delete made in the messenger to the stored conversation
+///
+/// `message_id` is the messenger's ID of the original message (Signal uses
+/// its timestamp). `new_text` replaces the message content; `None` removes
+/// the message from the history.
+pub async fn apply_message_revision(
+    conversation_store: Option<&Arc<dyn ConversationStore>>,
+    source: ConversationSource,
+    from: &str,
+    message_id: &str,
+    new_text: Option<&str>,
+) {
+    let Some(store) = conversation_store else {
+        return;
+    };
+    let mut conversation = match store.get_by_phone_number(source, from).await {
+        Ok(Some(conversation)) => conversation,
+        Ok(None) => {
+            debug!(message_id, "No conversation for revised message");
+            return;
+        },
+        Err(e) => {
+            warn!(error = %e, "Failed to load conversation for revised message");
+            return;
+        },
+    };
+
+    let applied = match new_text {
+        Some(text) => conversation.edit_message(message_id, text),
+        None => conversation.remove_message(message_id),
+    };
+    if !applied {
+        debug!(
+            message_id,
+            "Revised message is not in the conversation history"
+        );
+        return;
+    }
+
+    match store.save(&conversation).await {
+        Ok(()) => info!(
+            conversation_id = %conversation.id,
+            message_id,
+            deleted = new_text.is_none(),
+            "Applied message revision to conversation"
+        ),
+        Err(e) => warn!(error = %e, "Failed to persist revised conversation"),
+    }
+}
+
+/// Reply to voice messages in an audio format speech-to-text cannot read
+pub const UNSUPPORTED_AUDIO_REPLY: &str = "Sorry, I can't play this audio format yet. \
+     Please send your message as text, or as a voice note in Opus, OGG, MP3 or WAV.";
+
 /// Parse an `AudioFormat` from a MIME type string
 ///
 /// Defaults to `Ogg` for unrecognized formats (common for messenger voice messages).
 pub fn parse_audio_format(mime_type: &str) -> AudioFormat {
+    supported_audio_format(mime_type).unwrap_or(AudioFormat::Ogg)
+}
+
+/// Parse an `AudioFormat` from a MIME type string, if it is supported
+///
+/// Returns `None` for codecs such as AAC that speech-to-text cannot read.
+pub fn supported_audio_format(mime_type: &str) -> Option<AudioFormat> {
     let mime_lower = mime_type.to_lowercase();
 
     if mime_lower.contains("opus") {
-        AudioFormat::Opus
+        Some(AudioFormat::Opus)
     } else if mime_lower.contains("ogg") {
-        AudioFormat::Ogg
+        Some(AudioFormat::Ogg)
     } else if mime_lower.contains("mp3") || mime_lower.contains("mpeg") {
-        AudioFormat::Mp3
+        Some(AudioFormat::Mp3)
     } else if mime_lower.contains("wav") {
-        AudioFormat::Wav
+        Some(AudioFormat::Wav)
     } else {
-        // Default to Ogg for voice messages
-        AudioFormat::Ogg
+        None
     }
 }
 
@@ -154,6 +281,20 @@ mod tests {
         assert_eq!(parse_audio_format("unknown/type"), AudioFormat::Ogg);
     }
 
+    #[test]
+    fn supported_audio_format_rejects_unknown_codecs() {
+        assert_eq!(
+            supported_audio_format("audio/ogg; codecs=opus"),
+            Some(AudioFormat::Opus)
+        );
+        assert_eq!(
+            supported_audio_format("audio/x-wav"),
+            Some(AudioFormat::Wav)
+        );
+        assert_eq!(supported_audio_format("audio/aac"), None);
+        assert_eq!(supported_audio_format("audio/mp4"), None);
+    }
+
     #[test]
     fn format_extensions() {
         assert_eq!(format_extension(AudioFormat::Opus), "opus");
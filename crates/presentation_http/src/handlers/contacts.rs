@@ -6,6 +6,8 @@ use application::ports::{ContactError, ContactPort, ContactUpdate, NewContact};
 use axum::{
     Json,
     extract::{Path, State},
+    http::header,
+    response::IntoResponse,
 };
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
@@ -344,6 +346,64 @@ pub async fn get_contact(
     }))
 }
 
+/// Export a contact as a vCard file
+///
+/// GET /v1/contacts/:id/vcard
+#[utoipa::path(
+    get,
+    path = "/v1/contacts/{id}/vcard",
+    tag = "contacts",
+    params(
+        ("id" = String, Path, description = "Contact ID (vCard UID)")
+    ),
+    responses(
+        (status = 200, description = "vCard 3.0 file", content(
+            (String = "text/vcard")
+        )),
+        (status = 404, description = "Contact not found", body = crate::error::ErrorResponse),
+        (status = 503, description = "Service unavailable", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state))]
+pub async fn export_vcard(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let port = contact_port(&state)?;
+
+    let vcard = port.export_vcard(&id).await.map_err(map_contact_error)?;
+
+    debug!(id = %id, "Exported contact as vCard");
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/vcard; charset=utf-8".to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", vcard_filename(&id)),
+            ),
+        ],
+        vcard,
+    ))
+}
+
+/// File name for an exported contact, safe for `Content-Disposition`
+fn vcard_filename(contact_id: &str) -> String {
+    let stem: String = contact_id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    let stem = stem.trim_matches('.');
+    if stem.is_empty() {
+        "contact.vcf".to_string()
+    } else {
+        format!("contact_{stem}.vcf")
+    }
+}
+
 /// Create a new contact
 ///
 /// POST /v1/contacts
@@ -535,3 +595,22 @@ pub async fn search_contacts(
     debug!(count = response.len(), query = %body.query, "Searched contacts");
     Ok(Json(response))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vcard_filename_uses_contact_id() {
+        assert_eq!(
+            vcard_filename("550e8400-e29b-41d4-a716-446655440000"),
+            "contact_550e8400-e29b-41d4-a716-446655440000.vcf"
+        );
+    }
+
+    #[test]
+    fn vcard_filename_strips_unsafe_characters() {
+        assert_eq!(vcard_filename("../a\"b;c"), "contact_abc.vcf");
+        assert_eq!(vcard_filename("\"/"), "contact.vcf");
+    }
+}
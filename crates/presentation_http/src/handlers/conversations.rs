@@ -0,0 +1,138 @@
+//! Conversation handlers
+//!
+//! Transcript export of stored conversations. Conversations are private to
+//! the user who started them.
+
+use application::{RequestContext, ports::ExportFormat};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use domain::ConversationId;
+use serde::Deserialize;
+use tracing::{info, instrument, warn};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{error::ApiError, state::AppState};
+
+/// Conversation export query parameters
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct ExportConversationQuery {
+    /// Output format: `json` (default) or `md`
+    pub format: Option<String>,
+}
+
+impl ExportConversationQuery {
+    fn export_format(&self) -> Result<ExportFormat, ApiError> {
+        self.format
+            .as_deref()
+            .map_or(Ok(ExportFormat::default()), str::parse)
+            .map_err(ApiError::BadRequest)
+    }
+}
+
+/// Export a conversation transcript
+///
+/// GET /v1/conversations/{id}/export
+#[utoipa::path(
+    get,
+    path = "/v1/conversations/{id}/export",
+    tag = "chat",
+    params(
+        ("id" = String, Path, description = "Conversation ID"),
+        ExportConversationQuery
+    ),
+    responses(
+        (status = 200, description = "Transcript with roles and timestamps", content(
+            (String = "application/json"),
+            (String = "text/markdown")
+        )),
+        (status = 400, description = "Invalid conversation ID or format", body = crate::error::ErrorResponse),
+        (status = 403, description = "Conversation belongs to another user", body = crate::error::ErrorResponse),
+        (status = 404, description = "Conversation not found", body = crate::error::ErrorResponse),
+        (status = 503, description = "Conversation store not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, ctx, params))]
+pub async fn export_conversation(
+    State(state): State<AppState>,
+    ctx: Option<Extension<RequestContext>>,
+    Path(id): Path<String>,
+    Query(params): Query<ExportConversationQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let store = state.conversation_store.as_ref().ok_or_else(|| {
+        ApiError::ServiceUnavailable("Conversation store not configured".to_string())
+    })?;
+    let format = params.export_format()?;
+    let conversation_id = ConversationId::parse(&id)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid conversation ID: {e}")))?;
+
+    let user_id = ctx.map(|Extension(c)| c.user_id()).unwrap_or_default();
+    let conversation = store
+        .get(&conversation_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Conversation {id} not found")))?;
+    if !conversation.is_owned_by(&user_id) {
+        warn!(
+            conversation_id = %conversation_id,
+            user_id = %user_id,
+            "Conversation export by non-owner rejected"
+        );
+        return Err(ApiError::Forbidden(
+            "Conversation belongs to another user".to_string(),
+        ));
+    }
+
+    let transcript = store
+        .export(&conversation_id, format)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Conversation {id} not found")))?;
+    info!(conversation_id = %conversation_id, ?format, "💬 Conversation exported");
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"conversation_{conversation_id}.{}\"",
+                    format.extension()
+                ),
+            ),
+        ],
+        transcript,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_defaults_to_json() {
+        let params = ExportConversationQuery::default();
+        assert_eq!(params.export_format().unwrap(), ExportFormat::Json);
+    }
+
+    #[test]
+    fn markdown_format_is_parsed() {
+        let params = ExportConversationQuery {
+            format: Some("md".to_string()),
+        };
+        assert_eq!(params.export_format().unwrap(), ExportFormat::Markdown);
+    }
+
+    #[test]
+    fn unknown_format_is_rejected() {
+        let params = ExportConversationQuery {
+            format: Some("pdf".to_string()),
+        };
+        assert!(matches!(
+            params.export_format(),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+}
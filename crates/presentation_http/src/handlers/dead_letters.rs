@@ -0,0 +1,177 @@
+//! Dead letter queue handlers
+//!
+//! Admin-only endpoints to inspect operations that exhausted their retries
+//! (e.g. messenger replies that could not be delivered) and move them back
+//! to the retry queue.
+
+use std::sync::Arc;
+
+use application::ports::{DeadLetter, QueueStats, RetryQueuePort};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    error::ApiError, handlers::common::require_admin, middleware::AdminAccess, state::AppState,
+};
+
+/// Default number of dead letters listed
+const DEFAULT_LIMIT: usize = 50;
+
+/// Maximum number of dead letters listed
+const MAX_LIMIT: usize = 500;
+
+/// Dead letter listing parameters
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct DeadLetterQuery {
+    /// Maximum number of items (default: 50, max: 500)
+    pub limit: Option<usize>,
+}
+
+/// Dead-lettered operation
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "id": "4f6c2a0e-8d1b-4c5e-9f3a-2b7d6e1c0a94",
+    "original_id": "9b2e4d7a-1c3f-4e8b-a6d5-0f7c2b9e3a18",
+    "operation_type": "message",
+    "target": "+491701234567",
+    "attempt_count": 3,
+    "last_error": "External service error: signal-cli daemon unavailable",
+    "created_at": "2026-02-06T10:30:00+00:00",
+    "failed_at": "2026-02-06T10:31:30+00:00"
+}))]
+pub struct DeadLetterResponse {
+    /// Dead letter ID, used to requeue the item
+    pub id: String,
+    /// ID the item had in the retry queue
+    pub original_id: String,
+    /// Type of operation (e.g. `message`)
+    pub operation_type: String,
+    /// Target endpoint or recipient
+    pub target: String,
+    /// Number of attempts made before giving up
+    pub attempt_count: u32,
+    /// Error of the final attempt
+    pub last_error: Option<String>,
+    /// When the item was first queued (ISO 8601)
+    pub created_at: String,
+    /// When the item was dead-lettered (ISO 8601)
+    pub failed_at: String,
+}
+
+impl From<DeadLetter> for DeadLetterResponse {
+    fn from(item: DeadLetter) -> Self {
+        Self {
+            id: item.id,
+            original_id: item.original_id,
+            operation_type: item.operation_type,
+            target: item.target,
+            attempt_count: item.attempt_count,
+            last_error: item.last_error,
+            created_at: item.created_at.to_rfc3339(),
+            failed_at: item.failed_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Dead letters with overall queue statistics
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetterListResponse {
+    /// Dead letters, most recently failed first
+    pub items: Vec<DeadLetterResponse>,
+    /// Items waiting for (re)delivery
+    pub pending: u64,
+    /// Items currently being delivered
+    pub in_progress: u64,
+    /// Total number of dead letters
+    pub dead_letter: u64,
+}
+
+/// Result of requeueing a dead letter
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RequeueResponse {
+    /// ID of the item in the retry queue
+    pub id: String,
+}
+
+fn retry_queue(state: &AppState) -> Result<&Arc<dyn RetryQueuePort>, ApiError> {
+    state
+        .retry_queue
+        .as_ref()
+        .ok_or_else(|| ApiError::ServiceUnavailable("Retry queue not configured".to_string()))
+}
+
+/// List dead-lettered operations
+///
+/// GET /v1/system/dead-letters
+#[utoipa::path(
+    get,
+    path = "/v1/system/dead-letters",
+    tag = "system",
+    params(DeadLetterQuery),
+    responses(
+        (status = 200, description = "Dead letters and queue statistics", body = DeadLetterListResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 503, description = "Retry queue not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn list(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Query(params): Query<DeadLetterQuery>,
+) -> Result<Json<DeadLetterListResponse>, ApiError> {
+    require_admin(admin)?;
+    let retry_queue = retry_queue(&state)?;
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let items = retry_queue.list_dead_letters(limit).await?;
+    let QueueStats {
+        pending,
+        in_progress,
+        dead_letter,
+    } = retry_queue.queue_stats().await?;
+
+    Ok(Json(DeadLetterListResponse {
+        items: items.into_iter().map(DeadLetterResponse::from).collect(),
+        pending,
+        in_progress,
+        dead_letter,
+    }))
+}
+
+/// Move a dead-lettered operation back to the retry queue
+///
+/// POST /v1/system/dead-letters/{id}/requeue
+#[utoipa::path(
+    post,
+    path = "/v1/system/dead-letters/{id}/requeue",
+    tag = "system",
+    params(
+        ("id" = String, Path, description = "Dead letter ID")
+    ),
+    responses(
+        (status = 200, description = "Item requeued", body = RequeueResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 404, description = "Dead letter not found", body = crate::error::ErrorResponse),
+        (status = 503, description = "Retry queue not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn requeue(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Path(id): Path<String>,
+) -> Result<Json<RequeueResponse>, ApiError> {
+    require_admin(admin)?;
+    let requeued = retry_queue(&state)?.requeue(&id).await?;
+
+    info!(dead_letter_id = %id, id = %requeued, "📮 Dead letter requeued via admin endpoint");
+    Ok(Json(RequeueResponse { id: requeued }))
+}
@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use application::{HealthReport, ServiceHealth};
+use application::{HealthReport, HealthStatus, QueueStats, ServiceHealth, ServiceState};
 use axum::{Json, extract::State, http::StatusCode};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -42,6 +42,16 @@ pub struct ReadinessResponse {
     pub ready: bool,
     /// Inference engine status
     pub inference: ServiceStatus,
+    /// Database status (if a database is configured)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<ServiceStatus>,
+    /// Whether model warmup finished (always `true` if warmup is disabled)
+    #[serde(default = "warmed_up_default")]
+    pub warmed_up: bool,
+}
+
+const fn warmed_up_default() -> bool {
+    true
 }
 
 /// Status of a service
@@ -63,10 +73,34 @@ pub struct ExtendedReadinessResponse {
     /// Latency percentiles for HTTP requests
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latency: Option<LatencyPercentiles>,
+    /// Retry queue backlog (if a retry queue is configured)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<QueueStatus>,
     /// Timestamp when the check was performed
     pub checked_at: String,
 }
 
+/// Retry queue backlog for spotting failing deliveries
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QueueStatus {
+    /// Items waiting for their next retry
+    pub pending: u64,
+    /// Items currently being retried
+    pub in_progress: u64,
+    /// Items that exhausted their retries
+    pub dead_letter: u64,
+}
+
+impl From<QueueStats> for QueueStatus {
+    fn from(stats: QueueStats) -> Self {
+        Self {
+            pending: stats.pending,
+            in_progress: stats.in_progress,
+            dead_letter: stats.dead_letter,
+        }
+    }
+}
+
 /// Latency percentiles for monitoring SLOs
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct LatencyPercentiles {
@@ -119,32 +153,164 @@ impl From<HealthReport> for ExtendedReadinessResponse {
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
             latency: None, // Set by handler when metrics available
+            queue: report.queue.map(Into::into),
             checked_at: report.checked_at.to_rfc3339(),
         }
     }
 }
 
+/// Aggregate status of the configured services
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OverallStatus {
+    /// All configured services are healthy
+    Healthy,
+    /// Some configured services are down or degraded
+    Degraded,
+    /// No configured service is healthy
+    Unhealthy,
+}
+
+impl From<HealthStatus> for OverallStatus {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Healthy => Self::Healthy,
+            HealthStatus::Degraded => Self::Degraded,
+            HealthStatus::Unhealthy => Self::Unhealthy,
+        }
+    }
+}
+
+/// State of an individual service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyState {
+    /// The service responds normally
+    Up,
+    /// The service responds, but slower than `health.degraded_latency_ms`
+    Degraded,
+    /// The service is unavailable, failing or not configured
+    Down,
+}
+
+impl From<ServiceState> for DependencyState {
+    fn from(state: ServiceState) -> Self {
+        match state {
+            ServiceState::Up => Self::Up,
+            ServiceState::Degraded => Self::Degraded,
+            ServiceState::Down => Self::Down,
+        }
+    }
+}
+
+/// Per-service health with latency and last error
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DetailedServiceStatus {
+    /// Whether the service is healthy (up or degraded)
+    pub healthy: bool,
+    /// State of the service
+    pub status: DependencyState,
+    /// Additional information (model name, integrity, etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    /// Latency of the health check in milliseconds (absent on timeout)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    /// Most recent error, kept after the service recovers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl From<ServiceHealth> for DetailedServiceStatus {
+    fn from(health: ServiceHealth) -> Self {
+        Self {
+            healthy: health.healthy,
+            status: health.status.into(),
+            info: health.info,
+            latency_ms: health.response_time_ms,
+            last_error: health.last_error.or(health.error),
+        }
+    }
+}
+
+/// Detailed health response covering every configured service
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DetailedHealthResponse {
+    /// Aggregate status
+    pub status: OverallStatus,
+    /// Status of each configured service
+    pub services: HashMap<String, DetailedServiceStatus>,
+    /// Timestamp when the check was performed
+    pub checked_at: String,
+}
+
+impl From<HealthReport> for DetailedHealthResponse {
+    fn from(report: HealthReport) -> Self {
+        Self {
+            status: report.status().into(),
+            services: report
+                .services
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+            checked_at: report.checked_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Health of the inference engine alone, for servers without a `HealthService`
+async fn inference_report(state: &AppState) -> HealthReport {
+    let healthy = state.chat_service.is_healthy().await;
+    let inference = if healthy {
+        ServiceHealth::healthy_with_info(state.chat_service.current_model())
+    } else {
+        ServiceHealth::unhealthy("Inference unhealthy")
+    };
+    HealthReport::new(HashMap::from([("inference".to_string(), inference)]))
+}
+
 /// Readiness check - is the server ready to accept requests?
+///
+/// Only critical dependencies (inference and, if configured, the database)
+/// are checked; the server is not ready if any of them is down, or while
+/// the model is still being warmed up after startup.
 #[utoipa::path(
     get,
     path = "/ready",
     tag = "health",
     responses(
         (status = 200, description = "Service is ready", body = ReadinessResponse),
-        (status = 503, description = "Service is not ready", body = ReadinessResponse)
+        (status = 503, description = "A critical dependency is down or the model is warming up", body = ReadinessResponse)
     )
 )]
 pub async fn readiness_check(
     State(state): State<AppState>,
 ) -> (StatusCode, Json<ReadinessResponse>) {
-    let inference_healthy = state.chat_service.is_healthy().await;
-    let model = if inference_healthy {
-        Some(state.chat_service.current_model())
-    } else {
-        None
+    let report = match &state.health_service {
+        Some(health_service) => health_service.check_critical().await,
+        None => inference_report(&state).await,
     };
 
-    let ready = inference_healthy;
+    let inference = report.service_status("inference").map_or(
+        ServiceStatus {
+            healthy: false,
+            model: None,
+        },
+        |health| ServiceStatus {
+            healthy: health.healthy,
+            model: health.info.clone().filter(|_| health.healthy),
+        },
+    );
+    let database = report
+        .service_status("database")
+        .map(|health| ServiceStatus {
+            healthy: health.healthy,
+            model: None,
+        });
+
+    let warmed_up = state.warmup.as_ref().is_none_or(|done| *done.borrow());
+
+    let ready = report.critical_healthy() && warmed_up;
     let status_code = if ready {
         StatusCode::OK
     } else {
@@ -155,10 +321,9 @@ pub async fn readiness_check(
         status_code,
         Json(ReadinessResponse {
             ready,
-            inference: ServiceStatus {
-                healthy: inference_healthy,
-                model,
-            },
+            inference,
+            database,
+            warmed_up,
         }),
     )
 }
@@ -225,6 +390,7 @@ pub async fn extended_readiness_check(
                 ready: inference_healthy,
                 services,
                 latency,
+                queue: None,
                 checked_at: chrono::Utc::now().to_rfc3339(),
             }),
         );
@@ -243,6 +409,38 @@ pub async fn extended_readiness_check(
     (status_code, Json(response))
 }
 
+/// Detailed health of each configured service
+///
+/// Services without a configured port are omitted. Returns `degraded` when
+/// some services are down or respond slowly; only a fully unhealthy system
+/// responds with 503.
+#[utoipa::path(
+    get,
+    path = "/health/detailed",
+    tag = "health",
+    responses(
+        (status = 200, description = "All or some services healthy", body = DetailedHealthResponse),
+        (status = 503, description = "No service healthy", body = DetailedHealthResponse)
+    )
+)]
+pub async fn detailed_health_check(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<DetailedHealthResponse>) {
+    let report = match &state.health_service {
+        Some(health_service) => health_service.check_configured().await,
+        None => inference_report(&state).await,
+    };
+
+    let response: DetailedHealthResponse = report.into();
+    let status_code = if response.status == OverallStatus::Unhealthy {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    (status_code, Json(response))
+}
+
 /// Check inference engine health
 #[utoipa::path(
     get,
@@ -504,6 +702,8 @@ mod tests {
     fn readiness_response_ready() {
         let resp = ReadinessResponse {
             ready: true,
+            database: None,
+            warmed_up: true,
             inference: ServiceStatus {
                 healthy: true,
                 model: Some("qwen".to_string()),
@@ -517,6 +717,8 @@ mod tests {
     fn readiness_response_not_ready() {
         let resp = ReadinessResponse {
             ready: false,
+            database: None,
+            warmed_up: true,
             inference: ServiceStatus {
                 healthy: false,
                 model: None,
@@ -530,6 +732,8 @@ mod tests {
     fn readiness_response_serialization() {
         let resp = ReadinessResponse {
             ready: true,
+            database: None,
+            warmed_up: true,
             inference: ServiceStatus {
                 healthy: true,
                 model: None,
@@ -574,6 +778,8 @@ mod tests {
     fn readiness_response_clone() {
         let resp = ReadinessResponse {
             ready: true,
+            database: None,
+            warmed_up: true,
             inference: ServiceStatus {
                 healthy: true,
                 model: Some("qwen".to_string()),
@@ -599,6 +805,8 @@ mod tests {
     fn readiness_response_has_debug() {
         let resp = ReadinessResponse {
             ready: false,
+            database: None,
+            warmed_up: true,
             inference: ServiceStatus {
                 healthy: false,
                 model: None,
@@ -703,6 +911,7 @@ mod tests {
             ready: true,
             services,
             latency: None,
+            queue: None,
             checked_at: "2024-01-01T00:00:00Z".to_string(),
         };
         assert!(resp.ready);
@@ -732,6 +941,7 @@ mod tests {
                 avg_ms: 7.5,
                 total_requests: 100,
             }),
+            queue: None,
             checked_at: "2024-01-01T00:00:00Z".to_string(),
         };
         let json = serde_json::to_string(&resp).unwrap();
@@ -760,6 +970,7 @@ mod tests {
             ready: true,
             services: HashMap::new(),
             latency: None,
+            queue: None,
             checked_at: "2024-01-01T00:00:00Z".to_string(),
         };
         #[allow(clippy::redundant_clone)]
@@ -785,6 +996,7 @@ mod tests {
             ready: true,
             services: HashMap::new(),
             latency: None,
+            queue: None,
             checked_at: "2024-01-01T00:00:00Z".to_string(),
         };
         let debug = format!("{resp:?}");
@@ -818,5 +1030,67 @@ mod tests {
 
         assert!(resp.ready);
         assert!(resp.services.contains_key("inference"));
+        assert!(resp.queue.is_none());
+    }
+
+    #[test]
+    fn health_report_with_queue_stats_to_extended_readiness() {
+        use application::{HealthReport, QueueStats};
+
+        let report =
+            HealthReport::new(std::collections::HashMap::new()).with_queue_stats(QueueStats {
+                pending: 3,
+                in_progress: 0,
+                dead_letter: 1,
+            });
+        let resp: ExtendedReadinessResponse = report.into();
+
+        let queue = resp.queue.unwrap();
+        assert_eq!(queue.pending, 3);
+        assert_eq!(queue.dead_letter, 1);
+        let json = serde_json::to_value(&resp.services).unwrap();
+        assert!(json.as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn health_report_to_detailed_response() {
+        let services = HashMap::from([
+            (
+                "inference".to_string(),
+                ServiceHealth::healthy().with_response_time(12),
+            ),
+            (
+                "email".to_string(),
+                ServiceHealth::unhealthy("IMAP login failed"),
+            ),
+        ]);
+
+        let resp: DetailedHealthResponse = HealthReport::new(services).into();
+
+        assert_eq!(resp.status, OverallStatus::Degraded);
+        assert_eq!(resp.services["inference"].status, DependencyState::Up);
+        assert_eq!(resp.services["email"].status, DependencyState::Down);
+        assert_eq!(resp.services["inference"].latency_ms, Some(12));
+        assert_eq!(
+            resp.services["email"].last_error.as_deref(),
+            Some("IMAP login failed")
+        );
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["status"], "degraded");
+        assert_eq!(json["services"]["email"]["status"], "down");
+    }
+
+    #[test]
+    fn degraded_service_is_reported_as_degraded() {
+        let services = HashMap::from([(
+            "inference".to_string(),
+            ServiceHealth::degraded("slow response").with_response_time(1800),
+        )]);
+
+        let resp: DetailedHealthResponse = HealthReport::new(services).into();
+
+        assert_eq!(resp.status, OverallStatus::Degraded);
+        assert!(resp.services["inference"].healthy);
+        assert_eq!(resp.services["inference"].status, DependencyState::Degraded);
     }
 }
@@ -0,0 +1,114 @@
+//! Message status handlers
+//!
+//! Delivery and read status of outgoing messenger messages, as reported by
+//! WhatsApp status webhooks and Signal receipts.
+
+use application::ports::MessageDelivery;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::Serialize;
+use tracing::instrument;
+use utoipa::ToSchema;
+
+use crate::{error::ApiError, state::AppState};
+
+/// Delivery status of an outgoing message
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageStatusResponse {
+    /// Messenger-assigned message ID
+    pub message_id: String,
+    /// Messenger the message was sent through (`whatsapp` or `signal`)
+    pub source: String,
+    /// Delivery status: `sent`, `delivered`, `read` or `failed`
+    pub status: String,
+    /// Error reported by the messenger for failed deliveries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Reminder this message notified about
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_id: Option<String>,
+    /// When the message was first tracked (RFC 3339)
+    pub created_at: String,
+    /// When the status last changed (RFC 3339)
+    pub updated_at: String,
+}
+
+impl From<MessageDelivery> for MessageStatusResponse {
+    fn from(delivery: MessageDelivery) -> Self {
+        Self {
+            message_id: delivery.message_id,
+            source: delivery.source.config_key().to_string(),
+            status: delivery.status.to_string(),
+            error: delivery.error,
+            reminder_id: delivery.reminder_id.map(|id| id.to_string()),
+            created_at: delivery.created_at.to_rfc3339(),
+            updated_at: delivery.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Get the delivery status of an outgoing message
+///
+/// GET /v1/messages/{id}/status
+#[utoipa::path(
+    get,
+    path = "/v1/messages/{id}/status",
+    tag = "messages",
+    params(("id" = String, Path, description = "Messenger message ID")),
+    responses(
+        (status = 200, description = "Current delivery status", body = MessageStatusResponse),
+        (status = 404, description = "Message not tracked", body = crate::error::ErrorResponse),
+        (status = 503, description = "Delivery tracking not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state))]
+pub async fn get_message_status(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<MessageStatusResponse>, ApiError> {
+    let store = state.delivery_status.as_ref().ok_or_else(|| {
+        ApiError::ServiceUnavailable("Delivery tracking not configured".to_string())
+    })?;
+
+    let delivery = store
+        .get(&id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Message {id} not found")))?;
+
+    Ok(Json(delivery.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use domain::{DeliveryStatus, MessengerSource, ReminderId};
+
+    use super::*;
+
+    #[test]
+    fn response_from_delivery() {
+        let reminder_id = ReminderId::new();
+        let delivery = MessageDelivery {
+            message_id: "1700000000000".to_string(),
+            source: MessengerSource::Signal,
+            recipient: Some("+491701234567".to_string()),
+            status: DeliveryStatus::Read,
+            error: None,
+            reminder_id: Some(reminder_id),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let response = MessageStatusResponse::from(delivery);
+        assert_eq!(response.source, "signal");
+        assert_eq!(response.status, "read");
+        assert_eq!(response.reminder_id, Some(reminder_id.to_string()));
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("error").is_none());
+        assert!(json.get("recipient").is_none());
+    }
+}
@@ -4,13 +4,25 @@
 //! in a structured format suitable for monitoring systems.
 
 use std::{
-    sync::atomic::{AtomicU64, Ordering},
+    collections::BTreeMap,
+    fmt::Write as _,
+    sync::{
+        PoisonError, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
     time::Instant,
 };
 
-use axum::{Json, extract::State};
+use application::ports::QueueStats;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::{HeaderMap, HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use infrastructure::adapters::CircuitState;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::state::AppState;
 
@@ -126,6 +138,36 @@ pub const INFERENCE_TIME_BUCKETS_MS: &[f64] = &[
     100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0, 30000.0, 60000.0,
 ];
 
+/// Content type of the Prometheus text exposition format
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Label set identifying a single endpoint in per-endpoint request counts
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EndpointKey {
+    /// HTTP method (e.g. `GET`)
+    pub method: String,
+    /// Matched route template (e.g. `/v1/approvals/{id}`)
+    pub path: String,
+    /// Response status code
+    pub status: u16,
+}
+
+/// Circuit breaker states in the order of [`CircuitMetrics::transitions`]
+const CIRCUIT_STATES: [CircuitState; 3] = [
+    CircuitState::Closed,
+    CircuitState::Open,
+    CircuitState::HalfOpen,
+];
+
+/// Circuit breaker state as tracked from its state change events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitMetrics {
+    /// Current state
+    pub state: CircuitState,
+    /// How often the circuit entered `Closed`, `Open` and `HalfOpen`
+    pub transitions: [u64; 3],
+}
+
 /// Atomic counters for request metrics
 #[derive(Debug)]
 pub struct MetricsCollector {
@@ -174,6 +216,12 @@ pub struct MetricsCollector {
     total_prompt_analysis_time_us: AtomicU64,
     /// Number of prompt analyses performed
     total_prompt_analyses: AtomicU64,
+    /// Request counts per method, route template and status code
+    endpoint_requests: RwLock<BTreeMap<EndpointKey, u64>>,
+    /// Circuit breakers that changed state, by name
+    circuit_breakers: RwLock<BTreeMap<String, CircuitMetrics>>,
+    /// Latest retry queue depth, if a retry queue is running
+    retry_queue: RwLock<Option<QueueStats>>,
 }
 
 impl Default for MetricsCollector {
@@ -222,6 +270,9 @@ impl MetricsCollector {
             ips_blocked: AtomicU64::new(0),
             total_prompt_analysis_time_us: AtomicU64::new(0),
             total_prompt_analyses: AtomicU64::new(0),
+            endpoint_requests: RwLock::new(BTreeMap::new()),
+            circuit_breakers: RwLock::new(BTreeMap::new()),
+            retry_queue: RwLock::new(None),
         }
     }
 
@@ -261,6 +312,80 @@ impl MetricsCollector {
         }
     }
 
+    /// Record a completed request for the per-endpoint counters
+    ///
+    /// `path` should be the matched route template rather than the raw URI
+    /// so that path parameters do not blow up label cardinality.
+    pub fn record_endpoint(&self, method: &str, path: &str, status_code: u16) {
+        let key = EndpointKey {
+            method: method.to_string(),
+            path: path.to_string(),
+            status: status_code,
+        };
+        let mut endpoints = self
+            .endpoint_requests
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        *endpoints.entry(key).or_insert(0) += 1;
+    }
+
+    /// Get request counts per endpoint, ordered by method, path and status
+    #[must_use]
+    pub fn endpoint_request_counts(&self) -> Vec<(EndpointKey, u64)> {
+        self.endpoint_requests
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(key, count)| (key.clone(), *count))
+            .collect()
+    }
+
+    /// Record a circuit breaker entering `state`
+    pub fn record_circuit_transition(&self, name: &str, state: CircuitState) {
+        let mut circuits = self
+            .circuit_breakers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let circuit = circuits
+            .entry(name.to_string())
+            .or_insert_with(|| CircuitMetrics {
+                state,
+                transitions: [0; 3],
+            });
+        circuit.state = state;
+        if let Some(index) = CIRCUIT_STATES.iter().position(|s| *s == state) {
+            circuit.transitions[index] += 1;
+        }
+    }
+
+    /// Get the tracked circuit breakers, ordered by name
+    #[must_use]
+    pub fn circuit_breaker_metrics(&self) -> Vec<(String, CircuitMetrics)> {
+        self.circuit_breakers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, circuit)| (name.clone(), *circuit))
+            .collect()
+    }
+
+    /// Record the current retry queue depth
+    pub fn record_retry_queue(&self, stats: QueueStats) {
+        *self
+            .retry_queue
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(stats);
+    }
+
+    /// Get the last recorded retry queue depth
+    #[must_use]
+    pub fn retry_queue_stats(&self) -> Option<QueueStats> {
+        *self
+            .retry_queue
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Record an inference operation
     #[allow(clippy::similar_names)]
     pub fn record_inference(&self, success: bool, duration_us: u64, tokens: u64) {
@@ -472,16 +597,58 @@ impl MetricsCollector {
     }
 }
 
+/// Query parameters for the metrics endpoint
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct MetricsQuery {
+    /// Set to `prometheus` for the Prometheus text exposition format
+    pub format: Option<String>,
+}
+
 /// Get metrics endpoint
+///
+/// Returns the structured JSON metrics. Prometheus text is served when asked
+/// for with `?format=prometheus` or an `Accept` header preferring
+/// `text/plain` or OpenMetrics, as Prometheus scrapers send.
 #[utoipa::path(
     get,
     path = "/metrics",
     tag = "metrics",
+    params(MetricsQuery),
     responses(
-        (status = 200, description = "Application metrics", body = MetricsResponse)
+        (status = 200, description = "Application metrics (JSON or Prometheus text)", content(
+            (MetricsResponse = "application/json"),
+            (String = "text/plain")
+        ))
     )
 )]
-pub async fn get_metrics(State(state): State<AppState>) -> Json<MetricsResponse> {
+pub async fn get_metrics(
+    State(state): State<AppState>,
+    Query(query): Query<MetricsQuery>,
+    headers: HeaderMap,
+) -> Response {
+    if wants_prometheus(query.format.as_deref(), &headers) {
+        get_metrics_prometheus(State(state)).await.into_response()
+    } else {
+        get_metrics_json(&state).await.into_response()
+    }
+}
+
+/// Whether the query or `Accept` header asks for Prometheus text over JSON
+fn wants_prometheus(format: Option<&str>, headers: &HeaderMap) -> bool {
+    if let Some(format) = format {
+        return format.eq_ignore_ascii_case("prometheus");
+    }
+    headers
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|accept| {
+            !accept.contains("application/json")
+                && (accept.contains("text/plain") || accept.contains("openmetrics"))
+        })
+}
+
+/// Build the structured JSON metrics response
+async fn get_metrics_json(state: &AppState) -> Json<MetricsResponse> {
     let inference_healthy = state.chat_service.is_healthy().await;
     let current_model = state.chat_service.current_model();
 
@@ -513,242 +680,364 @@ pub async fn get_metrics(State(state): State<AppState>) -> Json<MetricsResponse>
         (status = 200, description = "Prometheus metrics", content_type = "text/plain")
     )
 )]
+pub async fn get_metrics_prometheus(State(state): State<AppState>) -> impl IntoResponse {
+    let inference_healthy = state.chat_service.is_healthy().await;
+    let current_model = state.chat_service.current_model();
+
+    (
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(PROMETHEUS_CONTENT_TYPE),
+        )],
+        render_prometheus(state.metrics.as_ref(), &current_model, inference_healthy),
+    )
+}
+
+/// Write a single-sample metric family with its `HELP` and `TYPE` lines
+fn write_metric(
+    output: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    value: impl std::fmt::Display,
+) {
+    let _ = write!(
+        output,
+        "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n\n"
+    );
+}
+
+/// Write a cumulative histogram family (`_bucket`, `_sum` and `_count` samples)
+fn write_histogram(
+    output: &mut String,
+    name: &str,
+    help: &str,
+    buckets: &[(f64, u64)],
+    sum: f64,
+    count: u64,
+) {
+    let _ = writeln!(output, "# HELP {name} {help}");
+    let _ = writeln!(output, "# TYPE {name} histogram");
+    for (bucket, bucket_count) in buckets {
+        let _ = writeln!(output, "{name}_bucket{{le=\"{bucket}\"}} {bucket_count}");
+    }
+    let _ = writeln!(output, "{name}_bucket{{le=\"+Inf\"}} {count}");
+    let _ = writeln!(output, "{name}_sum {sum:.2}");
+    let _ = writeln!(output, "{name}_count {count}\n");
+}
+
+/// Escape a label value according to the Prometheus text format
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Render all collected metrics in the Prometheus text exposition format
+#[must_use]
 #[allow(clippy::too_many_lines)]
-pub async fn get_metrics_prometheus(State(state): State<AppState>) -> String {
-    let metrics = state.metrics.as_ref();
+pub fn render_prometheus(
+    metrics: &MetricsCollector,
+    current_model: &str,
+    inference_healthy: bool,
+) -> String {
     let request_metrics = metrics.request_metrics();
-
-    let inference_healthy = state.chat_service.is_healthy().await;
-    let inference_metrics =
-        metrics.inference_metrics(state.chat_service.current_model(), inference_healthy);
+    let inference_metrics = metrics.inference_metrics(current_model.to_string(), inference_healthy);
+    let security_metrics = metrics.security_metrics();
 
     let mut output = String::new();
 
     // Application metrics
-    output.push_str(&format!(
-        "# HELP app_uptime_seconds Application uptime in seconds\n\
-         # TYPE app_uptime_seconds counter\n\
-         app_uptime_seconds {}\n\n",
-        metrics.uptime_seconds()
-    ));
+    write_metric(
+        &mut output,
+        "app_uptime_seconds",
+        "counter",
+        "Application uptime in seconds",
+        metrics.uptime_seconds(),
+    );
 
     // Request metrics
-    output.push_str(&format!(
-        "# HELP http_requests_total Total HTTP requests\n\
-         # TYPE http_requests_total counter\n\
-         http_requests_total {}\n\n",
-        request_metrics.total_requests
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_requests_success_total Successful HTTP requests\n\
-         # TYPE http_requests_success_total counter\n\
-         http_requests_success_total {}\n\n",
-        request_metrics.success_count
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_requests_client_error_total Client error HTTP requests\n\
-         # TYPE http_requests_client_error_total counter\n\
-         http_requests_client_error_total {}\n\n",
-        request_metrics.client_error_count
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_requests_server_error_total Server error HTTP requests\n\
-         # TYPE http_requests_server_error_total counter\n\
-         http_requests_server_error_total {}\n\n",
-        request_metrics.server_error_count
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_requests_active Current active HTTP requests\n\
-         # TYPE http_requests_active gauge\n\
-         http_requests_active {}\n\n",
-        request_metrics.active_requests
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_response_time_avg_ms Average response time in milliseconds\n\
-         # TYPE http_response_time_avg_ms gauge\n\
-         http_response_time_avg_ms {:.2}\n\n",
-        request_metrics.avg_response_time_ms
-    ));
+    write_metric(
+        &mut output,
+        "http_requests_total",
+        "counter",
+        "Total HTTP requests",
+        request_metrics.total_requests,
+    );
+    write_metric(
+        &mut output,
+        "http_requests_success_total",
+        "counter",
+        "Successful HTTP requests",
+        request_metrics.success_count,
+    );
+    write_metric(
+        &mut output,
+        "http_requests_client_error_total",
+        "counter",
+        "Client error HTTP requests",
+        request_metrics.client_error_count,
+    );
+    write_metric(
+        &mut output,
+        "http_requests_server_error_total",
+        "counter",
+        "Server error HTTP requests",
+        request_metrics.server_error_count,
+    );
+    write_metric(
+        &mut output,
+        "http_requests_active",
+        "gauge",
+        "Current active HTTP requests",
+        request_metrics.active_requests,
+    );
 
-    // Response time percentiles
-    output.push_str(&format!(
-        "# HELP http_response_time_p50_ms P50 (median) response time in milliseconds\n\
-         # TYPE http_response_time_p50_ms gauge\n\
-         http_response_time_p50_ms {:.2}\n\n",
-        request_metrics.p50_response_time_ms
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_response_time_p90_ms P90 response time in milliseconds\n\
-         # TYPE http_response_time_p90_ms gauge\n\
-         http_response_time_p90_ms {:.2}\n\n",
-        request_metrics.p90_response_time_ms
-    ));
-
-    output.push_str(&format!(
-        "# HELP http_response_time_p99_ms P99 response time in milliseconds\n\
-         # TYPE http_response_time_p99_ms gauge\n\
-         http_response_time_p99_ms {:.2}\n\n",
-        request_metrics.p99_response_time_ms
-    ));
-
-    // Response time histogram
-    output.push_str(
-        "# HELP http_response_time_ms_bucket HTTP response time histogram (cumulative)\n\
-         # TYPE http_response_time_ms_bucket histogram\n",
+    // Per-endpoint request counts
+    let _ = writeln!(
+        output,
+        "# HELP http_endpoint_requests_total HTTP requests by method, route and status\n\
+         # TYPE http_endpoint_requests_total counter"
     );
-    let response_histogram = metrics.response_time_histogram();
-    for (bucket, count) in &response_histogram {
-        output.push_str(&format!(
-            "http_response_time_ms_bucket{{le=\"{bucket}\"}} {count}\n"
-        ));
+    for (key, count) in metrics.endpoint_request_counts() {
+        let _ = writeln!(
+            output,
+            "http_endpoint_requests_total{{method=\"{}\",path=\"{}\",status=\"{}\"}} {count}",
+            escape_label_value(&key.method),
+            escape_label_value(&key.path),
+            key.status
+        );
     }
-    output.push_str(&format!(
-        "http_response_time_ms_bucket{{le=\"+Inf\"}} {}\n",
-        request_metrics.total_requests
-    ));
-    // Calculate sum from avg * count
+    output.push('\n');
+
+    write_metric(
+        &mut output,
+        "http_response_time_avg_ms",
+        "gauge",
+        "Average response time in milliseconds",
+        format_args!("{:.2}", request_metrics.avg_response_time_ms),
+    );
+
+    // Response time percentiles
+    write_metric(
+        &mut output,
+        "http_response_time_p50_ms",
+        "gauge",
+        "P50 (median) response time in milliseconds",
+        format_args!("{:.2}", request_metrics.p50_response_time_ms),
+    );
+    write_metric(
+        &mut output,
+        "http_response_time_p90_ms",
+        "gauge",
+        "P90 response time in milliseconds",
+        format_args!("{:.2}", request_metrics.p90_response_time_ms),
+    );
+    write_metric(
+        &mut output,
+        "http_response_time_p99_ms",
+        "gauge",
+        "P99 response time in milliseconds",
+        format_args!("{:.2}", request_metrics.p99_response_time_ms),
+    );
+
+    // Response time histogram (sum derived from avg * count)
     #[allow(clippy::cast_precision_loss)]
     let response_time_sum =
         request_metrics.avg_response_time_ms * request_metrics.total_requests as f64;
-    output.push_str(&format!(
-        "http_response_time_ms_sum {response_time_sum:.2}\n"
-    ));
-    output.push_str(&format!(
-        "http_response_time_ms_count {}\n\n",
-        request_metrics.total_requests
-    ));
+    write_histogram(
+        &mut output,
+        "http_response_time_ms",
+        "HTTP response time histogram in milliseconds",
+        &metrics.response_time_histogram(),
+        response_time_sum,
+        request_metrics.total_requests,
+    );
 
     // Inference metrics
-    output.push_str(&format!(
-        "# HELP inference_requests_total Total inference requests\n\
-         # TYPE inference_requests_total counter\n\
-         inference_requests_total {}\n\n",
-        inference_metrics.total_inferences
-    ));
-
-    output.push_str(&format!(
-        "# HELP inference_requests_success_total Successful inference requests\n\
-         # TYPE inference_requests_success_total counter\n\
-         inference_requests_success_total {}\n\n",
-        inference_metrics.successful_inferences
-    ));
-
-    output.push_str(&format!(
-        "# HELP inference_requests_failed_total Failed inference requests\n\
-         # TYPE inference_requests_failed_total counter\n\
-         inference_requests_failed_total {}\n\n",
-        inference_metrics.failed_inferences
-    ));
-
-    output.push_str(&format!(
-        "# HELP inference_time_avg_ms Average inference time in milliseconds\n\
-         # TYPE inference_time_avg_ms gauge\n\
-         inference_time_avg_ms {:.2}\n\n",
-        inference_metrics.avg_inference_time_ms
-    ));
+    write_metric(
+        &mut output,
+        "inference_requests_total",
+        "counter",
+        "Total inference requests",
+        inference_metrics.total_inferences,
+    );
+    write_metric(
+        &mut output,
+        "inference_requests_success_total",
+        "counter",
+        "Successful inference requests",
+        inference_metrics.successful_inferences,
+    );
+    write_metric(
+        &mut output,
+        "inference_requests_failed_total",
+        "counter",
+        "Failed inference requests",
+        inference_metrics.failed_inferences,
+    );
+    write_metric(
+        &mut output,
+        "inference_time_avg_ms",
+        "gauge",
+        "Average inference time in milliseconds",
+        format_args!("{:.2}", inference_metrics.avg_inference_time_ms),
+    );
 
     // Inference time histogram
-    output.push_str(
-        "# HELP inference_time_ms_bucket Inference time histogram (cumulative)\n\
-         # TYPE inference_time_ms_bucket histogram\n",
-    );
-    let inference_histogram = metrics.inference_time_histogram();
-    for (bucket, count) in &inference_histogram {
-        output.push_str(&format!(
-            "inference_time_ms_bucket{{le=\"{bucket}\"}} {count}\n"
-        ));
-    }
-    output.push_str(&format!(
-        "inference_time_ms_bucket{{le=\"+Inf\"}} {}\n",
-        inference_metrics.total_inferences
-    ));
     #[allow(clippy::cast_precision_loss)]
     let inference_time_sum =
         inference_metrics.avg_inference_time_ms * inference_metrics.total_inferences as f64;
-    output.push_str(&format!("inference_time_ms_sum {inference_time_sum:.2}\n"));
-    output.push_str(&format!(
-        "inference_time_ms_count {}\n\n",
-        inference_metrics.total_inferences
-    ));
-
-    output.push_str(&format!(
-        "# HELP inference_tokens_total Total tokens generated\n\
-         # TYPE inference_tokens_total counter\n\
-         inference_tokens_total {}\n\n",
-        inference_metrics.total_tokens_generated
-    ));
-
-    output.push_str(&format!(
-        "# HELP inference_healthy Inference engine health status\n\
-         # TYPE inference_healthy gauge\n\
-         inference_healthy {}\n\n",
-        i32::from(inference_metrics.healthy)
-    ));
+    write_histogram(
+        &mut output,
+        "inference_time_ms",
+        "Inference time histogram in milliseconds",
+        &metrics.inference_time_histogram(),
+        inference_time_sum,
+        inference_metrics.total_inferences,
+    );
 
-    // Security metrics
-    let security_metrics = metrics.security_metrics();
+    write_metric(
+        &mut output,
+        "inference_tokens_total",
+        "counter",
+        "Total tokens generated",
+        inference_metrics.total_tokens_generated,
+    );
+    write_metric(
+        &mut output,
+        "inference_healthy",
+        "gauge",
+        "Inference engine health status",
+        i32::from(inference_metrics.healthy),
+    );
+    let _ = writeln!(
+        output,
+        "# HELP inference_model_info Currently configured inference model\n\
+         # TYPE inference_model_info gauge\n\
+         inference_model_info{{model=\"{}\"}} 1\n",
+        escape_label_value(current_model)
+    );
 
-    output.push_str(&format!(
-        "# HELP security_prompt_injection_attempts_total Prompt injection attempts detected\n\
-         # TYPE security_prompt_injection_attempts_total counter\n\
-         security_prompt_injection_attempts_total {}\n\n",
-        security_metrics.prompt_injection_attempts
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_jailbreak_attempts_total Jailbreak attempts detected\n\
-         # TYPE security_jailbreak_attempts_total counter\n\
-         security_jailbreak_attempts_total {}\n\n",
-        security_metrics.jailbreak_attempts
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_system_prompt_leak_attempts_total System prompt leak attempts\n\
-         # TYPE security_system_prompt_leak_attempts_total counter\n\
-         security_system_prompt_leak_attempts_total {}\n\n",
-        security_metrics.system_prompt_leak_attempts
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_other_threats_total Other security threats detected\n\
-         # TYPE security_other_threats_total counter\n\
-         security_other_threats_total {}\n\n",
-        security_metrics.other_security_threats
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_blocked_requests_total Requests blocked due to security threats\n\
-         # TYPE security_blocked_requests_total counter\n\
-         security_blocked_requests_total {}\n\n",
-        security_metrics.security_blocked_requests
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_ips_blocked_total IPs blocked due to suspicious activity\n\
-         # TYPE security_ips_blocked_total counter\n\
-         security_ips_blocked_total {}\n\n",
-        security_metrics.ips_blocked
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_prompt_analysis_avg_us Average prompt analysis time in microseconds\n\
-         # TYPE security_prompt_analysis_avg_us gauge\n\
-         security_prompt_analysis_avg_us {:.2}\n\n",
-        security_metrics.avg_prompt_analysis_time_us
-    ));
-
-    output.push_str(&format!(
-        "# HELP security_prompt_analyses_total Total prompt analyses performed\n\
-         # TYPE security_prompt_analyses_total counter\n\
-         security_prompt_analyses_total {}\n",
-        security_metrics.total_prompt_analyses
-    ));
+    // Circuit breaker metrics
+    let circuits = metrics.circuit_breaker_metrics();
+    let _ = writeln!(
+        output,
+        "# HELP circuit_breaker_state Circuit breaker state (1 for the current state)\n\
+         # TYPE circuit_breaker_state gauge"
+    );
+    for (name, circuit) in &circuits {
+        for state in CIRCUIT_STATES {
+            let _ = writeln!(
+                output,
+                "circuit_breaker_state{{circuit=\"{}\",state=\"{}\"}} {}",
+                escape_label_value(name),
+                state.as_str(),
+                i32::from(circuit.state == state)
+            );
+        }
+    }
+    output.push('\n');
+    let _ = writeln!(
+        output,
+        "# HELP circuit_breaker_transitions_total Circuit breaker transitions by entered state\n\
+         # TYPE circuit_breaker_transitions_total counter"
+    );
+    for (name, circuit) in &circuits {
+        for (state, count) in CIRCUIT_STATES.iter().zip(circuit.transitions) {
+            let _ = writeln!(
+                output,
+                "circuit_breaker_transitions_total{{circuit=\"{}\",state=\"{}\"}} {count}",
+                escape_label_value(name),
+                state.as_str()
+            );
+        }
+    }
+    output.push('\n');
+
+    // Retry queue depth
+    if let Some(queue) = metrics.retry_queue_stats() {
+        write_metric(
+            &mut output,
+            "retry_queue_pending",
+            "gauge",
+            "Outgoing messages waiting for redelivery",
+            queue.pending,
+        );
+        write_metric(
+            &mut output,
+            "retry_queue_in_progress",
+            "gauge",
+            "Outgoing messages currently being redelivered",
+            queue.in_progress,
+        );
+        write_metric(
+            &mut output,
+            "retry_queue_dead_letter",
+            "gauge",
+            "Outgoing messages that exhausted their retries",
+            queue.dead_letter,
+        );
+    }
+
+    // Security metrics
+    write_metric(
+        &mut output,
+        "security_prompt_injection_attempts_total",
+        "counter",
+        "Prompt injection attempts detected",
+        security_metrics.prompt_injection_attempts,
+    );
+    write_metric(
+        &mut output,
+        "security_jailbreak_attempts_total",
+        "counter",
+        "Jailbreak attempts detected",
+        security_metrics.jailbreak_attempts,
+    );
+    write_metric(
+        &mut output,
+        "security_system_prompt_leak_attempts_total",
+        "counter",
+        "System prompt leak attempts",
+        security_metrics.system_prompt_leak_attempts,
+    );
+    write_metric(
+        &mut output,
+        "security_other_threats_total",
+        "counter",
+        "Other security threats detected",
+        security_metrics.other_security_threats,
+    );
+    write_metric(
+        &mut output,
+        "security_blocked_requests_total",
+        "counter",
+        "Requests blocked due to security threats",
+        security_metrics.security_blocked_requests,
+    );
+    write_metric(
+        &mut output,
+        "security_ips_blocked_total",
+        "counter",
+        "IPs blocked due to suspicious activity",
+        security_metrics.ips_blocked,
+    );
+    write_metric(
+        &mut output,
+        "security_prompt_analysis_avg_us",
+        "gauge",
+        "Average prompt analysis time in microseconds",
+        format_args!("{:.2}", security_metrics.avg_prompt_analysis_time_us),
+    );
+    write_metric(
+        &mut output,
+        "security_prompt_analyses_total",
+        "counter",
+        "Total prompt analyses performed",
+        security_metrics.total_prompt_analyses,
+    );
 
     output
 }
@@ -1055,4 +1344,299 @@ mod tests {
         assert_eq!(metrics.success_count, 1000);
         assert_eq!(metrics.active_requests, 0);
     }
+
+    // === Endpoint Metrics Tests ===
+
+    #[test]
+    fn record_endpoint_counts_per_label_set() {
+        let collector = MetricsCollector::new();
+        collector.record_endpoint("GET", "/health", 200);
+        collector.record_endpoint("GET", "/health", 200);
+        collector.record_endpoint("POST", "/v1/chat", 503);
+
+        let counts = collector.endpoint_request_counts();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].0.path, "/health");
+        assert_eq!(counts[0].1, 2);
+        assert_eq!(counts[1].0.method, "POST");
+        assert_eq!(counts[1].0.status, 503);
+        assert_eq!(counts[1].1, 1);
+    }
+
+    // === Prometheus Exposition Tests ===
+
+    fn is_valid_metric_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == ':')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+    }
+
+    /// Parse a label set like `{a="x",b="y"}`, returning the label pairs
+    fn parse_labels(labels: &str) -> Result<Vec<(String, String)>, String> {
+        let inner = labels
+            .strip_prefix('{')
+            .and_then(|l| l.strip_suffix('}'))
+            .ok_or_else(|| format!("unterminated label set: {labels}"))?;
+        let mut pairs = Vec::new();
+        let mut rest = inner;
+        while !rest.is_empty() {
+            let (name, after) = rest
+                .split_once("=\"")
+                .ok_or_else(|| format!("malformed label: {rest}"))?;
+            if !is_valid_metric_name(name) || name.contains(':') {
+                return Err(format!("invalid label name: {name}"));
+            }
+            let mut value = String::new();
+            let mut chars = after.char_indices();
+            let end = loop {
+                match chars.next() {
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, 'n')) => value.push('\n'),
+                        Some((_, c @ ('\\' | '"'))) => value.push(c),
+                        other => return Err(format!("invalid escape: {other:?}")),
+                    },
+                    Some((i, '"')) => break i,
+                    Some((_, c)) => value.push(c),
+                    None => return Err(format!("unterminated label value: {after}")),
+                }
+            };
+            pairs.push((name.to_string(), value));
+            rest = &after[end + 1..];
+            rest = rest.strip_prefix(',').unwrap_or(rest);
+        }
+        Ok(pairs)
+    }
+
+    /// Minimal validator for the Prometheus text exposition format (0.0.4)
+    ///
+    /// Returns the parsed samples as `(name, labels, value)` tuples.
+    fn parse_exposition(text: &str) -> Result<Vec<(String, Vec<(String, String)>, f64)>, String> {
+        let mut types = std::collections::HashMap::new();
+        let mut samples = Vec::new();
+
+        for line in text.lines() {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix("# ") {
+                let mut parts = comment.splitn(3, ' ');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some("HELP"), Some(name), Some(_)) if is_valid_metric_name(name) => {},
+                    (Some("TYPE"), Some(name), Some(kind)) if is_valid_metric_name(name) => {
+                        if !matches!(
+                            kind,
+                            "counter" | "gauge" | "histogram" | "summary" | "untyped"
+                        ) {
+                            return Err(format!("unknown metric type: {line}"));
+                        }
+                        if types.insert(name.to_string(), kind.to_string()).is_some() {
+                            return Err(format!("duplicate TYPE line: {line}"));
+                        }
+                    },
+                    _ => return Err(format!("malformed comment: {line}")),
+                }
+                continue;
+            }
+
+            let (series, value) = line
+                .rsplit_once(' ')
+                .ok_or_else(|| format!("missing value: {line}"))?;
+            let value: f64 = match value {
+                "+Inf" => f64::INFINITY,
+                "-Inf" => f64::NEG_INFINITY,
+                v => v.parse().map_err(|_| format!("invalid value: {line}"))?,
+            };
+            let (name, labels) = match series.find('{') {
+                Some(idx) => (&series[..idx], parse_labels(&series[idx..])?),
+                None => (series, Vec::new()),
+            };
+            if !is_valid_metric_name(name) {
+                return Err(format!("invalid metric name: {line}"));
+            }
+
+            let family = ["_bucket", "_sum", "_count"]
+                .iter()
+                .find_map(|suffix| {
+                    name.strip_suffix(suffix)
+                        .filter(|base| types.get(*base).is_some_and(|t| t == "histogram"))
+                })
+                .unwrap_or(name);
+            if !types.contains_key(family) {
+                return Err(format!("sample without TYPE: {line}"));
+            }
+            if name.ends_with("_bucket") && !labels.iter().any(|(l, _)| l == "le") {
+                return Err(format!("histogram bucket without le label: {line}"));
+            }
+
+            samples.push((name.to_string(), labels, value));
+        }
+
+        Ok(samples)
+    }
+
+    #[test]
+    fn prometheus_output_is_valid_exposition() {
+        let collector = MetricsCollector::new();
+        collector.request_start();
+        collector.request_end(12_000, 200);
+        collector.request_start();
+        collector.request_end(3_000, 404);
+        collector.record_endpoint("GET", "/v1/approvals/{id}", 200);
+        collector.record_endpoint("GET", "/weird\"path", 404);
+        collector.record_inference(true, 1_500_000, 42);
+
+        let output = render_prometheus(&collector, "qwen2.5:1.5b", true);
+        let samples = parse_exposition(&output).unwrap();
+
+        let value_of = |name: &str| {
+            samples
+                .iter()
+                .find(|(n, labels, _)| n == name && labels.is_empty())
+                .map(|(_, _, v)| *v)
+        };
+        assert_eq!(value_of("http_requests_total"), Some(2.0));
+        assert_eq!(value_of("http_response_time_ms_count"), Some(2.0));
+        assert_eq!(value_of("inference_time_ms_count"), Some(1.0));
+        assert_eq!(value_of("inference_tokens_total"), Some(42.0));
+
+        let endpoint = samples
+            .iter()
+            .find(|(n, labels, _)| {
+                n == "http_endpoint_requests_total"
+                    && labels.contains(&("path".to_string(), "/weird\"path".to_string()))
+            })
+            .unwrap();
+        assert!((endpoint.2 - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn prometheus_histogram_buckets_are_cumulative() {
+        let collector = MetricsCollector::new();
+        for duration_us in [4_000, 30_000, 700_000] {
+            collector.request_start();
+            collector.request_end(duration_us, 200);
+        }
+
+        let output = render_prometheus(&collector, "model", false);
+        let samples = parse_exposition(&output).unwrap();
+        let buckets: Vec<f64> = samples
+            .iter()
+            .filter(|(n, _, _)| n == "http_response_time_ms_bucket")
+            .map(|(_, _, v)| *v)
+            .collect();
+
+        assert_eq!(buckets.len(), RESPONSE_TIME_BUCKETS_MS.len() + 1);
+        assert!(buckets.windows(2).all(|w| w[0] <= w[1]));
+        assert!((buckets[buckets.len() - 1] - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn prometheus_circuit_breaker_gauges() {
+        let collector = MetricsCollector::new();
+        collector.record_circuit_transition("weather", CircuitState::Open);
+        collector.record_circuit_transition("weather", CircuitState::HalfOpen);
+        collector.record_circuit_transition("weather", CircuitState::Open);
+        collector.record_circuit_transition("caldav-calendar", CircuitState::Closed);
+
+        let output = render_prometheus(&collector, "model", true);
+        let samples = parse_exposition(&output).unwrap();
+        let value_of = |name: &str, circuit: &str, state: &str| {
+            samples
+                .iter()
+                .find(|(n, labels, _)| {
+                    n == name
+                        && labels.contains(&("circuit".to_string(), circuit.to_string()))
+                        && labels.contains(&("state".to_string(), state.to_string()))
+                })
+                .map(|(_, _, v)| *v)
+        };
+
+        assert_eq!(
+            value_of("circuit_breaker_state", "weather", "open"),
+            Some(1.0)
+        );
+        assert_eq!(
+            value_of("circuit_breaker_state", "weather", "half_open"),
+            Some(0.0)
+        );
+        assert_eq!(
+            value_of("circuit_breaker_state", "caldav-calendar", "closed"),
+            Some(1.0)
+        );
+        assert_eq!(
+            value_of("circuit_breaker_transitions_total", "weather", "open"),
+            Some(2.0)
+        );
+        assert_eq!(
+            value_of("circuit_breaker_transitions_total", "weather", "half_open"),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn prometheus_retry_queue_gauges() {
+        let collector = MetricsCollector::new();
+        let output = render_prometheus(&collector, "model", true);
+        assert!(!output.contains("retry_queue_pending"));
+
+        collector.record_retry_queue(QueueStats {
+            pending: 3,
+            in_progress: 1,
+            dead_letter: 2,
+        });
+        let output = render_prometheus(&collector, "model", true);
+        let samples = parse_exposition(&output).unwrap();
+        let value_of = |name: &str| {
+            samples
+                .iter()
+                .find(|(n, _, _)| n == name)
+                .map(|(_, _, v)| *v)
+        };
+
+        assert_eq!(value_of("retry_queue_pending"), Some(3.0));
+        assert_eq!(value_of("retry_queue_in_progress"), Some(1.0));
+        assert_eq!(value_of("retry_queue_dead_letter"), Some(2.0));
+    }
+
+    #[test]
+    fn exposition_validator_rejects_untyped_samples() {
+        assert!(parse_exposition("orphan_metric 1\n").is_err());
+        assert!(parse_exposition("# TYPE x counter\nx{le=\"1} 2\n").is_err());
+    }
+
+    #[test]
+    fn json_is_the_default_format() {
+        let mut headers = HeaderMap::new();
+        assert!(!wants_prometheus(None, &headers));
+
+        headers.insert(header::ACCEPT, HeaderValue::from_static("*/*"));
+        assert!(!wants_prometheus(None, &headers));
+
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
+        assert!(!wants_prometheus(None, &headers));
+    }
+
+    #[test]
+    fn prometheus_is_served_on_request() {
+        let mut headers = HeaderMap::new();
+        assert!(wants_prometheus(Some("prometheus"), &headers));
+        assert!(!wants_prometheus(Some("json"), &headers));
+
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("text/plain;version=0.0.4"),
+        );
+        assert!(wants_prometheus(None, &headers));
+
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/openmetrics-text;version=1.0.0;q=0.5,text/plain;version=0.0.4;q=0.4,*/*;q=0.1",
+            ),
+        );
+        assert!(wants_prometheus(None, &headers));
+        assert!(!wants_prometheus(Some("json"), &headers));
+    }
 }
@@ -1,12 +1,21 @@
 //! HTTP request handlers
 
 pub mod approvals;
+pub mod audit;
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod chat;
+pub mod chat_ws;
 pub mod commands;
 pub mod common;
 pub mod contacts;
+pub mod conversations;
+pub mod dead_letters;
 pub mod health;
+pub mod messages;
 pub mod metrics;
+pub mod security;
 pub mod signal;
 pub mod system;
+pub mod system_prompts;
 pub mod whatsapp;
@@ -0,0 +1,176 @@
+//! Security admin handlers
+//!
+//! Lets admins review IP blocks set by the suspicious activity tracker and
+//! lift them, e.g. when a legitimate user trips the prompt-injection detector.
+
+use std::{net::IpAddr, sync::Arc};
+
+use application::ports::{BlockedEntry, SuspiciousActivityPort};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Serialize;
+use tracing::{info, instrument};
+use utoipa::ToSchema;
+
+use crate::{
+    error::ApiError, handlers::common::require_admin, middleware::AdminAccess, state::AppState,
+};
+
+/// Active IP block
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "ip": "203.0.113.7",
+    "reason": "PromptInjection (high)",
+    "violation_count": 3,
+    "blocked_until": "2026-02-07T10:30:00+00:00"
+}))]
+pub struct BlockResponse {
+    /// Blocked IP address
+    pub ip: String,
+    /// Why the IP was blocked (violation category and level, or `manual`)
+    pub reason: String,
+    /// Number of recorded violations for the IP
+    pub violation_count: u32,
+    /// When the block expires (ISO 8601)
+    pub blocked_until: String,
+}
+
+impl From<BlockedEntry> for BlockResponse {
+    fn from(entry: BlockedEntry) -> Self {
+        Self {
+            ip: entry.ip.to_string(),
+            reason: entry.reason,
+            violation_count: entry.violation_count,
+            blocked_until: entry.blocked_until.to_rfc3339(),
+        }
+    }
+}
+
+/// List of active IP blocks
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BlockListResponse {
+    /// Active blocks, soonest expiry first
+    pub blocks: Vec<BlockResponse>,
+    /// Number of active blocks
+    pub total: usize,
+}
+
+fn tracker(state: &AppState) -> Result<&Arc<dyn SuspiciousActivityPort>, ApiError> {
+    state.suspicious_activity_tracker.as_ref().ok_or_else(|| {
+        ApiError::ServiceUnavailable("Suspicious activity tracking not enabled".to_string())
+    })
+}
+
+fn parse_ip(id: &str) -> Result<IpAddr, ApiError> {
+    id.parse()
+        .map_err(|_| ApiError::BadRequest(format!("Invalid IP address: {id}")))
+}
+
+/// List active IP blocks
+///
+/// GET /v1/security/blocks
+#[utoipa::path(
+    get,
+    path = "/v1/security/blocks",
+    tag = "security",
+    responses(
+        (status = 200, description = "Active IP blocks", body = BlockListResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 503, description = "Prompt security disabled", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn list_blocks(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+) -> Result<Json<BlockListResponse>, ApiError> {
+    require_admin(admin)?;
+    let blocks: Vec<BlockResponse> = tracker(&state)?
+        .list_blocked()
+        .await
+        .into_iter()
+        .map(BlockResponse::from)
+        .collect();
+
+    Ok(Json(BlockListResponse {
+        total: blocks.len(),
+        blocks,
+    }))
+}
+
+/// Lift an IP block and clear its violation history
+///
+/// DELETE /v1/security/blocks/{id}
+#[utoipa::path(
+    delete,
+    path = "/v1/security/blocks/{id}",
+    tag = "security",
+    params(
+        ("id" = String, Path, description = "Blocked IP address")
+    ),
+    responses(
+        (status = 204, description = "Block lifted"),
+        (status = 400, description = "Invalid IP address", body = crate::error::ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 404, description = "IP is not blocked", body = crate::error::ErrorResponse),
+        (status = 503, description = "Prompt security disabled", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn unblock(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    require_admin(admin)?;
+    let tracker = tracker(&state)?;
+    let ip = parse_ip(&id)?;
+
+    if tracker.unblock(ip).await {
+        info!(ip = %ip, "🛡️ IP block lifted via admin endpoint");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::NotFound(format!("IP {ip} is not blocked")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    #[test]
+    fn parses_ipv4_and_ipv6() {
+        assert_eq!(
+            parse_ip("203.0.113.7").unwrap(),
+            "203.0.113.7".parse::<IpAddr>().unwrap()
+        );
+        assert!(parse_ip("::1").unwrap().is_loopback());
+        assert!(matches!(
+            parse_ip("+4915112345678"),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn block_response_from_entry() {
+        let until = Utc::now();
+        let response = BlockResponse::from(BlockedEntry {
+            ip: "203.0.113.7".parse().unwrap(),
+            reason: "manual".to_string(),
+            violation_count: 2,
+            blocked_until: until,
+        });
+
+        assert_eq!(response.ip, "203.0.113.7");
+        assert_eq!(response.reason, "manual");
+        assert_eq!(response.violation_count, 2);
+        assert_eq!(response.blocked_until, until.to_rfc3339());
+    }
+}
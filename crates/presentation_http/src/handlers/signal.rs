@@ -3,10 +3,11 @@
 //! Handles message polling from signal-cli daemon and message processing.
 //! Signal uses a polling model rather than webhooks.
 
+use application::error::ApplicationError;
 use application::ports::SynthesisResult;
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use domain::PhoneNumber;
-use domain::entities::{Conversation, ConversationSource};
+use domain::entities::{ChatMessage, Conversation, ConversationSource};
 use integration_signal::Attachment;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info, instrument, warn};
@@ -300,10 +301,34 @@ pub async fn poll_messages(
             continue;
         }
 
+        // Keep the history in line with edits made in Signal
+        if let Some((target, body)) = envelope.edited_text() {
+            super::common::apply_message_revision(
+                state.conversation_store.as_ref(),
+                ConversationSource::Signal,
+                sender,
+                &target.to_string(),
+                Some(body),
+            )
+            .await;
+        }
+
         // Process based on message type
         if let Some(data_message) = envelope.data_message {
             let timestamp = data_message.timestamp;
 
+            if let Some(target) = data_message.deleted_timestamp() {
+                super::common::apply_message_revision(
+                    state.conversation_store.as_ref(),
+                    ConversationSource::Signal,
+                    sender,
+                    &target.to_string(),
+                    None,
+                )
+                .await;
+                continue;
+            }
+
             // Handle text messages
             if let Some(ref body) = data_message.body {
                 let response =
@@ -400,11 +425,15 @@ async fn handle_text_message(
         Conversation::for_messenger(ConversationSource::Signal, phone)
     };
 
-    // Add user message to conversation
-    conversation.add_user_message(text);
+    // Add user message to conversation, keyed by its Signal timestamp
+    conversation.add_message(ChatMessage::user(text).with_external_id(timestamp.to_string()));
 
-    // Process message through agent service
-    let result = state.agent_service.handle_input(text).await;
+    // Process message through agent service; a newer message from the same
+    // sender cancels this one
+    let result = state
+        .agent_service
+        .handle_input_for_sender(from, text, None)
+        .await;
 
     match result {
         Ok(agent_result) => {
@@ -438,6 +467,13 @@ async fn handle_text_message(
                     from = %from,
                     "Failed to send Signal response"
                 );
+                super::common::queue_failed_reply(
+                    state.retry_queue.as_ref(),
+                    from,
+                    &response_text,
+                    &e.to_string(),
+                )
+                .await;
             } else {
                 info!(
                     timestamp = timestamp,
@@ -460,6 +496,21 @@ async fn handle_text_message(
                 response_type: Some("text".to_string()),
             }
         },
+        Err(ApplicationError::Cancelled) => {
+            info!(
+                timestamp = timestamp,
+                from = %from,
+                "Signal text message superseded by a newer one, no reply sent"
+            );
+
+            MessageResponse {
+                timestamp,
+                from: from.to_string(),
+                status: "cancelled".to_string(),
+                response: None,
+                response_type: None,
+            }
+        },
         Err(e) => {
             error!(
                 error = %e,
@@ -561,8 +612,19 @@ async fn handle_audio_message(
         "Read audio from Signal attachment"
     );
 
-    // Parse audio format
-    let format = super::common::parse_audio_format(&attachment.content_type);
+    let Some(format) = super::common::supported_audio_format(&attachment.content_type) else {
+        warn!(content_type = %attachment.content_type, "Unsupported Signal audio format");
+        let _ = signal_client
+            .send_text(from, super::common::UNSUPPORTED_AUDIO_REPLY)
+            .await;
+        return MessageResponse {
+            timestamp,
+            from: from.to_string(),
+            status: "unsupported".to_string(),
+            response: Some(super::common::UNSUPPORTED_AUDIO_REPLY.to_string()),
+            response_type: Some("text".to_string()),
+        };
+    };
 
     // Create a deterministic conversation ID from phone number
     let conversation_id = super::common::conversation_id_from_phone("signal", from);
@@ -588,8 +650,13 @@ async fn handle_audio_message(
                 "Voice message processed successfully"
             );
 
-            // Send response (audio if available, otherwise text)
-            let response_type = if let Some(ref audio_response) = voice_result.response_audio {
+            // Send response (audio if available and enabled, otherwise text)
+            let voice_replies = state.config.load().signal.voice_replies;
+            let audio_response = voice_result
+                .response_audio
+                .as_ref()
+                .filter(|_| voice_replies);
+            let response_type = if let Some(audio_response) = audio_response {
                 // Write audio to temp file and send
                 match send_audio_response(signal_client, from, audio_response).await {
                     Ok(()) => "audio".to_string(),
@@ -1,10 +1,12 @@
 //! System handlers
 
 use axum::{Json, extract::State};
+use infrastructure::{AppConfig, AsyncDatabase};
 use serde::Serialize;
+use tracing::warn;
 use utoipa::ToSchema;
 
-use crate::state::AppState;
+use crate::{error::ApiError, state::AppState};
 
 /// System status response
 #[derive(Debug, Serialize, ToSchema)]
@@ -46,12 +48,114 @@ pub async fn status(State(state): State<AppState>) -> Json<StatusResponse> {
     })
 }
 
+/// System information response
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "version": "0.1.0",
+    "git_commit": "3f2a9c1b7d4e",
+    "uptime_secs": 86400,
+    "messenger": "signal",
+    "integrations": ["weather", "caldav", "proton"],
+    "model": "qwen2.5-1.5b-instruct",
+    "database_version": "SQLite 3.45.0",
+    "schema_version": 12,
+    "latest_schema_version": 12
+}))]
+pub struct SystemInfoResponse {
+    /// Application version
+    pub version: String,
+    /// Git commit the server was built from (if known at build time)
+    pub git_commit: Option<String>,
+    /// Seconds since the server started
+    pub uptime_secs: u64,
+    /// Active messenger (`whatsapp` or `signal`, if enabled)
+    pub messenger: Option<String>,
+    /// Integrations with a configuration section
+    pub integrations: Vec<String>,
+    /// Current model name
+    pub model: String,
+    /// Database engine version (if a database is configured)
+    pub database_version: Option<String>,
+    /// Version of the most recently applied schema migration
+    pub schema_version: Option<i64>,
+    /// Latest schema version bundled with this build
+    pub latest_schema_version: Option<i64>,
+}
+
+/// Names of the integrations configured in `config`
+fn configured_integrations(config: &AppConfig) -> Vec<String> {
+    [
+        ("weather", config.weather.is_some()),
+        ("websearch", config.websearch.is_some()),
+        ("caldav", config.caldav.is_some()),
+        ("carddav", config.carddav.is_some()),
+        ("proton", config.proton.is_some()),
+        ("transit", config.transit.is_some()),
+        ("speech", config.speech.is_some()),
+        ("memory", config.memory.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, configured)| configured.then(|| name.to_string()))
+    .collect()
+}
+
+/// Get system information for support requests
+///
+/// Includes build metadata, uptime, the active messenger, configured
+/// integrations and the database schema version.
+#[utoipa::path(
+    get,
+    path = "/v1/system/info",
+    tag = "system",
+    responses(
+        (status = 200, description = "System information", body = SystemInfoResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn info(State(state): State<AppState>) -> Json<SystemInfoResponse> {
+    let database = match &state.health_service {
+        Some(health_service) => health_service.database_details().await,
+        None => None,
+    };
+
+    let config = state.config.load();
+
+    Json(SystemInfoResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: option_env!("PISOVEREIGN_GIT_COMMIT").map(str::to_string),
+        uptime_secs: state.started_at.elapsed().as_secs(),
+        messenger: config
+            .messenger
+            .is_enabled()
+            .then(|| config.messenger.to_string()),
+        integrations: configured_integrations(&config),
+        model: state.chat_service.current_model(),
+        database_version: database.as_ref().and_then(|d| d.version.clone()),
+        schema_version: database.and_then(|d| d.schema_version),
+        latest_schema_version: AsyncDatabase::available_migrations()
+            .last()
+            .map(|m| m.version),
+    })
+}
+
 /// Models list response
 #[derive(Debug, Serialize, ToSchema)]
 #[schema(example = json!({
     "current": "qwen2.5-1.5b-instruct",
     "available": [
-        {"name": "qwen2.5-1.5b-instruct", "description": "Qwen 1.5B - General purpose", "parameters": "1.5B"}
+        {
+            "name": "qwen2.5-1.5b-instruct",
+            "description": "Qwen 1.5B - General purpose language model",
+            "parameters": "1.5B",
+            "context_length": 32768,
+            "capabilities": {
+                "chat": true,
+                "embeddings": false,
+                "code": false,
+                "tools": true,
+                "vision": false
+            }
+        }
     ]
 }))]
 pub struct ModelsResponse {
@@ -70,9 +174,100 @@ pub struct ModelInfo {
     pub description: String,
     /// Model parameter count
     pub parameters: String,
+    /// Context window in tokens (if known)
+    pub context_length: Option<u32>,
+    /// What the model supports; `None` if the backend cannot be queried
+    pub capabilities: Option<ModelCapabilitiesInfo>,
+}
+
+/// Capabilities of a model
+#[derive(Debug, Serialize, ToSchema)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ModelCapabilitiesInfo {
+    /// Chat and text generation
+    pub chat: bool,
+    /// Text embeddings
+    pub embeddings: bool,
+    /// Code completion
+    pub code: bool,
+    /// Tool/function calling
+    pub tools: bool,
+    /// Image input
+    pub vision: bool,
+}
+
+impl From<application::ports::ModelCapabilities> for ModelCapabilitiesInfo {
+    fn from(capabilities: application::ports::ModelCapabilities) -> Self {
+        Self {
+            chat: capabilities.chat || capabilities.text_generation,
+            embeddings: capabilities.embeddings,
+            code: capabilities.code,
+            tools: capabilities.tools,
+            vision: capabilities.vision,
+        }
+    }
+}
+
+impl From<application::ports::ModelInfo> for ModelInfo {
+    fn from(model: application::ports::ModelInfo) -> Self {
+        let parameters = model
+            .parameter_size
+            .unwrap_or_else(|| parameters_from_name(&model.id));
+        let description = model
+            .description
+            .unwrap_or_else(|| describe_model(&model.id, &parameters));
+
+        Self {
+            name: model.id,
+            description,
+            parameters,
+            context_length: model.capabilities.context_length,
+            capabilities: Some(model.capabilities.into()),
+        }
+    }
+}
+
+impl ModelInfo {
+    /// Model info guessed from the name alone
+    fn from_name(name: String) -> Self {
+        let parameters = parameters_from_name(&name);
+        let description = describe_model(&name, &parameters);
+
+        Self {
+            name,
+            description,
+            parameters,
+            context_length: None,
+            capabilities: None,
+        }
+    }
+}
+
+/// Extract the parameter size from a model name (e.g., "qwen2.5-1.5b-instruct" → "1.5B")
+fn parameters_from_name(name: &str) -> String {
+    name.split(['-', ':'])
+        .find(|part| part.ends_with('b'))
+        .map_or_else(|| "Unknown".to_string(), str::to_uppercase)
+}
+
+/// Create a description based on the model name
+fn describe_model(name: &str, parameters: &str) -> String {
+    if name.contains("qwen") {
+        format!("Qwen {parameters} - General purpose language model")
+    } else if name.contains("llama") {
+        format!("Llama {parameters} - Meta's language model")
+    } else if name.contains("phi") {
+        format!("Phi {parameters} - Microsoft's small language model")
+    } else {
+        format!("{name} - Language model")
+    }
 }
 
 /// List available models
+///
+/// With a model registry, each model includes its context window and
+/// capabilities as reported by Ollama's `/api/show`. Otherwise only names
+/// and guessed parameter sizes are returned.
 #[utoipa::path(
     get,
     path = "/v1/system/models",
@@ -83,45 +278,109 @@ pub struct ModelInfo {
     security(("api_key" = []))
 )]
 pub async fn list_models(State(state): State<AppState>) -> Json<ModelsResponse> {
+    let current = state.chat_service.current_model();
+
+    if let Some(ref registry) = state.model_registry {
+        match registry.list_models().await {
+            Ok(models) => {
+                return Json(ModelsResponse {
+                    current,
+                    available: models.into_iter().map(ModelInfo::from).collect(),
+                });
+            },
+            Err(e) => warn!(error = %e, "Model registry unavailable, listing names only"),
+        }
+    }
+
     // Query actual available models from Hailo
     let model_names = state
         .chat_service
         .list_available_models()
         .await
-        .unwrap_or_else(|_| vec![state.chat_service.current_model()]);
+        .unwrap_or_else(|_| vec![current.clone()]);
+
+    Json(ModelsResponse {
+        current,
+        available: model_names.into_iter().map(ModelInfo::from_name).collect(),
+    })
+}
+
+/// Voices list response
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "default": "de_DE-thorsten-medium",
+    "available": [
+        {
+            "id": "de_DE-thorsten-medium",
+            "name": "thorsten (de_DE)",
+            "description": "Piper voice: de_DE-thorsten-medium (medium quality)",
+            "languages": ["de-DE"],
+            "gender": "male"
+        }
+    ]
+}))]
+pub struct VoicesResponse {
+    /// Voice used for replies unless another is configured
+    pub default: String,
+    /// Voices available for synthesis
+    pub available: Vec<VoiceInfo>,
+}
 
-    let available = model_names
+/// Information about a voice
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VoiceInfo {
+    /// Voice identifier
+    pub id: String,
+    /// Human-readable name
+    pub name: String,
+    /// Voice description
+    pub description: Option<String>,
+    /// Supported language codes
+    pub languages: Vec<String>,
+    /// Voice gender (`male`, `female` or `neutral`), if known
+    pub gender: Option<String>,
+}
+
+impl From<application::ports::VoiceInfo> for VoiceInfo {
+    fn from(voice: application::ports::VoiceInfo) -> Self {
+        Self {
+            id: voice.id,
+            name: voice.name,
+            description: voice.description,
+            languages: voice.languages,
+            gender: voice.gender,
+        }
+    }
+}
+
+/// List available text-to-speech voices
+#[utoipa::path(
+    get,
+    path = "/v1/system/voices",
+    tag = "system",
+    responses(
+        (status = 200, description = "Available voices", body = VoicesResponse),
+        (status = 503, description = "Speech not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_voices(State(state): State<AppState>) -> Result<Json<VoicesResponse>, ApiError> {
+    let voice_service = state
+        .voice_message_service
+        .as_ref()
+        .ok_or_else(|| ApiError::ServiceUnavailable("Speech not configured".to_string()))?;
+
+    let available = voice_service
+        .list_voices()
+        .await?
         .into_iter()
-        .map(|name| {
-            // Extract parameter size from model name (e.g., "qwen2.5-1.5b-instruct" → "1.5B")
-            let parameters = name
-                .split('-')
-                .find(|part| part.ends_with('b'))
-                .map_or_else(|| "Unknown".to_string(), str::to_uppercase);
-
-            // Create description based on model name
-            let description = if name.contains("qwen") {
-                format!("Qwen {parameters} - General purpose language model")
-            } else if name.contains("llama") {
-                format!("Llama {parameters} - Meta's language model")
-            } else if name.contains("phi") {
-                format!("Phi {parameters} - Microsoft's small language model")
-            } else {
-                format!("{name} - Language model")
-            };
-
-            ModelInfo {
-                name,
-                description,
-                parameters,
-            }
-        })
+        .map(VoiceInfo::from)
         .collect();
 
-    Json(ModelsResponse {
-        current: state.chat_service.current_model(),
+    Ok(Json(VoicesResponse {
+        default: voice_service.config().default_voice.clone(),
         available,
-    })
+    }))
 }
 
 #[cfg(test)]
@@ -154,6 +413,36 @@ mod tests {
         assert!(debug.contains("StatusResponse"));
     }
 
+    #[test]
+    fn system_info_response_serialize() {
+        let response = SystemInfoResponse {
+            version: "0.1.0".to_string(),
+            git_commit: Some("3f2a9c1b7d4e".to_string()),
+            uptime_secs: 42,
+            messenger: Some("signal".to_string()),
+            integrations: vec!["weather".to_string()],
+            model: "qwen".to_string(),
+            database_version: None,
+            schema_version: Some(12),
+            latest_schema_version: Some(12),
+        };
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"schema_version\":12"));
+        assert!(json.contains("\"database_version\":null"));
+        assert!(json.contains("\"uptime_secs\":42"));
+        assert!(json.contains("\"integrations\":[\"weather\"]"));
+    }
+
+    #[test]
+    fn configured_integrations_lists_present_sections() {
+        let mut config = AppConfig::default();
+        assert!(configured_integrations(&config).is_empty());
+
+        config.weather = Some(infrastructure::WeatherConfig::default());
+        config.transit = Some(infrastructure::config::TransitAppConfig::default());
+        assert_eq!(configured_integrations(&config), vec!["weather", "transit"]);
+    }
+
     #[test]
     fn models_response_serialize() {
         let response = ModelsResponse {
@@ -162,6 +451,8 @@ mod tests {
                 name: "qwen".to_string(),
                 description: "Qwen model".to_string(),
                 parameters: "1.5B".to_string(),
+                context_length: None,
+                capabilities: None,
             }],
         };
         let json = serde_json::to_string(&response).unwrap();
@@ -169,6 +460,25 @@ mod tests {
         assert!(json.contains("1.5B"));
     }
 
+    #[test]
+    fn voices_response_serialize() {
+        let voice = application::ports::VoiceInfo {
+            id: "nova".to_string(),
+            name: "Nova".to_string(),
+            description: None,
+            languages: vec!["en".to_string()],
+            gender: Some("female".to_string()),
+        };
+        let response = VoicesResponse {
+            default: "nova".to_string(),
+            available: vec![VoiceInfo::from(voice)],
+        };
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"default\":\"nova\""));
+        assert!(json.contains("\"gender\":\"female\""));
+        assert!(json.contains("\"languages\":[\"en\"]"));
+    }
+
     #[test]
     fn models_response_debug() {
         let response = ModelsResponse {
@@ -185,6 +495,8 @@ mod tests {
             name: "llama".to_string(),
             description: "Llama model".to_string(),
             parameters: "1B".to_string(),
+            context_length: None,
+            capabilities: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains("llama"));
@@ -197,6 +509,8 @@ mod tests {
             name: "test".to_string(),
             description: "Test model".to_string(),
             parameters: "100M".to_string(),
+            context_length: None,
+            capabilities: None,
         };
         let debug = format!("{info:?}");
         assert!(debug.contains("ModelInfo"));
@@ -242,14 +556,57 @@ mod tests {
                     name: "qwen".to_string(),
                     description: "Qwen".to_string(),
                     parameters: "1.5B".to_string(),
+                    context_length: None,
+                    capabilities: None,
                 },
                 ModelInfo {
                     name: "llama".to_string(),
                     description: "Llama".to_string(),
                     parameters: "1B".to_string(),
+                    context_length: None,
+                    capabilities: None,
                 },
             ],
         };
         assert_eq!(response.available.len(), 2);
     }
+
+    #[test]
+    fn model_info_from_registry() {
+        let model = application::ports::ModelInfo {
+            id: "qwen2.5:1.5b".to_string(),
+            name: "Qwen2.5".to_string(),
+            description: None,
+            capabilities: application::ports::ModelCapabilities {
+                text_generation: true,
+                chat: true,
+                tools: true,
+                context_length: Some(32_768),
+                ..Default::default()
+            },
+            variant: Some("1.5B".to_string()),
+            parameter_size: Some("1.5B".to_string()),
+            available: true,
+        };
+
+        let info = ModelInfo::from(model);
+
+        assert_eq!(info.name, "qwen2.5:1.5b");
+        assert_eq!(info.parameters, "1.5B");
+        assert_eq!(info.context_length, Some(32_768));
+        let capabilities = info.capabilities.unwrap();
+        assert!(capabilities.chat);
+        assert!(capabilities.tools);
+        assert!(!capabilities.vision);
+        assert!(info.description.starts_with("Qwen 1.5B"));
+    }
+
+    #[test]
+    fn model_info_from_name() {
+        let info = ModelInfo::from_name("qwen2.5-1.5b-instruct".to_string());
+
+        assert_eq!(info.parameters, "1.5B");
+        assert!(info.capabilities.is_none());
+        assert!(info.context_length.is_none());
+    }
 }
@@ -0,0 +1,237 @@
+//! System prompt override handlers
+//!
+//! Admin-only endpoints to give single conversations or messenger contacts
+//! their own system prompt (e.g. formal for work contacts, casual for
+//! family). Prompts are length-checked and run through the prompt sanitizer
+//! before they are stored.
+
+use std::sync::Arc;
+
+use application::{
+    ports::{PromptOverrideTarget, SystemPromptStore},
+    services::PromptSanitizer,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use domain::value_objects::{ConversationId, PhoneNumber};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use utoipa::ToSchema;
+
+use crate::{
+    error::ApiError, handlers::common::require_admin, middleware::AdminAccess, state::AppState,
+};
+
+/// Request to set a system prompt override
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "prompt": "You are a formal assistant. Address the user as Sie and keep answers short."
+}))]
+pub struct SetSystemPromptRequest {
+    /// Prompt replacing the global system prompt (max 4000 characters)
+    pub prompt: String,
+}
+
+/// Stored system prompt override
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "target": "contact",
+    "id": "+491701234567",
+    "prompt": "You are a casual assistant. Keep it friendly."
+}))]
+pub struct SystemPromptResponse {
+    /// Target kind (`conversation` or `contact`)
+    pub target: String,
+    /// Conversation ID or contact phone number
+    pub id: String,
+    /// Stored prompt, after sanitization
+    pub prompt: String,
+}
+
+impl SystemPromptResponse {
+    fn new(target: &PromptOverrideTarget, prompt: String) -> Self {
+        Self {
+            target: target.kind().to_string(),
+            id: target.key(),
+            prompt,
+        }
+    }
+}
+
+fn store(state: &AppState) -> Result<&Arc<dyn SystemPromptStore>, ApiError> {
+    state.system_prompt_store.as_ref().ok_or_else(|| {
+        ApiError::ServiceUnavailable("System prompt overrides not configured".to_string())
+    })
+}
+
+fn parse_target(kind: &str, id: &str) -> Result<PromptOverrideTarget, ApiError> {
+    match kind {
+        "conversations" => ConversationId::parse(id)
+            .map(PromptOverrideTarget::Conversation)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid conversation ID: {e}"))),
+        "contacts" => PhoneNumber::normalize(id)
+            .map(PromptOverrideTarget::Contact)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid phone number: {e}"))),
+        other => Err(ApiError::NotFound(format!(
+            "Unknown override target '{other}', expected 'conversations' or 'contacts'"
+        ))),
+    }
+}
+
+/// Get the system prompt override of a conversation or contact
+///
+/// GET /v1/admin/system-prompts/{target}/{id}
+#[utoipa::path(
+    get,
+    path = "/v1/admin/system-prompts/{target}/{id}",
+    tag = "system",
+    params(
+        ("target" = String, Path, description = "`conversations` or `contacts`"),
+        ("id" = String, Path, description = "Conversation ID or E.164 phone number")
+    ),
+    responses(
+        (status = 200, description = "Stored override", body = SystemPromptResponse),
+        (status = 400, description = "Invalid conversation ID or phone number", body = crate::error::ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 404, description = "No override stored", body = crate::error::ErrorResponse),
+        (status = 503, description = "Persistence not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn get_override(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Path((kind, id)): Path<(String, String)>,
+) -> Result<Json<SystemPromptResponse>, ApiError> {
+    require_admin(admin)?;
+    let store = store(&state)?;
+    let target = parse_target(&kind, &id)?;
+
+    let prompt = store
+        .get(&target)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("No system prompt override for {target}")))?;
+
+    Ok(Json(SystemPromptResponse::new(&target, prompt)))
+}
+
+/// Set the system prompt override of a conversation or contact
+///
+/// PUT /v1/admin/system-prompts/{target}/{id}
+#[utoipa::path(
+    put,
+    path = "/v1/admin/system-prompts/{target}/{id}",
+    tag = "system",
+    params(
+        ("target" = String, Path, description = "`conversations` or `contacts`"),
+        ("id" = String, Path, description = "Conversation ID or E.164 phone number")
+    ),
+    request_body = SetSystemPromptRequest,
+    responses(
+        (status = 200, description = "Override stored", body = SystemPromptResponse),
+        (status = 400, description = "Invalid target, or prompt empty or too long", body = crate::error::ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 503, description = "Persistence not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin, body))]
+pub async fn set_override(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Path((kind, id)): Path<(String, String)>,
+    Json(body): Json<SetSystemPromptRequest>,
+) -> Result<Json<SystemPromptResponse>, ApiError> {
+    require_admin(admin)?;
+    let store = store(&state)?;
+    let target = parse_target(&kind, &id)?;
+
+    // Overrides are always screened, even if request analysis is disabled
+    let prompt = state.prompt_sanitizer.as_ref().map_or_else(
+        || PromptSanitizer::new().sanitize_system_prompt(&body.prompt),
+        |sanitizer| sanitizer.sanitize_system_prompt(&body.prompt),
+    )?;
+    store.set(&target, &prompt).await?;
+
+    info!(target = %target, prompt_len = prompt.len(), "📝 System prompt override set via admin endpoint");
+    Ok(Json(SystemPromptResponse::new(&target, prompt)))
+}
+
+/// Remove the system prompt override of a conversation or contact
+///
+/// DELETE /v1/admin/system-prompts/{target}/{id}
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/system-prompts/{target}/{id}",
+    tag = "system",
+    params(
+        ("target" = String, Path, description = "`conversations` or `contacts`"),
+        ("id" = String, Path, description = "Conversation ID or E.164 phone number")
+    ),
+    responses(
+        (status = 204, description = "Override removed"),
+        (status = 400, description = "Invalid conversation ID or phone number", body = crate::error::ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::error::ErrorResponse),
+        (status = 404, description = "No override stored", body = crate::error::ErrorResponse),
+        (status = 503, description = "Persistence not configured", body = crate::error::ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+#[instrument(skip(state, admin))]
+pub async fn delete_override(
+    State(state): State<AppState>,
+    admin: Option<Extension<AdminAccess>>,
+    Path((kind, id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    require_admin(admin)?;
+    let store = store(&state)?;
+    let target = parse_target(&kind, &id)?;
+
+    if store.delete(&target).await? {
+        info!(target = %target, "📝 System prompt override removed via admin endpoint");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::NotFound(format!(
+            "No system prompt override for {target}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_conversation_target() {
+        let id = ConversationId::new();
+        let target = parse_target("conversations", &id.to_string()).unwrap();
+        assert_eq!(target, PromptOverrideTarget::Conversation(id));
+    }
+
+    #[test]
+    fn parses_and_normalizes_contact_target() {
+        let target = parse_target("contacts", "0049 170 1234567").unwrap();
+        assert_eq!(target.kind(), "contact");
+        assert_eq!(target.key(), "+491701234567");
+    }
+
+    #[test]
+    fn rejects_invalid_targets() {
+        assert!(matches!(
+            parse_target("conversations", "not-a-uuid"),
+            Err(ApiError::BadRequest(_))
+        ));
+        assert!(matches!(
+            parse_target("contacts", "12"),
+            Err(ApiError::BadRequest(_))
+        ));
+        assert!(matches!(
+            parse_target("users", "x"),
+            Err(ApiError::NotFound(_))
+        ));
+    }
+}
@@ -3,6 +3,7 @@
 //! Handles WhatsApp Business API webhook verification and message processing.
 //! Supports both text and audio (voice) messages.
 
+use application::error::ApplicationError;
 use axum::{
     Json,
     body::Bytes,
@@ -14,7 +15,7 @@ use domain::PhoneNumber;
 use domain::entities::{AudioFormat, Conversation, ConversationSource};
 use integration_whatsapp::{
     IncomingMessage, WebhookPayload, WhatsAppClient, WhatsAppClientConfig, extract_all_messages,
-    verify_signature,
+    extract_status_updates, verify_signature,
 };
 use secrecy::ExposeSecret;
 use serde::{Deserialize, Serialize};
@@ -200,6 +201,8 @@ pub async fn handle_webhook(
         },
     };
 
+    record_status_updates(&state, &payload).await;
+
     // Extract all messages (text and audio)
     let messages = extract_all_messages(&payload);
 
@@ -241,6 +244,19 @@ pub async fn handle_webhook(
                 .await;
                 responses.push(response);
             },
+            IncomingMessage::Reaction {
+                from,
+                message_id,
+                emoji,
+            } => {
+                // Reactions are acknowledgements, not commands
+                debug!(
+                    from = %from,
+                    message_id = %message_id,
+                    removed = emoji.is_empty(),
+                    "Ignoring WhatsApp reaction"
+                );
+            },
         }
     }
 
@@ -255,6 +271,29 @@ pub async fn handle_webhook(
         .into_response()
 }
 
+/// Record delivery and read receipts contained in a webhook payload
+async fn record_status_updates(state: &AppState, payload: &WebhookPayload) {
+    let Some(store) = &state.delivery_status else {
+        return;
+    };
+
+    for update in extract_status_updates(payload) {
+        match store.apply_update(&update).await {
+            Ok(changed) => debug!(
+                message_id = %update.message_id,
+                status = %update.status,
+                changed,
+                "Recorded WhatsApp message status"
+            ),
+            Err(e) => warn!(
+                error = %e,
+                message_id = %update.message_id,
+                "Failed to record WhatsApp message status"
+            ),
+        }
+    }
+}
+
 /// Handle a text message
 async fn handle_text_message(
     state: &AppState,
@@ -315,8 +354,12 @@ async fn handle_text_message(
     // Add user message to conversation
     conversation.add_user_message(text);
 
-    // Process message through agent service
-    let result = state.agent_service.handle_input(text).await;
+    // Process message through agent service; a newer message from the same
+    // sender cancels this one
+    let result = state
+        .agent_service
+        .handle_input_for_sender(from, text, None)
+        .await;
     let config = state.config.load();
 
     match result {
@@ -351,6 +394,13 @@ async fn handle_text_message(
                     from = %from,
                     "Failed to send WhatsApp response"
                 );
+                super::common::queue_failed_reply(
+                    state.retry_queue.as_ref(),
+                    from,
+                    &response_text,
+                    &e,
+                )
+                .await;
             } else {
                 info!(
                     message_id = %message_id,
@@ -373,6 +423,21 @@ async fn handle_text_message(
                 response_type: Some("text".to_string()),
             }
         },
+        Err(ApplicationError::Cancelled) => {
+            info!(
+                message_id = %message_id,
+                from = %from,
+                "WhatsApp text message superseded by a newer one, no reply sent"
+            );
+
+            MessageResponse {
+                message_id: message_id.to_string(),
+                from: from.to_string(),
+                status: "cancelled".to_string(),
+                response: None,
+                response_type: None,
+            }
+        },
         Err(e) => {
             error!(
                 error = %e,
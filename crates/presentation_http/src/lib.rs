@@ -3,6 +3,7 @@
 //!
 //! This crate provides the HTTP API for PiSovereign.
 
+pub mod bootstrap;
 pub mod config_reload;
 pub mod error;
 pub mod handlers;
@@ -12,14 +13,27 @@ pub mod routes;
 pub mod state;
 pub mod tasks;
 
-pub use config_reload::{ReloadableConfig, spawn_config_reload_handler};
+pub use bootstrap::{AgentPorts, ServeOptions, bootstrap, build_agent_service};
+pub use config_reload::{
+    LiveSettings, ReloadableConfig, RotatingSecrets, spawn_config_reload_handler,
+    spawn_live_settings_task,
+};
 pub use error::ApiError;
 pub use middleware::{
-    ApiKeyAuthLayer, ApiKeyStore, RateLimiterConfig, RateLimiterLayer, RequestId, RequestIdLayer,
-    SecurityHeadersLayer, ValidatedJson, ValidationError, spawn_cleanup_task,
+    AdminAccess, ApiKeyAuthLayer, ApiKeyStore, HttpMetricsLayer, JwtAuthLayer, JwtValidator,
+    KeyScopes, RateLimiterConfig, RateLimiterLayer, RequestId, RequestIdLayer,
+    SecurityHeadersLayer, TimeoutLayer, ValidatedJson, ValidationError, spawn_cleanup_task,
 };
 pub use openapi::{ApiDoc, create_openapi_routes};
 pub use routes::create_router;
 pub use state::AppState;
+pub use tasks::spawn_circuit_breaker_metrics_task;
 pub use tasks::spawn_conversation_cleanup_task;
+pub use tasks::spawn_database_maintenance_task;
+pub use tasks::spawn_draft_cleanup_task;
+pub use tasks::spawn_jwks_refresh_task;
+pub use tasks::spawn_memory_consolidation_task;
+pub use tasks::spawn_model_warmup_task;
+pub use tasks::spawn_retry_worker_task;
+pub use tasks::spawn_secret_refresh_task;
 pub use tasks::spawn_signal_polling_task;
@@ -7,9 +7,15 @@
 //! API keys are stored as Argon2id hashes in configuration, and incoming keys
 //! are verified against these hashes. Each key is associated with a user ID
 //! for tenant isolation.
+//!
+//! Keys may be limited to a set of scopes. Every route requires a scope of
+//! the form `<resource>:<read|write>` (see [`required_scope`]), and admin
+//! routes require `admin`. Keys without scopes keep full access.
 
 use std::{
+    collections::HashSet,
     future::Future,
+    net::{IpAddr, SocketAddr},
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
@@ -17,8 +23,8 @@ use std::{
 
 use application::RequestContext;
 use axum::{
-    extract::Request,
-    http::header::AUTHORIZATION,
+    extract::{ConnectInfo, Request},
+    http::{Method, header::AUTHORIZATION},
     response::{IntoResponse, Response},
 };
 use domain::{TenantId, UserId};
@@ -29,6 +35,94 @@ use tracing::{debug, warn};
 use crate::error::ApiError;
 use crate::middleware::RequestId;
 
+/// Scope that grants access to every route, including admin routes
+pub const ADMIN_SCOPE: &str = "admin";
+
+/// Route prefixes (below `/v1/`) that require [`ADMIN_SCOPE`]
+const ADMIN_PREFIXES: &[&str] = &["admin", "system/audit", "system/dead-letters", "security"];
+
+/// `POST` routes that only read data
+const READ_ONLY_POSTS: &[&str] = &["/v1/commands/parse", "/v1/contacts/search"];
+
+/// `GET` routes that change data (WebSocket upgrades)
+const WRITING_GETS: &[&str] = &["/v1/chat/ws"];
+
+/// Scope a request needs, or `None` for routes outside the API
+///
+/// The resource is the first path segment below `/v1/`; conversation
+/// exports belong to `chat`. `GET` and `HEAD` need `<resource>:read`, other
+/// methods `<resource>:write`. The chat WebSocket counts as a write, and a
+/// few `POST` routes that only read count as reads.
+#[must_use]
+pub fn required_scope(method: &Method, path: &str) -> Option<String> {
+    let rest = path.strip_prefix("/v1/")?;
+    if ADMIN_PREFIXES
+        .iter()
+        .any(|p| rest == *p || rest.starts_with(&format!("{p}/")))
+    {
+        return Some(ADMIN_SCOPE.to_string());
+    }
+
+    let resource = match rest.split('/').next().filter(|s| !s.is_empty())? {
+        "conversations" => "chat",
+        other => other,
+    };
+    let read = match *method {
+        Method::GET | Method::HEAD => !WRITING_GETS.contains(&path),
+        Method::POST => READ_ONLY_POSTS.contains(&path),
+        _ => false,
+    };
+    let action = if read { "read" } else { "write" };
+
+    Some(format!("{resource}:{action}"))
+}
+
+/// Scopes granted to the authenticated API key
+///
+/// Inserted into request extensions next to the `RequestContext`. An empty
+/// set means full access.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyScopes(Vec<String>);
+
+impl KeyScopes {
+    /// Wrap the scopes configured for a key
+    #[must_use]
+    pub const fn new(scopes: Vec<String>) -> Self {
+        Self(scopes)
+    }
+
+    /// Whether the key has no scope restrictions
+    #[must_use]
+    pub fn is_unrestricted(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether the key may use a route requiring `required`
+    ///
+    /// `admin` grants everything, and `<resource>:write` implies
+    /// `<resource>:read`.
+    #[must_use]
+    pub fn allows(&self, required: &str) -> bool {
+        if self.is_unrestricted() {
+            return true;
+        }
+        let implied_by_write = required
+            .strip_suffix(":read")
+            .map(|resource| format!("{resource}:write"));
+        self.0.iter().any(|scope| {
+            scope == ADMIN_SCOPE
+                || scope == required
+                || implied_by_write.as_deref() == Some(scope.as_str())
+        })
+    }
+
+    /// Whether the scopes explicitly include [`ADMIN_SCOPE`]
+    #[must_use]
+    pub fn grants_admin(&self) -> bool {
+        self.0.iter().any(|scope| scope == ADMIN_SCOPE)
+    }
+}
+
 /// Verified API key entry with parsed user ID
 #[derive(Clone, Debug)]
 struct VerifiedKeyEntry {
@@ -36,6 +130,8 @@ struct VerifiedKeyEntry {
     hash: String,
     /// Parsed user ID
     user_id: UserId,
+    /// Scopes granted to the key
+    scopes: KeyScopes,
 }
 
 /// Storage for API key entries with hash verification
@@ -68,6 +164,7 @@ impl ApiKeyStore {
                 Ok(user_id) => Some(VerifiedKeyEntry {
                     hash: entry.hash,
                     user_id,
+                    scopes: KeyScopes::new(entry.scopes),
                 }),
                 Err(e) => {
                     warn!(
@@ -98,11 +195,17 @@ impl ApiKeyStore {
     /// protection against timing attacks.
     #[must_use]
     pub fn verify(&self, api_key: &str) -> Option<UserId> {
+        self.verify_with_scopes(api_key).map(|(user_id, _)| user_id)
+    }
+
+    /// Verify an API key and return its user ID and granted scopes
+    #[must_use]
+    pub fn verify_with_scopes(&self, api_key: &str) -> Option<(UserId, KeyScopes)> {
         for entry in &self.entries {
             match self.hasher.verify(api_key, &entry.hash) {
                 Ok(true) => {
                     debug!("API key verified successfully");
-                    return Some(entry.user_id);
+                    return Some((entry.user_id, entry.scopes.clone()));
                 },
                 Ok(false) => {},
                 Err(e) => {
@@ -114,6 +217,20 @@ impl ApiKeyStore {
     }
 }
 
+/// Marker inserted into request extensions once a caller is authenticated
+///
+/// Lets an outer authentication layer (e.g. [`JwtAuthLayer`](super::JwtAuthLayer))
+/// hand a request to [`ApiKeyAuth`] without checking credentials twice.
+#[derive(Debug, Clone, Copy)]
+pub struct Authenticated;
+
+/// Marker inserted into request extensions when the caller may use admin endpoints
+///
+/// Present for API keys whose user ID is listed in `security.admin_user_ids`,
+/// and for every request when authentication is disabled.
+#[derive(Debug, Clone, Copy)]
+pub struct AdminAccess;
+
 /// Layer that applies API key authentication
 #[derive(Clone, Debug)]
 pub struct ApiKeyAuthLayer {
@@ -121,6 +238,14 @@ pub struct ApiKeyAuthLayer {
     api_key_store: Arc<ApiKeyStore>,
     /// Paths that should be excluded from authentication
     excluded_paths: Vec<String>,
+    /// Paths that peers in `allowed_ips` may access without an API key
+    ip_allowed_paths: Vec<String>,
+    /// Peer addresses allowed to access `ip_allowed_paths` without an API key
+    allowed_ips: Arc<HashSet<IpAddr>>,
+    /// Users granted [`AdminAccess`]
+    admin_users: Arc<HashSet<UserId>>,
+    /// Reject unauthenticated requests even when no API keys are configured
+    require_credentials: bool,
 }
 
 impl ApiKeyAuthLayer {
@@ -132,6 +257,10 @@ impl ApiKeyAuthLayer {
         Self {
             api_key_store: Arc::new(ApiKeyStore::new()),
             excluded_paths: vec!["/health".to_string(), "/ready".to_string()],
+            ip_allowed_paths: Vec::new(),
+            allowed_ips: Arc::new(HashSet::new()),
+            admin_users: Arc::new(HashSet::new()),
+            require_credentials: false,
         }
     }
 
@@ -146,6 +275,10 @@ impl ApiKeyAuthLayer {
         Self {
             api_key_store: Arc::new(ApiKeyStore::from_entries(entries)),
             excluded_paths: vec!["/health".to_string(), "/ready".to_string()],
+            ip_allowed_paths: Vec::new(),
+            allowed_ips: Arc::new(HashSet::new()),
+            admin_users: Arc::new(HashSet::new()),
+            require_credentials: false,
         }
     }
 
@@ -155,6 +288,55 @@ impl ApiKeyAuthLayer {
         self.excluded_paths.extend(paths);
         self
     }
+
+    /// Allow the given peer addresses to access paths without an API key
+    ///
+    /// Only the address of the TCP connection is checked; `X-Forwarded-For`
+    /// is ignored so the allowlist cannot be bypassed with a spoofed header.
+    /// Used to let a Prometheus server scrape `/metrics` without credentials.
+    #[must_use]
+    pub fn allow_ips_for_paths(mut self, paths: Vec<String>, ips: &[IpAddr]) -> Self {
+        self.ip_allowed_paths.extend(paths);
+        Arc::make_mut(&mut self.allowed_ips).extend(ips.iter().copied());
+        self
+    }
+
+    /// Reject requests without valid credentials even if no API keys exist
+    ///
+    /// Used when another layer (e.g. JWT) is the only way to authenticate,
+    /// so that an empty key list does not disable authentication.
+    #[must_use]
+    pub const fn require_credentials(mut self) -> Self {
+        self.require_credentials = true;
+        self
+    }
+
+    /// Grant [`AdminAccess`] to the given user IDs
+    ///
+    /// Logs warnings for IDs that are not valid UUIDs and skips them.
+    #[must_use]
+    pub fn with_admin_users(mut self, user_ids: &[String]) -> Self {
+        Arc::make_mut(&mut self.admin_users).extend(parse_admin_users(user_ids));
+        self
+    }
+}
+
+/// Parse configured admin user IDs, skipping invalid entries with a warning
+pub(crate) fn parse_admin_users(user_ids: &[String]) -> HashSet<UserId> {
+    user_ids
+        .iter()
+        .filter_map(|user_id| {
+            UserId::parse(user_id)
+                .inspect_err(|e| {
+                    warn!(
+                        user_id = %user_id,
+                        error = %e,
+                        "Invalid user ID format in admin_user_ids configuration, skipping entry"
+                    );
+                })
+                .ok()
+        })
+        .collect()
 }
 
 impl<S> Layer<S> for ApiKeyAuthLayer {
@@ -165,6 +347,10 @@ impl<S> Layer<S> for ApiKeyAuthLayer {
             inner,
             api_key_store: Arc::clone(&self.api_key_store),
             excluded_paths: self.excluded_paths.clone(),
+            ip_allowed_paths: self.ip_allowed_paths.clone(),
+            allowed_ips: Arc::clone(&self.allowed_ips),
+            admin_users: Arc::clone(&self.admin_users),
+            require_credentials: self.require_credentials,
         }
     }
 }
@@ -175,6 +361,41 @@ pub struct ApiKeyAuth<S> {
     inner: S,
     api_key_store: Arc<ApiKeyStore>,
     excluded_paths: Vec<String>,
+    ip_allowed_paths: Vec<String>,
+    allowed_ips: Arc<HashSet<IpAddr>>,
+    admin_users: Arc<HashSet<UserId>>,
+    require_credentials: bool,
+}
+
+impl<S> ApiKeyAuth<S> {
+    /// Whether the request comes from an allowlisted peer for an allowlisted path
+    fn is_ip_allowed(&self, req: &Request) -> bool {
+        if self.allowed_ips.is_empty() {
+            return false;
+        }
+
+        let path = req.uri().path();
+        if !self
+            .ip_allowed_paths
+            .iter()
+            .any(|allowed| path_is_within(path, allowed))
+        {
+            return false;
+        }
+
+        req.extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .is_some_and(|ci| self.allowed_ips.contains(&ci.0.ip()))
+    }
+}
+
+/// Whether `path` is `base` itself or a sub-path of it
+///
+/// `/metrics` covers `/metrics` and `/metrics/prometheus`, but not
+/// `/metrics-internal`.
+fn path_is_within(path: &str, base: &str) -> bool {
+    path.strip_prefix(base)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/') || base.ends_with('/'))
 }
 
 impl<S> Service<Request> for ApiKeyAuth<S>
@@ -192,7 +413,10 @@ where
 
     fn call(&mut self, mut req: Request) -> Self::Future {
         let api_key_store = Arc::clone(&self.api_key_store);
+        let admin_users = Arc::clone(&self.admin_users);
         let excluded_paths = self.excluded_paths.clone();
+        let ip_allowed = self.is_ip_allowed(&req);
+        let require_credentials = self.require_credentials;
         let mut inner = self.inner.clone();
 
         Box::pin(async move {
@@ -202,10 +426,23 @@ where
                 return inner.call(req).await;
             }
 
+            // Already authenticated by an outer layer (e.g. JWT)
+            if req.extensions().get::<Authenticated>().is_some() {
+                return inner.call(req).await;
+            }
+
+            // Allowlisted peers may access their paths without an API key
+            if ip_allowed {
+                debug!(path = %req.uri().path(), "Request allowed by IP allowlist");
+                inject_request_context(&mut req, UserId::default());
+                return inner.call(req).await;
+            }
+
             // If no API keys configured, auth is disabled
-            if api_key_store.is_empty() {
+            if api_key_store.is_empty() && !require_credentials {
                 // Inject default request context for unauthenticated requests
                 inject_request_context(&mut req, UserId::default());
+                req.extensions_mut().insert(AdminAccess);
                 return inner.call(req).await;
             }
 
@@ -220,8 +457,11 @@ where
                     let token = &header[7..]; // Skip "Bearer "
 
                     // Verify API key against stored hashes
-                    if let Some(user_id) = api_key_store.verify(token) {
-                        inject_request_context(&mut req, user_id);
+                    if let Some((user_id, scopes)) = api_key_store.verify_with_scopes(token) {
+                        let is_admin = admin_users.contains(&user_id);
+                        if let Err(response) = authorize(&mut req, user_id, scopes, is_admin) {
+                            return Ok(response);
+                        }
                         return inner.call(req).await;
                     }
 
@@ -236,6 +476,43 @@ where
     }
 }
 
+/// Check the route's scope and attach the caller's identity to the request
+///
+/// Shared by all authentication layers. Inserts the `RequestContext`,
+/// [`KeyScopes`], [`Authenticated`] and, for admins or credentials with the
+/// [`ADMIN_SCOPE`], [`AdminAccess`].
+///
+/// # Errors
+///
+/// Returns a `403 insufficient_scope` response if `scopes` do not allow the
+/// route.
+pub(crate) fn authorize(
+    req: &mut Request,
+    user_id: UserId,
+    scopes: KeyScopes,
+    is_admin: bool,
+) -> Result<(), Response> {
+    if let Some(required) = required_scope(req.method(), req.uri().path()) {
+        if !scopes.allows(&required) {
+            debug!(
+                path = %req.uri().path(),
+                required = %required,
+                "Credentials lack required scope"
+            );
+            return Err(ApiError::InsufficientScope { required }.into_response());
+        }
+    }
+
+    inject_request_context(req, user_id);
+    let extensions = req.extensions_mut();
+    if is_admin || scopes.grants_admin() {
+        extensions.insert(AdminAccess);
+    }
+    extensions.insert(scopes);
+    extensions.insert(Authenticated);
+    Ok(())
+}
+
 /// Inject `RequestContext` into request extensions
 ///
 /// Creates a `RequestContext` with the authenticated user ID and the request ID
@@ -262,7 +539,7 @@ fn inject_request_context(req: &mut Request, user_id: UserId) {
     req.extensions_mut().insert(ctx);
 }
 
-fn unauthorized_response(message: &str) -> Response {
+pub(crate) fn unauthorized_response(message: &str) -> Response {
     let error = ApiError::Unauthorized(message.to_string());
     error.into_response()
 }
@@ -320,6 +597,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hash_key("secret-key"),
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let app = create_test_router(entries);
 
@@ -342,6 +620,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hash_key("secret-key"),
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let app = create_test_router(entries);
 
@@ -364,6 +643,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hash_key("secret-key"),
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let app = create_test_router(entries);
 
@@ -380,6 +660,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hash_key("secret-key"),
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let app = create_test_router(entries);
 
@@ -402,6 +683,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hash_key("secret-key"),
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let app = create_test_router(entries);
 
@@ -424,6 +706,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hash_key("sk-user1"),
             user_id: user_uuid.to_string(),
+            scopes: Vec::new(),
         }];
         let app = create_test_router(entries);
 
@@ -451,10 +734,12 @@ mod tests {
             ApiKeyEntry {
                 hash: hash_key("sk-user1"),
                 user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+                scopes: Vec::new(),
             },
             ApiKeyEntry {
                 hash: hash_key("sk-user2"),
                 user_id: "550e8400-e29b-41d4-a716-446655440002".to_string(),
+                scopes: Vec::new(),
             },
         ];
         let app = create_test_router(entries);
@@ -509,6 +794,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash,
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let store = ApiKeyStore::from_entries(entries);
 
@@ -528,6 +814,7 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash,
             user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
         }];
         let store = ApiKeyStore::from_entries(entries);
 
@@ -541,10 +828,300 @@ mod tests {
         let entries = vec![ApiKeyEntry {
             hash: hasher.hash("sk-test").unwrap(),
             user_id: "not-a-valid-uuid".to_string(),
+            scopes: Vec::new(),
         }];
         let store = ApiKeyStore::from_entries(entries);
 
         // Store should be empty because the user ID was invalid
         assert!(store.is_empty());
     }
+
+    /// Handler that reports whether the request was granted admin access
+    async fn admin_handler(admin: Option<Extension<AdminAccess>>) -> &'static str {
+        if admin.is_some() { "admin" } else { "user" }
+    }
+
+    fn create_admin_router() -> Router {
+        let entries = vec![
+            ApiKeyEntry {
+                hash: hash_key("sk-admin"),
+                user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+                scopes: Vec::new(),
+            },
+            ApiKeyEntry {
+                hash: hash_key("sk-user"),
+                user_id: "550e8400-e29b-41d4-a716-446655440002".to_string(),
+                scopes: Vec::new(),
+            },
+        ];
+        Router::new().route("/admin", get(admin_handler)).layer(
+            ApiKeyAuthLayer::from_api_keys(entries).with_admin_users(&[
+                "550e8400-e29b-41d4-a716-446655440001".to_string(),
+                "not-a-valid-uuid".to_string(),
+            ]),
+        )
+    }
+
+    async fn admin_body(app: Router, key: &str) -> String {
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/admin")
+                    .header(AUTHORIZATION, format!("Bearer {key}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        String::from_utf8_lossy(&body).into_owned()
+    }
+
+    #[tokio::test]
+    async fn admin_user_gets_admin_access() {
+        assert_eq!(admin_body(create_admin_router(), "sk-admin").await, "admin");
+    }
+
+    #[tokio::test]
+    async fn regular_user_has_no_admin_access() {
+        assert_eq!(admin_body(create_admin_router(), "sk-user").await, "user");
+    }
+
+    #[tokio::test]
+    async fn disabled_auth_grants_admin_access() {
+        let app = Router::new()
+            .route("/admin", get(admin_handler))
+            .layer(ApiKeyAuthLayer::disabled());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/admin")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&body), "admin");
+    }
+
+    fn create_allowlisted_router(allowed: IpAddr) -> Router {
+        let entries = vec![ApiKeyEntry {
+            hash: hash_key("secret-key"),
+            user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            scopes: Vec::new(),
+        }];
+        Router::new()
+            .route("/metrics", get(test_handler))
+            .route("/metrics/prometheus", get(test_handler))
+            .route("/metrics-internal", get(test_handler))
+            .route("/test", get(test_handler))
+            .layer(
+                ApiKeyAuthLayer::from_api_keys(entries)
+                    .allow_ips_for_paths(vec!["/metrics".to_string()], &[allowed]),
+            )
+    }
+
+    fn request_from(uri: &str, peer: &str) -> Request {
+        let addr: SocketAddr = peer.parse().unwrap();
+        Request::builder()
+            .uri(uri)
+            .extension(ConnectInfo(addr))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn allowlisted_ip_accesses_path_without_key() {
+        let app = create_allowlisted_router("10.0.0.5".parse().unwrap());
+
+        let response = app
+            .oneshot(request_from("/metrics", "10.0.0.5:40000"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn allowlisted_ip_still_needs_key_for_other_paths() {
+        let app = create_allowlisted_router("10.0.0.5".parse().unwrap());
+
+        let response = app
+            .oneshot(request_from("/test", "10.0.0.5:40000"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn allowlisted_path_matches_whole_segments() {
+        let app = create_allowlisted_router("10.0.0.5".parse().unwrap());
+
+        let sub_path = app
+            .clone()
+            .oneshot(request_from("/metrics/prometheus", "10.0.0.5:40000"))
+            .await
+            .unwrap();
+        assert_eq!(sub_path.status(), StatusCode::OK);
+
+        let sibling = app
+            .oneshot(request_from("/metrics-internal", "10.0.0.5:40000"))
+            .await
+            .unwrap();
+        assert_eq!(sibling.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn non_allowlisted_ip_needs_key() {
+        let app = create_allowlisted_router("10.0.0.5".parse().unwrap());
+
+        let response = app
+            .oneshot(request_from("/metrics", "10.0.0.6:40000"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn required_scope_by_method_and_resource() {
+        assert_eq!(
+            required_scope(&Method::POST, "/v1/chat").as_deref(),
+            Some("chat:write")
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/v1/conversations/abc/export").as_deref(),
+            Some("chat:read")
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/v1/chat/ws").as_deref(),
+            Some("chat:write")
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/v1/commands").as_deref(),
+            Some("commands:write")
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/v1/contacts/search").as_deref(),
+            Some("contacts:read")
+        );
+        assert_eq!(
+            required_scope(&Method::DELETE, "/v1/contacts/c-1").as_deref(),
+            Some("contacts:write")
+        );
+    }
+
+    #[test]
+    fn required_scope_for_admin_routes() {
+        for path in [
+            "/v1/admin/chaos",
+            "/v1/system/audit/export",
+            "/v1/system/dead-letters",
+            "/v1/security/blocks",
+        ] {
+            assert_eq!(
+                required_scope(&Method::GET, path).as_deref(),
+                Some(ADMIN_SCOPE),
+                "{path}"
+            );
+        }
+        assert_eq!(
+            required_scope(&Method::GET, "/v1/system/status").as_deref(),
+            Some("system:read")
+        );
+        assert!(required_scope(&Method::GET, "/metrics").is_none());
+    }
+
+    #[test]
+    fn key_scopes_allows() {
+        let scopes = KeyScopes::new(vec!["chat:read".to_string(), "contacts:write".to_string()]);
+        assert!(scopes.allows("chat:read"));
+        assert!(!scopes.allows("chat:write"));
+        assert!(scopes.allows("contacts:read"));
+        assert!(!scopes.allows(ADMIN_SCOPE));
+
+        assert!(KeyScopes::default().allows(ADMIN_SCOPE));
+        assert!(KeyScopes::new(vec![ADMIN_SCOPE.to_string()]).allows("commands:write"));
+    }
+
+    fn create_scoped_router() -> Router {
+        let entries = vec![
+            ApiKeyEntry {
+                hash: hash_key("sk-dashboard"),
+                user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
+                scopes: vec!["chat:read".to_string()],
+            },
+            ApiKeyEntry {
+                hash: hash_key("sk-full"),
+                user_id: "550e8400-e29b-41d4-a716-446655440002".to_string(),
+                scopes: Vec::new(),
+            },
+        ];
+        Router::new()
+            .route("/v1/chat", axum::routing::post(test_handler))
+            .route("/v1/conversations/{id}/export", get(test_handler))
+            .route("/v1/admin/chaos", get(test_handler))
+            .layer(ApiKeyAuthLayer::from_api_keys(entries))
+    }
+
+    fn scoped_request(method: Method, uri: &str, key: &str) -> Request {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(AUTHORIZATION, format!("Bearer {key}"))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn scoped_key_allowed_within_scope() {
+        let response = create_scoped_router()
+            .oneshot(scoped_request(
+                Method::GET,
+                "/v1/conversations/c-1/export",
+                "sk-dashboard",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn scoped_key_rejected_with_required_scope() {
+        let response = create_scoped_router()
+            .oneshot(scoped_request(Method::POST, "/v1/chat", "sk-dashboard"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], "insufficient_scope");
+        assert_eq!(json["details"], "chat:write");
+    }
+
+    #[tokio::test]
+    async fn unscoped_key_has_full_access() {
+        let app = create_scoped_router();
+        for (method, uri) in [(Method::POST, "/v1/chat"), (Method::GET, "/v1/admin/chaos")] {
+            let response = app
+                .clone()
+                .oneshot(scoped_request(method, uri, "sk-full"))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+        }
+    }
 }
@@ -0,0 +1,842 @@
+//! JWT bearer token authentication middleware
+//!
+//! Validates `Authorization: Bearer <jwt>` tokens issued by an external
+//! identity provider. RS256 tokens are checked against the provider's JWKS,
+//! which is cached and refreshed periodically; HS256 tokens against a shared
+//! secret. The `sub` claim becomes the [`UserId`], and the `scope`, `scp`
+//! and `roles` claims become [`KeyScopes`], so routes are authorized the same
+//! way as for API keys. Tokens granting no scope are rejected rather than
+//! treated as unrestricted.
+//!
+//! The layer can run in front of [`ApiKeyAuthLayer`](super::ApiKeyAuthLayer):
+//! with [`JwtAuthLayer::with_api_key_fallback`], bearer tokens that are not
+//! JWTs are passed on for API key verification.
+
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use arc_swap::ArcSwap;
+use axum::{extract::Request, http::header::AUTHORIZATION, response::Response};
+use domain::UserId;
+use infrastructure::JwtAuthConfig;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, errors::ErrorKind, jwk::JwkSet};
+use secrecy::ExposeSecret;
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tower::{Layer, Service};
+use tracing::{debug, warn};
+
+use super::auth::{KeyScopes, authorize, parse_admin_users, unauthorized_response};
+
+/// Minimum time between JWKS fetches triggered by unknown key IDs
+const MIN_JWKS_REFETCH: Duration = Duration::from_secs(30);
+
+/// Timeout for fetching the JWKS
+const JWKS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reasons a token is rejected
+#[derive(Debug, Error)]
+pub enum JwtError {
+    /// Not a decodable JWT
+    #[error("Malformed token")]
+    Malformed,
+
+    /// Signed with an algorithm no key is configured for
+    #[error("Unsupported token algorithm: {0:?}")]
+    UnsupportedAlgorithm(Algorithm),
+
+    /// The `kid` is not in the JWKS
+    #[error("Unknown signing key")]
+    UnknownKey,
+
+    /// The `exp` claim has passed
+    #[error("Token expired")]
+    Expired,
+
+    /// Bad signature, issuer, audience or claims
+    #[error("Invalid token: {0}")]
+    Invalid(String),
+
+    /// The `sub` claim is not a user ID
+    #[error("Token subject is not a valid user ID")]
+    InvalidSubject,
+
+    /// None of the `scope`, `scp` or `roles` claims grant anything
+    #[error("Token grants no scopes")]
+    MissingScopes,
+
+    /// The JWKS could not be fetched or parsed
+    #[error("JWKS fetch failed: {0}")]
+    Jwks(String),
+}
+
+/// Claims read from a validated token
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: String,
+    /// Space-separated scopes (RFC 8693)
+    #[serde(default)]
+    scope: Option<String>,
+    /// Scopes as used by Okta and Entra ID
+    #[serde(default)]
+    scp: Option<ScopeList>,
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+/// A scope claim given as a space-separated string or as an array
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ScopeList {
+    Joined(String),
+    List(Vec<String>),
+}
+
+impl ScopeList {
+    fn into_scopes(self) -> Vec<String> {
+        match self {
+            Self::Joined(scopes) => scopes.split_whitespace().map(str::to_string).collect(),
+            Self::List(scopes) => scopes,
+        }
+    }
+}
+
+/// Caller identity carried by a valid token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JwtIdentity {
+    /// User ID from the `sub` claim
+    pub user_id: UserId,
+    /// Scopes from the `scope`, `scp` and `roles` claims; never empty
+    pub scopes: KeyScopes,
+}
+
+/// Signing keys of an identity provider, by key ID
+///
+/// Keys are fetched from the JWKS URL by [`refresh`](Self::refresh). A token
+/// with an unknown `kid` triggers an extra fetch, at most once every
+/// 30 seconds, so key rotation is picked up between scheduled refreshes.
+pub struct JwksCache {
+    url: String,
+    client: reqwest::Client,
+    keys: ArcSwap<HashMap<String, DecodingKey>>,
+    last_fetch: Mutex<Option<Instant>>,
+}
+
+impl std::fmt::Debug for JwksCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwksCache")
+            .field("url", &self.url)
+            .field("keys", &self.keys.load().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl JwksCache {
+    /// Create an empty cache for the given JWKS URL
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(JWKS_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            url: url.into(),
+            client,
+            keys: ArcSwap::from_pointee(HashMap::new()),
+            last_fetch: Mutex::new(None),
+        }
+    }
+
+    /// Fetch the JWKS and replace the cached keys
+    ///
+    /// Returns the number of usable keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtError::Jwks`] if the request fails or the response is not
+    /// a JWKS. The previous keys are kept in that case.
+    pub async fn refresh(&self) -> Result<usize, JwtError> {
+        *self.last_fetch.lock().await = Some(Instant::now());
+
+        let set: JwkSet = self
+            .client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| JwtError::Jwks(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| JwtError::Jwks(e.to_string()))?;
+
+        Ok(self.replace_keys(&set))
+    }
+
+    /// Replace the cached keys with those of `set`
+    ///
+    /// Keys that cannot be used for verification are skipped.
+    pub fn replace_keys(&self, set: &JwkSet) -> usize {
+        let keys: HashMap<String, DecodingKey> = set
+            .keys
+            .iter()
+            .filter_map(|jwk| {
+                let kid = jwk.common.key_id.clone().unwrap_or_default();
+                match DecodingKey::from_jwk(jwk) {
+                    Ok(key) => Some((kid, key)),
+                    Err(e) => {
+                        warn!(kid = %kid, error = %e, "Skipping unusable JWKS key");
+                        None
+                    },
+                }
+            })
+            .collect();
+
+        let count = keys.len();
+        self.keys.store(Arc::new(keys));
+        debug!(count, "JWKS keys updated");
+        count
+    }
+
+    /// Cached key for `kid`; a token without `kid` needs a single-key set
+    fn cached(&self, kid: Option<&str>) -> Option<DecodingKey> {
+        let keys = self.keys.load();
+        match kid {
+            Some(kid) => keys.get(kid).cloned(),
+            None if keys.len() == 1 => keys.values().next().cloned(),
+            None => None,
+        }
+    }
+
+    /// Key for `kid`, fetching the JWKS again if it is unknown
+    async fn key(&self, kid: Option<&str>) -> Result<DecodingKey, JwtError> {
+        if let Some(key) = self.cached(kid) {
+            return Ok(key);
+        }
+
+        let recently_fetched = self
+            .last_fetch
+            .lock()
+            .await
+            .is_some_and(|at| at.elapsed() < MIN_JWKS_REFETCH);
+        if recently_fetched {
+            return Err(JwtError::UnknownKey);
+        }
+
+        self.refresh().await?;
+        self.cached(kid).ok_or(JwtError::UnknownKey)
+    }
+}
+
+/// Validates JWTs and extracts the caller identity
+pub struct JwtValidator {
+    secret: Option<DecodingKey>,
+    jwks: Option<Arc<JwksCache>>,
+    issuer: Option<String>,
+    audience: Option<String>,
+    leeway_secs: u64,
+}
+
+impl std::fmt::Debug for JwtValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtValidator")
+            .field("secret", &self.secret.as_ref().map(|_| "[REDACTED]"))
+            .field("jwks", &self.jwks)
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .field("leeway_secs", &self.leeway_secs)
+            .finish()
+    }
+}
+
+impl JwtValidator {
+    /// Validator for HS256 tokens signed with `secret`
+    #[must_use]
+    pub fn with_secret(secret: &[u8]) -> Self {
+        Self {
+            secret: Some(DecodingKey::from_secret(secret)),
+            ..Self::empty()
+        }
+    }
+
+    /// Validator for RS256 tokens signed with keys from `jwks`
+    #[must_use]
+    pub fn with_jwks(jwks: Arc<JwksCache>) -> Self {
+        Self {
+            jwks: Some(jwks),
+            ..Self::empty()
+        }
+    }
+
+    /// Validator from configuration
+    #[must_use]
+    pub fn from_config(config: &JwtAuthConfig) -> Self {
+        Self {
+            secret: config
+                .secret
+                .as_ref()
+                .map(|s| DecodingKey::from_secret(s.expose_secret().as_bytes())),
+            jwks: config
+                .jwks_url
+                .as_ref()
+                .map(|url| Arc::new(JwksCache::new(url.clone()))),
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            leeway_secs: config.leeway_secs,
+        }
+    }
+
+    const fn empty() -> Self {
+        Self {
+            secret: None,
+            jwks: None,
+            issuer: None,
+            audience: None,
+            leeway_secs: 60,
+        }
+    }
+
+    /// Require the `iss` claim to match
+    #[must_use]
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    /// Require the `aud` claim to match
+    #[must_use]
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// Set the allowed clock skew for `exp` and `nbf`
+    #[must_use]
+    pub const fn with_leeway(mut self, leeway_secs: u64) -> Self {
+        self.leeway_secs = leeway_secs;
+        self
+    }
+
+    /// JWKS cache, if RS256 tokens are accepted
+    #[must_use]
+    pub const fn jwks(&self) -> Option<&Arc<JwksCache>> {
+        self.jwks.as_ref()
+    }
+
+    /// Validate a token and return the caller identity
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JwtError`] describing why the token was rejected, including
+    /// [`JwtError::MissingScopes`] for a token without any scope or role, since
+    /// an empty [`KeyScopes`] would grant full access.
+    pub async fn validate(&self, token: &str) -> Result<JwtIdentity, JwtError> {
+        let header = jsonwebtoken::decode_header(token).map_err(|_| JwtError::Malformed)?;
+        let key = match (header.alg, &self.secret, &self.jwks) {
+            (Algorithm::HS256, Some(secret), _) => secret.clone(),
+            (Algorithm::RS256, _, Some(jwks)) => jwks.key(header.kid.as_deref()).await?,
+            (alg, ..) => return Err(JwtError::UnsupportedAlgorithm(alg)),
+        };
+
+        let claims = jsonwebtoken::decode::<Claims>(token, &key, &self.validation(header.alg))
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => JwtError::Expired,
+                _ => JwtError::Invalid(e.to_string()),
+            })?
+            .claims;
+
+        let user_id = UserId::parse(&claims.sub).map_err(|_| JwtError::InvalidSubject)?;
+        let mut scopes: Vec<String> = claims
+            .scope
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        scopes.extend(claims.scp.map(ScopeList::into_scopes).unwrap_or_default());
+        scopes.extend(claims.roles);
+        if scopes.is_empty() {
+            return Err(JwtError::MissingScopes);
+        }
+
+        Ok(JwtIdentity {
+            user_id,
+            scopes: KeyScopes::new(scopes),
+        })
+    }
+
+    fn validation(&self, alg: Algorithm) -> Validation {
+        let mut validation = Validation::new(alg);
+        validation.leeway = self.leeway_secs;
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        if let Some(ref issuer) = self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        match self.audience {
+            Some(ref audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+        validation
+    }
+}
+
+/// Whether a bearer token has the three-part shape of a JWT
+fn looks_like_jwt(token: &str) -> bool {
+    let mut parts = token.split('.');
+    parts.by_ref().take(3).filter(|p| !p.is_empty()).count() == 3 && parts.next().is_none()
+}
+
+/// Layer that applies JWT bearer token authentication
+#[derive(Clone, Debug)]
+pub struct JwtAuthLayer {
+    /// Token validator, `None` when JWT auth is disabled
+    validator: Option<Arc<JwtValidator>>,
+    /// Paths that should be excluded from authentication
+    excluded_paths: Vec<String>,
+    /// Users granted [`AdminAccess`](super::AdminAccess)
+    admin_users: Arc<HashSet<UserId>>,
+    /// Pass requests without a JWT on to the next auth layer
+    api_key_fallback: bool,
+}
+
+impl JwtAuthLayer {
+    /// Create a layer that passes all requests through
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            validator: None,
+            excluded_paths: vec!["/health".to_string(), "/ready".to_string()],
+            admin_users: Arc::new(HashSet::new()),
+            api_key_fallback: false,
+        }
+    }
+
+    /// Create a layer that requires a valid JWT on every non-excluded path
+    #[must_use]
+    pub fn new(validator: Arc<JwtValidator>) -> Self {
+        Self {
+            validator: Some(validator),
+            ..Self::disabled()
+        }
+    }
+
+    /// Pass requests without a JWT to the inner [`ApiKeyAuthLayer`](super::ApiKeyAuthLayer)
+    ///
+    /// Invalid JWTs are still rejected here.
+    #[must_use]
+    pub const fn with_api_key_fallback(mut self) -> Self {
+        self.api_key_fallback = true;
+        self
+    }
+
+    /// Add paths that should be excluded from authentication
+    #[must_use]
+    pub fn exclude_paths(mut self, paths: Vec<String>) -> Self {
+        self.excluded_paths.extend(paths);
+        self
+    }
+
+    /// Grant [`AdminAccess`](super::AdminAccess) to the given user IDs
+    ///
+    /// Logs warnings for IDs that are not valid UUIDs and skips them.
+    #[must_use]
+    pub fn with_admin_users(mut self, user_ids: &[String]) -> Self {
+        Arc::make_mut(&mut self.admin_users).extend(parse_admin_users(user_ids));
+        self
+    }
+}
+
+impl<S> Layer<S> for JwtAuthLayer {
+    type Service = JwtAuth<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        JwtAuth {
+            inner,
+            validator: self.validator.clone(),
+            excluded_paths: self.excluded_paths.clone(),
+            admin_users: Arc::clone(&self.admin_users),
+            api_key_fallback: self.api_key_fallback,
+        }
+    }
+}
+
+/// Middleware service for JWT bearer token authentication
+#[derive(Clone, Debug)]
+pub struct JwtAuth<S> {
+    inner: S,
+    validator: Option<Arc<JwtValidator>>,
+    excluded_paths: Vec<String>,
+    admin_users: Arc<HashSet<UserId>>,
+    api_key_fallback: bool,
+}
+
+impl<S> Service<Request> for JwtAuth<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let validator = self.validator.clone();
+        let admin_users = Arc::clone(&self.admin_users);
+        let excluded = self
+            .excluded_paths
+            .iter()
+            .any(|p| req.uri().path().starts_with(p));
+        let api_key_fallback = self.api_key_fallback;
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            let Some(validator) = validator.filter(|_| !excluded) else {
+                return inner.call(req).await;
+            };
+
+            let token = req
+                .headers()
+                .get(AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|h| h.strip_prefix("Bearer "))
+                .map(str::to_string);
+
+            match token {
+                Some(token) if looks_like_jwt(&token) => match validator.validate(&token).await {
+                    Ok(identity) => {
+                        let is_admin = admin_users.contains(&identity.user_id);
+                        if let Err(response) =
+                            authorize(&mut req, identity.user_id, identity.scopes, is_admin)
+                        {
+                            return Ok(response);
+                        }
+                        inner.call(req).await
+                    },
+                    Err(e) => {
+                        debug!(error = %e, "JWT rejected");
+                        Ok(unauthorized_response(&e.to_string()))
+                    },
+                },
+                _ if api_key_fallback => inner.call(req).await,
+                Some(_) => Ok(unauthorized_response("Invalid bearer token")),
+                None => Ok(unauthorized_response("Missing bearer token")),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Extension, Router,
+        body::Body,
+        http::{Method, StatusCode},
+        routing::{get, post},
+    };
+    use infrastructure::{ApiKeyEntry, ApiKeyHasher};
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::middleware::ApiKeyAuthLayer;
+    use application::RequestContext;
+
+    const SECRET: &[u8] = b"test-secret";
+    const USER: &str = "550e8400-e29b-41d4-a716-446655440001";
+
+    fn token(claims: &serde_json::Value) -> String {
+        jsonwebtoken::encode(
+            &Header::new(Algorithm::HS256),
+            claims,
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap()
+    }
+
+    fn claims(sub: &str) -> serde_json::Value {
+        json!({
+            "sub": sub,
+            "exp": chrono::Utc::now().timestamp() + 3600,
+            "scope": "system:read"
+        })
+    }
+
+    #[tokio::test]
+    async fn valid_token_yields_user_and_scopes() {
+        let mut claims = claims(USER);
+        claims["scope"] = json!("chat:read contacts:read");
+        claims["roles"] = json!(["admin"]);
+
+        let identity = JwtValidator::with_secret(SECRET)
+            .validate(&token(&claims))
+            .await
+            .unwrap();
+
+        assert_eq!(identity.user_id.to_string(), USER);
+        assert_eq!(
+            identity.scopes,
+            KeyScopes::new(vec![
+                "chat:read".to_string(),
+                "contacts:read".to_string(),
+                "admin".to_string(),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn scp_claim_is_read_as_string_or_array() {
+        let validator = JwtValidator::with_secret(SECRET);
+        let mut claims = json!({ "sub": USER, "exp": chrono::Utc::now().timestamp() + 3600 });
+
+        claims["scp"] = json!(["chat:write", "memory:read"]);
+        let identity = validator.validate(&token(&claims)).await.unwrap();
+        assert_eq!(
+            identity.scopes,
+            KeyScopes::new(vec!["chat:write".to_string(), "memory:read".to_string()])
+        );
+
+        claims["scp"] = json!("chat:write memory:read");
+        let identity = validator.validate(&token(&claims)).await.unwrap();
+        assert!(identity.scopes.allows("memory:read"));
+        assert!(!identity.scopes.allows("contacts:read"));
+    }
+
+    #[tokio::test]
+    async fn token_without_scopes_is_rejected() {
+        let claims = json!({ "sub": USER, "exp": chrono::Utc::now().timestamp() + 3600 });
+
+        let result = JwtValidator::with_secret(SECRET)
+            .validate(&token(&claims))
+            .await;
+
+        assert!(matches!(result, Err(JwtError::MissingScopes)));
+    }
+
+    #[tokio::test]
+    async fn expired_token_is_rejected() {
+        let claims = json!({ "sub": USER, "exp": chrono::Utc::now().timestamp() - 3600 });
+
+        let result = JwtValidator::with_secret(SECRET)
+            .with_leeway(0)
+            .validate(&token(&claims))
+            .await;
+
+        assert!(matches!(result, Err(JwtError::Expired)));
+    }
+
+    #[tokio::test]
+    async fn wrong_secret_is_rejected() {
+        let result = JwtValidator::with_secret(b"other-secret")
+            .validate(&token(&claims(USER)))
+            .await;
+
+        assert!(matches!(result, Err(JwtError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn issuer_and_audience_are_checked() {
+        let mut claims = claims(USER);
+        claims["iss"] = json!("https://id.example.com");
+        claims["aud"] = json!("pisovereign");
+        let token = token(&claims);
+
+        let matching = JwtValidator::with_secret(SECRET)
+            .with_issuer("https://id.example.com")
+            .with_audience("pisovereign");
+        assert!(matching.validate(&token).await.is_ok());
+
+        let other_issuer = JwtValidator::with_secret(SECRET).with_issuer("https://evil.example");
+        assert!(matches!(
+            other_issuer.validate(&token).await,
+            Err(JwtError::Invalid(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn non_uuid_subject_is_rejected() {
+        let result = JwtValidator::with_secret(SECRET)
+            .validate(&token(&claims("alice")))
+            .await;
+
+        assert!(matches!(result, Err(JwtError::InvalidSubject)));
+    }
+
+    #[tokio::test]
+    async fn unconfigured_algorithm_is_unsupported() {
+        let token = jsonwebtoken::encode(
+            &Header::new(Algorithm::HS384),
+            &claims(USER),
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap();
+
+        let result = JwtValidator::with_secret(SECRET).validate(&token).await;
+
+        assert!(matches!(
+            result,
+            Err(JwtError::UnsupportedAlgorithm(Algorithm::HS384))
+        ));
+    }
+
+    #[test]
+    fn jwks_keys_are_looked_up_by_kid() {
+        let set: JwkSet = serde_json::from_value(json!({
+            "keys": [{
+                "kty": "RSA",
+                "kid": "key-1",
+                "use": "sig",
+                "alg": "RS256",
+                "n": "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw",
+                "e": "AQAB"
+            }]
+        }))
+        .unwrap();
+        let cache = JwksCache::new("http://127.0.0.1:1/jwks.json");
+
+        assert_eq!(cache.replace_keys(&set), 1);
+        assert!(cache.cached(Some("key-1")).is_some());
+        assert!(cache.cached(Some("key-2")).is_none());
+        // A token without kid may use the only key
+        assert!(cache.cached(None).is_some());
+    }
+
+    #[test]
+    fn jwt_shape_detection() {
+        assert!(looks_like_jwt("aaa.bbb.ccc"));
+        assert!(!looks_like_jwt("sk-secret-key"));
+        assert!(!looks_like_jwt("aaa..ccc"));
+        assert!(!looks_like_jwt("a.b.c.d"));
+    }
+
+    async fn user_handler(Extension(ctx): Extension<RequestContext>) -> String {
+        ctx.user_id().to_string()
+    }
+
+    fn jwt_router(layer: JwtAuthLayer) -> Router {
+        Router::new()
+            .route("/v1/system/status", get(user_handler))
+            .route("/v1/chat", post(user_handler))
+            .route("/health", get(|| async { "ok" }))
+            .layer(layer)
+    }
+
+    fn request(method: Method, uri: &str, bearer: Option<&str>) -> Request {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(bearer) = bearer {
+            builder = builder.header(AUTHORIZATION, format!("Bearer {bearer}"));
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn validator() -> Arc<JwtValidator> {
+        Arc::new(JwtValidator::with_secret(SECRET))
+    }
+
+    #[tokio::test]
+    async fn layer_accepts_valid_token() {
+        let app = jwt_router(JwtAuthLayer::new(validator()));
+
+        let response = app
+            .oneshot(request(
+                Method::GET,
+                "/v1/system/status",
+                Some(&token(&claims(USER))),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), 1024)
+            .await
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&body), USER);
+    }
+
+    #[tokio::test]
+    async fn layer_rejects_invalid_and_missing_tokens() {
+        let app = jwt_router(JwtAuthLayer::new(validator()));
+        let bad = jsonwebtoken::encode(
+            &Header::new(Algorithm::HS256),
+            &claims(USER),
+            &EncodingKey::from_secret(b"other-secret"),
+        )
+        .unwrap();
+
+        for bearer in [Some(bad.as_str()), Some("sk-not-a-jwt"), None] {
+            let response = app
+                .clone()
+                .oneshot(request(Method::GET, "/v1/system/status", bearer))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{bearer:?}");
+        }
+
+        let health = app
+            .oneshot(request(Method::GET, "/health", None))
+            .await
+            .unwrap();
+        assert_eq!(health.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn layer_enforces_token_scopes() {
+        let app = jwt_router(JwtAuthLayer::new(validator()));
+        let mut claims = claims(USER);
+        claims["scope"] = json!("chat:read");
+
+        let response = app
+            .oneshot(request(Method::POST, "/v1/chat", Some(&token(&claims))))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn api_keys_still_work_behind_jwt_layer() {
+        let entries = vec![ApiKeyEntry {
+            hash: ApiKeyHasher::new().hash("sk-key").unwrap(),
+            user_id: "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            scopes: Vec::new(),
+        }];
+        let app = Router::new()
+            .route("/v1/system/status", get(user_handler))
+            .layer(ApiKeyAuthLayer::from_api_keys(entries))
+            .layer(JwtAuthLayer::new(validator()).with_api_key_fallback());
+
+        let by_key = app
+            .clone()
+            .oneshot(request(Method::GET, "/v1/system/status", Some("sk-key")))
+            .await
+            .unwrap();
+        assert_eq!(by_key.status(), StatusCode::OK);
+
+        let by_jwt = app
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                "/v1/system/status",
+                Some(&token(&claims(USER))),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(by_jwt.status(), StatusCode::OK);
+
+        let by_wrong_key = app
+            .oneshot(request(Method::GET, "/v1/system/status", Some("sk-wrong")))
+            .await
+            .unwrap();
+        assert_eq!(by_wrong_key.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,170 @@
+//! HTTP request metrics middleware
+//!
+//! Records request counts, status classes, latency histograms and
+//! per-endpoint counters in the shared [`MetricsCollector`].
+//!
+//! Endpoints are labelled with the matched route template (e.g.
+//! `/v1/approvals/{id}`) instead of the raw URI to keep the number of
+//! distinct label values bounded. Requests that match no route are
+//! grouped under [`UNMATCHED_PATH`].
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use axum::{
+    extract::{MatchedPath, Request},
+    response::Response,
+};
+use tower::{Layer, Service};
+
+use crate::handlers::metrics::MetricsCollector;
+
+/// Path label used for requests that did not match any route
+pub const UNMATCHED_PATH: &str = "unmatched";
+
+/// Layer that records HTTP request metrics
+#[derive(Clone, Debug)]
+pub struct HttpMetricsLayer {
+    metrics: Arc<MetricsCollector>,
+}
+
+impl HttpMetricsLayer {
+    /// Create a new metrics layer recording into the given collector
+    #[must_use]
+    pub const fn new(metrics: Arc<MetricsCollector>) -> Self {
+        Self { metrics }
+    }
+}
+
+impl<S> Layer<S> for HttpMetricsLayer {
+    type Service = HttpMetrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HttpMetrics {
+            inner,
+            metrics: Arc::clone(&self.metrics),
+        }
+    }
+}
+
+/// Middleware service that records HTTP request metrics
+#[derive(Clone, Debug)]
+pub struct HttpMetrics<S> {
+    inner: S,
+    metrics: Arc<MetricsCollector>,
+}
+
+impl<S> Service<Request> for HttpMetrics<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let metrics = Arc::clone(&self.metrics);
+        let mut inner = self.inner.clone();
+
+        let method = req.method().to_string();
+        let path = req
+            .extensions()
+            .get::<MatchedPath>()
+            .map_or_else(|| UNMATCHED_PATH.to_string(), |p| p.as_str().to_string());
+
+        Box::pin(async move {
+            metrics.request_start();
+            let started = Instant::now();
+
+            let result = inner.call(req).await;
+
+            let elapsed_us = u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX);
+            // Errors from the inner service never reach the client as a
+            // response, so count them as server errors.
+            let status = result
+                .as_ref()
+                .map_or(500, |response| response.status().as_u16());
+            metrics.request_end(elapsed_us, status);
+            metrics.record_endpoint(&method, &path, status);
+
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, body::Body, http::StatusCode, routing::get};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn test_handler() -> &'static str {
+        "ok"
+    }
+
+    fn create_router(metrics: Arc<MetricsCollector>) -> Router {
+        Router::new()
+            .route("/items/{id}", get(test_handler))
+            .layer(HttpMetricsLayer::new(metrics))
+    }
+
+    #[tokio::test]
+    async fn records_request_with_route_template() {
+        let metrics = Arc::new(MetricsCollector::new());
+        let app = create_router(Arc::clone(&metrics));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/items/42")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request_metrics = metrics.request_metrics();
+        assert_eq!(request_metrics.total_requests, 1);
+        assert_eq!(request_metrics.success_count, 1);
+        assert_eq!(request_metrics.active_requests, 0);
+
+        let endpoints = metrics.endpoint_request_counts();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].0.method, "GET");
+        assert_eq!(endpoints[0].0.path, "/items/{id}");
+        assert_eq!(endpoints[0].0.status, 200);
+    }
+
+    #[tokio::test]
+    async fn groups_unmatched_requests() {
+        let metrics = Arc::new(MetricsCollector::new());
+        let app = create_router(Arc::clone(&metrics));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/does-not-exist")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let endpoints = metrics.endpoint_request_counts();
+        assert_eq!(endpoints[0].0.path, UNMATCHED_PATH);
+        assert_eq!(metrics.request_metrics().client_error_count, 1);
+    }
+}
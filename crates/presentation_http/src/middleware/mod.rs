@@ -1,19 +1,29 @@
 //! HTTP middleware components
 //!
-//! This module contains middleware for authentication, rate limiting,
-//! request ID correlation, security headers, and other cross-cutting concerns.
+//! This module contains middleware for authentication (API keys and JWTs), rate limiting,
+//! request ID correlation, request metrics, security headers, request timeouts, and other cross-cutting concerns.
 
 pub mod auth;
+pub mod jwt;
+pub mod metrics;
 pub mod rate_limit;
 pub mod request_id;
 pub mod security_headers;
+pub mod timeout;
 pub mod validation;
 
-pub use auth::{ApiKeyAuth, ApiKeyAuthLayer, ApiKeyStore};
+pub use auth::{
+    ADMIN_SCOPE, AdminAccess, ApiKeyAuth, ApiKeyAuthLayer, ApiKeyStore, Authenticated, KeyScopes,
+    required_scope,
+};
+pub use jwt::{JwksCache, JwtAuth, JwtAuthLayer, JwtError, JwtIdentity, JwtValidator};
+pub use metrics::{HttpMetrics, HttpMetricsLayer};
 pub use rate_limit::{
-    ClientIp, RateLimiter, RateLimiterConfig, RateLimiterLayer, RateLimiterState,
-    extract_client_ip, spawn_cleanup_task,
+    ClientIp, RATELIMIT_LIMIT, RATELIMIT_REMAINING, RATELIMIT_RESET, RateLimitKey, RateLimitStatus,
+    RateLimiter, RateLimiterConfig, RateLimiterLayer, RateLimiterState, extract_client_ip,
+    spawn_cleanup_task,
 };
 pub use request_id::{REQUEST_ID_HEADER, RequestId, RequestIdLayer};
 pub use security_headers::{SecurityHeaders, SecurityHeadersLayer};
+pub use timeout::{Timeout, TimeoutLayer};
 pub use validation::{ValidatedJson, ValidationError};
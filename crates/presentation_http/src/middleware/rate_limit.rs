@@ -1,7 +1,13 @@
 //! Rate limiting middleware
 //!
-//! Token bucket rate limiter that limits requests per IP address.
-//! Supports trusted reverse proxies for proper client IP extraction.
+//! Token bucket rate limiter that limits requests per IP address, or per
+//! authenticated user when enabled. Supports trusted reverse proxies for
+//! proper client IP extraction.
+//!
+//! Limited routes report the client's quota in `RateLimit-Limit`,
+//! `RateLimit-Remaining` and `RateLimit-Reset` headers (IETF
+//! `draft-ietf-httpapi-ratelimit-headers`); a `429` response also carries
+//! `Retry-After`.
 
 use std::{
     collections::HashMap,
@@ -9,15 +15,21 @@ use std::{
     future::Future,
     net::IpAddr,
     pin::Pin,
-    sync::Arc,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
     task::{Context, Poll},
     time::{Duration, Instant},
 };
 
+use application::RequestContext;
 use axum::{
     extract::{ConnectInfo, Request},
+    http::{HeaderMap, HeaderName, HeaderValue, header},
     response::{IntoResponse, Response},
 };
+use domain::UserId;
 use tokio::sync::RwLock;
 use tower::{Layer, Service};
 use tracing::{debug, info, warn};
@@ -44,6 +56,11 @@ pub struct RateLimiterConfig {
     /// use the X-Forwarded-For header to determine the real client IP.
     /// If the connecting IP is not in this list, X-Forwarded-For is ignored.
     pub trusted_proxies: Vec<IpAddr>,
+    /// Rate limit authenticated requests per user instead of per IP
+    ///
+    /// Requests without a `RequestContext` (unauthenticated) are still
+    /// limited per client IP.
+    pub per_user: bool,
 }
 
 impl Default for RateLimiterConfig {
@@ -52,11 +69,51 @@ impl Default for RateLimiterConfig {
             requests_per_minute: 60,
             enabled: true,
             trusted_proxies: Vec::new(),
+            per_user: false,
+        }
+    }
+}
+
+/// Identity a token bucket is kept for
+///
+/// IP and user buckets never share state, even if both limit the same
+/// client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// Client IP address
+    Ip(IpAddr),
+    /// Authenticated user
+    User(UserId),
+}
+
+impl From<IpAddr> for RateLimitKey {
+    fn from(ip: IpAddr) -> Self {
+        Self::Ip(ip)
+    }
+}
+
+impl From<UserId> for RateLimitKey {
+    fn from(user_id: UserId) -> Self {
+        Self::User(user_id)
+    }
+}
+
+impl RateLimitKey {
+    /// Select the bucket key for a request
+    ///
+    /// Uses the authenticated user when `per_user` is set and the auth layer
+    /// attached a `RequestContext`, the client IP otherwise.
+    fn for_request(req: &Request, client_ip: IpAddr, per_user: bool) -> Self {
+        if per_user {
+            if let Some(ctx) = req.extensions().get::<RequestContext>() {
+                return Self::User(ctx.user_id());
+            }
         }
+        Self::Ip(client_ip)
     }
 }
 
-/// Token bucket entry for a single IP
+/// Token bucket entry for a single key
 #[derive(Debug, Clone)]
 struct TokenBucket {
     tokens: f64,
@@ -91,38 +148,118 @@ impl TokenBucket {
     }
 }
 
+/// `RateLimit-Limit` header: requests allowed per window
+pub const RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+
+/// `RateLimit-Remaining` header: requests left in the current window
+pub const RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+
+/// `RateLimit-Reset` header: seconds until the quota is fully restored
+pub const RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Outcome of a rate limit check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Whether the request may proceed
+    pub allowed: bool,
+    /// Requests allowed per minute
+    pub limit: u32,
+    /// Requests left before the client is limited
+    pub remaining: u32,
+    /// Seconds until the bucket is full again
+    pub reset_secs: u64,
+    /// Seconds until the next request is allowed, 0 if this one was
+    pub retry_after_secs: u64,
+}
+
+impl RateLimitStatus {
+    /// Derive the status from the tokens left in a bucket
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_bucket(allowed: bool, limit: u32, tokens: f64, tokens_per_second: f64) -> Self {
+        let secs_until = |target: f64| {
+            if tokens >= target || tokens_per_second <= 0.0 {
+                0
+            } else {
+                ((target - tokens) / tokens_per_second).ceil() as u64
+            }
+        };
+        Self {
+            allowed,
+            limit,
+            remaining: tokens.max(0.0).floor() as u32,
+            reset_secs: secs_until(f64::from(limit)),
+            retry_after_secs: if allowed { 0 } else { secs_until(1.0).max(1) },
+        }
+    }
+
+    /// Add the `RateLimit-*` headers, and `Retry-After` when limited
+    pub fn insert_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(RATELIMIT_LIMIT, HeaderValue::from(self.limit));
+        headers.insert(RATELIMIT_REMAINING, HeaderValue::from(self.remaining));
+        headers.insert(RATELIMIT_RESET, HeaderValue::from(self.reset_secs));
+        if !self.allowed {
+            headers.insert(
+                header::RETRY_AFTER,
+                HeaderValue::from(self.retry_after_secs),
+            );
+        }
+    }
+}
+
 /// Shared rate limiter state
+///
+/// The limit can be changed at runtime with
+/// [`set_requests_per_minute`](Self::set_requests_per_minute); existing
+/// buckets are refilled at the new rate from their next request on.
 #[derive(Debug)]
 pub struct RateLimiterState {
-    buckets: RwLock<HashMap<IpAddr, TokenBucket>>,
-    tokens_per_second: f64,
-    max_tokens: f64,
+    buckets: RwLock<HashMap<RateLimitKey, TokenBucket>>,
+    requests_per_minute: AtomicU32,
 }
 
 impl RateLimiterState {
     /// Create a new rate limiter state
     #[must_use]
     pub fn new(requests_per_minute: u32) -> Self {
-        let max_tokens = f64::from(requests_per_minute);
         Self {
             buckets: RwLock::new(HashMap::new()),
-            tokens_per_second: max_tokens / 60.0,
-            max_tokens,
+            requests_per_minute: AtomicU32::new(requests_per_minute),
         }
     }
 
-    /// Check if a request from the given IP is allowed
-    #[allow(clippy::significant_drop_tightening)]
-    pub async fn check(&self, ip: IpAddr) -> bool {
-        let mut buckets = self.buckets.write().await;
+    /// Current limit in requests per minute
+    #[must_use]
+    pub fn requests_per_minute(&self) -> u32 {
+        self.requests_per_minute.load(Ordering::Relaxed)
+    }
+
+    /// Change the limit, e.g. after a config reload
+    pub fn set_requests_per_minute(&self, requests_per_minute: u32) {
+        self.requests_per_minute
+            .store(requests_per_minute, Ordering::Relaxed);
+    }
 
-        let bucket = buckets
-            .entry(ip)
-            .or_insert_with(|| TokenBucket::new(self.max_tokens));
+    /// Check if a request for the given key (IP or user) is allowed
+    pub async fn check(&self, key: impl Into<RateLimitKey>) -> bool {
+        self.check_quota(key).await.allowed
+    }
 
-        let tokens_per_second = self.tokens_per_second;
-        let max_tokens = self.max_tokens;
-        bucket.try_consume(tokens_per_second, max_tokens)
+    /// Check a request and report the remaining quota of its key
+    pub async fn check_quota(&self, key: impl Into<RateLimitKey>) -> RateLimitStatus {
+        let limit = self.requests_per_minute();
+        let max_tokens = f64::from(limit);
+        let tokens_per_second = max_tokens / 60.0;
+
+        let (allowed, tokens) = {
+            let mut buckets = self.buckets.write().await;
+            let bucket = buckets
+                .entry(key.into())
+                .or_insert_with(|| TokenBucket::new(max_tokens));
+            let allowed = bucket.try_consume(tokens_per_second, max_tokens);
+            (allowed, bucket.tokens)
+        };
+
+        RateLimitStatus::from_bucket(allowed, limit, tokens, tokens_per_second)
     }
 
     /// Clean up stale entries older than the specified duration
@@ -145,7 +282,7 @@ impl RateLimiterState {
         }
     }
 
-    /// Get the current number of tracked IP addresses
+    /// Get the current number of tracked keys (IPs and users)
     pub async fn entry_count(&self) -> usize {
         self.buckets.read().await.len()
     }
@@ -206,6 +343,7 @@ pub struct RateLimiterLayer {
     enabled: bool,
     excluded_paths: Vec<String>,
     trusted_proxies: Arc<HashSet<IpAddr>>,
+    per_user: bool,
 }
 
 impl RateLimiterLayer {
@@ -227,6 +365,7 @@ impl RateLimiterLayer {
             enabled: config.enabled,
             excluded_paths: vec!["/health".to_string(), "/ready".to_string()],
             trusted_proxies: Arc::new(trusted_set),
+            per_user: config.per_user,
         }
     }
 
@@ -254,6 +393,7 @@ impl<S> Layer<S> for RateLimiterLayer {
             enabled: self.enabled,
             excluded_paths: self.excluded_paths.clone(),
             trusted_proxies: Arc::clone(&self.trusted_proxies),
+            per_user: self.per_user,
         }
     }
 }
@@ -266,6 +406,7 @@ pub struct RateLimiter<S> {
     enabled: bool,
     excluded_paths: Vec<String>,
     trusted_proxies: Arc<HashSet<IpAddr>>,
+    per_user: bool,
 }
 
 impl<S> Service<Request> for RateLimiter<S>
@@ -294,6 +435,8 @@ where
         // Insert client IP into request extensions for downstream handlers
         req.extensions_mut().insert(ClientIp(client_ip));
 
+        let key = RateLimitKey::for_request(&req, client_ip, self.per_user);
+
         Box::pin(async move {
             // If rate limiting is disabled, pass through
             if !enabled {
@@ -307,11 +450,14 @@ where
             }
 
             // Check rate limit
-            if state.check(client_ip).await {
-                inner.call(req).await
+            let status = state.check_quota(key).await;
+            let mut response = if status.allowed {
+                inner.call(req).await?
             } else {
-                Ok(ApiError::RateLimited.into_response())
-            }
+                ApiError::RateLimited.into_response()
+            };
+            status.insert_headers(response.headers_mut());
+            Ok(response)
         })
     }
 }
@@ -402,6 +548,7 @@ mod tests {
             enabled,
             requests_per_minute: rpm,
             trusted_proxies: Vec::new(),
+            per_user: false,
         };
         Router::new()
             .route("/test", get(test_handler))
@@ -429,6 +576,7 @@ mod tests {
             enabled: true,
             requests_per_minute: 60,
             trusted_proxies: Vec::new(),
+            per_user: false,
         };
         let layer = RateLimiterLayer::new(&config);
         let app = Router::new().route("/test", get(test_handler)).layer(layer);
@@ -448,6 +596,7 @@ mod tests {
             enabled: true,
             requests_per_minute: 2, // Very low limit for testing
             trusted_proxies: Vec::new(),
+            per_user: false,
         };
         let layer = RateLimiterLayer::new(&config);
         let app = Router::new().route("/test", get(test_handler)).layer(layer);
@@ -469,12 +618,78 @@ mod tests {
         unreachable!("Expected rate limit to be hit with only 2 rpm");
     }
 
+    fn header_u64(response: &Response, name: &HeaderName) -> u64 {
+        response.headers()[name].to_str().unwrap().parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn rate_limit_headers_decrement_within_window() {
+        let app = create_test_router(true, 3);
+        let mut remaining = Vec::new();
+
+        for _ in 0..3 {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri("/test").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), axum::http::StatusCode::OK);
+            assert_eq!(header_u64(&response, &RATELIMIT_LIMIT), 3);
+            assert!(response.headers().get(header::RETRY_AFTER).is_none());
+            remaining.push(header_u64(&response, &RATELIMIT_REMAINING));
+        }
+
+        assert_eq!(remaining, [2, 1, 0]);
+    }
+
+    #[tokio::test]
+    async fn limited_response_has_retry_after() {
+        let app = create_test_router(true, 1);
+        let request = || Request::builder().uri("/test").body(Body::empty()).unwrap();
+
+        let first = app.clone().oneshot(request()).await.unwrap();
+        // One request per minute: the bucket is full again after 60 seconds
+        assert_eq!(header_u64(&first, &RATELIMIT_RESET), 60);
+
+        let limited = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(limited.status(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(header_u64(&limited, &RATELIMIT_LIMIT), 1);
+        assert_eq!(header_u64(&limited, &RATELIMIT_REMAINING), 0);
+        let retry_after = header_u64(&limited, &header::RETRY_AFTER);
+        assert!((59..=60).contains(&retry_after));
+    }
+
+    #[tokio::test]
+    async fn excluded_and_disabled_routes_have_no_headers() {
+        for (enabled, uri) in [(true, "/health"), (false, "/test")] {
+            let response = create_test_router(enabled, 1)
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert!(response.headers().get(RATELIMIT_LIMIT).is_none());
+        }
+    }
+
+    #[test]
+    fn status_from_bucket_rounds_conservatively() {
+        // 60 rpm: one token per second
+        let status = RateLimitStatus::from_bucket(true, 60, 44.6, 1.0);
+        assert_eq!(status.remaining, 44);
+        assert_eq!(status.reset_secs, 16);
+        assert_eq!(status.retry_after_secs, 0);
+
+        let limited = RateLimitStatus::from_bucket(false, 60, 0.25, 1.0);
+        assert_eq!(limited.remaining, 0);
+        assert_eq!(limited.retry_after_secs, 1);
+    }
+
     #[tokio::test]
     async fn health_endpoint_excluded_from_rate_limit() {
         let config = RateLimiterConfig {
             enabled: true,
             requests_per_minute: 1, // Very restrictive
             trusted_proxies: Vec::new(),
+            per_user: false,
         };
         let layer = RateLimiterLayer::new(&config);
         let app = Router::new()
@@ -705,6 +920,7 @@ mod tests {
                 "::1".parse().unwrap(),
                 "10.0.0.1".parse().unwrap(),
             ],
+            per_user: false,
         };
 
         let layer = RateLimiterLayer::new(&config);
@@ -717,4 +933,143 @@ mod tests {
         assert!(layer.trusted_proxies.contains(&"::1".parse().unwrap()));
         assert!(layer.trusted_proxies.contains(&"10.0.0.1".parse().unwrap()));
     }
+
+    /// Build a request from `addr`, authenticated as `user_id` if given
+    fn request_as_user(addr: std::net::SocketAddr, user_id: Option<UserId>) -> Request<Body> {
+        let mut req = request_with_connect_info("/test", addr);
+        if let Some(user_id) = user_id {
+            req.extensions_mut()
+                .insert(RequestContext::new(user_id, domain::TenantId::default()));
+        }
+        req
+    }
+
+    fn per_user_router(rpm: u32) -> Router {
+        let config = RateLimiterConfig {
+            enabled: true,
+            requests_per_minute: rpm,
+            trusted_proxies: Vec::new(),
+            per_user: true,
+        };
+        Router::new()
+            .route("/test", get(test_handler))
+            .layer(RateLimiterLayer::new(&config))
+    }
+
+    #[tokio::test]
+    async fn per_user_limits_users_behind_same_ip_independently() {
+        let app = per_user_router(1);
+        let addr: std::net::SocketAddr = "203.0.113.7:4000".parse().unwrap();
+        let alice = UserId::new();
+        let bob = UserId::new();
+
+        let first = app
+            .clone()
+            .oneshot(request_as_user(addr, Some(alice)))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), axum::http::StatusCode::OK);
+
+        let alice_again = app
+            .clone()
+            .oneshot(request_as_user(addr, Some(alice)))
+            .await
+            .unwrap();
+        assert_eq!(
+            alice_again.status(),
+            axum::http::StatusCode::TOO_MANY_REQUESTS
+        );
+
+        // Same IP, different user: own bucket
+        let bob_first = app
+            .clone()
+            .oneshot(request_as_user(addr, Some(bob)))
+            .await
+            .unwrap();
+        assert_eq!(bob_first.status(), axum::http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn per_user_falls_back_to_ip_for_unauthenticated_requests() {
+        let app = per_user_router(1);
+        let addr: std::net::SocketAddr = "203.0.113.7:4000".parse().unwrap();
+
+        // Exhaust the user's bucket; the IP bucket stays untouched
+        let user = UserId::new();
+        app.clone()
+            .oneshot(request_as_user(addr, Some(user)))
+            .await
+            .unwrap();
+
+        let anonymous = app
+            .clone()
+            .oneshot(request_as_user(addr, None))
+            .await
+            .unwrap();
+        assert_eq!(anonymous.status(), axum::http::StatusCode::OK);
+
+        let anonymous_again = app
+            .clone()
+            .oneshot(request_as_user(addr, None))
+            .await
+            .unwrap();
+        assert_eq!(
+            anonymous_again.status(),
+            axum::http::StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn per_ip_mode_ignores_authenticated_user() {
+        let config = RateLimiterConfig {
+            enabled: true,
+            requests_per_minute: 1,
+            trusted_proxies: Vec::new(),
+            per_user: false,
+        };
+        let app = Router::new()
+            .route("/test", get(test_handler))
+            .layer(RateLimiterLayer::new(&config));
+        let addr: std::net::SocketAddr = "203.0.113.7:4000".parse().unwrap();
+
+        app.clone()
+            .oneshot(request_as_user(addr, Some(UserId::new())))
+            .await
+            .unwrap();
+        let other_user = app
+            .clone()
+            .oneshot(request_as_user(addr, Some(UserId::new())))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            other_user.status(),
+            axum::http::StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn changed_limit_applies_to_existing_buckets() {
+        let state = RateLimiterState::new(1);
+        let ip: IpAddr = "192.168.1.1".parse().unwrap();
+
+        assert!(state.check(ip).await);
+        assert!(!state.check(ip).await);
+
+        state.set_requests_per_minute(600);
+        assert_eq!(state.requests_per_minute(), 600);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert!(state.check(ip).await);
+    }
+
+    #[tokio::test]
+    async fn ip_and_user_keys_use_separate_buckets() {
+        let state = RateLimiterState::new(1);
+        let ip: IpAddr = "192.168.1.1".parse().unwrap();
+
+        assert!(state.check(ip).await);
+        assert!(state.check(UserId::new()).await);
+        assert!(!state.check(ip).await);
+        assert_eq!(state.entry_count().await, 2);
+    }
 }
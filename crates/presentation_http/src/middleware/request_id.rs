@@ -1,9 +1,11 @@
 //! Request ID middleware for HTTP request correlation
 //!
 //! Extracts or generates a unique request ID for each incoming request,
-//! making it available in the tracing span for log correlation.
+//! making it available in the tracing span for log correlation and as the
+//! ambient ID for outgoing integration calls.
 
 use axum::{body::Body, extract::Request, http::header::HeaderValue, response::Response};
+use infrastructure::scope_request_id;
 use std::{
     future::Future,
     pin::Pin,
@@ -81,7 +83,9 @@ where
 
         Box::pin(
             async move {
-                let mut response = inner.call(request).await?;
+                // Outgoing integration calls made while handling this request
+                // carry the same X-Request-Id
+                let mut response = scope_request_id(request_id, inner.call(request)).await?;
 
                 // Add request ID to response headers
                 if let Ok(value) = HeaderValue::from_str(&request_id.to_string()) {
@@ -142,4 +146,38 @@ mod tests {
         let debug_str = format!("{id:?}");
         assert!(debug_str.contains("RequestId"));
     }
+
+    #[tokio::test]
+    async fn request_id_is_ambient_inside_handler() {
+        use axum::{Router, routing::get};
+        use infrastructure::http::current_request_id;
+        use tower::ServiceExt;
+
+        async fn handler() -> String {
+            current_request_id()
+                .map(|id| id.to_string())
+                .unwrap_or_default()
+        }
+
+        let app = Router::new()
+            .route("/", get(handler))
+            .layer(RequestIdLayer::new());
+
+        let request_id = Uuid::now_v7();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(REQUEST_ID_HEADER, request_id.to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, request_id.to_string().as_bytes());
+    }
 }
@@ -0,0 +1,224 @@
+//! Request timeout middleware
+//!
+//! Aborts request handling that takes longer than the configured timeout and
+//! responds with `503 Service Unavailable`, so a stuck upstream (e.g. a
+//! CalDAV server that never answers) cannot hold a connection open forever.
+//!
+//! Long-lived streaming endpoints are excluded; their responses are expected
+//! to stay open.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use presentation_http::middleware::TimeoutLayer;
+//!
+//! let app = Router::new()
+//!     .route("/api", get(handler))
+//!     .layer(TimeoutLayer::new(Duration::from_secs(60)));
+//! ```
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use axum::{
+    extract::Request,
+    response::{IntoResponse, Response},
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+use crate::error::ApiError;
+
+/// Paths excluded from the timeout by default (SSE streaming)
+const DEFAULT_EXCLUDED_PATHS: &[&str] = &["/v1/chat/stream"];
+
+/// Layer that applies a request timeout
+#[derive(Clone, Debug)]
+pub struct TimeoutLayer {
+    timeout: Duration,
+    excluded_paths: Vec<String>,
+}
+
+impl TimeoutLayer {
+    /// Create a new timeout layer
+    ///
+    /// The SSE streaming endpoint is excluded.
+    #[must_use]
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            excluded_paths: DEFAULT_EXCLUDED_PATHS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
+    /// Add paths that should be excluded from the timeout
+    #[must_use]
+    pub fn exclude_paths(mut self, paths: Vec<String>) -> Self {
+        self.excluded_paths.extend(paths);
+        self
+    }
+
+    /// Get the configured timeout
+    #[must_use]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<S> Layer<S> for TimeoutLayer {
+    type Service = Timeout<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Timeout {
+            inner,
+            timeout: self.timeout,
+            excluded_paths: self.excluded_paths.clone(),
+        }
+    }
+}
+
+/// Middleware service that aborts slow requests
+#[derive(Clone, Debug)]
+pub struct Timeout<S> {
+    inner: S,
+    timeout: Duration,
+    excluded_paths: Vec<String>,
+}
+
+impl<S> Service<Request> for Timeout<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let mut inner = self.inner.clone();
+        let timeout = self.timeout;
+        let excluded = self
+            .excluded_paths
+            .iter()
+            .any(|p| req.uri().path().starts_with(p.as_str()));
+
+        Box::pin(async move {
+            if excluded {
+                return inner.call(req).await;
+            }
+
+            let method = req.method().clone();
+            let path = req.uri().path().to_string();
+
+            if let Ok(result) = tokio::time::timeout(timeout, inner.call(req)).await {
+                result
+            } else {
+                warn!(
+                    method = %method,
+                    path = %path,
+                    timeout_secs = timeout.as_secs(),
+                    "Request timed out"
+                );
+                Ok(ApiError::ServiceUnavailable(format!(
+                    "Request timed out after {}s",
+                    timeout.as_secs()
+                ))
+                .into_response())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, body::Body, http::StatusCode, routing::get};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn fast_handler() -> &'static str {
+        "ok"
+    }
+
+    async fn slow_handler() -> &'static str {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        "too late"
+    }
+
+    fn create_test_router() -> Router {
+        Router::new()
+            .route("/fast", get(fast_handler))
+            .route("/slow", get(slow_handler))
+            .route("/v1/chat/stream", get(slow_handler))
+            .layer(TimeoutLayer::new(Duration::from_millis(50)))
+    }
+
+    fn request(uri: &str) -> Request<Body> {
+        Request::builder().uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn slow_handler_returns_service_unavailable() {
+        let response = create_test_router()
+            .oneshot(request("/slow"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], "service_unavailable");
+    }
+
+    #[tokio::test]
+    async fn fast_handler_is_unaffected() {
+        let response = create_test_router()
+            .oneshot(request("/fast"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"ok");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn streaming_endpoint_is_excluded() {
+        let response = create_test_router()
+            .oneshot(request("/v1/chat/stream"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn exclude_paths_extends_defaults() {
+        let layer =
+            TimeoutLayer::new(Duration::from_secs(1)).exclude_paths(vec!["/v1/audio".to_string()]);
+
+        assert_eq!(layer.timeout(), Duration::from_secs(1));
+        assert!(
+            layer
+                .excluded_paths
+                .contains(&"/v1/chat/stream".to_string())
+        );
+        assert!(layer.excluded_paths.contains(&"/v1/audio".to_string()));
+    }
+}
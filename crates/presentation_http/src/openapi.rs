@@ -36,8 +36,10 @@ use crate::{handlers, state::AppState};
         (name = "approvals", description = "Approval workflow management"),
         (name = "system", description = "System status and model information"),
         (name = "metrics", description = "Application metrics and observability"),
+        (name = "security", description = "Prompt security administration"),
         (name = "signal", description = "Signal messenger integration"),
         (name = "whatsapp", description = "WhatsApp Business API integration"),
+        (name = "messages", description = "Outgoing message delivery status"),
         (name = "contacts", description = "CardDAV contact management")
     ),
     paths(
@@ -45,6 +47,7 @@ use crate::{handlers, state::AppState};
         handlers::health::health_check,
         handlers::health::readiness_check,
         handlers::health::extended_readiness_check,
+        handlers::health::detailed_health_check,
         handlers::health::inference_health_check,
         handlers::health::email_health_check,
         handlers::health::calendar_health_check,
@@ -52,6 +55,8 @@ use crate::{handlers, state::AppState};
         // Chat endpoints
         handlers::chat::chat,
         handlers::chat::chat_stream,
+        handlers::chat_ws::chat_ws,
+        handlers::conversations::export_conversation,
         // Command endpoints
         handlers::commands::execute_command,
         handlers::commands::parse_command,
@@ -63,7 +68,19 @@ use crate::{handlers, state::AppState};
         handlers::approvals::cancel_request,
         // System endpoints
         handlers::system::status,
+        handlers::system::info,
         handlers::system::list_models,
+        handlers::system::list_voices,
+        handlers::audit::query,
+        handlers::audit::export,
+        handlers::dead_letters::list,
+        handlers::dead_letters::requeue,
+        handlers::system_prompts::get_override,
+        handlers::system_prompts::set_override,
+        handlers::system_prompts::delete_override,
+        // Security endpoints
+        handlers::security::list_blocks,
+        handlers::security::unblock,
         // Metrics endpoints
         handlers::metrics::get_metrics,
         handlers::metrics::get_metrics_prometheus,
@@ -73,10 +90,13 @@ use crate::{handlers, state::AppState};
         // WhatsApp endpoints
         handlers::whatsapp::verify_webhook,
         handlers::whatsapp::handle_webhook,
+        // Message status endpoints
+        handlers::messages::get_message_status,
         // Contact endpoints
         handlers::contacts::list_addressbooks,
         handlers::contacts::list_contacts,
         handlers::contacts::get_contact,
+        handlers::contacts::export_vcard,
         handlers::contacts::create_contact,
         handlers::contacts::update_contact,
         handlers::contacts::delete_contact,
@@ -91,10 +111,16 @@ use crate::{handlers, state::AppState};
             handlers::health::ExtendedReadinessResponse,
             handlers::health::ExtendedServiceStatus,
             handlers::health::LatencyPercentiles,
+            handlers::health::QueueStatus,
+            handlers::health::OverallStatus,
+            handlers::health::DependencyState,
+            handlers::health::DetailedServiceStatus,
+            handlers::health::DetailedHealthResponse,
             // Chat schemas
             handlers::chat::ChatRequest,
             handlers::chat::ChatResponse,
             handlers::chat::StreamChatRequest,
+            handlers::conversations::ExportConversationQuery,
             // Command schemas
             handlers::commands::ExecuteCommandRequest,
             handlers::commands::ExecuteCommandResponse,
@@ -106,8 +132,24 @@ use crate::{handlers, state::AppState};
             handlers::approvals::DenyRequest,
             // System schemas
             handlers::system::StatusResponse,
+            handlers::system::SystemInfoResponse,
             handlers::system::ModelsResponse,
             handlers::system::ModelInfo,
+            handlers::system::ModelCapabilitiesInfo,
+            handlers::system::VoicesResponse,
+            handlers::system::VoiceInfo,
+            handlers::audit::AuditLogQuery,
+            handlers::audit::AuditEntryResponse,
+            handlers::audit::AuditLogResponse,
+            handlers::dead_letters::DeadLetterQuery,
+            handlers::dead_letters::DeadLetterResponse,
+            handlers::dead_letters::DeadLetterListResponse,
+            handlers::dead_letters::RequeueResponse,
+            handlers::system_prompts::SetSystemPromptRequest,
+            handlers::system_prompts::SystemPromptResponse,
+            // Security schemas
+            handlers::security::BlockResponse,
+            handlers::security::BlockListResponse,
             // Metrics schemas
             handlers::metrics::MetricsResponse,
             handlers::metrics::AppMetrics,
@@ -116,6 +158,8 @@ use crate::{handlers, state::AppState};
             handlers::metrics::SystemMetrics,
             // Error schemas
             crate::error::ErrorResponse,
+            crate::error::SecurityDetails,
+            crate::error::SecurityThreatDetail,
             // Signal schemas
             handlers::signal::SignalHealthResponse,
             handlers::signal::PollQuery,
@@ -124,6 +168,8 @@ use crate::{handlers, state::AppState};
             // WhatsApp schemas
             handlers::whatsapp::WebhookVerifyQuery,
             handlers::whatsapp::MessageResponse,
+            // Message status schemas
+            handlers::messages::MessageStatusResponse,
             // Contact schemas
             handlers::contacts::ContactResponse,
             handlers::contacts::ContactDetailResponse,
@@ -236,6 +282,12 @@ pub enum AgentCommandSchema {
         count: Option<u32>,
         only_important: Option<bool>,
     },
+    /// Summarize a conversation
+    #[schema(rename = "summarize_conversation")]
+    SummarizeConversation {
+        conversation_id: Option<String>,
+        max_sentences: Option<u32>,
+    },
     /// Draft an email
     #[schema(rename = "draft_email")]
     DraftEmail {
@@ -342,6 +394,7 @@ mod tests {
         assert!(tags.contains(&"approvals"));
         assert!(tags.contains(&"system"));
         assert!(tags.contains(&"metrics"));
+        assert!(tags.contains(&"security"));
     }
 
     #[test]
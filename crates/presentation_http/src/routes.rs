@@ -2,18 +2,38 @@
 
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{delete, get, post},
+};
+use tower_http::compression::{
+    CompressionLayer,
+    predicate::{NotForContentType, Predicate, SizeAbove},
 };
 
 use crate::{handlers, openapi::create_openapi_routes, state::AppState};
 
+/// Responses smaller than this (in bytes) are sent uncompressed
+pub const COMPRESSION_MIN_SIZE: u16 = 1024;
+
+/// Gzip/Brotli compression negotiated via `Accept-Encoding`
+///
+/// Skips small responses and SSE streams, which must be flushed per event.
+fn compression_layer() -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new().compress_when(
+        SizeAbove::new(COMPRESSION_MIN_SIZE)
+            .and(NotForContentType::SSE)
+            .and(NotForContentType::GRPC)
+            .and(NotForContentType::IMAGES),
+    )
+}
+
 /// Create the main router with all routes
 pub fn create_router(state: AppState) -> Router {
-    Router::new()
+    let router = Router::new()
         // Health and status endpoints
         .route("/health", get(handlers::health::health_check))
         .route("/ready", get(handlers::health::readiness_check))
         .route("/ready/all", get(handlers::health::extended_readiness_check))
+        .route("/health/detailed", get(handlers::health::detailed_health_check))
         // Individual service health endpoints
         .route("/health/inference", get(handlers::health::inference_health_check))
         .route("/health/email", get(handlers::health::email_health_check))
@@ -28,6 +48,16 @@ pub fn create_router(state: AppState) -> Router {
         // Chat API (v1)
         .route("/v1/chat", post(handlers::chat::chat))
         .route("/v1/chat/stream", post(handlers::chat::chat_stream))
+        .route("/v1/chat/ws", get(handlers::chat_ws::chat_ws))
+        .route(
+            "/v1/conversations/{id}/export",
+            get(handlers::conversations::export_conversation),
+        )
+        // Message status API (v1)
+        .route(
+            "/v1/messages/{id}/status",
+            get(handlers::messages::get_message_status),
+        )
         // Command API (v1)
         .route("/v1/commands", post(handlers::commands::execute_command))
         .route("/v1/commands/parse", post(handlers::commands::parse_command))
@@ -48,10 +78,33 @@ pub fn create_router(state: AppState) -> Router {
         )
         // System API
         .route("/v1/system/status", get(handlers::system::status))
+        .route("/v1/system/info", get(handlers::system::info))
         .route("/v1/system/models", get(handlers::system::list_models))
+        .route("/v1/system/voices", get(handlers::system::list_voices))
+        .route("/v1/system/audit", get(handlers::audit::query))
+        .route("/v1/system/audit/export", get(handlers::audit::export))
+        .route("/v1/system/dead-letters", get(handlers::dead_letters::list))
+        .route(
+            "/v1/system/dead-letters/{id}/requeue",
+            post(handlers::dead_letters::requeue),
+        )
+        // System prompt overrides (admin)
+        .route(
+            "/v1/admin/system-prompts/{target}/{id}",
+            get(handlers::system_prompts::get_override)
+                .put(handlers::system_prompts::set_override)
+                .delete(handlers::system_prompts::delete_override),
+        )
+        // Security API
+        .route("/v1/security/blocks", get(handlers::security::list_blocks))
+        .route(
+            "/v1/security/blocks/{id}",
+            delete(handlers::security::unblock),
+        )
         // Contact API (v1)
         .route("/v1/contacts", get(handlers::contacts::list_contacts).post(handlers::contacts::create_contact))
         .route("/v1/contacts/{id}", get(handlers::contacts::get_contact).put(handlers::contacts::update_contact).delete(handlers::contacts::delete_contact))
+        .route("/v1/contacts/{id}/vcard", get(handlers::contacts::export_vcard))
         .route("/v1/contacts/search", post(handlers::contacts::search_contacts))
         .route("/v1/contacts/addressbooks", get(handlers::contacts::list_addressbooks))
         // WhatsApp webhook (Meta Platform)
@@ -60,9 +113,25 @@ pub fn create_router(state: AppState) -> Router {
             get(handlers::whatsapp::verify_webhook).post(handlers::whatsapp::handle_webhook),
         )
         // Signal polling endpoint
-        .route("/v1/signal/poll", post(handlers::signal::poll_messages))
+        .route("/v1/signal/poll", post(handlers::signal::poll_messages));
+
+    // Fault injection for game days (not part of the OpenAPI document)
+    #[cfg(feature = "chaos")]
+    let router = router
+        .route(
+            "/v1/admin/chaos",
+            get(handlers::chaos::status).delete(handlers::chaos::clear_all),
+        )
+        .route(
+            "/v1/admin/chaos/{target}",
+            axum::routing::put(handlers::chaos::set_policy).delete(handlers::chaos::clear_policy),
+        );
+
+    router
         // OpenAPI documentation
         .merge(create_openapi_routes())
+        // Response compression
+        .layer(compression_layer())
         // Attach state
         .with_state(state)
 }
@@ -1,13 +1,17 @@
 //! Application state shared across handlers
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use application::ports::{
-    ContactPort, ConversationStore, MessengerPort, SecretStorePort, SuspiciousActivityPort,
+    AuditLogPort, ContactPort, ConversationStore, DegradedModePort, DeliveryStatusPort,
+    MessengerPort, ModelRegistryPort, RetryQueuePort, SecretStorePort, SuspiciousActivityPort,
+    SystemPromptStore,
 };
 use application::services::PromptSanitizer;
 use application::{AgentService, ApprovalService, ChatService, HealthService, VoiceMessageService};
 use integration_signal::SignalClient;
+use tokio::sync::watch;
 
 use crate::{config_reload::ReloadableConfig, handlers::metrics::MetricsCollector};
 
@@ -42,6 +46,24 @@ pub struct AppState {
     pub secret_store: Option<Arc<dyn SecretStorePort>>,
     /// Contact service for CardDAV contact operations
     pub contact_service: Option<Arc<dyn ContactPort>>,
+    /// Degraded mode state of the inference backend
+    pub degraded_mode: Option<Arc<dyn DegradedModePort>>,
+    /// Audit log for compliance queries and exports
+    pub audit_log: Option<Arc<dyn AuditLogPort>>,
+    /// Delivery and read receipts of outgoing messenger messages
+    pub delivery_status: Option<Arc<dyn DeliveryStatusPort>>,
+    /// Queue for redelivering failed outgoing messages
+    pub retry_queue: Option<Arc<dyn RetryQueuePort>>,
+    /// Per-conversation and per-contact system prompt overrides
+    pub system_prompt_store: Option<Arc<dyn SystemPromptStore>>,
+    /// Model capabilities as reported by the inference backend
+    pub model_registry: Option<Arc<dyn ModelRegistryPort>>,
+    /// Set to `true` when the server begins graceful shutdown
+    pub shutdown: Option<watch::Receiver<bool>>,
+    /// Set to `true` once model warmup finished; `None` if warmup is disabled
+    pub warmup: Option<watch::Receiver<bool>>,
+    /// When the server started, for uptime reporting
+    pub started_at: Instant,
 }
 
 impl std::fmt::Debug for AppState {
@@ -67,6 +89,15 @@ impl std::fmt::Debug for AppState {
             .field("conversation_store", &self.conversation_store.is_some())
             .field("secret_store", &self.secret_store.is_some())
             .field("contact_service", &self.contact_service.is_some())
+            .field("degraded_mode", &self.degraded_mode.is_some())
+            .field("audit_log", &self.audit_log.is_some())
+            .field("delivery_status", &self.delivery_status.is_some())
+            .field("retry_queue", &self.retry_queue.is_some())
+            .field("system_prompt_store", &self.system_prompt_store.is_some())
+            .field("model_registry", &self.model_registry.is_some())
+            .field("shutdown", &self.shutdown.is_some())
+            .field("warmup", &self.warmup.is_some())
+            .field("started_at", &self.started_at)
             .finish()
     }
 }
@@ -0,0 +1,86 @@
+//! Circuit breaker metrics task
+//!
+//! Feeds circuit breaker state changes into the metrics collector so they
+//! show up in the Prometheus output.
+
+use std::sync::Arc;
+
+use infrastructure::adapters::CircuitBreakerEvent;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{debug, info, warn};
+
+use crate::handlers::metrics::MetricsCollector;
+
+/// Spawn a background task that records circuit breaker transitions.
+///
+/// Subscribe before the adapters are created so that no early transition is
+/// missed. The task ends when the event channel closes.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+pub fn spawn_circuit_breaker_metrics_task(
+    mut events: broadcast::Receiver<CircuitBreakerEvent>,
+    metrics: Arc<MetricsCollector>,
+) -> tokio::task::JoinHandle<()> {
+    info!("Starting circuit breaker metrics task");
+
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    debug!(
+                        circuit = %event.name,
+                        from = %event.from,
+                        to = %event.to,
+                        "Recording circuit breaker transition"
+                    );
+                    metrics.record_circuit_transition(&event.name, event.to);
+                },
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        "Circuit breaker metrics lagged behind, events dropped"
+                    );
+                },
+                Err(RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use infrastructure::adapters::CircuitState;
+
+    fn event(from: CircuitState, to: CircuitState) -> CircuitBreakerEvent {
+        CircuitBreakerEvent {
+            name: "weather".to_string(),
+            from,
+            to,
+            at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn records_transitions_until_channel_closes() {
+        let (tx, rx) = broadcast::channel(8);
+        let metrics = Arc::new(MetricsCollector::new());
+        let handle = spawn_circuit_breaker_metrics_task(rx, Arc::clone(&metrics));
+
+        tx.send(event(CircuitState::Closed, CircuitState::Open))
+            .unwrap();
+        tx.send(event(CircuitState::Open, CircuitState::HalfOpen))
+            .unwrap();
+        tx.send(event(CircuitState::HalfOpen, CircuitState::Closed))
+            .unwrap();
+        drop(tx);
+        handle.await.unwrap();
+
+        let circuits = metrics.circuit_breaker_metrics();
+        assert_eq!(circuits.len(), 1);
+        assert_eq!(circuits[0].0, "weather");
+        assert_eq!(circuits[0].1.state, CircuitState::Closed);
+        assert_eq!(circuits[0].1.transitions, [1, 1, 1]);
+    }
+}
@@ -0,0 +1,123 @@
+//! Database maintenance task
+//!
+//! Periodically runs `PRAGMA integrity_check` followed by `VACUUM` so that
+//! long-running deployments notice corruption early and reclaim free pages.
+
+use std::time::Duration;
+
+use infrastructure::persistence::{AsyncDatabase, SqliteDatabaseHealth};
+use tracing::{debug, error, info, warn};
+
+/// Default maintenance interval: once per week
+const DEFAULT_MAINTENANCE_INTERVAL_SECS: u64 = 7 * 24 * 3600;
+
+/// Run a single maintenance pass
+///
+/// Records the integrity result on `database_health` and only vacuums a
+/// database that passed the check, since rewriting a corrupted file can
+/// make recovery harder. Returns the integrity result.
+pub async fn run_database_maintenance(
+    database: &AsyncDatabase,
+    database_health: &SqliteDatabaseHealth,
+) -> bool {
+    let integrity_ok = match database.integrity_check().await {
+        Ok(ok) => ok,
+        Err(e) => {
+            error!(error = %e, "Failed to run database integrity check");
+            false
+        },
+    };
+    database_health.record_integrity(integrity_ok);
+
+    if !integrity_ok {
+        error!(
+            "🚨 CRITICAL: Database integrity check failed. Restore from a backup \
+             (set database.restore_backup_dir to restore automatically on restart)."
+        );
+        return false;
+    }
+
+    match database.vacuum().await {
+        Ok(()) => debug!("Database maintenance completed"),
+        Err(e) => warn!(error = %e, "Database VACUUM failed"),
+    }
+    true
+}
+
+/// Spawn a background task that periodically checks and vacuums the database.
+///
+/// The first run happens one `interval` after startup.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+///
+/// # Arguments
+///
+/// * `database` - The database to maintain
+/// * `database_health` - Health adapter that reports the integrity status
+/// * `interval` - How often to run maintenance (defaults to weekly if None)
+pub fn spawn_database_maintenance_task(
+    database: AsyncDatabase,
+    database_health: SqliteDatabaseHealth,
+    interval: Option<Duration>,
+) -> tokio::task::JoinHandle<()> {
+    let interval = interval.unwrap_or(Duration::from_secs(DEFAULT_MAINTENANCE_INTERVAL_SECS));
+
+    info!(
+        interval_secs = interval.as_secs(),
+        "Starting database maintenance task"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // Don't run immediately on startup
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            debug!("Running database maintenance");
+            run_database_maintenance(&database, &database_health).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup() -> (AsyncDatabase, SqliteDatabaseHealth) {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let health = SqliteDatabaseHealth::new(db.pool().clone());
+        (db, health)
+    }
+
+    #[tokio::test]
+    async fn maintenance_records_integrity() {
+        let (db, health) = setup().await;
+
+        assert!(run_database_maintenance(&db, &health).await);
+        assert_eq!(health.last_integrity(), Some(true));
+    }
+
+    #[tokio::test]
+    async fn maintenance_task_runs_periodically() {
+        let (db, health) = setup().await;
+
+        let handle =
+            spawn_database_maintenance_task(db, health.clone(), Some(Duration::from_millis(50)));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        handle.abort();
+
+        assert_eq!(health.last_integrity(), Some(true));
+    }
+
+    #[tokio::test]
+    async fn maintenance_task_can_be_aborted() {
+        let (db, health) = setup().await;
+
+        let handle = spawn_database_maintenance_task(db, health, None);
+        handle.abort();
+
+        assert!(handle.await.is_err());
+    }
+}
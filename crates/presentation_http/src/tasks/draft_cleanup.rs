@@ -0,0 +1,127 @@
+//! Email draft cleanup task
+//!
+//! Periodically removes email drafts that have expired or outlived the configured TTL.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use application::ports::DraftStorePort;
+use chrono::Utc;
+use tracing::{debug, error, info};
+
+/// Default cleanup interval: once per day
+const DEFAULT_CLEANUP_INTERVAL_SECS: u64 = 86_400;
+
+/// Spawn a background task that periodically deletes expired email drafts.
+///
+/// The TTL is enforced by the draft store itself; this task only triggers
+/// the cleanup and logs how many drafts were removed.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+///
+/// # Arguments
+///
+/// * `draft_store` - The draft store to clean
+/// * `cleanup_interval` - How often to run the cleanup (defaults to 1 day if None)
+pub fn spawn_draft_cleanup_task(
+    draft_store: Arc<dyn DraftStorePort>,
+    cleanup_interval: Option<Duration>,
+) -> tokio::task::JoinHandle<()> {
+    let interval = cleanup_interval.unwrap_or(Duration::from_secs(DEFAULT_CLEANUP_INTERVAL_SECS));
+
+    info!(
+        interval_secs = interval.as_secs(),
+        "Starting email draft cleanup task"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // Don't run immediately on startup
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+
+            debug!("Running email draft cleanup");
+
+            match draft_store.cleanup_expired(Utc::now()).await {
+                Ok(removed) => {
+                    if removed > 0 {
+                        info!(removed_count = removed, "Cleaned up expired email drafts");
+                    } else {
+                        debug!("No email drafts to clean up");
+                    }
+                },
+                Err(e) => {
+                    error!(
+                        error = %e,
+                        "Failed to clean up expired email drafts"
+                    );
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::error::ApplicationError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use domain::{DraftId, PersistedEmailDraft, UserId};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MockDraftStore {
+        cleanup_calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl DraftStorePort for MockDraftStore {
+        async fn save(&self, draft: &PersistedEmailDraft) -> Result<DraftId, ApplicationError> {
+            Ok(draft.id)
+        }
+
+        async fn get(&self, _: &DraftId) -> Result<Option<PersistedEmailDraft>, ApplicationError> {
+            Ok(None)
+        }
+
+        async fn get_for_user(
+            &self,
+            _: &DraftId,
+            _: &UserId,
+        ) -> Result<Option<PersistedEmailDraft>, ApplicationError> {
+            Ok(None)
+        }
+
+        async fn delete(&self, _: &DraftId) -> Result<bool, ApplicationError> {
+            Ok(false)
+        }
+
+        async fn list_for_user(
+            &self,
+            _: &UserId,
+            _: usize,
+        ) -> Result<Vec<PersistedEmailDraft>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn cleanup_expired(&self, _: DateTime<Utc>) -> Result<usize, ApplicationError> {
+            self.cleanup_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(2)
+        }
+    }
+
+    #[tokio::test]
+    async fn cleanup_task_calls_cleanup_periodically() {
+        let store = Arc::new(MockDraftStore {
+            cleanup_calls: AtomicUsize::new(0),
+        });
+
+        let handle = spawn_draft_cleanup_task(store.clone(), Some(Duration::from_millis(50)));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        handle.abort();
+
+        assert!(store.cleanup_calls.load(Ordering::SeqCst) >= 1);
+    }
+}
@@ -0,0 +1,44 @@
+//! JWKS refresh task
+//!
+//! Keeps the identity provider's signing keys cached so JWT validation does
+//! not hit the network on every request.
+
+use std::{sync::Arc, time::Duration};
+
+use tracing::{debug, info, warn};
+
+use crate::middleware::JwksCache;
+
+/// Spawn a background task that periodically fetches the JWKS.
+///
+/// The first fetch happens immediately. Failed fetches keep the previously
+/// cached keys.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+///
+/// # Arguments
+///
+/// * `jwks` - The key cache shared with the JWT validator
+/// * `refresh_interval` - How often to fetch the JWKS
+pub fn spawn_jwks_refresh_task(
+    jwks: Arc<JwksCache>,
+    refresh_interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    info!(
+        interval_secs = refresh_interval.as_secs(),
+        "Starting JWKS refresh task"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(refresh_interval);
+
+        loop {
+            ticker.tick().await;
+
+            match jwks.refresh().await {
+                Ok(count) => debug!(count, "JWKS refreshed"),
+                Err(e) => warn!(error = %e, "JWKS refresh failed, keeping cached keys"),
+            }
+        }
+    })
+}
@@ -0,0 +1,217 @@
+//! Memory consolidation task
+//!
+//! Periodically decays memory importance, merges near-duplicate memories and
+//! prunes entries that have become irrelevant, keeping the RAG store small.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use application::{
+    MemoryService,
+    ports::{EmbeddingPort, EncryptionPort, MemoryStore},
+};
+use tracing::{debug, error, info};
+
+/// Default consolidation interval: once per day
+const DEFAULT_CONSOLIDATION_INTERVAL_SECS: u64 = 86_400;
+
+/// Spawn a background task that periodically consolidates the memory store.
+///
+/// The first run happens one `interval` after startup.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+///
+/// # Arguments
+///
+/// * `memory_service` - The memory service whose store is consolidated
+/// * `interval` - How often to run consolidation (defaults to 1 day if None)
+pub fn spawn_memory_consolidation_task<S, E, C>(
+    memory_service: Arc<MemoryService<S, E, C>>,
+    interval: Option<Duration>,
+) -> tokio::task::JoinHandle<()>
+where
+    S: MemoryStore + 'static,
+    E: EmbeddingPort + 'static,
+    C: EncryptionPort + 'static,
+{
+    let interval = interval.unwrap_or(Duration::from_secs(DEFAULT_CONSOLIDATION_INTERVAL_SECS));
+
+    info!(
+        interval_secs = interval.as_secs(),
+        "Starting memory consolidation task"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // Don't run immediately on startup
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+
+            debug!("Running memory consolidation");
+
+            match memory_service.consolidate().await {
+                Ok(report) => {
+                    info!(
+                        before_count = report.before.total_count,
+                        after_count = report.after.total_count,
+                        before_avg_importance = report.before.avg_importance,
+                        after_avg_importance = report.after.avg_importance,
+                        merged = report.merged,
+                        pruned = report.pruned,
+                        "Memory consolidation completed"
+                    );
+                },
+                Err(e) => {
+                    error!(error = %e, "Failed to consolidate memories");
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::{
+        MemoryServiceConfig,
+        error::ApplicationError,
+        ports::{EmbeddingModelInfo, MemoryStats, NoOpEncryption, SimilarMemory},
+    };
+    use async_trait::async_trait;
+    use domain::{Memory, MemoryId, MemoryQuery, MemoryType, TenantContext, UserId};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct MockMemoryStore {
+        decay_calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MemoryStore for MockMemoryStore {
+        async fn save(&self, _: &Memory) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn get(
+            &self,
+            _: &TenantContext,
+            _: &MemoryId,
+        ) -> Result<Option<Memory>, ApplicationError> {
+            Ok(None)
+        }
+
+        async fn update(&self, _: &TenantContext, _: &Memory) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _: &TenantContext, _: &MemoryId) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn search_similar(
+            &self,
+            _: &TenantContext,
+            _: &UserId,
+            _: &[f32],
+            _: usize,
+            _: f32,
+        ) -> Result<Vec<SimilarMemory>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn list(&self, _: &MemoryQuery) -> Result<Vec<Memory>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn list_by_type(
+            &self,
+            _: &TenantContext,
+            _: &UserId,
+            _: MemoryType,
+            _: usize,
+        ) -> Result<Vec<Memory>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn apply_decay(
+            &self,
+            _: &TenantContext,
+            _: f32,
+        ) -> Result<Vec<MemoryId>, ApplicationError> {
+            self.decay_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![])
+        }
+
+        async fn cleanup_below_threshold(
+            &self,
+            _: &TenantContext,
+            _: f32,
+        ) -> Result<usize, ApplicationError> {
+            Ok(0)
+        }
+
+        async fn find_merge_candidates(
+            &self,
+            _: &Memory,
+            _: f32,
+        ) -> Result<Vec<SimilarMemory>, ApplicationError> {
+            Ok(vec![])
+        }
+
+        async fn stats(
+            &self,
+            _: &TenantContext,
+            _: &UserId,
+        ) -> Result<MemoryStats, ApplicationError> {
+            Ok(MemoryStats::default())
+        }
+
+        async fn record_access(
+            &self,
+            _: &TenantContext,
+            _: &MemoryId,
+        ) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    struct MockEmbedding;
+
+    #[async_trait]
+    impl EmbeddingPort for MockEmbedding {
+        async fn embed(&self, _: &str) -> Result<Vec<f32>, ApplicationError> {
+            Ok(vec![0.0; 4])
+        }
+
+        async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, ApplicationError> {
+            Ok(texts.iter().map(|_| vec![0.0; 4]).collect())
+        }
+
+        fn model_info(&self) -> EmbeddingModelInfo {
+            EmbeddingModelInfo {
+                model: "test".to_string(),
+                dimensions: 4,
+                max_tokens: None,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn consolidation_task_runs_periodically() {
+        let store = Arc::new(MockMemoryStore::default());
+        let service = Arc::new(MemoryService::new(
+            Arc::clone(&store),
+            Arc::new(MockEmbedding),
+            Arc::new(NoOpEncryption),
+            MemoryServiceConfig::default(),
+        ));
+
+        let handle = spawn_memory_consolidation_task(service, Some(Duration::from_millis(50)));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        handle.abort();
+
+        assert!(store.decay_calls.load(Ordering::SeqCst) >= 1);
+    }
+}
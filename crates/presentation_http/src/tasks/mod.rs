@@ -1,7 +1,23 @@
 //! Background tasks for the HTTP presentation layer
 
+mod circuit_breaker_metrics;
 mod conversation_cleanup;
+mod database_maintenance;
+mod draft_cleanup;
+mod jwks_refresh;
+mod memory_consolidation;
+mod model_warmup;
+mod retry_worker;
+mod secret_refresh;
 mod signal_polling;
 
+pub use circuit_breaker_metrics::spawn_circuit_breaker_metrics_task;
 pub use conversation_cleanup::spawn_conversation_cleanup_task;
+pub use database_maintenance::{run_database_maintenance, spawn_database_maintenance_task};
+pub use draft_cleanup::spawn_draft_cleanup_task;
+pub use jwks_refresh::spawn_jwks_refresh_task;
+pub use memory_consolidation::spawn_memory_consolidation_task;
+pub use model_warmup::spawn_model_warmup_task;
+pub use retry_worker::spawn_retry_worker_task;
+pub use secret_refresh::spawn_secret_refresh_task;
 pub use signal_polling::spawn_signal_polling_task;
@@ -0,0 +1,126 @@
+//! Model warmup task
+//!
+//! Loads the inference model right after startup so the first user request
+//! doesn't pay for it. `/ready` reports not-ready until the warmup finished.
+
+use std::sync::Arc;
+
+use application::ports::InferencePort;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+/// Spawn a background task that warms up the inference model.
+///
+/// `done` is set to `true` once the attempt finished, whether it succeeded
+/// or not; a failed warmup leaves the model to load on first use.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+pub fn spawn_model_warmup_task(
+    inference: Arc<dyn InferencePort>,
+    done: watch::Sender<bool>,
+) -> tokio::task::JoinHandle<()> {
+    info!(model = %inference.current_model(), "🔥 Warming up inference model");
+
+    tokio::spawn(async move {
+        match inference.warmup().await {
+            Ok(()) => info!(model = %inference.current_model(), "🔥 Model warmup complete"),
+            Err(e) => warn!(error = %e, "⚠️ Model warmup failed, model will load on first use"),
+        }
+        done.send_replace(true);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use application::{
+        error::ApplicationError,
+        ports::{InferenceResult, InferenceStream},
+    };
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// Backend whose warmup succeeds or fails as configured
+    struct WarmupInference {
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl InferencePort for WarmupInference {
+        async fn generate(&self, _message: &str) -> Result<InferenceResult, ApplicationError> {
+            if self.fail {
+                return Err(ApplicationError::Inference("model not found".to_string()));
+            }
+            Ok(InferenceResult {
+                content: "Hello".to_string(),
+                model: "test-model".to_string(),
+                tokens_used: Some(1),
+                latency_ms: 1,
+            })
+        }
+
+        async fn generate_with_context(
+            &self,
+            _conversation: &domain::Conversation,
+        ) -> Result<InferenceResult, ApplicationError> {
+            self.generate("").await
+        }
+
+        async fn generate_with_system(
+            &self,
+            _system_prompt: &str,
+            message: &str,
+        ) -> Result<InferenceResult, ApplicationError> {
+            self.generate(message).await
+        }
+
+        async fn generate_stream(
+            &self,
+            _message: &str,
+        ) -> Result<InferenceStream, ApplicationError> {
+            Err(ApplicationError::Internal("not supported".to_string()))
+        }
+
+        async fn generate_stream_with_system(
+            &self,
+            _system_prompt: &str,
+            _message: &str,
+        ) -> Result<InferenceStream, ApplicationError> {
+            Err(ApplicationError::Internal("not supported".to_string()))
+        }
+
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+
+        fn current_model(&self) -> String {
+            "test-model".to_string()
+        }
+
+        async fn list_available_models(&self) -> Result<Vec<String>, ApplicationError> {
+            Ok(vec!["test-model".to_string()])
+        }
+
+        async fn switch_model(&self, _model_name: &str) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn marks_warmup_done_on_success() {
+        let (tx, rx) = watch::channel(false);
+        spawn_model_warmup_task(Arc::new(WarmupInference { fail: false }), tx)
+            .await
+            .unwrap();
+        assert!(*rx.borrow());
+    }
+
+    #[tokio::test]
+    async fn marks_warmup_done_on_failure() {
+        let (tx, rx) = watch::channel(false);
+        spawn_model_warmup_task(Arc::new(WarmupInference { fail: true }), tx)
+            .await
+            .unwrap();
+        assert!(*rx.borrow());
+    }
+}
@@ -0,0 +1,298 @@
+//! Retry queue worker task
+//!
+//! Periodically redelivers outgoing messages that failed to send. Messages
+//! that exhaust their retries are moved to the dead letter queue by the
+//! retry queue itself; messages past their validity window are dropped.
+//! After every run the queue depth is recorded in the metrics.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use application::ports::{MessengerPort, RetryQueuePort};
+use chrono::Utc;
+use tracing::{debug, error, info, warn};
+
+use crate::handlers::metrics::MetricsCollector;
+
+/// Default delivery interval: every 30 seconds
+const DEFAULT_RETRY_INTERVAL_SECS: u64 = 30;
+
+/// Maximum number of messages redelivered per run
+const BATCH_SIZE: usize = 20;
+
+/// Spawn a background task that periodically redelivers queued messages.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+///
+/// # Arguments
+///
+/// * `retry_queue` - The queue holding undelivered messages
+/// * `messenger` - The messenger used to send them; must not queue failed sends itself
+/// * `metrics` - Collector the queue depth is reported to
+/// * `retry_interval` - How often to check for due messages (defaults to 30 seconds if None)
+pub fn spawn_retry_worker_task(
+    retry_queue: Arc<dyn RetryQueuePort>,
+    messenger: Arc<dyn MessengerPort>,
+    metrics: Arc<MetricsCollector>,
+    retry_interval: Option<Duration>,
+) -> tokio::task::JoinHandle<()> {
+    let interval = retry_interval.unwrap_or(Duration::from_secs(DEFAULT_RETRY_INTERVAL_SECS));
+
+    info!(
+        interval_secs = interval.as_secs(),
+        "Starting retry queue worker"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            deliver_due_messages(retry_queue.as_ref(), messenger.as_ref()).await;
+
+            match retry_queue.queue_stats().await {
+                Ok(stats) => metrics.record_retry_queue(stats),
+                Err(e) => warn!(error = %e, "Failed to read retry queue statistics"),
+            }
+        }
+    })
+}
+
+/// Single run: send every due message once and report the outcome.
+///
+/// Returns the number of messages delivered.
+async fn deliver_due_messages(
+    retry_queue: &dyn RetryQueuePort,
+    messenger: &dyn MessengerPort,
+) -> usize {
+    let due = match retry_queue.due_messages(BATCH_SIZE).await {
+        Ok(due) => due,
+        Err(e) => {
+            error!(error = %e, "Failed to fetch due messages from retry queue");
+            return 0;
+        },
+    };
+
+    if due.is_empty() {
+        debug!("No queued messages due for redelivery");
+        return 0;
+    }
+
+    let mut delivered = 0;
+    for queued in due {
+        let recipient = queued.message.recipient.to_string();
+        if queued.message.is_expired_at(Utc::now()) {
+            info!(
+                id = %queued.id,
+                recipient = %recipient,
+                context = queued.message.context.as_deref().unwrap_or("-"),
+                "Dropping queued message past its validity window"
+            );
+            if let Err(e) = retry_queue.complete(&queued.id).await {
+                error!(id = %queued.id, error = %e, "Failed to drop expired queued message");
+            }
+            continue;
+        }
+
+        match messenger.send_text(queued.message).await {
+            Ok(_) => {
+                delivered += 1;
+                if let Err(e) = retry_queue.complete(&queued.id).await {
+                    error!(id = %queued.id, error = %e, "Failed to mark queued message as delivered");
+                }
+            },
+            Err(send_error) => match retry_queue.fail(&queued.id, &send_error.to_string()).await {
+                Ok(true) => debug!(
+                    id = %queued.id,
+                    recipient = %recipient,
+                    attempt = queued.attempt_count + 1,
+                    error = %send_error,
+                    "Redelivery failed, will retry"
+                ),
+                Ok(false) => warn!(
+                    id = %queued.id,
+                    recipient = %recipient,
+                    error = %send_error,
+                    "Redelivery failed permanently, message moved to dead letter queue"
+                ),
+                Err(e) => {
+                    error!(id = %queued.id, error = %e, "Failed to record redelivery failure");
+                },
+            },
+        }
+    }
+
+    if delivered > 0 {
+        info!(delivered, "Redelivered queued messages");
+    }
+    delivered
+}
+
+#[cfg(test)]
+mod tests {
+    use application::{
+        error::ApplicationError,
+        ports::{DownloadedAudio, OutgoingAudioMessage, OutgoingTextMessage},
+    };
+    use async_trait::async_trait;
+    use domain::{MessengerSource, PhoneNumber};
+    use infrastructure::{
+        AsyncDatabase, JitterStrategy, RetryConfig, persistence::RetryQueueStore,
+    };
+
+    use super::*;
+
+    /// Messenger that either delivers every message or rejects every message
+    struct TestMessenger {
+        available: bool,
+    }
+
+    #[async_trait]
+    impl MessengerPort for TestMessenger {
+        fn source(&self) -> MessengerSource {
+            MessengerSource::Signal
+        }
+
+        async fn is_available(&self) -> bool {
+            self.available
+        }
+
+        async fn is_whitelisted(&self, _phone: &PhoneNumber) -> bool {
+            true
+        }
+
+        async fn send_text(
+            &self,
+            _message: OutgoingTextMessage,
+        ) -> Result<String, ApplicationError> {
+            if self.available {
+                Ok("1700000000000".to_string())
+            } else {
+                Err(ApplicationError::ExternalService(
+                    "signal-cli daemon unavailable".to_string(),
+                ))
+            }
+        }
+
+        async fn send_audio(
+            &self,
+            _message: OutgoingAudioMessage,
+        ) -> Result<String, ApplicationError> {
+            Err(ApplicationError::Internal("not supported".to_string()))
+        }
+
+        async fn download_audio(
+            &self,
+            _media_id: &str,
+        ) -> Result<DownloadedAudio, ApplicationError> {
+            Err(ApplicationError::Internal("not supported".to_string()))
+        }
+
+        async fn mark_read(&self, _message_id: &str) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    async fn queue_with_message(db: &AsyncDatabase, max_retries: u32) -> RetryQueueStore {
+        let message = OutgoingTextMessage::new(PhoneNumber::new("+491701234567").unwrap(), "Hello");
+        queue_with(db, max_retries, message).await
+    }
+
+    async fn queue_with(
+        db: &AsyncDatabase,
+        max_retries: u32,
+        message: OutgoingTextMessage,
+    ) -> RetryQueueStore {
+        let store = RetryQueueStore::with_config(
+            db.pool().clone(),
+            RetryConfig {
+                initial_delay_ms: 0,
+                max_delay_ms: 0,
+                multiplier: 1.0,
+                max_retries,
+                jitter_enabled: false,
+                jitter_factor: 0.0,
+                jitter: JitterStrategy::None,
+            },
+        );
+        store
+            .enqueue_message(&message, "signal-cli daemon unavailable")
+            .await
+            .unwrap();
+        store
+    }
+
+    #[tokio::test]
+    async fn delivers_queued_message() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let store = queue_with_message(&db, 3).await;
+
+        let delivered = deliver_due_messages(&store, &TestMessenger { available: true }).await;
+
+        assert_eq!(delivered, 1);
+        let stats = store.queue_stats().await.unwrap();
+        assert_eq!(stats.backlog(), 0);
+        assert_eq!(stats.dead_letter, 0);
+    }
+
+    #[tokio::test]
+    async fn drops_message_past_validity_window() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let message = OutgoingTextMessage::new(PhoneNumber::new("+491701234567").unwrap(), "Hello")
+            .with_context("reminder:1")
+            .with_valid_until(Utc::now() - chrono::Duration::minutes(1));
+        let store = queue_with(&db, 3, message).await;
+
+        let delivered = deliver_due_messages(&store, &TestMessenger { available: true }).await;
+
+        assert_eq!(delivered, 0);
+        let stats = store.queue_stats().await.unwrap();
+        assert_eq!(stats.backlog(), 0);
+        assert_eq!(stats.dead_letter, 0);
+    }
+
+    #[tokio::test]
+    async fn worker_records_queue_depth() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let store = queue_with_message(&db, 3).await;
+        let metrics = Arc::new(MetricsCollector::new());
+
+        let handle = spawn_retry_worker_task(
+            Arc::new(store),
+            Arc::new(TestMessenger { available: false }),
+            Arc::clone(&metrics),
+            Some(Duration::from_millis(20)),
+        );
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+
+        let stats = metrics.retry_queue_stats().unwrap();
+        assert_eq!(stats.backlog(), 1);
+    }
+
+    #[tokio::test]
+    async fn message_exhausting_retries_lands_in_dead_letter_queue() {
+        let db = AsyncDatabase::in_memory().await.unwrap();
+        db.migrate().await.unwrap();
+        let store = queue_with_message(&db, 2).await;
+        let messenger = TestMessenger { available: false };
+
+        for _ in 0..2 {
+            assert_eq!(deliver_due_messages(&store, &messenger).await, 0);
+        }
+
+        let stats = store.queue_stats().await.unwrap();
+        assert_eq!(stats.backlog(), 0);
+        assert_eq!(stats.dead_letter, 1);
+
+        let dead = RetryQueuePort::list_dead_letters(&store, 10).await.unwrap();
+        assert_eq!(dead[0].target, "+491701234567");
+        assert_eq!(
+            dead[0].last_error.as_deref(),
+            Some("External service error: signal-cli daemon unavailable")
+        );
+    }
+}
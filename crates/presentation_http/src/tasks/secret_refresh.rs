@@ -0,0 +1,130 @@
+//! Secret refresh task
+//!
+//! Periodically re-reads rotating secrets from the secret store so secrets
+//! rotated in Vault reach the adapters without a restart.
+
+use std::{sync::Arc, time::Duration};
+
+use application::ports::SecretStorePort;
+use tracing::{debug, info};
+
+use crate::config_reload::RotatingSecrets;
+
+/// Spawn a background task that periodically re-reads rotating secrets.
+///
+/// Each run refreshes only the secret store paths of `secrets`, bypassing the
+/// secret cache for those paths, and swaps changed passwords into the
+/// adapters. The config file is not reloaded; SIGHUP does that on demand.
+///
+/// Returns a `JoinHandle` that can be used to abort the task when shutting down.
+///
+/// # Arguments
+///
+/// * `store` - The secret store the secrets were resolved from
+/// * `secrets` - The adapter secrets and their store paths
+/// * `refresh_interval` - How often to refresh secrets
+pub fn spawn_secret_refresh_task(
+    store: Arc<dyn SecretStorePort>,
+    secrets: RotatingSecrets,
+    refresh_interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    info!(
+        interval_secs = refresh_interval.as_secs(),
+        "Starting secret refresh task"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(refresh_interval);
+        // Secrets were just resolved at startup
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+
+            let rotated = secrets.refresh(store.as_ref()).await;
+            debug!(rotated, "Secrets refreshed");
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use application::error::ApplicationError;
+    use async_trait::async_trait;
+    use infrastructure::SharedSecret;
+    use secrecy::ExposeSecret;
+
+    use super::*;
+
+    /// Serves a fixed password and records which paths were refreshed
+    struct RotatedStore {
+        password: &'static str,
+        refreshed: Mutex<Vec<String>>,
+        reads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SecretStorePort for RotatedStore {
+        async fn get_secret(&self, key: &str) -> Result<String, ApplicationError> {
+            Err(ApplicationError::NotFound(key.to_string()))
+        }
+
+        async fn get_json(&self, _path: &str) -> Result<serde_json::Value, ApplicationError> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            Ok(serde_json::json!({ "password": self.password }))
+        }
+
+        async fn exists(&self, _key: &str) -> Result<bool, ApplicationError> {
+            Ok(true)
+        }
+
+        async fn is_healthy(&self) -> bool {
+            true
+        }
+
+        async fn refresh_json(&self, path: &str) -> Result<serde_json::Value, ApplicationError> {
+            self.refreshed.lock().unwrap().push(path.to_string());
+            self.get_json(path).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refresh_task_rotates_store_secrets_periodically() {
+        let store = Arc::new(RotatedStore {
+            password: "rotated",
+            refreshed: Mutex::new(Vec::new()),
+            reads: AtomicUsize::new(0),
+        });
+        let caldav = SharedSecret::new("initial".into());
+        let secrets = RotatingSecrets::new()
+            .with_caldav(caldav.clone())
+            .with_store_paths(vec![("caldav", "pisovereign/caldav".to_string())]);
+
+        let handle = spawn_secret_refresh_task(
+            Arc::clone(&store) as Arc<dyn SecretStorePort>,
+            secrets,
+            Duration::from_secs(3600),
+        );
+
+        // The first tick is skipped: secrets were resolved at startup
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(store.reads.load(Ordering::SeqCst), 0);
+        assert_eq!(caldav.current().expose_secret(), "initial");
+
+        tokio::time::sleep(Duration::from_secs(3600)).await;
+        assert_eq!(caldav.current().expose_secret(), "rotated");
+
+        tokio::time::sleep(Duration::from_secs(3600)).await;
+        handle.abort();
+
+        assert_eq!(
+            *store.refreshed.lock().unwrap(),
+            vec!["pisovereign/caldav", "pisovereign/caldav"]
+        );
+    }
+}
@@ -8,13 +8,16 @@ use std::time::Duration;
 
 use application::AgentService;
 use application::VoiceMessageService;
-use application::ports::ConversationStore;
-use domain::PhoneNumber;
-use domain::entities::{Conversation, ConversationSource};
-use integration_signal::SignalClient;
+use application::ports::{ConversationStore, DeliveryStatusPort, DeliveryUpdate, RetryQueuePort};
+use chrono::{DateTime, Utc};
+use domain::entities::{ChatMessage, Conversation, ConversationSource};
+use domain::{DeliveryStatus, MessengerSource, PhoneNumber};
+use integration_signal::{ReceiptMessage, SignalClient};
 use tracing::{debug, error, info, warn};
 
-use crate::handlers::common::{conversation_id_from_phone, parse_audio_format};
+use crate::handlers::common::{
+    UNSUPPORTED_AUDIO_REPLY, apply_message_revision, queue_failed_reply, supported_audio_format,
+};
 
 /// Spawn a background task that periodically polls Signal for new messages.
 ///
@@ -30,6 +33,9 @@ use crate::handlers::common::{conversation_id_from_phone, parse_audio_format};
 /// * `agent_service` - The agent service for processing messages
 /// * `conversation_store` - Optional conversation persistence store
 /// * `voice_message_service` - Optional voice message processor (STT/TTS)
+/// * `voice_replies` - Whether to answer voice messages with synthesized audio
+/// * `delivery_status` - Optional store for delivery and read receipts
+/// * `retry_queue` - Optional queue for redelivering replies that failed to send
 /// * `poll_interval` - How often to poll for new messages
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_signal_polling_task(
@@ -37,6 +43,9 @@ pub fn spawn_signal_polling_task(
     agent_service: Arc<AgentService>,
     conversation_store: Option<Arc<dyn ConversationStore>>,
     voice_message_service: Option<Arc<VoiceMessageService>>,
+    voice_replies: bool,
+    delivery_status: Option<Arc<dyn DeliveryStatusPort>>,
+    retry_queue: Option<Arc<dyn RetryQueuePort>>,
     poll_interval: Duration,
 ) -> tokio::task::JoinHandle<()> {
     info!(
@@ -57,6 +66,9 @@ pub fn spawn_signal_polling_task(
                 &agent_service,
                 conversation_store.as_ref(),
                 voice_message_service.as_ref(),
+                voice_replies,
+                delivery_status.as_ref(),
+                retry_queue.as_ref(),
             )
             .await;
         }
@@ -69,6 +81,9 @@ async fn poll_and_process(
     agent_service: &AgentService,
     conversation_store: Option<&Arc<dyn ConversationStore>>,
     voice_message_service: Option<&Arc<VoiceMessageService>>,
+    voice_replies: bool,
+    delivery_status: Option<&Arc<dyn DeliveryStatusPort>>,
+    retry_queue: Option<&Arc<dyn RetryQueuePort>>,
 ) {
     // Non-blocking poll (timeout = 1s to avoid long blocking)
     let envelopes = match signal_client.receive(1).await {
@@ -101,15 +116,44 @@ async fn poll_and_process(
             continue;
         }
 
+        if let (Some(receipt), Some(store)) = (&envelope.receipt_message, delivery_status) {
+            record_receipt(store.as_ref(), receipt, envelope.timestamp).await;
+        }
+
+        // Keep the history in line with edits made in Signal
+        if let Some((target, body)) = envelope.edited_text() {
+            apply_message_revision(
+                conversation_store,
+                ConversationSource::Signal,
+                sender,
+                &target.to_string(),
+                Some(body),
+            )
+            .await;
+        }
+
         if let Some(data_message) = envelope.data_message {
             let timestamp = data_message.timestamp;
 
+            if let Some(target) = data_message.deleted_timestamp() {
+                apply_message_revision(
+                    conversation_store,
+                    ConversationSource::Signal,
+                    sender,
+                    &target.to_string(),
+                    None,
+                )
+                .await;
+                continue;
+            }
+
             // Handle text messages
             if let Some(ref body) = data_message.body {
                 handle_text_message(
                     signal_client,
                     agent_service,
                     conversation_store,
+                    retry_queue,
                     sender,
                     timestamp,
                     body,
@@ -123,7 +167,9 @@ async fn poll_and_process(
                     handle_audio_message(
                         signal_client,
                         agent_service,
+                        conversation_store,
                         voice_message_service,
+                        voice_replies,
                         sender,
                         timestamp,
                         attachment,
@@ -143,11 +189,45 @@ async fn poll_and_process(
     }
 }
 
+/// Map a signal-cli receipt type to a delivery status.
+fn receipt_status(receipt_type: &str) -> Option<DeliveryStatus> {
+    match receipt_type.to_uppercase().as_str() {
+        "DELIVERY" => Some(DeliveryStatus::Delivered),
+        "READ" | "VIEWED" => Some(DeliveryStatus::Read),
+        _ => None,
+    }
+}
+
+/// Record a delivery or read receipt for the messages it covers.
+///
+/// Signal identifies sent messages by their timestamp, which is also the
+/// message ID returned when sending.
+async fn record_receipt(store: &dyn DeliveryStatusPort, receipt: &ReceiptMessage, when: i64) {
+    let Some(status) = receipt_status(&receipt.receipt_type) else {
+        debug!(receipt_type = %receipt.receipt_type, "Signal auto-poll: ignoring receipt type");
+        return;
+    };
+    let timestamp = DateTime::from_timestamp_millis(when).unwrap_or_else(Utc::now);
+
+    for message_timestamp in &receipt.timestamps {
+        let update = DeliveryUpdate::new(
+            message_timestamp.to_string(),
+            MessengerSource::Signal,
+            status,
+            timestamp,
+        );
+        if let Err(e) = store.apply_update(&update).await {
+            warn!(error = %e, "Signal auto-poll: failed to record receipt");
+        }
+    }
+}
+
 /// Process a text message through the agent and reply via Signal.
 async fn handle_text_message(
     signal_client: &SignalClient,
     agent_service: &AgentService,
     conversation_store: Option<&Arc<dyn ConversationStore>>,
+    retry_queue: Option<&Arc<dyn RetryQueuePort>>,
     from: &str,
     timestamp: i64,
     text: &str,
@@ -159,11 +239,52 @@ async fn handle_text_message(
         "Signal auto-poll: processing text message"
     );
 
+    let Some(response_text) = reply_with_agent(
+        signal_client,
+        agent_service,
+        conversation_store,
+        from,
+        timestamp,
+        text,
+    )
+    .await
+    else {
+        return;
+    };
+
+    if let Err(e) = signal_client.send_text(from, &response_text).await {
+        error!(
+            error = %e,
+            from = %from,
+            "Signal auto-poll: failed to send response"
+        );
+        queue_failed_reply(retry_queue, from, &response_text, &e.to_string()).await;
+    } else {
+        info!(
+            from = %from,
+            timestamp = timestamp,
+            "Signal auto-poll: text message processed and response sent"
+        );
+    }
+}
+
+/// Run a message through the agent and record it in the conversation.
+///
+/// Returns the agent's reply. On failure the user is sent an apology and
+/// `None` is returned.
+async fn reply_with_agent(
+    signal_client: &SignalClient,
+    agent_service: &AgentService,
+    conversation_store: Option<&Arc<dyn ConversationStore>>,
+    from: &str,
+    timestamp: i64,
+    text: &str,
+) -> Option<String> {
     let phone = match PhoneNumber::new(from) {
         Ok(p) => p,
         Err(e) => {
             warn!(error = %e, from = %from, "Signal auto-poll: invalid sender phone number");
-            return;
+            return None;
         },
     };
 
@@ -194,7 +315,7 @@ async fn handle_text_message(
         Conversation::for_messenger(ConversationSource::Signal, phone)
     };
 
-    conversation.add_user_message(text);
+    conversation.add_message(ChatMessage::user(text).with_external_id(timestamp.to_string()));
 
     // Process through agent
     let result = agent_service.handle_input(text).await;
@@ -215,22 +336,14 @@ async fn handle_text_message(
                 }
             }
 
-            // Send response back
-            if let Err(e) = signal_client.send_text(from, &response_text).await {
-                error!(
-                    error = %e,
-                    from = %from,
-                    "Signal auto-poll: failed to send response"
-                );
-            } else {
-                info!(
-                    from = %from,
-                    timestamp = timestamp,
-                    conversation_id = %conversation.id,
-                    success = agent_result.success,
-                    "Signal auto-poll: text message processed and response sent"
-                );
-            }
+            debug!(
+                from = %from,
+                timestamp = timestamp,
+                conversation_id = %conversation.id,
+                success = agent_result.success,
+                "Signal auto-poll: agent replied"
+            );
+            Some(response_text)
         },
         Err(e) => {
             error!(
@@ -257,15 +370,23 @@ async fn handle_text_message(
                     "Sorry, I couldn't process your message right now. Please try again later.",
                 )
                 .await;
+            None
         },
     }
 }
 
 /// Process an audio/voice message through STT → agent → TTS and reply.
+///
+/// The transcription is handled like a text message, so commands work by
+/// voice too. The reply is spoken if `voice_replies` is set and synthesis
+/// succeeds, and sent as text otherwise.
+#[allow(clippy::too_many_arguments)]
 async fn handle_audio_message(
     signal_client: &SignalClient,
-    _agent_service: &AgentService,
+    agent_service: &AgentService,
+    conversation_store: Option<&Arc<dyn ConversationStore>>,
     voice_message_service: Option<&Arc<VoiceMessageService>>,
+    voice_replies: bool,
     from: &str,
     timestamp: i64,
     attachment: &integration_signal::Attachment,
@@ -288,6 +409,15 @@ async fn handle_audio_message(
         return;
     };
 
+    let Some(format) = supported_audio_format(&attachment.content_type) else {
+        warn!(
+            content_type = %attachment.content_type,
+            "Signal auto-poll: unsupported audio format"
+        );
+        let _ = signal_client.send_text(from, UNSUPPORTED_AUDIO_REPLY).await;
+        return;
+    };
+
     let Some(file_path) = signal_client.get_attachment_path(attachment) else {
         error!(
             timestamp = timestamp,
@@ -310,52 +440,14 @@ async fn handle_audio_message(
         "Signal auto-poll: read audio attachment"
     );
 
-    let format = parse_audio_format(&attachment.content_type);
-    let conversation_id = conversation_id_from_phone("signal", from);
-
-    let result = voice_service
-        .process_voice_message(
-            audio_data,
-            format,
-            conversation_id,
-            Some(timestamp.to_string()),
-        )
-        .await;
-
-    match result {
-        Ok(voice_result) => {
-            info!(
-                from = %from,
-                timestamp = timestamp,
-                transcription_len = voice_result.transcription.len(),
-                has_audio = voice_result.response_audio.is_some(),
-                "Signal auto-poll: voice message processed"
-            );
-
-            // Send audio response if available, otherwise text
-            if let Some(ref audio_response) = voice_result.response_audio {
-                match send_audio_response(signal_client, from, audio_response).await {
-                    Ok(()) => {},
-                    Err(e) => {
-                        warn!(error = %e, "Signal auto-poll: audio send failed, falling back to text");
-                        let _ = signal_client
-                            .send_text(from, &voice_result.response_text)
-                            .await;
-                    },
-                }
-            } else if let Err(e) = signal_client
-                .send_text(from, &voice_result.response_text)
-                .await
-            {
-                error!(error = %e, "Signal auto-poll: failed to send text response");
-            }
-        },
+    let transcription = match voice_service.transcribe(&audio_data, format).await {
+        Ok(transcription) => transcription.text,
         Err(e) => {
             error!(
                 error = %e,
                 from = %from,
                 timestamp = timestamp,
-                "Signal auto-poll: voice processing failed"
+                "Signal auto-poll: transcription failed"
             );
             let _ = signal_client
                 .send_text(
@@ -363,7 +455,69 @@ async fn handle_audio_message(
                     "Sorry, I couldn't process your voice message. Please try again or send a text message.",
                 )
                 .await;
+            return;
         },
+    };
+
+    if transcription.trim().is_empty() {
+        let _ = signal_client
+            .send_text(
+                from,
+                "I couldn't hear anything in your voice message. Please try again.",
+            )
+            .await;
+        return;
+    }
+
+    debug!(
+        transcription_len = transcription.len(),
+        "Signal auto-poll: voice message transcribed"
+    );
+
+    let Some(response_text) = reply_with_agent(
+        signal_client,
+        agent_service,
+        conversation_store,
+        from,
+        timestamp,
+        &transcription,
+    )
+    .await
+    else {
+        return;
+    };
+
+    if voice_replies {
+        match voice_service.synthesize(&response_text).await {
+            Ok(audio_response) => {
+                match send_audio_response(signal_client, from, &audio_response).await {
+                    Ok(()) => {
+                        info!(
+                            from = %from,
+                            timestamp = timestamp,
+                            "Signal auto-poll: voice message answered with audio"
+                        );
+                        return;
+                    },
+                    Err(e) => {
+                        warn!(error = %e, "Signal auto-poll: audio send failed, falling back to text");
+                    },
+                }
+            },
+            Err(e) => {
+                warn!(error = %e, "Signal auto-poll: speech synthesis failed, falling back to text");
+            },
+        }
+    }
+
+    if let Err(e) = signal_client.send_text(from, &response_text).await {
+        error!(error = %e, "Signal auto-poll: failed to send text response");
+    } else {
+        info!(
+            from = %from,
+            timestamp = timestamp,
+            "Signal auto-poll: voice message answered with text"
+        );
     }
 }
 
@@ -397,3 +551,16 @@ async fn send_audio_response(
 
     result.map(|_| ())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn receipt_types_map_to_delivery_status() {
+        assert_eq!(receipt_status("DELIVERY"), Some(DeliveryStatus::Delivered));
+        assert_eq!(receipt_status("READ"), Some(DeliveryStatus::Read));
+        assert_eq!(receipt_status("viewed"), Some(DeliveryStatus::Read));
+        assert_eq!(receipt_status("UNKNOWN"), None);
+    }
+}
@@ -0,0 +1,7 @@
+-- Migration 12: Encrypted-at-rest message content
+-- When conversation encryption is enabled, message content is stored as
+-- XChaCha20-Poly1305 ciphertext plus nonce and the plaintext column is empty.
+-- Rows with NULL ciphertext are plaintext (written before encryption was enabled).
+
+ALTER TABLE messages ADD COLUMN content_ciphertext BLOB;
+ALTER TABLE messages ADD COLUMN content_nonce BLOB;
@@ -0,0 +1,6 @@
+-- Migration 13: Composite index for audit log queries
+-- The audit endpoint filters by event type and time range and orders by
+-- timestamp; the single-column indexes from migration 1 cover each filter
+-- on its own but not the combination.
+
+CREATE INDEX IF NOT EXISTS idx_audit_event_type_timestamp ON audit_log(event_type, timestamp);
@@ -0,0 +1,5 @@
+-- Migration 14: Store why an IP was blocked
+-- Shown by the admin block listing; NULL for blocks created before this
+-- migration.
+
+ALTER TABLE ip_blocks ADD COLUMN reason TEXT;
@@ -0,0 +1,5 @@
+-- Migration 15: Recurring reminders
+-- Repeat rule in canonical form (e.g. "daily", "weekly:Mon"); NULL for
+-- one-off reminders.
+
+ALTER TABLE reminders ADD COLUMN recurrence TEXT;
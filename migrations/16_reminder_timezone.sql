@@ -0,0 +1,5 @@
+-- Migration 16: Reminder timezone
+-- IANA timezone the reminder was scheduled in (e.g. "Europe/Berlin");
+-- recurring reminders keep their local time of day in it. NULL means UTC.
+
+ALTER TABLE reminders ADD COLUMN timezone TEXT;
@@ -0,0 +1,9 @@
+-- Migration 17: Conversation owner
+-- User that owns the conversation; only the owner may export it.
+-- NULL marks conversations created before ownership was tracked and is
+-- read as the default user.
+
+ALTER TABLE conversations ADD COLUMN user_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_conversations_user
+    ON conversations(user_id);
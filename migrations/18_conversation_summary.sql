@@ -0,0 +1,8 @@
+-- Migration 18: Conversation summary
+-- Rolling summary of old turns that were condensed instead of truncated.
+-- Like message content, the summary is stored in `summary` as plaintext or,
+-- with encryption at rest, in `summary_ciphertext`/`summary_nonce`.
+
+ALTER TABLE conversations ADD COLUMN summary TEXT;
+ALTER TABLE conversations ADD COLUMN summary_ciphertext BLOB;
+ALTER TABLE conversations ADD COLUMN summary_nonce BLOB;
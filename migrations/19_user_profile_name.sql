@@ -0,0 +1,4 @@
+-- Migration 19: User profile name
+-- Name the assistant addresses the user by in the system prompt (optional).
+
+ALTER TABLE user_profiles ADD COLUMN display_name TEXT;
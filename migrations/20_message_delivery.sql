@@ -0,0 +1,26 @@
+-- Migration 20: Message delivery status
+-- Tracks delivery and read receipts for outgoing messenger messages.
+
+CREATE TABLE IF NOT EXISTS message_deliveries (
+    -- Messenger-assigned message ID (WhatsApp wamid or Signal timestamp)
+    message_id TEXT PRIMARY KEY,
+    -- Messenger: 'whatsapp', 'signal'
+    source TEXT NOT NULL CHECK(source IN ('whatsapp', 'signal')),
+    -- Recipient phone number (unknown if a receipt arrives before the send is recorded)
+    recipient TEXT,
+    -- Delivery status: 'sent', 'delivered', 'read', 'failed'
+    status TEXT NOT NULL DEFAULT 'sent'
+        CHECK(status IN ('sent', 'delivered', 'read', 'failed')),
+    -- Error reported by the messenger for failed deliveries
+    error TEXT,
+    -- Reminder this message notified about
+    reminder_id TEXT,
+    -- Timestamps (ISO 8601)
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- Undelivered reminder lookup
+CREATE INDEX IF NOT EXISTS idx_message_deliveries_reminder
+    ON message_deliveries(reminder_id, created_at)
+    WHERE reminder_id IS NOT NULL;
@@ -0,0 +1,9 @@
+-- Migration 21: Memory tenant isolation
+-- Tenant that owns the memory; all memory searches are filtered by it.
+-- Existing memories belong to the default single-tenant deployment.
+
+ALTER TABLE memories ADD COLUMN tenant_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001';
+
+CREATE INDEX IF NOT EXISTS idx_memories_tenant_user
+    ON memories(tenant_id, user_id);
@@ -0,0 +1,5 @@
+-- Migration 22: HTML part of email drafts
+-- Drafts rendered through the email templates keep an optional HTML version
+-- next to the plaintext body. Existing drafts stay plaintext only.
+
+ALTER TABLE email_drafts ADD COLUMN html_body TEXT;
@@ -0,0 +1,15 @@
+-- Migration 23: System prompt overrides
+-- Admin-defined system prompts that replace the global prompt for a single
+-- conversation or for every conversation with a messenger contact.
+
+CREATE TABLE IF NOT EXISTS system_prompt_overrides (
+    -- Target kind: 'conversation', 'contact'
+    target_kind TEXT NOT NULL CHECK(target_kind IN ('conversation', 'contact')),
+    -- Conversation ID or E.164 phone number of the contact
+    target_key TEXT NOT NULL,
+    -- Validated and sanitized prompt
+    prompt TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (target_kind, target_key)
+);
@@ -0,0 +1,4 @@
+-- Migration 24: User briefing sections
+-- Comma-separated morning briefing sections in display order (NULL = configured default).
+
+ALTER TABLE user_profiles ADD COLUMN briefing_sections TEXT;